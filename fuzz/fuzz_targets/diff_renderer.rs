@@ -0,0 +1,202 @@
+// Render an arbitrary commit diff, with arbitrary diff settings, in a window of an arbitrary size. The parts of a diff
+// that come from the repository, the messages, names, paths and lines, are arbitrary strings, while the hash is always
+// hexadecimal, and the times are in the range of 32 bit timestamps, as they are when loaded by Git.
+#![no_main]
+
+use arbitrary::Arbitrary;
+use config::{Config, DiffShowWhitespaceSetting};
+use git::{
+	testutil::{CommitBuilder, CommitDiffBuilder, FileStatusBuilder},
+	Delta,
+	DiffLine,
+	Origin,
+	Status,
+	User,
+};
+use libfuzzer_sys::fuzz_target;
+
+#[derive(Arbitrary, Debug)]
+enum FuzzOrigin {
+	Addition,
+	Binary,
+	Context,
+	Deletion,
+	Header,
+}
+
+#[derive(Arbitrary, Debug)]
+enum FuzzStatus {
+	Added,
+	Copied,
+	Deleted,
+	Modified,
+	Renamed,
+	Typechange,
+	Other,
+}
+
+#[derive(Arbitrary, Debug)]
+enum FuzzShowWhitespace {
+	Both,
+	Leading,
+	None,
+	Trailing,
+}
+
+#[derive(Arbitrary, Debug)]
+struct FuzzLine {
+	end_of_file: bool,
+	line: String,
+	new_line_number: Option<u32>,
+	old_line_number: Option<u32>,
+	origin: FuzzOrigin,
+}
+
+#[derive(Arbitrary, Debug)]
+struct FuzzDelta {
+	header: String,
+	lines: Vec<FuzzLine>,
+	new_lines_start: u32,
+	new_number_lines: u32,
+	old_lines_start: u32,
+	old_number_lines: u32,
+}
+
+#[derive(Arbitrary, Debug)]
+struct FuzzFileStatus {
+	deltas: Vec<FuzzDelta>,
+	destination_is_binary: bool,
+	destination_path: String,
+	largest_new_line_number: u32,
+	largest_old_line_number: u32,
+	source_is_binary: bool,
+	source_path: String,
+	status: FuzzStatus,
+}
+
+#[derive(Arbitrary, Debug)]
+struct FuzzInput {
+	author: Option<(Option<String>, Option<String>)>,
+	authored_time: Option<i32>,
+	commit_time: i32,
+	committer: Option<(Option<String>, Option<String>)>,
+	deletions: usize,
+	file_statuses: Vec<FuzzFileStatus>,
+	files_changed: usize,
+	hash: [u8; 20],
+	height: u8,
+	icons: bool,
+	insertions: usize,
+	message: Option<String>,
+	screen_reader: bool,
+	show_whitespace: FuzzShowWhitespace,
+	space_symbol: String,
+	summary: Option<String>,
+	tab_symbol: String,
+	tab_width: u8,
+	width: u8,
+}
+
+fn user(user: &(Option<String>, Option<String>)) -> User {
+	User::new(user.0.as_deref(), user.1.as_deref())
+}
+
+fn build_delta(fuzz_delta: &FuzzDelta) -> Delta {
+	let mut delta = Delta::new(
+		fuzz_delta.header.as_str(),
+		fuzz_delta.old_lines_start,
+		fuzz_delta.new_lines_start,
+		fuzz_delta.old_number_lines,
+		fuzz_delta.new_number_lines,
+	);
+	for line in &fuzz_delta.lines {
+		let origin = match line.origin {
+			FuzzOrigin::Addition => Origin::Addition,
+			FuzzOrigin::Binary => Origin::Binary,
+			FuzzOrigin::Context => Origin::Context,
+			FuzzOrigin::Deletion => Origin::Deletion,
+			FuzzOrigin::Header => Origin::Header,
+		};
+		delta.add_line(&DiffLine::new(
+			origin,
+			line.line.as_str(),
+			line.old_line_number,
+			line.new_line_number,
+			line.end_of_file,
+		));
+	}
+	delta
+}
+
+fn build_file_status(file_status: &FuzzFileStatus) -> git::FileStatus {
+	let status = match file_status.status {
+		FuzzStatus::Added => Status::Added,
+		FuzzStatus::Copied => Status::Copied,
+		FuzzStatus::Deleted => Status::Deleted,
+		FuzzStatus::Modified => Status::Modified,
+		FuzzStatus::Renamed => Status::Renamed,
+		FuzzStatus::Typechange => Status::Typechange,
+		FuzzStatus::Other => Status::Other,
+	};
+	file_status
+		.deltas
+		.iter()
+		.fold(
+			FileStatusBuilder::new()
+				.destination_is_binary(file_status.destination_is_binary)
+				.destination_path(file_status.destination_path.as_str())
+				.largest_new_line_number(file_status.largest_new_line_number)
+				.largest_old_line_number(file_status.largest_old_line_number)
+				.source_is_binary(file_status.source_is_binary)
+				.source_path(file_status.source_path.as_str())
+				.status(status),
+			|builder, delta| builder.push_delta(build_delta(delta)),
+		)
+		.build()
+}
+
+fuzz_target!(|input: FuzzInput| {
+	let hash = input
+		.hash
+		.iter()
+		.map(|byte| format!("{:02x}", byte))
+		.collect::<String>();
+	let mut commit = CommitBuilder::new(hash.as_str()).commit_time(i64::from(input.commit_time));
+	if let Some(ref author) = input.author {
+		commit = commit.author(user(author));
+	}
+	if let Some(authored_time) = input.authored_time {
+		commit = commit.authored_time(i64::from(authored_time));
+	}
+	if let Some(ref committer) = input.committer {
+		commit = commit.committer(user(committer));
+	}
+	if let Some(ref summary) = input.summary {
+		commit = commit.summary(summary.as_str());
+	}
+	if let Some(ref message) = input.message {
+		commit = commit.message(message.as_str());
+	}
+	let diff = CommitDiffBuilder::new(commit.build())
+		.file_statuses(input.file_statuses.iter().map(build_file_status).collect())
+		.number_files_changed(input.files_changed)
+		.number_insertions(input.insertions)
+		.number_deletions(input.deletions)
+		.build();
+
+	let mut config = Config::new();
+	config.diff_show_whitespace = match input.show_whitespace {
+		FuzzShowWhitespace::Both => DiffShowWhitespaceSetting::Both,
+		FuzzShowWhitespace::Leading => DiffShowWhitespaceSetting::Leading,
+		FuzzShowWhitespace::None => DiffShowWhitespaceSetting::None,
+		FuzzShowWhitespace::Trailing => DiffShowWhitespaceSetting::Trailing,
+	};
+	config.diff_space_symbol = input.space_symbol;
+	config.diff_tab_symbol = input.tab_symbol;
+	config.diff_tab_width = u32::from(input.tab_width);
+	config.icons = input.icons;
+	config.screen_reader = input.screen_reader;
+
+	let _lines =
+		girt_core::fuzz::render_commit_diff(&config, &diff, usize::from(input.width), usize::from(input.height));
+});
@@ -0,0 +1,21 @@
+// Feed arbitrary bytes, as a todo file, to the todo file parser. Loading must not panic, and a loaded todo file must be
+// written to text that loads to the same text.
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use todo_file::TodoFile;
+
+fn load(contents: &str) -> Option<TodoFile> {
+	let mut todo_file = TodoFile::new("git-rebase-todo", 1, "#");
+	todo_file.load_str(contents).ok().map(|()| todo_file)
+}
+
+fuzz_target!(|data: &[u8]| {
+	let contents = String::from_utf8_lossy(data);
+	let text = match load(contents.as_ref()).and_then(|todo_file| todo_file.to_text().ok()) {
+		Some(text) => text,
+		None => return,
+	};
+	let reloaded = load(text.as_str()).expect("serialized text could not be loaded");
+	assert_eq!(reloaded.to_text().unwrap(), text, "serialized text is not stable");
+});
@@ -0,0 +1,22 @@
+use std::fmt::{Display, Formatter};
+
+/// Configuration option for how the rebase is cancelled when the todo list is aborted.
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[non_exhaustive]
+pub enum AbortBehavior {
+	/// Write an empty todo file, which git treats as a cancelled rebase.
+	Empty,
+	/// Exit with a non-zero exit code, without writing the todo file.
+	ExitCode,
+}
+
+impl Display for AbortBehavior {
+	/// Formats the setting as a value that can be written to the git config.
+	#[inline]
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		match *self {
+			Self::Empty => write!(f, "empty"),
+			Self::ExitCode => write!(f, "exit-code"),
+		}
+	}
+}
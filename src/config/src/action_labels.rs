@@ -0,0 +1,233 @@
+use anyhow::{anyhow, Error, Result};
+use git::Config;
+
+use super::{config_reader::ConfigReader, config_values::ConfigValues, utils::get_string};
+
+/// Represents the text shown for an action.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ActionLabel {
+	/// The name of the action, shown in full width windows.
+	pub name: String,
+	/// The abbreviation of the action, shown in narrow windows.
+	pub abbreviation: String,
+}
+
+impl ActionLabel {
+	fn new_with_reader(reader: &mut ConfigReader<'_>, action: &str, abbreviation: &str) -> Self {
+		let name = format!("interactive-rebase-tool.{}Label", action);
+		let abbreviation_name = format!("interactive-rebase-tool.{}Abbreviation", action);
+		Self {
+			name: reader.read(|config| get_label(config, name.as_str(), action)),
+			abbreviation: reader.read(|config| get_label(config, abbreviation_name.as_str(), abbreviation)),
+		}
+	}
+}
+
+/// Represents the action label configuration options, which allow the name and abbreviation of each action to be
+/// replaced, for example with an icon.
+#[derive(Clone, Debug)]
+pub struct ActionLabels {
+	/// The label for the break action.
+	pub action_break: ActionLabel,
+	/// The label for the drop action.
+	pub action_drop: ActionLabel,
+	/// The label for the edit action.
+	pub action_edit: ActionLabel,
+	/// The label for the exec action.
+	pub action_exec: ActionLabel,
+	/// The label for the fixup action.
+	pub action_fixup: ActionLabel,
+	/// The label for the pick action.
+	pub action_pick: ActionLabel,
+	/// The label for the reword action.
+	pub action_reword: ActionLabel,
+	/// The label for the squash action.
+	pub action_squash: ActionLabel,
+	/// The label for the label action.
+	pub action_label: ActionLabel,
+	/// The label for the reset action.
+	pub action_reset: ActionLabel,
+	/// The label for the merge action.
+	pub action_merge: ActionLabel,
+}
+
+impl ActionLabels {
+	/// Create a new configuration with default values.
+	#[must_use]
+	#[inline]
+	pub fn new() -> Self {
+		Self::new_with_config(None).expect("Panic without git config instance") // should never error with None config
+	}
+
+	/// Create new action labels from a Git Config reference.
+	pub(super) fn new_with_config(git_config: Option<&dyn ConfigValues>) -> Result<Self> {
+		ConfigReader::load(git_config, Self::new_with_reader)
+	}
+
+	pub(super) fn new_with_reader(reader: &mut ConfigReader<'_>) -> Self {
+		Self {
+			action_break: ActionLabel::new_with_reader(reader, "break", "b"),
+			action_drop: ActionLabel::new_with_reader(reader, "drop", "d"),
+			action_edit: ActionLabel::new_with_reader(reader, "edit", "e"),
+			action_exec: ActionLabel::new_with_reader(reader, "exec", "x"),
+			action_fixup: ActionLabel::new_with_reader(reader, "fixup", "f"),
+			action_pick: ActionLabel::new_with_reader(reader, "pick", "p"),
+			action_reword: ActionLabel::new_with_reader(reader, "reword", "r"),
+			action_squash: ActionLabel::new_with_reader(reader, "squash", "s"),
+			action_label: ActionLabel::new_with_reader(reader, "label", "l"),
+			action_reset: ActionLabel::new_with_reader(reader, "reset", "t"),
+			action_merge: ActionLabel::new_with_reader(reader, "merge", "m"),
+		}
+	}
+
+	/// Get all of the labels, in the order of the fields.
+	#[must_use]
+	#[inline]
+	pub fn all(&self) -> [&ActionLabel; 11] {
+		[
+			&self.action_break,
+			&self.action_drop,
+			&self.action_edit,
+			&self.action_exec,
+			&self.action_fixup,
+			&self.action_pick,
+			&self.action_reword,
+			&self.action_squash,
+			&self.action_label,
+			&self.action_reset,
+			&self.action_merge,
+		]
+	}
+}
+
+// an empty label would leave the action column blank, so it is not allowed
+fn get_label(git_config: Option<&dyn ConfigValues>, name: &str, default: &str) -> Result<String> {
+	let label = get_string(git_config, name, default)?;
+	if label.is_empty() {
+		return Err(anyhow!("the label cannot be empty").context(anyhow!("\"{}\" is not valid", name)));
+	}
+	Ok(label)
+}
+
+impl Default for ActionLabels {
+	#[inline]
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl TryFrom<&Config> for ActionLabels {
+	type Error = Error;
+
+	#[inline]
+	fn try_from(config: &Config) -> core::result::Result<Self, Error> {
+		Self::new_with_config(Some(config))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use rstest::rstest;
+
+	use super::*;
+	use crate::testutils::{assert_error, invalid_utf, with_git_config};
+
+	#[test]
+	fn new() {
+		let _config = ActionLabels::new();
+	}
+
+	#[test]
+	fn default() {
+		let _config = ActionLabels::default();
+	}
+
+	#[test]
+	fn try_from_git_config() {
+		with_git_config(&[], |git_config| {
+			assert!(ActionLabels::try_from(&git_config).is_ok());
+		});
+	}
+
+	#[test]
+	fn try_from_git_config_error() {
+		with_git_config(&["[interactive-rebase-tool]", "pickLabel = \"\""], |git_config| {
+			assert!(ActionLabels::try_from(&git_config).is_err());
+		});
+	}
+
+	#[rstest]
+	#[case::action_break("break", "b", |labels: ActionLabels| labels.action_break)]
+	#[case::action_drop("drop", "d", |labels: ActionLabels| labels.action_drop)]
+	#[case::action_edit("edit", "e", |labels: ActionLabels| labels.action_edit)]
+	#[case::action_exec("exec", "x", |labels: ActionLabels| labels.action_exec)]
+	#[case::action_fixup("fixup", "f", |labels: ActionLabels| labels.action_fixup)]
+	#[case::action_pick("pick", "p", |labels: ActionLabels| labels.action_pick)]
+	#[case::action_reword("reword", "r", |labels: ActionLabels| labels.action_reword)]
+	#[case::action_squash("squash", "s", |labels: ActionLabels| labels.action_squash)]
+	#[case::action_label("label", "l", |labels: ActionLabels| labels.action_label)]
+	#[case::action_reset("reset", "t", |labels: ActionLabels| labels.action_reset)]
+	#[case::action_merge("merge", "m", |labels: ActionLabels| labels.action_merge)]
+	fn action_label<F: 'static>(#[case] action: &str, #[case] abbreviation: &str, #[case] access: F)
+	where F: Fn(ActionLabels) -> ActionLabel {
+		assert_eq!(access(ActionLabels::new()), ActionLabel {
+			name: String::from(action),
+			abbreviation: String::from(abbreviation),
+		});
+
+		let name_value = format!("{}Label = \"🍒\"", action);
+		let abbreviation_value = format!("{}Abbreviation = \"🍒\"", action);
+		with_git_config(
+			&[
+				"[interactive-rebase-tool]",
+				name_value.as_str(),
+				abbreviation_value.as_str(),
+			],
+			|config| {
+				let labels = ActionLabels::new_with_config(Some(&config)).unwrap();
+				assert_eq!(access(labels), ActionLabel {
+					name: String::from("🍒"),
+					abbreviation: String::from("🍒"),
+				});
+			},
+		);
+	}
+
+	#[test]
+	fn action_label_empty() {
+		with_git_config(&["[interactive-rebase-tool]", "pickAbbreviation = \"\""], |config| {
+			assert_error(
+				ActionLabels::new_with_config(Some(&config)),
+				"\"interactive-rebase-tool.pickAbbreviation\" is not valid: the label cannot be empty",
+			);
+		});
+	}
+
+	#[test]
+	fn action_label_invalid() {
+		with_git_config(
+			&[
+				"[interactive-rebase-tool]",
+				format!("squashLabel = {}", invalid_utf()).as_str(),
+			],
+			|config| {
+				assert_error(
+					ActionLabels::new_with_config(Some(&config)),
+					"\"interactive-rebase-tool.squashLabel\" is not valid: configuration value is not valid utf8",
+				);
+			},
+		);
+	}
+
+	#[test]
+	fn all() {
+		assert_eq!(
+			ActionLabels::new()
+				.all()
+				.iter()
+				.map(|label| label.name.as_str())
+				.collect::<Vec<_>>(),
+			vec!["break", "drop", "edit", "exec", "fixup", "pick", "reword", "squash", "label", "reset", "merge"]
+		);
+	}
+}
@@ -0,0 +1,104 @@
+use anyhow::{anyhow, Result};
+
+use super::config_layer::{is_valid_name, Entry};
+
+/// Parse a setting of the `-c` option, as a `name=value`, in the same format as the `-c` option of git. The name has a
+/// section and a setting name, with an optional subsection between them, such as `interactive-rebase-tool.icons` or
+/// `interactive-rebase-tool.execTemplate.test`, and a name without a value is `true`, like in git.
+pub(super) fn parse_override(setting: &str) -> Result<Entry> {
+	let (key, value) = setting.split_once('=').unwrap_or((setting, "true"));
+	let (section, rest) = match key.split_once('.') {
+		Some(parts) => parts,
+		None => return Err(anyhow!("\"{}\" is not valid: key does not contain a section", setting)),
+	};
+	let (subsection, name) = match rest.rsplit_once('.') {
+		Some((subsection, name)) => (Some(String::from(subsection)), name),
+		None => (None, rest),
+	};
+	if !is_valid_name(section) || !is_valid_name(name) {
+		return Err(anyhow!("\"{}\" is not valid: key is not a valid setting name", setting));
+	}
+	Ok(Entry {
+		section: String::from(section),
+		subsection,
+		name: String::from(name),
+		value: String::from(value),
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use git::ConfigSource;
+	use rstest::rstest;
+
+	use super::*;
+	use crate::{
+		config_layer::{ConfigLayer, LayeredConfig},
+		config_values::ConfigValues,
+		testutils::{assert_error, with_git_config},
+		utils::get_string,
+	};
+
+	fn entry(section: &str, subsection: Option<&str>, name: &str, value: &str) -> Entry {
+		Entry {
+			section: String::from(section),
+			subsection: subsection.map(String::from),
+			name: String::from(name),
+			value: String::from(value),
+		}
+	}
+
+	#[rstest]
+	#[case::setting(
+		"interactive-rebase-tool.icons=false",
+		entry("interactive-rebase-tool", None, "icons", "false")
+	)]
+	#[case::without_value(
+		"interactive-rebase-tool.icons",
+		entry("interactive-rebase-tool", None, "icons", "true")
+	)]
+	#[case::empty_value("core.editor=", entry("core", None, "editor", ""))]
+	#[case::value_with_equals("core.editor=vim -c set=1", entry("core", None, "editor", "vim -c set=1"))]
+	#[case::subsection(
+		"interactive-rebase-tool.execTemplate.test=cargo test",
+		entry("interactive-rebase-tool", Some("execTemplate"), "test", "cargo test")
+	)]
+	#[case::subsection_with_dot(
+		"interactive-rebase-tool.a.b.test=1",
+		entry("interactive-rebase-tool", Some("a.b"), "test", "1")
+	)]
+	fn parse(#[case] setting: &str, #[case] expected: Entry) {
+		assert_eq!(parse_override(setting).unwrap(), expected);
+	}
+
+	#[rstest]
+	#[case::no_section("icons=true", "\"icons=true\" is not valid: key does not contain a section")]
+	#[case::empty_name(
+		"interactive-rebase-tool.=true",
+		"\"interactive-rebase-tool.=true\" is not valid: key is not a valid setting name"
+	)]
+	#[case::invalid_name(
+		"interactive-rebase-tool.auto_select=true",
+		"\"interactive-rebase-tool.auto_select=true\" is not valid: key is not a valid setting name"
+	)]
+	#[case::invalid_section(".icons=true", "\".icons=true\" is not valid: key is not a valid setting name")]
+	fn parse_error(#[case] setting: &str, #[case] expected: &str) {
+		assert_error(parse_override(setting), expected);
+	}
+
+	#[test]
+	fn override_above_environment() {
+		with_git_config(&["[core]", "editor = vim"], |git_config| {
+			let environment = ConfigLayer::new(ConfigSource::Environment, vec![entry("core", None, "editor", "nano")]);
+			let command_line = ConfigLayer::new(ConfigSource::CommandLine, vec![
+				parse_override("core.editor=emacs").unwrap(),
+			]);
+			let mut config = LayeredConfig::new();
+			config.push(&git_config);
+			config.push(&environment);
+			config.push(&command_line);
+			assert_eq!(get_string(Some(&config), "core.editor", "").unwrap(), "emacs");
+			assert_eq!(config.sources().get("core.editor"), Some(&ConfigSource::CommandLine));
+		});
+	}
+}
@@ -1,3 +1,5 @@
+use std::fmt::{Display, Formatter};
+
 use anyhow::{anyhow, Error};
 
 /// Represents a color.
@@ -120,6 +122,36 @@ impl TryFrom<&str> for Color {
 	}
 }
 
+impl Display for Color {
+	/// Formats the color as a value that can be written to the git config.
+	#[inline]
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		match *self {
+			Self::Default => write!(f, "transparent"),
+			Self::LightWhite => write!(f, "white"),
+			Self::LightBlack => write!(f, "black"),
+			Self::LightBlue => write!(f, "blue"),
+			Self::LightCyan => write!(f, "cyan"),
+			Self::LightGreen => write!(f, "green"),
+			Self::LightMagenta => write!(f, "magenta"),
+			Self::LightRed => write!(f, "red"),
+			Self::LightYellow => write!(f, "yellow"),
+			Self::LightGrey => write!(f, "grey"),
+			Self::DarkWhite => write!(f, "dark white"),
+			Self::DarkBlack => write!(f, "dark black"),
+			Self::DarkBlue => write!(f, "dark blue"),
+			Self::DarkCyan => write!(f, "dark cyan"),
+			Self::DarkGreen => write!(f, "dark green"),
+			Self::DarkMagenta => write!(f, "dark magenta"),
+			Self::DarkRed => write!(f, "dark red"),
+			Self::DarkYellow => write!(f, "dark yellow"),
+			Self::DarkGrey => write!(f, "dark grey"),
+			Self::Index(index) => write!(f, "{}", index),
+			Self::Rgb { red, green, blue } => write!(f, "{},{},{}", red, green, blue),
+		}
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use rstest::rstest;
@@ -201,4 +233,34 @@ mod tests {
 	fn color_try_from_fail(#[case] color_string: &str, #[case] expected: &str) {
 		assert_eq!(Color::try_from(color_string).unwrap_err().to_string(), expected);
 	}
+
+	#[rstest]
+	#[case::default(Color::Default)]
+	#[case::light_white(Color::LightWhite)]
+	#[case::light_black(Color::LightBlack)]
+	#[case::light_blue(Color::LightBlue)]
+	#[case::light_cyan(Color::LightCyan)]
+	#[case::light_green(Color::LightGreen)]
+	#[case::light_magenta(Color::LightMagenta)]
+	#[case::light_red(Color::LightRed)]
+	#[case::light_yellow(Color::LightYellow)]
+	#[case::light_grey(Color::LightGrey)]
+	#[case::dark_white(Color::DarkWhite)]
+	#[case::dark_black(Color::DarkBlack)]
+	#[case::dark_blue(Color::DarkBlue)]
+	#[case::dark_cyan(Color::DarkCyan)]
+	#[case::dark_green(Color::DarkGreen)]
+	#[case::dark_magenta(Color::DarkMagenta)]
+	#[case::dark_red(Color::DarkRed)]
+	#[case::dark_yellow(Color::DarkYellow)]
+	#[case::dark_grey(Color::DarkGrey)]
+	#[case::index(Color::Index(42))]
+	#[case::rgb(Color::Rgb {
+		red: 100,
+		green: 101,
+		blue: 102
+	})]
+	fn to_string_round_trip(#[case] color: Color) {
+		assert_eq!(Color::try_from(color.to_string().as_str()).unwrap(), color);
+	}
 }
@@ -0,0 +1,313 @@
+use std::collections::HashMap;
+
+use anyhow::{anyhow, Result};
+use git::{Config, ConfigSource};
+
+use super::config_values::{ConfigValues, READ_SECTIONS};
+
+/// The section of the git config of the settings of the tool.
+pub(crate) const SECTION: &str = "interactive-rebase-tool";
+
+/// A setting of a section of the git config, in a subsection of the section.
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) struct Entry {
+	pub(crate) section: String,
+	pub(crate) subsection: Option<String>,
+	pub(crate) name: String,
+	pub(crate) value: String,
+}
+
+impl Entry {
+	// the full name of the setting, with the section and the name of the setting in lowercase, like the names of the
+	// entries of a git config
+	fn full_name(&self) -> String {
+		match self.subsection {
+			Some(ref subsection) => {
+				format!(
+					"{}.{}.{}",
+					self.section.to_lowercase(),
+					subsection,
+					self.name.to_lowercase()
+				)
+			},
+			None => format!("{}.{}", self.section.to_lowercase(), self.name.to_lowercase()),
+		}
+	}
+}
+
+/// Whether a name is a valid name of a setting of the git config, which starts with a letter, and has only letters,
+/// digits and `-`.
+pub(crate) fn is_valid_name(name: &str) -> bool {
+	name.starts_with(|c: char| c.is_ascii_alphabetic()) && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+}
+
+// the full name of a setting, with the section and the name of the setting in lowercase, and the subsection as it is
+fn normalize_name(name: &str) -> String {
+	match name
+		.split_once('.')
+		.and_then(|(section, rest)| rest.rsplit_once('.').map(|(subsection, setting)| (section, subsection, setting)))
+	{
+		Some((section, subsection, setting)) => {
+			format!(
+				"{}.{}.{}",
+				section.to_lowercase(),
+				subsection,
+				setting.to_lowercase()
+			)
+		},
+		None => name.to_lowercase(),
+	}
+}
+
+/// Settings that are held in memory, rather than read from a git config file, with where they are loaded from.
+#[derive(Debug)]
+pub(crate) struct ConfigLayer {
+	source: ConfigSource,
+	entries: Vec<Entry>,
+}
+
+impl ConfigLayer {
+	pub(crate) const fn new(source: ConfigSource, entries: Vec<Entry>) -> Self {
+		Self { source, entries }
+	}
+
+	// the value of a setting that is set more than once is the last value, like in a git config file
+	fn find(&self, name: &str) -> Option<&str> {
+		let name = normalize_name(name);
+		self.entries
+			.iter()
+			.rev()
+			.find(|entry| entry.full_name() == name)
+			.map(|entry| entry.value.as_str())
+	}
+}
+
+impl ConfigValues for ConfigLayer {
+	fn string(&self, name: &str) -> Result<Option<String>> {
+		Ok(self.find(name).map(String::from))
+	}
+
+	fn bool(&self, name: &str) -> Result<Option<bool>> {
+		self.find(name)
+			.map(|value| Config::parse_bool(value).map_err(|e| anyhow!(String::from(e.message()))))
+			.transpose()
+	}
+
+	fn i32(&self, name: &str) -> Result<Option<i32>> {
+		self.find(name)
+			.map(|value| Config::parse_i32(value).map_err(|e| anyhow!(String::from(e.message()))))
+			.transpose()
+	}
+
+	fn entries(&self, prefix: &str) -> Result<Vec<(String, Option<String>)>> {
+		Ok(self
+			.entries
+			.iter()
+			.map(|entry| (entry.full_name(), Some(entry.value.clone())))
+			.filter(|entry| entry.0.starts_with(prefix))
+			.collect())
+	}
+
+	fn sources(&self) -> HashMap<String, ConfigSource> {
+		self.entries
+			.iter()
+			.filter(|entry| READ_SECTIONS.contains(&entry.section.to_lowercase().as_str()))
+			.map(|entry| (entry.full_name(), self.source))
+			.collect()
+	}
+}
+
+/// The layers of the settings, from the lowest to the highest, such as the layers of the config file of the tool
+/// beneath a git config. A setting is read from the highest layer that sets it.
+pub(crate) struct LayeredConfig<'c> {
+	layers: Vec<&'c dyn ConfigValues>,
+}
+
+impl<'c> LayeredConfig<'c> {
+	pub(crate) const fn new() -> Self {
+		Self { layers: vec![] }
+	}
+
+	/// Add a layer above the layers that are already added.
+	pub(crate) fn push(&mut self, layer: &'c dyn ConfigValues) {
+		self.layers.push(layer);
+	}
+
+	fn read<T, F>(&self, get: F) -> Result<Option<T>>
+	where F: Fn(&dyn ConfigValues) -> Result<Option<T>> {
+		for layer in self.layers.iter().rev() {
+			if let Some(value) = get(*layer)? {
+				return Ok(Some(value));
+			}
+		}
+		Ok(None)
+	}
+}
+
+impl ConfigValues for LayeredConfig<'_> {
+	fn string(&self, name: &str) -> Result<Option<String>> {
+		self.read(|layer| layer.string(name))
+	}
+
+	fn bool(&self, name: &str) -> Result<Option<bool>> {
+		self.read(|layer| layer.bool(name))
+	}
+
+	fn i32(&self, name: &str) -> Result<Option<i32>> {
+		self.read(|layer| layer.i32(name))
+	}
+
+	fn entries(&self, prefix: &str) -> Result<Vec<(String, Option<String>)>> {
+		let mut entries = vec![];
+		for layer in &self.layers {
+			entries.extend(layer.entries(prefix)?);
+		}
+		Ok(entries)
+	}
+
+	fn sources(&self) -> HashMap<String, ConfigSource> {
+		let mut sources = HashMap::new();
+		for layer in &self.layers {
+			sources.extend(layer.sources());
+		}
+		sources
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use rstest::rstest;
+
+	use super::*;
+	use crate::testutils::{assert_error, with_git_config};
+
+	fn entry(subsection: Option<&str>, name: &str, value: &str) -> Entry {
+		Entry {
+			section: String::from(SECTION),
+			subsection: subsection.map(String::from),
+			name: String::from(name),
+			value: String::from(value),
+		}
+	}
+
+	#[rstest]
+	#[case::setting("interactive-rebase-tool.autoSelectNext", "interactive-rebase-tool.autoselectnext")]
+	#[case::subsection(
+		"Interactive-Rebase-Tool.execTemplate.Test",
+		"interactive-rebase-tool.execTemplate.test"
+	)]
+	#[case::subsection_with_dot("core.a.B.c", "core.a.B.c")]
+	#[case::without_section("Icons", "icons")]
+	fn normalize(#[case] name: &str, #[case] expected: &str) {
+		assert_eq!(normalize_name(name), expected);
+	}
+
+	#[test]
+	fn layer_values() {
+		let layer = ConfigLayer::new(ConfigSource::ConfigFile, vec![
+			entry(None, "icons", "yes"),
+			entry(None, "undoLimit", "1k"),
+			entry(None, "todoEditor", "nano"),
+			entry(None, "todoEditor", "vim"),
+			entry(Some("execTemplate"), "test", "cargo test"),
+		]);
+		assert!(layer.bool("interactive-rebase-tool.icons").unwrap().unwrap());
+		assert_eq!(layer.i32("interactive-rebase-tool.undoLimit").unwrap(), Some(1024));
+		assert_eq!(
+			layer.string("interactive-rebase-tool.todoeditor").unwrap(),
+			Some(String::from("vim"))
+		);
+		assert_eq!(
+			layer.string("interactive-rebase-tool.execTemplate.test").unwrap(),
+			Some(String::from("cargo test"))
+		);
+		assert_eq!(layer.string("interactive-rebase-tool.exectemplate.test").unwrap(), None);
+		assert_eq!(layer.string("interactive-rebase-tool.keyHints").unwrap(), None);
+	}
+
+	#[test]
+	fn layer_value_invalid() {
+		let layer = ConfigLayer::new(ConfigSource::ConfigFile, vec![entry(None, "icons", "invalid")]);
+		assert_error(
+			layer.bool("interactive-rebase-tool.icons"),
+			"failed to parse 'invalid' as a boolean value",
+		);
+	}
+
+	#[test]
+	fn layer_sources() {
+		let layer = ConfigLayer::new(ConfigSource::ConfigFile, vec![
+			entry(None, "autoSelectNext", "true"),
+			Entry {
+				section: String::from("remote"),
+				subsection: Some(String::from("origin")),
+				name: String::from("url"),
+				value: String::from("https://example.com"),
+			},
+		]);
+		let sources = layer.sources();
+		assert_eq!(sources.len(), 1);
+		assert_eq!(
+			sources.get("interactive-rebase-tool.autoselectnext"),
+			Some(&ConfigSource::ConfigFile)
+		);
+	}
+
+	#[test]
+	fn layered_config_highest_layer() {
+		with_git_config(
+			&["[interactive-rebase-tool]", "icons = false", "keyHints = true"],
+			|git_config| {
+				let beneath = ConfigLayer::new(ConfigSource::ConfigFile, vec![
+					entry(None, "icons", "true"),
+					entry(None, "autoSelectNext", "true"),
+				]);
+				let mut config = LayeredConfig::new();
+				config.push(&beneath);
+				config.push(&git_config);
+				assert_eq!(config.bool("interactive-rebase-tool.icons").unwrap(), Some(false));
+				assert_eq!(config.bool("interactive-rebase-tool.keyHints").unwrap(), Some(true));
+				assert_eq!(config.bool("interactive-rebase-tool.autoSelectNext").unwrap(), Some(true));
+				assert_eq!(config.bool("interactive-rebase-tool.screenReader").unwrap(), None);
+				let sources = config.sources();
+				assert_eq!(
+					sources.get("interactive-rebase-tool.icons"),
+					Some(&ConfigSource::Local)
+				);
+				assert_eq!(
+					sources.get("interactive-rebase-tool.autoselectnext"),
+					Some(&ConfigSource::ConfigFile)
+				);
+			},
+		);
+	}
+
+	#[test]
+	fn layered_config_entries() {
+		with_git_config(
+			&["[interactive-rebase-tool \"execTemplate\"]", "test = cargo test"],
+			|git_config| {
+				let beneath = ConfigLayer::new(ConfigSource::ConfigFile, vec![
+					entry(Some("execTemplate"), "test", "make test"),
+					entry(Some("execTemplate"), "check", "make check"),
+				]);
+				let mut config = LayeredConfig::new();
+				config.push(&beneath);
+				config.push(&git_config);
+				assert_eq!(
+					config
+						.entries("interactive-rebase-tool.execTemplate.")
+						.unwrap()
+						.into_iter()
+						.map(|(name, value)| format!("{}={}", name, value.unwrap()))
+						.collect::<Vec<String>>(),
+					vec![
+						"interactive-rebase-tool.execTemplate.test=make test",
+						"interactive-rebase-tool.execTemplate.check=make check",
+						"interactive-rebase-tool.execTemplate.test=cargo test",
+					]
+				);
+			},
+		);
+	}
+}
@@ -0,0 +1,170 @@
+use std::{
+	collections::HashMap,
+	fmt::{self, Display, Formatter},
+};
+
+use anyhow::{Error, Result};
+use git::ConfigSource;
+
+use super::{
+	config_values::ConfigValues,
+	utils::{get_bool, get_input, get_string, get_unsigned_integer},
+};
+
+/// The errors of every config value that is not valid, reported together, rather than only the first.
+#[derive(Debug)]
+pub struct InvalidConfigError {
+	errors: Vec<Error>,
+}
+
+impl InvalidConfigError {
+	/// The errors of the values that are not valid, in the order that the values are read.
+	#[inline]
+	#[must_use]
+	pub fn errors(&self) -> &[Error] {
+		self.errors.as_slice()
+	}
+}
+
+impl Display for InvalidConfigError {
+	#[inline]
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		write!(f, "{} config values are not valid", self.errors.len())?;
+		for error in &self.errors {
+			write!(f, "\n  {:#}", error)?;
+		}
+		Ok(())
+	}
+}
+
+impl std::error::Error for InvalidConfigError {}
+
+/// Reads the typed values of the settings from a Git config. A value that is not valid is replaced with its default
+/// value, and its error is kept, so that every value is read before the errors are reported.
+pub(crate) struct ConfigReader<'c> {
+	config: Option<&'c dyn ConfigValues>,
+	errors: Vec<Error>,
+}
+
+impl<'c> ConfigReader<'c> {
+	const fn new(config: Option<&'c dyn ConfigValues>) -> Self {
+		Self { config, errors: vec![] }
+	}
+
+	/// Read the values of the settings with a reader of the config, returning the error of the value that is not
+	/// valid, or an `InvalidConfigError` when more than one value is not valid.
+	pub(crate) fn load<T, F>(config: Option<&'c dyn ConfigValues>, read: F) -> Result<T>
+	where F: FnOnce(&mut Self) -> T {
+		let mut reader = Self::new(config);
+		let value = read(&mut reader);
+		match reader.errors.len() {
+			0 => Ok(value),
+			1 => Err(reader.errors.remove(0)),
+			_ => Err(Error::new(InvalidConfigError { errors: reader.errors })),
+		}
+	}
+
+	/// Read a value, with the value that is read without a config, the default value, used when it is not valid.
+	pub(crate) fn read<T, F>(&mut self, get: F) -> T
+	where F: Fn(Option<&dyn ConfigValues>) -> Result<T> {
+		get(self.config).unwrap_or_else(|err| {
+			self.errors.push(err);
+			get(None).expect("Panic without git config instance") // should never error with None config
+		})
+	}
+
+	pub(crate) fn bool(&mut self, name: &str, default: bool) -> bool {
+		self.read(|config| get_bool(config, name, default))
+	}
+
+	pub(crate) fn input(&mut self, name: &str, default: &str) -> Vec<String> {
+		self.read(|config| get_input(config, name, default))
+	}
+
+	pub(crate) fn string(&mut self, name: &str, default: &str) -> String {
+		self.read(|config| get_string(config, name, default))
+	}
+
+	pub(crate) fn unsigned_integer(&mut self, name: &str, default: u32) -> u32 {
+		self.read(|config| get_unsigned_integer(config, name, default))
+	}
+
+	/// Find where the value of each setting that is set is loaded from, by the lowercase name of the setting.
+	pub(crate) fn sources(&self) -> HashMap<String, ConfigSource> {
+		self.config.map_or_else(HashMap::new, ConfigValues::sources)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::testutils::{assert_error, with_git_config};
+
+	#[test]
+	fn load_without_errors() {
+		with_git_config(&["[interactive-rebase-tool]", "autoSelectNext = true"], |config| {
+			let value = ConfigReader::load(Some(&config), |reader| {
+				reader.bool("interactive-rebase-tool.autoSelectNext", false)
+			});
+			assert!(value.unwrap());
+		});
+	}
+
+	#[test]
+	fn load_with_single_error() {
+		with_git_config(&["[interactive-rebase-tool]", "autoSelectNext = invalid"], |config| {
+			assert_error(
+				ConfigReader::load(Some(&config), |reader| {
+					reader.bool("interactive-rebase-tool.autoSelectNext", false)
+				}),
+				"\"interactive-rebase-tool.autoSelectNext\" is not valid: failed to parse 'invalid' as a boolean value",
+			);
+		});
+	}
+
+	#[test]
+	fn load_with_every_error() {
+		with_git_config(
+			&[
+				"[interactive-rebase-tool]",
+				"autoSelectNext = invalid",
+				"diffTabWidth = invalid",
+				"undoLimit = 10",
+			],
+			|config| {
+				assert_error(
+					ConfigReader::load(Some(&config), |reader| {
+						(
+							reader.bool("interactive-rebase-tool.autoSelectNext", false),
+							reader.unsigned_integer("interactive-rebase-tool.diffTabWidth", 4),
+							reader.unsigned_integer("interactive-rebase-tool.undoLimit", 5000),
+						)
+					}),
+					[
+						"2 config values are not valid",
+						"  \"interactive-rebase-tool.autoSelectNext\" is not valid: failed to parse 'invalid' as a boolean \
+						 value",
+						"  \"interactive-rebase-tool.diffTabWidth\" is not valid: failed to parse 'invalid' as a 32-bit \
+						 integer",
+					]
+					.join("\n")
+					.as_str(),
+				);
+			},
+		);
+	}
+
+	#[test]
+	fn read_invalid_uses_default() {
+		with_git_config(&["[interactive-rebase-tool]", "diffTabWidth = invalid"], |config| {
+			let mut reader = ConfigReader::new(Some(&config));
+			assert_eq!(reader.unsigned_integer("interactive-rebase-tool.diffTabWidth", 4), 4);
+			assert_eq!(reader.errors.len(), 1);
+		});
+	}
+
+	#[test]
+	fn sources_without_config() {
+		assert!(ConfigReader::new(None).sources().is_empty());
+	}
+}
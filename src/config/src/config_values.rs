@@ -0,0 +1,164 @@
+use std::collections::{hash_map::Entry, HashMap};
+
+use anyhow::{anyhow, Result};
+use git::{Config, ConfigSource, ErrorCode};
+
+/// The sections of the settings that are read, the settings of the tool and the Git settings that the tool follows.
+pub(crate) const READ_SECTIONS: [&str; 4] = ["interactive-rebase-tool", "core", "diff", "rebase"];
+
+/// The values of the settings of a config, by the full name of a setting, such as `interactive-rebase-tool.icons`. The
+/// section and the name of a setting are not case sensitive, and the subsection of a setting is.
+pub(crate) trait ConfigValues {
+	/// The value of a setting, or `None` when the setting is not set.
+	fn string(&self, name: &str) -> Result<Option<String>>;
+
+	/// The value of a setting as a boolean, in any of the formats that Git accepts, or `None` when the setting is not
+	/// set.
+	fn bool(&self, name: &str) -> Result<Option<bool>>;
+
+	/// The value of a setting as an integer, in any of the formats that Git accepts, or `None` when the setting is not
+	/// set.
+	fn i32(&self, name: &str) -> Result<Option<i32>>;
+
+	/// The names and values of the settings with a name that starts with a prefix, in the order that they are read, so
+	/// that a later setting overrides an earlier setting with the same name. The section and the name of a setting are
+	/// lowercase, and a value that is not valid utf8 is `None`.
+	fn entries(&self, prefix: &str) -> Result<Vec<(String, Option<String>)>>;
+
+	/// Where the value of each setting of the sections that are read is loaded from, by the lowercase name of the
+	/// setting.
+	fn sources(&self) -> HashMap<String, ConfigSource>;
+}
+
+impl ConfigValues for Config {
+	fn string(&self, name: &str) -> Result<Option<String>> {
+		match self.get_string(name) {
+			Ok(v) => Ok(Some(v)),
+			Err(ref e) if e.code() == ErrorCode::NotFound => Ok(None),
+			Err(e) => Err(anyhow!(String::from(e.message()))),
+		}
+	}
+
+	fn bool(&self, name: &str) -> Result<Option<bool>> {
+		match self.get_bool(name) {
+			Ok(v) => Ok(Some(v)),
+			Err(ref e) if e.code() == ErrorCode::NotFound => Ok(None),
+			Err(e) => Err(anyhow!(String::from(e.message()))),
+		}
+	}
+
+	fn i32(&self, name: &str) -> Result<Option<i32>> {
+		match self.get_i32(name) {
+			Ok(v) => Ok(Some(v)),
+			Err(ref e) if e.code() == ErrorCode::NotFound => Ok(None),
+			Err(e) => Err(anyhow!(String::from(e.message()))),
+		}
+	}
+
+	fn entries(&self, prefix: &str) -> Result<Vec<(String, Option<String>)>> {
+		let entries = self
+			.entries(Some(format!("^{}", prefix.replace('.', "\\.")).as_str()))
+			.map_err(|e| anyhow!(String::from(e.message())))?;
+		let mut values = vec![];
+		for entry in &entries {
+			let entry = entry.map_err(|e| anyhow!(String::from(e.message())))?;
+			if let Some(name) = entry.name() {
+				values.push((String::from(name), entry.value().map(String::from)));
+			}
+		}
+		Ok(values)
+	}
+
+	fn sources(&self) -> HashMap<String, ConfigSource> {
+		let mut sources = HashMap::new();
+		let pattern = format!("^({})\\.", READ_SECTIONS.join("|"));
+		let entries = match self.entries(Some(pattern.as_str())) {
+			Ok(entries) => entries,
+			Err(_) => return sources,
+		};
+		for entry in &entries {
+			let name = entry.ok().and_then(|config_entry| config_entry.name().map(str::to_lowercase));
+			// a setting can be set at more than one level, and the value that is used is from the highest level
+			if let Some(Entry::Vacant(source)) = name.map(|name| sources.entry(name)) {
+				if let Ok(used_entry) = self.get_entry(source.key().as_str()) {
+					let _ = source.insert(ConfigSource::from(used_entry.level()));
+				}
+			}
+		}
+		sources
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::testutils::with_git_config;
+
+	#[test]
+	fn git_config_values() {
+		with_git_config(
+			&[
+				"[interactive-rebase-tool]",
+				"icons = yes",
+				"undoLimit = 1k",
+				"todoEditor = nano",
+			],
+			|config| {
+				assert!(ConfigValues::bool(&config, "interactive-rebase-tool.icons").unwrap().unwrap());
+				assert_eq!(
+					ConfigValues::i32(&config, "interactive-rebase-tool.undoLimit").unwrap(),
+					Some(1024)
+				);
+				assert_eq!(
+					ConfigValues::string(&config, "interactive-rebase-tool.todoEditor").unwrap(),
+					Some(String::from("nano"))
+				);
+				assert_eq!(ConfigValues::string(&config, "interactive-rebase-tool.keyHints").unwrap(), None);
+			},
+		);
+	}
+
+	#[test]
+	fn git_config_entries() {
+		with_git_config(
+			&[
+				"[interactive-rebase-tool \"execTemplate\"]",
+				"test = cargo test",
+				"[interactive-rebase-tool]",
+				"icons = true",
+			],
+			|config| {
+				assert_eq!(
+					ConfigValues::entries(&config, "interactive-rebase-tool.execTemplate.").unwrap(),
+					vec![(
+						String::from("interactive-rebase-tool.execTemplate.test"),
+						Some(String::from("cargo test"))
+					)]
+				);
+			},
+		);
+	}
+
+	#[test]
+	fn git_config_sources() {
+		with_git_config(
+			&[
+				"[interactive-rebase-tool]",
+				"autoSelectNext = true",
+				"[diff]",
+				"context = 5",
+				"[remote \"origin\"]",
+				"url = https://example.com",
+			],
+			|config| {
+				let sources = ConfigValues::sources(&config);
+				assert_eq!(sources.len(), 2);
+				assert_eq!(
+					sources.get("interactive-rebase-tool.autoselectnext"),
+					Some(&ConfigSource::from(git::ConfigLevel::Local))
+				);
+				assert!(sources.contains_key("diff.context"));
+			},
+		);
+	}
+}
@@ -0,0 +1,36 @@
+use std::fmt::{Display, Formatter};
+
+/// Configuration option for how a date is shown.
+#[derive(Clone, PartialEq, Debug)]
+#[non_exhaustive]
+pub enum DateFormat {
+	/// Show the time since the date, for example `3 days ago`.
+	Relative,
+	/// Show the date using a `strftime` style format, for example `%Y-%m-%d`.
+	Format(String),
+}
+
+impl Display for DateFormat {
+	/// Formats the setting as a value that can be written to the git config.
+	#[inline]
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		match *self {
+			Self::Relative => write!(f, "relative"),
+			Self::Format(ref format) => write!(f, "{}", format),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use rstest::rstest;
+
+	use super::*;
+
+	#[rstest]
+	#[case::relative(DateFormat::Relative, "relative")]
+	#[case::format(DateFormat::Format(String::from("%Y-%m-%d")), "%Y-%m-%d")]
+	fn to_string(#[case] date_format: DateFormat, #[case] expected: &str) {
+		assert_eq!(date_format.to_string(), expected);
+	}
+}
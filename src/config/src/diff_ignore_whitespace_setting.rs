@@ -1,3 +1,5 @@
+use std::fmt::{Display, Formatter};
+
 /// Configuration option for how to ignore whitespace during diff calculation.
 #[derive(Clone, Copy, PartialEq, Debug)]
 #[non_exhaustive]
@@ -13,3 +15,15 @@ pub enum DiffIgnoreWhitespaceSetting {
 	/// ) flag.
 	Change,
 }
+
+impl Display for DiffIgnoreWhitespaceSetting {
+	/// Formats the setting as a value that can be written to the git config.
+	#[inline]
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		match *self {
+			Self::None => write!(f, "none"),
+			Self::All => write!(f, "all"),
+			Self::Change => write!(f, "change"),
+		}
+	}
+}
@@ -1,3 +1,5 @@
+use std::fmt::{Display, Formatter};
+
 /// Configuration option for how to show whitespace when displaying diffs.
 #[derive(Clone, Copy, PartialEq, Debug)]
 #[non_exhaustive]
@@ -11,3 +13,16 @@ pub enum DiffShowWhitespaceSetting {
 	/// Show both leading and trailing whitespace characters.
 	Both,
 }
+
+impl Display for DiffShowWhitespaceSetting {
+	/// Formats the setting as a value that can be written to the git config.
+	#[inline]
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		match *self {
+			Self::None => write!(f, "none"),
+			Self::Trailing => write!(f, "trailing"),
+			Self::Leading => write!(f, "leading"),
+			Self::Both => write!(f, "both"),
+		}
+	}
+}
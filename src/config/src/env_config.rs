@@ -0,0 +1,203 @@
+use std::ffi::OsString;
+
+use anyhow::{anyhow, Result};
+
+use super::config_layer::{is_valid_name, Entry, SECTION};
+
+const PREFIX: &str = "GIRT_";
+
+// the separator of the name of a subsection and the name of a setting, such as `GIRT_EXEC_TEMPLATE__TEST`
+const SUBSECTION_SEPARATOR: &str = "__";
+
+// the environment variables of the tool that are not settings
+const IGNORED_VARIABLES: [&str; 3] = ["GIRT_LOG_FILE", "GIRT_LOG_LEVEL", "GIRT_UPDATE_SNAPSHOTS"];
+
+// the variable of the editor, which is a setting of the `core` section of the git config
+const EDITOR_VARIABLE: &str = "GIRT_EDITOR";
+
+// convert the words of a variable, separated by `_`, to the camel case name of a setting, such as `AUTO_SELECT_NEXT`
+// to `autoSelectNext`
+fn to_setting_name(words: &str) -> Option<String> {
+	let mut name = String::new();
+	for (index, word) in words.split('_').enumerate() {
+		let mut chars = word.chars();
+		let first = chars.next()?;
+		if index == 0 {
+			name.push(first.to_ascii_lowercase());
+		}
+		else {
+			name.push(first.to_ascii_uppercase());
+		}
+		name.extend(chars.map(|c| c.to_ascii_lowercase()));
+	}
+	is_valid_name(name.as_str()).then_some(name)
+}
+
+/// Get the settings of the `GIRT_*` environment variables, that are read above all of the levels of the git config, so
+/// that a variable takes precedence over the same setting from git, or from the config file.
+///
+/// The name of a variable, after the `GIRT_` prefix, is the name of a setting of the `interactive-rebase-tool` section
+/// of the git config, with the words separated by `_`, such as `GIRT_AUTO_SELECT_NEXT` for `autoSelectNext`. A
+/// subsection is separated from the name of the setting with `__`, such as `GIRT_EXEC_TEMPLATE__TEST` for the `test`
+/// exec template. `GIRT_EDITOR` is the `core.editor` setting. The variables that are not the names of settings are
+/// ignored.
+pub(super) fn get_env_entries<V>(variables: V) -> Result<Vec<Entry>>
+where V: IntoIterator<Item = (OsString, OsString)> {
+	let mut entries = vec![];
+	let mut editor = None;
+	for (variable, value) in variables {
+		let variable = match variable.to_str() {
+			Some(variable) => variable,
+			None => continue,
+		};
+		let words = match variable.strip_prefix(PREFIX) {
+			Some(words) => words,
+			None => continue,
+		};
+		if IGNORED_VARIABLES.contains(&variable) {
+			continue;
+		}
+		let value = value
+			.into_string()
+			.map_err(|_| anyhow!("\"{}\" is not valid: value is not valid utf8", variable))?;
+		if variable == EDITOR_VARIABLE {
+			editor = Some(value);
+			continue;
+		}
+		let (subsection, name) = match words.split_once(SUBSECTION_SEPARATOR) {
+			Some((subsection, name)) => {
+				match to_setting_name(subsection) {
+					Some(subsection) => (Some(subsection), name),
+					None => continue,
+				}
+			},
+			None => (None, words),
+		};
+		let name = match to_setting_name(name) {
+			Some(name) => name,
+			None => continue,
+		};
+		entries.push(Entry {
+			section: String::from(SECTION),
+			subsection,
+			name,
+			value,
+		});
+	}
+	if let Some(editor) = editor {
+		entries.push(Entry {
+			section: String::from("core"),
+			subsection: None,
+			name: String::from("editor"),
+			value: editor,
+		});
+	}
+	Ok(entries)
+}
+
+#[cfg(test)]
+mod tests {
+	use git::ConfigSource;
+	use rstest::rstest;
+
+	use super::*;
+	use crate::{
+		config_layer::{ConfigLayer, LayeredConfig},
+		config_values::ConfigValues,
+		exec_template::get_exec_templates,
+		testutils::{assert_error, with_git_config},
+		utils::{get_bool, get_string},
+	};
+
+	fn variables(variables: &[(&str, &str)]) -> Vec<(OsString, OsString)> {
+		variables
+			.iter()
+			.map(|&(name, value)| (OsString::from(name), OsString::from(value)))
+			.collect()
+	}
+
+	#[rstest]
+	#[case::single_word("ICONS", Some("icons"))]
+	#[case::multiple_words("AUTO_SELECT_NEXT", Some("autoSelectNext"))]
+	#[case::digits("INPUT_MOVE_UP_5", Some("inputMoveUp5"))]
+	#[case::empty("", None)]
+	#[case::empty_word("AUTO__SELECT", None)]
+	#[case::starts_with_digit("5_UP", None)]
+	#[case::invalid_character("ICONS.ON", None)]
+	fn setting_name(#[case] words: &str, #[case] expected: Option<&str>) {
+		assert_eq!(to_setting_name(words).as_deref(), expected);
+	}
+
+	fn entry(section: &str, subsection: Option<&str>, name: &str, value: &str) -> Entry {
+		Entry {
+			section: String::from(section),
+			subsection: subsection.map(String::from),
+			name: String::from(name),
+			value: String::from(value),
+		}
+	}
+
+	#[test]
+	fn env_entries() {
+		assert_eq!(
+			get_env_entries(variables(&[
+				("GIRT_EXEC_TEMPLATE__CHECK", "cargo check"),
+				("GIRT_AUTO_SELECT_NEXT", "true"),
+				("GIRT_EDITOR", "nano"),
+				("GIRT_LOG_LEVEL", "debug"),
+				("GIRT_", "value"),
+				("GIRT_EXEC_TEMPLATE__", "value"),
+				("PATH", "/bin"),
+			]))
+			.unwrap(),
+			vec![
+				entry(SECTION, Some("execTemplate"), "check", "cargo check"),
+				entry(SECTION, None, "autoSelectNext", "true"),
+				entry("core", None, "editor", "nano"),
+			]
+		);
+	}
+
+	#[test]
+	fn env_entries_above_git_config() {
+		with_git_config(
+			&[
+				"[core]",
+				"editor = vim",
+				"[interactive-rebase-tool]",
+				"autoSelectNext = false",
+			],
+			|git_config| {
+				let entries = get_env_entries(variables(&[
+					("GIRT_AUTO_SELECT_NEXT", "true"),
+					("GIRT_EXEC_TEMPLATE__CHECK", "cargo check"),
+					("GIRT_EDITOR", "nano"),
+				]))
+				.unwrap();
+				let layer = ConfigLayer::new(ConfigSource::Environment, entries);
+				let mut config = LayeredConfig::new();
+				config.push(&git_config);
+				config.push(&layer);
+				assert!(get_bool(Some(&config), "interactive-rebase-tool.autoSelectNext", false).unwrap());
+				assert_eq!(get_string(Some(&config), "core.editor", "").unwrap(), "nano");
+				assert_eq!(config.sources().get("core.editor"), Some(&ConfigSource::Environment));
+				let templates = get_exec_templates(Some(&config)).unwrap();
+				assert_eq!(templates.len(), 1);
+				assert_eq!(templates[0].command, "cargo check");
+			},
+		);
+	}
+
+	#[cfg(unix)]
+	#[test]
+	fn env_entries_invalid_utf() {
+		use std::os::unix::ffi::OsStringExt;
+		assert_error(
+			get_env_entries(vec![(
+				OsString::from("GIRT_ICONS"),
+				OsString::from_vec(vec![0xC3, 0x28]),
+			)]),
+			"\"GIRT_ICONS\" is not valid: value is not valid utf8",
+		);
+	}
+}
@@ -0,0 +1,134 @@
+use anyhow::{anyhow, Result};
+
+use super::config_values::ConfigValues;
+
+// the templates are the variables of the `[interactive-rebase-tool "execTemplate"]` section, and like the
+// subsection of a git config, the name of the section is case sensitive
+const EXEC_TEMPLATE_PREFIX: &str = "interactive-rebase-tool.execTemplate.";
+
+/// A named command of an exec line, that can be inserted into the todo list.
+///
+/// The command can contain the placeholders `{commit}`, `{subject}` and `{branch}`, that are replaced with the hash
+/// and subject of the commit that the exec line is inserted after, and the branch that is rebased.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExecTemplate {
+	/// The name of the template.
+	pub name: String,
+	/// The command of the template, with its placeholders.
+	pub command: String,
+}
+
+/// Get the exec templates, in the order they are configured. A template that is configured more than once takes the
+/// last command.
+pub(super) fn get_exec_templates(config: Option<&dyn ConfigValues>) -> Result<Vec<ExecTemplate>> {
+	let mut templates: Vec<ExecTemplate> = vec![];
+	let cfg = match config {
+		None => return Ok(templates),
+		Some(c) => c,
+	};
+	for (full_name, value) in cfg.entries(EXEC_TEMPLATE_PREFIX)? {
+		let name = match full_name.strip_prefix(EXEC_TEMPLATE_PREFIX) {
+			Some(name) => name,
+			None => continue,
+		};
+		let command = value
+			.ok_or_else(|| anyhow!("configuration value is not valid utf8"))
+			.map_err(|e| e.context(anyhow!("\"{}{}\" is not valid", EXEC_TEMPLATE_PREFIX, name)))?;
+		match templates.iter_mut().find(|template| template.name == name) {
+			Some(template) => template.command = command,
+			None => {
+				templates.push(ExecTemplate {
+					name: String::from(name),
+					command,
+				});
+			},
+		}
+	}
+	Ok(templates)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::testutils::{assert_error, invalid_utf, with_git_config};
+
+	fn template(name: &str, command: &str) -> ExecTemplate {
+		ExecTemplate {
+			name: String::from(name),
+			command: String::from(command),
+		}
+	}
+
+	#[test]
+	fn without_config() {
+		assert_eq!(get_exec_templates(None).unwrap(), vec![]);
+	}
+
+	#[test]
+	fn not_configured() {
+		with_git_config(&["[interactive-rebase-tool]", "autoSelectNext = true"], |git_config| {
+			assert_eq!(get_exec_templates(Some(&git_config)).unwrap(), vec![]);
+		});
+	}
+
+	#[test]
+	fn templates() {
+		with_git_config(
+			&[
+				"[interactive-rebase-tool \"execTemplate\"]",
+				"test = cargo test",
+				"show = git show --stat {commit}",
+			],
+			|git_config| {
+				assert_eq!(get_exec_templates(Some(&git_config)).unwrap(), vec![
+					template("test", "cargo test"),
+					template("show", "git show --stat {commit}"),
+				]);
+			},
+		);
+	}
+
+	#[test]
+	fn template_configured_twice() {
+		with_git_config(
+			&[
+				"[interactive-rebase-tool \"execTemplate\"]",
+				"test = cargo test",
+				"show = git show {commit}",
+				"test = make test",
+			],
+			|git_config| {
+				assert_eq!(get_exec_templates(Some(&git_config)).unwrap(), vec![
+					template("test", "make test"),
+					template("show", "git show {commit}"),
+				]);
+			},
+		);
+	}
+
+	#[test]
+	fn other_subsection() {
+		with_git_config(
+			&["[interactive-rebase-tool \"other\"]", "test = cargo test"],
+			|git_config| {
+				assert_eq!(get_exec_templates(Some(&git_config)).unwrap(), vec![]);
+			},
+		);
+	}
+
+	#[test]
+	fn invalid_command() {
+		with_git_config(
+			&[
+				"[interactive-rebase-tool \"execTemplate\"]",
+				format!("test = {}", invalid_utf()).as_str(),
+			],
+			|git_config| {
+				assert_error(
+					get_exec_templates(Some(&git_config)),
+					"\"interactive-rebase-tool.execTemplate.test\" is not valid: configuration value is not valid utf8",
+				);
+			},
+		);
+	}
+}
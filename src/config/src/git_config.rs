@@ -1,11 +1,19 @@
 use anyhow::{anyhow, Error, Result};
 use git::Config;
 
-use super::utils::{editor_from_env, get_string, get_unsigned_integer};
+use super::{
+	config_reader::ConfigReader,
+	config_values::ConfigValues,
+	utils::{editor_from_env, get_abbreviation_length, get_string, git_editor_from_env},
+};
 
 /// Represents the git configuration options.
 #[derive(Clone, Debug)]
 pub struct GitConfig {
+	/// The length of abbreviated hashes, from [`core.abbrev`](
+	///     https://git-scm.com/docs/git-config#Documentation/git-config.txt-coreabbrev
+	/// ), or `None` when the length is chosen automatically.
+	pub abbrev: Option<u32>,
 	/// The Git comment character, from [`core.commentChar`](
 	///     https://git-scm.com/docs/git-config#Documentation/git-config.txt-corecommentChar
 	/// ).
@@ -30,10 +38,14 @@ pub struct GitConfig {
 	///     https://git-scm.com/docs/diff-config/#Documentation/diff-config.txt-diffrenames
 	/// ).
 	pub diff_copies: bool,
-	/// The Git editor, from [`core.editor`](
+	/// The Git editor, from `GIT_EDITOR`, [`core.editor`](
 	///     https://git-scm.com/docs/git-config#Documentation/git-config.txt-coreeditor
-	/// ).
+	/// ), `VISUAL` or `EDITOR`, in that order, like in Git.
 	pub editor: String,
+	/// If to stash changes before a rebase, from [`rebase.autoStash`](
+	///     https://git-scm.com/docs/git-config#Documentation/git-config.txt-rebaseautoStash
+	/// ).
+	pub rebase_auto_stash: bool,
 }
 
 impl GitConfig {
@@ -44,35 +56,46 @@ impl GitConfig {
 		Self::new_with_config(None).expect("Panic without git config instance") // should never error with None config
 	}
 
-	pub(super) fn new_with_config(git_config: Option<&Config>) -> Result<Self> {
-		let mut comment_char = get_string(git_config, "core.commentChar", "#")?;
+	pub(super) fn new_with_config(git_config: Option<&dyn ConfigValues>) -> Result<Self> {
+		ConfigReader::load(git_config, Self::new_with_reader)
+	}
+
+	pub(super) fn new_with_reader(reader: &mut ConfigReader<'_>) -> Self {
+		let mut comment_char = reader.string("core.commentChar", "#");
 		if comment_char.as_str().eq("auto") {
 			comment_char = String::from("#");
 		}
 
-		let git_diff_renames = get_string(git_config, "diff.renames", "true")?.to_lowercase();
-		let (diff_renames, diff_copies) = match git_diff_renames.to_lowercase().as_str() {
-			"true" => (true, false),
-			"false" => (false, false),
-			"copy" | "copies" => (true, true),
-			v => {
-				return Err(anyhow!(
-					"\"{}\" does not match one of \"true\", \"false\", \"copy\" or \"copies\"",
-					v
-				)
-				.context("\"diff.renames\" is not valid"));
-			},
-		};
+		let (diff_renames, diff_copies) = reader.read(|config| {
+			let git_diff_renames = get_string(config, "diff.renames", "true")?;
+			match git_diff_renames.to_lowercase().as_str() {
+				"true" => Ok((true, false)),
+				"false" => Ok((false, false)),
+				"copy" | "copies" => Ok((true, true)),
+				v => {
+					Err(anyhow!(
+						"\"{}\" does not match one of \"true\", \"false\", \"copy\" or \"copies\"",
+						v
+					)
+					.context("\"diff.renames\" is not valid"))
+				},
+			}
+		});
 
-		Ok(Self {
+		Self {
+			abbrev: reader.read(|config| get_abbreviation_length(config, "core.abbrev")),
 			comment_char,
-			diff_context: get_unsigned_integer(git_config, "diff.context", 3)?,
-			diff_interhunk_lines: get_unsigned_integer(git_config, "diff.interHunkContext", 0)?,
-			diff_rename_limit: get_unsigned_integer(git_config, "diff.renameLimit", 200)?,
+			diff_context: reader.unsigned_integer("diff.context", 3),
+			diff_interhunk_lines: reader.unsigned_integer("diff.interHunkContext", 0),
+			diff_rename_limit: reader.unsigned_integer("diff.renameLimit", 200),
 			diff_renames,
 			diff_copies,
-			editor: get_string(git_config, "core.editor", editor_from_env().as_str())?,
-		})
+			editor: match git_editor_from_env() {
+				Some(editor) => editor,
+				None => reader.string("core.editor", editor_from_env().as_str()),
+			},
+			rebase_auto_stash: reader.bool("rebase.autoStash", false),
+		}
 	}
 }
 
@@ -94,7 +117,7 @@ impl TryFrom<&Config> for GitConfig {
 
 #[cfg(test)]
 mod tests {
-	use std::env::{remove_var, set_var};
+	use std::env::{remove_var, set_var, var_os};
 
 	use super::*;
 	use crate::testutils::{assert_error, invalid_utf, with_git_config};
@@ -123,6 +146,46 @@ mod tests {
 		});
 	}
 
+	#[test]
+	fn abbrev_default() {
+		let config = GitConfig::new();
+		assert_eq!(config.abbrev, None);
+	}
+
+	#[test]
+	fn abbrev_auto() {
+		with_git_config(&["[core]", "abbrev = auto"], |git_config| {
+			let config = GitConfig::new_with_config(Some(&git_config)).unwrap();
+			assert_eq!(config.abbrev, None);
+		});
+	}
+
+	#[test]
+	fn abbrev_no() {
+		with_git_config(&["[core]", "abbrev = no"], |git_config| {
+			let config = GitConfig::new_with_config(Some(&git_config)).unwrap();
+			assert_eq!(config.abbrev, Some(40));
+		});
+	}
+
+	#[test]
+	fn abbrev() {
+		with_git_config(&["[core]", "abbrev = 12"], |git_config| {
+			let config = GitConfig::new_with_config(Some(&git_config)).unwrap();
+			assert_eq!(config.abbrev, Some(12));
+		});
+	}
+
+	#[test]
+	fn abbrev_invalid() {
+		with_git_config(&["[core]", "abbrev = 3"], |git_config| {
+			assert_error(
+				GitConfig::new_with_config(Some(&git_config)),
+				"\"core.abbrev\" is not valid: \"3\" does not match one of \"auto\", \"no\" or a length from 4 to 40",
+			);
+		});
+	}
+
 	#[test]
 	fn comment_char_default() {
 		let config = GitConfig::new();
@@ -327,24 +390,33 @@ mod tests {
 	#[test]
 	#[serial_test::serial]
 	fn git_editor_default_no_env() {
+		remove_var("GIT_EDITOR");
 		remove_var("VISUAL");
 		remove_var("EDITOR");
 		let config = GitConfig::new();
-		assert_eq!(config.editor, "vi");
+		assert_eq!(config.editor, if cfg!(windows) { "notepad" } else { "vi" });
 	}
 
 	#[test]
 	#[serial_test::serial]
 	fn git_editor_default_visual_env() {
+		remove_var("GIT_EDITOR");
 		remove_var("EDITOR");
 		set_var("VISUAL", "visual-editor");
+		let term = var_os("TERM");
+		set_var("TERM", "xterm");
 		let config = GitConfig::new();
+		match term {
+			Some(term) => set_var("TERM", term),
+			None => remove_var("TERM"),
+		}
 		assert_eq!(config.editor, "visual-editor");
 	}
 
 	#[test]
 	#[serial_test::serial]
 	fn git_editor_default_editor_env() {
+		remove_var("GIT_EDITOR");
 		remove_var("VISUAL");
 		set_var("EDITOR", "editor");
 
@@ -355,6 +427,7 @@ mod tests {
 	#[test]
 	#[serial_test::serial]
 	fn git_editor() {
+		remove_var("GIT_EDITOR");
 		remove_var("VISUAL");
 		remove_var("EDITOR");
 		with_git_config(&["[core]", "editor = custom"], |git_config| {
@@ -363,9 +436,51 @@ mod tests {
 		});
 	}
 
+	#[test]
+	#[serial_test::serial]
+	fn git_editor_default_dumb_terminal() {
+		remove_var("GIT_EDITOR");
+		set_var("VISUAL", "visual-editor");
+		set_var("EDITOR", "editor");
+		let term = var_os("TERM");
+		set_var("TERM", "dumb");
+		let config = GitConfig::new();
+		match term {
+			Some(term) => set_var("TERM", term),
+			None => remove_var("TERM"),
+		}
+		remove_var("VISUAL");
+		remove_var("EDITOR");
+		assert_eq!(config.editor, "editor");
+	}
+
+	#[test]
+	#[serial_test::serial]
+	fn git_editor_default_empty_env() {
+		remove_var("GIT_EDITOR");
+		set_var("VISUAL", "");
+		set_var("EDITOR", "");
+		let config = GitConfig::new();
+		remove_var("VISUAL");
+		remove_var("EDITOR");
+		assert_eq!(config.editor, if cfg!(windows) { "notepad" } else { "vi" });
+	}
+
+	#[test]
+	#[serial_test::serial]
+	fn git_editor_git_editor_env() {
+		set_var("GIT_EDITOR", "git-editor");
+		with_git_config(&["[core]", "editor = custom"], |git_config| {
+			let config = GitConfig::new_with_config(Some(&git_config)).unwrap();
+			remove_var("GIT_EDITOR");
+			assert_eq!(config.editor, "git-editor");
+		});
+	}
+
 	#[test]
 	#[serial_test::serial]
 	fn git_editor_invalid() {
+		remove_var("GIT_EDITOR");
 		remove_var("VISUAL");
 		remove_var("EDITOR");
 		with_git_config(
@@ -378,4 +493,28 @@ mod tests {
 			},
 		);
 	}
+
+	#[test]
+	fn rebase_auto_stash_default() {
+		let config = GitConfig::new();
+		assert!(!config.rebase_auto_stash);
+	}
+
+	#[test]
+	fn rebase_auto_stash() {
+		with_git_config(&["[rebase]", "autoStash = true"], |git_config| {
+			let config = GitConfig::new_with_config(Some(&git_config)).unwrap();
+			assert!(config.rebase_auto_stash);
+		});
+	}
+
+	#[test]
+	fn rebase_auto_stash_invalid() {
+		with_git_config(&["[rebase]", "autoStash = invalid"], |git_config| {
+			assert_error(
+				GitConfig::new_with_config(Some(&git_config)),
+				"\"rebase.autoStash\" is not valid: failed to parse 'invalid' as a boolean value",
+			);
+		});
+	}
 }
@@ -1,14 +1,15 @@
 use anyhow::{Error, Result};
 use git::Config;
 
-use super::utils::get_input;
-use crate::utils::map_single_ascii_to_lower;
+use crate::{config_reader::ConfigReader, config_values::ConfigValues, utils::map_single_ascii_to_lower};
 
 /// Represents the key binding configuration options.
 #[derive(Clone, Debug)]
 pub struct KeyBindings {
 	/// Key bindings for aborting.
 	pub abort: Vec<String>,
+	/// Key bindings for aborting an in progress rebase.
+	pub abort_rebase: Vec<String>,
 	/// Key bindings for the break action.
 	pub action_break: Vec<String>,
 	/// Key bindings for the drop action.
@@ -23,12 +24,26 @@ pub struct KeyBindings {
 	pub action_reword: Vec<String>,
 	/// Key bindings for the squash action.
 	pub action_squash: Vec<String>,
+	/// Key bindings for previewing the autosquash order of the fixup and squash commits.
+	pub autosquash: Vec<String>,
+	/// Key bindings for moving the selected commits to a new branch.
+	pub branch_out: Vec<String>,
+	/// Key bindings for inserting a break after every number of commits.
+	pub break_every: Vec<String>,
+	/// Key bindings for unmarking all of the marked lines.
+	pub clear_marks: Vec<String>,
 	/// Key bindings for negative confirmation.
 	pub confirm_no: Vec<String>,
 	/// Key bindings for positive confirmation.
 	pub confirm_yes: Vec<String>,
+	/// Key bindings for cycling the option of a fixup line, between no option, `-C` and `-c`.
+	pub cycle_fixup_option: Vec<String>,
+	/// Key bindings for describing the selected line.
+	pub describe_line: Vec<String>,
 	/// Key bindings for editing.
 	pub edit: Vec<String>,
+	/// Key bindings for filtering the commits to the commits that change a path.
+	pub filter_by_path: Vec<String>,
 	/// Key bindings for forcing a abort.
 	pub force_abort: Vec<String>,
 	/// Key bindings for forcing a rebase.
@@ -37,6 +52,10 @@ pub struct KeyBindings {
 	pub help: Vec<String>,
 	/// Key bindings for inserting a line.
 	pub insert_line: Vec<String>,
+	/// Key bindings for jumping to a line by its number.
+	pub jump_to_line: Vec<String>,
+	/// Key bindings for marking the lines that match an action, an author, or the last path filter.
+	pub mark_matching: Vec<String>,
 	/// Key bindings for moving down.
 	pub move_down: Vec<String>,
 	/// Key bindings for moving down a step.
@@ -57,18 +76,64 @@ pub struct KeyBindings {
 	pub move_up: Vec<String>,
 	/// Key bindings for moving up a step.
 	pub move_up_step: Vec<String>,
+	/// Key bindings for naming the group of the selected line.
+	pub name_group: Vec<String>,
 	/// Key bindings for opening the external editor.
 	pub open_in_external_editor: Vec<String>,
+	/// Key bindings for opening the settings.
+	pub open_settings: Vec<String>,
+	/// Key bindings for fixing the position or action of the selected fixup or squash line.
+	pub quick_fix: Vec<String>,
+	/// Key bindings for quitting without saving, keeping the original todo file.
+	pub quit_without_saving: Vec<String>,
 	/// Key bindings for rebasing.
 	pub rebase: Vec<String>,
 	/// Key bindings for redoing a change.
 	pub redo: Vec<String>,
 	/// Key bindings for removing a line.
 	pub remove_line: Vec<String>,
+	/// Key bindings for removing all of the break lines.
+	pub remove_breaks: Vec<String>,
+	/// Key bindings for reversing the order of the selected or marked lines, or of all of the lines.
+	pub reverse_lines: Vec<String>,
+	/// Key bindings for saving the changed settings to the global git config.
+	pub save_global_config: Vec<String>,
+	/// Key bindings for saving the changed settings to the git config of the repository.
+	pub save_repository_config: Vec<String>,
 	/// Key bindings for showing a commit.
 	pub show_commit: Vec<String>,
+	/// Key bindings for showing the diagnostics report.
+	pub show_diagnostics: Vec<String>,
 	/// Key bindings for showing a diff.
 	pub show_diff: Vec<String>,
+	/// Key bindings for showing the content of the changed files of a commit.
+	pub show_file: Vec<String>,
+	/// Key bindings for showing the history of the notifications.
+	pub show_notifications: Vec<String>,
+	/// Key bindings for showing the other commits that change the files of the selected commit.
+	pub show_related_commits: Vec<String>,
+	/// Key bindings for showing the commit that a commit was cherry picked from.
+	pub show_source_commit: Vec<String>,
+	/// Key bindings for showing the message of the combined commit of a chain of squash commits.
+	pub show_squash_message: Vec<String>,
+	/// Key bindings for showing the statistics of the todo list.
+	pub show_statistics: Vec<String>,
+	/// Key bindings for skipping the commit an in progress rebase stopped on.
+	pub skip_commit: Vec<String>,
+	/// Key bindings for sorting the commits of the selected or marked lines.
+	pub sort_lines: Vec<String>,
+	/// Key bindings for moving the selected lines with a preview of the new position.
+	pub start_move: Vec<String>,
+	/// Key bindings for inserting the exec template that tests a commit after the selected commit.
+	pub test_commit: Vec<String>,
+	/// Key bindings for collapsing all of the groups, or expanding all of the folds.
+	pub toggle_all_folds: Vec<String>,
+	/// Key bindings for collapsing or expanding the group of the selected line.
+	pub toggle_group: Vec<String>,
+	/// Key bindings for marking or unmarking the selected lines.
+	pub toggle_mark: Vec<String>,
+	/// Key bindings for toggling the uncommitted changes details.
+	pub toggle_stash_info: Vec<String>,
 	/// Key bindings for toggling visual mode.
 	pub toggle_visual_mode: Vec<String>,
 	/// Key bindings for undoing a change.
@@ -83,50 +148,89 @@ impl KeyBindings {
 		Self::new_with_config(None).expect("Panic without git config instance") // should never error with None config
 	}
 
-	pub(super) fn new_with_config(git_config: Option<&Config>) -> Result<Self> {
-		let confirm_no = get_input(git_config, "interactive-rebase-tool.inputConfirmNo", "n")?
+	pub(super) fn new_with_config(git_config: Option<&dyn ConfigValues>) -> Result<Self> {
+		ConfigReader::load(git_config, Self::new_with_reader)
+	}
+
+	pub(super) fn new_with_reader(reader: &mut ConfigReader<'_>) -> Self {
+		let confirm_no = reader
+			.input("interactive-rebase-tool.inputConfirmNo", "n")
 			.iter()
 			.map(|s| map_single_ascii_to_lower(s))
 			.collect();
-		let confirm_yes = get_input(git_config, "interactive-rebase-tool.inputConfirmYes", "y")?
+		let confirm_yes = reader
+			.input("interactive-rebase-tool.inputConfirmYes", "y")
 			.iter()
 			.map(|s| map_single_ascii_to_lower(s))
 			.collect();
-		Ok(Self {
-			abort: get_input(git_config, "interactive-rebase-tool.inputAbort", "q")?,
-			action_break: get_input(git_config, "interactive-rebase-tool.inputActionBreak", "b")?,
-			action_drop: get_input(git_config, "interactive-rebase-tool.inputActionDrop", "d")?,
-			action_edit: get_input(git_config, "interactive-rebase-tool.inputActionEdit", "e")?,
-			action_fixup: get_input(git_config, "interactive-rebase-tool.inputActionFixup", "f")?,
-			action_pick: get_input(git_config, "interactive-rebase-tool.inputActionPick", "p")?,
-			action_reword: get_input(git_config, "interactive-rebase-tool.inputActionReword", "r")?,
-			action_squash: get_input(git_config, "interactive-rebase-tool.inputActionSquash", "s")?,
+		Self {
+			abort: reader.input("interactive-rebase-tool.inputAbort", "q"),
+			abort_rebase: reader.input("interactive-rebase-tool.inputAbortRebase", "A"),
+			action_break: reader.input("interactive-rebase-tool.inputActionBreak", "b"),
+			action_drop: reader.input("interactive-rebase-tool.inputActionDrop", "d"),
+			action_edit: reader.input("interactive-rebase-tool.inputActionEdit", "e"),
+			action_fixup: reader.input("interactive-rebase-tool.inputActionFixup", "f"),
+			action_pick: reader.input("interactive-rebase-tool.inputActionPick", "p"),
+			action_reword: reader.input("interactive-rebase-tool.inputActionReword", "r"),
+			action_squash: reader.input("interactive-rebase-tool.inputActionSquash", "s"),
+			autosquash: reader.input("interactive-rebase-tool.inputAutosquash", "a"),
+			branch_out: reader.input("interactive-rebase-tool.inputBranchOut", "B"),
+			break_every: reader.input("interactive-rebase-tool.inputBreakEvery", "N"),
 			confirm_no,
 			confirm_yes,
-			edit: get_input(git_config, "interactive-rebase-tool.inputEdit", "E")?,
-			force_abort: get_input(git_config, "interactive-rebase-tool.inputForceAbort", "Q")?,
-			force_rebase: get_input(git_config, "interactive-rebase-tool.inputForceRebase", "W")?,
-			help: get_input(git_config, "interactive-rebase-tool.inputHelp", "?")?,
-			insert_line: get_input(git_config, "interactive-rebase-tool.insertLine", "I")?,
-			move_down: get_input(git_config, "interactive-rebase-tool.inputMoveDown", "Down")?,
-			move_down_step: get_input(git_config, "interactive-rebase-tool.inputMoveStepDown", "PageDown")?,
-			move_end: get_input(git_config, "interactive-rebase-tool.inputMoveEnd", "End")?,
-			move_home: get_input(git_config, "interactive-rebase-tool.inputMoveHome", "Home")?,
-			move_left: get_input(git_config, "interactive-rebase-tool.inputMoveLeft", "Left")?,
-			move_right: get_input(git_config, "interactive-rebase-tool.inputMoveRight", "Right")?,
-			move_selection_down: get_input(git_config, "interactive-rebase-tool.inputMoveSelectionDown", "j")?,
-			move_selection_up: get_input(git_config, "interactive-rebase-tool.inputMoveSelectionUp", "k")?,
-			move_up_step: get_input(git_config, "interactive-rebase-tool.inputMoveStepUp", "PageUp")?,
-			move_up: get_input(git_config, "interactive-rebase-tool.inputMoveUp", "Up")?,
-			open_in_external_editor: get_input(git_config, "interactive-rebase-tool.inputOpenInExternalEditor", "!")?,
-			rebase: get_input(git_config, "interactive-rebase-tool.inputRebase", "w")?,
-			redo: get_input(git_config, "interactive-rebase-tool.inputRedo", "control+y")?,
-			remove_line: get_input(git_config, "interactive-rebase-tool.removeLine", "delete")?,
-			show_commit: get_input(git_config, "interactive-rebase-tool.inputShowCommit", "c")?,
-			show_diff: get_input(git_config, "interactive-rebase-tool.inputShowDiff", "d")?,
-			toggle_visual_mode: get_input(git_config, "interactive-rebase-tool.inputToggleVisualMode", "v")?,
-			undo: get_input(git_config, "interactive-rebase-tool.inputUndo", "control+z")?,
-		})
+			clear_marks: reader.input("interactive-rebase-tool.inputClearMarks", "U"),
+			cycle_fixup_option: reader.input("interactive-rebase-tool.inputCycleFixupOption", "C"),
+			describe_line: reader.input("interactive-rebase-tool.inputDescribeLine", "L"),
+			edit: reader.input("interactive-rebase-tool.inputEdit", "E"),
+			filter_by_path: reader.input("interactive-rebase-tool.inputFilterByPath", "/"),
+			force_abort: reader.input("interactive-rebase-tool.inputForceAbort", "Q"),
+			force_rebase: reader.input("interactive-rebase-tool.inputForceRebase", "W"),
+			help: reader.input("interactive-rebase-tool.inputHelp", "?"),
+			insert_line: reader.input("interactive-rebase-tool.insertLine", "I"),
+			jump_to_line: reader.input("interactive-rebase-tool.inputJumpToLine", ":"),
+			mark_matching: reader.input("interactive-rebase-tool.inputMarkMatching", "*"),
+			move_down: reader.input("interactive-rebase-tool.inputMoveDown", "Down"),
+			move_down_step: reader.input("interactive-rebase-tool.inputMoveStepDown", "PageDown"),
+			move_end: reader.input("interactive-rebase-tool.inputMoveEnd", "End"),
+			move_home: reader.input("interactive-rebase-tool.inputMoveHome", "Home"),
+			move_left: reader.input("interactive-rebase-tool.inputMoveLeft", "Left"),
+			move_right: reader.input("interactive-rebase-tool.inputMoveRight", "Right"),
+			move_selection_down: reader.input("interactive-rebase-tool.inputMoveSelectionDown", "j"),
+			move_selection_up: reader.input("interactive-rebase-tool.inputMoveSelectionUp", "k"),
+			move_up_step: reader.input("interactive-rebase-tool.inputMoveStepUp", "PageUp"),
+			move_up: reader.input("interactive-rebase-tool.inputMoveUp", "Up"),
+			name_group: reader.input("interactive-rebase-tool.inputNameGroup", "n"),
+			open_in_external_editor: reader.input("interactive-rebase-tool.inputOpenInExternalEditor", "!"),
+			open_settings: reader.input("interactive-rebase-tool.inputOpenSettings", "o"),
+			quick_fix: reader.input("interactive-rebase-tool.inputQuickFix", "F"),
+			quit_without_saving: reader.input("interactive-rebase-tool.inputQuitWithoutSaving", "D"),
+			rebase: reader.input("interactive-rebase-tool.inputRebase", "w"),
+			redo: reader.input("interactive-rebase-tool.inputRedo", "control+y"),
+			remove_breaks: reader.input("interactive-rebase-tool.inputRemoveBreaks", "X"),
+			remove_line: reader.input("interactive-rebase-tool.removeLine", "delete"),
+			reverse_lines: reader.input("interactive-rebase-tool.inputReverseLines", "~"),
+			save_global_config: reader.input("interactive-rebase-tool.inputSaveGlobalConfig", "s"),
+			save_repository_config: reader.input("interactive-rebase-tool.inputSaveRepositoryConfig", "r"),
+			show_commit: reader.input("interactive-rebase-tool.inputShowCommit", "c"),
+			show_diagnostics: reader.input("interactive-rebase-tool.inputShowDiagnostics", "d"),
+			show_diff: reader.input("interactive-rebase-tool.inputShowDiff", "d"),
+			show_file: reader.input("interactive-rebase-tool.inputShowFile", "f"),
+			show_notifications: reader.input("interactive-rebase-tool.inputShowNotifications", "H"),
+			show_related_commits: reader.input("interactive-rebase-tool.inputShowRelatedCommits", "R"),
+			show_source_commit: reader.input("interactive-rebase-tool.inputShowSourceCommit", "o"),
+			show_squash_message: reader.input("interactive-rebase-tool.inputShowSquashMessage", "M"),
+			show_statistics: reader.input("interactive-rebase-tool.inputShowStatistics", "%"),
+			skip_commit: reader.input("interactive-rebase-tool.inputSkipCommit", "S"),
+			sort_lines: reader.input("interactive-rebase-tool.inputSortLines", "t"),
+			start_move: reader.input("interactive-rebase-tool.inputStartMove", "x"),
+			test_commit: reader.input("interactive-rebase-tool.inputTestCommit", "T"),
+			toggle_all_folds: reader.input("interactive-rebase-tool.inputToggleAllFolds", "Z"),
+			toggle_group: reader.input("interactive-rebase-tool.inputToggleGroup", "z"),
+			toggle_mark: reader.input("interactive-rebase-tool.inputToggleMark", "m"),
+			toggle_stash_info: reader.input("interactive-rebase-tool.inputToggleStashInfo", "i"),
+			toggle_visual_mode: reader.input("interactive-rebase-tool.inputToggleVisualMode", "v"),
+			undo: reader.input("interactive-rebase-tool.inputUndo", "control+z"),
+		}
 	}
 }
 
@@ -262,6 +366,7 @@ mod tests {
 
 	#[rstest]
 	#[case::abort("inputAbort", "q", |bindings: KeyBindings| bindings.abort)]
+	#[case::abort_rebase("inputAbortRebase", "A", |bindings: KeyBindings| bindings.abort_rebase)]
 	#[case::action_break("inputActionBreak", "b", |bindings: KeyBindings| bindings.action_break)]
 	#[case::action_drop("inputActionDrop", "d", |bindings: KeyBindings| bindings.action_drop)]
 	#[case::action_edit("inputActionEdit", "e", |bindings: KeyBindings| bindings.action_edit)]
@@ -269,13 +374,22 @@ mod tests {
 	#[case::action_pick("inputActionPick", "p", |bindings: KeyBindings| bindings.action_pick)]
 	#[case::action_reword("inputActionReword", "r", |bindings: KeyBindings| bindings.action_reword)]
 	#[case::action_squash("inputActionSquash", "s", |bindings: KeyBindings| bindings.action_squash)]
+	#[case::autosquash("inputAutosquash", "a", |bindings: KeyBindings| bindings.autosquash)]
+	#[case::branch_out("inputBranchOut", "B", |bindings: KeyBindings| bindings.branch_out)]
+	#[case::break_every("inputBreakEvery", "N", |bindings: KeyBindings| bindings.break_every)]
+	#[case::clear_marks("inputClearMarks", "U", |bindings: KeyBindings| bindings.clear_marks)]
 	#[case::confirm_no("inputConfirmNo", "n", |bindings: KeyBindings| bindings.confirm_no)]
 	#[case::confirm_yes("inputConfirmYes", "y", |bindings: KeyBindings| bindings.confirm_yes)]
+	#[case::cycle_fixup_option("inputCycleFixupOption", "C", |bindings: KeyBindings| bindings.cycle_fixup_option)]
+	#[case::describe_line("inputDescribeLine", "L", |bindings: KeyBindings| bindings.describe_line)]
 	#[case::edit("inputEdit", "E", |bindings: KeyBindings| bindings.edit)]
+	#[case::filter_by_path("inputFilterByPath", "/", |bindings: KeyBindings| bindings.filter_by_path)]
 	#[case::force_abort("inputForceAbort", "Q", |bindings: KeyBindings| bindings.force_abort)]
 	#[case::force_rebase("inputForceRebase", "W", |bindings: KeyBindings| bindings.force_rebase)]
 	#[case::help("inputHelp", "?", |bindings: KeyBindings| bindings.help)]
 	#[case::insert_line("insertLine", "I", |bindings: KeyBindings| bindings.insert_line)]
+	#[case::jump_to_line("inputJumpToLine", ":", |bindings: KeyBindings| bindings.jump_to_line)]
+	#[case::mark_matching("inputMarkMatching", "*", |bindings: KeyBindings| bindings.mark_matching)]
 	#[case::move_down("inputMoveDown", "Down", |bindings: KeyBindings| bindings.move_down)]
 	#[case::move_down_step("inputMoveStepDown", "PageDown", |bindings: KeyBindings| bindings.move_down_step)]
 	#[case::move_end("inputMoveEnd", "End", |bindings: KeyBindings| bindings.move_end)]
@@ -291,11 +405,42 @@ mod tests {
 		"!",
 		|bindings: KeyBindings| bindings.open_in_external_editor)
 	]
+	#[case::name_group("inputNameGroup", "n", |bindings: KeyBindings| bindings.name_group)]
+	#[case::open_settings("inputOpenSettings", "o", |bindings: KeyBindings| bindings.open_settings)]
+	#[case::quick_fix("inputQuickFix", "F", |bindings: KeyBindings| bindings.quick_fix)]
+	#[case::quit_without_saving(
+		"inputQuitWithoutSaving",
+		"D",
+		|bindings: KeyBindings| bindings.quit_without_saving
+	)]
 	#[case::rebase("inputRebase", "w", |bindings: KeyBindings| bindings.rebase)]
 	#[case::redo("inputRedo", "Controly", |bindings: KeyBindings| bindings.redo)]
+	#[case::remove_breaks("inputRemoveBreaks", "X", |bindings: KeyBindings| bindings.remove_breaks)]
 	#[case::remove_line("removeLine", "Delete", |bindings: KeyBindings| bindings.remove_line)]
+	#[case::reverse_lines("inputReverseLines", "~", |bindings: KeyBindings| bindings.reverse_lines)]
+	#[case::save_global_config("inputSaveGlobalConfig", "s", |bindings: KeyBindings| bindings.save_global_config)]
+	#[case::save_repository_config(
+		"inputSaveRepositoryConfig",
+		"r",
+		|bindings: KeyBindings| bindings.save_repository_config
+	)]
 	#[case::show_commit("inputShowCommit", "c", |bindings: KeyBindings| bindings.show_commit)]
+	#[case::show_diagnostics("inputShowDiagnostics", "d", |bindings: KeyBindings| bindings.show_diagnostics)]
 	#[case::show_diff("inputShowDiff", "d", |bindings: KeyBindings| bindings.show_diff)]
+	#[case::show_file("inputShowFile", "f", |bindings: KeyBindings| bindings.show_file)]
+	#[case::show_related_commits("inputShowRelatedCommits", "R", |bindings: KeyBindings| bindings.show_related_commits)]
+	#[case::show_source_commit("inputShowSourceCommit", "o", |bindings: KeyBindings| bindings.show_source_commit)]
+	#[case::show_squash_message("inputShowSquashMessage", "M", |bindings: KeyBindings| bindings.show_squash_message)]
+	#[case::show_notifications("inputShowNotifications", "H", |bindings: KeyBindings| bindings.show_notifications)]
+	#[case::show_statistics("inputShowStatistics", "%", |bindings: KeyBindings| bindings.show_statistics)]
+	#[case::skip_commit("inputSkipCommit", "S", |bindings: KeyBindings| bindings.skip_commit)]
+	#[case::sort_lines("inputSortLines", "t", |bindings: KeyBindings| bindings.sort_lines)]
+	#[case::start_move("inputStartMove", "x", |bindings: KeyBindings| bindings.start_move)]
+	#[case::test_commit("inputTestCommit", "T", |bindings: KeyBindings| bindings.test_commit)]
+	#[case::toggle_all_folds("inputToggleAllFolds", "Z", |bindings: KeyBindings| bindings.toggle_all_folds)]
+	#[case::toggle_group("inputToggleGroup", "z", |bindings: KeyBindings| bindings.toggle_group)]
+	#[case::toggle_mark("inputToggleMark", "m", |bindings: KeyBindings| bindings.toggle_mark)]
+	#[case::toggle_stash_info("inputToggleStashInfo", "i", |bindings: KeyBindings| bindings.toggle_stash_info)]
 	#[case::toggle_visual_mode("inputToggleVisualMode", "v", |bindings: KeyBindings| bindings.toggle_visual_mode)]
 	#[case::undo("inputUndo", "Controlz", |bindings: KeyBindings| bindings.undo)]
 	pub(crate) fn test_binding<F: 'static>(#[case] config_name: &str, #[case] default: &str, #[case] access: F)
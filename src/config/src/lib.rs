@@ -94,35 +94,75 @@
 //! To facilitate testing the usages of this crate, a set of testing utilities are provided. Since
 //! these utilities are not tested, and often are optimized for developer experience than
 //! performance should only be used in test code.
+mod abort_behavior;
+mod action_labels;
+mod cli_config;
 mod color;
+mod config_layer;
+mod config_reader;
+mod config_values;
+mod date_format;
 mod diff_ignore_whitespace_setting;
 mod diff_show_whitespace_setting;
+mod env_config;
+mod exec_template;
 mod git_config;
 mod key_bindings;
+mod palette;
 mod theme;
+mod toml_config;
 mod utils;
 
 #[cfg(test)]
 mod testutils;
 
+use std::{collections::HashMap, env};
+
 use anyhow::{Error, Result};
-use git::Repository;
+use git::{ConfigSource, Repository};
 
-use self::utils::{get_bool, get_diff_ignore_whitespace, get_diff_show_whitespace, get_string, get_unsigned_integer};
 pub use self::{
+	abort_behavior::AbortBehavior,
+	action_labels::{ActionLabel, ActionLabels},
 	color::Color,
+	config_reader::InvalidConfigError,
+	date_format::DateFormat,
 	diff_ignore_whitespace_setting::DiffIgnoreWhitespaceSetting,
 	diff_show_whitespace_setting::DiffShowWhitespaceSetting,
+	exec_template::ExecTemplate,
 	git_config::GitConfig,
 	key_bindings::KeyBindings,
+	palette::Palette,
 	theme::Theme,
 };
+use self::{
+	cli_config::parse_override,
+	config_layer::{ConfigLayer, LayeredConfig},
+	config_reader::ConfigReader,
+	config_values::ConfigValues,
+	env_config::get_env_entries,
+	exec_template::get_exec_templates,
+	toml_config::{get_config_file_path, load_config_file},
+	utils::{
+		get_abbreviation_length,
+		get_abort_behavior,
+		get_date_format,
+		get_diff_ignore_whitespace,
+		get_diff_show_whitespace,
+	},
+};
 
 /// Represents the configuration options.
 #[derive(Clone, Debug)]
 pub struct Config {
+	/// How the rebase is cancelled when the todo list is aborted.
+	pub abort_behavior: AbortBehavior,
+	/// The labels shown for the actions.
+	pub action_labels: ActionLabels,
 	/// If to select the next line in the list after performing an action.
 	pub auto_select_next: bool,
+	/// How the date of a commit is shown in the show commit view and the preview pane of the list.
+	pub date_format: DateFormat,
 	/// How to handle whitespace when calculating diffs.
 	pub diff_ignore_whitespace: DiffIgnoreWhitespaceSetting,
 	/// How to show whitespace in diffs.
@@ -133,6 +173,42 @@ pub struct Config {
 	pub diff_tab_symbol: String,
 	/// The display width of the tab character.
 	pub diff_tab_width: u32,
+	/// The named commands of exec lines, that can be inserted into the todo list.
+	pub exec_templates: Vec<ExecTemplate>,
+	/// If to fetch the objects of a commit that have not been fetched into a partial clone, from the promisor remote
+	/// of the clone, when the commit is shown.
+	pub fetch_missing_objects: bool,
+	/// The length of the hashes shown in the list, overriding the length from `core.abbrev`, or `None` to use it.
+	pub hash_length: Option<u32>,
+	/// If to show icons for the actions and the changed files, which requires a font with the Nerd Fonts icons.
+	pub icons: bool,
+	/// If to show a footer with the most relevant key bindings for the selected line and the mode.
+	pub key_hints: bool,
+	/// The number of repeats of a held navigation key after which the selection moves five lines at a time, or zero
+	/// to always move one line.
+	pub key_repeat_fast_threshold: u32,
+	/// The number of repeats of a held navigation key after which the selection moves a page at a time, or zero to
+	/// never move a page.
+	pub key_repeat_page_threshold: u32,
+	/// How the date of a commit is shown in the date column of the list.
+	pub list_date_format: DateFormat,
+	/// The editor of the combined message of a chain of squash commits, or the Git editor when empty.
+	pub message_editor: String,
+	/// If to remember the selected line, the shown stash information and the last path filter of the list of a
+	/// repository, and restore them when the list of the repository is next opened.
+	pub persist_ui_state: bool,
+	/// If to show the changes to the todo file, compared to the todo file that was opened, before the rebase is
+	/// confirmed.
+	pub review_changes: bool,
+	/// If to use output that is easier to follow with a screen reader, without box drawing characters or signals shown
+	/// only with color, and with announcements of changes to the list.
+	pub screen_reader: bool,
+	/// If to insert a break after the exec line that tests a commit.
+	pub test_commit_break: bool,
+	/// The name of the exec template that is inserted after a commit to test it.
+	pub test_commit_template: String,
+	/// The editor of the todo file, when it is edited as text, or the Git editor when empty.
+	pub todo_editor: String,
 	/// The maximum number of undo steps.
 	pub undo_limit: u32,
 	/// Configuration options loaded directly from Git.
@@ -141,6 +217,7 @@ pub struct Config {
 	pub key_bindings: KeyBindings,
 	/// Theme configuration.
 	pub theme: Theme,
+	sources: HashMap<String, ConfigSource>,
 }
 
 impl Config {
@@ -151,19 +228,91 @@ impl Config {
 		Self::new_with_config(None).expect("Panic without git config instance") // should never error with None config
 	}
 
-	fn new_with_config(git_config: Option<&git::Config>) -> Result<Self> {
-		Ok(Self {
-			auto_select_next: get_bool(git_config, "interactive-rebase-tool.autoSelectNext", false)?,
-			diff_ignore_whitespace: get_diff_ignore_whitespace(git_config)?,
-			diff_show_whitespace: get_diff_show_whitespace(git_config)?,
-			diff_space_symbol: get_string(git_config, "interactive-rebase-tool.diffSpaceSymbol", "·")?,
-			diff_tab_symbol: get_string(git_config, "interactive-rebase-tool.diffTabSymbol", "→")?,
-			diff_tab_width: get_unsigned_integer(git_config, "interactive-rebase-tool.diffTabWidth", 4)?,
-			undo_limit: get_unsigned_integer(git_config, "interactive-rebase-tool.undoLimit", 5000)?,
-			git: GitConfig::new_with_config(git_config)?,
-			key_bindings: KeyBindings::new_with_config(git_config)?,
-			theme: Theme::new_with_config(git_config)?,
-		})
+	/// Creates a new Config instance like [`Config::try_from`] for a [`git::Repository`], with the settings of the `-c`
+	/// option above all of the other settings. A setting is a `name=value`, in the same format as the `-c` option of
+	/// git, such as `interactive-rebase-tool.icons=true`.
+	///
+	/// # Errors
+	///
+	/// Will return an `Err` if there is a problem loading the configuration, or if a setting is not valid.
+	#[inline]
+	pub fn try_from_repository(repo: &Repository, overrides: &[String]) -> Result<Self> {
+		let config = repo.load_config().map_err(|e| e.context("Error loading git config"))?;
+		let config_file = match get_config_file_path() {
+			Some(path) => load_config_file(path.as_path()).map_err(|e| e.context("Error loading config file"))?,
+			None => None,
+		};
+		let environment = ConfigLayer::new(
+			ConfigSource::Environment,
+			get_env_entries(env::vars_os()).map_err(|e| e.context("Error loading environment variables"))?,
+		);
+		let mut entries = vec![];
+		for setting in overrides {
+			entries.push(parse_override(setting.as_str()).map_err(|e| e.context("Error reading config option"))?);
+		}
+		let command_line = ConfigLayer::new(ConfigSource::CommandLine, entries);
+		// the settings of the config file are beneath the settings of the git config, and the settings of the
+		// environment variables and then of the option are above them
+		let mut layered_config = LayeredConfig::new();
+		if let Some(ref layer) = config_file {
+			layered_config.push(layer);
+		}
+		layered_config.push(&config);
+		layered_config.push(&environment);
+		layered_config.push(&command_line);
+		Self::new_with_config(Some(&layered_config)).map_err(|e| e.context("Error reading git config"))
+	}
+
+	/// Where the value of the setting, with the full name of the setting such as `interactive-rebase-tool.icons`, is
+	/// loaded from, which is `ConfigSource::Default` when the setting is not set.
+	#[inline]
+	#[must_use]
+	pub fn source(&self, name: &str) -> ConfigSource {
+		self
+			.sources
+			.get(name.to_lowercase().as_str())
+			.copied()
+			.unwrap_or(ConfigSource::Default)
+	}
+
+	fn new_with_config(git_config: Option<&dyn ConfigValues>) -> Result<Self> {
+		ConfigReader::load(git_config, Self::new_with_reader)
+	}
+
+	fn new_with_reader(reader: &mut ConfigReader<'_>) -> Self {
+		Self {
+			abort_behavior: reader.read(get_abort_behavior),
+			action_labels: ActionLabels::new_with_reader(reader),
+			auto_select_next: reader.bool("interactive-rebase-tool.autoSelectNext", false),
+			date_format: reader.read(|config| get_date_format(config, "interactive-rebase-tool.dateFormat", "%c %z")),
+			diff_ignore_whitespace: reader.read(get_diff_ignore_whitespace),
+			diff_show_whitespace: reader.read(get_diff_show_whitespace),
+			diff_space_symbol: reader.string("interactive-rebase-tool.diffSpaceSymbol", "·"),
+			diff_tab_symbol: reader.string("interactive-rebase-tool.diffTabSymbol", "→"),
+			diff_tab_width: reader.unsigned_integer("interactive-rebase-tool.diffTabWidth", 4),
+			exec_templates: reader.read(get_exec_templates),
+			fetch_missing_objects: reader.bool("interactive-rebase-tool.fetchMissingObjects", false),
+			hash_length: reader.read(|config| get_abbreviation_length(config, "interactive-rebase-tool.hashLength")),
+			icons: reader.bool("interactive-rebase-tool.icons", false),
+			key_hints: reader.bool("interactive-rebase-tool.keyHints", false),
+			key_repeat_fast_threshold: reader.unsigned_integer("interactive-rebase-tool.keyRepeatFastThreshold", 10),
+			key_repeat_page_threshold: reader.unsigned_integer("interactive-rebase-tool.keyRepeatPageThreshold", 30),
+			list_date_format: reader.read(|config| {
+				get_date_format(config, "interactive-rebase-tool.listDateFormat", "%Y-%m-%d")
+			}),
+			message_editor: reader.string("interactive-rebase-tool.messageEditor", ""),
+			persist_ui_state: reader.bool("interactive-rebase-tool.persistUiState", true),
+			review_changes: reader.bool("interactive-rebase-tool.reviewChanges", false),
+			screen_reader: reader.bool("interactive-rebase-tool.screenReader", false),
+			test_commit_break: reader.bool("interactive-rebase-tool.testCommitBreak", false),
+			test_commit_template: reader.string("interactive-rebase-tool.testCommitTemplate", "test"),
+			todo_editor: reader.string("interactive-rebase-tool.todoEditor", ""),
+			undo_limit: reader.unsigned_integer("interactive-rebase-tool.undoLimit", 5000),
+			git: GitConfig::new_with_reader(reader),
+			key_bindings: KeyBindings::new_with_reader(reader),
+			theme: Theme::new_with_reader(reader),
+			sources: reader.sources(),
+		}
 	}
 }
 
@@ -177,15 +326,16 @@ impl Default for Config {
 impl TryFrom<&Repository> for Config {
 	type Error = Error;
 
-	/// Creates a new Config instance loading the Git Config using [`git::Repository`].
+	/// Creates a new Config instance loading the Git Config using [`git::Repository`], with the settings of the
+	/// `interactive-rebase-tool/config.toml` file of the XDG config directory beneath the settings of the Git Config,
+	/// and the settings of the `GIRT_*` environment variables above them.
 	///
 	/// # Errors
 	///
 	/// Will return an `Err` if there is a problem loading the configuration.
 	#[inline]
 	fn try_from(repo: &Repository) -> core::result::Result<Self, Error> {
-		let config = repo.load_config().map_err(|e| e.context("Error loading git config"))?;
-		Self::new_with_config(Some(&config)).map_err(|e| e.context("Error reading git config"))
+		Self::try_from_repository(repo, &[])
 	}
 }
 
@@ -242,10 +392,60 @@ mod tests {
 		);
 	}
 
+	#[test]
+	fn try_from_git_config_every_error() {
+		with_git_config(
+			&[
+				"[interactive-rebase-tool]",
+				"autoSelectNext = invalid",
+				"breakColor = invalid",
+				"[diff]",
+				"context = invalid",
+				"renames = invalid",
+			],
+			|git_config| {
+				let error = Config::try_from(&git_config).unwrap_err();
+				let invalid_config_error = error.downcast_ref::<InvalidConfigError>().unwrap();
+				assert_eq!(invalid_config_error.errors().len(), 4);
+				assert!(format!("{}", error).starts_with("4 config values are not valid"));
+			},
+		);
+	}
+
+	#[test]
+	fn source() {
+		with_git_config(&["[interactive-rebase-tool]", "icons = true"], |git_config| {
+			let config = Config::try_from(&git_config).unwrap();
+			assert_eq!(config.source("interactive-rebase-tool.icons"), ConfigSource::Local);
+			assert_eq!(config.source("interactive-rebase-tool.keyHints"), ConfigSource::Default);
+		});
+	}
+
+	#[test]
+	fn source_default_config() {
+		assert_eq!(Config::new().source("interactive-rebase-tool.icons"), ConfigSource::Default);
+	}
+
 	#[rstest]
+	#[case::abort_behavior_default("abortBehavior", "", AbortBehavior::Empty, |config: Config| config.abort_behavior)]
+	#[case::abort_behavior_empty("abortBehavior", "empty", AbortBehavior::Empty, |config: Config| config.abort_behavior)]
+	#[case::abort_behavior_exit_code(
+		"abortBehavior",
+		"exit-code",
+		AbortBehavior::ExitCode,
+		|config: Config| config.abort_behavior)
+	]
 	#[case::auto_select_next_default("autoSelectNext", "", false, |config: Config| config.auto_select_next)]
 	#[case::auto_select_next_false("autoSelectNext", "false", false, |config: Config| config.auto_select_next)]
 	#[case::auto_select_next_true("autoSelectNext", "true", true, |config: Config| config.auto_select_next)]
+	#[case::date_format_default(
+		"dateFormat",
+		"",
+		DateFormat::Format(String::from("%c %z")),
+		|config: Config| config.date_format)
+	]
+	#[case::date_format("dateFormat", "%d/%m/%Y", DateFormat::Format(String::from("%d/%m/%Y")), |config: Config| config.date_format)]
+	#[case::date_format_relative("dateFormat", "Relative", DateFormat::Relative, |config: Config| config.date_format)]
 	#[case::diff_ignore_whitespace_default(
 		"diffIgnoreWhitespace",
 		"",
@@ -362,6 +562,32 @@ mod tests {
 	]
 	#[case::diff_tab_width_default("diffTabWidth", "", 4, |config: Config| config.diff_tab_width)]
 	#[case::diff_tab_width("diffTabWidth", "42", 42, |config: Config| config.diff_tab_width)]
+	#[case::key_repeat_fast_threshold_default(
+		"keyRepeatFastThreshold",
+		"",
+		10,
+		|config: Config| config.key_repeat_fast_threshold)
+	]
+	#[case::key_repeat_fast_threshold(
+		"keyRepeatFastThreshold",
+		"0",
+		0,
+		|config: Config| config.key_repeat_fast_threshold)
+	]
+	#[case::key_repeat_page_threshold_default(
+		"keyRepeatPageThreshold",
+		"",
+		30,
+		|config: Config| config.key_repeat_page_threshold)
+	]
+	#[case::key_repeat_page_threshold(
+		"keyRepeatPageThreshold",
+		"42",
+		42,
+		|config: Config| config.key_repeat_page_threshold)
+	]
+	#[case::fetch_missing_objects_default("fetchMissingObjects", "", false, |config: Config| config.fetch_missing_objects)]
+	#[case::fetch_missing_objects_true("fetchMissingObjects", "true", true, |config: Config| config.fetch_missing_objects)]
 	#[case::diff_tab_symbol_default("diffTabSymbol", "", String::from("→"), |config: Config| config.diff_tab_symbol)]
 	#[case::diff_tab_symbol("diffTabSymbol", "|", String::from("|"), |config: Config| config.diff_tab_symbol)]
 	#[case::diff_tab_symbol("diffTabSymbol", "|", String::from("|"), |config: Config| config.diff_tab_symbol)]
@@ -372,6 +598,55 @@ mod tests {
 		|config: Config| config.diff_space_symbol)
 	]
 	#[case::diff_space_symbol("diffSpaceSymbol", "-", String::from("-"), |config: Config| config.diff_space_symbol)]
+	#[case::hash_length_default("hashLength", "", None, |config: Config| config.hash_length)]
+	#[case::hash_length_auto("hashLength", "auto", None, |config: Config| config.hash_length)]
+	#[case::hash_length_no("hashLength", "no", Some(40), |config: Config| config.hash_length)]
+	#[case::hash_length("hashLength", "12", Some(12), |config: Config| config.hash_length)]
+	#[case::icons_default("icons", "", false, |config: Config| config.icons)]
+	#[case::icons_true("icons", "true", true, |config: Config| config.icons)]
+	#[case::key_hints_default("keyHints", "", false, |config: Config| config.key_hints)]
+	#[case::key_hints_true("keyHints", "true", true, |config: Config| config.key_hints)]
+	#[case::list_date_format_default(
+		"listDateFormat",
+		"",
+		DateFormat::Format(String::from("%Y-%m-%d")),
+		|config: Config| config.list_date_format)
+	]
+	#[case::list_date_format_relative(
+		"listDateFormat",
+		"relative",
+		DateFormat::Relative,
+		|config: Config| config.list_date_format)
+	]
+	#[case::message_editor_default("messageEditor", "", String::new(), |config: Config| config.message_editor)]
+	#[case::message_editor(
+		"messageEditor",
+		"code --wait",
+		String::from("code --wait"),
+		|config: Config| config.message_editor)
+	]
+	#[case::persist_ui_state_default("persistUiState", "", true, |config: Config| config.persist_ui_state)]
+	#[case::persist_ui_state_false("persistUiState", "false", false, |config: Config| config.persist_ui_state)]
+	#[case::review_changes_default("reviewChanges", "", false, |config: Config| config.review_changes)]
+	#[case::review_changes_true("reviewChanges", "true", true, |config: Config| config.review_changes)]
+	#[case::screen_reader_default("screenReader", "", false, |config: Config| config.screen_reader)]
+	#[case::screen_reader_true("screenReader", "true", true, |config: Config| config.screen_reader)]
+	#[case::test_commit_break_default("testCommitBreak", "", false, |config: Config| config.test_commit_break)]
+	#[case::test_commit_break_true("testCommitBreak", "true", true, |config: Config| config.test_commit_break)]
+	#[case::test_commit_template_default(
+		"testCommitTemplate",
+		"",
+		String::from("test"),
+		|config: Config| config.test_commit_template)
+	]
+	#[case::test_commit_template(
+		"testCommitTemplate",
+		"check",
+		String::from("check"),
+		|config: Config| config.test_commit_template)
+	]
+	#[case::todo_editor_default("todoEditor", "", String::new(), |config: Config| config.todo_editor)]
+	#[case::todo_editor("todoEditor", "nano", String::from("nano"), |config: Config| config.todo_editor)]
 	#[case::undo_limit_default("undoLimit", "", 5000, |config: Config| config.undo_limit)]
 	#[case::undo_limit_default("undoLimit", "42", 42, |config: Config| config.undo_limit)]
 	pub(crate) fn theme_color<F: 'static, T: Debug + PartialEq>(
@@ -396,11 +671,27 @@ mod tests {
 	}
 
 	#[rstest]
+	#[case::abort_behavior(
+		"abortBehavior",
+		"invalid",
+		"\"interactive-rebase-tool.abortBehavior\" is not valid: \"invalid\" does not match one of \"empty\" or \
+		 \"exit-code\""
+	)]
 	#[case::auto_select_next(
 		"autoSelectNext",
 		"invalid",
 		"\"interactive-rebase-tool.autoSelectNext\" is not valid: failed to parse \'invalid\' as a boolean value"
 	)]
+	#[case::date_format(
+		"dateFormat",
+		"%Q",
+		"\"interactive-rebase-tool.dateFormat\" is not valid: \"%Q\" is not \"relative\" or a valid strftime format"
+	)]
+	#[case::list_date_format(
+		"listDateFormat",
+		invalid_utf(),
+		"\"interactive-rebase-tool.listDateFormat\" is not valid: configuration value is not valid utf8"
+	)]
 	#[case::diff_ignore_whitespace(
 		"diffIgnoreWhitespace",
 		"invalid",
@@ -434,6 +725,68 @@ mod tests {
 		invalid_utf(),
 		"\"interactive-rebase-tool.diffSpaceSymbol\" is not valid: configuration value is not valid utf8"
 	)]
+	#[case::hash_length_non_integer(
+		"hashLength",
+		"invalid",
+		"\"interactive-rebase-tool.hashLength\" is not valid: \"invalid\" does not match one of \"auto\", \"no\" or a \
+		 length from 4 to 40"
+	)]
+	#[case::hash_length_out_of_range(
+		"hashLength",
+		"41",
+		"\"interactive-rebase-tool.hashLength\" is not valid: \"41\" does not match one of \"auto\", \"no\" or a \
+		 length from 4 to 40"
+	)]
+	#[case::fetch_missing_objects(
+		"fetchMissingObjects",
+		"invalid",
+		"\"interactive-rebase-tool.fetchMissingObjects\" is not valid: failed to parse \'invalid\' as a boolean value"
+	)]
+	#[case::icons(
+		"icons",
+		"invalid",
+		"\"interactive-rebase-tool.icons\" is not valid: failed to parse \'invalid\' as a boolean value"
+	)]
+	#[case::key_hints(
+		"keyHints",
+		"invalid",
+		"\"interactive-rebase-tool.keyHints\" is not valid: failed to parse \'invalid\' as a boolean value"
+	)]
+	#[case::message_editor(
+		"messageEditor",
+		invalid_utf(),
+		"\"interactive-rebase-tool.messageEditor\" is not valid: configuration value is not valid utf8"
+	)]
+	#[case::persist_ui_state(
+		"persistUiState",
+		"invalid",
+		"\"interactive-rebase-tool.persistUiState\" is not valid: failed to parse \'invalid\' as a boolean value"
+	)]
+	#[case::review_changes(
+		"reviewChanges",
+		"invalid",
+		"\"interactive-rebase-tool.reviewChanges\" is not valid: failed to parse \'invalid\' as a boolean value"
+	)]
+	#[case::screen_reader(
+		"screenReader",
+		"invalid",
+		"\"interactive-rebase-tool.screenReader\" is not valid: failed to parse \'invalid\' as a boolean value"
+	)]
+	#[case::test_commit_break(
+		"testCommitBreak",
+		"invalid",
+		"\"interactive-rebase-tool.testCommitBreak\" is not valid: failed to parse \'invalid\' as a boolean value"
+	)]
+	#[case::test_commit_template(
+		"testCommitTemplate",
+		invalid_utf(),
+		"\"interactive-rebase-tool.testCommitTemplate\" is not valid: configuration value is not valid utf8"
+	)]
+	#[case::todo_editor(
+		"todoEditor",
+		invalid_utf(),
+		"\"interactive-rebase-tool.todoEditor\" is not valid: configuration value is not valid utf8"
+	)]
 	#[case::undo_limit_non_integer(
 		"undoLimit",
 		"invalid",
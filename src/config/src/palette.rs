@@ -0,0 +1,136 @@
+use std::fmt::{Display, Formatter};
+
+use super::Color;
+
+/// Configuration option for the palette that provides the default colors of the theme.
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[non_exhaustive]
+pub enum Palette {
+	/// The default colors.
+	Default,
+	/// Bright colors with a strong contrast to the background.
+	HighContrast,
+	/// Colors that can be told apart with deuteranopia, red-green color blindness from a missing green cone.
+	Deuteranopia,
+	/// Colors that can be told apart with protanopia, red-green color blindness from a missing red cone, where reds
+	/// appear dark.
+	Protanopia,
+}
+
+impl Palette {
+	// the blues, oranges and yellows are close to the Okabe-Ito palette, which pairs blue with orange in place of green
+	// with red, so added and removed lines do not rely on telling red from green
+	const fn colors(self) -> &'static [(&'static str, Color)] {
+		match self {
+			Self::Default => &[],
+			Self::HighContrast => {
+				&[
+					("breakColor", Color::LightWhite),
+					("execColor", Color::LightWhite),
+					("labelColor", Color::LightYellow),
+					("resetColor", Color::LightYellow),
+					("mergeColor", Color::LightYellow),
+					("diffContextColor", Color::LightWhite),
+					("diffWhitespace", Color::LightWhite),
+					("foregroundColor", Color::LightWhite),
+					("indicatorColor", Color::LightYellow),
+					("selectedBackgroundColor", Color::Index(18)),
+				]
+			},
+			Self::Deuteranopia => {
+				&[
+					("dropColor", Color::Index(208)),
+					("editColor", Color::Index(175)),
+					("fixupColor", Color::Index(141)),
+					("pickColor", Color::Index(75)),
+					("rewordColor", Color::Index(227)),
+					("squashColor", Color::Index(36)),
+					("diffAddColor", Color::Index(75)),
+					("diffChangeColor", Color::Index(227)),
+					("diffRemoveColor", Color::Index(208)),
+				]
+			},
+			Self::Protanopia => {
+				&[
+					("dropColor", Color::Index(220)),
+					("editColor", Color::Index(183)),
+					("fixupColor", Color::Index(141)),
+					("pickColor", Color::Index(33)),
+					("rewordColor", Color::LightWhite),
+					("squashColor", Color::Index(80)),
+					("diffAddColor", Color::Index(33)),
+					("diffChangeColor", Color::LightWhite),
+					("diffRemoveColor", Color::Index(220)),
+				]
+			},
+		}
+	}
+
+	/// Get the default color, for the name of a color setting, from the palette.
+	pub(crate) fn color(self, name: &str, default: Color) -> Color {
+		self.colors()
+			.iter()
+			.find(|&&(color_name, _)| color_name == name)
+			.map_or(default, |&(_, color)| color)
+	}
+}
+
+impl Display for Palette {
+	/// Formats the setting as a value that can be written to the git config.
+	#[inline]
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		match *self {
+			Self::Default => write!(f, "default"),
+			Self::HighContrast => write!(f, "high-contrast"),
+			Self::Deuteranopia => write!(f, "deuteranopia"),
+			Self::Protanopia => write!(f, "protanopia"),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use rstest::rstest;
+
+	use super::*;
+
+	#[rstest]
+	#[case::default(Palette::Default, "default")]
+	#[case::high_contrast(Palette::HighContrast, "high-contrast")]
+	#[case::deuteranopia(Palette::Deuteranopia, "deuteranopia")]
+	#[case::protanopia(Palette::Protanopia, "protanopia")]
+	fn to_string(#[case] palette: Palette, #[case] expected: &str) {
+		assert_eq!(palette.to_string(), expected);
+	}
+
+	#[test]
+	fn color_from_palette() {
+		assert_eq!(
+			Palette::Deuteranopia.color("diffAddColor", Color::LightGreen),
+			Color::Index(75)
+		);
+	}
+
+	#[test]
+	fn color_not_in_palette() {
+		assert_eq!(
+			Palette::Deuteranopia.color("breakColor", Color::LightWhite),
+			Color::LightWhite
+		);
+	}
+
+	// red and green are the colors that cannot be told apart, so they are not used by the color blind palettes
+	#[rstest]
+	#[case::deuteranopia(Palette::Deuteranopia)]
+	#[case::protanopia(Palette::Protanopia)]
+	fn color_blind_palettes_replace_red_and_green(#[case] palette: Palette) {
+		for name in ["dropColor", "pickColor", "diffAddColor", "diffRemoveColor"] {
+			let color = palette.color(name, Color::LightRed);
+			assert!(
+				![Color::LightRed, Color::LightGreen, Color::DarkRed, Color::DarkGreen].contains(&color),
+				"{}",
+				name
+			);
+		}
+	}
+}
@@ -2,8 +2,11 @@ use anyhow::{Error, Result};
 use git::Config;
 
 use super::{
-	utils::{get_color, get_string},
+	config_reader::ConfigReader,
+	config_values::ConfigValues,
+	utils::{get_color, get_palette},
 	Color,
+	Palette,
 };
 
 /// Represents the theme configuration options.
@@ -51,6 +54,8 @@ pub struct Theme {
 	pub color_indicator: Color,
 	/// The background color for selected lines.
 	pub color_selected_background: Color,
+	/// The palette that provides the default colors.
+	pub palette: Palette,
 }
 
 impl Theme {
@@ -62,46 +67,47 @@ impl Theme {
 	}
 
 	/// Create a new theme from a Git Config reference.
-	pub(super) fn new_with_config(git_config: Option<&Config>) -> Result<Self> {
-		Ok(Self {
-			character_vertical_spacing: get_string(
-				git_config,
-				"interactive-rebase-tool.verticalSpacingCharacter",
-				"~",
-			)?,
-			color_action_break: get_color(git_config, "interactive-rebase-tool.breakColor", Color::LightWhite)?,
-			color_action_drop: get_color(git_config, "interactive-rebase-tool.dropColor", Color::LightRed)?,
-			color_action_edit: get_color(git_config, "interactive-rebase-tool.editColor", Color::LightBlue)?,
-			color_action_exec: get_color(git_config, "interactive-rebase-tool.execColor", Color::LightWhite)?,
-			color_action_fixup: get_color(git_config, "interactive-rebase-tool.fixupColor", Color::LightMagenta)?,
-			color_action_pick: get_color(git_config, "interactive-rebase-tool.pickColor", Color::LightGreen)?,
-			color_action_reword: get_color(git_config, "interactive-rebase-tool.rewordColor", Color::LightYellow)?,
-			color_action_squash: get_color(git_config, "interactive-rebase-tool.squashColor", Color::LightCyan)?,
-			color_action_label: get_color(git_config, "interactive-rebase-tool.labelColor", Color::DarkYellow)?,
-			color_action_reset: get_color(git_config, "interactive-rebase-tool.resetColor", Color::DarkYellow)?,
-			color_action_merge: get_color(git_config, "interactive-rebase-tool.mergeColor", Color::DarkYellow)?,
-			color_background: get_color(git_config, "interactive-rebase-tool.backgroundColor", Color::Default)?,
-			color_diff_add: get_color(git_config, "interactive-rebase-tool.diffAddColor", Color::LightGreen)?,
-			color_diff_change: get_color(
-				git_config,
-				"interactive-rebase-tool.diffChangeColor",
-				Color::LightYellow,
-			)?,
-			color_diff_context: get_color(
-				git_config,
-				"interactive-rebase-tool.diffContextColor",
-				Color::LightWhite,
-			)?,
-			color_diff_remove: get_color(git_config, "interactive-rebase-tool.diffRemoveColor", Color::LightRed)?,
-			color_diff_whitespace: get_color(git_config, "interactive-rebase-tool.diffWhitespace", Color::LightBlack)?,
-			color_foreground: get_color(git_config, "interactive-rebase-tool.foregroundColor", Color::Default)?,
-			color_indicator: get_color(git_config, "interactive-rebase-tool.indicatorColor", Color::LightCyan)?,
-			color_selected_background: get_color(
-				git_config,
-				"interactive-rebase-tool.selectedBackgroundColor",
-				Color::Index(237),
-			)?,
-		})
+	pub(super) fn new_with_config(git_config: Option<&dyn ConfigValues>) -> Result<Self> {
+		ConfigReader::load(git_config, Self::new_with_reader)
+	}
+
+	pub(super) fn new_with_reader(reader: &mut ConfigReader<'_>) -> Self {
+		let palette = reader.read(get_palette);
+		let character_vertical_spacing = reader.string("interactive-rebase-tool.verticalSpacingCharacter", "~");
+		// a color that is not set uses the default color from the palette
+		let mut get_theme_color = |name: &str, default: Color| {
+			reader.read(|config| {
+				get_color(
+					config,
+					format!("interactive-rebase-tool.{}", name).as_str(),
+					palette.color(name, default),
+				)
+			})
+		};
+		Self {
+			character_vertical_spacing,
+			color_action_break: get_theme_color("breakColor", Color::LightWhite),
+			color_action_drop: get_theme_color("dropColor", Color::LightRed),
+			color_action_edit: get_theme_color("editColor", Color::LightBlue),
+			color_action_exec: get_theme_color("execColor", Color::LightWhite),
+			color_action_fixup: get_theme_color("fixupColor", Color::LightMagenta),
+			color_action_pick: get_theme_color("pickColor", Color::LightGreen),
+			color_action_reword: get_theme_color("rewordColor", Color::LightYellow),
+			color_action_squash: get_theme_color("squashColor", Color::LightCyan),
+			color_action_label: get_theme_color("labelColor", Color::DarkYellow),
+			color_action_reset: get_theme_color("resetColor", Color::DarkYellow),
+			color_action_merge: get_theme_color("mergeColor", Color::DarkYellow),
+			color_background: get_theme_color("backgroundColor", Color::Default),
+			color_diff_add: get_theme_color("diffAddColor", Color::LightGreen),
+			color_diff_change: get_theme_color("diffChangeColor", Color::LightYellow),
+			color_diff_context: get_theme_color("diffContextColor", Color::LightWhite),
+			color_diff_remove: get_theme_color("diffRemoveColor", Color::LightRed),
+			color_diff_whitespace: get_theme_color("diffWhitespace", Color::LightBlack),
+			color_foreground: get_theme_color("foregroundColor", Color::Default),
+			color_indicator: get_theme_color("indicatorColor", Color::LightCyan),
+			color_selected_background: get_theme_color("selectedBackgroundColor", Color::Index(237)),
+			palette,
+		}
 	}
 }
 
@@ -226,4 +232,54 @@ mod tests {
 			},
 		);
 	}
+
+	#[rstest]
+	#[case::default("default", Palette::Default)]
+	#[case::high_contrast("high-contrast", Palette::HighContrast)]
+	#[case::deuteranopia("deuteranopia", Palette::Deuteranopia)]
+	#[case::protanopia("Protanopia", Palette::Protanopia)]
+	fn palette(#[case] value: &str, #[case] expected: Palette) {
+		assert_eq!(Theme::new().palette, Palette::Default);
+		let config_value = format!("palette = {}", value);
+		with_git_config(&["[interactive-rebase-tool]", config_value.as_str()], |config| {
+			assert_eq!(Theme::new_with_config(Some(&config)).unwrap().palette, expected);
+		});
+	}
+
+	#[test]
+	fn palette_default_colors() {
+		with_git_config(&["[interactive-rebase-tool]", "palette = deuteranopia"], |config| {
+			let theme = Theme::new_with_config(Some(&config)).unwrap();
+			assert_eq!(theme.color_diff_add, Color::Index(75));
+			assert_eq!(theme.color_diff_remove, Color::Index(208));
+			assert_eq!(theme.color_action_break, Color::LightWhite);
+		});
+	}
+
+	#[test]
+	fn palette_color_overridden() {
+		with_git_config(
+			&[
+				"[interactive-rebase-tool]",
+				"palette = deuteranopia",
+				"diffAddColor = 42",
+			],
+			|config| {
+				let theme = Theme::new_with_config(Some(&config)).unwrap();
+				assert_eq!(theme.color_diff_add, Color::Index(42));
+				assert_eq!(theme.color_diff_remove, Color::Index(208));
+			},
+		);
+	}
+
+	#[test]
+	fn palette_invalid() {
+		with_git_config(&["[interactive-rebase-tool]", "palette = invalid"], |config| {
+			assert_error(
+				Theme::new_with_config(Some(&config)),
+				"\"interactive-rebase-tool.palette\" is not valid: \"invalid\" does not match one of \"default\", \
+				 \"high-contrast\", \"deuteranopia\" or \"protanopia\"",
+			);
+		});
+	}
 }
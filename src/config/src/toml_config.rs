@@ -0,0 +1,231 @@
+use std::{
+	env,
+	fs::read_to_string,
+	io::ErrorKind,
+	path::{Path, PathBuf},
+};
+
+use anyhow::{anyhow, Result};
+use git::ConfigSource;
+use toml::{value::Table, Value};
+
+use super::config_layer::{is_valid_name, ConfigLayer, Entry, SECTION};
+
+const CONFIG_FILE_PATH: &str = "interactive-rebase-tool/config.toml";
+
+/// Get the path of the configuration file, in `$XDG_CONFIG_HOME`, or in `~/.config` when `$XDG_CONFIG_HOME` is not
+/// set.
+pub(super) fn get_config_file_path() -> Option<PathBuf> {
+	env::var_os("XDG_CONFIG_HOME")
+		.filter(|directory| !directory.is_empty())
+		.map(PathBuf::from)
+		.or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))
+		.map(|directory| directory.join(CONFIG_FILE_PATH))
+}
+
+/// Load the settings of a TOML configuration file, as a layer of settings that is read beneath all of the levels of the
+/// git config, so that a setting from git takes precedence over the same setting from the file. The file is optional,
+/// and there is no layer when it does not exist.
+///
+/// The keys of the file are the names of the settings of the `interactive-rebase-tool` section of the git config, and
+/// a table is a subsection of the section, such as `[execTemplate]` for the exec templates. An array is a list of
+/// values separated by spaces, such as the keys of a key binding.
+pub(super) fn load_config_file(path: &Path) -> Result<Option<ConfigLayer>> {
+	let contents = match read_to_string(path) {
+		Ok(contents) => contents,
+		Err(ref err) if err.kind() == ErrorKind::NotFound => return Ok(None),
+		Err(err) => return Err(anyhow!(err).context(format!("Error reading file: {}", path.display()))),
+	};
+	let entries =
+		parse(contents.as_str()).map_err(|err| err.context(format!("Error reading file: {}", path.display())))?;
+	Ok(Some(ConfigLayer::new(ConfigSource::ConfigFile, entries)))
+}
+
+// the value of a setting, with the values of an array separated by spaces
+fn to_setting(name: &str, value: Value) -> Result<String> {
+	match value {
+		Value::String(value) => Ok(value),
+		Value::Integer(value) => Ok(value.to_string()),
+		Value::Boolean(value) => Ok(value.to_string()),
+		Value::Array(values) => {
+			if values.iter().any(Value::is_table) {
+				return Err(anyhow!("\"{}\" is an array of tables, which is not supported", name));
+			}
+			values
+				.into_iter()
+				.map(|value| to_setting(name, value))
+				.collect::<Result<Vec<String>>>()
+				.map(|values| values.join(" "))
+		},
+		Value::Table(_) => Err(anyhow!("\"{}\" is a nested table, tables can only be one level deep", name)),
+		Value::Float(_) | Value::Datetime(_) => {
+			Err(anyhow!("\"{}\" has a {}, which is not supported", name, value.type_str()))
+		},
+	}
+}
+
+fn to_entry(subsection: Option<&str>, name: String, value: Value) -> Result<Entry> {
+	if !is_valid_name(name.as_str()) {
+		return Err(anyhow!("\"{}\" is not a valid setting name", name));
+	}
+	let value = to_setting(name.as_str(), value)?;
+	Ok(Entry {
+		section: String::from(SECTION),
+		subsection: subsection.map(String::from),
+		name,
+		value,
+	})
+}
+
+fn parse(contents: &str) -> Result<Vec<Entry>> {
+	let table: Table = toml::from_str(contents).map_err(|err| anyhow!(err))?;
+	let mut entries = vec![];
+	for (key, value) in table {
+		match value {
+			Value::Table(settings) => {
+				for (name, value) in settings {
+					entries.push(to_entry(Some(key.as_str()), name, value)?);
+				}
+			},
+			value => entries.push(to_entry(None, key, value)?),
+		}
+	}
+	Ok(entries)
+}
+
+#[cfg(test)]
+mod tests {
+	use std::fs::write;
+
+	use rstest::rstest;
+	use tempfile::tempdir;
+
+	use super::*;
+	use crate::{
+		config_layer::LayeredConfig,
+		config_values::ConfigValues,
+		testutils::{assert_error, with_git_config},
+		utils::{get_bool, get_string},
+	};
+
+	fn entry(subsection: Option<&str>, name: &str, value: &str) -> Entry {
+		Entry {
+			section: String::from(SECTION),
+			subsection: subsection.map(String::from),
+			name: String::from(name),
+			value: String::from(value),
+		}
+	}
+
+	#[test]
+	fn parse_settings() {
+		let contents = [
+			"# the settings of the tool",
+			"autoSelectNext = true",
+			"undoLimit = 1_000 # a comment",
+			"diffSpaceSymbol = '\\s'",
+			"",
+			"inputAbort = [",
+			"  \"q\", # quit",
+			"  \"Control+c\",",
+			"]",
+			"theme.pickColor = \"green\"",
+			"[execTemplate]",
+			"test = \"cargo \\\"test\\\"\"",
+		]
+		.join("\n");
+		assert_eq!(parse(contents.as_str()).unwrap(), vec![
+			entry(None, "autoSelectNext", "true"),
+			entry(None, "undoLimit", "1000"),
+			entry(None, "diffSpaceSymbol", "\\s"),
+			entry(None, "inputAbort", "q Control+c"),
+			entry(Some("theme"), "pickColor", "green"),
+			entry(Some("execTemplate"), "test", "cargo \"test\""),
+		]);
+	}
+
+	#[rstest]
+	#[case::float("tabWidth = 1.5", "\"tabWidth\" has a float, which is not supported")]
+	#[case::date("date = 2021-01-01", "\"date\" has a datetime, which is not supported")]
+	#[case::array_of_tables("[[templates]]", "\"templates\" is an array of tables, which is not supported")]
+	#[case::nested_table("[a.b]\nc = 1", "\"b\" is a nested table, tables can only be one level deep")]
+	#[case::invalid_name("snake_case = true", "\"snake_case\" is not a valid setting name")]
+	#[case::missing_value("icons =", "unexpected eof encountered at line 1 column 8")]
+	#[case::unterminated_string("name = \"value", "unterminated string at line 1 column 8")]
+	#[case::trailing_characters(
+		"icons = true false",
+		"expected newline, found an identifier at line 1 column 14"
+	)]
+	fn parse_error(#[case] contents: &str, #[case] expected: &str) {
+		assert_error(parse(contents), expected);
+	}
+
+	#[test]
+	fn load_config_file_beneath_git_config() {
+		let directory = tempdir().unwrap();
+		let path = directory.path().join("config.toml");
+		write(&path, "autoSelectNext = true\ntestCommitTemplate = \"check\"\n").unwrap();
+		with_git_config(
+			&["[interactive-rebase-tool]", "testCommitTemplate = test"],
+			|git_config| {
+				let layer = load_config_file(path.as_path()).unwrap().unwrap();
+				let mut config = LayeredConfig::new();
+				config.push(&layer);
+				config.push(&git_config);
+				assert!(get_bool(Some(&config), "interactive-rebase-tool.autoSelectNext", false).unwrap());
+				assert_eq!(
+					get_string(Some(&config), "interactive-rebase-tool.testCommitTemplate", "").unwrap(),
+					"test"
+				);
+				assert_eq!(
+					config.sources().get("interactive-rebase-tool.autoselectnext"),
+					Some(&ConfigSource::ConfigFile)
+				);
+			},
+		);
+	}
+
+	#[test]
+	fn load_config_file_missing() {
+		let directory = tempdir().unwrap();
+		assert!(load_config_file(directory.path().join("config.toml").as_path())
+			.unwrap()
+			.is_none());
+	}
+
+	#[test]
+	fn load_config_file_invalid() {
+		let directory = tempdir().unwrap();
+		let path = directory.path().join("config.toml");
+		write(&path, "icons = 1.5\n").unwrap();
+		assert_error(
+			load_config_file(path.as_path()),
+			format!(
+				"Error reading file: {}: \"icons\" has a float, which is not supported",
+				path.display()
+			)
+			.as_str(),
+		);
+	}
+
+	#[test]
+	#[serial_test::serial]
+	fn config_file_path() {
+		let xdg_config_home = env::var_os("XDG_CONFIG_HOME");
+		env::set_var("XDG_CONFIG_HOME", "/config");
+		assert_eq!(
+			get_config_file_path(),
+			Some(PathBuf::from("/config/interactive-rebase-tool/config.toml"))
+		);
+		env::set_var("XDG_CONFIG_HOME", "");
+		let home = env::var_os("HOME").map(PathBuf::from);
+		assert_eq!(
+			get_config_file_path(),
+			home.map(|home| home.join(".config").join("interactive-rebase-tool/config.toml"))
+		);
+		match xdg_config_home {
+			Some(value) => env::set_var("XDG_CONFIG_HOME", value),
+			None => env::remove_var("XDG_CONFIG_HOME"),
+		}
+	}
+}
@@ -1,16 +1,20 @@
 use std::env;
 
 use anyhow::{anyhow, Result};
-use git::{Config, ErrorCode};
+use chrono::format::{Item, StrftimeItems};
 
 use super::{
+	abort_behavior::AbortBehavior,
+	date_format::DateFormat,
 	diff_ignore_whitespace_setting::DiffIgnoreWhitespaceSetting,
 	diff_show_whitespace_setting::DiffShowWhitespaceSetting,
 	Color,
+	Palette,
 };
+use crate::config_values::ConfigValues;
 
 #[allow(clippy::string_slice)]
-pub(super) fn get_input(config: Option<&Config>, name: &str, default: &str) -> Result<Vec<String>> {
+pub(super) fn get_input(config: Option<&dyn ConfigValues>, name: &str, default: &str) -> Result<Vec<String>> {
 	let mut values = vec![];
 	for mut value in get_string(config, name, default)?.split_whitespace().map(String::from) {
 		let mut modifiers = vec![];
@@ -67,69 +71,114 @@ pub(super) fn get_input(config: Option<&Config>, name: &str, default: &str) -> R
 	Ok(values)
 }
 
-pub(super) fn get_string(config: Option<&Config>, name: &str, default: &str) -> Result<String> {
+pub(super) fn get_string(config: Option<&dyn ConfigValues>, name: &str, default: &str) -> Result<String> {
 	let cfg = match config {
 		None => return Ok(String::from(default)),
 		Some(c) => c,
 	};
-	match cfg.get_string(name) {
-		Ok(v) => Ok(v),
-		Err(ref e) if e.code() == ErrorCode::NotFound => Ok(String::from(default)),
-		Err(e) => Err(anyhow!(String::from(e.message()))),
-	}
+	cfg.string(name)
+		.map(|v| v.unwrap_or_else(|| String::from(default)))
 	.map_err(|e| e.context(anyhow!("\"{}\" is not valid", name)))
 }
 
-pub(super) fn get_bool(config: Option<&Config>, name: &str, default: bool) -> Result<bool> {
+pub(super) fn get_bool(config: Option<&dyn ConfigValues>, name: &str, default: bool) -> Result<bool> {
 	let cfg = match config {
 		None => return Ok(default),
 		Some(c) => c,
 	};
-	match cfg.get_bool(name) {
-		Ok(v) => Ok(v),
-		Err(ref e) if e.code() == ErrorCode::NotFound => Ok(default),
-		Err(e) => Err(anyhow!(String::from(e.message()))),
-	}
+	cfg.bool(name)
+		.map(|v| v.unwrap_or(default))
 	.map_err(|e| e.context(anyhow!("\"{}\" is not valid", name)))
 }
 
 #[allow(clippy::map_err_ignore)]
-pub(super) fn get_unsigned_integer(config: Option<&Config>, name: &str, default: u32) -> Result<u32> {
+pub(super) fn get_unsigned_integer(config: Option<&dyn ConfigValues>, name: &str, default: u32) -> Result<u32> {
 	let cfg = match config {
 		None => return Ok(default),
 		Some(c) => c,
 	};
-	match cfg.get_i32(name) {
-		Ok(v) => {
+	match cfg.i32(name) {
+		Ok(Some(v)) => {
 			v.try_into()
 				.map_err(|_| anyhow!("\"{}\" is outside of valid range for an unsigned 32-bit integer", v))
 		},
-		Err(ref e) if e.code() == ErrorCode::NotFound => Ok(default),
-		Err(e) => Err(anyhow!(String::from(e.message()))),
+		Ok(None) => Ok(default),
+		Err(e) => Err(e),
 	}
 	.map_err(|e| e.context(anyhow!("\"{}\" is not valid", name)))
 }
 
-pub(super) fn get_color(config: Option<&Config>, name: &str, default: Color) -> Result<Color> {
+/// Get an abbreviated hash length, that is either `auto`, `None`, or a length from 4 to 40, with `no` being the full
+/// length of a hash, matching the values of `core.abbrev`.
+pub(super) fn get_abbreviation_length(config: Option<&dyn ConfigValues>, name: &str) -> Result<Option<u32>> {
+	let value = get_string(config, name, "auto")?;
+	match value.to_lowercase().as_str() {
+		"auto" => Ok(None),
+		"no" => Ok(Some(40)),
+		v => {
+			match v.parse::<u32>() {
+				Ok(length) if (4..=40).contains(&length) => Ok(Some(length)),
+				_ => {
+					Err(anyhow!(
+						"\"{}\" does not match one of \"auto\", \"no\" or a length from 4 to 40",
+						value
+					)
+					.context(anyhow!("\"{}\" is not valid", name)))
+				},
+			}
+		},
+	}
+}
+
+/// Get a date format, that is either `relative`, or a `strftime` style format.
+pub(super) fn get_date_format(config: Option<&dyn ConfigValues>, name: &str, default: &str) -> Result<DateFormat> {
+	let value = get_string(config, name, default)?;
+	if value.to_lowercase() == "relative" {
+		return Ok(DateFormat::Relative);
+	}
+	// an invalid specifier would fail when a date is formatted, so the format is checked when it is loaded
+	if StrftimeItems::new(value.as_str()).any(|item| item == Item::Error) {
+		return Err(anyhow!("\"{}\" is not \"relative\" or a valid strftime format", value)
+			.context(anyhow!("\"{}\" is not valid", name)));
+	}
+	Ok(DateFormat::Format(value))
+}
+
+pub(super) fn get_color(config: Option<&dyn ConfigValues>, name: &str, default: Color) -> Result<Color> {
 	let cfg = match config {
 		None => return Ok(default),
 		Some(c) => c,
 	};
-	match cfg.get_string(name) {
-		Ok(v) => Color::try_from(v.to_lowercase().as_str()),
-		Err(ref e) if e.code() == ErrorCode::NotFound => Ok(default),
-		Err(e) => Err(anyhow!(String::from(e.message()))),
+	match cfg.string(name) {
+		Ok(Some(v)) => Color::try_from(v.to_lowercase().as_str()),
+		Ok(None) => Ok(default),
+		Err(e) => Err(e),
 	}
 	.map_err(|e| e.context(anyhow!("\"{}\" is not valid", name)))
 }
 
+// the value of an environment variable, when it is set and not empty
+fn non_empty_var(name: &str) -> Option<String> {
+	env::var(name).ok().filter(|value| !value.is_empty())
+}
+
+/// The editor from `GIT_EDITOR`, which takes precedence over `core.editor`, like in Git.
+pub(super) fn git_editor_from_env() -> Option<String> {
+	non_empty_var("GIT_EDITOR")
+}
+
+/// The editor used when `core.editor` is not set, from `VISUAL`, unless the terminal is dumb, then `EDITOR`, and
+/// otherwise the default editor of the platform.
 pub(super) fn editor_from_env() -> String {
-	env::var("VISUAL")
-		.or_else(|_| env::var("EDITOR"))
-		.unwrap_or_else(|_| String::from("vi"))
+	let is_dumb_terminal = env::var("TERM").map_or(false, |term| term == "dumb");
+	(!is_dumb_terminal)
+		.then(|| non_empty_var("VISUAL"))
+		.flatten()
+		.or_else(|| non_empty_var("EDITOR"))
+		.unwrap_or_else(|| String::from(if cfg!(windows) { "notepad" } else { "vi" }))
 }
 
-pub(super) fn get_diff_show_whitespace(git_config: Option<&Config>) -> Result<DiffShowWhitespaceSetting> {
+pub(super) fn get_diff_show_whitespace(git_config: Option<&dyn ConfigValues>) -> Result<DiffShowWhitespaceSetting> {
 	let diff_show_whitespace = get_string(git_config, "interactive-rebase-tool.diffShowWhitespace", "both")?;
 
 	match diff_show_whitespace.to_lowercase().as_str() {
@@ -148,7 +197,41 @@ pub(super) fn get_diff_show_whitespace(git_config: Option<&Config>) -> Result<Di
 	}
 }
 
-pub(super) fn get_diff_ignore_whitespace(git_config: Option<&Config>) -> Result<DiffIgnoreWhitespaceSetting> {
+pub(super) fn get_abort_behavior(git_config: Option<&dyn ConfigValues>) -> Result<AbortBehavior> {
+	let abort_behavior = get_string(git_config, "interactive-rebase-tool.abortBehavior", "empty")?;
+
+	match abort_behavior.to_lowercase().as_str() {
+		"empty" => Ok(AbortBehavior::Empty),
+		"exit-code" => Ok(AbortBehavior::ExitCode),
+		_ => {
+			Err(anyhow!(
+				"\"{}\" does not match one of \"empty\" or \"exit-code\"",
+				abort_behavior
+			)
+			.context("\"interactive-rebase-tool.abortBehavior\" is not valid"))
+		},
+	}
+}
+
+pub(super) fn get_palette(git_config: Option<&dyn ConfigValues>) -> Result<Palette> {
+	let palette = get_string(git_config, "interactive-rebase-tool.palette", "default")?;
+
+	match palette.to_lowercase().as_str() {
+		"default" => Ok(Palette::Default),
+		"high-contrast" => Ok(Palette::HighContrast),
+		"deuteranopia" => Ok(Palette::Deuteranopia),
+		"protanopia" => Ok(Palette::Protanopia),
+		_ => {
+			Err(anyhow!(
+				"\"{}\" does not match one of \"default\", \"high-contrast\", \"deuteranopia\" or \"protanopia\"",
+				palette
+			)
+			.context("\"interactive-rebase-tool.palette\" is not valid"))
+		},
+	}
+}
+
+pub(super) fn get_diff_ignore_whitespace(git_config: Option<&dyn ConfigValues>) -> Result<DiffIgnoreWhitespaceSetting> {
 	let diff_ignore_whitespace = get_string(git_config, "interactive-rebase-tool.diffIgnoreWhitespace", "none")?;
 
 	match diff_ignore_whitespace.to_lowercase().as_str() {
@@ -10,11 +10,34 @@ pub(crate) enum Mode {
 	Help,
 	Version,
 	License,
+	Diagnostics,
+	Tutorial,
+	Demo(String),
+}
+
+impl Mode {
+	/// Whether the list is opened for a temporary repository, where the setup of a real repository is not relevant.
+	pub(crate) const fn is_sandbox(&self) -> bool {
+		matches!(*self, Self::Tutorial | Self::Demo(_))
+	}
 }
 
 #[derive(Debug)]
 pub(crate) struct Args {
+	config_overrides: Vec<String>,
+	export_json: Option<String>,
+	export_shell: Option<String>,
+	import_json: Option<String>,
+	log_file: Option<String>,
+	log_level: Option<String>,
 	mode: Mode,
+	profile_startup: bool,
+	range_diff: Option<String>,
+	read_only: bool,
+	record_events: Option<String>,
+	replay_events: Option<String>,
+	script: Option<String>,
+	script_file: Option<String>,
 	todo_file_path: Option<String>,
 }
 
@@ -23,6 +46,58 @@ impl Args {
 		&self.mode
 	}
 
+	pub(crate) fn config_overrides(&self) -> &[String] {
+		self.config_overrides.as_slice()
+	}
+
+	pub(crate) const fn export_json(&self) -> &Option<String> {
+		&self.export_json
+	}
+
+	pub(crate) const fn export_shell(&self) -> &Option<String> {
+		&self.export_shell
+	}
+
+	pub(crate) const fn import_json(&self) -> &Option<String> {
+		&self.import_json
+	}
+
+	pub(crate) const fn log_file(&self) -> &Option<String> {
+		&self.log_file
+	}
+
+	pub(crate) const fn log_level(&self) -> &Option<String> {
+		&self.log_level
+	}
+
+	pub(crate) const fn profile_startup(&self) -> bool {
+		self.profile_startup
+	}
+
+	pub(crate) const fn range_diff(&self) -> &Option<String> {
+		&self.range_diff
+	}
+
+	pub(crate) const fn read_only(&self) -> bool {
+		self.read_only
+	}
+
+	pub(crate) const fn record_events(&self) -> &Option<String> {
+		&self.record_events
+	}
+
+	pub(crate) const fn replay_events(&self) -> &Option<String> {
+		&self.replay_events
+	}
+
+	pub(crate) const fn script(&self) -> &Option<String> {
+		&self.script
+	}
+
+	pub(crate) const fn script_file(&self) -> &Option<String> {
+		&self.script_file
+	}
+
 	pub(crate) const fn todo_file_path(&self) -> &Option<String> {
 		&self.todo_file_path
 	}
@@ -43,14 +118,116 @@ impl TryFrom<Vec<OsString>> for Args {
 		else if pargs.contains(["-v", "--license"]) {
 			Mode::License
 		}
+		else if pargs.contains("--diagnostics") {
+			Mode::Diagnostics
+		}
+		else if pargs.contains("--tutorial") {
+			Mode::Tutorial
+		}
+		else if let Some(fixture) = pargs
+			.opt_value_from_str("--demo")
+			.map_err(|err| Exit::new(ExitStatus::StateError, err.to_string().as_str()))?
+		{
+			Mode::Demo(fixture)
+		}
 		else {
 			Mode::Normal
 		};
 
+		let config_overrides = pargs
+			.values_from_str(["-c", "--config"])
+			.map_err(|err| Exit::new(ExitStatus::StateError, err.to_string().as_str()))?;
+
+		let log_file = pargs
+			.opt_value_from_str("--log-file")
+			.map_err(|err| Exit::new(ExitStatus::StateError, err.to_string().as_str()))?;
+		let log_level = pargs
+			.opt_value_from_str("--log-level")
+			.map_err(|err| Exit::new(ExitStatus::StateError, err.to_string().as_str()))?;
+
+		let profile_startup = pargs.contains("--profile-startup");
+
+		let record_events: Option<String> = pargs
+			.opt_value_from_str("--record-events")
+			.map_err(|err| Exit::new(ExitStatus::StateError, err.to_string().as_str()))?;
+		let replay_events: Option<String> = pargs
+			.opt_value_from_str("--replay-events")
+			.map_err(|err| Exit::new(ExitStatus::StateError, err.to_string().as_str()))?;
+		if record_events.is_some() && replay_events.is_some() {
+			return Err(Exit::new(
+				ExitStatus::StateError,
+				"The --record-events and --replay-events options cannot be used together",
+			));
+		}
+
+		let script: Option<String> = pargs
+			.opt_value_from_str("--script")
+			.map_err(|err| Exit::new(ExitStatus::StateError, err.to_string().as_str()))?;
+		let script_file: Option<String> = pargs
+			.opt_value_from_str("--script-file")
+			.map_err(|err| Exit::new(ExitStatus::StateError, err.to_string().as_str()))?;
+		if script.is_some() && script_file.is_some() {
+			return Err(Exit::new(
+				ExitStatus::StateError,
+				"The --script and --script-file options cannot be used together",
+			));
+		}
+
+		let read_only = pargs.contains("--read-only");
+		if read_only && (script.is_some() || script_file.is_some()) {
+			return Err(Exit::new(
+				ExitStatus::StateError,
+				"The --read-only option cannot be used with --script or --script-file",
+			));
+		}
+
+		let export_json: Option<String> = pargs
+			.opt_value_from_str("--export-json")
+			.map_err(|err| Exit::new(ExitStatus::StateError, err.to_string().as_str()))?;
+		let import_json: Option<String> = pargs
+			.opt_value_from_str("--import-json")
+			.map_err(|err| Exit::new(ExitStatus::StateError, err.to_string().as_str()))?;
+		if export_json.is_some() && import_json.is_some() {
+			return Err(Exit::new(
+				ExitStatus::StateError,
+				"The --export-json and --import-json options cannot be used together",
+			));
+		}
+
+		let export_shell: Option<String> = pargs
+			.opt_value_from_str("--export-shell")
+			.map_err(|err| Exit::new(ExitStatus::StateError, err.to_string().as_str()))?;
+		if export_json.is_some() && export_shell.is_some() {
+			return Err(Exit::new(
+				ExitStatus::StateError,
+				"The --export-json and --export-shell options cannot be used together",
+			));
+		}
+
+		let range_diff: Option<String> = pargs
+			.opt_value_from_str("--range-diff")
+			.map_err(|err| Exit::new(ExitStatus::StateError, err.to_string().as_str()))?;
+
 		let todo_file_path = pargs
 			.opt_free_from_str()
 			.map_err(|err| Exit::new(ExitStatus::StateError, err.to_string().as_str()))?;
 
-		Ok(Self { mode, todo_file_path })
+		Ok(Self {
+			config_overrides,
+			export_json,
+			export_shell,
+			import_json,
+			log_file,
+			log_level,
+			mode,
+			profile_startup,
+			range_diff,
+			read_only,
+			record_events,
+			replay_events,
+			script,
+			script_file,
+			todo_file_path,
+		})
 	}
 }
@@ -8,6 +8,8 @@ use input::{Event, EventHandler, InputOptions, KeyCode};
 use lazy_static::lazy_static;
 use view::{handle_view_data_scroll, LineSegment, ViewData, ViewLine, ViewSender};
 
+use crate::i18n::translate;
+
 lazy_static! {
 	static ref INPUT_OPTIONS: InputOptions = InputOptions::new().movement(true);
 }
@@ -63,13 +65,13 @@ where T: Clone
 			updater.push_line(ViewLine::new_empty_line());
 			if invalid_selection {
 				updater.push_line(ViewLine::from(LineSegment::new_with_color(
-					"Invalid option selected. Please choose an option.",
+					translate("Invalid option selected. Please choose an option."),
 					DisplayColor::IndicatorColor,
 				)));
 			}
 			else {
 				updater.push_line(ViewLine::from(LineSegment::new_with_color(
-					"Please choose an option.",
+					translate("Please choose an option."),
 					DisplayColor::IndicatorColor,
 				)));
 			}
@@ -8,6 +8,8 @@ use input::{Event, EventHandler, InputOptions, KeyCode, KeyEvent, MetaEvent};
 use lazy_static::lazy_static;
 use view::{ViewData, ViewLine};
 
+use crate::i18n::translate_format;
+
 lazy_static! {
 	static ref INPUT_OPTIONS: InputOptions = InputOptions::new().movement(true);
 }
@@ -22,12 +24,11 @@ impl Confirm {
 			capture!(confirm_yes, confirm_no);
 			updater.set_show_title(true);
 			updater.set_retain_scroll_position(false);
-			updater.push_line(ViewLine::from(format!(
-				"{} ({}/{})? ",
-				prompt,
-				confirm_yes.join(","),
-				confirm_no.join(",")
-			)));
+			updater.push_line(ViewLine::from(translate_format("{} ({}/{})? ", &[
+				&prompt,
+				&confirm_yes.join(","),
+				&confirm_no.join(","),
+			])));
 		});
 		Self { view_data }
 	}
@@ -0,0 +1,67 @@
+use std::collections::VecDeque;
+
+const HISTORY_LIMIT: usize = 100;
+
+/// The entries that were finished in a prompt, from the oldest to the newest, that are browsed from the newest entry.
+/// The content that was being edited before the entries were browsed is kept, so that it is restored after the
+/// newest entry.
+pub(super) struct History {
+	draft: String,
+	entries: VecDeque<String>,
+	index: Option<usize>,
+}
+
+impl History {
+	pub(super) const fn new() -> Self {
+		Self {
+			draft: String::new(),
+			entries: VecDeque::new(),
+			index: None,
+		}
+	}
+
+	/// Add a finished entry, that is not added when it is empty or the same as the newest entry.
+	pub(super) fn push(&mut self, entry: &str) {
+		self.index = None;
+		if entry.is_empty() || self.entries.back().map_or(false, |last| last == entry) {
+			return;
+		}
+		if self.entries.len() == HISTORY_LIMIT {
+			let _entry = self.entries.pop_front();
+		}
+		self.entries.push_back(String::from(entry));
+	}
+
+	/// The entry before the browsed entry, or the newest entry when the entries are not being browsed.
+	pub(super) fn previous(&mut self, content: &str) -> Option<&str> {
+		let index = match self.index {
+			None if !self.entries.is_empty() => {
+				self.draft = String::from(content);
+				self.entries.len() - 1
+			},
+			Some(index) if index > 0 => index - 1,
+			_ => return None,
+		};
+		self.index = Some(index);
+		self.entries.get(index).map(String::as_str)
+	}
+
+	/// The entry after the browsed entry, or the content from before the entries were browsed after the newest entry.
+	pub(super) fn next(&mut self) -> Option<&str> {
+		let index = self.index?;
+		if index + 1 < self.entries.len() {
+			self.index = Some(index + 1);
+			self.entries.get(index + 1).map(String::as_str)
+		}
+		else {
+			self.index = None;
+			Some(self.draft.as_str())
+		}
+	}
+
+	/// Stop browsing the entries.
+	pub(super) fn reset(&mut self) {
+		self.index = None;
+		self.draft.clear();
+	}
+}
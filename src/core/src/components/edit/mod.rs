@@ -1,21 +1,34 @@
+mod history;
 #[cfg(test)]
 mod tests;
 
+use std::collections::HashMap;
+
 use display::DisplayColor;
 use input::{Event, EventHandler, InputOptions, KeyCode, KeyEvent, KeyModifiers};
 use lazy_static::lazy_static;
 use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
 use view::{LineSegment, ViewData, ViewLine};
 
+use self::history::History;
+use crate::i18n::translate;
+
 lazy_static! {
 	static ref INPUT_OPTIONS: InputOptions = InputOptions::new();
 }
 
+fn is_whitespace(grapheme: &str) -> bool {
+	grapheme.chars().all(char::is_whitespace)
+}
+
 pub(crate) struct Edit {
 	content: String,
 	cursor_position: usize,
 	description: Option<String>,
 	finished: bool,
+	histories: HashMap<&'static str, History>,
+	history_name: Option<&'static str>,
 	label: Option<String>,
 	view_data: ViewData,
 }
@@ -30,6 +43,8 @@ impl Edit {
 			cursor_position: 0,
 			description: None,
 			finished: false,
+			histories: HashMap::new(),
+			history_name: None,
 			label: None,
 			view_data,
 		}
@@ -69,6 +84,8 @@ impl Edit {
 		if !end.is_empty() {
 			segments.push(LineSegment::new(end.as_str()));
 		}
+		// wide characters, such as those of an input method, take more than one column
+		let column = self.label.as_ref().map_or(0, |label| label.width()) + start.width();
 		let description = self.description.as_ref();
 		self.view_data.update_view_data(|updater| {
 			updater.clear();
@@ -81,10 +98,10 @@ impl Edit {
 			}
 			updater.push_line(ViewLine::from(segments));
 			updater.push_trailing_line(ViewLine::new_pinned(vec![LineSegment::new_with_color(
-				"Enter to finish",
+				translate("Enter to finish"),
 				DisplayColor::IndicatorColor,
 			)]));
-			updater.ensure_column_visible(pointer);
+			updater.ensure_column_visible(column);
 			updater.ensure_line_visible(0);
 		});
 		&self.view_data
@@ -97,81 +114,247 @@ impl Edit {
 			Event::Key(KeyEvent {
 				code: KeyCode::Backspace,
 				modifiers: KeyModifiers::NONE,
+				..
 			}) => {
 				if self.cursor_position != 0 {
-					let start = UnicodeSegmentation::graphemes(self.content.as_str(), true)
-						.take(self.cursor_position - 1)
-						.collect::<String>();
-					let end = UnicodeSegmentation::graphemes(self.content.as_str(), true)
-						.skip(self.cursor_position)
-						.collect::<String>();
-					self.content = format!("{}{}", start, end);
-					self.cursor_position -= 1;
+					self.delete_range(self.cursor_position - 1, self.cursor_position);
 				}
 			},
 			Event::Key(KeyEvent {
 				code: KeyCode::Delete,
 				modifiers: KeyModifiers::NONE,
+				..
 			}) => {
-				let length = UnicodeSegmentation::graphemes(self.content.as_str(), true).count();
-				if self.cursor_position != length {
-					let start = UnicodeSegmentation::graphemes(self.content.as_str(), true)
-						.take(self.cursor_position)
-						.collect::<String>();
-					let end = UnicodeSegmentation::graphemes(self.content.as_str(), true)
-						.skip(self.cursor_position + 1)
-						.collect::<String>();
-					self.content = format!("{}{}", start, end);
+				if self.cursor_position != self.length() {
+					self.delete_range(self.cursor_position, self.cursor_position + 1);
 				}
 			},
-			Event::Key(KeyEvent {
-				code: KeyCode::Home,
-				modifiers: KeyModifiers::NONE,
-			}) => self.cursor_position = 0,
-			Event::Key(KeyEvent {
-				code: KeyCode::End,
-				modifiers: KeyModifiers::NONE,
-			}) => self.cursor_position = UnicodeSegmentation::graphemes(self.content.as_str(), true).count(),
+			Event::Key(
+				KeyEvent {
+					code: KeyCode::Home,
+					modifiers: KeyModifiers::NONE,
+					..
+				}
+				| KeyEvent {
+					code: KeyCode::Char('a'),
+					modifiers: KeyModifiers::CONTROL,
+					..
+				},
+			) => self.cursor_position = 0,
+			Event::Key(
+				KeyEvent {
+					code: KeyCode::End,
+					modifiers: KeyModifiers::NONE,
+					..
+				}
+				| KeyEvent {
+					code: KeyCode::Char('e'),
+					modifiers: KeyModifiers::CONTROL,
+					..
+				},
+			) => self.cursor_position = self.length(),
 			Event::Key(KeyEvent {
 				code: KeyCode::Right,
 				modifiers: KeyModifiers::NONE,
+				..
 			}) => {
-				let length = UnicodeSegmentation::graphemes(self.content.as_str(), true).count();
-				if self.cursor_position < length {
+				if self.cursor_position < self.length() {
 					self.cursor_position += 1;
 				}
 			},
 			Event::Key(KeyEvent {
 				code: KeyCode::Left,
 				modifiers: KeyModifiers::NONE,
+				..
 			}) => {
 				if self.cursor_position != 0 {
 					self.cursor_position -= 1;
 				}
 			},
+			Event::Key(
+				KeyEvent {
+					code: KeyCode::Char('b'),
+					modifiers: KeyModifiers::ALT,
+					..
+				}
+				| KeyEvent {
+					code: KeyCode::Left,
+					modifiers: KeyModifiers::CONTROL,
+					..
+				},
+			) => self.cursor_position = self.previous_word_start(),
+			Event::Key(
+				KeyEvent {
+					code: KeyCode::Char('f'),
+					modifiers: KeyModifiers::ALT,
+					..
+				}
+				| KeyEvent {
+					code: KeyCode::Right,
+					modifiers: KeyModifiers::CONTROL,
+					..
+				},
+			) => self.cursor_position = self.next_word_end(),
+			Event::Key(
+				KeyEvent {
+					code: KeyCode::Char('w'),
+					modifiers: KeyModifiers::CONTROL,
+					..
+				}
+				| KeyEvent {
+					code: KeyCode::Backspace,
+					modifiers: KeyModifiers::ALT | KeyModifiers::CONTROL,
+					..
+				},
+			) => self.delete_range(self.previous_word_start(), self.cursor_position),
+			Event::Key(
+				KeyEvent {
+					code: KeyCode::Char('d'),
+					modifiers: KeyModifiers::ALT,
+					..
+				}
+				| KeyEvent {
+					code: KeyCode::Delete,
+					modifiers: KeyModifiers::CONTROL,
+					..
+				},
+			) => self.delete_range(self.cursor_position, self.next_word_end()),
+			Event::Key(KeyEvent {
+				code: KeyCode::Char('u'),
+				modifiers: KeyModifiers::CONTROL,
+				..
+			}) => self.delete_range(0, self.cursor_position),
+			Event::Key(KeyEvent {
+				code: KeyCode::Char('k'),
+				modifiers: KeyModifiers::CONTROL,
+				..
+			}) => self.delete_range(self.cursor_position, self.length()),
+			Event::Key(
+				KeyEvent {
+					code: KeyCode::Up,
+					modifiers: KeyModifiers::NONE,
+					..
+				}
+				| KeyEvent {
+					code: KeyCode::Char('p'),
+					modifiers: KeyModifiers::CONTROL,
+					..
+				},
+			) => {
+				let content = self.content.clone();
+				let previous_entry = self
+					.history()
+					.and_then(|history| history.previous(content.as_str()))
+					.map(String::from);
+				if let Some(entry) = previous_entry {
+					self.set_content(entry.as_str());
+				}
+			},
+			Event::Key(
+				KeyEvent {
+					code: KeyCode::Down,
+					modifiers: KeyModifiers::NONE,
+					..
+				}
+				| KeyEvent {
+					code: KeyCode::Char('n'),
+					modifiers: KeyModifiers::CONTROL,
+					..
+				},
+			) => {
+				let next_entry = self.history().and_then(History::next).map(String::from);
+				if let Some(entry) = next_entry {
+					self.set_content(entry.as_str());
+				}
+			},
 			Event::Key(KeyEvent {
 				code: KeyCode::Enter,
 				modifiers: KeyModifiers::NONE,
-			}) => self.finished = true,
+				..
+			}) => {
+				let content = self.content.clone();
+				if let Some(history) = self.history() {
+					history.push(content.as_str());
+				}
+				self.finished = true;
+			},
 			Event::Key(KeyEvent {
 				code: KeyCode::Char(c),
 				modifiers: KeyModifiers::NONE | KeyModifiers::SHIFT,
-			}) => {
-				let start = UnicodeSegmentation::graphemes(self.content.as_str(), true)
-					.take(self.cursor_position)
-					.collect::<String>();
-				let end = UnicodeSegmentation::graphemes(self.content.as_str(), true)
-					.skip(self.cursor_position)
-					.collect::<String>();
-				self.content = format!("{}{}{}", start, c, end);
-				self.cursor_position += 1;
-			},
+				..
+			}) => self.insert_char(c),
+			// the edit is a single line, so the line endings of pasted text are inserted as spaces
+			Event::Paste('\n') => self.insert_char(' '),
+			Event::Paste(c) => self.insert_char(c),
 			_ => {},
 		}
 
 		event
 	}
 
+	fn history(&mut self) -> Option<&mut History> {
+		let name = self.history_name?;
+		Some(self.histories.entry(name).or_insert_with(History::new))
+	}
+
+	fn length(&self) -> usize {
+		UnicodeSegmentation::graphemes(self.content.as_str(), true).count()
+	}
+
+	// remove the graphemes from the start index, up to but not including the end index, with the cursor at the start
+	fn delete_range(&mut self, start: usize, end: usize) {
+		if start >= end {
+			return;
+		}
+		self.content = UnicodeSegmentation::graphemes(self.content.as_str(), true)
+			.enumerate()
+			.filter(|&(index, _)| index < start || index >= end)
+			.map(|(_, grapheme)| grapheme)
+			.collect::<String>();
+		self.cursor_position = start;
+	}
+
+	// like readline, a word is a run of graphemes that are not whitespace
+	fn previous_word_start(&self) -> usize {
+		let graphemes = UnicodeSegmentation::graphemes(self.content.as_str(), true).collect::<Vec<&str>>();
+		let mut index = self.cursor_position;
+		while index > 0 && is_whitespace(graphemes[index - 1]) {
+			index -= 1;
+		}
+		while index > 0 && !is_whitespace(graphemes[index - 1]) {
+			index -= 1;
+		}
+		index
+	}
+
+	fn next_word_end(&self) -> usize {
+		let graphemes = UnicodeSegmentation::graphemes(self.content.as_str(), true).collect::<Vec<&str>>();
+		let mut index = self.cursor_position;
+		while index < graphemes.len() && is_whitespace(graphemes[index]) {
+			index += 1;
+		}
+		while index < graphemes.len() && !is_whitespace(graphemes[index]) {
+			index += 1;
+		}
+		index
+	}
+
+	// A character does not always start a new grapheme, such as a combining mark or a jamo of an input method, that
+	// joins with the graphemes around it, so the cursor is placed after the grapheme that contains the character.
+	fn insert_char(&mut self, c: char) {
+		let start = UnicodeSegmentation::graphemes(self.content.as_str(), true)
+			.take(self.cursor_position)
+			.collect::<String>();
+		let end = UnicodeSegmentation::graphemes(self.content.as_str(), true)
+			.skip(self.cursor_position)
+			.collect::<String>();
+		let inserted_end = start.len() + c.len_utf8();
+		self.content = format!("{}{}{}", start, c, end);
+		self.cursor_position = UnicodeSegmentation::grapheme_indices(self.content.as_str(), true)
+			.take_while(|&(index, _)| index < inserted_end)
+			.count();
+	}
+
 	pub(crate) fn set_description(&mut self, description: &str) {
 		self.description = Some(String::from(description));
 	}
@@ -185,10 +368,20 @@ impl Edit {
 		self.cursor_position = UnicodeSegmentation::graphemes(content, true).count();
 	}
 
+	/// Keep the finished content in the history of the name, that is browsed with up and down, until the edit is
+	/// cleared. Each kind of prompt has a history of its own name.
+	pub(crate) fn set_history(&mut self, name: &'static str) {
+		self.history_name = Some(name);
+		if let Some(history) = self.history() {
+			history.reset();
+		}
+	}
+
 	pub(crate) fn clear(&mut self) {
 		self.content.clear();
 		self.cursor_position = 0;
 		self.finished = false;
+		self.history_name = None;
 	}
 
 	pub(crate) const fn is_finished(&self) -> bool {
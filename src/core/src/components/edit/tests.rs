@@ -1,4 +1,5 @@
 use input::testutil::with_event_handler;
+use rstest::rstest;
 use view::assert_rendered_output;
 
 use super::*;
@@ -310,6 +311,67 @@ fn multiple_width_unicode_emoji() {
 	});
 }
 
+#[test]
+fn add_combining_character() {
+	with_event_handler(&[Event::from('\u{301}'), Event::from('x')], |context| {
+		let mut module = Edit::new();
+		module.set_content("e");
+		let _ = module.handle_event(&context.event_handler);
+		assert_eq!(module.cursor_position, 1);
+		let _ = module.handle_event(&context.event_handler);
+		assert_eq!(module.get_content(), "e\u{301}x");
+		assert_eq!(module.cursor_position, 2);
+	});
+}
+
+#[test]
+fn add_conjoining_jamo() {
+	with_event_handler(
+		&[
+			Event::from('\u{1112}'),
+			Event::from('\u{1161}'),
+			Event::from('\u{11ab}'),
+			Event::from(KeyCode::Left),
+			Event::from('日'),
+		],
+		|context| {
+			let mut module = Edit::new();
+			let _ = context.for_each_event(|event_handler| module.handle_event(event_handler));
+			assert_eq!(module.get_content(), "日\u{1112}\u{1161}\u{11ab}");
+			assert_eq!(module.cursor_position, 1);
+		},
+	);
+}
+
+#[test]
+fn backspace_grapheme_cluster() {
+	with_event_handler(&[Event::from(KeyCode::Backspace)], |context| {
+		let mut module = Edit::new();
+		module.set_content("a\u{1112}\u{1161}\u{11ab}");
+		let _ = module.handle_event(&context.event_handler);
+		assert_eq!(module.get_content(), "a");
+		assert_eq!(module.cursor_position, 1);
+	});
+}
+
+#[test]
+fn add_wide_character_between() {
+	with_event_handler(&[Event::from(KeyCode::Left), Event::from('本')], |context| {
+		let mut module = Edit::new();
+		module.set_content("日語");
+		let _ = context.for_each_event(|event_handler| module.handle_event(event_handler));
+		let view_data = module.get_view_data();
+		assert_rendered_output!(
+			view_data,
+			"{TITLE}",
+			"{BODY}",
+			"{Normal}日本{Normal,Underline}語",
+			"{TRAILING}",
+			"{IndicatorColor}Enter to finish"
+		);
+	});
+}
+
 #[test]
 fn add_character_end() {
 	with_event_handler(&[Event::from('x')], |context| {
@@ -405,10 +467,7 @@ fn add_character_at_start() {
 #[test]
 fn add_character_uppercase() {
 	with_event_handler(
-		&[Event::Key(KeyEvent {
-			code: input::KeyCode::Char('X'),
-			modifiers: input::KeyModifiers::SHIFT,
-		})],
+		&[Event::Key(KeyEvent::new(KeyCode::Char('X'), KeyModifiers::SHIFT))],
 		|context| {
 			let mut module = Edit::new();
 			module.set_content("abcd");
@@ -619,6 +678,143 @@ fn delete_first_character() {
 	);
 }
 
+#[test]
+fn paste_text() {
+	with_event_handler(
+		&[
+			Event::from(KeyCode::Left),
+			Event::Paste('x'),
+			Event::Paste('\n'),
+			Event::Paste('y'),
+		],
+		|context| {
+			let mut module = Edit::new();
+			module.set_content("abcd");
+			let _ = context.for_each_event(|event_handler| module.handle_event(event_handler));
+			assert_eq!(module.get_content(), "abcx yd");
+			assert!(!module.is_finished());
+		},
+	);
+}
+
+fn ctrl(c: char) -> Event {
+	Event::Key(KeyEvent::new(KeyCode::Char(c), KeyModifiers::CONTROL))
+}
+
+fn alt(c: char) -> Event {
+	Event::Key(KeyEvent::new(KeyCode::Char(c), KeyModifiers::ALT))
+}
+
+#[rstest]
+#[case::start_of_line(ctrl('a'), "foo bar baz", 0)]
+#[case::end_of_line(ctrl('e'), "foo bar baz", 11)]
+#[case::word_back(alt('b'), "foo bar baz", 4)]
+#[case::word_forward(alt('f'), "foo bar baz", 7)]
+#[case::word_back_control_left(Event::Key(KeyEvent::new(KeyCode::Left, KeyModifiers::CONTROL)), "foo bar baz", 4)]
+#[case::word_forward_control_right(Event::Key(KeyEvent::new(KeyCode::Right, KeyModifiers::CONTROL)), "foo bar baz", 7)]
+#[case::delete_word_before(ctrl('w'), "foo ar baz", 4)]
+#[case::delete_word_before_alt_backspace(
+	Event::Key(KeyEvent::new(KeyCode::Backspace, KeyModifiers::ALT)),
+	"foo ar baz",
+	4
+)]
+#[case::delete_word_after(alt('d'), "foo b baz", 5)]
+#[case::delete_word_after_control_delete(
+	Event::Key(KeyEvent::new(KeyCode::Delete, KeyModifiers::CONTROL)),
+	"foo b baz",
+	5
+)]
+#[case::delete_to_start(ctrl('u'), "ar baz", 0)]
+#[case::delete_to_end(ctrl('k'), "foo b", 5)]
+fn readline_shortcut(#[case] event: Event, #[case] expected_content: &str, #[case] expected_cursor: usize) {
+	with_event_handler(&[event], |context| {
+		let mut module = Edit::new();
+		module.set_content("foo bar baz");
+		module.cursor_position = 5;
+		let _ = module.handle_event(&context.event_handler);
+		assert_eq!(module.get_content(), expected_content);
+		assert_eq!(module.cursor_position, expected_cursor);
+	});
+}
+
+#[test]
+fn delete_word_before_with_trailing_whitespace() {
+	with_event_handler(&[ctrl('w')], |context| {
+		let mut module = Edit::new();
+		module.set_content("foo bar  ");
+		let _ = module.handle_event(&context.event_handler);
+		assert_eq!(module.get_content(), "foo ");
+	});
+}
+
+#[test]
+fn history_browse() {
+	with_event_handler(
+		&[
+			Event::from('a'),
+			Event::from(KeyCode::Enter),
+			Event::from('b'),
+			Event::from(KeyCode::Enter),
+			Event::from('c'),
+			Event::from(KeyCode::Up),
+			Event::from(KeyCode::Up),
+			Event::from(KeyCode::Up),
+			Event::from(KeyCode::Down),
+			Event::from(KeyCode::Down),
+		],
+		|context| {
+			let mut module = Edit::new();
+			module.set_history("test");
+			for _ in 0..2 {
+				let _ = module.handle_event(&context.event_handler);
+				let _ = module.handle_event(&context.event_handler);
+				module.clear();
+				module.set_history("test");
+			}
+			let _ = module.handle_event(&context.event_handler);
+			let _ = module.handle_event(&context.event_handler);
+			assert_eq!(module.get_content(), "b");
+			let _ = module.handle_event(&context.event_handler);
+			assert_eq!(module.get_content(), "a");
+			let _ = module.handle_event(&context.event_handler);
+			assert_eq!(module.get_content(), "a");
+			let _ = module.handle_event(&context.event_handler);
+			assert_eq!(module.get_content(), "b");
+			let _ = module.handle_event(&context.event_handler);
+			assert_eq!(module.get_content(), "c");
+		},
+	);
+}
+
+#[test]
+fn history_separate_names() {
+	with_event_handler(
+		&[Event::from('a'), Event::from(KeyCode::Enter), Event::from(KeyCode::Up)],
+		|context| {
+			let mut module = Edit::new();
+			module.set_history("first");
+			let _ = module.handle_event(&context.event_handler);
+			let _ = module.handle_event(&context.event_handler);
+			module.clear();
+			module.set_history("second");
+			let _ = module.handle_event(&context.event_handler);
+			assert_eq!(module.get_content(), "");
+		},
+	);
+}
+
+#[test]
+fn history_not_set() {
+	with_event_handler(
+		&[Event::from('a'), Event::from(KeyCode::Enter), Event::from(KeyCode::Up)],
+		|context| {
+			let mut module = Edit::new();
+			let _ = context.for_each_event(|event_handler| module.handle_event(event_handler));
+			assert_eq!(module.get_content(), "a");
+		},
+	);
+}
+
 #[test]
 fn ignore_other_input() {
 	with_event_handler(&[Event::from(KeyCode::Null)], |context| {
@@ -7,6 +7,8 @@ use lazy_static::lazy_static;
 use unicode_segmentation::UnicodeSegmentation;
 use view::{handle_view_data_scroll, LineSegment, ViewData, ViewLine, ViewSender};
 
+use crate::i18n::translate;
+
 lazy_static! {
 	static ref INPUT_OPTIONS: InputOptions = InputOptions::new().movement(true);
 }
@@ -36,7 +38,13 @@ impl Help {
 			updater.set_retain_scroll_position(false);
 			updater.push_leading_line(
 				ViewLine::new_pinned(vec![LineSegment::new_with_color_and_style(
-					format!(" {0:width$} Action", "Key", width = max_key_length).as_str(),
+					format!(
+						" {0:width$} {1}",
+						translate("Key"),
+						translate("Action"),
+						width = max_key_length
+					)
+					.as_str(),
 					DisplayColor::Normal,
 					false,
 					true,
@@ -60,7 +68,7 @@ impl Help {
 			}
 
 			updater.push_trailing_line(ViewLine::new_pinned(vec![LineSegment::new_with_color(
-				"Press any key to close",
+				translate("Press any key to close"),
 				DisplayColor::IndicatorColor,
 			)]));
 		});
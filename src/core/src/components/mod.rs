@@ -2,6 +2,8 @@ pub(crate) mod choice;
 pub(crate) mod confirm;
 pub(crate) mod edit;
 pub(crate) mod help;
+pub(crate) mod number_input;
+pub(crate) mod progress;
 
 #[cfg(test)]
 mod testutil;
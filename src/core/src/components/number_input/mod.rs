@@ -0,0 +1,239 @@
+#[cfg(test)]
+mod tests;
+
+use display::DisplayColor;
+use input::{Event, EventHandler, InputOptions, KeyCode, KeyEvent, KeyModifiers};
+use lazy_static::lazy_static;
+use view::{LineSegment, ViewData, ViewLine};
+
+use crate::i18n::{translate, translate_format};
+
+lazy_static! {
+	static ref INPUT_OPTIONS: InputOptions = InputOptions::new();
+}
+
+// the amount that a page up or a page down changes the number
+const PAGE_STEP: usize = 10;
+
+/// A prompt for a number within a minimum and a maximum, that is typed or changed with up and down. The number is
+/// only finished when it is within the bounds, or when the prompt is left empty to cancel it.
+pub(crate) struct NumberInput {
+	content: String,
+	description: Option<String>,
+	error: Option<String>,
+	finished: bool,
+	label: Option<String>,
+	maximum: usize,
+	minimum: usize,
+	view_data: ViewData,
+}
+
+impl NumberInput {
+	pub(crate) fn new() -> Self {
+		let view_data = ViewData::new(|updater| {
+			updater.set_show_title(true);
+		});
+		Self {
+			content: String::new(),
+			description: None,
+			error: None,
+			finished: false,
+			label: None,
+			maximum: usize::MAX,
+			minimum: 0,
+			view_data,
+		}
+	}
+
+	pub(crate) fn get_view_data(&mut self) -> &ViewData {
+		let mut segments = vec![];
+		if let Some(label) = self.label.as_ref() {
+			segments.push(LineSegment::new_with_color_and_style(
+				label.as_str(),
+				DisplayColor::Normal,
+				true,
+				false,
+				false,
+			));
+		}
+		if !self.content.is_empty() {
+			segments.push(LineSegment::new(self.content.as_str()));
+		}
+		segments.push(LineSegment::new_with_color_and_style(
+			" ",
+			DisplayColor::Normal,
+			false,
+			true,
+			false,
+		));
+		// an error replaces the description, until the number is changed
+		let description = self.error.as_ref().or(self.description.as_ref());
+		self.view_data.update_view_data(|updater| {
+			updater.clear();
+			if let Some(desc) = description {
+				updater.push_leading_line(ViewLine::from(vec![LineSegment::new_with_color(
+					desc.as_str(),
+					DisplayColor::IndicatorColor,
+				)]));
+				updater.push_leading_line(ViewLine::new_empty_line());
+			}
+			updater.push_line(ViewLine::from(segments));
+			updater.push_trailing_line(ViewLine::new_pinned(vec![LineSegment::new_with_color(
+				translate("Up and down to change, enter to finish"),
+				DisplayColor::IndicatorColor,
+			)]));
+			updater.ensure_line_visible(0);
+		});
+		&self.view_data
+	}
+
+	pub(crate) fn handle_event(&mut self, event_handler: &EventHandler) -> Event {
+		let event = event_handler.read_event(&INPUT_OPTIONS, |event, _| event);
+
+		match event {
+			Event::Key(KeyEvent {
+				code: KeyCode::Backspace,
+				modifiers: KeyModifiers::NONE,
+				..
+			}) => {
+				let _digit = self.content.pop();
+				self.error = None;
+			},
+			Event::Key(KeyEvent {
+				code: KeyCode::Char('u'),
+				modifiers: KeyModifiers::CONTROL,
+				..
+			}) => {
+				self.content.clear();
+				self.error = None;
+			},
+			Event::Key(
+				KeyEvent {
+					code: KeyCode::Up,
+					modifiers: KeyModifiers::NONE,
+					..
+				}
+				| KeyEvent {
+					code: KeyCode::Char('+'),
+					modifiers: KeyModifiers::NONE | KeyModifiers::SHIFT,
+					..
+				},
+			) => self.step_up(1),
+			Event::Key(
+				KeyEvent {
+					code: KeyCode::Down,
+					modifiers: KeyModifiers::NONE,
+					..
+				}
+				| KeyEvent {
+					code: KeyCode::Char('-'),
+					modifiers: KeyModifiers::NONE,
+					..
+				},
+			) => self.step_down(1),
+			Event::Key(KeyEvent {
+				code: KeyCode::PageUp,
+				modifiers: KeyModifiers::NONE,
+				..
+			}) => self.step_up(PAGE_STEP),
+			Event::Key(KeyEvent {
+				code: KeyCode::PageDown,
+				modifiers: KeyModifiers::NONE,
+				..
+			}) => self.step_down(PAGE_STEP),
+			Event::Key(KeyEvent {
+				code: KeyCode::Enter,
+				modifiers: KeyModifiers::NONE,
+				..
+			}) => {
+				if self.content.is_empty() || self.get_value().is_some() {
+					self.finished = true;
+				}
+				else {
+					self.error = Some(translate_format("A number from {} to {} is needed", &[
+						&self.minimum,
+						&self.maximum,
+					]));
+				}
+			},
+			Event::Key(KeyEvent {
+				code: KeyCode::Char(c),
+				modifiers: KeyModifiers::NONE | KeyModifiers::SHIFT,
+				..
+			})
+			| Event::Paste(c) => self.push_digit(c),
+			_ => {},
+		}
+
+		event
+	}
+
+	// a digit that would make the number too large to be held is ignored, like any other character
+	fn push_digit(&mut self, c: char) {
+		if !c.is_ascii_digit() {
+			return;
+		}
+		self.content.push(c);
+		if self.content.parse::<usize>().is_err() {
+			let _digit = self.content.pop();
+		}
+		self.error = None;
+	}
+
+	fn step_up(&mut self, step: usize) {
+		let value = match self.content.parse::<usize>() {
+			Ok(value) => value.saturating_add(step),
+			Err(_) => self.minimum,
+		};
+		self.set_value(value);
+	}
+
+	fn step_down(&mut self, step: usize) {
+		let value = match self.content.parse::<usize>() {
+			Ok(value) => value.saturating_sub(step),
+			Err(_) => self.minimum,
+		};
+		self.set_value(value);
+	}
+
+	pub(crate) fn set_bounds(&mut self, minimum: usize, maximum: usize) {
+		self.minimum = minimum;
+		self.maximum = maximum.max(minimum);
+	}
+
+	pub(crate) fn set_description(&mut self, description: &str) {
+		self.description = Some(String::from(description));
+	}
+
+	pub(crate) fn set_label(&mut self, label: &str) {
+		self.label = Some(String::from(label));
+	}
+
+	/// Set the number, that is kept within the bounds.
+	pub(crate) fn set_value(&mut self, value: usize) {
+		self.content = value.clamp(self.minimum, self.maximum).to_string();
+		self.error = None;
+	}
+
+	pub(crate) fn clear(&mut self) {
+		self.content.clear();
+		self.description = None;
+		self.error = None;
+		self.finished = false;
+		self.label = None;
+		self.minimum = 0;
+		self.maximum = usize::MAX;
+	}
+
+	pub(crate) const fn is_finished(&self) -> bool {
+		self.finished
+	}
+
+	/// The number, or none when the prompt is empty or the number is not within the bounds.
+	pub(crate) fn get_value(&self) -> Option<usize> {
+		self.content
+			.parse::<usize>()
+			.ok()
+			.filter(|value| (self.minimum..=self.maximum).contains(value))
+	}
+}
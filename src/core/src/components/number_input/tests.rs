@@ -0,0 +1,218 @@
+use input::testutil::with_event_handler;
+use rstest::rstest;
+use view::assert_rendered_output;
+
+use super::*;
+
+fn handle_events(module: &mut NumberInput, events: &[Event]) {
+	with_event_handler(events, |context| {
+		let _ = context.for_each_event(|event_handler| module.handle_event(event_handler));
+	});
+}
+
+#[test]
+fn with_label_and_description() {
+	let mut module = NumberInput::new();
+	module.set_label("Label: ");
+	module.set_description("Description");
+	module.set_value(42);
+	let view_data = module.get_view_data();
+	assert_rendered_output!(
+		Options AssertRenderOptions {
+			ignore_trailing_whitespace: false
+		},
+		view_data,
+		"{TITLE}",
+		"{LEADING}",
+		"{IndicatorColor}Description",
+		"",
+		"{BODY}",
+		"{Normal,Dimmed}Label: {Normal}42{Normal,Underline} ",
+		"{TRAILING}",
+		"{IndicatorColor}Up and down to change, enter to finish"
+	);
+}
+
+#[test]
+fn empty() {
+	let mut module = NumberInput::new();
+	let view_data = module.get_view_data();
+	assert_rendered_output!(
+		Options AssertRenderOptions {
+			ignore_trailing_whitespace: false
+		},
+		view_data,
+		"{TITLE}",
+		"{BODY}",
+		"{Normal,Underline} ",
+		"{TRAILING}",
+		"{IndicatorColor}Up and down to change, enter to finish"
+	);
+}
+
+#[test]
+fn type_digits() {
+	let mut module = NumberInput::new();
+	handle_events(&mut module, &[
+		Event::from(KeyCode::Char('1')),
+		Event::from(KeyCode::Char('a')),
+		Event::from(KeyCode::Char('2')),
+		Event::Paste('3'),
+	]);
+	assert_eq!(module.get_value(), Some(123));
+	assert!(!module.is_finished());
+}
+
+#[test]
+fn digit_past_largest_number_ignored() {
+	let mut module = NumberInput::new();
+	module.set_value(usize::MAX);
+	handle_events(&mut module, &[Event::from(KeyCode::Char('9'))]);
+	assert_eq!(module.get_value(), Some(usize::MAX));
+}
+
+#[test]
+fn backspace() {
+	let mut module = NumberInput::new();
+	module.set_value(123);
+	handle_events(&mut module, &[Event::from(KeyCode::Backspace)]);
+	assert_eq!(module.get_value(), Some(12));
+}
+
+#[test]
+fn clear_with_control_u() {
+	let mut module = NumberInput::new();
+	module.set_value(123);
+	handle_events(&mut module, &[Event::Key(KeyEvent::new(
+		KeyCode::Char('u'),
+		KeyModifiers::CONTROL,
+	))]);
+	assert_eq!(module.get_value(), None);
+}
+
+#[rstest]
+#[case::up_from_empty(None, KeyCode::Up, Some(2))]
+#[case::down_from_empty(None, KeyCode::Down, Some(2))]
+#[case::up(Some(4), KeyCode::Up, Some(5))]
+#[case::plus(Some(4), KeyCode::Char('+'), Some(5))]
+#[case::up_at_maximum(Some(20), KeyCode::Up, Some(20))]
+#[case::down(Some(4), KeyCode::Down, Some(3))]
+#[case::minus(Some(4), KeyCode::Char('-'), Some(3))]
+#[case::down_at_minimum(Some(2), KeyCode::Down, Some(2))]
+#[case::page_up(Some(4), KeyCode::PageUp, Some(14))]
+#[case::page_up_past_maximum(Some(14), KeyCode::PageUp, Some(20))]
+#[case::page_down(Some(14), KeyCode::PageDown, Some(4))]
+#[case::page_down_past_minimum(Some(4), KeyCode::PageDown, Some(2))]
+fn step(#[case] initial: Option<usize>, #[case] code: KeyCode, #[case] expected: Option<usize>) {
+	let mut module = NumberInput::new();
+	module.set_bounds(2, 20);
+	if let Some(value) = initial {
+		module.set_value(value);
+	}
+	handle_events(&mut module, &[Event::from(code)]);
+	assert_eq!(module.get_value(), expected);
+}
+
+#[test]
+fn step_from_out_of_bounds() {
+	let mut module = NumberInput::new();
+	module.set_bounds(2, 20);
+	handle_events(&mut module, &[
+		Event::from(KeyCode::Char('9')),
+		Event::from(KeyCode::Char('9')),
+		Event::from(KeyCode::Down),
+	]);
+	assert_eq!(module.get_value(), Some(20));
+}
+
+#[test]
+fn set_value_within_bounds() {
+	let mut module = NumberInput::new();
+	module.set_bounds(2, 20);
+	module.set_value(30);
+	assert_eq!(module.get_value(), Some(20));
+}
+
+#[test]
+fn finish_with_number() {
+	let mut module = NumberInput::new();
+	module.set_bounds(1, 10);
+	handle_events(&mut module, &[
+		Event::from(KeyCode::Char('5')),
+		Event::from(KeyCode::Enter),
+	]);
+	assert!(module.is_finished());
+	assert_eq!(module.get_value(), Some(5));
+}
+
+#[test]
+fn finish_empty() {
+	let mut module = NumberInput::new();
+	module.set_bounds(1, 10);
+	handle_events(&mut module, &[Event::from(KeyCode::Enter)]);
+	assert!(module.is_finished());
+	assert_eq!(module.get_value(), None);
+}
+
+#[test]
+fn finish_out_of_bounds() {
+	let mut module = NumberInput::new();
+	module.set_bounds(1, 10);
+	module.set_description("Description");
+	handle_events(&mut module, &[
+		Event::from(KeyCode::Char('0')),
+		Event::from(KeyCode::Enter),
+	]);
+	assert!(!module.is_finished());
+	assert_eq!(module.get_value(), None);
+	assert_rendered_output!(
+		module.get_view_data(),
+		"{TITLE}",
+		"{LEADING}",
+		"{IndicatorColor}A number from 1 to 10 is needed",
+		"",
+		"{BODY}",
+		"{Normal}0{Normal,Underline}",
+		"{TRAILING}",
+		"{IndicatorColor}Up and down to change, enter to finish"
+	);
+}
+
+#[test]
+fn error_cleared_on_change() {
+	let mut module = NumberInput::new();
+	module.set_bounds(1, 10);
+	module.set_description("Description");
+	handle_events(&mut module, &[
+		Event::from(KeyCode::Char('0')),
+		Event::from(KeyCode::Enter),
+		Event::from(KeyCode::Backspace),
+	]);
+	assert_rendered_output!(
+		module.get_view_data(),
+		"{TITLE}",
+		"{LEADING}",
+		"{IndicatorColor}Description",
+		"",
+		"{BODY}",
+		"{Normal,Underline}",
+		"{TRAILING}",
+		"{IndicatorColor}Up and down to change, enter to finish"
+	);
+}
+
+#[test]
+fn clear() {
+	let mut module = NumberInput::new();
+	module.set_bounds(1, 10);
+	module.set_label("Label: ");
+	handle_events(&mut module, &[
+		Event::from(KeyCode::Char('5')),
+		Event::from(KeyCode::Enter),
+	]);
+	module.clear();
+	assert!(!module.is_finished());
+	assert_eq!(module.get_value(), None);
+	module.set_value(50);
+	assert_eq!(module.get_value(), Some(50));
+}
@@ -0,0 +1,77 @@
+#[cfg(test)]
+mod tests;
+
+use std::time::Duration;
+
+use display::DisplayColor;
+use input::{Event, KeyCode, KeyEvent};
+use view::{LineSegment, ViewLine};
+
+use crate::i18n::translate_format;
+
+/// The interval of the ticks that advance the spinner, and that update the progress of the operation.
+pub(crate) const PROGRESS_INTERVAL: Duration = Duration::from_millis(100);
+
+// the frames of the spinner, that is shown until the amount of the work that is done is known
+const SPINNER_FRAMES: [&str; 4] = ["-", "\\", "|", "/"];
+
+// the width of the bar, without the brackets
+const BAR_WIDTH: usize = 20;
+
+/// The progress of a long operation, shown as a line with a spinner, or with a bar once the amount of the work that
+/// is done is known, followed by the time since the operation started. The operation is cancelled with escape.
+pub(crate) struct Progress {
+	frame: usize,
+	label: String,
+	progress: Option<(usize, usize)>,
+}
+
+impl Progress {
+	pub(crate) fn new(label: &str) -> Self {
+		Self {
+			frame: 0,
+			label: String::from(label),
+			progress: None,
+		}
+	}
+
+	/// Advance the spinner to its next frame.
+	pub(crate) fn tick(&mut self) {
+		self.frame = (self.frame + 1) % SPINNER_FRAMES.len();
+	}
+
+	/// Set the amount of the work that is done, and the amount of the work in total, with the spinner shown while the
+	/// total is not known.
+	pub(crate) fn set_progress(&mut self, progress: Option<(usize, usize)>) {
+		self.progress = progress.filter(|&(_, total)| total > 0);
+	}
+
+	pub(crate) fn get_line(&self, elapsed: Duration) -> ViewLine {
+		let indicator = match self.progress {
+			Some((done, total)) => {
+				let completed = done.min(total);
+				let filled = completed * BAR_WIDTH / total;
+				format!(
+					"[{}{}] {}%",
+					"#".repeat(filled),
+					"-".repeat(BAR_WIDTH - filled),
+					completed * 100 / total
+				)
+			},
+			None => String::from(SPINNER_FRAMES[self.frame]),
+		};
+		ViewLine::from(LineSegment::new_with_color(
+			format!(
+				"{} {}",
+				indicator,
+				translate_format("{}, {}s, press escape to cancel", &[&self.label, &elapsed.as_secs()])
+			)
+			.as_str(),
+			DisplayColor::IndicatorColor,
+		))
+	}
+
+	pub(crate) const fn is_cancel_event(event: Event) -> bool {
+		matches!(event, Event::Key(KeyEvent { code: KeyCode::Esc, .. }))
+	}
+}
@@ -0,0 +1,81 @@
+use input::KeyModifiers;
+use rstest::rstest;
+use view::{assert_rendered_output, ViewData};
+
+use super::*;
+
+fn render(progress: &Progress, elapsed: Duration) -> ViewData {
+	let mut view_data = ViewData::new(|_| {});
+	view_data.update_view_data(|updater| updater.push_line(progress.get_line(elapsed)));
+	view_data
+}
+
+#[test]
+fn spinner() {
+	let progress = Progress::new("Loading");
+	assert_rendered_output!(
+		&render(&progress, Duration::from_secs(3)),
+		"{BODY}",
+		"{IndicatorColor}- Loading, 3s, press escape to cancel"
+	);
+}
+
+#[test]
+fn spinner_tick() {
+	let mut progress = Progress::new("Loading");
+	progress.tick();
+	assert_rendered_output!(
+		&render(&progress, Duration::ZERO),
+		"{BODY}",
+		"{IndicatorColor}\\ Loading, 0s, press escape to cancel"
+	);
+}
+
+#[test]
+fn spinner_tick_wraps() {
+	let mut progress = Progress::new("Loading");
+	for _ in 0..SPINNER_FRAMES.len() {
+		progress.tick();
+	}
+	assert_rendered_output!(
+		&render(&progress, Duration::ZERO),
+		"{BODY}",
+		"{IndicatorColor}- Loading, 0s, press escape to cancel"
+	);
+}
+
+#[rstest]
+#[case::none(0, 4, "[--------------------] 0%")]
+#[case::half(2, 4, "[##########----------] 50%")]
+#[case::partial(1, 3, "[######--------------] 33%")]
+#[case::complete(4, 4, "[####################] 100%")]
+#[case::past_total(5, 4, "[####################] 100%")]
+fn bar(#[case] completed: usize, #[case] total: usize, #[case] expected: &str) {
+	let mut progress = Progress::new("Loading");
+	progress.set_progress(Some((completed, total)));
+	assert_rendered_output!(
+		&render(&progress, Duration::ZERO),
+		"{BODY}",
+		format!("{{IndicatorColor}}{} Loading, 0s, press escape to cancel", expected)
+	);
+}
+
+#[test]
+fn bar_without_total() {
+	let mut progress = Progress::new("Loading");
+	progress.set_progress(Some((0, 0)));
+	assert_rendered_output!(
+		&render(&progress, Duration::ZERO),
+		"{BODY}",
+		"{IndicatorColor}- Loading, 0s, press escape to cancel"
+	);
+}
+
+#[rstest]
+#[case::escape(Event::from(KeyCode::Esc), true)]
+#[case::escape_with_modifier(Event::Key(KeyEvent::new(KeyCode::Esc, KeyModifiers::SHIFT)), true)]
+#[case::other_key(Event::from('a'), false)]
+#[case::none(Event::None, false)]
+fn is_cancel_event(#[case] event: Event, #[case] expected: bool) {
+	assert_eq!(Progress::is_cancel_event(event), expected);
+}
@@ -0,0 +1,161 @@
+use std::{
+	fs::File,
+	io::Write,
+	panic::{set_hook, take_hook},
+	path::{Path, PathBuf},
+	sync::Once,
+};
+
+use anyhow::{anyhow, Result};
+#[cfg(test)]
+use display::testutil::CrossTerm;
+#[cfg(not(test))]
+use display::CrossTerm;
+use display::Tui;
+use todo_file::{Line, TodoFile};
+
+use crate::{
+	exit::Exit,
+	module::ExitStatus,
+};
+
+const RECOVERY_FILE_NAME: &str = "interactive-rebase-tool-recovery";
+
+static INSTALL_PANIC_HOOK: Once = Once::new();
+
+/// Install a panic hook that restores the terminal before the panic message is printed, since a panic while the
+/// terminal is in raw mode, and the alternate screen, leaves the terminal unusable and the message unreadable.
+pub(crate) fn install_panic_hook() {
+	INSTALL_PANIC_HOOK.call_once(|| {
+		let default_hook = take_hook();
+		set_hook(Box::new(move |info| {
+			let _result = CrossTerm::new().end();
+			log::error!(target: "panic", "{}", info);
+			default_hook(info);
+		}));
+	});
+}
+
+/// Write the lines of the todo file to the recovery file in the directory, returning the path of the file.
+pub(crate) fn write_recovery_file(directory: &Path, todo_file: &TodoFile) -> Result<PathBuf> {
+	let path = directory.join(RECOVERY_FILE_NAME);
+	let mut file =
+		File::create(&path).map_err(|err| anyhow!(err).context(format!("Error opening file: {}", path.display())))?;
+	let contents = todo_file
+		.lines_iter()
+		.map(Line::to_text)
+		.collect::<Vec<String>>()
+		.join("\n");
+	writeln!(file, "{}", contents)
+		.map_err(|err| anyhow!(err).context(format!("Error writing file: {}", path.display())))?;
+	Ok(path)
+}
+
+// save the todo file to the recovery file, and build the exit with the reason and the location of the saved file
+fn save(directory: &Path, todo_file: &TodoFile, reason: &str, status: ExitStatus) -> Exit {
+	let message = match write_recovery_file(directory, todo_file) {
+		Ok(path) => format!("{} The edited todo list was saved to: {}", reason, path.display()),
+		Err(err) => format!("{} The edited todo list could not be saved: {:#}", reason, err),
+	};
+	Exit::new(status, message.as_str())
+}
+
+/// Save the todo file after a crash, and build the exit with the location of the saved file.
+pub(crate) fn recover(directory: &Path, todo_file: &TodoFile) -> Exit {
+	let exit = save(
+		directory,
+		todo_file,
+		"The interactive rebase tool crashed unexpectedly, see the message above for details.",
+		ExitStatus::StateError,
+	);
+	log::error!(target: "panic", "{}", exit.get_message().as_deref().unwrap_or_default());
+	exit
+}
+
+/// Save the todo file after the tool was stopped by a termination signal, and build the exit with the location of the
+/// saved file. The todo file is not written, since the rebase is expected to stop with the tool.
+pub(crate) fn save_after_signal(directory: &Path, todo_file: &TodoFile, signal: i32) -> Exit {
+	let exit = save(
+		directory,
+		todo_file,
+		format!("The interactive rebase tool was stopped by signal {}.", signal).as_str(),
+		ExitStatus::Signal(signal),
+	);
+	log::warn!(target: "signal", "{}", exit.get_message().as_deref().unwrap_or_default());
+	exit
+}
+
+#[cfg(test)]
+mod tests {
+	use std::fs::read_to_string;
+
+	use tempfile::tempdir;
+
+	use super::*;
+
+	fn create_todo_file(lines: &[&str]) -> TodoFile {
+		let mut todo_file = TodoFile::new("git-rebase-todo", 1, "#");
+		todo_file.set_lines(lines.iter().map(|line| Line::new(line).unwrap()).collect());
+		todo_file
+	}
+
+	#[test]
+	fn write_recovery_file_success() {
+		let directory = tempdir().unwrap();
+		let todo_file = create_todo_file(&["pick aaa comment", "drop bbb comment"]);
+		let path = write_recovery_file(directory.path(), &todo_file).unwrap();
+		assert_eq!(path, directory.path().join(RECOVERY_FILE_NAME));
+		assert_eq!(read_to_string(path).unwrap(), "pick aaa comment\ndrop bbb comment\n");
+	}
+
+	#[test]
+	fn recover_success() {
+		let directory = tempdir().unwrap();
+		let todo_file = create_todo_file(&["pick aaa comment"]);
+		let exit = recover(directory.path(), &todo_file);
+		assert_eq!(exit.get_status(), &ExitStatus::StateError);
+		assert!(exit
+			.get_message()
+			.as_ref()
+			.unwrap()
+			.ends_with(format!("saved to: {}", directory.path().join(RECOVERY_FILE_NAME).display()).as_str()));
+	}
+
+	#[test]
+	fn recover_write_error() {
+		let directory = tempdir().unwrap();
+		let todo_file = create_todo_file(&["pick aaa comment"]);
+		let exit = recover(directory.path().join("missing").as_path(), &todo_file);
+		assert_eq!(exit.get_status(), &ExitStatus::StateError);
+		assert!(exit
+			.get_message()
+			.as_ref()
+			.unwrap()
+			.contains("The edited todo list could not be saved: Error opening file: "));
+	}
+
+	#[test]
+	fn save_after_signal_success() {
+		let directory = tempdir().unwrap();
+		let todo_file = create_todo_file(&["pick aaa comment"]);
+		let exit = save_after_signal(directory.path(), &todo_file, 15);
+		assert_eq!(exit.get_status(), &ExitStatus::Signal(15));
+		assert_eq!(
+			exit.get_message().as_deref(),
+			Some(
+				format!(
+					"The interactive rebase tool was stopped by signal 15. The edited todo list was saved to: {}",
+					directory.path().join(RECOVERY_FILE_NAME).display()
+				)
+				.as_str()
+			)
+		);
+	}
+
+	#[test]
+	fn install_panic_hook_twice() {
+		install_panic_hook();
+		install_panic_hook();
+		assert!(std::panic::catch_unwind(|| panic!("test panic")).is_err());
+	}
+}
@@ -0,0 +1,93 @@
+use chrono::{DateTime, Local};
+use config::DateFormat;
+
+use crate::i18n::{translate, translate_format};
+
+/// Format a date with the configured format.
+pub(crate) fn format_date(date: &DateTime<Local>, format: &DateFormat) -> String {
+	match *format {
+		DateFormat::Relative => format_relative_date(date, &Local::now()),
+		// the format is validated when the configuration is loaded
+		DateFormat::Format(ref strftime) => date.format(strftime.as_str()).to_string(),
+		_ => date.to_rfc2822(),
+	}
+}
+
+fn format_unit(count: i64, singular: &'static str, plural: &'static str) -> String {
+	translate_format(if count == 1 { singular } else { plural }, &[&count])
+}
+
+/// Format the time from the date to `now`, using the same units and rounding as the relative dates of Git.
+pub(crate) fn format_relative_date(date: &DateTime<Local>, now: &DateTime<Local>) -> String {
+	let seconds = now.signed_duration_since(*date).num_seconds();
+	if seconds < 0 {
+		return String::from(translate("in the future"));
+	}
+	if seconds < 90 {
+		return format_unit(seconds, "{} second ago", "{} seconds ago");
+	}
+	let minutes = (seconds + 30) / 60;
+	if minutes < 90 {
+		return format_unit(minutes, "{} minute ago", "{} minutes ago");
+	}
+	let hours = (minutes + 30) / 60;
+	if hours < 36 {
+		return format_unit(hours, "{} hour ago", "{} hours ago");
+	}
+	let days = (hours + 12) / 24;
+	if days < 14 {
+		return format_unit(days, "{} day ago", "{} days ago");
+	}
+	if days < 70 {
+		return format_unit((days + 3) / 7, "{} week ago", "{} weeks ago");
+	}
+	if days < 365 {
+		return format_unit((days + 15) / 30, "{} month ago", "{} months ago");
+	}
+	format_unit((days + 183) / 365, "{} year ago", "{} years ago")
+}
+
+#[cfg(test)]
+mod tests {
+	use chrono::{Duration, TimeZone};
+	use rstest::rstest;
+
+	use super::*;
+
+	#[rstest]
+	#[case::future(Duration::seconds(-10), "in the future")]
+	#[case::now(Duration::zero(), "0 seconds ago")]
+	#[case::second(Duration::seconds(1), "1 second ago")]
+	#[case::seconds(Duration::seconds(89), "89 seconds ago")]
+	#[case::minutes_rounded_up(Duration::seconds(90), "2 minutes ago")]
+	#[case::minutes(Duration::minutes(89), "89 minutes ago")]
+	#[case::hours(Duration::minutes(90), "2 hours ago")]
+	#[case::hour_limit(Duration::hours(35), "35 hours ago")]
+	#[case::days(Duration::hours(36), "2 days ago")]
+	#[case::day_limit(Duration::days(13), "13 days ago")]
+	#[case::weeks(Duration::days(14), "2 weeks ago")]
+	#[case::week_limit(Duration::days(69), "10 weeks ago")]
+	#[case::months(Duration::days(70), "2 months ago")]
+	#[case::month_limit(Duration::days(364), "12 months ago")]
+	#[case::year(Duration::days(365), "1 year ago")]
+	#[case::years(Duration::days(365 * 3), "3 years ago")]
+	fn relative_date(#[case] age: Duration, #[case] expected: &str) {
+		let now = Local.timestamp(1_609_502_400, 0);
+		assert_eq!(format_relative_date(&(now - age), &now), expected);
+	}
+
+	#[test]
+	fn format_date_with_format() {
+		let date = Local.timestamp(1_609_502_400, 0);
+		assert_eq!(
+			format_date(&date, &DateFormat::Format(String::from("%Y-%m-%d"))),
+			"2021-01-01"
+		);
+	}
+
+	#[test]
+	fn format_date_relative() {
+		let date = Local::now() - Duration::days(3);
+		assert_eq!(format_date(&date, &DateFormat::Relative), "3 days ago");
+	}
+}
@@ -0,0 +1,150 @@
+// the time of the first commit of each fixture, 2021-01-01 00:00:00 UTC, so that the hashes of the commits are the
+// same on each run
+const START_TIME: i64 = 1_609_459_200;
+const HOUR: i64 = 3600;
+
+pub(super) struct DemoCommit {
+	pub(super) author: (&'static str, &'static str),
+	pub(super) files: &'static [(&'static str, &'static str)],
+	pub(super) message: &'static str,
+	pub(super) time: i64,
+}
+
+pub(super) enum TodoLine {
+	/// A line with an action and a commit, by the index of the commit in the fixture.
+	Commit(&'static str, usize),
+	/// A merge line, of a commit by the index of the commit in the fixture, and a label.
+	Merge(usize, &'static str),
+	/// A line that is written as is.
+	Other(&'static str),
+}
+
+pub(super) struct Fixture {
+	/// The commits of the fixture, the first of which is the base of the todo list.
+	pub(super) commits: &'static [DemoCommit],
+	pub(super) description: &'static str,
+	pub(super) name: &'static str,
+	pub(super) todo: &'static [TodoLine],
+}
+
+const ALICE: (&str, &str) = ("Alice Smith", "alice@example.com");
+const BOB: (&str, &str) = ("Bob Jones", "bob@example.com");
+const CAROL: (&str, &str) = ("Carol Brown", "carol@example.com");
+
+const CALCULATOR_COMMITS: &[DemoCommit] = &[
+	DemoCommit {
+		author: ALICE,
+		files: &[
+			("README.md", "# Calculator\n"),
+			("src/main.rs", "fn main() {}\n"),
+		],
+		message: "Initial commit",
+		time: START_TIME,
+	},
+	DemoCommit {
+		author: ALICE,
+		files: &[("src/add.rs", "pub fn add(a: i32, b: i32) -> i32 {\n\ta + b\n}\n")],
+		message: "Add addition",
+		time: START_TIME + HOUR,
+	},
+	DemoCommit {
+		author: BOB,
+		files: &[(
+			"src/subtract.rs",
+			"pub fn subtract(a: i32, b: i32) -> i32 {\n\ta - b\n}\n",
+		)],
+		message: "Add subtraction",
+		time: START_TIME + 2 * HOUR,
+	},
+	DemoCommit {
+		author: BOB,
+		files: &[(
+			"src/subtract.rs",
+			"/// Subtract b from a.\npub fn subtract(a: i32, b: i32) -> i32 {\n\ta - b\n}\n",
+		)],
+		message: "Document subtraction",
+		time: START_TIME + 3 * HOUR,
+	},
+	DemoCommit {
+		author: CAROL,
+		files: &[(
+			"src/multiply.rs",
+			"pub fn multiply(a: i32, b: i32) -> i32 {\n\ta * b\n}\n",
+		)],
+		message: "Add multiplication",
+		time: START_TIME + 4 * HOUR,
+	},
+	DemoCommit {
+		author: CAROL,
+		files: &[(
+			"src/main.rs",
+			"fn main() {\n\tprintln!(\"{}\", add::add(1, 2));\n}\n",
+		)],
+		message: "Print a sum",
+		time: START_TIME + 5 * HOUR,
+	},
+	DemoCommit {
+		author: ALICE,
+		files: &[(
+			"README.md",
+			"# Calculator\n\nAdds, subtracts and multiplies numbers.\n",
+		)],
+		message: "Describe the calculator",
+		time: START_TIME + 6 * HOUR,
+	},
+	DemoCommit {
+		author: ALICE,
+		files: &[],
+		message: "Merge the subtraction branch",
+		time: START_TIME + 7 * HOUR,
+	},
+];
+
+pub(super) const FIXTURES: [Fixture; 3] = [
+	Fixture {
+		commits: CALCULATOR_COMMITS,
+		description: "A todo list of picked commits from a few authors",
+		name: "basic",
+		todo: &[
+			TodoLine::Commit("pick", 1),
+			TodoLine::Commit("pick", 2),
+			TodoLine::Commit("pick", 3),
+			TodoLine::Commit("pick", 4),
+			TodoLine::Commit("pick", 5),
+			TodoLine::Commit("pick", 6),
+		],
+	},
+	Fixture {
+		commits: CALCULATOR_COMMITS,
+		description: "A todo list with each of the actions on a commit, an exec and a break",
+		name: "actions",
+		todo: &[
+			TodoLine::Commit("pick", 1),
+			TodoLine::Commit("reword", 2),
+			TodoLine::Commit("squash", 3),
+			TodoLine::Commit("edit", 4),
+			TodoLine::Other("exec cargo test"),
+			TodoLine::Commit("drop", 5),
+			TodoLine::Other("break"),
+			TodoLine::Commit("fixup", 6),
+		],
+	},
+	Fixture {
+		commits: CALCULATOR_COMMITS,
+		description: "A todo list of a rebase that keeps a merge, with labels and resets",
+		name: "merges",
+		todo: &[
+			TodoLine::Other("label onto"),
+			TodoLine::Other("reset onto"),
+			TodoLine::Commit("pick", 2),
+			TodoLine::Commit("pick", 3),
+			TodoLine::Other("label subtraction"),
+			TodoLine::Other("reset onto"),
+			TodoLine::Commit("pick", 1),
+			TodoLine::Commit("pick", 4),
+			TodoLine::Merge(7, "subtraction"),
+			TodoLine::Commit("pick", 5),
+			TodoLine::Commit("pick", 6),
+		],
+	},
+];
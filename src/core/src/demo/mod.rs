@@ -0,0 +1,127 @@
+mod fixtures;
+
+use git::SandboxCommit;
+
+use self::fixtures::{Fixture, TodoLine, FIXTURES};
+use crate::{arguments::Args, exit::Exit, module::ExitStatus, run::start_logging, sandbox::Sandbox};
+
+fn find_fixture(name: &str) -> Result<&'static Fixture, Exit> {
+	FIXTURES.iter().find(|fixture| fixture.name == name).ok_or_else(|| {
+		Exit::new(
+			ExitStatus::StateError,
+			format!(
+				"Unknown demo fixture: {}, the fixtures are:\n{}",
+				name,
+				FIXTURES
+					.iter()
+					.map(|fixture| format!("  {:<10}{}", fixture.name, fixture.description))
+					.collect::<Vec<String>>()
+					.join("\n")
+			)
+			.as_str(),
+		)
+	})
+}
+
+fn todo_contents(fixture: &Fixture, hashes: &[String]) -> String {
+	let commit = |index: usize| (hashes[index].as_str(), fixture.commits[index].message);
+	fixture
+		.todo
+		.iter()
+		.map(|line| {
+			match *line {
+				TodoLine::Commit(action, index) => {
+					let (hash, message) = commit(index);
+					format!("{} {} {}", action, hash, message)
+				},
+				TodoLine::Merge(index, label) => {
+					let (hash, message) = commit(index);
+					format!("merge -C {} {} # {}", hash, label, message)
+				},
+				TodoLine::Other(content) => String::from(content),
+			}
+		})
+		.collect::<Vec<String>>()
+		.join("\n")
+}
+
+/// Open the list with the todo list of a bundled fixture, in a temporary repository with the commits of the fixture.
+/// The commits have fixed authors and times, so each run shows the same list, for screenshots and the development of
+/// themes.
+pub(crate) fn run(args: &Args, name: &str) -> Exit {
+	let fixture = match find_fixture(name) {
+		Ok(fixture) => fixture,
+		Err(exit) => return exit,
+	};
+	if let Err(exit) = start_logging(args) {
+		return exit;
+	}
+	let commits = fixture
+		.commits
+		.iter()
+		.map(|commit| {
+			commit.files.iter().fold(
+				SandboxCommit::new(commit.message)
+					.author(commit.author.0, commit.author.1)
+					.time(commit.time),
+				|sandbox_commit, &(path, content)| sandbox_commit.file(path, content),
+			)
+		})
+		.collect::<Vec<SandboxCommit>>();
+	let sandbox = match Sandbox::new("demo", commits.as_slice()) {
+		Ok(sandbox) => sandbox,
+		Err(exit) => return exit,
+	};
+	let contents = todo_contents(fixture, sandbox.hashes());
+	sandbox.run(args, contents.as_str())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn find_fixture_known() {
+		assert_eq!(find_fixture("basic").unwrap().name, "basic");
+	}
+
+	#[test]
+	fn find_fixture_unknown() {
+		assert_eq!(
+			find_fixture("unknown").err().unwrap(),
+			Exit::new(
+				ExitStatus::StateError,
+				"Unknown demo fixture: unknown, the fixtures are:\n  basic     A todo list of picked commits from a few \
+				 authors\n  actions   A todo list with each of the actions on a commit, an exec and a break\n  merges    A \
+				 todo list of a rebase that keeps a merge, with labels and resets"
+			)
+		);
+	}
+
+	#[test]
+	fn fixtures_reference_commits() {
+		for fixture in &FIXTURES {
+			for line in fixture.todo {
+				if let TodoLine::Commit(_, index) | TodoLine::Merge(index, _) = *line {
+					assert!(index > 0 && index < fixture.commits.len(), "{}", fixture.name);
+				}
+			}
+		}
+	}
+
+	#[test]
+	fn todo_contents_lines() {
+		let fixture = &FIXTURES[2];
+		let hashes = (0..fixture.commits.len())
+			.map(|index| format!("{:040}", index))
+			.collect::<Vec<String>>();
+		let contents = todo_contents(fixture, hashes.as_slice());
+		let lines = contents.lines().collect::<Vec<&str>>();
+		assert_eq!(lines[0], "label onto");
+		assert_eq!(lines[2], format!("pick {} Add subtraction", hashes[2]));
+		assert_eq!(
+			lines[8],
+			format!("merge -C {} subtraction # Merge the subtraction branch", hashes[7])
+		);
+	}
+}
@@ -0,0 +1,405 @@
+use std::{env::var, process::Command};
+
+use config::Config;
+#[cfg(test)]
+use display::testutil::CrossTerm;
+#[cfg(not(test))]
+use display::CrossTerm;
+use display::{ColorMode, Size, Tui};
+use git::{libgit2_version, DiffMemoryUsage, Repository};
+
+use crate::{
+	arguments::Args,
+	exit::Exit,
+	key_binding_conflicts::find_key_binding_conflicts,
+	modules::{config_source_name, SETTINGS},
+	version::build_version,
+};
+
+/// The details of the terminal and the installed tools, that are not part of the configuration.
+#[derive(Debug)]
+pub(crate) struct Environment {
+	color_mode: ColorMode,
+	color_term: Option<String>,
+	git_version: String,
+	interactive: bool,
+	size: Size,
+	term: Option<String>,
+}
+
+impl Environment {
+	pub(crate) fn detect<T: Tui>(tui: &T, interactive: bool) -> Self {
+		let git_version = Command::new("git")
+			.arg("--version")
+			.output()
+			.ok()
+			.filter(|output| output.status.success())
+			.map_or_else(
+				|| String::from("git was not found"),
+				|output| String::from(String::from_utf8_lossy(output.stdout.as_slice()).trim()),
+			);
+		Self {
+			color_mode: tui.get_color_mode(),
+			color_term: var("COLORTERM").ok(),
+			git_version,
+			interactive,
+			size: tui.get_size(),
+			term: var("TERM").ok(),
+		}
+	}
+
+	pub(crate) fn set_size(&mut self, size: Size) {
+		self.size = size;
+	}
+}
+
+const fn color_mode_name(color_mode: ColorMode) -> &'static str {
+	match color_mode {
+		ColorMode::TwoTone => "2 colors",
+		ColorMode::ThreeBit => "8 colors",
+		ColorMode::FourBit => "16 colors",
+		ColorMode::EightBit => "256 colors",
+		ColorMode::TrueColor => "true color",
+	}
+}
+
+const fn yes_no(value: bool) -> &'static str {
+	if value {
+		"yes"
+	}
+	else {
+		"no"
+	}
+}
+
+fn push_environment(lines: &mut Vec<String>, environment: &Environment) {
+	lines.push(String::from("Versions"));
+	lines.push(format!("  {}", build_version()));
+	lines.push(format!("  libgit2 {}", libgit2_version()));
+	lines.push(format!("  {}", environment.git_version));
+	lines.push(String::new());
+	lines.push(String::from("Terminal"));
+	lines.push(format!("  Interactive: {}", yes_no(environment.interactive)));
+	lines.push(format!(
+		"  Size: {}x{}",
+		environment.size.width(),
+		environment.size.height()
+	));
+	lines.push(format!("  Color mode: {}", color_mode_name(environment.color_mode)));
+	lines.push(format!(
+		"  TERM: {}",
+		environment.term.as_deref().unwrap_or("(not set)")
+	));
+	lines.push(format!(
+		"  COLORTERM: {}",
+		environment.color_term.as_deref().unwrap_or("(not set)")
+	));
+}
+
+fn push_repository(lines: &mut Vec<String>, repository: Result<&Repository, &str>) {
+	lines.push(String::from("Repository"));
+	match repository {
+		Ok(repository) => {
+			lines.push(format!("  Git directory: {}", repository.git_directory().display()));
+			lines.push(format!(
+				"  Common directory: {}",
+				repository.common_directory().display()
+			));
+			lines.push(format!(
+				"  Work directory: {}",
+				repository
+					.work_directory()
+					.map_or_else(|| String::from("(bare repository)"), |path| path.display().to_string())
+			));
+			lines.push(format!(
+				"  Linked worktree: {}",
+				yes_no(repository.is_linked_worktree())
+			));
+			lines.push(format!("  Shallow clone: {}", yes_no(repository.is_shallow())));
+			lines.push(format!("  Partial clone: {}", yes_no(repository.is_partial_clone())));
+		},
+		Err(err) => lines.push(format!("  Unable to load Git repository: {}", err)),
+	}
+}
+
+fn push_config(lines: &mut Vec<String>, config: Result<&Config, &str>) {
+	let config = match config {
+		Ok(config) => config,
+		Err(err) => {
+			lines.push(String::from("Configuration"));
+			lines.push(format!("  Unable to load configuration: {}", err));
+			return;
+		},
+	};
+
+	lines.push(String::from("Git Configuration"));
+	lines.push(format!(
+		"  core.abbrev: {}",
+		config
+			.git
+			.abbrev
+			.map_or_else(|| String::from("auto"), |length| length.to_string())
+	));
+	lines.push(format!("  core.commentChar: {}", config.git.comment_char));
+	lines.push(format!("  core.editor: {}", config.git.editor));
+	lines.push(format!("  diff.context: {}", config.git.diff_context));
+	lines.push(format!("  diff.interHunkContext: {}", config.git.diff_interhunk_lines));
+	lines.push(format!("  diff.renameLimit: {}", config.git.diff_rename_limit));
+	lines.push(format!(
+		"  diff.renames: {}",
+		match (config.git.diff_renames, config.git.diff_copies) {
+			(true, true) => "copies",
+			(true, false) => "true",
+			_ => "false",
+		}
+	));
+	lines.push(format!("  rebase.autoStash: {}", config.git.rebase_auto_stash));
+	lines.push(String::new());
+
+	lines.push(String::from("Configuration"));
+	for setting in SETTINGS {
+		lines.push(format!(
+			"  {}: {} ({})",
+			setting.name(),
+			setting.value(config),
+			config_source_name(config.source(setting.config_name().as_str()))
+		));
+	}
+	lines.push(String::new());
+
+	lines.push(String::from("Key Binding Conflicts"));
+	let conflicts = find_key_binding_conflicts(&config.key_bindings);
+	if conflicts.is_empty() {
+		lines.push(String::from("  None"));
+	}
+	for conflict in conflicts {
+		lines.push(format!("  {}", conflict.description()));
+	}
+}
+
+// the size is shown in the largest unit that it has at least one of, with a single decimal
+fn format_bytes(bytes: usize) -> String {
+	const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+	let mut unit = 0;
+	let mut tenths = bytes.saturating_mul(10);
+	while tenths >= 10 * 1024 && unit < UNITS.len() - 1 {
+		tenths /= 1024;
+		unit += 1;
+	}
+	if unit == 0 {
+		format!("{} B", bytes)
+	}
+	else {
+		format!("{}.{} {}", tenths / 10, tenths % 10, UNITS[unit])
+	}
+}
+
+fn push_diff_memory_usage(lines: &mut Vec<String>, diff_memory_usage: Option<DiffMemoryUsage>) {
+	lines.push(String::from("Diff Memory"));
+	match diff_memory_usage {
+		Some(usage) => {
+			lines.push(format!("  Files: {}", usage.files()));
+			lines.push(format!("  Hunks: {}", usage.deltas()));
+			lines.push(format!("  Lines: {}", usage.lines()));
+			lines.push(format!("  Memory: {}", format_bytes(usage.bytes())));
+		},
+		None => lines.push(String::from("  No diff has been loaded")),
+	}
+}
+
+/// Build a report of the environment, repository and resolved configuration, for use in bug reports, with the memory
+/// used by the last loaded diff, when a diff has been loaded.
+pub(crate) fn build_report(
+	environment: &Environment,
+	repository: Result<&Repository, &str>,
+	config: Result<&Config, &str>,
+	diff_memory_usage: Option<DiffMemoryUsage>,
+) -> Vec<String> {
+	let mut lines = vec![String::from("Git Interactive Rebase Tool Diagnostics"), String::new()];
+	push_environment(&mut lines, environment);
+	lines.push(String::new());
+	push_repository(&mut lines, repository);
+	lines.push(String::new());
+	push_config(&mut lines, config);
+	lines.push(String::new());
+	push_diff_memory_usage(&mut lines, diff_memory_usage);
+	lines
+}
+
+pub(crate) fn run(args: &Args) -> Exit {
+	let environment = Environment::detect(&CrossTerm::new(), CrossTerm::is_interactive());
+	let repository = Repository::open_from_env().map_err(|err| format!("{:#}", err));
+	let config = repository
+		.as_ref()
+		.map_err(String::clone)
+		.and_then(|repo| {
+			Config::try_from_repository(repo, args.config_overrides()).map_err(|err| format!("{:#}", err))
+		});
+	Exit::from(
+		build_report(
+			&environment,
+			repository.as_ref().map_err(String::as_str),
+			config.as_ref().map_err(String::as_str),
+			None,
+		)
+		.join("\n"),
+	)
+}
+
+#[cfg(all(unix, test))]
+mod tests {
+	use git::testutil::{with_temp_repository, CommitBuilder, CommitDiffBuilder};
+	use rstest::rstest;
+
+	use super::*;
+
+	fn create_environment() -> Environment {
+		Environment {
+			color_mode: ColorMode::EightBit,
+			color_term: None,
+			git_version: String::from("git version 2.34.1"),
+			interactive: true,
+			size: Size::new(80, 24),
+			term: Some(String::from("xterm-256color")),
+		}
+	}
+
+	fn find_section<'l>(lines: &'l [String], name: &str) -> &'l [String] {
+		let start = lines.iter().position(|line| line == name).unwrap() + 1;
+		let length = lines[start..]
+			.iter()
+			.position(String::is_empty)
+			.unwrap_or(lines.len() - start);
+		&lines[start..start + length]
+	}
+
+	#[test]
+	fn detect() {
+		let environment = Environment::detect(&CrossTerm::new(), false);
+		assert_eq!(environment.color_mode, ColorMode::FourBit);
+		assert_eq!(environment.size.width(), 10);
+		assert!(!environment.interactive);
+		assert!(environment.git_version.starts_with("git version "));
+	}
+
+	#[test]
+	fn set_size() {
+		let mut environment = create_environment();
+		environment.set_size(Size::new(100, 50));
+		assert_eq!(environment.size.width(), 100);
+		assert_eq!(environment.size.height(), 50);
+	}
+
+	#[test]
+	fn report_environment() {
+		let lines = build_report(&create_environment(), Err("error"), Err("error"), None);
+		assert_eq!(lines[0], "Git Interactive Rebase Tool Diagnostics");
+		let versions = find_section(&lines, "Versions");
+		assert!(versions[0].starts_with("  interactive-rebase-tool "));
+		assert!(versions[1].starts_with("  libgit2 "));
+		assert_eq!(versions[2], "  git version 2.34.1");
+		assert_eq!(find_section(&lines, "Terminal"), &[
+			String::from("  Interactive: yes"),
+			String::from("  Size: 80x24"),
+			String::from("  Color mode: 256 colors"),
+			String::from("  TERM: xterm-256color"),
+			String::from("  COLORTERM: (not set)"),
+		]);
+	}
+
+	#[test]
+	fn report_repository() {
+		with_temp_repository(|repository| {
+			let config = Config::new();
+			let lines = build_report(&create_environment(), Ok(&repository), Ok(&config), None);
+			let section = find_section(&lines, "Repository");
+			assert_eq!(
+				section[0],
+				format!("  Git directory: {}", repository.git_directory().display())
+			);
+			assert_eq!(section[3], "  Linked worktree: no");
+			assert_eq!(section[4], "  Shallow clone: no");
+			assert_eq!(section[5], "  Partial clone: no");
+			Ok(())
+		});
+	}
+
+	#[test]
+	fn report_repository_error() {
+		let lines = build_report(
+			&create_environment(),
+			Err("not a repository"),
+			Err("not a repository"),
+			None,
+		);
+		assert_eq!(find_section(&lines, "Repository"), &[String::from(
+			"  Unable to load Git repository: not a repository"
+		)]);
+		assert_eq!(find_section(&lines, "Configuration"), &[String::from(
+			"  Unable to load configuration: not a repository"
+		)]);
+	}
+
+	#[test]
+	fn report_config() {
+		with_temp_repository(|repository| {
+			repository
+				.load_config()
+				.unwrap()
+				.set_str("interactive-rebase-tool.pickColor", "blue")?;
+			let config = Config::try_from(&repository).unwrap();
+			let lines = build_report(&create_environment(), Ok(&repository), Ok(&config), None);
+			let git_section = find_section(&lines, "Git Configuration");
+			assert!(git_section.contains(&String::from("  core.commentChar: #")));
+			let section = find_section(&lines, "Configuration");
+			assert_eq!(section.len(), SETTINGS.len());
+			assert!(section.contains(&String::from("  pickColor: blue (local)")));
+			assert!(section.contains(&String::from("  dropColor: red (default)")));
+			assert_eq!(find_section(&lines, "Key Binding Conflicts"), &[String::from("  None")]);
+			Ok(())
+		});
+	}
+
+	#[test]
+	fn report_key_binding_conflicts() {
+		with_temp_repository(|repository| {
+			let mut config = Config::new();
+			config.key_bindings.action_pick = vec![String::from("d")];
+			let lines = build_report(&create_environment(), Ok(&repository), Ok(&config), None);
+			assert_eq!(find_section(&lines, "Key Binding Conflicts"), &[String::from(
+				"  The key \"d\" is bound to inputActionDrop, inputActionPick in the list mode, only inputActionDrop \
+				 is used"
+			)]);
+			Ok(())
+		});
+	}
+
+	#[test]
+	fn report_without_diff_memory_usage() {
+		let lines = build_report(&create_environment(), Err("error"), Err("error"), None);
+		assert_eq!(find_section(&lines, "Diff Memory"), &[String::from("  No diff has been loaded")]);
+	}
+
+	#[test]
+	fn report_diff_memory_usage() {
+		let diff = CommitDiffBuilder::new(CommitBuilder::new("0123456789ABCDEF").build()).build();
+		let usage = diff.memory_usage();
+		let lines = build_report(&create_environment(), Err("error"), Err("error"), Some(usage));
+		assert_eq!(find_section(&lines, "Diff Memory"), &[
+			String::from("  Files: 0"),
+			String::from("  Hunks: 0"),
+			String::from("  Lines: 0"),
+			format!("  Memory: {} B", usage.bytes()),
+		]);
+	}
+
+	#[rstest]
+	#[case::bytes(1023, "1023 B")]
+	#[case::kibibytes(1024, "1.0 KiB")]
+	#[case::kibibytes_decimal(1536, "1.5 KiB")]
+	#[case::mebibytes(5 * 1024 * 1024 + 512 * 1024, "5.5 MiB")]
+	#[case::gibibytes(3 * 1024 * 1024 * 1024, "3.0 GiB")]
+	fn format_bytes(#[case] bytes: usize, #[case] expected: &str) {
+		assert_eq!(super::format_bytes(bytes), expected);
+	}
+}
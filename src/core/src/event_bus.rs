@@ -0,0 +1,112 @@
+use std::sync::mpsc::{channel, Receiver, Sender};
+
+use crate::timers::Timer;
+
+/// The kinds of events that a module subscribes to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub(crate) enum Topic {
+	Git,
+	Timer,
+	TodoFile,
+}
+
+/// An event of the work that is done in the background, that is dispatched by the process to the modules that
+/// subscribe to its topic, instead of each module polling for the work that has finished.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) enum BusEvent {
+	/// Details of the repository, or of the commits of the todo list, have been loaded.
+	DetailsLoaded,
+	/// The todo file has been changed by another program.
+	TodoFileChanged,
+	/// A timer that was started by a module has reached its interval.
+	Tick(Timer),
+}
+
+impl BusEvent {
+	pub(crate) const fn topic(&self) -> Topic {
+		match *self {
+			Self::DetailsLoaded => Topic::Git,
+			Self::TodoFileChanged => Topic::TodoFile,
+			Self::Tick(_) => Topic::Timer,
+		}
+	}
+}
+
+/// Sends events to the bus, from any thread.
+#[derive(Clone, Debug)]
+pub(crate) struct BusSender {
+	sender: Sender<BusEvent>,
+}
+
+impl BusSender {
+	/// Send an event, that is ignored once the process has stopped reading the bus.
+	pub(crate) fn send(&self, event: BusEvent) {
+		let _result = self.sender.send(event);
+	}
+}
+
+/// The events of the background work, in the order that they were sent, until they are dispatched by the process.
+#[derive(Debug)]
+pub(crate) struct EventBus {
+	receiver: Receiver<BusEvent>,
+	sender: Sender<BusEvent>,
+}
+
+impl EventBus {
+	pub(crate) fn new() -> Self {
+		let (sender, receiver) = channel();
+		Self { receiver, sender }
+	}
+
+	pub(crate) fn sender(&self) -> BusSender {
+		BusSender {
+			sender: self.sender.clone(),
+		}
+	}
+
+	/// Take the events that have been sent since the last call, without waiting.
+	pub(crate) fn drain(&self) -> Vec<BusEvent> {
+		self.receiver.try_iter().collect()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::thread::spawn;
+
+	use rstest::rstest;
+
+	use super::*;
+
+	#[rstest]
+	#[case::details_loaded(BusEvent::DetailsLoaded, Topic::Git)]
+	#[case::todo_file_changed(BusEvent::TodoFileChanged, Topic::TodoFile)]
+	#[case::tick(BusEvent::Tick(Timer::RelativeDates), Topic::Timer)]
+	fn topic(#[case] event: BusEvent, #[case] expected: Topic) {
+		assert_eq!(event.topic(), expected);
+	}
+
+	#[test]
+	fn drain_in_order() {
+		let event_bus = EventBus::new();
+		let sender = event_bus.sender();
+		sender.send(BusEvent::DetailsLoaded);
+		sender.send(BusEvent::TodoFileChanged);
+		assert_eq!(event_bus.drain(), vec![BusEvent::DetailsLoaded, BusEvent::TodoFileChanged]);
+		assert_eq!(event_bus.drain(), vec![]);
+	}
+
+	#[test]
+	fn send_from_thread() {
+		let event_bus = EventBus::new();
+		let sender = event_bus.sender();
+		spawn(move || sender.send(BusEvent::DetailsLoaded)).join().unwrap();
+		assert_eq!(event_bus.drain(), vec![BusEvent::DetailsLoaded]);
+	}
+
+	#[test]
+	fn send_after_bus_dropped() {
+		let sender = EventBus::new().sender();
+		sender.send(BusEvent::DetailsLoaded);
+	}
+}
@@ -0,0 +1,125 @@
+use std::{fs, path::Path};
+
+use todo_file::{Action, Line, TodoFile};
+
+// the file of the rebase that has the reference of the branch that is rebased, which is `detached HEAD` when no branch
+// is rebased
+const HEAD_NAME_FILE: &str = "head-name";
+
+/// The commit that an exec line inserted after the selected line runs after, which is the selected line, or the
+/// nearest earlier line, that applies a commit.
+fn get_commit_line(todo_file: &TodoFile) -> Option<&Line> {
+	(0..=todo_file.get_selected_line_index())
+		.rev()
+		.filter_map(|index| todo_file.get_line(index))
+		.find(|line| line.has_reference() && *line.get_action() != Action::Drop)
+}
+
+/// The name of the branch that is rebased, from the rebase directory that has the todo file.
+fn get_branch(todo_file: &TodoFile) -> Option<String> {
+	let path = Path::new(todo_file.get_filepath()).parent()?.join(HEAD_NAME_FILE);
+	let head_name = fs::read_to_string(path).ok()?;
+	head_name
+		.trim()
+		.strip_prefix("refs/heads/")
+		.map(String::from)
+}
+
+/// Expand the placeholders of the command of an exec template, from the commit that the exec line is inserted after,
+/// and the branch that is rebased. A placeholder without a value is kept, so that it can be replaced before the line
+/// is inserted.
+fn expand(command: &str, line: Option<&Line>, branch: Option<&str>) -> String {
+	let mut expanded = String::from(command);
+	if let Some(line) = line {
+		expanded = expanded
+			.replace("{commit}", line.get_hash())
+			.replace("{subject}", line.get_content());
+	}
+	if let Some(branch) = branch {
+		expanded = expanded.replace("{branch}", branch);
+	}
+	expanded
+}
+
+/// Expand the command of an exec template, for an exec line inserted after the selected line of the todo file.
+pub(crate) fn expand_for_todo_file(command: &str, todo_file: &TodoFile) -> String {
+	expand(
+		command,
+		get_commit_line(todo_file),
+		get_branch(todo_file).as_deref(),
+	)
+}
+
+#[cfg(test)]
+mod tests {
+	use rstest::rstest;
+	use tempfile::tempdir;
+
+	use super::*;
+
+	#[rstest]
+	#[case::commit("git show {commit}", "git show aaa")]
+	#[case::subject("echo '{subject}'", "echo 'the subject'")]
+	#[case::branch("git push origin HEAD:{branch}", "git push origin HEAD:feature")]
+	#[case::all("test {commit} {subject} {branch}", "test aaa the subject feature")]
+	#[case::repeated("{commit} {commit}", "aaa aaa")]
+	#[case::unknown("echo {other}", "echo {other}")]
+	#[case::without_placeholders("cargo test", "cargo test")]
+	fn expand_placeholders(#[case] command: &str, #[case] expected: &str) {
+		let line = Line::new("pick aaa the subject").unwrap();
+		assert_eq!(expand(command, Some(&line), Some("feature")), expected);
+	}
+
+	#[test]
+	fn expand_without_values() {
+		assert_eq!(
+			expand("test {commit} {subject} {branch}", None, None),
+			"test {commit} {subject} {branch}"
+		);
+	}
+
+	#[rstest]
+	#[case::selected_commit(1, "git show bbb")]
+	#[case::earlier_commit(2, "git show bbb")]
+	#[case::skip_dropped_commit(3, "git show bbb")]
+	#[case::first_line(0, "git show aaa")]
+	fn expand_todo_file_commit(#[case] selected_index: usize, #[case] expected: &str) {
+		let mut todo_file = TodoFile::new("git-rebase-todo", 1, "#");
+		todo_file.set_lines(vec![
+			Line::new("pick aaa c1").unwrap(),
+			Line::new("pick bbb c2").unwrap(),
+			Line::new("exec make").unwrap(),
+			Line::new("drop ccc c3").unwrap(),
+		]);
+		todo_file.set_selected_line_index(selected_index);
+		assert_eq!(expand_for_todo_file("git show {commit}", &todo_file), expected);
+	}
+
+	#[test]
+	fn expand_todo_file_without_commit() {
+		let mut todo_file = TodoFile::new("git-rebase-todo", 1, "#");
+		todo_file.set_lines(vec![Line::new("exec make").unwrap()]);
+		assert_eq!(
+			expand_for_todo_file("git show {commit}", &todo_file),
+			"git show {commit}"
+		);
+	}
+
+	#[test]
+	fn expand_todo_file_branch() {
+		let directory = tempdir().unwrap();
+		fs::write(directory.path().join(HEAD_NAME_FILE), "refs/heads/feature\n").unwrap();
+		let todo_path = directory.path().join("git-rebase-todo");
+		let todo_file = TodoFile::new(todo_path.to_str().unwrap(), 1, "#");
+		assert_eq!(expand_for_todo_file("echo {branch}", &todo_file), "echo feature");
+	}
+
+	#[test]
+	fn expand_todo_file_detached_head() {
+		let directory = tempdir().unwrap();
+		fs::write(directory.path().join(HEAD_NAME_FILE), "detached HEAD\n").unwrap();
+		let todo_path = directory.path().join("git-rebase-todo");
+		let todo_file = TodoFile::new(todo_path.to_str().unwrap(), 1, "#");
+		assert_eq!(expand_for_todo_file("echo {branch}", &todo_file), "echo {branch}");
+	}
+}
@@ -0,0 +1,13 @@
+//! Entry points for the fuzz targets in the `fuzz` directory, which are only available with the `fuzz` feature, and are
+//! not part of the public interface of the crate.
+use config::Config;
+use display::Size;
+use git::CommitDiff;
+
+/// Render the overview and the diff of a commit, as the show commit module would, in a window of a width and height,
+/// to the lines of the rendered frames.
+#[must_use]
+#[inline]
+pub fn render_commit_diff(config: &Config, diff: &CommitDiff, width: usize, height: usize) -> Vec<String> {
+	crate::modules::render_commit_diff(config, diff, Size::new(width, height))
+}
@@ -0,0 +1,52 @@
+use git::{GitBackend, Repository};
+#[cfg(feature = "gix")]
+use git::GixRepository;
+
+/// Open the experimental gitoxide backend for the repository, when the tool is built with the `gix` feature, which is
+/// used to read the commits instead of the repository. When the backend is not available, or the repository cannot be
+/// opened with it, the commits are read with libgit2 from the repository.
+#[cfg(feature = "gix")]
+pub(crate) fn open_alternate_backend(repository: &Repository) -> Option<Box<dyn GitBackend>> {
+	match GixRepository::open_from_path(repository.git_directory()) {
+		Ok(gix_repository) => {
+			log::info!(target: "git", "Reading commits with gitoxide");
+			Some(Box::new(gix_repository))
+		},
+		Err(err) => {
+			log::warn!(target: "git", "Unable to open repository with gitoxide, using libgit2: {:#}", err);
+			None
+		},
+	}
+}
+
+/// Open the experimental gitoxide backend for the repository, which is not available without the `gix` feature.
+#[cfg(not(feature = "gix"))]
+#[allow(clippy::unnecessary_wraps)]
+pub(crate) const fn open_alternate_backend(_repository: &Repository) -> Option<Box<dyn GitBackend>> {
+	None
+}
+
+#[cfg(test)]
+mod tests {
+	use git::testutil::with_temp_repository;
+
+	use super::*;
+
+	#[cfg(not(feature = "gix"))]
+	#[test]
+	fn open_alternate_backend_without_gix() {
+		with_temp_repository(|repository| {
+			assert!(open_alternate_backend(&repository).is_none());
+			Ok(())
+		});
+	}
+
+	#[cfg(feature = "gix")]
+	#[test]
+	fn open_alternate_backend_with_gix() {
+		with_temp_repository(|repository| {
+			assert_eq!(open_alternate_backend(&repository).unwrap().name(), "gitoxide");
+			Ok(())
+		});
+	}
+}
@@ -5,15 +5,50 @@ Git Interactive Rebase Editor ({{VERSION}})
 Full feature terminal based sequence editor for git interactive rebase.
 
 USAGE:
-  interactive-rebase-tool [FLAGS] [REBASE-TODO-FILE]
+  interactive-rebase-tool [FLAGS] [OPTIONS] [REBASE-TODO-FILE]
 
 FLAGS:
   -v, --version       Prints versioning information
   -h, --help          Prints help information
   --license           Prints Open Source Software licensing
+  --diagnostics       Prints the resolved configuration, terminal and repository details
+  --profile-startup   Prints the time taken by each phase of the startup on exit
+  --read-only         Opens the todo list to be browsed, without any changes, and exits without writing the todo file
+  --tutorial          Walks through editing a todo list, in a temporary repository that is removed on exit
+
+OPTIONS:
+  -c, --config <NAME=VALUE>
+                      Overrides a setting for this run, in the format of the -c option of git, for example
+                      -c interactive-rebase-tool.icons=true, and can be repeated
+  --demo <FIXTURE>    Opens a bundled todo list, one of basic, actions or merges, with the commits in a temporary
+                      repository that is removed on exit
+  --export-json <PATH>
+                      Writes the todo list, with the details of each commit, as JSON to the file, or - for stdout
+  --export-shell <PATH>
+                      Writes the todo list as a shell script of the equivalent Git commands to the file, or - for stdout
+  --import-json <PATH>
+                      Replaces the todo list with the lines of a JSON file, in the format of --export-json
+  --log-file <PATH>   Writes a log of input events, state changes, Git operations and render timings to the file
+  --log-level <LEVEL> The detail of the log, one of error, warn, info, debug or trace [default: info]
+  --range-diff <REF>  Prints the commits of the todo list that have the same changes, or the same summary, as a commit
+                      of the branch, and the commits that are only in the todo list or only in the branch
+  --record-events <PATH>
+                      Writes the input events to the file, to be replayed with --replay-events
+  --replay-events <PATH>
+                      Reads the input events from the file, instead of the terminal
+  --script <COMMANDS> Applies the commands to the todo file and writes it, without opening the interface
+  --script-file <PATH>
+                      Applies the commands in the file to the todo file and writes it, without opening the interface
 
 ARGS:
   <REBASE-TODO-FILE>  The path to the Git rebase todo file
+
+SCRIPT COMMANDS:
+  Commands are separated by semicolons or new lines. Lines are referenced by line number, or by commit hash.
+  <ACTION> <LINE>              Set the action of the line, for example: drop abc123
+  squash <LINE> into <LINE>    Move the commit after the other commit and squash it, fixup is also supported
+  move <LINE> after <LINE>     Move the line after, or before, the other line
+  break after <LINE>           Insert a break after the line
 "#;
 
 pub(crate) fn build_help(message: Option<String>) -> String {
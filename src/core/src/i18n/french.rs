@@ -0,0 +1,830 @@
+/// The French translations of the messages, by the English message.
+pub(super) const MESSAGES: &[(&str, &str)] = &[
+	// help
+	("Key", "Touche"),
+	("Action", "Action"),
+	("Press any key to close", "Appuyez sur une touche pour fermer"),
+	("Move selection up", "Déplacer la sélection vers le haut"),
+	("Move selection down", "Déplacer la sélection vers le bas"),
+	(
+		"Move selection up 5 lines",
+		"Déplacer la sélection de 5 lignes vers le haut",
+	),
+	(
+		"Move selection down 5 lines",
+		"Déplacer la sélection de 5 lignes vers le bas",
+	),
+	(
+		"Move selection to top of the list",
+		"Déplacer la sélection au début de la liste",
+	),
+	(
+		"Move selection to end of the list",
+		"Déplacer la sélection à la fin de la liste",
+	),
+	(
+		"Move selection to a line by its number",
+		"Déplacer la sélection vers une ligne par son numéro",
+	),
+	("Scroll content to the left", "Faire défiler le contenu vers la gauche"),
+	("Scroll content to the right", "Faire défiler le contenu vers la droite"),
+	("Abort interactive rebase", "Annuler le rebase interactif"),
+	(
+		"Immediately abort interactive rebase",
+		"Annuler immédiatement le rebase interactif",
+	),
+	(
+		"Quit without saving, keeping the original rebase file",
+		"Quitter sans enregistrer, en gardant le fichier de rebase d'origine",
+	),
+	(
+		"Write interactive rebase file",
+		"Écrire le fichier du rebase interactif",
+	),
+	(
+		"Immediately write interactive rebase file",
+		"Écrire immédiatement le fichier du rebase interactif",
+	),
+	(
+		"Abort the stopped rebase (--edit-todo only)",
+		"Annuler le rebase arrêté (--edit-todo uniquement)",
+	),
+	(
+		"Skip the stopped commit (--edit-todo only)",
+		"Sauter le commit arrêté (--edit-todo uniquement)",
+	),
+	("Enter visual mode", "Entrer en mode visuel"),
+	("Exit visual mode", "Quitter le mode visuel"),
+	("Show help", "Afficher l'aide"),
+	("Show commit information", "Afficher les informations du commit"),
+	("Move selected commit down", "Descendre le commit sélectionné"),
+	("Move selected commit up", "Monter le commit sélectionné"),
+	("Move selected commits down", "Descendre les commits sélectionnés"),
+	("Move selected commits up", "Monter les commits sélectionnés"),
+	("Toggle break action", "Ajouter ou retirer une action break"),
+	(
+		"Insert a break after every number of commits",
+		"Insérer un break après chaque nombre de commits",
+	),
+	("Remove all of the break lines", "Retirer toutes les lignes break"),
+	(
+		"Collapse or expand the group of lines of the selected line",
+		"Réduire ou développer le groupe de lignes de la ligne sélectionnée",
+	),
+	(
+		"Name the group of lines of the selected line",
+		"Nommer le groupe de lignes de la ligne sélectionnée",
+	),
+	("Group: ", "Groupe : "),
+	(
+		"Collapse the selected lines to a single row",
+		"Réduire les lignes sélectionnées à une seule ligne",
+	),
+	(
+		"Collapse all of the groups of lines, or expand all of the collapsed lines",
+		"Réduire tous les groupes de lignes, ou développer toutes les lignes réduites",
+	),
+	("{} {} lines", "{} lignes {}"),
+	(
+		"Mark or unmark the selected line, to change or move the marked lines together",
+		"Marquer ou démarquer la ligne sélectionnée, pour modifier ou déplacer les lignes marquées ensemble",
+	),
+	("Mark or unmark the selected lines", "Marquer ou démarquer les lignes sélectionnées"),
+	("Unmark all of the marked lines", "Démarquer toutes les lignes marquées"),
+	("{} lines marked. {}", "{} lignes marquées. {}"),
+	(
+		"Mark the lines of an action, the commits of an author, or the filtered commits",
+		"Marquer les lignes d'une action, les commits d'un auteur, ou les commits filtrés",
+	),
+	("Mark: ", "Marquer : "),
+	(
+		"An action, such as exec or fixup, author: and a part of the name or email of an author, or filter for the \
+		 commits that match the last path filter",
+		"Une action, comme exec ou fixup, author: et une partie du nom ou de l'email d'un auteur, ou filter pour les \
+		 commits qui correspondent au dernier filtre de chemin",
+	),
+	("Invalid lines to mark: {}", "Lignes à marquer invalides : {}"),
+	("No lines match: {}", "Aucune ligne ne correspond : {}"),
+	(
+		"Move the selected line, with a preview of its new position",
+		"Déplacer la ligne sélectionnée, avec un aperçu de sa nouvelle position",
+	),
+	(
+		"Move the selected lines, with a preview of their new position",
+		"Déplacer les lignes sélectionnées, avec un aperçu de leur nouvelle position",
+	),
+	("Moving {} lines to line {} of {}", "Déplacement de {} lignes à la ligne {} sur {}"),
+	(
+		"Moving {} lines past {} lines, with {} possible conflicts",
+		"Déplacement de {} lignes au-delà de {} lignes, avec {} conflits possibles",
+	),
+	("  Possible conflict with the moved commits", "  Conflit possible avec les commits déplacés"),
+	(
+		"Press Enter to move the lines, or Escape to cancel",
+		"Appuyez sur Entrée pour déplacer les lignes, ou sur Échap pour annuler",
+	),
+	("Original position ", "Position d'origine "),
+	("Sort the commits of the marked lines", "Trier les commits des lignes marquées"),
+	("Sort the commits of the selected lines", "Trier les commits des lignes sélectionnées"),
+	("Sort by: ", "Trier par : "),
+	(
+		"author-date, committer-date, author or subject",
+		"author-date, committer-date, author ou subject",
+	),
+	("Invalid order: {}", "Ordre invalide : {}"),
+	(
+		"Reverse the order of the marked lines, or of all of the lines",
+		"Inverser l'ordre des lignes marquées, ou de toutes les lignes",
+	),
+	("Reverse the order of the selected lines", "Inverser l'ordre des lignes sélectionnées"),
+	(
+		"The name of the group of lines that starts with {}, or nothing to remove the name",
+		"Le nom du groupe de lignes qui commence par {}, ou rien pour retirer le nom",
+	),
+	("Collapsed: ", "Réduit : "),
+	("{} lines", "{} lignes"),
+	(
+		"Set selected commit to be picked",
+		"Conserver le commit sélectionné (pick)",
+	),
+	(
+		"Set selected commit to be reworded",
+		"Reformuler le commit sélectionné (reword)",
+	),
+	(
+		"Set selected commit to be edited",
+		"Modifier le commit sélectionné (edit)",
+	),
+	(
+		"Set selected commit to be squashed",
+		"Fusionner le commit sélectionné (squash)",
+	),
+	(
+		"Set selected commit to be fixed-up",
+		"Corriger avec le commit sélectionné (fixup)",
+	),
+	(
+		"Cycle the option of the selected fixup commit, between none, -C and -c",
+		"Alterner l'option du commit fixup sélectionné, entre aucune, -C et -c",
+	),
+	(
+		"Set selected commit to be dropped",
+		"Supprimer le commit sélectionné (drop)",
+	),
+	(
+		"Set selected commits to be picked",
+		"Conserver les commits sélectionnés (pick)",
+	),
+	(
+		"Set selected commits to be reworded",
+		"Reformuler les commits sélectionnés (reword)",
+	),
+	(
+		"Set selected commits to be edited",
+		"Modifier les commits sélectionnés (edit)",
+	),
+	(
+		"Set selected commits to be squashed",
+		"Fusionner les commits sélectionnés (squash)",
+	),
+	(
+		"Set selected commits to be fixed-up",
+		"Corriger avec les commits sélectionnés (fixup)",
+	),
+	(
+		"Cycle the option of the selected fixup commits, between none, -C and -c",
+		"Alterner l'option des commits fixup sélectionnés, entre aucune, -C et -c",
+	),
+	(
+		"Set selected commits to be dropped",
+		"Supprimer les commits sélectionnés (drop)",
+	),
+	(
+		"Edit an exec action's command",
+		"Modifier la commande d'une action exec",
+	),
+	(
+		"Move the selected commit to a new branch",
+		"Déplacer le commit sélectionné vers une nouvelle branche",
+	),
+	(
+		"Preview the autosquash order of the fixup and squash commits",
+		"Prévisualiser l'ordre autosquash des commits fixup et squash",
+	),
+	(
+		"Fix the position or action of the selected fixup or squash commit",
+		"Corriger la position ou l'action du commit fixup ou squash sélectionné",
+	),
+	(
+		"Show the message of the commit that a chain of squash commits is squashed into",
+		"Afficher le message du commit dans lequel une chaîne de commits squash est fusionnée",
+	),
+	(
+		"Show the statistics of the todo list",
+		"Afficher les statistiques de la liste todo",
+	),
+	(
+		"Show the commits that change a path",
+		"Afficher les commits qui modifient un chemin",
+	),
+	(
+		"Show the history of the notifications",
+		"Afficher l'historique des notifications",
+	),
+	(
+		"Show the other commits that change the files of the selected commit",
+		"Afficher les autres commits qui modifient les fichiers du commit sélectionné",
+	),
+	("Describe the selected line", "Décrire la ligne sélectionnée"),
+	(
+		"Show the combined changes of the selected commits",
+		"Afficher les modifications combinées des commits sélectionnés",
+	),
+	("Insert a new line", "Insérer une nouvelle ligne"),
+	(
+		"Insert the exec template that tests the selected commit after it",
+		"Insérer après le commit sélectionné le modèle exec qui le teste",
+	),
+	(
+		"No exec template named {} is configured to test a commit",
+		"Aucun modèle exec nommé {} n'est configuré pour tester un commit",
+	),
+	(
+		"Completely remove the selected line",
+		"Retirer complètement la ligne sélectionnée",
+	),
+	(
+		"Completely remove the selected lines",
+		"Retirer complètement les lignes sélectionnées",
+	),
+	(
+		"Move the selected commits to a new branch",
+		"Déplacer les commits sélectionnés vers une nouvelle branche",
+	),
+	("Undo the last change", "Annuler la dernière modification"),
+	(
+		"Redo the previous undone change",
+		"Rétablir la dernière modification annulée",
+	),
+	(
+		"Open the todo file in the default editor",
+		"Ouvrir le fichier todo dans l'éditeur par défaut",
+	),
+	(
+		"Toggle details on uncommitted changes",
+		"Afficher ou masquer les détails des modifications non commitées",
+	),
+	("Open the settings", "Ouvrir les paramètres"),
+	("Scroll up", "Défiler vers le haut"),
+	("Scroll down", "Défiler vers le bas"),
+	("Scroll up half a page", "Défiler d'une demi-page vers le haut"),
+	("Scroll down half a page", "Défiler d'une demi-page vers le bas"),
+	("Scroll right", "Défiler vers la droite"),
+	("Scroll left", "Défiler vers la gauche"),
+	("Show full diff", "Afficher le diff complet"),
+	(
+		"Show the changed files, a page at a time",
+		"Afficher les fichiers modifiés, une page à la fois",
+	),
+	(
+		"Show the commit that the commit was cherry picked from",
+		"Afficher le commit dont le commit a été copié",
+	),
+	// list
+	("Rebase todo file is empty", "Le fichier todo du rebase est vide"),
+	("Line {} of {}: {}", "Ligne {} sur {} : {}"),
+	(
+		"Visual mode, {} lines selected. {}",
+		"Mode visuel, {} lignes sélectionnées. {}",
+	),
+	("Warning: {}", "Avertissement : {}"),
+	(
+		"The todo list is read only, and will not be written on exit",
+		"La liste todo est en lecture seule, et ne sera pas écrite à la sortie",
+	),
+	("The todo list is read only", "La liste todo est en lecture seule"),
+	("Removed breaks: {}", "Breaks supprimés : {}"),
+	// external change
+	("Todo file changed", "Fichier todo modifié"),
+	(
+		"The todo file was changed by another program.",
+		"Le fichier todo a été modifié par un autre programme.",
+	),
+	(
+		"Up and down to select, enter or the key of a choice to choose",
+		"Haut et bas pour sélectionner, entrée ou la touche d'un choix pour choisir",
+	),
+	(
+		"The todo list was also changed, and the changes can be merged.",
+		"La liste todo a aussi été modifiée, et les modifications peuvent être fusionnées.",
+	),
+	(
+		"The todo list was also changed, and the changes conflict.",
+		"La liste todo a aussi été modifiée, et les modifications sont en conflit.",
+	),
+	(
+		"Merge the changes of the todo file into the todo list",
+		"Fusionner les modifications du fichier todo dans la liste todo",
+	),
+	(
+		"Reload the todo file, discarding the changes to the todo list",
+		"Recharger le fichier todo, en abandonnant les modifications de la liste todo",
+	),
+	(
+		"Keep the todo list, discarding the changes to the todo file",
+		"Garder la liste todo, en abandonnant les modifications du fichier todo",
+	),
+	("Reload the todo file", "Recharger le fichier todo"),
+	(
+		"The todo file was changed by another program, and cannot be read: {}",
+		"Le fichier todo a été modifié par un autre programme, et ne peut pas être lu : {}",
+	),
+	(
+		"The label {} is not created by any line",
+		"L'étiquette {} n'est créée par aucune ligne",
+	),
+	(
+		"The label {} is not created until line {}",
+		"L'étiquette {} n'est créée qu'à la ligne {}",
+	),
+	(
+		"The label {} is already created on line {}",
+		"L'étiquette {} est déjà créée à la ligne {}",
+	),
+	(
+		"The label {} is not used by any line",
+		"L'étiquette {} n'est utilisée par aucune ligne",
+	),
+	(
+		"The commit that this commit fixes is dropped on line {}, press {} to pick this commit",
+		"Le commit que ce commit corrige est supprimé à la ligne {}, appuyez sur {} pour conserver ce commit",
+	),
+	(
+		"The commit that this commit fixes is not picked until line {}, press {} to move this commit after it",
+		"Le commit que ce commit corrige n'est conservé qu'à la ligne {}, appuyez sur {} pour déplacer ce commit après \
+		 lui",
+	),
+	("(cherry picked from {})", "(copié de {})"),
+	(
+		"The commit that this commit was cherry picked from is also in the list",
+		"Le commit dont ce commit a été copié est aussi dans la liste",
+	),
+	(
+		"Uncommitted changes will be stashed by rebase.autoStash",
+		"Les modifications non commitées seront remisées par rebase.autoStash",
+	),
+	(
+		"Warning: The working tree has uncommitted changes, press {} to show details",
+		"Avertissement : l'arbre de travail a des modifications non commitées, appuyez sur {} pour afficher les \
+		 détails",
+	),
+	(
+		"Warning: The working tree has uncommitted changes, press {} to hide details",
+		"Avertissement : l'arbre de travail a des modifications non commitées, appuyez sur {} pour masquer les détails",
+	),
+	(
+		"The rebase will fail to start unless the changes are committed or stashed,",
+		"Le rebase ne pourra pas démarrer tant que les modifications ne sont pas commitées ou remisées,",
+	),
+	(
+		"or rebase.autoStash is enabled. The todo list will not be changed.",
+		"ou que rebase.autoStash n'est pas activé. La liste todo ne sera pas modifiée.",
+	),
+	("Modifying line: {}", "Modification de la ligne : {}"),
+	("Merge: ", "Fusion : "),
+	("Message: ", "Message : "),
+	(
+		"The labels or commits to merge, separated by spaces, for: {}",
+		"Les labels ou commits à fusionner, séparés par des espaces, pour : {}",
+	),
+	(
+		"-C <commit> to use the message of a commit, -c <commit> to also edit it, or nothing for the default \
+		 message",
+		"-C <commit> pour utiliser le message d'un commit, -c <commit> pour aussi le modifier, ou rien pour le message \
+		 par défaut",
+	),
+	(
+		"Expected -C <commit>, -c <commit>, a commit, or nothing",
+		"-C <commit>, -c <commit>, un commit, ou rien était attendu",
+	),
+	("Invalid merge: {}", "Fusion non valide : {}"),
+	("Commits: ", "Commits : "),
+	(
+		"Insert a break after every number of commits, counted from the start and from each break",
+		"Insérer un break après chaque nombre de commits, compté depuis le début et depuis chaque break",
+	),
+	("Line: ", "Ligne : "),
+	("The number of the line to select", "Le numéro de la ligne à sélectionner"),
+	("Loading commit details", "Chargement des détails du commit"),
+	(
+		"Commit details are not available",
+		"Les détails du commit ne sont pas disponibles",
+	),
+	("{} files changed, ", "{} fichiers modifiés, "),
+	("{} insertions(+)", "{} insertions(+)"),
+	("{} deletions(-)", "{} suppressions(-)"),
+	("change action", "changer l'action"),
+	("details", "détails"),
+	("move", "déplacer"),
+	("visual mode", "mode visuel"),
+	("exit visual mode", "quitter le mode visuel"),
+	("edit", "modifier"),
+	("remove", "supprimer"),
+	("insert", "insérer"),
+	("write", "écrire"),
+	("abort", "annuler"),
+	("help", "aide"),
+	(
+		"The key \"{}\" is bound to {} in the {} mode, only {} is used",
+		"La touche \"{}\" est associée à {} dans le mode {}, seule {} est utilisée",
+	),
+	("list", "liste"),
+	("show commit", "affichage du commit"),
+	("confirm", "confirmation"),
+	("settings", "paramètres"),
+	// show commit
+	("Commit: ", "Commit : "),
+	("Date: ", "Date : "),
+	("Author: ", "Auteur : "),
+	("Committer: ", "Committeur : "),
+	(" file", " fichier"),
+	(" files", " fichiers"),
+	(" with ", " avec "),
+	(" insertion", " insertion"),
+	(" insertions", " insertions"),
+	(" and ", " et "),
+	(" deletion", " suppression"),
+	(" deletions", " suppressions"),
+	(" binary file, {} bytes", " fichier binaire, {} octets"),
+	(" {} bytes", " {} octets"),
+	(" lines {}-{}, {} bytes", " lignes {}-{}, {} octets"),
+	("{} more bytes", "{} octets de plus"),
+	("Error loading commit", "Erreur lors du chargement du commit"),
+	("No valid commit to show", "Aucun commit valide à afficher"),
+	("Unable to show file", "Impossible d'afficher le fichier"),
+	("Unable to show the source commit", "Impossible d'afficher le commit source"),
+	(" object not available", " objet non disponible"),
+	(
+		"The parent commit is not in this shallow clone, the files are compared to an empty tree",
+		"Le commit parent n'est pas dans ce clone superficiel, les fichiers sont comparés à un arbre vide",
+	),
+	(
+		"The files of the commit are not in this partial clone",
+		"Les fichiers du commit ne sont pas dans ce clone partiel",
+	),
+	(
+		"Fetching {} missing objects from {}",
+		"Récupération de {} objets manquants depuis {}",
+	),
+	("{}, {}s, press escape to cancel", "{}, {} s, appuyez sur échap pour annuler"),
+	("Unable to fetch missing objects", "Impossible de récupérer les objets manquants"),
+	(
+		"The combined changes of {} commits, as if they were squashed into the first commit",
+		"Les modifications combinées de {} commits, comme s'ils étaient fusionnés dans le premier commit",
+	),
+	(
+		"Unable to combine the changes of the selected commits",
+		"Impossible de combiner les modifications des commits sélectionnés",
+	),
+	// confirm
+	("{} ({}/{})? ", "{} ({}/{}) ? "),
+	("Are you sure you want to abort", "Voulez-vous vraiment annuler"),
+	(
+		"Are you sure you want to abort, exiting with an error without writing the rebase file",
+		"Voulez-vous vraiment annuler, en sortant avec une erreur sans écrire le fichier de rebase",
+	),
+	(
+		"Are you sure you want to quit without saving, keeping the original rebase file",
+		"Voulez-vous vraiment quitter sans enregistrer, en gardant le fichier de rebase d'origine",
+	),
+	(
+		"Are you sure you want to rebase",
+		"Voulez-vous vraiment lancer le rebase",
+	),
+	(
+		"Are you sure you want to abort the stopped rebase",
+		"Voulez-vous vraiment annuler le rebase arrêté",
+	),
+	(
+		"Are you sure you want to skip the commit the rebase stopped on",
+		"Voulez-vous vraiment sauter le commit sur lequel le rebase s'est arrêté",
+	),
+	("abort the rebase", "annuler le rebase"),
+	("skip the current commit", "sauter le commit actuel"),
+	(
+		"Unable to {}, a rebase is only stopped when opened with --edit-todo",
+		"Impossible de {}, un rebase n'est arrêté que lorsqu'il est ouvert avec --edit-todo",
+	),
+	(
+		"Unable to {}, git returned a non-zero exit status",
+		"Impossible de {}, git a renvoyé un code de sortie non nul",
+	),
+	// choice, edit and error
+	(
+		"Invalid option selected. Please choose an option.",
+		"Option sélectionnée non valide. Veuillez choisir une option.",
+	),
+	("Please choose an option.", "Veuillez choisir une option."),
+	("Enter to finish", "Entrée pour terminer"),
+	(
+		"Up and down to change, enter to finish",
+		"Haut et bas pour changer, entrée pour terminer",
+	),
+	("A number from {} to {} is needed", "Un nombre de {} à {} est attendu"),
+	(
+		"Leave empty to keep the current value",
+		"Laisser vide pour garder la valeur actuelle",
+	),
+	("Press any key to continue", "Appuyez sur une touche pour continuer"),
+	("Caused by:", "Causée par :"),
+	("Suggestions:", "Suggestions :"),
+	(
+		"Up and down to scroll, c to copy the error, any other key to continue",
+		"Haut et bas pour défiler, c pour copier l'erreur, une autre touche pour continuer",
+	),
+	("Copied the error to the clipboard", "Erreur copiée dans le presse-papiers"),
+	(
+		"Check that the file, or the program, exists",
+		"Vérifiez que le fichier, ou le programme, existe",
+	),
+	(
+		"Check that the file can be read and written by the current user",
+		"Vérifiez que le fichier peut être lu et écrit par l'utilisateur actuel",
+	),
+	(
+		"Start the tool with --log-file and a path, to write the details of the error to a log",
+		"Lancez l'outil avec --log-file et un chemin, pour écrire les détails de l'erreur dans un journal",
+	),
+	(
+		"Start the tool with --diagnostics, to show the configuration and the details of the repository",
+		"Lancez l'outil avec --diagnostics, pour afficher la configuration et les détails du dépôt",
+	),
+	// insert
+	(
+		"Enter contents of the new line. Empty content cancels creation of a new line.",
+		"Saisissez le contenu de la nouvelle ligne. Un contenu vide annule la création de la ligne.",
+	),
+	("Cancel add line", "Annuler l'ajout de la ligne"),
+	(
+		"Select the type of line to insert:",
+		"Choisissez le type de ligne à insérer :",
+	),
+	(
+		"Select the exec template to insert:",
+		"Choisissez le modèle de ligne exec à insérer :",
+	),
+	// external editor
+	("Editing...", "Modification..."),
+	("Abort rebase", "Annuler le rebase"),
+	("Edit rebase file", "Modifier le fichier du rebase"),
+	(
+		"Undo modifications and edit rebase file",
+		"Annuler les modifications et modifier le fichier du rebase",
+	),
+	(
+		"Restore rebase file and abort edit",
+		"Restaurer le fichier du rebase et annuler la modification",
+	),
+	("The rebase file is empty.", "Le fichier du rebase est vide."),
+	("Invalid editor: \"{}\"", "Éditeur non valide : \"{}\""),
+	("No editor configured", "Aucun éditeur configuré"),
+	(
+		"Please see the git \"core.editor\" configuration for details",
+		"Consultez la configuration git \"core.editor\" pour plus de détails",
+	),
+	(
+		"Editor returned a non-zero exit status",
+		"L'éditeur a renvoyé un code de sortie non nul",
+	),
+	("The edited rebase file is not valid", "Le fichier de rebase modifié n'est pas valide"),
+	// settings
+	(
+		"Enter or Left/Right to change a value, {} to save changes to the global config, {} to save changes to the \
+		 repository config, {} to show diagnostics, {} to close",
+		"Entrée ou Gauche/Droite pour changer une valeur, {} pour enregistrer dans la configuration globale, {} pour \
+		 enregistrer dans la configuration du dépôt, {} pour afficher les diagnostics, {} pour fermer",
+	),
+	("modified", "modifié"),
+	("default", "par défaut"),
+	("file", "fichier"),
+	("system", "système"),
+	("global", "global"),
+	("local", "local"),
+	("environment", "environnement"),
+	("command line", "ligne de commande"),
+	("Unable to save settings", "Impossible d'enregistrer les paramètres"),
+	(
+		"{} is set by the {}, and cannot be saved",
+		"{} est défini par la source « {} », et ne peut pas être enregistré",
+	),
+	(
+		"Saved the settings to the global config",
+		"Paramètres enregistrés dans la configuration globale",
+	),
+	(
+		"Saved the settings to the repository config",
+		"Paramètres enregistrés dans la configuration du dépôt",
+	),
+	// notification history
+	("Notifications", "Notifications"),
+	("No notifications", "Aucune notification"),
+	(
+		"This feature is not included in this build",
+		"Cette fonctionnalité n'est pas incluse dans cette version",
+	),
+	// review changes
+	(
+		"{} moved, {} changed, {} added, {} removed",
+		"Déplacées : {}, modifiées : {}, ajoutées : {}, supprimées : {}",
+	),
+	(
+		"Press {} to rebase, or {} to return to the list",
+		"Appuyez sur {} pour rebaser, ou sur {} pour revenir à la liste",
+	),
+	("The todo file has not been changed", "Le fichier todo n'a pas été modifié"),
+	("(was {})", "(était {})"),
+	// autosquash preview
+	(
+		"There are no fixup or squash commits to move",
+		"Il n'y a aucun commit fixup ou squash à déplacer",
+	),
+	(
+		"{} fixup and squash commits are moved after the commits they apply to",
+		"{} commits fixup et squash sont déplacés après les commits auxquels ils s'appliquent",
+	),
+	(
+		"Press {} to apply, or {} to return to the list",
+		"Appuyez sur {} pour appliquer, ou sur {} pour revenir à la liste",
+	),
+	("(was {} on line {})", "(était {} à la ligne {})"),
+	// squash message
+	(
+		"The selected line is not in a chain of squash commits",
+		"La ligne sélectionnée n'est pas dans une chaîne de commits squash",
+	),
+	(
+		"Unable to load the messages of the squashed commits",
+		"Impossible de charger les messages des commits fusionnés",
+	),
+	(
+		"This is a combination of {} commits.",
+		"Ceci est la combinaison de {} commits.",
+	),
+	("This is the 1st commit message:", "Ceci est le 1er message de commit :"),
+	("This is the commit message #{}:", "Ceci est le message de commit numéro {} :"),
+	(
+		"The commit message #{} will be skipped:",
+		"Le message de commit numéro {} sera ignoré :",
+	),
+	(
+		"The message that Git asks for when the commits are squashed",
+		"Le message que Git demande lorsque les commits sont fusionnés",
+	),
+	(
+		"Press {} to edit the message, or {} to return to the list",
+		"Appuyez sur {} pour modifier le message, ou sur {} pour revenir à la liste",
+	),
+	("Unable to write {}", "Impossible d'écrire {}"),
+	("Unable to read {}", "Impossible de lire {}"),
+	(
+		"The message is empty, so the commits are not changed",
+		"Le message est vide, les commits ne sont donc pas modifiés",
+	),
+	// statistics
+	("Commits: {}", "Commits : {}"),
+	("Exec lines: {}", "Lignes exec : {}"),
+	("Break lines: {}", "Lignes break : {}"),
+	(
+		"Loading the changed files of {} commits",
+		"Chargement des fichiers modifiés de {} commits",
+	),
+	("Possible conflicts: {}", "Conflits possibles : {}"),
+	(
+		"  Commits that change a file that an earlier commit also changes",
+		"  Commits qui modifient un fichier qu'un commit précédent modifie aussi",
+	),
+	(
+		"Commits per changed top-level directory:",
+		"Commits par répertoire de premier niveau modifié :",
+	),
+	(
+		"The changed files of the commits are not available",
+		"Les fichiers modifiés des commits ne sont pas disponibles",
+	),
+	// path filter
+	("Path: ", "Chemin : "),
+	(
+		"Show the commits that change a file that matches a path, or a glob with *, ** and ?",
+		"Afficher les commits qui modifient un fichier correspondant à un chemin, ou à un motif avec *, ** et ?",
+	),
+	("Commits that change {}: {}", "Commits qui modifient {} : {}"),
+	(
+		"Commits that change the files of {}: {}",
+		"Commits qui modifient les fichiers de {} : {}",
+	),
+	(
+		"Press Enter to select the commit in the list, or any other key to return to the list",
+		"Appuyez sur Entrée pour sélectionner le commit dans la liste, ou sur une autre touche pour revenir à la liste",
+	),
+	// setup wizard
+	(
+		"Welcome to Git Interactive Rebase Tool!",
+		"Bienvenue dans Git Interactive Rebase Tool !",
+	),
+	(
+		"Choices are saved to your global git config, and are used the next time the tool is started.",
+		"Les choix sont enregistrés dans votre configuration git globale, et sont utilisés au prochain démarrage de \
+		 l'outil.",
+	),
+	("Select a key binding preset:", "Choisissez un ensemble de raccourcis :"),
+	(
+		"Default, arrow keys for movement",
+		"Par défaut, les flèches pour se déplacer",
+	),
+	(
+		"Vim, h/j/k/l for movement and J/K to move selected lines",
+		"Vim, h/j/k/l pour se déplacer et J/K pour déplacer les lignes sélectionnées",
+	),
+	(
+		"Skip setup and do not ask again",
+		"Passer la configuration et ne plus demander",
+	),
+	("Select a theme:", "Choisissez un thème :"),
+	(
+		"Default, for dark terminal backgrounds",
+		"Par défaut, pour les terminaux à fond sombre",
+	),
+	(
+		"Light, for light terminal backgrounds",
+		"Clair, pour les terminaux à fond clair",
+	),
+	(
+		"Monochrome, using the terminal colors",
+		"Monochrome, avec les couleurs du terminal",
+	),
+	(
+		"High contrast, with bright colors",
+		"Contraste élevé, avec des couleurs vives",
+	),
+	(
+		"Deuteranopia, for green color blindness, with blue and orange in place of green and red",
+		"Deutéranopie, pour le daltonisme du vert, avec du bleu et de l'orange à la place du vert et du rouge",
+	),
+	(
+		"Protanopia, for red color blindness, with blue and yellow in place of green and red",
+		"Protanopie, pour le daltonisme du rouge, avec du bleu et du jaune à la place du vert et du rouge",
+	),
+	(
+		"Unable to save setup choices",
+		"Impossible d'enregistrer les choix de configuration",
+	),
+	// dates
+	("in the future", "dans le futur"),
+	("{} second ago", "il y a {} seconde"),
+	("{} seconds ago", "il y a {} secondes"),
+	("{} minute ago", "il y a {} minute"),
+	("{} minutes ago", "il y a {} minutes"),
+	("{} hour ago", "il y a {} heure"),
+	("{} hours ago", "il y a {} heures"),
+	("{} day ago", "il y a {} jour"),
+	("{} days ago", "il y a {} jours"),
+	("{} week ago", "il y a {} semaine"),
+	("{} weeks ago", "il y a {} semaines"),
+	("{} month ago", "il y a {} mois"),
+	("{} months ago", "il y a {} mois"),
+	("{} year ago", "il y a {} an"),
+	("{} years ago", "il y a {} ans"),
+	// tutorial
+	("Tutorial {}/{}: ", "Tutoriel {}/{} : "),
+	(
+		"Move \"{}\" to the top of the list: select it with {}, then move it up with {}",
+		"Déplacez \"{}\" en haut de la liste : sélectionnez-le avec {}, puis montez-le avec {}",
+	),
+	(
+		"Squash \"{}\" into the commit above it: select it and press {}, or {} to also discard its message",
+		"Fusionnez \"{}\" dans le commit au-dessus : sélectionnez-le et appuyez sur {}, ou sur {} pour aussi \
+		 abandonner son message",
+	),
+	(
+		"Reword \"{}\": select it and press {}, to change the message of the commit during the rebase",
+		"Reformulez \"{}\" : sélectionnez-le et appuyez sur {}, pour changer le message du commit pendant le rebase",
+	),
+	(
+		"Drop both of the debugging commits at once: press {} to start visual mode, extend the selection with {}, \
+		 then press {}",
+		"Supprimez les deux commits de débogage en une fois : appuyez sur {} pour passer en mode visuel, étendez la \
+		 sélection avec {}, puis appuyez sur {}",
+	),
+	(
+		"Done! Press {} to finish the tutorial, or {} to see all of the keys. No repository is changed.",
+		"Terminé ! Appuyez sur {} pour finir le tutoriel, ou sur {} pour voir toutes les touches. Aucun dépôt \
+		 n'est modifié.",
+	),
+	// window size error
+	(
+		"Window too small, increase height to continue",
+		"Fenêtre trop petite, augmentez la hauteur pour continuer",
+	),
+	("Window too small", "Fenêtre trop petite"),
+	("Size!", "Taille !"),
+];
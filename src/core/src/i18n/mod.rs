@@ -0,0 +1,288 @@
+mod french;
+
+use std::{collections::HashMap, env::var, fmt::Display, sync::RwLock};
+
+use lazy_static::lazy_static;
+
+lazy_static! {
+	static ref LOCALE: RwLock<Locale> = RwLock::new(Locale::English);
+	static ref FRENCH: HashMap<&'static str, &'static str> = french::MESSAGES.iter().copied().collect();
+}
+
+/// The language of the messages of the interface.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(crate) enum Locale {
+	English,
+	French,
+}
+
+impl Locale {
+	/// Get the locale from the value of a locale environment variable, such as `fr_CA.UTF-8`, falling back to English
+	/// for a language without a translation.
+	pub(crate) fn from_name(name: &str) -> Self {
+		let language = name
+			.split(['_', '-', '.', '@'])
+			.next()
+			.unwrap_or_default()
+			.to_lowercase();
+		match language.as_str() {
+			"fr" => Self::French,
+			_ => Self::English,
+		}
+	}
+
+	/// Get the locale of the messages from the environment, using the same precedence as `gettext`, where `LC_ALL`
+	/// overrides `LC_MESSAGES`, which overrides `LANG`.
+	pub(crate) fn from_env() -> Self {
+		["LC_ALL", "LC_MESSAGES", "LANG"]
+			.iter()
+			.find_map(|name| var(name).ok().filter(|value| !value.is_empty()))
+			.map_or(Self::English, |name| Self::from_name(name.as_str()))
+	}
+
+	fn messages(self) -> Option<&'static HashMap<&'static str, &'static str>> {
+		match self {
+			Self::English => None,
+			Self::French => Some(&FRENCH),
+		}
+	}
+
+	/// Translate a message, the English message is returned when the message has no translation.
+	pub(crate) fn translate(self, message: &'static str) -> &'static str {
+		self.messages()
+			.and_then(|messages| messages.get(message).copied())
+			.unwrap_or(message)
+	}
+}
+
+// tests always use English, so that the rendered output does not depend on the locale of the environment
+#[cfg(not(test))]
+fn environment_locale() -> Locale {
+	Locale::from_env()
+}
+
+#[cfg(test)]
+const fn environment_locale() -> Locale {
+	Locale::English
+}
+
+/// Use the locale of the environment for the messages of the interface. This must be called before the modules are
+/// created, since most messages are translated when a module is created.
+pub(crate) fn init() {
+	let locale = environment_locale();
+	log::info!(target: "i18n", "Using the {:?} locale", locale);
+	if let Ok(mut current) = LOCALE.write() {
+		*current = locale;
+	}
+}
+
+fn locale() -> Locale {
+	LOCALE.read().map_or(Locale::English, |locale| *locale)
+}
+
+/// Translate a message to the current locale.
+pub(crate) fn translate(message: &'static str) -> &'static str {
+	locale().translate(message)
+}
+
+/// Replace the placeholders of a message with the arguments. A `{}` placeholder is replaced by the next argument, and
+/// `{0}`, `{1}` and so on by the argument at the index, so a translation can change the order of the arguments.
+pub(crate) fn format_message(message: &str, arguments: &[&dyn Display]) -> String {
+	let mut formatted = String::with_capacity(message.len());
+	let mut next_argument = 0;
+	let mut rest = message;
+	while let Some(start) = rest.find('{') {
+		let (before, placeholder) = rest.split_at(start);
+		formatted.push_str(before);
+		let end = placeholder.find('}');
+		let index = end.and_then(|name_end| {
+			let name = placeholder.get(1..name_end).unwrap_or_default();
+			if name.is_empty() {
+				next_argument += 1;
+				Some(next_argument - 1)
+			}
+			else {
+				name.parse::<usize>().ok()
+			}
+		});
+		match (end, index.and_then(|index| arguments.get(index))) {
+			(Some(placeholder_end), Some(argument)) => {
+				formatted.push_str(argument.to_string().as_str());
+				rest = placeholder.get(placeholder_end + 1..).unwrap_or_default();
+			},
+			_ => {
+				formatted.push('{');
+				rest = placeholder.get(1..).unwrap_or_default();
+			},
+		}
+	}
+	formatted.push_str(rest);
+	formatted
+}
+
+/// Translate a message to the current locale, and replace the placeholders with the arguments.
+pub(crate) fn translate_format(message: &'static str, arguments: &[&dyn Display]) -> String {
+	format_message(translate(message), arguments)
+}
+
+#[cfg(test)]
+mod tests {
+	use std::{
+		env::{remove_var, set_var},
+		fs::{read_dir, read_to_string},
+		path::Path,
+	};
+
+	use rstest::rstest;
+	use serial_test::serial;
+
+	use super::*;
+
+	#[rstest]
+	#[case::french("fr", Locale::French)]
+	#[case::french_region("fr_CA.UTF-8", Locale::French)]
+	#[case::french_modifier("fr_FR@euro", Locale::French)]
+	#[case::french_uppercase("FR-fr", Locale::French)]
+	#[case::english("en_CA.UTF-8", Locale::English)]
+	#[case::posix("C", Locale::English)]
+	#[case::no_translation("de_DE.UTF-8", Locale::English)]
+	#[case::empty("", Locale::English)]
+	fn locale_from_name(#[case] name: &str, #[case] expected: Locale) {
+		assert_eq!(Locale::from_name(name), expected);
+	}
+
+	#[rstest]
+	#[case::lang(None, None, Some("fr_CA.UTF-8"), Locale::French)]
+	#[case::lc_messages_overrides_lang(None, Some("fr_CA.UTF-8"), Some("en_CA.UTF-8"), Locale::French)]
+	#[case::lc_all_overrides_lc_messages(Some("C"), Some("fr_CA.UTF-8"), None, Locale::English)]
+	#[case::empty_lc_all(Some(""), None, Some("fr_CA.UTF-8"), Locale::French)]
+	#[case::none(None, None, None, Locale::English)]
+	#[serial]
+	fn locale_from_env(
+		#[case] lc_all: Option<&str>,
+		#[case] lc_messages: Option<&str>,
+		#[case] lang: Option<&str>,
+		#[case] expected: Locale,
+	) {
+		let names = ["LC_ALL", "LC_MESSAGES", "LANG"];
+		let previous = names.map(|name| var(name).ok());
+		let set_env = |values: [Option<&str>; 3]| {
+			for (name, value) in names.iter().zip(values) {
+				match value {
+					Some(value) => set_var(name, value),
+					None => remove_var(name),
+				}
+			}
+		};
+		set_env([lc_all, lc_messages, lang]);
+		let locale = Locale::from_env();
+		set_env(previous.each_ref().map(Option::as_deref));
+		assert_eq!(locale, expected);
+	}
+
+	#[test]
+	fn translate_english() {
+		assert_eq!(Locale::English.translate("Show help"), "Show help");
+	}
+
+	#[test]
+	fn translate_french() {
+		assert_eq!(Locale::French.translate("Show help"), "Afficher l'aide");
+	}
+
+	#[test]
+	fn translate_without_translation() {
+		assert_eq!(Locale::French.translate("Not a message"), "Not a message");
+	}
+
+	#[test]
+	fn translate_uses_english_in_tests() {
+		init();
+		assert_eq!(translate("Show help"), "Show help");
+	}
+
+	#[rstest]
+	#[case::no_placeholders("message", "message")]
+	#[case::next("{} of {}", "first of second")]
+	#[case::indexed("{1} of {0}", "second of first")]
+	#[case::mixed("{} and {0}", "first and first")]
+	#[case::missing_argument("{} {} {}", "first second {}")]
+	#[case::not_a_placeholder("{name}", "{name}")]
+	#[case::unclosed("{", "{")]
+	#[case::unicode("é{}é", "éfirsté")]
+	fn format_message_arguments(#[case] message: &str, #[case] expected: &str) {
+		assert_eq!(format_message(message, &[&"first", &"second"]), expected);
+	}
+
+	// a missing placeholder in a translation would drop an argument, and an extra placeholder would show a raw `{}`
+	#[test]
+	fn french_placeholders_match() {
+		for &(english, translation) in french::MESSAGES {
+			assert_eq!(
+				format_message(english, &[&"0", &"1", &"2"])
+					.matches(char::is_numeric)
+					.count(),
+				format_message(translation, &[&"0", &"1", &"2"])
+					.matches(char::is_numeric)
+					.count(),
+				"{}",
+				english
+			);
+		}
+	}
+
+	#[test]
+	fn french_messages_unique() {
+		assert_eq!(FRENCH.len(), french::MESSAGES.len());
+	}
+
+	fn read_sources(directory: &Path, sources: &mut String) {
+		for entry in read_dir(directory).unwrap() {
+			let path = entry.unwrap().path();
+			if path.is_dir() {
+				if !path.ends_with("i18n") {
+					read_sources(path.as_path(), sources);
+				}
+			}
+			else if path.extension().map_or(false, |extension| extension == "rs") {
+				// long strings are split over lines that end with a backslash, which skips the new line and the
+				// leading whitespace of the next line
+				let mut is_continued = false;
+				for source_line in read_to_string(path).unwrap().lines() {
+					let line = if is_continued {
+						source_line.trim_start()
+					}
+					else {
+						source_line
+					};
+					is_continued = line.ends_with('\\');
+					match line.strip_suffix('\\') {
+						Some(continued) => sources.push_str(continued),
+						None => {
+							sources.push_str(line);
+							sources.push('\n');
+						},
+					}
+				}
+			}
+		}
+	}
+
+	// a message in the catalog that does not match a message in the source, for example after the message was changed,
+	// would never be translated
+	#[test]
+	fn french_messages_used() {
+		let mut sources = String::new();
+		read_sources(
+			Path::new(env!("CARGO_MANIFEST_DIR")).join("src").as_path(),
+			&mut sources,
+		);
+		for &(english, _) in french::MESSAGES {
+			assert!(
+				sources.contains(format!("{:?}", english).as_str()),
+				"\"{}\" is not used",
+				english
+			);
+		}
+	}
+}
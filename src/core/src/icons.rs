@@ -0,0 +1,128 @@
+use std::{env::var, path::Path};
+
+use todo_file::Action;
+
+// the icons are from Nerd Fonts, https://www.nerdfonts.com/cheat-sheet, which require a patched font
+const FILE_ICON: &str = "\u{f15b}";
+const FILE_ICONS: &[(&str, &str)] = &[
+	("c", "\u{e61e}"),
+	("cpp", "\u{e61d}"),
+	("css", "\u{e749}"),
+	("go", "\u{e626}"),
+	("h", "\u{e61e}"),
+	("html", "\u{e736}"),
+	("java", "\u{e738}"),
+	("js", "\u{e74e}"),
+	("json", "\u{e60b}"),
+	("lock", "\u{f023}"),
+	("md", "\u{f48a}"),
+	("py", "\u{e73c}"),
+	("rb", "\u{e739}"),
+	("rs", "\u{e7a8}"),
+	("sh", "\u{f489}"),
+	("toml", "\u{e615}"),
+	("ts", "\u{e628}"),
+	("txt", "\u{f15c}"),
+	("yaml", "\u{e615}"),
+	("yml", "\u{e615}"),
+];
+
+/// Is the terminal likely able to show icons. The font cannot be detected, so this only checks that the locale uses
+/// UTF-8 and that the terminal is not the Linux console, or a terminal without any capabilities.
+pub(crate) fn are_icons_supported() -> bool {
+	let locale = var("LC_ALL")
+		.ok()
+		.filter(|value| !value.is_empty())
+		.or_else(|| var("LC_CTYPE").ok().filter(|value| !value.is_empty()))
+		.or_else(|| var("LANG").ok())
+		.unwrap_or_default()
+		.to_lowercase();
+	let term = var("TERM").unwrap_or_default();
+	(locale.contains("utf-8") || locale.contains("utf8")) && term != "linux" && term != "dumb"
+}
+
+/// Get the icon for an action.
+pub(crate) const fn get_action_icon(action: Action) -> &'static str {
+	match action {
+		Action::Break => "\u{f04c}",
+		Action::Drop => "\u{f1f8}",
+		Action::Edit => "\u{f044}",
+		Action::Exec => "\u{f120}",
+		Action::Fixup => "\u{f0ad}",
+		Action::Label => "\u{f02b}",
+		Action::Merge => "\u{f126}",
+		Action::Noop => "\u{f05e}",
+		Action::Pick => "\u{f417}",
+		Action::Reset => "\u{f0e2}",
+		Action::Reword => "\u{f040}",
+		Action::Squash => "\u{f419}",
+	}
+}
+
+/// Get the icon for the type of a file, from the extension of the file.
+pub(crate) fn get_file_icon(path: &Path) -> &'static str {
+	let extension = path
+		.extension()
+		.and_then(|extension| extension.to_str())
+		.map(str::to_lowercase)
+		.unwrap_or_default();
+	FILE_ICONS
+		.iter()
+		.find(|&&(file_extension, _)| file_extension == extension)
+		.map_or(FILE_ICON, |&(_, icon)| icon)
+}
+
+#[cfg(test)]
+mod tests {
+	use std::env::{remove_var, set_var};
+
+	use rstest::rstest;
+	use serial_test::serial;
+
+	use super::*;
+
+	const NAMES: [&str; 4] = ["LC_ALL", "LC_CTYPE", "LANG", "TERM"];
+
+	fn set_env(values: [Option<&str>; 4]) {
+		for (name, value) in NAMES.iter().zip(values) {
+			match value {
+				Some(value) => set_var(name, value),
+				None => remove_var(name),
+			}
+		}
+	}
+
+	#[rstest]
+	#[case::utf8_lang(None, None, Some("en_CA.UTF-8"), Some("xterm-256color"), true)]
+	#[case::utf8_lowercase(None, None, Some("en_CA.utf8"), Some("xterm"), true)]
+	#[case::no_locale(None, None, None, Some("xterm"), false)]
+	#[case::posix_locale(None, None, Some("C"), Some("xterm"), false)]
+	#[case::lc_all_overrides_lang(Some("C"), None, Some("en_CA.UTF-8"), Some("xterm"), false)]
+	#[case::lc_ctype_overrides_lang(None, Some("en_CA.UTF-8"), Some("C"), Some("xterm"), true)]
+	#[case::empty_lc_all(Some(""), None, Some("en_CA.UTF-8"), Some("xterm"), true)]
+	#[case::linux_console(None, None, Some("en_CA.UTF-8"), Some("linux"), false)]
+	#[case::dumb_terminal(None, None, Some("en_CA.UTF-8"), Some("dumb"), false)]
+	#[serial]
+	fn are_icons_supported_from_env(
+		#[case] lc_all: Option<&str>,
+		#[case] lc_ctype: Option<&str>,
+		#[case] lang: Option<&str>,
+		#[case] term: Option<&str>,
+		#[case] expected: bool,
+	) {
+		let previous = NAMES.map(|name| var(name).ok());
+		set_env([lc_all, lc_ctype, lang, term]);
+		let supported = are_icons_supported();
+		set_env(previous.each_ref().map(Option::as_deref));
+		assert_eq!(supported, expected);
+	}
+
+	#[rstest]
+	#[case::known("src/lib.rs", "\u{e7a8}")]
+	#[case::uppercase("README.MD", "\u{f48a}")]
+	#[case::unknown("image.png", FILE_ICON)]
+	#[case::no_extension("Makefile", FILE_ICON)]
+	fn get_file_icon_from_extension(#[case] path: &str, #[case] expected: &str) {
+		assert_eq!(get_file_icon(Path::new(path)), expected);
+	}
+}
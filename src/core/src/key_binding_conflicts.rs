@@ -0,0 +1,273 @@
+use config::KeyBindings;
+
+use crate::i18n::{translate, translate_format};
+
+type KeyBindingAccessor = fn(&KeyBindings) -> &Vec<String>;
+
+// The order of each mode matches the order that the key bindings are checked when reading events, so the first
+// action with a key is the one that is used. The help, undo and redo key bindings are handled before the other key
+// bindings of the list.
+const LIST_KEY_BINDINGS: &[(&str, KeyBindingAccessor)] = &[
+	("inputHelp", |key_bindings| &key_bindings.help),
+	("inputUndo", |key_bindings| &key_bindings.undo),
+	("inputRedo", |key_bindings| &key_bindings.redo),
+	("inputAbort", |key_bindings| &key_bindings.abort),
+	("inputAbortRebase", |key_bindings| &key_bindings.abort_rebase),
+	("inputActionBreak", |key_bindings| &key_bindings.action_break),
+	("inputActionDrop", |key_bindings| &key_bindings.action_drop),
+	("inputActionEdit", |key_bindings| &key_bindings.action_edit),
+	("inputActionFixup", |key_bindings| &key_bindings.action_fixup),
+	("inputActionPick", |key_bindings| &key_bindings.action_pick),
+	("inputActionReword", |key_bindings| &key_bindings.action_reword),
+	("inputActionSquash", |key_bindings| &key_bindings.action_squash),
+	("inputAutosquash", |key_bindings| &key_bindings.autosquash),
+	("inputBranchOut", |key_bindings| &key_bindings.branch_out),
+	("inputBreakEvery", |key_bindings| &key_bindings.break_every),
+	("inputClearMarks", |key_bindings| &key_bindings.clear_marks),
+	("inputCycleFixupOption", |key_bindings| &key_bindings.cycle_fixup_option),
+	("inputDescribeLine", |key_bindings| &key_bindings.describe_line),
+	("inputEdit", |key_bindings| &key_bindings.edit),
+	("inputFilterByPath", |key_bindings| &key_bindings.filter_by_path),
+	("inputForceAbort", |key_bindings| &key_bindings.force_abort),
+	("inputForceRebase", |key_bindings| &key_bindings.force_rebase),
+	("insertLine", |key_bindings| &key_bindings.insert_line),
+	("inputJumpToLine", |key_bindings| &key_bindings.jump_to_line),
+	("inputMarkMatching", |key_bindings| &key_bindings.mark_matching),
+	("inputMoveDown", |key_bindings| &key_bindings.move_down),
+	("inputMoveStepDown", |key_bindings| &key_bindings.move_down_step),
+	("inputMoveEnd", |key_bindings| &key_bindings.move_end),
+	("inputMoveHome", |key_bindings| &key_bindings.move_home),
+	("inputMoveLeft", |key_bindings| &key_bindings.move_left),
+	("inputMoveRight", |key_bindings| &key_bindings.move_right),
+	("inputMoveSelectionDown", |key_bindings| {
+		&key_bindings.move_selection_down
+	}),
+	("inputMoveSelectionUp", |key_bindings| &key_bindings.move_selection_up),
+	("inputMoveUp", |key_bindings| &key_bindings.move_up),
+	("inputMoveStepUp", |key_bindings| &key_bindings.move_up_step),
+	("inputNameGroup", |key_bindings| &key_bindings.name_group),
+	("inputOpenInExternalEditor", |key_bindings| {
+		&key_bindings.open_in_external_editor
+	}),
+	("inputOpenSettings", |key_bindings| &key_bindings.open_settings),
+	("inputQuickFix", |key_bindings| &key_bindings.quick_fix),
+	("inputQuitWithoutSaving", |key_bindings| &key_bindings.quit_without_saving),
+	("inputRebase", |key_bindings| &key_bindings.rebase),
+	("inputRemoveBreaks", |key_bindings| &key_bindings.remove_breaks),
+	("removeLine", |key_bindings| &key_bindings.remove_line),
+	("inputReverseLines", |key_bindings| &key_bindings.reverse_lines),
+	("inputShowCommit", |key_bindings| &key_bindings.show_commit),
+	("inputShowNotifications", |key_bindings| &key_bindings.show_notifications),
+	("inputShowRelatedCommits", |key_bindings| {
+		&key_bindings.show_related_commits
+	}),
+	("inputShowSquashMessage", |key_bindings| &key_bindings.show_squash_message),
+	("inputShowStatistics", |key_bindings| &key_bindings.show_statistics),
+	("inputSkipCommit", |key_bindings| &key_bindings.skip_commit),
+	("inputSortLines", |key_bindings| &key_bindings.sort_lines),
+	("inputStartMove", |key_bindings| &key_bindings.start_move),
+	("inputTestCommit", |key_bindings| &key_bindings.test_commit),
+	("inputToggleAllFolds", |key_bindings| &key_bindings.toggle_all_folds),
+	("inputToggleGroup", |key_bindings| &key_bindings.toggle_group),
+	("inputToggleMark", |key_bindings| &key_bindings.toggle_mark),
+	("inputToggleStashInfo", |key_bindings| &key_bindings.toggle_stash_info),
+	("inputToggleVisualMode", |key_bindings| &key_bindings.toggle_visual_mode),
+];
+
+const CONFIRM_KEY_BINDINGS: &[(&str, KeyBindingAccessor)] = &[
+	("inputConfirmYes", |key_bindings| &key_bindings.confirm_yes),
+	("inputConfirmNo", |key_bindings| &key_bindings.confirm_no),
+];
+
+// The help key binding is handled before the other key bindings of the show commit mode.
+const SHOW_COMMIT_KEY_BINDINGS: &[(&str, KeyBindingAccessor)] = &[
+	("inputHelp", |key_bindings| &key_bindings.help),
+	("inputShowDiff", |key_bindings| &key_bindings.show_diff),
+	("inputShowFile", |key_bindings| &key_bindings.show_file),
+	("inputShowSourceCommit", |key_bindings| {
+		&key_bindings.show_source_commit
+	}),
+];
+
+#[cfg(feature = "settings-editor")]
+const SETTINGS_KEY_BINDINGS: &[(&str, KeyBindingAccessor)] = &[
+	("inputAbort", |key_bindings| &key_bindings.abort),
+	("inputMoveUp", |key_bindings| &key_bindings.move_up),
+	("inputMoveDown", |key_bindings| &key_bindings.move_down),
+	("inputMoveStepUp", |key_bindings| &key_bindings.move_up_step),
+	("inputMoveStepDown", |key_bindings| &key_bindings.move_down_step),
+	("inputMoveHome", |key_bindings| &key_bindings.move_home),
+	("inputMoveEnd", |key_bindings| &key_bindings.move_end),
+	("inputMoveLeft", |key_bindings| &key_bindings.move_left),
+	("inputMoveRight", |key_bindings| &key_bindings.move_right),
+	("inputSaveGlobalConfig", |key_bindings| &key_bindings.save_global_config),
+	("inputSaveRepositoryConfig", |key_bindings| {
+		&key_bindings.save_repository_config
+	}),
+	("inputShowDiagnostics", |key_bindings| &key_bindings.show_diagnostics),
+];
+
+const MODES: &[(&str, &[(&str, KeyBindingAccessor)])] = &[
+	("list", LIST_KEY_BINDINGS),
+	("show commit", SHOW_COMMIT_KEY_BINDINGS),
+	("confirm", CONFIRM_KEY_BINDINGS),
+	#[cfg(feature = "settings-editor")]
+	("settings", SETTINGS_KEY_BINDINGS),
+];
+
+/// A key that is bound to more than one action in the same mode.
+#[derive(Debug, PartialEq)]
+pub(crate) struct KeyBindingConflict {
+	key: String,
+	mode: &'static str,
+	actions: Vec<&'static str>,
+}
+
+impl KeyBindingConflict {
+	/// The action that is performed when the key is pressed.
+	pub(crate) fn used_action(&self) -> &'static str {
+		self.actions[0]
+	}
+
+	pub(crate) fn description(&self) -> String {
+		translate_format("The key \"{}\" is bound to {} in the {} mode, only {} is used", &[
+			&self.key,
+			&self.actions.join(", "),
+			&translate(self.mode),
+			&self.used_action(),
+		])
+	}
+}
+
+pub(crate) fn find_key_binding_conflicts(key_bindings: &KeyBindings) -> Vec<KeyBindingConflict> {
+	let mut conflicts: Vec<KeyBindingConflict> = vec![];
+	for &(mode, bindings) in MODES {
+		let mode_start = conflicts.len();
+		for &(action, accessor) in bindings {
+			for key in accessor(key_bindings) {
+				match conflicts[mode_start..].iter_mut().find(|conflict| &conflict.key == key) {
+					Some(conflict) => {
+						if !conflict.actions.contains(&action) {
+							conflict.actions.push(action);
+						}
+					},
+					None => {
+						conflicts.push(KeyBindingConflict {
+							key: key.clone(),
+							mode,
+							actions: vec![action],
+						});
+					},
+				}
+			}
+		}
+	}
+	conflicts.retain(|conflict| conflict.actions.len() > 1);
+	conflicts
+}
+
+#[cfg(test)]
+mod tests {
+	use config::Config;
+	use git::Config as GitConfig;
+	use rstest::rstest;
+	use tempfile::tempdir;
+
+	use super::*;
+	use crate::modules::KeyBindingPreset;
+
+	#[test]
+	fn no_conflicts_with_defaults() {
+		assert!(find_key_binding_conflicts(&Config::new().key_bindings).is_empty());
+	}
+
+	#[rstest]
+	#[case::default(KeyBindingPreset::Default)]
+	#[case::skip(KeyBindingPreset::Skip)]
+	#[case::vim(KeyBindingPreset::Vim)]
+	fn no_conflicts_with_preset(#[case] preset: KeyBindingPreset) {
+		let directory = tempdir().unwrap();
+		let mut git_config = GitConfig::open(directory.path().join("config").as_path()).unwrap();
+		for &(name, value) in preset.config_values() {
+			git_config.set_str(name, value).unwrap();
+		}
+		let config = Config::try_from(&git_config).unwrap();
+		assert_eq!(find_key_binding_conflicts(&config.key_bindings), vec![]);
+	}
+
+	#[test]
+	fn conflict_in_list() {
+		let mut key_bindings = Config::new().key_bindings;
+		key_bindings.action_pick = vec![String::from("d")];
+		let conflicts = find_key_binding_conflicts(&key_bindings);
+		assert_eq!(conflicts.len(), 1);
+		let conflict = &conflicts[0];
+		assert_eq!(conflict.key, "d");
+		assert_eq!(conflict.mode, "list");
+		assert_eq!(conflict.actions, &["inputActionDrop", "inputActionPick"]);
+		assert_eq!(conflict.used_action(), "inputActionDrop");
+		assert_eq!(
+			conflict.description(),
+			"The key \"d\" is bound to inputActionDrop, inputActionPick in the list mode, only inputActionDrop is used"
+		);
+	}
+
+	#[test]
+	fn conflict_with_help() {
+		let mut key_bindings = Config::new().key_bindings;
+		key_bindings.abort = vec![String::from("q"), String::from("?")];
+		let conflicts = find_key_binding_conflicts(&key_bindings);
+		assert_eq!(conflicts.len(), 1);
+		assert_eq!(conflicts[0].actions, &["inputHelp", "inputAbort"]);
+	}
+
+	#[test]
+	fn conflict_with_three_actions() {
+		let mut key_bindings = Config::new().key_bindings;
+		key_bindings.action_pick = vec![String::from("h")];
+		key_bindings.action_drop = vec![String::from("h")];
+		key_bindings.rebase = vec![String::from("h")];
+		let conflicts = find_key_binding_conflicts(&key_bindings);
+		assert_eq!(conflicts.len(), 1);
+		assert_eq!(conflicts[0].actions, &[
+			"inputActionDrop",
+			"inputActionPick",
+			"inputRebase"
+		]);
+	}
+
+	#[test]
+	fn same_key_twice_in_action() {
+		let mut key_bindings = Config::new().key_bindings;
+		key_bindings.action_pick = vec![String::from("p"), String::from("p")];
+		assert!(find_key_binding_conflicts(&key_bindings).is_empty());
+	}
+
+	#[test]
+	fn conflict_in_confirm() {
+		let mut key_bindings = Config::new().key_bindings;
+		key_bindings.confirm_no = vec![String::from("n"), String::from("y")];
+		let conflicts = find_key_binding_conflicts(&key_bindings);
+		assert_eq!(conflicts.len(), 1);
+		assert_eq!(conflicts[0].mode, "confirm");
+		assert_eq!(conflicts[0].actions, &["inputConfirmYes", "inputConfirmNo"]);
+	}
+
+	#[cfg(feature = "settings-editor")]
+	#[test]
+	fn conflict_in_settings() {
+		let mut key_bindings = Config::new().key_bindings;
+		key_bindings.show_diagnostics = vec![String::from("s")];
+		let conflicts = find_key_binding_conflicts(&key_bindings);
+		assert_eq!(conflicts.len(), 1);
+		assert_eq!(conflicts[0].mode, "settings");
+		assert_eq!(conflicts[0].actions, &["inputSaveGlobalConfig", "inputShowDiagnostics"]);
+	}
+
+	#[test]
+	fn same_key_in_different_modes() {
+		let mut key_bindings = Config::new().key_bindings;
+		key_bindings.confirm_yes = vec![String::from("w")];
+		assert!(find_key_binding_conflicts(&key_bindings).is_empty());
+	}
+}
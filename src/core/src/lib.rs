@@ -89,17 +89,44 @@
 
 mod arguments;
 mod components;
+mod crash;
+mod demo;
+mod dates;
+mod diagnostics;
+mod exec_template;
+mod event_bus;
 mod exit;
+#[cfg(feature = "fuzz")]
+#[doc(hidden)]
+pub mod fuzz;
+mod git_backend;
 mod help;
+mod i18n;
+mod icons;
+mod key_binding_conflicts;
 mod license;
+mod logger;
 mod module;
 mod modules;
+mod notifications;
 mod process;
+mod range_diff;
 mod run;
+mod sandbox;
+mod script;
+mod shell_script;
+mod signals;
+mod startup_profile;
+mod tasks;
 #[cfg(all(unix, test))]
 mod tests;
 #[cfg(test)]
 pub mod testutil;
+mod timers;
+mod todo_json;
+#[cfg(feature = "tutorial")]
+mod tutorial;
+mod ui_state;
 mod version;
 
 use std::ffi::OsString;
@@ -119,6 +146,17 @@ pub fn run(os_args: Vec<OsString>) -> Exit {
 				Mode::Help => help::run(),
 				Mode::Version => version::run(),
 				Mode::License => license::run(),
+				Mode::Diagnostics => diagnostics::run(&args),
+				#[cfg(feature = "tutorial")]
+				Mode::Tutorial => tutorial::run(&args),
+				#[cfg(not(feature = "tutorial"))]
+				Mode::Tutorial => {
+					Exit::new(
+						module::ExitStatus::StateError,
+						"The tutorial is not included in this build, it is included with the tutorial feature",
+					)
+				},
+				Mode::Demo(ref fixture) => demo::run(&args, fixture.as_str()),
 				Mode::Normal => run::run(&args),
 			}
 		},
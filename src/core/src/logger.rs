@@ -0,0 +1,204 @@
+use std::{
+	fs::File,
+	io::Write,
+	path::Path,
+	sync::Mutex,
+	time::Instant,
+};
+
+use anyhow::{anyhow, Result};
+use lazy_static::lazy_static;
+use log::{Level, Log, Metadata, Record};
+
+lazy_static! {
+	static ref LOGGER: Logger = Logger {
+		output: Mutex::new(None),
+	};
+}
+
+// the file that the messages are written to, with the time that the logging started
+struct Output {
+	start: Instant,
+	writer: Box<dyn Write + Send>,
+}
+
+// the logger of the `log` macros, which writes the messages to the output while one is set
+struct Logger {
+	output: Mutex<Option<Output>>,
+}
+
+impl Log for Logger {
+	fn enabled(&self, metadata: &Metadata<'_>) -> bool {
+		metadata.level() <= log::max_level()
+	}
+
+	fn log(&self, record: &Record<'_>) {
+		if !self.enabled(record.metadata()) {
+			return;
+		}
+		if let Ok(mut guard) = self.output.lock() {
+			if let Some(ref mut output) = *guard {
+				// a failure to write a log message should never stop the application
+				let _result = writeln!(
+					output.writer,
+					"{:>10.3} {:<5} {}: {}",
+					output.start.elapsed().as_secs_f64() * 1000.0,
+					record.level(),
+					record.target(),
+					record.args()
+				)
+				.and_then(|_| output.writer.flush());
+			}
+		}
+	}
+
+	fn flush(&self) {
+		if let Ok(mut guard) = self.output.lock() {
+			if let Some(ref mut output) = *guard {
+				let _result = output.writer.flush();
+			}
+		}
+	}
+}
+
+/// Parse the level of detail of the log messages, where each level includes the messages of the levels before it.
+pub(crate) fn parse_level(level: &str) -> Result<Level> {
+	level.parse::<Level>().map_err(|_err| {
+		anyhow!(
+			"\"{}\" is not one of: error, warn, info, debug or trace",
+			level
+		)
+	})
+}
+
+/// Start writing log messages, of the level and lower, to the file at the path. The file is truncated.
+pub(crate) fn init(path: &Path, level: Level) -> Result<()> {
+	let file = File::create(path).map_err(|err| anyhow!(err).context(format!("Unable to open {}", path.display())))?;
+	set_writer(Box::new(file), level);
+	Ok(())
+}
+
+fn set_writer(writer: Box<dyn Write + Send>, level: Level) {
+	// the logger can only be set once, and is already set when logging is started again
+	let _result = log::set_logger(&*LOGGER);
+	if let Ok(mut output) = LOGGER.output.lock() {
+		*output = Some(Output {
+			start: Instant::now(),
+			writer,
+		});
+		log::set_max_level(level.to_level_filter());
+	}
+}
+
+#[cfg(test)]
+pub(crate) fn stop() {
+	log::set_max_level(log::LevelFilter::Off);
+	*LOGGER.output.lock().unwrap() = None;
+}
+
+#[cfg(test)]
+mod tests {
+	use std::sync::Arc;
+
+	use rstest::rstest;
+
+	use super::*;
+
+	#[derive(Clone)]
+	struct Buffer(Arc<Mutex<Vec<u8>>>);
+
+	impl Write for Buffer {
+		fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+			self.0.lock().unwrap().write(buf)
+		}
+
+		fn flush(&mut self) -> std::io::Result<()> {
+			Ok(())
+		}
+	}
+
+	impl Buffer {
+		// other tests can log while the logger is set, so only the lines of the test target are returned
+		fn lines(&self) -> Vec<String> {
+			String::from_utf8(self.0.lock().unwrap().clone())
+				.unwrap()
+				.lines()
+				.filter(|line| line.contains(" test: "))
+				.map(|line| String::from(line.trim_start().split_once(' ').unwrap().1))
+				.collect()
+		}
+	}
+
+	// a message that can only be logged when it is built, which is not done for a level that is not logged
+	fn unbuilt_message() -> String {
+		panic!("message should not be built")
+	}
+
+	fn with_logger<F: FnOnce(&Buffer)>(level: Level, callback: F) {
+		let buffer = Buffer(Arc::new(Mutex::new(vec![])));
+		set_writer(Box::new(buffer.clone()), level);
+		callback(&buffer);
+		stop();
+	}
+
+	#[rstest]
+	#[case::error("error", Level::Error)]
+	#[case::warn("warn", Level::Warn)]
+	#[case::info("info", Level::Info)]
+	#[case::debug("debug", Level::Debug)]
+	#[case::trace("trace", Level::Trace)]
+	#[case::uppercase("DEBUG", Level::Debug)]
+	fn parse_level_valid(#[case] value: &str, #[case] expected: Level) {
+		assert_eq!(parse_level(value).unwrap(), expected);
+	}
+
+	#[test]
+	fn parse_level_invalid() {
+		assert_eq!(
+			parse_level("verbose").unwrap_err().to_string(),
+			"\"verbose\" is not one of: error, warn, info, debug or trace"
+		);
+	}
+
+	#[test]
+	#[serial_test::serial]
+	fn log_without_logger() {
+		stop();
+		log::error!(target: "test", "{}", unbuilt_message());
+	}
+
+	#[test]
+	#[serial_test::serial]
+	fn log_at_level() {
+		with_logger(Level::Info, |buffer| {
+			log::error!(target: "test", "first");
+			log::info!(target: "test", "second");
+			log::debug!(target: "test", "{}", unbuilt_message());
+			assert_eq!(buffer.lines(), vec!["ERROR test: first", "INFO  test: second"]);
+		});
+	}
+
+	#[test]
+	#[serial_test::serial]
+	fn init_invalid_path() {
+		let directory = tempfile::tempdir().unwrap();
+		let path = directory.path().join("missing").join("girt.log");
+		assert!(init(path.as_path(), Level::Info)
+			.unwrap_err()
+			.to_string()
+			.starts_with("Unable to open "));
+	}
+
+	#[test]
+	#[serial_test::serial]
+	fn init_file() {
+		let directory = tempfile::tempdir().unwrap();
+		let path = directory.path().join("girt.log");
+		init(path.as_path(), Level::Trace).unwrap();
+		log::trace!(target: "test", "message");
+		stop();
+		assert!(std::fs::read_to_string(path)
+			.unwrap()
+			.contains(" TRACE test: message\n"));
+	}
+}
@@ -1,26 +1,38 @@
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub enum ExitStatus {
 	Abort,
+	Cancel,
 	ConfigError,
 	FileReadError,
 	FileWriteError,
 	Good,
 	StateError,
 	Kill,
+	Quit,
+	Signal(i32),
 }
 
 impl ExitStatus {
 	pub const fn to_code(self) -> i32 {
 		match self {
-			Self::Abort => 5,
+			Self::Abort | Self::Cancel => 5,
 			Self::ConfigError => 1,
 			Self::FileReadError => 2,
 			Self::FileWriteError => 3,
 			Self::Good => 0,
 			Self::StateError => 4,
 			Self::Kill => 6,
+			Self::Quit => 0,
+			// like a shell, the code of a process that was stopped by a signal is 128 and the number of the signal
+			Self::Signal(signal) => 128 + signal,
 		}
 	}
+
+	/// Whether the todo file is written on exit, which it is not for a cancel, a kill, a quit or a signal, that keep the
+	/// todo file that was opened.
+	pub const fn writes_todo_file(self) -> bool {
+		!matches!(self, Self::Cancel | Self::Kill | Self::Quit | Self::Signal(_))
+	}
 }
 
 #[cfg(test)]
@@ -31,13 +43,28 @@ mod tests {
 
 	#[rstest]
 	#[case::abort(ExitStatus::Abort, 5)]
+	#[case::cancel(ExitStatus::Cancel, 5)]
 	#[case::config_error(ExitStatus::ConfigError, 1)]
 	#[case::file_read_error(ExitStatus::FileReadError, 2)]
 	#[case::file_write_error(ExitStatus::FileWriteError, 3)]
 	#[case::good(ExitStatus::Good, 0)]
 	#[case::state_error(ExitStatus::StateError, 4)]
 	#[case::kill(ExitStatus::Kill, 6)]
+	#[case::quit(ExitStatus::Quit, 0)]
+	#[case::signal_interrupt(ExitStatus::Signal(2), 130)]
+	#[case::signal_terminate(ExitStatus::Signal(15), 143)]
 	fn to_code(#[case] input: ExitStatus, #[case] expected: i32) {
 		assert_eq!(ExitStatus::to_code(input), expected);
 	}
+
+	#[rstest]
+	#[case::abort(ExitStatus::Abort, true)]
+	#[case::cancel(ExitStatus::Cancel, false)]
+	#[case::good(ExitStatus::Good, true)]
+	#[case::kill(ExitStatus::Kill, false)]
+	#[case::quit(ExitStatus::Quit, false)]
+	#[case::signal(ExitStatus::Signal(15), false)]
+	fn writes_todo_file(#[case] input: ExitStatus, #[case] expected: bool) {
+		assert_eq!(input.writes_todo_file(), expected);
+	}
 }
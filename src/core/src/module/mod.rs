@@ -1,14 +1,24 @@
 mod exit_status;
+mod module_context;
 mod modules;
 mod process_result;
 mod state;
 
 use anyhow::Error;
+use config::Config;
 use input::EventHandler;
 use todo_file::TodoFile;
 use view::{RenderContext, ViewData, ViewSender};
 
-pub(crate) use self::{exit_status::ExitStatus, modules::Modules, process_result::ProcessResult, state::State};
+use crate::event_bus::{BusEvent, Topic};
+
+pub(crate) use self::{
+	exit_status::ExitStatus,
+	module_context::ModuleContext,
+	modules::Modules,
+	process_result::ProcessResult,
+	state::State,
+};
 
 pub(crate) trait Module {
 	fn activate(&mut self, _rebase_todo: &TodoFile, _previous_state: State) -> ProcessResult {
@@ -27,4 +37,15 @@ pub(crate) trait Module {
 	) -> ProcessResult;
 
 	fn handle_error(&mut self, _error: &Error) {}
+
+	/// The topics of the events of the bus that are dispatched to the module, whether or not it is active.
+	fn subscriptions(&self) -> &'static [Topic] {
+		&[]
+	}
+
+	fn handle_bus_event(&mut self, _event: &BusEvent, _rebase_todo: &TodoFile) -> ProcessResult {
+		ProcessResult::new()
+	}
+
+	fn update_config(&mut self, _config: &Config) {}
 }
@@ -0,0 +1,14 @@
+use std::{cell::RefCell, rc::Rc};
+
+use config::Config;
+use git::Repository;
+
+use crate::notifications::Notifications;
+
+/// The shared state that a module, that registers itself, is created with.
+#[cfg_attr(not(feature = "full"), allow(dead_code))]
+pub(crate) struct ModuleContext<'c, 'm> {
+	pub(crate) config: &'c Config,
+	pub(crate) notifications: &'c Rc<RefCell<Notifications>>,
+	pub(crate) repository: &'m Repository,
+}
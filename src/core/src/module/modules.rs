@@ -1,10 +1,12 @@
 use std::collections::HashMap;
 
+use config::Config;
 use input::EventHandler;
 use todo_file::TodoFile;
 use view::{RenderContext, ViewData, ViewSender};
 
 use super::{Module, ProcessResult, State};
+use crate::event_bus::BusEvent;
 
 pub(crate) struct Modules<'m> {
 	modules: HashMap<State, Box<dyn Module + 'm>>,
@@ -21,6 +23,10 @@ impl<'m> Modules<'m> {
 		let _previous = self.modules.insert(state, Box::new(module));
 	}
 
+	pub(crate) fn is_registered(&self, state: State) -> bool {
+		self.modules.contains_key(&state)
+	}
+
 	fn get_mut_module(&mut self, state: State) -> &mut Box<dyn Module + 'm> {
 		self.modules
 			.get_mut(&state)
@@ -58,6 +64,22 @@ impl<'m> Modules<'m> {
 	pub(crate) fn error(&mut self, state: State, error: &anyhow::Error) {
 		self.get_mut_module(state).handle_error(error);
 	}
+
+	/// Dispatch an event of the bus to each module that subscribes to its topic.
+	pub(crate) fn dispatch(&mut self, event: &BusEvent, rebase_todo: &TodoFile) -> Vec<ProcessResult> {
+		let topic = event.topic();
+		self.modules
+			.values_mut()
+			.filter(|module| module.subscriptions().contains(&topic))
+			.map(|module| module.handle_bus_event(event, rebase_todo))
+			.collect()
+	}
+
+	pub(crate) fn update_config(&mut self, config: &Config) {
+		for module in self.modules.values_mut() {
+			module.update_config(config);
+		}
+	}
 }
 
 #[cfg(test)]
@@ -68,7 +90,7 @@ mod tests {
 	use input::{Event, MetaEvent};
 
 	use super::*;
-	use crate::testutil::module_test;
+	use crate::{event_bus::Topic, testutil::module_test};
 
 	struct TestModule {
 		view_data: ViewData,
@@ -107,6 +129,19 @@ mod tests {
 		fn handle_error(&mut self, error: &Error) {
 			self.trace.borrow_mut().push(error.to_string());
 		}
+
+		fn update_config(&mut self, _: &Config) {
+			self.trace.borrow_mut().push(String::from("Update Config"));
+		}
+
+		fn subscriptions(&self) -> &'static [Topic] {
+			&[Topic::Git]
+		}
+
+		fn handle_bus_event(&mut self, event: &BusEvent, _: &TodoFile) -> ProcessResult {
+			self.trace.borrow_mut().push(format!("{:?}", event));
+			ProcessResult::new()
+		}
 	}
 
 	#[test]
@@ -142,4 +177,45 @@ mod tests {
 		modules.error(State::Error, &anyhow!("Test Error"));
 		assert_eq!((*trace).borrow().join(","), "Test Error");
 	}
+
+	#[test]
+	fn update_config() {
+		let mut modules = Modules::new();
+		let trace = Rc::new(RefCell::new(Vec::new()));
+		modules.register_module(State::List, TestModule::new(Rc::clone(&trace)));
+		modules.register_module(State::Insert, TestModule::new(Rc::clone(&trace)));
+		modules.update_config(&Config::new());
+		assert_eq!((*trace).borrow().join(","), "Update Config,Update Config");
+	}
+
+	#[test]
+	fn is_registered() {
+		let mut modules = Modules::new();
+		modules.register_module(State::List, TestModule::new(Rc::new(RefCell::new(Vec::new()))));
+		assert!(modules.is_registered(State::List));
+		assert!(!modules.is_registered(State::Settings));
+	}
+
+	#[test]
+	fn dispatch_to_subscribed_modules() {
+		module_test(&["pick aaa comment"], &[], |context| {
+			let mut modules = Modules::new();
+			let trace = Rc::new(RefCell::new(Vec::new()));
+			modules.register_module(State::List, TestModule::new(Rc::clone(&trace)));
+			modules.register_module(State::Insert, TestModule::new(Rc::clone(&trace)));
+			assert_eq!(modules.dispatch(&BusEvent::DetailsLoaded, &context.rebase_todo_file).len(), 2);
+			assert_eq!((*trace).borrow().join(","), "DetailsLoaded,DetailsLoaded");
+		});
+	}
+
+	#[test]
+	fn dispatch_without_subscribed_modules() {
+		module_test(&["pick aaa comment"], &[], |context| {
+			let mut modules = Modules::new();
+			let trace = Rc::new(RefCell::new(Vec::new()));
+			modules.register_module(State::List, TestModule::new(Rc::clone(&trace)));
+			assert!(modules.dispatch(&BusEvent::TodoFileChanged, &context.rebase_todo_file).is_empty());
+			assert!((*trace).borrow().is_empty());
+		});
+	}
 }
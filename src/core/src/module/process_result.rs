@@ -1,7 +1,13 @@
+use std::time::Duration;
+
 use anyhow::Error;
+use config::Config;
 use input::Event;
 
-use crate::module::{ExitStatus, State};
+use crate::{
+	module::{ExitStatus, State},
+	timers::{Timer, TimerRequest},
+};
 
 #[derive(Debug)]
 pub(crate) struct ProcessResult {
@@ -10,6 +16,9 @@ pub(crate) struct ProcessResult {
 	pub(crate) event: Option<Event>,
 	pub(crate) state: Option<State>,
 	pub(crate) external_command: Option<(String, Vec<String>)>,
+	pub(crate) config: Option<Config>,
+	pub(crate) notification: Option<String>,
+	pub(crate) timer: Option<TimerRequest>,
 }
 
 impl ProcessResult {
@@ -20,6 +29,9 @@ impl ProcessResult {
 			event: None,
 			state: None,
 			external_command: None,
+			config: None,
+			notification: None,
+			timer: None,
 		}
 	}
 
@@ -48,6 +60,30 @@ impl ProcessResult {
 		self.external_command = Some((command, arguments));
 		self
 	}
+
+	#[cfg_attr(not(feature = "settings-editor"), allow(dead_code))]
+	pub(crate) fn config(mut self, config: Config) -> Self {
+		self.config = Some(config);
+		self
+	}
+
+	/// Show a message for a short time, without interrupting the module, and keep it in the history of the
+	/// notifications.
+	pub(crate) fn notification(mut self, message: &str) -> Self {
+		self.notification = Some(String::from(message));
+		self
+	}
+
+	/// Receive a tick of the timer after each interval, as an event of the bus, until the timer is stopped.
+	pub(crate) const fn start_timer(mut self, timer: Timer, interval: Duration) -> Self {
+		self.timer = Some(TimerRequest::Start(timer, interval));
+		self
+	}
+
+	pub(crate) const fn stop_timer(mut self, timer: Timer) -> Self {
+		self.timer = Some(TimerRequest::Stop(timer));
+		self
+	}
 }
 
 impl From<Event> for ProcessResult {
@@ -58,6 +94,9 @@ impl From<Event> for ProcessResult {
 			event: Some(event),
 			state: None,
 			external_command: None,
+			config: None,
+			notification: None,
+			timer: None,
 		}
 	}
 }
@@ -132,6 +171,49 @@ mod tests {
 		);
 	}
 
+	#[test]
+	fn config() {
+		let mut config = Config::new();
+		config.auto_select_next = true;
+		let result = ProcessResult::new().config(config);
+		assert!(result.error.is_none());
+		assert_eq!(result.exit_status, None);
+		assert_eq!(result.event, None);
+		assert_eq!(result.state, None);
+		assert_eq!(result.external_command, None);
+		assert!(result.config.unwrap().auto_select_next);
+	}
+
+	#[test]
+	fn notification() {
+		let result = ProcessResult::new().notification("Message");
+		assert!(result.error.is_none());
+		assert_eq!(result.exit_status, None);
+		assert_eq!(result.event, None);
+		assert_eq!(result.state, None);
+		assert_eq!(result.external_command, None);
+		assert_eq!(result.notification, Some(String::from("Message")));
+	}
+
+	#[test]
+	fn start_timer() {
+		let result = ProcessResult::new().start_timer(Timer::RelativeDates, Duration::from_secs(60));
+		assert_eq!(result.event, None);
+		assert_eq!(result.state, None);
+		assert_eq!(
+			result.timer,
+			Some(TimerRequest::Start(Timer::RelativeDates, Duration::from_secs(60)))
+		);
+	}
+
+	#[test]
+	fn stop_timer() {
+		let result = ProcessResult::new().stop_timer(Timer::RelativeDates);
+		assert_eq!(result.event, None);
+		assert_eq!(result.state, None);
+		assert_eq!(result.timer, Some(TimerRequest::Stop(Timer::RelativeDates)));
+	}
+
 	#[test]
 	fn everything() {
 		let result = ProcessResult::new()
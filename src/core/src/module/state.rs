@@ -1,11 +1,22 @@
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub(crate) enum State {
+	AutosquashPreview,
 	ConfirmAbort,
+	ConfirmAbortRebase,
+	ConfirmQuit,
 	ConfirmRebase,
+	ConfirmSkipCommit,
+	Diagnostics,
 	Error,
 	ExternalEditor,
 	List,
+	NotificationHistory,
+	ReviewChanges,
 	Insert,
+	Settings,
+	SetupWizard,
 	ShowCommit,
+	SquashMessage,
+	SquashPreview,
 	WindowSizeError,
 }
@@ -0,0 +1,161 @@
+#[cfg(all(unix, test))]
+mod tests;
+
+use config::Config;
+use display::DisplayColor;
+use input::{Event, EventHandler, InputOptions, KeyCode, KeyEvent, MetaEvent};
+use lazy_static::lazy_static;
+use todo_file::{AutosquashLine, TodoFile};
+use view::{handle_view_data_scroll, LineSegment, RenderContext, ViewData, ViewLine, ViewSender};
+
+use crate::{
+	i18n::{translate, translate_format},
+	module::{Module, ProcessResult, State},
+};
+
+lazy_static! {
+	static ref INPUT_OPTIONS: InputOptions = InputOptions::new().movement(true);
+}
+
+// a fixup or squash commit has an arrow to the commit that it is applied to, which is the closest earlier line without
+// an arrow
+fn build_autosquash_line(todo_file: &TodoFile, autosquash_line: &AutosquashLine) -> Option<ViewLine> {
+	let mut line = todo_file.get_line(autosquash_line.index())?.clone();
+	let action = *line.get_action();
+	line.set_action(autosquash_line.action());
+	let mut segments = vec![];
+	if autosquash_line.target_index().is_some() {
+		segments.push(LineSegment::new_with_color("->", DisplayColor::IndicatorColor));
+		segments.push(LineSegment::new(" "));
+		segments.push(LineSegment::new_with_color(
+			line.to_text().as_str(),
+			DisplayColor::IndicatorColor,
+		));
+		segments.push(LineSegment::new(" "));
+		segments.push(LineSegment::new_with_color_and_style(
+			translate_format("(was {} on line {})", &[
+				&action.as_string(),
+				&(autosquash_line.index() + 1),
+			])
+			.as_str(),
+			DisplayColor::Normal,
+			true,
+			false,
+			false,
+		));
+	}
+	else {
+		segments.push(LineSegment::new("  "));
+		segments.push(LineSegment::new(" "));
+		segments.push(LineSegment::new(line.to_text().as_str()));
+	}
+	Some(ViewLine::new_with_pinned_segments(segments, 2))
+}
+
+pub(crate) struct AutosquashPreview {
+	abort_key: String,
+	apply_key: String,
+	is_stale: bool,
+	view_data: ViewData,
+}
+
+impl Module for AutosquashPreview {
+	fn activate(&mut self, _: &TodoFile, _: State) -> ProcessResult {
+		self.is_stale = true;
+		ProcessResult::new()
+	}
+
+	fn build_view_data(&mut self, _: &RenderContext, todo_file: &TodoFile) -> &ViewData {
+		// the order is only found again when the preview is opened, since the todo file cannot change while open
+		if self.is_stale {
+			self.is_stale = false;
+			let autosquash_lines = todo_file.autosquash_preview();
+			let moved_count = autosquash_lines
+				.iter()
+				.filter(|autosquash_line| autosquash_line.target_index().is_some())
+				.count();
+			let summary = if moved_count == 0 {
+				String::from(translate("There are no fixup or squash commits to move"))
+			}
+			else {
+				translate_format("{} fixup and squash commits are moved after the commits they apply to", &[
+					&moved_count,
+				])
+			};
+			let hint = translate_format("Press {} to apply, or {} to return to the list", &[
+				&self.apply_key,
+				&self.abort_key,
+			]);
+			self.view_data.update_view_data(|updater| {
+				updater.clear();
+				updater.push_leading_line(ViewLine::from(summary.as_str()));
+				for autosquash_line in &autosquash_lines {
+					if let Some(line) = build_autosquash_line(todo_file, autosquash_line) {
+						updater.push_line(line);
+					}
+				}
+				updater.push_trailing_line(ViewLine::from(LineSegment::new_with_color(
+					hint.as_str(),
+					DisplayColor::IndicatorColor,
+				)));
+			});
+		}
+		&self.view_data
+	}
+
+	fn handle_events(
+		&mut self,
+		event_handler: &EventHandler,
+		view_sender: &ViewSender,
+		todo_file: &mut TodoFile,
+	) -> ProcessResult {
+		let event = event_handler.read_event(&INPUT_OPTIONS, |event, key_bindings| {
+			if key_bindings.autosquash.contains(&event) || key_bindings.confirm_yes.contains(&event) {
+				Event::from(MetaEvent::Autosquash)
+			}
+			else if key_bindings.abort.contains(&event) {
+				Event::from(MetaEvent::Abort)
+			}
+			else {
+				event
+			}
+		});
+		let mut result = ProcessResult::from(event);
+
+		if handle_view_data_scroll(event, view_sender).is_none() {
+			match event {
+				Event::Meta(MetaEvent::Autosquash) => {
+					let _changed = todo_file.autosquash();
+					result = result.state(State::List);
+				},
+				Event::Meta(MetaEvent::Abort) | Event::Key(KeyEvent { code: KeyCode::Esc, .. }) => {
+					result = result.state(State::List);
+				},
+				_ => {},
+			}
+		}
+		result
+	}
+
+	fn update_config(&mut self, config: &Config) {
+		self.abort_key = Self::first_key(&config.key_bindings.abort, "q");
+		self.apply_key = Self::first_key(&config.key_bindings.confirm_yes, "y");
+	}
+}
+
+impl AutosquashPreview {
+	pub(crate) fn new(config: &Config) -> Self {
+		Self {
+			abort_key: Self::first_key(&config.key_bindings.abort, "q"),
+			apply_key: Self::first_key(&config.key_bindings.confirm_yes, "y"),
+			is_stale: true,
+			view_data: ViewData::new(|updater| {
+				updater.set_show_title(true);
+			}),
+		}
+	}
+
+	fn first_key(keys: &[String], default: &str) -> String {
+		keys.first().map_or(String::from(default), String::from)
+	}
+}
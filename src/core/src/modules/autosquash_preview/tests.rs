@@ -0,0 +1,139 @@
+use todo_file::Line;
+use view::assert_rendered_output;
+
+use super::*;
+use crate::{assert_process_result, testutil::module_test};
+
+#[test]
+fn build_view_data() {
+	module_test(
+		&["pick aaa c1", "pick bbb c2", "pick ccc fixup! c1", "pick ddd squash! c1"],
+		&[],
+		|test_context| {
+			let mut module = AutosquashPreview::new(&Config::new());
+			let view_data = test_context.build_view_data(&mut module);
+			assert_rendered_output!(
+				view_data,
+				"{TITLE}",
+				"{LEADING}",
+				"{Normal}2 fixup and squash commits are moved after the commits they apply to",
+				"{BODY}",
+				"{Normal}  {Normal} {Normal}pick aaa c1",
+				"{IndicatorColor}->{Normal} {IndicatorColor}fixup ccc fixup! c1{Normal} {Normal,Dimmed}(was pick on line 3)",
+				"{IndicatorColor}->{Normal} {IndicatorColor}squash ddd squash! c1{Normal} {Normal,Dimmed}(was pick on line 4)",
+				"{Normal}  {Normal} {Normal}pick bbb c2",
+				"{TRAILING}",
+				"{IndicatorColor}Press y to apply, or q to return to the list"
+			);
+		},
+	);
+}
+
+#[test]
+fn build_view_data_without_fixup_commits() {
+	module_test(&["pick aaa c1"], &[], |test_context| {
+		let mut module = AutosquashPreview::new(&Config::new());
+		let view_data = test_context.build_view_data(&mut module);
+		assert_rendered_output!(
+			view_data,
+			"{TITLE}",
+			"{LEADING}",
+			"{Normal}There are no fixup or squash commits to move",
+			"{BODY}",
+			"{Normal}  {Normal} {Normal}pick aaa c1",
+			"{TRAILING}",
+			"{IndicatorColor}Press y to apply, or q to return to the list"
+		);
+	});
+}
+
+#[test]
+fn activate() {
+	module_test(&["pick aaa c1"], &[], |test_context| {
+		let mut module = AutosquashPreview::new(&Config::new());
+		let _ = test_context.build_view_data(&mut module);
+		assert_process_result!(test_context.activate(&mut module, State::List));
+		assert!(module.is_stale);
+	});
+}
+
+#[test]
+fn apply() {
+	module_test(
+		&["pick aaa c1", "pick bbb c2", "pick ccc fixup! c1"],
+		&[Event::from('y')],
+		|mut test_context| {
+			let mut module = AutosquashPreview::new(&Config::new());
+			assert_process_result!(
+				test_context.handle_event(&mut module),
+				event = Event::from(MetaEvent::Autosquash),
+				state = State::List
+			);
+			assert_eq!(
+				test_context
+					.rebase_todo_file
+					.lines_iter()
+					.map(Line::to_text)
+					.collect::<Vec<String>>(),
+				vec!["pick aaa c1", "fixup ccc fixup! c1", "pick bbb c2"]
+			);
+		},
+	);
+}
+
+#[test]
+fn apply_with_autosquash_key() {
+	module_test(&["pick aaa c1"], &[Event::from('a')], |mut test_context| {
+		let mut module = AutosquashPreview::new(&Config::new());
+		assert_process_result!(
+			test_context.handle_event(&mut module),
+			event = Event::from(MetaEvent::Autosquash),
+			state = State::List
+		);
+	});
+}
+
+#[test]
+fn abort() {
+	module_test(
+		&["pick aaa c1", "pick bbb c2", "pick ccc fixup! c1"],
+		&[Event::from('q')],
+		|mut test_context| {
+			let mut module = AutosquashPreview::new(&Config::new());
+			assert_process_result!(
+				test_context.handle_event(&mut module),
+				event = Event::from(MetaEvent::Abort),
+				state = State::List
+			);
+			assert_eq!(
+				test_context.rebase_todo_file.get_line(1).unwrap().to_text(),
+				"pick bbb c2"
+			);
+		},
+	);
+}
+
+#[test]
+fn escape() {
+	module_test(&["pick aaa c1"], &[Event::from(KeyCode::Esc)], |mut test_context| {
+		let mut module = AutosquashPreview::new(&Config::new());
+		assert_process_result!(
+			test_context.handle_event(&mut module),
+			event = Event::from(KeyCode::Esc),
+			state = State::List
+		);
+	});
+}
+
+#[test]
+fn update_config() {
+	module_test(&["pick aaa c1"], &[], |_| {
+		let mut module = AutosquashPreview::new(&Config::new());
+		let mut config = Config::new();
+		config.key_bindings.abort = vec![String::from("x")];
+		config.key_bindings.confirm_yes = vec![String::from("z")];
+		module.update_config(&config);
+		assert_eq!(module.abort_key, "x");
+		assert_eq!(module.apply_key, "z");
+	});
+}
@@ -1,13 +1,16 @@
+use config::{AbortBehavior, Config};
 use input::EventHandler;
 use todo_file::TodoFile;
 use view::{RenderContext, ViewData, ViewSender};
 
 use crate::{
 	components::confirm::{Confirm, Confirmed},
+	i18n::translate,
 	module::{ExitStatus, Module, ProcessResult, State},
 };
 
 pub(crate) struct ConfirmAbort {
+	abort_behavior: AbortBehavior,
 	dialog: Confirm,
 }
 
@@ -26,8 +29,7 @@ impl Module for ConfirmAbort {
 		let mut result = ProcessResult::from(event);
 		match confirmed {
 			Confirmed::Yes => {
-				rebase_todo.set_lines(vec![]);
-				result = result.exit_status(ExitStatus::Good);
+				result = result.exit_status(abort(self.abort_behavior, rebase_todo));
 			},
 			Confirmed::No => {
 				result = result.state(State::List);
@@ -36,12 +38,39 @@ impl Module for ConfirmAbort {
 		}
 		result
 	}
+
+	fn update_config(&mut self, config: &Config) {
+		*self = Self::new(
+			config.abort_behavior,
+			&config.key_bindings.confirm_yes,
+			&config.key_bindings.confirm_no,
+		);
+	}
+}
+
+/// Abort the todo list, with an empty todo file that cancels the rebase, or with an exit code that cancels the rebase
+/// without writing the todo file, returning the status to exit with.
+pub(crate) fn abort(abort_behavior: AbortBehavior, rebase_todo: &mut TodoFile) -> ExitStatus {
+	match abort_behavior {
+		AbortBehavior::ExitCode => ExitStatus::Cancel,
+		_ => {
+			rebase_todo.set_lines(vec![]);
+			ExitStatus::Good
+		},
+	}
 }
 
 impl ConfirmAbort {
-	pub(crate) fn new(confirm_yes: &[String], confirm_no: &[String]) -> Self {
+	pub(crate) fn new(abort_behavior: AbortBehavior, confirm_yes: &[String], confirm_no: &[String]) -> Self {
+		let prompt = match abort_behavior {
+			AbortBehavior::ExitCode => {
+				translate("Are you sure you want to abort, exiting with an error without writing the rebase file")
+			},
+			_ => translate("Are you sure you want to abort"),
+		};
 		Self {
-			dialog: Confirm::new("Are you sure you want to abort", confirm_yes, confirm_no),
+			abort_behavior,
+			dialog: Confirm::new(prompt, confirm_yes, confirm_no),
 		}
 	}
 }
@@ -55,7 +84,7 @@ mod tests {
 	use crate::{assert_process_result, testutil::module_test};
 
 	fn create_confirm_abort() -> ConfirmAbort {
-		ConfirmAbort::new(&[String::from("y")], &[String::from("n")])
+		ConfirmAbort::new(AbortBehavior::Empty, &[String::from("y")], &[String::from("n")])
 	}
 
 	#[test]
@@ -92,6 +121,54 @@ mod tests {
 		);
 	}
 
+	#[test]
+	fn build_view_data_exit_code() {
+		module_test(&["pick aaa comment"], &[], |test_context| {
+			let mut module = ConfirmAbort::new(AbortBehavior::ExitCode, &[String::from("y")], &[String::from("n")]);
+			let view_data = test_context.build_view_data(&mut module);
+			assert_rendered_output!(
+				view_data,
+				"{TITLE}",
+				"{BODY}",
+				"{Normal}Are you sure you want to abort, exiting with an error without writing the rebase file (y/n)?"
+			);
+		});
+	}
+
+	#[test]
+	fn handle_event_yes_exit_code() {
+		module_test(
+			&["pick aaa comment"],
+			&[Event::from(MetaEvent::Yes)],
+			|mut test_context| {
+				let mut module = ConfirmAbort::new(AbortBehavior::ExitCode, &[String::from("y")], &[String::from("n")]);
+				assert_process_result!(
+					test_context.handle_event(&mut module),
+					event = Event::from(MetaEvent::Yes),
+					exit_status = ExitStatus::Cancel
+				);
+				assert!(!test_context.rebase_todo_file.is_empty());
+			},
+		);
+	}
+
+	#[test]
+	fn update_config() {
+		module_test(&["pick aaa comment"], &[], |test_context| {
+			let mut module = create_confirm_abort();
+			let mut config = Config::new();
+			config.abort_behavior = AbortBehavior::ExitCode;
+			module.update_config(&config);
+			let view_data = test_context.build_view_data(&mut module);
+			assert_rendered_output!(
+				view_data,
+				"{TITLE}",
+				"{BODY}",
+				"{Normal}Are you sure you want to abort, exiting with an error without writing the rebase file (y/n)?"
+			);
+		});
+	}
+
 	#[test]
 	fn handle_event_no() {
 		module_test(
@@ -0,0 +1,122 @@
+use input::EventHandler;
+use todo_file::TodoFile;
+use view::{RenderContext, ViewData, ViewSender};
+
+use crate::{
+	components::confirm::{Confirm, Confirmed},
+	i18n::translate,
+	module::{ExitStatus, Module, ProcessResult, State},
+};
+
+// the todo file is not written on a quit, so the rebase continues with the todo file that was opened
+pub(crate) struct ConfirmQuit {
+	dialog: Confirm,
+}
+
+impl Module for ConfirmQuit {
+	fn build_view_data(&mut self, _: &RenderContext, _: &TodoFile) -> &ViewData {
+		self.dialog.get_view_data()
+	}
+
+	fn handle_events(&mut self, event_handler: &EventHandler, _: &ViewSender, _: &mut TodoFile) -> ProcessResult {
+		let (confirmed, event) = self.dialog.handle_event(event_handler);
+		let mut result = ProcessResult::from(event);
+		match confirmed {
+			Confirmed::Yes => {
+				result = result.exit_status(ExitStatus::Quit);
+			},
+			Confirmed::No => {
+				result = result.state(State::List);
+			},
+			Confirmed::Other => {},
+		}
+		result
+	}
+}
+
+impl ConfirmQuit {
+	pub(crate) fn new(confirm_yes: &[String], confirm_no: &[String]) -> Self {
+		Self {
+			dialog: Confirm::new(
+				translate("Are you sure you want to quit without saving, keeping the original rebase file"),
+				confirm_yes,
+				confirm_no,
+			),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use input::{Event, KeyCode, MetaEvent};
+	use view::assert_rendered_output;
+
+	use super::*;
+	use crate::{assert_process_result, testutil::module_test};
+
+	fn create_confirm_quit() -> ConfirmQuit {
+		ConfirmQuit::new(&[String::from("y")], &[String::from("n")])
+	}
+
+	#[test]
+	fn build_view_data() {
+		module_test(&["pick aaa comment"], &[], |test_context| {
+			let mut module = create_confirm_quit();
+			let view_data = test_context.build_view_data(&mut module);
+			assert_rendered_output!(
+				view_data,
+				"{TITLE}",
+				"{BODY}",
+				"{Normal}Are you sure you want to quit without saving, keeping the original rebase file (y/n)?"
+			);
+		});
+	}
+
+	#[test]
+	fn handle_event_yes() {
+		module_test(
+			&["pick aaa comment"],
+			&[Event::from(MetaEvent::Yes)],
+			|mut test_context| {
+				let mut module = create_confirm_quit();
+				assert_process_result!(
+					test_context.handle_event(&mut module),
+					event = Event::from(MetaEvent::Yes),
+					exit_status = ExitStatus::Quit
+				);
+				assert!(!test_context.rebase_todo_file.is_empty());
+			},
+		);
+	}
+
+	#[test]
+	fn handle_event_no() {
+		module_test(
+			&["pick aaa comment"],
+			&[Event::from(MetaEvent::No)],
+			|mut test_context| {
+				let mut module = create_confirm_quit();
+				assert_process_result!(
+					test_context.handle_event(&mut module),
+					event = Event::from(MetaEvent::No),
+					state = State::List
+				);
+			},
+		);
+	}
+
+	#[test]
+	fn handle_event_confirmed_other() {
+		module_test(
+			&["pick aaa comment"],
+			&[Event::from(KeyCode::Null)],
+			|mut test_context| {
+				let mut module = create_confirm_quit();
+				assert_process_result!(
+					test_context.handle_event(&mut module),
+					event = Event::from(KeyCode::Null)
+				);
+			},
+		);
+	}
+}
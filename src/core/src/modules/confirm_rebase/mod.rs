@@ -4,6 +4,7 @@ use view::{RenderContext, ViewData, ViewSender};
 
 use crate::{
 	components::confirm::{Confirm, Confirmed},
+	i18n::translate,
 	module::{ExitStatus, Module, ProcessResult, State},
 };
 
@@ -35,7 +36,7 @@ impl Module for ConfirmRebase {
 impl ConfirmRebase {
 	pub(crate) fn new(confirm_yes: &[String], confirm_no: &[String]) -> Self {
 		Self {
-			dialog: Confirm::new("Are you sure you want to rebase", confirm_yes, confirm_no),
+			dialog: Confirm::new(translate("Are you sure you want to rebase"), confirm_yes, confirm_no),
 		}
 	}
 }
@@ -0,0 +1,248 @@
+mod rebase_command;
+
+use std::path::Path;
+
+use anyhow::anyhow;
+use input::{Event, EventHandler, MetaEvent};
+use todo_file::TodoFile;
+use view::{RenderContext, ViewData, ViewSender};
+
+pub(crate) use self::rebase_command::RebaseCommand;
+use crate::{
+	components::confirm::{Confirm, Confirmed},
+	i18n::{translate, translate_format},
+	module::{ExitStatus, Module, ProcessResult, State},
+};
+
+// Git only creates the done file once it has started processing the todo list, so its presence next to the todo
+// file means the tool was opened with `git rebase --edit-todo` during a stopped rebase.
+fn is_rebase_in_progress(todo_file: &TodoFile) -> bool {
+	Path::new(todo_file.get_filepath()).with_file_name("done").exists()
+}
+
+pub(crate) struct ConfirmRebaseCommand {
+	command: RebaseCommand,
+	dialog: Confirm,
+}
+
+impl Module for ConfirmRebaseCommand {
+	fn activate(&mut self, todo_file: &TodoFile, _: State) -> ProcessResult {
+		if is_rebase_in_progress(todo_file) {
+			ProcessResult::new()
+		}
+		else {
+			ProcessResult::new()
+				.error(anyhow!(translate_format(
+					"Unable to {}, a rebase is only stopped when opened with --edit-todo",
+					&[&translate(self.command.description())]
+				)))
+				.state(State::List)
+		}
+	}
+
+	fn build_view_data(&mut self, _: &RenderContext, _: &TodoFile) -> &ViewData {
+		self.dialog.get_view_data()
+	}
+
+	fn handle_events(&mut self, event_handler: &EventHandler, _: &ViewSender, _: &mut TodoFile) -> ProcessResult {
+		let (confirmed, event) = self.dialog.handle_event(event_handler);
+		let mut result = ProcessResult::from(event);
+		match confirmed {
+			Confirmed::Yes => {
+				result = result.external_command(String::from("git"), vec![
+					String::from("rebase"),
+					String::from(self.command.argument()),
+				]);
+			},
+			Confirmed::No => {
+				result = result.state(State::List);
+			},
+			Confirmed::Other => {
+				match event {
+					// Git has already updated, or removed, the rebase state, so the todo file must not be written and
+					// the non-zero exit stops the waiting `git rebase --edit-todo` from reading it back.
+					Event::Meta(MetaEvent::ExternalCommandSuccess) => result = result.exit_status(ExitStatus::Kill),
+					Event::Meta(MetaEvent::ExternalCommandError) => {
+						result = result
+							.error(anyhow!(translate_format(
+								"Unable to {}, git returned a non-zero exit status",
+								&[&translate(self.command.description())]
+							)))
+							.state(State::List);
+					},
+					_ => {},
+				}
+			},
+		}
+		result
+	}
+}
+
+impl ConfirmRebaseCommand {
+	pub(crate) fn new(command: RebaseCommand, confirm_yes: &[String], confirm_no: &[String]) -> Self {
+		Self {
+			command,
+			dialog: Confirm::new(translate(command.prompt()), confirm_yes, confirm_no),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::fs::File;
+
+	use input::KeyCode;
+	use tempfile::tempdir;
+	use view::assert_rendered_output;
+
+	use super::*;
+	use crate::{assert_process_result, testutil::module_test};
+
+	fn create_module(command: RebaseCommand) -> ConfirmRebaseCommand {
+		ConfirmRebaseCommand::new(command, &[String::from("y")], &[String::from("n")])
+	}
+
+	#[test]
+	fn activate_rebase_in_progress() {
+		module_test(&["pick aaa comment"], &[], |mut test_context| {
+			let directory = tempdir().unwrap();
+			let _done = File::create(directory.path().join("done")).unwrap();
+			let todo_path = directory.path().join("git-rebase-todo");
+			test_context.rebase_todo_file = TodoFile::new(todo_path.to_str().unwrap(), 1, "#");
+			let mut module = create_module(RebaseCommand::Abort);
+			assert_process_result!(test_context.activate(&mut module, State::List));
+		});
+	}
+
+	#[test]
+	fn activate_rebase_not_in_progress() {
+		module_test(&["pick aaa comment"], &[], |test_context| {
+			let mut module = create_module(RebaseCommand::Skip);
+			assert_process_result!(
+				test_context.activate(&mut module, State::List),
+				state = State::List,
+				error =
+					anyhow!("Unable to skip the current commit, a rebase is only stopped when opened with --edit-todo")
+			);
+		});
+	}
+
+	#[test]
+	fn build_view_data() {
+		module_test(&["pick aaa comment"], &[], |test_context| {
+			let mut module = create_module(RebaseCommand::Abort);
+			let view_data = test_context.build_view_data(&mut module);
+			assert_rendered_output!(
+				Options AssertRenderOptions {
+					ignore_trailing_whitespace: false
+				},
+				view_data,
+				"{TITLE}",
+				"{BODY}",
+				"{Normal}Are you sure you want to abort the stopped rebase (y/n)? "
+			);
+		});
+	}
+
+	#[test]
+	fn handle_event_yes_abort() {
+		module_test(
+			&["pick aaa comment"],
+			&[Event::from(MetaEvent::Yes)],
+			|mut test_context| {
+				let mut module = create_module(RebaseCommand::Abort);
+				assert_process_result!(
+					test_context.handle_event(&mut module),
+					event = Event::from(MetaEvent::Yes),
+					external_command = (String::from("git"), vec![
+						String::from("rebase"),
+						String::from("--abort")
+					])
+				);
+			},
+		);
+	}
+
+	#[test]
+	fn handle_event_yes_skip() {
+		module_test(
+			&["pick aaa comment"],
+			&[Event::from(MetaEvent::Yes)],
+			|mut test_context| {
+				let mut module = create_module(RebaseCommand::Skip);
+				assert_process_result!(
+					test_context.handle_event(&mut module),
+					event = Event::from(MetaEvent::Yes),
+					external_command = (String::from("git"), vec![
+						String::from("rebase"),
+						String::from("--skip")
+					])
+				);
+			},
+		);
+	}
+
+	#[test]
+	fn handle_event_no() {
+		module_test(
+			&["pick aaa comment"],
+			&[Event::from(MetaEvent::No)],
+			|mut test_context| {
+				let mut module = create_module(RebaseCommand::Abort);
+				assert_process_result!(
+					test_context.handle_event(&mut module),
+					event = Event::from(MetaEvent::No),
+					state = State::List
+				);
+			},
+		);
+	}
+
+	#[test]
+	fn handle_event_external_command_success() {
+		module_test(&["pick aaa comment"], &[], |mut test_context| {
+			test_context
+				.event_handler_context
+				.event_handler
+				.push_event(Event::from(MetaEvent::ExternalCommandSuccess));
+			let mut module = create_module(RebaseCommand::Abort);
+			assert_process_result!(
+				test_context.handle_event(&mut module),
+				event = Event::from(MetaEvent::ExternalCommandSuccess),
+				exit_status = ExitStatus::Kill
+			);
+		});
+	}
+
+	#[test]
+	fn handle_event_external_command_error() {
+		module_test(&["pick aaa comment"], &[], |mut test_context| {
+			test_context
+				.event_handler_context
+				.event_handler
+				.push_event(Event::from(MetaEvent::ExternalCommandError));
+			let mut module = create_module(RebaseCommand::Skip);
+			assert_process_result!(
+				test_context.handle_event(&mut module),
+				event = Event::from(MetaEvent::ExternalCommandError),
+				state = State::List,
+				error = anyhow!("Unable to skip the current commit, git returned a non-zero exit status")
+			);
+		});
+	}
+
+	#[test]
+	fn handle_event_confirmed_other() {
+		module_test(
+			&["pick aaa comment"],
+			&[Event::from(KeyCode::Null)],
+			|mut test_context| {
+				let mut module = create_module(RebaseCommand::Abort);
+				assert_process_result!(
+					test_context.handle_event(&mut module),
+					event = Event::from(KeyCode::Null)
+				);
+			},
+		);
+	}
+}
@@ -0,0 +1,42 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum RebaseCommand {
+	Abort,
+	Skip,
+}
+
+impl RebaseCommand {
+	pub(super) const fn argument(self) -> &'static str {
+		match self {
+			Self::Abort => "--abort",
+			Self::Skip => "--skip",
+		}
+	}
+
+	pub(super) const fn prompt(self) -> &'static str {
+		match self {
+			Self::Abort => "Are you sure you want to abort the stopped rebase",
+			Self::Skip => "Are you sure you want to skip the commit the rebase stopped on",
+		}
+	}
+
+	pub(super) const fn description(self) -> &'static str {
+		match self {
+			Self::Abort => "abort the rebase",
+			Self::Skip => "skip the current commit",
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use rstest::rstest;
+
+	use super::*;
+
+	#[rstest]
+	#[case::abort(RebaseCommand::Abort, "--abort")]
+	#[case::skip(RebaseCommand::Skip, "--skip")]
+	fn argument(#[case] command: RebaseCommand, #[case] expected: &str) {
+		assert_eq!(command.argument(), expected);
+	}
+}
@@ -0,0 +1,122 @@
+#[cfg(all(unix, test))]
+mod tests;
+
+use std::{cell::Cell, rc::Rc};
+
+use config::Config;
+#[cfg(test)]
+use display::testutil::CrossTerm;
+#[cfg(not(test))]
+use display::CrossTerm;
+use display::Size;
+use git::{DiffMemoryUsage, Repository};
+use input::{Event, EventHandler, InputOptions, KeyCode, KeyEvent, MetaEvent};
+use lazy_static::lazy_static;
+use todo_file::TodoFile;
+use view::{handle_view_data_scroll, RenderContext, ViewData, ViewLine, ViewSender};
+
+use crate::{
+	diagnostics::{build_report, Environment},
+	module::{Module, ProcessResult, State},
+};
+
+lazy_static! {
+	static ref INPUT_OPTIONS: InputOptions = InputOptions::new().movement(true);
+}
+
+pub(crate) struct Diagnostics<'d> {
+	config: Config,
+	diff_memory_usage: Rc<Cell<Option<DiffMemoryUsage>>>,
+	environment: Option<Environment>,
+	is_stale: bool,
+	repository: &'d Repository,
+	return_state: State,
+	view_data: ViewData,
+}
+
+impl Module for Diagnostics<'_> {
+	fn activate(&mut self, _: &TodoFile, previous_state: State) -> ProcessResult {
+		self.return_state = previous_state;
+		self.is_stale = true;
+		// detecting the environment runs git, so it is only done once the report is first shown
+		if self.environment.is_none() {
+			self.environment = Some(Environment::detect(&CrossTerm::new(), CrossTerm::is_interactive()));
+		}
+		ProcessResult::new()
+	}
+
+	fn build_view_data(&mut self, context: &RenderContext, _: &TodoFile) -> &ViewData {
+		// the report checks the source of every setting, so it is only built again when something has changed
+		if let (true, Some(environment)) = (self.is_stale, self.environment.as_mut()) {
+			self.is_stale = false;
+			environment.set_size(Size::new(context.width(), context.height()));
+			let lines = build_report(
+				environment,
+				Ok(self.repository),
+				Ok(&self.config),
+				self.diff_memory_usage.get(),
+			);
+			self.view_data.update_view_data(|updater| {
+				updater.clear();
+				for line in lines {
+					updater.push_line(ViewLine::from(line));
+				}
+			});
+		}
+		&self.view_data
+	}
+
+	fn handle_events(
+		&mut self,
+		event_handler: &EventHandler,
+		view_sender: &ViewSender,
+		_: &mut TodoFile,
+	) -> ProcessResult {
+		let event = event_handler.read_event(&INPUT_OPTIONS, |event, key_bindings| {
+			if key_bindings.abort.contains(&event) {
+				Event::from(MetaEvent::Abort)
+			}
+			else {
+				event
+			}
+		});
+		let mut result = ProcessResult::from(event);
+
+		if handle_view_data_scroll(event, view_sender).is_none() {
+			match event {
+				Event::Meta(MetaEvent::Abort) | Event::Key(KeyEvent { code: KeyCode::Esc, .. }) => {
+					result = result.state(self.return_state);
+				},
+				Event::Resize(..) => self.is_stale = true,
+				_ => {},
+			}
+		}
+		result
+	}
+
+	fn update_config(&mut self, config: &Config) {
+		self.config = config.clone();
+		self.is_stale = true;
+	}
+}
+
+impl<'d> Diagnostics<'d> {
+	pub(crate) fn new(config: &Config, repository: &'d Repository) -> Self {
+		Self {
+			config: config.clone(),
+			diff_memory_usage: Rc::new(Cell::new(None)),
+			environment: None,
+			is_stale: true,
+			repository,
+			return_state: State::List,
+			view_data: ViewData::new(|updater| {
+				updater.set_show_title(true);
+			}),
+		}
+	}
+
+	/// Show the memory used by the last diff that was loaded by the modules that share the usage.
+	pub(crate) fn set_diff_memory_usage(&mut self, diff_memory_usage: Rc<Cell<Option<DiffMemoryUsage>>>) {
+		self.diff_memory_usage = diff_memory_usage;
+	}
+}
@@ -0,0 +1,118 @@
+use git::testutil::with_temp_repository;
+
+use super::*;
+use crate::{assert_process_result, testutil::module_test};
+
+fn create_module(repository: &Repository) -> Diagnostics<'_> {
+	Diagnostics::new(&Config::new(), repository)
+}
+
+#[test]
+fn activate() {
+	with_temp_repository(|repository| {
+		module_test(&["pick aaa comment"], &[], |test_context| {
+			let mut module = create_module(&repository);
+			assert!(module.environment.is_none());
+			assert_process_result!(test_context.activate(&mut module, State::Settings));
+			assert_eq!(module.return_state, State::Settings);
+			assert!(module.environment.is_some());
+		});
+		Ok(())
+	});
+}
+
+#[test]
+fn build_view_data() {
+	with_temp_repository(|repository| {
+		module_test(&["pick aaa comment"], &[], |test_context| {
+			let mut module = create_module(&repository);
+			let _ = test_context.activate(&mut module, State::Settings);
+			assert!(!test_context.build_view_data(&mut module).is_empty());
+			assert!(!module.is_stale);
+		});
+		Ok(())
+	});
+}
+
+#[test]
+fn abort() {
+	with_temp_repository(|repository| {
+		module_test(&["pick aaa comment"], &[Event::from('q')], |mut test_context| {
+			let mut module = create_module(&repository);
+			let _ = test_context.activate(&mut module, State::Settings);
+			assert_process_result!(
+				test_context.handle_event(&mut module),
+				event = Event::from(MetaEvent::Abort),
+				state = State::Settings
+			);
+		});
+		Ok(())
+	});
+}
+
+#[test]
+fn escape() {
+	with_temp_repository(|repository| {
+		module_test(
+			&["pick aaa comment"],
+			&[Event::from(KeyCode::Esc)],
+			|mut test_context| {
+				let mut module = create_module(&repository);
+				let _ = test_context.activate(&mut module, State::List);
+				assert_process_result!(
+					test_context.handle_event(&mut module),
+					event = Event::from(KeyCode::Esc),
+					state = State::List
+				);
+			},
+		);
+		Ok(())
+	});
+}
+
+#[test]
+fn scroll() {
+	with_temp_repository(|repository| {
+		module_test(
+			&["pick aaa comment"],
+			&[Event::from(MetaEvent::ScrollDown)],
+			|mut test_context| {
+				let mut module = create_module(&repository);
+				assert_process_result!(
+					test_context.handle_event(&mut module),
+					event = Event::from(MetaEvent::ScrollDown)
+				);
+			},
+		);
+		Ok(())
+	});
+}
+
+#[test]
+fn resize() {
+	with_temp_repository(|repository| {
+		module_test(&["pick aaa comment"], &[Event::Resize(100, 100)], |mut test_context| {
+			let mut module = create_module(&repository);
+			let _ = test_context.build_view_data(&mut module);
+			let _ = test_context.handle_event(&mut module);
+			assert!(module.is_stale);
+		});
+		Ok(())
+	});
+}
+
+#[test]
+fn update_config() {
+	with_temp_repository(|repository| {
+		module_test(&["pick aaa comment"], &[], |test_context| {
+			let mut module = create_module(&repository);
+			let _ = test_context.build_view_data(&mut module);
+			let mut config = Config::new();
+			config.auto_select_next = true;
+			module.update_config(&config);
+			assert!(module.is_stale);
+			assert!(module.config.auto_select_next);
+		});
+		Ok(())
+	});
+}
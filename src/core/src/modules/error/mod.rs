@@ -1,19 +1,33 @@
-use captur::capture;
+#[cfg(test)]
+mod tests;
+
+use std::{io, mem};
+
 use display::DisplayColor;
-use input::{Event, EventHandler, InputOptions};
+use input::{Event, EventHandler, InputOptions, KeyCode, KeyEvent, KeyModifiers};
 use lazy_static::lazy_static;
 use todo_file::TodoFile;
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
 use view::{handle_view_data_scroll, LineSegment, RenderContext, ViewData, ViewLine, ViewSender};
 
-use crate::module::{Module, ProcessResult, State};
+use crate::{
+	i18n::translate,
+	module::{Module, ProcessResult, State},
+};
 
 lazy_static! {
 	static ref INPUT_OPTIONS: InputOptions = InputOptions::new().movement(true);
 }
 
+// the causes of an error are indented beneath the operation that failed
+const CAUSE_INDENT: &str = "  ";
+
 pub(crate) struct Error {
+	causes: Vec<String>,
 	return_state: State,
+	suggestions: Vec<&'static str>,
 	view_data: ViewData,
+	wrap_width: Option<usize>,
 }
 
 impl Module for Error {
@@ -22,7 +36,13 @@ impl Module for Error {
 		ProcessResult::new()
 	}
 
-	fn build_view_data(&mut self, _: &RenderContext, _: &TodoFile) -> &ViewData {
+	fn build_view_data(&mut self, context: &RenderContext, _: &TodoFile) -> &ViewData {
+		// the last column is left for the scroll bar
+		let width = context.width().saturating_sub(1).max(CAUSE_INDENT.len() + 1);
+		if self.wrap_width != Some(width) {
+			self.wrap_width = Some(width);
+			self.update_view_data(width);
+		}
 		&self.view_data
 	}
 
@@ -35,172 +55,163 @@ impl Module for Error {
 		let event = event_handler.read_event(&INPUT_OPTIONS, |event, _| event);
 		let mut result = ProcessResult::from(event);
 		if handle_view_data_scroll(event, view_sender).is_none() {
-			if let Event::Key(_) = event {
-				result = result.state(self.return_state);
+			match event {
+				Event::Key(KeyEvent {
+					code: KeyCode::Char('c'),
+					modifiers: KeyModifiers::NONE,
+					..
+				}) => {
+					if view_sender.copy_to_clipboard(self.error_text().as_str()).is_ok() {
+						result = result.notification(translate("Copied the error to the clipboard"));
+					}
+				},
+				Event::Key(_) => result = result.state(self.return_state),
+				_ => {},
 			}
 		}
 		result
 	}
 
 	fn handle_error(&mut self, error: &anyhow::Error) {
-		self.view_data.update_view_data(|updater| {
-			capture!(error);
-			updater.clear();
-			for cause in error.chain() {
-				let error_text = format!("{:#}", cause);
-				for err in error_text.split('\n') {
-					updater.push_line(ViewLine::from(err));
-				}
-			}
-			updater.push_trailing_line(ViewLine::from(LineSegment::new_with_color(
-				"Press any key to continue",
-				DisplayColor::IndicatorColor,
-			)));
-		});
+		self.causes = error.chain().map(|cause| format!("{:#}", cause)).collect();
+		self.suggestions = suggestions(error);
+		self.wrap_width = None;
 	}
 }
 
 impl Error {
 	pub(crate) fn new() -> Self {
 		Self {
+			causes: vec![],
 			return_state: State::List,
+			suggestions: vec![],
 			view_data: ViewData::new(|updater| {
 				updater.set_show_title(true);
 				updater.set_retain_scroll_position(false);
 			}),
+			wrap_width: None,
 		}
 	}
-}
-
-#[cfg(test)]
-mod tests {
-	use anyhow::anyhow;
-	use input::{Event, MetaEvent};
-	use view::assert_rendered_output;
-
-	use super::*;
-	use crate::{assert_process_result, testutil::module_test};
-
-	#[test]
-	fn simple_error() {
-		module_test(&[], &[], |test_context| {
-			let mut module = Error::new();
-			module.handle_error(&anyhow!("Test Error"));
-			let view_data = test_context.build_view_data(&mut module);
-			assert_rendered_output!(
-				view_data,
-				"{TITLE}",
-				"{BODY}",
-				"{Normal}Test Error",
-				"{TRAILING}",
-				"{IndicatorColor}Press any key to continue"
-			);
-		});
-	}
-
-	#[test]
-	fn error_with_contest() {
-		module_test(&[], &[], |test_context| {
-			let mut module = Error::new();
-			module.handle_error(&anyhow!("Test Error").context("Context"));
-			let view_data = test_context.build_view_data(&mut module);
-			assert_rendered_output!(
-				view_data,
-				"{TITLE}",
-				"{BODY}",
-				"{Normal}Context",
-				"{Normal}Test Error",
-				"{TRAILING}",
-				"{IndicatorColor}Press any key to continue"
-			);
-		});
-	}
 
-	#[test]
-	fn error_with_newlines() {
-		module_test(&[], &[], |test_context| {
-			let mut module = Error::new();
-			module.handle_error(&anyhow!("Test\nError").context("With\nContext"));
-			let view_data = test_context.build_view_data(&mut module);
-			assert_rendered_output!(
-				view_data,
-				"{TITLE}",
-				"{BODY}",
-				"{Normal}With",
-				"{Normal}Context",
-				"{Normal}Test",
-				"{Normal}Error",
-				"{TRAILING}",
-				"{IndicatorColor}Press any key to continue"
-			);
+	// the error is shown with the operation that failed, followed by what caused it to fail, and the steps that may
+	// resolve it, with the lines wrapped to the width of the view, instead of being cut off
+	fn update_view_data(&mut self, width: usize) {
+		let causes = &self.causes;
+		let suggestions = &self.suggestions;
+		self.view_data.update_view_data(|updater| {
+			updater.clear();
+			let mut causes_iter = causes.iter();
+			if let Some(operation) = causes_iter.next() {
+				for line in wrap(operation, width) {
+					updater.push_leading_line(ViewLine::from(LineSegment::new_with_color(
+						line.as_str(),
+						DisplayColor::IndicatorColor,
+					)));
+				}
+			}
+			if causes.len() > 1 {
+				updater.push_line(ViewLine::from(LineSegment::new_with_color_and_style(
+					translate("Caused by:"),
+					DisplayColor::Normal,
+					true,
+					false,
+					false,
+				)));
+				for cause in causes_iter {
+					for line in wrap(cause, width - CAUSE_INDENT.len()) {
+						updater.push_line(ViewLine::from(format!("{}{}", CAUSE_INDENT, line).as_str()));
+					}
+				}
+				updater.push_line(ViewLine::new_empty_line());
+			}
+			updater.push_line(ViewLine::from(LineSegment::new_with_color_and_style(
+				translate("Suggestions:"),
+				DisplayColor::Normal,
+				true,
+				false,
+				false,
+			)));
+			for suggestion in suggestions {
+				for line in wrap(suggestion, width - CAUSE_INDENT.len()) {
+					updater.push_line(ViewLine::from(format!("{}{}", CAUSE_INDENT, line).as_str()));
+				}
+			}
+			updater.push_trailing_line(ViewLine::from(LineSegment::new_with_color(
+				translate("Up and down to scroll, c to copy the error, any other key to continue"),
+				DisplayColor::IndicatorColor,
+			)));
 		});
 	}
 
-	#[test]
-	fn return_state() {
-		module_test(&[], &[Event::from('a')], |mut test_context| {
-			let mut module = Error::new();
-			let _ = test_context.activate(&mut module, State::ConfirmRebase);
-			module.handle_error(&anyhow!("Test Error"));
-			assert_process_result!(
-				test_context.handle_event(&mut module),
-				event = Event::from('a'),
-				state = State::ConfirmRebase
-			);
-		});
+	// the text that is copied, in the format that anyhow uses to print an error, without the wrapping of the view
+	fn error_text(&self) -> String {
+		let mut text = self.causes.first().cloned().unwrap_or_default();
+		if self.causes.len() > 1 {
+			text.push_str("\n\nCaused by:");
+			for cause in self.causes.iter().skip(1) {
+				text.push_str("\n    ");
+				text.push_str(cause.as_str());
+			}
+		}
+		text
 	}
+}
 
-	#[test]
-	fn resize() {
-		module_test(&[], &[Event::Resize(100, 100)], |mut test_context| {
-			let mut module = Error::new();
-			let _ = test_context.activate(&mut module, State::ConfirmRebase);
-			module.handle_error(&anyhow!("Test Error"));
-			assert_process_result!(test_context.handle_event(&mut module), event = Event::Resize(100, 100));
-		});
+// the steps that may resolve an error, for the kinds of errors that are known, followed by the steps to find more
+// details about any error
+fn suggestions(error: &anyhow::Error) -> Vec<&'static str> {
+	let mut suggestions = vec![];
+	for cause in error.chain() {
+		let io_error = match cause.downcast_ref::<io::Error>() {
+			Some(io_error) => io_error,
+			None => continue,
+		};
+		let suggestion = match io_error.kind() {
+			io::ErrorKind::NotFound => translate("Check that the file, or the program, exists"),
+			io::ErrorKind::PermissionDenied => {
+				translate("Check that the file can be read and written by the current user")
+			},
+			_ => continue,
+		};
+		if !suggestions.contains(&suggestion) {
+			suggestions.push(suggestion);
+		}
 	}
+	suggestions.push(translate(
+		"Start the tool with --log-file and a path, to write the details of the error to a log",
+	));
+	suggestions.push(translate(
+		"Start the tool with --diagnostics, to show the configuration and the details of the repository",
+	));
+	suggestions
+}
 
-	#[test]
-	fn scroll_events() {
-		module_test(
-			&[],
-			&[
-				Event::from(MetaEvent::ScrollLeft),
-				Event::from(MetaEvent::ScrollRight),
-				Event::from(MetaEvent::ScrollDown),
-				Event::from(MetaEvent::ScrollUp),
-				Event::from(MetaEvent::ScrollJumpDown),
-				Event::from(MetaEvent::ScrollJumpUp),
-			],
-			|mut test_context| {
-				let mut module = Error::new();
-				let _ = test_context.activate(&mut module, State::ConfirmRebase);
-				module.handle_error(&anyhow!("Test Error"));
-				assert_process_result!(
-					test_context.handle_event(&mut module),
-					event = Event::from(MetaEvent::ScrollLeft)
-				);
-				assert_process_result!(
-					test_context.handle_event(&mut module),
-					event = Event::from(MetaEvent::ScrollRight)
-				);
-				assert_process_result!(
-					test_context.handle_event(&mut module),
-					event = Event::from(MetaEvent::ScrollDown)
-				);
-				assert_process_result!(
-					test_context.handle_event(&mut module),
-					event = Event::from(MetaEvent::ScrollUp)
-				);
-				assert_process_result!(
-					test_context.handle_event(&mut module),
-					event = Event::from(MetaEvent::ScrollJumpDown)
-				);
-				assert_process_result!(
-					test_context.handle_event(&mut module),
-					event = Event::from(MetaEvent::ScrollJumpUp)
-				);
-			},
-		);
+// lines are wrapped on spaces, and a word that is longer than a line is split over as many lines as it needs
+fn wrap(text: &str, width: usize) -> Vec<String> {
+	let mut lines = vec![];
+	for text_line in text.split('\n') {
+		let mut line = String::new();
+		let mut line_width = 0;
+		for word in text_line.split(' ') {
+			if line_width > 0 && line_width + 1 + UnicodeWidthStr::width(word) > width {
+				lines.push(mem::take(&mut line));
+				line_width = 0;
+			}
+			if line_width > 0 {
+				line.push(' ');
+				line_width += 1;
+			}
+			for c in word.chars() {
+				let char_width = UnicodeWidthChar::width(c).unwrap_or(0);
+				if line_width > 0 && line_width + char_width > width {
+					lines.push(mem::take(&mut line));
+					line_width = 0;
+				}
+				line.push(c);
+				line_width += char_width;
+			}
+		}
+		lines.push(line);
 	}
+	lines
 }
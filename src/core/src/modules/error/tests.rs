@@ -0,0 +1,265 @@
+use std::io;
+
+use anyhow::anyhow;
+use input::MetaEvent;
+use rstest::rstest;
+use view::{assert_rendered_output, render_line};
+
+use super::*;
+use crate::{assert_process_result, testutil::module_test};
+
+#[test]
+fn simple_error() {
+	module_test(&[], &[], |test_context| {
+		let mut module = Error::new();
+		module.handle_error(&anyhow!("Test Error"));
+		let view_data = test_context.build_view_data(&mut module);
+		assert_rendered_output!(
+			view_data,
+			"{TITLE}",
+			"{LEADING}",
+			"{IndicatorColor}Test Error",
+			"{BODY}",
+			"{Normal,Dimmed}Suggestions:",
+			"{Normal}  Start the tool with --log-file and a path, to write the details of the error to a log",
+			"{Normal}  Start the tool with --diagnostics, to show the configuration and the details of the repository",
+			"{TRAILING}",
+			"{IndicatorColor}Up and down to scroll, c to copy the error, any other key to continue"
+		);
+	});
+}
+
+#[test]
+fn error_with_context() {
+	module_test(&[], &[], |test_context| {
+		let mut module = Error::new();
+		module.handle_error(&anyhow!("Test Error").context("Context"));
+		let view_data = test_context.build_view_data(&mut module);
+		assert_rendered_output!(
+			view_data,
+			"{TITLE}",
+			"{LEADING}",
+			"{IndicatorColor}Context",
+			"{BODY}",
+			"{Normal,Dimmed}Caused by:",
+			"{Normal}  Test Error",
+			"",
+			"{Normal,Dimmed}Suggestions:",
+			"{Normal}  Start the tool with --log-file and a path, to write the details of the error to a log",
+			"{Normal}  Start the tool with --diagnostics, to show the configuration and the details of the repository",
+			"{TRAILING}",
+			"{IndicatorColor}Up and down to scroll, c to copy the error, any other key to continue"
+		);
+	});
+}
+
+#[test]
+fn error_with_newlines() {
+	module_test(&[], &[], |test_context| {
+		let mut module = Error::new();
+		module.handle_error(&anyhow!("Test\nError").context("With\nContext"));
+		let view_data = test_context.build_view_data(&mut module);
+		assert_rendered_output!(
+			view_data,
+			"{TITLE}",
+			"{LEADING}",
+			"{IndicatorColor}With",
+			"{IndicatorColor}Context",
+			"{BODY}",
+			"{Normal,Dimmed}Caused by:",
+			"{Normal}  Test",
+			"{Normal}  Error",
+			"",
+			"{Normal,Dimmed}Suggestions:",
+			"{Normal}  Start the tool with --log-file and a path, to write the details of the error to a log",
+			"{Normal}  Start the tool with --diagnostics, to show the configuration and the details of the repository",
+			"{TRAILING}",
+			"{IndicatorColor}Up and down to scroll, c to copy the error, any other key to continue"
+		);
+	});
+}
+
+#[test]
+fn error_wrapped_to_width() {
+	module_test(&[], &[], |mut test_context| {
+		test_context.render_context.update(40, 20);
+		let mut module = Error::new();
+		module.handle_error(&anyhow!("An error message that is longer than the width of the view"));
+		let view_data = test_context.build_view_data(&mut module);
+		assert_rendered_output!(
+			view_data,
+			"{TITLE}",
+			"{LEADING}",
+			"{IndicatorColor}An error message that is longer than",
+			"{IndicatorColor}the width of the view",
+			"{BODY}",
+			"{Normal,Dimmed}Suggestions:",
+			"{Normal}  Start the tool with --log-file and a",
+			"{Normal}  path, to write the details of the",
+			"{Normal}  error to a log",
+			"{Normal}  Start the tool with --diagnostics, to",
+			"{Normal}  show the configuration and the",
+			"{Normal}  details of the repository",
+			"{TRAILING}",
+			"{IndicatorColor}Up and down to scroll, c to copy the error, any other key to continue"
+		);
+	});
+}
+
+#[test]
+fn error_wrapped_after_resize() {
+	module_test(&[], &[], |mut test_context| {
+		let mut module = Error::new();
+		module.handle_error(&anyhow!("An error message that is longer than the width of the view"));
+		let _ = test_context.build_view_data(&mut module);
+		test_context.render_context.update(40, 20);
+		let view_data = test_context.build_view_data(&mut module);
+		assert_rendered_output!(
+			view_data,
+			"{TITLE}",
+			"{LEADING}",
+			"{IndicatorColor}An error message that is longer than",
+			"{IndicatorColor}the width of the view",
+			"{BODY}",
+			render_line!(AnyLine 7),
+			"{TRAILING}",
+			render_line!(AnyLine)
+		);
+	});
+}
+
+#[rstest]
+#[case::not_found(io::ErrorKind::NotFound, "Check that the file, or the program, exists")]
+#[case::permission_denied(
+	io::ErrorKind::PermissionDenied,
+	"Check that the file can be read and written by the current user"
+)]
+fn error_with_io_error_suggestion(#[case] kind: io::ErrorKind, #[case] suggestion: &str) {
+	module_test(&[], &[], |test_context| {
+		let mut module = Error::new();
+		module.handle_error(&anyhow::Error::from(io::Error::from(kind)).context("Unable to read the file"));
+		let view_data = test_context.build_view_data(&mut module);
+		assert_rendered_output!(
+			view_data,
+			"{TITLE}",
+			"{LEADING}",
+			"{IndicatorColor}Unable to read the file",
+			"{BODY}",
+			"{Normal,Dimmed}Caused by:",
+			render_line!(AnyLine),
+			"",
+			"{Normal,Dimmed}Suggestions:",
+			format!("{{Normal}}  {}", suggestion),
+			"{Normal}  Start the tool with --log-file and a path, to write the details of the error to a log",
+			"{Normal}  Start the tool with --diagnostics, to show the configuration and the details of the repository",
+			"{TRAILING}",
+			"{IndicatorColor}Up and down to scroll, c to copy the error, any other key to continue"
+		);
+	});
+}
+
+#[rstest]
+#[case::empty("", 10, &[""])]
+#[case::fits("one two", 10, &["one two"])]
+#[case::wrap_on_space("one two three", 7, &["one two", "three"])]
+#[case::long_word("a verylongword", 5, &["a", "veryl", "ongwo", "rd"])]
+#[case::newline("one\ntwo", 10, &["one", "two"])]
+#[case::wide_characters("🦀🦀🦀", 4, &["🦀🦀", "🦀"])]
+fn wrap_text(#[case] text: &str, #[case] width: usize, #[case] expected: &[&str]) {
+	assert_eq!(wrap(text, width), expected);
+}
+
+#[test]
+fn error_text() {
+	let mut module = Error::new();
+	module.handle_error(&anyhow!("Root").context("Middle").context("Operation"));
+	assert_eq!(module.error_text(), "Operation\n\nCaused by:\n    Middle\n    Root");
+}
+
+#[test]
+fn error_text_without_causes() {
+	let mut module = Error::new();
+	module.handle_error(&anyhow!("Operation"));
+	assert_eq!(module.error_text(), "Operation");
+}
+
+#[test]
+fn copy_error() {
+	module_test(&[], &[Event::from('c')], |mut test_context| {
+		let mut module = Error::new();
+		let _ = test_context.activate(&mut module, State::ConfirmRebase);
+		module.handle_error(&anyhow!("Test Error"));
+		let result = test_context.handle_event(&mut module);
+		assert_eq!(result.notification, Some(String::from("Copied the error to the clipboard")));
+		assert_process_result!(result, event = Event::from('c'));
+		test_context.view_sender_context.assert_sent_messages(vec!["CopyToClipboard"]);
+	});
+}
+
+#[test]
+fn return_state() {
+	module_test(&[], &[Event::from('a')], |mut test_context| {
+		let mut module = Error::new();
+		let _ = test_context.activate(&mut module, State::ConfirmRebase);
+		module.handle_error(&anyhow!("Test Error"));
+		assert_process_result!(
+			test_context.handle_event(&mut module),
+			event = Event::from('a'),
+			state = State::ConfirmRebase
+		);
+	});
+}
+
+#[test]
+fn resize() {
+	module_test(&[], &[Event::Resize(100, 100)], |mut test_context| {
+		let mut module = Error::new();
+		let _ = test_context.activate(&mut module, State::ConfirmRebase);
+		module.handle_error(&anyhow!("Test Error"));
+		assert_process_result!(test_context.handle_event(&mut module), event = Event::Resize(100, 100));
+	});
+}
+
+#[test]
+fn scroll_events() {
+	module_test(
+		&[],
+		&[
+			Event::from(MetaEvent::ScrollLeft),
+			Event::from(MetaEvent::ScrollRight),
+			Event::from(MetaEvent::ScrollDown),
+			Event::from(MetaEvent::ScrollUp),
+			Event::from(MetaEvent::ScrollJumpDown),
+			Event::from(MetaEvent::ScrollJumpUp),
+		],
+		|mut test_context| {
+			let mut module = Error::new();
+			let _ = test_context.activate(&mut module, State::ConfirmRebase);
+			module.handle_error(&anyhow!("Test Error"));
+			assert_process_result!(
+				test_context.handle_event(&mut module),
+				event = Event::from(MetaEvent::ScrollLeft)
+			);
+			assert_process_result!(
+				test_context.handle_event(&mut module),
+				event = Event::from(MetaEvent::ScrollRight)
+			);
+			assert_process_result!(
+				test_context.handle_event(&mut module),
+				event = Event::from(MetaEvent::ScrollDown)
+			);
+			assert_process_result!(
+				test_context.handle_event(&mut module),
+				event = Event::from(MetaEvent::ScrollUp)
+			);
+			assert_process_result!(
+				test_context.handle_event(&mut module),
+				event = Event::from(MetaEvent::ScrollJumpDown)
+			);
+			assert_process_result!(
+				test_context.handle_event(&mut module),
+				event = Event::from(MetaEvent::ScrollJumpUp)
+			);
+		},
+	);
+}
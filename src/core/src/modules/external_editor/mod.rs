@@ -14,6 +14,7 @@ use view::{RenderContext, ViewData, ViewLine, ViewSender};
 use self::{action::Action, argument_tokenizer::tokenize, external_editor_state::ExternalEditorState};
 use crate::{
 	components::choice::Choice,
+	i18n::{translate, translate_format},
 	module::{ExitStatus, Module, ProcessResult, State},
 };
 
@@ -21,6 +22,57 @@ lazy_static! {
 	static ref INPUT_OPTIONS: InputOptions = InputOptions::new();
 }
 
+/// Select the editor of a kind of file, which is the Git editor when the editor is not set.
+pub(crate) fn select_editor<'e>(editor: &'e str, git_editor: &'e str) -> &'e str {
+	if editor.trim().is_empty() {
+		git_editor
+	}
+	else {
+		editor
+	}
+}
+
+/// The command that opens the file in the editor, with the `%` arguments of the editor replaced by the path of the
+/// file, or with the path added after the arguments.
+pub(crate) fn editor_command(editor: &str, filepath: &str) -> Result<(String, Vec<String>)> {
+	let mut parameters = tokenize(editor)
+		.map_or(
+			Err(anyhow!(translate_format("Invalid editor: \"{}\"", &[&editor]))),
+			|args| {
+				if args.is_empty() {
+					Err(anyhow!(translate("No editor configured")))
+				}
+				else {
+					Ok(args.into_iter())
+				}
+			},
+		)
+		.map_err(|e| {
+			anyhow!(translate(
+				"Please see the git \"core.editor\" configuration for details"
+			))
+			.context(e)
+		})?;
+
+	let mut file_pattern_found = false;
+	let command = parameters.next().unwrap_or_else(|| String::from("false"));
+	let mut arguments = parameters
+		.map(|a| {
+			if a.as_str() == "%" {
+				file_pattern_found = true;
+				String::from(filepath)
+			}
+			else {
+				a
+			}
+		})
+		.collect::<Vec<String>>();
+	if !file_pattern_found {
+		arguments.push(String::from(filepath));
+	}
+	Ok((command, arguments))
+}
+
 pub(crate) struct ExternalEditor {
 	editor: String,
 	empty_choice: Choice<Action>,
@@ -59,7 +111,7 @@ impl Module for ExternalEditor {
 			ExternalEditorState::Active => {
 				self.view_data.update_view_data(|updater| {
 					updater.clear();
-					updater.push_leading_line(ViewLine::from("Editing..."));
+					updater.push_leading_line(ViewLine::from(translate("Editing...")));
 				});
 				&self.view_data
 			},
@@ -85,11 +137,21 @@ impl Module for ExternalEditor {
 				result = result.event(event);
 				match event {
 					Event::Meta(MetaEvent::ExternalCommandSuccess) => {
-						match todo_file.load_file() {
+						// the edited lines replace the lines of the list as a change that can be undone, and the
+						// problems that would stop Git from starting the rebase are shown before returning to the list
+						match todo_file.reload_file() {
 							Ok(_) => {
 								if todo_file.is_empty() || todo_file.is_noop() {
 									result = self.set_state(result, ExternalEditorState::Empty);
 								}
+								else if let Err(err) = todo_file.validate() {
+									result = self.set_state(
+										result,
+										ExternalEditorState::Error(
+											anyhow!(err).context(translate("The edited rebase file is not valid")),
+										),
+									);
+								}
 								else {
 									result = result.state(State::List);
 								}
@@ -100,7 +162,7 @@ impl Module for ExternalEditor {
 					Event::Meta(MetaEvent::ExternalCommandError) => {
 						result = self.set_state(
 							result,
-							ExternalEditorState::Error(anyhow!("Editor returned a non-zero exit status")),
+							ExternalEditorState::Error(anyhow!(translate("Editor returned a non-zero exit status"))),
 						);
 					},
 					_ => {},
@@ -157,28 +219,28 @@ impl ExternalEditor {
 		});
 
 		let mut empty_choice = Choice::new(vec![
-			(Action::AbortRebase, '1', String::from("Abort rebase")),
-			(Action::EditRebase, '2', String::from("Edit rebase file")),
+			(Action::AbortRebase, '1', String::from(translate("Abort rebase"))),
+			(Action::EditRebase, '2', String::from(translate("Edit rebase file"))),
 			(
 				Action::UndoAndEdit,
 				'3',
-				String::from("Undo modifications and edit rebase file"),
+				String::from(translate("Undo modifications and edit rebase file")),
 			),
 		]);
-		empty_choice.set_prompt(vec![ViewLine::from("The rebase file is empty.")]);
+		empty_choice.set_prompt(vec![ViewLine::from(translate("The rebase file is empty."))]);
 
 		let error_choice = Choice::new(vec![
-			(Action::AbortRebase, '1', String::from("Abort rebase")),
-			(Action::EditRebase, '2', String::from("Edit rebase file")),
+			(Action::AbortRebase, '1', String::from(translate("Abort rebase"))),
+			(Action::EditRebase, '2', String::from(translate("Edit rebase file"))),
 			(
 				Action::RestoreAndAbortEdit,
 				'3',
-				String::from("Restore rebase file and abort edit"),
+				String::from(translate("Restore rebase file and abort edit")),
 			),
 			(
 				Action::UndoAndEdit,
 				'4',
-				String::from("Undo modifications and edit rebase file"),
+				String::from(translate("Undo modifications and edit rebase file")),
 			),
 		]);
 
@@ -212,34 +274,6 @@ impl ExternalEditor {
 	}
 
 	fn get_command(&mut self, todo_file: &TodoFile) -> Result<(String, Vec<String>)> {
-		let mut parameters = tokenize(self.editor.as_str())
-			.map_or(Err(anyhow!("Invalid editor: \"{}\"", self.editor)), |args| {
-				if args.is_empty() {
-					Err(anyhow!("No editor configured"))
-				}
-				else {
-					Ok(args.into_iter())
-				}
-			})
-			.map_err(|e| anyhow!("Please see the git \"core.editor\" configuration for details").context(e))?;
-
-		let filepath = todo_file.get_filepath();
-		let mut file_pattern_found = false;
-		let command = parameters.next().unwrap_or_else(|| String::from("false"));
-		let mut arguments = parameters
-			.map(|a| {
-				if a.as_str() == "%" {
-					file_pattern_found = true;
-					String::from(filepath)
-				}
-				else {
-					a
-				}
-			})
-			.collect::<Vec<String>>();
-		if !file_pattern_found {
-			arguments.push(String::from(filepath));
-		}
-		Ok((command, arguments))
+		editor_command(self.editor.as_str(), todo_file.get_filepath())
 	}
 }
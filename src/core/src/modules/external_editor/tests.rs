@@ -1,4 +1,7 @@
+use std::fs::create_dir;
+
 use input::{Event, KeyCode};
+use rstest::rstest;
 use view::assert_rendered_output;
 
 use super::*;
@@ -69,14 +72,20 @@ fn activate() {
 
 #[test]
 fn activate_write_file_fail() {
-	module_test(&["pick aaa comment"], &[], |test_context| {
-		let todo_path = test_context.get_todo_file_path();
+	module_test(&["pick aaa comment"], &[], |mut test_context| {
+		// a directory cannot be replaced by the todo file
+		let directory = tempfile::tempdir().unwrap();
+		let todo_path = directory.path().join("git-rebase-todo");
+		create_dir(todo_path.as_path()).unwrap();
+		test_context.rebase_todo_file = TodoFile::new(todo_path.to_str().unwrap(), 1, "#");
+		test_context
+			.rebase_todo_file
+			.set_lines(vec![Line::new("pick aaa comment").unwrap()]);
 		let mut module = ExternalEditor::new("editor");
-		test_context.set_todo_file_readonly();
 		assert_process_result!(
 			test_context.activate(&mut module, State::List),
 			state = State::List,
-			error = anyhow!("Error opening file: {}: Permission denied (os error 13)", todo_path)
+			error = anyhow!("Error replacing file: {}: Is a directory (os error 21)", todo_path.display())
 		);
 	});
 }
@@ -125,6 +134,94 @@ fn edit_success() {
 	});
 }
 
+#[test]
+fn edit_success_undoable() {
+	module_test(&["pick aaa c1", "pick bbb c2"], &[], |mut test_context| {
+		test_context
+			.event_handler_context
+			.event_handler
+			.push_event(Event::from(MetaEvent::ExternalCommandSuccess));
+		let mut module = ExternalEditor::new("editor");
+		let _ = test_context.activate(&mut module, State::List);
+		std::fs::write(test_context.get_todo_file_path(), "pick bbb c2\ndrop aaa c1\n").unwrap();
+		assert_process_result!(
+			test_context.handle_event(&mut module),
+			event = Event::from(MetaEvent::ExternalCommandSuccess),
+			state = State::List
+		);
+		assert_eq!(test_context.rebase_todo_file.get_lines_owned(), vec![
+			Line::new("pick bbb c2").unwrap(),
+			Line::new("drop aaa c1").unwrap()
+		]);
+		let _ = test_context.rebase_todo_file.undo();
+		assert_eq!(test_context.rebase_todo_file.get_lines_owned(), vec![
+			Line::new("pick aaa c1").unwrap(),
+			Line::new("pick bbb c2").unwrap()
+		]);
+	});
+}
+
+#[test]
+fn edit_invalid_line() {
+	module_test(&["pick aaa c1"], &[], |mut test_context| {
+		test_context
+			.event_handler_context
+			.event_handler
+			.push_event(Event::from(MetaEvent::ExternalCommandSuccess));
+		let todo_path = test_context.get_todo_file_path();
+		let mut module = ExternalEditor::new("editor");
+		let _ = test_context.activate(&mut module, State::List);
+		std::fs::write(todo_path.as_str(), "pick aaa c1\njump bbb c2\n").unwrap();
+		let _ = test_context.handle_event(&mut module);
+		let view_data = test_context.build_view_data(&mut module);
+		assert_rendered_output!(
+			view_data,
+			"{TITLE}",
+			"{LEADING}",
+			"{Normal}Error on line 2: Invalid action: jump",
+			format!("{{Normal}}Error reading file: {}", todo_path),
+			"",
+			"{BODY}",
+			"{Normal}1) Abort rebase",
+			"{Normal}2) Edit rebase file",
+			"{Normal}3) Restore rebase file and abort edit",
+			"{Normal}4) Undo modifications and edit rebase file",
+			"",
+			"{IndicatorColor}Please choose an option."
+		);
+	});
+}
+
+#[test]
+fn edit_not_valid() {
+	module_test(&["pick aaa c1", "pick bbb c2"], &[], |mut test_context| {
+		test_context
+			.event_handler_context
+			.event_handler
+			.push_event(Event::from(MetaEvent::ExternalCommandSuccess));
+		let mut module = ExternalEditor::new("editor");
+		let _ = test_context.activate(&mut module, State::List);
+		std::fs::write(test_context.get_todo_file_path(), "fixup bbb c2\npick aaa c1\n").unwrap();
+		let _ = test_context.handle_event(&mut module);
+		let view_data = test_context.build_view_data(&mut module);
+		assert_rendered_output!(
+			view_data,
+			"{TITLE}",
+			"{LEADING}",
+			"{Normal}The edited rebase file is not valid",
+			"{Normal}The first commit, bbb, cannot be a fixup, since there is no previous commit",
+			"",
+			"{BODY}",
+			"{Normal}1) Abort rebase",
+			"{Normal}2) Edit rebase file",
+			"{Normal}3) Restore rebase file and abort edit",
+			"{Normal}4) Undo modifications and edit rebase file",
+			"",
+			"{IndicatorColor}Please choose an option."
+		);
+	});
+}
+
 #[test]
 fn empty_edit_error() {
 	module_test(&["pick aaa comment"], &[Event::from('1')], |mut test_context| {
@@ -399,3 +496,11 @@ fn error_undo_modifications_and_reedit() {
 		.unwrap()]);
 	});
 }
+
+#[rstest]
+#[case::set("nano", "nano")]
+#[case::empty("", "vi")]
+#[case::whitespace("  ", "vi")]
+fn select_editor_fallback(#[case] editor: &str, #[case] expected: &str) {
+	assert_eq!(select_editor(editor, "vi"), expected);
+}
@@ -3,17 +3,32 @@ pub(crate) enum LineType {
 	Cancel,
 	Pick,
 	Exec,
+	ExecTemplate,
 	Label,
 	Merge,
 	Reset,
 }
 
+impl LineType {
+	/// The name of the history of the edit of the line, where an exec from a template shares the history of an exec.
+	pub(crate) const fn history_name(&self) -> &'static str {
+		match *self {
+			Self::Cancel => "cancel",
+			Self::Pick => "pick",
+			Self::Exec | Self::ExecTemplate => "exec",
+			Self::Label => "label",
+			Self::Merge => "merge",
+			Self::Reset => "reset",
+		}
+	}
+}
+
 impl ToString for LineType {
 	fn to_string(&self) -> String {
 		match *self {
 			Self::Cancel => String::from("<cancel>"),
 			Self::Pick => String::from("pick"),
-			Self::Exec => String::from("exec"),
+			Self::Exec | Self::ExecTemplate => String::from("exec"),
 			Self::Label => String::from("label"),
 			Self::Merge => String::from("merge"),
 			Self::Reset => String::from("reset"),
@@ -31,10 +46,16 @@ mod tests {
 	#[case::cancel(&LineType::Cancel, "<cancel>")]
 	#[case::pick(&LineType::Pick, "pick")]
 	#[case::exec(&LineType::Exec, "exec")]
+	#[case::exec_template(&LineType::ExecTemplate, "exec")]
 	#[case::label(&LineType::Label, "label")]
 	#[case::merge(&LineType::Merge, "merge")]
 	#[case::reset(&LineType::Reset, "reset")]
 	fn to_string(#[case] line_type: &LineType, #[case] expected: &str) {
 		assert_eq!(line_type.to_string(), String::from(expected));
 	}
+
+	#[test]
+	fn history_name_exec_template() {
+		assert_eq!(LineType::ExecTemplate.history_name(), LineType::Exec.history_name());
+	}
 }
@@ -4,6 +4,7 @@ mod line_type;
 #[cfg(all(unix, test))]
 mod tests;
 
+use config::ExecTemplate;
 use input::EventHandler;
 use todo_file::{Line, TodoFile};
 use view::{RenderContext, ViewData, ViewLine, ViewSender};
@@ -11,14 +12,21 @@ use view::{RenderContext, ViewData, ViewLine, ViewSender};
 use self::{insert_state::InsertState, line_type::LineType};
 use crate::{
 	components::{choice::Choice, edit::Edit},
+	exec_template::expand_for_todo_file,
+	i18n::translate,
 	module::{Module, ProcessResult, State},
 };
 
+// the keys of the exec templates, in the order of the templates, without the key that cancels the choice
+const TEMPLATE_KEYS: &str = "123456789abcdefghijklmnoprstuvwxyz";
+
 pub(crate) struct Insert {
 	action_choices: Choice<LineType>,
 	edit: Edit,
+	exec_templates: Vec<ExecTemplate>,
 	line_type: LineType,
 	state: InsertState,
+	template_choices: Choice<Option<usize>>,
 }
 
 impl Module for Insert {
@@ -31,6 +39,7 @@ impl Module for Insert {
 	fn build_view_data(&mut self, _: &RenderContext, _: &TodoFile) -> &ViewData {
 		match self.state {
 			InsertState::Prompt => self.action_choices.get_view_data(),
+			InsertState::Template => self.template_choices.get_view_data(),
 			InsertState::Edit => self.edit.get_view_data(),
 		}
 	}
@@ -49,14 +58,35 @@ impl Module for Insert {
 					if action == &LineType::Cancel {
 						result = result.state(State::List);
 					}
+					else if action == &LineType::ExecTemplate {
+						self.state = InsertState::Template;
+					}
 					else {
 						self.line_type = action.clone();
 						self.edit.set_label(format!("{} ", action.to_string()).as_str());
+						self.edit.set_history(action.history_name());
 						self.state = InsertState::Edit;
 					}
 				}
 				result
 			},
+			InsertState::Template => {
+				let (choice, event) = self.template_choices.handle_event(event_handler, view_sender);
+				let mut result = ProcessResult::from(event);
+				match choice {
+					Some(&Some(index)) => {
+						let command = expand_for_todo_file(self.exec_templates[index].command.as_str(), rebase_todo);
+						self.line_type = LineType::Exec;
+						self.edit.set_label(format!("{} ", LineType::Exec.to_string()).as_str());
+						self.edit.set_content(command.as_str());
+						self.edit.set_history(LineType::Exec.history_name());
+						self.state = InsertState::Edit;
+					},
+					Some(&None) => result = result.state(State::List),
+					None => {},
+				}
+				result
+			},
 			InsertState::Edit => {
 				let mut result = ProcessResult::from(self.edit.handle_event(event_handler));
 				if self.edit.is_finished() {
@@ -69,8 +99,8 @@ impl Module for Insert {
 							LineType::Label => Line::new_label(content.as_str()),
 							LineType::Reset => Line::new_reset(content.as_str()),
 							LineType::Merge => Line::new_merge(content.as_str()),
-							// this should exit in the prompt state and never get here
-							LineType::Cancel => unreachable!(),
+							// these should exit in the prompt or template state and never get here
+							LineType::Cancel | LineType::ExecTemplate => unreachable!(),
 						};
 						let new_line_index = rebase_todo.get_selected_line_index() + 1;
 						rebase_todo.add_line(new_line_index, line);
@@ -84,12 +114,17 @@ impl Module for Insert {
 }
 
 impl Insert {
-	pub(crate) fn new() -> Self {
+	pub(crate) fn new(exec_templates: &[ExecTemplate]) -> Self {
 		let mut edit = Edit::new();
-		edit.set_description("Enter contents of the new line. Empty content cancels creation of a new line.");
+		edit.set_description(translate(
+			"Enter contents of the new line. Empty content cancels creation of a new line.",
+		));
 
-		let mut action_choices = Choice::new(vec![
-			(LineType::Exec, 'e', String::from("exec <command>")),
+		let mut line_types = vec![(LineType::Exec, 'e', String::from("exec <command>"))];
+		if !exec_templates.is_empty() {
+			line_types.push((LineType::ExecTemplate, 't', String::from("exec <template>")));
+		}
+		line_types.extend([
 			(LineType::Pick, 'p', String::from("pick <hash>")),
 			(LineType::Label, 'l', String::from("label <label>")),
 			(LineType::Reset, 'r', String::from("reset <label>")),
@@ -98,15 +133,28 @@ impl Insert {
 				'm',
 				String::from("merge [-C <commit> | -c <commit>] <label> [# <oneline>]"),
 			),
-			(LineType::Cancel, 'q', String::from("Cancel add line")),
+			(LineType::Cancel, 'q', String::from(translate("Cancel add line"))),
 		]);
-		action_choices.set_prompt(vec![ViewLine::from("Select the type of line to insert:")]);
+		let mut action_choices = Choice::new(line_types);
+		action_choices.set_prompt(vec![ViewLine::from(translate("Select the type of line to insert:"))]);
+
+		let mut templates: Vec<(Option<usize>, char, String)> = exec_templates
+			.iter()
+			.zip(TEMPLATE_KEYS.chars())
+			.enumerate()
+			.map(|(index, (template, key))| (Some(index), key, format!("{}: {}", template.name, template.command)))
+			.collect();
+		templates.push((None, 'q', String::from(translate("Cancel add line"))));
+		let mut template_choices = Choice::new(templates);
+		template_choices.set_prompt(vec![ViewLine::from(translate("Select the exec template to insert:"))]);
 
 		Self {
 			state: InsertState::Prompt,
 			edit,
+			exec_templates: exec_templates.to_vec(),
 			action_choices,
 			line_type: LineType::Exec,
+			template_choices,
 		}
 	}
 }
@@ -1,3 +1,4 @@
+use config::ExecTemplate;
 use input::{Event, KeyCode};
 use view::assert_rendered_output;
 
@@ -7,7 +8,7 @@ use crate::{assert_process_result, testutil::module_test};
 #[test]
 fn activate() {
 	module_test(&[], &[], |test_context| {
-		let mut module = Insert::new();
+		let mut module = Insert::new(&[]);
 		assert_process_result!(test_context.activate(&mut module, State::List));
 	});
 }
@@ -15,7 +16,7 @@ fn activate() {
 #[test]
 fn render_prompt() {
 	module_test(&[], &[], |test_context| {
-		let mut module = Insert::new();
+		let mut module = Insert::new(&[]);
 		let view_data = test_context.build_view_data(&mut module);
 		assert_rendered_output!(
 			view_data,
@@ -39,7 +40,7 @@ fn render_prompt() {
 #[test]
 fn prompt_cancel() {
 	module_test(&[], &[Event::from('q')], |mut test_context| {
-		let mut module = Insert::new();
+		let mut module = Insert::new(&[]);
 		assert_process_result!(
 			test_context.handle_event(&mut module),
 			event = Event::from('q'),
@@ -60,7 +61,7 @@ fn edit_render_exec() {
 			Event::from(KeyCode::Enter),
 		],
 		|mut test_context| {
-			let mut module = Insert::new();
+			let mut module = Insert::new(&[]);
 			let _ = test_context.handle_n_events(&mut module, 4);
 			let view_data = test_context.build_view_data(&mut module);
 			assert_rendered_output!(
@@ -96,7 +97,7 @@ fn edit_render_pick() {
 			Event::from(KeyCode::Enter),
 		],
 		|mut test_context| {
-			let mut module = Insert::new();
+			let mut module = Insert::new(&[]);
 			let _ = test_context.handle_n_events(&mut module, 4);
 			let view_data = test_context.build_view_data(&mut module);
 			assert_rendered_output!(
@@ -135,7 +136,7 @@ fn edit_render_label() {
 			Event::from(KeyCode::Enter),
 		],
 		|mut test_context| {
-			let mut module = Insert::new();
+			let mut module = Insert::new(&[]);
 			let _ = test_context.handle_n_events(&mut module, 4);
 			let view_data = test_context.build_view_data(&mut module);
 			assert_rendered_output!(
@@ -174,7 +175,7 @@ fn edit_render_reset() {
 			Event::from(KeyCode::Enter),
 		],
 		|mut test_context| {
-			let mut module = Insert::new();
+			let mut module = Insert::new(&[]);
 			let _ = test_context.handle_n_events(&mut module, 4);
 			let view_data = test_context.build_view_data(&mut module);
 			assert_rendered_output!(
@@ -213,7 +214,7 @@ fn edit_render_merge() {
 			Event::from(KeyCode::Enter),
 		],
 		|mut test_context| {
-			let mut module = Insert::new();
+			let mut module = Insert::new(&[]);
 			let _ = test_context.handle_n_events(&mut module, 4);
 			let view_data = test_context.build_view_data(&mut module);
 			assert_rendered_output!(
@@ -252,7 +253,7 @@ fn edit_select_next_index() {
 			Event::from(KeyCode::Enter),
 		],
 		|mut test_context| {
-			let mut module = Insert::new();
+			let mut module = Insert::new(&[]);
 			let _ = test_context.handle_all_events(&mut module);
 			assert_eq!(test_context.rebase_todo_file.get_selected_line_index(), 1);
 		},
@@ -265,9 +266,143 @@ fn cancel_edit() {
 		&[],
 		&[Event::from('e'), Event::from(KeyCode::Enter)],
 		|mut test_context| {
-			let mut module = Insert::new();
+			let mut module = Insert::new(&[]);
 			let _ = test_context.handle_all_events(&mut module);
 			assert!(test_context.rebase_todo_file.is_empty());
 		},
 	);
 }
+
+fn exec_templates() -> Vec<ExecTemplate> {
+	vec![
+		ExecTemplate {
+			name: String::from("test"),
+			command: String::from("cargo test"),
+		},
+		ExecTemplate {
+			name: String::from("show"),
+			command: String::from("git show {commit} # {subject}"),
+		},
+	]
+}
+
+#[test]
+fn render_prompt_with_exec_templates() {
+	module_test(&[], &[], |test_context| {
+		let mut module = Insert::new(&exec_templates());
+		let view_data = test_context.build_view_data(&mut module);
+		assert_rendered_output!(
+			view_data,
+			"{TITLE}",
+			"{LEADING}",
+			"{Normal}Select the type of line to insert:",
+			"",
+			"{BODY}",
+			"{Normal}e) exec <command>",
+			"{Normal}t) exec <template>",
+			"{Normal}p) pick <hash>",
+			"{Normal}l) label <label>",
+			"{Normal}r) reset <label>",
+			"{Normal}m) merge [-C <commit> | -c <commit>] <label> [# <oneline>]",
+			"{Normal}q) Cancel add line",
+			"",
+			"{IndicatorColor}Please choose an option."
+		);
+	});
+}
+
+#[test]
+fn render_exec_templates() {
+	module_test(&[], &[Event::from('t')], |mut test_context| {
+		let mut module = Insert::new(&exec_templates());
+		let _ = test_context.handle_all_events(&mut module);
+		let view_data = test_context.build_view_data(&mut module);
+		assert_rendered_output!(
+			view_data,
+			"{TITLE}",
+			"{LEADING}",
+			"{Normal}Select the exec template to insert:",
+			"",
+			"{BODY}",
+			"{Normal}1) test: cargo test",
+			"{Normal}2) show: git show {commit} # {subject}",
+			"{Normal}q) Cancel add line",
+			"",
+			"{IndicatorColor}Please choose an option."
+		);
+	});
+}
+
+#[test]
+fn exec_template_expanded() {
+	module_test(
+		&["pick aaa c1", "pick bbb c2"],
+		&[Event::from('t'), Event::from('2'), Event::from(KeyCode::Enter)],
+		|mut test_context| {
+			let mut module = Insert::new(&exec_templates());
+			test_context.rebase_todo_file.set_selected_line_index(1);
+			let _ = test_context.handle_n_events(&mut module, 2);
+			let view_data = test_context.build_view_data(&mut module);
+			assert_rendered_output!(
+				view_data,
+				"{TITLE}",
+				"{LEADING}",
+				"{IndicatorColor}Enter contents of the new line. Empty content cancels creation of a new line.",
+				"",
+				"{BODY}",
+				"{Normal,Dimmed}exec {Normal}git show bbb # c2{Normal,Underline}",
+				"{TRAILING}",
+				"{IndicatorColor}Enter to finish"
+			);
+			assert_process_result!(
+				test_context.handle_event(&mut module),
+				event = Event::from(KeyCode::Enter),
+				state = State::List
+			);
+			assert_eq!(
+				test_context.rebase_todo_file.get_line(2).unwrap().to_text(),
+				"exec git show bbb # c2"
+			);
+			assert_eq!(test_context.rebase_todo_file.get_selected_line_index(), 2);
+		},
+	);
+}
+
+#[test]
+fn exec_template_cancel() {
+	module_test(&[], &[Event::from('t'), Event::from('q')], |mut test_context| {
+		let mut module = Insert::new(&exec_templates());
+		let _ = test_context.handle_event(&mut module);
+		assert_process_result!(
+			test_context.handle_event(&mut module),
+			event = Event::from('q'),
+			state = State::List
+		);
+		assert!(test_context.rebase_todo_file.is_empty());
+	});
+}
+
+#[test]
+fn exec_template_without_templates() {
+	module_test(&[], &[Event::from('t')], |mut test_context| {
+		let mut module = Insert::new(&[]);
+		let _ = test_context.handle_all_events(&mut module);
+		let view_data = test_context.build_view_data(&mut module);
+		assert_rendered_output!(
+			view_data,
+			"{TITLE}",
+			"{LEADING}",
+			"{Normal}Select the type of line to insert:",
+			"",
+			"{BODY}",
+			"{Normal}e) exec <command>",
+			"{Normal}p) pick <hash>",
+			"{Normal}l) label <label>",
+			"{Normal}r) reset <label>",
+			"{Normal}m) merge [-C <commit> | -c <commit>] <label> [# <oneline>]",
+			"{Normal}q) Cancel add line",
+			"",
+			"{IndicatorColor}Invalid option selected. Please choose an option."
+		);
+	});
+}
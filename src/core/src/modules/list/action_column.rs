@@ -0,0 +1,156 @@
+use config::{ActionLabel, ActionLabels};
+use todo_file::Action;
+use unicode_width::UnicodeWidthStr;
+
+use crate::icons::get_action_icon;
+
+/// Formats the action of a line, using the configured labels, padded to the width of the widest label so that the
+/// columns after the action line up when the labels have different widths, such as with icons.
+#[derive(Debug)]
+pub(super) struct ActionColumn {
+	abbreviation_width: usize,
+	labels: ActionLabels,
+	name_width: usize,
+}
+
+impl ActionColumn {
+	/// Create the column, with `icons` replacing the labels that have not been changed from the defaults with icons.
+	pub(super) fn new(labels: &ActionLabels, icons: bool) -> Self {
+		let mut column_labels = labels.clone();
+		if icons {
+			let defaults = ActionLabels::new();
+			for action in [
+				Action::Break,
+				Action::Drop,
+				Action::Edit,
+				Action::Exec,
+				Action::Fixup,
+				Action::Pick,
+				Action::Reword,
+				Action::Squash,
+				Action::Label,
+				Action::Reset,
+				Action::Merge,
+			] {
+				if let Some(label) = Self::label_mut(&mut column_labels, action) {
+					if Self::label_of(&defaults, action) == Some(&*label) {
+						label.name = String::from(get_action_icon(action));
+						label.abbreviation = String::from(get_action_icon(action));
+					}
+				}
+			}
+		}
+		let all = column_labels.all();
+		Self {
+			abbreviation_width: all.iter().map(|label| label.abbreviation.width()).max().unwrap_or(0),
+			name_width: all.iter().map(|label| label.name.width()).max().unwrap_or(0),
+			labels: column_labels,
+		}
+	}
+
+	fn label_mut(labels: &mut ActionLabels, action: Action) -> Option<&mut ActionLabel> {
+		match action {
+			Action::Break => Some(&mut labels.action_break),
+			Action::Drop => Some(&mut labels.action_drop),
+			Action::Edit => Some(&mut labels.action_edit),
+			Action::Exec => Some(&mut labels.action_exec),
+			Action::Fixup => Some(&mut labels.action_fixup),
+			Action::Pick => Some(&mut labels.action_pick),
+			Action::Reword => Some(&mut labels.action_reword),
+			Action::Squash => Some(&mut labels.action_squash),
+			Action::Label => Some(&mut labels.action_label),
+			Action::Reset => Some(&mut labels.action_reset),
+			Action::Merge => Some(&mut labels.action_merge),
+			Action::Noop => None,
+		}
+	}
+
+	const fn label_of(labels: &ActionLabels, action: Action) -> Option<&ActionLabel> {
+		match action {
+			Action::Break => Some(&labels.action_break),
+			Action::Drop => Some(&labels.action_drop),
+			Action::Edit => Some(&labels.action_edit),
+			Action::Exec => Some(&labels.action_exec),
+			Action::Fixup => Some(&labels.action_fixup),
+			Action::Pick => Some(&labels.action_pick),
+			Action::Reword => Some(&labels.action_reword),
+			Action::Squash => Some(&labels.action_squash),
+			Action::Label => Some(&labels.action_label),
+			Action::Reset => Some(&labels.action_reset),
+			Action::Merge => Some(&labels.action_merge),
+			// noops are never rendered, so do not have a label
+			Action::Noop => None,
+		}
+	}
+
+	const fn label(&self, action: Action) -> Option<&ActionLabel> {
+		Self::label_of(&self.labels, action)
+	}
+
+	/// Format the action with a trailing space, using the name in a full width window, and otherwise the
+	/// abbreviation.
+	pub(super) fn format(&self, action: Action, is_full_width: bool) -> String {
+		let (text, width) = match self.label(action) {
+			Some(label) if is_full_width => (label.name.clone(), self.name_width),
+			Some(label) => (label.abbreviation.clone(), self.abbreviation_width),
+			None if is_full_width => (action.as_string(), self.name_width),
+			None => (action.to_abbreviation(), self.abbreviation_width),
+		};
+		let padding = " ".repeat(width.saturating_sub(text.width()));
+		format!("{}{} ", text, padding)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use rstest::rstest;
+
+	use super::*;
+
+	#[rstest]
+	#[case::full_width(Action::Pick, true, "pick   ")]
+	#[case::full_width_widest(Action::Reword, true, "reword ")]
+	#[case::abbreviation(Action::Pick, false, "p ")]
+	#[case::noop_full_width(Action::Noop, true, "noop   ")]
+	#[case::noop_abbreviation(Action::Noop, false, "n ")]
+	fn format_default(#[case] action: Action, #[case] is_full_width: bool, #[case] expected: &str) {
+		let column = ActionColumn::new(&ActionLabels::new(), false);
+		assert_eq!(column.format(action, is_full_width), expected);
+	}
+
+	#[test]
+	fn format_wide_characters() {
+		let mut labels = ActionLabels::new();
+		labels.action_pick.abbreviation = String::from("🍒");
+		labels.action_fixup.name = String::from("🔧");
+		let column = ActionColumn::new(&labels, false);
+		assert_eq!(column.format(Action::Pick, false), "🍒 ");
+		assert_eq!(column.format(Action::Drop, false), "d  ");
+		assert_eq!(column.format(Action::Fixup, true), "🔧     ");
+	}
+
+	#[test]
+	fn format_longer_name() {
+		let mut labels = ActionLabels::new();
+		labels.action_drop.name = String::from("discard");
+		let column = ActionColumn::new(&labels, false);
+		assert_eq!(column.format(Action::Drop, true), "discard ");
+		assert_eq!(column.format(Action::Pick, true), "pick    ");
+	}
+
+	#[test]
+	fn format_icons() {
+		let column = ActionColumn::new(&ActionLabels::new(), true);
+		assert_eq!(column.format(Action::Pick, true), "\u{f417} ");
+		assert_eq!(column.format(Action::Squash, false), "\u{f419} ");
+	}
+
+	#[test]
+	fn format_icons_keeps_configured_labels() {
+		let mut labels = ActionLabels::new();
+		labels.action_drop.name = String::from("discard");
+		let column = ActionColumn::new(&labels, true);
+		assert_eq!(column.format(Action::Drop, true), "discard ");
+		assert_eq!(column.format(Action::Pick, true), "\u{f417}       ");
+	}
+}
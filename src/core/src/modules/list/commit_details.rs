@@ -0,0 +1,46 @@
+use std::path::PathBuf;
+
+use chrono::{DateTime, Local};
+use git::{CommitDiffLoaderOptions, GitBackend};
+
+/// The details of a commit, shown in the additional columns and the preview pane of the wide layout.
+#[derive(Debug, Clone, PartialEq)]
+pub(super) struct CommitDetails {
+	pub(super) author: String,
+	pub(super) cherry_picked_from: Option<String>,
+	pub(super) committed_date: DateTime<Local>,
+	pub(super) date: DateTime<Local>,
+	pub(super) deletions: usize,
+	pub(super) files_changed: usize,
+	pub(super) insertions: usize,
+	// the paths of the changed files, with both paths of a renamed file
+	pub(super) paths: Vec<PathBuf>,
+	pub(super) summary: String,
+}
+
+impl CommitDetails {
+	pub(super) fn load(repository: &dyn GitBackend, hash: &str) -> Option<Self> {
+		let diff = repository
+			.load_commit_diff(hash, &CommitDiffLoaderOptions::new())
+			.ok()?;
+		let commit = diff.commit();
+		let mut paths = vec![];
+		for file_status in diff.file_statuses() {
+			paths.push(file_status.destination_path().to_path_buf());
+			if file_status.source_path() != file_status.destination_path() {
+				paths.push(file_status.source_path().to_path_buf());
+			}
+		}
+		Some(Self {
+			author: commit.author().to_string(),
+			cherry_picked_from: commit.cherry_picked_from().map(String::from),
+			committed_date: *commit.committed_date(),
+			date: commit.authored_date().unwrap_or(*commit.committed_date()),
+			deletions: diff.number_deletions(),
+			files_changed: diff.number_files_changed(),
+			insertions: diff.number_insertions(),
+			paths,
+			summary: commit.summary().clone().unwrap_or_default(),
+		})
+	}
+}
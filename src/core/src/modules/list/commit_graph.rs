@@ -0,0 +1,211 @@
+use std::collections::{HashMap, HashSet};
+
+use todo_file::{Action, TodoFile};
+
+// Each branch of the rebase gets a lane, that uses two columns, the first for the lane and the second for any line
+// that connects the lane to the lane to its right.
+struct CommitGraph<'t> {
+	current: usize,
+	// the labels on each active lane that are still to be merged, an active lane is kept until its labels are merged
+	lanes: Vec<Option<Vec<&'t str>>>,
+	labels: HashMap<&'t str, usize>,
+}
+
+impl<'t> CommitGraph<'t> {
+	fn new() -> Self {
+		Self {
+			current: 0,
+			lanes: vec![Some(vec![])],
+			labels: HashMap::new(),
+		}
+	}
+
+	fn is_active(&self, lane: usize) -> bool {
+		self.lanes.get(lane).map_or(false, Option::is_some)
+	}
+
+	fn row(&self) -> Vec<char> {
+		self.lanes
+			.iter()
+			.flat_map(|lane| [if lane.is_some() { '|' } else { ' ' }, ' '])
+			.collect()
+	}
+
+	// a line from the first lane to the second, crossing any lanes between them, with the ends left to the caller
+	fn connect(row: &mut [char], from: usize, to: usize) {
+		for column in &mut row[(from.min(to) * 2 + 1)..(from.max(to) * 2)] {
+			*column = if *column == '|' { '+' } else { '-' };
+		}
+	}
+
+	fn label(&mut self, label: &'t str, is_merged: bool) {
+		let _previous = self.labels.insert(label, self.current);
+		if is_merged {
+			if let Some(&mut Some(ref mut pending)) = self.lanes.get_mut(self.current) {
+				pending.push(label);
+			}
+		}
+	}
+
+	fn reset(&mut self, target: Option<&str>) -> Vec<char> {
+		// the lane that is left is kept when one of its labels is merged later, otherwise it ends at the reset
+		if self.lanes[self.current].as_ref().map_or(true, Vec::is_empty) {
+			self.lanes[self.current] = None;
+		}
+		let origin = target
+			.and_then(|label| self.labels.get(label))
+			.copied()
+			.filter(|&lane| self.is_active(lane));
+		self.current = self.lanes.iter().position(Option::is_none).unwrap_or_else(|| {
+			self.lanes.push(None);
+			self.lanes.len() - 1
+		});
+		self.lanes[self.current] = Some(vec![]);
+		let mut row = self.row();
+		if let Some(origin_lane) = origin {
+			Self::connect(&mut row, origin_lane, self.current);
+		}
+		row[self.current * 2] = '.';
+		row
+	}
+
+	fn merge(&mut self, labels: &[&str]) -> Vec<char> {
+		let mut ends = vec![];
+		for label in labels {
+			let lane = match self.labels.get(label) {
+				Some(&lane) => lane,
+				None => continue,
+			};
+			if lane == self.current || !self.is_active(lane) {
+				continue;
+			}
+			if let Some(&mut Some(ref mut pending)) = self.lanes.get_mut(lane) {
+				pending.retain(|pending_label| pending_label != label);
+				if pending.is_empty() {
+					self.lanes[lane] = None;
+					ends.push(lane);
+				}
+			}
+		}
+		let mut row = self.row();
+		for &lane in &ends {
+			Self::connect(&mut row, lane, self.current);
+		}
+		for lane in ends {
+			row[lane * 2] = '\'';
+		}
+		row[self.current * 2] = '*';
+		row
+	}
+
+	fn commit(&self) -> Vec<char> {
+		let mut row = self.row();
+		row[self.current * 2] = '*';
+		row
+	}
+}
+
+// A graph of the branches of a rebase with merges, like the graph of `git log --graph`, but with the oldest commit
+// first, from the structure of the label, reset and merge lines. There is a row for each line of the todo file, and the
+// rows are padded to the width of the widest row.
+pub(super) fn build_commit_graph(todo_file: &TodoFile) -> Vec<String> {
+	let merged_labels: HashSet<&str> = todo_file
+		.lines_iter()
+		.filter(|line| *line.get_action() == Action::Merge)
+		.flat_map(|line| line.get_labels())
+		.collect();
+	let mut lanes = CommitGraph::new();
+	let rows: Vec<Vec<char>> = todo_file
+		.lines_iter()
+		.map(|line| {
+			match *line.get_action() {
+				Action::Label => {
+					if let Some(&label) = line.get_labels().first() {
+						lanes.label(label, merged_labels.contains(label));
+					}
+					lanes.row()
+				},
+				Action::Reset => lanes.reset(line.get_labels().first().copied()),
+				Action::Merge => lanes.merge(line.get_labels().as_slice()),
+				Action::Drop | Action::Edit | Action::Fixup | Action::Pick | Action::Reword | Action::Squash => {
+					lanes.commit()
+				},
+				Action::Break | Action::Exec | Action::Noop => lanes.row(),
+			}
+		})
+		.collect();
+	let width = rows.iter().map(Vec::len).max().unwrap_or(0);
+	rows.into_iter()
+		.map(|row| format!("{:width$}", row.into_iter().collect::<String>(), width = width))
+		.collect()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn graph(lines: &[&str]) -> Vec<String> {
+		let mut todo_file = TodoFile::new("git-rebase-todo", 1, "#");
+		todo_file.set_lines(lines.iter().map(|line| todo_file::Line::new(line).unwrap()).collect());
+		build_commit_graph(&todo_file)
+	}
+
+	#[test]
+	fn without_branches() {
+		assert_eq!(graph(&["pick aaa c1", "exec make", "pick bbb c2"]), vec!["* ", "| ", "* "]);
+	}
+
+	#[test]
+	fn merged_branch() {
+		assert_eq!(
+			graph(&[
+				"label onto",
+				"reset onto",
+				"pick aaa side",
+				"label side",
+				"reset onto",
+				"pick bbb main",
+				"merge -C ccc side # Merge side",
+			]),
+			vec!["|   ", ".   ", "*   ", "|   ", "|-. ", "| * ", "'-* "]
+		);
+	}
+
+	#[test]
+	fn branch_that_is_not_merged() {
+		assert_eq!(
+			graph(&["label onto", "pick aaa side", "label side", "reset onto", "pick bbb main"]),
+			vec!["| ", "* ", "| ", ". ", "* "]
+		);
+	}
+
+	#[test]
+	fn octopus_merge() {
+		assert_eq!(
+			graph(&[
+				"label onto",
+				"pick aaa one",
+				"label one",
+				"reset onto",
+				"pick bbb two",
+				"label two",
+				"reset onto",
+				"pick ccc main",
+				"merge -C ddd one two",
+			]),
+			vec![
+				"|     ", "*     ", "|     ", "|-.   ", "| *   ", "| |   ", "|-+-. ", "| | * ", "'-'-* ",
+			]
+		);
+	}
+
+	#[test]
+	fn merge_of_unknown_label() {
+		assert_eq!(graph(&["pick aaa c1", "merge -C bbb missing"]), vec!["* ", "* "]);
+	}
+
+	#[test]
+	fn reset_to_commit() {
+		assert_eq!(graph(&["pick aaa c1", "reset bbb", "pick ccc c2"]), vec!["* ", ". ", "* "]);
+	}
+}
@@ -0,0 +1,180 @@
+use std::{
+	path::{Path, PathBuf},
+	sync::mpsc::{channel, Receiver, Sender},
+};
+
+use git::{GitBackend, RangeTopology, Repository};
+
+use super::commit_details::CommitDetails;
+use crate::{
+	event_bus::{BusEvent, BusSender},
+	git_backend::open_alternate_backend,
+	tasks::{CancellationToken, Priority, TaskManager},
+};
+
+// Git uses at least seven characters for automatically abbreviated hashes
+const MINIMUM_ABBREVIATION_LENGTH: usize = 7;
+
+/// A detail of the repository, or of a commit in the todo list, that was requested to be loaded in the background.
+#[derive(Debug)]
+enum DetailRequest {
+	AbbreviationLength(Vec<String>),
+	Commit(String),
+}
+
+/// A detail of the repository, or of a commit in the todo list, that was loaded in the background.
+#[derive(Debug)]
+pub(super) enum LoadedDetail {
+	AbbreviationLength(usize),
+	Commit(String, Option<CommitDetails>),
+	Topology(RangeTopology),
+	UncommittedChanges(bool),
+	Warning(String),
+}
+
+/// Loads the details of the repository and of the commits in the todo list on a worker of the task manager, with its
+/// own instance of the repository, so that the list can be shown before the repository status has been read, and the
+/// commit details are filled in as they are loaded.
+pub(crate) struct DetailsLoader {
+	receiver: Receiver<LoadedDetail>,
+	request_sender: Option<Sender<DetailRequest>>,
+	token: CancellationToken,
+}
+
+impl DetailsLoader {
+	/// Start loading the details of the repository that owns the todo file at `todo_file_path`, with an event sent to
+	/// the bus for each detail that is loaded.
+	pub(crate) fn spawn(todo_file_path: &Path, events: BusSender, tasks: &TaskManager) -> Self {
+		let (sender, receiver) = channel();
+		let (request_sender, request_receiver) = channel::<DetailRequest>();
+		let todo_file_path = PathBuf::from(todo_file_path);
+
+		// the details are shown with the list, so they are loaded before the other work
+		let token = tasks.spawn("load details", Priority::High, move |token| {
+			// the detail is sent before the event, so that it can be taken when the event is dispatched
+			let send = |loaded_detail: LoadedDetail| -> bool {
+				let sent = !token.is_cancelled() && sender.send(loaded_detail).is_ok();
+				if sent {
+					events.send(BusEvent::DetailsLoaded);
+				}
+				sent
+			};
+			let repository = match Repository::open_for_todo_file(todo_file_path.as_path()) {
+				Ok(repository) => Some(repository),
+				Err(err) => {
+					log::warn!(target: "git", "Unable to open repository to load details: {:#}", err);
+					None
+				},
+			};
+			if let Some(ref repository) = repository {
+				let has_uncommitted_changes = repository.has_uncommitted_changes().unwrap_or(false);
+				if !send(LoadedDetail::UncommittedChanges(has_uncommitted_changes)) {
+					return;
+				}
+				for rebase in repository.find_conflicting_worktree_rebases() {
+					let warning = format!(
+						"{} is also being rebased in the worktree at {}",
+						rebase.head_name().trim_start_matches("refs/heads/"),
+						rebase.work_tree().display()
+					);
+					if !send(LoadedDetail::Warning(warning)) {
+						return;
+					}
+				}
+				if let Some((onto, orig_head)) = repository.rebase_range() {
+					match repository.load_range_topology(onto.as_str(), orig_head.as_str()) {
+						Ok(topology) => {
+							log::info!(
+								target: "git",
+								"Rebasing {} commits onto {}, which has {} commits that are not rebased",
+								topology.ahead(),
+								onto,
+								topology.behind()
+							);
+							if !send(LoadedDetail::Topology(topology)) {
+								return;
+							}
+						},
+						Err(err) => {
+							log::warn!(target: "git", "Unable to load the topology of the rebased commits: {:#}", err);
+						},
+					}
+				}
+			}
+			let alternate_backend = repository.as_ref().and_then(open_alternate_backend);
+			let backend: Option<&dyn GitBackend> = alternate_backend
+				.as_deref()
+				.or_else(|| repository.as_ref().map(|repository| -> &dyn GitBackend { repository }));
+			// requests are still answered without a repository, so that the commits are shown as not available
+			for request in request_receiver {
+				let loaded_detail = match request {
+					DetailRequest::AbbreviationLength(hashes) => {
+						LoadedDetail::AbbreviationLength(backend.map_or(MINIMUM_ABBREVIATION_LENGTH, |backend| {
+							backend
+								.unique_abbreviation_length(
+									hashes.iter().map(String::as_str).collect::<Vec<&str>>().as_slice(),
+									MINIMUM_ABBREVIATION_LENGTH,
+								)
+								.unwrap_or(MINIMUM_ABBREVIATION_LENGTH)
+						}))
+					},
+					DetailRequest::Commit(hash) => {
+						let details = backend.and_then(|backend| CommitDetails::load(backend, hash.as_str()));
+						LoadedDetail::Commit(hash, details)
+					},
+				};
+				if !send(loaded_detail) {
+					return;
+				}
+			}
+		});
+
+		Self {
+			receiver,
+			request_sender: Some(request_sender),
+			token,
+		}
+	}
+
+	/// Queue the loading of the details of a commit.
+	pub(super) fn request_commit(&self, hash: &str) {
+		self.request(DetailRequest::Commit(String::from(hash)));
+	}
+
+	/// Queue finding the shortest length at which all of the hashes are unique in the repository, and at least as
+	/// long as Git would abbreviate a hash.
+	pub(super) fn request_abbreviation_length(&self, hashes: Vec<String>) {
+		self.request(DetailRequest::AbbreviationLength(hashes));
+	}
+
+	fn request(&self, request: DetailRequest) {
+		if let Some(ref request_sender) = self.request_sender {
+			let _ = request_sender.send(request);
+		}
+	}
+
+	/// Take the details that have been loaded since the last call, without waiting.
+	pub(super) fn take_loaded(&self) -> Vec<LoadedDetail> {
+		self.receiver.try_iter().collect()
+	}
+
+	/// Wait for all queued details to be loaded, and take them. No further details can be requested.
+	#[cfg(test)]
+	pub(super) fn wait(&mut self) -> Vec<LoadedDetail> {
+		drop(self.request_sender.take());
+		self.receiver.iter().collect()
+	}
+}
+
+impl Drop for DetailsLoader {
+	fn drop(&mut self) {
+		self.token.cancel();
+	}
+}
+
+impl ::std::fmt::Debug for DetailsLoader {
+	#[inline]
+	fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> Result<(), ::std::fmt::Error> {
+		f.debug_struct("DetailsLoader").finish()
+	}
+}
@@ -0,0 +1,100 @@
+use input::{Event, EventHandler, InputOptions};
+use lazy_static::lazy_static;
+use todo_file::Line;
+use view::{Dialog, ViewData};
+
+use crate::i18n::translate;
+
+lazy_static! {
+	static ref INPUT_OPTIONS: InputOptions = InputOptions::new();
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(super) enum ExternalChangeAction {
+	Keep,
+	Merge,
+	Reload,
+}
+
+/// Are the lines the same as they are written to the todo file, ignoring whether a line was changed and then changed
+/// back in the list.
+pub(super) fn is_same_lines(lines: &[Line], other: &[Line]) -> bool {
+	lines.len() == other.len()
+		&& lines.iter().zip(other).all(|(line, other_line)| {
+			line.to_text() == other_line.to_text() && line.get_group_name() == other_line.get_group_name()
+		})
+}
+
+/// A change to the todo file by another program, while the list is open, with the choice of reloading the changed
+/// file, keeping the list, or merging the changes when the list was also changed.
+pub(super) struct ExternalChange {
+	dialog: Dialog<ExternalChangeAction>,
+	lines: Vec<Line>,
+	merged_lines: Option<Vec<Line>>,
+}
+
+impl ExternalChange {
+	/// A change to the lines of the todo file, with the merge of the changes to the list, when the list was also
+	/// changed, that is `None` if the changes conflict.
+	pub(super) fn new(lines: Vec<Line>, edited: bool, merged_lines: Option<Vec<Line>>) -> Self {
+		let mut dialog = Dialog::new(translate("Todo file changed"));
+		dialog.push_body_line(translate("The todo file was changed by another program."));
+		dialog.set_hint(translate("Up and down to select, enter or the key of a choice to choose"));
+		if edited {
+			dialog.push_body_line(if merged_lines.is_some() {
+				translate("The todo list was also changed, and the changes can be merged.")
+			}
+			else {
+				translate("The todo list was also changed, and the changes conflict.")
+			});
+			if merged_lines.is_some() {
+				dialog.add_choice(
+					'm',
+					translate("Merge the changes of the todo file into the todo list"),
+					ExternalChangeAction::Merge,
+				);
+			}
+			dialog.add_choice(
+				'r',
+				translate("Reload the todo file, discarding the changes to the todo list"),
+				ExternalChangeAction::Reload,
+			);
+			dialog.add_choice(
+				'k',
+				translate("Keep the todo list, discarding the changes to the todo file"),
+				ExternalChangeAction::Keep,
+			);
+			// conflicting changes keep the edits to the list, unless another choice is made
+			if merged_lines.is_none() {
+				dialog.set_default(&ExternalChangeAction::Keep);
+			}
+		}
+		else {
+			dialog.add_choice('r', translate("Reload the todo file"), ExternalChangeAction::Reload);
+			dialog.add_choice(
+				'k',
+				translate("Keep the todo list, discarding the changes to the todo file"),
+				ExternalChangeAction::Keep,
+			);
+		}
+		Self {
+			dialog,
+			lines,
+			merged_lines,
+		}
+	}
+
+	pub(super) fn get_view_data(&mut self) -> &ViewData {
+		self.dialog.get_view_data()
+	}
+
+	pub(super) fn handle_event(&mut self, event_handler: &EventHandler) -> (Option<ExternalChangeAction>, Event) {
+		let event = event_handler.read_event(&INPUT_OPTIONS, |event, _| event);
+		(self.dialog.handle_event(event).copied(), event)
+	}
+
+	/// The lines of the changed todo file, and the lines of the merge of the changes, if they can be merged.
+	pub(super) fn into_lines(self) -> (Vec<Line>, Option<Vec<Line>>) {
+		(self.lines, self.merged_lines)
+	}
+}
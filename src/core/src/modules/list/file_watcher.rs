@@ -0,0 +1,191 @@
+use std::{
+	fs::read_to_string,
+	path::{Path, PathBuf},
+	sync::mpsc::{channel, Receiver},
+	time::Duration,
+};
+
+use crate::{
+	event_bus::{BusEvent, BusSender},
+	tasks::{CancellationToken, Priority, TaskManager},
+};
+
+// the todo file is small, so reading it is cheap enough to compare the contents, which also finds the changes that are
+// made within the resolution of the modified time of the file system
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Watches the todo file on a worker of the task manager while the list is open, so that a change by another program,
+/// such as a script or another editor, can be offered to be reloaded. The file is polled, since the tool does not depend
+/// on the file change notifications of each platform.
+pub(crate) struct FileWatcher {
+	changed_contents: Option<String>,
+	receiver: Receiver<String>,
+	token: CancellationToken,
+}
+
+impl FileWatcher {
+	/// Start watching the todo file at `todo_file_path`, for changes from the contents that it has now, with an event
+	/// sent to the bus for each change.
+	pub(crate) fn spawn(todo_file_path: &Path, events: BusSender, tasks: &TaskManager) -> Self {
+		Self::spawn_with_interval(todo_file_path, POLL_INTERVAL, events, tasks)
+	}
+
+	pub(super) fn spawn_with_interval(
+		todo_file_path: &Path,
+		interval: Duration,
+		events: BusSender,
+		tasks: &TaskManager,
+	) -> Self {
+		let (sender, receiver) = channel();
+		let todo_file_path = PathBuf::from(todo_file_path);
+		let mut previous_contents = read_to_string(todo_file_path.as_path()).ok();
+
+		let token = tasks.spawn("watch todo file", Priority::Low, move |token| {
+			// a change is only sent once the contents are the same for two polls, so that a file that is written while
+			// it is read, and that is not replaced atomically, is not sent partially written
+			let mut changing_contents = None;
+			while !token.sleep(interval) {
+				// a file that cannot be read, such as while it is replaced, is read again on the next poll
+				let contents = match read_to_string(todo_file_path.as_path()) {
+					Ok(contents) => contents,
+					Err(_) => continue,
+				};
+				if previous_contents.as_ref() == Some(&contents) {
+					changing_contents = None;
+					continue;
+				}
+				if changing_contents.as_ref() != Some(&contents) {
+					changing_contents = Some(contents);
+					continue;
+				}
+				log::info!(target: "todo", "{} was changed by another program", todo_file_path.display());
+				changing_contents = None;
+				previous_contents = Some(contents.clone());
+				if sender.send(contents).is_err() {
+					return;
+				}
+				events.send(BusEvent::TodoFileChanged);
+			}
+		});
+
+		Self {
+			changed_contents: None,
+			receiver,
+			token,
+		}
+	}
+
+	/// Take the latest contents of the todo file, if it has changed since the last call, without waiting.
+	pub(super) fn take_changed(&mut self) -> Option<String> {
+		self.receiver.try_iter().last().or_else(|| self.changed_contents.take())
+	}
+
+	/// Wait for the todo file to change, so that the change is taken by the next call to `take_changed`.
+	#[cfg(test)]
+	pub(super) fn wait(&mut self) {
+		self.changed_contents = self.receiver.recv_timeout(Duration::from_secs(5)).ok();
+	}
+}
+
+impl Drop for FileWatcher {
+	fn drop(&mut self) {
+		self.token.cancel();
+	}
+}
+
+impl ::std::fmt::Debug for FileWatcher {
+	#[inline]
+	fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> Result<(), ::std::fmt::Error> {
+		f.debug_struct("FileWatcher").finish()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::{fs::write, thread::sleep};
+
+	use super::*;
+	use crate::event_bus::EventBus;
+
+	fn spawn_watcher(path: &Path) -> FileWatcher {
+		FileWatcher::spawn_with_interval(
+			path,
+			Duration::from_millis(10),
+			EventBus::new().sender(),
+			&TaskManager::new(),
+		)
+	}
+
+	#[test]
+	fn take_changed_unchanged() {
+		let directory = tempfile::tempdir().unwrap();
+		let path = directory.path().join("git-rebase-todo");
+		write(path.as_path(), "pick aaa c1\n").unwrap();
+		let mut file_watcher = spawn_watcher(path.as_path());
+		sleep(Duration::from_millis(50));
+		assert_eq!(file_watcher.take_changed(), None);
+	}
+
+	#[test]
+	fn take_changed_changed() {
+		let directory = tempfile::tempdir().unwrap();
+		let path = directory.path().join("git-rebase-todo");
+		write(path.as_path(), "pick aaa c1\n").unwrap();
+		let mut file_watcher = spawn_watcher(path.as_path());
+		write(path.as_path(), "drop aaa c1\n").unwrap();
+		file_watcher.wait();
+		assert_eq!(file_watcher.take_changed(), Some(String::from("drop aaa c1\n")));
+		assert_eq!(file_watcher.take_changed(), None);
+	}
+
+	#[test]
+	fn take_changed_file_does_not_exist() {
+		let directory = tempfile::tempdir().unwrap();
+		let path = directory.path().join("git-rebase-todo");
+		let mut file_watcher = spawn_watcher(path.as_path());
+		write(path.as_path(), "pick aaa c1\n").unwrap();
+		file_watcher.wait();
+		assert_eq!(file_watcher.take_changed(), Some(String::from("pick aaa c1\n")));
+	}
+
+	#[test]
+	fn change_sends_event() {
+		let directory = tempfile::tempdir().unwrap();
+		let path = directory.path().join("git-rebase-todo");
+		write(path.as_path(), "pick aaa c1\n").unwrap();
+		let event_bus = EventBus::new();
+		let mut file_watcher = FileWatcher::spawn_with_interval(
+			path.as_path(),
+			Duration::from_millis(10),
+			event_bus.sender(),
+			&TaskManager::new(),
+		);
+		write(path.as_path(), "drop aaa c1\n").unwrap();
+		// the event is sent after the contents, so wait for it to arrive
+		let mut events = vec![];
+		for _ in 0..500 {
+			events = event_bus.drain();
+			if !events.is_empty() {
+				break;
+			}
+			sleep(Duration::from_millis(10));
+		}
+		assert_eq!(events, vec![BusEvent::TodoFileChanged]);
+		assert_eq!(file_watcher.take_changed(), Some(String::from("drop aaa c1\n")));
+	}
+
+	#[test]
+	fn drop_stops_watching() {
+		let directory = tempfile::tempdir().unwrap();
+		let path = directory.path().join("git-rebase-todo");
+		write(path.as_path(), "pick aaa c1\n").unwrap();
+		let task_manager = TaskManager::new();
+		drop(FileWatcher::spawn_with_interval(
+			path.as_path(),
+			Duration::from_millis(10),
+			EventBus::new().sender(),
+			&task_manager,
+		));
+		assert!(task_manager.shutdown(Duration::from_secs(5)));
+	}
+}
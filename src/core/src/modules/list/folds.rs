@@ -0,0 +1,148 @@
+/// The ranges of lines of the todo list that are collapsed to a single row. The folds only change how the list is
+/// shown, the lines of a fold are still written to the rebase file.
+#[derive(Debug)]
+pub(super) struct Folds {
+	lines_length: usize,
+	ranges: Vec<(usize, usize)>,
+}
+
+impl Folds {
+	pub(super) const fn new() -> Self {
+		Self {
+			lines_length: 0,
+			ranges: vec![],
+		}
+	}
+
+	pub(super) fn is_empty(&self) -> bool {
+		self.ranges.is_empty()
+	}
+
+	/// Get the fold that contains a line.
+	pub(super) fn get(&self, index: usize) -> Option<(usize, usize)> {
+		self.ranges
+			.iter()
+			.copied()
+			.find(|&(start, end)| index >= start && index <= end)
+	}
+
+	/// Collapse a range of lines, of a list with a number of lines, replacing the folds that overlap the range. A single
+	/// line is not collapsed.
+	pub(super) fn add(&mut self, start: usize, end: usize, lines_length: usize) {
+		if start >= end || end >= lines_length {
+			return;
+		}
+		self.update(lines_length);
+		self.ranges
+			.retain(|&(fold_start, fold_end)| fold_end < start || fold_start > end);
+		self.ranges.push((start, end));
+		self.ranges.sort_unstable();
+	}
+
+	/// Expand the fold that contains a line, returning the fold.
+	pub(super) fn remove(&mut self, index: usize) -> Option<(usize, usize)> {
+		let fold = self.get(index)?;
+		self.ranges.retain(|&range| range != fold);
+		Some(fold)
+	}
+
+	/// Expand all of the folds.
+	pub(super) fn clear(&mut self) {
+		self.ranges.clear();
+	}
+
+	/// Expand all of the folds when lines have been added to or removed from the list, since the ranges would no
+	/// longer match the lines that were collapsed.
+	pub(super) fn update(&mut self, lines_length: usize) {
+		if self.lines_length != lines_length {
+			self.lines_length = lines_length;
+			self.ranges.clear();
+		}
+	}
+
+	/// Get the index of the line shown on each row, which is the first line of a fold.
+	pub(super) fn get_rows(&self, lines_length: usize) -> Vec<usize> {
+		let mut rows = Vec::with_capacity(lines_length);
+		let mut index = 0;
+		for &(start, end) in &self.ranges {
+			rows.extend(index..=start);
+			index = end + 1;
+		}
+		rows.extend(index..lines_length);
+		rows
+	}
+
+	/// Get the line to select instead of a line hidden in a fold, which is the line after the fold when the selection
+	/// moved down into the fold from before it, and otherwise the first line of the fold.
+	pub(super) fn get_shown_index(&self, previous_index: usize, index: usize) -> usize {
+		match self.get(index) {
+			Some((start, end)) if index != start => {
+				if previous_index <= start && index > previous_index && end + 1 < self.lines_length {
+					end + 1
+				}
+				else {
+					start
+				}
+			},
+			_ => index,
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn create_folds(ranges: &[(usize, usize)], lines_length: usize) -> Folds {
+		let mut folds = Folds::new();
+		for &(start, end) in ranges {
+			folds.add(start, end, lines_length);
+		}
+		folds
+	}
+
+	#[test]
+	fn get_rows() {
+		let folds = create_folds(&[(1, 3), (5, 6)], 8);
+		assert_eq!(folds.get_rows(8), vec![0, 1, 4, 5, 7]);
+	}
+
+	#[test]
+	fn add_single_line() {
+		let folds = create_folds(&[(1, 1)], 3);
+		assert!(folds.is_empty());
+	}
+
+	#[test]
+	fn add_replaces_overlapping_folds() {
+		let folds = create_folds(&[(1, 2), (4, 5), (0, 4)], 8);
+		assert_eq!(folds.get_rows(8), vec![0, 5, 6, 7]);
+	}
+
+	#[test]
+	fn remove() {
+		let mut folds = create_folds(&[(1, 3)], 5);
+		assert_eq!(folds.remove(2), Some((1, 3)));
+		assert_eq!(folds.remove(2), None);
+		assert!(folds.is_empty());
+	}
+
+	#[test]
+	fn update_lines_length_changed() {
+		let mut folds = create_folds(&[(1, 3)], 5);
+		folds.update(5);
+		assert!(!folds.is_empty());
+		folds.update(6);
+		assert!(folds.is_empty());
+	}
+
+	#[test]
+	fn get_shown_index() {
+		let folds = create_folds(&[(1, 3), (5, 6)], 7);
+		assert_eq!(folds.get_shown_index(0, 1), 1);
+		assert_eq!(folds.get_shown_index(1, 2), 4);
+		assert_eq!(folds.get_shown_index(4, 3), 1);
+		assert_eq!(folds.get_shown_index(4, 6), 5);
+		assert_eq!(folds.get_shown_index(0, 0), 0);
+	}
+}
@@ -10,6 +10,7 @@ pub(crate) fn get_event(event_handler: &EventHandler) -> Event {
 	event_handler.read_event(&INPUT_OPTIONS, |event, key_bindings| {
 		match event {
 			e if key_bindings.abort.contains(&e) => Event::from(MetaEvent::Abort),
+			e if key_bindings.abort_rebase.contains(&e) => Event::from(MetaEvent::AbortRebase),
 			e if key_bindings.action_break.contains(&e) => Event::from(MetaEvent::ActionBreak),
 			e if key_bindings.action_drop.contains(&e) => Event::from(MetaEvent::ActionDrop),
 			e if key_bindings.action_edit.contains(&e) => Event::from(MetaEvent::ActionEdit),
@@ -17,10 +18,19 @@ pub(crate) fn get_event(event_handler: &EventHandler) -> Event {
 			e if key_bindings.action_pick.contains(&e) => Event::from(MetaEvent::ActionPick),
 			e if key_bindings.action_reword.contains(&e) => Event::from(MetaEvent::ActionReword),
 			e if key_bindings.action_squash.contains(&e) => Event::from(MetaEvent::ActionSquash),
+			e if key_bindings.autosquash.contains(&e) => Event::from(MetaEvent::Autosquash),
+			e if key_bindings.branch_out.contains(&e) => Event::from(MetaEvent::BranchOut),
+			e if key_bindings.break_every.contains(&e) => Event::from(MetaEvent::BreakEvery),
+			e if key_bindings.clear_marks.contains(&e) => Event::from(MetaEvent::ClearMarks),
+			e if key_bindings.cycle_fixup_option.contains(&e) => Event::from(MetaEvent::CycleFixupOption),
+			e if key_bindings.describe_line.contains(&e) => Event::from(MetaEvent::DescribeLine),
 			e if key_bindings.edit.contains(&e) => Event::from(MetaEvent::Edit),
+			e if key_bindings.filter_by_path.contains(&e) => Event::from(MetaEvent::FilterByPath),
 			e if key_bindings.force_abort.contains(&e) => Event::from(MetaEvent::ForceAbort),
 			e if key_bindings.force_rebase.contains(&e) => Event::from(MetaEvent::ForceRebase),
 			e if key_bindings.insert_line.contains(&e) => Event::from(MetaEvent::InsertLine),
+			e if key_bindings.jump_to_line.contains(&e) => Event::from(MetaEvent::JumpToLine),
+			e if key_bindings.mark_matching.contains(&e) => Event::from(MetaEvent::MarkMatching),
 			e if key_bindings.move_down.contains(&e) => Event::from(MetaEvent::MoveCursorDown),
 			e if key_bindings.move_down_step.contains(&e) => Event::from(MetaEvent::MoveCursorPageDown),
 			e if key_bindings.move_end.contains(&e) => Event::from(MetaEvent::MoveCursorEnd),
@@ -31,10 +41,28 @@ pub(crate) fn get_event(event_handler: &EventHandler) -> Event {
 			e if key_bindings.move_selection_up.contains(&e) => Event::from(MetaEvent::SwapSelectedUp),
 			e if key_bindings.move_up.contains(&e) => Event::from(MetaEvent::MoveCursorUp),
 			e if key_bindings.move_up_step.contains(&e) => Event::from(MetaEvent::MoveCursorPageUp),
+			e if key_bindings.name_group.contains(&e) => Event::from(MetaEvent::NameGroup),
 			e if key_bindings.open_in_external_editor.contains(&e) => Event::from(MetaEvent::OpenInEditor),
+			e if key_bindings.open_settings.contains(&e) => Event::from(MetaEvent::OpenSettings),
+			e if key_bindings.quick_fix.contains(&e) => Event::from(MetaEvent::QuickFix),
+			e if key_bindings.quit_without_saving.contains(&e) => Event::from(MetaEvent::QuitWithoutSaving),
 			e if key_bindings.rebase.contains(&e) => Event::from(MetaEvent::Rebase),
+			e if key_bindings.remove_breaks.contains(&e) => Event::from(MetaEvent::RemoveBreaks),
 			e if key_bindings.remove_line.contains(&e) => Event::from(MetaEvent::Delete),
+			e if key_bindings.reverse_lines.contains(&e) => Event::from(MetaEvent::ReverseLines),
 			e if key_bindings.show_commit.contains(&e) => Event::from(MetaEvent::ShowCommit),
+			e if key_bindings.show_notifications.contains(&e) => Event::from(MetaEvent::ShowNotifications),
+			e if key_bindings.show_related_commits.contains(&e) => Event::from(MetaEvent::ShowRelatedCommits),
+			e if key_bindings.show_squash_message.contains(&e) => Event::from(MetaEvent::ShowSquashMessage),
+			e if key_bindings.show_statistics.contains(&e) => Event::from(MetaEvent::ShowStatistics),
+			e if key_bindings.skip_commit.contains(&e) => Event::from(MetaEvent::SkipCommit),
+			e if key_bindings.sort_lines.contains(&e) => Event::from(MetaEvent::SortLines),
+			e if key_bindings.start_move.contains(&e) => Event::from(MetaEvent::StartMove),
+			e if key_bindings.test_commit.contains(&e) => Event::from(MetaEvent::TestCommit),
+			e if key_bindings.toggle_all_folds.contains(&e) => Event::from(MetaEvent::ToggleAllFolds),
+			e if key_bindings.toggle_group.contains(&e) => Event::from(MetaEvent::ToggleGroup),
+			e if key_bindings.toggle_mark.contains(&e) => Event::from(MetaEvent::ToggleMark),
+			e if key_bindings.toggle_stash_info.contains(&e) => Event::from(MetaEvent::ToggleStashInfo),
 			e if key_bindings.toggle_visual_mode.contains(&e) => Event::from(MetaEvent::ToggleVisualMode),
 			Event::Mouse(mouse_event) => {
 				match mouse_event.kind {
@@ -0,0 +1,106 @@
+use config::KeyBindings;
+use display::DisplayColor;
+use todo_file::{Action, Line};
+use view::{LineSegment, ViewLine};
+
+use crate::i18n::translate;
+
+/// The footer of the list, with the most relevant key bindings for the selected line and the mode, so that the main
+/// actions can be found without opening the help.
+#[derive(Debug)]
+pub(super) struct KeyHints {
+	commit: Vec<(String, &'static str)>,
+	editable: Vec<(String, &'static str)>,
+	empty: Vec<(String, &'static str)>,
+	other: Vec<(String, &'static str)>,
+	visual: Vec<(String, &'static str)>,
+}
+
+impl KeyHints {
+	pub(super) fn new(key_bindings: &KeyBindings) -> Self {
+		// the first binding of each action is shown, and an action without a binding is left out
+		let first = |keys: &[String]| keys.first().cloned();
+		let action_keys = [
+			&key_bindings.action_pick,
+			&key_bindings.action_reword,
+			&key_bindings.action_edit,
+			&key_bindings.action_squash,
+			&key_bindings.action_fixup,
+			&key_bindings.action_drop,
+		]
+		.iter()
+		.filter_map(|keys| first(keys))
+		.collect::<Vec<String>>()
+		.join("/");
+		let actions = (!action_keys.is_empty()).then_some(action_keys);
+		let hints = |hints: &[(Option<String>, &'static str)]| {
+			hints
+				.iter()
+				.filter_map(|&(ref key, description)| key.clone().map(|hint_key| (hint_key, translate(description))))
+				.collect::<Vec<(String, &'static str)>>()
+		};
+
+		let help = (first(&key_bindings.help), "help");
+		let move_selection = first(&key_bindings.move_selection_up)
+			.zip(first(&key_bindings.move_selection_down))
+			.map(|(up, down)| format!("{}/{}", up, down));
+		Self {
+			commit: hints(&[
+				(actions.clone(), "change action"),
+				(first(&key_bindings.show_commit), "details"),
+				(move_selection.clone(), "move"),
+				(first(&key_bindings.toggle_visual_mode), "visual mode"),
+				(first(&key_bindings.rebase), "write"),
+				help.clone(),
+			]),
+			editable: hints(&[
+				(first(&key_bindings.edit), "edit"),
+				(move_selection.clone(), "move"),
+				(first(&key_bindings.remove_line), "remove"),
+				(first(&key_bindings.rebase), "write"),
+				help.clone(),
+			]),
+			empty: hints(&[
+				(first(&key_bindings.insert_line), "insert"),
+				(first(&key_bindings.abort), "abort"),
+				help.clone(),
+			]),
+			other: hints(&[
+				(move_selection.clone(), "move"),
+				(first(&key_bindings.remove_line), "remove"),
+				(first(&key_bindings.insert_line), "insert"),
+				(first(&key_bindings.rebase), "write"),
+				help.clone(),
+			]),
+			visual: hints(&[
+				(actions, "change action"),
+				(move_selection, "move"),
+				(first(&key_bindings.remove_line), "remove"),
+				(first(&key_bindings.toggle_visual_mode), "exit visual mode"),
+				help,
+			]),
+		}
+	}
+
+	/// Get the footer for the selected line, or for an empty list when there is no selected line.
+	pub(super) fn get_line(&self, selected_line: Option<&Line>, is_visual_mode: bool) -> ViewLine {
+		let hints = match selected_line.map(Line::get_action) {
+			_ if is_visual_mode => &self.visual,
+			None => &self.empty,
+			Some(&(Action::Drop | Action::Edit | Action::Fixup | Action::Pick | Action::Reword | Action::Squash)) => {
+				&self.commit
+			},
+			Some(&(Action::Exec | Action::Label | Action::Reset | Action::Merge)) => &self.editable,
+			Some(_) => &self.other,
+		};
+		let mut segments = vec![];
+		for (index, &(ref key, description)) in hints.iter().enumerate() {
+			if index > 0 {
+				segments.push(LineSegment::new("  "));
+			}
+			segments.push(LineSegment::new_with_color(key.as_str(), DisplayColor::IndicatorColor));
+			segments.push(LineSegment::new(format!(" {}", description).as_str()));
+		}
+		ViewLine::from(segments)
+	}
+}
@@ -0,0 +1,117 @@
+use std::time::{Duration, Instant};
+
+use input::MetaEvent;
+
+const FAST_LINES: usize = 5;
+
+// terminals repeat a held key around thirty times a second, so a press that follows the last press of the same key
+// within this time is a repeat
+const REPEAT_INTERVAL: Duration = Duration::from_millis(150);
+
+/// Accelerates the movement of the selection while a navigation key is held, from a line, to five lines, to a page at
+/// a time, once the key has been repeated a number of times. A terminal does not report whether a key is held, so a
+/// key is held while it is pressed again within a short time.
+pub(super) struct KeyRepeat {
+	count: u32,
+	fast_threshold: u32,
+	last_event: Option<MetaEvent>,
+	last_time: Option<Instant>,
+	page_threshold: u32,
+}
+
+impl KeyRepeat {
+	pub(super) const fn new(fast_threshold: u32, page_threshold: u32) -> Self {
+		Self {
+			count: 0,
+			fast_threshold,
+			last_event: None,
+			last_time: None,
+			page_threshold,
+		}
+	}
+
+	pub(super) fn set_thresholds(&mut self, fast_threshold: u32, page_threshold: u32) {
+		self.fast_threshold = fast_threshold;
+		self.page_threshold = page_threshold;
+	}
+
+	/// The number of lines to move for a press of the navigation key, at the time of the press.
+	pub(super) fn get_amount(&mut self, event: MetaEvent, now: Instant, page_lines: usize) -> usize {
+		let is_repeat = self.last_event == Some(event)
+			&& self.last_time.map_or(false, |last_time| {
+				now.saturating_duration_since(last_time) <= REPEAT_INTERVAL
+			});
+		self.count = if is_repeat {
+			self.count.saturating_add(1)
+		}
+		else {
+			0
+		};
+		self.last_event = Some(event);
+		self.last_time = Some(now);
+
+		if self.page_threshold != 0 && self.count >= self.page_threshold {
+			page_lines.max(1)
+		}
+		else if self.fast_threshold != 0 && self.count >= self.fast_threshold {
+			FAST_LINES
+		}
+		else {
+			1
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn press(key_repeat: &mut KeyRepeat, event: MetaEvent, start: Instant, millis: u64) -> usize {
+		key_repeat.get_amount(event, start + Duration::from_millis(millis), 20)
+	}
+
+	#[test]
+	fn single_press() {
+		let mut key_repeat = KeyRepeat::new(2, 4);
+		assert_eq!(press(&mut key_repeat, MetaEvent::MoveCursorDown, Instant::now(), 0), 1);
+	}
+
+	#[test]
+	fn held_key_accelerates() {
+		let start = Instant::now();
+		let mut key_repeat = KeyRepeat::new(2, 4);
+		let amounts = (0..6)
+			.map(|index| press(&mut key_repeat, MetaEvent::MoveCursorDown, start, index * 30))
+			.collect::<Vec<usize>>();
+		assert_eq!(amounts, vec![1, 1, 5, 5, 20, 20]);
+	}
+
+	#[test]
+	fn slow_presses_do_not_accelerate() {
+		let start = Instant::now();
+		let mut key_repeat = KeyRepeat::new(1, 2);
+		let amounts = (0..3)
+			.map(|index| press(&mut key_repeat, MetaEvent::MoveCursorDown, start, index * 500))
+			.collect::<Vec<usize>>();
+		assert_eq!(amounts, vec![1, 1, 1]);
+	}
+
+	#[test]
+	fn other_key_stops_acceleration() {
+		let start = Instant::now();
+		let mut key_repeat = KeyRepeat::new(1, 0);
+		assert_eq!(press(&mut key_repeat, MetaEvent::MoveCursorDown, start, 0), 1);
+		assert_eq!(press(&mut key_repeat, MetaEvent::MoveCursorDown, start, 30), 5);
+		assert_eq!(press(&mut key_repeat, MetaEvent::MoveCursorUp, start, 60), 1);
+	}
+
+	#[test]
+	fn disabled_thresholds() {
+		let start = Instant::now();
+		let mut key_repeat = KeyRepeat::new(0, 0);
+		let amounts = (0..4)
+			.map(|index| press(&mut key_repeat, MetaEvent::MoveCursorDown, start, index * 30))
+			.collect::<Vec<usize>>();
+		assert_eq!(amounts, vec![1, 1, 1, 1]);
+	}
+}
@@ -0,0 +1,62 @@
+use todo_file::Action;
+
+const AUTHOR_PREFIX: &str = "author:";
+const FILTER: &str = "filter";
+
+/// The lines of the todo list that are marked together, by the action of the lines, the author of the commits, or the
+/// commits that match the last path filter.
+#[derive(Debug, PartialEq)]
+pub(super) enum MarkPredicate {
+	Action(Action),
+	Author(String),
+	PathFilter,
+}
+
+impl MarkPredicate {
+	/// Parse the predicate, which is an action, such as `exec` or `fixup`, `author:` followed by a part of the name or
+	/// email of the author, or `filter`.
+	pub(super) fn parse(predicate: &str) -> Option<Self> {
+		let predicate = predicate.trim();
+		if predicate == FILTER {
+			return Some(Self::PathFilter);
+		}
+		if let Some(author) = predicate.strip_prefix(AUTHOR_PREFIX) {
+			let author = author.trim();
+			return (!author.is_empty()).then(|| Self::Author(author.to_lowercase()));
+		}
+		Action::try_from(predicate).ok().map(Self::Action)
+	}
+
+	/// Whether an author, of the name and email of the author, matches the predicate, ignoring case.
+	pub(super) fn matches_author(&self, author: &str) -> bool {
+		match *self {
+			Self::Author(ref name) => author.to_lowercase().contains(name.as_str()),
+			_ => false,
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use rstest::rstest;
+
+	use super::*;
+
+	#[rstest]
+	#[case::action("exec", Some(MarkPredicate::Action(Action::Exec)))]
+	#[case::action_abbreviation("f", Some(MarkPredicate::Action(Action::Fixup)))]
+	#[case::author("author: Tim", Some(MarkPredicate::Author(String::from("tim"))))]
+	#[case::author_empty("author:", None)]
+	#[case::filter(" filter ", Some(MarkPredicate::PathFilter))]
+	#[case::invalid("commits", None)]
+	fn parse(#[case] predicate: &str, #[case] expected: Option<MarkPredicate>) {
+		assert_eq!(MarkPredicate::parse(predicate), expected);
+	}
+
+	#[test]
+	fn matches_author() {
+		let predicate = MarkPredicate::parse("author:tim").unwrap();
+		assert!(predicate.matches_author("Tim Oram <dev@example.com>"));
+		assert!(!predicate.matches_author("Other <other@example.com>"));
+	}
+}
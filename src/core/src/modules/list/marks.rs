@@ -0,0 +1,138 @@
+/// The marked lines of the todo list, that do not have to be next to each other, that the actions and moves of the
+/// list apply to instead of the selected line.
+#[derive(Debug)]
+pub(super) struct Marks {
+	lines_length: usize,
+	indexes: Vec<usize>,
+}
+
+impl Marks {
+	pub(super) const fn new() -> Self {
+		Self {
+			lines_length: 0,
+			indexes: vec![],
+		}
+	}
+
+	pub(super) fn is_empty(&self) -> bool {
+		self.indexes.is_empty()
+	}
+
+	pub(super) fn len(&self) -> usize {
+		self.indexes.len()
+	}
+
+	pub(super) fn contains(&self, index: usize) -> bool {
+		self.indexes.binary_search(&index).is_ok()
+	}
+
+	/// Get the indexes of the marked lines, in the order of the list.
+	pub(super) fn indexes(&self) -> &[usize] {
+		self.indexes.as_slice()
+	}
+
+	/// Mark the lines of a range, of a list with a number of lines, or unmark the lines when all of the lines of the
+	/// range are marked.
+	pub(super) fn toggle(&mut self, start: usize, end: usize, lines_length: usize) {
+		self.update(lines_length);
+		if lines_length == 0 {
+			return;
+		}
+		let range = start..=end.min(lines_length - 1);
+		if range.clone().all(|index| self.contains(index)) {
+			self.indexes.retain(|index| !range.contains(index));
+		}
+		else {
+			self.indexes.extend(range);
+			self.indexes.sort_unstable();
+			self.indexes.dedup();
+		}
+	}
+
+	/// Mark only the lines with the indexes, of a list with a number of lines.
+	pub(super) fn set(&mut self, indexes: &[usize], lines_length: usize) {
+		self.update(lines_length);
+		self.indexes = indexes.iter().copied().filter(|&index| index < lines_length).collect();
+		self.indexes.sort_unstable();
+		self.indexes.dedup();
+	}
+
+	pub(super) fn clear(&mut self) {
+		self.indexes.clear();
+	}
+
+	/// Move the marks with the marked lines, after the lines have been moved up or down by one line.
+	pub(super) fn shift(&mut self, down: bool) {
+		for index in &mut self.indexes {
+			*index = if down { *index + 1 } else { *index - 1 };
+		}
+	}
+
+	/// Unmark all of the lines when lines have been added to or removed from the list, since the indexes would no
+	/// longer match the lines that were marked.
+	pub(super) fn update(&mut self, lines_length: usize) {
+		if self.lines_length != lines_length {
+			self.lines_length = lines_length;
+			self.indexes.clear();
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn toggle() {
+		let mut marks = Marks::new();
+		marks.toggle(3, 3, 6);
+		marks.toggle(0, 1, 6);
+		assert_eq!(marks.indexes(), &[0, 1, 3]);
+		assert!(marks.contains(1));
+		assert!(!marks.contains(2));
+	}
+
+	#[test]
+	fn toggle_marked_range() {
+		let mut marks = Marks::new();
+		marks.toggle(1, 3, 6);
+		marks.toggle(2, 3, 6);
+		assert_eq!(marks.indexes(), &[1]);
+	}
+
+	#[test]
+	fn toggle_partly_marked_range() {
+		let mut marks = Marks::new();
+		marks.toggle(2, 2, 6);
+		marks.toggle(1, 3, 6);
+		assert_eq!(marks.indexes(), &[1, 2, 3]);
+	}
+
+	#[test]
+	fn set() {
+		let mut marks = Marks::new();
+		marks.toggle(0, 0, 6);
+		marks.set(&[4, 2, 7], 6);
+		assert_eq!(marks.indexes(), &[2, 4]);
+	}
+
+	#[test]
+	fn shift() {
+		let mut marks = Marks::new();
+		marks.toggle(1, 2, 6);
+		marks.shift(true);
+		assert_eq!(marks.indexes(), &[2, 3]);
+		marks.shift(false);
+		assert_eq!(marks.indexes(), &[1, 2]);
+	}
+
+	#[test]
+	fn update_lines_length_changed() {
+		let mut marks = Marks::new();
+		marks.toggle(1, 1, 5);
+		marks.update(5);
+		assert_eq!(marks.len(), 1);
+		marks.update(4);
+		assert!(marks.is_empty());
+	}
+}
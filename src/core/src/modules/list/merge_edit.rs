@@ -0,0 +1,44 @@
+use anyhow::{anyhow, Result};
+
+use crate::i18n::translate;
+
+/// Parse the commit that the message of a merge is taken from, as `-C <commit>` to use the message, or `-c <commit>`
+/// to also edit it. A commit without an option uses the message, and an empty input uses the default message of Git.
+pub(super) fn parse_message_commit(input: &str) -> Result<Option<(&str, &str)>> {
+	let words: Vec<&str> = input.split_whitespace().collect();
+	match *words.as_slice() {
+		[] => Ok(None),
+		[commit] => Ok(Some(("-C", commit))),
+		[option, commit] => Ok(Some((option, commit))),
+		_ => {
+			Err(anyhow!(translate(
+				"Expected -C <commit>, -c <commit>, a commit, or nothing"
+			)))
+		},
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use rstest::rstest;
+
+	use super::*;
+
+	#[rstest]
+	#[case::empty("", None)]
+	#[case::whitespace("  ", None)]
+	#[case::use_message("-C aaa", Some(("-C", "aaa")))]
+	#[case::edit_message("-c aaa", Some(("-c", "aaa")))]
+	#[case::commit("aaa", Some(("-C", "aaa")))]
+	fn message_commit(#[case] input: &str, #[case] expected: Option<(&str, &str)>) {
+		assert_eq!(parse_message_commit(input).unwrap(), expected);
+	}
+
+	#[test]
+	fn message_commit_too_many_words() {
+		assert_eq!(
+			parse_message_commit("-C aaa bbb").unwrap_err().to_string(),
+			"Expected -C <commit>, -c <commit>, a commit, or nothing"
+		);
+	}
+}
@@ -1,51 +1,209 @@
+mod action_column;
+mod commit_details;
+mod commit_graph;
+mod details_loader;
+mod external_change;
+mod file_watcher;
+mod folds;
 mod input;
+mod key_hints;
+mod key_repeat;
+mod mark_predicate;
+mod marks;
+mod merge_edit;
+mod move_preview;
+mod path_filter;
+mod sort_order;
+mod statistics;
 mod utils;
 
 #[cfg(all(unix, test))]
 mod tests;
 
-use std::cmp::min;
+use std::{
+	cell::RefCell,
+	cmp::{max, min},
+	collections::{HashMap, HashSet},
+	rc::Rc,
+	time::{Duration, Instant},
+};
 
-use ::input::{Event, EventHandler, MetaEvent};
+use anyhow::anyhow;
+use ::input::{Event, EventHandler, KeyCode, KeyEvent, MetaEvent};
 use captur::capture;
-use config::Config;
+use config::{AbortBehavior, Config, DateFormat};
 use display::DisplayColor;
+use git::RangeTopology;
 use todo_file::{Action, EditContext, Line, TodoFile};
-use view::{LineSegment, RenderContext, ViewData, ViewLine, ViewSender};
+use view::{Layout, LineSegment, RenderContext, ViewData, ViewLine, ViewSender};
 
+pub(crate) use self::{details_loader::DetailsLoader, file_watcher::FileWatcher};
 use self::{
+	action_column::ActionColumn,
+	commit_details::CommitDetails,
+	commit_graph::build_commit_graph,
+	details_loader::LoadedDetail,
+	external_change::{is_same_lines, ExternalChange, ExternalChangeAction},
+	folds::Folds,
 	input::get_event,
-	utils::{get_list_normal_mode_help_lines, get_list_visual_mode_help_lines, get_todo_line_segments},
+	key_hints::KeyHints,
+	key_repeat::KeyRepeat,
+	mark_predicate::MarkPredicate,
+	marks::Marks,
+	merge_edit::parse_message_commit,
+	move_preview::MovePreview,
+	sort_order::SortOrder,
+	path_filter::PathFilter,
+	statistics::Statistics,
+	utils::{
+		get_cherry_pick_segments,
+		get_commit_details_segments,
+		get_fold_segments,
+		get_move_ghost_line,
+		get_list_normal_mode_help_lines,
+		get_list_visual_mode_help_lines,
+		get_lint_segment,
+		get_preview_lines,
+		get_todo_line_segments,
+		get_topology_segment,
+		is_mutation,
+		PREVIEW_PANE_HEIGHT,
+	},
 };
 use crate::{
-	components::{edit::Edit, help::Help},
+	components::{edit::Edit, help::Help, number_input::NumberInput},
+	dates::format_date,
+	event_bus::{BusEvent, Topic},
+	exec_template::expand_for_todo_file,
+	i18n::{translate, translate_format},
 	module::{ExitStatus, Module, ProcessResult, State},
+	modules::abort,
+	timers::Timer,
+	ui_state::UiState,
 };
+#[cfg(feature = "tutorial")]
+use crate::tutorial::Tutorial;
 
 #[derive(Debug, PartialEq)]
 enum ListState {
 	Normal,
 	Visual,
 	Edit,
+	MergeLabelsEdit,
+	MergeCommitEdit,
+	PathFilterEdit,
+	BreakEveryEdit,
+	JumpToLineEdit,
+	GroupNameEdit,
+	MarkEdit,
+	SortEdit,
+	Move,
+	PathFilter,
+	RelatedCommits,
+	ExternalChange,
 }
 
+// the preview pane is only shown when the list still has most of the window
+const MINIMUM_PREVIEW_WINDOW_HEIGHT: usize = PREVIEW_PANE_HEIGHT * 4;
+
+// the number of lines built past a window height on either side of the selected line, only these lines can be visible
+const VIRTUAL_LINES_MARGIN: usize = 10;
+
+// the length of the hashes in a full width window, until the length has been configured or loaded
+const DEFAULT_HASH_LENGTH: usize = 8;
+
+// the width of the date column, until the details of a commit have been loaded
+const DEFAULT_DATE_WIDTH: usize = 10;
+
+// the smallest unit of a relative date is a minute, so the dates are shown again each minute
+const RELATIVE_DATES_INTERVAL: Duration = Duration::from_secs(60);
+
 pub(crate) struct List {
+	abort_behavior: AbortBehavior,
+	abbreviation_length: Option<usize>,
+	abbreviation_length_requested: bool,
+	action_column: ActionColumn,
+	announcement: Option<String>,
 	auto_select_next: bool,
+	auto_stash: bool,
+	commit_details: HashMap<String, Option<CommitDetails>>,
+	date_format: DateFormat,
+	details_loader: Option<DetailsLoader>,
 	edit: Edit,
+	external_change: Option<ExternalChange>,
+	external_change_pending: bool,
+	file_watcher: Option<FileWatcher>,
+	folds: Folds,
+	has_uncommitted_changes: bool,
+	hash_length: Option<usize>,
 	height: usize,
+	key_hints: KeyHints,
+	key_repeat: KeyRepeat,
+	list_date_format: DateFormat,
+	marks: Marks,
+	merge_labels: Vec<String>,
+	move_range: Option<(usize, usize)>,
+	move_start: usize,
 	normal_mode_help: Help,
+	number_input: NumberInput,
+	filter_index: usize,
+	path_filter: Option<PathFilter>,
+	quick_fix_key: String,
+	read_only: bool,
+	related_commit: Option<String>,
+	requested_commit_details: HashSet<String>,
+	review_changes: bool,
+	screen_reader: bool,
+	show_key_hints: bool,
+	show_stash_info: bool,
+	sort_indexes: Vec<usize>,
+	stash_info_key: String,
 	state: ListState,
+	statistics: Statistics,
+	test_commit_break: bool,
+	test_commit_command: Option<String>,
+	test_commit_template: String,
+	topology: Option<RangeTopology>,
+	#[cfg(feature = "tutorial")]
+	tutorial: Option<Tutorial>,
+	ui_state: Option<Rc<RefCell<UiState>>>,
 	view_data: ViewData,
 	visual_index_start: Option<usize>,
 	visual_mode_help: Help,
+	warnings: Vec<String>,
+	watched_lines: Vec<Line>,
 }
 
 impl Module for List {
+	fn activate(&mut self, _: &TodoFile, _: State) -> ProcessResult {
+		if self.list_date_format == DateFormat::Relative {
+			ProcessResult::new().start_timer(Timer::RelativeDates, RELATIVE_DATES_INTERVAL)
+		}
+		else {
+			ProcessResult::new().stop_timer(Timer::RelativeDates)
+		}
+	}
+
 	fn build_view_data(&mut self, context: &RenderContext, todo_file: &TodoFile) -> &ViewData {
 		match self.state {
 			ListState::Normal => self.get_normal_mode_view_data(todo_file, context),
 			ListState::Visual => self.get_visual_mode_view_data(todo_file, context),
-			ListState::Edit => self.edit.get_view_data(),
+			ListState::Edit
+			| ListState::MergeLabelsEdit
+			| ListState::MergeCommitEdit
+			| ListState::PathFilterEdit
+			| ListState::GroupNameEdit
+			| ListState::MarkEdit
+			| ListState::SortEdit => self.edit.get_view_data(),
+			ListState::BreakEveryEdit | ListState::JumpToLineEdit => self.number_input.get_view_data(),
+			ListState::Move => self.get_move_view_data(todo_file, context),
+			ListState::PathFilter | ListState::RelatedCommits => self.get_filter_view_data(todo_file, context),
+			ListState::ExternalChange => {
+				match self.external_change {
+					Some(ref mut external_change) => external_change.get_view_data(),
+					None => self.get_normal_mode_view_data(todo_file, context),
+				}
+			},
 		}
 	}
 
@@ -55,11 +213,91 @@ impl Module for List {
 		view_sender: &ViewSender,
 		todo_file: &mut TodoFile,
 	) -> ProcessResult {
-		match self.state {
+		// a change to the todo file that was made while the list was in another mode is offered once the list is back
+		// in the normal mode
+		if self.external_change_pending && self.receive_external_change(todo_file) {
+			return ProcessResult::from(Event::from(MetaEvent::BackgroundLoaded));
+		}
+		let previous_description = self.screen_reader.then(|| self.describe_selected_line(todo_file));
+		let previous_selected_index = todo_file.get_selected_line_index();
+		let result = match self.state {
 			ListState::Normal => self.handle_normal_mode_input(event_handler, view_sender, todo_file),
 			ListState::Visual => self.handle_visual_mode_input(event_handler, view_sender, todo_file),
 			ListState::Edit => self.handle_edit_mode_input(event_handler, todo_file),
+			ListState::MergeLabelsEdit | ListState::MergeCommitEdit => {
+				self.handle_merge_edit_input(event_handler, todo_file)
+			},
+			ListState::PathFilterEdit => self.handle_path_filter_edit_input(event_handler, todo_file),
+			ListState::BreakEveryEdit => self.handle_break_every_edit_input(event_handler, todo_file),
+			ListState::JumpToLineEdit => self.handle_jump_to_line_edit_input(event_handler, todo_file),
+			ListState::GroupNameEdit => self.handle_group_name_edit_input(event_handler, todo_file),
+			ListState::MarkEdit => self.handle_mark_edit_input(event_handler, todo_file),
+			ListState::SortEdit => self.handle_sort_edit_input(event_handler, todo_file),
+			ListState::Move => self.handle_move_input(event_handler, todo_file),
+			ListState::PathFilter | ListState::RelatedCommits => {
+				self.handle_filter_input(event_handler, view_sender, todo_file)
+			},
+			ListState::ExternalChange => self.handle_external_change_input(event_handler, todo_file),
+		};
+		// the selection skips over the lines that are hidden in a fold
+		self.folds.update(todo_file.lines_iter().len());
+		if !self.folds.is_empty() {
+			let selected_index = todo_file.get_selected_line_index();
+			todo_file.set_selected_line_index(self.folds.get_shown_index(previous_selected_index, selected_index));
+		}
+		self.marks.update(todo_file.lines_iter().len());
+		#[cfg(feature = "tutorial")]
+		if let Some(ref mut tutorial) = self.tutorial {
+			tutorial.update(todo_file, self.state == ListState::Visual);
+		}
+		// with a screen reader, a change to the selected line or the mode is announced
+		if let Some(previous_description) = previous_description {
+			let description = self.describe_selected_line(todo_file);
+			if description != previous_description {
+				self.announcement = Some(description);
+			}
 		}
+		result
+	}
+
+	fn subscriptions(&self) -> &'static [Topic] {
+		&[Topic::Git, Topic::Timer, Topic::TodoFile]
+	}
+
+	// the view is rebuilt for the details that have been loaded, for a change to the todo file, and for the relative
+	// dates that have aged, before any further input is handled
+	fn handle_bus_event(&mut self, event: &BusEvent, todo_file: &TodoFile) -> ProcessResult {
+		let changed = match *event {
+			BusEvent::DetailsLoaded => self.receive_loaded_details(),
+			BusEvent::TodoFileChanged => self.receive_external_change(todo_file),
+			BusEvent::Tick(Timer::RelativeDates) => {
+				self.list_date_format == DateFormat::Relative && !self.commit_details.is_empty()
+			},
+			BusEvent::Tick(Timer::Progress) => false,
+		};
+		if changed {
+			ProcessResult::from(Event::from(MetaEvent::BackgroundLoaded))
+		}
+		else {
+			ProcessResult::new()
+		}
+	}
+
+	fn update_config(&mut self, config: &Config) {
+		self.abort_behavior = config.abort_behavior;
+		self.action_column = ActionColumn::new(&config.action_labels, config.icons);
+		self.auto_select_next = config.auto_select_next;
+		self.date_format = config.date_format.clone();
+		self.hash_length = Self::configured_hash_length(config);
+		self.key_repeat
+			.set_thresholds(config.key_repeat_fast_threshold, config.key_repeat_page_threshold);
+		self.list_date_format = config.list_date_format.clone();
+		self.review_changes = config.review_changes;
+		self.screen_reader = config.screen_reader;
+		self.show_key_hints = config.key_hints;
+		self.test_commit_break = config.test_commit_break;
+		self.test_commit_command = Self::test_commit_command(config);
+		self.test_commit_template = config.test_commit_template.clone();
 	}
 }
 
@@ -71,17 +309,131 @@ impl List {
 		});
 
 		Self {
+			abort_behavior: config.abort_behavior,
+			abbreviation_length: None,
+			abbreviation_length_requested: false,
+			action_column: ActionColumn::new(&config.action_labels, config.icons),
+			announcement: None,
 			auto_select_next: config.auto_select_next,
+			auto_stash: config.git.rebase_auto_stash,
+			commit_details: HashMap::new(),
+			date_format: config.date_format.clone(),
+			details_loader: None,
 			edit: Edit::new(),
+			external_change: None,
+			external_change_pending: false,
+			file_watcher: None,
+			folds: Folds::new(),
+			has_uncommitted_changes: false,
+			hash_length: Self::configured_hash_length(config),
 			height: 0,
+			key_hints: KeyHints::new(&config.key_bindings),
+			key_repeat: KeyRepeat::new(config.key_repeat_fast_threshold, config.key_repeat_page_threshold),
+			list_date_format: config.list_date_format.clone(),
+			marks: Marks::new(),
+			merge_labels: vec![],
+			move_range: None,
+			move_start: 0,
 			normal_mode_help: Help::new_from_keybindings(&get_list_normal_mode_help_lines(&config.key_bindings)),
+			number_input: NumberInput::new(),
+			filter_index: 0,
+			path_filter: None,
+			quick_fix_key: config
+				.key_bindings
+				.quick_fix
+				.first()
+				.map_or(String::from("F"), String::from),
+			read_only: false,
+			related_commit: None,
+			requested_commit_details: HashSet::new(),
+			review_changes: config.review_changes,
+			screen_reader: config.screen_reader,
+			show_key_hints: config.key_hints,
+			sort_indexes: vec![],
+			show_stash_info: false,
+			stash_info_key: config
+				.key_bindings
+				.toggle_stash_info
+				.first()
+				.map_or(String::from("i"), String::from),
 			state: ListState::Normal,
+			statistics: Statistics::new(),
+			test_commit_break: config.test_commit_break,
+			test_commit_command: Self::test_commit_command(config),
+			test_commit_template: config.test_commit_template.clone(),
+			topology: None,
+			#[cfg(feature = "tutorial")]
+			tutorial: None,
+			ui_state: None,
 			view_data,
 			visual_index_start: None,
 			visual_mode_help: Help::new_from_keybindings(&get_list_visual_mode_help_lines(&config.key_bindings)),
+			warnings: vec![],
+			watched_lines: vec![],
+		}
+	}
+
+	/// Set the loader of the repository status, and of the details of the commits that are shown in the wide
+	/// layout.
+	pub(crate) fn set_details_loader(&mut self, details_loader: DetailsLoader) {
+		self.details_loader = Some(details_loader);
+	}
+
+	/// Set the watcher of the todo file, that finds the changes to the todo file by another program, from the lines
+	/// that the todo file has now.
+	pub(crate) fn set_file_watcher(&mut self, file_watcher: FileWatcher, todo_file: &TodoFile) {
+		self.file_watcher = Some(file_watcher);
+		self.watched_lines = todo_file.get_lines_owned();
+	}
+
+	// like the names of the variables of a git config, the names of the templates are case insensitive
+	fn test_commit_command(config: &Config) -> Option<String> {
+		config
+			.exec_templates
+			.iter()
+			.find(|template| template.name.eq_ignore_ascii_case(config.test_commit_template.as_str()))
+			.map(|template| template.command.clone())
+	}
+
+	// the length set for the tool takes precedence over the length of abbreviated hashes in Git
+	fn configured_hash_length(config: &Config) -> Option<usize> {
+		config.hash_length.or(config.git.abbrev).map(|length| length as usize)
+	}
+
+	/// Open the todo list to be browsed without changes, with a warning that the todo list is read only.
+	pub(crate) fn set_read_only(&mut self) {
+		self.read_only = true;
+		self.add_warning(translate("The todo list is read only, and will not be written on exit"));
+	}
+
+	#[cfg(feature = "tutorial")]
+	pub(crate) fn set_tutorial(&mut self, tutorial: Tutorial) {
+		self.tutorial = Some(tutorial);
+	}
+
+	/// Set the state of the interface that was remembered for the repository, restoring the shown stash information
+	/// and the last path filter. The state is updated as the list changes.
+	pub(crate) fn set_ui_state(&mut self, ui_state: Rc<RefCell<UiState>>) {
+		{
+			let state = ui_state.borrow();
+			self.show_stash_info = state.show_stash_info();
+			self.path_filter = state.path_filter().map(PathFilter::new);
+		}
+		self.ui_state = Some(ui_state);
+	}
+
+	fn update_ui_state(&self) {
+		if let Some(ref ui_state) = self.ui_state {
+			let mut state = ui_state.borrow_mut();
+			state.set_show_stash_info(self.show_stash_info);
+			state.set_path_filter(self.path_filter.as_ref().map(PathFilter::pattern));
 		}
 	}
 
+	pub(crate) fn add_warning(&mut self, warning: &str) {
+		self.warnings.push(String::from(warning));
+	}
+
 	pub(crate) fn move_cursor_up(todo_file: &mut TodoFile, amount: usize) {
 		let current_selected_line_index = todo_file.get_selected_line_index();
 		todo_file.set_selected_line_index(
@@ -99,7 +451,46 @@ impl List {
 		rebase_todo.set_selected_line_index(current_selected_line_index + amount);
 	}
 
+	/// Describe the selected line with its full content, and the selected lines in visual mode.
+	fn describe_selected_line(&self, todo_file: &TodoFile) -> String {
+		if let Some((start, end)) = self.move_range.filter(|_| self.state == ListState::Move) {
+			return translate_format("Moving {} lines to line {} of {}", &[
+				&(end - start + 1),
+				&(self.move_start + 1),
+				&todo_file.lines_iter().len(),
+			]);
+		}
+		let selected_index = todo_file.get_selected_line_index();
+		let description = todo_file.get_selected_line().map_or_else(
+			|| String::from(translate("Rebase todo file is empty")),
+			|line| {
+				translate_format("Line {} of {}: {}", &[
+					&(selected_index + 1),
+					&todo_file.lines_iter().len(),
+					&line.to_text(),
+				])
+			},
+		);
+		match self.visual_index_start {
+			Some(visual_index) if self.state == ListState::Visual => {
+				translate_format("Visual mode, {} lines selected. {}", &[
+					&(visual_index.abs_diff(selected_index) + 1),
+					&description,
+				])
+			},
+			_ if !self.marks.is_empty() => {
+				translate_format("{} lines marked. {}", &[&self.marks.len(), &description])
+			},
+			_ => description,
+		}
+	}
+
 	fn set_selected_line_action(&self, rebase_todo: &mut TodoFile, action: Action) {
+		// outside of the visual mode, the action is set on the marked lines, when lines are marked
+		if self.state == ListState::Normal && !self.marks.is_empty() {
+			rebase_todo.update_lines(self.marks.indexes(), &EditContext::new().action(action));
+			return;
+		}
 		let start_index = rebase_todo.get_selected_line_index();
 		let end_index = self.visual_index_start.unwrap_or(start_index);
 
@@ -109,42 +500,630 @@ impl List {
 		}
 	}
 
-	fn update_list_view_data(&mut self, context: &RenderContext, todo_file: &TodoFile) -> &ViewData {
-		let is_visual_mode = self.state == ListState::Visual;
+	// Move the marked lines, and the selected line when it is marked, up or down by one line.
+	fn move_marked_lines(&mut self, rebase_todo: &mut TodoFile, down: bool) {
+		let moved = if down {
+			rebase_todo.move_lines_down(self.marks.indexes())
+		}
+		else {
+			rebase_todo.move_lines_up(self.marks.indexes())
+		};
+		if !moved {
+			return;
+		}
+		let selected_index = rebase_todo.get_selected_line_index();
+		if self.marks.contains(selected_index) {
+			rebase_todo.set_selected_line_index(if down { selected_index + 1 } else { selected_index - 1 });
+		}
+		self.marks.shift(down);
+	}
+
+	// A preview of moved lines is shown with the moved lines selected, the original position of the lines as a row of
+	// its own, and without the folds and the marks, which are of the lines before the move.
+	fn update_list_view_data(
+		&mut self,
+		context: &RenderContext,
+		todo_file: &TodoFile,
+		move_preview: Option<&MovePreview>,
+	) -> &ViewData {
+		let is_visual_mode = self.state == ListState::Visual || move_preview.is_some();
 		let selected_index = todo_file.get_selected_line_index();
-		let visual_index = self.visual_index_start.unwrap_or(selected_index);
+		let visual_index = move_preview.map_or_else(
+			|| self.visual_index_start.unwrap_or(selected_index),
+			MovePreview::end_index,
+		);
+		let _ = self.receive_loaded_details();
+		let warning_lines = self.get_warning_lines();
+		// the lines of a fold are shown on a single row, the first line of the fold
+		let mut rows = if move_preview.is_some() {
+			(0..todo_file.lines_iter().len()).collect()
+		}
+		else {
+			self.folds.get_rows(todo_file.lines_iter().len())
+		};
+		// the row of the original position takes the index of the line after it, so the rows stay in order
+		let ghost_row = move_preview.and_then(MovePreview::ghost_index);
+		if let Some(ghost_index) = ghost_row {
+			rows.insert(ghost_index, ghost_index);
+		}
+		let get_row = |index: usize| rows.partition_point(|&row_index| row_index <= index).saturating_sub(1);
+		let selected_row = get_row(selected_index);
+		let visual_row = get_row(visual_index);
+		// the view scrolls to keep the selected line visible, so only the lines within a window height of the
+		// selected line, and a margin, need to be built
+		let lines_length = rows.len();
+		let lines_start = selected_row.saturating_sub(context.height() + VIRTUAL_LINES_MARGIN);
+		let lines_end = min(
+			lines_length,
+			selected_row + context.height() + VIRTUAL_LINES_MARGIN + 1,
+		);
+		if context.is_full_width() {
+			self.request_abbreviation_length(todo_file);
+		}
+		let hash_length = self
+			.hash_length
+			.or(self.abbreviation_length)
+			.unwrap_or(DEFAULT_HASH_LENGTH);
+		let show_details = context.layout() == Layout::Wide && self.details_loader.is_some();
+		if show_details {
+			// the details are requested for the lines of the rows, the lines hidden in the folds are not shown
+			let details_end = rows.get(lines_end).copied().unwrap_or_else(|| todo_file.lines_iter().len());
+			self.request_commit_details(todo_file, rows.get(lines_start).copied().unwrap_or(0), details_end);
+		}
+		let show_preview = show_details && context.height() >= MINIMUM_PREVIEW_WINDOW_HEIGHT;
+		let action_column = &self.action_column;
+		let folds = &self.folds;
+		let marks = &self.marks;
+		let announcement = self.announcement.as_deref();
+		#[cfg(feature = "tutorial")]
+		let tutorial_line = self.tutorial.as_ref().and_then(Tutorial::get_line);
+		#[cfg(not(feature = "tutorial"))]
+		let tutorial_line: Option<ViewLine> = None;
+		let screen_reader = self.screen_reader;
+		// the graph and the topology of the rebased commits are shown for rebases with merges, where the commits are not
+		// all on one branch, and not with a screen reader, since the glyphs would be read out
+		let show_branches = context.is_full_width()
+			&& !screen_reader
+			&& todo_file
+				.lines_iter()
+				.any(|line| matches!(*line.get_action(), Action::Label | Action::Merge | Action::Reset));
+		let commit_graph = show_branches.then(|| build_commit_graph(todo_file));
+		let topology = self.topology.as_ref().filter(|_| show_branches);
+		let lints = todo_file.lint();
+		let quick_fix_key = self.quick_fix_key.as_str();
+		let commit_details = &self.commit_details;
+		let get_details = |line: &Line| commit_details.get(line.get_hash()).and_then(Option::as_ref);
+		let date_format = &self.date_format;
+		let footer = self
+			.show_key_hints
+			.then_some(&self.key_hints)
+			.filter(|_| move_preview.is_none());
+		let list_date_format = &self.list_date_format;
+		// the dates are aligned to the longest loaded date, since a relative or custom format varies in length
+		let date_width = commit_details
+			.values()
+			.flatten()
+			.map(|details| format_date(&details.date, list_date_format).chars().count())
+			.max()
+			.unwrap_or(DEFAULT_DATE_WIDTH);
 
 		self.view_data.update_view_data(|updater| {
 			capture!(todo_file);
 			updater.clear();
+			if let Some(tutorial_line) = tutorial_line {
+				updater.push_leading_line(tutorial_line);
+			}
+			for line in warning_lines {
+				updater.push_leading_line(line);
+			}
+			if let Some(announcement) = announcement {
+				updater.push_leading_line(ViewLine::from(announcement));
+			}
+			if let Some(preview) = move_preview {
+				updater.push_leading_line(ViewLine::from(LineSegment::new_with_color(
+					translate_format("Moving {} lines past {} lines, with {} possible conflicts", &[
+						&preview.length(),
+						&preview.passed_lines(),
+						&preview.conflicts(),
+					])
+					.as_str(),
+					DisplayColor::IndicatorColor,
+				)));
+			}
 			if todo_file.is_empty() {
 				updater.push_leading_line(ViewLine::from(LineSegment::new_with_color(
-					"Rebase todo file is empty",
+					translate("Rebase todo file is empty"),
 					DisplayColor::IndicatorColor,
 				)));
 			}
 			else {
-				for (index, line) in todo_file.lines_iter().enumerate() {
-					let selected_line = is_visual_mode
+				updater.set_virtual_lines(lines_start, lines_length);
+				for (row, &index) in rows
+					.iter()
+					.enumerate()
+					.skip(lines_start)
+					.take(lines_end.saturating_sub(lines_start))
+				{
+					if ghost_row == Some(row) {
+						updater.push_line(get_move_ghost_line(context.is_full_width(), screen_reader));
+						continue;
+					}
+					let line = match todo_file.get_line(index) {
+						Some(line) => line,
+						None => continue,
+					};
+					// the marked lines are shown like the lines selected in the visual mode
+					let selected_line = (is_visual_mode
 						&& ((visual_index <= selected_index && index >= visual_index && index <= selected_index)
-							|| (visual_index > selected_index && index >= selected_index && index <= visual_index));
-					let mut view_line = ViewLine::new_with_pinned_segments(
-						get_todo_line_segments(line, selected_index == index, selected_line, context.is_full_width()),
-						if *line.get_action() == Action::Exec { 2 } else { 3 },
-					)
-					.set_selected(selected_index == index || selected_line);
+							|| (visual_index > selected_index && index >= selected_index && index <= visual_index)))
+						|| (move_preview.is_none() && marks.contains(index));
+					if let Some((_, fold_end)) = folds.get(index).filter(|_| move_preview.is_none()) {
+						let fold_lines = &todo_file.lines_iter().as_slice()[index..=fold_end];
+						// a collapsed group is named by the name of the group, or by the line that starts the group
+						let name = line
+							.is_group_start()
+							.then(|| line.get_group_name().map_or_else(|| line.to_text(), String::from));
+						let mut view_line = ViewLine::from(get_fold_segments(
+							fold_lines,
+							name.as_deref(),
+							selected_index == index,
+							selected_line,
+							context,
+							hash_length,
+							screen_reader,
+						));
+						if selected_index == index || selected_line {
+							view_line = view_line.set_selected(true).set_padding(' ');
+						}
+						updater.push_line(view_line);
+						continue;
+					}
+					let mut segments = get_todo_line_segments(
+						line,
+						selected_index == index,
+						selected_line,
+						context,
+						action_column,
+						hash_length,
+						screen_reader,
+					);
+					// the indicator, action and, if shown, the hash are always visible
+					let mut pinned_segments = if *line.get_action() == Action::Exec || !context.is_compact_width() {
+						2
+					}
+					else {
+						3
+					};
+					if show_details && line.has_reference() {
+						let details_segments = get_commit_details_segments(get_details(line), list_date_format, date_width);
+						pinned_segments += details_segments.len();
+						let _ = segments.splice(3..3, details_segments);
+					}
+					let content_index = segments.len() - usize::from(!line.get_content().is_empty());
+					if let Some(topology) = topology {
+						segments.insert(content_index, get_topology_segment(line, topology));
+						pinned_segments += 1;
+					}
+					if let Some(row) = commit_graph.as_ref().and_then(|rows| rows.get(index)) {
+						segments.insert(
+							content_index,
+							LineSegment::new_with_color_and_style(row, DisplayColor::Normal, true, false, false),
+						);
+						pinned_segments += 1;
+					}
+					if let Some(name) = line.get_group_name() {
+						segments.push(LineSegment::new_with_color(
+							format!(" {name} ").as_str(),
+							DisplayColor::IndicatorColor,
+						));
+					}
+					segments.extend(
+						lints
+							.iter()
+							.filter(|lint| lint.index() == index)
+							.map(|lint| get_lint_segment(lint, quick_fix_key)),
+					);
+					if move_preview.map_or(false, |preview| preview.is_conflict(index)) {
+						segments.push(LineSegment::new_with_color(
+							translate("  Possible conflict with the moved commits"),
+							DisplayColor::IndicatorColor,
+						));
+					}
+					if let Some(source) = get_details(line).and_then(|details| details.cherry_picked_from.as_deref()) {
+						segments.extend(get_cherry_pick_segments(source, todo_file, hash_length));
+					}
+					let mut view_line = ViewLine::new_with_pinned_segments(segments, pinned_segments)
+						.set_selected(selected_index == index || selected_line);
 
 					if selected_index == index || selected_line {
 						view_line = view_line.set_selected(true).set_padding(' ');
 					}
+					// the break and label lines that start the groups of lines are drawn across the view, to separate
+					// the groups, but not with a screen reader, since the glyphs would be read out
+					else if line.is_group_start() && !screen_reader {
+						let color = if *line.get_action() == Action::Label {
+							DisplayColor::ActionLabel
+						}
+						else {
+							DisplayColor::ActionBreak
+						};
+						view_line = view_line.set_padding_with_color_and_style(
+							'─',
+							color,
+							true,
+							false,
+							false,
+						);
+					}
 
 					updater.push_line(view_line);
 				}
 			}
-			if visual_index != selected_index {
-				updater.ensure_line_visible(visual_index);
+			if show_preview {
+				let selected_line = todo_file.get_selected_line();
+				let is_loading = selected_line.map_or(false, |line| !commit_details.contains_key(line.get_hash()));
+				for line in get_preview_lines(
+					selected_line,
+					selected_line.and_then(get_details),
+					is_loading,
+					date_format,
+				) {
+					updater.push_trailing_line(line);
+				}
+			}
+			if move_preview.is_some() {
+				updater.push_trailing_line(ViewLine::new_pinned(vec![LineSegment::new_with_color(
+					translate("Press Enter to move the lines, or Escape to cancel"),
+					DisplayColor::IndicatorColor,
+				)]));
+			}
+			if let Some(key_hints) = footer {
+				updater.push_trailing_line(key_hints.get_line(todo_file.get_selected_line(), is_visual_mode));
 			}
-			updater.ensure_line_visible(selected_index);
+			if visual_row != selected_row {
+				updater.ensure_line_visible(visual_row);
+			}
+			updater.ensure_line_visible(selected_row);
+		});
+		&self.view_data
+	}
+
+	fn request_commit_details(&mut self, todo_file: &TodoFile, start: usize, end: usize) {
+		if let Some(ref details_loader) = self.details_loader {
+			for line in todo_file
+				.lines_iter()
+				.skip(start)
+				.take(end.saturating_sub(start))
+				.filter(|line| line.has_reference())
+			{
+				if self.requested_commit_details.insert(String::from(line.get_hash())) {
+					details_loader.request_commit(line.get_hash());
+				}
+			}
+		}
+	}
+
+	// when the length is not configured, it is found from the repository once, when the hashes are first shown
+	fn request_abbreviation_length(&mut self, todo_file: &TodoFile) {
+		if self.hash_length.is_some() || self.abbreviation_length_requested {
+			return;
+		}
+		if let Some(ref details_loader) = self.details_loader {
+			self.abbreviation_length_requested = true;
+			details_loader.request_abbreviation_length(
+				todo_file
+					.lines_iter()
+					.filter(|line| line.has_reference())
+					.map(|line| String::from(line.get_hash()))
+					.collect(),
+			);
+		}
+	}
+
+	fn receive_loaded_details(&mut self) -> bool {
+		let loaded_details = match self.details_loader {
+			Some(ref details_loader) => details_loader.take_loaded(),
+			None => return false,
+		};
+		let received = !loaded_details.is_empty();
+		for loaded_detail in loaded_details {
+			self.apply_loaded_detail(loaded_detail);
+		}
+		received
+	}
+
+	fn apply_loaded_detail(&mut self, loaded_detail: LoadedDetail) {
+		match loaded_detail {
+			LoadedDetail::AbbreviationLength(length) => self.abbreviation_length = Some(length),
+			LoadedDetail::Commit(hash, details) => {
+				let _ = self.commit_details.insert(hash, details);
+			},
+			LoadedDetail::Topology(topology) => self.topology = Some(topology),
+			LoadedDetail::UncommittedChanges(has_uncommitted_changes) => {
+				self.has_uncommitted_changes = has_uncommitted_changes;
+			},
+			LoadedDetail::Warning(warning) => self.warnings.push(warning),
+		}
+	}
+
+	// a change to the todo file is only offered in the normal mode, so that an edit is not interrupted, and a change
+	// that is the same as the list, such as the file written for the external editor, is not offered
+	fn receive_external_change(&mut self, todo_file: &TodoFile) -> bool {
+		if self.state != ListState::Normal {
+			self.external_change_pending = true;
+			return false;
+		}
+		self.external_change_pending = false;
+		let contents = match self.file_watcher.as_mut().and_then(FileWatcher::take_changed) {
+			Some(contents) => contents,
+			None => return false,
+		};
+		let mut changed_todo_file = todo_file.with_lines(vec![]);
+		if let Err(err) = changed_todo_file.load_str(contents.as_str()) {
+			self.announcement = Some(translate_format(
+				"The todo file was changed by another program, and cannot be read: {}",
+				&[&format!("{:#}", err)],
+			));
+			return true;
+		}
+		let lines = changed_todo_file.get_lines_owned();
+		let current_lines = todo_file.get_lines_owned();
+		if is_same_lines(lines.as_slice(), self.watched_lines.as_slice())
+			|| is_same_lines(lines.as_slice(), current_lines.as_slice())
+		{
+			self.watched_lines = lines;
+			return false;
+		}
+		let edited = !is_same_lines(current_lines.as_slice(), self.watched_lines.as_slice());
+		let merged_lines = edited
+			.then(|| todo_file.merge_lines(self.watched_lines.as_slice(), lines.as_slice()))
+			.flatten();
+		self.external_change = Some(ExternalChange::new(lines, edited, merged_lines));
+		self.state = ListState::ExternalChange;
+		true
+	}
+
+	fn handle_external_change_input(&mut self, event_handler: &EventHandler, todo_file: &mut TodoFile) -> ProcessResult {
+		let external_change = match self.external_change {
+			Some(ref mut external_change) => external_change,
+			None => {
+				self.state = ListState::Normal;
+				return ProcessResult::new();
+			},
+		};
+		let (action, event) = external_change.handle_event(event_handler);
+		if let Some(action) = action {
+			if let Some(external_change) = self.external_change.take() {
+				let (lines, merged_lines) = external_change.into_lines();
+				match action {
+					ExternalChangeAction::Reload => todo_file.replace_lines(lines.clone()),
+					ExternalChangeAction::Merge => {
+						if let Some(merged_lines) = merged_lines {
+							todo_file.replace_lines(merged_lines);
+						}
+					},
+					ExternalChangeAction::Keep => {},
+				}
+				// later changes are compared to the todo file as it is now, whichever of the changes were kept
+				self.watched_lines = lines;
+			}
+			self.state = ListState::Normal;
+		}
+		ProcessResult::from(event)
+	}
+
+	#[cfg(test)]
+	fn wait_for_external_change(&mut self) {
+		if let Some(ref mut file_watcher) = self.file_watcher {
+			file_watcher.wait();
+		}
+	}
+
+	#[cfg(test)]
+	fn wait_for_loaded_details(&mut self) {
+		let loaded_details = self.details_loader.as_mut().map_or_else(Vec::new, DetailsLoader::wait);
+		for loaded_detail in loaded_details {
+			self.apply_loaded_detail(loaded_detail);
+		}
+	}
+
+	fn get_warning_lines(&self) -> Vec<ViewLine> {
+		let mut lines: Vec<ViewLine> = self
+			.warnings
+			.iter()
+			.map(|warning| {
+				ViewLine::from(LineSegment::new_with_color(
+					translate_format("Warning: {}", &[warning]).as_str(),
+					DisplayColor::IndicatorColor,
+				))
+			})
+			.collect();
+
+		if !self.has_uncommitted_changes {
+			return lines;
+		}
+
+		if self.auto_stash {
+			lines.push(ViewLine::from(LineSegment::new_with_color(
+				translate("Uncommitted changes will be stashed by rebase.autoStash"),
+				DisplayColor::IndicatorColor,
+			)));
+			return lines;
+		}
+
+		lines.push(ViewLine::from(LineSegment::new_with_color(
+			translate_format(
+				if self.show_stash_info {
+					"Warning: The working tree has uncommitted changes, press {} to hide details"
+				}
+				else {
+					"Warning: The working tree has uncommitted changes, press {} to show details"
+				},
+				&[&self.stash_info_key],
+			)
+			.as_str(),
+			DisplayColor::IndicatorColor,
+		)));
+		if self.show_stash_info {
+			lines.push(ViewLine::from(translate(
+				"The rebase will fail to start unless the changes are committed or stashed,",
+			)));
+			lines.push(ViewLine::from(translate(
+				"or rebase.autoStash is enabled. The todo list will not be changed.",
+			)));
+		}
+		lines
+	}
+
+	// the commits that change a file that matches the path filter, of the commits whose changed files have been loaded
+	fn get_path_filter_matches(&self, todo_file: &TodoFile) -> Vec<(usize, Option<String>)> {
+		let path_filter = match self.path_filter {
+			Some(ref path_filter) => path_filter,
+			None => return vec![],
+		};
+		todo_file
+			.lines_iter()
+			.enumerate()
+			.filter(|&(_, line)| line.has_reference() && *line.get_action() != Action::Merge)
+			.filter(|&(_, line)| {
+				self.commit_details
+					.get(line.get_hash())
+					.and_then(Option::as_ref)
+					.map_or(false, |details| details.paths.iter().any(|path| path_filter.matches(path)))
+			})
+			.map(|(index, _)| (index, None))
+			.collect()
+	}
+
+	// the other commits that change a file that the related commit changes, with the files that both commits change
+	fn get_related_commit_matches(&self, todo_file: &TodoFile) -> Vec<(usize, Option<String>)> {
+		let hash = match self.related_commit {
+			Some(ref hash) => hash,
+			None => return vec![],
+		};
+		let related_details = match self.commit_details.get(hash).and_then(Option::as_ref) {
+			Some(related_details) => related_details,
+			None => return vec![],
+		};
+		todo_file
+			.lines_iter()
+			.enumerate()
+			.filter(|&(_, line)| {
+				line.has_reference() && *line.get_action() != Action::Merge && line.get_hash() != hash
+			})
+			.filter_map(|(index, line)| {
+				let details = self.commit_details.get(line.get_hash())?.as_ref()?;
+				let shared_paths: Vec<String> = details
+					.paths
+					.iter()
+					.filter(|path| related_details.paths.contains(path))
+					.map(|path| path.to_string_lossy().into_owned())
+					.collect();
+				(!shared_paths.is_empty()).then(|| (index, Some(shared_paths.join(", "))))
+			})
+			.collect()
+	}
+
+	fn get_filter_matches(&self, todo_file: &TodoFile) -> Vec<(usize, Option<String>)> {
+		if self.state == ListState::RelatedCommits {
+			self.get_related_commit_matches(todo_file)
+		}
+		else {
+			self.get_path_filter_matches(todo_file)
+		}
+	}
+
+	fn get_filter_view_data(&mut self, todo_file: &TodoFile, context: &RenderContext) -> &ViewData {
+		let _ = self.receive_loaded_details();
+		let matches = self.get_filter_matches(todo_file);
+		let selected = min(self.filter_index, matches.len().saturating_sub(1));
+		let loading = todo_file
+			.lines_iter()
+			.filter(|line| line.has_reference() && !self.commit_details.contains_key(line.get_hash()))
+			.count();
+		let is_available = self.details_loader.is_some();
+		let hash_length = self
+			.hash_length
+			.or(self.abbreviation_length)
+			.unwrap_or(DEFAULT_HASH_LENGTH);
+		let title = match self.related_commit {
+			Some(ref hash) if self.state == ListState::RelatedCommits => {
+				translate_format("Commits that change the files of {}: {}", &[
+					&hash.get(..hash_length).unwrap_or(hash),
+					&matches.len(),
+				])
+			},
+			_ => {
+				translate_format("Commits that change {}: {}", &[
+					&self.path_filter.as_ref().map_or("", PathFilter::pattern),
+					&matches.len(),
+				])
+			},
+		};
+		let action_column = &self.action_column;
+		let screen_reader = self.screen_reader;
+
+		self.view_data.update_view_data(|updater| {
+			updater.clear();
+			updater.push_leading_line(ViewLine::from(LineSegment::new_with_color(
+				title.as_str(),
+				DisplayColor::IndicatorColor,
+			)));
+			// without a loader, the changed files of the commits are never loaded
+			if !is_available {
+				updater.push_leading_line(ViewLine::from(LineSegment::new_with_color_and_style(
+					translate("The changed files of the commits are not available"),
+					DisplayColor::Normal,
+					true,
+					false,
+					false,
+				)));
+			}
+			else if loading > 0 {
+				updater.push_leading_line(ViewLine::from(LineSegment::new_with_color_and_style(
+					translate_format("Loading the changed files of {} commits", &[&loading]).as_str(),
+					DisplayColor::Normal,
+					true,
+					false,
+					false,
+				)));
+			}
+			for (match_index, (line, note)) in matches
+				.iter()
+				.filter_map(|&(index, ref note)| todo_file.get_line(index).map(|line| (line, note)))
+				.enumerate()
+			{
+				let is_selected = match_index == selected;
+				let mut segments = get_todo_line_segments(
+					line,
+					is_selected,
+					false,
+					context,
+					action_column,
+					hash_length,
+					screen_reader,
+				);
+				if let Some(note) = note.as_deref() {
+					segments.push(LineSegment::new_with_color_and_style(
+						format!("  {}", note).as_str(),
+						DisplayColor::Normal,
+						true,
+						false,
+						false,
+					));
+				}
+				let pinned_segments = if context.is_compact_width() { 3 } else { 2 };
+				let mut view_line = ViewLine::new_with_pinned_segments(segments, pinned_segments);
+				if is_selected {
+					view_line = view_line.set_selected(true).set_padding(' ');
+				}
+				updater.push_line(view_line);
+			}
+			updater.push_trailing_line(ViewLine::from(LineSegment::new_with_color(
+				translate("Press Enter to select the commit in the list, or any other key to return to the list"),
+				DisplayColor::IndicatorColor,
+			)));
+			updater.ensure_line_visible(selected);
 		});
 		&self.view_data
 	}
@@ -154,16 +1133,56 @@ impl List {
 			self.visual_mode_help.get_view_data()
 		}
 		else {
-			self.update_list_view_data(context, todo_file)
+			self.update_list_view_data(context, todo_file, None)
 		}
 	}
 
+	fn get_move_view_data(&mut self, todo_file: &TodoFile, context: &RenderContext) -> &ViewData {
+		let _ = self.receive_loaded_details();
+		let (start, end) = match self.move_range {
+			Some((start, end)) => (start, end),
+			None => return self.update_list_view_data(context, todo_file, None),
+		};
+		let preview = MovePreview::new(todo_file, start, end, self.move_start, &self.commit_details);
+		self.update_list_view_data(context, preview.todo_file(), Some(&preview))
+	}
+
 	fn get_normal_mode_view_data(&mut self, todo_file: &TodoFile, context: &RenderContext) -> &ViewData {
 		if self.normal_mode_help.is_active() {
 			self.normal_mode_help.get_view_data()
 		}
+		else if self.statistics.is_active() {
+			let _ = self.receive_loaded_details();
+			// without a loader, the changed files of the commits are never loaded
+			let commit_details = self.details_loader.is_some().then_some(&self.commit_details);
+			self.statistics.get_view_data(todo_file, commit_details)
+		}
 		else {
-			self.update_list_view_data(context, todo_file)
+			self.update_list_view_data(context, todo_file, None)
+		}
+	}
+
+	// when the todo list is read only, the events that change it are ignored, and a rebase or an abort exits without
+	// a confirmation, since the todo file is not written
+	fn handle_read_only_input(&mut self, event: Event) -> Option<ProcessResult> {
+		let meta_event = match event {
+			Event::Meta(meta_event) => meta_event,
+			_ => return None,
+		};
+		if !self.read_only {
+			return None;
+		}
+		match meta_event {
+			MetaEvent::Rebase | MetaEvent::ForceRebase => {
+				Some(ProcessResult::from(event).exit_status(ExitStatus::Good))
+			},
+			MetaEvent::QuitWithoutSaving => Some(ProcessResult::from(event).exit_status(ExitStatus::Quit)),
+			MetaEvent::Abort | MetaEvent::ForceAbort => Some(ProcessResult::from(event).exit_status(ExitStatus::Abort)),
+			_ if is_mutation(meta_event) => {
+				self.announcement = Some(String::from(translate("The todo list is read only")));
+				Some(ProcessResult::from(event))
+			},
+			_ => None,
 		}
 	}
 
@@ -174,13 +1193,24 @@ impl List {
 		rebase_todo: &mut TodoFile,
 	) -> Option<ProcessResult> {
 		let mut result = ProcessResult::from(event);
+		// without a screen reader, a description of the line is only shown until the next event
+		if !self.screen_reader && event != Event::None {
+			self.announcement = None;
+		}
 		match event {
 			Event::Meta(meta_event) => {
 				match meta_event {
+					MetaEvent::DescribeLine => self.announcement = Some(self.describe_selected_line(rebase_todo)),
 					MetaEvent::MoveCursorLeft => view_sender.scroll_left(),
 					MetaEvent::MoveCursorRight => view_sender.scroll_right(),
-					MetaEvent::MoveCursorDown => Self::move_cursor_down(rebase_todo, 1),
-					MetaEvent::MoveCursorUp => Self::move_cursor_up(rebase_todo, 1),
+					MetaEvent::MoveCursorDown => {
+						let amount = self.key_repeat.get_amount(meta_event, Instant::now(), self.height / 2);
+						Self::move_cursor_down(rebase_todo, amount);
+					},
+					MetaEvent::MoveCursorUp => {
+						let amount = self.key_repeat.get_amount(meta_event, Instant::now(), self.height / 2);
+						Self::move_cursor_up(rebase_todo, amount);
+					},
 					MetaEvent::MoveCursorPageDown => Self::move_cursor_down(rebase_todo, self.height / 2),
 					MetaEvent::MoveCursorPageUp => Self::move_cursor_up(rebase_todo, self.height / 2),
 					MetaEvent::MoveCursorHome => rebase_todo.set_selected_line_index(0),
@@ -188,12 +1218,23 @@ impl List {
 						rebase_todo.set_selected_line_index(rebase_todo.get_max_selected_line_index());
 					},
 					MetaEvent::Abort => result = result.state(State::ConfirmAbort),
-					MetaEvent::ForceAbort => {
-						rebase_todo.set_lines(vec![]);
-						result = result.exit_status(ExitStatus::Good);
+					MetaEvent::ForceAbort => result = result.exit_status(abort(self.abort_behavior, rebase_todo)),
+					MetaEvent::QuitWithoutSaving => result = result.state(State::ConfirmQuit),
+					MetaEvent::Rebase => {
+						result = result.state(if self.review_changes {
+							State::ReviewChanges
+						}
+						else {
+							State::ConfirmRebase
+						});
 					},
-					MetaEvent::Rebase => result = result.state(State::ConfirmRebase),
 					MetaEvent::ForceRebase => result = result.exit_status(ExitStatus::Good),
+					MetaEvent::SwapSelectedDown if self.state == ListState::Normal && !self.marks.is_empty() => {
+						self.move_marked_lines(rebase_todo, true);
+					},
+					MetaEvent::SwapSelectedUp if self.state == ListState::Normal && !self.marks.is_empty() => {
+						self.move_marked_lines(rebase_todo, false);
+					},
 					MetaEvent::SwapSelectedDown => {
 						let start_index = rebase_todo.get_selected_line_index();
 						let end_index = self.visual_index_start.unwrap_or(start_index);
@@ -216,12 +1257,29 @@ impl List {
 							Self::move_cursor_up(rebase_todo, 1);
 						}
 					},
+					MetaEvent::BranchOut => {
+						let start_index = rebase_todo.get_selected_line_index();
+						let end_index = self.visual_index_start.unwrap_or(start_index);
+
+						// the selected lines are moved down by the label of the base of the new branch
+						if rebase_todo.branch_out(start_index, end_index).is_some() {
+							rebase_todo.set_selected_line_index(start_index + 1);
+							if let Some(visual_index_start) = self.visual_index_start {
+								self.visual_index_start = Some(visual_index_start + 1);
+							}
+						}
+					},
 					MetaEvent::ActionDrop => self.set_selected_line_action(rebase_todo, Action::Drop),
 					MetaEvent::ActionEdit => self.set_selected_line_action(rebase_todo, Action::Edit),
 					MetaEvent::ActionFixup => self.set_selected_line_action(rebase_todo, Action::Fixup),
 					MetaEvent::ActionPick => self.set_selected_line_action(rebase_todo, Action::Pick),
 					MetaEvent::ActionReword => self.set_selected_line_action(rebase_todo, Action::Reword),
 					MetaEvent::ActionSquash => self.set_selected_line_action(rebase_todo, Action::Squash),
+					MetaEvent::CycleFixupOption => {
+						let start_index = rebase_todo.get_selected_line_index();
+						let end_index = self.visual_index_start.unwrap_or(start_index);
+						let _ = rebase_todo.cycle_fixup_option(start_index, end_index);
+					},
 					MetaEvent::Undo => {
 						if let Some((start_index, end_index)) = rebase_todo.undo() {
 							rebase_todo.set_selected_line_index(start_index);
@@ -262,6 +1320,79 @@ impl List {
 						}
 					},
 					MetaEvent::OpenInEditor => result = result.state(State::ExternalEditor),
+					MetaEvent::ToggleMark => {
+						let start_index = rebase_todo.get_selected_line_index();
+						let end_index = self.visual_index_start.unwrap_or(start_index);
+						self.marks.toggle(
+							min(start_index, end_index),
+							max(start_index, end_index),
+							rebase_todo.lines_iter().len(),
+						);
+						// the marked lines replace the selected lines of the visual mode
+						self.state = ListState::Normal;
+						self.visual_index_start = None;
+					},
+					MetaEvent::ClearMarks => self.marks.clear(),
+					MetaEvent::StartMove => {
+						if !rebase_todo.is_empty() {
+							let start_index = rebase_todo.get_selected_line_index();
+							let end_index = self.visual_index_start.unwrap_or(start_index);
+							self.move_range = Some((min(start_index, end_index), max(start_index, end_index)));
+							self.move_start = min(start_index, end_index);
+							// the changed files of the commits that the lines are moved past are compared to the
+							// changed files of the moved commits
+							self.request_commit_details(rebase_todo, 0, rebase_todo.lines_iter().len());
+							self.state = ListState::Move;
+						}
+					},
+					MetaEvent::SortLines => {
+						// the marked lines are sorted in the normal mode, and the selected lines in the visual mode
+						let indexes: Vec<usize> = if self.state == ListState::Visual {
+							let start_index = rebase_todo.get_selected_line_index();
+							let end_index = self.visual_index_start.unwrap_or(start_index);
+							(min(start_index, end_index)..=max(start_index, end_index)).collect()
+						}
+						else {
+							self.marks.indexes().to_vec()
+						};
+						if indexes.len() > 1 {
+							// the dates and the authors of the commits are needed to sort the commits
+							self.request_commit_details(rebase_todo, 0, rebase_todo.lines_iter().len());
+							self.sort_indexes = indexes;
+							self.edit.clear();
+							self.edit.set_label(translate("Sort by: "));
+							self.edit.set_history("sort");
+							self.edit.set_description(translate("author-date, committer-date, author or subject"));
+							self.state = ListState::SortEdit;
+						}
+					},
+					MetaEvent::ReverseLines => {
+						// the selected lines are reversed in the visual mode, and the marked lines, or all of the
+						// lines, in the normal mode
+						let is_marked = self.state != ListState::Visual && !self.marks.is_empty();
+						let indexes: Vec<usize> = if self.state == ListState::Visual {
+							let start_index = rebase_todo.get_selected_line_index();
+							let end_index = self.visual_index_start.unwrap_or(start_index);
+							(min(start_index, end_index)..=max(start_index, end_index)).collect()
+						}
+						else if is_marked {
+							self.marks.indexes().to_vec()
+						}
+						else {
+							(0..rebase_todo.lines_iter().len()).collect()
+						};
+						let reversed: Vec<usize> = indexes.iter().rev().copied().collect();
+						if let (Some(&start), Some(&end)) = (indexes.first(), indexes.last()) {
+							// the selected line moves with its line, and the marks stay on the marked positions
+							if rebase_todo.reorder_lines(reversed.as_slice()) && !is_marked {
+								let selected_index = rebase_todo.get_selected_line_index();
+								rebase_todo.set_selected_line_index(start + end - selected_index);
+								if let Some(visual_index_start) = self.visual_index_start {
+									self.visual_index_start = Some(start + end - visual_index_start);
+								}
+							}
+						}
+					},
 					MetaEvent::ToggleVisualMode => {
 						if self.state == ListState::Visual {
 							self.state = ListState::Normal;
@@ -301,8 +1432,14 @@ impl List {
 		if self.normal_mode_help.is_active() {
 			return ProcessResult::from(self.normal_mode_help.handle_event(event_handler, view_sender));
 		}
+		if self.statistics.is_active() {
+			return ProcessResult::from(self.statistics.handle_event(event_handler, view_sender));
+		}
 
 		let event = get_event(event_handler);
+		if let Some(result) = self.handle_read_only_input(event) {
+			return result;
+		}
 		if let Some(result) = self.handle_common_list_input(event, view_sender, rebase_todo) {
 			result
 		}
@@ -317,6 +1454,42 @@ impl List {
 							}
 						}
 					},
+					MetaEvent::Autosquash => result = result.state(State::AutosquashPreview),
+					MetaEvent::FilterByPath => {
+						self.edit.clear();
+						self.edit
+							.set_content(self.path_filter.as_ref().map_or("", PathFilter::pattern));
+						self.edit.set_label(translate("Path: "));
+						self.edit.set_history("path");
+						self.edit.set_description(translate(
+							"Show the commits that change a file that matches a path, or a glob with *, ** and ?",
+						));
+						self.state = ListState::PathFilterEdit;
+					},
+					MetaEvent::ShowRelatedCommits => {
+						if let Some(selected_line) = rebase_todo.get_selected_line() {
+							if selected_line.has_reference() && *selected_line.get_action() != Action::Merge {
+								self.related_commit = Some(String::from(selected_line.get_hash()));
+								// the changed files of all of the commits are needed to find the related commits
+								self.request_commit_details(rebase_todo, 0, rebase_todo.lines_iter().len());
+								self.filter_index = 0;
+								self.state = ListState::RelatedCommits;
+							}
+						}
+					},
+					MetaEvent::ShowSquashMessage => {
+						if rebase_todo
+							.get_squash_chain(rebase_todo.get_selected_line_index())
+							.is_some()
+						{
+							result = result.state(State::SquashMessage);
+						}
+					},
+					MetaEvent::ShowStatistics => {
+						// the statistics of the changed files need the details of all of the commits
+						self.request_commit_details(rebase_todo, 0, rebase_todo.lines_iter().len());
+						self.statistics.set_active();
+					},
 					MetaEvent::ActionBreak => {
 						let selected_line_index = rebase_todo.get_selected_line_index();
 						let next_action_is_break = rebase_todo
@@ -336,19 +1509,129 @@ impl List {
 							}
 						}
 					},
+					MetaEvent::MarkMatching => {
+						// the authors and the changed files of all of the commits are needed to match the commits
+						self.request_commit_details(rebase_todo, 0, rebase_todo.lines_iter().len());
+						self.edit.clear();
+						self.edit.set_label(translate("Mark: "));
+						self.edit.set_history("mark");
+						self.edit.set_description(translate(
+							"An action, such as exec or fixup, author: and a part of the name or email of an \
+							 author, or filter for the commits that match the last path filter",
+						));
+						self.state = ListState::MarkEdit;
+					},
+					MetaEvent::BreakEvery => {
+						self.number_input.clear();
+						self.number_input.set_label(translate("Commits: "));
+						self.number_input.set_bounds(1, rebase_todo.lines_iter().len());
+						self.number_input.set_description(translate(
+							"Insert a break after every number of commits, counted from the start and from each break",
+						));
+						self.state = ListState::BreakEveryEdit;
+					},
+					MetaEvent::JumpToLine => {
+						self.number_input.clear();
+						self.number_input.set_label(translate("Line: "));
+						self.number_input.set_bounds(1, rebase_todo.lines_iter().len());
+						self.number_input.set_description(translate("The number of the line to select"));
+						self.state = ListState::JumpToLineEdit;
+					},
+					MetaEvent::ToggleGroup => {
+						let selected_line_index = rebase_todo.get_selected_line_index();
+						// a collapsed group is expanded, even if the lines of the group have changed
+						if self.folds.remove(selected_line_index).is_none() {
+							if let Some((start, end)) = rebase_todo.get_group_range(selected_line_index) {
+								self.folds.add(start, end, rebase_todo.lines_iter().len());
+								rebase_todo.set_selected_line_index(start);
+							}
+						}
+					},
+					MetaEvent::ToggleAllFolds => {
+						let lines_length = rebase_todo.lines_iter().len();
+						if self.folds.is_empty() {
+							let mut index = 0;
+							while let Some((start, end)) = rebase_todo.get_group_range(index) {
+								self.folds.add(start, end, lines_length);
+								index = end + 1;
+							}
+						}
+						else {
+							self.folds.clear();
+						}
+						// the selection moves to the row of its fold
+						if let Some((start, _)) = self.folds.get(rebase_todo.get_selected_line_index()) {
+							rebase_todo.set_selected_line_index(start);
+						}
+					},
+					MetaEvent::NameGroup => {
+						let group_start = rebase_todo
+							.get_group_range(rebase_todo.get_selected_line_index())
+							.and_then(|(start, _)| rebase_todo.get_line(start))
+							.filter(|line| line.is_group_start());
+						if let Some(line) = group_start {
+							self.edit.clear();
+							self.edit.set_content(line.get_group_name().unwrap_or_default());
+							self.edit.set_label(translate("Group: "));
+							self.edit.set_history("group");
+							self.edit.set_description(
+								translate_format(
+									"The name of the group of lines that starts with {}, or nothing to remove the name",
+									&[&line.to_text()],
+								)
+								.as_str(),
+							);
+							self.state = ListState::GroupNameEdit;
+						}
+					},
+					MetaEvent::RemoveBreaks => {
+						let selected_line_index = rebase_todo.get_selected_line_index();
+						let breaks_before = rebase_todo
+							.lines_iter()
+							.take(selected_line_index)
+							.filter(|line| *line.get_action() == Action::Break)
+							.count();
+						let breaks = rebase_todo
+							.lines_iter()
+							.filter(|line| *line.get_action() == Action::Break)
+							.count();
+						if rebase_todo.remove_breaks() {
+							rebase_todo.set_selected_line_index(selected_line_index - breaks_before);
+							result = result.notification(translate_format("Removed breaks: {}", &[&breaks]).as_str());
+						}
+					},
 					MetaEvent::Edit => {
 						if let Some(selected_line) = rebase_todo.get_selected_line() {
-							if selected_line.is_editable() {
+							if *selected_line.get_action() == Action::Merge {
+								self.start_merge_edit(selected_line);
+							}
+							else if selected_line.is_editable() {
 								self.state = ListState::Edit;
 								self.edit.set_content(selected_line.get_content());
 								self.edit
 									.set_label(format!("{} ", selected_line.get_action().as_string()).as_str());
-								self.edit
-									.set_description(format!("Modifying line: {}", selected_line.to_text()).as_str());
+								self.edit.set_history("edit");
+								self.edit.set_description(
+									translate_format("Modifying line: {}", &[&selected_line.to_text()]).as_str(),
+								);
 							}
 						}
 					},
 					MetaEvent::InsertLine => result = result.state(State::Insert),
+					MetaEvent::TestCommit => result = self.test_selected_commit(rebase_todo, result),
+					MetaEvent::ToggleStashInfo => {
+						self.show_stash_info = !self.show_stash_info;
+						self.update_ui_state();
+					},
+					MetaEvent::AbortRebase => result = result.state(State::ConfirmAbortRebase),
+					MetaEvent::SkipCommit => result = result.state(State::ConfirmSkipCommit),
+					MetaEvent::OpenSettings => result = result.state(State::Settings),
+					MetaEvent::ShowNotifications => result = result.state(State::NotificationHistory),
+					MetaEvent::QuickFix => {
+						if let Some(index) = rebase_todo.fix_fixup_target(rebase_todo.get_selected_line_index()) {
+							rebase_todo.set_selected_line_index(index);
+						}
+					},
 					_ => {},
 				}
 			}
@@ -367,8 +1650,425 @@ impl List {
 		}
 
 		let event = get_event(event_handler);
-		self.handle_common_list_input(event, view_sender, rebase_todo)
-			.unwrap_or_else(|| ProcessResult::from(event))
+		if let Some(result) = self.handle_read_only_input(event) {
+			return result;
+		}
+		if let Some(result) = self.handle_common_list_input(event, view_sender, rebase_todo) {
+			return result;
+		}
+		let mut result = ProcessResult::from(event);
+		// the selected lines are collapsed to a single row, and the visual mode is left
+		if let Event::Meta(MetaEvent::ToggleGroup) = event {
+			let selected_index = rebase_todo.get_selected_line_index();
+			let visual_index = self.visual_index_start.unwrap_or(selected_index);
+			let start = min(selected_index, visual_index);
+			self.folds
+				.add(start, max(selected_index, visual_index), rebase_todo.lines_iter().len());
+			rebase_todo.set_selected_line_index(start);
+			self.visual_index_start = None;
+			self.state = ListState::Normal;
+		}
+		if let Event::Meta(MetaEvent::ShowCommit) = event {
+			rebase_todo.set_selected_range_start(self.visual_index_start);
+			if rebase_todo
+				.get_selected_lines()
+				.iter()
+				.any(|line| line.has_reference() && *line.get_action() != Action::Merge)
+			{
+				result = result.state(State::SquashPreview);
+			}
+		}
+		result
+	}
+
+	// The move is only made when it is confirmed, until then the lines are shown in a preview of the move.
+	fn handle_move_input(&mut self, event_handler: &EventHandler, rebase_todo: &mut TodoFile) -> ProcessResult {
+		let event = get_event(event_handler);
+		let (start, end) = match self.move_range {
+			Some((start, end)) => (start, end),
+			None => {
+				self.state = ListState::Normal;
+				return ProcessResult::from(event);
+			},
+		};
+		let max_start = rebase_todo.lines_iter().len().saturating_sub(end - start + 1);
+		let previous_state = self.get_list_state();
+		match event {
+			Event::Meta(MetaEvent::MoveCursorUp | MetaEvent::SwapSelectedUp) => {
+				self.move_start = self.move_start.saturating_sub(1);
+			},
+			Event::Meta(MetaEvent::MoveCursorDown | MetaEvent::SwapSelectedDown) => {
+				self.move_start = min(self.move_start + 1, max_start);
+			},
+			Event::Meta(MetaEvent::MoveCursorPageUp) => {
+				self.move_start = self.move_start.saturating_sub(self.height / 2);
+			},
+			Event::Meta(MetaEvent::MoveCursorPageDown) => {
+				self.move_start = min(self.move_start + self.height / 2, max_start);
+			},
+			Event::Meta(MetaEvent::MoveCursorHome) => self.move_start = 0,
+			Event::Meta(MetaEvent::MoveCursorEnd) => self.move_start = max_start,
+			Event::Key(KeyEvent {
+				code: KeyCode::Enter, ..
+			}) => {
+				if rebase_todo.move_range(start, end, self.move_start) {
+					// the selected lines are moved with the lines
+					let selected_index = rebase_todo.get_selected_line_index();
+					rebase_todo.set_selected_line_index(selected_index - start + self.move_start);
+					if let Some(visual_index_start) = self.visual_index_start {
+						self.visual_index_start = Some(visual_index_start - start + self.move_start);
+					}
+				}
+				self.move_range = None;
+				self.state = previous_state;
+			},
+			Event::Key(KeyEvent { code: KeyCode::Esc, .. }) => {
+				self.move_range = None;
+				self.state = previous_state;
+			},
+			Event::Resize(_, height) => self.height = height as usize,
+			_ => {},
+		}
+		ProcessResult::from(event)
+	}
+
+	fn handle_path_filter_edit_input(&mut self, event_handler: &EventHandler, todo_file: &TodoFile) -> ProcessResult {
+		let result = ProcessResult::from(self.edit.handle_event(event_handler));
+		if self.edit.is_finished() {
+			let pattern = self.edit.get_content();
+			if pattern.trim().is_empty() {
+				self.path_filter = None;
+				self.state = ListState::Normal;
+			}
+			else {
+				// the changed files of all of the commits are needed to filter the commits
+				self.request_commit_details(todo_file, 0, todo_file.lines_iter().len());
+				self.path_filter = Some(PathFilter::new(pattern.as_str()));
+				self.filter_index = 0;
+				self.state = ListState::PathFilter;
+			}
+			self.update_ui_state();
+		}
+		result
+	}
+
+	fn handle_break_every_edit_input(
+		&mut self,
+		event_handler: &EventHandler,
+		rebase_todo: &mut TodoFile,
+	) -> ProcessResult {
+		let result = ProcessResult::from(self.number_input.handle_event(event_handler));
+		if !self.number_input.is_finished() {
+			return result;
+		}
+		self.state = ListState::Normal;
+		let commits = match self.number_input.get_value() {
+			Some(commits) => commits,
+			None => return result,
+		};
+		// the selected line is kept, counted without the breaks before it, which are moved
+		let selected_line_index = rebase_todo.get_selected_line_index();
+		let non_break_index = rebase_todo
+			.lines_iter()
+			.take(selected_line_index)
+			.filter(|line| *line.get_action() != Action::Break)
+			.count();
+		if rebase_todo.break_every(commits) {
+			if let Some((index, _)) = rebase_todo
+				.lines_iter()
+				.enumerate()
+				.filter(|&(_, line)| *line.get_action() != Action::Break)
+				.nth(non_break_index)
+			{
+				rebase_todo.set_selected_line_index(index);
+			}
+		}
+		result
+	}
+
+	fn handle_jump_to_line_edit_input(
+		&mut self,
+		event_handler: &EventHandler,
+		rebase_todo: &mut TodoFile,
+	) -> ProcessResult {
+		let result = ProcessResult::from(self.number_input.handle_event(event_handler));
+		if !self.number_input.is_finished() {
+			return result;
+		}
+		self.state = ListState::Normal;
+		if let Some(line_number) = self.number_input.get_value() {
+			rebase_todo.set_selected_line_index(line_number - 1);
+		}
+		result
+	}
+
+	fn handle_mark_edit_input(&mut self, event_handler: &EventHandler, todo_file: &TodoFile) -> ProcessResult {
+		let result = ProcessResult::from(self.edit.handle_event(event_handler));
+		if !self.edit.is_finished() {
+			return result;
+		}
+		let content = self.edit.get_content();
+		if content.trim().is_empty() {
+			self.state = ListState::Normal;
+			return result;
+		}
+		let predicate = match MarkPredicate::parse(content.as_str()) {
+			Some(predicate) => predicate,
+			None => {
+				let description = translate_format("Invalid lines to mark: {}", &[&content.trim()]);
+				self.show_edit_error(content.as_str(), description.as_str());
+				return result;
+			},
+		};
+		let _ = self.receive_loaded_details();
+		// the commits whose details have not been loaded are not matched by an author
+		let indexes: Vec<usize> = match predicate {
+			MarkPredicate::Action(action) => {
+				todo_file
+					.lines_iter()
+					.enumerate()
+					.filter(|&(_, line)| *line.get_action() == action)
+					.map(|(index, _)| index)
+					.collect()
+			},
+			MarkPredicate::Author(_) => {
+				todo_file
+					.lines_iter()
+					.enumerate()
+					.filter(|&(_, line)| {
+						line.has_reference()
+							&& self
+								.commit_details
+								.get(line.get_hash())
+								.and_then(Option::as_ref)
+								.map_or(false, |details| predicate.matches_author(details.author.as_str()))
+					})
+					.map(|(index, _)| index)
+					.collect()
+			},
+			MarkPredicate::PathFilter => {
+				self.get_path_filter_matches(todo_file)
+					.into_iter()
+					.map(|(index, _)| index)
+					.collect()
+			},
+		};
+		if indexes.is_empty() {
+			let description = translate_format("No lines match: {}", &[&content.trim()]);
+			self.show_edit_error(content.as_str(), description.as_str());
+			return result;
+		}
+		self.marks.set(indexes.as_slice(), todo_file.lines_iter().len());
+		self.state = ListState::Normal;
+		result
+	}
+
+	fn handle_sort_edit_input(&mut self, event_handler: &EventHandler, rebase_todo: &mut TodoFile) -> ProcessResult {
+		let result = ProcessResult::from(self.edit.handle_event(event_handler));
+		if !self.edit.is_finished() {
+			return result;
+		}
+		let content = self.edit.get_content();
+		if content.trim().is_empty() {
+			self.state = self.get_list_state();
+			return result;
+		}
+		let order = match SortOrder::parse(content.as_str()) {
+			Some(order) => order,
+			None => {
+				let description = translate_format("Invalid order: {}", &[&content.trim()]);
+				self.show_edit_error(content.as_str(), description.as_str());
+				return result;
+			},
+		};
+		let _ = self.receive_loaded_details();
+		let sorted = order.sort(rebase_todo, self.sort_indexes.as_slice(), &self.commit_details);
+		let _ = rebase_todo.reorder_lines(sorted.as_slice());
+		self.state = self.get_list_state();
+		result
+	}
+
+	// the mode of the list that an edit or a move of the lines returns to
+	const fn get_list_state(&self) -> ListState {
+		if self.visual_index_start.is_some() {
+			ListState::Visual
+		}
+		else {
+			ListState::Normal
+		}
+	}
+
+	fn show_edit_error(&mut self, content: &str, description: &str) {
+		self.edit.clear();
+		self.edit.set_content(content);
+		self.edit.set_description(description);
+	}
+
+	fn handle_group_name_edit_input(
+		&mut self,
+		event_handler: &EventHandler,
+		rebase_todo: &mut TodoFile,
+	) -> ProcessResult {
+		let result = ProcessResult::from(self.edit.handle_event(event_handler));
+		if self.edit.is_finished() {
+			if let Some((start, _)) = rebase_todo.get_group_range(rebase_todo.get_selected_line_index()) {
+				let _ = rebase_todo.set_group_name(start, Some(self.edit.get_content().as_str()));
+			}
+			self.state = ListState::Normal;
+		}
+		result
+	}
+
+	fn handle_filter_input(
+		&mut self,
+		event_handler: &EventHandler,
+		view_sender: &ViewSender,
+		todo_file: &mut TodoFile,
+	) -> ProcessResult {
+		let event = get_event(event_handler);
+		let matches = self.get_filter_matches(todo_file);
+		let last_index = matches.len().saturating_sub(1);
+		match event {
+			Event::Meta(meta_event) => {
+				match meta_event {
+					MetaEvent::MoveCursorUp => self.filter_index = self.filter_index.saturating_sub(1),
+					MetaEvent::MoveCursorDown => self.filter_index = min(self.filter_index + 1, last_index),
+					MetaEvent::MoveCursorPageUp => {
+						self.filter_index = self.filter_index.saturating_sub(self.height / 2);
+					},
+					MetaEvent::MoveCursorPageDown => {
+						self.filter_index = min(self.filter_index + self.height / 2, last_index);
+					},
+					MetaEvent::MoveCursorHome => self.filter_index = 0,
+					MetaEvent::MoveCursorEnd => self.filter_index = last_index,
+					MetaEvent::MoveCursorLeft => view_sender.scroll_left(),
+					MetaEvent::MoveCursorRight => view_sender.scroll_right(),
+					_ => self.state = ListState::Normal,
+				}
+			},
+			Event::Key(KeyEvent {
+				code: KeyCode::Enter, ..
+			}) => {
+				if let Some(&(index, _)) = matches.get(self.filter_index) {
+					todo_file.set_selected_line_index(index);
+				}
+				self.state = ListState::Normal;
+			},
+			Event::Key(_) => self.state = ListState::Normal,
+			Event::Resize(_, height) => self.height = height as usize,
+			_ => {},
+		}
+		ProcessResult::from(event)
+	}
+
+	// A merge line is edited in two steps, the labels or commits that are merged, and then the commit that the message
+	// of the merge is taken from, with each step validated before the next.
+	fn start_merge_edit(&mut self, line: &Line) {
+		self.merge_labels = line.get_labels().into_iter().map(String::from).collect();
+		self.state = ListState::MergeLabelsEdit;
+		self.edit.clear();
+		self.edit.set_content(self.merge_labels.join(" ").as_str());
+		self.edit.set_label(translate("Merge: "));
+		self.edit.set_description(
+			translate_format("The labels or commits to merge, separated by spaces, for: {}", &[
+				&line.to_text(),
+			])
+			.as_str(),
+		);
+	}
+
+	fn start_merge_commit_edit(&mut self, line: &Line) {
+		self.state = ListState::MergeCommitEdit;
+		self.edit.clear();
+		self.edit.set_content(
+			line.get_merge_message_commit()
+				.map_or(String::new(), |(option, commit)| format!("{option} {commit}"))
+				.as_str(),
+		);
+		self.edit.set_label(translate("Message: "));
+		self.edit.set_description(translate(
+			"-C <commit> to use the message of a commit, -c <commit> to also edit it, or nothing for the default \
+			 message",
+		));
+	}
+
+	fn handle_merge_edit_input(&mut self, event_handler: &EventHandler, rebase_todo: &mut TodoFile) -> ProcessResult {
+		let result = ProcessResult::from(self.edit.handle_event(event_handler));
+		if !self.edit.is_finished() {
+			return result;
+		}
+		let line = match rebase_todo.get_selected_line().cloned() {
+			Some(line) => line,
+			None => {
+				self.state = ListState::Normal;
+				return result;
+			},
+		};
+		let content = self.edit.get_content();
+		if self.state == ListState::MergeLabelsEdit {
+			self.merge_labels = content.split_whitespace().map(String::from).collect();
+			let labels: Vec<&str> = self.merge_labels.iter().map(String::as_str).collect();
+			match line.with_merge_arguments(line.get_merge_message_commit(), labels.as_slice()) {
+				Ok(_) => self.start_merge_commit_edit(&line),
+				Err(err) => self.show_merge_edit_error(content.as_str(), &err),
+			}
+		}
+		else {
+			let labels: Vec<&str> = self.merge_labels.iter().map(String::as_str).collect();
+			match parse_message_commit(content.as_str())
+				.and_then(|message_commit| line.with_merge_arguments(message_commit, labels.as_slice()))
+			{
+				Ok(edited_line) => {
+					let selected_index = rebase_todo.get_selected_line_index();
+					rebase_todo.update_range(
+						selected_index,
+						selected_index,
+						&EditContext::new().content(edited_line.get_content()),
+					);
+					self.visual_index_start = None;
+					self.state = ListState::Normal;
+				},
+				Err(err) => self.show_merge_edit_error(content.as_str(), &err),
+			}
+		}
+		result
+	}
+
+	// the exec line that tests the selected commit is inserted after it, followed by a break when configured, and
+	// selected, so the next commit can be selected to be tested
+	fn test_selected_commit(&self, rebase_todo: &mut TodoFile, result: ProcessResult) -> ProcessResult {
+		let selected_line = match rebase_todo.get_selected_line() {
+			Some(selected_line) => selected_line,
+			None => return result,
+		};
+		if !selected_line.has_reference() || matches!(*selected_line.get_action(), Action::Drop | Action::Merge) {
+			return result;
+		}
+		let command = match self.test_commit_command.as_deref() {
+			Some(command) => command,
+			None => {
+				return result.error(anyhow!(translate_format(
+					"No exec template named {} is configured to test a commit",
+					&[&self.test_commit_template]
+				)));
+			},
+		};
+		let mut lines = vec![Line::new_exec(expand_for_todo_file(command, rebase_todo).as_str())];
+		if self.test_commit_break {
+			lines.push(Line::new_break());
+		}
+		let selected_line_index = rebase_todo.get_selected_line_index();
+		rebase_todo.add_lines(selected_line_index + 1, &lines);
+		rebase_todo.set_selected_line_index(selected_line_index + lines.len());
+		result
+	}
+
+	// the input is edited again, with the error
+	fn show_merge_edit_error(&mut self, content: &str, err: &anyhow::Error) {
+		self.edit.clear();
+		self.edit.set_content(content);
+		self.edit
+			.set_description(translate_format("Invalid merge: {}", &[&format!("{err:#}")]).as_str());
 	}
 
 	fn handle_edit_mode_input(&mut self, event_handler: &EventHandler, rebase_todo: &mut TodoFile) -> ProcessResult {
@@ -0,0 +1,211 @@
+use std::{
+	collections::{HashMap, HashSet},
+	ops::Range,
+	path::PathBuf,
+};
+
+use todo_file::{Action, Line, TodoFile};
+
+use super::commit_details::CommitDetails;
+
+// the lines of the commits that change files, a merge line only refers to the commit of its message
+fn is_changing_commit(line: &Line) -> bool {
+	line.has_reference() && *line.get_action() != Action::Merge
+}
+
+/// A preview of a range of lines of the todo list moved to a new index, before the move is made, with the lines that
+/// the range is moved past, and the commits of those lines that change a file that a moved commit also changes, and
+/// so can conflict when the lines are moved.
+#[derive(Debug)]
+pub(super) struct MovePreview {
+	conflicts: Vec<usize>,
+	length: usize,
+	passed: Range<usize>,
+	todo_file: TodoFile,
+}
+
+impl MovePreview {
+	/// Create the preview of the lines of a range moved, so that the first line of the range is at a new index. The
+	/// conflicts are found from the changed files of the commits that have been loaded.
+	pub(super) fn new(
+		todo_file: &TodoFile,
+		start: usize,
+		end: usize,
+		new_start: usize,
+		commit_details: &HashMap<String, Option<CommitDetails>>,
+	) -> Self {
+		let length = end - start + 1;
+		let mut preview = todo_file.with_lines(todo_file.get_lines_owned());
+		let _ = preview.move_range(start, end, new_start);
+		preview.set_selected_line_index(new_start);
+		// in the preview, the lines that the range is moved past are before the range when it is moved down, and after
+		// the range when it is moved up
+		let passed = if new_start > start {
+			start..new_start
+		}
+		else {
+			new_start + length..start + length
+		};
+		let get_paths = |line: &Line| {
+			commit_details
+				.get(line.get_hash())
+				.and_then(Option::as_ref)
+				.map(|details| details.paths.as_slice())
+				.unwrap_or_default()
+		};
+		let moved_paths: HashSet<&PathBuf> = preview
+			.lines_iter()
+			.skip(new_start)
+			.take(length)
+			.filter(|line| is_changing_commit(line))
+			.flat_map(get_paths)
+			.collect();
+		let conflicts = preview
+			.lines_iter()
+			.enumerate()
+			.skip(passed.start)
+			.take(passed.len())
+			.filter(|&(_, line)| is_changing_commit(line) && get_paths(line).iter().any(|path| moved_paths.contains(path)))
+			.map(|(index, _)| index)
+			.collect();
+		Self {
+			conflicts,
+			length,
+			passed,
+			todo_file: preview,
+		}
+	}
+
+	/// Get the todo file with the lines moved.
+	pub(super) const fn todo_file(&self) -> &TodoFile {
+		&self.todo_file
+	}
+
+	/// Get the index of the last moved line, in the preview.
+	pub(super) fn end_index(&self) -> usize {
+		self.todo_file.get_selected_line_index() + self.length - 1
+	}
+
+	pub(super) const fn length(&self) -> usize {
+		self.length
+	}
+
+	pub(super) fn passed_lines(&self) -> usize {
+		self.passed.len()
+	}
+
+	/// Get the index of the line, in the preview, that the original position of the moved lines is shown before,
+	/// which is after the lines that the range is moved past when it is moved up, and before the lines when it is
+	/// moved down. There is no original position to show when the lines are not moved.
+	pub(super) fn ghost_index(&self) -> Option<usize> {
+		if self.passed.is_empty() {
+			None
+		}
+		else if self.passed.start > self.todo_file.get_selected_line_index() {
+			Some(self.passed.end)
+		}
+		else {
+			Some(self.passed.start)
+		}
+	}
+
+	/// Whether the commit of a line, of the preview, can conflict with the moved commits.
+	pub(super) fn is_conflict(&self, index: usize) -> bool {
+		self.conflicts.contains(&index)
+	}
+
+	pub(super) fn conflicts(&self) -> usize {
+		self.conflicts.len()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use chrono::Local;
+
+	use super::*;
+
+	fn create_todo_file(lines: &[&str]) -> TodoFile {
+		let mut todo_file = TodoFile::new("git-rebase-todo", 1, "#");
+		todo_file.set_lines(lines.iter().map(|line| Line::new(line).unwrap()).collect());
+		todo_file
+	}
+
+	fn details(paths: &[&str]) -> Option<CommitDetails> {
+		Some(CommitDetails {
+			author: String::from("name"),
+			cherry_picked_from: None,
+			committed_date: Local::now(),
+			date: Local::now(),
+			deletions: 0,
+			files_changed: paths.len(),
+			insertions: 0,
+			paths: paths.iter().map(PathBuf::from).collect(),
+			summary: String::new(),
+		})
+	}
+
+	fn todo_lines(todo_file: &TodoFile) -> Vec<String> {
+		todo_file.lines_iter().map(Line::to_text).collect()
+	}
+
+	#[test]
+	fn move_down() {
+		let todo_file = create_todo_file(&["pick aaa c1", "pick bbb c2", "pick ccc c3", "pick ddd c4"]);
+		let preview = MovePreview::new(&todo_file, 0, 1, 2, &HashMap::new());
+		assert_eq!(todo_lines(preview.todo_file()), vec![
+			"pick ccc c3",
+			"pick ddd c4",
+			"pick aaa c1",
+			"pick bbb c2"
+		]);
+		assert_eq!(preview.todo_file().get_selected_line_index(), 2);
+		assert_eq!(preview.end_index(), 3);
+		assert_eq!(preview.passed_lines(), 2);
+		assert_eq!(preview.ghost_index(), Some(0));
+		assert_eq!(todo_lines(&todo_file), vec![
+			"pick aaa c1",
+			"pick bbb c2",
+			"pick ccc c3",
+			"pick ddd c4"
+		]);
+	}
+
+	#[test]
+	fn move_up() {
+		let todo_file = create_todo_file(&["pick aaa c1", "pick bbb c2", "pick ccc c3", "pick ddd c4"]);
+		let preview = MovePreview::new(&todo_file, 3, 3, 1, &HashMap::new());
+		assert_eq!(todo_lines(preview.todo_file()), vec![
+			"pick aaa c1",
+			"pick ddd c4",
+			"pick bbb c2",
+			"pick ccc c3"
+		]);
+		assert_eq!(preview.passed_lines(), 2);
+		assert_eq!(preview.ghost_index(), Some(4));
+	}
+
+	#[test]
+	fn not_moved() {
+		let todo_file = create_todo_file(&["pick aaa c1", "pick bbb c2"]);
+		let preview = MovePreview::new(&todo_file, 1, 1, 1, &HashMap::new());
+		assert_eq!(preview.passed_lines(), 0);
+		assert_eq!(preview.ghost_index(), None);
+	}
+
+	#[test]
+	fn conflicts() {
+		let todo_file = create_todo_file(&["pick aaa c1", "pick bbb c2", "exec make", "pick ccc c3", "pick ddd c4"]);
+		let commit_details = HashMap::from([
+			(String::from("aaa"), details(&["src/main.rs"])),
+			(String::from("bbb"), details(&["src/main.rs", "readme.md"])),
+			(String::from("ccc"), details(&["readme.md"])),
+			(String::from("ddd"), details(&["src/lib.rs"])),
+		]);
+		let preview = MovePreview::new(&todo_file, 0, 0, 4, &commit_details);
+		assert_eq!(preview.conflicts(), 1);
+		assert!(preview.is_conflict(0));
+		assert!(!preview.is_conflict(2));
+		assert!(!preview.is_conflict(3));
+	}
+}
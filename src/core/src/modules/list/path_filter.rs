@@ -0,0 +1,90 @@
+use std::path::Path;
+
+// Match the name of a file or directory to a segment of a glob, where `*` matches any characters, and `?` matches
+// one character.
+fn matches_name(pattern: &[char], name: &[char]) -> bool {
+	match pattern.split_first() {
+		None => name.is_empty(),
+		Some((&'*', rest)) => (0..=name.len()).any(|skip| matches_name(rest, &name[skip..])),
+		Some((&'?', rest)) => !name.is_empty() && matches_name(rest, &name[1..]),
+		Some((character, rest)) => name.first() == Some(character) && matches_name(rest, &name[1..]),
+	}
+}
+
+// Match the names of a path to the segments of a glob, where `**` matches any number of directories.
+fn matches_names(pattern: &[Vec<char>], names: &[Vec<char>]) -> bool {
+	match pattern.split_first() {
+		None => names.is_empty(),
+		Some((segment, rest)) if segment.as_slice() == ['*', '*'] => {
+			(0..=names.len()).any(|skip| matches_names(rest, &names[skip..]))
+		},
+		Some((segment, rest)) => {
+			names
+				.first()
+				.map_or(false, |name| matches_name(segment.as_slice(), name.as_slice()))
+				&& matches_names(rest, &names[1..])
+		},
+	}
+}
+
+fn split_path(path: &str) -> Vec<Vec<char>> {
+	path.split('/')
+		.filter(|name| !name.is_empty() && *name != ".")
+		.map(|name| name.chars().collect())
+		.collect()
+}
+
+/// A filter of the commits of the todo list, to the commits that change a file that matches a path, or a glob.
+#[derive(Debug)]
+pub(super) struct PathFilter {
+	pattern: String,
+	segments: Vec<Vec<char>>,
+}
+
+impl PathFilter {
+	pub(super) fn new(pattern: &str) -> Self {
+		let pattern = pattern.trim();
+		Self {
+			pattern: String::from(pattern),
+			segments: split_path(pattern),
+		}
+	}
+
+	pub(super) fn pattern(&self) -> &str {
+		self.pattern.as_str()
+	}
+
+	/// Whether the path matches the pattern, or is in a directory that matches the pattern.
+	pub(super) fn matches(&self, path: &Path) -> bool {
+		let names = split_path(path.to_string_lossy().as_ref());
+		!self.segments.is_empty() && (1..=names.len()).any(|length| matches_names(&self.segments, &names[..length]))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use rstest::rstest;
+
+	use super::*;
+
+	#[rstest]
+	#[case::file("src/main.rs", "src/main.rs", true)]
+	#[case::directory("src/view", "src/view/lib.rs", true)]
+	#[case::directory_with_slash("src/view/", "src/view/render/mod.rs", true)]
+	#[case::relative_directory("./src", "src/main.rs", true)]
+	#[case::other_directory("src/view", "src/viewer/lib.rs", false)]
+	#[case::parent_directory("src/view", "src/lib.rs", false)]
+	#[case::star("src/*.rs", "src/main.rs", true)]
+	#[case::star_only_in_directory("src/*.rs", "src/view/lib.rs", false)]
+	#[case::star_directory("src/*/lib.rs", "src/view/lib.rs", true)]
+	#[case::star_directory_prefix("src/v*", "src/view/lib.rs", true)]
+	#[case::question_mark("readme.m?", "readme.md", true)]
+	#[case::question_mark_one_character("readme.m?", "readme.mdx", false)]
+	#[case::double_star("**/lib.rs", "src/view/lib.rs", true)]
+	#[case::double_star_root("**/lib.rs", "lib.rs", true)]
+	#[case::double_star_middle("src/**/mod.rs", "src/view/render/mod.rs", true)]
+	#[case::empty("", "src/main.rs", false)]
+	fn matches(#[case] pattern: &str, #[case] path: &str, #[case] expected: bool) {
+		assert_eq!(PathFilter::new(pattern).matches(Path::new(path)), expected);
+	}
+}
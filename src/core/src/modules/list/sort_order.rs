@@ -0,0 +1,131 @@
+use std::{cmp::Ordering, collections::HashMap};
+
+use todo_file::{Action, Line, TodoFile};
+
+use super::commit_details::CommitDetails;
+
+/// The order that the commits of the selected or marked lines are sorted in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum SortOrder {
+	AuthorDate,
+	CommitterDate,
+	AuthorName,
+	Subject,
+}
+
+impl SortOrder {
+	/// Parse the order, which is `author-date`, `committer-date`, `author` or `subject`.
+	pub(super) fn parse(order: &str) -> Option<Self> {
+		match order.trim() {
+			"author-date" => Some(Self::AuthorDate),
+			"committer-date" => Some(Self::CommitterDate),
+			"author" => Some(Self::AuthorName),
+			"subject" => Some(Self::Subject),
+			_ => None,
+		}
+	}
+
+	/// Get the indexes of the commits of the lines with the indexes, sorted in the order. The other lines, such as the
+	/// exec and label lines, are not sorted and keep their positions. The commits that are equal keep their order, and
+	/// the commits whose details have not been loaded are sorted after the other commits.
+	pub(super) fn sort(
+		self,
+		todo_file: &TodoFile,
+		indexes: &[usize],
+		commit_details: &HashMap<String, Option<CommitDetails>>,
+	) -> Vec<usize> {
+		let get_details = |line: &Line| commit_details.get(line.get_hash()).and_then(Option::as_ref);
+		let mut commits: Vec<(usize, &Line)> = todo_file
+			.lines_iter()
+			.enumerate()
+			.filter(|&(index, line)| {
+				indexes.contains(&index) && line.has_reference() && *line.get_action() != Action::Merge
+			})
+			.collect();
+		commits.sort_by(|&(_, a), &(_, b)| {
+			// the subject of a commit is the content of its line, so the subjects are sorted without the details
+			if self == Self::Subject {
+				return a.get_content().to_lowercase().cmp(&b.get_content().to_lowercase());
+			}
+			match (get_details(a), get_details(b)) {
+				(Some(a_details), Some(b_details)) => {
+					match self {
+						Self::AuthorDate => a_details.date.cmp(&b_details.date),
+						Self::CommitterDate => a_details.committed_date.cmp(&b_details.committed_date),
+						Self::AuthorName | Self::Subject => {
+							a_details.author.to_lowercase().cmp(&b_details.author.to_lowercase())
+						},
+					}
+				},
+				(Some(_), None) => Ordering::Less,
+				(None, Some(_)) => Ordering::Greater,
+				(None, None) => Ordering::Equal,
+			}
+		});
+		commits.into_iter().map(|(index, _)| index).collect()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::path::PathBuf;
+
+	use chrono::{Local, TimeZone};
+	use rstest::rstest;
+
+	use super::*;
+
+	fn create_todo_file(lines: &[&str]) -> TodoFile {
+		let mut todo_file = TodoFile::new("git-rebase-todo", 1, "#");
+		todo_file.set_lines(lines.iter().map(|line| Line::new(line).unwrap()).collect());
+		todo_file
+	}
+
+	fn details(author: &str, date: i64, committed_date: i64) -> Option<CommitDetails> {
+		Some(CommitDetails {
+			author: String::from(author),
+			cherry_picked_from: None,
+			committed_date: Local.timestamp(committed_date, 0),
+			date: Local.timestamp(date, 0),
+			deletions: 0,
+			files_changed: 0,
+			insertions: 0,
+			paths: Vec::<PathBuf>::new(),
+			summary: String::new(),
+		})
+	}
+
+	#[rstest]
+	#[case::author_date("author-date", Some(SortOrder::AuthorDate))]
+	#[case::committer_date(" committer-date ", Some(SortOrder::CommitterDate))]
+	#[case::author("author", Some(SortOrder::AuthorName))]
+	#[case::subject("subject", Some(SortOrder::Subject))]
+	#[case::invalid("date", None)]
+	fn parse(#[case] order: &str, #[case] expected: Option<SortOrder>) {
+		assert_eq!(SortOrder::parse(order), expected);
+	}
+
+	#[rstest]
+	#[case::author_date(SortOrder::AuthorDate, vec![3, 0, 2])]
+	#[case::committer_date(SortOrder::CommitterDate, vec![0, 2, 3])]
+	#[case::author(SortOrder::AuthorName, vec![2, 0, 3])]
+	#[case::subject(SortOrder::Subject, vec![3, 2, 0])]
+	fn sort(#[case] order: SortOrder, #[case] expected: Vec<usize>) {
+		let todo_file = create_todo_file(&["pick aaa c3", "exec make", "pick bbb B2", "pick ccc a1", "pick ddd c4"]);
+		let commit_details = HashMap::from([
+			(String::from("aaa"), details("Bob <bob@example.com>", 20, 40)),
+			(String::from("bbb"), details("alice <alice@example.com>", 30, 50)),
+			(String::from("ccc"), details("Carol <carol@example.com>", 10, 60)),
+		]);
+		assert_eq!(order.sort(&todo_file, &[0, 1, 2, 3], &commit_details), expected);
+	}
+
+	#[test]
+	fn sort_details_not_loaded() {
+		let todo_file = create_todo_file(&["pick aaa c1", "pick bbb c2", "pick ccc c3"]);
+		let commit_details = HashMap::from([(String::from("ccc"), details("name", 10, 10))]);
+		assert_eq!(SortOrder::AuthorDate.sort(&todo_file, &[0, 1, 2], &commit_details), vec![
+			2, 0, 1
+		]);
+	}
+}
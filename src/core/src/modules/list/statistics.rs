@@ -0,0 +1,217 @@
+use std::{
+	collections::{HashMap, HashSet},
+	path::{Path, PathBuf},
+};
+
+use display::DisplayColor;
+use input::{Event, EventHandler, InputOptions};
+use lazy_static::lazy_static;
+use todo_file::{Action, Line, TodoFile};
+use view::{handle_view_data_scroll, LineSegment, ViewData, ViewLine, ViewSender};
+
+use super::commit_details::CommitDetails;
+use crate::i18n::{translate, translate_format};
+
+lazy_static! {
+	static ref INPUT_OPTIONS: InputOptions = InputOptions::new().movement(true);
+}
+
+// the actions that create a commit, in the order that the number of lines of each action is shown
+const COMMIT_ACTIONS: [Action; 7] = [
+	Action::Pick,
+	Action::Reword,
+	Action::Edit,
+	Action::Squash,
+	Action::Fixup,
+	Action::Drop,
+	Action::Merge,
+];
+
+// the files at the root of the repository are counted under this directory
+const ROOT_DIRECTORY: &str = ".";
+
+// the commits that are applied with their changes, a merge line only refers to the commit of its message
+fn is_applied_commit(line: &Line) -> bool {
+	matches!(
+		*line.get_action(),
+		Action::Pick | Action::Reword | Action::Edit | Action::Squash | Action::Fixup
+	)
+}
+
+fn top_level_directory(path: &Path) -> String {
+	let mut components = path.components();
+	match (components.next(), components.next()) {
+		(Some(directory), Some(_)) => directory.as_os_str().to_string_lossy().into_owned(),
+		_ => String::from(ROOT_DIRECTORY),
+	}
+}
+
+fn count_lines(todo_file: &TodoFile, action: Action) -> usize {
+	todo_file
+		.lines_iter()
+		.filter(|line| *line.get_action() == action)
+		.count()
+}
+
+// The lines of the changed files, estimated from the paths of the commits that have been loaded. A commit that
+// changes a file that an earlier commit also changes depends on that commit, and can conflict when the commits are
+// reordered, or the earlier commit is dropped.
+fn get_changed_files_lines(
+	todo_file: &TodoFile,
+	commit_details: &HashMap<String, Option<CommitDetails>>,
+) -> Vec<ViewLine> {
+	let mut lines = vec![];
+	let mut loading = 0;
+	let mut possible_conflicts = 0;
+	let mut changed_paths: HashSet<&PathBuf> = HashSet::new();
+	let mut directories: Vec<(String, usize)> = vec![];
+	for line in todo_file.lines_iter().filter(|line| is_applied_commit(line)) {
+		let loaded_details = match commit_details.get(line.get_hash()) {
+			Some(loaded_details) => loaded_details,
+			None => {
+				loading += 1;
+				continue;
+			},
+		};
+		// the details of a commit that is not in the repository are never available
+		let details = match loaded_details.as_ref() {
+			Some(details) => details,
+			None => continue,
+		};
+		if details.paths.iter().any(|path| changed_paths.contains(path)) {
+			possible_conflicts += 1;
+		}
+		changed_paths.extend(details.paths.iter());
+		let mut commit_directories: Vec<String> =
+			details.paths.iter().map(|path| top_level_directory(path)).collect();
+		commit_directories.sort();
+		commit_directories.dedup();
+		for directory in commit_directories {
+			match directories.iter_mut().find(|&&mut (ref name, _)| *name == directory) {
+				Some(&mut (_, ref mut count)) => *count += 1,
+				None => directories.push((directory, 1)),
+			}
+		}
+	}
+
+	if loading > 0 {
+		lines.push(ViewLine::from(LineSegment::new_with_color_and_style(
+			translate_format("Loading the changed files of {} commits", &[&loading]).as_str(),
+			DisplayColor::Normal,
+			true,
+			false,
+			false,
+		)));
+	}
+	lines.push(ViewLine::from(translate_format("Possible conflicts: {}", &[&possible_conflicts]).as_str()));
+	lines.push(ViewLine::from(LineSegment::new_with_color_and_style(
+		translate("  Commits that change a file that an earlier commit also changes"),
+		DisplayColor::Normal,
+		true,
+		false,
+		false,
+	)));
+	lines.push(ViewLine::from(translate("Commits per changed top-level directory:")));
+	// the directories changed by the most commits are shown first
+	directories.sort_by(|&(ref a_name, a_count), &(ref b_name, b_count)| {
+		b_count.cmp(&a_count).then(a_name.cmp(b_name))
+	});
+	for (directory, count) in directories {
+		lines.push(ViewLine::from(vec![
+			LineSegment::new_with_color(format!("  {count:>4} ").as_str(), DisplayColor::IndicatorColor),
+			LineSegment::new(directory.as_str()),
+		]));
+	}
+	lines
+}
+
+fn get_statistics_lines(
+	todo_file: &TodoFile,
+	commit_details: Option<&HashMap<String, Option<CommitDetails>>>,
+) -> Vec<ViewLine> {
+	let commits: usize = COMMIT_ACTIONS.iter().map(|&action| count_lines(todo_file, action)).sum();
+	let mut lines = vec![ViewLine::from(translate_format("Commits: {}", &[&commits]).as_str())];
+	for action in COMMIT_ACTIONS {
+		let count = count_lines(todo_file, action);
+		if count > 0 {
+			lines.push(ViewLine::from(vec![
+				LineSegment::new_with_color(format!("  {count:>4} ").as_str(), DisplayColor::IndicatorColor),
+				LineSegment::new(action.as_string().as_str()),
+			]));
+		}
+	}
+	lines.push(ViewLine::from(
+		translate_format("Exec lines: {}", &[&count_lines(todo_file, Action::Exec)]).as_str(),
+	));
+	lines.push(ViewLine::from(
+		translate_format("Break lines: {}", &[&count_lines(todo_file, Action::Break)]).as_str(),
+	));
+	match commit_details {
+		Some(commit_details) => lines.extend(get_changed_files_lines(todo_file, commit_details)),
+		None => {
+			lines.push(ViewLine::from(LineSegment::new_with_color_and_style(
+				translate("The changed files of the commits are not available"),
+				DisplayColor::Normal,
+				true,
+				false,
+				false,
+			)));
+		},
+	}
+	lines
+}
+
+/// The statistics of the todo list, shown instead of the list until a key is pressed.
+pub(super) struct Statistics {
+	active: bool,
+	view_data: ViewData,
+}
+
+impl Statistics {
+	pub(super) fn new() -> Self {
+		Self {
+			active: false,
+			view_data: ViewData::new(|updater| updater.set_show_title(true)),
+		}
+	}
+
+	/// Get the view of the statistics, which are built again as the details of the commits are loaded.
+	pub(super) fn get_view_data(
+		&mut self,
+		todo_file: &TodoFile,
+		commit_details: Option<&HashMap<String, Option<CommitDetails>>>,
+	) -> &ViewData {
+		let lines = get_statistics_lines(todo_file, commit_details);
+		self.view_data.update_view_data(|updater| {
+			updater.clear();
+			for line in lines {
+				updater.push_line(line);
+			}
+			updater.push_trailing_line(ViewLine::new_pinned(vec![LineSegment::new_with_color(
+				translate("Press any key to close"),
+				DisplayColor::IndicatorColor,
+			)]));
+		});
+		&self.view_data
+	}
+
+	pub(super) fn handle_event(&mut self, event_handler: &EventHandler, view_sender: &ViewSender) -> Event {
+		let event = event_handler.read_event(&INPUT_OPTIONS, |event, _| event);
+
+		if handle_view_data_scroll(event, view_sender).is_none() {
+			if let Event::Key(_) = event {
+				self.active = false;
+			}
+		}
+
+		event
+	}
+
+	pub(super) fn set_active(&mut self) {
+		self.active = true;
+	}
+
+	pub(super) const fn is_active(&self) -> bool {
+		self.active
+	}
+}
@@ -1,8 +1,27 @@
+use std::{path::Path, thread::sleep, time::Duration};
+
 use ::input::{KeyCode, KeyModifiers, MouseEvent, MouseEventKind};
-use view::assert_rendered_output;
+use git::{
+	testutil::{create_commit, head_id, with_temp_repository, CreateCommitOptions, RangeTopologyBuilder, RepoBuilder},
+	CommitTopology,
+};
+use rstest::rstest;
+use view::{assert_rendered_output, assert_snapshot, render_line};
+
+use config::ExecTemplate;
 
 use super::*;
-use crate::{assert_process_result, testutil::module_test};
+use crate::{
+	assert_process_result,
+	event_bus::EventBus,
+	tasks::TaskManager,
+	testutil::module_test,
+	timers::TimerRequest,
+};
+
+fn spawn_details_loader(todo_file_path: &Path) -> DetailsLoader {
+	DetailsLoader::spawn(todo_file_path, EventBus::new().sender(), &TaskManager::new())
+}
 
 #[test]
 fn render_empty_list() {
@@ -43,23 +62,70 @@ fn render_full() {
 				view_data,
 				"{TITLE}{HELP}",
 				"{BODY}",
-				"{Selected}{Normal} > {ActionPick}pick   {Normal}aaaaaaaa {Normal}comment 1{Normal}{Pad( )}",
-				"{Normal}   {ActionDrop}drop   {Normal}bbbbbbbb {Normal}comment 2",
-				"{Normal}   {ActionFixup}fixup  {Normal}cccccccc {Normal}comment 3",
-				"{Normal}   {ActionExec}exec   {Normal}echo 'foo'",
-				"{Normal}   {ActionPick}pick   {Normal}dddddddd {Normal}comment 4",
-				"{Normal}   {ActionReword}reword {Normal}eeeeeeee {Normal}comment 5",
-				"{Normal}   {ActionBreak}break",
-				"{Normal}   {ActionSquash}squash {Normal}ffffffff {Normal}comment 6",
-				"{Normal}   {ActionEdit}edit   {Normal}11111111 {Normal}comment 7",
-				"{Normal}   {ActionLabel}label  {Normal}ref",
-				"{Normal}   {ActionReset}reset  {Normal}ref",
-				"{Normal}   {ActionMerge}merge  {Normal}command"
+				"{Selected}{Normal} > {ActionPick}pick   {Normal}aaaaaaaa {Normal,Dimmed}* {Normal}comment 1{Normal}{Pad( )}",
+				"{Normal}   {ActionDrop}drop   {Normal}bbbbbbbb {Normal,Dimmed}* {Normal}comment 2",
+				"{Normal}   {ActionFixup}fixup  {Normal}cccccccc {Normal,Dimmed}* {Normal}comment 3",
+				"{Normal}   {ActionExec}exec   {Normal,Dimmed}| {Normal}echo 'foo'",
+				"{Normal}   {ActionPick}pick   {Normal}dddddddd {Normal,Dimmed}* {Normal}comment 4",
+				"{Normal}   {ActionReword}reword {Normal}eeeeeeee {Normal,Dimmed}* {Normal}comment 5",
+				"{Normal}   {ActionBreak}break  {Normal,Dimmed}| {ActionBreak,Dimmed}{Pad(─)}",
+				"{Normal}   {ActionSquash}squash {Normal}ffffffff {Normal,Dimmed}* {Normal}comment 6",
+				"{Normal}   {ActionEdit}edit   {Normal}11111111 {Normal,Dimmed}* {Normal}comment 7",
+				"{Normal}   {ActionLabel}label  {Normal,Dimmed}| {Normal}ref{ActionLabel,Dimmed}{Pad(─)}",
+				"{Normal}   {ActionReset}reset  {Normal,Dimmed}. {Normal}ref",
+				"{Normal}   {ActionMerge}merge  {Normal,Dimmed}* {Normal}command{IndicatorColor}  The label command is not created by any line"
 			);
 		},
 	);
 }
 
+#[test]
+fn snapshot_full() {
+	module_test(
+		&[
+			"pick aaaaaaaa comment 1",
+			"drop bbbbbbbb comment 2",
+			"fixup cccccccc comment 3",
+			"exec echo 'foo'",
+			"pick dddddddd comment 4",
+			"reword eeeeeeee comment 5",
+			"break",
+			"squash ffffffff comment 6",
+			"edit 11111111 comment 7",
+			"label ref",
+			"reset ref",
+			"merge command",
+		],
+		&[],
+		|test_context| {
+			let mut module = List::new(&Config::new());
+			assert_snapshot!("list_full", test_context.build_view_data(&mut module));
+		},
+	);
+}
+
+#[test]
+fn snapshot_scrolled() {
+	module_test(
+		&[
+			"pick aaaaaaaa comment 1",
+			"pick bbbbbbbb comment 2",
+			"pick cccccccc comment 3",
+			"pick dddddddd comment 4",
+			"pick eeeeeeee comment 5",
+			"pick ffffffff comment 6",
+			"pick 11111111 comment 7",
+			"pick 22222222 comment 8",
+		],
+		&[Event::from(MetaEvent::MoveCursorEnd)],
+		|mut test_context| {
+			let mut module = List::new(&Config::new());
+			let _ = test_context.handle_all_events(&mut module);
+			assert_snapshot!("list_scrolled", test_context.build_view_data(&mut module), 40, 5);
+		},
+	);
+}
+
 #[test]
 fn render_compact() {
 	module_test(
@@ -92,313 +158,583 @@ fn render_compact() {
 				"{Normal} {ActionExec}x {Normal}echo 'foo'",
 				"{Normal} {ActionPick}p {Normal}ddd {Normal}comment 4",
 				"{Normal} {ActionReword}r {Normal}eee {Normal}comment 5",
-				"{Normal} {ActionBreak}b",
+				"{Normal} {ActionBreak}b {ActionBreak,Dimmed}{Pad(─)}",
 				"{Normal} {ActionSquash}s {Normal}fff {Normal}comment 6",
 				"{Normal} {ActionEdit}e {Normal}111 {Normal}comment 7",
-				"{Normal} {ActionLabel}l {Normal}ref",
+				"{Normal} {ActionLabel}l {Normal}ref{ActionLabel,Dimmed}{Pad(─)}",
 				"{Normal} {ActionReset}t {Normal}ref",
-				"{Normal} {ActionMerge}m {Normal}command"
+				"{Normal} {ActionMerge}m {Normal}command{IndicatorColor}  The label command is not created by any line"
 			);
 		},
 	);
 }
 
 #[test]
-fn move_cursor_down_1() {
+fn render_minimal() {
 	module_test(
-		&["pick aaa c1", "pick aaa c2", "pick aaa c3"],
-		&[Event::from(MetaEvent::MoveCursorDown)],
+		&["pick aaaaaaaa comment 1", "exec echo 'foo'", "break"],
+		&[],
 		|mut test_context| {
+			test_context.render_context.update(15, 300);
 			let mut module = List::new(&Config::new());
-			let _ = test_context.handle_all_events(&mut module);
 			let view_data = test_context.build_view_data(&mut module);
 			assert_rendered_output!(
 				view_data,
 				"{TITLE}{HELP}",
 				"{BODY}",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c1",
-				"{Selected}{Normal} > {ActionPick}pick   {Normal}aaa      {Normal}c2{Normal}{Pad( )}",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c3"
+				"{Selected}{Normal}>{ActionPick}p {Normal}comment 1{Normal}{Pad( )}",
+				"{Normal} {ActionExec}x {Normal}echo 'foo'",
+				"{Normal} {ActionBreak}b {ActionBreak,Dimmed}{Pad(─)}"
 			);
 		},
 	);
 }
 
 #[test]
-fn move_cursor_down_view_end() {
-	module_test(
-		&["pick aaa c1", "pick aaa c2", "pick aaa c3"],
-		&[Event::from(MetaEvent::MoveCursorDown); 2],
-		|mut test_context| {
+fn render_wide_with_commit_details() {
+	with_temp_repository(|repository| {
+		// midday on January 1st 2021, so that the date is the same in all time zones
+		create_commit(&repository, Some(CreateCommitOptions::new().author_time(1_609_502_400)))?;
+		let hash = head_id(&repository, "main").to_string();
+		let line = format!("pick {} title", hash);
+		module_test(
+			&[line.as_str(), "exec make", "drop ffffffff gone"],
+			&[],
+			|test_context| {
+				let mut module = List::new(&Config::new());
+				module.set_details_loader(spawn_details_loader(
+					repository
+						.git_directory()
+						.join("rebase-merge/git-rebase-todo")
+						.as_path(),
+				));
+				let _ = test_context.build_view_data(&mut module);
+				module.wait_for_loaded_details();
+				let view_data = test_context.build_view_data(&mut module);
+				assert_rendered_output!(
+					view_data,
+					"{TITLE}{HELP}",
+					"{BODY}",
+					format!(
+						"{{Selected}}{{Normal}} > {{ActionPick}}pick   {{Normal}}{} {{IndicatorColor}}2021-01-01 \
+						 {{Normal}}  0f {{DiffAddColor}}   +0 {{DiffRemoveColor}}   -0 \
+						 {{Normal}}title{{Normal}}{{Pad( )}}",
+						&hash[0..7]
+					),
+					"{Normal}   {ActionExec}exec   {Normal}make",
+					"{Normal}   {ActionDrop}drop   {Normal}fffffff {Normal}           {Normal}     {Normal}      \
+					 {Normal}      {Normal}gone",
+					"{TRAILING}",
+					"{Normal,Dimmed}{Pad(-)}",
+					format!(
+						"{{IndicatorColor}}{}{{Normal}} {{Normal}}Author <author@example.com>",
+						hash
+					),
+					render_line!(AnyLine),
+					"{Normal}title",
+					"{Normal}0 files changed, {DiffAddColor}0 insertions(+){Normal}, {DiffRemoveColor}0 deletions(-)"
+				);
+			},
+		);
+		Ok(())
+	});
+}
+
+#[test]
+fn render_wide_with_cherry_picked_commit() {
+	with_temp_repository(|repository| {
+		let source = head_id(&repository, "main").to_string();
+		let message = format!("title\n\n(cherry picked from commit {})", source);
+		create_commit(
+			&repository,
+			Some(CreateCommitOptions::new().author_time(1_609_502_400).message(message.as_str())),
+		)?;
+		let hash = head_id(&repository, "main").to_string();
+		let line = format!("pick {} title", hash);
+		let source_line = format!("pick {} source", &source[0..7]);
+		module_test(&[line.as_str(), source_line.as_str()], &[], |test_context| {
 			let mut module = List::new(&Config::new());
-			let _ = test_context.handle_all_events(&mut module);
+			module.set_details_loader(spawn_details_loader(
+				repository
+					.git_directory()
+					.join("rebase-merge/git-rebase-todo")
+					.as_path(),
+			));
+			let _ = test_context.build_view_data(&mut module);
+			module.wait_for_loaded_details();
 			let view_data = test_context.build_view_data(&mut module);
 			assert_rendered_output!(
 				view_data,
 				"{TITLE}{HELP}",
 				"{BODY}",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c1",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c2",
-				"{Selected}{Normal} > {ActionPick}pick   {Normal}aaa      {Normal}c3{Normal}{Pad( )}"
+				format!(
+					"{{Selected}}{{Normal}} > {{ActionPick}}pick   {{Normal}}{} {{IndicatorColor}}2021-01-01 \
+					 {{Normal}}  0f {{DiffAddColor}}   +0 {{DiffRemoveColor}}   -0 {{Normal}}title\
+					 {{Normal,Dimmed}}  (cherry picked from {}){{IndicatorColor}}  The commit that this commit \
+					 was cherry picked from is also in the list{{Normal}}{{Pad( )}}",
+					&hash[0..7],
+					&source[0..7]
+				),
+				render_line!(AnyLine),
+				"{TRAILING}",
+				render_line!(AnyLine 5)
 			);
-		},
-	);
+		});
+		Ok(())
+	});
 }
 
 #[test]
-fn move_cursor_down_past_end() {
-	module_test(
-		&["pick aaa c1", "pick aaa c2", "pick aaa c3"],
-		&[Event::from(MetaEvent::MoveCursorDown); 3],
-		|mut test_context| {
-			let mut module = List::new(&Config::new());
-			let _ = test_context.handle_all_events(&mut module);
+fn render_wide_with_commit_details_date_formats() {
+	with_temp_repository(|repository| {
+		create_commit(&repository, Some(CreateCommitOptions::new().author_time(1_609_502_400)))?;
+		let hash = head_id(&repository, "main").to_string();
+		let line = format!("pick {} title", hash);
+		module_test(&[line.as_str(), "drop ffffffff gone"], &[], |test_context| {
+			let mut config = Config::new();
+			config.date_format = DateFormat::Format(String::from("%Y"));
+			config.list_date_format = DateFormat::Format(String::from("%b %d, %Y"));
+			let mut module = List::new(&config);
+			module.set_details_loader(spawn_details_loader(
+				repository
+					.git_directory()
+					.join("rebase-merge/git-rebase-todo")
+					.as_path(),
+			));
+			let _ = test_context.build_view_data(&mut module);
+			module.wait_for_loaded_details();
 			let view_data = test_context.build_view_data(&mut module);
 			assert_rendered_output!(
 				view_data,
 				"{TITLE}{HELP}",
 				"{BODY}",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c1",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c2",
-				"{Selected}{Normal} > {ActionPick}pick   {Normal}aaa      {Normal}c3{Normal}{Pad( )}"
+				format!(
+					"{{Selected}}{{Normal}} > {{ActionPick}}pick   {{Normal}}{} {{IndicatorColor}}Jan 01, 2021 \
+					 {{Normal}}  0f {{DiffAddColor}}   +0 {{DiffRemoveColor}}   -0 \
+					 {{Normal}}title{{Normal}}{{Pad( )}}",
+					&hash[0..7]
+				),
+				"{Normal}   {ActionDrop}drop   {Normal}fffffff {Normal}             {Normal}     {Normal}      \
+				 {Normal}      {Normal}gone",
+				"{TRAILING}",
+				"{Normal,Dimmed}{Pad(-)}",
+				format!(
+					"{{IndicatorColor}}{}{{Normal}} {{Normal}}Author <author@example.com>",
+					hash
+				),
+				"{Normal}2021",
+				"{Normal}title",
+				"{Normal}0 files changed, {DiffAddColor}0 insertions(+){Normal}, {DiffRemoveColor}0 deletions(-)"
 			);
-		},
-	);
+		});
+		Ok(())
+	});
 }
 
-#[test]
-fn move_cursor_down_scroll_bottom_move_up_one() {
+#[rstest]
+#[case::commit(
+	0,
+	"{IndicatorColor}p/r/e/s/f/d{Normal} change action{Normal}  {IndicatorColor}c{Normal} details{Normal}  \
+	 {IndicatorColor}k/j{Normal} move{Normal}  {IndicatorColor}v{Normal} visual mode{Normal}  \
+	 {IndicatorColor}w{Normal} write{Normal}  {IndicatorColor}?{Normal} help"
+)]
+#[case::exec(
+	1,
+	"{IndicatorColor}E{Normal} edit{Normal}  {IndicatorColor}k/j{Normal} move{Normal}  \
+	 {IndicatorColor}Delete{Normal} remove{Normal}  {IndicatorColor}w{Normal} write{Normal}  {IndicatorColor}?{Normal} help"
+)]
+#[case::label(
+	3,
+	"{IndicatorColor}E{Normal} edit{Normal}  {IndicatorColor}k/j{Normal} move{Normal}  \
+	 {IndicatorColor}Delete{Normal} remove{Normal}  {IndicatorColor}w{Normal} write{Normal}  {IndicatorColor}?{Normal} help"
+)]
+#[case::other(
+	2,
+	"{IndicatorColor}k/j{Normal} move{Normal}  {IndicatorColor}Delete{Normal} remove{Normal}  \
+	 {IndicatorColor}I{Normal} insert{Normal}  {IndicatorColor}w{Normal} write{Normal}  {IndicatorColor}?{Normal} help"
+)]
+fn render_key_hints(#[case] selected_index: usize, #[case] expected: &str) {
 	module_test(
-		&["pick aaa c1", "pick aaa c2", "pick aaa c3"],
-		&[
-			Event::from(MetaEvent::MoveCursorDown),
-			Event::from(MetaEvent::MoveCursorDown),
-			Event::from(MetaEvent::MoveCursorUp),
-		],
+		&["pick aaaaaaaa comment", "exec make", "break", "label ref"],
+		&[],
 		|mut test_context| {
-			let mut module = List::new(&Config::new());
-			let _ = test_context.handle_all_events(&mut module);
+			let mut config = Config::new();
+			config.key_hints = true;
+			let mut module = List::new(&config);
+			test_context.rebase_todo_file.set_selected_line_index(selected_index);
 			let view_data = test_context.build_view_data(&mut module);
 			assert_rendered_output!(
 				view_data,
 				"{TITLE}{HELP}",
 				"{BODY}",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c1",
-				"{Selected}{Normal} > {ActionPick}pick   {Normal}aaa      {Normal}c2{Normal}{Pad( )}",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c3"
+				render_line!(AnyLine 4),
+				"{TRAILING}",
+				expected
 			);
 		},
 	);
 }
 
+#[cfg(feature = "tutorial")]
 #[test]
-fn move_cursor_down_scroll_bottom_move_up_top() {
+fn render_tutorial_step() {
 	module_test(
-		&["pick aaa c1", "pick aaa c2", "pick aaa c3"],
+		&["pick aaaaaaaa Add the login page", "pick bbbbbbbb Update the README"],
 		&[
 			Event::from(MetaEvent::MoveCursorDown),
-			Event::from(MetaEvent::MoveCursorUp),
-			Event::from(MetaEvent::MoveCursorUp),
+			Event::from(MetaEvent::SwapSelectedUp),
 		],
 		|mut test_context| {
-			let mut module = List::new(&Config::new());
-			let _ = test_context.handle_all_events(&mut module);
+			let config = Config::new();
+			let mut module = List::new(&config);
+			module.set_tutorial(Tutorial::new(&config.key_bindings));
+			{
+				let view_data = test_context.build_view_data(&mut module);
+				assert_rendered_output!(
+					view_data,
+					"{TITLE}{HELP}",
+					"{LEADING}",
+					"{IndicatorColor}Tutorial 1/5: {Normal}Move \"Update the README\" to the top of the list: select \
+					 it with Down, then move it up with k",
+					"{BODY}",
+					render_line!(AnyLine 2)
+				);
+			}
+			let _ = test_context.handle_n_events(&mut module, 2);
 			let view_data = test_context.build_view_data(&mut module);
 			assert_rendered_output!(
 				view_data,
 				"{TITLE}{HELP}",
+				"{LEADING}",
+				"{IndicatorColor}Tutorial 2/5: {Normal}Squash \"Fix a typo in the login page\" into the commit above it: \
+				 select it and press s, or f to also discard its message",
 				"{BODY}",
-				"{Selected}{Normal} > {ActionPick}pick   {Normal}aaa      {Normal}c1{Normal}{Pad( )}",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c2",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c3"
+				render_line!(AnyLine 2)
 			);
 		},
 	);
 }
 
 #[test]
-fn move_cursor_up_attempt_above_top() {
+fn render_key_hints_empty_list() {
+	module_test(&[], &[], |test_context| {
+		let mut config = Config::new();
+		config.key_hints = true;
+		let mut module = List::new(&config);
+		let view_data = test_context.build_view_data(&mut module);
+		assert_rendered_output!(
+			view_data,
+			"{TITLE}{HELP}",
+			"{LEADING}",
+			"{IndicatorColor}Rebase todo file is empty",
+			"{TRAILING}",
+			"{IndicatorColor}I{Normal} insert{Normal}  {IndicatorColor}q{Normal} abort{Normal}  \
+			 {IndicatorColor}?{Normal} help"
+		);
+	});
+}
+
+#[test]
+fn render_key_hints_visual_mode() {
 	module_test(
-		&["pick aaa c1", "pick aaa c2", "pick aaa c3", "pick aaa c4"],
-		&[
-			Event::from(MetaEvent::MoveCursorUp),
-			Event::from(MetaEvent::MoveCursorUp),
-		],
+		&["pick aaaaaaaa comment", "exec make"],
+		&[Event::from(MetaEvent::ToggleVisualMode)],
 		|mut test_context| {
-			let mut module = List::new(&Config::new());
-			let _ = test_context.handle_all_events(&mut module);
+			let mut config = Config::new();
+			config.key_hints = true;
+			let mut module = List::new(&config);
+			let _ = test_context.handle_event(&mut module);
 			let view_data = test_context.build_view_data(&mut module);
 			assert_rendered_output!(
 				view_data,
 				"{TITLE}{HELP}",
 				"{BODY}",
-				"{Selected}{Normal} > {ActionPick}pick   {Normal}aaa      {Normal}c1{Normal}{Pad( )}",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c2",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c3",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c4"
+				render_line!(AnyLine 2),
+				"{TRAILING}",
+				"{IndicatorColor}p/r/e/s/f/d{Normal} change action{Normal}  {IndicatorColor}k/j{Normal} move{Normal}  \
+				 {IndicatorColor}Delete{Normal} remove{Normal}  {IndicatorColor}v{Normal} exit visual mode{Normal}  \
+				 {IndicatorColor}?{Normal} help"
 			);
 		},
 	);
 }
 
 #[test]
-fn move_cursor_down_attempt_below_bottom() {
+fn render_key_hints_disabled_by_update_config() {
+	module_test(&["pick aaaaaaaa comment"], &[], |test_context| {
+		let mut config = Config::new();
+		config.key_hints = true;
+		let mut module = List::new(&config);
+		config.key_hints = false;
+		module.update_config(&config);
+		let view_data = test_context.build_view_data(&mut module);
+		assert_rendered_output!(view_data, "{TITLE}{HELP}", "{BODY}", render_line!(AnyLine));
+	});
+}
+
+#[rstest]
+#[case::default(None, None, "aaaaaaaa ")]
+#[case::core_abbrev(None, Some(4), "aaaa ")]
+#[case::hash_length(Some(10), None, "aaaaaaaaaa ")]
+#[case::hash_length_overrides_core_abbrev(Some(10), Some(4), "aaaaaaaaaa ")]
+#[case::longer_than_hash(Some(20), None, "aaaaaaaaaaaa         ")]
+fn render_full_hash_length(#[case] hash_length: Option<u32>, #[case] abbrev: Option<u32>, #[case] expected: &str) {
+	module_test(&["pick aaaaaaaaaaaa comment"], &[], |test_context| {
+		let mut config = Config::new();
+		config.hash_length = hash_length;
+		config.git.abbrev = abbrev;
+		let mut module = List::new(&config);
+		let view_data = test_context.build_view_data(&mut module);
+		assert_rendered_output!(
+			view_data,
+			"{TITLE}{HELP}",
+			"{BODY}",
+			format!(
+				"{{Selected}}{{Normal}} > {{ActionPick}}pick   {{Normal}}{}{{Normal}}comment{{Normal}}{{Pad( )}}",
+				expected
+			)
+		);
+	});
+}
+
+#[test]
+fn render_full_with_action_labels() {
 	module_test(
-		&["pick aaa c1", "pick aaa c2", "pick aaa c3", "pick aaa c4"],
-		&[Event::from(MetaEvent::MoveCursorDown); 4],
-		|mut test_context| {
-			let mut module = List::new(&Config::new());
-			let _ = test_context.handle_all_events(&mut module);
+		&["pick aaaaaaaa comment 1", "fixup bbbbbbbb comment 2"],
+		&[],
+		|test_context| {
+			let mut config = Config::new();
+			config.action_labels.action_fixup.name = String::from("🔧");
+			config.action_labels.action_pick.name = String::from("cherry-pick");
+			let mut module = List::new(&config);
 			let view_data = test_context.build_view_data(&mut module);
 			assert_rendered_output!(
 				view_data,
 				"{TITLE}{HELP}",
 				"{BODY}",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c1",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c2",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c3",
-				"{Selected}{Normal} > {ActionPick}pick   {Normal}aaa      {Normal}c4{Normal}{Pad( )}"
+				"{Selected}{Normal} > {ActionPick}cherry-pick {Normal}aaaaaaaa {Normal}comment 1{Normal}{Pad( )}",
+				"{Normal}   {ActionFixup}🔧          {Normal}bbbbbbbb {Normal}comment 2"
 			);
 		},
 	);
 }
 
 #[test]
-fn move_cursor_page_up_from_top() {
-	module_test(
-		&["pick aaa c1", "pick aaa c2", "pick aaa c3", "pick aaa c4"],
-		&[Event::from(MetaEvent::MoveCursorPageUp)],
-		|mut test_context| {
+fn render_full_without_preview_on_narrow_window() {
+	with_temp_repository(|repository| {
+		create_commit(&repository, None)?;
+		let hash = head_id(&repository, "main").to_string();
+		let line = format!("pick {} title", hash);
+		module_test(&[line.as_str()], &[], |mut test_context| {
+			test_context.render_context.update(100, 120);
 			let mut module = List::new(&Config::new());
-			module.height = 4;
-			let _ = test_context.handle_all_events(&mut module);
+			module.set_details_loader(spawn_details_loader(
+				repository
+					.git_directory()
+					.join("rebase-merge/git-rebase-todo")
+					.as_path(),
+			));
 			let view_data = test_context.build_view_data(&mut module);
 			assert_rendered_output!(
 				view_data,
 				"{TITLE}{HELP}",
 				"{BODY}",
-				"{Selected}{Normal} > {ActionPick}pick   {Normal}aaa      {Normal}c1{Normal}{Pad( )}",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c2",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c3",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c4"
+				format!(
+					"{{Selected}}{{Normal}} > {{ActionPick}}pick   {{Normal}}{} {{Normal}}title{{Normal}}{{Pad( )}}",
+					&hash[0..8]
+				)
 			);
-		},
-	);
+		});
+		Ok(())
+	});
 }
 
 #[test]
-fn move_cursor_page_up_from_one_page_down() {
+fn render_only_lines_near_selected_line() {
+	let lines = (1..=200)
+		.map(|index| format!("pick aaa c{}", index))
+		.collect::<Vec<String>>();
 	module_test(
-		&["pick aaa c1", "pick aaa c2", "pick aaa c3", "pick aaa c4"],
-		&[
-			Event::from(MetaEvent::MoveCursorDown),
-			Event::from(MetaEvent::MoveCursorDown),
-			Event::from(MetaEvent::MoveCursorPageUp),
-		],
+		&lines.iter().map(String::as_str).collect::<Vec<&str>>(),
+		&[Event::from(MetaEvent::MoveCursorEnd)],
 		|mut test_context| {
+			test_context.render_context.update(100, 10);
 			let mut module = List::new(&Config::new());
-			module.height = 4;
 			let _ = test_context.handle_all_events(&mut module);
 			let view_data = test_context.build_view_data(&mut module);
-			assert_rendered_output!(
+			let mut expected = vec![String::from("{TITLE}{HELP}"), String::from("{BODY}")];
+			expected.extend((180..200).map(|index| {
+				format!(
+					"{{Normal}}   {{ActionPick}}pick   {{Normal}}aaa      {{Normal}}c{}",
+					index
+				)
+			}));
+			expected.push(String::from(
+				"{Selected}{Normal} > {ActionPick}pick   {Normal}aaa      {Normal}c200{Normal}{Pad( )}",
+			));
+			view::testutil::_assert_rendered_output_from_view_data(
 				view_data,
-				"{TITLE}{HELP}",
-				"{BODY}",
-				"{Selected}{Normal} > {ActionPick}pick   {Normal}aaa      {Normal}c1{Normal}{Pad( )}",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c2",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c3",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c4"
+				&expected,
+				view::testutil::AssertRenderOptions::default(),
 			);
 		},
 	);
 }
 
 #[test]
-fn move_cursor_page_up_from_one_page_down_minus_1() {
-	module_test(
-		&["pick aaa c1", "pick aaa c2", "pick aaa c3", "pick aaa c4"],
-		&[
-			Event::from(MetaEvent::MoveCursorDown),
-			Event::from(MetaEvent::MoveCursorDown),
-			Event::from(MetaEvent::MoveCursorPageUp),
-		],
-		|mut test_context| {
+fn render_wide_while_loading_commit_details() {
+	with_temp_repository(|repository| {
+		module_test(&["pick aaaaaaaa title"], &[], |test_context| {
 			let mut module = List::new(&Config::new());
-			module.height = 4;
-			let _ = test_context.handle_all_events(&mut module);
+			module.set_details_loader(spawn_details_loader(
+				repository
+					.git_directory()
+					.join("rebase-merge/git-rebase-todo")
+					.as_path(),
+			));
+			{
+				let view_data = test_context.build_view_data(&mut module);
+				assert_rendered_output!(
+					view_data,
+					"{TITLE}{HELP}",
+					"{BODY}",
+					render_line!(AnyLine),
+					"{TRAILING}",
+					"{Normal,Dimmed}{Pad(-)}",
+					"{IndicatorColor}aaaaaaaa",
+					"{Normal}Loading commit details",
+					render_line!(AnyLine),
+					render_line!(AnyLine)
+				);
+			}
+			module.wait_for_loaded_details();
 			let view_data = test_context.build_view_data(&mut module);
 			assert_rendered_output!(
 				view_data,
 				"{TITLE}{HELP}",
 				"{BODY}",
-				"{Selected}{Normal} > {ActionPick}pick   {Normal}aaa      {Normal}c1{Normal}{Pad( )}",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c2",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c3",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c4"
+				render_line!(AnyLine),
+				"{TRAILING}",
+				"{Normal,Dimmed}{Pad(-)}",
+				"{IndicatorColor}aaaaaaaa",
+				"{Normal}Commit details are not available",
+				render_line!(AnyLine),
+				render_line!(AnyLine)
 			);
-		},
-	);
+		});
+		Ok(())
+	});
 }
 
 #[test]
-fn move_cursor_page_up_from_bottom() {
-	module_test(
-		&["pick aaa c1", "pick aaa c2", "pick aaa c3", "pick aaa c4"],
-		&[
-			Event::from(MetaEvent::MoveCursorDown),
-			Event::from(MetaEvent::MoveCursorDown),
-			Event::from(MetaEvent::MoveCursorDown),
-			Event::from(MetaEvent::MoveCursorPageUp),
-		],
-		|mut test_context| {
+fn handle_bus_event_after_details_loaded() {
+	with_temp_repository(|repository| {
+		module_test(&["pick aaa c1"], &[], |test_context| {
 			let mut module = List::new(&Config::new());
-			module.height = 4;
-			let _ = test_context.handle_all_events(&mut module);
-			let view_data = test_context.build_view_data(&mut module);
-			assert_rendered_output!(
-				view_data,
-				"{TITLE}{HELP}",
-				"{BODY}",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c1",
-				"{Selected}{Normal} > {ActionPick}pick   {Normal}aaa      {Normal}c2{Normal}{Pad( )}",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c3",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c4"
+			let event_bus = EventBus::new();
+			module.set_details_loader(DetailsLoader::spawn(
+				repository
+					.git_directory()
+					.join("rebase-merge/git-rebase-todo")
+					.as_path(),
+				event_bus.sender(),
+				&TaskManager::new(),
+			));
+			// the repository status is loaded on the worker thread, so wait for the event of the bus to arrive
+			let mut events = vec![];
+			for _ in 0..500 {
+				events = event_bus.drain();
+				if !events.is_empty() {
+					break;
+				}
+				sleep(Duration::from_millis(10));
+			}
+			assert_eq!(events.first(), Some(&BusEvent::DetailsLoaded));
+			assert_process_result!(
+				module.handle_bus_event(&BusEvent::DetailsLoaded, &test_context.rebase_todo_file),
+				event = Event::from(MetaEvent::BackgroundLoaded)
 			);
-		},
-	);
+			assert_process_result!(module.handle_bus_event(&BusEvent::DetailsLoaded, &test_context.rebase_todo_file));
+		});
+		Ok(())
+	});
 }
 
 #[test]
-fn move_cursor_home() {
-	module_test(
-		&["pick aaa c1", "pick aaa c2", "pick aaa c3", "pick aaa c4"],
-		&[
-			Event::from(MetaEvent::MoveCursorDown),
-			Event::from(MetaEvent::MoveCursorDown),
-			Event::from(MetaEvent::MoveCursorHome),
-		],
-		|mut test_context| {
-			let mut module = List::new(&Config::new());
-			let _ = test_context.handle_all_events(&mut module);
-			let view_data = test_context.build_view_data(&mut module);
-			assert_rendered_output!(
-				view_data,
-				"{TITLE}{HELP}",
-				"{BODY}",
-				"{Selected}{Normal} > {ActionPick}pick   {Normal}aaa      {Normal}c1{Normal}{Pad( )}",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c2",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c3",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c4"
-			);
-		},
-	);
+fn subscriptions() {
+	let module = List::new(&Config::new());
+	assert_eq!(module.subscriptions(), &[Topic::Git, Topic::Timer, Topic::TodoFile]);
 }
 
 #[test]
-fn move_cursor_end() {
+fn activate_with_relative_dates_starts_timer() {
+	module_test(&["pick aaa c1"], &[], |test_context| {
+		let mut config = Config::new();
+		config.list_date_format = DateFormat::Relative;
+		let mut module = List::new(&config);
+		assert_eq!(
+			test_context.activate(&mut module, State::List).timer,
+			Some(TimerRequest::Start(Timer::RelativeDates, RELATIVE_DATES_INTERVAL))
+		);
+	});
+}
+
+#[test]
+fn activate_with_date_format_stops_timer() {
+	module_test(&["pick aaa c1"], &[], |test_context| {
+		let mut config = Config::new();
+		config.list_date_format = DateFormat::Format(String::from("%Y"));
+		let mut module = List::new(&config);
+		assert_eq!(
+			test_context.activate(&mut module, State::List).timer,
+			Some(TimerRequest::Stop(Timer::RelativeDates))
+		);
+	});
+}
+
+#[test]
+fn tick_with_relative_dates() {
+	module_test(&["pick aaa c1"], &[], |test_context| {
+		let mut config = Config::new();
+		config.list_date_format = DateFormat::Relative;
+		let mut module = List::new(&config);
+		module.apply_loaded_detail(LoadedDetail::Commit(String::from("aaa"), None));
+		let tick = BusEvent::Tick(Timer::RelativeDates);
+		assert_process_result!(
+			module.handle_bus_event(&tick, &test_context.rebase_todo_file),
+			event = Event::from(MetaEvent::BackgroundLoaded)
+		);
+	});
+}
+
+#[test]
+fn tick_without_commit_details() {
+	module_test(&["pick aaa c1"], &[], |test_context| {
+		let mut config = Config::new();
+		config.list_date_format = DateFormat::Relative;
+		let mut module = List::new(&config);
+		let tick = BusEvent::Tick(Timer::RelativeDates);
+		assert_process_result!(module.handle_bus_event(&tick, &test_context.rebase_todo_file));
+	});
+}
+
+#[test]
+fn tick_with_date_format() {
+	module_test(&["pick aaa c1"], &[], |test_context| {
+		let mut config = Config::new();
+		config.list_date_format = DateFormat::Format(String::from("%Y"));
+		let mut module = List::new(&config);
+		module.apply_loaded_detail(LoadedDetail::Commit(String::from("aaa"), None));
+		let tick = BusEvent::Tick(Timer::RelativeDates);
+		assert_process_result!(module.handle_bus_event(&tick, &test_context.rebase_todo_file));
+	});
+}
+
+#[test]
+fn move_cursor_down_1() {
 	module_test(
-		&["pick aaa c1", "pick aaa c2", "pick aaa c3", "pick aaa c4"],
-		&[Event::from(MetaEvent::MoveCursorEnd)],
+		&["pick aaa c1", "pick aaa c2", "pick aaa c3"],
+		&[Event::from(MetaEvent::MoveCursorDown)],
 		|mut test_context| {
 			let mut module = List::new(&Config::new());
 			let _ = test_context.handle_all_events(&mut module);
@@ -408,22 +744,20 @@ fn move_cursor_end() {
 				"{TITLE}{HELP}",
 				"{BODY}",
 				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c1",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c2",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c3",
-				"{Selected}{Normal} > {ActionPick}pick   {Normal}aaa      {Normal}c4{Normal}{Pad( )}"
+				"{Selected}{Normal} > {ActionPick}pick   {Normal}aaa      {Normal}c2{Normal}{Pad( )}",
+				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c3"
 			);
 		},
 	);
 }
 
 #[test]
-fn move_cursor_page_down_past_bottom() {
+fn move_cursor_down_view_end() {
 	module_test(
-		&["pick aaa c1", "pick aaa c2", "pick aaa c3", "pick aaa c4"],
-		&[Event::from(MetaEvent::MoveCursorPageDown); 3],
+		&["pick aaa c1", "pick aaa c2", "pick aaa c3"],
+		&[Event::from(MetaEvent::MoveCursorDown); 2],
 		|mut test_context| {
 			let mut module = List::new(&Config::new());
-			module.height = 4;
 			let _ = test_context.handle_all_events(&mut module);
 			let view_data = test_context.build_view_data(&mut module);
 			assert_rendered_output!(
@@ -432,23 +766,17 @@ fn move_cursor_page_down_past_bottom() {
 				"{BODY}",
 				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c1",
 				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c2",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c3",
-				"{Selected}{Normal} > {ActionPick}pick   {Normal}aaa      {Normal}c4{Normal}{Pad( )}"
+				"{Selected}{Normal} > {ActionPick}pick   {Normal}aaa      {Normal}c3{Normal}{Pad( )}"
 			);
 		},
 	);
 }
 
 #[test]
-fn move_cursor_page_down_one_from_bottom() {
+fn move_cursor_down_past_end() {
 	module_test(
-		&["pick aaa c1", "pick aaa c2", "pick aaa c3", "pick aaa c4"],
-		&[
-			Event::from(MetaEvent::MoveCursorDown),
-			Event::from(MetaEvent::MoveCursorDown),
-			Event::from(MetaEvent::MoveCursorDown),
-			Event::from(MetaEvent::MoveCursorPageDown),
-		],
+		&["pick aaa c1", "pick aaa c2", "pick aaa c3"],
+		&[Event::from(MetaEvent::MoveCursorDown); 3],
 		|mut test_context| {
 			let mut module = List::new(&Config::new());
 			let _ = test_context.handle_all_events(&mut module);
@@ -459,62 +787,50 @@ fn move_cursor_page_down_one_from_bottom() {
 				"{BODY}",
 				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c1",
 				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c2",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c3",
-				"{Selected}{Normal} > {ActionPick}pick   {Normal}aaa      {Normal}c4{Normal}{Pad( )}"
+				"{Selected}{Normal} > {ActionPick}pick   {Normal}aaa      {Normal}c3{Normal}{Pad( )}"
 			);
 		},
 	);
 }
 
 #[test]
-fn move_cursor_page_down_one_page_from_bottom() {
+fn move_cursor_down_held_key() {
 	module_test(
-		&["pick aaa c1", "pick aaa c2", "pick aaa c3", "pick aaa c4"],
 		&[
-			Event::from(MetaEvent::MoveCursorDown),
-			Event::from(MetaEvent::MoveCursorPageDown),
+			"pick aaa c1",
+			"pick aaa c2",
+			"pick aaa c3",
+			"pick aaa c4",
+			"pick aaa c5",
+			"pick aaa c6",
+			"pick aaa c7",
+			"pick aaa c8",
+			"pick aaa c9",
+			"pick aaa c10",
+			"pick aaa c11",
+			"pick aaa c12",
+			"pick aaa c13",
 		],
+		&[Event::from(MetaEvent::MoveCursorDown); 3],
 		|mut test_context| {
-			let mut module = List::new(&Config::new());
-			module.height = 4;
+			let mut config = Config::new();
+			config.key_repeat_fast_threshold = 1;
+			config.key_repeat_page_threshold = 0;
+			let mut module = List::new(&config);
 			let _ = test_context.handle_all_events(&mut module);
-			let view_data = test_context.build_view_data(&mut module);
-			assert_rendered_output!(
-				view_data,
-				"{TITLE}{HELP}",
-				"{BODY}",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c1",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c2",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c3",
-				"{Selected}{Normal} > {ActionPick}pick   {Normal}aaa      {Normal}c4{Normal}{Pad( )}"
-			);
+			assert_eq!(test_context.rebase_todo_file.get_selected_line_index(), 11);
 		},
 	);
 }
 
 #[test]
-fn mouse_scroll() {
+fn move_cursor_down_scroll_bottom_move_up_one() {
 	module_test(
 		&["pick aaa c1", "pick aaa c2", "pick aaa c3"],
 		&[
-			Event::Mouse(MouseEvent {
-				kind: MouseEventKind::ScrollDown,
-				column: 0,
-				row: 0,
-				modifiers: KeyModifiers::empty(),
-			}),
-			Event::Mouse(MouseEvent {
-				kind: MouseEventKind::ScrollDown,
-				column: 0,
-				row: 0,
-				modifiers: KeyModifiers::empty(),
-			}),
-			Event::Mouse(MouseEvent {
-				kind: MouseEventKind::ScrollUp,
-				column: 0,
-				row: 0,
-				modifiers: KeyModifiers::empty(),
-			}),
+			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::MoveCursorUp),
 		],
 		|mut test_context| {
 			let mut module = List::new(&Config::new());
@@ -533,10 +849,14 @@ fn mouse_scroll() {
 }
 
 #[test]
-fn visual_mode_start() {
+fn move_cursor_down_scroll_bottom_move_up_top() {
 	module_test(
 		&["pick aaa c1", "pick aaa c2", "pick aaa c3"],
-		&[Event::from(MetaEvent::ToggleVisualMode)],
+		&[
+			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::MoveCursorUp),
+			Event::from(MetaEvent::MoveCursorUp),
+		],
 		|mut test_context| {
 			let mut module = List::new(&Config::new());
 			let _ = test_context.handle_all_events(&mut module);
@@ -554,12 +874,12 @@ fn visual_mode_start() {
 }
 
 #[test]
-fn visual_mode_start_cursor_down_one() {
+fn move_cursor_up_attempt_above_top() {
 	module_test(
-		&["pick aaa c1", "pick aaa c2", "pick aaa c3"],
+		&["pick aaa c1", "pick aaa c2", "pick aaa c3", "pick aaa c4"],
 		&[
-			Event::from(MetaEvent::ToggleVisualMode),
-			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::MoveCursorUp),
+			Event::from(MetaEvent::MoveCursorUp),
 		],
 		|mut test_context| {
 			let mut module = List::new(&Config::new());
@@ -569,106 +889,72 @@ fn visual_mode_start_cursor_down_one() {
 				view_data,
 				"{TITLE}{HELP}",
 				"{BODY}",
-				"{Selected}{Normal,Dimmed} > {ActionPick}pick   {Normal}aaa      {Normal}c1{Normal}{Pad( )}",
-				"{Selected}{Normal} > {ActionPick}pick   {Normal}aaa      {Normal}c2{Normal}{Pad( )}",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c3"
+				"{Selected}{Normal} > {ActionPick}pick   {Normal}aaa      {Normal}c1{Normal}{Pad( )}",
+				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c2",
+				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c3",
+				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c4"
 			);
 		},
 	);
 }
 
 #[test]
-fn visual_mode_start_cursor_page_down() {
+fn move_cursor_down_attempt_below_bottom() {
 	module_test(
-		&[
-			"pick aaa c1",
-			"pick aaa c2",
-			"pick aaa c3",
-			"pick aaa c4",
-			"pick aaa c5",
-		],
-		&[
-			Event::from(MetaEvent::ToggleVisualMode),
-			Event::from(MetaEvent::MoveCursorPageDown),
-		],
+		&["pick aaa c1", "pick aaa c2", "pick aaa c3", "pick aaa c4"],
+		&[Event::from(MetaEvent::MoveCursorDown); 4],
 		|mut test_context| {
 			let mut module = List::new(&Config::new());
-			module.height = 4;
 			let _ = test_context.handle_all_events(&mut module);
 			let view_data = test_context.build_view_data(&mut module);
 			assert_rendered_output!(
 				view_data,
 				"{TITLE}{HELP}",
 				"{BODY}",
-				"{Selected}{Normal,Dimmed} > {ActionPick}pick   {Normal}aaa      {Normal}c1{Normal}{Pad( )}",
-				"{Selected}{Normal,Dimmed} > {ActionPick}pick   {Normal}aaa      {Normal}c2{Normal}{Pad( )}",
-				"{Selected}{Normal} > {ActionPick}pick   {Normal}aaa      {Normal}c3{Normal}{Pad( )}",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c4",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c5"
+				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c1",
+				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c2",
+				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c3",
+				"{Selected}{Normal} > {ActionPick}pick   {Normal}aaa      {Normal}c4{Normal}{Pad( )}"
 			);
 		},
 	);
 }
 
 #[test]
-fn visual_mode_start_cursor_from_bottom_move_up() {
+fn move_cursor_page_up_from_top() {
 	module_test(
-		&[
-			"pick aaa c1",
-			"pick aaa c2",
-			"pick aaa c3",
-			"pick aaa c4",
-			"pick aaa c5",
-		],
-		&[
-			Event::from(MetaEvent::MoveCursorDown),
-			Event::from(MetaEvent::MoveCursorDown),
-			Event::from(MetaEvent::MoveCursorDown),
-			Event::from(MetaEvent::MoveCursorDown),
-			Event::from(MetaEvent::ToggleVisualMode),
-			Event::from(MetaEvent::MoveCursorUp),
-		],
+		&["pick aaa c1", "pick aaa c2", "pick aaa c3", "pick aaa c4"],
+		&[Event::from(MetaEvent::MoveCursorPageUp)],
 		|mut test_context| {
 			let mut module = List::new(&Config::new());
+			module.height = 4;
 			let _ = test_context.handle_all_events(&mut module);
 			let view_data = test_context.build_view_data(&mut module);
 			assert_rendered_output!(
 				view_data,
 				"{TITLE}{HELP}",
 				"{BODY}",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c1",
+				"{Selected}{Normal} > {ActionPick}pick   {Normal}aaa      {Normal}c1{Normal}{Pad( )}",
 				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c2",
 				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c3",
-				"{Selected}{Normal} > {ActionPick}pick   {Normal}aaa      {Normal}c4{Normal}{Pad( )}",
-				"{Selected}{Normal,Dimmed} > {ActionPick}pick   {Normal}aaa      {Normal}c5{Normal}{Pad( )}"
+				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c4"
 			);
 		},
 	);
 }
 
 #[test]
-fn visual_mode_start_cursor_from_bottom_to_top() {
+fn move_cursor_page_up_from_one_page_down() {
 	module_test(
+		&["pick aaa c1", "pick aaa c2", "pick aaa c3", "pick aaa c4"],
 		&[
-			"pick aaa c1",
-			"pick aaa c2",
-			"pick aaa c3",
-			"pick aaa c4",
-			"pick aaa c5",
-		],
-		&[
-			Event::from(MetaEvent::MoveCursorDown),
 			Event::from(MetaEvent::MoveCursorDown),
 			Event::from(MetaEvent::MoveCursorDown),
-			Event::from(MetaEvent::MoveCursorDown),
-			Event::from(MetaEvent::ToggleVisualMode),
-			Event::from(MetaEvent::MoveCursorUp),
-			Event::from(MetaEvent::MoveCursorUp),
-			Event::from(MetaEvent::MoveCursorUp),
-			Event::from(MetaEvent::MoveCursorUp),
+			Event::from(MetaEvent::MoveCursorPageUp),
 		],
 		|mut test_context| {
 			let mut module = List::new(&Config::new());
+			module.height = 4;
 			let _ = test_context.handle_all_events(&mut module);
 			let view_data = test_context.build_view_data(&mut module);
 			assert_rendered_output!(
@@ -676,58 +962,78 @@ fn visual_mode_start_cursor_from_bottom_to_top() {
 				"{TITLE}{HELP}",
 				"{BODY}",
 				"{Selected}{Normal} > {ActionPick}pick   {Normal}aaa      {Normal}c1{Normal}{Pad( )}",
-				"{Selected}{Normal,Dimmed} > {ActionPick}pick   {Normal}aaa      {Normal}c2{Normal}{Pad( )}",
-				"{Selected}{Normal,Dimmed} > {ActionPick}pick   {Normal}aaa      {Normal}c3{Normal}{Pad( )}",
-				"{Selected}{Normal,Dimmed} > {ActionPick}pick   {Normal}aaa      {Normal}c4{Normal}{Pad( )}",
-				"{Selected}{Normal,Dimmed} > {ActionPick}pick   {Normal}aaa      {Normal}c5{Normal}{Pad( )}"
+				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c2",
+				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c3",
+				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c4"
 			);
 		},
 	);
 }
 
 #[test]
-fn change_selected_line_to_drop() {
+fn move_cursor_page_up_from_one_page_down_minus_1() {
 	module_test(
-		&["pick aaa c1"],
-		&[Event::from(MetaEvent::ActionDrop)],
+		&["pick aaa c1", "pick aaa c2", "pick aaa c3", "pick aaa c4"],
+		&[
+			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::MoveCursorPageUp),
+		],
 		|mut test_context| {
 			let mut module = List::new(&Config::new());
+			module.height = 4;
 			let _ = test_context.handle_all_events(&mut module);
 			let view_data = test_context.build_view_data(&mut module);
 			assert_rendered_output!(
 				view_data,
 				"{TITLE}{HELP}",
 				"{BODY}",
-				"{Selected}{Normal} > {ActionDrop}drop   {Normal}aaa      {Normal}c1{Normal}{Pad( )}"
+				"{Selected}{Normal} > {ActionPick}pick   {Normal}aaa      {Normal}c1{Normal}{Pad( )}",
+				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c2",
+				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c3",
+				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c4"
 			);
 		},
 	);
 }
 
 #[test]
-fn change_selected_line_to_edit() {
+fn move_cursor_page_up_from_bottom() {
 	module_test(
-		&["pick aaa c1"],
-		&[Event::from(MetaEvent::ActionEdit)],
+		&["pick aaa c1", "pick aaa c2", "pick aaa c3", "pick aaa c4"],
+		&[
+			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::MoveCursorPageUp),
+		],
 		|mut test_context| {
 			let mut module = List::new(&Config::new());
+			module.height = 4;
 			let _ = test_context.handle_all_events(&mut module);
 			let view_data = test_context.build_view_data(&mut module);
 			assert_rendered_output!(
 				view_data,
 				"{TITLE}{HELP}",
 				"{BODY}",
-				"{Selected}{Normal} > {ActionEdit}edit   {Normal}aaa      {Normal}c1{Normal}{Pad( )}"
+				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c1",
+				"{Selected}{Normal} > {ActionPick}pick   {Normal}aaa      {Normal}c2{Normal}{Pad( )}",
+				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c3",
+				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c4"
 			);
 		},
 	);
 }
 
 #[test]
-fn change_selected_line_to_fixup() {
+fn move_cursor_home() {
 	module_test(
-		&["pick aaa c1"],
-		&[Event::from(MetaEvent::ActionFixup)],
+		&["pick aaa c1", "pick aaa c2", "pick aaa c3", "pick aaa c4"],
+		&[
+			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::MoveCursorHome),
+		],
 		|mut test_context| {
 			let mut module = List::new(&Config::new());
 			let _ = test_context.handle_all_events(&mut module);
@@ -736,17 +1042,20 @@ fn change_selected_line_to_fixup() {
 				view_data,
 				"{TITLE}{HELP}",
 				"{BODY}",
-				"{Selected}{Normal} > {ActionFixup}fixup  {Normal}aaa      {Normal}c1{Normal}{Pad( )}"
+				"{Selected}{Normal} > {ActionPick}pick   {Normal}aaa      {Normal}c1{Normal}{Pad( )}",
+				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c2",
+				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c3",
+				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c4"
 			);
 		},
 	);
 }
 
 #[test]
-fn change_selected_line_to_pick() {
+fn move_cursor_end() {
 	module_test(
-		&["drop aaa c1"],
-		&[Event::from(MetaEvent::ActionPick)],
+		&["pick aaa c1", "pick aaa c2", "pick aaa c3", "pick aaa c4"],
+		&[Event::from(MetaEvent::MoveCursorEnd)],
 		|mut test_context| {
 			let mut module = List::new(&Config::new());
 			let _ = test_context.handle_all_events(&mut module);
@@ -755,36 +1064,48 @@ fn change_selected_line_to_pick() {
 				view_data,
 				"{TITLE}{HELP}",
 				"{BODY}",
-				"{Selected}{Normal} > {ActionPick}pick   {Normal}aaa      {Normal}c1{Normal}{Pad( )}"
+				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c1",
+				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c2",
+				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c3",
+				"{Selected}{Normal} > {ActionPick}pick   {Normal}aaa      {Normal}c4{Normal}{Pad( )}"
 			);
 		},
 	);
 }
 
 #[test]
-fn change_selected_line_to_reword() {
+fn move_cursor_page_down_past_bottom() {
 	module_test(
-		&["pick aaa c1"],
-		&[Event::from(MetaEvent::ActionReword)],
+		&["pick aaa c1", "pick aaa c2", "pick aaa c3", "pick aaa c4"],
+		&[Event::from(MetaEvent::MoveCursorPageDown); 3],
 		|mut test_context| {
 			let mut module = List::new(&Config::new());
+			module.height = 4;
 			let _ = test_context.handle_all_events(&mut module);
 			let view_data = test_context.build_view_data(&mut module);
 			assert_rendered_output!(
 				view_data,
 				"{TITLE}{HELP}",
 				"{BODY}",
-				"{Selected}{Normal} > {ActionReword}reword {Normal}aaa      {Normal}c1{Normal}{Pad( )}"
+				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c1",
+				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c2",
+				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c3",
+				"{Selected}{Normal} > {ActionPick}pick   {Normal}aaa      {Normal}c4{Normal}{Pad( )}"
 			);
 		},
 	);
 }
 
 #[test]
-fn change_selected_line_to_squash() {
+fn move_cursor_page_down_one_from_bottom() {
 	module_test(
-		&["pick aaa c1"],
-		&[Event::from(MetaEvent::ActionSquash)],
+		&["pick aaa c1", "pick aaa c2", "pick aaa c3", "pick aaa c4"],
+		&[
+			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::MoveCursorPageDown),
+		],
 		|mut test_context| {
 			let mut module = List::new(&Config::new());
 			let _ = test_context.handle_all_events(&mut module);
@@ -793,19 +1114,26 @@ fn change_selected_line_to_squash() {
 				view_data,
 				"{TITLE}{HELP}",
 				"{BODY}",
-				"{Selected}{Normal} > {ActionSquash}squash {Normal}aaa      {Normal}c1{Normal}{Pad( )}"
+				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c1",
+				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c2",
+				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c3",
+				"{Selected}{Normal} > {ActionPick}pick   {Normal}aaa      {Normal}c4{Normal}{Pad( )}"
 			);
 		},
 	);
 }
 
 #[test]
-fn change_selected_line_toggle_break_add() {
+fn move_cursor_page_down_one_page_from_bottom() {
 	module_test(
-		&["pick aaa c1"],
-		&[Event::from(MetaEvent::ActionBreak)],
+		&["pick aaa c1", "pick aaa c2", "pick aaa c3", "pick aaa c4"],
+		&[
+			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::MoveCursorPageDown),
+		],
 		|mut test_context| {
 			let mut module = List::new(&Config::new());
+			module.height = 4;
 			let _ = test_context.handle_all_events(&mut module);
 			let view_data = test_context.build_view_data(&mut module);
 			assert_rendered_output!(
@@ -813,19 +1141,37 @@ fn change_selected_line_toggle_break_add() {
 				"{TITLE}{HELP}",
 				"{BODY}",
 				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c1",
-				"{Selected}{Normal} > {ActionBreak}break  {Normal}{Pad( )}"
+				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c2",
+				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c3",
+				"{Selected}{Normal} > {ActionPick}pick   {Normal}aaa      {Normal}c4{Normal}{Pad( )}"
 			);
 		},
 	);
 }
 
 #[test]
-fn change_selected_line_toggle_break_remove() {
+fn mouse_scroll() {
 	module_test(
-		&["pick aaa c1", "break"],
+		&["pick aaa c1", "pick aaa c2", "pick aaa c3"],
 		&[
-			Event::from(MetaEvent::MoveCursorDown),
-			Event::from(MetaEvent::ActionBreak),
+			Event::Mouse(MouseEvent {
+				kind: MouseEventKind::ScrollDown,
+				column: 0,
+				row: 0,
+				modifiers: KeyModifiers::empty(),
+			}),
+			Event::Mouse(MouseEvent {
+				kind: MouseEventKind::ScrollDown,
+				column: 0,
+				row: 0,
+				modifiers: KeyModifiers::empty(),
+			}),
+			Event::Mouse(MouseEvent {
+				kind: MouseEventKind::ScrollUp,
+				column: 0,
+				row: 0,
+				modifiers: KeyModifiers::empty(),
+			}),
 		],
 		|mut test_context| {
 			let mut module = List::new(&Config::new());
@@ -835,17 +1181,19 @@ fn change_selected_line_toggle_break_remove() {
 				view_data,
 				"{TITLE}{HELP}",
 				"{BODY}",
-				"{Selected}{Normal} > {ActionPick}pick   {Normal}aaa      {Normal}c1{Normal}{Pad( )}"
+				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c1",
+				"{Selected}{Normal} > {ActionPick}pick   {Normal}aaa      {Normal}c2{Normal}{Pad( )}",
+				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c3"
 			);
 		},
 	);
 }
 
 #[test]
-fn change_selected_line_toggle_break_above_existing() {
+fn visual_mode_start() {
 	module_test(
-		&["pick aaa c1", "break"],
-		&[Event::from(MetaEvent::ActionBreak)],
+		&["pick aaa c1", "pick aaa c2", "pick aaa c3"],
+		&[Event::from(MetaEvent::ToggleVisualMode)],
 		|mut test_context| {
 			let mut module = List::new(&Config::new());
 			let _ = test_context.handle_all_events(&mut module);
@@ -855,63 +1203,87 @@ fn change_selected_line_toggle_break_above_existing() {
 				"{TITLE}{HELP}",
 				"{BODY}",
 				"{Selected}{Normal} > {ActionPick}pick   {Normal}aaa      {Normal}c1{Normal}{Pad( )}",
-				"{Normal}   {ActionBreak}break"
+				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c2",
+				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c3"
 			);
 		},
 	);
 }
 
 #[test]
-fn change_selected_line_auto_select_next_with_next_line() {
+fn visual_mode_start_cursor_down_one() {
 	module_test(
-		&["pick aaa c1", "pick aaa c2"],
-		&[Event::from(MetaEvent::ActionSquash)],
+		&["pick aaa c1", "pick aaa c2", "pick aaa c3"],
+		&[
+			Event::from(MetaEvent::ToggleVisualMode),
+			Event::from(MetaEvent::MoveCursorDown),
+		],
 		|mut test_context| {
-			let mut config = Config::new();
-			config.auto_select_next = true;
-			let mut module = List::new(&config);
+			let mut module = List::new(&Config::new());
 			let _ = test_context.handle_all_events(&mut module);
 			let view_data = test_context.build_view_data(&mut module);
 			assert_rendered_output!(
 				view_data,
 				"{TITLE}{HELP}",
 				"{BODY}",
-				"{Normal}   {ActionSquash}squash {Normal}aaa      {Normal}c1",
-				"{Selected}{Normal} > {ActionPick}pick   {Normal}aaa      {Normal}c2{Normal}{Pad( )}"
+				"{Selected}{Normal,Dimmed} > {ActionPick}pick   {Normal}aaa      {Normal}c1{Normal}{Pad( )}",
+				"{Selected}{Normal} > {ActionPick}pick   {Normal}aaa      {Normal}c2{Normal}{Pad( )}",
+				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c3"
 			);
 		},
 	);
 }
 
 #[test]
-fn change_selected_line_swap_down() {
+fn visual_mode_start_cursor_page_down() {
 	module_test(
-		&["pick aaa c1", "pick aaa c2", "pick aaa c3"],
-		&[Event::from(MetaEvent::SwapSelectedDown)],
+		&[
+			"pick aaa c1",
+			"pick aaa c2",
+			"pick aaa c3",
+			"pick aaa c4",
+			"pick aaa c5",
+		],
+		&[
+			Event::from(MetaEvent::ToggleVisualMode),
+			Event::from(MetaEvent::MoveCursorPageDown),
+		],
 		|mut test_context| {
 			let mut module = List::new(&Config::new());
+			module.height = 4;
 			let _ = test_context.handle_all_events(&mut module);
 			let view_data = test_context.build_view_data(&mut module);
 			assert_rendered_output!(
 				view_data,
 				"{TITLE}{HELP}",
 				"{BODY}",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c2",
-				"{Selected}{Normal} > {ActionPick}pick   {Normal}aaa      {Normal}c1{Normal}{Pad( )}",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c3"
+				"{Selected}{Normal,Dimmed} > {ActionPick}pick   {Normal}aaa      {Normal}c1{Normal}{Pad( )}",
+				"{Selected}{Normal,Dimmed} > {ActionPick}pick   {Normal}aaa      {Normal}c2{Normal}{Pad( )}",
+				"{Selected}{Normal} > {ActionPick}pick   {Normal}aaa      {Normal}c3{Normal}{Pad( )}",
+				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c4",
+				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c5"
 			);
 		},
 	);
 }
 
 #[test]
-fn change_selected_line_swap_up() {
+fn visual_mode_start_cursor_from_bottom_move_up() {
 	module_test(
-		&["pick aaa c1", "pick aaa c2", "pick aaa c3"],
+		&[
+			"pick aaa c1",
+			"pick aaa c2",
+			"pick aaa c3",
+			"pick aaa c4",
+			"pick aaa c5",
+		],
 		&[
 			Event::from(MetaEvent::MoveCursorDown),
 			Event::from(MetaEvent::MoveCursorDown),
-			Event::from(MetaEvent::SwapSelectedUp),
+			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::ToggleVisualMode),
+			Event::from(MetaEvent::MoveCursorUp),
 		],
 		|mut test_context| {
 			let mut module = List::new(&Config::new());
@@ -922,1520 +1294,4448 @@ fn change_selected_line_swap_up() {
 				"{TITLE}{HELP}",
 				"{BODY}",
 				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c1",
-				"{Selected}{Normal} > {ActionPick}pick   {Normal}aaa      {Normal}c3{Normal}{Pad( )}",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c2"
+				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c2",
+				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c3",
+				"{Selected}{Normal} > {ActionPick}pick   {Normal}aaa      {Normal}c4{Normal}{Pad( )}",
+				"{Selected}{Normal,Dimmed} > {ActionPick}pick   {Normal}aaa      {Normal}c5{Normal}{Pad( )}"
 			);
 		},
 	);
 }
 
 #[test]
-fn normal_mode_show_commit_when_hash_available() {
+fn visual_mode_start_cursor_from_bottom_to_top() {
 	module_test(
-		&["pick aaa c1"],
-		&[Event::from(MetaEvent::ShowCommit)],
+		&[
+			"pick aaa c1",
+			"pick aaa c2",
+			"pick aaa c3",
+			"pick aaa c4",
+			"pick aaa c5",
+		],
+		&[
+			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::ToggleVisualMode),
+			Event::from(MetaEvent::MoveCursorUp),
+			Event::from(MetaEvent::MoveCursorUp),
+			Event::from(MetaEvent::MoveCursorUp),
+			Event::from(MetaEvent::MoveCursorUp),
+		],
 		|mut test_context| {
 			let mut module = List::new(&Config::new());
-			assert_process_result!(
-				test_context.handle_event(&mut module),
-				event = Event::from(MetaEvent::ShowCommit),
-				state = State::ShowCommit
+			let _ = test_context.handle_all_events(&mut module);
+			let view_data = test_context.build_view_data(&mut module);
+			assert_rendered_output!(
+				view_data,
+				"{TITLE}{HELP}",
+				"{BODY}",
+				"{Selected}{Normal} > {ActionPick}pick   {Normal}aaa      {Normal}c1{Normal}{Pad( )}",
+				"{Selected}{Normal,Dimmed} > {ActionPick}pick   {Normal}aaa      {Normal}c2{Normal}{Pad( )}",
+				"{Selected}{Normal,Dimmed} > {ActionPick}pick   {Normal}aaa      {Normal}c3{Normal}{Pad( )}",
+				"{Selected}{Normal,Dimmed} > {ActionPick}pick   {Normal}aaa      {Normal}c4{Normal}{Pad( )}",
+				"{Selected}{Normal,Dimmed} > {ActionPick}pick   {Normal}aaa      {Normal}c5{Normal}{Pad( )}"
 			);
 		},
 	);
 }
 
 #[test]
-fn normal_mode_show_commit_when_no_selected_line() {
-	module_test(&[], &[Event::from(MetaEvent::ShowCommit)], |mut test_context| {
-		let mut module = List::new(&Config::new());
-		assert_process_result!(
-			test_context.handle_event(&mut module),
-			event = Event::from(MetaEvent::ShowCommit)
-		);
-	});
-}
-
-#[test]
-fn normal_mode_do_not_show_commit_when_hash_not_available() {
+fn change_selected_line_to_drop() {
 	module_test(
-		&["exec echo foo"],
-		&[Event::from(MetaEvent::ShowCommit)],
+		&["pick aaa c1"],
+		&[Event::from(MetaEvent::ActionDrop)],
 		|mut test_context| {
 			let mut module = List::new(&Config::new());
-			assert_process_result!(
-				test_context.handle_event(&mut module),
-				event = Event::from(MetaEvent::ShowCommit)
+			let _ = test_context.handle_all_events(&mut module);
+			let view_data = test_context.build_view_data(&mut module);
+			assert_rendered_output!(
+				view_data,
+				"{TITLE}{HELP}",
+				"{BODY}",
+				"{Selected}{Normal} > {ActionDrop}drop   {Normal}aaa      {Normal}c1{Normal}{Pad( )}"
 			);
 		},
 	);
 }
 
 #[test]
-fn normal_mode_abort() {
+fn change_selected_line_to_edit() {
 	module_test(
 		&["pick aaa c1"],
-		&[Event::from(MetaEvent::Abort)],
+		&[Event::from(MetaEvent::ActionEdit)],
 		|mut test_context| {
 			let mut module = List::new(&Config::new());
-			assert_process_result!(
-				test_context.handle_event(&mut module),
-				event = Event::from(MetaEvent::Abort),
-				state = State::ConfirmAbort
+			let _ = test_context.handle_all_events(&mut module);
+			let view_data = test_context.build_view_data(&mut module);
+			assert_rendered_output!(
+				view_data,
+				"{TITLE}{HELP}",
+				"{BODY}",
+				"{Selected}{Normal} > {ActionEdit}edit   {Normal}aaa      {Normal}c1{Normal}{Pad( )}"
 			);
 		},
 	);
 }
 
 #[test]
-fn normal_mode_force_abort() {
+fn change_selected_line_to_fixup() {
 	module_test(
 		&["pick aaa c1"],
-		&[Event::from(MetaEvent::ForceAbort)],
+		&[Event::from(MetaEvent::ActionFixup)],
 		|mut test_context| {
 			let mut module = List::new(&Config::new());
-			assert_process_result!(
-				test_context.handle_event(&mut module),
-				event = Event::from(MetaEvent::ForceAbort),
-				exit_status = ExitStatus::Good
+			let _ = test_context.handle_all_events(&mut module);
+			let view_data = test_context.build_view_data(&mut module);
+			assert_rendered_output!(
+				view_data,
+				"{TITLE}{HELP}",
+				"{BODY}",
+				"{Selected}{Normal} > {ActionFixup}fixup  {Normal}aaa      {Normal}c1{Normal}{Pad( )}"
 			);
-			assert!(test_context.rebase_todo_file.is_empty());
 		},
 	);
 }
 
 #[test]
-fn normal_mode_rebase() {
+fn cycle_fixup_option() {
 	module_test(
-		&["pick aaa c1"],
-		&[Event::from(MetaEvent::Rebase)],
-		|mut test_context| {
+		&["pick aaa c1", "fixup bbb c2"],
+		&[
+			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::CycleFixupOption),
+		],
+		|mut test_context| {
 			let mut module = List::new(&Config::new());
-			assert_process_result!(
-				test_context.handle_event(&mut module),
-				event = Event::from(MetaEvent::Rebase),
-				state = State::ConfirmRebase
+			let _ = test_context.handle_all_events(&mut module);
+			let view_data = test_context.build_view_data(&mut module);
+			assert_rendered_output!(
+				view_data,
+				"{TITLE}{HELP}",
+				"{BODY}",
+				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c1",
+				"{Selected}{Normal} > {ActionFixup}fixup  {Normal}bbb      {ActionFixup}-C {Normal}c2{Normal}{Pad( )}"
 			);
+			assert_eq!(test_context.rebase_todo_file.get_line(1).unwrap().to_text(), "fixup -C bbb c2");
 		},
 	);
 }
 
 #[test]
-fn normal_mode_force_rebase() {
+fn cycle_fixup_option_visual_mode() {
 	module_test(
-		&["pick aaa c1"],
-		&[Event::from(MetaEvent::ForceRebase)],
+		&["fixup aaa c1", "pick bbb c2", "fixup ccc c3"],
+		&[
+			Event::from(MetaEvent::ToggleVisualMode),
+			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::CycleFixupOption),
+			Event::from(MetaEvent::CycleFixupOption),
+		],
 		|mut test_context| {
 			let mut module = List::new(&Config::new());
-			assert_process_result!(
-				test_context.handle_event(&mut module),
-				event = Event::from(MetaEvent::ForceRebase),
-				exit_status = ExitStatus::Good
+			let _ = test_context.handle_all_events(&mut module);
+			assert_eq!(
+				test_context
+					.rebase_todo_file
+					.lines_iter()
+					.map(Line::to_text)
+					.collect::<Vec<String>>(),
+				vec!["fixup -c aaa c1", "pick bbb c2", "fixup -c ccc c3"]
 			);
-			assert!(!test_context.rebase_todo_file.is_noop());
 		},
 	);
 }
 
 #[test]
-fn normal_mode_edit_with_edit_content() {
+fn change_selected_line_to_pick() {
 	module_test(
-		&["exec echo foo"],
-		&[Event::from(MetaEvent::Edit)],
+		&["drop aaa c1"],
+		&[Event::from(MetaEvent::ActionPick)],
 		|mut test_context| {
 			let mut module = List::new(&Config::new());
-			assert_process_result!(
-				test_context.handle_event(&mut module),
-				event = Event::from(MetaEvent::Edit)
+			let _ = test_context.handle_all_events(&mut module);
+			let view_data = test_context.build_view_data(&mut module);
+			assert_rendered_output!(
+				view_data,
+				"{TITLE}{HELP}",
+				"{BODY}",
+				"{Selected}{Normal} > {ActionPick}pick   {Normal}aaa      {Normal}c1{Normal}{Pad( )}"
 			);
-			assert_eq!(module.state, ListState::Edit);
 		},
 	);
 }
 
 #[test]
-fn normal_mode_edit_without_edit_content() {
-	module_test(&["pick aaa c1"], &[Event::from(MetaEvent::Edit)], |mut test_context| {
-		let mut module = List::new(&Config::new());
-		assert_process_result!(
-			test_context.handle_event(&mut module),
-			event = Event::from(MetaEvent::Edit)
-		);
-		assert_eq!(module.state, ListState::Normal);
-	});
-}
-
-#[test]
-fn normal_mode_edit_without_selected_line() {
-	module_test(&[], &[Event::from(MetaEvent::Edit)], |mut test_context| {
-		let mut module = List::new(&Config::new());
-		assert_process_result!(
-			test_context.handle_event(&mut module),
-			event = Event::from(MetaEvent::Edit)
-		);
-		assert_eq!(module.state, ListState::Normal);
-	});
-}
-
-#[test]
-fn normal_mode_insert_line() {
-	module_test(&[], &[Event::from(MetaEvent::InsertLine)], |mut test_context| {
-		let mut module = List::new(&Config::new());
-		assert_process_result!(
-			test_context.handle_event(&mut module),
-			event = Event::from(MetaEvent::InsertLine),
-			state = State::Insert
-		);
-	});
+fn change_selected_line_to_reword() {
+	module_test(
+		&["pick aaa c1"],
+		&[Event::from(MetaEvent::ActionReword)],
+		|mut test_context| {
+			let mut module = List::new(&Config::new());
+			let _ = test_context.handle_all_events(&mut module);
+			let view_data = test_context.build_view_data(&mut module);
+			assert_rendered_output!(
+				view_data,
+				"{TITLE}{HELP}",
+				"{BODY}",
+				"{Selected}{Normal} > {ActionReword}reword {Normal}aaa      {Normal}c1{Normal}{Pad( )}"
+			);
+		},
+	);
 }
 
 #[test]
-fn normal_mode_open_external_editor() {
+fn change_selected_line_to_squash() {
 	module_test(
 		&["pick aaa c1"],
-		&[Event::from(MetaEvent::OpenInEditor)],
+		&[Event::from(MetaEvent::ActionSquash)],
 		|mut test_context| {
 			let mut module = List::new(&Config::new());
-			assert_process_result!(
-				test_context.handle_event(&mut module),
-				event = Event::from(MetaEvent::OpenInEditor),
-				state = State::ExternalEditor
+			let _ = test_context.handle_all_events(&mut module);
+			let view_data = test_context.build_view_data(&mut module);
+			assert_rendered_output!(
+				view_data,
+				"{TITLE}{HELP}",
+				"{BODY}",
+				"{Selected}{Normal} > {ActionSquash}squash {Normal}aaa      {Normal}c1{Normal}{Pad( )}"
 			);
 		},
 	);
 }
 
 #[test]
-fn normal_mode_undo() {
+fn change_selected_line_toggle_break_add() {
 	module_test(
 		&["pick aaa c1"],
-		&[Event::from(MetaEvent::ActionDrop), Event::from(MetaEvent::Undo)],
+		&[Event::from(MetaEvent::ActionBreak)],
 		|mut test_context| {
 			let mut module = List::new(&Config::new());
-			let _ = test_context.handle_event(&mut module);
-			assert_process_result!(
-				test_context.handle_event(&mut module),
-				event = Event::from(MetaEvent::Undo)
-			);
+			let _ = test_context.handle_all_events(&mut module);
+			let view_data = test_context.build_view_data(&mut module);
 			assert_rendered_output!(
-				test_context.build_view_data(&mut module),
+				view_data,
 				"{TITLE}{HELP}",
 				"{BODY}",
-				"{Selected}{Normal} > {ActionPick}pick   {Normal}aaa      {Normal}c1{Normal}{Pad( )}"
+				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c1",
+				"{Selected}{Normal} > {ActionBreak}break  {Normal}{Pad( )}"
 			);
-			assert_eq!(module.state, ListState::Normal);
 		},
 	);
 }
 
 #[test]
-fn normal_mode_undo_visual_mode_change() {
+fn change_selected_line_toggle_break_remove() {
 	module_test(
-		&["pick aaa c1", "pick bbb c2"],
+		&["pick aaa c1", "break"],
 		&[
-			Event::from(MetaEvent::ToggleVisualMode),
 			Event::from(MetaEvent::MoveCursorDown),
-			Event::from(MetaEvent::ActionDrop),
-			Event::from(MetaEvent::ToggleVisualMode),
-			Event::from(MetaEvent::Undo),
+			Event::from(MetaEvent::ActionBreak),
 		],
 		|mut test_context| {
 			let mut module = List::new(&Config::new());
 			let _ = test_context.handle_all_events(&mut module);
+			let view_data = test_context.build_view_data(&mut module);
 			assert_rendered_output!(
-				test_context.build_view_data(&mut module),
+				view_data,
 				"{TITLE}{HELP}",
 				"{BODY}",
-				"{Selected}{Normal,Dimmed} > {ActionPick}pick   {Normal}aaa      {Normal}c1{Normal}{Pad( )}",
-				"{Selected}{Normal} > {ActionPick}pick   {Normal}bbb      {Normal}c2{Normal}{Pad( )}"
+				"{Selected}{Normal} > {ActionPick}pick   {Normal}aaa      {Normal}c1{Normal}{Pad( )}"
 			);
-			assert_eq!(module.state, ListState::Visual);
 		},
 	);
 }
 
 #[test]
-fn normal_mode_redo() {
+fn change_selected_line_toggle_break_above_existing() {
 	module_test(
-		&["drop aaa c1"],
-		&[
-			Event::from(MetaEvent::ActionPick),
-			Event::from(MetaEvent::Undo),
-			Event::from(MetaEvent::Redo),
-		],
+		&["pick aaa c1", "break"],
+		&[Event::from(MetaEvent::ActionBreak)],
 		|mut test_context| {
 			let mut module = List::new(&Config::new());
-			let _ = test_context.handle_event(&mut module);
-			let _ = test_context.handle_event(&mut module);
-			assert_process_result!(
-				test_context.handle_event(&mut module),
-				event = Event::from(MetaEvent::Redo)
+			let _ = test_context.handle_all_events(&mut module);
+			let view_data = test_context.build_view_data(&mut module);
+			assert_rendered_output!(
+				view_data,
+				"{TITLE}{HELP}",
+				"{BODY}",
+				"{Selected}{Normal} > {ActionPick}pick   {Normal}aaa      {Normal}c1{Normal}{Pad( )}",
+				"{Normal}   {ActionBreak}break  {ActionBreak,Dimmed}{Pad(─)}"
 			);
+		},
+	);
+}
+
+#[test]
+fn change_selected_line_auto_select_next_with_next_line() {
+	module_test(
+		&["pick aaa c1", "pick aaa c2"],
+		&[Event::from(MetaEvent::ActionSquash)],
+		|mut test_context| {
+			let mut config = Config::new();
+			config.auto_select_next = true;
+			let mut module = List::new(&config);
+			let _ = test_context.handle_all_events(&mut module);
+			let view_data = test_context.build_view_data(&mut module);
 			assert_rendered_output!(
-				test_context.build_view_data(&mut module),
+				view_data,
 				"{TITLE}{HELP}",
 				"{BODY}",
-				"{Selected}{Normal} > {ActionPick}pick   {Normal}aaa      {Normal}c1{Normal}{Pad( )}"
+				"{Normal}   {ActionSquash}squash {Normal}aaa      {Normal}c1",
+				"{Selected}{Normal} > {ActionPick}pick   {Normal}aaa      {Normal}c2{Normal}{Pad( )}"
 			);
 		},
 	);
 }
 
 #[test]
-fn normal_mode_redo_visual_mode_change() {
+fn update_config_auto_select_next() {
 	module_test(
-		&["drop aaa c1", "drop bbb c2"],
-		&[
-			Event::from(MetaEvent::ToggleVisualMode),
-			Event::from(MetaEvent::MoveCursorDown),
-			Event::from(MetaEvent::ActionPick),
-			Event::from(MetaEvent::Undo),
-			Event::from(MetaEvent::ToggleVisualMode),
-			Event::from(MetaEvent::Redo),
-		],
+		&["pick aaa c1", "pick aaa c2"],
+		&[Event::from(MetaEvent::ActionSquash)],
 		|mut test_context| {
 			let mut module = List::new(&Config::new());
+			let mut config = Config::new();
+			config.auto_select_next = true;
+			module.update_config(&config);
 			let _ = test_context.handle_all_events(&mut module);
+			let view_data = test_context.build_view_data(&mut module);
 			assert_rendered_output!(
-				test_context.build_view_data(&mut module),
+				view_data,
 				"{TITLE}{HELP}",
 				"{BODY}",
-				"{Selected}{Normal,Dimmed} > {ActionPick}pick   {Normal}aaa      {Normal}c1{Normal}{Pad( )}",
-				"{Selected}{Normal} > {ActionPick}pick   {Normal}bbb      {Normal}c2{Normal}{Pad( )}"
+				"{Normal}   {ActionSquash}squash {Normal}aaa      {Normal}c1",
+				"{Selected}{Normal} > {ActionPick}pick   {Normal}aaa      {Normal}c2{Normal}{Pad( )}"
 			);
-			assert_eq!(module.state, ListState::Visual);
 		},
 	);
 }
 
 #[test]
-fn normal_mode_remove_line_first() {
+fn change_selected_line_swap_down() {
 	module_test(
-		&[
-			"pick aaa c1",
-			"pick bbb c2",
-			"pick ccc c3",
-			"pick ddd c4",
-			"pick eee c5",
-		],
-		&[Event::from(MetaEvent::Delete)],
+		&["pick aaa c1", "pick aaa c2", "pick aaa c3"],
+		&[Event::from(MetaEvent::SwapSelectedDown)],
 		|mut test_context| {
 			let mut module = List::new(&Config::new());
 			let _ = test_context.handle_all_events(&mut module);
+			let view_data = test_context.build_view_data(&mut module);
 			assert_rendered_output!(
-				test_context.build_view_data(&mut module),
+				view_data,
 				"{TITLE}{HELP}",
 				"{BODY}",
-				"{Selected}{Normal} > {ActionPick}pick   {Normal}bbb      {Normal}c2{Normal}{Pad( )}",
-				"{Normal}   {ActionPick}pick   {Normal}ccc      {Normal}c3",
-				"{Normal}   {ActionPick}pick   {Normal}ddd      {Normal}c4",
-				"{Normal}   {ActionPick}pick   {Normal}eee      {Normal}c5"
+				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c2",
+				"{Selected}{Normal} > {ActionPick}pick   {Normal}aaa      {Normal}c1{Normal}{Pad( )}",
+				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c3"
 			);
 		},
 	);
 }
 
 #[test]
-fn normal_mode_remove_line_end() {
+fn change_selected_line_swap_up() {
 	module_test(
-		&[
-			"pick aaa c1",
-			"pick bbb c2",
-			"pick ccc c3",
-			"pick ddd c4",
-			"pick eee c5",
-		],
+		&["pick aaa c1", "pick aaa c2", "pick aaa c3"],
 		&[
 			Event::from(MetaEvent::MoveCursorDown),
 			Event::from(MetaEvent::MoveCursorDown),
-			Event::from(MetaEvent::MoveCursorDown),
-			Event::from(MetaEvent::MoveCursorDown),
-			Event::from(MetaEvent::Delete),
+			Event::from(MetaEvent::SwapSelectedUp),
 		],
 		|mut test_context| {
 			let mut module = List::new(&Config::new());
 			let _ = test_context.handle_all_events(&mut module);
+			let view_data = test_context.build_view_data(&mut module);
 			assert_rendered_output!(
-				test_context.build_view_data(&mut module),
+				view_data,
 				"{TITLE}{HELP}",
 				"{BODY}",
 				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c1",
-				"{Normal}   {ActionPick}pick   {Normal}bbb      {Normal}c2",
-				"{Normal}   {ActionPick}pick   {Normal}ccc      {Normal}c3",
-				"{Selected}{Normal} > {ActionPick}pick   {Normal}ddd      {Normal}c4{Normal}{Pad( )}"
+				"{Selected}{Normal} > {ActionPick}pick   {Normal}aaa      {Normal}c3{Normal}{Pad( )}",
+				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c2"
 			);
 		},
 	);
 }
 
 #[test]
-fn normal_mode_toggle_visual_mode() {
+fn normal_mode_show_commit_when_hash_available() {
 	module_test(
 		&["pick aaa c1"],
-		&[Event::from(MetaEvent::ToggleVisualMode)],
+		&[Event::from(MetaEvent::ShowCommit)],
 		|mut test_context| {
 			let mut module = List::new(&Config::new());
+			assert_process_result!(
+				test_context.handle_event(&mut module),
+				event = Event::from(MetaEvent::ShowCommit),
+				state = State::ShowCommit
+			);
+		},
+	);
+}
+
+#[test]
+fn normal_mode_show_commit_when_no_selected_line() {
+	module_test(&[], &[Event::from(MetaEvent::ShowCommit)], |mut test_context| {
+		let mut module = List::new(&Config::new());
+		assert_process_result!(
+			test_context.handle_event(&mut module),
+			event = Event::from(MetaEvent::ShowCommit)
+		);
+	});
+}
+
+#[test]
+fn normal_mode_do_not_show_commit_when_hash_not_available() {
+	module_test(
+		&["exec echo foo"],
+		&[Event::from(MetaEvent::ShowCommit)],
+		|mut test_context| {
+			let mut module = List::new(&Config::new());
+			assert_process_result!(
+				test_context.handle_event(&mut module),
+				event = Event::from(MetaEvent::ShowCommit)
+			);
+		},
+	);
+}
+
+#[test]
+fn normal_mode_show_squash_message() {
+	module_test(
+		&["pick aaa c1", "squash bbb c2"],
+		&[Event::from(MetaEvent::ShowSquashMessage)],
+		|mut test_context| {
+			let mut module = List::new(&Config::new());
+			assert_process_result!(
+				test_context.handle_event(&mut module),
+				event = Event::from(MetaEvent::ShowSquashMessage),
+				state = State::SquashMessage
+			);
+		},
+	);
+}
+
+#[test]
+fn normal_mode_show_squash_message_without_squash_chain() {
+	module_test(
+		&["pick aaa c1", "fixup bbb c2"],
+		&[Event::from(MetaEvent::ShowSquashMessage)],
+		|mut test_context| {
+			let mut module = List::new(&Config::new());
+			assert_process_result!(
+				test_context.handle_event(&mut module),
+				event = Event::from(MetaEvent::ShowSquashMessage)
+			);
+		},
+	);
+}
+
+#[test]
+fn normal_mode_abort() {
+	module_test(
+		&["pick aaa c1"],
+		&[Event::from(MetaEvent::Abort)],
+		|mut test_context| {
+			let mut module = List::new(&Config::new());
+			assert_process_result!(
+				test_context.handle_event(&mut module),
+				event = Event::from(MetaEvent::Abort),
+				state = State::ConfirmAbort
+			);
+		},
+	);
+}
+
+#[test]
+fn normal_mode_force_abort() {
+	module_test(
+		&["pick aaa c1"],
+		&[Event::from(MetaEvent::ForceAbort)],
+		|mut test_context| {
+			let mut module = List::new(&Config::new());
+			assert_process_result!(
+				test_context.handle_event(&mut module),
+				event = Event::from(MetaEvent::ForceAbort),
+				exit_status = ExitStatus::Good
+			);
+			assert!(test_context.rebase_todo_file.is_empty());
+		},
+	);
+}
+
+#[test]
+fn normal_mode_rebase() {
+	module_test(
+		&["pick aaa c1"],
+		&[Event::from(MetaEvent::Rebase)],
+		|mut test_context| {
+			let mut module = List::new(&Config::new());
+			assert_process_result!(
+				test_context.handle_event(&mut module),
+				event = Event::from(MetaEvent::Rebase),
+				state = State::ConfirmRebase
+			);
+		},
+	);
+}
+
+#[test]
+fn normal_mode_autosquash() {
+	module_test(
+		&["pick aaa c1"],
+		&[Event::from(MetaEvent::Autosquash)],
+		|mut test_context| {
+			let mut module = List::new(&Config::new());
+			assert_process_result!(
+				test_context.handle_event(&mut module),
+				event = Event::from(MetaEvent::Autosquash),
+				state = State::AutosquashPreview
+			);
+		},
+	);
+}
+
+#[test]
+fn normal_mode_show_statistics() {
+	module_test(
+		&[
+			"pick aaa c1",
+			"fixup bbb c2",
+			"exec make",
+			"pick ccc c3",
+			"break",
+			"drop ddd c4",
+		],
+		&[Event::from(MetaEvent::ShowStatistics)],
+		|mut test_context| {
+			let mut module = List::new(&Config::new());
+			assert_process_result!(
+				test_context.handle_event(&mut module),
+				event = Event::from(MetaEvent::ShowStatistics)
+			);
+			assert_rendered_output!(
+				test_context.build_view_data(&mut module),
+				"{TITLE}",
+				"{BODY}",
+				"{Normal}Commits: 4",
+				"{IndicatorColor}     2 {Normal}pick",
+				"{IndicatorColor}     1 {Normal}fixup",
+				"{IndicatorColor}     1 {Normal}drop",
+				"{Normal}Exec lines: 1",
+				"{Normal}Break lines: 1",
+				"{Normal,Dimmed}The changed files of the commits are not available",
+				"{TRAILING}",
+				"{IndicatorColor}Press any key to close"
+			);
+		},
+	);
+}
+
+#[test]
+fn normal_mode_show_statistics_with_changed_files() {
+	RepoBuilder::new()
+		.commit("base")
+		.commit_files("one", &[("src/one.rs", "one\n")])
+		.commit_files("two", &[("src/two.rs", "two\n"), ("readme.md", "two\n")])
+		.commit_files("three", &[("src/one.rs", "three\n"), ("docs/three.md", "three\n")])
+		.with_repository(|repository, commits| {
+			let lines = [
+				format!("pick {} one", commits.hash("one")),
+				format!("pick {} two", commits.hash("two")),
+				format!("pick {} three", commits.hash("three")),
+			];
+			module_test(
+				&[lines[0].as_str(), lines[1].as_str(), lines[2].as_str()],
+				&[Event::from(MetaEvent::ShowStatistics)],
+				|mut test_context| {
+					let mut module = List::new(&Config::new());
+					module.set_details_loader(spawn_details_loader(
+						repository
+							.git_directory()
+							.join("rebase-merge/git-rebase-todo")
+							.as_path(),
+					));
+					let _ = test_context.handle_event(&mut module);
+					module.wait_for_loaded_details();
+					assert_rendered_output!(
+						test_context.build_view_data(&mut module),
+						"{TITLE}",
+						"{BODY}",
+						"{Normal}Commits: 3",
+						"{IndicatorColor}     3 {Normal}pick",
+						"{Normal}Exec lines: 0",
+						"{Normal}Break lines: 0",
+						"{Normal}Possible conflicts: 1",
+						"{Normal,Dimmed}  Commits that change a file that an earlier commit also changes",
+						"{Normal}Commits per changed top-level directory:",
+						"{IndicatorColor}     3 {Normal}src",
+						"{IndicatorColor}     1 {Normal}.",
+						"{IndicatorColor}     1 {Normal}docs",
+						"{TRAILING}",
+						"{IndicatorColor}Press any key to close"
+					);
+				},
+			);
+			Ok(())
+		});
+}
+
+#[test]
+fn normal_mode_show_statistics_close() {
+	module_test(
+		&["pick aaa c1"],
+		&[
+			Event::from(MetaEvent::ShowStatistics),
+			Event::from(KeyCode::Char('x')),
+		],
+		|mut test_context| {
+			let mut module = List::new(&Config::new());
+			let _ = test_context.handle_all_events(&mut module);
+			assert!(!module.statistics.is_active());
+			assert_rendered_output!(
+				test_context.build_view_data(&mut module),
+				"{TITLE}{HELP}",
+				"{BODY}",
+				"{Selected}{Normal} > {ActionPick}pick   {Normal}aaa      {Normal}c1{Normal}{Pad( )}"
+			);
+		},
+	);
+}
+
+#[test]
+fn normal_mode_filter_by_path() {
+	module_test(
+		&["pick aaa c1"],
+		&[Event::from(MetaEvent::FilterByPath)],
+		|mut test_context| {
+			let mut module = List::new(&Config::new());
+			assert_process_result!(
+				test_context.handle_event(&mut module),
+				event = Event::from(MetaEvent::FilterByPath)
+			);
+			assert_eq!(module.state, ListState::PathFilterEdit);
+			assert_rendered_output!(
+				test_context.build_view_data(&mut module),
+				"{TITLE}",
+				"{LEADING}",
+				"{IndicatorColor}Show the commits that change a file that matches a path, or a glob with *, ** and ?",
+				"",
+				"{BODY}",
+				"{Normal,Dimmed}Path: {Normal,Underline}",
+				"{TRAILING}",
+				"{IndicatorColor}Enter to finish"
+			);
+		},
+	);
+}
+
+#[test]
+fn path_filter_empty_pattern() {
+	module_test(
+		&["pick aaa c1"],
+		&[Event::from(MetaEvent::FilterByPath), Event::from(KeyCode::Enter)],
+		|mut test_context| {
+			let mut module = List::new(&Config::new());
+			let _ = test_context.handle_all_events(&mut module);
+			assert_eq!(module.state, ListState::Normal);
+			assert!(module.path_filter.is_none());
+		},
+	);
+}
+
+#[test]
+fn path_filter_without_details() {
+	module_test(
+		&["pick aaa c1"],
+		&[
+			Event::from(MetaEvent::FilterByPath),
+			Event::from(KeyCode::Char('s')),
+			Event::from(KeyCode::Enter),
+		],
+		|mut test_context| {
+			let mut module = List::new(&Config::new());
+			let _ = test_context.handle_all_events(&mut module);
+			assert_eq!(module.state, ListState::PathFilter);
+			assert_rendered_output!(
+				test_context.build_view_data(&mut module),
+				"{TITLE}{HELP}",
+				"{LEADING}",
+				"{IndicatorColor}Commits that change s: 0",
+				"{Normal,Dimmed}The changed files of the commits are not available",
+				"{TRAILING}",
+				"{IndicatorColor}Press Enter to select the commit in the list, or any other key to return to the list"
+			);
+		},
+	);
+}
+
+#[test]
+fn path_filter_select_commit() {
+	RepoBuilder::new()
+		.commit("base")
+		.commit_files("one", &[("src/view/one.rs", "one\n")])
+		.commit_files("two", &[("readme.md", "two\n")])
+		.commit_files("three", &[("src/view/three.rs", "three\n")])
+		.with_repository(|repository, commits| {
+			let lines = [
+				format!("pick {} one", commits.hash("one")),
+				format!("pick {} two", commits.hash("two")),
+				format!("pick {} three", commits.hash("three")),
+			];
+			let mut events = vec![Event::from(MetaEvent::FilterByPath)];
+			events.extend("src/view/".chars().map(|character| Event::from(KeyCode::Char(character))));
+			events.push(Event::from(KeyCode::Enter));
+			module_test(
+				&[lines[0].as_str(), lines[1].as_str(), lines[2].as_str()],
+				&events,
+				|mut test_context| {
+					let mut module = List::new(&Config::new());
+					module.set_details_loader(spawn_details_loader(
+						repository
+							.git_directory()
+							.join("rebase-merge/git-rebase-todo")
+							.as_path(),
+					));
+					let _ = test_context.handle_all_events(&mut module);
+					module.wait_for_loaded_details();
+					assert_rendered_output!(
+						test_context.build_view_data(&mut module),
+						"{TITLE}{HELP}",
+						"{LEADING}",
+						"{IndicatorColor}Commits that change src/view/: 2",
+						"{BODY}",
+						format!(
+							"{{Selected}}{{Normal}} > {{ActionPick}}pick   {{Normal}}{} {{Normal}}one{{Normal}}{{Pad( )}}",
+							&commits.hash("one")[0..8]
+						),
+						format!(
+							"{{Normal}}   {{ActionPick}}pick   {{Normal}}{} {{Normal}}three",
+							&commits.hash("three")[0..8]
+						),
+						"{TRAILING}",
+						"{IndicatorColor}Press Enter to select the commit in the list, or any other key to return to the list"
+					);
+				},
+			);
+			module_test(
+				&[lines[0].as_str(), lines[1].as_str(), lines[2].as_str()],
+				&[Event::from(MetaEvent::MoveCursorDown), Event::from(KeyCode::Enter)],
+				|mut test_context| {
+					let mut module = List::new(&Config::new());
+					module.set_details_loader(spawn_details_loader(
+						repository
+							.git_directory()
+							.join("rebase-merge/git-rebase-todo")
+							.as_path(),
+					));
+					module.path_filter = Some(PathFilter::new("src/view/"));
+					module.state = ListState::PathFilter;
+					module.request_commit_details(&test_context.rebase_todo_file, 0, 3);
+					module.wait_for_loaded_details();
+					let _ = test_context.handle_all_events(&mut module);
+					assert_eq!(module.state, ListState::Normal);
+					assert_eq!(test_context.rebase_todo_file.get_selected_line_index(), 2);
+				},
+			);
+			Ok(())
+		});
+}
+
+#[test]
+fn show_related_commits() {
+	RepoBuilder::new()
+		.commit("base")
+		.commit_files("one", &[("src/one.rs", "one\n")])
+		.commit_files("two", &[("readme.md", "two\n")])
+		.commit_files("three", &[("src/one.rs", "three\n"), ("readme.md", "three\n")])
+		.commit_files("four", &[("src/four.rs", "four\n")])
+		.with_repository(|repository, commits| {
+			let lines = [
+				format!("pick {} one", commits.hash("one")),
+				format!("pick {} two", commits.hash("two")),
+				format!("pick {} three", commits.hash("three")),
+				format!("pick {} four", commits.hash("four")),
+			];
+			module_test(
+				&[
+					lines[0].as_str(),
+					lines[1].as_str(),
+					lines[2].as_str(),
+					lines[3].as_str(),
+				],
+				&[Event::from(MetaEvent::ShowRelatedCommits), Event::from(KeyCode::Enter)],
+				|mut test_context| {
+					let mut module = List::new(&Config::new());
+					module.set_details_loader(spawn_details_loader(
+						repository
+							.git_directory()
+							.join("rebase-merge/git-rebase-todo")
+							.as_path(),
+					));
+					assert_process_result!(
+						test_context.handle_event(&mut module),
+						event = Event::from(MetaEvent::ShowRelatedCommits)
+					);
+					assert_eq!(module.state, ListState::RelatedCommits);
+					module.wait_for_loaded_details();
+					assert_rendered_output!(
+						test_context.build_view_data(&mut module),
+						"{TITLE}{HELP}",
+						"{LEADING}",
+						format!(
+							"{{IndicatorColor}}Commits that change the files of {}: 1",
+							&commits.hash("one")[0..8]
+						),
+						"{BODY}",
+						format!(
+							"{{Selected}}{{Normal}} > {{ActionPick}}pick   {{Normal}}{} {{Normal}}three{{Normal,Dimmed}}  \
+							 src/one.rs{{Normal}}{{Pad( )}}",
+							&commits.hash("three")[0..8]
+						),
+						"{TRAILING}",
+						"{IndicatorColor}Press Enter to select the commit in the list, or any other key to return to the list"
+					);
+					let _ = test_context.handle_event(&mut module);
+					assert_eq!(module.state, ListState::Normal);
+					assert_eq!(test_context.rebase_todo_file.get_selected_line_index(), 2);
+				},
+			);
+			Ok(())
+		});
+}
+
+#[test]
+fn show_related_commits_without_commit() {
+	module_test(
+		&["exec make"],
+		&[Event::from(MetaEvent::ShowRelatedCommits)],
+		|mut test_context| {
+			let mut module = List::new(&Config::new());
+			let _ = test_context.handle_all_events(&mut module);
+			assert_eq!(module.state, ListState::Normal);
+			assert!(module.related_commit.is_none());
+		},
+	);
+}
+
+#[test]
+fn path_filter_close() {
+	module_test(&["pick aaa c1"], &[Event::from(KeyCode::Char('x'))], |mut test_context| {
+		let mut module = List::new(&Config::new());
+		module.path_filter = Some(PathFilter::new("src"));
+		module.state = ListState::PathFilter;
+		let _ = test_context.handle_all_events(&mut module);
+		assert_eq!(module.state, ListState::Normal);
+		assert_eq!(module.path_filter.as_ref().map(PathFilter::pattern), Some("src"));
+	});
+}
+
+#[test]
+fn normal_mode_quick_fix() {
+	module_test(
+		&["fixup aaa fixup! c1", "pick bbb c1", "pick ccc c2"],
+		&[Event::from(MetaEvent::QuickFix)],
+		|mut test_context| {
+			let mut module = List::new(&Config::new());
+			assert_process_result!(
+				test_context.handle_event(&mut module),
+				event = Event::from(MetaEvent::QuickFix)
+			);
+			assert_rendered_output!(
+				test_context.build_view_data(&mut module),
+				"{TITLE}{HELP}",
+				"{BODY}",
+				"{Normal}   {ActionPick}pick   {Normal}bbb      {Normal}c1",
+				"{Selected}{Normal} > {ActionFixup}fixup  {Normal}aaa      {Normal}fixup! c1{Normal}{Pad( )}",
+				"{Normal}   {ActionPick}pick   {Normal}ccc      {Normal}c2"
+			);
+		},
+	);
+}
+
+#[test]
+fn normal_mode_quick_fix_without_problem() {
+	module_test(
+		&["pick aaa c1", "fixup bbb fixup! c1"],
+		&[Event::from(MetaEvent::MoveCursorDown), Event::from(MetaEvent::QuickFix)],
+		|mut test_context| {
+			let mut module = List::new(&Config::new());
+			let _ = test_context.handle_all_events(&mut module);
+			assert_eq!(test_context.rebase_todo_file.get_selected_line_index(), 1);
+			assert_eq!(
+				test_context.rebase_todo_file.get_line(1).unwrap().to_text(),
+				"fixup bbb fixup! c1"
+			);
+		},
+	);
+}
+
+#[test]
+fn normal_mode_rebase_with_review_changes() {
+	module_test(
+		&["pick aaa c1"],
+		&[Event::from(MetaEvent::Rebase)],
+		|mut test_context| {
+			let mut config = Config::new();
+			config.review_changes = true;
+			let mut module = List::new(&config);
+			assert_process_result!(
+				test_context.handle_event(&mut module),
+				event = Event::from(MetaEvent::Rebase),
+				state = State::ReviewChanges
+			);
+		},
+	);
+}
+
+#[test]
+fn normal_mode_force_rebase() {
+	module_test(
+		&["pick aaa c1"],
+		&[Event::from(MetaEvent::ForceRebase)],
+		|mut test_context| {
+			let mut module = List::new(&Config::new());
+			assert_process_result!(
+				test_context.handle_event(&mut module),
+				event = Event::from(MetaEvent::ForceRebase),
+				exit_status = ExitStatus::Good
+			);
+			assert!(!test_context.rebase_todo_file.is_noop());
+		},
+	);
+}
+
+#[test]
+fn normal_mode_edit_with_edit_content() {
+	module_test(
+		&["exec echo foo"],
+		&[Event::from(MetaEvent::Edit)],
+		|mut test_context| {
+			let mut module = List::new(&Config::new());
+			assert_process_result!(
+				test_context.handle_event(&mut module),
+				event = Event::from(MetaEvent::Edit)
+			);
+			assert_eq!(module.state, ListState::Edit);
+		},
+	);
+}
+
+#[test]
+fn normal_mode_edit_without_edit_content() {
+	module_test(&["pick aaa c1"], &[Event::from(MetaEvent::Edit)], |mut test_context| {
+		let mut module = List::new(&Config::new());
+		assert_process_result!(
+			test_context.handle_event(&mut module),
+			event = Event::from(MetaEvent::Edit)
+		);
+		assert_eq!(module.state, ListState::Normal);
+	});
+}
+
+#[test]
+fn normal_mode_edit_without_selected_line() {
+	module_test(&[], &[Event::from(MetaEvent::Edit)], |mut test_context| {
+		let mut module = List::new(&Config::new());
+		assert_process_result!(
+			test_context.handle_event(&mut module),
+			event = Event::from(MetaEvent::Edit)
+		);
+		assert_eq!(module.state, ListState::Normal);
+	});
+}
+
+#[test]
+fn render_uncommitted_changes_auto_stash() {
+	module_test(&["pick aaaaaaaa comment 1"], &[], |test_context| {
+		let mut config = Config::new();
+		config.git.rebase_auto_stash = true;
+		let mut module = List::new(&config);
+		module.apply_loaded_detail(LoadedDetail::UncommittedChanges(true));
+		let view_data = test_context.build_view_data(&mut module);
+		assert_rendered_output!(
+			view_data,
+			"{TITLE}{HELP}",
+			"{LEADING}",
+			"{IndicatorColor}Uncommitted changes will be stashed by rebase.autoStash",
+			"{BODY}",
+			"{Selected}{Normal} > {ActionPick}pick   {Normal}aaaaaaaa {Normal}comment 1{Normal}{Pad( )}"
+		);
+	});
+}
+
+#[test]
+fn render_uncommitted_changes_without_auto_stash() {
+	module_test(&["pick aaaaaaaa comment 1"], &[], |test_context| {
+		let mut module = List::new(&Config::new());
+		module.apply_loaded_detail(LoadedDetail::UncommittedChanges(true));
+		let view_data = test_context.build_view_data(&mut module);
+		assert_rendered_output!(
+			view_data,
+			"{TITLE}{HELP}",
+			"{LEADING}",
+			"{IndicatorColor}Warning: The working tree has uncommitted changes, press i to show details",
+			"{BODY}",
+			"{Selected}{Normal} > {ActionPick}pick   {Normal}aaaaaaaa {Normal}comment 1{Normal}{Pad( )}"
+		);
+	});
+}
+
+#[test]
+fn render_warnings() {
+	module_test(&["pick aaaaaaaa comment 1"], &[], |test_context| {
+		let mut module = List::new(&Config::new());
+		module.add_warning("first");
+		module.add_warning("second");
+		module.apply_loaded_detail(LoadedDetail::UncommittedChanges(true));
+		let view_data = test_context.build_view_data(&mut module);
+		assert_rendered_output!(
+			view_data,
+			"{TITLE}{HELP}",
+			"{LEADING}",
+			"{IndicatorColor}Warning: first",
+			"{IndicatorColor}Warning: second",
+			"{IndicatorColor}Warning: The working tree has uncommitted changes, press i to show details",
+			"{BODY}",
+			"{Selected}{Normal} > {ActionPick}pick   {Normal}aaaaaaaa {Normal}comment 1{Normal}{Pad( )}"
+		);
+	});
+}
+
+fn create_range_topology() -> RangeTopology {
+	RangeTopologyBuilder::new()
+		.ahead(3)
+		.commit("aaaaaaaa00000000", CommitTopology::SideBranch)
+		.commit("bbbbbbbb00000000", CommitTopology::Mainline)
+		.commit("cccccccc00000000", CommitTopology::Merge)
+		.build()
+}
+
+const REBASE_MERGES_TODO: &[&str; 6] = &[
+	"label onto",
+	"pick aaaaaaaa side",
+	"label side",
+	"reset onto",
+	"pick bbbbbbbb main",
+	"merge -C cccccccc side # Merge side",
+];
+
+#[test]
+fn render_topology() {
+	module_test(REBASE_MERGES_TODO, &[], |test_context| {
+		let mut module = List::new(&Config::new());
+		module.apply_loaded_detail(LoadedDetail::Topology(create_range_topology()));
+		let view_data = test_context.build_view_data(&mut module);
+		assert_rendered_output!(
+			view_data,
+			"{TITLE}{HELP}",
+			"{BODY}",
+			"{Selected}{Normal} > {ActionLabel}label  {Normal,Dimmed}|   {Normal,Dimmed}  {Normal}onto{Normal}{Pad( )}",
+			"{Normal}   {ActionPick}pick   {Normal}aaaaaaaa {Normal,Dimmed}*   {Normal,Dimmed}○ {Normal}side",
+			"{Normal}   {ActionLabel}label  {Normal,Dimmed}|   {Normal,Dimmed}  {Normal}side{ActionLabel,Dimmed}{Pad(─)}",
+			"{Normal}   {ActionReset}reset  {Normal,Dimmed}|-. {Normal,Dimmed}  {Normal}onto",
+			"{Normal}   {ActionPick}pick   {Normal}bbbbbbbb {Normal,Dimmed}| * {Normal,Dimmed}● {Normal}main",
+			"{Normal}   {ActionMerge}merge  {Normal,Dimmed}'-* {Normal,Dimmed}◆ {Normal}-C cccccccc side # Merge side"
+		);
+	});
+}
+
+#[test]
+fn render_topology_without_merges() {
+	module_test(&["pick aaaaaaaa side", "pick bbbbbbbb main"], &[], |test_context| {
+		let mut module = List::new(&Config::new());
+		module.apply_loaded_detail(LoadedDetail::Topology(create_range_topology()));
+		let view_data = test_context.build_view_data(&mut module);
+		assert_rendered_output!(
+			view_data,
+			"{TITLE}{HELP}",
+			"{BODY}",
+			"{Selected}{Normal} > {ActionPick}pick   {Normal}aaaaaaaa {Normal}side{Normal}{Pad( )}",
+			"{Normal}   {ActionPick}pick   {Normal}bbbbbbbb {Normal}main"
+		);
+	});
+}
+
+#[test]
+fn render_topology_with_screen_reader() {
+	module_test(REBASE_MERGES_TODO, &[], |test_context| {
+		let mut config = Config::new();
+		config.screen_reader = true;
+		let mut module = List::new(&config);
+		module.apply_loaded_detail(LoadedDetail::Topology(create_range_topology()));
+		let view_data = test_context.build_view_data(&mut module);
+		assert_rendered_output!(
+			view_data,
+			"{TITLE}{HELP}",
+			"{BODY}",
+			render_line!(AnyLine),
+			"{Normal}   {ActionPick}pick   {Normal}aaaaaaaa {Normal}side",
+			render_line!(AnyLine 4)
+		);
+	});
+}
+
+#[test]
+fn render_lints() {
+	module_test(&["label onto", "pick aaaaaaaa c1", "reset missing"], &[], |test_context| {
+		let mut module = List::new(&Config::new());
+		let view_data = test_context.build_view_data(&mut module);
+		assert_rendered_output!(
+			view_data,
+			"{TITLE}{HELP}",
+			"{BODY}",
+			"{Selected}{Normal} > {ActionLabel}label  {Normal,Dimmed}| {Normal}onto{IndicatorColor}  The label onto is not \
+			 used by any line{Normal}{Pad( )}",
+			"{Normal}   {ActionPick}pick   {Normal}aaaaaaaa {Normal,Dimmed}* {Normal}c1",
+			"{Normal}   {ActionReset}reset  {Normal,Dimmed}. {Normal}missing{IndicatorColor}  The label missing is not \
+			 created by any line"
+		);
+	});
+}
+
+#[test]
+fn render_fixup_target_lints() {
+	module_test(
+		&["drop aaaaaaaa c1", "fixup bbbbbbbb fixup! c1", "squash cccccccc squash! c2", "pick dddddddd c2"],
+		&[],
+		|test_context| {
+			let mut module = List::new(&Config::new());
+			let view_data = test_context.build_view_data(&mut module);
+			assert_rendered_output!(
+				view_data,
+				"{TITLE}{HELP}",
+				"{BODY}",
+				"{Selected}{Normal} > {ActionDrop}drop   {Normal}aaaaaaaa {Normal}c1{Normal}{Pad( )}",
+				"{Normal}   {ActionFixup}fixup  {Normal}bbbbbbbb {Normal}fixup! c1{IndicatorColor}  The commit that this \
+				 commit fixes is dropped on line 1, press F to pick this commit",
+				"{Normal}   {ActionSquash}squash {Normal}cccccccc {Normal}squash! c2{IndicatorColor}  The commit that this \
+				 commit fixes is not picked until line 4, press F to move this commit after it",
+				"{Normal}   {ActionPick}pick   {Normal}dddddddd {Normal}c2"
+			);
+		},
+	);
+}
+
+#[test]
+fn load_topology_of_rebase() {
+	RepoBuilder::new()
+		.commit("base")
+		.branch("feature")
+		.commit("side")
+		.checkout("main")
+		.commit("main")
+		.merge("feature", "merge feature")
+		.with_repository(|repository, commits| {
+			let state_directory = repository.git_directory().join("rebase-merge");
+			std::fs::create_dir_all(&state_directory).unwrap();
+			std::fs::write(state_directory.join("onto"), commits.hash("base")).unwrap();
+			std::fs::write(state_directory.join("orig-head"), commits.hash("merge feature")).unwrap();
+			let mut module = List::new(&Config::new());
+			module.set_details_loader(spawn_details_loader(state_directory.join("git-rebase-todo").as_path()));
+			module.wait_for_loaded_details();
+			let topology = module.topology.unwrap();
+			assert_eq!(topology.ahead(), 3);
+			assert_eq!(
+				topology.find(commits.hash("side").as_str()),
+				Some(CommitTopology::SideBranch)
+			);
+			Ok(())
+		});
+}
+
+#[test]
+fn normal_mode_toggle_stash_info() {
+	module_test(
+		&["pick aaaaaaaa comment 1"],
+		&[Event::from(MetaEvent::ToggleStashInfo)],
+		|mut test_context| {
+			let mut module = List::new(&Config::new());
+			module.apply_loaded_detail(LoadedDetail::UncommittedChanges(true));
+			assert_process_result!(
+				test_context.handle_event(&mut module),
+				event = Event::from(MetaEvent::ToggleStashInfo)
+			);
+			assert_eq!(test_context.rebase_todo_file.get_lines_owned().len(), 1);
+			let view_data = test_context.build_view_data(&mut module);
+			assert_rendered_output!(
+				view_data,
+				"{TITLE}{HELP}",
+				"{LEADING}",
+				"{IndicatorColor}Warning: The working tree has uncommitted changes, press i to hide details",
+				"{Normal}The rebase will fail to start unless the changes are committed or stashed,",
+				"{Normal}or rebase.autoStash is enabled. The todo list will not be changed.",
+				"{BODY}",
+				"{Selected}{Normal} > {ActionPick}pick   {Normal}aaaaaaaa {Normal}comment 1{Normal}{Pad( )}"
+			);
+		},
+	);
+}
+
+#[test]
+fn normal_mode_insert_line() {
+	module_test(&[], &[Event::from(MetaEvent::InsertLine)], |mut test_context| {
+		let mut module = List::new(&Config::new());
+		assert_process_result!(
+			test_context.handle_event(&mut module),
+			event = Event::from(MetaEvent::InsertLine),
+			state = State::Insert
+		);
+	});
+}
+
+#[test]
+fn normal_mode_abort_rebase() {
+	module_test(
+		&["pick aaa c1"],
+		&[Event::from(MetaEvent::AbortRebase)],
+		|mut test_context| {
+			let mut module = List::new(&Config::new());
+			assert_process_result!(
+				test_context.handle_event(&mut module),
+				event = Event::from(MetaEvent::AbortRebase),
+				state = State::ConfirmAbortRebase
+			);
+		},
+	);
+}
+
+#[test]
+fn normal_mode_skip_commit() {
+	module_test(
+		&["pick aaa c1"],
+		&[Event::from(MetaEvent::SkipCommit)],
+		|mut test_context| {
+			let mut module = List::new(&Config::new());
+			assert_process_result!(
+				test_context.handle_event(&mut module),
+				event = Event::from(MetaEvent::SkipCommit),
+				state = State::ConfirmSkipCommit
+			);
+		},
+	);
+}
+
+#[test]
+fn normal_mode_open_external_editor() {
+	module_test(
+		&["pick aaa c1"],
+		&[Event::from(MetaEvent::OpenInEditor)],
+		|mut test_context| {
+			let mut module = List::new(&Config::new());
+			assert_process_result!(
+				test_context.handle_event(&mut module),
+				event = Event::from(MetaEvent::OpenInEditor),
+				state = State::ExternalEditor
+			);
+		},
+	);
+}
+
+#[test]
+fn normal_mode_undo() {
+	module_test(
+		&["pick aaa c1"],
+		&[Event::from(MetaEvent::ActionDrop), Event::from(MetaEvent::Undo)],
+		|mut test_context| {
+			let mut module = List::new(&Config::new());
+			let _ = test_context.handle_event(&mut module);
+			assert_process_result!(
+				test_context.handle_event(&mut module),
+				event = Event::from(MetaEvent::Undo)
+			);
+			assert_rendered_output!(
+				test_context.build_view_data(&mut module),
+				"{TITLE}{HELP}",
+				"{BODY}",
+				"{Selected}{Normal} > {ActionPick}pick   {Normal}aaa      {Normal}c1{Normal}{Pad( )}"
+			);
+			assert_eq!(module.state, ListState::Normal);
+		},
+	);
+}
+
+#[test]
+fn normal_mode_undo_visual_mode_change() {
+	module_test(
+		&["pick aaa c1", "pick bbb c2"],
+		&[
+			Event::from(MetaEvent::ToggleVisualMode),
+			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::ActionDrop),
+			Event::from(MetaEvent::ToggleVisualMode),
+			Event::from(MetaEvent::Undo),
+		],
+		|mut test_context| {
+			let mut module = List::new(&Config::new());
+			let _ = test_context.handle_all_events(&mut module);
+			assert_rendered_output!(
+				test_context.build_view_data(&mut module),
+				"{TITLE}{HELP}",
+				"{BODY}",
+				"{Selected}{Normal,Dimmed} > {ActionPick}pick   {Normal}aaa      {Normal}c1{Normal}{Pad( )}",
+				"{Selected}{Normal} > {ActionPick}pick   {Normal}bbb      {Normal}c2{Normal}{Pad( )}"
+			);
+			assert_eq!(module.state, ListState::Visual);
+		},
+	);
+}
+
+#[test]
+fn normal_mode_redo() {
+	module_test(
+		&["drop aaa c1"],
+		&[
+			Event::from(MetaEvent::ActionPick),
+			Event::from(MetaEvent::Undo),
+			Event::from(MetaEvent::Redo),
+		],
+		|mut test_context| {
+			let mut module = List::new(&Config::new());
+			let _ = test_context.handle_event(&mut module);
+			let _ = test_context.handle_event(&mut module);
+			assert_process_result!(
+				test_context.handle_event(&mut module),
+				event = Event::from(MetaEvent::Redo)
+			);
+			assert_rendered_output!(
+				test_context.build_view_data(&mut module),
+				"{TITLE}{HELP}",
+				"{BODY}",
+				"{Selected}{Normal} > {ActionPick}pick   {Normal}aaa      {Normal}c1{Normal}{Pad( )}"
+			);
+		},
+	);
+}
+
+#[test]
+fn normal_mode_redo_visual_mode_change() {
+	module_test(
+		&["drop aaa c1", "drop bbb c2"],
+		&[
+			Event::from(MetaEvent::ToggleVisualMode),
+			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::ActionPick),
+			Event::from(MetaEvent::Undo),
+			Event::from(MetaEvent::ToggleVisualMode),
+			Event::from(MetaEvent::Redo),
+		],
+		|mut test_context| {
+			let mut module = List::new(&Config::new());
+			let _ = test_context.handle_all_events(&mut module);
+			assert_rendered_output!(
+				test_context.build_view_data(&mut module),
+				"{TITLE}{HELP}",
+				"{BODY}",
+				"{Selected}{Normal,Dimmed} > {ActionPick}pick   {Normal}aaa      {Normal}c1{Normal}{Pad( )}",
+				"{Selected}{Normal} > {ActionPick}pick   {Normal}bbb      {Normal}c2{Normal}{Pad( )}"
+			);
+			assert_eq!(module.state, ListState::Visual);
+		},
+	);
+}
+
+#[test]
+fn normal_mode_remove_line_first() {
+	module_test(
+		&[
+			"pick aaa c1",
+			"pick bbb c2",
+			"pick ccc c3",
+			"pick ddd c4",
+			"pick eee c5",
+		],
+		&[Event::from(MetaEvent::Delete)],
+		|mut test_context| {
+			let mut module = List::new(&Config::new());
+			let _ = test_context.handle_all_events(&mut module);
+			assert_rendered_output!(
+				test_context.build_view_data(&mut module),
+				"{TITLE}{HELP}",
+				"{BODY}",
+				"{Selected}{Normal} > {ActionPick}pick   {Normal}bbb      {Normal}c2{Normal}{Pad( )}",
+				"{Normal}   {ActionPick}pick   {Normal}ccc      {Normal}c3",
+				"{Normal}   {ActionPick}pick   {Normal}ddd      {Normal}c4",
+				"{Normal}   {ActionPick}pick   {Normal}eee      {Normal}c5"
+			);
+		},
+	);
+}
+
+#[test]
+fn normal_mode_remove_line_end() {
+	module_test(
+		&[
+			"pick aaa c1",
+			"pick bbb c2",
+			"pick ccc c3",
+			"pick ddd c4",
+			"pick eee c5",
+		],
+		&[
+			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::Delete),
+		],
+		|mut test_context| {
+			let mut module = List::new(&Config::new());
+			let _ = test_context.handle_all_events(&mut module);
+			assert_rendered_output!(
+				test_context.build_view_data(&mut module),
+				"{TITLE}{HELP}",
+				"{BODY}",
+				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c1",
+				"{Normal}   {ActionPick}pick   {Normal}bbb      {Normal}c2",
+				"{Normal}   {ActionPick}pick   {Normal}ccc      {Normal}c3",
+				"{Selected}{Normal} > {ActionPick}pick   {Normal}ddd      {Normal}c4{Normal}{Pad( )}"
+			);
+		},
+	);
+}
+
+#[test]
+fn normal_mode_toggle_visual_mode() {
+	module_test(
+		&["pick aaa c1"],
+		&[Event::from(MetaEvent::ToggleVisualMode)],
+		|mut test_context| {
+			let mut module = List::new(&Config::new());
+			assert_process_result!(
+				test_context.handle_event(&mut module),
+				event = Event::from(MetaEvent::ToggleVisualMode)
+			);
+			assert_eq!(module.visual_index_start, Some(0));
+			assert_eq!(module.state, ListState::Visual);
+		},
+	);
+}
+
+#[test]
+fn normal_mode_other_event() {
+	module_test(&["pick aaa c1"], &[Event::from(KeyCode::Null)], |mut test_context| {
+		let mut module = List::new(&Config::new());
+		assert_process_result!(
+			test_context.handle_event(&mut module),
+			event = Event::from(KeyCode::Null)
+		);
+	});
+}
+
+#[test]
+fn visual_mode_action_change_top_bottom() {
+	module_test(
+		&["pick aaa c1", "pick aaa c2", "pick aaa c3"],
+		&[
+			Event::from(MetaEvent::ToggleVisualMode),
+			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::ActionReword),
+		],
+		|mut test_context| {
+			let mut module = List::new(&Config::new());
+			let _ = test_context.handle_all_events(&mut module);
+			let view_data = test_context.build_view_data(&mut module);
+			assert_rendered_output!(
+				view_data,
+				"{TITLE}{HELP}",
+				"{BODY}",
+				"{Selected}{Normal,Dimmed} > {ActionReword}reword {Normal}aaa      {Normal}c1{Normal}{Pad( )}",
+				"{Selected}{Normal,Dimmed} > {ActionReword}reword {Normal}aaa      {Normal}c2{Normal}{Pad( )}",
+				"{Selected}{Normal} > {ActionReword}reword {Normal}aaa      {Normal}c3{Normal}{Pad( )}"
+			);
+		},
+	);
+}
+
+#[test]
+fn visual_mode_action_change_bottom_top() {
+	module_test(
+		&["pick aaa c1", "pick aaa c2", "pick aaa c3"],
+		&[
+			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::ToggleVisualMode),
+			Event::from(MetaEvent::MoveCursorUp),
+			Event::from(MetaEvent::MoveCursorUp),
+			Event::from(MetaEvent::ActionReword),
+		],
+		|mut test_context| {
+			let mut module = List::new(&Config::new());
+			let _ = test_context.handle_all_events(&mut module);
+			let view_data = test_context.build_view_data(&mut module);
+			assert_rendered_output!(
+				view_data,
+				"{TITLE}{HELP}",
+				"{BODY}",
+				"{Selected}{Normal} > {ActionReword}reword {Normal}aaa      {Normal}c1{Normal}{Pad( )}",
+				"{Selected}{Normal,Dimmed} > {ActionReword}reword {Normal}aaa      {Normal}c2{Normal}{Pad( )}",
+				"{Selected}{Normal,Dimmed} > {ActionReword}reword {Normal}aaa      {Normal}c3{Normal}{Pad( )}"
+			);
+		},
+	);
+}
+
+#[test]
+fn visual_mode_action_change_drop() {
+	module_test(
+		&[
+			"pick aaa c1",
+			"pick aaa c2",
+			"pick aaa c3",
+			"pick aaa c4",
+			"pick aaa c5",
+		],
+		&[
+			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::ToggleVisualMode),
+			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::ActionDrop),
+		],
+		|mut test_context| {
+			let mut module = List::new(&Config::new());
+			let _ = test_context.handle_all_events(&mut module);
+			let view_data = test_context.build_view_data(&mut module);
+			assert_rendered_output!(
+				view_data,
+				"{TITLE}{HELP}",
+				"{BODY}",
+				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c1",
+				"{Selected}{Normal,Dimmed} > {ActionDrop}drop   {Normal}aaa      {Normal}c2{Normal}{Pad( )}",
+				"{Selected}{Normal,Dimmed} > {ActionDrop}drop   {Normal}aaa      {Normal}c3{Normal}{Pad( )}",
+				"{Selected}{Normal} > {ActionDrop}drop   {Normal}aaa      {Normal}c4{Normal}{Pad( )}",
+				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c5"
+			);
+		},
+	);
+}
+
+#[test]
+fn visual_mode_action_change_edit() {
+	module_test(
+		&[
+			"pick aaa c1",
+			"pick aaa c2",
+			"pick aaa c3",
+			"pick aaa c4",
+			"pick aaa c5",
+		],
+		&[
+			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::ToggleVisualMode),
+			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::ActionEdit),
+		],
+		|mut test_context| {
+			let mut module = List::new(&Config::new());
+			let _ = test_context.handle_all_events(&mut module);
+			let view_data = test_context.build_view_data(&mut module);
+			assert_rendered_output!(
+				view_data,
+				"{TITLE}{HELP}",
+				"{BODY}",
+				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c1",
+				"{Selected}{Normal,Dimmed} > {ActionEdit}edit   {Normal}aaa      {Normal}c2{Normal}{Pad( )}",
+				"{Selected}{Normal,Dimmed} > {ActionEdit}edit   {Normal}aaa      {Normal}c3{Normal}{Pad( )}",
+				"{Selected}{Normal} > {ActionEdit}edit   {Normal}aaa      {Normal}c4{Normal}{Pad( )}",
+				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c5"
+			);
+		},
+	);
+}
+
+#[test]
+fn visual_mode_action_change_fixup() {
+	module_test(
+		&[
+			"pick aaa c1",
+			"pick aaa c2",
+			"pick aaa c3",
+			"pick aaa c4",
+			"pick aaa c5",
+		],
+		&[
+			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::ToggleVisualMode),
+			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::ActionFixup),
+		],
+		|mut test_context| {
+			let mut module = List::new(&Config::new());
+			let _ = test_context.handle_all_events(&mut module);
+			let view_data = test_context.build_view_data(&mut module);
+			assert_rendered_output!(
+				view_data,
+				"{TITLE}{HELP}",
+				"{BODY}",
+				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c1",
+				"{Selected}{Normal,Dimmed} > {ActionFixup}fixup  {Normal}aaa      {Normal}c2{Normal}{Pad( )}",
+				"{Selected}{Normal,Dimmed} > {ActionFixup}fixup  {Normal}aaa      {Normal}c3{Normal}{Pad( )}",
+				"{Selected}{Normal} > {ActionFixup}fixup  {Normal}aaa      {Normal}c4{Normal}{Pad( )}",
+				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c5"
+			);
+		},
+	);
+}
+
+#[test]
+fn visual_mode_action_change_pick() {
+	module_test(
+		&[
+			"drop aaa c1",
+			"drop aaa c2",
+			"drop aaa c3",
+			"drop aaa c4",
+			"drop aaa c5",
+		],
+		&[
+			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::ToggleVisualMode),
+			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::ActionPick),
+		],
+		|mut test_context| {
+			let mut module = List::new(&Config::new());
+			let _ = test_context.handle_all_events(&mut module);
+			let view_data = test_context.build_view_data(&mut module);
+			assert_rendered_output!(
+				view_data,
+				"{TITLE}{HELP}",
+				"{BODY}",
+				"{Normal}   {ActionDrop}drop   {Normal}aaa      {Normal}c1",
+				"{Selected}{Normal,Dimmed} > {ActionPick}pick   {Normal}aaa      {Normal}c2{Normal}{Pad( )}",
+				"{Selected}{Normal,Dimmed} > {ActionPick}pick   {Normal}aaa      {Normal}c3{Normal}{Pad( )}",
+				"{Selected}{Normal} > {ActionPick}pick   {Normal}aaa      {Normal}c4{Normal}{Pad( )}",
+				"{Normal}   {ActionDrop}drop   {Normal}aaa      {Normal}c5"
+			);
+		},
+	);
+}
+
+#[test]
+fn visual_mode_action_change_reword() {
+	module_test(
+		&[
+			"pick aaa c1",
+			"pick aaa c2",
+			"pick aaa c3",
+			"pick aaa c4",
+			"pick aaa c5",
+		],
+		&[
+			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::ToggleVisualMode),
+			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::ActionReword),
+		],
+		|mut test_context| {
+			let mut module = List::new(&Config::new());
+			let _ = test_context.handle_all_events(&mut module);
+			let view_data = test_context.build_view_data(&mut module);
+			assert_rendered_output!(
+				view_data,
+				"{TITLE}{HELP}",
+				"{BODY}",
+				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c1",
+				"{Selected}{Normal,Dimmed} > {ActionReword}reword {Normal}aaa      {Normal}c2{Normal}{Pad( )}",
+				"{Selected}{Normal,Dimmed} > {ActionReword}reword {Normal}aaa      {Normal}c3{Normal}{Pad( )}",
+				"{Selected}{Normal} > {ActionReword}reword {Normal}aaa      {Normal}c4{Normal}{Pad( )}",
+				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c5"
+			);
+		},
+	);
+}
+
+#[test]
+fn visual_mode_action_change_squash() {
+	module_test(
+		&[
+			"pick aaa c1",
+			"pick aaa c2",
+			"pick aaa c3",
+			"pick aaa c4",
+			"pick aaa c5",
+		],
+		&[
+			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::ToggleVisualMode),
+			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::ActionSquash),
+		],
+		|mut test_context| {
+			let mut module = List::new(&Config::new());
+			let _ = test_context.handle_all_events(&mut module);
+			let view_data = test_context.build_view_data(&mut module);
+			assert_rendered_output!(
+				view_data,
+				"{TITLE}{HELP}",
+				"{BODY}",
+				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c1",
+				"{Selected}{Normal,Dimmed} > {ActionSquash}squash {Normal}aaa      {Normal}c2{Normal}{Pad( )}",
+				"{Selected}{Normal,Dimmed} > {ActionSquash}squash {Normal}aaa      {Normal}c3{Normal}{Pad( )}",
+				"{Selected}{Normal} > {ActionSquash}squash {Normal}aaa      {Normal}c4{Normal}{Pad( )}",
+				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c5"
+			);
+		},
+	);
+}
+
+#[test]
+fn visual_mode_abort() {
+	module_test(
+		&["pick aaa c1"],
+		&[Event::from(MetaEvent::ToggleVisualMode), Event::from(MetaEvent::Abort)],
+		|mut test_context| {
+			let mut module = List::new(&Config::new());
+			let _ = test_context.handle_event(&mut module);
+			assert_process_result!(
+				test_context.handle_event(&mut module),
+				event = Event::from(MetaEvent::Abort),
+				state = State::ConfirmAbort
+			);
+		},
+	);
+}
+
+#[test]
+fn visual_mode_show_commit() {
+	module_test(
+		&["pick aaa c1", "exec make", "pick bbb c2", "pick ccc c3"],
+		&[
+			Event::from(MetaEvent::ToggleVisualMode),
+			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::ShowCommit),
+		],
+		|mut test_context| {
+			let mut module = List::new(&Config::new());
+			let _ = test_context.handle_n_events(&mut module, 3);
+			assert_process_result!(
+				test_context.handle_event(&mut module),
+				event = Event::from(MetaEvent::ShowCommit),
+				state = State::SquashPreview
+			);
+			assert_eq!(test_context.rebase_todo_file.get_selected_lines(), &[
+				Line::new("pick aaa c1").unwrap(),
+				Line::new("exec make").unwrap(),
+				Line::new("pick bbb c2").unwrap()
+			]);
+		},
+	);
+}
+
+#[test]
+fn visual_mode_show_commit_without_commit() {
+	module_test(
+		&["exec make", "break"],
+		&[
+			Event::from(MetaEvent::ToggleVisualMode),
+			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::ShowCommit),
+		],
+		|mut test_context| {
+			let mut module = List::new(&Config::new());
+			let _ = test_context.handle_n_events(&mut module, 2);
+			assert_process_result!(
+				test_context.handle_event(&mut module),
+				event = Event::from(MetaEvent::ShowCommit)
+			);
+		},
+	);
+}
+
+#[test]
+fn visual_mode_force_abort() {
+	module_test(
+		&["pick aaa c1"],
+		&[
+			Event::from(MetaEvent::ToggleVisualMode),
+			Event::from(MetaEvent::ForceAbort),
+		],
+		|mut test_context| {
+			let mut module = List::new(&Config::new());
+			let _ = test_context.handle_event(&mut module);
+			assert_process_result!(
+				test_context.handle_event(&mut module),
+				event = Event::from(MetaEvent::ForceAbort),
+				exit_status = ExitStatus::Good
+			);
+			assert!(test_context.rebase_todo_file.is_empty());
+		},
+	);
+}
+
+#[test]
+fn visual_mode_rebase() {
+	module_test(
+		&["pick aaa c1"],
+		&[Event::from(MetaEvent::ToggleVisualMode), Event::from(MetaEvent::Rebase)],
+		|mut test_context| {
+			let mut module = List::new(&Config::new());
+			let _ = test_context.handle_event(&mut module);
+			assert_process_result!(
+				test_context.handle_event(&mut module),
+				event = Event::from(MetaEvent::Rebase),
+				state = State::ConfirmRebase
+			);
+		},
+	);
+}
+
+#[test]
+fn visual_mode_force_rebase() {
+	module_test(
+		&["pick aaa c1"],
+		&[
+			Event::from(MetaEvent::ToggleVisualMode),
+			Event::from(MetaEvent::ForceRebase),
+		],
+		|mut test_context| {
+			let mut module = List::new(&Config::new());
+			let _ = test_context.handle_event(&mut module);
+			assert_process_result!(
+				test_context.handle_event(&mut module),
+				event = Event::from(MetaEvent::ForceRebase),
+				exit_status = ExitStatus::Good
+			);
+			assert!(!test_context.rebase_todo_file.is_noop());
+		},
+	);
+}
+
+#[test]
+fn visual_mode_swap_down_from_top_to_bottom_selection() {
+	module_test(
+		&[
+			"pick aaa c1",
+			"pick aaa c2",
+			"pick aaa c3",
+			"pick aaa c4",
+			"pick aaa c5",
+		],
+		&[
+			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::ToggleVisualMode),
+			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::SwapSelectedDown),
+		],
+		|mut test_context| {
+			let mut module = List::new(&Config::new());
+			let _ = test_context.handle_all_events(&mut module);
+			let view_data = test_context.build_view_data(&mut module);
+			assert_rendered_output!(
+				view_data,
+				"{TITLE}{HELP}",
+				"{BODY}",
+				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c1",
+				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c5",
+				"{Selected}{Normal,Dimmed} > {ActionPick}pick   {Normal}aaa      {Normal}c2{Normal}{Pad( )}",
+				"{Selected}{Normal,Dimmed} > {ActionPick}pick   {Normal}aaa      {Normal}c3{Normal}{Pad( )}",
+				"{Selected}{Normal} > {ActionPick}pick   {Normal}aaa      {Normal}c4{Normal}{Pad( )}"
+			);
+		},
+	);
+}
+
+#[test]
+fn visual_mode_swap_down_from_bottom_to_top_selection() {
+	module_test(
+		&[
+			"pick aaa c1",
+			"pick aaa c2",
+			"pick aaa c3",
+			"pick aaa c4",
+			"pick aaa c5",
+		],
+		&[
+			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::ToggleVisualMode),
+			Event::from(MetaEvent::MoveCursorUp),
+			Event::from(MetaEvent::MoveCursorUp),
+			Event::from(MetaEvent::SwapSelectedDown),
+		],
+		|mut test_context| {
+			let mut module = List::new(&Config::new());
+			let _ = test_context.handle_all_events(&mut module);
+			let view_data = test_context.build_view_data(&mut module);
+			assert_rendered_output!(
+				view_data,
+				"{TITLE}{HELP}",
+				"{BODY}",
+				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c1",
+				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c5",
+				"{Selected}{Normal} > {ActionPick}pick   {Normal}aaa      {Normal}c2{Normal}{Pad( )}",
+				"{Selected}{Normal,Dimmed} > {ActionPick}pick   {Normal}aaa      {Normal}c3{Normal}{Pad( )}",
+				"{Selected}{Normal,Dimmed} > {ActionPick}pick   {Normal}aaa      {Normal}c4{Normal}{Pad( )}"
+			);
+		},
+	);
+}
+
+#[test]
+fn visual_mode_swap_up_from_top_to_bottom_selection() {
+	module_test(
+		&[
+			"pick aaa c1",
+			"pick aaa c2",
+			"pick aaa c3",
+			"pick aaa c4",
+			"pick aaa c5",
+		],
+		&[
+			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::ToggleVisualMode),
+			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::SwapSelectedUp),
+		],
+		|mut test_context| {
+			let mut module = List::new(&Config::new());
+			let _ = test_context.handle_all_events(&mut module);
+			let view_data = test_context.build_view_data(&mut module);
+			assert_rendered_output!(
+				view_data,
+				"{TITLE}{HELP}",
+				"{BODY}",
+				"{Selected}{Normal,Dimmed} > {ActionPick}pick   {Normal}aaa      {Normal}c2{Normal}{Pad( )}",
+				"{Selected}{Normal,Dimmed} > {ActionPick}pick   {Normal}aaa      {Normal}c3{Normal}{Pad( )}",
+				"{Selected}{Normal} > {ActionPick}pick   {Normal}aaa      {Normal}c4{Normal}{Pad( )}",
+				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c1",
+				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c5"
+			);
+		},
+	);
+}
+
+#[test]
+fn visual_mode_swap_up_from_bottom_to_top_selection() {
+	module_test(
+		&[
+			"pick aaa c1",
+			"pick aaa c2",
+			"pick aaa c3",
+			"pick aaa c4",
+			"pick aaa c5",
+		],
+		&[
+			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::ToggleVisualMode),
+			Event::from(MetaEvent::MoveCursorUp),
+			Event::from(MetaEvent::MoveCursorUp),
+			Event::from(MetaEvent::SwapSelectedUp),
+		],
+		|mut test_context| {
+			let mut module = List::new(&Config::new());
+			let _ = test_context.handle_all_events(&mut module);
+			let view_data = test_context.build_view_data(&mut module);
+			assert_rendered_output!(
+				view_data,
+				"{TITLE}{HELP}",
+				"{BODY}",
+				"{Selected}{Normal} > {ActionPick}pick   {Normal}aaa      {Normal}c2{Normal}{Pad( )}",
+				"{Selected}{Normal,Dimmed} > {ActionPick}pick   {Normal}aaa      {Normal}c3{Normal}{Pad( )}",
+				"{Selected}{Normal,Dimmed} > {ActionPick}pick   {Normal}aaa      {Normal}c4{Normal}{Pad( )}",
+				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c1",
+				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c5"
+			);
+		},
+	);
+}
+
+#[test]
+fn visual_mode_swap_down_to_limit_from_bottom_to_top_selection() {
+	module_test(
+		&[
+			"pick aaa c1",
+			"pick aaa c2",
+			"pick aaa c3",
+			"pick aaa c4",
+			"pick aaa c5",
+		],
+		&[
+			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::ToggleVisualMode),
+			Event::from(MetaEvent::MoveCursorUp),
+			Event::from(MetaEvent::MoveCursorUp),
+			Event::from(MetaEvent::SwapSelectedDown),
+			Event::from(MetaEvent::SwapSelectedDown),
+		],
+		|mut test_context| {
+			let mut module = List::new(&Config::new());
+			let _ = test_context.handle_all_events(&mut module);
+			let view_data = test_context.build_view_data(&mut module);
+			assert_rendered_output!(
+				view_data,
+				"{TITLE}{HELP}",
+				"{BODY}",
+				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c1",
+				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c5",
+				"{Selected}{Normal} > {ActionPick}pick   {Normal}aaa      {Normal}c2{Normal}{Pad( )}",
+				"{Selected}{Normal,Dimmed} > {ActionPick}pick   {Normal}aaa      {Normal}c3{Normal}{Pad( )}",
+				"{Selected}{Normal,Dimmed} > {ActionPick}pick   {Normal}aaa      {Normal}c4{Normal}{Pad( )}"
+			);
+		},
+	);
+}
+
+#[test]
+fn visual_mode_swap_down_to_limit_from_top_to_bottom_selection() {
+	module_test(
+		&[
+			"pick aaa c1",
+			"pick aaa c2",
+			"pick aaa c3",
+			"pick aaa c4",
+			"pick aaa c5",
+		],
+		&[
+			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::ToggleVisualMode),
+			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::SwapSelectedDown),
+			Event::from(MetaEvent::SwapSelectedDown),
+		],
+		|mut test_context| {
+			let mut module = List::new(&Config::new());
+			let _ = test_context.handle_all_events(&mut module);
+			let view_data = test_context.build_view_data(&mut module);
+			assert_rendered_output!(
+				view_data,
+				"{TITLE}{HELP}",
+				"{BODY}",
+				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c1",
+				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c5",
+				"{Selected}{Normal,Dimmed} > {ActionPick}pick   {Normal}aaa      {Normal}c2{Normal}{Pad( )}",
+				"{Selected}{Normal,Dimmed} > {ActionPick}pick   {Normal}aaa      {Normal}c3{Normal}{Pad( )}",
+				"{Selected}{Normal} > {ActionPick}pick   {Normal}aaa      {Normal}c4{Normal}{Pad( )}"
+			);
+		},
+	);
+}
+
+#[test]
+fn visual_mode_swap_up_to_limit_from_top_to_bottom_selection() {
+	module_test(
+		&[
+			"pick aaa c1",
+			"pick aaa c2",
+			"pick aaa c3",
+			"pick aaa c4",
+			"pick aaa c5",
+		],
+		&[
+			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::ToggleVisualMode),
+			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::SwapSelectedUp),
+			Event::from(MetaEvent::SwapSelectedUp),
+		],
+		|mut test_context| {
+			let mut module = List::new(&Config::new());
+			let _ = test_context.handle_all_events(&mut module);
+			let view_data = test_context.build_view_data(&mut module);
+			assert_rendered_output!(
+				view_data,
+				"{TITLE}{HELP}",
+				"{BODY}",
+				"{Selected}{Normal,Dimmed} > {ActionPick}pick   {Normal}aaa      {Normal}c2{Normal}{Pad( )}",
+				"{Selected}{Normal,Dimmed} > {ActionPick}pick   {Normal}aaa      {Normal}c3{Normal}{Pad( )}",
+				"{Selected}{Normal} > {ActionPick}pick   {Normal}aaa      {Normal}c4{Normal}{Pad( )}",
+				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c1",
+				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c5"
+			);
+		},
+	);
+}
+
+#[test]
+fn visual_mode_swap_up_to_limit_from_bottom_to_top_selection() {
+	module_test(
+		&[
+			"pick aaa c1",
+			"pick aaa c2",
+			"pick aaa c3",
+			"pick aaa c4",
+			"pick aaa c5",
+		],
+		&[
+			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::ToggleVisualMode),
+			Event::from(MetaEvent::MoveCursorUp),
+			Event::from(MetaEvent::MoveCursorUp),
+			Event::from(MetaEvent::SwapSelectedUp),
+			Event::from(MetaEvent::SwapSelectedUp),
+		],
+		|mut test_context| {
+			let mut module = List::new(&Config::new());
+			let _ = test_context.handle_all_events(&mut module);
+			let view_data = test_context.build_view_data(&mut module);
+			assert_rendered_output!(
+				view_data,
+				"{TITLE}{HELP}",
+				"{BODY}",
+				"{Selected}{Normal} > {ActionPick}pick   {Normal}aaa      {Normal}c2{Normal}{Pad( )}",
+				"{Selected}{Normal,Dimmed} > {ActionPick}pick   {Normal}aaa      {Normal}c3{Normal}{Pad( )}",
+				"{Selected}{Normal,Dimmed} > {ActionPick}pick   {Normal}aaa      {Normal}c4{Normal}{Pad( )}",
+				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c1",
+				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c5"
+			);
+		},
+	);
+}
+
+#[test]
+fn visual_mode_toggle_visual_mode() {
+	module_test(
+		&["pick aaa c1"],
+		&[
+			Event::from(MetaEvent::ToggleVisualMode),
+			Event::from(MetaEvent::ToggleVisualMode),
+		],
+		|mut test_context| {
+			let mut module = List::new(&Config::new());
+			let _ = test_context.handle_event(&mut module);
 			assert_process_result!(
 				test_context.handle_event(&mut module),
 				event = Event::from(MetaEvent::ToggleVisualMode)
 			);
-			assert_eq!(module.visual_index_start, Some(0));
-			assert_eq!(module.state, ListState::Visual);
+			assert_eq!(module.visual_index_start, None);
+			assert_eq!(module.state, ListState::Normal);
+		},
+	);
+}
+
+#[test]
+fn visual_mode_open_external_editor() {
+	module_test(
+		&["pick aaa c1"],
+		&[
+			Event::from(MetaEvent::ToggleVisualMode),
+			Event::from(MetaEvent::OpenInEditor),
+		],
+		|mut test_context| {
+			let mut module = List::new(&Config::new());
+			let _ = test_context.handle_event(&mut module);
+			assert_process_result!(
+				test_context.handle_event(&mut module),
+				event = Event::from(MetaEvent::OpenInEditor),
+				state = State::ExternalEditor
+			);
+		},
+	);
+}
+
+#[test]
+fn visual_mode_undo() {
+	module_test(
+		&["pick aaa c1", "pick bbb c2"],
+		&[
+			Event::from(MetaEvent::ToggleVisualMode),
+			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::ActionDrop),
+			Event::from(MetaEvent::Undo),
+		],
+		|mut test_context| {
+			let mut module = List::new(&Config::new());
+			let _ = test_context.handle_n_events(&mut module, 3);
+			assert_process_result!(
+				test_context.handle_event(&mut module),
+				event = Event::from(MetaEvent::Undo)
+			);
+			assert_rendered_output!(
+				test_context.build_view_data(&mut module),
+				"{TITLE}{HELP}",
+				"{BODY}",
+				"{Selected}{Normal,Dimmed} > {ActionPick}pick   {Normal}aaa      {Normal}c1{Normal}{Pad( )}",
+				"{Selected}{Normal} > {ActionPick}pick   {Normal}bbb      {Normal}c2{Normal}{Pad( )}"
+			);
+		},
+	);
+}
+
+#[test]
+fn visual_mode_undo_normal_mode_change() {
+	module_test(
+		&["pick aaa c1", "pick bbb c2"],
+		&[
+			Event::from(MetaEvent::ActionDrop),
+			Event::from(MetaEvent::ToggleVisualMode),
+			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::Undo),
+		],
+		|mut test_context| {
+			let mut module = List::new(&Config::new());
+			let _ = test_context.handle_n_events(&mut module, 3);
+			assert_process_result!(
+				test_context.handle_event(&mut module),
+				event = Event::from(MetaEvent::Undo)
+			);
+			assert_rendered_output!(
+				test_context.build_view_data(&mut module),
+				"{TITLE}{HELP}",
+				"{BODY}",
+				"{Selected}{Normal} > {ActionPick}pick   {Normal}aaa      {Normal}c1{Normal}{Pad( )}",
+				"{Normal}   {ActionPick}pick   {Normal}bbb      {Normal}c2"
+			);
+			assert_eq!(module.state, ListState::Normal);
+		},
+	);
+}
+
+#[test]
+fn visual_mode_redo() {
+	module_test(
+		&["drop aaa c1", "drop bbb c2"],
+		&[
+			Event::from(MetaEvent::ToggleVisualMode),
+			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::ActionPick),
+			Event::from(MetaEvent::Undo),
+			Event::from(MetaEvent::Redo),
+		],
+		|mut test_context| {
+			let mut module = List::new(&Config::new());
+			let _ = test_context.handle_all_events(&mut module);
+			assert_rendered_output!(
+				test_context.build_view_data(&mut module),
+				"{TITLE}{HELP}",
+				"{BODY}",
+				"{Selected}{Normal,Dimmed} > {ActionPick}pick   {Normal}aaa      {Normal}c1{Normal}{Pad( )}",
+				"{Selected}{Normal} > {ActionPick}pick   {Normal}bbb      {Normal}c2{Normal}{Pad( )}"
+			);
+			assert_eq!(module.state, ListState::Visual);
+		},
+	);
+}
+#[test]
+fn visual_mode_redo_normal_mode_change() {
+	module_test(
+		&["drop aaa c1", "drop bbb c2"],
+		&[
+			Event::from(MetaEvent::ActionPick),
+			Event::from(MetaEvent::ToggleVisualMode),
+			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::Undo),
+			Event::from(MetaEvent::Redo),
+		],
+		|mut test_context| {
+			let mut module = List::new(&Config::new());
+			let _ = test_context.handle_all_events(&mut module);
+			assert_rendered_output!(
+				test_context.build_view_data(&mut module),
+				"{TITLE}{HELP}",
+				"{BODY}",
+				"{Selected}{Normal} > {ActionPick}pick   {Normal}aaa      {Normal}c1{Normal}{Pad( )}",
+				"{Normal}   {ActionDrop}drop   {Normal}bbb      {Normal}c2"
+			);
+			assert_eq!(module.state, ListState::Normal);
+		},
+	);
+}
+
+#[test]
+fn visual_mode_remove_lines_start_index_first() {
+	module_test(
+		&[
+			"pick aaa c1",
+			"pick bbb c2",
+			"pick ccc c3",
+			"pick ddd c4",
+			"pick eee c5",
+		],
+		&[
+			Event::from(MetaEvent::ToggleVisualMode),
+			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::Delete),
+		],
+		|mut test_context| {
+			let mut module = List::new(&Config::new());
+			let _ = test_context.handle_all_events(&mut module);
+			assert_rendered_output!(
+				test_context.build_view_data(&mut module),
+				"{TITLE}{HELP}",
+				"{BODY}",
+				"{Selected}{Normal} > {ActionPick}pick   {Normal}ddd      {Normal}c4{Normal}{Pad( )}",
+				"{Normal}   {ActionPick}pick   {Normal}eee      {Normal}c5"
+			);
+			assert_eq!(
+				module.visual_index_start.unwrap(),
+				test_context.rebase_todo_file.get_selected_line_index()
+			);
+		},
+	);
+}
+
+#[test]
+fn visual_mode_remove_lines_end_index_first() {
+	module_test(
+		&[
+			"pick aaa c1",
+			"pick bbb c2",
+			"pick ccc c3",
+			"pick ddd c4",
+			"pick eee c5",
+		],
+		&[
+			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::ToggleVisualMode),
+			Event::from(MetaEvent::MoveCursorUp),
+			Event::from(MetaEvent::MoveCursorUp),
+			Event::from(MetaEvent::Delete),
+		],
+		|mut test_context| {
+			let mut module = List::new(&Config::new());
+			let _ = test_context.handle_all_events(&mut module);
+			assert_rendered_output!(
+				test_context.build_view_data(&mut module),
+				"{TITLE}{HELP}",
+				"{BODY}",
+				"{Selected}{Normal} > {ActionPick}pick   {Normal}ddd      {Normal}c4{Normal}{Pad( )}",
+				"{Normal}   {ActionPick}pick   {Normal}eee      {Normal}c5"
+			);
+			assert_eq!(
+				module.visual_index_start.unwrap(),
+				test_context.rebase_todo_file.get_selected_line_index()
+			);
+		},
+	);
+}
+
+#[test]
+fn visual_mode_remove_lines_start_index_last() {
+	module_test(
+		&[
+			"pick aaa c1",
+			"pick bbb c2",
+			"pick ccc c3",
+			"pick ddd c4",
+			"pick eee c5",
+		],
+		&[
+			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::ToggleVisualMode),
+			Event::from(MetaEvent::MoveCursorUp),
+			Event::from(MetaEvent::MoveCursorUp),
+			Event::from(MetaEvent::Delete),
+		],
+		|mut test_context| {
+			let mut module = List::new(&Config::new());
+			let _ = test_context.handle_all_events(&mut module);
+			assert_rendered_output!(
+				test_context.build_view_data(&mut module),
+				"{TITLE}{HELP}",
+				"{BODY}",
+				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c1",
+				"{Selected}{Normal} > {ActionPick}pick   {Normal}bbb      {Normal}c2{Normal}{Pad( )}"
+			);
+			assert_eq!(
+				module.visual_index_start.unwrap(),
+				test_context.rebase_todo_file.get_selected_line_index()
+			);
+		},
+	);
+}
+
+#[test]
+fn visual_mode_remove_lines_end_index_last() {
+	module_test(
+		&[
+			"pick aaa c1",
+			"pick bbb c2",
+			"pick ccc c3",
+			"pick ddd c4",
+			"pick eee c5",
+		],
+		&[
+			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::ToggleVisualMode),
+			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::Delete),
+		],
+		|mut test_context| {
+			let mut module = List::new(&Config::new());
+			let _ = test_context.handle_all_events(&mut module);
+			assert_rendered_output!(
+				test_context.build_view_data(&mut module),
+				"{TITLE}{HELP}",
+				"{BODY}",
+				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c1",
+				"{Selected}{Normal} > {ActionPick}pick   {Normal}bbb      {Normal}c2{Normal}{Pad( )}"
+			);
+			assert_eq!(
+				module.visual_index_start.unwrap(),
+				test_context.rebase_todo_file.get_selected_line_index()
+			);
+		},
+	);
+}
+
+#[test]
+fn visual_mode_other_event() {
+	module_test(&["pick aaa c1"], &[Event::from(KeyCode::Null)], |mut test_context| {
+		let mut module = List::new(&Config::new());
+		assert_process_result!(
+			test_context.handle_event(&mut module),
+			event = Event::from(KeyCode::Null)
+		);
+	});
+}
+
+#[test]
+fn edit_mode_render() {
+	module_test(&["exec foo"], &[Event::from(MetaEvent::Edit)], |mut test_context| {
+		let mut module = List::new(&Config::new());
+		let _ = test_context.handle_all_events(&mut module);
+		let view_data = test_context.build_view_data(&mut module);
+		assert_rendered_output!(
+			view_data,
+			"{TITLE}",
+			"{LEADING}",
+			"{IndicatorColor}Modifying line: exec foo",
+			"",
+			"{BODY}",
+			"{Normal,Dimmed}exec {Normal}foo{Normal,Underline}",
+			"{TRAILING}",
+			"{IndicatorColor}Enter to finish"
+		);
+	});
+}
+
+#[test]
+fn edit_mode_handle_event() {
+	module_test(
+		&["exec foo"],
+		&[
+			Event::from(MetaEvent::Edit),
+			Event::from(KeyCode::Backspace),
+			Event::from(KeyCode::Enter),
+		],
+		|mut test_context| {
+			let mut module = List::new(&Config::new());
+			let _ = test_context.build_view_data(&mut module);
+			let _ = test_context.handle_all_events(&mut module);
+			assert_eq!(test_context.rebase_todo_file.get_line(0).unwrap().get_content(), "fo");
+			assert_eq!(module.state, ListState::Normal);
+		},
+	);
+}
+
+#[test]
+fn merge_edit_render_labels() {
+	module_test(
+		&["merge -C aaa feature # Merge feature"],
+		&[Event::from(MetaEvent::Edit)],
+		|mut test_context| {
+			let mut module = List::new(&Config::new());
+			let _ = test_context.handle_all_events(&mut module);
+			assert_rendered_output!(
+				test_context.build_view_data(&mut module),
+				"{TITLE}",
+				"{LEADING}",
+				"{IndicatorColor}The labels or commits to merge, separated by spaces, for: merge -C aaa feature # Merge \
+				 feature",
+				"",
+				"{BODY}",
+				"{Normal,Dimmed}Merge: {Normal}feature{Normal,Underline}",
+				"{TRAILING}",
+				"{IndicatorColor}Enter to finish"
+			);
+		},
+	);
+}
+
+#[test]
+fn merge_edit_render_message_commit() {
+	module_test(
+		&["merge -C aaa feature # Merge feature"],
+		&[Event::from(MetaEvent::Edit), Event::from(KeyCode::Enter)],
+		|mut test_context| {
+			let mut module = List::new(&Config::new());
+			let _ = test_context.handle_all_events(&mut module);
+			assert_rendered_output!(
+				test_context.build_view_data(&mut module),
+				"{TITLE}",
+				"{LEADING}",
+				"{IndicatorColor}-C <commit> to use the message of a commit, -c <commit> to also edit it, or nothing for \
+				 the default message",
+				"",
+				"{BODY}",
+				"{Normal,Dimmed}Message: {Normal}-C aaa{Normal,Underline}",
+				"{TRAILING}",
+				"{IndicatorColor}Enter to finish"
+			);
+		},
+	);
+}
+
+#[test]
+fn merge_edit_labels() {
+	let mut events = vec![Event::from(MetaEvent::Edit)];
+	events.extend(" other".chars().map(|character| Event::from(KeyCode::Char(character))));
+	events.push(Event::from(KeyCode::Enter));
+	events.push(Event::from(KeyCode::Enter));
+	module_test(&["merge -C aaa feature # Merge feature"], &events, |mut test_context| {
+		let mut module = List::new(&Config::new());
+		let _ = test_context.handle_all_events(&mut module);
+		assert_eq!(
+			test_context.rebase_todo_file.get_line(0).unwrap().to_text(),
+			"merge -C aaa feature other # Merge feature"
+		);
+		assert_eq!(module.state, ListState::Normal);
+	});
+}
+
+#[test]
+fn merge_edit_message_commit() {
+	let mut events = vec![Event::from(MetaEvent::Edit), Event::from(KeyCode::Enter)];
+	events.extend([Event::from(KeyCode::Backspace); 6]);
+	events.extend("-c bbb".chars().map(|character| Event::from(KeyCode::Char(character))));
+	events.push(Event::from(KeyCode::Enter));
+	module_test(&["merge -C aaa feature"], &events, |mut test_context| {
+		let mut module = List::new(&Config::new());
+		let _ = test_context.handle_all_events(&mut module);
+		assert_eq!(
+			test_context.rebase_todo_file.get_line(0).unwrap().to_text(),
+			"merge -c bbb feature"
+		);
+	});
+}
+
+#[test]
+fn merge_edit_remove_message_commit() {
+	let mut events = vec![Event::from(MetaEvent::Edit), Event::from(KeyCode::Enter)];
+	events.extend([Event::from(KeyCode::Backspace); 6]);
+	events.push(Event::from(KeyCode::Enter));
+	module_test(&["merge -C aaa feature"], &events, |mut test_context| {
+		let mut module = List::new(&Config::new());
+		let _ = test_context.handle_all_events(&mut module);
+		assert_eq!(test_context.rebase_todo_file.get_line(0).unwrap().to_text(), "merge feature");
+	});
+}
+
+#[test]
+fn merge_edit_invalid_label() {
+	module_test(
+		&["merge feature"],
+		&[
+			Event::from(MetaEvent::Edit),
+			Event::from(KeyCode::Home),
+			Event::from(KeyCode::Char('-')),
+			Event::from(KeyCode::Enter),
+		],
+		|mut test_context| {
+			let mut module = List::new(&Config::new());
+			let _ = test_context.handle_all_events(&mut module);
+			assert_eq!(module.state, ListState::MergeLabelsEdit);
+			assert_rendered_output!(
+				test_context.build_view_data(&mut module),
+				"{TITLE}",
+				"{LEADING}",
+				"{IndicatorColor}Invalid merge: Invalid label: -feature",
+				"",
+				"{BODY}",
+				"{Normal,Dimmed}Merge: {Normal}-feature{Normal,Underline}",
+				"{TRAILING}",
+				"{IndicatorColor}Enter to finish"
+			);
+			assert_eq!(test_context.rebase_todo_file.get_line(0).unwrap().to_text(), "merge feature");
+		},
+	);
+}
+
+#[test]
+fn merge_edit_invalid_message_commit() {
+	let mut events = vec![Event::from(MetaEvent::Edit), Event::from(KeyCode::Enter)];
+	events.extend("aaa bbb ccc".chars().map(|character| Event::from(KeyCode::Char(character))));
+	events.push(Event::from(KeyCode::Enter));
+	module_test(&["merge feature"], &events, |mut test_context| {
+		let mut module = List::new(&Config::new());
+		let _ = test_context.handle_all_events(&mut module);
+		assert_eq!(module.state, ListState::MergeCommitEdit);
+		assert_eq!(test_context.rebase_todo_file.get_line(0).unwrap().to_text(), "merge feature");
+	});
+}
+
+#[test]
+fn scroll_right() {
+	module_test(
+		&["pick aaa c1"],
+		&[Event::from(MetaEvent::MoveCursorRight)],
+		|mut test_context| {
+			let mut module = List::new(&Config::new());
+			let _ = test_context.handle_all_events(&mut module);
+			test_context.view_sender_context.assert_render_action(&["ScrollRight"]);
+		},
+	);
+}
+
+#[test]
+fn scroll_left() {
+	module_test(
+		&["pick aaa c1"],
+		&[Event::from(MetaEvent::MoveCursorLeft)],
+		|mut test_context| {
+			let mut module = List::new(&Config::new());
+			let _ = test_context.handle_all_events(&mut module);
+			test_context.view_sender_context.assert_render_action(&["ScrollLeft"]);
+		},
+	);
+}
+
+#[test]
+fn normal_mode_help() {
+	module_test(&["pick aaa c1"], &[Event::from(MetaEvent::Help)], |mut test_context| {
+		let mut module = List::new(&Config::new());
+		module.state = ListState::Normal;
+		let _ = test_context.handle_all_events(&mut module);
+		let view_data = test_context.build_view_data(&mut module);
+		assert_rendered_output!(
+			view_data,
+			"{TITLE}",
+			"{LEADING}",
+			"{Normal,Underline} Key      Action{Normal,Underline}{Pad( )}",
+			"{BODY}",
+			"{IndicatorColor} Up      {Normal,Dimmed}|{Normal}Move selection up",
+			"{IndicatorColor} Down    {Normal,Dimmed}|{Normal}Move selection down",
+			"{IndicatorColor} PageUp  {Normal,Dimmed}|{Normal}Move selection up 5 lines",
+			"{IndicatorColor} PageDown{Normal,Dimmed}|{Normal}Move selection down 5 lines",
+			"{IndicatorColor} Home    {Normal,Dimmed}|{Normal}Move selection to top of the list",
+			"{IndicatorColor} End     {Normal,Dimmed}|{Normal}Move selection to end of the list",
+			"{IndicatorColor} :       {Normal,Dimmed}|{Normal}Move selection to a line by its number",
+			"{IndicatorColor} Left    {Normal,Dimmed}|{Normal}Scroll content to the left",
+			"{IndicatorColor} Right   {Normal,Dimmed}|{Normal}Scroll content to the right",
+			"{IndicatorColor} q       {Normal,Dimmed}|{Normal}Abort interactive rebase",
+			"{IndicatorColor} Q       {Normal,Dimmed}|{Normal}Immediately abort interactive rebase",
+			"{IndicatorColor} D       {Normal,Dimmed}|{Normal}Quit without saving, keeping the original rebase file",
+			"{IndicatorColor} w       {Normal,Dimmed}|{Normal}Write interactive rebase file",
+			"{IndicatorColor} W       {Normal,Dimmed}|{Normal}Immediately write interactive rebase file",
+			"{IndicatorColor} A       {Normal,Dimmed}|{Normal}Abort the stopped rebase (--edit-todo only)",
+			"{IndicatorColor} S       {Normal,Dimmed}|{Normal}Skip the stopped commit (--edit-todo only)",
+			"{IndicatorColor} v       {Normal,Dimmed}|{Normal}Enter visual mode",
+			"{IndicatorColor} ?       {Normal,Dimmed}|{Normal}Show help",
+			"{IndicatorColor} c       {Normal,Dimmed}|{Normal}Show commit information",
+			"{IndicatorColor} j       {Normal,Dimmed}|{Normal}Move selected commit down",
+			"{IndicatorColor} k       {Normal,Dimmed}|{Normal}Move selected commit up",
+			"{IndicatorColor} b       {Normal,Dimmed}|{Normal}Toggle break action",
+			"{IndicatorColor} N       {Normal,Dimmed}|{Normal}Insert a break after every number of commits",
+			"{IndicatorColor} X       {Normal,Dimmed}|{Normal}Remove all of the break lines",
+			"{IndicatorColor} z       {Normal,Dimmed}|{Normal}Collapse or expand the group of lines of the selected line",
+			"{IndicatorColor} n       {Normal,Dimmed}|{Normal}Name the group of lines of the selected line",
+			"{IndicatorColor} Z       {Normal,Dimmed}|{Normal}Collapse all of the groups of lines, or expand all of the collapsed lines",
+			"{IndicatorColor} m       {Normal,Dimmed}|{Normal}Mark or unmark the selected line, to change or move the marked lines together",
+			"{IndicatorColor} U       {Normal,Dimmed}|{Normal}Unmark all of the marked lines",
+			"{IndicatorColor} *       {Normal,Dimmed}|{Normal}Mark the lines of an action, the commits of an author, or the filtered commits",
+			"{IndicatorColor} x       {Normal,Dimmed}|{Normal}Move the selected line, with a preview of its new position",
+			"{IndicatorColor} t       {Normal,Dimmed}|{Normal}Sort the commits of the marked lines",
+			"{IndicatorColor} ~       {Normal,Dimmed}|{Normal}Reverse the order of the marked lines, or of all of the lines",
+			"{IndicatorColor} p       {Normal,Dimmed}|{Normal}Set selected commit to be picked",
+			"{IndicatorColor} r       {Normal,Dimmed}|{Normal}Set selected commit to be reworded",
+			"{IndicatorColor} e       {Normal,Dimmed}|{Normal}Set selected commit to be edited",
+			"{IndicatorColor} s       {Normal,Dimmed}|{Normal}Set selected commit to be squashed",
+			"{IndicatorColor} f       {Normal,Dimmed}|{Normal}Set selected commit to be fixed-up",
+			"{IndicatorColor} C       {Normal,Dimmed}|{Normal}Cycle the option of the selected fixup commit, between none, -C and -c",
+			"{IndicatorColor} d       {Normal,Dimmed}|{Normal}Set selected commit to be dropped",
+			"{IndicatorColor} E       {Normal,Dimmed}|{Normal}Edit an exec action's command",
+			"{IndicatorColor} B       {Normal,Dimmed}|{Normal}Move the selected commit to a new branch",
+			"{IndicatorColor} a       {Normal,Dimmed}|{Normal}Preview the autosquash order of the fixup and squash commits",
+			"{IndicatorColor} F       {Normal,Dimmed}|{Normal}Fix the position or action of the selected fixup or squash commit",
+			"{IndicatorColor} M       {Normal,Dimmed}|{Normal}Show the message of the commit that a chain of squash commits is squashed into",
+			"{IndicatorColor} %       {Normal,Dimmed}|{Normal}Show the statistics of the todo list",
+			"{IndicatorColor} /       {Normal,Dimmed}|{Normal}Show the commits that change a path",
+			"{IndicatorColor} H       {Normal,Dimmed}|{Normal}Show the history of the notifications",
+			"{IndicatorColor} R       {Normal,Dimmed}|{Normal}Show the other commits that change the files of the selected commit",
+			"{IndicatorColor} L       {Normal,Dimmed}|{Normal}Describe the selected line",
+			"{IndicatorColor} I       {Normal,Dimmed}|{Normal}Insert a new line",
+			"{IndicatorColor} T       {Normal,Dimmed}|{Normal}Insert the exec template that tests the selected commit after it",
+			"{IndicatorColor} Delete  {Normal,Dimmed}|{Normal}Completely remove the selected line",
+			"{IndicatorColor} Controlz{Normal,Dimmed}|{Normal}Undo the last change",
+			"{IndicatorColor} Controly{Normal,Dimmed}|{Normal}Redo the previous undone change",
+			"{IndicatorColor} !       {Normal,Dimmed}|{Normal}Open the todo file in the default editor",
+			"{IndicatorColor} i       {Normal,Dimmed}|{Normal}Toggle details on uncommitted changes",
+			"{IndicatorColor} o       {Normal,Dimmed}|{Normal}Open the settings",
+			"{TRAILING}",
+			"{IndicatorColor}Press any key to close"
+		);
+	});
+}
+
+#[test]
+fn normal_mode_help_event() {
+	module_test(
+		&["pick aaa c1"],
+		&[Event::from(MetaEvent::Help), Event::from(MetaEvent::SwapSelectedDown)],
+		|mut test_context| {
+			let mut module = List::new(&Config::new());
+			module.state = ListState::Normal;
+			let _ = test_context.handle_all_events(&mut module);
+			assert!(!module.normal_mode_help.is_active());
+		},
+	);
+}
+
+#[test]
+fn visual_mode_help() {
+	module_test(&["pick aaa c1"], &[Event::from(MetaEvent::Help)], |mut test_context| {
+		let mut module = List::new(&Config::new());
+		module.state = ListState::Visual;
+		let _ = test_context.handle_all_events(&mut module);
+		let view_data = test_context.build_view_data(&mut module);
+		assert_rendered_output!(
+			view_data,
+			"{TITLE}",
+			"{LEADING}",
+			"{Normal,Underline} Key      Action{Normal,Underline}{Pad( )}",
+			"{BODY}",
+			"{IndicatorColor} Up      {Normal,Dimmed}|{Normal}Move selection up",
+			"{IndicatorColor} Down    {Normal,Dimmed}|{Normal}Move selection down",
+			"{IndicatorColor} PageUp  {Normal,Dimmed}|{Normal}Move selection up 5 lines",
+			"{IndicatorColor} PageDown{Normal,Dimmed}|{Normal}Move selection down 5 lines",
+			"{IndicatorColor} Home    {Normal,Dimmed}|{Normal}Move selection to top of the list",
+			"{IndicatorColor} End     {Normal,Dimmed}|{Normal}Move selection to end of the list",
+			"{IndicatorColor} Left    {Normal,Dimmed}|{Normal}Scroll content to the left",
+			"{IndicatorColor} Right   {Normal,Dimmed}|{Normal}Scroll content to the right",
+			"{IndicatorColor} ?       {Normal,Dimmed}|{Normal}Show help",
+			"{IndicatorColor} j       {Normal,Dimmed}|{Normal}Move selected commits down",
+			"{IndicatorColor} k       {Normal,Dimmed}|{Normal}Move selected commits up",
+			"{IndicatorColor} p       {Normal,Dimmed}|{Normal}Set selected commits to be picked",
+			"{IndicatorColor} r       {Normal,Dimmed}|{Normal}Set selected commits to be reworded",
+			"{IndicatorColor} e       {Normal,Dimmed}|{Normal}Set selected commits to be edited",
+			"{IndicatorColor} s       {Normal,Dimmed}|{Normal}Set selected commits to be squashed",
+			"{IndicatorColor} f       {Normal,Dimmed}|{Normal}Set selected commits to be fixed-up",
+			"{IndicatorColor} C       {Normal,Dimmed}|{Normal}Cycle the option of the selected fixup commits, between none, -C and -c",
+			"{IndicatorColor} d       {Normal,Dimmed}|{Normal}Set selected commits to be dropped",
+			"{IndicatorColor} Delete  {Normal,Dimmed}|{Normal}Completely remove the selected lines",
+			"{IndicatorColor} B       {Normal,Dimmed}|{Normal}Move the selected commits to a new branch",
+			"{IndicatorColor} L       {Normal,Dimmed}|{Normal}Describe the selected line",
+			"{IndicatorColor} c       {Normal,Dimmed}|{Normal}Show the combined changes of the selected commits",
+			"{IndicatorColor} z       {Normal,Dimmed}|{Normal}Collapse the selected lines to a single row",
+			"{IndicatorColor} m       {Normal,Dimmed}|{Normal}Mark or unmark the selected lines",
+			"{IndicatorColor} x       {Normal,Dimmed}|{Normal}Move the selected lines, with a preview of their new position",
+			"{IndicatorColor} t       {Normal,Dimmed}|{Normal}Sort the commits of the selected lines",
+			"{IndicatorColor} ~       {Normal,Dimmed}|{Normal}Reverse the order of the selected lines",
+			"{IndicatorColor} U       {Normal,Dimmed}|{Normal}Unmark all of the marked lines",
+			"{IndicatorColor} Controlz{Normal,Dimmed}|{Normal}Undo the last change",
+			"{IndicatorColor} Controly{Normal,Dimmed}|{Normal}Redo the previous undone change",
+			"{IndicatorColor} v       {Normal,Dimmed}|{Normal}Exit visual mode",
+			"{TRAILING}",
+			"{IndicatorColor}Press any key to close"
+		);
+	});
+}
+
+#[test]
+fn visual_mode_help_event() {
+	module_test(
+		&["pick aaa c1"],
+		&[Event::from(MetaEvent::Help), Event::from(MetaEvent::SwapSelectedDown)],
+		|mut test_context| {
+			let mut module = List::new(&Config::new());
+			module.state = ListState::Visual;
+			let _ = test_context.handle_all_events(&mut module);
+			assert!(!module.visual_mode_help.is_active());
+		},
+	);
+}
+
+// this can technically never happen, but it's worth testing, just in case of an invalid state
+#[test]
+fn render_noop_list() {
+	module_test(&["break"], &[], |mut test_context| {
+		let mut module = List::new(&Config::new());
+		test_context.rebase_todo_file.remove_lines(0, 0);
+		test_context.rebase_todo_file.add_line(0, Line::new("noop").unwrap());
+		let view_data = test_context.build_view_data(&mut module);
+		assert_rendered_output!(
+			view_data,
+			"{TITLE}{HELP}",
+			"{BODY}",
+			"{Selected}{Normal} > {Normal}noop   {Normal}{Pad( )}"
+		);
+	});
+}
+
+#[test]
+fn resize() {
+	module_test(&["pick aaa c1"], &[Event::Resize(100, 200)], |mut test_context| {
+		let mut module = List::new(&Config::new());
+		let _ = test_context.handle_all_events(&mut module);
+		assert_eq!(module.height, 200);
+	});
+}
+
+#[test]
+fn normal_mode_branch_out() {
+	module_test(
+		&["pick aaa c1", "pick bbb c2"],
+		&[Event::from(MetaEvent::MoveCursorDown), Event::from(MetaEvent::BranchOut)],
+		|mut test_context| {
+			let mut module = List::new(&Config::new());
+			let _ = test_context.handle_all_events(&mut module);
+			assert_eq!(
+				test_context
+					.rebase_todo_file
+					.get_lines_owned()
+					.iter()
+					.map(Line::to_text)
+					.collect::<Vec<String>>(),
+				vec![
+					"pick aaa c1",
+					"label branch-1-base",
+					"pick bbb c2",
+					"label branch-1",
+					"reset branch-1-base",
+					"merge branch-1 # Merge branch-1",
+				]
+			);
+			assert_eq!(test_context.rebase_todo_file.get_selected_line_index(), 2);
+		},
+	);
+}
+
+#[test]
+fn normal_mode_branch_out_without_commit() {
+	module_test(
+		&["pick aaa c1", "exec make"],
+		&[Event::from(MetaEvent::MoveCursorDown), Event::from(MetaEvent::BranchOut)],
+		|mut test_context| {
+			let mut module = List::new(&Config::new());
+			let _ = test_context.handle_all_events(&mut module);
+			assert_eq!(test_context.rebase_todo_file.get_lines_owned().len(), 2);
+			assert_eq!(test_context.rebase_todo_file.get_selected_line_index(), 1);
+		},
+	);
+}
+
+#[test]
+fn visual_mode_branch_out() {
+	module_test(
+		&["pick aaa c1", "pick bbb c2", "pick ccc c3"],
+		&[
+			Event::from(MetaEvent::ToggleVisualMode),
+			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::BranchOut),
+		],
+		|mut test_context| {
+			let mut module = List::new(&Config::new());
+			let _ = test_context.handle_all_events(&mut module);
+			let view_data = test_context.build_view_data(&mut module);
+			assert_rendered_output!(
+				view_data,
+				"{TITLE}{HELP}",
+				"{BODY}",
+				"{Normal}   {ActionLabel}label  {Normal,Dimmed}|   {Normal}branch-1-base{ActionLabel,Dimmed}{Pad(─)}",
+				"{Selected}{Normal,Dimmed} > {ActionPick}pick   {Normal}aaa      {Normal,Dimmed}*   {Normal}c1{Normal}{Pad( )}",
+				"{Selected}{Normal} > {ActionPick}pick   {Normal}bbb      {Normal,Dimmed}*   {Normal}c2{Normal}{Pad( )}",
+				"{Normal}   {ActionLabel}label  {Normal,Dimmed}|   {Normal}branch-1{ActionLabel,Dimmed}{Pad(─)}",
+				"{Normal}   {ActionReset}reset  {Normal,Dimmed}|-. {Normal}branch-1-base",
+				"{Normal}   {ActionMerge}merge  {Normal,Dimmed}'-* {Normal}branch-1 # Merge branch-1",
+				"{Normal}   {ActionPick}pick   {Normal}ccc      {Normal,Dimmed}  * {Normal}c3"
+			);
+		},
+	);
+}
+
+#[test]
+fn describe_line() {
+	module_test(
+		&["pick aaaaaaaaaaaaaaaa comment 1", "exec echo foo"],
+		&[Event::from(MetaEvent::DescribeLine)],
+		|mut test_context| {
+			let mut module = List::new(&Config::new());
+			let _ = test_context.handle_all_events(&mut module);
+			let view_data = test_context.build_view_data(&mut module);
+			assert_rendered_output!(
+				view_data,
+				"{TITLE}{HELP}",
+				"{LEADING}",
+				"{Normal}Line 1 of 2: pick aaaaaaaaaaaaaaaa comment 1",
+				"{BODY}",
+				"{Selected}{Normal} > {ActionPick}pick   {Normal}aaaaaaaa {Normal}comment 1{Normal}{Pad( )}",
+				"{Normal}   {ActionExec}exec   {Normal}echo foo"
+			);
+		},
+	);
+}
+
+#[test]
+fn describe_line_cleared_on_next_event() {
+	module_test(
+		&["pick aaa comment 1", "exec echo foo"],
+		&[
+			Event::from(MetaEvent::DescribeLine),
+			Event::from(MetaEvent::MoveCursorDown),
+		],
+		|mut test_context| {
+			let mut module = List::new(&Config::new());
+			let _ = test_context.handle_all_events(&mut module);
+			assert!(module.announcement.is_none());
 		},
 	);
 }
 
 #[test]
-fn normal_mode_other_event() {
-	module_test(&["pick aaa c1"], &[Event::from(KeyCode::Null)], |mut test_context| {
+fn describe_line_empty() {
+	module_test(&[], &[Event::from(MetaEvent::DescribeLine)], |mut test_context| {
+		let mut module = List::new(&Config::new());
+		let _ = test_context.handle_all_events(&mut module);
+		assert_eq!(module.announcement.as_deref(), Some("Rebase todo file is empty"));
+	});
+}
+
+#[test]
+fn screen_reader_announce_selected_line_change() {
+	module_test(
+		&["pick aaa comment 1", "exec echo foo"],
+		&[Event::from(MetaEvent::MoveCursorDown)],
+		|mut test_context| {
+			let mut config = Config::new();
+			config.screen_reader = true;
+			let mut module = List::new(&config);
+			let _ = test_context.handle_all_events(&mut module);
+			assert_eq!(module.announcement.as_deref(), Some("Line 2 of 2: exec echo foo"));
+		},
+	);
+}
+
+#[test]
+fn screen_reader_announce_action_change() {
+	module_test(
+		&["pick aaa comment 1", "exec echo foo"],
+		&[Event::from(MetaEvent::ActionDrop)],
+		|mut test_context| {
+			let mut config = Config::new();
+			config.screen_reader = true;
+			let mut module = List::new(&config);
+			let _ = test_context.handle_all_events(&mut module);
+			assert_eq!(module.announcement.as_deref(), Some("Line 1 of 2: drop aaa comment 1"));
+		},
+	);
+}
+
+#[test]
+fn screen_reader_announcement_kept_without_change() {
+	module_test(
+		&["pick aaa comment 1", "exec echo foo"],
+		&[
+			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::MoveCursorDown),
+		],
+		|mut test_context| {
+			let mut config = Config::new();
+			config.screen_reader = true;
+			let mut module = List::new(&config);
+			let _ = test_context.handle_all_events(&mut module);
+			assert_eq!(module.announcement.as_deref(), Some("Line 2 of 2: exec echo foo"));
+		},
+	);
+}
+
+#[test]
+fn screen_reader_visual_mode() {
+	module_test(
+		&["pick aaa c1", "pick aaa c2", "pick aaa c3"],
+		&[
+			Event::from(MetaEvent::ToggleVisualMode),
+			Event::from(MetaEvent::MoveCursorDown),
+		],
+		|mut test_context| {
+			let mut config = Config::new();
+			config.screen_reader = true;
+			let mut module = List::new(&config);
+			let _ = test_context.handle_all_events(&mut module);
+			let view_data = test_context.build_view_data(&mut module);
+			assert_rendered_output!(
+				view_data,
+				"{TITLE}{HELP}",
+				"{LEADING}",
+				"{Normal}Visual mode, 2 lines selected. Line 2 of 3: pick aaa c2",
+				"{BODY}",
+				"{Selected}{Normal,Dimmed} + {ActionPick}pick   {Normal}aaa      {Normal}c1{Normal}{Pad( )}",
+				"{Selected}{Normal} > {ActionPick}pick   {Normal}aaa      {Normal}c2{Normal}{Pad( )}",
+				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c3"
+			);
+		},
+	);
+}
+
+fn test_commit_config(test_commit_break: bool) -> Config {
+	let mut config = Config::new();
+	config.exec_templates = vec![ExecTemplate {
+		name: String::from("test"),
+		command: String::from("cargo test # {subject}"),
+	}];
+	config.test_commit_break = test_commit_break;
+	config
+}
+
+#[test]
+fn normal_mode_test_commit() {
+	module_test(
+		&["pick aaa c1", "pick bbb c2"],
+		&[Event::from(MetaEvent::TestCommit)],
+		|mut test_context| {
+			let mut module = List::new(&test_commit_config(false));
+			let _ = test_context.handle_all_events(&mut module);
+			assert_eq!(
+				test_context
+					.rebase_todo_file
+					.get_lines_owned()
+					.iter()
+					.map(Line::to_text)
+					.collect::<Vec<String>>(),
+				vec!["pick aaa c1", "exec cargo test # c1", "pick bbb c2"]
+			);
+			assert_eq!(test_context.rebase_todo_file.get_selected_line_index(), 1);
+		},
+	);
+}
+
+#[test]
+fn normal_mode_test_commit_with_break() {
+	module_test(
+		&["pick aaa c1", "pick bbb c2"],
+		&[Event::from(MetaEvent::MoveCursorDown), Event::from(MetaEvent::TestCommit)],
+		|mut test_context| {
+			let mut module = List::new(&test_commit_config(true));
+			let _ = test_context.handle_all_events(&mut module);
+			assert_eq!(
+				test_context
+					.rebase_todo_file
+					.get_lines_owned()
+					.iter()
+					.map(Line::to_text)
+					.collect::<Vec<String>>(),
+				vec!["pick aaa c1", "pick bbb c2", "exec cargo test # c2", "break"]
+			);
+			assert_eq!(test_context.rebase_todo_file.get_selected_line_index(), 3);
+			let _ = test_context.rebase_todo_file.undo();
+			assert_eq!(test_context.rebase_todo_file.get_lines_owned().len(), 2);
+		},
+	);
+}
+
+#[test]
+fn normal_mode_test_commit_template_name_case_insensitive() {
+	module_test(&["pick aaa c1"], &[Event::from(MetaEvent::TestCommit)], |mut test_context| {
+		let mut config = test_commit_config(false);
+		config.test_commit_template = String::from("Test");
+		let mut module = List::new(&config);
+		let _ = test_context.handle_all_events(&mut module);
+		assert_eq!(test_context.rebase_todo_file.get_lines_owned().len(), 2);
+	});
+}
+
+#[rstest]
+#[case::exec("exec make")]
+#[case::drop("drop aaa c1")]
+#[case::merge("merge -C aaa side")]
+fn normal_mode_test_commit_not_a_commit(#[case] line: &str) {
+	module_test(&[line], &[Event::from(MetaEvent::TestCommit)], |mut test_context| {
+		let mut module = List::new(&test_commit_config(false));
+		let _ = test_context.handle_all_events(&mut module);
+		assert_eq!(test_context.rebase_todo_file.get_lines_owned().len(), 1);
+	});
+}
+
+#[test]
+fn normal_mode_test_commit_without_template() {
+	module_test(&["pick aaa c1"], &[Event::from(MetaEvent::TestCommit)], |mut test_context| {
+		let mut module = List::new(&Config::new());
+		assert_process_result!(
+			test_context.handle_event(&mut module),
+			event = Event::from(MetaEvent::TestCommit),
+			error = anyhow!("No exec template named test is configured to test a commit")
+		);
+		assert_eq!(test_context.rebase_todo_file.get_lines_owned().len(), 1);
+	});
+}
+
+fn todo_lines(todo_file: &TodoFile) -> Vec<String> {
+	todo_file
+		.get_lines_owned()
+		.iter()
+		.map(Line::to_text)
+		.collect()
+}
+
+#[test]
+fn normal_mode_remove_breaks() {
+	module_test(
+		&["pick aaa c1", "break", "pick bbb c2", "break", "pick ccc c3"],
+		&[
+			Event::from(MetaEvent::MoveCursorEnd),
+			Event::from(MetaEvent::RemoveBreaks),
+		],
+		|mut test_context| {
+			let mut module = List::new(&Config::new());
+			let results = test_context.handle_all_events(&mut module);
+			assert_eq!(todo_lines(&test_context.rebase_todo_file), vec!["pick aaa c1", "pick bbb c2", "pick ccc c3"]);
+			assert_eq!(test_context.rebase_todo_file.get_selected_line_index(), 2);
+			assert_eq!(results.last().unwrap().notification, Some(String::from("Removed breaks: 2")));
+		},
+	);
+}
+
+#[test]
+fn normal_mode_remove_breaks_without_breaks() {
+	module_test(&["pick aaa c1", "pick bbb c2"], &[Event::from(MetaEvent::RemoveBreaks)], |mut test_context| {
+		let mut module = List::new(&Config::new());
+		let result = test_context.handle_event(&mut module);
+		assert!(result.notification.is_none());
+	});
+}
+
+#[test]
+fn normal_mode_show_notifications() {
+	module_test(&["pick aaa c1"], &[Event::from(MetaEvent::ShowNotifications)], |mut test_context| {
+		let mut module = List::new(&Config::new());
+		assert_process_result!(
+			test_context.handle_event(&mut module),
+			event = Event::from(MetaEvent::ShowNotifications),
+			state = State::NotificationHistory
+		);
+	});
+}
+
+#[test]
+fn normal_mode_break_every() {
+	module_test(&["pick aaa c1"], &[Event::from(MetaEvent::BreakEvery)], |mut test_context| {
 		let mut module = List::new(&Config::new());
-		assert_process_result!(
-			test_context.handle_event(&mut module),
-			event = Event::from(KeyCode::Null)
+		let _ = test_context.handle_all_events(&mut module);
+		assert_eq!(module.state, ListState::BreakEveryEdit);
+		assert_rendered_output!(
+			test_context.build_view_data(&mut module),
+			"{TITLE}",
+			"{LEADING}",
+			"{IndicatorColor}Insert a break after every number of commits, counted from the start and from each break",
+			"",
+			"{BODY}",
+			"{Normal,Dimmed}Commits: {Normal,Underline}",
+			"{TRAILING}",
+			"{IndicatorColor}Up and down to change, enter to finish"
 		);
 	});
 }
 
 #[test]
-fn visual_mode_action_change_top_bottom() {
+fn break_every_number() {
 	module_test(
-		&["pick aaa c1", "pick aaa c2", "pick aaa c3"],
+		&["pick aaa c1", "pick bbb c2", "pick ccc c3", "pick ddd c4", "pick eee c5"],
 		&[
-			Event::from(MetaEvent::ToggleVisualMode),
-			Event::from(MetaEvent::MoveCursorDown),
-			Event::from(MetaEvent::MoveCursorDown),
-			Event::from(MetaEvent::ActionReword),
+			Event::from(MetaEvent::MoveCursorEnd),
+			Event::from(MetaEvent::BreakEvery),
+			Event::from(KeyCode::Char('2')),
+			Event::from(KeyCode::Enter),
 		],
 		|mut test_context| {
 			let mut module = List::new(&Config::new());
 			let _ = test_context.handle_all_events(&mut module);
-			let view_data = test_context.build_view_data(&mut module);
-			assert_rendered_output!(
-				view_data,
-				"{TITLE}{HELP}",
-				"{BODY}",
-				"{Selected}{Normal,Dimmed} > {ActionReword}reword {Normal}aaa      {Normal}c1{Normal}{Pad( )}",
-				"{Selected}{Normal,Dimmed} > {ActionReword}reword {Normal}aaa      {Normal}c2{Normal}{Pad( )}",
-				"{Selected}{Normal} > {ActionReword}reword {Normal}aaa      {Normal}c3{Normal}{Pad( )}"
-			);
+			assert_eq!(module.state, ListState::Normal);
+			assert_eq!(todo_lines(&test_context.rebase_todo_file), vec![
+				"pick aaa c1",
+				"pick bbb c2",
+				"break",
+				"pick ccc c3",
+				"pick ddd c4",
+				"break",
+				"pick eee c5"
+			]);
+			assert_eq!(test_context.rebase_todo_file.get_selected_line_index(), 6);
 		},
 	);
 }
 
 #[test]
-fn visual_mode_action_change_bottom_top() {
+fn break_every_invalid_number() {
 	module_test(
-		&["pick aaa c1", "pick aaa c2", "pick aaa c3"],
+		&["pick aaa c1", "pick bbb c2"],
 		&[
-			Event::from(MetaEvent::MoveCursorDown),
-			Event::from(MetaEvent::MoveCursorDown),
-			Event::from(MetaEvent::ToggleVisualMode),
-			Event::from(MetaEvent::MoveCursorUp),
-			Event::from(MetaEvent::MoveCursorUp),
-			Event::from(MetaEvent::ActionReword),
+			Event::from(MetaEvent::BreakEvery),
+			Event::from(KeyCode::Char('0')),
+			Event::from(KeyCode::Enter),
 		],
 		|mut test_context| {
 			let mut module = List::new(&Config::new());
 			let _ = test_context.handle_all_events(&mut module);
-			let view_data = test_context.build_view_data(&mut module);
+			assert_eq!(module.state, ListState::BreakEveryEdit);
 			assert_rendered_output!(
-				view_data,
-				"{TITLE}{HELP}",
+				test_context.build_view_data(&mut module),
+				"{TITLE}",
+				"{LEADING}",
+				"{IndicatorColor}A number from 1 to 2 is needed",
+				"",
 				"{BODY}",
-				"{Selected}{Normal} > {ActionReword}reword {Normal}aaa      {Normal}c1{Normal}{Pad( )}",
-				"{Selected}{Normal,Dimmed} > {ActionReword}reword {Normal}aaa      {Normal}c2{Normal}{Pad( )}",
-				"{Selected}{Normal,Dimmed} > {ActionReword}reword {Normal}aaa      {Normal}c3{Normal}{Pad( )}"
+				"{Normal,Dimmed}Commits: {Normal}0{Normal,Underline}",
+				"{TRAILING}",
+				"{IndicatorColor}Up and down to change, enter to finish"
 			);
+			assert_eq!(todo_lines(&test_context.rebase_todo_file), vec!["pick aaa c1", "pick bbb c2"]);
 		},
 	);
 }
 
 #[test]
-fn visual_mode_action_change_drop() {
+fn break_every_empty_number() {
 	module_test(
-		&[
-			"pick aaa c1",
-			"pick aaa c2",
-			"pick aaa c3",
-			"pick aaa c4",
-			"pick aaa c5",
-		],
-		&[
-			Event::from(MetaEvent::MoveCursorDown),
-			Event::from(MetaEvent::ToggleVisualMode),
-			Event::from(MetaEvent::MoveCursorDown),
-			Event::from(MetaEvent::MoveCursorDown),
-			Event::from(MetaEvent::ActionDrop),
-		],
+		&["pick aaa c1", "pick bbb c2"],
+		&[Event::from(MetaEvent::BreakEvery), Event::from(KeyCode::Enter)],
 		|mut test_context| {
 			let mut module = List::new(&Config::new());
 			let _ = test_context.handle_all_events(&mut module);
-			let view_data = test_context.build_view_data(&mut module);
-			assert_rendered_output!(
-				view_data,
-				"{TITLE}{HELP}",
-				"{BODY}",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c1",
-				"{Selected}{Normal,Dimmed} > {ActionDrop}drop   {Normal}aaa      {Normal}c2{Normal}{Pad( )}",
-				"{Selected}{Normal,Dimmed} > {ActionDrop}drop   {Normal}aaa      {Normal}c3{Normal}{Pad( )}",
-				"{Selected}{Normal} > {ActionDrop}drop   {Normal}aaa      {Normal}c4{Normal}{Pad( )}",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c5"
-			);
+			assert_eq!(module.state, ListState::Normal);
+			assert_eq!(todo_lines(&test_context.rebase_todo_file), vec!["pick aaa c1", "pick bbb c2"]);
 		},
 	);
 }
 
 #[test]
-fn visual_mode_action_change_edit() {
+fn break_every_number_from_up() {
 	module_test(
+		&["pick aaa c1", "pick bbb c2", "pick ccc c3"],
 		&[
-			"pick aaa c1",
-			"pick aaa c2",
-			"pick aaa c3",
-			"pick aaa c4",
-			"pick aaa c5",
+			Event::from(MetaEvent::BreakEvery),
+			Event::from(KeyCode::Up),
+			Event::from(KeyCode::Up),
+			Event::from(KeyCode::Enter),
 		],
+		|mut test_context| {
+			let mut module = List::new(&Config::new());
+			let _ = test_context.handle_all_events(&mut module);
+			assert_eq!(todo_lines(&test_context.rebase_todo_file), vec![
+				"pick aaa c1",
+				"pick bbb c2",
+				"break",
+				"pick ccc c3"
+			]);
+		},
+	);
+}
+
+#[test]
+fn normal_mode_jump_to_line() {
+	module_test(&["pick aaa c1"], &[Event::from(MetaEvent::JumpToLine)], |mut test_context| {
+		let mut module = List::new(&Config::new());
+		let _ = test_context.handle_all_events(&mut module);
+		assert_eq!(module.state, ListState::JumpToLineEdit);
+		assert_rendered_output!(
+			test_context.build_view_data(&mut module),
+			"{TITLE}",
+			"{LEADING}",
+			"{IndicatorColor}The number of the line to select",
+			"",
+			"{BODY}",
+			"{Normal,Dimmed}Line: {Normal,Underline}",
+			"{TRAILING}",
+			"{IndicatorColor}Up and down to change, enter to finish"
+		);
+	});
+}
+
+#[test]
+fn jump_to_line_number() {
+	module_test(
+		&["pick aaa c1", "pick bbb c2", "pick ccc c3", "pick ddd c4"],
 		&[
-			Event::from(MetaEvent::MoveCursorDown),
-			Event::from(MetaEvent::ToggleVisualMode),
-			Event::from(MetaEvent::MoveCursorDown),
-			Event::from(MetaEvent::MoveCursorDown),
-			Event::from(MetaEvent::ActionEdit),
+			Event::from(MetaEvent::JumpToLine),
+			Event::from(KeyCode::Char('3')),
+			Event::from(KeyCode::Enter),
 		],
 		|mut test_context| {
 			let mut module = List::new(&Config::new());
 			let _ = test_context.handle_all_events(&mut module);
-			let view_data = test_context.build_view_data(&mut module);
-			assert_rendered_output!(
-				view_data,
-				"{TITLE}{HELP}",
-				"{BODY}",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c1",
-				"{Selected}{Normal,Dimmed} > {ActionEdit}edit   {Normal}aaa      {Normal}c2{Normal}{Pad( )}",
-				"{Selected}{Normal,Dimmed} > {ActionEdit}edit   {Normal}aaa      {Normal}c3{Normal}{Pad( )}",
-				"{Selected}{Normal} > {ActionEdit}edit   {Normal}aaa      {Normal}c4{Normal}{Pad( )}",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c5"
-			);
+			assert_eq!(module.state, ListState::Normal);
+			assert_eq!(test_context.rebase_todo_file.get_selected_line_index(), 2);
 		},
 	);
 }
 
 #[test]
-fn visual_mode_action_change_fixup() {
+fn jump_to_line_past_end() {
 	module_test(
+		&["pick aaa c1", "pick bbb c2"],
 		&[
-			"pick aaa c1",
-			"pick aaa c2",
-			"pick aaa c3",
-			"pick aaa c4",
-			"pick aaa c5",
+			Event::from(MetaEvent::JumpToLine),
+			Event::from(KeyCode::Char('5')),
+			Event::from(KeyCode::Enter),
 		],
+		|mut test_context| {
+			let mut module = List::new(&Config::new());
+			let _ = test_context.handle_all_events(&mut module);
+			assert_eq!(module.state, ListState::JumpToLineEdit);
+			assert_eq!(test_context.rebase_todo_file.get_selected_line_index(), 0);
+		},
+	);
+}
+
+#[test]
+fn jump_to_line_empty() {
+	module_test(
+		&["pick aaa c1", "pick bbb c2"],
 		&[
 			Event::from(MetaEvent::MoveCursorDown),
-			Event::from(MetaEvent::ToggleVisualMode),
-			Event::from(MetaEvent::MoveCursorDown),
-			Event::from(MetaEvent::MoveCursorDown),
-			Event::from(MetaEvent::ActionFixup),
+			Event::from(MetaEvent::JumpToLine),
+			Event::from(KeyCode::Enter),
 		],
 		|mut test_context| {
 			let mut module = List::new(&Config::new());
 			let _ = test_context.handle_all_events(&mut module);
-			let view_data = test_context.build_view_data(&mut module);
-			assert_rendered_output!(
-				view_data,
-				"{TITLE}{HELP}",
-				"{BODY}",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c1",
-				"{Selected}{Normal,Dimmed} > {ActionFixup}fixup  {Normal}aaa      {Normal}c2{Normal}{Pad( )}",
-				"{Selected}{Normal,Dimmed} > {ActionFixup}fixup  {Normal}aaa      {Normal}c3{Normal}{Pad( )}",
-				"{Selected}{Normal} > {ActionFixup}fixup  {Normal}aaa      {Normal}c4{Normal}{Pad( )}",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c5"
-			);
+			assert_eq!(module.state, ListState::Normal);
+			assert_eq!(test_context.rebase_todo_file.get_selected_line_index(), 1);
 		},
 	);
 }
 
 #[test]
-fn visual_mode_action_change_pick() {
+fn render_break_with_screen_reader() {
+	module_test(&["pick aaa c1", "break"], &[], |test_context| {
+		let mut config = Config::new();
+		config.screen_reader = true;
+		let mut module = List::new(&config);
+		assert_rendered_output!(
+			test_context.build_view_data(&mut module),
+			"{TITLE}{HELP}",
+			"{BODY}",
+			render_line!(AnyLine),
+			"{Normal}   {ActionBreak}break"
+		);
+	});
+}
+
+#[test]
+fn toggle_mark_render() {
 	module_test(
+		&["pick aaa c1", "pick bbb c2", "pick ccc c3"],
 		&[
-			"drop aaa c1",
-			"drop aaa c2",
-			"drop aaa c3",
-			"drop aaa c4",
-			"drop aaa c5",
-		],
-		&[
-			Event::from(MetaEvent::MoveCursorDown),
-			Event::from(MetaEvent::ToggleVisualMode),
-			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::ToggleMark),
 			Event::from(MetaEvent::MoveCursorDown),
-			Event::from(MetaEvent::ActionPick),
 		],
 		|mut test_context| {
 			let mut module = List::new(&Config::new());
 			let _ = test_context.handle_all_events(&mut module);
-			let view_data = test_context.build_view_data(&mut module);
 			assert_rendered_output!(
-				view_data,
+				test_context.build_view_data(&mut module),
 				"{TITLE}{HELP}",
 				"{BODY}",
-				"{Normal}   {ActionDrop}drop   {Normal}aaa      {Normal}c1",
-				"{Selected}{Normal,Dimmed} > {ActionPick}pick   {Normal}aaa      {Normal}c2{Normal}{Pad( )}",
-				"{Selected}{Normal,Dimmed} > {ActionPick}pick   {Normal}aaa      {Normal}c3{Normal}{Pad( )}",
-				"{Selected}{Normal} > {ActionPick}pick   {Normal}aaa      {Normal}c4{Normal}{Pad( )}",
-				"{Normal}   {ActionDrop}drop   {Normal}aaa      {Normal}c5"
+				"{Selected}{Normal,Dimmed} > {ActionPick}pick   {Normal}aaa      {Normal}c1{Normal}{Pad( )}",
+				"{Selected}{Normal} > {ActionPick}pick   {Normal}bbb      {Normal}c2{Normal}{Pad( )}",
+				"{Normal}   {ActionPick}pick   {Normal}ccc      {Normal}c3"
 			);
 		},
 	);
 }
 
 #[test]
-fn visual_mode_action_change_reword() {
+fn toggle_mark_unmark() {
 	module_test(
-		&[
-			"pick aaa c1",
-			"pick aaa c2",
-			"pick aaa c3",
-			"pick aaa c4",
-			"pick aaa c5",
-		],
+		&["pick aaa c1", "pick bbb c2"],
+		&[Event::from(MetaEvent::ToggleMark), Event::from(MetaEvent::ToggleMark)],
+		|mut test_context| {
+			let mut module = List::new(&Config::new());
+			let _ = test_context.handle_all_events(&mut module);
+			assert!(module.marks.is_empty());
+		},
+	);
+}
+
+#[test]
+fn toggle_mark_visual_mode() {
+	module_test(
+		&["pick aaa c1", "pick bbb c2", "pick ccc c3", "pick ddd c4"],
 		&[
 			Event::from(MetaEvent::MoveCursorDown),
 			Event::from(MetaEvent::ToggleVisualMode),
 			Event::from(MetaEvent::MoveCursorDown),
-			Event::from(MetaEvent::MoveCursorDown),
-			Event::from(MetaEvent::ActionReword),
+			Event::from(MetaEvent::ToggleMark),
 		],
 		|mut test_context| {
 			let mut module = List::new(&Config::new());
 			let _ = test_context.handle_all_events(&mut module);
-			let view_data = test_context.build_view_data(&mut module);
-			assert_rendered_output!(
-				view_data,
-				"{TITLE}{HELP}",
-				"{BODY}",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c1",
-				"{Selected}{Normal,Dimmed} > {ActionReword}reword {Normal}aaa      {Normal}c2{Normal}{Pad( )}",
-				"{Selected}{Normal,Dimmed} > {ActionReword}reword {Normal}aaa      {Normal}c3{Normal}{Pad( )}",
-				"{Selected}{Normal} > {ActionReword}reword {Normal}aaa      {Normal}c4{Normal}{Pad( )}",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c5"
-			);
+			assert_eq!(module.marks.indexes(), &[1, 2]);
+			assert_eq!(module.state, ListState::Normal);
 		},
 	);
 }
 
 #[test]
-fn visual_mode_action_change_squash() {
+fn marked_lines_set_action() {
 	module_test(
+		&["pick aaa c1", "pick bbb c2", "pick ccc c3"],
 		&[
-			"pick aaa c1",
-			"pick aaa c2",
-			"pick aaa c3",
-			"pick aaa c4",
-			"pick aaa c5",
+			Event::from(MetaEvent::ToggleMark),
+			Event::from(MetaEvent::MoveCursorEnd),
+			Event::from(MetaEvent::ToggleMark),
+			Event::from(MetaEvent::MoveCursorUp),
+			Event::from(MetaEvent::ActionDrop),
 		],
+		|mut test_context| {
+			let mut module = List::new(&Config::new());
+			let _ = test_context.handle_all_events(&mut module);
+			assert_eq!(todo_lines(&test_context.rebase_todo_file), vec!["drop aaa c1", "pick bbb c2", "drop ccc c3"]);
+			assert_eq!(test_context.rebase_todo_file.get_selected_line_index(), 1);
+		},
+	);
+}
+
+#[test]
+fn marked_lines_move_down() {
+	module_test(
+		&["pick aaa c1", "pick bbb c2", "pick ccc c3", "pick ddd c4"],
 		&[
-			Event::from(MetaEvent::MoveCursorDown),
-			Event::from(MetaEvent::ToggleVisualMode),
+			Event::from(MetaEvent::ToggleMark),
 			Event::from(MetaEvent::MoveCursorDown),
 			Event::from(MetaEvent::MoveCursorDown),
-			Event::from(MetaEvent::ActionSquash),
+			Event::from(MetaEvent::ToggleMark),
+			Event::from(MetaEvent::SwapSelectedDown),
 		],
-		|mut test_context| {
-			let mut module = List::new(&Config::new());
-			let _ = test_context.handle_all_events(&mut module);
-			let view_data = test_context.build_view_data(&mut module);
-			assert_rendered_output!(
-				view_data,
-				"{TITLE}{HELP}",
-				"{BODY}",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c1",
-				"{Selected}{Normal,Dimmed} > {ActionSquash}squash {Normal}aaa      {Normal}c2{Normal}{Pad( )}",
-				"{Selected}{Normal,Dimmed} > {ActionSquash}squash {Normal}aaa      {Normal}c3{Normal}{Pad( )}",
-				"{Selected}{Normal} > {ActionSquash}squash {Normal}aaa      {Normal}c4{Normal}{Pad( )}",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c5"
-			);
+		|mut test_context| {
+			let mut module = List::new(&Config::new());
+			let _ = test_context.handle_all_events(&mut module);
+			assert_eq!(todo_lines(&test_context.rebase_todo_file), vec![
+				"pick bbb c2",
+				"pick aaa c1",
+				"pick ddd c4",
+				"pick ccc c3"
+			]);
+			assert_eq!(module.marks.indexes(), &[1, 3]);
+			assert_eq!(test_context.rebase_todo_file.get_selected_line_index(), 3);
 		},
 	);
 }
 
 #[test]
-fn visual_mode_abort() {
+fn marked_lines_move_up() {
 	module_test(
-		&["pick aaa c1"],
-		&[Event::from(MetaEvent::ToggleVisualMode), Event::from(MetaEvent::Abort)],
+		&["pick aaa c1", "pick bbb c2", "pick ccc c3"],
+		&[
+			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::ToggleMark),
+			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::SwapSelectedUp),
+		],
 		|mut test_context| {
 			let mut module = List::new(&Config::new());
-			let _ = test_context.handle_event(&mut module);
-			assert_process_result!(
-				test_context.handle_event(&mut module),
-				event = Event::from(MetaEvent::Abort),
-				state = State::ConfirmAbort
-			);
+			let _ = test_context.handle_all_events(&mut module);
+			assert_eq!(todo_lines(&test_context.rebase_todo_file), vec!["pick bbb c2", "pick aaa c1", "pick ccc c3"]);
+			assert_eq!(module.marks.indexes(), &[0]);
+			assert_eq!(test_context.rebase_todo_file.get_selected_line_index(), 2);
 		},
 	);
 }
 
 #[test]
-fn visual_mode_force_abort() {
+fn marked_lines_move_up_first_line() {
 	module_test(
-		&["pick aaa c1"],
-		&[
-			Event::from(MetaEvent::ToggleVisualMode),
-			Event::from(MetaEvent::ForceAbort),
-		],
+		&["pick aaa c1", "pick bbb c2"],
+		&[Event::from(MetaEvent::ToggleMark), Event::from(MetaEvent::SwapSelectedUp)],
 		|mut test_context| {
 			let mut module = List::new(&Config::new());
-			let _ = test_context.handle_event(&mut module);
-			assert_process_result!(
-				test_context.handle_event(&mut module),
-				event = Event::from(MetaEvent::ForceAbort),
-				exit_status = ExitStatus::Good
-			);
-			assert!(test_context.rebase_todo_file.is_empty());
+			let _ = test_context.handle_all_events(&mut module);
+			assert_eq!(todo_lines(&test_context.rebase_todo_file), vec!["pick aaa c1", "pick bbb c2"]);
+			assert_eq!(module.marks.indexes(), &[0]);
 		},
 	);
 }
 
 #[test]
-fn visual_mode_rebase() {
+fn clear_marks() {
 	module_test(
-		&["pick aaa c1"],
-		&[Event::from(MetaEvent::ToggleVisualMode), Event::from(MetaEvent::Rebase)],
+		&["pick aaa c1", "pick bbb c2"],
+		&[Event::from(MetaEvent::ToggleMark), Event::from(MetaEvent::ClearMarks)],
 		|mut test_context| {
 			let mut module = List::new(&Config::new());
-			let _ = test_context.handle_event(&mut module);
-			assert_process_result!(
-				test_context.handle_event(&mut module),
-				event = Event::from(MetaEvent::Rebase),
-				state = State::ConfirmRebase
-			);
+			let _ = test_context.handle_all_events(&mut module);
+			assert!(module.marks.is_empty());
 		},
 	);
 }
 
 #[test]
-fn visual_mode_force_rebase() {
+fn marks_cleared_when_lines_change() {
 	module_test(
-		&["pick aaa c1"],
+		&["pick aaa c1", "pick bbb c2", "pick ccc c3"],
 		&[
-			Event::from(MetaEvent::ToggleVisualMode),
-			Event::from(MetaEvent::ForceRebase),
+			Event::from(MetaEvent::ToggleMark),
+			Event::from(MetaEvent::MoveCursorEnd),
+			Event::from(MetaEvent::Delete),
 		],
 		|mut test_context| {
 			let mut module = List::new(&Config::new());
-			let _ = test_context.handle_event(&mut module);
-			assert_process_result!(
-				test_context.handle_event(&mut module),
-				event = Event::from(MetaEvent::ForceRebase),
-				exit_status = ExitStatus::Good
-			);
-			assert!(!test_context.rebase_todo_file.is_noop());
+			let _ = test_context.handle_all_events(&mut module);
+			assert!(module.marks.is_empty());
 		},
 	);
 }
 
 #[test]
-fn visual_mode_swap_down_from_top_to_bottom_selection() {
+fn mark_matching_action() {
+	let mut events = vec![Event::from(MetaEvent::MarkMatching)];
+	events.extend("exec".chars().map(|character| Event::from(KeyCode::Char(character))));
+	events.push(Event::from(KeyCode::Enter));
 	module_test(
-		&[
-			"pick aaa c1",
-			"pick aaa c2",
-			"pick aaa c3",
-			"pick aaa c4",
-			"pick aaa c5",
-		],
-		&[
-			Event::from(MetaEvent::MoveCursorDown),
-			Event::from(MetaEvent::ToggleVisualMode),
-			Event::from(MetaEvent::MoveCursorDown),
-			Event::from(MetaEvent::MoveCursorDown),
-			Event::from(MetaEvent::SwapSelectedDown),
-		],
+		&["pick aaa c1", "exec make", "pick bbb c2", "exec make test"],
+		&events,
 		|mut test_context| {
 			let mut module = List::new(&Config::new());
 			let _ = test_context.handle_all_events(&mut module);
-			let view_data = test_context.build_view_data(&mut module);
-			assert_rendered_output!(
-				view_data,
-				"{TITLE}{HELP}",
-				"{BODY}",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c1",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c5",
-				"{Selected}{Normal,Dimmed} > {ActionPick}pick   {Normal}aaa      {Normal}c2{Normal}{Pad( )}",
-				"{Selected}{Normal,Dimmed} > {ActionPick}pick   {Normal}aaa      {Normal}c3{Normal}{Pad( )}",
-				"{Selected}{Normal} > {ActionPick}pick   {Normal}aaa      {Normal}c4{Normal}{Pad( )}"
-			);
+			assert_eq!(module.marks.indexes(), &[1, 3]);
+			assert_eq!(module.state, ListState::Normal);
 		},
 	);
 }
 
 #[test]
-fn visual_mode_swap_down_from_bottom_to_top_selection() {
+fn mark_matching_invalid() {
+	let mut events = vec![Event::from(MetaEvent::MarkMatching)];
+	events.extend("commits".chars().map(|character| Event::from(KeyCode::Char(character))));
+	events.push(Event::from(KeyCode::Enter));
+	module_test(&["pick aaa c1"], &events, |mut test_context| {
+		let mut module = List::new(&Config::new());
+		let _ = test_context.handle_all_events(&mut module);
+		assert!(module.marks.is_empty());
+		assert_eq!(module.state, ListState::MarkEdit);
+		assert_rendered_output!(
+			test_context.build_view_data(&mut module),
+			"{TITLE}",
+			"{LEADING}",
+			"{IndicatorColor}Invalid lines to mark: commits",
+			"",
+			"{BODY}",
+			"{Normal,Dimmed}Mark: {Normal}commits{Normal,Underline}",
+			"{TRAILING}",
+			"{IndicatorColor}Enter to finish"
+		);
+	});
+}
+
+#[test]
+fn mark_matching_without_matches() {
+	let mut events = vec![Event::from(MetaEvent::MarkMatching)];
+	events.extend("filter".chars().map(|character| Event::from(KeyCode::Char(character))));
+	events.push(Event::from(KeyCode::Enter));
+	module_test(&["pick aaa c1"], &events, |mut test_context| {
+		let mut module = List::new(&Config::new());
+		let _ = test_context.handle_all_events(&mut module);
+		assert!(module.marks.is_empty());
+		assert_eq!(module.state, ListState::MarkEdit);
+	});
+}
+
+#[test]
+fn mark_matching_empty() {
 	module_test(
-		&[
-			"pick aaa c1",
-			"pick aaa c2",
-			"pick aaa c3",
-			"pick aaa c4",
-			"pick aaa c5",
-		],
-		&[
-			Event::from(MetaEvent::MoveCursorDown),
-			Event::from(MetaEvent::MoveCursorDown),
-			Event::from(MetaEvent::MoveCursorDown),
-			Event::from(MetaEvent::ToggleVisualMode),
-			Event::from(MetaEvent::MoveCursorUp),
-			Event::from(MetaEvent::MoveCursorUp),
-			Event::from(MetaEvent::SwapSelectedDown),
-		],
+		&["pick aaa c1"],
+		&[Event::from(MetaEvent::MarkMatching), Event::from(KeyCode::Enter)],
 		|mut test_context| {
 			let mut module = List::new(&Config::new());
 			let _ = test_context.handle_all_events(&mut module);
-			let view_data = test_context.build_view_data(&mut module);
-			assert_rendered_output!(
-				view_data,
-				"{TITLE}{HELP}",
-				"{BODY}",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c1",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c5",
-				"{Selected}{Normal} > {ActionPick}pick   {Normal}aaa      {Normal}c2{Normal}{Pad( )}",
-				"{Selected}{Normal,Dimmed} > {ActionPick}pick   {Normal}aaa      {Normal}c3{Normal}{Pad( )}",
-				"{Selected}{Normal,Dimmed} > {ActionPick}pick   {Normal}aaa      {Normal}c4{Normal}{Pad( )}"
-			);
+			assert_eq!(module.state, ListState::Normal);
 		},
 	);
 }
 
 #[test]
-fn visual_mode_swap_up_from_top_to_bottom_selection() {
+fn mark_matching_author() {
+	RepoBuilder::new()
+		.commit("base")
+		.commit("one")
+		.with_repository(|repository, commits| {
+			let line = format!("pick {} one", commits.hash("one"));
+			let mut events = vec![Event::from(MetaEvent::MarkMatching)];
+			events.extend("author:NAME@".chars().map(|character| Event::from(KeyCode::Char(character))));
+			events.push(Event::from(KeyCode::Enter));
+			module_test(&[line.as_str(), "exec make"], &events, |mut test_context| {
+				let mut module = List::new(&Config::new());
+				module.set_details_loader(spawn_details_loader(
+					repository
+						.git_directory()
+						.join("rebase-merge/git-rebase-todo")
+						.as_path(),
+				));
+				module.request_commit_details(&test_context.rebase_todo_file, 0, 2);
+				module.wait_for_loaded_details();
+				let _ = test_context.handle_all_events(&mut module);
+				assert_eq!(module.marks.indexes(), &[0]);
+			});
+			Ok(())
+		});
+}
+
+#[test]
+fn start_move_render() {
 	module_test(
+		&["pick aaa c1", "pick bbb c2", "pick ccc c3", "pick ddd c4"],
 		&[
-			"pick aaa c1",
-			"pick aaa c2",
-			"pick aaa c3",
-			"pick aaa c4",
-			"pick aaa c5",
-		],
-		&[
-			Event::from(MetaEvent::MoveCursorDown),
-			Event::from(MetaEvent::ToggleVisualMode),
+			Event::from(MetaEvent::StartMove),
 			Event::from(MetaEvent::MoveCursorDown),
 			Event::from(MetaEvent::MoveCursorDown),
-			Event::from(MetaEvent::SwapSelectedUp),
 		],
 		|mut test_context| {
 			let mut module = List::new(&Config::new());
 			let _ = test_context.handle_all_events(&mut module);
-			let view_data = test_context.build_view_data(&mut module);
+			assert_eq!(module.state, ListState::Move);
 			assert_rendered_output!(
-				view_data,
+				test_context.build_view_data(&mut module),
 				"{TITLE}{HELP}",
+				"{LEADING}",
+				"{IndicatorColor}Moving 1 lines past 2 lines, with 0 possible conflicts",
 				"{BODY}",
-				"{Selected}{Normal,Dimmed} > {ActionPick}pick   {Normal}aaa      {Normal}c2{Normal}{Pad( )}",
-				"{Selected}{Normal,Dimmed} > {ActionPick}pick   {Normal}aaa      {Normal}c3{Normal}{Pad( )}",
-				"{Selected}{Normal} > {ActionPick}pick   {Normal}aaa      {Normal}c4{Normal}{Pad( )}",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c1",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c5"
+				"{Normal}   {IndicatorColor,Dimmed}Original position {IndicatorColor,Dimmed}{Pad(┄)}",
+				"{Normal}   {ActionPick}pick   {Normal}bbb      {Normal}c2",
+				"{Normal}   {ActionPick}pick   {Normal}ccc      {Normal}c3",
+				"{Selected}{Normal} > {ActionPick}pick   {Normal}aaa      {Normal}c1{Normal}{Pad( )}",
+				"{Normal}   {ActionPick}pick   {Normal}ddd      {Normal}c4",
+				"{TRAILING}",
+				"{IndicatorColor}Press Enter to move the lines, or Escape to cancel"
 			);
+			assert_eq!(todo_lines(&test_context.rebase_todo_file), vec![
+				"pick aaa c1",
+				"pick bbb c2",
+				"pick ccc c3",
+				"pick ddd c4"
+			]);
 		},
 	);
 }
 
 #[test]
-fn visual_mode_swap_up_from_bottom_to_top_selection() {
+fn start_move_confirm() {
 	module_test(
+		&["pick aaa c1", "pick bbb c2", "pick ccc c3"],
 		&[
-			"pick aaa c1",
-			"pick aaa c2",
-			"pick aaa c3",
-			"pick aaa c4",
-			"pick aaa c5",
-		],
-		&[
-			Event::from(MetaEvent::MoveCursorDown),
-			Event::from(MetaEvent::MoveCursorDown),
-			Event::from(MetaEvent::MoveCursorDown),
-			Event::from(MetaEvent::ToggleVisualMode),
-			Event::from(MetaEvent::MoveCursorUp),
-			Event::from(MetaEvent::MoveCursorUp),
-			Event::from(MetaEvent::SwapSelectedUp),
+			Event::from(MetaEvent::StartMove),
+			Event::from(MetaEvent::MoveCursorEnd),
+			Event::from(KeyCode::Enter),
 		],
 		|mut test_context| {
 			let mut module = List::new(&Config::new());
 			let _ = test_context.handle_all_events(&mut module);
-			let view_data = test_context.build_view_data(&mut module);
-			assert_rendered_output!(
-				view_data,
-				"{TITLE}{HELP}",
-				"{BODY}",
-				"{Selected}{Normal} > {ActionPick}pick   {Normal}aaa      {Normal}c2{Normal}{Pad( )}",
-				"{Selected}{Normal,Dimmed} > {ActionPick}pick   {Normal}aaa      {Normal}c3{Normal}{Pad( )}",
-				"{Selected}{Normal,Dimmed} > {ActionPick}pick   {Normal}aaa      {Normal}c4{Normal}{Pad( )}",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c1",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c5"
-			);
+			assert_eq!(module.state, ListState::Normal);
+			assert_eq!(todo_lines(&test_context.rebase_todo_file), vec![
+				"pick bbb c2",
+				"pick ccc c3",
+				"pick aaa c1"
+			]);
+			assert_eq!(test_context.rebase_todo_file.get_selected_line_index(), 2);
+			let _ = test_context.rebase_todo_file.undo();
+			assert_eq!(todo_lines(&test_context.rebase_todo_file), vec![
+				"pick aaa c1",
+				"pick bbb c2",
+				"pick ccc c3"
+			]);
 		},
 	);
 }
 
 #[test]
-fn visual_mode_swap_down_to_limit_from_bottom_to_top_selection() {
+fn start_move_cancel() {
 	module_test(
+		&["pick aaa c1", "pick bbb c2", "pick ccc c3"],
 		&[
-			"pick aaa c1",
-			"pick aaa c2",
-			"pick aaa c3",
-			"pick aaa c4",
-			"pick aaa c5",
-		],
-		&[
+			Event::from(MetaEvent::StartMove),
 			Event::from(MetaEvent::MoveCursorDown),
-			Event::from(MetaEvent::MoveCursorDown),
-			Event::from(MetaEvent::MoveCursorDown),
-			Event::from(MetaEvent::ToggleVisualMode),
-			Event::from(MetaEvent::MoveCursorUp),
-			Event::from(MetaEvent::MoveCursorUp),
-			Event::from(MetaEvent::SwapSelectedDown),
-			Event::from(MetaEvent::SwapSelectedDown),
+			Event::from(KeyCode::Esc),
 		],
 		|mut test_context| {
 			let mut module = List::new(&Config::new());
 			let _ = test_context.handle_all_events(&mut module);
-			let view_data = test_context.build_view_data(&mut module);
-			assert_rendered_output!(
-				view_data,
-				"{TITLE}{HELP}",
-				"{BODY}",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c1",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c5",
-				"{Selected}{Normal} > {ActionPick}pick   {Normal}aaa      {Normal}c2{Normal}{Pad( )}",
-				"{Selected}{Normal,Dimmed} > {ActionPick}pick   {Normal}aaa      {Normal}c3{Normal}{Pad( )}",
-				"{Selected}{Normal,Dimmed} > {ActionPick}pick   {Normal}aaa      {Normal}c4{Normal}{Pad( )}"
-			);
+			assert_eq!(module.state, ListState::Normal);
+			assert_eq!(todo_lines(&test_context.rebase_todo_file), vec![
+				"pick aaa c1",
+				"pick bbb c2",
+				"pick ccc c3"
+			]);
+			assert_eq!(test_context.rebase_todo_file.get_selected_line_index(), 0);
 		},
 	);
 }
 
 #[test]
-fn visual_mode_swap_down_to_limit_from_top_to_bottom_selection() {
+fn start_move_visual_mode() {
 	module_test(
+		&["pick aaa c1", "pick bbb c2", "pick ccc c3", "pick ddd c4"],
 		&[
-			"pick aaa c1",
-			"pick aaa c2",
-			"pick aaa c3",
-			"pick aaa c4",
-			"pick aaa c5",
-		],
-		&[
-			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::MoveCursorEnd),
 			Event::from(MetaEvent::ToggleVisualMode),
-			Event::from(MetaEvent::MoveCursorDown),
-			Event::from(MetaEvent::MoveCursorDown),
-			Event::from(MetaEvent::SwapSelectedDown),
-			Event::from(MetaEvent::SwapSelectedDown),
+			Event::from(MetaEvent::MoveCursorUp),
+			Event::from(MetaEvent::StartMove),
+			Event::from(MetaEvent::MoveCursorUp),
+			Event::from(MetaEvent::MoveCursorUp),
+			Event::from(MetaEvent::MoveCursorUp),
+			Event::from(KeyCode::Enter),
 		],
 		|mut test_context| {
 			let mut module = List::new(&Config::new());
 			let _ = test_context.handle_all_events(&mut module);
-			let view_data = test_context.build_view_data(&mut module);
-			assert_rendered_output!(
-				view_data,
-				"{TITLE}{HELP}",
-				"{BODY}",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c1",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c5",
-				"{Selected}{Normal,Dimmed} > {ActionPick}pick   {Normal}aaa      {Normal}c2{Normal}{Pad( )}",
-				"{Selected}{Normal,Dimmed} > {ActionPick}pick   {Normal}aaa      {Normal}c3{Normal}{Pad( )}",
-				"{Selected}{Normal} > {ActionPick}pick   {Normal}aaa      {Normal}c4{Normal}{Pad( )}"
-			);
+			assert_eq!(todo_lines(&test_context.rebase_todo_file), vec![
+				"pick ccc c3",
+				"pick ddd c4",
+				"pick aaa c1",
+				"pick bbb c2"
+			]);
+			assert_eq!(module.state, ListState::Visual);
+			assert_eq!(test_context.rebase_todo_file.get_selected_line_index(), 0);
+			assert_eq!(module.visual_index_start, Some(1));
 		},
 	);
 }
 
 #[test]
-fn visual_mode_swap_up_to_limit_from_top_to_bottom_selection() {
+fn toggle_group_collapse() {
 	module_test(
-		&[
-			"pick aaa c1",
-			"pick aaa c2",
-			"pick aaa c3",
-			"pick aaa c4",
-			"pick aaa c5",
-		],
-		&[
-			Event::from(MetaEvent::MoveCursorDown),
-			Event::from(MetaEvent::ToggleVisualMode),
+		&["pick aaaaaaaa c1", "break", "pick bbbbbbbb c2", "pick cccccccc c3", "break", "pick dddddddd c4"],
+		&[
 			Event::from(MetaEvent::MoveCursorDown),
 			Event::from(MetaEvent::MoveCursorDown),
-			Event::from(MetaEvent::SwapSelectedUp),
-			Event::from(MetaEvent::SwapSelectedUp),
+			Event::from(MetaEvent::ToggleGroup),
 		],
 		|mut test_context| {
 			let mut module = List::new(&Config::new());
 			let _ = test_context.handle_all_events(&mut module);
-			let view_data = test_context.build_view_data(&mut module);
+			assert_eq!(test_context.rebase_todo_file.get_selected_line_index(), 1);
 			assert_rendered_output!(
-				view_data,
+				test_context.build_view_data(&mut module),
 				"{TITLE}{HELP}",
 				"{BODY}",
-				"{Selected}{Normal,Dimmed} > {ActionPick}pick   {Normal}aaa      {Normal}c2{Normal}{Pad( )}",
-				"{Selected}{Normal,Dimmed} > {ActionPick}pick   {Normal}aaa      {Normal}c3{Normal}{Pad( )}",
-				"{Selected}{Normal} > {ActionPick}pick   {Normal}aaa      {Normal}c4{Normal}{Pad( )}",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c1",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c5"
+				"{Normal}   {ActionPick}pick   {Normal}aaaaaaaa {Normal}c1",
+				"{Selected}{Normal} > {IndicatorColor}▸ {IndicatorColor}break: {Normal}3 lines{Normal,Dimmed} \
+				 bbbbbbbb..cccccccc{Normal}{Pad( )}",
+				"{Normal}   {ActionBreak}break  {ActionBreak,Dimmed}{Pad(─)}",
+				"{Normal}   {ActionPick}pick   {Normal}dddddddd {Normal}c4"
 			);
 		},
 	);
 }
 
 #[test]
-fn visual_mode_swap_up_to_limit_from_bottom_to_top_selection() {
+fn toggle_group_expand() {
 	module_test(
+		&["pick aaa c1", "break", "pick bbb c2"],
 		&[
-			"pick aaa c1",
-			"pick aaa c2",
-			"pick aaa c3",
-			"pick aaa c4",
-			"pick aaa c5",
+			Event::from(MetaEvent::MoveCursorEnd),
+			Event::from(MetaEvent::ToggleGroup),
+			Event::from(MetaEvent::ToggleGroup),
 		],
+		|mut test_context| {
+			let mut module = List::new(&Config::new());
+			let _ = test_context.handle_all_events(&mut module);
+			assert!(module.folds.is_empty());
+			assert_eq!(test_context.rebase_todo_file.get_selected_line_index(), 1);
+		},
+	);
+}
+
+#[test]
+fn toggle_group_move_over_collapsed_group() {
+	module_test(
+		&["pick aaa c1", "break", "pick bbb c2", "pick ccc c3", "break", "pick ddd c4"],
 		&[
 			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::ToggleGroup),
 			Event::from(MetaEvent::MoveCursorDown),
-			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::MoveCursorUp),
+		],
+		|mut test_context| {
+			let mut module = List::new(&Config::new());
+			let _ = test_context.handle_event(&mut module);
+			let _ = test_context.handle_event(&mut module);
+			let _ = test_context.handle_event(&mut module);
+			assert_eq!(test_context.rebase_todo_file.get_selected_line_index(), 4);
+			let _ = test_context.handle_event(&mut module);
+			assert_eq!(test_context.rebase_todo_file.get_selected_line_index(), 1);
+		},
+	);
+}
+
+#[test]
+fn toggle_group_visual_mode() {
+	module_test(
+		&["pick aaaaaaaa c1", "pick bbbbbbbb c2", "pick cccccccc c3", "pick dddddddd c4"],
+		&[
+			Event::from(MetaEvent::MoveCursorEnd),
 			Event::from(MetaEvent::ToggleVisualMode),
 			Event::from(MetaEvent::MoveCursorUp),
 			Event::from(MetaEvent::MoveCursorUp),
-			Event::from(MetaEvent::SwapSelectedUp),
-			Event::from(MetaEvent::SwapSelectedUp),
+			Event::from(MetaEvent::ToggleGroup),
 		],
 		|mut test_context| {
 			let mut module = List::new(&Config::new());
 			let _ = test_context.handle_all_events(&mut module);
-			let view_data = test_context.build_view_data(&mut module);
+			assert_eq!(test_context.rebase_todo_file.get_selected_line_index(), 1);
+			assert_eq!(module.state, ListState::Normal);
 			assert_rendered_output!(
-				view_data,
+				test_context.build_view_data(&mut module),
 				"{TITLE}{HELP}",
 				"{BODY}",
-				"{Selected}{Normal} > {ActionPick}pick   {Normal}aaa      {Normal}c2{Normal}{Pad( )}",
-				"{Selected}{Normal,Dimmed} > {ActionPick}pick   {Normal}aaa      {Normal}c3{Normal}{Pad( )}",
-				"{Selected}{Normal,Dimmed} > {ActionPick}pick   {Normal}aaa      {Normal}c4{Normal}{Pad( )}",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c1",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c5"
+				"{Normal}   {ActionPick}pick   {Normal}aaaaaaaa {Normal}c1",
+				"{Selected}{Normal} > {IndicatorColor}▸ {Normal}3 pick lines{Normal,Dimmed} \
+				 bbbbbbbb..dddddddd{Normal}{Pad( )}"
 			);
 		},
 	);
 }
 
 #[test]
-fn visual_mode_toggle_visual_mode() {
+fn toggle_all_folds_collapse() {
 	module_test(
-		&["pick aaa c1"],
+		&["pick aaa c1", "break", "pick bbb c2", "pick ccc c3", "label onto", "pick ddd c4"],
 		&[
-			Event::from(MetaEvent::ToggleVisualMode),
-			Event::from(MetaEvent::ToggleVisualMode),
+			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::ToggleAllFolds),
 		],
 		|mut test_context| {
 			let mut module = List::new(&Config::new());
-			let _ = test_context.handle_event(&mut module);
-			assert_process_result!(
-				test_context.handle_event(&mut module),
-				event = Event::from(MetaEvent::ToggleVisualMode)
-			);
-			assert_eq!(module.visual_index_start, None);
-			assert_eq!(module.state, ListState::Normal);
+			let _ = test_context.handle_all_events(&mut module);
+			assert_eq!(module.folds.get_rows(6), vec![0, 1, 4]);
+			assert_eq!(test_context.rebase_todo_file.get_selected_line_index(), 1);
 		},
 	);
 }
 
 #[test]
-fn visual_mode_open_external_editor() {
+fn toggle_all_folds_expand() {
 	module_test(
-		&["pick aaa c1"],
+		&["pick aaa c1", "break", "pick bbb c2", "pick ccc c3"],
 		&[
-			Event::from(MetaEvent::ToggleVisualMode),
-			Event::from(MetaEvent::OpenInEditor),
+			Event::from(MetaEvent::ToggleAllFolds),
+			Event::from(MetaEvent::ToggleAllFolds),
 		],
 		|mut test_context| {
 			let mut module = List::new(&Config::new());
-			let _ = test_context.handle_event(&mut module);
-			assert_process_result!(
-				test_context.handle_event(&mut module),
-				event = Event::from(MetaEvent::OpenInEditor),
-				state = State::ExternalEditor
-			);
+			let _ = test_context.handle_all_events(&mut module);
+			assert!(module.folds.is_empty());
 		},
 	);
 }
 
 #[test]
-fn visual_mode_undo() {
+fn toggle_group_expanded_when_lines_change() {
 	module_test(
-		&["pick aaa c1", "pick bbb c2"],
+		&["pick aaa c1", "break", "pick bbb c2", "pick ccc c3"],
 		&[
-			Event::from(MetaEvent::ToggleVisualMode),
 			Event::from(MetaEvent::MoveCursorDown),
-			Event::from(MetaEvent::ActionDrop),
-			Event::from(MetaEvent::Undo),
+			Event::from(MetaEvent::ToggleGroup),
+			Event::from(MetaEvent::RemoveBreaks),
 		],
 		|mut test_context| {
 			let mut module = List::new(&Config::new());
-			let _ = test_context.handle_n_events(&mut module, 3);
-			assert_process_result!(
-				test_context.handle_event(&mut module),
-				event = Event::from(MetaEvent::Undo)
-			);
-			assert_rendered_output!(
-				test_context.build_view_data(&mut module),
-				"{TITLE}{HELP}",
-				"{BODY}",
-				"{Selected}{Normal,Dimmed} > {ActionPick}pick   {Normal}aaa      {Normal}c1{Normal}{Pad( )}",
-				"{Selected}{Normal} > {ActionPick}pick   {Normal}bbb      {Normal}c2{Normal}{Pad( )}"
-			);
+			let _ = test_context.handle_all_events(&mut module);
+			assert!(module.folds.is_empty());
 		},
 	);
 }
 
 #[test]
-fn visual_mode_undo_normal_mode_change() {
+fn name_group() {
 	module_test(
-		&["pick aaa c1", "pick bbb c2"],
-		&[
-			Event::from(MetaEvent::ActionDrop),
-			Event::from(MetaEvent::ToggleVisualMode),
-			Event::from(MetaEvent::MoveCursorDown),
-			Event::from(MetaEvent::Undo),
-		],
+		&["pick aaa c1", "break", "pick bbb c2"],
+		&[Event::from(MetaEvent::MoveCursorEnd), Event::from(MetaEvent::NameGroup)],
 		|mut test_context| {
 			let mut module = List::new(&Config::new());
-			let _ = test_context.handle_n_events(&mut module, 3);
-			assert_process_result!(
-				test_context.handle_event(&mut module),
-				event = Event::from(MetaEvent::Undo)
-			);
+			let _ = test_context.handle_all_events(&mut module);
+			assert_eq!(module.state, ListState::GroupNameEdit);
 			assert_rendered_output!(
 				test_context.build_view_data(&mut module),
-				"{TITLE}{HELP}",
+				"{TITLE}",
+				"{LEADING}",
+				"{IndicatorColor}The name of the group of lines that starts with break, or nothing to remove the name",
+				"",
 				"{BODY}",
-				"{Selected}{Normal} > {ActionPick}pick   {Normal}aaa      {Normal}c1{Normal}{Pad( )}",
-				"{Normal}   {ActionPick}pick   {Normal}bbb      {Normal}c2"
+				"{Normal,Dimmed}Group: {Normal,Underline}",
+				"{TRAILING}",
+				"{IndicatorColor}Enter to finish"
 			);
-			assert_eq!(module.state, ListState::Normal);
 		},
 	);
 }
 
 #[test]
-fn visual_mode_redo() {
+fn name_group_set_name() {
 	module_test(
-		&["drop aaa c1", "drop bbb c2"],
+		&["pick aaa c1", "break", "pick bbb c2"],
 		&[
-			Event::from(MetaEvent::ToggleVisualMode),
-			Event::from(MetaEvent::MoveCursorDown),
-			Event::from(MetaEvent::ActionPick),
-			Event::from(MetaEvent::Undo),
-			Event::from(MetaEvent::Redo),
+			Event::from(MetaEvent::MoveCursorEnd),
+			Event::from(MetaEvent::NameGroup),
+			Event::from(KeyCode::Char('t')),
+			Event::from(KeyCode::Enter),
 		],
 		|mut test_context| {
 			let mut module = List::new(&Config::new());
 			let _ = test_context.handle_all_events(&mut module);
+			assert_eq!(module.state, ListState::Normal);
+			assert_eq!(
+				test_context.rebase_todo_file.get_line(1).unwrap().get_group_name(),
+				Some("t")
+			);
 			assert_rendered_output!(
 				test_context.build_view_data(&mut module),
 				"{TITLE}{HELP}",
 				"{BODY}",
-				"{Selected}{Normal,Dimmed} > {ActionPick}pick   {Normal}aaa      {Normal}c1{Normal}{Pad( )}",
+				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c1",
+				"{Normal}   {ActionBreak}break  {IndicatorColor} t {ActionBreak,Dimmed}{Pad(─)}",
 				"{Selected}{Normal} > {ActionPick}pick   {Normal}bbb      {Normal}c2{Normal}{Pad( )}"
 			);
-			assert_eq!(module.state, ListState::Visual);
 		},
 	);
 }
+
 #[test]
-fn visual_mode_redo_normal_mode_change() {
+fn name_group_without_group_start() {
 	module_test(
-		&["drop aaa c1", "drop bbb c2"],
-		&[
-			Event::from(MetaEvent::ActionPick),
-			Event::from(MetaEvent::ToggleVisualMode),
-			Event::from(MetaEvent::MoveCursorDown),
-			Event::from(MetaEvent::Undo),
-			Event::from(MetaEvent::Redo),
-		],
+		&["pick aaa c1", "break"],
+		&[Event::from(MetaEvent::NameGroup)],
 		|mut test_context| {
 			let mut module = List::new(&Config::new());
 			let _ = test_context.handle_all_events(&mut module);
-			assert_rendered_output!(
-				test_context.build_view_data(&mut module),
-				"{TITLE}{HELP}",
-				"{BODY}",
-				"{Selected}{Normal} > {ActionPick}pick   {Normal}aaa      {Normal}c1{Normal}{Pad( )}",
-				"{Normal}   {ActionDrop}drop   {Normal}bbb      {Normal}c2"
-			);
 			assert_eq!(module.state, ListState::Normal);
 		},
 	);
 }
 
 #[test]
-fn visual_mode_remove_lines_start_index_first() {
+fn sort_lines_visual_mode() {
+	let mut events = vec![
+		Event::from(MetaEvent::ToggleVisualMode),
+		Event::from(MetaEvent::MoveCursorDown),
+		Event::from(MetaEvent::MoveCursorDown),
+		Event::from(MetaEvent::MoveCursorDown),
+		Event::from(MetaEvent::SortLines),
+	];
+	events.extend("subject".chars().map(|character| Event::from(KeyCode::Char(character))));
+	events.push(Event::from(KeyCode::Enter));
 	module_test(
-		&[
-			"pick aaa c1",
+		&["pick aaa c3", "exec make", "pick bbb c1", "pick ccc c2", "pick ddd c0"],
+		&events,
+		|mut test_context| {
+			let mut module = List::new(&Config::new());
+			let _ = test_context.handle_all_events(&mut module);
+			assert_eq!(module.state, ListState::Visual);
+			assert_eq!(todo_lines(&test_context.rebase_todo_file), vec![
+				"pick bbb c1",
+				"exec make",
+				"pick ccc c2",
+				"pick aaa c3",
+				"pick ddd c0"
+			]);
+			let _ = test_context.rebase_todo_file.undo();
+			assert_eq!(todo_lines(&test_context.rebase_todo_file), vec![
+				"pick aaa c3",
+				"exec make",
+				"pick bbb c1",
+				"pick ccc c2",
+				"pick ddd c0"
+			]);
+		},
+	);
+}
+
+#[test]
+fn sort_lines_marked_lines() {
+	let mut events = vec![
+		Event::from(MetaEvent::ToggleMark),
+		Event::from(MetaEvent::MoveCursorEnd),
+		Event::from(MetaEvent::ToggleMark),
+		Event::from(MetaEvent::SortLines),
+	];
+	events.extend("subject".chars().map(|character| Event::from(KeyCode::Char(character))));
+	events.push(Event::from(KeyCode::Enter));
+	module_test(&["pick aaa c3", "pick bbb c2", "pick ccc c1"], &events, |mut test_context| {
+		let mut module = List::new(&Config::new());
+		let _ = test_context.handle_all_events(&mut module);
+		assert_eq!(module.state, ListState::Normal);
+		assert_eq!(todo_lines(&test_context.rebase_todo_file), vec![
+			"pick ccc c1",
 			"pick bbb c2",
-			"pick ccc c3",
-			"pick ddd c4",
-			"pick eee c5",
-		],
-		&[
-			Event::from(MetaEvent::ToggleVisualMode),
-			Event::from(MetaEvent::MoveCursorDown),
-			Event::from(MetaEvent::MoveCursorDown),
-			Event::from(MetaEvent::Delete),
-		],
+			"pick aaa c3"
+		]);
+	});
+}
+
+#[test]
+fn sort_lines_without_marks() {
+	module_test(
+		&["pick aaa c2", "pick bbb c1"],
+		&[Event::from(MetaEvent::SortLines)],
 		|mut test_context| {
 			let mut module = List::new(&Config::new());
 			let _ = test_context.handle_all_events(&mut module);
-			assert_rendered_output!(
-				test_context.build_view_data(&mut module),
-				"{TITLE}{HELP}",
-				"{BODY}",
-				"{Selected}{Normal} > {ActionPick}pick   {Normal}ddd      {Normal}c4{Normal}{Pad( )}",
-				"{Normal}   {ActionPick}pick   {Normal}eee      {Normal}c5"
-			);
-			assert_eq!(
-				module.visual_index_start.unwrap(),
-				test_context.rebase_todo_file.get_selected_line_index()
-			);
+			assert_eq!(module.state, ListState::Normal);
 		},
 	);
 }
 
 #[test]
-fn visual_mode_remove_lines_end_index_first() {
+fn sort_lines_invalid_order() {
+	let mut events = vec![
+		Event::from(MetaEvent::ToggleVisualMode),
+		Event::from(MetaEvent::MoveCursorDown),
+		Event::from(MetaEvent::SortLines),
+	];
+	events.extend("date".chars().map(|character| Event::from(KeyCode::Char(character))));
+	events.push(Event::from(KeyCode::Enter));
+	module_test(&["pick aaa c2", "pick bbb c1"], &events, |mut test_context| {
+		let mut module = List::new(&Config::new());
+		let _ = test_context.handle_all_events(&mut module);
+		assert_eq!(module.state, ListState::SortEdit);
+		assert_rendered_output!(
+			test_context.build_view_data(&mut module),
+			"{TITLE}",
+			"{LEADING}",
+			"{IndicatorColor}Invalid order: date",
+			"",
+			"{BODY}",
+			"{Normal,Dimmed}Sort by: {Normal}date{Normal,Underline}",
+			"{TRAILING}",
+			"{IndicatorColor}Enter to finish"
+		);
+	});
+}
+
+#[test]
+fn reverse_lines_all_lines() {
 	module_test(
-		&[
-			"pick aaa c1",
-			"pick bbb c2",
-			"pick ccc c3",
-			"pick ddd c4",
-			"pick eee c5",
-		],
-		&[
-			Event::from(MetaEvent::MoveCursorDown),
-			Event::from(MetaEvent::MoveCursorDown),
-			Event::from(MetaEvent::ToggleVisualMode),
-			Event::from(MetaEvent::MoveCursorUp),
-			Event::from(MetaEvent::MoveCursorUp),
-			Event::from(MetaEvent::Delete),
-		],
+		&["pick aaa c1", "exec make", "pick bbb c2", "pick ccc c3"],
+		&[Event::from(MetaEvent::MoveCursorDown), Event::from(MetaEvent::ReverseLines)],
 		|mut test_context| {
 			let mut module = List::new(&Config::new());
 			let _ = test_context.handle_all_events(&mut module);
-			assert_rendered_output!(
-				test_context.build_view_data(&mut module),
-				"{TITLE}{HELP}",
-				"{BODY}",
-				"{Selected}{Normal} > {ActionPick}pick   {Normal}ddd      {Normal}c4{Normal}{Pad( )}",
-				"{Normal}   {ActionPick}pick   {Normal}eee      {Normal}c5"
-			);
-			assert_eq!(
-				module.visual_index_start.unwrap(),
-				test_context.rebase_todo_file.get_selected_line_index()
-			);
+			assert_eq!(todo_lines(&test_context.rebase_todo_file), vec![
+				"pick ccc c3",
+				"pick bbb c2",
+				"exec make",
+				"pick aaa c1"
+			]);
+			assert_eq!(test_context.rebase_todo_file.get_selected_line_index(), 2);
+			let _ = test_context.rebase_todo_file.undo();
+			assert_eq!(todo_lines(&test_context.rebase_todo_file), vec![
+				"pick aaa c1",
+				"exec make",
+				"pick bbb c2",
+				"pick ccc c3"
+			]);
 		},
 	);
 }
 
 #[test]
-fn visual_mode_remove_lines_start_index_last() {
+fn reverse_lines_visual_mode() {
 	module_test(
-		&[
-			"pick aaa c1",
-			"pick bbb c2",
-			"pick ccc c3",
-			"pick ddd c4",
-			"pick eee c5",
-		],
+		&["pick aaa c1", "pick bbb c2", "pick ccc c3", "pick ddd c4"],
 		&[
 			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::ToggleVisualMode),
 			Event::from(MetaEvent::MoveCursorDown),
 			Event::from(MetaEvent::MoveCursorDown),
-			Event::from(MetaEvent::MoveCursorDown),
-			Event::from(MetaEvent::ToggleVisualMode),
-			Event::from(MetaEvent::MoveCursorUp),
-			Event::from(MetaEvent::MoveCursorUp),
-			Event::from(MetaEvent::Delete),
+			Event::from(MetaEvent::ReverseLines),
 		],
 		|mut test_context| {
 			let mut module = List::new(&Config::new());
-			let _ = test_context.handle_all_events(&mut module);
-			assert_rendered_output!(
-				test_context.build_view_data(&mut module),
-				"{TITLE}{HELP}",
-				"{BODY}",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c1",
-				"{Selected}{Normal} > {ActionPick}pick   {Normal}bbb      {Normal}c2{Normal}{Pad( )}"
-			);
-			assert_eq!(
-				module.visual_index_start.unwrap(),
-				test_context.rebase_todo_file.get_selected_line_index()
-			);
+			let _ = test_context.handle_all_events(&mut module);
+			assert_eq!(module.state, ListState::Visual);
+			assert_eq!(todo_lines(&test_context.rebase_todo_file), vec![
+				"pick aaa c1",
+				"pick ddd c4",
+				"pick ccc c3",
+				"pick bbb c2"
+			]);
+			assert_eq!(test_context.rebase_todo_file.get_selected_line_index(), 1);
+			assert_eq!(module.visual_index_start, Some(3));
 		},
 	);
 }
 
 #[test]
-fn visual_mode_remove_lines_end_index_last() {
+fn reverse_lines_marked_lines() {
 	module_test(
+		&["pick aaa c1", "pick bbb c2", "pick ccc c3"],
 		&[
-			"pick aaa c1",
-			"pick bbb c2",
-			"pick ccc c3",
-			"pick ddd c4",
-			"pick eee c5",
+			Event::from(MetaEvent::ToggleMark),
+			Event::from(MetaEvent::MoveCursorEnd),
+			Event::from(MetaEvent::ToggleMark),
+			Event::from(MetaEvent::ReverseLines),
 		],
+		|mut test_context| {
+			let mut module = List::new(&Config::new());
+			let _ = test_context.handle_all_events(&mut module);
+			assert_eq!(todo_lines(&test_context.rebase_todo_file), vec![
+				"pick ccc c3",
+				"pick bbb c2",
+				"pick aaa c1"
+			]);
+			assert_eq!(test_context.rebase_todo_file.get_selected_line_index(), 2);
+			assert_eq!(module.marks.indexes(), &[0, 2]);
+		},
+	);
+}
+
+#[test]
+fn ui_state_restored() {
+	module_test(&["pick aaa c1"], &[], |_| {
+		let mut ui_state = UiState::default();
+		ui_state.set_show_stash_info(true);
+		ui_state.set_path_filter(Some("src/**"));
+		let mut module = List::new(&Config::new());
+		module.set_ui_state(Rc::new(RefCell::new(ui_state)));
+		assert!(module.show_stash_info);
+		assert_eq!(module.path_filter.as_ref().map(PathFilter::pattern), Some("src/**"));
+	});
+}
+
+#[test]
+fn ui_state_updated() {
+	let mut events = vec![Event::from(MetaEvent::ToggleStashInfo), Event::from(MetaEvent::FilterByPath)];
+	events.extend("*.rs".chars().map(|character| Event::from(KeyCode::Char(character))));
+	events.push(Event::from(KeyCode::Enter));
+	module_test(&["pick aaa c1"], &events, |mut test_context| {
+		let ui_state = Rc::new(RefCell::new(UiState::default()));
+		let mut module = List::new(&Config::new());
+		module.set_ui_state(Rc::clone(&ui_state));
+		let _ = test_context.handle_all_events(&mut module);
+		assert!(ui_state.borrow().show_stash_info());
+		assert_eq!(ui_state.borrow().path_filter(), Some("*.rs"));
+	});
+}
+
+#[test]
+fn read_only_ignores_changes() {
+	module_test(
+		&["pick aaa c1", "pick bbb c2"],
 		&[
 			Event::from(MetaEvent::MoveCursorDown),
-			Event::from(MetaEvent::MoveCursorDown),
-			Event::from(MetaEvent::ToggleVisualMode),
-			Event::from(MetaEvent::MoveCursorDown),
-			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::ActionDrop),
+			Event::from(MetaEvent::SwapSelectedUp),
 			Event::from(MetaEvent::Delete),
 		],
 		|mut test_context| {
 			let mut module = List::new(&Config::new());
+			module.set_read_only();
 			let _ = test_context.handle_all_events(&mut module);
+			let view_data = test_context.build_view_data(&mut module);
 			assert_rendered_output!(
-				test_context.build_view_data(&mut module),
+				view_data,
 				"{TITLE}{HELP}",
+				"{LEADING}",
+				"{IndicatorColor}Warning: The todo list is read only, and will not be written on exit",
+				"{Normal}The todo list is read only",
 				"{BODY}",
 				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c1",
 				"{Selected}{Normal} > {ActionPick}pick   {Normal}bbb      {Normal}c2{Normal}{Pad( )}"
 			);
-			assert_eq!(
-				module.visual_index_start.unwrap(),
-				test_context.rebase_todo_file.get_selected_line_index()
-			);
 		},
 	);
 }
 
-#[test]
-fn visual_mode_other_event() {
-	module_test(&["pick aaa c1"], &[Event::from(KeyCode::Null)], |mut test_context| {
+#[rstest]
+#[case::rebase(MetaEvent::Rebase, ExitStatus::Good)]
+#[case::force_rebase(MetaEvent::ForceRebase, ExitStatus::Good)]
+#[case::abort(MetaEvent::Abort, ExitStatus::Abort)]
+#[case::force_abort(MetaEvent::ForceAbort, ExitStatus::Abort)]
+#[case::quit_without_saving(MetaEvent::QuitWithoutSaving, ExitStatus::Quit)]
+fn read_only_exit(#[case] meta_event: MetaEvent, #[case] exit_status: ExitStatus) {
+	module_test(&["pick aaa c1"], &[Event::from(meta_event)], |mut test_context| {
 		let mut module = List::new(&Config::new());
+		module.set_read_only();
 		assert_process_result!(
 			test_context.handle_event(&mut module),
-			event = Event::from(KeyCode::Null)
-		);
-	});
-}
-
-#[test]
-fn edit_mode_render() {
-	module_test(&["exec foo"], &[Event::from(MetaEvent::Edit)], |mut test_context| {
-		let mut module = List::new(&Config::new());
-		let _ = test_context.handle_all_events(&mut module);
-		let view_data = test_context.build_view_data(&mut module);
-		assert_rendered_output!(
-			view_data,
-			"{TITLE}",
-			"{LEADING}",
-			"{IndicatorColor}Modifying line: exec foo",
-			"",
-			"{BODY}",
-			"{Normal,Dimmed}exec {Normal}foo{Normal,Underline}",
-			"{TRAILING}",
-			"{IndicatorColor}Enter to finish"
+			event = Event::from(meta_event),
+			exit_status = exit_status
 		);
+		assert_eq!(test_context.rebase_todo_file.get_lines_owned().len(), 1);
 	});
 }
 
 #[test]
-fn edit_mode_handle_event() {
+fn read_only_does_not_open_editor() {
 	module_test(
-		&["exec foo"],
-		&[
-			Event::from(MetaEvent::Edit),
-			Event::from(KeyCode::Backspace),
-			Event::from(KeyCode::Enter),
-		],
+		&["pick aaa c1"],
+		&[Event::from(MetaEvent::OpenInEditor)],
 		|mut test_context| {
 			let mut module = List::new(&Config::new());
-			let _ = test_context.build_view_data(&mut module);
-			let _ = test_context.handle_all_events(&mut module);
-			assert_eq!(test_context.rebase_todo_file.get_line(0).unwrap().get_content(), "fo");
-			assert_eq!(module.state, ListState::Normal);
+			module.set_read_only();
+			assert_process_result!(
+				test_context.handle_event(&mut module),
+				event = Event::from(MetaEvent::OpenInEditor)
+			);
 		},
 	);
 }
 
 #[test]
-fn scroll_right() {
+fn quit_without_saving() {
 	module_test(
 		&["pick aaa c1"],
-		&[Event::from(MetaEvent::MoveCursorRight)],
+		&[Event::from(MetaEvent::QuitWithoutSaving)],
 		|mut test_context| {
 			let mut module = List::new(&Config::new());
-			let _ = test_context.handle_all_events(&mut module);
-			test_context.view_sender_context.assert_render_action(&["ScrollRight"]);
+			assert_process_result!(
+				test_context.handle_event(&mut module),
+				event = Event::from(MetaEvent::QuitWithoutSaving),
+				state = State::ConfirmQuit
+			);
 		},
 	);
 }
 
 #[test]
-fn scroll_left() {
+fn force_abort_exit_code() {
 	module_test(
 		&["pick aaa c1"],
-		&[Event::from(MetaEvent::MoveCursorLeft)],
+		&[Event::from(MetaEvent::ForceAbort)],
 		|mut test_context| {
-			let mut module = List::new(&Config::new());
-			let _ = test_context.handle_all_events(&mut module);
-			test_context.view_sender_context.assert_render_action(&["ScrollLeft"]);
+			let mut config = Config::new();
+			config.abort_behavior = AbortBehavior::ExitCode;
+			let mut module = List::new(&config);
+			assert_process_result!(
+				test_context.handle_event(&mut module),
+				event = Event::from(MetaEvent::ForceAbort),
+				exit_status = ExitStatus::Cancel
+			);
+			assert!(!test_context.rebase_todo_file.is_empty());
 		},
 	);
 }
 
+fn watch_todo_file(module: &mut List, todo_file: &TodoFile) {
+	todo_file.write_file().unwrap();
+	module.set_file_watcher(
+		FileWatcher::spawn_with_interval(
+			Path::new(todo_file.get_filepath()),
+			Duration::from_millis(10),
+			EventBus::new().sender(),
+			&TaskManager::new(),
+		),
+		todo_file,
+	);
+}
+
+// the change is dispatched to the list, as the process would for the event sent by the watcher
+fn change_todo_file(module: &mut List, todo_file: &TodoFile, contents: &str) -> ProcessResult {
+	std::fs::write(todo_file.get_filepath(), contents).unwrap();
+	module.wait_for_external_change();
+	module.handle_bus_event(&BusEvent::TodoFileChanged, todo_file)
+}
+
 #[test]
-fn normal_mode_help() {
-	module_test(&["pick aaa c1"], &[Event::from(MetaEvent::Help)], |mut test_context| {
+fn external_change_reload() {
+	module_test(&["pick aaa c1", "pick bbb c2"], &[Event::from('r')], |mut test_context| {
 		let mut module = List::new(&Config::new());
-		module.state = ListState::Normal;
-		let _ = test_context.handle_all_events(&mut module);
+		watch_todo_file(&mut module, &test_context.rebase_todo_file);
+		assert_process_result!(
+			change_todo_file(&mut module, &test_context.rebase_todo_file, "drop aaa c1\npick bbb c2\n"),
+			event = Event::from(MetaEvent::BackgroundLoaded)
+		);
 		let view_data = test_context.build_view_data(&mut module);
 		assert_rendered_output!(
 			view_data,
 			"{TITLE}",
 			"{LEADING}",
-			"{Normal,Underline} Key      Action{Normal,Underline}{Pad( )}",
+			"{IndicatorColor}Todo file changed",
+			"",
+			"{Normal}The todo file was changed by another program.",
+			"",
 			"{BODY}",
-			"{IndicatorColor} Up      {Normal,Dimmed}|{Normal}Move selection up",
-			"{IndicatorColor} Down    {Normal,Dimmed}|{Normal}Move selection down",
-			"{IndicatorColor} PageUp  {Normal,Dimmed}|{Normal}Move selection up 5 lines",
-			"{IndicatorColor} PageDown{Normal,Dimmed}|{Normal}Move selection down 5 lines",
-			"{IndicatorColor} Home    {Normal,Dimmed}|{Normal}Move selection to top of the list",
-			"{IndicatorColor} End     {Normal,Dimmed}|{Normal}Move selection to end of the list",
-			"{IndicatorColor} Left    {Normal,Dimmed}|{Normal}Scroll content to the left",
-			"{IndicatorColor} Right   {Normal,Dimmed}|{Normal}Scroll content to the right",
-			"{IndicatorColor} q       {Normal,Dimmed}|{Normal}Abort interactive rebase",
-			"{IndicatorColor} Q       {Normal,Dimmed}|{Normal}Immediately abort interactive rebase",
-			"{IndicatorColor} w       {Normal,Dimmed}|{Normal}Write interactive rebase file",
-			"{IndicatorColor} W       {Normal,Dimmed}|{Normal}Immediately write interactive rebase file",
-			"{IndicatorColor} v       {Normal,Dimmed}|{Normal}Enter visual mode",
-			"{IndicatorColor} ?       {Normal,Dimmed}|{Normal}Show help",
-			"{IndicatorColor} c       {Normal,Dimmed}|{Normal}Show commit information",
-			"{IndicatorColor} j       {Normal,Dimmed}|{Normal}Move selected commit down",
-			"{IndicatorColor} k       {Normal,Dimmed}|{Normal}Move selected commit up",
-			"{IndicatorColor} b       {Normal,Dimmed}|{Normal}Toggle break action",
-			"{IndicatorColor} p       {Normal,Dimmed}|{Normal}Set selected commit to be picked",
-			"{IndicatorColor} r       {Normal,Dimmed}|{Normal}Set selected commit to be reworded",
-			"{IndicatorColor} e       {Normal,Dimmed}|{Normal}Set selected commit to be edited",
-			"{IndicatorColor} s       {Normal,Dimmed}|{Normal}Set selected commit to be squashed",
-			"{IndicatorColor} f       {Normal,Dimmed}|{Normal}Set selected commit to be fixed-up",
-			"{IndicatorColor} d       {Normal,Dimmed}|{Normal}Set selected commit to be dropped",
-			"{IndicatorColor} E       {Normal,Dimmed}|{Normal}Edit an exec action's command",
-			"{IndicatorColor} I       {Normal,Dimmed}|{Normal}Insert a new line",
-			"{IndicatorColor} Delete  {Normal,Dimmed}|{Normal}Completely remove the selected line",
-			"{IndicatorColor} Controlz{Normal,Dimmed}|{Normal}Undo the last change",
-			"{IndicatorColor} Controly{Normal,Dimmed}|{Normal}Redo the previous undone change",
-			"{IndicatorColor} !       {Normal,Dimmed}|{Normal}Open the todo file in the default editor",
+			"{Selected}{IndicatorColor}r) {Normal}Reload the todo file",
+			"{IndicatorColor}k) {Normal}Keep the todo list, discarding the changes to the todo file",
 			"{TRAILING}",
-			"{IndicatorColor}Press any key to close"
+			"{IndicatorColor}Up and down to select, enter or the key of a choice to choose"
 		);
+		assert_process_result!(test_context.handle_event(&mut module), event = Event::from('r'));
+		assert_eq!(module.state, ListState::Normal);
+		assert_eq!(todo_lines(&test_context.rebase_todo_file), vec!["drop aaa c1", "pick bbb c2"]);
+		let _ = test_context.rebase_todo_file.undo();
+		assert_eq!(todo_lines(&test_context.rebase_todo_file), vec!["pick aaa c1", "pick bbb c2"]);
 	});
 }
 
 #[test]
-fn normal_mode_help_event() {
+fn external_change_merge() {
 	module_test(
-		&["pick aaa c1"],
-		&[Event::from(MetaEvent::Help), Event::from(MetaEvent::SwapSelectedDown)],
+		&["pick aaa c1", "pick bbb c2", "pick ccc c3"],
+		&[Event::from(KeyCode::Enter)],
 		|mut test_context| {
 			let mut module = List::new(&Config::new());
-			module.state = ListState::Normal;
-			let _ = test_context.handle_all_events(&mut module);
-			assert!(!module.normal_mode_help.is_active());
+			watch_todo_file(&mut module, &test_context.rebase_todo_file);
+			test_context
+				.rebase_todo_file
+				.update_range(0, 0, &EditContext::new().action(Action::Drop));
+			assert_process_result!(
+				change_todo_file(
+					&mut module,
+					&test_context.rebase_todo_file,
+					"pick aaa c1\npick bbb c2\nfixup ccc c3\n",
+				),
+				event = Event::from(MetaEvent::BackgroundLoaded)
+			);
+			let view_data = test_context.build_view_data(&mut module);
+			assert_rendered_output!(
+				view_data,
+				"{TITLE}",
+				"{LEADING}",
+				"{IndicatorColor}Todo file changed",
+				"",
+				"{Normal}The todo file was changed by another program.",
+				"{Normal}The todo list was also changed, and the changes can be merged.",
+				"",
+				"{BODY}",
+				"{Selected}{IndicatorColor}m) {Normal}Merge the changes of the todo file into the todo list",
+				"{IndicatorColor}r) {Normal}Reload the todo file, discarding the changes to the todo list",
+				"{IndicatorColor}k) {Normal}Keep the todo list, discarding the changes to the todo file",
+				"{TRAILING}",
+				"{IndicatorColor}Up and down to select, enter or the key of a choice to choose"
+			);
+			assert_process_result!(
+				test_context.handle_event(&mut module),
+				event = Event::from(KeyCode::Enter)
+			);
+			assert_eq!(
+				todo_lines(&test_context.rebase_todo_file),
+				vec!["drop aaa c1", "pick bbb c2", "fixup ccc c3"]
+			);
 		},
 	);
 }
 
 #[test]
-fn visual_mode_help() {
-	module_test(&["pick aaa c1"], &[Event::from(MetaEvent::Help)], |mut test_context| {
+fn external_change_conflict_keep() {
+	module_test(&["pick aaa c1", "pick bbb c2"], &[Event::from(KeyCode::Enter)], |mut test_context| {
 		let mut module = List::new(&Config::new());
-		module.state = ListState::Visual;
-		let _ = test_context.handle_all_events(&mut module);
+		watch_todo_file(&mut module, &test_context.rebase_todo_file);
+		test_context
+			.rebase_todo_file
+			.update_range(0, 0, &EditContext::new().action(Action::Drop));
+		assert_process_result!(
+			change_todo_file(&mut module, &test_context.rebase_todo_file, "edit aaa c1\npick bbb c2\n"),
+			event = Event::from(MetaEvent::BackgroundLoaded)
+		);
 		let view_data = test_context.build_view_data(&mut module);
 		assert_rendered_output!(
 			view_data,
 			"{TITLE}",
 			"{LEADING}",
-			"{Normal,Underline} Key      Action{Normal,Underline}{Pad( )}",
+			"{IndicatorColor}Todo file changed",
+			"",
+			"{Normal}The todo file was changed by another program.",
+			"{Normal}The todo list was also changed, and the changes conflict.",
+			"",
 			"{BODY}",
-			"{IndicatorColor} Up      {Normal,Dimmed}|{Normal}Move selection up",
-			"{IndicatorColor} Down    {Normal,Dimmed}|{Normal}Move selection down",
-			"{IndicatorColor} PageUp  {Normal,Dimmed}|{Normal}Move selection up 5 lines",
-			"{IndicatorColor} PageDown{Normal,Dimmed}|{Normal}Move selection down 5 lines",
-			"{IndicatorColor} Home    {Normal,Dimmed}|{Normal}Move selection to top of the list",
-			"{IndicatorColor} End     {Normal,Dimmed}|{Normal}Move selection to end of the list",
-			"{IndicatorColor} Left    {Normal,Dimmed}|{Normal}Scroll content to the left",
-			"{IndicatorColor} Right   {Normal,Dimmed}|{Normal}Scroll content to the right",
-			"{IndicatorColor} ?       {Normal,Dimmed}|{Normal}Show help",
-			"{IndicatorColor} j       {Normal,Dimmed}|{Normal}Move selected commits down",
-			"{IndicatorColor} k       {Normal,Dimmed}|{Normal}Move selected commits up",
-			"{IndicatorColor} p       {Normal,Dimmed}|{Normal}Set selected commits to be picked",
-			"{IndicatorColor} r       {Normal,Dimmed}|{Normal}Set selected commits to be reworded",
-			"{IndicatorColor} e       {Normal,Dimmed}|{Normal}Set selected commits to be edited",
-			"{IndicatorColor} s       {Normal,Dimmed}|{Normal}Set selected commits to be squashed",
-			"{IndicatorColor} f       {Normal,Dimmed}|{Normal}Set selected commits to be fixed-up",
-			"{IndicatorColor} d       {Normal,Dimmed}|{Normal}Set selected commits to be dropped",
-			"{IndicatorColor} Delete  {Normal,Dimmed}|{Normal}Completely remove the selected lines",
-			"{IndicatorColor} Controlz{Normal,Dimmed}|{Normal}Undo the last change",
-			"{IndicatorColor} Controly{Normal,Dimmed}|{Normal}Redo the previous undone change",
-			"{IndicatorColor} v       {Normal,Dimmed}|{Normal}Exit visual mode",
+			"{IndicatorColor}r) {Normal}Reload the todo file, discarding the changes to the todo list",
+			"{Selected}{IndicatorColor}k) {Normal}Keep the todo list, discarding the changes to the todo file",
 			"{TRAILING}",
-			"{IndicatorColor}Press any key to close"
+			"{IndicatorColor}Up and down to select, enter or the key of a choice to choose"
+		);
+		assert_process_result!(
+			test_context.handle_event(&mut module),
+			event = Event::from(KeyCode::Enter)
+		);
+		assert_eq!(module.state, ListState::Normal);
+		assert_eq!(todo_lines(&test_context.rebase_todo_file), vec!["drop aaa c1", "pick bbb c2"]);
+		assert_eq!(
+			module.watched_lines.iter().map(Line::to_text).collect::<Vec<String>>(),
+			vec!["edit aaa c1", "pick bbb c2"]
 		);
 	});
 }
 
 #[test]
-fn visual_mode_help_event() {
+fn external_change_same_as_list() {
 	module_test(
-		&["pick aaa c1"],
-		&[Event::from(MetaEvent::Help), Event::from(MetaEvent::SwapSelectedDown)],
+		&["pick aaa c1", "pick bbb c2"],
+		&[Event::from(MetaEvent::MoveCursorDown)],
 		|mut test_context| {
 			let mut module = List::new(&Config::new());
-			module.state = ListState::Visual;
-			let _ = test_context.handle_all_events(&mut module);
-			assert!(!module.visual_mode_help.is_active());
+			watch_todo_file(&mut module, &test_context.rebase_todo_file);
+			test_context
+				.rebase_todo_file
+				.update_range(0, 0, &EditContext::new().action(Action::Drop));
+			assert_process_result!(change_todo_file(
+				&mut module,
+				&test_context.rebase_todo_file,
+				"drop aaa c1\npick bbb c2\n"
+			));
+			assert_process_result!(
+				test_context.handle_event(&mut module),
+				event = Event::from(MetaEvent::MoveCursorDown)
+			);
+			assert_eq!(module.state, ListState::Normal);
 		},
 	);
 }
 
-// this can technically never happen, but it's worth testing, just in case of an invalid state
 #[test]
-fn render_noop_list() {
-	module_test(&["break"], &[], |mut test_context| {
-		let mut module = List::new(&Config::new());
-		test_context.rebase_todo_file.remove_lines(0, 0);
-		test_context.rebase_todo_file.add_line(0, Line::new("noop").unwrap());
-		let view_data = test_context.build_view_data(&mut module);
-		assert_rendered_output!(
-			view_data,
-			"{TITLE}{HELP}",
-			"{BODY}",
-			"{Selected}{Normal} > {Normal}noop   {Normal}{Pad( )}"
-		);
-	});
+fn external_change_not_normal_mode() {
+	module_test(
+		&["pick aaa c1", "pick bbb c2"],
+		&[
+			Event::from(MetaEvent::ToggleVisualMode),
+			Event::from(MetaEvent::ToggleVisualMode),
+		],
+		|mut test_context| {
+			let mut module = List::new(&Config::new());
+			watch_todo_file(&mut module, &test_context.rebase_todo_file);
+			assert_process_result!(
+				test_context.handle_event(&mut module),
+				event = Event::from(MetaEvent::ToggleVisualMode)
+			);
+			assert_process_result!(change_todo_file(
+				&mut module,
+				&test_context.rebase_todo_file,
+				"drop aaa c1\npick bbb c2\n"
+			));
+			assert_eq!(module.state, ListState::Visual);
+			assert_process_result!(
+				test_context.handle_event(&mut module),
+				event = Event::from(MetaEvent::ToggleVisualMode)
+			);
+			// the change is offered once the list is back in the normal mode
+			assert_process_result!(
+				test_context.handle_event(&mut module),
+				event = Event::from(MetaEvent::BackgroundLoaded)
+			);
+			assert_eq!(module.state, ListState::ExternalChange);
+		},
+	);
 }
 
 #[test]
-fn resize() {
-	module_test(&["pick aaa c1"], &[Event::Resize(100, 200)], |mut test_context| {
+fn external_change_invalid() {
+	module_test(&["pick aaa c1"], &[], |test_context| {
 		let mut module = List::new(&Config::new());
-		let _ = test_context.handle_all_events(&mut module);
-		assert_eq!(module.height, 200);
+		watch_todo_file(&mut module, &test_context.rebase_todo_file);
+		assert_process_result!(
+			change_todo_file(&mut module, &test_context.rebase_todo_file, "jump aaa c1\n"),
+			event = Event::from(MetaEvent::BackgroundLoaded)
+		);
+		assert_eq!(module.state, ListState::Normal);
+		assert!(module
+			.announcement
+			.unwrap()
+			.starts_with("The todo file was changed by another program, and cannot be read: "));
 	});
 }
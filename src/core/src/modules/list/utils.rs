@@ -1,184 +1,415 @@
 use std::cmp;
 
-use config::KeyBindings;
+use config::{DateFormat, KeyBindings};
 use display::DisplayColor;
-use todo_file::{Action, Line};
-use view::LineSegment;
+use git::{CommitTopology, RangeTopology};
+use input::MetaEvent;
+use todo_file::{Action, Line, Lint, TodoFile};
+use view::{LineSegment, RenderContext, ViewLine};
+
+use super::{action_column::ActionColumn, commit_details::CommitDetails};
+use crate::{
+	dates::format_date,
+	i18n::{translate, translate_format},
+};
+
+pub(super) const PREVIEW_PANE_HEIGHT: usize = 5;
+
+// the events that change the todo list, or that open a state that changes it or that runs a rebase command, that are
+// ignored when the todo list is read only
+pub(super) const fn is_mutation(meta_event: MetaEvent) -> bool {
+	matches!(
+		meta_event,
+		MetaEvent::AbortRebase
+			| MetaEvent::ActionBreak
+			| MetaEvent::ActionDrop
+			| MetaEvent::ActionEdit
+			| MetaEvent::ActionFixup
+			| MetaEvent::ActionPick
+			| MetaEvent::ActionReword
+			| MetaEvent::ActionSquash
+			| MetaEvent::Autosquash
+			| MetaEvent::BranchOut
+			| MetaEvent::BreakEvery
+			| MetaEvent::CycleFixupOption
+			| MetaEvent::Delete
+			| MetaEvent::Edit
+			| MetaEvent::InsertLine
+			| MetaEvent::NameGroup
+			| MetaEvent::OpenInEditor
+			| MetaEvent::QuickFix
+			| MetaEvent::Redo
+			| MetaEvent::RemoveBreaks
+			| MetaEvent::ReverseLines
+			| MetaEvent::ShowSquashMessage
+			| MetaEvent::SkipCommit
+			| MetaEvent::SortLines
+			| MetaEvent::StartMove
+			| MetaEvent::SwapSelectedDown
+			| MetaEvent::SwapSelectedUp
+			| MetaEvent::TestCommit
+			| MetaEvent::Undo
+	)
+}
 
 pub(super) fn get_list_normal_mode_help_lines(key_bindings: &KeyBindings) -> Vec<(Vec<String>, String)> {
 	vec![
-		(key_bindings.move_up.clone(), String::from("Move selection up")),
-		(key_bindings.move_down.clone(), String::from("Move selection down")),
+		(
+			key_bindings.move_up.clone(),
+			String::from(translate("Move selection up")),
+		),
+		(
+			key_bindings.move_down.clone(),
+			String::from(translate("Move selection down")),
+		),
 		(
 			key_bindings.move_up_step.clone(),
-			String::from("Move selection up 5 lines"),
+			String::from(translate("Move selection up 5 lines")),
 		),
 		(
 			key_bindings.move_down_step.clone(),
-			String::from("Move selection down 5 lines"),
+			String::from(translate("Move selection down 5 lines")),
 		),
 		(
 			key_bindings.move_home.clone(),
-			String::from("Move selection to top of the list"),
+			String::from(translate("Move selection to top of the list")),
 		),
 		(
 			key_bindings.move_end.clone(),
-			String::from("Move selection to end of the list"),
+			String::from(translate("Move selection to end of the list")),
+		),
+		(
+			key_bindings.jump_to_line.clone(),
+			String::from(translate("Move selection to a line by its number")),
 		),
 		(
 			key_bindings.move_left.clone(),
-			String::from("Scroll content to the left"),
+			String::from(translate("Scroll content to the left")),
 		),
 		(
 			key_bindings.move_right.clone(),
-			String::from("Scroll content to the right"),
+			String::from(translate("Scroll content to the right")),
+		),
+		(
+			key_bindings.abort.clone(),
+			String::from(translate("Abort interactive rebase")),
 		),
-		(key_bindings.abort.clone(), String::from("Abort interactive rebase")),
 		(
 			key_bindings.force_abort.clone(),
-			String::from("Immediately abort interactive rebase"),
+			String::from(translate("Immediately abort interactive rebase")),
+		),
+		(
+			key_bindings.quit_without_saving.clone(),
+			String::from(translate("Quit without saving, keeping the original rebase file")),
 		),
 		(
 			key_bindings.rebase.clone(),
-			String::from("Write interactive rebase file"),
+			String::from(translate("Write interactive rebase file")),
 		),
 		(
 			key_bindings.force_rebase.clone(),
-			String::from("Immediately write interactive rebase file"),
+			String::from(translate("Immediately write interactive rebase file")),
+		),
+		(
+			key_bindings.abort_rebase.clone(),
+			String::from(translate("Abort the stopped rebase (--edit-todo only)")),
+		),
+		(
+			key_bindings.skip_commit.clone(),
+			String::from(translate("Skip the stopped commit (--edit-todo only)")),
 		),
 		(
 			key_bindings.toggle_visual_mode.clone(),
-			String::from("Enter visual mode"),
+			String::from(translate("Enter visual mode")),
 		),
-		(key_bindings.help.clone(), String::from("Show help")),
+		(key_bindings.help.clone(), String::from(translate("Show help"))),
 		(
 			key_bindings.show_commit.clone(),
-			String::from("Show commit information"),
+			String::from(translate("Show commit information")),
 		),
 		(
 			key_bindings.move_selection_down.clone(),
-			String::from("Move selected commit down"),
+			String::from(translate("Move selected commit down")),
 		),
 		(
 			key_bindings.move_selection_up.clone(),
-			String::from("Move selected commit up"),
+			String::from(translate("Move selected commit up")),
+		),
+		(
+			key_bindings.action_break.clone(),
+			String::from(translate("Toggle break action")),
+		),
+		(
+			key_bindings.break_every.clone(),
+			String::from(translate("Insert a break after every number of commits")),
+		),
+		(
+			key_bindings.remove_breaks.clone(),
+			String::from(translate("Remove all of the break lines")),
+		),
+		(
+			key_bindings.toggle_group.clone(),
+			String::from(translate("Collapse or expand the group of lines of the selected line")),
+		),
+		(
+			key_bindings.name_group.clone(),
+			String::from(translate("Name the group of lines of the selected line")),
+		),
+		(
+			key_bindings.toggle_all_folds.clone(),
+			String::from(translate("Collapse all of the groups of lines, or expand all of the collapsed lines")),
+		),
+		(
+			key_bindings.toggle_mark.clone(),
+			String::from(translate("Mark or unmark the selected line, to change or move the marked lines together")),
+		),
+		(
+			key_bindings.clear_marks.clone(),
+			String::from(translate("Unmark all of the marked lines")),
+		),
+		(
+			key_bindings.mark_matching.clone(),
+			String::from(translate("Mark the lines of an action, the commits of an author, or the filtered commits")),
+		),
+		(
+			key_bindings.start_move.clone(),
+			String::from(translate("Move the selected line, with a preview of its new position")),
+		),
+		(
+			key_bindings.sort_lines.clone(),
+			String::from(translate("Sort the commits of the marked lines")),
+		),
+		(
+			key_bindings.reverse_lines.clone(),
+			String::from(translate("Reverse the order of the marked lines, or of all of the lines")),
 		),
-		(key_bindings.action_break.clone(), String::from("Toggle break action")),
 		(
 			key_bindings.action_pick.clone(),
-			String::from("Set selected commit to be picked"),
+			String::from(translate("Set selected commit to be picked")),
 		),
 		(
 			key_bindings.action_reword.clone(),
-			String::from("Set selected commit to be reworded"),
+			String::from(translate("Set selected commit to be reworded")),
 		),
 		(
 			key_bindings.action_edit.clone(),
-			String::from("Set selected commit to be edited"),
+			String::from(translate("Set selected commit to be edited")),
 		),
 		(
 			key_bindings.action_squash.clone(),
-			String::from("Set selected commit to be squashed"),
+			String::from(translate("Set selected commit to be squashed")),
 		),
 		(
 			key_bindings.action_fixup.clone(),
-			String::from("Set selected commit to be fixed-up"),
+			String::from(translate("Set selected commit to be fixed-up")),
+		),
+		(
+			key_bindings.cycle_fixup_option.clone(),
+			String::from(translate("Cycle the option of the selected fixup commit, between none, -C and -c")),
 		),
 		(
 			key_bindings.action_drop.clone(),
-			String::from("Set selected commit to be dropped"),
+			String::from(translate("Set selected commit to be dropped")),
+		),
+		(
+			key_bindings.edit.clone(),
+			String::from(translate("Edit an exec action's command")),
+		),
+		(
+			key_bindings.branch_out.clone(),
+			String::from(translate("Move the selected commit to a new branch")),
+		),
+		(
+			key_bindings.autosquash.clone(),
+			String::from(translate("Preview the autosquash order of the fixup and squash commits")),
+		),
+		(
+			key_bindings.quick_fix.clone(),
+			String::from(translate("Fix the position or action of the selected fixup or squash commit")),
+		),
+		(
+			key_bindings.show_squash_message.clone(),
+			String::from(translate(
+				"Show the message of the commit that a chain of squash commits is squashed into",
+			)),
+		),
+		(
+			key_bindings.show_statistics.clone(),
+			String::from(translate("Show the statistics of the todo list")),
+		),
+		(
+			key_bindings.filter_by_path.clone(),
+			String::from(translate("Show the commits that change a path")),
+		),
+		(
+			key_bindings.show_notifications.clone(),
+			String::from(translate("Show the history of the notifications")),
+		),
+		(
+			key_bindings.show_related_commits.clone(),
+			String::from(translate("Show the other commits that change the files of the selected commit")),
+		),
+		(
+			key_bindings.describe_line.clone(),
+			String::from(translate("Describe the selected line")),
+		),
+		(
+			key_bindings.insert_line.clone(),
+			String::from(translate("Insert a new line")),
+		),
+		(
+			key_bindings.test_commit.clone(),
+			String::from(translate("Insert the exec template that tests the selected commit after it")),
 		),
-		(key_bindings.edit.clone(), String::from("Edit an exec action's command")),
-		(key_bindings.insert_line.clone(), String::from("Insert a new line")),
 		(
 			key_bindings.remove_line.clone(),
-			String::from("Completely remove the selected line"),
+			String::from(translate("Completely remove the selected line")),
+		),
+		(
+			key_bindings.undo.clone(),
+			String::from(translate("Undo the last change")),
 		),
-		(key_bindings.undo.clone(), String::from("Undo the last change")),
 		(
 			key_bindings.redo.clone(),
-			String::from("Redo the previous undone change"),
+			String::from(translate("Redo the previous undone change")),
 		),
 		(
 			key_bindings.open_in_external_editor.clone(),
-			String::from("Open the todo file in the default editor"),
+			String::from(translate("Open the todo file in the default editor")),
+		),
+		(
+			key_bindings.toggle_stash_info.clone(),
+			String::from(translate("Toggle details on uncommitted changes")),
+		),
+		(
+			key_bindings.open_settings.clone(),
+			String::from(translate("Open the settings")),
 		),
 	]
 }
 
 pub(super) fn get_list_visual_mode_help_lines(key_bindings: &KeyBindings) -> Vec<(Vec<String>, String)> {
 	vec![
-		(key_bindings.move_up.clone(), String::from("Move selection up")),
-		(key_bindings.move_down.clone(), String::from("Move selection down")),
+		(
+			key_bindings.move_up.clone(),
+			String::from(translate("Move selection up")),
+		),
+		(
+			key_bindings.move_down.clone(),
+			String::from(translate("Move selection down")),
+		),
 		(
 			key_bindings.move_up_step.clone(),
-			String::from("Move selection up 5 lines"),
+			String::from(translate("Move selection up 5 lines")),
 		),
 		(
 			key_bindings.move_down_step.clone(),
-			String::from("Move selection down 5 lines"),
+			String::from(translate("Move selection down 5 lines")),
 		),
 		(
 			key_bindings.move_home.clone(),
-			String::from("Move selection to top of the list"),
+			String::from(translate("Move selection to top of the list")),
 		),
 		(
 			key_bindings.move_end.clone(),
-			String::from("Move selection to end of the list"),
+			String::from(translate("Move selection to end of the list")),
 		),
 		(
 			key_bindings.move_left.clone(),
-			String::from("Scroll content to the left"),
+			String::from(translate("Scroll content to the left")),
 		),
 		(
 			key_bindings.move_right.clone(),
-			String::from("Scroll content to the right"),
+			String::from(translate("Scroll content to the right")),
 		),
-		(key_bindings.help.clone(), String::from("Show help")),
+		(key_bindings.help.clone(), String::from(translate("Show help"))),
 		(
 			key_bindings.move_selection_down.clone(),
-			String::from("Move selected commits down"),
+			String::from(translate("Move selected commits down")),
 		),
 		(
 			key_bindings.move_selection_up.clone(),
-			String::from("Move selected commits up"),
+			String::from(translate("Move selected commits up")),
 		),
 		(
 			key_bindings.action_pick.clone(),
-			String::from("Set selected commits to be picked"),
+			String::from(translate("Set selected commits to be picked")),
 		),
 		(
 			key_bindings.action_reword.clone(),
-			String::from("Set selected commits to be reworded"),
+			String::from(translate("Set selected commits to be reworded")),
 		),
 		(
 			key_bindings.action_edit.clone(),
-			String::from("Set selected commits to be edited"),
+			String::from(translate("Set selected commits to be edited")),
 		),
 		(
 			key_bindings.action_squash.clone(),
-			String::from("Set selected commits to be squashed"),
+			String::from(translate("Set selected commits to be squashed")),
 		),
 		(
 			key_bindings.action_fixup.clone(),
-			String::from("Set selected commits to be fixed-up"),
+			String::from(translate("Set selected commits to be fixed-up")),
+		),
+		(
+			key_bindings.cycle_fixup_option.clone(),
+			String::from(translate("Cycle the option of the selected fixup commits, between none, -C and -c")),
 		),
 		(
 			key_bindings.action_drop.clone(),
-			String::from("Set selected commits to be dropped"),
+			String::from(translate("Set selected commits to be dropped")),
 		),
 		(
 			key_bindings.remove_line.clone(),
-			String::from("Completely remove the selected lines"),
+			String::from(translate("Completely remove the selected lines")),
+		),
+		(
+			key_bindings.branch_out.clone(),
+			String::from(translate("Move the selected commits to a new branch")),
+		),
+		(
+			key_bindings.describe_line.clone(),
+			String::from(translate("Describe the selected line")),
+		),
+		(
+			key_bindings.show_commit.clone(),
+			String::from(translate("Show the combined changes of the selected commits")),
+		),
+		(
+			key_bindings.toggle_group.clone(),
+			String::from(translate("Collapse the selected lines to a single row")),
+		),
+		(
+			key_bindings.toggle_mark.clone(),
+			String::from(translate("Mark or unmark the selected lines")),
+		),
+		(
+			key_bindings.start_move.clone(),
+			String::from(translate("Move the selected lines, with a preview of their new position")),
+		),
+		(
+			key_bindings.sort_lines.clone(),
+			String::from(translate("Sort the commits of the selected lines")),
+		),
+		(
+			key_bindings.reverse_lines.clone(),
+			String::from(translate("Reverse the order of the selected lines")),
+		),
+		(
+			key_bindings.clear_marks.clone(),
+			String::from(translate("Unmark all of the marked lines")),
+		),
+		(
+			key_bindings.undo.clone(),
+			String::from(translate("Undo the last change")),
 		),
-		(key_bindings.undo.clone(), String::from("Undo the last change")),
 		(
 			key_bindings.redo.clone(),
-			String::from("Redo the previous undone change"),
+			String::from(translate("Redo the previous undone change")),
 		),
 		(
 			key_bindings.toggle_visual_mode.clone(),
-			String::from("Exit visual mode"),
+			String::from(translate("Exit visual mode")),
 		),
 	]
 }
@@ -203,17 +434,22 @@ const fn get_action_color(action: Action) -> DisplayColor {
 
 // safe slice, as it is only on the hash, which is hexadecimal
 #[allow(clippy::string_slice)]
-pub(super) fn get_todo_line_segments(
-	line: &Line,
+fn get_indicator_segment(
 	is_cursor_line: bool,
 	selected: bool,
 	is_full_width: bool,
-) -> Vec<LineSegment> {
-	let mut segments: Vec<LineSegment> = vec![];
-
-	let action = line.get_action();
-
-	let indicator = if is_cursor_line || selected {
+	screen_reader: bool,
+) -> LineSegment {
+	// a screen reader cannot tell the cursor line from the other selected lines by the style of the indicator
+	let indicator = if screen_reader && !is_cursor_line && selected {
+		if is_full_width {
+			" + "
+		}
+		else {
+			"+"
+		}
+	}
+	else if is_cursor_line || selected {
 		if is_full_width {
 			" > "
 		}
@@ -228,20 +464,102 @@ pub(super) fn get_todo_line_segments(
 		" "
 	};
 
-	segments.push(LineSegment::new_with_color_and_style(
-		indicator,
-		DisplayColor::Normal,
-		!is_cursor_line && selected,
-		false,
-		false,
-	));
+	LineSegment::new_with_color_and_style(indicator, DisplayColor::Normal, !is_cursor_line && selected, false, false)
+}
 
-	let action_name = if is_full_width {
-		format!("{:6} ", action.as_string())
+/// Get the row of the original position of the lines that are moved, drawn across the view, but not with a screen
+/// reader, since the glyphs would be read out.
+pub(super) fn get_move_ghost_line(is_full_width: bool, screen_reader: bool) -> ViewLine {
+	let view_line = ViewLine::from(vec![
+		LineSegment::new(if is_full_width { "   " } else { " " }),
+		LineSegment::new_with_color_and_style(
+			translate("Original position "),
+			DisplayColor::IndicatorColor,
+			true,
+			false,
+			false,
+		),
+	]);
+	if screen_reader {
+		view_line
 	}
 	else {
-		format!("{:1} ", action.to_abbreviation())
+		view_line.set_padding_with_color_and_style('┄', DisplayColor::IndicatorColor, true, false, false)
+	}
+}
+
+/// Get the segments of the row that a fold of lines is collapsed to, with the name of the fold, the number of lines,
+/// by their action when the lines all have the same action, and the range of the commits of the lines.
+pub(super) fn get_fold_segments(
+	lines: &[Line],
+	name: Option<&str>,
+	is_cursor_line: bool,
+	selected: bool,
+	context: &RenderContext,
+	hash_length: usize,
+	screen_reader: bool,
+) -> Vec<LineSegment> {
+	let mut segments = vec![
+		get_indicator_segment(is_cursor_line, selected, context.is_full_width(), screen_reader),
+		// the glyph would be read out by a screen reader
+		LineSegment::new_with_color(
+			if screen_reader { translate("Collapsed: ") } else { "▸ " },
+			DisplayColor::IndicatorColor,
+		),
+	];
+	if let Some(name) = name {
+		segments.push(LineSegment::new_with_color(
+			format!("{name}: ").as_str(),
+			DisplayColor::IndicatorColor,
+		));
+	}
+	// the lines of a single action, such as a range of picks, are counted by the action
+	let action = lines.first().map(Line::get_action).filter(|&action| {
+		lines.iter().all(|line| line.get_action() == action)
+	});
+	let count = match action {
+		Some(action) => translate_format("{} {} lines", &[&lines.len(), &action.as_string()]),
+		None => translate_format("{} lines", &[&lines.len()]),
 	};
+	segments.push(LineSegment::new(count.as_str()));
+	let mut hashes = lines
+		.iter()
+		.filter(|line| line.has_reference())
+		.map(|line| line.get_hash().chars().take(hash_length).collect::<String>());
+	if let Some(first_hash) = hashes.next() {
+		let range = match hashes.last() {
+			Some(last_hash) => format!(" {first_hash}..{last_hash}"),
+			None => format!(" {first_hash}"),
+		};
+		segments.push(LineSegment::new_with_color_and_style(
+			range.as_str(),
+			DisplayColor::Normal,
+			true,
+			false,
+			false,
+		));
+	}
+	segments
+}
+
+pub(super) fn get_todo_line_segments(
+	line: &Line,
+	is_cursor_line: bool,
+	selected: bool,
+	context: &RenderContext,
+	action_column: &ActionColumn,
+	hash_length: usize,
+	screen_reader: bool,
+) -> Vec<LineSegment> {
+	let mut segments: Vec<LineSegment> = vec![];
+
+	let is_full_width = context.is_full_width();
+
+	let action = line.get_action();
+
+	segments.push(get_indicator_segment(is_cursor_line, selected, is_full_width, screen_reader));
+
+	let action_name = action_column.format(*action, is_full_width);
 
 	segments.push(LineSegment::new_with_color(
 		action_name.as_str(),
@@ -249,8 +567,11 @@ pub(super) fn get_todo_line_segments(
 	));
 
 	match *action {
-		Action::Drop | Action::Edit | Action::Fixup | Action::Pick | Action::Reword | Action::Squash => {
-			let action_width = if is_full_width { 8 } else { 3 };
+		// on the narrowest windows the hash is hidden, leaving room for the content
+		Action::Drop | Action::Edit | Action::Fixup | Action::Pick | Action::Reword | Action::Squash
+			if context.is_compact_width() =>
+		{
+			let action_width = if is_full_width { hash_length } else { 3 };
 			let max_index = cmp::min(line.get_hash().len(), action_width);
 			segments.push(LineSegment::new(
 				format!(
@@ -261,7 +582,14 @@ pub(super) fn get_todo_line_segments(
 				.as_str(),
 			));
 		},
-		Action::Exec | Action::Label | Action::Reset | Action::Merge | Action::Break | Action::Noop => {},
+		_ => {},
+	}
+	// the `-C` or `-c` option of a fixup line, that uses the message of the fixup commit, is shown with the content
+	if let Some(option) = line.get_option() {
+		segments.push(LineSegment::new_with_color(
+			format!("{option} ").as_str(),
+			get_action_color(*action),
+		));
 	}
 	let content = line.get_content();
 	if !content.is_empty() {
@@ -269,3 +597,174 @@ pub(super) fn get_todo_line_segments(
 	}
 	segments
 }
+
+// the position of the commit of a line in the history of the rebased commits, which is blank for the lines without a
+// commit, so the content of the lines is aligned
+pub(super) fn get_topology_segment(line: &Line, topology: &RangeTopology) -> LineSegment {
+	let glyph = if *line.get_action() == Action::Merge {
+		"◆ "
+	}
+	else {
+		match topology.find(line.get_hash()) {
+			Some(CommitTopology::Mainline) => "● ",
+			Some(CommitTopology::SideBranch) => "○ ",
+			Some(CommitTopology::Merge) => "◆ ",
+			None => "  ",
+		}
+	};
+	LineSegment::new_with_color_and_style(glyph, DisplayColor::Normal, true, false, false)
+}
+
+// a problem with the labels of a rebase with merges, shown after the content of the line with the problem
+pub(super) fn get_lint_segment(lint: &Lint, quick_fix_key: &str) -> LineSegment {
+	let message = match *lint {
+		Lint::UndefinedLabel { ref label, .. } => translate_format("The label {} is not created by any line", &[label]),
+		Lint::LabelDefinedLater {
+			ref label,
+			definition_index,
+			..
+		} => {
+			translate_format("The label {} is not created until line {}", &[
+				label,
+				&(definition_index + 1),
+			])
+		},
+		Lint::DuplicateLabel {
+			ref label, first_index, ..
+		} => translate_format("The label {} is already created on line {}", &[label, &(first_index + 1)]),
+		Lint::UnusedLabel { ref label, .. } => translate_format("The label {} is not used by any line", &[label]),
+		Lint::FixupTargetDropped { target_index, .. } => {
+			translate_format("The commit that this commit fixes is dropped on line {}, press {} to pick this commit", &[
+				&(target_index + 1),
+				&quick_fix_key,
+			])
+		},
+		Lint::FixupTargetLater { target_index, .. } => {
+			translate_format(
+				"The commit that this commit fixes is not picked until line {}, press {} to move this commit after it",
+				&[&(target_index + 1), &quick_fix_key],
+			)
+		},
+		_ => lint.to_string(),
+	};
+	LineSegment::new_with_color(format!("  {}", message).as_str(), DisplayColor::IndicatorColor)
+}
+
+// the source of a cherry picked commit is also in the todo list when a hash of the list is a prefix of its full hash
+pub(super) fn get_cherry_pick_segments(source: &str, todo_file: &TodoFile, hash_length: usize) -> Vec<LineSegment> {
+	let abbreviated_source = source.get(..hash_length).unwrap_or(source);
+	let mut segments = vec![LineSegment::new_with_color_and_style(
+		format!("  {}", translate_format("(cherry picked from {})", &[&abbreviated_source])).as_str(),
+		DisplayColor::Normal,
+		true,
+		false,
+		false,
+	)];
+	if todo_file
+		.lines_iter()
+		.any(|line| line.has_reference() && source.starts_with(line.get_hash()))
+	{
+		segments.push(LineSegment::new_with_color(
+			format!(
+				"  {}",
+				translate("The commit that this commit was cherry picked from is also in the list")
+			)
+			.as_str(),
+			DisplayColor::IndicatorColor,
+		));
+	}
+	segments
+}
+
+pub(super) fn get_commit_details_segments(
+	details: Option<&CommitDetails>,
+	date_format: &DateFormat,
+	date_width: usize,
+) -> Vec<LineSegment> {
+	details.map_or_else(
+		|| {
+			vec![
+				LineSegment::new(format!("{:width$} ", "", width = date_width).as_str()),
+				LineSegment::new(format!("{:4} ", "").as_str()),
+				LineSegment::new(format!("{:5} ", "").as_str()),
+				LineSegment::new(format!("{:5} ", "").as_str()),
+			]
+		},
+		|details| {
+			vec![
+				LineSegment::new_with_color(
+					format!("{:width$} ", format_date(&details.date, date_format), width = date_width).as_str(),
+					DisplayColor::IndicatorColor,
+				),
+				LineSegment::new(format!("{:>3}f ", details.files_changed).as_str()),
+				LineSegment::new_with_color(
+					format!("{:>5} ", format!("+{}", details.insertions)).as_str(),
+					DisplayColor::DiffAddColor,
+				),
+				LineSegment::new_with_color(
+					format!("{:>5} ", format!("-{}", details.deletions)).as_str(),
+					DisplayColor::DiffRemoveColor,
+				),
+			]
+		},
+	)
+}
+
+pub(super) fn get_preview_lines(
+	line: Option<&Line>,
+	details: Option<&CommitDetails>,
+	is_loading: bool,
+	date_format: &DateFormat,
+) -> Vec<ViewLine> {
+	let mut lines = vec![ViewLine::new_empty_line().set_padding_with_color_and_style(
+		'-',
+		DisplayColor::Normal,
+		true,
+		false,
+		false,
+	)];
+	match (line, details) {
+		(Some(line), Some(details)) => {
+			lines.push(ViewLine::from(vec![
+				LineSegment::new_with_color(line.get_hash(), DisplayColor::IndicatorColor),
+				LineSegment::new(" "),
+				LineSegment::new(details.author.as_str()),
+			]));
+			lines.push(ViewLine::from(format_date(&details.date, date_format).as_str()));
+			lines.push(ViewLine::from(details.summary.as_str()));
+			lines.push(ViewLine::from(vec![
+				LineSegment::new(translate_format("{} files changed, ", &[&details.files_changed]).as_str()),
+				LineSegment::new_with_color(
+					translate_format("{} insertions(+)", &[&details.insertions]).as_str(),
+					DisplayColor::DiffAddColor,
+				),
+				LineSegment::new(", "),
+				LineSegment::new_with_color(
+					translate_format("{} deletions(-)", &[&details.deletions]).as_str(),
+					DisplayColor::DiffRemoveColor,
+				),
+			]));
+		},
+		(Some(line), None) if line.has_reference() => {
+			lines.push(ViewLine::from(LineSegment::new_with_color(
+				line.get_hash(),
+				DisplayColor::IndicatorColor,
+			)));
+			lines.push(ViewLine::from(translate(
+				if is_loading {
+					"Loading commit details"
+				}
+				else {
+					"Commit details are not available"
+				},
+			)));
+		},
+		(Some(line), None) => lines.push(ViewLine::from(line.to_text().as_str())),
+		(None, _) => {},
+	}
+	// the pane is always the same height, so that the list does not move when the selected line changes
+	while lines.len() < PREVIEW_PANE_HEIGHT {
+		lines.push(ViewLine::new_empty_line());
+	}
+	lines
+}
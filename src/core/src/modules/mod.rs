@@ -1,19 +1,56 @@
+mod autosquash_preview;
 mod confirm_abort;
+mod confirm_quit;
 mod confirm_rebase;
+mod confirm_rebase_command;
+mod diagnostics;
 mod error;
 mod external_editor;
 mod insert;
 mod list;
+#[cfg(feature = "notification-history")]
+mod notification_history;
+mod review_changes;
+mod settings;
+mod setup_wizard;
 mod show_commit;
+mod squash_message;
 mod window_size_error;
 
+use crate::module::{ModuleContext, Modules};
+
+#[cfg(feature = "fuzz")]
+pub(crate) use self::show_commit::render_commit_diff;
+#[cfg(test)]
+pub(crate) use self::setup_wizard::KeyBindingPreset;
 pub(crate) use self::{
-	confirm_abort::ConfirmAbort,
+	autosquash_preview::AutosquashPreview,
+	confirm_abort::{abort, ConfirmAbort},
+	confirm_quit::ConfirmQuit,
 	confirm_rebase::ConfirmRebase,
+	confirm_rebase_command::{ConfirmRebaseCommand, RebaseCommand},
+	diagnostics::Diagnostics,
 	error::Error,
-	external_editor::ExternalEditor,
+	external_editor::{editor_command, select_editor, ExternalEditor},
 	insert::Insert,
-	list::List,
+	list::{DetailsLoader, FileWatcher, List},
+	review_changes::ReviewChanges,
+	settings::{config_source_name, SETTINGS},
+	setup_wizard::{is_setup_required, SetupWizard},
 	show_commit::ShowCommit,
+	squash_message::SquashMessage,
 	window_size_error::WindowSizeError,
 };
+
+// the optional modules register themselves, so that a build without a module does not need to know about it, and the
+// process only needs to handle a change to a state that has no module
+#[cfg_attr(
+	not(any(feature = "notification-history", feature = "settings-editor")),
+	allow(unused_variables)
+)]
+pub(crate) fn register_optional_modules<'m>(modules: &mut Modules<'m>, context: &ModuleContext<'_, 'm>) {
+	#[cfg(feature = "notification-history")]
+	notification_history::register(modules, context);
+	#[cfg(feature = "settings-editor")]
+	settings::register(modules, context);
+}
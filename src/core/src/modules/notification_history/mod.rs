@@ -0,0 +1,182 @@
+use std::{cell::RefCell, rc::Rc};
+
+use display::DisplayColor;
+use input::{Event, EventHandler, InputOptions};
+use lazy_static::lazy_static;
+use todo_file::TodoFile;
+use view::{handle_view_data_scroll, LineSegment, RenderContext, ViewData, ViewLine, ViewSender};
+
+use crate::{
+	i18n::translate,
+	module::{Module, ModuleContext, Modules, ProcessResult, State},
+	notifications::Notifications,
+};
+
+lazy_static! {
+	static ref INPUT_OPTIONS: InputOptions = InputOptions::new().movement(true);
+}
+
+// the notifications are pushed by the process, and shown in a history by the module
+pub(crate) fn register(modules: &mut Modules<'_>, context: &ModuleContext<'_, '_>) {
+	modules.register_module(
+		State::NotificationHistory,
+		NotificationHistory::new(Rc::clone(context.notifications)),
+	);
+}
+
+/// The history of the notifications, from the newest to the oldest.
+pub(crate) struct NotificationHistory {
+	notifications: Rc<RefCell<Notifications>>,
+	return_state: State,
+	view_data: ViewData,
+}
+
+impl Module for NotificationHistory {
+	fn activate(&mut self, _: &TodoFile, previous_state: State) -> ProcessResult {
+		self.return_state = previous_state;
+		let notifications = self.notifications.borrow();
+		self.view_data.update_view_data(|updater| {
+			updater.clear();
+			updater.push_leading_line(ViewLine::from(LineSegment::new_with_color(
+				translate("Notifications"),
+				DisplayColor::IndicatorColor,
+			)));
+			updater.push_leading_line(ViewLine::new_empty_line());
+			if notifications.is_empty() {
+				updater.push_line(ViewLine::from(LineSegment::new_with_color_and_style(
+					translate("No notifications"),
+					DisplayColor::Normal,
+					true,
+					false,
+					false,
+				)));
+			}
+			for message in notifications.history() {
+				updater.push_line(ViewLine::from(message));
+			}
+			updater.push_trailing_line(ViewLine::from(LineSegment::new_with_color(
+				translate("Press any key to continue"),
+				DisplayColor::IndicatorColor,
+			)));
+		});
+		ProcessResult::new()
+	}
+
+	fn build_view_data(&mut self, _: &RenderContext, _: &TodoFile) -> &ViewData {
+		&self.view_data
+	}
+
+	fn handle_events(
+		&mut self,
+		event_handler: &EventHandler,
+		view_sender: &ViewSender,
+		_: &mut TodoFile,
+	) -> ProcessResult {
+		let event = event_handler.read_event(&INPUT_OPTIONS, |event, _| event);
+		let mut result = ProcessResult::from(event);
+		if handle_view_data_scroll(event, view_sender).is_none() {
+			if let Event::Key(_) = event {
+				result = result.state(self.return_state);
+			}
+		}
+		result
+	}
+}
+
+impl NotificationHistory {
+	pub(crate) fn new(notifications: Rc<RefCell<Notifications>>) -> Self {
+		Self {
+			notifications,
+			return_state: State::List,
+			view_data: ViewData::new(|updater| {
+				updater.set_show_title(true);
+				updater.set_retain_scroll_position(false);
+			}),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::time::Instant;
+
+	use input::MetaEvent;
+	use view::assert_rendered_output;
+
+	use super::*;
+	use crate::{assert_process_result, testutil::module_test};
+
+	fn create_module(messages: &[&str]) -> NotificationHistory {
+		let mut notifications = Notifications::new();
+		for message in messages {
+			notifications.push(message, Instant::now());
+		}
+		NotificationHistory::new(Rc::new(RefCell::new(notifications)))
+	}
+
+	#[test]
+	fn render_newest_first() {
+		module_test(&[], &[], |test_context| {
+			let mut module = create_module(&["First", "Second"]);
+			let _ = test_context.activate(&mut module, State::List);
+			let view_data = test_context.build_view_data(&mut module);
+			assert_rendered_output!(
+				view_data,
+				"{TITLE}",
+				"{LEADING}",
+				"{IndicatorColor}Notifications",
+				"",
+				"{BODY}",
+				"{Normal}Second",
+				"{Normal}First",
+				"{TRAILING}",
+				"{IndicatorColor}Press any key to continue"
+			);
+		});
+	}
+
+	#[test]
+	fn render_empty() {
+		module_test(&[], &[], |test_context| {
+			let mut module = create_module(&[]);
+			let _ = test_context.activate(&mut module, State::List);
+			let view_data = test_context.build_view_data(&mut module);
+			assert_rendered_output!(
+				view_data,
+				"{TITLE}",
+				"{LEADING}",
+				"{IndicatorColor}Notifications",
+				"",
+				"{BODY}",
+				"{Normal,Dimmed}No notifications",
+				"{TRAILING}",
+				"{IndicatorColor}Press any key to continue"
+			);
+		});
+	}
+
+	#[test]
+	fn return_state() {
+		module_test(&[], &[Event::from('a')], |mut test_context| {
+			let mut module = create_module(&["First"]);
+			let _ = test_context.activate(&mut module, State::ShowCommit);
+			assert_process_result!(
+				test_context.handle_event(&mut module),
+				event = Event::from('a'),
+				state = State::ShowCommit
+			);
+		});
+	}
+
+	#[test]
+	fn scroll_event() {
+		module_test(&[], &[Event::from(MetaEvent::ScrollDown)], |mut test_context| {
+			let mut module = create_module(&["First"]);
+			let _ = test_context.activate(&mut module, State::List);
+			assert_process_result!(
+				test_context.handle_event(&mut module),
+				event = Event::from(MetaEvent::ScrollDown)
+			);
+		});
+	}
+}
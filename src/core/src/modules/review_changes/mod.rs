@@ -0,0 +1,155 @@
+#[cfg(all(unix, test))]
+mod tests;
+mod todo_diff;
+
+use config::Config;
+use display::DisplayColor;
+use input::{Event, EventHandler, InputOptions, KeyCode, KeyEvent, MetaEvent};
+use lazy_static::lazy_static;
+use todo_file::{Action, Line, TodoFile};
+use view::{handle_view_data_scroll, LineSegment, RenderContext, ViewData, ViewLine, ViewSender};
+
+use self::todo_diff::{diff_lines, Change, TodoChange};
+use crate::{
+	i18n::{translate, translate_format},
+	module::{Module, ProcessResult, State},
+};
+
+lazy_static! {
+	static ref INPUT_OPTIONS: InputOptions = InputOptions::new().movement(true);
+}
+
+fn count_changes(changes: &[TodoChange], predicate: fn(&Change) -> bool) -> usize {
+	changes.iter().filter(|change| predicate(&change.change)).count()
+}
+
+fn build_change_line(change: &TodoChange) -> ViewLine {
+	let (marker, color) = match change.change {
+		Change::Unchanged => (" ", DisplayColor::Normal),
+		Change::Changed(_) | Change::Moved(_) if *change.line.get_action() == Action::Drop => {
+			("-", DisplayColor::DiffRemoveColor)
+		},
+		Change::Changed(_) => ("~", DisplayColor::DiffChangeColor),
+		Change::Moved(_) => (">", DisplayColor::IndicatorColor),
+		Change::Added => ("+", DisplayColor::DiffAddColor),
+		Change::Removed => ("-", DisplayColor::DiffRemoveColor),
+	};
+	let mut segments = vec![
+		LineSegment::new_with_color(marker, color),
+		LineSegment::new(" "),
+		LineSegment::new_with_color(change.line.to_text().as_str(), color),
+	];
+	if let Change::Changed(previous) | Change::Moved(Some(previous)) = change.change {
+		segments.push(LineSegment::new(" "));
+		segments.push(LineSegment::new_with_color_and_style(
+			translate_format("(was {})", &[&previous.as_string()]).as_str(),
+			DisplayColor::Normal,
+			true,
+			false,
+			false,
+		));
+	}
+	ViewLine::new_with_pinned_segments(segments, 2)
+}
+
+pub(crate) struct ReviewChanges {
+	abort_key: String,
+	is_stale: bool,
+	original_lines: Vec<Line>,
+	rebase_key: String,
+	view_data: ViewData,
+}
+
+impl Module for ReviewChanges {
+	fn activate(&mut self, _: &TodoFile, _: State) -> ProcessResult {
+		self.is_stale = true;
+		ProcessResult::new()
+	}
+
+	fn build_view_data(&mut self, _: &RenderContext, todo_file: &TodoFile) -> &ViewData {
+		// the changes are only compared again when the review is opened, since the todo file cannot change while open
+		if self.is_stale {
+			self.is_stale = false;
+			let changes = diff_lines(&self.original_lines, &todo_file.get_lines_owned());
+			let summary = translate_format("{} moved, {} changed, {} added, {} removed", &[
+				&count_changes(&changes, |change| matches!(*change, Change::Moved(_))),
+				&count_changes(&changes, |change| matches!(*change, Change::Changed(_))),
+				&count_changes(&changes, |change| *change == Change::Added),
+				&count_changes(&changes, |change| *change == Change::Removed),
+			]);
+			let hint = translate_format("Press {} to rebase, or {} to return to the list", &[
+				&self.rebase_key,
+				&self.abort_key,
+			]);
+			self.view_data.update_view_data(|updater| {
+				updater.clear();
+				updater.push_leading_line(ViewLine::from(summary.as_str()));
+				if changes.iter().all(|change| change.change == Change::Unchanged) {
+					updater.push_line(ViewLine::from(translate("The todo file has not been changed")));
+				}
+				for change in &changes {
+					updater.push_line(build_change_line(change));
+				}
+				updater.push_trailing_line(ViewLine::from(LineSegment::new_with_color(
+					hint.as_str(),
+					DisplayColor::IndicatorColor,
+				)));
+			});
+		}
+		&self.view_data
+	}
+
+	fn handle_events(
+		&mut self,
+		event_handler: &EventHandler,
+		view_sender: &ViewSender,
+		_: &mut TodoFile,
+	) -> ProcessResult {
+		let event = event_handler.read_event(&INPUT_OPTIONS, |event, key_bindings| {
+			if key_bindings.rebase.contains(&event) || key_bindings.confirm_yes.contains(&event) {
+				Event::from(MetaEvent::Rebase)
+			}
+			else if key_bindings.abort.contains(&event) {
+				Event::from(MetaEvent::Abort)
+			}
+			else {
+				event
+			}
+		});
+		let mut result = ProcessResult::from(event);
+
+		if handle_view_data_scroll(event, view_sender).is_none() {
+			match event {
+				Event::Meta(MetaEvent::Rebase) => result = result.state(State::ConfirmRebase),
+				Event::Meta(MetaEvent::Abort) | Event::Key(KeyEvent { code: KeyCode::Esc, .. }) => {
+					result = result.state(State::List);
+				},
+				_ => {},
+			}
+		}
+		result
+	}
+
+	fn update_config(&mut self, config: &Config) {
+		self.abort_key = Self::first_key(&config.key_bindings.abort, "q");
+		self.rebase_key = Self::first_key(&config.key_bindings.rebase, "w");
+	}
+}
+
+impl ReviewChanges {
+	pub(crate) fn new(config: &Config, original_lines: Vec<Line>) -> Self {
+		Self {
+			abort_key: Self::first_key(&config.key_bindings.abort, "q"),
+			is_stale: true,
+			original_lines,
+			rebase_key: Self::first_key(&config.key_bindings.rebase, "w"),
+			view_data: ViewData::new(|updater| {
+				updater.set_show_title(true);
+			}),
+		}
+	}
+
+	fn first_key(keys: &[String], default: &str) -> String {
+		keys.first().map_or(String::from(default), String::from)
+	}
+}
@@ -0,0 +1,143 @@
+use view::assert_rendered_output;
+
+use super::*;
+use crate::{assert_process_result, testutil::module_test};
+
+fn create_module(lines: &[&str]) -> ReviewChanges {
+	ReviewChanges::new(
+		&Config::new(),
+		lines.iter().map(|line| Line::new(line).unwrap()).collect(),
+	)
+}
+
+#[test]
+fn build_view_data() {
+	module_test(
+		&["pick ccc c3", "pick aaa c1", "fixup bbb c2", "drop ddd c4", "break"],
+		&[],
+		|test_context| {
+			let mut module = create_module(&["pick aaa c1", "pick bbb c2", "pick ccc c3", "pick ddd c4", "exec make"]);
+			let view_data = test_context.build_view_data(&mut module);
+			assert_rendered_output!(
+				view_data,
+				"{TITLE}",
+				"{LEADING}",
+				"{Normal}1 moved, 2 changed, 1 added, 1 removed",
+				"{BODY}",
+				"{IndicatorColor}>{Normal} {IndicatorColor}pick ccc c3",
+				"{Normal} {Normal} {Normal}pick aaa c1",
+				"{DiffChangeColor}~{Normal} {DiffChangeColor}fixup bbb c2{Normal} {Normal,Dimmed}(was pick)",
+				"{DiffRemoveColor}-{Normal} {DiffRemoveColor}drop ddd c4{Normal} {Normal,Dimmed}(was pick)",
+				"{DiffRemoveColor}-{Normal} {DiffRemoveColor}exec make",
+				"{DiffAddColor}+{Normal} {DiffAddColor}break",
+				"{TRAILING}",
+				"{IndicatorColor}Press w to rebase, or q to return to the list"
+			);
+		},
+	);
+}
+
+#[test]
+fn build_view_data_without_changes() {
+	module_test(&["pick aaa c1"], &[], |test_context| {
+		let mut module = create_module(&["pick aaa c1"]);
+		let view_data = test_context.build_view_data(&mut module);
+		assert_rendered_output!(
+			view_data,
+			"{TITLE}",
+			"{LEADING}",
+			"{Normal}0 moved, 0 changed, 0 added, 0 removed",
+			"{BODY}",
+			"{Normal}The todo file has not been changed",
+			"{Normal} {Normal} {Normal}pick aaa c1",
+			"{TRAILING}",
+			"{IndicatorColor}Press w to rebase, or q to return to the list"
+		);
+	});
+}
+
+#[test]
+fn activate() {
+	module_test(&["pick aaa c1"], &[], |test_context| {
+		let mut module = create_module(&["pick aaa c1"]);
+		let _ = test_context.build_view_data(&mut module);
+		assert_process_result!(test_context.activate(&mut module, State::List));
+		assert!(module.is_stale);
+	});
+}
+
+#[test]
+fn rebase() {
+	module_test(&["pick aaa c1"], &[Event::from('w')], |mut test_context| {
+		let mut module = create_module(&["pick aaa c1"]);
+		assert_process_result!(
+			test_context.handle_event(&mut module),
+			event = Event::from(MetaEvent::Rebase),
+			state = State::ConfirmRebase
+		);
+	});
+}
+
+#[test]
+fn confirm_yes() {
+	module_test(&["pick aaa c1"], &[Event::from('y')], |mut test_context| {
+		let mut module = create_module(&["pick aaa c1"]);
+		assert_process_result!(
+			test_context.handle_event(&mut module),
+			event = Event::from(MetaEvent::Rebase),
+			state = State::ConfirmRebase
+		);
+	});
+}
+
+#[test]
+fn abort() {
+	module_test(&["pick aaa c1"], &[Event::from('q')], |mut test_context| {
+		let mut module = create_module(&["pick aaa c1"]);
+		assert_process_result!(
+			test_context.handle_event(&mut module),
+			event = Event::from(MetaEvent::Abort),
+			state = State::List
+		);
+	});
+}
+
+#[test]
+fn escape() {
+	module_test(&["pick aaa c1"], &[Event::from(KeyCode::Esc)], |mut test_context| {
+		let mut module = create_module(&["pick aaa c1"]);
+		assert_process_result!(
+			test_context.handle_event(&mut module),
+			event = Event::from(KeyCode::Esc),
+			state = State::List
+		);
+	});
+}
+
+#[test]
+fn scroll() {
+	module_test(
+		&["pick aaa c1"],
+		&[Event::from(MetaEvent::ScrollDown)],
+		|mut test_context| {
+			let mut module = create_module(&["pick aaa c1"]);
+			assert_process_result!(
+				test_context.handle_event(&mut module),
+				event = Event::from(MetaEvent::ScrollDown)
+			);
+		},
+	);
+}
+
+#[test]
+fn update_config() {
+	module_test(&["pick aaa c1"], &[], |_| {
+		let mut module = create_module(&["pick aaa c1"]);
+		let mut config = Config::new();
+		config.key_bindings.abort = vec![String::from("x")];
+		config.key_bindings.rebase = vec![String::from("r")];
+		module.update_config(&config);
+		assert_eq!(module.abort_key, "x");
+		assert_eq!(module.rebase_key, "r");
+	});
+}
@@ -0,0 +1,205 @@
+use std::collections::HashMap;
+
+use todo_file::{Action, Line};
+
+#[derive(Clone, Debug, PartialEq)]
+pub(super) enum Change {
+	Unchanged,
+	// the action of the line was changed
+	Changed(Action),
+	// the line was moved, and its action may also have been changed
+	Moved(Option<Action>),
+	Added,
+	Removed,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub(super) struct TodoChange {
+	pub(super) change: Change,
+	pub(super) line: Line,
+}
+
+impl TodoChange {
+	const fn new(change: Change, line: Line) -> Self {
+		Self { change, line }
+	}
+}
+
+// A line with a commit is the same line for as long as it has the same commit, whatever its action, any other line is
+// only the same line when it has the same text.
+fn line_key(line: &Line) -> String {
+	if line.has_reference() {
+		String::from(line.get_hash())
+	}
+	else {
+		line.to_text()
+	}
+}
+
+fn changed_action(original: &Line, edited: &Line) -> Option<Action> {
+	(original.get_action() != edited.get_action()).then(|| *original.get_action())
+}
+
+// The changes from the original lines to the edited lines, in the order of the edited lines, with each removed line
+// in the position it had in the original lines. The lines in the longest common subsequence of the two are unchanged,
+// or have a changed action, and a line that is removed from one position and added in another is moved.
+pub(super) fn diff_lines(original: &[Line], edited: &[Line]) -> Vec<TodoChange> {
+	let original_keys: Vec<String> = original.iter().map(line_key).collect();
+	let edited_keys: Vec<String> = edited.iter().map(line_key).collect();
+
+	// the length of the longest common subsequence of the lines from each pair of positions to the end of the lines
+	let mut lengths = vec![vec![0_usize; edited.len() + 1]; original.len() + 1];
+	for (original_index, original_key) in original_keys.iter().enumerate().rev() {
+		for (edited_index, edited_key) in edited_keys.iter().enumerate().rev() {
+			lengths[original_index][edited_index] = if original_key == edited_key {
+				lengths[original_index + 1][edited_index + 1] + 1
+			}
+			else {
+				lengths[original_index + 1][edited_index].max(lengths[original_index][edited_index + 1])
+			};
+		}
+	}
+
+	let mut changes = vec![];
+	let (mut original_index, mut edited_index) = (0, 0);
+	while original_index < original.len() || edited_index < edited.len() {
+		let is_original_remaining = original_index < original.len();
+		let is_edited_remaining = edited_index < edited.len();
+		if is_original_remaining && is_edited_remaining && original_keys[original_index] == edited_keys[edited_index] {
+			let change = changed_action(&original[original_index], &edited[edited_index])
+				.map_or(Change::Unchanged, Change::Changed);
+			changes.push(TodoChange::new(change, edited[edited_index].clone()));
+			original_index += 1;
+			edited_index += 1;
+		}
+		// a removed line is shown before a line that is added in its place
+		else if is_original_remaining
+			&& (!is_edited_remaining
+				|| lengths[original_index + 1][edited_index] >= lengths[original_index][edited_index + 1])
+		{
+			changes.push(TodoChange::new(Change::Removed, original[original_index].clone()));
+			original_index += 1;
+		}
+		else {
+			changes.push(TodoChange::new(Change::Added, edited[edited_index].clone()));
+			edited_index += 1;
+		}
+	}
+
+	// a line that is both removed and added was moved, and is only shown in its new position
+	let mut removed: HashMap<String, Vec<usize>> = HashMap::new();
+	for (index, change) in changes.iter().enumerate() {
+		if change.change == Change::Removed {
+			removed.entry(line_key(&change.line)).or_default().push(index);
+		}
+	}
+	let mut moved = vec![];
+	for index in 0..changes.len() {
+		if changes[index].change != Change::Added {
+			continue;
+		}
+		if let Some(indexes) = removed.get_mut(&line_key(&changes[index].line)) {
+			if !indexes.is_empty() {
+				let removed_index = indexes.remove(0);
+				let previous_action = changed_action(&changes[removed_index].line, &changes[index].line);
+				changes[index].change = Change::Moved(previous_action);
+				moved.push(removed_index);
+			}
+		}
+	}
+	changes
+		.into_iter()
+		.enumerate()
+		.filter(|&(index, _)| !moved.contains(&index))
+		.map(|(_, change)| change)
+		.collect()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn lines(lines: &[&str]) -> Vec<Line> {
+		lines.iter().map(|line| Line::new(line).unwrap()).collect()
+	}
+
+	fn diff(original: &[&str], edited: &[&str]) -> Vec<(Change, String)> {
+		diff_lines(&lines(original), &lines(edited))
+			.into_iter()
+			.map(|change| (change.change, change.line.to_text()))
+			.collect()
+	}
+
+	#[test]
+	fn unchanged() {
+		assert_eq!(diff(&["pick aaa c1", "pick bbb c2"], &["pick aaa c1", "pick bbb c2"]), vec![
+			(Change::Unchanged, String::from("pick aaa c1")),
+			(Change::Unchanged, String::from("pick bbb c2")),
+		]);
+	}
+
+	#[test]
+	fn action_changed() {
+		assert_eq!(diff(&["pick aaa c1", "pick bbb c2"], &["pick aaa c1", "drop bbb c2"]), vec![
+			(Change::Unchanged, String::from("pick aaa c1")),
+			(Change::Changed(Action::Pick), String::from("drop bbb c2")),
+		]);
+	}
+
+	#[test]
+	fn added_and_removed() {
+		assert_eq!(
+			diff(&["pick aaa c1", "exec make", "pick bbb c2"], &[
+				"pick aaa c1",
+				"pick bbb c2",
+				"break"
+			]),
+			vec![
+				(Change::Unchanged, String::from("pick aaa c1")),
+				(Change::Removed, String::from("exec make")),
+				(Change::Unchanged, String::from("pick bbb c2")),
+				(Change::Added, String::from("break")),
+			]
+		);
+	}
+
+	#[test]
+	fn moved() {
+		assert_eq!(
+			diff(&["pick aaa c1", "pick bbb c2", "pick ccc c3"], &[
+				"pick ccc c3",
+				"pick aaa c1",
+				"pick bbb c2"
+			]),
+			vec![
+				(Change::Moved(None), String::from("pick ccc c3")),
+				(Change::Unchanged, String::from("pick aaa c1")),
+				(Change::Unchanged, String::from("pick bbb c2")),
+			]
+		);
+	}
+
+	#[test]
+	fn moved_with_changed_action() {
+		assert_eq!(
+			diff(&["pick aaa c1", "pick bbb c2", "pick ccc c3"], &[
+				"pick aaa c1",
+				"fixup ccc c3",
+				"pick bbb c2"
+			]),
+			vec![
+				(Change::Unchanged, String::from("pick aaa c1")),
+				(Change::Changed(Action::Pick), String::from("fixup ccc c3")),
+				(Change::Moved(None), String::from("pick bbb c2")),
+			]
+		);
+	}
+
+	#[test]
+	fn all_removed() {
+		assert_eq!(diff(&["pick aaa c1"], &[]), vec![(
+			Change::Removed,
+			String::from("pick aaa c1")
+		)]);
+	}
+}
@@ -0,0 +1,282 @@
+#[cfg(all(unix, test))]
+mod tests;
+
+use anyhow::{anyhow, Result};
+use config::Config;
+use display::DisplayColor;
+use git::{ConfigLocation, ConfigSource, Repository};
+use input::{Event, EventHandler, InputOptions, KeyCode, KeyEvent, MetaEvent};
+use lazy_static::lazy_static;
+use todo_file::TodoFile;
+use view::{LineSegment, RenderContext, ViewData, ViewLine, ViewSender};
+
+use super::{config_source_name, SETTINGS};
+use crate::{
+	components::number_input::NumberInput,
+	i18n::{translate, translate_format},
+	module::{Module, ModuleContext, Modules, ProcessResult, State},
+};
+
+lazy_static! {
+	static ref INPUT_OPTIONS: InputOptions = InputOptions::new();
+}
+
+pub(crate) fn register<'m>(modules: &mut Modules<'m>, context: &ModuleContext<'_, 'm>) {
+	modules.register_module(State::Settings, Settings::new(context.config, context.repository));
+}
+
+pub(crate) struct Settings<'s> {
+	changed: Vec<usize>,
+	close_key: String,
+	config: Config,
+	diagnostics_key: String,
+	editing_number: bool,
+	number_input: NumberInput,
+	repository: &'s Repository,
+	save_global_key: String,
+	save_repository_key: String,
+	selected_index: usize,
+	sources: Vec<ConfigSource>,
+	view_data: ViewData,
+}
+
+impl Module for Settings<'_> {
+	fn build_view_data(&mut self, _: &RenderContext, _: &TodoFile) -> &ViewData {
+		if self.editing_number {
+			return self.number_input.get_view_data();
+		}
+		let name_width = SETTINGS.iter().map(|setting| setting.name().len()).max().unwrap_or(0) + 2;
+		let values: Vec<String> = SETTINGS.iter().map(|setting| setting.value(&self.config)).collect();
+		let value_width = values.iter().map(|value| value.chars().count()).max().unwrap_or(0) + 2;
+		let changed = &self.changed;
+		let sources = &self.sources;
+		let selected_index = self.selected_index;
+		let close_key = self.close_key.as_str();
+		let save_global_key = self.save_global_key.as_str();
+		let save_repository_key = self.save_repository_key.as_str();
+		let diagnostics_key = self.diagnostics_key.as_str();
+
+		self.view_data.update_view_data(|updater| {
+			updater.clear();
+			updater.push_leading_line(ViewLine::from(LineSegment::new_with_color(
+				translate_format(
+					"Enter or Left/Right to change a value, {} to save changes to the global config, {} to save \
+					 changes to the repository config, {} to show diagnostics, {} to close",
+					&[&save_global_key, &save_repository_key, &diagnostics_key, &close_key],
+				)
+				.as_str(),
+				DisplayColor::IndicatorColor,
+			)));
+			updater.push_leading_line(ViewLine::new_empty_line());
+			for (index, (setting, value)) in SETTINGS.iter().zip(values.iter()).enumerate() {
+				let source = if changed.contains(&index) {
+					LineSegment::new_with_color(translate("modified"), DisplayColor::IndicatorColor)
+				}
+				else {
+					LineSegment::new_with_color_and_style(
+						translate(config_source_name(sources[index])),
+						DisplayColor::Normal,
+						true,
+						false,
+						false,
+					)
+				};
+				let mut view_line = ViewLine::new_with_pinned_segments(
+					vec![
+						LineSegment::new(format!("{:width$}", setting.name(), width = name_width).as_str()),
+						LineSegment::new_with_color_and_style(
+							format!("{:width$}", value, width = value_width).as_str(),
+							DisplayColor::Normal,
+							!setting.is_editable(),
+							false,
+							false,
+						),
+						source,
+					],
+					1,
+				);
+				if index == selected_index {
+					view_line = view_line.set_selected(true).set_padding(' ');
+				}
+				updater.push_line(view_line);
+			}
+			updater.ensure_line_visible(selected_index);
+		});
+		&self.view_data
+	}
+
+	fn handle_events(&mut self, event_handler: &EventHandler, _: &ViewSender, _: &mut TodoFile) -> ProcessResult {
+		if self.editing_number {
+			return self.handle_number_input(event_handler);
+		}
+		let event = event_handler.read_event(&INPUT_OPTIONS, |event, key_bindings| {
+			match event {
+				e if key_bindings.abort.contains(&e) => Event::from(MetaEvent::Abort),
+				e if key_bindings.move_up.contains(&e) => Event::from(MetaEvent::MoveCursorUp),
+				e if key_bindings.move_down.contains(&e) => Event::from(MetaEvent::MoveCursorDown),
+				e if key_bindings.move_up_step.contains(&e) => Event::from(MetaEvent::MoveCursorPageUp),
+				e if key_bindings.move_down_step.contains(&e) => Event::from(MetaEvent::MoveCursorPageDown),
+				e if key_bindings.move_home.contains(&e) => Event::from(MetaEvent::MoveCursorHome),
+				e if key_bindings.move_end.contains(&e) => Event::from(MetaEvent::MoveCursorEnd),
+				e if key_bindings.move_left.contains(&e) => Event::from(MetaEvent::MoveCursorLeft),
+				e if key_bindings.move_right.contains(&e) => Event::from(MetaEvent::MoveCursorRight),
+				e if key_bindings.save_global_config.contains(&e) => Event::from(MetaEvent::SaveGlobalConfig),
+				e if key_bindings.save_repository_config.contains(&e) => Event::from(MetaEvent::SaveRepositoryConfig),
+				e if key_bindings.show_diagnostics.contains(&e) => Event::from(MetaEvent::ShowDiagnostics),
+				_ => event,
+			}
+		});
+		let mut result = ProcessResult::from(event);
+		let last_index = SETTINGS.len() - 1;
+
+		match event {
+			Event::Meta(MetaEvent::Abort) | Event::Key(KeyEvent { code: KeyCode::Esc, .. }) => {
+				result = result.state(State::List);
+			},
+			Event::Meta(MetaEvent::MoveCursorUp) => self.selected_index = self.selected_index.saturating_sub(1),
+			Event::Meta(MetaEvent::MoveCursorDown) => self.selected_index = (self.selected_index + 1).min(last_index),
+			Event::Meta(MetaEvent::MoveCursorPageUp) => self.selected_index = self.selected_index.saturating_sub(5),
+			Event::Meta(MetaEvent::MoveCursorPageDown) => {
+				self.selected_index = (self.selected_index + 5).min(last_index);
+			},
+			Event::Meta(MetaEvent::MoveCursorHome) => self.selected_index = 0,
+			Event::Meta(MetaEvent::MoveCursorEnd) => self.selected_index = last_index,
+			Event::Meta(MetaEvent::MoveCursorLeft) => result = self.change_selected(result, false),
+			Event::Key(KeyEvent {
+				code: KeyCode::Enter,
+				..
+			}) if SETTINGS[self.selected_index].bounds().is_some() => self.edit_number(),
+			Event::Meta(MetaEvent::MoveCursorRight)
+			| Event::Key(KeyEvent {
+				code: KeyCode::Enter | KeyCode::Char(' '),
+				..
+			}) => result = self.change_selected(result, true),
+			Event::Meta(MetaEvent::SaveGlobalConfig) => result = self.save(result, ConfigLocation::Global),
+			Event::Meta(MetaEvent::SaveRepositoryConfig) => result = self.save(result, ConfigLocation::Local),
+			Event::Meta(MetaEvent::ShowDiagnostics) => result = result.state(State::Diagnostics),
+			_ => {},
+		}
+		result
+	}
+}
+
+impl<'s> Settings<'s> {
+	pub(crate) fn new(config: &Config, repository: &'s Repository) -> Self {
+		Self {
+			changed: vec![],
+			close_key: Self::first_key(&config.key_bindings.abort, "q"),
+			config: config.clone(),
+			diagnostics_key: Self::first_key(&config.key_bindings.show_diagnostics, "d"),
+			editing_number: false,
+			number_input: NumberInput::new(),
+			repository,
+			save_global_key: Self::first_key(&config.key_bindings.save_global_config, "s"),
+			save_repository_key: Self::first_key(&config.key_bindings.save_repository_config, "r"),
+			selected_index: 0,
+			sources: SETTINGS
+				.iter()
+				.map(|setting| config.source(setting.config_name().as_str()))
+				.collect(),
+			view_data: ViewData::new(|updater| {
+				updater.set_show_title(true);
+			}),
+		}
+	}
+
+	fn first_key(keys: &[String], default: &str) -> String {
+		keys.first().map_or(String::from(default), String::from)
+	}
+
+	fn change_selected(&mut self, result: ProcessResult, forward: bool) -> ProcessResult {
+		if !SETTINGS[self.selected_index].cycle(&mut self.config, forward) {
+			return result;
+		}
+		if !self.changed.contains(&self.selected_index) {
+			self.changed.push(self.selected_index);
+		}
+		result.config(self.config.clone())
+	}
+
+	// a number setting is typed into a number input, and is kept when the input is left empty
+	fn edit_number(&mut self) {
+		let setting = &SETTINGS[self.selected_index];
+		let (minimum, maximum) = match setting.bounds() {
+			Some(bounds) => bounds,
+			None => return,
+		};
+		self.number_input.clear();
+		self.number_input.set_label(format!("{}: ", setting.name()).as_str());
+		self.number_input.set_description(translate("Leave empty to keep the current value"));
+		self.number_input.set_bounds(minimum, maximum);
+		if let Ok(value) = setting.value(&self.config).parse::<usize>() {
+			self.number_input.set_value(value);
+		}
+		self.editing_number = true;
+	}
+
+	fn handle_number_input(&mut self, event_handler: &EventHandler) -> ProcessResult {
+		let result = ProcessResult::from(self.number_input.handle_event(event_handler));
+		if !self.number_input.is_finished() {
+			return result;
+		}
+		self.editing_number = false;
+		let value = match self.number_input.get_value() {
+			Some(value) => value,
+			None => return result,
+		};
+		let setting = &SETTINGS[self.selected_index];
+		if setting.value(&self.config) == value.to_string() {
+			return result;
+		}
+		setting.set_number(&mut self.config, value);
+		if !self.changed.contains(&self.selected_index) {
+			self.changed.push(self.selected_index);
+		}
+		result.config(self.config.clone())
+	}
+
+	fn save(&mut self, result: ProcessResult, location: ConfigLocation) -> ProcessResult {
+		let write_result = self.write_changed(location);
+		log::info!(target: "git", "Saved settings to the {:?} config: {:?}", location, write_result);
+		match write_result {
+			Ok(_) => {
+				result.notification(match location {
+					ConfigLocation::Global => translate("Saved the settings to the global config"),
+					ConfigLocation::Local => translate("Saved the settings to the repository config"),
+				})
+			},
+			Err(err) => result.error(err.context(translate("Unable to save settings"))),
+		}
+	}
+
+	// a setting from the environment variables or the `-c` option is read above the git config, so saving it to the
+	// git config would not change its value, and nothing is saved
+	fn write_changed(&mut self, location: ConfigLocation) -> Result<()> {
+		if let Some(&index) = self
+			.changed
+			.iter()
+			.find(|&&index| matches!(self.sources[index], ConfigSource::Environment | ConfigSource::CommandLine))
+		{
+			return Err(anyhow!(translate_format("{} is set by the {}, and cannot be saved", &[
+				&SETTINGS[index].name(),
+				&translate(config_source_name(self.sources[index])),
+			])));
+		}
+		let mut writer = self.repository.open_config_writer(location)?;
+		while let Some(&index) = self.changed.first() {
+			let setting = &SETTINGS[index];
+			writer.set_string(setting.config_name().as_str(), setting.value(&self.config).as_str())?;
+			self.sources[index] = saved_source(self.sources[index], location);
+			let _ = self.changed.remove(0);
+		}
+		Ok(())
+	}
+}
+
+// a setting that is saved to the global config is still read from the repository config when it is set there
+const fn saved_source(source: ConfigSource, location: ConfigLocation) -> ConfigSource {
+	match (source, location) {
+		(ConfigSource::Local, _) | (_, ConfigLocation::Local) => ConfigSource::Local,
+		(_, ConfigLocation::Global) => ConfigSource::Global,
+	}
+}
@@ -0,0 +1,527 @@
+use git::testutil::with_temp_repository;
+use input::KeyCode;
+use rstest::rstest;
+use view::assert_rendered_output;
+
+use super::*;
+use crate::{assert_process_result, testutil::module_test};
+
+fn index_of(name: &str) -> usize {
+	SETTINGS.iter().position(|setting| setting.name() == name).unwrap()
+}
+
+#[test]
+fn activate() {
+	with_temp_repository(|repository| {
+		module_test(&["pick aaa comment"], &[], |test_context| {
+			let mut module = Settings::new(&Config::new(), &repository);
+			assert_process_result!(test_context.activate(&mut module, State::List));
+		});
+		Ok(())
+	});
+}
+
+#[test]
+fn new_loads_sources() {
+	with_temp_repository(|repository| {
+		let mut git_config = repository.load_config().unwrap();
+		git_config.set_str("interactive-rebase-tool.pickColor", "blue")?;
+		let config = Config::try_from(&git_config).unwrap();
+		let module = Settings::new(&config, &repository);
+		assert_eq!(module.sources[index_of("pickColor")], ConfigSource::Local);
+		assert_eq!(module.sources[index_of("dropColor")], ConfigSource::Default);
+		Ok(())
+	});
+}
+
+#[test]
+fn render() {
+	with_temp_repository(|repository| {
+		module_test(
+			&["pick aaa comment"],
+			&[Event::from(KeyCode::Enter)],
+			|mut test_context| {
+				let mut module = Settings::new(&Config::new(), &repository);
+				let _ = test_context.activate(&mut module, State::List);
+				let _ = test_context.handle_event(&mut module);
+				let view_data = test_context.build_view_data(&mut module);
+				assert_rendered_output!(
+					view_data,
+					"{TITLE}",
+					"{LEADING}",
+					"{IndicatorColor}Enter or Left/Right to change a value, s to save changes to the global config, r \
+					 to save changes to the repository config, d to show diagnostics, q to close",
+					"",
+					"{BODY}",
+					"{Selected}{Normal}abortBehavior              {Normal}exit-code    \
+					 {IndicatorColor}modified{Normal}{Pad( )}",
+					"{Normal}autoSelectNext             {Normal}false        {Normal,Dimmed}default",
+					"{Normal}dateFormat                 {Normal,Dimmed}%c %z        {Normal,Dimmed}default",
+					"{Normal}diffIgnoreWhitespace       {Normal}none         {Normal,Dimmed}default",
+					"{Normal}diffShowWhitespace         {Normal}both         {Normal,Dimmed}default",
+					"{Normal}diffSpaceSymbol            {Normal,Dimmed}·            {Normal,Dimmed}default",
+					"{Normal}diffTabSymbol              {Normal,Dimmed}→            {Normal,Dimmed}default",
+					"{Normal}diffTabWidth               {Normal}4            {Normal,Dimmed}default",
+					"{Normal}fetchMissingObjects        {Normal}false        {Normal,Dimmed}default",
+					"{Normal}hashLength                 {Normal,Dimmed}auto         {Normal,Dimmed}default",
+					"{Normal}icons                      {Normal}false        {Normal,Dimmed}default",
+					"{Normal}keyHints                   {Normal}false        {Normal,Dimmed}default",
+					"{Normal}keyRepeatFastThreshold     {Normal}10           {Normal,Dimmed}default",
+					"{Normal}keyRepeatPageThreshold     {Normal}30           {Normal,Dimmed}default",
+					"{Normal}listDateFormat             {Normal,Dimmed}%Y-%m-%d     {Normal,Dimmed}default",
+					"{Normal}palette                    {Normal,Dimmed}default      {Normal,Dimmed}default",
+					"{Normal}messageEditor              {Normal,Dimmed}             {Normal,Dimmed}default",
+					"{Normal}persistUiState             {Normal}true         {Normal,Dimmed}default",
+					"{Normal}reviewChanges              {Normal}false        {Normal,Dimmed}default",
+					"{Normal}screenReader               {Normal}false        {Normal,Dimmed}default",
+					"{Normal}testCommitBreak            {Normal}false        {Normal,Dimmed}default",
+					"{Normal}testCommitTemplate         {Normal,Dimmed}test         {Normal,Dimmed}default",
+					"{Normal}todoEditor                 {Normal,Dimmed}             {Normal,Dimmed}default",
+					"{Normal}undoLimit                  {Normal}5000         {Normal,Dimmed}default",
+					"{Normal}verticalSpacingCharacter   {Normal,Dimmed}~            {Normal,Dimmed}default",
+					"{Normal}breakLabel                 {Normal,Dimmed}break        {Normal,Dimmed}default",
+					"{Normal}breakAbbreviation          {Normal,Dimmed}b            {Normal,Dimmed}default",
+					"{Normal}dropLabel                  {Normal,Dimmed}drop         {Normal,Dimmed}default",
+					"{Normal}dropAbbreviation           {Normal,Dimmed}d            {Normal,Dimmed}default",
+					"{Normal}editLabel                  {Normal,Dimmed}edit         {Normal,Dimmed}default",
+					"{Normal}editAbbreviation           {Normal,Dimmed}e            {Normal,Dimmed}default",
+					"{Normal}execLabel                  {Normal,Dimmed}exec         {Normal,Dimmed}default",
+					"{Normal}execAbbreviation           {Normal,Dimmed}x            {Normal,Dimmed}default",
+					"{Normal}fixupLabel                 {Normal,Dimmed}fixup        {Normal,Dimmed}default",
+					"{Normal}fixupAbbreviation          {Normal,Dimmed}f            {Normal,Dimmed}default",
+					"{Normal}pickLabel                  {Normal,Dimmed}pick         {Normal,Dimmed}default",
+					"{Normal}pickAbbreviation           {Normal,Dimmed}p            {Normal,Dimmed}default",
+					"{Normal}rewordLabel                {Normal,Dimmed}reword       {Normal,Dimmed}default",
+					"{Normal}rewordAbbreviation         {Normal,Dimmed}r            {Normal,Dimmed}default",
+					"{Normal}squashLabel                {Normal,Dimmed}squash       {Normal,Dimmed}default",
+					"{Normal}squashAbbreviation         {Normal,Dimmed}s            {Normal,Dimmed}default",
+					"{Normal}labelLabel                 {Normal,Dimmed}label        {Normal,Dimmed}default",
+					"{Normal}labelAbbreviation          {Normal,Dimmed}l            {Normal,Dimmed}default",
+					"{Normal}resetLabel                 {Normal,Dimmed}reset        {Normal,Dimmed}default",
+					"{Normal}resetAbbreviation          {Normal,Dimmed}t            {Normal,Dimmed}default",
+					"{Normal}mergeLabel                 {Normal,Dimmed}merge        {Normal,Dimmed}default",
+					"{Normal}mergeAbbreviation          {Normal,Dimmed}m            {Normal,Dimmed}default",
+					"{Normal}breakColor                 {Normal}white        {Normal,Dimmed}default",
+					"{Normal}dropColor                  {Normal}red          {Normal,Dimmed}default",
+					"{Normal}editColor                  {Normal}blue         {Normal,Dimmed}default",
+					"{Normal}execColor                  {Normal}white        {Normal,Dimmed}default",
+					"{Normal}fixupColor                 {Normal}magenta      {Normal,Dimmed}default",
+					"{Normal}pickColor                  {Normal}green        {Normal,Dimmed}default",
+					"{Normal}rewordColor                {Normal}yellow       {Normal,Dimmed}default",
+					"{Normal}squashColor                {Normal}cyan         {Normal,Dimmed}default",
+					"{Normal}labelColor                 {Normal}dark yellow  {Normal,Dimmed}default",
+					"{Normal}resetColor                 {Normal}dark yellow  {Normal,Dimmed}default",
+					"{Normal}mergeColor                 {Normal}dark yellow  {Normal,Dimmed}default",
+					"{Normal}backgroundColor            {Normal}transparent  {Normal,Dimmed}default",
+					"{Normal}diffAddColor               {Normal}green        {Normal,Dimmed}default",
+					"{Normal}diffChangeColor            {Normal}yellow       {Normal,Dimmed}default",
+					"{Normal}diffContextColor           {Normal}white        {Normal,Dimmed}default",
+					"{Normal}diffRemoveColor            {Normal}red          {Normal,Dimmed}default",
+					"{Normal}diffWhitespace             {Normal}black        {Normal,Dimmed}default",
+					"{Normal}foregroundColor            {Normal}transparent  {Normal,Dimmed}default",
+					"{Normal}indicatorColor             {Normal}cyan         {Normal,Dimmed}default",
+					"{Normal}selectedBackgroundColor    {Normal}237          {Normal,Dimmed}default",
+					"{Normal}inputAbort                 {Normal,Dimmed}q            {Normal,Dimmed}default",
+					"{Normal}inputAbortRebase           {Normal,Dimmed}A            {Normal,Dimmed}default",
+					"{Normal}inputActionBreak           {Normal,Dimmed}b            {Normal,Dimmed}default",
+					"{Normal}inputActionDrop            {Normal,Dimmed}d            {Normal,Dimmed}default",
+					"{Normal}inputActionEdit            {Normal,Dimmed}e            {Normal,Dimmed}default",
+					"{Normal}inputActionFixup           {Normal,Dimmed}f            {Normal,Dimmed}default",
+					"{Normal}inputActionPick            {Normal,Dimmed}p            {Normal,Dimmed}default",
+					"{Normal}inputActionReword          {Normal,Dimmed}r            {Normal,Dimmed}default",
+					"{Normal}inputActionSquash          {Normal,Dimmed}s            {Normal,Dimmed}default",
+					"{Normal}inputAutosquash            {Normal,Dimmed}a            {Normal,Dimmed}default",
+					"{Normal}inputBranchOut             {Normal,Dimmed}B            {Normal,Dimmed}default",
+					"{Normal}inputBreakEvery            {Normal,Dimmed}N            {Normal,Dimmed}default",
+					"{Normal}inputClearMarks            {Normal,Dimmed}U            {Normal,Dimmed}default",
+					"{Normal}inputConfirmNo             {Normal,Dimmed}n            {Normal,Dimmed}default",
+					"{Normal}inputConfirmYes            {Normal,Dimmed}y            {Normal,Dimmed}default",
+					"{Normal}inputCycleFixupOption      {Normal,Dimmed}C            {Normal,Dimmed}default",
+					"{Normal}inputDescribeLine          {Normal,Dimmed}L            {Normal,Dimmed}default",
+					"{Normal}inputEdit                  {Normal,Dimmed}E            {Normal,Dimmed}default",
+					"{Normal}inputFilterByPath          {Normal,Dimmed}/            {Normal,Dimmed}default",
+					"{Normal}inputForceAbort            {Normal,Dimmed}Q            {Normal,Dimmed}default",
+					"{Normal}inputForceRebase           {Normal,Dimmed}W            {Normal,Dimmed}default",
+					"{Normal}inputHelp                  {Normal,Dimmed}?            {Normal,Dimmed}default",
+					"{Normal}insertLine                 {Normal,Dimmed}I            {Normal,Dimmed}default",
+					"{Normal}inputJumpToLine            {Normal,Dimmed}:            {Normal,Dimmed}default",
+					"{Normal}inputMarkMatching          {Normal,Dimmed}*            {Normal,Dimmed}default",
+					"{Normal}inputMoveDown              {Normal,Dimmed}Down         {Normal,Dimmed}default",
+					"{Normal}inputMoveEnd               {Normal,Dimmed}End          {Normal,Dimmed}default",
+					"{Normal}inputMoveHome              {Normal,Dimmed}Home         {Normal,Dimmed}default",
+					"{Normal}inputMoveLeft              {Normal,Dimmed}Left         {Normal,Dimmed}default",
+					"{Normal}inputMoveRight             {Normal,Dimmed}Right        {Normal,Dimmed}default",
+					"{Normal}inputMoveSelectionDown     {Normal,Dimmed}j            {Normal,Dimmed}default",
+					"{Normal}inputMoveSelectionUp       {Normal,Dimmed}k            {Normal,Dimmed}default",
+					"{Normal}inputMoveStepDown          {Normal,Dimmed}PageDown     {Normal,Dimmed}default",
+					"{Normal}inputMoveStepUp            {Normal,Dimmed}PageUp       {Normal,Dimmed}default",
+					"{Normal}inputMoveUp                {Normal,Dimmed}Up           {Normal,Dimmed}default",
+					"{Normal}inputNameGroup             {Normal,Dimmed}n            {Normal,Dimmed}default",
+					"{Normal}inputOpenInExternalEditor  {Normal,Dimmed}!            {Normal,Dimmed}default",
+					"{Normal}inputOpenSettings          {Normal,Dimmed}o            {Normal,Dimmed}default",
+					"{Normal}inputQuickFix              {Normal,Dimmed}F            {Normal,Dimmed}default",
+					"{Normal}inputQuitWithoutSaving     {Normal,Dimmed}D            {Normal,Dimmed}default",
+					"{Normal}inputRebase                {Normal,Dimmed}w            {Normal,Dimmed}default",
+					"{Normal}inputRedo                  {Normal,Dimmed}Controly     {Normal,Dimmed}default",
+					"{Normal}inputRemoveBreaks          {Normal,Dimmed}X            {Normal,Dimmed}default",
+					"{Normal}removeLine                 {Normal,Dimmed}Delete       {Normal,Dimmed}default",
+					"{Normal}inputReverseLines          {Normal,Dimmed}~            {Normal,Dimmed}default",
+					"{Normal}inputSaveGlobalConfig      {Normal,Dimmed}s            {Normal,Dimmed}default",
+					"{Normal}inputSaveRepositoryConfig  {Normal,Dimmed}r            {Normal,Dimmed}default",
+					"{Normal}inputShowCommit            {Normal,Dimmed}c            {Normal,Dimmed}default",
+					"{Normal}inputShowDiagnostics       {Normal,Dimmed}d            {Normal,Dimmed}default",
+					"{Normal}inputShowDiff              {Normal,Dimmed}d            {Normal,Dimmed}default",
+					"{Normal}inputShowFile              {Normal,Dimmed}f            {Normal,Dimmed}default",
+					"{Normal}inputShowNotifications     {Normal,Dimmed}H            {Normal,Dimmed}default",
+					"{Normal}inputShowRelatedCommits    {Normal,Dimmed}R            {Normal,Dimmed}default",
+					"{Normal}inputShowSourceCommit      {Normal,Dimmed}o            {Normal,Dimmed}default",
+					"{Normal}inputShowSquashMessage     {Normal,Dimmed}M            {Normal,Dimmed}default",
+					"{Normal}inputShowStatistics        {Normal,Dimmed}%            {Normal,Dimmed}default",
+					"{Normal}inputSkipCommit            {Normal,Dimmed}S            {Normal,Dimmed}default",
+					"{Normal}inputSortLines             {Normal,Dimmed}t            {Normal,Dimmed}default",
+					"{Normal}inputStartMove             {Normal,Dimmed}x            {Normal,Dimmed}default",
+					"{Normal}inputTestCommit            {Normal,Dimmed}T            {Normal,Dimmed}default",
+					"{Normal}inputToggleAllFolds        {Normal,Dimmed}Z            {Normal,Dimmed}default",
+					"{Normal}inputToggleGroup           {Normal,Dimmed}z            {Normal,Dimmed}default",
+					"{Normal}inputToggleMark            {Normal,Dimmed}m            {Normal,Dimmed}default",
+					"{Normal}inputToggleStashInfo       {Normal,Dimmed}i            {Normal,Dimmed}default",
+					"{Normal}inputToggleVisualMode      {Normal,Dimmed}v            {Normal,Dimmed}default",
+					"{Normal}inputUndo                  {Normal,Dimmed}Controlz     {Normal,Dimmed}default"
+				);
+			},
+		);
+		Ok(())
+	});
+}
+
+#[test]
+fn move_cursor() {
+	with_temp_repository(|repository| {
+		module_test(
+			&["pick aaa comment"],
+			&[
+				Event::from(MetaEvent::MoveCursorUp),
+				Event::from(MetaEvent::MoveCursorDown),
+				Event::from(MetaEvent::MoveCursorPageDown),
+				Event::from(MetaEvent::MoveCursorPageUp),
+				Event::from(MetaEvent::MoveCursorEnd),
+				Event::from(MetaEvent::MoveCursorDown),
+				Event::from(MetaEvent::MoveCursorHome),
+			],
+			|mut test_context| {
+				let mut module = Settings::new(&Config::new(), &repository);
+				let _ = test_context.handle_event(&mut module);
+				assert_eq!(module.selected_index, 0);
+				let _ = test_context.handle_event(&mut module);
+				assert_eq!(module.selected_index, 1);
+				let _ = test_context.handle_event(&mut module);
+				assert_eq!(module.selected_index, 6);
+				let _ = test_context.handle_event(&mut module);
+				assert_eq!(module.selected_index, 1);
+				let _ = test_context.handle_event(&mut module);
+				assert_eq!(module.selected_index, SETTINGS.len() - 1);
+				let _ = test_context.handle_event(&mut module);
+				assert_eq!(module.selected_index, SETTINGS.len() - 1);
+				let _ = test_context.handle_event(&mut module);
+				assert_eq!(module.selected_index, 0);
+			},
+		);
+		Ok(())
+	});
+}
+
+#[test]
+fn change_value_applies_config() {
+	with_temp_repository(|repository| {
+		module_test(
+			&["pick aaa comment"],
+			&[Event::from(KeyCode::Enter)],
+			|mut test_context| {
+				let mut module = Settings::new(&Config::new(), &repository);
+				module.selected_index = index_of("autoSelectNext");
+				let result = test_context.handle_event(&mut module);
+				assert!(result.config.unwrap().auto_select_next);
+				assert_eq!(module.changed, vec![index_of("autoSelectNext")]);
+			},
+		);
+		Ok(())
+	});
+}
+
+#[test]
+fn change_value_previous() {
+	with_temp_repository(|repository| {
+		module_test(
+			&["pick aaa comment"],
+			&[Event::from(MetaEvent::MoveCursorLeft)],
+			|mut test_context| {
+				let mut module = Settings::new(&Config::new(), &repository);
+				module.selected_index = index_of("pickColor");
+				let result = test_context.handle_event(&mut module);
+				assert_eq!(result.config.unwrap().theme.color_action_pick, config::Color::LightCyan);
+			},
+		);
+		Ok(())
+	});
+}
+
+#[test]
+fn edit_number_value() {
+	with_temp_repository(|repository| {
+		module_test(
+			&["pick aaa comment"],
+			&[Event::from(KeyCode::Enter)],
+			|mut test_context| {
+				let mut module = Settings::new(&Config::new(), &repository);
+				module.selected_index = index_of("diffTabWidth");
+				let _ = test_context.handle_event(&mut module);
+				assert_rendered_output!(
+					test_context.build_view_data(&mut module),
+					"{TITLE}",
+					"{LEADING}",
+					"{IndicatorColor}Leave empty to keep the current value",
+					"",
+					"{BODY}",
+					"{Normal,Dimmed}diffTabWidth: {Normal}4{Normal,Underline}",
+					"{TRAILING}",
+					"{IndicatorColor}Up and down to change, enter to finish"
+				);
+			},
+		);
+		Ok(())
+	});
+}
+
+#[test]
+fn change_number_value() {
+	with_temp_repository(|repository| {
+		module_test(
+			&["pick aaa comment"],
+			&[
+				Event::from(KeyCode::Enter),
+				Event::from(KeyCode::Backspace),
+				Event::from(KeyCode::Char('8')),
+				Event::from(KeyCode::Enter),
+			],
+			|mut test_context| {
+				let mut module = Settings::new(&Config::new(), &repository);
+				module.selected_index = index_of("diffTabWidth");
+				let _ = test_context.handle_n_events(&mut module, 3);
+				let result = test_context.handle_event(&mut module);
+				assert_eq!(result.config.unwrap().diff_tab_width, 8);
+				assert_eq!(module.changed, vec![index_of("diffTabWidth")]);
+				assert!(!module.editing_number);
+			},
+		);
+		Ok(())
+	});
+}
+
+#[test]
+fn change_number_value_empty() {
+	with_temp_repository(|repository| {
+		module_test(
+			&["pick aaa comment"],
+			&[
+				Event::from(KeyCode::Enter),
+				Event::from(KeyCode::Backspace),
+				Event::from(KeyCode::Enter),
+			],
+			|mut test_context| {
+				let mut module = Settings::new(&Config::new(), &repository);
+				module.selected_index = index_of("diffTabWidth");
+				let _ = test_context.handle_n_events(&mut module, 2);
+				let result = test_context.handle_event(&mut module);
+				assert!(result.config.is_none());
+				assert!(module.changed.is_empty());
+				assert!(!module.editing_number);
+			},
+		);
+		Ok(())
+	});
+}
+
+#[test]
+fn cycle_number_value() {
+	with_temp_repository(|repository| {
+		module_test(
+			&["pick aaa comment"],
+			&[Event::from(MetaEvent::MoveCursorRight)],
+			|mut test_context| {
+				let mut module = Settings::new(&Config::new(), &repository);
+				module.selected_index = index_of("undoLimit");
+				let result = test_context.handle_event(&mut module);
+				assert_eq!(result.config.unwrap().undo_limit, 5001);
+			},
+		);
+		Ok(())
+	});
+}
+
+#[test]
+fn change_read_only_value() {
+	with_temp_repository(|repository| {
+		module_test(
+			&["pick aaa comment"],
+			&[Event::from(KeyCode::Char(' '))],
+			|mut test_context| {
+				let mut module = Settings::new(&Config::new(), &repository);
+				module.selected_index = index_of("todoEditor");
+				let result = test_context.handle_event(&mut module);
+				assert!(result.config.is_none());
+				assert!(module.changed.is_empty());
+			},
+		);
+		Ok(())
+	});
+}
+
+#[test]
+fn save_local() {
+	with_temp_repository(|repository| {
+		module_test(
+			&["pick aaa comment"],
+			&[
+				Event::from(MetaEvent::MoveCursorRight),
+				Event::from(MetaEvent::MoveCursorDown),
+				Event::from(MetaEvent::MoveCursorDown),
+				Event::from(MetaEvent::MoveCursorRight),
+				Event::from('r'),
+			],
+			|mut test_context| {
+				let mut module = Settings::new(&Config::new(), &repository);
+				module.selected_index = index_of("autoSelectNext");
+				let _ = test_context.handle_n_events(&mut module, 4);
+				let result = test_context.handle_event(&mut module);
+				assert_eq!(
+					result.notification,
+					Some(String::from("Saved the settings to the repository config"))
+				);
+				assert_process_result!(result, event = Event::from(MetaEvent::SaveRepositoryConfig));
+				assert!(module.changed.is_empty());
+				assert_eq!(module.sources[index_of("autoSelectNext")], ConfigSource::Local);
+				assert_eq!(module.sources[index_of("diffIgnoreWhitespace")], ConfigSource::Local);
+				let config = repository.load_config().unwrap();
+				assert!(config.get_bool("interactive-rebase-tool.autoSelectNext").unwrap());
+				assert_eq!(
+					config
+						.get_string("interactive-rebase-tool.diffIgnoreWhitespace")
+						.unwrap(),
+					"all"
+				);
+			},
+		);
+		Ok(())
+	});
+}
+
+#[test]
+fn save_local_error() {
+	with_temp_repository(|repository| {
+		let config_path = repository.common_directory().join("config");
+		std::fs::remove_file(config_path.as_path()).unwrap();
+		std::fs::create_dir(config_path.as_path()).unwrap();
+		module_test(
+			&["pick aaa comment"],
+			&[Event::from(MetaEvent::MoveCursorRight), Event::from('r')],
+			|mut test_context| {
+				let mut module = Settings::new(&Config::new(), &repository);
+				let _ = test_context.handle_event(&mut module);
+				let result = test_context.handle_event(&mut module);
+				assert!(format!("{:#}", result.error.unwrap()).starts_with("Unable to save settings"));
+				assert_eq!(module.changed, vec![0]);
+			},
+		);
+		Ok(())
+	});
+}
+
+#[rstest]
+#[case::default_global(ConfigSource::Default, ConfigLocation::Global, ConfigSource::Global)]
+#[case::default_local(ConfigSource::Default, ConfigLocation::Local, ConfigSource::Local)]
+#[case::file_global(ConfigSource::ConfigFile, ConfigLocation::Global, ConfigSource::Global)]
+#[case::global_local(ConfigSource::Global, ConfigLocation::Local, ConfigSource::Local)]
+#[case::local_global(ConfigSource::Local, ConfigLocation::Global, ConfigSource::Local)]
+fn source_after_save(#[case] source: ConfigSource, #[case] location: ConfigLocation, #[case] expected: ConfigSource) {
+	assert_eq!(saved_source(source, location), expected);
+}
+
+#[test]
+fn save_command_line_setting() {
+	with_temp_repository(|repository| {
+		let config = Config::try_from_repository(&repository, &[String::from(
+			"interactive-rebase-tool.autoSelectNext=false",
+		)])
+		.unwrap();
+		module_test(
+			&["pick aaa comment"],
+			&[Event::from(MetaEvent::MoveCursorRight), Event::from('r')],
+			|mut test_context| {
+				let mut module = Settings::new(&config, &repository);
+				module.selected_index = index_of("autoSelectNext");
+				let _ = test_context.handle_event(&mut module);
+				let result = test_context.handle_event(&mut module);
+				assert_eq!(
+					format!("{:#}", result.error.unwrap()),
+					"Unable to save settings: autoSelectNext is set by the command line, and cannot be saved"
+				);
+				assert_eq!(module.changed, vec![index_of("autoSelectNext")]);
+				assert!(repository
+					.load_config()
+					.unwrap()
+					.get_bool("interactive-rebase-tool.autoSelectNext")
+					.is_err());
+			},
+		);
+		Ok(())
+	});
+}
+
+#[test]
+fn show_diagnostics() {
+	with_temp_repository(|repository| {
+		module_test(&["pick aaa comment"], &[Event::from('d')], |mut test_context| {
+			let mut module = Settings::new(&Config::new(), &repository);
+			assert_process_result!(
+				test_context.handle_event(&mut module),
+				event = Event::from(MetaEvent::ShowDiagnostics),
+				state = State::Diagnostics
+			);
+		});
+		Ok(())
+	});
+}
+
+#[test]
+fn abort() {
+	with_temp_repository(|repository| {
+		module_test(&["pick aaa comment"], &[Event::from('q')], |mut test_context| {
+			let mut module = Settings::new(&Config::new(), &repository);
+			assert_process_result!(
+				test_context.handle_event(&mut module),
+				event = Event::from(MetaEvent::Abort),
+				state = State::List
+			);
+		});
+		Ok(())
+	});
+}
+
+#[test]
+fn escape() {
+	with_temp_repository(|repository| {
+		module_test(
+			&["pick aaa comment"],
+			&[Event::from(KeyCode::Esc)],
+			|mut test_context| {
+				let mut module = Settings::new(&Config::new(), &repository);
+				assert_process_result!(
+					test_context.handle_event(&mut module),
+					event = Event::from(KeyCode::Esc),
+					state = State::List
+				);
+			},
+		);
+		Ok(())
+	});
+}
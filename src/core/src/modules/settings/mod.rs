@@ -0,0 +1,23 @@
+#[cfg(feature = "settings-editor")]
+mod editor;
+// the settings are also listed by the diagnostics, so only the editor is left out of a build without the editor
+#[cfg_attr(not(feature = "settings-editor"), allow(dead_code))]
+mod setting;
+
+use git::ConfigSource;
+
+#[cfg(feature = "settings-editor")]
+pub(crate) use self::editor::register;
+pub(crate) use self::setting::SETTINGS;
+
+pub(crate) const fn config_source_name(source: ConfigSource) -> &'static str {
+	match source {
+		ConfigSource::Default => "default",
+		ConfigSource::ConfigFile => "file",
+		ConfigSource::System => "system",
+		ConfigSource::Global => "global",
+		ConfigSource::Local => "local",
+		ConfigSource::Environment => "environment",
+		ConfigSource::CommandLine => "command line",
+	}
+}
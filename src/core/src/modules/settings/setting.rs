@@ -0,0 +1,456 @@
+use config::{AbortBehavior, Color, Config, DiffIgnoreWhitespaceSetting, DiffShowWhitespaceSetting};
+
+const ABORT_BEHAVIOR_OPTIONS: &[&str] = &["empty", "exit-code"];
+const BOOL_OPTIONS: &[&str] = &["false", "true"];
+const COLOR_OPTIONS: &[&str] = &[
+	"transparent",
+	"black",
+	"blue",
+	"cyan",
+	"green",
+	"grey",
+	"magenta",
+	"red",
+	"white",
+	"yellow",
+	"dark black",
+	"dark blue",
+	"dark cyan",
+	"dark green",
+	"dark grey",
+	"dark magenta",
+	"dark red",
+	"dark white",
+	"dark yellow",
+];
+const DIFF_IGNORE_WHITESPACE_OPTIONS: &[&str] = &["none", "all", "change"];
+const DIFF_SHOW_WHITESPACE_OPTIONS: &[&str] = &["none", "trailing", "leading", "both"];
+
+pub(crate) struct Setting {
+	name: &'static str,
+	options: &'static [&'static str],
+	bounds: Option<(usize, usize)>,
+	get: fn(&Config) -> String,
+	set: fn(&mut Config, &str),
+}
+
+impl Setting {
+	const fn new(
+		name: &'static str,
+		options: &'static [&'static str],
+		get: fn(&Config) -> String,
+		set: fn(&mut Config, &str),
+	) -> Self {
+		Self {
+			name,
+			options,
+			bounds: None,
+			get,
+			set,
+		}
+	}
+
+	const fn new_number(
+		name: &'static str,
+		minimum: usize,
+		maximum: usize,
+		get: fn(&Config) -> String,
+		set: fn(&mut Config, &str),
+	) -> Self {
+		Self {
+			name,
+			options: &[],
+			bounds: Some((minimum, maximum)),
+			get,
+			set,
+		}
+	}
+
+	pub(crate) const fn name(&self) -> &'static str {
+		self.name
+	}
+
+	pub(crate) fn config_name(&self) -> String {
+		format!("interactive-rebase-tool.{}", self.name)
+	}
+
+	pub(crate) const fn is_editable(&self) -> bool {
+		!self.options.is_empty() || self.bounds.is_some()
+	}
+
+	/// The minimum and the maximum of a number setting, that is changed with a number input.
+	pub(crate) const fn bounds(&self) -> Option<(usize, usize)> {
+		self.bounds
+	}
+
+	pub(crate) fn value(&self, config: &Config) -> String {
+		(self.get)(config)
+	}
+
+	pub(crate) fn set_number(&self, config: &mut Config, value: usize) {
+		if let Some((minimum, maximum)) = self.bounds {
+			(self.set)(config, value.clamp(minimum, maximum).to_string().as_str());
+		}
+	}
+
+	// Values that are not one of the options, like a color index, continue from the start, or the end, of the options.
+	// A number moves up or down by one, within the bounds of the setting.
+	pub(crate) fn cycle(&self, config: &mut Config, forward: bool) -> bool {
+		if !self.is_editable() {
+			return false;
+		}
+		if let Some((minimum, _)) = self.bounds {
+			let value = self.value(config).parse::<usize>().unwrap_or(minimum);
+			self.set_number(
+				config,
+				if forward {
+					value.saturating_add(1)
+				}
+				else {
+					value.saturating_sub(1)
+				},
+			);
+			return true;
+		}
+		let length = self.options.len();
+		let next_index = match self.options.iter().position(|&option| option == self.value(config)) {
+			Some(index) if forward => (index + 1) % length,
+			Some(index) => (index + length - 1) % length,
+			None if forward => 0,
+			None => length - 1,
+		};
+		(self.set)(config, self.options[next_index]);
+		true
+	}
+}
+
+macro_rules! bool_setting {
+	($name:literal, $($field:ident).+) => {
+		Setting::new(
+			$name,
+			BOOL_OPTIONS,
+			|config| config.$($field).+.to_string(),
+			|config, value| config.$($field).+ = value == "true",
+		)
+	};
+}
+
+macro_rules! color_setting {
+	($name:literal, $field:ident) => {
+		Setting::new(
+			$name,
+			COLOR_OPTIONS,
+			|config| config.theme.$field.to_string(),
+			|config, value| {
+				if let Ok(color) = Color::try_from(value) {
+					config.theme.$field = color;
+				}
+			},
+		)
+	};
+}
+
+macro_rules! number_setting {
+	($name:literal, $minimum:literal, $maximum:literal, $($field:ident).+) => {
+		Setting::new_number(
+			$name,
+			$minimum,
+			$maximum,
+			|config| config.$($field).+.to_string(),
+			|config, value| {
+				if let Ok(number) = value.parse() {
+					config.$($field).+ = number;
+				}
+			},
+		)
+	};
+}
+
+macro_rules! text_setting {
+	($name:literal, $($field:ident).+) => {
+		Setting::new($name, &[], |config| config.$($field).+.to_string(), |_, _| {})
+	};
+}
+
+macro_rules! key_binding_setting {
+	($name:literal, $field:ident) => {
+		Setting::new($name, &[], |config| config.key_bindings.$field.join(" "), |_, _| {})
+	};
+}
+
+pub(crate) const SETTINGS: &[Setting] = &[
+	Setting::new(
+		"abortBehavior",
+		ABORT_BEHAVIOR_OPTIONS,
+		|config| config.abort_behavior.to_string(),
+		|config, value| {
+			config.abort_behavior = match value {
+				"exit-code" => AbortBehavior::ExitCode,
+				_ => AbortBehavior::Empty,
+			};
+		},
+	),
+	bool_setting!("autoSelectNext", auto_select_next),
+	text_setting!("dateFormat", date_format),
+	Setting::new(
+		"diffIgnoreWhitespace",
+		DIFF_IGNORE_WHITESPACE_OPTIONS,
+		|config| config.diff_ignore_whitespace.to_string(),
+		|config, value| {
+			config.diff_ignore_whitespace = match value {
+				"all" => DiffIgnoreWhitespaceSetting::All,
+				"change" => DiffIgnoreWhitespaceSetting::Change,
+				_ => DiffIgnoreWhitespaceSetting::None,
+			};
+		},
+	),
+	Setting::new(
+		"diffShowWhitespace",
+		DIFF_SHOW_WHITESPACE_OPTIONS,
+		|config| config.diff_show_whitespace.to_string(),
+		|config, value| {
+			config.diff_show_whitespace = match value {
+				"trailing" => DiffShowWhitespaceSetting::Trailing,
+				"leading" => DiffShowWhitespaceSetting::Leading,
+				"both" => DiffShowWhitespaceSetting::Both,
+				_ => DiffShowWhitespaceSetting::None,
+			};
+		},
+	),
+	text_setting!("diffSpaceSymbol", diff_space_symbol),
+	text_setting!("diffTabSymbol", diff_tab_symbol),
+	number_setting!("diffTabWidth", 1, 32, diff_tab_width),
+	bool_setting!("fetchMissingObjects", fetch_missing_objects),
+	Setting::new(
+		"hashLength",
+		&[],
+		|config| {
+			config
+				.hash_length
+				.map_or_else(|| String::from("auto"), |length| length.to_string())
+		},
+		|_, _| {},
+	),
+	bool_setting!("icons", icons),
+	bool_setting!("keyHints", key_hints),
+	number_setting!("keyRepeatFastThreshold", 0, 100, key_repeat_fast_threshold),
+	number_setting!("keyRepeatPageThreshold", 0, 100, key_repeat_page_threshold),
+	text_setting!("listDateFormat", list_date_format),
+	// the palette only provides the default colors when the configuration is loaded
+	Setting::new("palette", &[], |config| config.theme.palette.to_string(), |_, _| {}),
+	text_setting!("messageEditor", message_editor),
+	bool_setting!("persistUiState", persist_ui_state),
+	bool_setting!("reviewChanges", review_changes),
+	bool_setting!("screenReader", screen_reader),
+	bool_setting!("testCommitBreak", test_commit_break),
+	text_setting!("testCommitTemplate", test_commit_template),
+	text_setting!("todoEditor", todo_editor),
+	number_setting!("undoLimit", 0, 100_000, undo_limit),
+	text_setting!("verticalSpacingCharacter", theme.character_vertical_spacing),
+	text_setting!("breakLabel", action_labels.action_break.name),
+	text_setting!("breakAbbreviation", action_labels.action_break.abbreviation),
+	text_setting!("dropLabel", action_labels.action_drop.name),
+	text_setting!("dropAbbreviation", action_labels.action_drop.abbreviation),
+	text_setting!("editLabel", action_labels.action_edit.name),
+	text_setting!("editAbbreviation", action_labels.action_edit.abbreviation),
+	text_setting!("execLabel", action_labels.action_exec.name),
+	text_setting!("execAbbreviation", action_labels.action_exec.abbreviation),
+	text_setting!("fixupLabel", action_labels.action_fixup.name),
+	text_setting!("fixupAbbreviation", action_labels.action_fixup.abbreviation),
+	text_setting!("pickLabel", action_labels.action_pick.name),
+	text_setting!("pickAbbreviation", action_labels.action_pick.abbreviation),
+	text_setting!("rewordLabel", action_labels.action_reword.name),
+	text_setting!("rewordAbbreviation", action_labels.action_reword.abbreviation),
+	text_setting!("squashLabel", action_labels.action_squash.name),
+	text_setting!("squashAbbreviation", action_labels.action_squash.abbreviation),
+	text_setting!("labelLabel", action_labels.action_label.name),
+	text_setting!("labelAbbreviation", action_labels.action_label.abbreviation),
+	text_setting!("resetLabel", action_labels.action_reset.name),
+	text_setting!("resetAbbreviation", action_labels.action_reset.abbreviation),
+	text_setting!("mergeLabel", action_labels.action_merge.name),
+	text_setting!("mergeAbbreviation", action_labels.action_merge.abbreviation),
+	color_setting!("breakColor", color_action_break),
+	color_setting!("dropColor", color_action_drop),
+	color_setting!("editColor", color_action_edit),
+	color_setting!("execColor", color_action_exec),
+	color_setting!("fixupColor", color_action_fixup),
+	color_setting!("pickColor", color_action_pick),
+	color_setting!("rewordColor", color_action_reword),
+	color_setting!("squashColor", color_action_squash),
+	color_setting!("labelColor", color_action_label),
+	color_setting!("resetColor", color_action_reset),
+	color_setting!("mergeColor", color_action_merge),
+	color_setting!("backgroundColor", color_background),
+	color_setting!("diffAddColor", color_diff_add),
+	color_setting!("diffChangeColor", color_diff_change),
+	color_setting!("diffContextColor", color_diff_context),
+	color_setting!("diffRemoveColor", color_diff_remove),
+	color_setting!("diffWhitespace", color_diff_whitespace),
+	color_setting!("foregroundColor", color_foreground),
+	color_setting!("indicatorColor", color_indicator),
+	color_setting!("selectedBackgroundColor", color_selected_background),
+	key_binding_setting!("inputAbort", abort),
+	key_binding_setting!("inputAbortRebase", abort_rebase),
+	key_binding_setting!("inputActionBreak", action_break),
+	key_binding_setting!("inputActionDrop", action_drop),
+	key_binding_setting!("inputActionEdit", action_edit),
+	key_binding_setting!("inputActionFixup", action_fixup),
+	key_binding_setting!("inputActionPick", action_pick),
+	key_binding_setting!("inputActionReword", action_reword),
+	key_binding_setting!("inputActionSquash", action_squash),
+	key_binding_setting!("inputAutosquash", autosquash),
+	key_binding_setting!("inputBranchOut", branch_out),
+	key_binding_setting!("inputBreakEvery", break_every),
+	key_binding_setting!("inputClearMarks", clear_marks),
+	key_binding_setting!("inputConfirmNo", confirm_no),
+	key_binding_setting!("inputConfirmYes", confirm_yes),
+	key_binding_setting!("inputCycleFixupOption", cycle_fixup_option),
+	key_binding_setting!("inputDescribeLine", describe_line),
+	key_binding_setting!("inputEdit", edit),
+	key_binding_setting!("inputFilterByPath", filter_by_path),
+	key_binding_setting!("inputForceAbort", force_abort),
+	key_binding_setting!("inputForceRebase", force_rebase),
+	key_binding_setting!("inputHelp", help),
+	key_binding_setting!("insertLine", insert_line),
+	key_binding_setting!("inputJumpToLine", jump_to_line),
+	key_binding_setting!("inputMarkMatching", mark_matching),
+	key_binding_setting!("inputMoveDown", move_down),
+	key_binding_setting!("inputMoveEnd", move_end),
+	key_binding_setting!("inputMoveHome", move_home),
+	key_binding_setting!("inputMoveLeft", move_left),
+	key_binding_setting!("inputMoveRight", move_right),
+	key_binding_setting!("inputMoveSelectionDown", move_selection_down),
+	key_binding_setting!("inputMoveSelectionUp", move_selection_up),
+	key_binding_setting!("inputMoveStepDown", move_down_step),
+	key_binding_setting!("inputMoveStepUp", move_up_step),
+	key_binding_setting!("inputMoveUp", move_up),
+	key_binding_setting!("inputNameGroup", name_group),
+	key_binding_setting!("inputOpenInExternalEditor", open_in_external_editor),
+	key_binding_setting!("inputOpenSettings", open_settings),
+	key_binding_setting!("inputQuickFix", quick_fix),
+	key_binding_setting!("inputQuitWithoutSaving", quit_without_saving),
+	key_binding_setting!("inputRebase", rebase),
+	key_binding_setting!("inputRedo", redo),
+	key_binding_setting!("inputRemoveBreaks", remove_breaks),
+	key_binding_setting!("removeLine", remove_line),
+	key_binding_setting!("inputReverseLines", reverse_lines),
+	key_binding_setting!("inputSaveGlobalConfig", save_global_config),
+	key_binding_setting!("inputSaveRepositoryConfig", save_repository_config),
+	key_binding_setting!("inputShowCommit", show_commit),
+	key_binding_setting!("inputShowDiagnostics", show_diagnostics),
+	key_binding_setting!("inputShowDiff", show_diff),
+	key_binding_setting!("inputShowFile", show_file),
+	key_binding_setting!("inputShowNotifications", show_notifications),
+	key_binding_setting!("inputShowRelatedCommits", show_related_commits),
+	key_binding_setting!("inputShowSourceCommit", show_source_commit),
+	key_binding_setting!("inputShowSquashMessage", show_squash_message),
+	key_binding_setting!("inputShowStatistics", show_statistics),
+	key_binding_setting!("inputSkipCommit", skip_commit),
+	key_binding_setting!("inputSortLines", sort_lines),
+	key_binding_setting!("inputStartMove", start_move),
+	key_binding_setting!("inputTestCommit", test_commit),
+	key_binding_setting!("inputToggleAllFolds", toggle_all_folds),
+	key_binding_setting!("inputToggleGroup", toggle_group),
+	key_binding_setting!("inputToggleMark", toggle_mark),
+	key_binding_setting!("inputToggleStashInfo", toggle_stash_info),
+	key_binding_setting!("inputToggleVisualMode", toggle_visual_mode),
+	key_binding_setting!("inputUndo", undo),
+];
+
+#[cfg(test)]
+mod tests {
+	use git::Config as GitConfig;
+	use rstest::rstest;
+	use tempfile::tempdir;
+
+	use super::*;
+
+	fn find_setting(name: &str) -> &'static Setting {
+		SETTINGS.iter().find(|setting| setting.name() == name).unwrap()
+	}
+
+	#[test]
+	fn names_are_unique() {
+		for (index, setting) in SETTINGS.iter().enumerate() {
+			assert!(
+				SETTINGS[index + 1..].iter().all(|other| other.name() != setting.name()),
+				"{} is duplicated",
+				setting.name()
+			);
+		}
+	}
+
+	#[test]
+	fn editable_values_are_valid_config() {
+		let config = Config::new();
+		let directory = tempdir().unwrap();
+		let mut git_config = GitConfig::open(directory.path().join("config").as_path()).unwrap();
+		for setting in SETTINGS.iter().filter(|setting| setting.is_editable()) {
+			git_config
+				.set_str(setting.config_name().as_str(), setting.value(&config).as_str())
+				.unwrap();
+		}
+		let _config = Config::try_from(&git_config).unwrap();
+	}
+
+	#[rstest]
+	#[case::abort_behavior("abortBehavior", "exit-code", "exit-code")]
+	#[case::bool("autoSelectNext", "true", "true")]
+	#[case::diff_ignore_whitespace("diffIgnoreWhitespace", "all", "change")]
+	#[case::diff_show_whitespace("diffShowWhitespace", "none", "leading")]
+	#[case::color("pickColor", "grey", "cyan")]
+	#[case::color_index("selectedBackgroundColor", "transparent", "dark yellow")]
+	fn cycle(#[case] name: &str, #[case] forward: &str, #[case] backward: &str) {
+		let setting = find_setting(name);
+		let mut config = Config::new();
+		assert!(setting.cycle(&mut config, true));
+		assert_eq!(setting.value(&config), forward);
+		let mut config = Config::new();
+		assert!(setting.cycle(&mut config, false));
+		assert_eq!(setting.value(&config), backward);
+	}
+
+	#[rstest]
+	#[case::tab_width("diffTabWidth", "5", "3")]
+	#[case::undo_limit("undoLimit", "5001", "4999")]
+	fn cycle_number(#[case] name: &str, #[case] forward: &str, #[case] backward: &str) {
+		let setting = find_setting(name);
+		let mut forward_config = Config::new();
+		assert!(setting.cycle(&mut forward_config, true));
+		assert_eq!(setting.value(&forward_config), forward);
+		let mut backward_config = Config::new();
+		assert!(setting.cycle(&mut backward_config, false));
+		assert_eq!(setting.value(&backward_config), backward);
+	}
+
+	#[test]
+	fn cycle_number_within_bounds() {
+		let setting = find_setting("diffTabWidth");
+		let mut config = Config::new();
+		config.diff_tab_width = 1;
+		assert!(setting.cycle(&mut config, false));
+		assert_eq!(setting.value(&config), "1");
+	}
+
+	#[test]
+	fn set_number_within_bounds() {
+		let setting = find_setting("diffTabWidth");
+		let mut config = Config::new();
+		setting.set_number(&mut config, 100);
+		assert_eq!(setting.value(&config), "32");
+	}
+
+	#[test]
+	fn cycle_read_only() {
+		let setting = find_setting("todoEditor");
+		let mut config = Config::new();
+		assert!(!setting.cycle(&mut config, true));
+		assert_eq!(setting.value(&config), "");
+	}
+
+	#[test]
+	fn key_binding_value() {
+		let config = Config::new();
+		assert_eq!(find_setting("inputMoveUp").value(&config), "Up");
+	}
+}
@@ -0,0 +1,187 @@
+mod preset;
+mod setup_step;
+
+#[cfg(all(unix, test))]
+mod tests;
+
+use std::path::PathBuf;
+
+use anyhow::Result;
+use git::{ConfigWriter, Repository};
+use input::EventHandler;
+use todo_file::TodoFile;
+use view::{RenderContext, ViewData, ViewLine, ViewSender};
+
+pub(crate) use self::preset::KeyBindingPreset;
+use self::{preset::ThemePreset, setup_step::SetupStep};
+use crate::{
+	components::choice::Choice,
+	i18n::translate,
+	module::{Module, ProcessResult, State},
+};
+
+const SETUP_COMPLETE_KEY: &str = "interactive-rebase-tool.setupComplete";
+
+// The wizard is only offered until the user has any tool specific configuration, which includes the marker
+// written when the wizard is completed or skipped.
+pub(crate) fn is_setup_required(repo: &Repository) -> bool {
+	repo.load_config().map_or(false, |config| {
+		config
+			.entries(Some("interactive-rebase-tool\\..*"))
+			.map_or(false, |entries| (&entries).next().is_none())
+	})
+}
+
+pub(crate) struct SetupWizard {
+	config_path: Option<PathBuf>,
+	key_binding_choices: Choice<KeyBindingPreset>,
+	key_binding_preset: KeyBindingPreset,
+	step: SetupStep,
+	theme_choices: Choice<ThemePreset>,
+}
+
+impl Module for SetupWizard {
+	fn activate(&mut self, _: &TodoFile, _: State) -> ProcessResult {
+		self.step = SetupStep::KeyBindings;
+		self.key_binding_preset = KeyBindingPreset::Default;
+		ProcessResult::new()
+	}
+
+	fn build_view_data(&mut self, _: &RenderContext, _: &TodoFile) -> &ViewData {
+		match self.step {
+			SetupStep::KeyBindings => self.key_binding_choices.get_view_data(),
+			SetupStep::Theme => self.theme_choices.get_view_data(),
+		}
+	}
+
+	fn handle_events(
+		&mut self,
+		event_handler: &EventHandler,
+		view_sender: &ViewSender,
+		_: &mut TodoFile,
+	) -> ProcessResult {
+		match self.step {
+			SetupStep::KeyBindings => {
+				let (choice, event) = self.key_binding_choices.handle_event(event_handler, view_sender);
+				let mut result = ProcessResult::from(event);
+				match choice {
+					Some(&KeyBindingPreset::Skip) => result = self.finish(result, ThemePreset::Default),
+					Some(&preset) => {
+						self.key_binding_preset = preset;
+						self.step = SetupStep::Theme;
+					},
+					None => {},
+				}
+				result
+			},
+			SetupStep::Theme => {
+				let (choice, event) = self.theme_choices.handle_event(event_handler, view_sender);
+				let result = ProcessResult::from(event);
+				match choice {
+					Some(&theme_preset) => self.finish(result, theme_preset),
+					None => result,
+				}
+			},
+		}
+	}
+}
+
+impl SetupWizard {
+	pub(crate) fn new(config_path: Option<PathBuf>) -> Self {
+		let mut key_binding_choices = Choice::new(vec![
+			(
+				KeyBindingPreset::Default,
+				'd',
+				String::from(translate("Default, arrow keys for movement")),
+			),
+			(
+				KeyBindingPreset::Vim,
+				'v',
+				String::from(translate("Vim, h/j/k/l for movement and J/K to move selected lines")),
+			),
+			(
+				KeyBindingPreset::Skip,
+				'q',
+				String::from(translate("Skip setup and do not ask again")),
+			),
+		]);
+		key_binding_choices.set_prompt(vec![
+			ViewLine::from(translate("Welcome to Git Interactive Rebase Tool!")),
+			ViewLine::from(translate(
+				"Choices are saved to your global git config, and are used the next time the tool is started.",
+			)),
+			ViewLine::new_empty_line(),
+			ViewLine::from(translate("Select a key binding preset:")),
+		]);
+
+		let mut theme_choices = Choice::new(vec![
+			(
+				ThemePreset::Default,
+				'd',
+				String::from(translate("Default, for dark terminal backgrounds")),
+			),
+			(
+				ThemePreset::LightBackground,
+				'l',
+				String::from(translate("Light, for light terminal backgrounds")),
+			),
+			(
+				ThemePreset::Monochrome,
+				'm',
+				String::from(translate("Monochrome, using the terminal colors")),
+			),
+			(
+				ThemePreset::HighContrast,
+				'h',
+				String::from(translate("High contrast, with bright colors")),
+			),
+			(
+				ThemePreset::Deuteranopia,
+				'g',
+				String::from(translate(
+					"Deuteranopia, for green color blindness, with blue and orange in place of green and red",
+				)),
+			),
+			(
+				ThemePreset::Protanopia,
+				'r',
+				String::from(translate(
+					"Protanopia, for red color blindness, with blue and yellow in place of green and red",
+				)),
+			),
+		]);
+		theme_choices.set_prompt(vec![ViewLine::from(translate("Select a theme:"))]);
+
+		Self {
+			config_path,
+			key_binding_choices,
+			key_binding_preset: KeyBindingPreset::Default,
+			step: SetupStep::KeyBindings,
+			theme_choices,
+		}
+	}
+
+	fn finish(&self, result: ProcessResult, theme_preset: ThemePreset) -> ProcessResult {
+		let result = result.state(State::List);
+		match self.write_config(theme_preset) {
+			Ok(_) => result,
+			Err(err) => result.error(err.context(translate("Unable to save setup choices"))),
+		}
+	}
+
+	fn write_config(&self, theme_preset: ThemePreset) -> Result<()> {
+		let mut writer = match self.config_path {
+			Some(ref path) => ConfigWriter::open_path(path.as_path())?,
+			None => ConfigWriter::open_global()?,
+		};
+		for &(name, value) in self
+			.key_binding_preset
+			.config_values()
+			.iter()
+			.chain(theme_preset.config_values())
+		{
+			writer.set_string(name, value)?;
+		}
+		writer.set_bool(SETUP_COMPLETE_KEY, true)
+	}
+}
@@ -0,0 +1,134 @@
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum KeyBindingPreset {
+	Default,
+	Skip,
+	Vim,
+}
+
+impl KeyBindingPreset {
+	pub(crate) const fn config_values(self) -> &'static [(&'static str, &'static str)] {
+		match self {
+			Self::Default | Self::Skip => &[],
+			Self::Vim => {
+				&[
+					("interactive-rebase-tool.inputMoveUp", "Up k"),
+					("interactive-rebase-tool.inputMoveDown", "Down j"),
+					("interactive-rebase-tool.inputMoveLeft", "Left h"),
+					("interactive-rebase-tool.inputMoveRight", "Right l"),
+					("interactive-rebase-tool.inputMoveHome", "Home g"),
+					("interactive-rebase-tool.inputMoveEnd", "End G"),
+					("interactive-rebase-tool.inputMoveSelectionUp", "K"),
+					("interactive-rebase-tool.inputMoveSelectionDown", "J"),
+				]
+			},
+		}
+	}
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum ThemePreset {
+	Default,
+	LightBackground,
+	Monochrome,
+	HighContrast,
+	Deuteranopia,
+	Protanopia,
+}
+
+impl ThemePreset {
+	pub(crate) const fn config_values(self) -> &'static [(&'static str, &'static str)] {
+		match self {
+			Self::Default => &[],
+			Self::LightBackground => {
+				&[
+					("interactive-rebase-tool.breakColor", "dark black"),
+					("interactive-rebase-tool.dropColor", "dark red"),
+					("interactive-rebase-tool.editColor", "dark blue"),
+					("interactive-rebase-tool.execColor", "dark black"),
+					("interactive-rebase-tool.fixupColor", "dark magenta"),
+					("interactive-rebase-tool.pickColor", "dark green"),
+					("interactive-rebase-tool.rewordColor", "dark yellow"),
+					("interactive-rebase-tool.squashColor", "dark cyan"),
+					("interactive-rebase-tool.diffAddColor", "dark green"),
+					("interactive-rebase-tool.diffRemoveColor", "dark red"),
+					("interactive-rebase-tool.indicatorColor", "dark cyan"),
+					("interactive-rebase-tool.selectedBackgroundColor", "255"),
+				]
+			},
+			Self::Monochrome => {
+				&[
+					("interactive-rebase-tool.breakColor", "transparent"),
+					("interactive-rebase-tool.dropColor", "transparent"),
+					("interactive-rebase-tool.editColor", "transparent"),
+					("interactive-rebase-tool.execColor", "transparent"),
+					("interactive-rebase-tool.fixupColor", "transparent"),
+					("interactive-rebase-tool.pickColor", "transparent"),
+					("interactive-rebase-tool.rewordColor", "transparent"),
+					("interactive-rebase-tool.squashColor", "transparent"),
+					("interactive-rebase-tool.labelColor", "transparent"),
+					("interactive-rebase-tool.resetColor", "transparent"),
+					("interactive-rebase-tool.mergeColor", "transparent"),
+					("interactive-rebase-tool.diffAddColor", "transparent"),
+					("interactive-rebase-tool.diffChangeColor", "transparent"),
+					("interactive-rebase-tool.diffRemoveColor", "transparent"),
+					("interactive-rebase-tool.diffContextColor", "transparent"),
+					("interactive-rebase-tool.indicatorColor", "transparent"),
+				]
+			},
+			Self::HighContrast => &[("interactive-rebase-tool.palette", "high-contrast")],
+			Self::Deuteranopia => &[("interactive-rebase-tool.palette", "deuteranopia")],
+			Self::Protanopia => &[("interactive-rebase-tool.palette", "protanopia")],
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use config::{Config, Palette};
+	use git::Config as GitConfig;
+	use rstest::rstest;
+	use tempfile::tempdir;
+
+	use super::*;
+
+	fn load_config(values: &[(&str, &str)]) -> Config {
+		let directory = tempdir().unwrap();
+		let mut git_config = GitConfig::open(directory.path().join("config").as_path()).unwrap();
+		for &(name, value) in values {
+			git_config.set_str(name, value).unwrap();
+		}
+		Config::try_from(&git_config).unwrap()
+	}
+
+	#[rstest]
+	#[case::default(KeyBindingPreset::Default)]
+	#[case::skip(KeyBindingPreset::Skip)]
+	#[case::vim(KeyBindingPreset::Vim)]
+	fn key_binding_preset_values_are_valid(#[case] preset: KeyBindingPreset) {
+		let _config = load_config(preset.config_values());
+	}
+
+	#[test]
+	fn key_binding_preset_vim() {
+		let config = load_config(KeyBindingPreset::Vim.config_values());
+		assert_eq!(config.key_bindings.move_up, vec![String::from("Up"), String::from("k")]);
+		assert_eq!(config.key_bindings.move_selection_up, vec![String::from("K")]);
+	}
+
+	#[rstest]
+	#[case::default(ThemePreset::Default)]
+	#[case::light_background(ThemePreset::LightBackground)]
+	#[case::monochrome(ThemePreset::Monochrome)]
+	#[case::high_contrast(ThemePreset::HighContrast)]
+	#[case::deuteranopia(ThemePreset::Deuteranopia)]
+	#[case::protanopia(ThemePreset::Protanopia)]
+	fn theme_preset_values_are_valid(#[case] preset: ThemePreset) {
+		let _config = load_config(preset.config_values());
+	}
+
+	#[test]
+	fn theme_preset_palette() {
+		let config = load_config(ThemePreset::Protanopia.config_values());
+		assert_eq!(config.theme.palette, Palette::Protanopia);
+	}
+}
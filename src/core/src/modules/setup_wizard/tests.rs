@@ -0,0 +1,200 @@
+use std::path::Path;
+
+use git::{testutil::with_temp_repository, Config};
+use input::Event;
+use tempfile::tempdir;
+use view::assert_rendered_output;
+
+use super::*;
+use crate::{assert_process_result, testutil::module_test};
+
+fn read_config(path: &Path) -> Vec<(String, String)> {
+	let config = Config::open(path).unwrap();
+	let mut values = vec![];
+	let entries = config.entries(None).unwrap();
+	for entry in &entries {
+		let entry = entry.unwrap();
+		values.push((
+			String::from(entry.name().unwrap()),
+			String::from(entry.value().unwrap()),
+		));
+	}
+	values
+}
+
+#[test]
+fn is_setup_required_without_tool_config() {
+	with_temp_repository(|repo| {
+		assert!(is_setup_required(&repo));
+		Ok(())
+	});
+}
+
+#[test]
+fn is_setup_required_with_tool_config() {
+	with_temp_repository(|repo| {
+		repo.load_config()
+			.unwrap()
+			.set_str("interactive-rebase-tool.pickColor", "green")?;
+		assert!(!is_setup_required(&repo));
+		Ok(())
+	});
+}
+
+#[test]
+fn activate() {
+	module_test(&["pick aaa comment"], &[], |test_context| {
+		let mut module = SetupWizard::new(None);
+		assert_process_result!(test_context.activate(&mut module, State::List));
+	});
+}
+
+#[test]
+fn render_key_bindings() {
+	module_test(&["pick aaa comment"], &[], |test_context| {
+		let mut module = SetupWizard::new(None);
+		let view_data = test_context.build_view_data(&mut module);
+		assert_rendered_output!(
+			view_data,
+			"{TITLE}",
+			"{LEADING}",
+			"{Normal}Welcome to Git Interactive Rebase Tool!",
+			"{Normal}Choices are saved to your global git config, and are used the next time the tool is started.",
+			"",
+			"{Normal}Select a key binding preset:",
+			"",
+			"{BODY}",
+			"{Normal}d) Default, arrow keys for movement",
+			"{Normal}v) Vim, h/j/k/l for movement and J/K to move selected lines",
+			"{Normal}q) Skip setup and do not ask again",
+			"",
+			"{IndicatorColor}Please choose an option."
+		);
+	});
+}
+
+#[test]
+fn render_theme() {
+	module_test(&["pick aaa comment"], &[Event::from('d')], |mut test_context| {
+		let mut module = SetupWizard::new(None);
+		let _ = test_context.handle_event(&mut module);
+		let view_data = test_context.build_view_data(&mut module);
+		assert_rendered_output!(
+			view_data,
+			"{TITLE}",
+			"{LEADING}",
+			"{Normal}Select a theme:",
+			"",
+			"{BODY}",
+			"{Normal}d) Default, for dark terminal backgrounds",
+			"{Normal}l) Light, for light terminal backgrounds",
+			"{Normal}m) Monochrome, using the terminal colors",
+			"{Normal}h) High contrast, with bright colors",
+			"{Normal}g) Deuteranopia, for green color blindness, with blue and orange in place of green and red",
+			"{Normal}r) Protanopia, for red color blindness, with blue and yellow in place of green and red",
+			"",
+			"{IndicatorColor}Please choose an option."
+		);
+	});
+}
+
+#[test]
+fn skip() {
+	module_test(&["pick aaa comment"], &[Event::from('q')], |mut test_context| {
+		let directory = tempdir().unwrap();
+		let path = directory.path().join("config");
+		let mut module = SetupWizard::new(Some(path.clone()));
+		assert_process_result!(
+			test_context.handle_event(&mut module),
+			event = Event::from('q'),
+			state = State::List
+		);
+		assert_eq!(read_config(path.as_path()), vec![(
+			String::from("interactive-rebase-tool.setupcomplete"),
+			String::from("true")
+		)]);
+	});
+}
+
+#[test]
+fn select_defaults() {
+	module_test(
+		&["pick aaa comment"],
+		&[Event::from('d'), Event::from('d')],
+		|mut test_context| {
+			let directory = tempdir().unwrap();
+			let path = directory.path().join("config");
+			let mut module = SetupWizard::new(Some(path.clone()));
+			assert_process_result!(test_context.handle_event(&mut module), event = Event::from('d'));
+			assert_process_result!(
+				test_context.handle_event(&mut module),
+				event = Event::from('d'),
+				state = State::List
+			);
+			assert_eq!(read_config(path.as_path()), vec![(
+				String::from("interactive-rebase-tool.setupcomplete"),
+				String::from("true")
+			)]);
+		},
+	);
+}
+
+#[test]
+fn select_presets() {
+	module_test(
+		&["pick aaa comment"],
+		&[Event::from('v'), Event::from('l')],
+		|mut test_context| {
+			let directory = tempdir().unwrap();
+			let path = directory.path().join("config");
+			let mut module = SetupWizard::new(Some(path.clone()));
+			let _ = test_context.handle_event(&mut module);
+			assert_process_result!(
+				test_context.handle_event(&mut module),
+				event = Event::from('l'),
+				state = State::List
+			);
+			let values = read_config(path.as_path());
+			assert!(values.contains(&(
+				String::from("interactive-rebase-tool.inputmoveup"),
+				String::from("Up k")
+			)));
+			assert!(values.contains(&(
+				String::from("interactive-rebase-tool.pickcolor"),
+				String::from("dark green")
+			)));
+			assert!(values.contains(&(
+				String::from("interactive-rebase-tool.setupcomplete"),
+				String::from("true")
+			)));
+		},
+	);
+}
+
+#[test]
+fn invalid_choice() {
+	module_test(&["pick aaa comment"], &[Event::from('x')], |mut test_context| {
+		let mut module = SetupWizard::new(None);
+		assert_process_result!(test_context.handle_event(&mut module), event = Event::from('x'));
+		assert_eq!(module.step, SetupStep::KeyBindings);
+	});
+}
+
+#[test]
+fn write_error() {
+	module_test(&["pick aaa comment"], &[Event::from('q')], |mut test_context| {
+		let directory = tempdir().unwrap();
+		let path = directory.path().join("missing").join("config");
+		let mut module = SetupWizard::new(Some(path.clone()));
+		assert_process_result!(
+			test_context.handle_event(&mut module),
+			event = Event::from('q'),
+			state = State::List,
+			error = anyhow::anyhow!(
+				"Unable to save setup choices: Could not write \"interactive-rebase-tool.setupComplete\": failed to \
+				 create locked file '{}.lock': No such file or directory",
+				path.display()
+			)
+		);
+	});
+}
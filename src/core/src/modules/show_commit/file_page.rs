@@ -0,0 +1,212 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+use git::GitBackend;
+
+/// The number of lines of a file that are loaded, and shown, at a time.
+pub(super) const FILE_PAGE_LINE_COUNT: usize = 1000;
+
+// the same heuristic as Git, a file with a null byte in the first 8000 bytes is a binary file
+const BINARY_CHECK_LENGTH: usize = 8000;
+
+/// A page of the lines of a file at a commit. Only the content of the page is read from the repository, so files of
+/// any size can be shown.
+#[derive(Debug)]
+pub(super) struct FilePage {
+	first_line_number: usize,
+	is_available: bool,
+	is_binary: bool,
+	lines: Vec<String>,
+	next_offset: Option<usize>,
+	path: PathBuf,
+	size: usize,
+}
+
+impl FilePage {
+	/// Load the lines of the file starting at the byte `offset`, which is the start of the line `first_line_number`.
+	pub(super) fn load(
+		repository: &dyn GitBackend,
+		hash: &str,
+		path: &Path,
+		offset: usize,
+		first_line_number: usize,
+	) -> Result<Self> {
+		let mut lines = vec![];
+		let mut partial_line = vec![];
+		let mut end_offset = offset;
+		let mut is_binary = false;
+		let mut is_file_start = offset == 0;
+		let size = repository.read_file_content(hash, path, offset, &mut |chunk| {
+			if is_file_start {
+				is_file_start = false;
+				if chunk.iter().take(BINARY_CHECK_LENGTH).any(|&byte| byte == 0) {
+					is_binary = true;
+					return false;
+				}
+			}
+			let mut remaining = chunk;
+			while lines.len() < FILE_PAGE_LINE_COUNT {
+				if let Some(index) = remaining.iter().position(|&byte| byte == b'\n') {
+					let (line, rest) = remaining.split_at(index);
+					partial_line.extend_from_slice(line);
+					end_offset += partial_line.len() + 1;
+					lines.push(Self::decode_line(partial_line.as_slice()));
+					partial_line.clear();
+					remaining = rest.get(1..).unwrap_or(&[]);
+				}
+				else {
+					partial_line.extend_from_slice(remaining);
+					break;
+				}
+			}
+			lines.len() < FILE_PAGE_LINE_COUNT
+		})?;
+
+		// the last line of a file without a trailing new line
+		if !is_binary && lines.len() < FILE_PAGE_LINE_COUNT && !partial_line.is_empty() {
+			end_offset += partial_line.len();
+			lines.push(Self::decode_line(partial_line.as_slice()));
+		}
+
+		Ok(Self {
+			first_line_number,
+			is_available: true,
+			is_binary,
+			lines: if is_binary { vec![] } else { lines },
+			next_offset: (!is_binary && end_offset < size).then(|| end_offset),
+			path: PathBuf::from(path),
+			size,
+		})
+	}
+
+	/// A page of a file that is not in the repository, such as a file that has not been fetched into a partial clone.
+	pub(super) fn unavailable(path: &Path) -> Self {
+		Self {
+			first_line_number: 1,
+			is_available: false,
+			is_binary: false,
+			lines: vec![],
+			next_offset: None,
+			path: PathBuf::from(path),
+			size: 0,
+		}
+	}
+
+	fn decode_line(line: &[u8]) -> String {
+		String::from_utf8_lossy(line.strip_suffix(b"\r").unwrap_or(line)).into_owned()
+	}
+
+	pub(super) const fn first_line_number(&self) -> usize {
+		self.first_line_number
+	}
+
+	pub(super) const fn is_available(&self) -> bool {
+		self.is_available
+	}
+
+	pub(super) const fn is_binary(&self) -> bool {
+		self.is_binary
+	}
+
+	pub(super) fn lines(&self) -> &[String] {
+		self.lines.as_slice()
+	}
+
+	/// The offset of the next page of the file, or `None` if this is the last page.
+	pub(super) const fn next_offset(&self) -> Option<usize> {
+		self.next_offset
+	}
+
+	/// The line number of the first line of the next page.
+	pub(super) fn next_line_number(&self) -> usize {
+		self.first_line_number + self.lines.len()
+	}
+
+	pub(super) fn path(&self) -> &Path {
+		self.path.as_path()
+	}
+
+	pub(super) const fn size(&self) -> usize {
+		self.size
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use git::testutil::{create_commit, head_id, with_temp_repository, CreateCommitOptions};
+
+	use super::*;
+
+	fn load_page(content: &str, offset: usize, first_line_number: usize) -> FilePage {
+		let mut page = None;
+		with_temp_repository(|repository| {
+			create_commit(&repository, Some(CreateCommitOptions::new().file("file.txt", content)))?;
+			let hash = head_id(&repository, "main").to_string();
+			page = Some(
+				FilePage::load(
+					&repository,
+					hash.as_str(),
+					Path::new("file.txt"),
+					offset,
+					first_line_number,
+				)
+				.unwrap(),
+			);
+			Ok(())
+		});
+		page.unwrap()
+	}
+
+	#[test]
+	fn load_small_file() {
+		let page = load_page("line 1\r\nline 2\nline 3", 0, 1);
+		assert_eq!(page.lines(), &["line 1", "line 2", "line 3"]);
+		assert_eq!(page.first_line_number(), 1);
+		assert_eq!(page.next_offset(), None);
+		assert_eq!(page.size(), 21);
+		assert_eq!(page.path(), Path::new("file.txt"));
+		assert!(page.is_available());
+		assert!(!page.is_binary());
+	}
+
+	#[test]
+	fn load_first_page_of_large_file() {
+		let content = (1..=FILE_PAGE_LINE_COUNT + 5)
+			.map(|line| format!("line {}\n", line))
+			.collect::<String>();
+		let page = load_page(content.as_str(), 0, 1);
+		assert_eq!(page.lines().len(), FILE_PAGE_LINE_COUNT);
+		assert_eq!(page.lines().last().unwrap(), &format!("line {}", FILE_PAGE_LINE_COUNT));
+		assert_eq!(page.next_line_number(), FILE_PAGE_LINE_COUNT + 1);
+		let next_offset = page.next_offset().unwrap();
+		assert_eq!(
+			content.get(..next_offset).unwrap().lines().count(),
+			FILE_PAGE_LINE_COUNT
+		);
+	}
+
+	#[test]
+	fn load_next_page() {
+		let page = load_page("line 1\nline 2\nline 3\n", 7, 2);
+		assert_eq!(page.lines(), &["line 2", "line 3"]);
+		assert_eq!(page.first_line_number(), 2);
+		assert_eq!(page.next_offset(), None);
+	}
+
+	#[test]
+	fn load_binary_file() {
+		let page = load_page("binary\0content", 0, 1);
+		assert!(page.is_binary());
+		assert!(page.lines().is_empty());
+		assert_eq!(page.next_offset(), None);
+	}
+
+	#[test]
+	fn unavailable() {
+		let page = FilePage::unavailable(Path::new("file.txt"));
+		assert!(!page.is_available());
+		assert!(page.lines().is_empty());
+		assert_eq!(page.next_offset(), None);
+		assert_eq!(page.path(), Path::new("file.txt"));
+	}
+}
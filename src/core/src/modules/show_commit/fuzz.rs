@@ -0,0 +1,26 @@
+use config::Config;
+use display::Size;
+use git::CommitDiff;
+use view::{testutil::render_frame, ViewData};
+
+use super::{view_builder::ViewBuilder, ShowCommit};
+
+/// Render the overview and the diff of a commit, in the full width and the compact layouts, to the lines of the frames
+/// that would be drawn in a window of a size. This is the entry point of the diff renderer fuzz target.
+pub(crate) fn render_commit_diff(config: &Config, diff: &CommitDiff, size: Size) -> Vec<String> {
+	let view_builder = ViewBuilder::new(ShowCommit::create_view_builder_options(config));
+	let mut lines = vec![];
+	for is_full_width in [true, false] {
+		let overview_view_data = ViewData::new(|updater| {
+			updater.set_show_title(true);
+			view_builder.build_view_data_for_overview(updater, diff, is_full_width);
+		});
+		lines.extend(render_frame(&overview_view_data, size));
+		let diff_view_data = ViewData::new(|updater| {
+			updater.set_show_title(true);
+			view_builder.build_view_data_diff(updater, diff, is_full_width);
+		});
+		lines.extend(render_frame(&diff_view_data, size));
+	}
+	lines
+}
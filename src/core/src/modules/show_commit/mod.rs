@@ -1,3 +1,6 @@
+mod file_page;
+#[cfg(feature = "fuzz")]
+mod fuzz;
 mod show_commit_state;
 mod util;
 mod view_builder;
@@ -5,23 +8,44 @@ mod view_builder;
 #[cfg(test)]
 mod tests;
 
-use anyhow::anyhow;
+use std::{cell::Cell, rc::Rc, time::Instant};
+
+use anyhow::{anyhow, Result};
 use captur::capture;
-use config::{Config, DiffIgnoreWhitespaceSetting, DiffShowWhitespaceSetting};
-use git::{CommitDiff, CommitDiffLoaderOptions, Repository};
+use config::{Config, DiffIgnoreWhitespaceSetting, DiffShowWhitespaceSetting, Palette};
+use display::DisplayColor;
+use git::{
+	CommitDiff,
+	CommitDiffLoaderOptions,
+	DiffMemoryUsage,
+	FileStatus,
+	GitBackend,
+	ObjectFetch,
+	Repository,
+	Status,
+};
 use input::{Event, EventHandler, InputOptions, MetaEvent};
 use lazy_static::lazy_static;
-use todo_file::TodoFile;
-use view::{handle_view_data_scroll, RenderContext, ViewData, ViewSender};
+use todo_file::{Action, Line, TodoFile};
+use view::{handle_view_data_scroll, LineSegment, RenderContext, ViewData, ViewLine, ViewSender};
 
+#[cfg(feature = "fuzz")]
+pub(crate) use self::fuzz::render_commit_diff;
 use self::{
+	file_page::FilePage,
 	show_commit_state::ShowCommitState,
 	util::get_show_commit_help_lines,
 	view_builder::{ViewBuilder, ViewBuilderOptions},
 };
 use crate::{
-	components::help::Help,
+	components::{
+		help::Help,
+		progress::{Progress, PROGRESS_INTERVAL},
+	},
+	event_bus::{BusEvent, Topic},
+	i18n::{translate, translate_format},
 	module::{Module, ProcessResult, State},
+	timers::Timer,
 };
 
 lazy_static! {
@@ -35,16 +59,30 @@ lazy_static! {
 pub(crate) struct ShowCommit<'s> {
 	commit_diff_loader_options: CommitDiffLoaderOptions,
 	diff: Option<CommitDiff>,
+	diff_memory_usage: Rc<Cell<Option<DiffMemoryUsage>>>,
 	diff_view_data: ViewData,
+	fetch_missing_objects: bool,
+	fetched_object_ids: Vec<String>,
+	file_index: usize,
+	file_page: Option<FilePage>,
+	file_view_data: ViewData,
 	help: Help,
+	object_fetch: Option<ObjectFetch>,
+	object_fetch_progress: Progress,
 	overview_view_data: ViewData,
-	repository: &'s Repository,
+	partial_clone: Option<&'s Repository>,
+	repository: &'s dyn GitBackend,
+	squash_preview: Option<&'s Repository>,
+	squashed_commits: usize,
 	state: ShowCommitState,
 	view_builder: ViewBuilder,
 }
 
 impl Module for ShowCommit<'_> {
 	fn activate(&mut self, rebase_todo: &TodoFile, _: State) -> ProcessResult {
+		if let Some(repository) = self.squash_preview {
+			return self.activate_squash_preview(repository, rebase_todo);
+		}
 		if let Some(selected_line) = rebase_todo.get_selected_line() {
 			// skip loading commit data if the currently loaded commit has not changed, this retains
 			// position after returning to the list view or help
@@ -53,35 +91,42 @@ impl Module for ShowCommit<'_> {
 					return ProcessResult::new();
 				}
 			}
-			self.overview_view_data.update_view_data(|updater| {
-				updater.clear();
-				updater.reset_scroll_position();
-			});
-
-			self.diff_view_data.update_view_data(|updater| {
-				updater.clear();
-				updater.reset_scroll_position();
-			});
+			self.reset_commit_view();
 
+			let start = Instant::now();
 			let new_diff = self
 				.repository
 				.load_commit_diff(selected_line.get_hash(), &self.commit_diff_loader_options);
 
 			match new_diff {
 				Ok(diff) => {
-					self.diff = Some(diff);
-					ProcessResult::new()
+					log::debug!(
+						target: "git",
+						"Loaded diff of {} with {} files in {}ms",
+						selected_line.get_hash(),
+						diff.file_statuses().len(),
+						start.elapsed().as_millis()
+					);
+					self.set_diff(diff);
+					match self.start_object_fetch() {
+						Ok(()) if self.object_fetch.is_some() => {
+							ProcessResult::new().start_timer(Timer::Progress, PROGRESS_INTERVAL)
+						},
+						Ok(()) => ProcessResult::new(),
+						Err(err) => ProcessResult::new().error(err.context(translate("Unable to fetch missing objects"))),
+					}
 				},
 				Err(e) => {
+					log::error!(target: "git", "Unable to load diff of {}: {:#}", selected_line.get_hash(), e);
 					ProcessResult::new()
-						.error(e.context(anyhow!("Error loading commit")))
+						.error(e.context(anyhow!(translate("Error loading commit"))))
 						.state(State::List)
 				},
 			}
 		}
 		else {
 			ProcessResult::new()
-				.error(anyhow!("No valid commit to show"))
+				.error(anyhow!(translate("No valid commit to show")))
 				.state(State::List)
 		}
 	}
@@ -95,6 +140,20 @@ impl Module for ShowCommit<'_> {
 		let state = &self.state;
 		let view_builder = &self.view_builder;
 		let is_full_width = context.is_full_width();
+		let fetch_progress = self
+			.object_fetch
+			.as_ref()
+			.map(|fetch| self.object_fetch_progress.get_line(fetch.elapsed()));
+		let squash_preview = self.squash_preview.is_some().then(|| {
+			ViewLine::from(LineSegment::new_with_color(
+				translate_format(
+					"The combined changes of {} commits, as if they were squashed into the first commit",
+					&[&self.squashed_commits],
+				)
+				.as_str(),
+				DisplayColor::IndicatorColor,
+			))
+		});
 
 		match *state {
 			ShowCommitState::Overview => {
@@ -102,6 +161,12 @@ impl Module for ShowCommit<'_> {
 					self.overview_view_data.update_view_data(|updater| {
 						capture!(view_builder, diff);
 						view_builder.build_view_data_for_overview(updater, diff, is_full_width);
+						if let Some(line) = fetch_progress {
+							updater.push_trailing_line(line);
+						}
+						if let Some(line) = squash_preview {
+							updater.push_trailing_line(line);
+						}
 					});
 				}
 				&self.overview_view_data
@@ -111,10 +176,26 @@ impl Module for ShowCommit<'_> {
 					self.diff_view_data.update_view_data(|updater| {
 						capture!(view_builder, diff);
 						view_builder.build_view_data_diff(updater, diff, is_full_width);
+						if let Some(line) = fetch_progress {
+							updater.push_trailing_line(line);
+						}
+						if let Some(line) = squash_preview {
+							updater.push_trailing_line(line);
+						}
 					});
 				}
 				&self.diff_view_data
 			},
+			ShowCommitState::File => {
+				if self.file_view_data.is_empty() {
+					let file_page = self.file_page.as_ref().unwrap(); // the file state is only set with a page
+					self.file_view_data.update_view_data(|updater| {
+						capture!(view_builder, file_page);
+						view_builder.build_view_data_for_file(updater, file_page);
+					});
+				}
+				&self.file_view_data
+			},
 		}
 	}
 
@@ -132,6 +213,12 @@ impl Module for ShowCommit<'_> {
 			if key_bindings.show_diff.contains(&event) {
 				Event::from(MetaEvent::ShowDiff)
 			}
+			else if key_bindings.show_file.contains(&event) {
+				Event::from(MetaEvent::ShowFile)
+			}
+			else if key_bindings.show_source_commit.contains(&event) {
+				Event::from(MetaEvent::ShowSourceCommit)
+			}
 			else {
 				event
 			}
@@ -142,6 +229,7 @@ impl Module for ShowCommit<'_> {
 		let active_view_data = match self.state {
 			ShowCommitState::Overview => &mut self.overview_view_data,
 			ShowCommitState::Diff => &mut self.diff_view_data,
+			ShowCommitState::File => &mut self.file_view_data,
 		};
 
 		if handle_view_data_scroll(event, view_sender).is_none() {
@@ -149,14 +237,32 @@ impl Module for ShowCommit<'_> {
 				Event::Meta(meta_event) if meta_event == MetaEvent::ShowDiff => {
 					active_view_data.update_view_data(|updater| updater.clear());
 					self.state = match self.state {
-						ShowCommitState::Overview => ShowCommitState::Diff,
+						ShowCommitState::Overview | ShowCommitState::File => ShowCommitState::Diff,
 						ShowCommitState::Diff => ShowCommitState::Overview,
 					}
 				},
+				// the files and the source commit are of a single commit, and not of the combined changes
+				Event::Meta(MetaEvent::ShowFile | MetaEvent::ShowSourceCommit) if self.squash_preview.is_some() => {},
+				Event::Meta(meta_event) if meta_event == MetaEvent::ShowFile => {
+					if let Err(err) = self.show_next_file_page() {
+						result = result.error(err.context(translate("Unable to show file")));
+					}
+				},
+				Event::Meta(meta_event) if meta_event == MetaEvent::ShowSourceCommit => {
+					if let Err(err) = self.show_source_commit() {
+						result = result.error(err.context(translate("Unable to show the source commit")));
+					}
+				},
 				Event::Meta(meta_event) if meta_event == MetaEvent::Help => self.help.set_active(),
+				Event::Key(_) if self.object_fetch.is_some() && Progress::is_cancel_event(event) => {
+					self.cancel_object_fetch();
+				},
 				Event::Key(_) => {
 					active_view_data.update_view_data(|updater| updater.clear());
-					if self.state == ShowCommitState::Diff {
+					if self.state == ShowCommitState::File {
+						self.state = ShowCommitState::Diff;
+					}
+					else if self.state == ShowCommitState::Diff {
 						self.state = ShowCommitState::Overview;
 					}
 					else {
@@ -169,10 +275,33 @@ impl Module for ShowCommit<'_> {
 		}
 		result
 	}
+
+	fn subscriptions(&self) -> &'static [Topic] {
+		&[Topic::Timer]
+	}
+
+	// the view is rebuilt for the progress of a fetch, and for the objects once they have been fetched, on each tick of
+	// the timer that is started with the fetch, and the timer is stopped once there is no fetch
+	fn handle_bus_event(&mut self, event: &BusEvent, _: &TodoFile) -> ProcessResult {
+		match *event {
+			BusEvent::Tick(Timer::Progress) => self
+				.poll_object_fetch()
+				.unwrap_or_else(|| ProcessResult::new().stop_timer(Timer::Progress)),
+			_ => ProcessResult::new(),
+		}
+	}
+
+	fn update_config(&mut self, config: &Config) {
+		self.commit_diff_loader_options = Self::create_commit_diff_loader_options(config);
+		self.fetch_missing_objects = config.fetch_missing_objects;
+		self.view_builder = ViewBuilder::new(Self::create_view_builder_options(config));
+		// the loaded diff depends on the options, so it is loaded again the next time a commit is shown
+		self.diff = None;
+	}
 }
 
 impl<'s> ShowCommit<'s> {
-	pub(crate) fn new(config: &Config, repository: &'s Repository) -> Self {
+	pub(crate) fn new(config: &Config, repository: &'s dyn GitBackend) -> Self {
 		let overview_view_data = ViewData::new(|updater| {
 			updater.set_show_title(true);
 			updater.set_show_help(true);
@@ -181,7 +310,302 @@ impl<'s> ShowCommit<'s> {
 			updater.set_show_title(true);
 			updater.set_show_help(true);
 		});
-		let view_builder_options = ViewBuilderOptions::new(
+		let file_view_data = ViewData::new(|updater| {
+			updater.set_show_title(true);
+			updater.set_show_help(true);
+		});
+
+		Self {
+			diff: None,
+			diff_memory_usage: Rc::new(Cell::new(None)),
+			diff_view_data,
+			fetch_missing_objects: config.fetch_missing_objects,
+			fetched_object_ids: vec![],
+			file_index: 0,
+			file_page: None,
+			file_view_data,
+			help: Help::new_from_keybindings(&get_show_commit_help_lines(&config.key_bindings)),
+			commit_diff_loader_options: Self::create_commit_diff_loader_options(config),
+			object_fetch: None,
+			object_fetch_progress: Progress::new(""),
+			overview_view_data,
+			partial_clone: None,
+			squash_preview: None,
+			squashed_commits: 0,
+			state: ShowCommitState::Overview,
+			view_builder: ViewBuilder::new(Self::create_view_builder_options(config)),
+			repository,
+		}
+	}
+
+	/// Set the partial clone that the objects of the shown commits that have not been fetched are fetched from, when
+	/// fetching them is enabled.
+	pub(crate) fn set_partial_clone(&mut self, repository: &'s Repository) {
+		self.partial_clone = Some(repository);
+	}
+
+	/// Share the memory used by each loaded diff, to be shown with the diagnostics.
+	pub(crate) fn set_diff_memory_usage(&mut self, diff_memory_usage: Rc<Cell<Option<DiffMemoryUsage>>>) {
+		self.diff_memory_usage = diff_memory_usage;
+	}
+
+	/// Show the combined changes of the commits of the selected lines of the todo list, as if they were squashed into
+	/// the first commit, loaded from the repository, instead of the selected commit.
+	pub(crate) fn set_squash_preview(&mut self, repository: &'s Repository) {
+		self.squash_preview = Some(repository);
+	}
+
+	// the selected commits can change while the list is shown, so the combined changes are loaded each time
+	fn activate_squash_preview(&mut self, repository: &Repository, rebase_todo: &TodoFile) -> ProcessResult {
+		// a merge line refers to the commit of the message of the merge, and not to the changes of the merge
+		let hashes: Vec<&str> = rebase_todo
+			.get_selected_lines()
+			.iter()
+			.filter(|line| line.has_reference() && *line.get_action() != Action::Merge)
+			.map(Line::get_hash)
+			.collect();
+		if hashes.is_empty() {
+			return ProcessResult::new()
+				.error(anyhow!(translate("No valid commit to show")))
+				.state(State::List);
+		}
+		self.reset_commit_view();
+
+		let start = Instant::now();
+		match repository.load_squash_diff(hashes.as_slice(), &self.commit_diff_loader_options) {
+			Ok(diff) => {
+				log::debug!(
+					target: "git",
+					"Loaded the combined diff of {} commits with {} files in {}ms",
+					hashes.len(),
+					diff.file_statuses().len(),
+					start.elapsed().as_millis()
+				);
+				self.set_diff(diff);
+				self.squashed_commits = hashes.len();
+				ProcessResult::new()
+			},
+			Err(e) => {
+				log::error!(target: "git", "Unable to load the combined diff of {} commits: {:#}", hashes.len(), e);
+				ProcessResult::new()
+					.error(e.context(anyhow!(translate("Unable to combine the changes of the selected commits"))))
+					.state(State::List)
+			},
+		}
+	}
+
+	fn reset_commit_view(&mut self) {
+		self.overview_view_data.update_view_data(|updater| {
+			updater.clear();
+			updater.reset_scroll_position();
+		});
+
+		self.diff_view_data.update_view_data(|updater| {
+			updater.clear();
+			updater.reset_scroll_position();
+		});
+
+		self.file_page = None;
+		if self.state == ShowCommitState::File {
+			self.state = ShowCommitState::Diff;
+		}
+		// a fetch for the previous commit is stopped when it is dropped
+		self.object_fetch = None;
+		self.fetched_object_ids.clear();
+	}
+
+	// the objects of a commit are fetched again only when different objects are missing, such as the files once the
+	// trees have been fetched, so a fetch that does not provide the objects is not repeated
+	fn start_object_fetch(&mut self) -> Result<()> {
+		let (repository, diff) = match (self.partial_clone, self.diff.as_ref()) {
+			(Some(repository), Some(diff)) if self.fetch_missing_objects => (repository, diff),
+			_ => return Ok(()),
+		};
+		if diff.is_tree_available() && diff.file_statuses().iter().all(FileStatus::is_content_available) {
+			return Ok(());
+		}
+		let hash = diff.commit().hash();
+		let object_ids = repository.find_missing_objects(hash)?;
+		if object_ids.is_empty() || object_ids == self.fetched_object_ids {
+			return Ok(());
+		}
+		let fetch = repository.fetch_missing_objects(&object_ids)?;
+		log::info!(
+			target: "git",
+			"Fetching {} missing objects of {} from {}",
+			fetch.object_count(),
+			hash,
+			fetch.remote()
+		);
+		self.object_fetch_progress = Progress::new(
+			translate_format("Fetching {} missing objects from {}", &[
+				&fetch.object_count(),
+				&fetch.remote(),
+			])
+			.as_str(),
+		);
+		self.fetched_object_ids = object_ids;
+		self.object_fetch = Some(fetch);
+		self.clear_commit_view_data();
+		Ok(())
+	}
+
+	fn poll_object_fetch(&mut self) -> Option<ProcessResult> {
+		let fetch = self.object_fetch.as_mut()?;
+		let result = match fetch.poll() {
+			Some(result) => result,
+			None => {
+				self.object_fetch_progress.set_progress(fetch.progress());
+				self.object_fetch_progress.tick();
+				self.clear_commit_view_data();
+				return Some(ProcessResult::from(Event::from(MetaEvent::BackgroundLoaded)));
+			},
+		};
+		let elapsed = fetch.elapsed();
+		self.object_fetch = None;
+		self.clear_commit_view_data();
+		let process_result = ProcessResult::from(Event::from(MetaEvent::BackgroundLoaded));
+		if let Err(err) = result.and_then(|()| self.reload_diff()) {
+			log::error!(target: "git", "Unable to fetch missing objects: {:#}", err);
+			return Some(process_result.error(err.context(translate("Unable to fetch missing objects"))));
+		}
+		log::info!(target: "git", "Fetched missing objects in {}ms", elapsed.as_millis());
+		Some(match self.start_object_fetch() {
+			Ok(()) => process_result,
+			Err(err) => process_result.error(err.context(translate("Unable to fetch missing objects"))),
+		})
+	}
+
+	fn set_diff(&mut self, diff: CommitDiff) {
+		let memory_usage = diff.memory_usage();
+		log::debug!(
+			target: "git",
+			"The diff of {} uses {} bytes for {} lines",
+			diff.commit().hash(),
+			memory_usage.bytes(),
+			memory_usage.lines()
+		);
+		self.diff_memory_usage.set(Some(memory_usage));
+		self.diff = Some(diff);
+	}
+
+	fn cancel_object_fetch(&mut self) {
+		if let Some(mut fetch) = self.object_fetch.take() {
+			fetch.cancel();
+			log::info!(target: "git", "Cancelled the fetch of the missing objects");
+		}
+		self.clear_commit_view_data();
+	}
+
+	// the diff is loaded again once the missing objects have been fetched, replacing a file that was shown as not
+	// available
+	fn reload_diff(&mut self) -> Result<()> {
+		let hash = match self.diff.as_ref() {
+			Some(diff) => String::from(diff.commit().hash()),
+			None => return Ok(()),
+		};
+		let diff = self
+			.repository
+			.load_commit_diff(hash.as_str(), &self.commit_diff_loader_options)?;
+		self.set_diff(diff);
+		self.file_page = None;
+		if self.state == ShowCommitState::File {
+			self.state = ShowCommitState::Diff;
+		}
+		Ok(())
+	}
+
+	// the commit that the shown commit was cherry picked from replaces the shown commit, until a commit is shown from
+	// the list again
+	fn show_source_commit(&mut self) -> Result<()> {
+		let source = match self.diff.as_ref().and_then(|diff| diff.commit().cherry_picked_from()) {
+			Some(source) => String::from(source),
+			None => return Ok(()),
+		};
+		let diff = self
+			.repository
+			.load_commit_diff(source.as_str(), &self.commit_diff_loader_options)?;
+		log::debug!(target: "git", "Loaded diff of the source commit {}", source);
+		self.set_diff(diff);
+		self.file_page = None;
+		self.state = ShowCommitState::Overview;
+		self.object_fetch = None;
+		self.fetched_object_ids.clear();
+		self.overview_view_data.update_view_data(|updater| {
+			updater.clear();
+			updater.reset_scroll_position();
+		});
+		self.diff_view_data.update_view_data(|updater| {
+			updater.clear();
+			updater.reset_scroll_position();
+		});
+		self.start_object_fetch()
+	}
+
+	fn clear_commit_view_data(&mut self) {
+		self.overview_view_data.update_view_data(|updater| updater.clear());
+		self.diff_view_data.update_view_data(|updater| updater.clear());
+	}
+
+	/// Show the next page of the current file, or the first page of the next file once the current file has been shown,
+	/// starting with the first file when a file is not being shown.
+	fn show_next_file_page(&mut self) -> Result<()> {
+		let diff = self.diff.as_ref().unwrap(); // will only fail on programmer error
+		let file_statuses = diff.file_statuses();
+		if file_statuses.is_empty() {
+			return Ok(());
+		}
+		let (file_index, offset, first_line_number) = match self.file_page.as_ref() {
+			Some(page) if self.state == ShowCommitState::File => {
+				match page.next_offset() {
+					Some(next_offset) => (self.file_index, next_offset, page.next_line_number()),
+					None => ((self.file_index + 1) % file_statuses.len(), 0, 1),
+				}
+			},
+			_ => (0, 0, 1),
+		};
+
+		let status = file_statuses.get(file_index).unwrap(); // the index is always within the file statuses
+													   // a deleted file only exists in the parent commit
+		let (hash, path) = if status.status() == Status::Deleted {
+			(
+				diff.parent()
+					.as_ref()
+					.map_or(diff.commit().hash(), |parent| parent.hash()),
+				status.source_path(),
+			)
+		}
+		else {
+			(diff.commit().hash(), status.destination_path())
+		};
+
+		let start = Instant::now();
+		// a file that has not been fetched into a partial clone is shown as not available, rather than as an error
+		let page = match FilePage::load(self.repository, hash, path, offset, first_line_number) {
+			Ok(page) => page,
+			Err(_) if !status.is_content_available() => FilePage::unavailable(path),
+			Err(err) => return Err(err),
+		};
+		log::debug!(
+			target: "git",
+			"Loaded {} lines of {} from {} in {}ms",
+			page.lines().len(),
+			path.display(),
+			hash,
+			start.elapsed().as_millis()
+		);
+		self.file_index = file_index;
+		self.file_page = Some(page);
+		self.state = ShowCommitState::File;
+		self.file_view_data.update_view_data(|updater| {
+			updater.clear();
+			updater.reset_scroll_position();
+		});
+		Ok(())
+	}
+
+	fn create_view_builder_options(config: &Config) -> ViewBuilderOptions {
+		ViewBuilderOptions::new(
 			config.diff_tab_width as usize,
 			config.diff_tab_symbol.as_str(),
 			config.diff_space_symbol.as_str(),
@@ -189,25 +613,21 @@ impl<'s> ShowCommit<'s> {
 				|| config.diff_show_whitespace == DiffShowWhitespaceSetting::Leading,
 			config.diff_show_whitespace == DiffShowWhitespaceSetting::Both
 				|| config.diff_show_whitespace == DiffShowWhitespaceSetting::Trailing,
-		);
+			config.icons,
+			// the palettes other than the default are for users that may not be able to tell the colors apart
+			config.theme.palette != Palette::Default,
+			config.screen_reader,
+			config.date_format.clone(),
+		)
+	}
 
-		let commit_diff_loader_options = CommitDiffLoaderOptions::new()
+	fn create_commit_diff_loader_options(config: &Config) -> CommitDiffLoaderOptions {
+		CommitDiffLoaderOptions::new()
 			.context_lines(config.git.diff_context)
 			.copies(config.git.diff_copies)
 			.ignore_whitespace(config.diff_ignore_whitespace == DiffIgnoreWhitespaceSetting::All)
 			.ignore_whitespace_change(config.diff_ignore_whitespace == DiffIgnoreWhitespaceSetting::Change)
 			.interhunk_context(config.git.diff_interhunk_lines)
-			.renames(config.git.diff_renames, config.git.diff_rename_limit);
-
-		Self {
-			diff: None,
-			diff_view_data,
-			help: Help::new_from_keybindings(&get_show_commit_help_lines(&config.key_bindings)),
-			commit_diff_loader_options,
-			overview_view_data,
-			state: ShowCommitState::Overview,
-			view_builder: ViewBuilder::new(view_builder_options),
-			repository,
-		}
+			.renames(config.git.diff_renames, config.git.diff_rename_limit)
 	}
 }
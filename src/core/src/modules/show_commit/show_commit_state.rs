@@ -2,4 +2,5 @@
 pub(super) enum ShowCommitState {
 	Overview,
 	Diff,
+	File,
 }
@@ -1,5 +1,17 @@
+use std::{thread::sleep, time::Duration};
+
 use git::{
-	testutil::{head_id, with_temp_repository, CommitBuilder, CommitDiffBuilder, FileStatusBuilder},
+	testutil::{
+		create_commit,
+		head_id,
+		with_temp_partial_clone,
+		with_temp_repository,
+		CommitBuilder,
+		CommitDiffBuilder,
+		CreateCommitOptions,
+		FileStatusBuilder,
+		RepoBuilder,
+	},
 	Delta,
 	DiffLine,
 	FileMode,
@@ -7,11 +19,12 @@ use git::{
 	Status,
 	User,
 };
+use input::KeyCode;
 use rstest::rstest;
 use view::{assert_rendered_output, render_line, ViewLine};
 
 use super::*;
-use crate::{assert_process_result, testutil::module_test};
+use crate::{assert_process_result, testutil::module_test, timers::TimerRequest};
 
 #[test]
 fn load_commit_during_activate() {
@@ -27,6 +40,26 @@ fn load_commit_during_activate() {
 	});
 }
 
+#[test]
+fn load_commit_shares_memory_usage() {
+	with_temp_repository(|repo| {
+		let oid = head_id(&repo, "main");
+		let line = format!("pick {} comment1", oid.to_string());
+		module_test(&[line.as_str()], &[], |test_context| {
+			let diff_memory_usage = Rc::new(Cell::new(None));
+			let mut module = ShowCommit::new(&Config::new(), &repo);
+			module.set_diff_memory_usage(Rc::clone(&diff_memory_usage));
+			assert_process_result!(test_context.activate(&mut module, State::List));
+			assert_eq!(
+				diff_memory_usage.get(),
+				module.diff.as_ref().map(CommitDiff::memory_usage)
+			);
+			assert!(diff_memory_usage.get().is_some());
+		});
+		Ok(())
+	});
+}
+
 #[test]
 fn cached_commit_in_activate() {
 	with_temp_repository(|repo| {
@@ -42,6 +75,25 @@ fn cached_commit_in_activate() {
 	});
 }
 
+#[test]
+fn update_config_clears_cached_commit() {
+	with_temp_repository(|repo| {
+		let oid = head_id(&repo, "main");
+		let line = format!("pick {} comment1", oid.to_string());
+		module_test(&[line.as_str()], &[], |test_context| {
+			let mut module = ShowCommit::new(&Config::new(), &repo);
+			assert_process_result!(test_context.activate(&mut module, State::List));
+			let mut config = Config::new();
+			config.git.diff_context = 5;
+			module.update_config(&config);
+			assert!(module.diff.is_none());
+			assert_process_result!(test_context.activate(&mut module, State::List));
+			assert!(module.diff.is_some());
+		});
+		Ok(())
+	});
+}
+
 #[test]
 fn no_selected_line_in_activate() {
 	with_temp_repository(|repo| {
@@ -394,6 +446,45 @@ fn render_overview_with_file_stats() {
 	});
 }
 
+#[test]
+fn render_overview_with_file_stats_and_icons() {
+	with_temp_repository(|repo| {
+		module_test(
+			&["pick 0123456789abcdef0123456789abcdef comment1"],
+			&[],
+			|test_context| {
+				let diff = CommitDiffBuilder::new(CommitBuilder::new("0123456789abcdef0123456789abcdef").build())
+					.file_statuses(vec![
+						FileStatusBuilder::new()
+							.source_path("src/main.rs")
+							.destination_path("src/main.rs")
+							.status(Status::Modified)
+							.build(),
+						FileStatusBuilder::new()
+							.source_path("file.unknown")
+							.destination_path("file.unknown")
+							.status(Status::Added)
+							.build(),
+					])
+					.build();
+				let mut config = Config::new();
+				config.icons = true;
+				let mut module = ShowCommit::new(&config, &repo);
+				module.diff = Some(diff);
+				assert_rendered_output!(
+					test_context.build_view_data(&mut module),
+					render_line!(AnyLine 6),
+					"{IndicatorColor}0{Normal} files{Normal} with {DiffAddColor}0{Normal} insertions{Normal} and \
+					 {DiffRemoveColor}0{Normal} deletions",
+					"{DiffChangeColor}modified: {Normal}\u{e7a8} {DiffChangeColor}src/main.rs",
+					"{DiffAddColor}   added: {Normal}\u{f15b} {DiffAddColor}file.unknown"
+				);
+			},
+		);
+		Ok(())
+	});
+}
+
 #[test]
 fn render_overview_with_file_stats_compact() {
 	with_temp_repository(|repo| {
@@ -486,6 +577,53 @@ fn render_overview_single_file_changed() {
 	});
 }
 
+#[test]
+fn render_overview_shallow_boundary() {
+	with_temp_repository(|repo| {
+		module_test(
+			&["pick 0123456789abcdef0123456789abcdef comment1"],
+			&[],
+			|test_context| {
+				let diff = CommitDiffBuilder::new(CommitBuilder::new("0123456789abcdef0123456789abcdef").build())
+					.shallow_boundary(true)
+					.build();
+				let mut module = ShowCommit::new(&Config::new(), &repo);
+				module.diff = Some(diff);
+				assert_rendered_output!(
+					test_context.build_view_data(&mut module),
+					render_line!(AnyLine 7),
+					"{IndicatorColor}The parent commit is not in this shallow clone, the files are compared to an empty \
+					 tree"
+				);
+			},
+		);
+		Ok(())
+	});
+}
+
+#[test]
+fn render_overview_tree_not_available() {
+	with_temp_repository(|repo| {
+		module_test(
+			&["pick 0123456789abcdef0123456789abcdef comment1"],
+			&[],
+			|test_context| {
+				let diff = CommitDiffBuilder::new(CommitBuilder::new("0123456789abcdef0123456789abcdef").build())
+					.tree_available(false)
+					.build();
+				let mut module = ShowCommit::new(&Config::new(), &repo);
+				module.diff = Some(diff);
+				assert_rendered_output!(
+					test_context.build_view_data(&mut module),
+					render_line!(AnyLine 7),
+					"{IndicatorColor}The files of the commit are not in this partial clone"
+				);
+			},
+		);
+		Ok(())
+	});
+}
+
 #[test]
 fn render_overview_more_than_one_file_changed() {
 	with_temp_repository(|repo| {
@@ -748,6 +886,43 @@ fn render_diff_basic_file_stats() {
 	});
 }
 
+#[test]
+fn render_diff_objects_not_available() {
+	with_temp_repository(|repo| {
+		module_test(
+			&["pick 0123456789abcdef0123456789abcdef comment1"],
+			&[],
+			|test_context| {
+				let diff = CommitDiffBuilder::new(CommitBuilder::new("0123456789abcdef0123456789abcdef").build())
+					.shallow_boundary(true)
+					.file_statuses(vec![
+						FileStatusBuilder::new()
+							.source_path("file.1a")
+							.destination_path("file.1a")
+							.content_available(false)
+							.status(Status::Added)
+							.build(),
+					])
+					.build();
+				let mut module = ShowCommit::new(&Config::new(), &repo);
+				module.diff = Some(diff);
+				module.state = ShowCommitState::Diff;
+				assert_rendered_output!(
+					test_context.build_view_data(&mut module),
+					render_line!(AnyLine 3),
+					render_line!(AnyLine 1),
+					"{BODY}",
+					"{IndicatorColor}The parent commit is not in this shallow clone, the files are compared to an empty \
+					 tree",
+					"{Normal}{Pad(―)}",
+					"{DiffAddColor}   added: {DiffAddColor}file.1a{IndicatorColor} object not available"
+				);
+			},
+		);
+		Ok(())
+	});
+}
+
 #[test]
 fn render_diff_end_new_line_missing() {
 	with_temp_repository(|repo| {
@@ -758,8 +933,8 @@ fn render_diff_end_new_line_missing() {
 				let mut config = Config::new();
 				config.diff_show_whitespace = DiffShowWhitespaceSetting::None;
 				let mut delta = Delta::new("@@ -14,2 +13,3 @@ context", 14, 14, 0, 1);
-				delta.add_line(DiffLine::new(Origin::Addition, "new line", None, Some(14), false));
-				delta.add_line(DiffLine::new(Origin::Addition, "", None, Some(15), true));
+				delta.add_line(&DiffLine::new(Origin::Addition, "new line", None, Some(14), false));
+				delta.add_line(&DiffLine::new(Origin::Addition, "", None, Some(15), true));
 
 				let diff = CommitDiffBuilder::new(CommitBuilder::new("0123456789abcdef0123456789abcdef").build())
 					.file_statuses(vec![FileStatusBuilder::new()
@@ -802,7 +977,7 @@ fn render_diff_add_line() {
 				let mut config = Config::new();
 				config.diff_show_whitespace = DiffShowWhitespaceSetting::None;
 				let mut delta = Delta::new("@@ -14,2 +13,3 @@ context", 14, 14, 0, 1);
-				delta.add_line(DiffLine::new(Origin::Addition, "new line", None, Some(14), false));
+				delta.add_line(&DiffLine::new(Origin::Addition, "new line", None, Some(14), false));
 
 				let diff = CommitDiffBuilder::new(CommitBuilder::new("0123456789abcdef0123456789abcdef").build())
 					.file_statuses(vec![FileStatusBuilder::new()
@@ -834,6 +1009,96 @@ fn render_diff_add_line() {
 	});
 }
 
+#[test]
+fn render_diff_screen_reader() {
+	with_temp_repository(|repo| {
+		module_test(
+			&["pick 0123456789abcdef0123456789abcdef comment1"],
+			&[],
+			|test_context| {
+				let mut config = Config::new();
+				config.diff_show_whitespace = DiffShowWhitespaceSetting::None;
+				config.screen_reader = true;
+				let mut delta = Delta::new("@@ -14,2 +13,3 @@ context", 14, 14, 1, 1);
+				delta.add_line(&DiffLine::new(Origin::Deletion, "old line", Some(14), None, false));
+				delta.add_line(&DiffLine::new(Origin::Addition, "new line", None, Some(14), false));
+				delta.add_line(&DiffLine::new(Origin::Context, "context", Some(15), Some(15), false));
+
+				let diff = CommitDiffBuilder::new(CommitBuilder::new("0123456789abcdef0123456789abcdef").build())
+					.file_statuses(vec![FileStatusBuilder::new()
+						.source_path("file.txt")
+						.destination_path("file.txt")
+						.status(Status::Modified)
+						.push_delta(delta)
+						.build()])
+					.build();
+				let mut module = ShowCommit::new(&config, &repo);
+				module.diff = Some(diff);
+				module.state = ShowCommitState::Diff;
+				assert_rendered_output!(
+					test_context.build_view_data(&mut module),
+					render_line!(AnyLine 3),
+					"{IndicatorColor}0{Normal} files{Normal} with {DiffAddColor}0{Normal} insertions{Normal} and \
+					 {DiffRemoveColor}0{Normal} deletions",
+					"{BODY}",
+					"{Normal}{Pad(-)}",
+					"{DiffChangeColor}modified: {DiffChangeColor}file.txt",
+					"",
+					"{Normal,Dimmed}@@{DiffContextColor} -14,1 +14,1 {Normal,Dimmed}@@{DiffContextColor} context",
+					"{Normal,Dimmed}{Pad(-)}",
+					"{Normal}14{Normal} {Normal}  {Normal}- {DiffRemoveColor}old line",
+					"{Normal}  {Normal} {Normal}14{Normal}+ {DiffAddColor}new line",
+					"{Normal}15{Normal} {Normal}15{Normal}| {DiffContextColor}context"
+				);
+			},
+		);
+		Ok(())
+	});
+}
+
+#[test]
+fn render_diff_add_line_with_palette() {
+	with_temp_repository(|repo| {
+		module_test(
+			&["pick 0123456789abcdef0123456789abcdef comment1"],
+			&[],
+			|test_context| {
+				let mut config = Config::new();
+				config.diff_show_whitespace = DiffShowWhitespaceSetting::None;
+				config.theme.palette = Palette::Deuteranopia;
+				let mut delta = Delta::new("@@ -14,2 +13,3 @@ context", 14, 14, 0, 1);
+				delta.add_line(&DiffLine::new(Origin::Addition, "new line", None, Some(14), false));
+
+				let diff = CommitDiffBuilder::new(CommitBuilder::new("0123456789abcdef0123456789abcdef").build())
+					.file_statuses(vec![FileStatusBuilder::new()
+						.source_path("file.txt")
+						.destination_path("file.txt")
+						.status(Status::Modified)
+						.push_delta(delta)
+						.build()])
+					.build();
+				let mut module = ShowCommit::new(&config, &repo);
+				module.diff = Some(diff);
+				module.state = ShowCommitState::Diff;
+				assert_rendered_output!(
+					test_context.build_view_data(&mut module),
+					render_line!(AnyLine 3),
+					"{IndicatorColor}0{Normal} files{Normal} with {DiffAddColor}0{Normal} insertions{Normal} and \
+					 {DiffRemoveColor}0{Normal} deletions",
+					"{BODY}",
+					"{Normal}{Pad(―)}",
+					"{DiffChangeColor}modified: {DiffChangeColor}file.txt",
+					"",
+					"{Normal,Dimmed}@@{DiffContextColor} -14,0 +14,1 {Normal,Dimmed}@@{DiffContextColor} context",
+					"{Normal,Dimmed}{Pad(┈)}",
+					"{Normal}  {Normal} {Normal}14{Normal}+ {DiffAddColor}new line"
+				);
+			},
+		);
+		Ok(())
+	});
+}
+
 #[test]
 fn render_diff_delete_line() {
 	with_temp_repository(|repo| {
@@ -844,7 +1109,7 @@ fn render_diff_delete_line() {
 				let mut config = Config::new();
 				config.diff_show_whitespace = DiffShowWhitespaceSetting::None;
 				let mut delta = Delta::new("@@ -14,2 +13,3 @@ context", 14, 14, 0, 1);
-				delta.add_line(DiffLine::new(Origin::Deletion, "old line", Some(14), None, false));
+				delta.add_line(&DiffLine::new(Origin::Deletion, "old line", Some(14), None, false));
 
 				let diff = CommitDiffBuilder::new(CommitBuilder::new("0123456789abcdef0123456789abcdef").build())
 					.file_statuses(vec![FileStatusBuilder::new()
@@ -886,10 +1151,10 @@ fn render_diff_context_add_remove_lines() {
 				let mut config = Config::new();
 				config.diff_show_whitespace = DiffShowWhitespaceSetting::None;
 				let mut delta = Delta::new("@@ -14,2 +13,3 @@ context", 14, 14, 0, 1);
-				delta.add_line(DiffLine::new(Origin::Context, "context 1", Some(13), Some(13), false));
-				delta.add_line(DiffLine::new(Origin::Deletion, "old line", Some(14), None, false));
-				delta.add_line(DiffLine::new(Origin::Addition, "new line", None, Some(14), false));
-				delta.add_line(DiffLine::new(Origin::Context, "context 2", Some(15), Some(15), false));
+				delta.add_line(&DiffLine::new(Origin::Context, "context 1", Some(13), Some(13), false));
+				delta.add_line(&DiffLine::new(Origin::Deletion, "old line", Some(14), None, false));
+				delta.add_line(&DiffLine::new(Origin::Addition, "new line", None, Some(14), false));
+				delta.add_line(&DiffLine::new(Origin::Context, "context 2", Some(15), Some(15), false));
 
 				let diff = CommitDiffBuilder::new(CommitBuilder::new("0123456789abcdef0123456789abcdef").build())
 					.file_statuses(vec![FileStatusBuilder::new()
@@ -924,26 +1189,26 @@ fn render_diff_context_add_remove_lines() {
 	});
 }
 
-fn generate_diff_line_context(content: &str, line_num: u32) -> DiffLine {
+fn generate_diff_line_context(content: &str, line_num: u32) -> DiffLine<'_> {
 	DiffLine::new(Origin::Context, content, Some(line_num), Some(line_num), false)
 }
 
 fn generate_white_space_delta() -> Delta {
 	let mut delta = Delta::new("@@ -1,7 +1,7 @@ context", 1, 1, 7, 7);
 	// leading tabs
-	delta.add_line(generate_diff_line_context("\t\tsp tabs\t\tcontent", 1));
+	delta.add_line(&generate_diff_line_context("\t\tsp tabs\t\tcontent", 1));
 	// trailing tabs
-	delta.add_line(generate_diff_line_context("sp tabs\t\tcontent\t\t", 2));
+	delta.add_line(&generate_diff_line_context("sp tabs\t\tcontent\t\t", 2));
 	// leading and trailing tabs
-	delta.add_line(generate_diff_line_context("\t\tsp tabs\t\tcontent\t\t", 3));
+	delta.add_line(&generate_diff_line_context("\t\tsp tabs\t\tcontent\t\t", 3));
 	// leading spaces
-	delta.add_line(generate_diff_line_context("    sp tabs\t\tcontent", 4));
+	delta.add_line(&generate_diff_line_context("    sp tabs\t\tcontent", 4));
 	// trailing spaces
-	delta.add_line(generate_diff_line_context("sp tabs\t\tcontent    ", 5));
+	delta.add_line(&generate_diff_line_context("sp tabs\t\tcontent    ", 5));
 	// leading and trailing spaces
-	delta.add_line(generate_diff_line_context("    sp tabs\t\tcontent    ", 6));
+	delta.add_line(&generate_diff_line_context("    sp tabs\t\tcontent    ", 6));
 	// mix of spaces and tabs
-	delta.add_line(generate_diff_line_context(" \t\t sp tabs\t\tcontent\t  \t", 7));
+	delta.add_line(&generate_diff_line_context(" \t\t sp tabs\t\tcontent\t  \t", 7));
 	delta
 }
 
@@ -1080,7 +1345,7 @@ fn render_diff_show_whitespace_all_spaces() {
 				config.diff_space_symbol = String::from("%");
 				config.diff_tab_width = 2;
 				let mut delta = Delta::new("@@ -1,7 +1,7 @@ context", 1, 1, 7, 7);
-				delta.add_line(DiffLine::new(Origin::Addition, "    ", None, Some(1), false));
+				delta.add_line(&DiffLine::new(Origin::Addition, "    ", None, Some(1), false));
 				let diff = CommitDiffBuilder::new(CommitBuilder::new("0123456789abcdef0123456789abcdef").build())
 					.file_statuses(vec![FileStatusBuilder::new()
 						.source_path("file.txt")
@@ -1103,6 +1368,48 @@ fn render_diff_show_whitespace_all_spaces() {
 	});
 }
 
+// found by the diff renderer fuzz target, the content of a line with multibyte characters was sliced by the number of
+// graphemes, rather than bytes
+#[test]
+fn render_diff_show_whitespace_multibyte_characters() {
+	with_temp_repository(|repo| {
+		module_test(
+			&["pick 0123456789abcdef0123456789abcdef comment1"],
+			&[],
+			|test_context| {
+				let mut config = Config::new();
+				config.diff_show_whitespace = DiffShowWhitespaceSetting::Both;
+				config.diff_tab_symbol = String::from("#>");
+				config.diff_space_symbol = String::from("%");
+				config.diff_tab_width = 2;
+				let mut delta = Delta::new("@@ -1,3 +1,3 @@ context", 1, 1, 3, 3);
+				delta.add_line(&generate_diff_line_context("café", 1));
+				delta.add_line(&generate_diff_line_context("  é 👍\t", 2));
+				delta.add_line(&generate_diff_line_context("\te\u{301}", 3));
+				let diff = CommitDiffBuilder::new(CommitBuilder::new("0123456789abcdef0123456789abcdef").build())
+					.file_statuses(vec![FileStatusBuilder::new()
+						.source_path("file.txt")
+						.destination_path("file.txt")
+						.status(Status::Modified)
+						.push_delta(delta)
+						.build()])
+					.build();
+				let mut module = ShowCommit::new(&config, &repo);
+				module.diff = Some(diff);
+				module.state = ShowCommitState::Diff;
+				assert_rendered_output!(
+					test_context.build_view_data(&mut module),
+					render_line!(AnyLine 10),
+					render_line!(EndsWith "{DiffContextColor}café"),
+					render_line!(EndsWith "%%{DiffContextColor}é 👍{DiffWhitespaceColor}#>"),
+					render_line!(EndsWith "#>{DiffContextColor}e\u{301}")
+				);
+			},
+		);
+		Ok(())
+	});
+}
+
 #[test]
 fn handle_event_toggle_diff_to_overview() {
 	with_temp_repository(|repo| {
@@ -1151,6 +1458,265 @@ fn handle_event_toggle_overview_to_diff() {
 	});
 }
 
+#[test]
+fn handle_event_show_file() {
+	with_temp_repository(|repo| {
+		create_commit(
+			&repo,
+			Some(
+				CreateCommitOptions::new()
+					.file("a.txt", "first\nsecond\n")
+					.file("b.txt", "other"),
+			),
+		)?;
+		let line = format!("pick {} comment1", head_id(&repo, "main"));
+		module_test(
+			&[line.as_str()],
+			&[Event::from(MetaEvent::ShowFile)],
+			|mut test_context| {
+				let mut module = ShowCommit::new(&Config::new(), &repo);
+				assert_process_result!(test_context.activate(&mut module, State::List));
+				assert_process_result!(
+					test_context.handle_event(&mut module),
+					event = Event::from(MetaEvent::ShowFile)
+				);
+				assert_eq!(module.state, ShowCommitState::File);
+				assert_rendered_output!(
+					test_context.build_view_data(&mut module),
+					"{TITLE}{HELP}",
+					"{LEADING}",
+					"{IndicatorColor}a.txt{Normal} lines 1-2, 13 bytes",
+					"{BODY}",
+					"{Normal}{Pad(―)}",
+					"{Normal}1 | {Normal}first",
+					"{Normal}2 | {Normal}second"
+				);
+			},
+		);
+		Ok(())
+	});
+}
+
+#[test]
+fn handle_event_show_file_next_file() {
+	with_temp_repository(|repo| {
+		create_commit(
+			&repo,
+			Some(
+				CreateCommitOptions::new()
+					.file("a.txt", "first\nsecond\n")
+					.file("b.txt", "other"),
+			),
+		)?;
+		let line = format!("pick {} comment1", head_id(&repo, "main"));
+		module_test(
+			&[line.as_str()],
+			&[
+				Event::from(MetaEvent::ShowFile),
+				Event::from(MetaEvent::ShowFile),
+				Event::from(MetaEvent::ShowFile),
+			],
+			|mut test_context| {
+				let mut module = ShowCommit::new(&Config::new(), &repo);
+				assert_process_result!(test_context.activate(&mut module, State::List));
+				let _ = test_context.handle_event(&mut module);
+				let _ = test_context.handle_event(&mut module);
+				{
+					assert_rendered_output!(
+						test_context.build_view_data(&mut module),
+						"{TITLE}{HELP}",
+						"{LEADING}",
+						"{IndicatorColor}b.txt{Normal} lines 1-1, 5 bytes",
+						"{BODY}",
+						"{Normal}{Pad(―)}",
+						"{Normal}1 | {Normal}other"
+					);
+				}
+				let _ = test_context.handle_event(&mut module);
+				assert_rendered_output!(
+					test_context.build_view_data(&mut module),
+					"{TITLE}{HELP}",
+					"{LEADING}",
+					"{IndicatorColor}a.txt{Normal} lines 1-2, 13 bytes",
+					"{BODY}",
+					"{Normal}{Pad(―)}",
+					"{Normal}1 | {Normal}first",
+					"{Normal}2 | {Normal}second"
+				);
+			},
+		);
+		Ok(())
+	});
+}
+
+#[test]
+fn handle_event_show_file_next_page() {
+	with_temp_repository(|repo| {
+		let content = (1..=file_page::FILE_PAGE_LINE_COUNT + 1)
+			.map(|line| format!("{}\n", line))
+			.collect::<String>();
+		create_commit(&repo, Some(CreateCommitOptions::new().file("a.txt", content.as_str())))?;
+		let line = format!("pick {} comment1", head_id(&repo, "main"));
+		module_test(
+			&[line.as_str()],
+			&[Event::from(MetaEvent::ShowFile), Event::from(MetaEvent::ShowFile)],
+			|mut test_context| {
+				let mut module = ShowCommit::new(&Config::new(), &repo);
+				assert_process_result!(test_context.activate(&mut module, State::List));
+				let _ = test_context.handle_all_events(&mut module);
+				assert_rendered_output!(
+					test_context.build_view_data(&mut module),
+					"{TITLE}{HELP}",
+					"{LEADING}",
+					"{IndicatorColor}a.txt{Normal} lines 1001-1001, 3,898 bytes",
+					"{BODY}",
+					"{Normal}{Pad(―)}",
+					"{Normal}1001 | {Normal}1001"
+				);
+			},
+		);
+		Ok(())
+	});
+}
+
+#[test]
+fn handle_event_show_file_not_available() {
+	with_temp_repository(|repo| {
+		let hash = head_id(&repo, "main").to_string();
+		let line = format!("pick {} comment1", hash);
+		module_test(
+			&[line.as_str()],
+			&[Event::from(MetaEvent::ShowFile)],
+			|mut test_context| {
+				let diff = CommitDiffBuilder::new(CommitBuilder::new(hash.as_str()).build())
+					.file_statuses(vec![
+						FileStatusBuilder::new()
+							.source_path("a.txt")
+							.destination_path("a.txt")
+							.content_available(false)
+							.status(Status::Added)
+							.build(),
+					])
+					.build();
+				let mut module = ShowCommit::new(&Config::new(), &repo);
+				module.diff = Some(diff);
+				assert_process_result!(
+					test_context.handle_event(&mut module),
+					event = Event::from(MetaEvent::ShowFile)
+				);
+				assert_eq!(module.state, ShowCommitState::File);
+				assert_rendered_output!(
+					test_context.build_view_data(&mut module),
+					"{TITLE}{HELP}",
+					"{LEADING}",
+					"{IndicatorColor}a.txt{Normal} object not available",
+					"{BODY}",
+					"{Normal}{Pad(―)}"
+				);
+			},
+		);
+		Ok(())
+	});
+}
+
+#[test]
+fn handle_event_show_source_commit() {
+	with_temp_repository(|repo| {
+		let source = head_id(&repo, "main").to_string();
+		let message = format!("comment1\n\n(cherry picked from commit {})", source);
+		module_test(
+			&["pick aaa comment1"],
+			&[Event::from(MetaEvent::ShowDiff), Event::from(MetaEvent::ShowSourceCommit)],
+			|mut test_context| {
+				let mut module = ShowCommit::new(&Config::new(), &repo);
+				module.diff = Some(
+					CommitDiffBuilder::new(CommitBuilder::new("aaa").message(message.as_str()).build()).build(),
+				);
+				let _ = test_context.handle_event(&mut module);
+				assert_process_result!(
+					test_context.handle_event(&mut module),
+					event = Event::from(MetaEvent::ShowSourceCommit)
+				);
+				assert_eq!(module.diff.as_ref().unwrap().commit().hash(), source);
+				assert_eq!(module.state, ShowCommitState::Overview);
+			},
+		);
+		Ok(())
+	});
+}
+
+#[test]
+fn handle_event_show_source_commit_not_cherry_picked() {
+	with_temp_repository(|repo| {
+		module_test(
+			&["pick aaa comment1"],
+			&[Event::from(MetaEvent::ShowSourceCommit)],
+			|mut test_context| {
+				let mut module = ShowCommit::new(&Config::new(), &repo);
+				module.diff = Some(
+					CommitDiffBuilder::new(CommitBuilder::new("aaa").message("comment1").build()).build(),
+				);
+				assert_process_result!(
+					test_context.handle_event(&mut module),
+					event = Event::from(MetaEvent::ShowSourceCommit)
+				);
+				assert_eq!(module.diff.as_ref().unwrap().commit().hash(), "aaa");
+			},
+		);
+		Ok(())
+	});
+}
+
+#[test]
+fn handle_event_show_source_commit_error() {
+	with_temp_repository(|repo| {
+		module_test(
+			&["pick aaa comment1"],
+			&[Event::from(MetaEvent::ShowSourceCommit)],
+			|mut test_context| {
+				let mut module = ShowCommit::new(&Config::new(), &repo);
+				module.diff = Some(
+					CommitDiffBuilder::new(
+						CommitBuilder::new("aaa")
+							.message("comment1\n\n(cherry picked from commit bbbbbbbbbb)")
+							.build(),
+					)
+					.build(),
+				);
+				assert_process_result!(
+					test_context.handle_event(&mut module),
+					event = Event::from(MetaEvent::ShowSourceCommit),
+					error = anyhow!(
+						"Unable to show the source commit: revspec 'bbbbbbbbbb' not found; class=Reference (4); \
+						 code=NotFound (-3)"
+					)
+				);
+				assert_eq!(module.diff.as_ref().unwrap().commit().hash(), "aaa");
+			},
+		);
+		Ok(())
+	});
+}
+
+#[test]
+fn handle_event_other_key_from_file() {
+	with_temp_repository(|repo| {
+		create_commit(&repo, Some(CreateCommitOptions::new().file("a.txt", "first\n")))?;
+		let line = format!("pick {} comment1", head_id(&repo, "main"));
+		module_test(
+			&[line.as_str()],
+			&[Event::from(MetaEvent::ShowFile), Event::from('a')],
+			|mut test_context| {
+				let mut module = ShowCommit::new(&Config::new(), &repo);
+				assert_process_result!(test_context.activate(&mut module, State::List));
+				let _ = test_context.handle_all_events(&mut module);
+				assert_eq!(module.state, ShowCommitState::Diff);
+			},
+		);
+		Ok(())
+	});
+}
+
 #[test]
 fn handle_event_resize() {
 	with_temp_repository(|repo| {
@@ -1185,6 +1751,8 @@ fn render_help() {
 				"{IndicatorColor} Right   {Normal,Dimmed}|{Normal}Scroll right",
 				"{IndicatorColor} Left    {Normal,Dimmed}|{Normal}Scroll left",
 				"{IndicatorColor} d       {Normal,Dimmed}|{Normal}Show full diff",
+				"{IndicatorColor} f       {Normal,Dimmed}|{Normal}Show the changed files, a page at a time",
+				"{IndicatorColor} o       {Normal,Dimmed}|{Normal}Show the commit that the commit was cherry picked from",
 				"{IndicatorColor} ?       {Normal,Dimmed}|{Normal}Show help",
 				"{TRAILING}",
 				"{IndicatorColor}Press any key to close"
@@ -1263,3 +1831,270 @@ fn scroll_events(#[case] event: MetaEvent) {
 		Ok(())
 	});
 }
+
+fn create_fetch_config() -> Config {
+	let mut config = Config::new();
+	config.fetch_missing_objects = true;
+	config
+}
+
+fn wait_for_object_fetch(module: &mut ShowCommit<'_>) -> Option<ProcessResult> {
+	let mut result = None;
+	while module.object_fetch.is_some() {
+		result = module.poll_object_fetch();
+		sleep(Duration::from_millis(10));
+	}
+	result
+}
+
+#[test]
+fn activate_fetch_missing_objects() {
+	with_temp_partial_clone(|clone, hash| {
+		let line = format!("pick {} comment1", hash);
+		module_test(&[line.as_str()], &[], |test_context| {
+			let mut module = ShowCommit::new(&create_fetch_config(), clone);
+			module.set_partial_clone(clone);
+			let result = test_context.activate(&mut module, State::List);
+			assert_eq!(result.timer, Some(TimerRequest::Start(Timer::Progress, PROGRESS_INTERVAL)));
+			assert_process_result!(result);
+			assert!(!module.diff.as_ref().unwrap().file_statuses()[0].is_content_available());
+			assert!(module.object_fetch.is_some());
+			assert_process_result!(
+				wait_for_object_fetch(&mut module).unwrap(),
+				event = Event::from(MetaEvent::BackgroundLoaded)
+			);
+			assert!(module.diff.as_ref().unwrap().file_statuses()[0].is_content_available());
+		});
+	});
+}
+
+#[test]
+fn activate_fetch_missing_objects_disabled() {
+	with_temp_partial_clone(|clone, hash| {
+		let line = format!("pick {} comment1", hash);
+		module_test(&[line.as_str()], &[], |test_context| {
+			let mut module = ShowCommit::new(&Config::new(), clone);
+			module.set_partial_clone(clone);
+			assert_process_result!(test_context.activate(&mut module, State::List));
+			assert!(module.object_fetch.is_none());
+		});
+	});
+}
+
+#[test]
+fn activate_fetch_missing_objects_without_partial_clone() {
+	with_temp_partial_clone(|clone, hash| {
+		let line = format!("pick {} comment1", hash);
+		module_test(&[line.as_str()], &[], |test_context| {
+			let mut module = ShowCommit::new(&create_fetch_config(), clone);
+			assert_process_result!(test_context.activate(&mut module, State::List));
+			assert!(module.object_fetch.is_none());
+		});
+	});
+}
+
+#[test]
+fn render_fetch_missing_objects_progress() {
+	with_temp_partial_clone(|clone, hash| {
+		let line = format!("pick {} comment1", hash);
+		module_test(&[line.as_str()], &[], |test_context| {
+			let mut module = ShowCommit::new(&create_fetch_config(), clone);
+			module.set_partial_clone(clone);
+			let _result = test_context.activate(&mut module, State::List);
+			assert_rendered_output!(
+				test_context.build_view_data(&mut module),
+				render_line!(AnyLine 12),
+				"{TRAILING}",
+				render_line!(StartsWith "{IndicatorColor}- Fetching 1 missing objects from origin, ")
+			);
+		});
+	});
+}
+
+#[test]
+fn handle_event_cancel_fetch_missing_objects() {
+	with_temp_partial_clone(|clone, hash| {
+		let line = format!("pick {} comment1", hash);
+		module_test(&[line.as_str()], &[Event::from(KeyCode::Esc)], |mut test_context| {
+			let mut module = ShowCommit::new(&create_fetch_config(), clone);
+			module.set_partial_clone(clone);
+			let _result = test_context.activate(&mut module, State::List);
+			assert_process_result!(
+				test_context.handle_event(&mut module),
+				event = Event::from(KeyCode::Esc)
+			);
+			assert!(module.object_fetch.is_none());
+			assert_eq!(module.state, ShowCommitState::Overview);
+			assert!(!module.diff.as_ref().unwrap().file_statuses()[0].is_content_available());
+		});
+	});
+}
+
+#[test]
+fn handle_event_other_key_during_fetch_missing_objects() {
+	with_temp_partial_clone(|clone, hash| {
+		let line = format!("pick {} comment1", hash);
+		module_test(&[line.as_str()], &[Event::from('a')], |mut test_context| {
+			let mut module = ShowCommit::new(&create_fetch_config(), clone);
+			module.set_partial_clone(clone);
+			let _result = test_context.activate(&mut module, State::List);
+			assert_process_result!(
+				test_context.handle_event(&mut module),
+				event = Event::from('a'),
+				state = State::List
+			);
+			assert!(module.object_fetch.is_some());
+		});
+	});
+}
+
+#[test]
+fn handle_bus_event_progress_tick_during_fetch_missing_objects() {
+	with_temp_partial_clone(|clone, hash| {
+		let line = format!("pick {} comment1", hash);
+		module_test(&[line.as_str()], &[], |test_context| {
+			let mut module = ShowCommit::new(&create_fetch_config(), clone);
+			module.set_partial_clone(clone);
+			let _result = test_context.activate(&mut module, State::List);
+			let tick = BusEvent::Tick(Timer::Progress);
+			while module.object_fetch.is_some() {
+				assert_process_result!(
+					module.handle_bus_event(&tick, &test_context.rebase_todo_file),
+					event = Event::from(MetaEvent::BackgroundLoaded)
+				);
+				sleep(Duration::from_millis(10));
+			}
+			assert!(module.diff.as_ref().unwrap().file_statuses()[0].is_content_available());
+			assert_eq!(
+				module.handle_bus_event(&tick, &test_context.rebase_todo_file).timer,
+				Some(TimerRequest::Stop(Timer::Progress))
+			);
+		});
+	});
+}
+
+#[test]
+fn subscriptions() {
+	with_temp_repository(|repository| {
+		let module = ShowCommit::new(&Config::new(), &repository);
+		assert_eq!(module.subscriptions(), &[Topic::Timer]);
+		Ok(())
+	});
+}
+
+#[test]
+fn handle_event_fetch_missing_objects_error() {
+	with_temp_partial_clone(|clone, hash| {
+		let line = format!("pick {} comment1", hash);
+		module_test(&[line.as_str()], &[], |test_context| {
+			let mut module = ShowCommit::new(&create_fetch_config(), clone);
+			module.set_partial_clone(clone);
+			let _result = test_context.activate(&mut module, State::List);
+			module.object_fetch = Some(
+				clone
+					.fetch_missing_objects(&[String::from("0123456789012345678901234567890123456789")])
+					.unwrap(),
+			);
+			let result = wait_for_object_fetch(&mut module).unwrap();
+			assert_eq!(
+				result.error.unwrap().to_string(),
+				"Unable to fetch missing objects"
+			);
+		});
+	});
+}
+
+#[test]
+fn squash_preview_activate() {
+	RepoBuilder::new()
+		.commit_files("base", &[("a.txt", "a\n")])
+		.commit_files("one", &[("a.txt", "a\none\n")])
+		.commit_files("two", &[("b.txt", "two\n")])
+		.with_repository(|repository, commits| {
+			let lines = [
+				format!("pick {} one", commits.hash("one")),
+				String::from("exec make"),
+				format!("fixup {} two", commits.hash("two")),
+			];
+			module_test(
+				&[lines[0].as_str(), lines[1].as_str(), lines[2].as_str()],
+				&[Event::from(MetaEvent::ShowFile)],
+				|mut test_context| {
+					let mut module = ShowCommit::new(&Config::new(), &repository);
+					module.set_squash_preview(&repository);
+					test_context.rebase_todo_file.set_selected_line_index(2);
+					test_context.rebase_todo_file.set_selected_range_start(Some(0));
+					assert_process_result!(test_context.activate(&mut module, State::List));
+					let diff = module.diff.as_ref().unwrap();
+					assert_eq!(diff.commit().hash(), commits.hash("one"));
+					assert_eq!(diff.number_files_changed(), 2);
+					assert_rendered_output!(
+						test_context.build_view_data(&mut module),
+						"{TITLE}{HELP}",
+						"{LEADING}",
+						render_line!(StartsWith "{IndicatorColor}Commit: "),
+						"{BODY}",
+						render_line!(AnyLine 6),
+						"{IndicatorColor}2{Normal} files{Normal} with {DiffAddColor}2{Normal} insertions{Normal} and \
+						 {DiffRemoveColor}0{Normal} deletions",
+						"{DiffChangeColor}modified: {DiffChangeColor}a.txt",
+						"{DiffAddColor}   added: {DiffAddColor}b.txt",
+						"{TRAILING}",
+						"{IndicatorColor}The combined changes of 2 commits, as if they were squashed into the first commit"
+					);
+					// the files are of a single commit, and not of the combined changes
+					assert_process_result!(
+						test_context.handle_event(&mut module),
+						event = Event::from(MetaEvent::ShowFile)
+					);
+					assert_eq!(module.state, ShowCommitState::Overview);
+				},
+			);
+			Ok(())
+		});
+}
+
+#[test]
+fn squash_preview_activate_without_commit() {
+	with_temp_repository(|repo| {
+		module_test(&["exec make"], &[], |test_context| {
+			let mut module = ShowCommit::new(&Config::new(), &repo);
+			module.set_squash_preview(&repo);
+			assert_process_result!(
+				test_context.activate(&mut module, State::List),
+				state = State::List,
+				error = anyhow!("No valid commit to show")
+			);
+		});
+		Ok(())
+	});
+}
+
+#[test]
+fn squash_preview_activate_conflict() {
+	RepoBuilder::new()
+		.commit_files("base", &[("a.txt", "a\n")])
+		.commit_files("one", &[("a.txt", "one\n")])
+		.commit_files("two", &[("a.txt", "two\n")])
+		.with_repository(|repository, commits| {
+			let lines = [
+				format!("pick {} two", commits.hash("two")),
+				format!("squash {} base", commits.hash("base")),
+			];
+			module_test(&[lines[0].as_str(), lines[1].as_str()], &[], |mut test_context| {
+				let mut module = ShowCommit::new(&Config::new(), &repository);
+				module.set_squash_preview(&repository);
+				test_context.rebase_todo_file.set_selected_range_start(Some(1));
+				assert_process_result!(
+					test_context.activate(&mut module, State::List),
+					state = State::List,
+					error = anyhow!(
+						"Unable to combine the changes of the selected commits: The changes of {} conflict with the \
+						 changes of the earlier commits",
+						commits.hash("base")
+					)
+				);
+			});
+			Ok(())
+		});
+}
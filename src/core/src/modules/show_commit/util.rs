@@ -7,19 +7,35 @@ use num_format::{Locale, ToFormattedString};
 use unicode_segmentation::UnicodeSegmentation;
 use view::{LineSegment, ViewLine};
 
+use crate::{i18n::translate, icons::get_file_icon};
+
 pub(super) fn get_show_commit_help_lines(key_bindings: &KeyBindings) -> Vec<(Vec<String>, String)> {
 	vec![
-		(key_bindings.move_up.clone(), String::from("Scroll up")),
-		(key_bindings.move_down.clone(), String::from("Scroll down")),
-		(key_bindings.move_up_step.clone(), String::from("Scroll up half a page")),
+		(key_bindings.move_up.clone(), String::from(translate("Scroll up"))),
+		(key_bindings.move_down.clone(), String::from(translate("Scroll down"))),
+		(
+			key_bindings.move_up_step.clone(),
+			String::from(translate("Scroll up half a page")),
+		),
 		(
 			key_bindings.move_down_step.clone(),
-			String::from("Scroll down half a page"),
+			String::from(translate("Scroll down half a page")),
+		),
+		(key_bindings.move_right.clone(), String::from(translate("Scroll right"))),
+		(key_bindings.move_left.clone(), String::from(translate("Scroll left"))),
+		(
+			key_bindings.show_diff.clone(),
+			String::from(translate("Show full diff")),
+		),
+		(
+			key_bindings.show_file.clone(),
+			String::from(translate("Show the changed files, a page at a time")),
+		),
+		(
+			key_bindings.show_source_commit.clone(),
+			String::from(translate("Show the commit that the commit was cherry picked from")),
 		),
-		(key_bindings.move_right.clone(), String::from("Scroll right")),
-		(key_bindings.move_left.clone(), String::from("Scroll left")),
-		(key_bindings.show_diff.clone(), String::from("Show full diff")),
-		(key_bindings.help.clone(), String::from("Show help")),
+		(key_bindings.help.clone(), String::from(translate("Show help"))),
 	]
 }
 
@@ -28,6 +44,7 @@ pub(super) fn get_stat_item_segments(
 	to_name: &Path,
 	from_name: &Path,
 	is_full_width: bool,
+	show_icons: bool,
 ) -> Vec<LineSegment> {
 	let status_name = if is_full_width {
 		match status {
@@ -64,7 +81,7 @@ pub(super) fn get_stat_item_segments(
 
 	let to_file_indicator = if is_full_width { " → " } else { "→" };
 
-	match status {
+	let mut segments = match status {
 		Status::Copied => {
 			vec![
 				LineSegment::new_with_color(status_name.as_str(), color),
@@ -87,7 +104,12 @@ pub(super) fn get_stat_item_segments(
 				LineSegment::new_with_color(from_name.to_str().unwrap_or("invalid"), color),
 			]
 		},
+	};
+
+	if show_icons {
+		segments.insert(1, LineSegment::new(format!("{} ", get_file_icon(from_name)).as_str()));
 	}
+	segments
 }
 
 pub(super) fn get_files_changed_summary(diff: &CommitDiff, is_full_width: bool) -> ViewLine {
@@ -101,19 +123,19 @@ pub(super) fn get_files_changed_summary(diff: &CommitDiff, is_full_width: bool)
 				files_changed.to_formatted_string(&Locale::en).as_str(),
 				DisplayColor::IndicatorColor,
 			),
-			LineSegment::new(if files_changed == 1 { " file" } else { " files" }),
-			LineSegment::new(" with "),
+			LineSegment::new(translate(if files_changed == 1 { " file" } else { " files" })),
+			LineSegment::new(translate(" with ")),
 			LineSegment::new_with_color(
 				insertions.to_formatted_string(&Locale::en).as_str(),
 				DisplayColor::DiffAddColor,
 			),
-			LineSegment::new(if insertions == 1 { " insertion" } else { " insertions" }),
-			LineSegment::new(" and "),
+			LineSegment::new(translate(if insertions == 1 { " insertion" } else { " insertions" })),
+			LineSegment::new(translate(" and ")),
 			LineSegment::new_with_color(
 				deletions.to_formatted_string(&Locale::en).as_str(),
 				DisplayColor::DiffRemoveColor,
 			),
-			LineSegment::new(if deletions == 1 { " deletion" } else { " deletions" }),
+			LineSegment::new(translate(if deletions == 1 { " deletion" } else { " deletions" })),
 		])
 	}
 	else {
@@ -136,25 +158,18 @@ pub(super) fn get_files_changed_summary(diff: &CommitDiff, is_full_width: bool)
 	}
 }
 
+/// The byte indexes of the start and the end of the content of a line, between the leading and trailing whitespace,
+/// which are always on the boundary of a grapheme.
 pub(super) fn get_partition_index_on_whitespace_for_line(line: &str) -> (usize, usize) {
-	let graphemes = UnicodeSegmentation::graphemes(line, true);
-	let length = graphemes.clone().count();
-	let mut start_partition_index = 0;
-	let mut end_partition_index = length;
-
-	for (index, c) in graphemes.clone().enumerate() {
-		if c != " " && c != "\t" && c != "\n" {
-			start_partition_index = index;
-			break;
-		}
-	}
-
-	for (index, c) in graphemes.rev().enumerate() {
-		if c != " " && c != "\t" && c != "\n" {
-			end_partition_index = length - index;
-			break;
-		}
-	}
+	let is_content = |&(_, grapheme): &(usize, &str)| grapheme != " " && grapheme != "\t" && grapheme != "\n";
+	let mut graphemes = UnicodeSegmentation::grapheme_indices(line, true);
+	let start_partition_index = graphemes
+		.clone()
+		.find(is_content)
+		.map_or(0, |(index, _)| index);
+	let end_partition_index = graphemes
+		.rfind(is_content)
+		.map_or(line.len(), |(index, grapheme)| index + grapheme.len());
 
 	(start_partition_index, end_partition_index)
 }
@@ -1,24 +1,43 @@
+use config::DateFormat;
 use display::DisplayColor;
 use git::{Commit, CommitDiff, DiffLine, Origin};
+use num_format::{Locale, ToFormattedString};
 use view::{LineSegment, ViewDataUpdater, ViewLine};
 
-use super::util::{get_files_changed_summary, get_partition_index_on_whitespace_for_line, get_stat_item_segments};
+use super::{
+	file_page::FilePage,
+	util::{get_files_changed_summary, get_partition_index_on_whitespace_for_line, get_stat_item_segments},
+};
+use crate::{
+	dates::format_date,
+	i18n::{translate, translate_format},
+};
 
+#[allow(clippy::struct_excessive_bools)]
 pub(super) struct ViewBuilderOptions {
 	space_character: String,
 	tab_character: String,
 	tab_width: usize,
 	show_leading_whitespace: bool,
 	show_trailing_whitespace: bool,
+	show_icons: bool,
+	show_origin: bool,
+	screen_reader: bool,
+	date_format: DateFormat,
 }
 
 impl ViewBuilderOptions {
+	#[allow(clippy::fn_params_excessive_bools, clippy::too_many_arguments)]
 	pub(crate) fn new(
 		tab_width: usize,
 		tab_character: &str,
 		space_character: &str,
 		show_leading_whitespace: bool,
 		show_trailing_whitespace: bool,
+		show_icons: bool,
+		show_origin: bool,
+		screen_reader: bool,
+		date_format: DateFormat,
 	) -> Self {
 		Self {
 			space_character: String::from(space_character),
@@ -26,16 +45,27 @@ impl ViewBuilderOptions {
 			tab_width,
 			show_leading_whitespace,
 			show_trailing_whitespace,
+			show_icons,
+			show_origin,
+			screen_reader,
+			date_format,
 		}
 	}
 }
 
+#[allow(clippy::struct_excessive_bools)]
 pub(super) struct ViewBuilder {
 	invisible_tab_string: String,
 	visible_tab_string: String,
 	visible_space_string: String,
 	show_leading_whitespace: bool,
 	show_trailing_whitespace: bool,
+	show_icons: bool,
+	file_separator_character: char,
+	hunk_separator_character: char,
+	// the origin of a diff line is shown with a character, rather than only with color
+	show_origin: bool,
+	date_format: DateFormat,
 }
 
 impl ViewBuilder {
@@ -46,6 +76,12 @@ impl ViewBuilder {
 			visible_space_string: options.space_character,
 			show_leading_whitespace: options.show_leading_whitespace,
 			show_trailing_whitespace: options.show_trailing_whitespace,
+			show_icons: options.show_icons,
+			// box drawing characters are not read well by screen readers
+			file_separator_character: if options.screen_reader { '-' } else { '―' },
+			hunk_separator_character: if options.screen_reader { '-' } else { '┈' },
+			show_origin: options.show_origin || options.screen_reader,
+			date_format: options.date_format,
 		}
 	}
 
@@ -61,12 +97,31 @@ impl ViewBuilder {
 		.replace("\n", "")
 	}
 
+	// the objects that are not in a shallow or partial clone are described, since the diff does not include them
+	fn push_unavailable_objects(updater: &mut ViewDataUpdater<'_>, diff: &CommitDiff) {
+		if diff.is_shallow_boundary() {
+			updater.push_line(ViewLine::from(LineSegment::new_with_color(
+				translate("The parent commit is not in this shallow clone, the files are compared to an empty tree"),
+				DisplayColor::IndicatorColor,
+			)));
+		}
+		if !diff.is_tree_available() {
+			updater.push_line(ViewLine::from(LineSegment::new_with_color(
+				translate("The files of the commit are not in this partial clone"),
+				DisplayColor::IndicatorColor,
+			)));
+		}
+	}
+
 	// safe slice, as it is only on the hash, which is hexadecimal
 	#[allow(clippy::string_slice)]
 	fn build_leading_summary(commit: &Commit, is_full_width: bool) -> ViewLine {
 		let mut segments = vec![];
 		if is_full_width {
-			segments.push(LineSegment::new_with_color("Commit: ", DisplayColor::IndicatorColor));
+			segments.push(LineSegment::new_with_color(
+				translate("Commit: "),
+				DisplayColor::IndicatorColor,
+			));
 		}
 		let hash = String::from(commit.hash());
 		segments.push(LineSegment::new(
@@ -94,16 +149,16 @@ impl ViewBuilder {
 		// TODO handle authored date
 		updater.push_line(ViewLine::from(vec![
 			LineSegment::new_with_color(
-				if is_full_width { "Date: " } else { "D: " },
+				if is_full_width { translate("Date: ") } else { "D: " },
 				DisplayColor::IndicatorColor,
 			),
-			LineSegment::new(commit.committed_date().format("%c %z").to_string().as_str()),
+			LineSegment::new(format_date(commit.committed_date(), &self.date_format).as_str()),
 		]));
 
 		if commit.author().is_some() {
 			updater.push_line(ViewLine::from(vec![
 				LineSegment::new_with_color(
-					if is_full_width { "Author: " } else { "A: " },
+					if is_full_width { translate("Author: ") } else { "A: " },
 					DisplayColor::IndicatorColor,
 				),
 				LineSegment::new(commit.author().to_string().as_str()),
@@ -113,7 +168,7 @@ impl ViewBuilder {
 		if let Some(committer) = commit.committer().as_ref() {
 			updater.push_line(ViewLine::from(vec![
 				LineSegment::new_with_color(
-					if is_full_width { "Committer: " } else { "C: " },
+					if is_full_width { translate("Committer: ") } else { "C: " },
 					DisplayColor::IndicatorColor,
 				),
 				LineSegment::new(committer.to_string().as_str()),
@@ -135,12 +190,14 @@ impl ViewBuilder {
 		}
 
 		updater.push_line(get_files_changed_summary(diff, is_full_width));
+		Self::push_unavailable_objects(updater, diff);
 		for status in diff.file_statuses() {
 			updater.push_line(ViewLine::from(get_stat_item_segments(
 				status.status(),
 				status.destination_path(),
 				status.source_path(),
 				is_full_width,
+				self.show_icons,
 			)));
 		}
 	}
@@ -153,11 +210,11 @@ impl ViewBuilder {
 		})
 	}
 
-	// safe slice, only slices across graphemes whitespace
+	// safe slice, the partition indexes are always on the boundary of a grapheme
 	#[allow(clippy::string_slice)]
 	fn get_diff_line_segments(
 		&self,
-		diff_line: &DiffLine,
+		diff_line: &DiffLine<'_>,
 		old_largest_line_number_length: usize,
 		new_largest_line_number_length: usize,
 	) -> Vec<LineSegment> {
@@ -179,7 +236,11 @@ impl ViewBuilder {
 				},
 				None => LineSegment::new(" ".repeat(new_largest_line_number_length).as_str()),
 			},
-			LineSegment::new("| "),
+			LineSegment::new(match diff_line.origin() {
+				Origin::Addition if self.show_origin => "+ ",
+				Origin::Deletion if self.show_origin => "- ",
+				_ => "| ",
+			}),
 		];
 
 		if self.show_leading_whitespace || self.show_trailing_whitespace {
@@ -226,6 +287,58 @@ impl ViewBuilder {
 		line_segments
 	}
 
+	pub(super) fn build_view_data_for_file(&self, updater: &mut ViewDataUpdater<'_>, page: &FilePage) {
+		let size = page.size().to_formatted_string(&Locale::en);
+		updater.push_leading_line(ViewLine::from(vec![
+			LineSegment::new_with_color(page.path().to_str().unwrap_or("invalid"), DisplayColor::IndicatorColor),
+			LineSegment::new(
+				if !page.is_available() {
+					String::from(translate(" object not available"))
+				}
+				else if page.is_binary() {
+					translate_format(" binary file, {} bytes", &[&size])
+				}
+				else if page.lines().is_empty() {
+					translate_format(" {} bytes", &[&size])
+				}
+				else {
+					translate_format(" lines {}-{}, {} bytes", &[
+						&page.first_line_number(),
+						&(page.next_line_number() - 1),
+						&size,
+					])
+				}
+				.as_str(),
+			),
+		]));
+		updater.push_line(ViewLine::new_empty_line().set_padding(self.file_separator_character));
+
+		let line_number_length = page.next_line_number().to_string().len();
+		for (index, line) in page.lines().iter().enumerate() {
+			updater.push_line(ViewLine::from(vec![
+				LineSegment::new(
+					format!(
+						"{:>width$} | ",
+						page.first_line_number() + index,
+						width = line_number_length
+					)
+					.as_str(),
+				),
+				LineSegment::new(self.replace_whitespace(line.as_str(), false).as_str()),
+			]));
+		}
+
+		if let Some(next_offset) = page.next_offset() {
+			updater.push_line(ViewLine::from(LineSegment::new_with_color(
+				translate_format("{} more bytes", &[
+					&(page.size() - next_offset).to_formatted_string(&Locale::en)
+				])
+				.as_str(),
+				DisplayColor::IndicatorColor,
+			)));
+		}
+	}
+
 	pub(super) fn build_view_data_diff(
 		&self,
 		updater: &mut ViewDataUpdater<'_>,
@@ -234,16 +347,25 @@ impl ViewBuilder {
 	) {
 		updater.push_leading_line(Self::build_leading_summary(diff.commit(), is_full_width));
 		updater.push_leading_line(get_files_changed_summary(diff, is_full_width));
-		updater.push_line(ViewLine::new_empty_line().set_padding('―'));
+		Self::push_unavailable_objects(updater, diff);
+		updater.push_line(ViewLine::new_empty_line().set_padding(self.file_separator_character));
 
 		let file_statuses = diff.file_statuses();
 		for (s_i, status) in file_statuses.iter().enumerate() {
-			updater.push_line(ViewLine::from(get_stat_item_segments(
+			let mut stat_item_segments = get_stat_item_segments(
 				status.status(),
 				status.destination_path(),
 				status.source_path(),
 				true,
-			)));
+				self.show_icons,
+			);
+			if !status.is_content_available() {
+				stat_item_segments.push(LineSegment::new_with_color(
+					translate(" object not available"),
+					DisplayColor::IndicatorColor,
+				));
+			}
+			updater.push_line(ViewLine::from(stat_item_segments));
 
 			let old_largest_line_number_length = status.last_old_line_number().to_string().len();
 			let new_largest_line_number_length = status.last_new_line_number().to_string().len();
@@ -269,7 +391,7 @@ impl ViewBuilder {
 					),
 				]));
 				updater.push_line(ViewLine::new_pinned(vec![]).set_padding_with_color_and_style(
-					'┈',
+					self.hunk_separator_character,
 					DisplayColor::Normal,
 					true,
 					false,
@@ -289,14 +411,14 @@ impl ViewBuilder {
 					}
 
 					updater.push_line(ViewLine::from(self.get_diff_line_segments(
-						line,
+						&line,
 						old_largest_line_number_length,
 						new_largest_line_number_length,
 					)));
 				}
 			}
 			if s_i + 1 != file_statuses.len() {
-				updater.push_line(ViewLine::new_empty_line().set_padding('―'));
+				updater.push_line(ViewLine::new_empty_line().set_padding(self.file_separator_character));
 			}
 		}
 	}
@@ -0,0 +1,290 @@
+#[cfg(all(unix, test))]
+mod tests;
+
+use std::{
+	fs::{read_to_string, remove_file, write},
+	path::PathBuf,
+};
+
+use anyhow::{anyhow, Result};
+use config::Config;
+use display::DisplayColor;
+use git::Repository;
+use input::{Event, EventHandler, InputOptions, KeyCode, KeyEvent, MetaEvent};
+use lazy_static::lazy_static;
+use todo_file::{Action, TodoFile};
+use view::{handle_view_data_scroll, LineSegment, RenderContext, ViewData, ViewLine, ViewSender};
+
+use super::{editor_command, select_editor};
+use crate::{
+	i18n::{translate, translate_format},
+	module::{Module, ProcessResult, State},
+};
+
+lazy_static! {
+	static ref INPUT_OPTIONS: InputOptions = InputOptions::new().movement(true);
+}
+
+// the prefixes of the subjects created by `git commit --fixup` and `git commit --squash`
+const FIXUP_PREFIXES: [&str; 3] = ["amend!", "fixup!", "squash!"];
+
+fn push_commented_line(message: &mut String, comment_char: &str, line: &str) {
+	message.push_str(comment_char);
+	if !line.is_empty() {
+		message.push(' ');
+		message.push_str(line);
+	}
+	message.push('\n');
+}
+
+/// Build the message that Git asks for when the commits of a chain of fixup and squash lines are squashed, with the
+/// message of each commit after a comment. Like Git, the messages of the fixup commits, and the subjects of the squash
+/// commits that were created with `git commit --fixup` or `git commit --squash`, are commented.
+pub(crate) fn build_squash_message(commits: &[(Action, &str)], comment_char: &str) -> String {
+	let mut message = String::new();
+	push_commented_line(
+		&mut message,
+		comment_char,
+		translate_format("This is a combination of {} commits.", &[&commits.len()]).as_str(),
+	);
+	for (index, &(action, commit_message)) in commits.iter().enumerate() {
+		let number = index + 1;
+		let header = if index == 0 {
+			String::from(translate("This is the 1st commit message:"))
+		}
+		else if action == Action::Fixup {
+			message.push('\n');
+			translate_format("The commit message #{} will be skipped:", &[&number])
+		}
+		else {
+			message.push('\n');
+			translate_format("This is the commit message #{}:", &[&number])
+		};
+		push_commented_line(&mut message, comment_char, header.as_str());
+		message.push('\n');
+
+		let is_fixup_subject = index > 0 && FIXUP_PREFIXES.iter().any(|prefix| commit_message.starts_with(prefix));
+		let mut is_subject = true;
+		for line in commit_message.lines() {
+			is_subject = is_subject && !line.trim().is_empty();
+			if action == Action::Fixup || (is_fixup_subject && is_subject) {
+				push_commented_line(&mut message, comment_char, line);
+			}
+			else {
+				message.push_str(line);
+				message.push('\n');
+			}
+		}
+	}
+	message
+}
+
+/// Clean up a message like the default cleanup of Git, removing the comment lines, the trailing white space of the
+/// lines, and the empty lines at the start and end of the message, and joining the consecutive empty lines.
+pub(crate) fn cleanup_message(message: &str, comment_char: &str) -> String {
+	let mut lines: Vec<&str> = vec![];
+	for line in message.lines().filter(|line| !line.starts_with(comment_char)) {
+		let line = line.trim_end();
+		if line.is_empty() && lines.last().map_or(true, |last| last.is_empty()) {
+			continue;
+		}
+		lines.push(line);
+	}
+	while lines.last() == Some(&"") {
+		let _ = lines.pop();
+	}
+	if lines.is_empty() {
+		String::new()
+	}
+	else {
+		format!("{}\n", lines.join("\n"))
+	}
+}
+
+pub(crate) struct SquashMessage<'s> {
+	abort_key: String,
+	chain_start: usize,
+	comment_char: String,
+	edit_key: String,
+	editor: String,
+	hash: String,
+	message: String,
+	message_path: PathBuf,
+	repository: &'s Repository,
+	view_data: ViewData,
+}
+
+impl Module for SquashMessage<'_> {
+	fn activate(&mut self, todo_file: &TodoFile, _: State) -> ProcessResult {
+		let (start, end) = match todo_file.get_squash_chain(todo_file.get_selected_line_index()) {
+			Some((start, end)) => (start, end),
+			None => {
+				return ProcessResult::new()
+					.error(anyhow!(translate(
+						"The selected line is not in a chain of squash commits"
+					)))
+					.state(State::List);
+			},
+		};
+		let mut messages = vec![];
+		for line in todo_file.lines_iter().skip(start).take(end - start + 1) {
+			match self.repository.load_commit(line.get_hash()) {
+				Ok(commit) => messages.push((*line.get_action(), commit.message().clone().unwrap_or_default())),
+				Err(err) => {
+					return ProcessResult::new()
+						.error(err.context(translate("Unable to load the messages of the squashed commits")))
+						.state(State::List);
+				},
+			}
+		}
+		let commits: Vec<(Action, &str)> = messages
+			.iter()
+			.map(|&(action, ref message)| (action, message.as_str()))
+			.collect();
+		self.chain_start = start;
+		self.hash = String::from(todo_file.get_line(start).map_or("", |line| line.get_hash()));
+		self.message = build_squash_message(commits.as_slice(), self.comment_char.as_str());
+		// the message is edited in a file next to the todo file, like the todo file is edited
+		self.message_path = PathBuf::from(format!("{}.squash-message", todo_file.get_filepath()));
+		self.build_message_view_data();
+		ProcessResult::new()
+	}
+
+	fn build_view_data(&mut self, _: &RenderContext, _: &TodoFile) -> &ViewData {
+		&self.view_data
+	}
+
+	fn handle_events(
+		&mut self,
+		event_handler: &EventHandler,
+		view_sender: &ViewSender,
+		todo_file: &mut TodoFile,
+	) -> ProcessResult {
+		let event = event_handler.read_event(&INPUT_OPTIONS, |event, key_bindings| {
+			if key_bindings.edit.contains(&event) {
+				Event::from(MetaEvent::Edit)
+			}
+			else if key_bindings.abort.contains(&event) {
+				Event::from(MetaEvent::Abort)
+			}
+			else {
+				event
+			}
+		});
+		let mut result = ProcessResult::from(event);
+
+		if handle_view_data_scroll(event, view_sender).is_none() {
+			match event {
+				Event::Meta(MetaEvent::Edit) => {
+					match self.start_edit() {
+						Ok((command, arguments)) => result = result.external_command(command, arguments),
+						Err(err) => result = result.error(err),
+					}
+				},
+				Event::Meta(MetaEvent::ExternalCommandSuccess) => {
+					match self.finish_edit(todo_file) {
+						Ok(true) => result = result.state(State::List),
+						Ok(false) => {},
+						Err(err) => result = result.error(err),
+					}
+				},
+				Event::Meta(MetaEvent::ExternalCommandError) => {
+					let _ = remove_file(self.message_path.as_path());
+					result = result.error(anyhow!(translate("Editor returned a non-zero exit status")));
+				},
+				Event::Meta(MetaEvent::Abort) | Event::Key(KeyEvent { code: KeyCode::Esc, .. }) => {
+					result = result.state(State::List);
+				},
+				_ => {},
+			}
+		}
+		result
+	}
+
+	fn update_config(&mut self, config: &Config) {
+		self.abort_key = Self::first_key(&config.key_bindings.abort, "q");
+		self.comment_char = config.git.comment_char.clone();
+		self.edit_key = Self::first_key(&config.key_bindings.edit, "E");
+		self.editor = String::from(select_editor(config.message_editor.as_str(), config.git.editor.as_str()));
+	}
+}
+
+impl<'s> SquashMessage<'s> {
+	pub(crate) fn new(config: &Config, repository: &'s Repository) -> Self {
+		Self {
+			abort_key: Self::first_key(&config.key_bindings.abort, "q"),
+			chain_start: 0,
+			comment_char: config.git.comment_char.clone(),
+			edit_key: Self::first_key(&config.key_bindings.edit, "E"),
+			editor: String::from(select_editor(config.message_editor.as_str(), config.git.editor.as_str())),
+			hash: String::new(),
+			message: String::new(),
+			message_path: PathBuf::new(),
+			repository,
+			view_data: ViewData::new(|updater| {
+				updater.set_show_title(true);
+			}),
+		}
+	}
+
+	fn first_key(keys: &[String], default: &str) -> String {
+		keys.first().map_or(String::from(default), String::from)
+	}
+
+	fn build_message_view_data(&mut self) {
+		let hint = translate_format("Press {} to edit the message, or {} to return to the list", &[
+			&self.edit_key,
+			&self.abort_key,
+		]);
+		let comment_char = self.comment_char.as_str();
+		let message = self.message.as_str();
+		self.view_data.update_view_data(|updater| {
+			updater.clear();
+			updater.reset_scroll_position();
+			updater.push_leading_line(ViewLine::from(translate(
+				"The message that Git asks for when the commits are squashed",
+			)));
+			for line in message.lines() {
+				// the comments are removed from the message by Git
+				updater.push_line(ViewLine::from(LineSegment::new_with_color_and_style(
+					line,
+					DisplayColor::Normal,
+					line.starts_with(comment_char),
+					false,
+					false,
+				)));
+			}
+			updater.push_trailing_line(ViewLine::from(LineSegment::new_with_color(
+				hint.as_str(),
+				DisplayColor::IndicatorColor,
+			)));
+		});
+	}
+
+	fn start_edit(&self) -> Result<(String, Vec<String>)> {
+		write(self.message_path.as_path(), self.message.as_str()).map_err(|err| {
+			anyhow!(err).context(translate_format("Unable to write {}", &[&self.message_path.display()]))
+		})?;
+		editor_command(self.editor.as_str(), self.message_path.to_string_lossy().as_ref())
+	}
+
+	// the message is kept in a copy of the first commit, that replaces the commit in the todo list, so Git uses the
+	// message when the commits are squashed, instead of asking for it
+	fn finish_edit(&self, todo_file: &mut TodoFile) -> Result<bool> {
+		let content = read_to_string(self.message_path.as_path()).map_err(|err| {
+			anyhow!(err).context(translate_format("Unable to read {}", &[&self.message_path.display()]))
+		});
+		let _ = remove_file(self.message_path.as_path());
+		let message = cleanup_message(content?.as_str(), self.comment_char.as_str());
+		if message.is_empty() {
+			return Err(anyhow!(translate(
+				"The message is empty, so the commits are not changed"
+			)));
+		}
+		let hash = self
+			.repository
+			.create_commit_with_message(self.hash.as_str(), message.as_str())?;
+		log::info!(target: "git", "Created {} with the message for the squashed commits of {}", hash, self.hash);
+		Ok(todo_file.set_squash_chain_message(self.chain_start, hash.as_str()))
+	}
+}
@@ -0,0 +1,299 @@
+use git::testutil::{with_temp_repository, RepoBuilder};
+use view::assert_rendered_output;
+
+use super::*;
+use crate::{assert_process_result, testutil::module_test};
+
+fn create_config() -> Config {
+	let mut config = Config::new();
+	config.git.editor = String::from("editor");
+	config
+}
+
+fn with_squash_chain<C>(callback: C)
+where C: FnOnce(&Repository, Vec<String>) {
+	RepoBuilder::new()
+		.commit_files("c1", &[("one.txt", "one\n")])
+		.commit_files("c2\n\nbody", &[("two.txt", "two\n")])
+		.commit_files("fixup! c1", &[("one.txt", "fixed\n")])
+		.with_repository(|repository, commits| {
+			callback(&repository, vec![
+				format!("pick {} c1", commits.hash("c1")),
+				format!("squash {} c2", commits.hash("c2\n\nbody")),
+				format!("fixup {} fixup! c1", commits.hash("fixup! c1")),
+			]);
+			Ok(())
+		});
+}
+
+#[test]
+fn squash_message() {
+	assert_eq!(
+		build_squash_message(
+			&[
+				(Action::Pick, "c1\n"),
+				(Action::Squash, "c2\n\nbody\n"),
+				(Action::Fixup, "fixup! c1\n\nfix\n"),
+				(Action::Squash, "squash! c1\nmore\n\nbody"),
+			],
+			"#"
+		),
+		"# This is a combination of 4 commits.\n# This is the 1st commit message:\n\nc1\n\n# This is the commit message \
+		 #2:\n\nc2\n\nbody\n\n# The commit message #3 will be skipped:\n\n# fixup! c1\n#\n# fix\n\n# This is the commit \
+		 message #4:\n\n# squash! c1\n# more\n\nbody\n"
+	);
+}
+
+#[test]
+fn squash_message_comment_char() {
+	assert_eq!(
+		build_squash_message(&[(Action::Pick, "c1"), (Action::Fixup, "c2")], ";"),
+		"; This is a combination of 2 commits.\n; This is the 1st commit message:\n\nc1\n\n; The commit message #2 will \
+		 be skipped:\n\n; c2\n"
+	);
+}
+
+#[test]
+fn cleanup() {
+	assert_eq!(
+		cleanup_message("\n# comment\n\ntitle  \n\n\n\nbody\n# comment\n\n", "#"),
+		"title\n\nbody\n"
+	);
+}
+
+#[test]
+fn cleanup_only_comments() {
+	assert_eq!(cleanup_message("# comment\n\n#\n", "#"), "");
+}
+
+#[test]
+fn activate_not_in_chain() {
+	with_temp_repository(|repository| {
+		module_test(&["pick aaa c1", "pick bbb c2"], &[], |test_context| {
+			let mut module = SquashMessage::new(&create_config(), &repository);
+			assert_process_result!(
+				test_context.activate(&mut module, State::List),
+				state = State::List,
+				error = anyhow!("The selected line is not in a chain of squash commits")
+			);
+		});
+		Ok(())
+	});
+}
+
+#[test]
+fn activate_unknown_commit() {
+	with_temp_repository(|repository| {
+		module_test(&["pick aaa c1", "squash bbb c2"], &[], |test_context| {
+			let mut module = SquashMessage::new(&create_config(), &repository);
+			let result = test_context.activate(&mut module, State::List);
+			assert!(result.error.is_some_and(|err| {
+				format!("{err}") == "Unable to load the messages of the squashed commits"
+			}));
+		});
+		Ok(())
+	});
+}
+
+#[test]
+fn build_view_data() {
+	with_squash_chain(|repository, lines| {
+		let lines: Vec<&str> = lines.iter().map(String::as_str).collect();
+		module_test(lines.as_slice(), &[], |test_context| {
+			let mut module = SquashMessage::new(&create_config(), repository);
+			assert_process_result!(test_context.activate(&mut module, State::List));
+			let view_data = test_context.build_view_data(&mut module);
+			assert_rendered_output!(
+				view_data,
+				"{TITLE}",
+				"{LEADING}",
+				"{Normal}The message that Git asks for when the commits are squashed",
+				"{BODY}",
+				"{Normal,Dimmed}# This is a combination of 3 commits.",
+				"{Normal,Dimmed}# This is the 1st commit message:",
+				"{Normal}",
+				"{Normal}c1",
+				"{Normal}",
+				"{Normal,Dimmed}# This is the commit message #2:",
+				"{Normal}",
+				"{Normal}c2",
+				"{Normal}",
+				"{Normal}body",
+				"{Normal}",
+				"{Normal,Dimmed}# The commit message #3 will be skipped:",
+				"{Normal}",
+				"{Normal,Dimmed}# fixup! c1",
+				"{TRAILING}",
+				"{IndicatorColor}Press E to edit the message, or q to return to the list"
+			);
+		});
+	});
+}
+
+#[test]
+fn activate_selected_squash_line() {
+	with_squash_chain(|repository, lines| {
+		let lines: Vec<&str> = lines.iter().map(String::as_str).collect();
+		module_test(lines.as_slice(), &[], |mut test_context| {
+			test_context.rebase_todo_file.set_selected_line_index(2);
+			let mut module = SquashMessage::new(&create_config(), repository);
+			assert_process_result!(test_context.activate(&mut module, State::List));
+			assert_eq!(module.chain_start, 0);
+			assert!(module.message.starts_with("# This is a combination of 3 commits."));
+		});
+	});
+}
+
+#[test]
+fn edit() {
+	with_squash_chain(|repository, lines| {
+		let lines: Vec<&str> = lines.iter().map(String::as_str).collect();
+		module_test(lines.as_slice(), &[Event::from(MetaEvent::Edit)], |mut test_context| {
+			let mut module = SquashMessage::new(&create_config(), repository);
+			let _ = test_context.activate(&mut module, State::List);
+			let message_path = format!("{}.squash-message", test_context.rebase_todo_file.get_filepath());
+			assert_process_result!(
+				test_context.handle_event(&mut module),
+				event = Event::from(MetaEvent::Edit),
+				external_command = (String::from("editor"), vec![message_path.clone()])
+			);
+			assert_eq!(read_to_string(message_path.as_str()).unwrap(), module.message);
+			remove_file(message_path).unwrap();
+		});
+	});
+}
+
+#[test]
+fn edit_with_message_editor() {
+	with_squash_chain(|repository, lines| {
+		let lines: Vec<&str> = lines.iter().map(String::as_str).collect();
+		module_test(lines.as_slice(), &[Event::from(MetaEvent::Edit)], |mut test_context| {
+			let mut config = create_config();
+			config.message_editor = String::from("message-editor --wait");
+			let mut module = SquashMessage::new(&config, repository);
+			let _ = test_context.activate(&mut module, State::List);
+			let message_path = format!("{}.squash-message", test_context.rebase_todo_file.get_filepath());
+			assert_process_result!(
+				test_context.handle_event(&mut module),
+				event = Event::from(MetaEvent::Edit),
+				external_command = (String::from("message-editor"), vec![
+					String::from("--wait"),
+					message_path.clone()
+				])
+			);
+			remove_file(message_path).unwrap();
+		});
+	});
+}
+
+#[test]
+fn edit_success() {
+	with_squash_chain(|repository, lines| {
+		let lines: Vec<&str> = lines.iter().map(String::as_str).collect();
+		module_test(
+			lines.as_slice(),
+			&[],
+			|mut test_context| {
+				test_context
+					.event_handler_context
+					.event_handler
+					.push_event(Event::from(MetaEvent::ExternalCommandSuccess));
+				let mut module = SquashMessage::new(&create_config(), repository);
+				let _ = test_context.activate(&mut module, State::List);
+				let first_hash = String::from(test_context.rebase_todo_file.get_line(0).unwrap().get_hash());
+				write(
+					module.message_path.as_path(),
+					"# The message\n\ncombined\n\n\nbody\n",
+				)
+				.unwrap();
+				assert_process_result!(
+					test_context.handle_event(&mut module),
+					event = Event::from(MetaEvent::ExternalCommandSuccess),
+					state = State::List
+				);
+				assert!(!module.message_path.exists());
+				let first_line = test_context.rebase_todo_file.get_line(0).unwrap();
+				assert_ne!(first_line.get_hash(), first_hash);
+				let commit = repository.load_commit(first_line.get_hash()).unwrap();
+				assert_eq!(commit.message().as_deref(), Some("combined\n\nbody\n"));
+				assert_eq!(
+					test_context
+						.rebase_todo_file
+						.lines_iter()
+						.map(|line| *line.get_action())
+						.collect::<Vec<Action>>(),
+					vec![Action::Pick, Action::Fixup, Action::Fixup]
+				);
+			},
+		);
+	});
+}
+
+#[test]
+fn edit_empty_message() {
+	with_squash_chain(|repository, lines| {
+		let lines: Vec<&str> = lines.iter().map(String::as_str).collect();
+		module_test(
+			lines.as_slice(),
+			&[],
+			|mut test_context| {
+				test_context
+					.event_handler_context
+					.event_handler
+					.push_event(Event::from(MetaEvent::ExternalCommandSuccess));
+				let mut module = SquashMessage::new(&create_config(), repository);
+				let _ = test_context.activate(&mut module, State::List);
+				write(module.message_path.as_path(), "# The message\n\n").unwrap();
+				assert_process_result!(
+					test_context.handle_event(&mut module),
+					event = Event::from(MetaEvent::ExternalCommandSuccess),
+					error = anyhow!("The message is empty, so the commits are not changed")
+				);
+				assert_eq!(
+					test_context.rebase_todo_file.get_line(1).unwrap().get_action(),
+					&Action::Squash
+				);
+			},
+		);
+	});
+}
+
+#[test]
+fn edit_error() {
+	with_squash_chain(|repository, lines| {
+		let lines: Vec<&str> = lines.iter().map(String::as_str).collect();
+		module_test(
+			lines.as_slice(),
+			&[],
+			|mut test_context| {
+				test_context
+					.event_handler_context
+					.event_handler
+					.push_event(Event::from(MetaEvent::ExternalCommandError));
+				let mut module = SquashMessage::new(&create_config(), repository);
+				let _ = test_context.activate(&mut module, State::List);
+				assert_process_result!(
+					test_context.handle_event(&mut module),
+					event = Event::from(MetaEvent::ExternalCommandError),
+					error = anyhow!("Editor returned a non-zero exit status")
+				);
+			},
+		);
+	});
+}
+
+#[test]
+fn abort() {
+	with_squash_chain(|repository, lines| {
+		let lines: Vec<&str> = lines.iter().map(String::as_str).collect();
+		module_test(lines.as_slice(), &[Event::from('q')], |mut test_context| {
+			let mut module = SquashMessage::new(&create_config(), repository);
+			let _ = test_context.activate(&mut module, State::List);
+			assert_process_result!(
+				test_context.handle_event(&mut module),
+				event = Event::from(MetaEvent::Abort),
+				state = State::List
+			);
+		});
+	});
+}
@@ -3,7 +3,10 @@ use lazy_static::lazy_static;
 use todo_file::TodoFile;
 use view::{RenderContext, ViewData, ViewLine, ViewSender};
 
-use crate::module::{Module, ProcessResult, State};
+use crate::{
+	i18n::translate,
+	module::{Module, ProcessResult, State},
+};
 
 const HEIGHT_ERROR_MESSAGE: &str = "Window too small, increase height to continue";
 const SHORT_ERROR_MESSAGE: &str = "Window too small";
@@ -26,21 +29,17 @@ impl Module for WindowSizeError {
 
 	fn build_view_data(&mut self, context: &RenderContext, _: &TodoFile) -> &ViewData {
 		let view_width = context.width();
-		let message = if !context.is_minimum_view_width() {
-			if view_width >= SHORT_ERROR_MESSAGE.len() {
-				SHORT_ERROR_MESSAGE
-			}
-			else {
-				// not much to do if the window gets too narrow
-				SIZE_ERROR_MESSAGE
-			}
+		let height_error_message = translate(HEIGHT_ERROR_MESSAGE);
+		let short_error_message = translate(SHORT_ERROR_MESSAGE);
+		let message = if context.is_minimum_view_width() && view_width >= height_error_message.chars().count() {
+			height_error_message
 		}
-		else if view_width >= HEIGHT_ERROR_MESSAGE.len() {
-			HEIGHT_ERROR_MESSAGE
+		else if view_width >= short_error_message.chars().count() {
+			short_error_message
 		}
 		else {
-			// this message will always be safe here
-			SHORT_ERROR_MESSAGE
+			// not much to do if the window gets too narrow
+			translate(SIZE_ERROR_MESSAGE)
 		};
 
 		self.view_data.update_view_data(|updater| {
@@ -82,16 +81,12 @@ mod tests {
 	use super::*;
 	use crate::{assert_process_result, testutil::module_test};
 
-	const MINIMUM_WINDOW_HEIGHT: usize = 5;
+	const MINIMUM_WINDOW_HEIGHT: usize = 2;
+	const MINIMUM_WINDOW_WIDTH: usize = 10;
 	const MINIMUM_WINDOW_HEIGHT_ERROR_WIDTH: usize = 45;
 
 	#[rstest]
-	#[case::width_too_small_long_message(
-		SHORT_ERROR_MESSAGE.len(),
-		MINIMUM_WINDOW_HEIGHT + 1,
-		"Window too small"
-	)]
-	#[case::width_too_small_short_message(SHORT_ERROR_MESSAGE.len() - 1, MINIMUM_WINDOW_HEIGHT + 1, "Size!")]
+	#[case::width_too_small(MINIMUM_WINDOW_WIDTH, MINIMUM_WINDOW_HEIGHT + 1, "Size!")]
 	#[case::height_too_small_long_message(
 		MINIMUM_WINDOW_HEIGHT_ERROR_WIDTH,
 		MINIMUM_WINDOW_HEIGHT,
@@ -102,6 +97,7 @@ mod tests {
 		MINIMUM_WINDOW_HEIGHT,
 		"Window too small"
 	)]
+	#[case::height_too_small_narrow(SHORT_ERROR_MESSAGE.len() - 1, MINIMUM_WINDOW_HEIGHT, "Size!")]
 	#[allow(clippy::cast_possible_wrap)]
 	fn build_view_data(#[case] width: usize, #[case] height: usize, #[case] expected: &str) {
 		module_test(&[], &[], |mut test_context| {
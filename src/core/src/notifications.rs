@@ -0,0 +1,115 @@
+use std::{
+	collections::VecDeque,
+	time::{Duration, Instant},
+};
+
+// how long a notification is shown over the view, before it is only found in the history
+const VISIBLE_DURATION: Duration = Duration::from_secs(3);
+
+// the oldest notifications are dropped from the history past this number of notifications
+const HISTORY_LIMIT: usize = 50;
+
+#[derive(Debug)]
+struct Notification {
+	message: String,
+	time: Instant,
+}
+
+/// The short messages about minor events, like the changes made by an action, that are shown for a few seconds over
+/// the bottom of the view, without interrupting the module, and are kept in a history that can be shown later.
+#[derive(Debug)]
+pub(crate) struct Notifications {
+	history: VecDeque<Notification>,
+}
+
+impl Notifications {
+	pub(crate) const fn new() -> Self {
+		Self {
+			history: VecDeque::new(),
+		}
+	}
+
+	pub(crate) fn push(&mut self, message: &str, now: Instant) {
+		if self.history.len() == HISTORY_LIMIT {
+			let _oldest = self.history.pop_front();
+		}
+		self.history.push_back(Notification {
+			message: String::from(message),
+			time: now,
+		});
+	}
+
+	/// The newest notification, while it is shown. A newer notification replaces the notification that is shown.
+	pub(crate) fn visible(&self, now: Instant) -> Option<&str> {
+		self.history
+			.back()
+			.filter(|notification| now.saturating_duration_since(notification.time) < VISIBLE_DURATION)
+			.map(|notification| notification.message.as_str())
+	}
+
+	/// The messages of the notifications, from the newest to the oldest.
+	#[cfg_attr(not(feature = "notification-history"), allow(dead_code))]
+	pub(crate) fn history(&self) -> impl Iterator<Item = &str> {
+		self.history.iter().rev().map(|notification| notification.message.as_str())
+	}
+
+	#[cfg_attr(not(feature = "notification-history"), allow(dead_code))]
+	pub(crate) fn is_empty(&self) -> bool {
+		self.history.is_empty()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn visible_newest() {
+		let now = Instant::now();
+		let mut notifications = Notifications::new();
+		notifications.push("First", now);
+		notifications.push("Second", now);
+		assert_eq!(notifications.visible(now), Some("Second"));
+	}
+
+	#[test]
+	fn visible_expired() {
+		let now = Instant::now();
+		let mut notifications = Notifications::new();
+		notifications.push("First", now);
+		assert_eq!(notifications.visible(now + VISIBLE_DURATION), None);
+	}
+
+	#[test]
+	fn visible_empty() {
+		assert_eq!(Notifications::new().visible(Instant::now()), None);
+	}
+
+	#[test]
+	fn history_newest_first() {
+		let now = Instant::now();
+		let mut notifications = Notifications::new();
+		notifications.push("First", now);
+		notifications.push("Second", now);
+		assert_eq!(notifications.history().collect::<Vec<&str>>(), vec!["Second", "First"]);
+	}
+
+	#[test]
+	fn history_limit() {
+		let now = Instant::now();
+		let mut notifications = Notifications::new();
+		for index in 0..=HISTORY_LIMIT {
+			notifications.push(index.to_string().as_str(), now);
+		}
+		assert_eq!(notifications.history().count(), HISTORY_LIMIT);
+		assert_eq!(notifications.history().last(), Some("1"));
+	}
+
+	#[test]
+	fn is_empty() {
+		let mut notifications = Notifications::new();
+		assert!(notifications.is_empty());
+		notifications.push("First", Instant::now());
+		assert!(!notifications.is_empty());
+	}
+}
@@ -0,0 +1,118 @@
+use std::{
+	env,
+	ffi::OsString,
+	path::{Path, PathBuf},
+};
+
+use anyhow::{anyhow, Result};
+
+// the executable extensions of Windows, when `PATHEXT` is not set
+const DEFAULT_PATH_EXTENSIONS: &str = ".COM;.EXE;.BAT;.CMD";
+
+#[cfg(unix)]
+fn is_executable(path: &Path) -> bool {
+	use std::os::unix::fs::PermissionsExt;
+	path.metadata()
+		.map_or(false, |metadata| metadata.is_file() && metadata.permissions().mode() & 0o111 != 0)
+}
+
+#[cfg(not(unix))]
+fn is_executable(path: &Path) -> bool {
+	path.is_file()
+}
+
+// the paths that a command is run from, in the order that they are searched, with the extensions of Windows added to
+// a command without an extension
+fn candidates(path: &Path) -> Vec<PathBuf> {
+	if !cfg!(windows) || path.extension().is_some() {
+		return vec![path.to_path_buf()];
+	}
+	let extensions = env::var("PATHEXT").unwrap_or_else(|_| String::from(DEFAULT_PATH_EXTENSIONS));
+	extensions
+		.split(';')
+		.filter(|extension| !extension.is_empty())
+		.map(|extension| {
+			let mut candidate = OsString::from(path.as_os_str());
+			candidate.push(extension);
+			PathBuf::from(candidate)
+		})
+		.collect()
+}
+
+/// Find the path of the executable of a command, so that a command that cannot be run is reported before the
+/// interface is stopped for the command. A command with a directory is relative to the current directory, and a
+/// command without a directory is searched for in the directories of `PATH`.
+pub(super) fn find_command(command: &str) -> Result<PathBuf> {
+	let path = Path::new(command);
+	let found = if path.components().count() > 1 {
+		if !path.exists() {
+			return Err(anyhow!("\"{}\" does not exist", command));
+		}
+		candidates(path).into_iter().find(|candidate| is_executable(candidate))
+	}
+	else {
+		env::var_os("PATH").and_then(|paths| {
+			env::split_paths(&paths)
+				.flat_map(|directory| candidates(directory.join(path).as_path()))
+				.find(|candidate| is_executable(candidate))
+		})
+	};
+	match found {
+		Some(path) => Ok(path),
+		None if path.components().count() > 1 => Err(anyhow!("\"{}\" is not executable", command)),
+		None => Err(anyhow!("\"{}\" was not found in PATH", command)),
+	}
+}
+
+#[cfg(all(unix, test))]
+mod tests {
+	use std::fs::{set_permissions, write, Permissions};
+
+	use super::*;
+
+	#[test]
+	fn find_command_in_path() {
+		assert!(find_command("true").unwrap().ends_with("true"));
+	}
+
+	#[test]
+	fn find_command_not_in_path() {
+		assert_eq!(
+			find_command("girt-command-that-does-not-exist").unwrap_err().to_string(),
+			"\"girt-command-that-does-not-exist\" was not found in PATH"
+		);
+	}
+
+	#[test]
+	fn find_command_with_directory() {
+		use std::os::unix::fs::PermissionsExt;
+		let directory = tempfile::tempdir().unwrap();
+		let command = directory.path().join("editor");
+		write(command.as_path(), "#!/bin/sh\n").unwrap();
+		set_permissions(command.as_path(), Permissions::from_mode(0o755)).unwrap();
+		assert_eq!(find_command(command.to_str().unwrap()).unwrap(), command);
+	}
+
+	#[test]
+	fn find_command_with_directory_not_executable() {
+		let directory = tempfile::tempdir().unwrap();
+		let command = directory.path().join("editor");
+		write(command.as_path(), "#!/bin/sh\n").unwrap();
+		let command = command.to_str().unwrap();
+		assert_eq!(
+			find_command(command).unwrap_err().to_string(),
+			format!("\"{}\" is not executable", command)
+		);
+	}
+
+	#[test]
+	fn find_command_with_directory_does_not_exist() {
+		let directory = tempfile::tempdir().unwrap();
+		let command = directory.path().join("editor");
+		let command = command.to_str().unwrap();
+		assert_eq!(
+			find_command(command).unwrap_err().to_string(),
+			format!("\"{}\" does not exist", command)
+		);
+	}
+}
@@ -1,7 +1,8 @@
+mod find_command;
 #[cfg(test)]
 mod tests;
 
-use std::{process::Command, thread};
+use std::{cell::RefCell, process::Command, rc::Rc, thread, time::Instant};
 
 use anyhow::{anyhow, Result};
 use display::Tui;
@@ -9,15 +10,29 @@ use input::{Event, EventHandler, MetaEvent};
 use todo_file::TodoFile;
 use view::{spawn_view_thread, RenderContext, View, ViewSender};
 
-use crate::module::{ExitStatus, Modules, ProcessResult, State};
+use self::find_command::find_command;
+use crate::{
+	event_bus::{BusEvent, EventBus},
+	i18n::translate,
+	module::{ExitStatus, Modules, ProcessResult, State},
+	notifications::Notifications,
+	signals::Signals,
+	timers::Timers,
+};
 
 pub(crate) struct Process {
+	event_bus: EventBus,
 	event_handler: EventHandler,
 	exit_status: Option<ExitStatus>,
+	first_frame_time: Option<Instant>,
+	notifications: Rc<RefCell<Notifications>>,
+	read_only: bool,
 	rebase_todo: TodoFile,
 	render_context: RenderContext,
+	signals: Signals,
 	state: State,
 	threads: Vec<thread::JoinHandle<()>>,
+	timers: Timers,
 	view_sender: ViewSender,
 }
 
@@ -35,16 +50,55 @@ impl Process {
 		threads.push(view_thread);
 
 		Self {
+			event_bus: EventBus::new(),
 			event_handler,
 			exit_status: None,
+			first_frame_time: None,
+			notifications: Rc::new(RefCell::new(Notifications::new())),
+			read_only: false,
 			rebase_todo,
 			render_context: RenderContext::new(view_size.width() as u16, view_size.height() as u16),
+			signals: Signals::new(),
 			state: State::List,
 			threads,
+			timers: Timers::new(),
 			view_sender,
 		}
 	}
 
+	pub(crate) const fn rebase_todo(&self) -> &TodoFile {
+		&self.rebase_todo
+	}
+
+	/// The time that the first frame was sent to the view, if the view was rendered.
+	pub(crate) const fn first_frame_time(&self) -> Option<Instant> {
+		self.first_frame_time
+	}
+
+	pub(crate) fn set_initial_state(&mut self, state: State) {
+		self.state = state;
+	}
+
+	/// Exit without writing the todo file, for a todo list that is only browsed.
+	pub(crate) fn set_read_only(&mut self) {
+		self.read_only = true;
+	}
+
+	/// Dispatch the events sent to the bus, by the work that is done in the background, to the modules.
+	pub(crate) fn set_event_bus(&mut self, event_bus: EventBus) {
+		self.event_bus = event_bus;
+	}
+
+	/// Share the notifications with the modules that show them.
+	pub(crate) fn set_notifications(&mut self, notifications: Rc<RefCell<Notifications>>) {
+		self.notifications = notifications;
+	}
+
+	/// Stop for the termination signals, restoring the terminal and exiting without writing the todo file.
+	pub(crate) fn set_signals(&mut self, signals: Signals) {
+		self.signals = signals;
+	}
+
 	pub(crate) fn run(&mut self, mut modules: Modules<'_>) -> Result<ExitStatus> {
 		if self.view_sender.start().is_err() {
 			self.exit_status = Some(ExitStatus::StateError);
@@ -60,16 +114,35 @@ impl Process {
 		);
 		self.activate(&mut modules, State::List);
 		while self.exit_status.is_none() {
+			let start = Instant::now();
+			let notification_visible = self.update_notification(start);
 			let view_data = modules.build_view_data(self.state, &self.render_context, &self.rebase_todo);
-			if self.view_sender.render(view_data).is_err() {
+			let render_result = self.view_sender.render(view_data);
+			log::trace!(target: "render", "Built {:?} view in {}us", self.state, start.elapsed().as_micros());
+			if render_result.is_err() {
 				self.exit_status = Some(ExitStatus::StateError);
 				continue;
 			}
+			if self.first_frame_time.is_none() {
+				self.first_frame_time = Some(Instant::now());
+			}
 			loop {
 				if self.view_sender.is_poisoned() {
 					self.exit_status = Some(ExitStatus::StateError);
 					break;
 				}
+				if let Some(signal) = self.signals.received() {
+					log::warn!(target: "process", "Stopping for signal {}", signal);
+					self.exit_status = Some(ExitStatus::Signal(signal));
+					break;
+				}
+				// the view is rendered again to hide a notification that is no longer shown
+				if notification_visible && self.notifications.borrow().visible(Instant::now()).is_none() {
+					break;
+				}
+				if self.dispatch_bus_events(&mut modules) {
+					break;
+				}
 				let result = modules.handle_input(
 					self.state,
 					&self.event_handler,
@@ -79,6 +152,7 @@ impl Process {
 
 				if let Some(event) = result.event {
 					if event != Event::None {
+						log::debug!(target: "input", "{:?}", event);
 						self.handle_process_result(&mut modules, &result);
 						break;
 					}
@@ -88,8 +162,9 @@ impl Process {
 		if self.view_sender.stop().is_err() {
 			return Ok(ExitStatus::StateError);
 		}
+		log::info!(target: "process", "Exiting with {:?}", self.exit_status);
 		if let Some(status) = self.exit_status {
-			if status != ExitStatus::Kill {
+			if status.writes_todo_file() && !self.read_only {
 				self.rebase_todo.write_file()?;
 			}
 		}
@@ -120,13 +195,40 @@ impl Process {
 			self.exit_status = Some(exit_status);
 		}
 
+		if let Some(ref notification) = result.notification {
+			log::info!(target: "notification", "{}", notification);
+			self.notifications.borrow_mut().push(notification.as_str(), Instant::now());
+		}
+
+		if let Some(timer) = result.timer {
+			log::debug!(target: "timer", "{:?}", timer);
+			self.timers.request(timer, Instant::now());
+		}
+
+		if let Some(ref config) = result.config {
+			modules.update_config(config);
+			if self.view_sender.update_theme(&config.theme).is_err() {
+				self.exit_status = Some(ExitStatus::StateError);
+			}
+		}
+
 		if let Some(ref error) = result.error {
+			log::error!(target: "state", "{:#}", error);
 			self.state = State::Error;
 			modules.error(self.state, error);
 			self.activate(modules, result.state.unwrap_or(previous_state));
 		}
 		else if let Some(new_state) = result.state {
-			if new_state != self.state {
+			// the optional modules are left out of some builds, and the state is kept when the module for the new state
+			// is not in the build
+			if new_state != self.state && !modules.is_registered(new_state) {
+				log::warn!(target: "state", "{:?} is not included in this build", new_state);
+				self.notifications
+					.borrow_mut()
+					.push(translate("This feature is not included in this build"), Instant::now());
+			}
+			else if new_state != self.state {
+				log::info!(target: "state", "{:?} -> {:?}", self.state, new_state);
 				modules.deactivate(self.state);
 				self.state = new_state;
 				self.activate(modules, previous_state);
@@ -167,8 +269,12 @@ impl Process {
 	}
 
 	fn run_command(&mut self, external_command: &(String, Vec<String>)) -> Result<MetaEvent> {
+		// the command is found before the view is stopped, so that the interface is not suspended for a command that
+		// cannot be run
+		let _ = find_command(external_command.0.as_str())?;
 		self.view_sender.stop()?;
 
+		log::info!(target: "command", "Running {} {}", external_command.0, external_command.1.join(" "));
 		let mut cmd = Command::new(external_command.0.clone());
 		let _ = cmd.args(external_command.1.clone());
 
@@ -183,12 +289,37 @@ impl Process {
 				}
 			})
 			.map_err(|err| anyhow!(err));
+		log::info!(target: "command", "Finished with {:?}", result);
 
 		self.view_sender.start()?;
 
 		result
 	}
 
+	// the events of the background work, and the ticks of the timers that are due, are dispatched to the modules that
+	// subscribe to them, and the view is rendered again when a module was changed by an event
+	fn dispatch_bus_events(&mut self, modules: &mut Modules<'_>) -> bool {
+		let mut changed = false;
+		let mut events = self.event_bus.drain();
+		events.extend(self.timers.due(Instant::now()).into_iter().map(BusEvent::Tick));
+		for event in events {
+			log::debug!(target: "event", "{:?}", event);
+			for result in modules.dispatch(&event, &self.rebase_todo) {
+				changed |= result.event.map_or(false, |event| event != Event::None);
+				self.handle_process_result(modules, &result);
+			}
+		}
+		changed
+	}
+
+	// returns if a notification is shown
+	fn update_notification(&self, now: Instant) -> bool {
+		let notifications = self.notifications.borrow();
+		let notification = notifications.visible(now);
+		self.view_sender.set_notification(notification);
+		notification.is_some()
+	}
+
 	fn activate(&mut self, modules: &mut Modules<'_>, previous_state: State) {
 		let result = modules.activate(self.state, &self.rebase_todo, previous_state);
 		// always trigger a resize on activate, for modules that track size
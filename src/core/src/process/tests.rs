@@ -1,4 +1,4 @@
-use std::{path::Path, sync::atomic::Ordering};
+use std::{path::Path, sync::atomic::Ordering, time::Duration};
 
 use anyhow::anyhow;
 use config::Theme;
@@ -8,8 +8,10 @@ use view::{assert_rendered_output, render_line, ViewData};
 
 use super::*;
 use crate::{
+	event_bus::{BusEvent, Topic},
 	module::Module,
 	modules::{Error, WindowSizeError},
+	timers::Timer,
 	testutil::module_test,
 };
 
@@ -45,6 +47,28 @@ impl Module for TestModule {
 	}
 }
 
+struct BusTestModule {
+	view_data: ViewData,
+}
+
+impl Module for BusTestModule {
+	fn subscriptions(&self) -> &'static [Topic] {
+		&[Topic::Timer, Topic::TodoFile]
+	}
+
+	fn build_view_data(&mut self, _render_context: &RenderContext, _rebase_todo: &TodoFile) -> &ViewData {
+		&self.view_data
+	}
+
+	fn handle_events(&mut self, _: &EventHandler, _: &ViewSender, _: &mut TodoFile) -> ProcessResult {
+		ProcessResult::new()
+	}
+
+	fn handle_bus_event(&mut self, _: &BusEvent, _: &TodoFile) -> ProcessResult {
+		ProcessResult::from(Event::from(MetaEvent::BackgroundLoaded)).notification("Changed")
+	}
+}
+
 fn create_crossterm() -> CrossTerm {
 	let mut crossterm = CrossTerm::new();
 	crossterm.set_size(Size::new(100, 300));
@@ -94,6 +118,25 @@ fn window_too_small_on_start() {
 	});
 }
 
+#[test]
+fn initial_state() {
+	module_test(&["pick aaa comment"], &[], |test_context| {
+		let crossterm = create_crossterm();
+		let display = Display::new(crossterm, &Theme::new());
+		let view = View::new(display, "~", "?");
+		let mut process = Process::new(
+			test_context.rebase_todo_file,
+			test_context.event_handler_context.event_handler,
+			view,
+		);
+		let mut modules = create_modules();
+		modules.register_module(State::Insert, TestModule::new());
+		process.set_initial_state(State::Insert);
+		assert_eq!(process.run(modules).unwrap(), ExitStatus::Kill);
+		assert_eq!(process.state, State::Insert);
+	});
+}
+
 #[test]
 fn render_error() {
 	module_test(&["pick aaa comment"], &[Event::from(MetaEvent::Exit)], |test_context| {
@@ -212,6 +255,31 @@ fn handle_exit_event_that_is_kill() {
 	});
 }
 
+#[test]
+fn signal_received() {
+	module_test(&["pick aaa comment"], &[], |mut test_context| {
+		test_context.rebase_todo_file.write_file().unwrap();
+		test_context.rebase_todo_file.set_lines(vec![]);
+		let mut shadow_rebase_file = test_context.new_todo_file();
+		let crossterm = create_crossterm();
+		let display = Display::new(crossterm, &Theme::new());
+		let view = View::new(display, "~", "?");
+		let mut process = Process::new(
+			test_context.rebase_todo_file,
+			test_context.event_handler_context.event_handler,
+			view,
+		);
+		let mut modules = create_modules();
+		modules.register_module(State::List, TestModule::new());
+		let signals = Signals::new();
+		signals.send(15);
+		process.set_signals(signals);
+		assert_eq!(process.run(modules).unwrap(), ExitStatus::Signal(15));
+		shadow_rebase_file.load_file().unwrap();
+		assert!(!shadow_rebase_file.is_empty());
+	});
+}
+
 #[test]
 fn handle_process_result_error() {
 	module_test(&["pick aaa comment"], &[], |test_context| {
@@ -304,6 +372,138 @@ fn handle_process_result_kill_event() {
 	});
 }
 
+#[test]
+fn handle_process_result_config() {
+	module_test(&["pick aaa comment"], &[], |test_context| {
+		let crossterm = create_crossterm();
+		let display = Display::new(crossterm, &Theme::new());
+		let view = View::new(display, "~", "?");
+		let mut modules = create_modules();
+		let mut process = Process::new(
+			test_context.rebase_todo_file,
+			test_context.event_handler_context.event_handler,
+			view,
+		);
+		let result = ProcessResult::new().config(config::Config::new());
+		process.handle_process_result(&mut modules, &result);
+		assert_eq!(process.exit_status, None);
+	});
+}
+
+#[test]
+fn handle_process_result_notification() {
+	module_test(&["pick aaa comment"], &[], |test_context| {
+		let crossterm = create_crossterm();
+		let display = Display::new(crossterm, &Theme::new());
+		let view = View::new(display, "~", "?");
+		let mut modules = create_modules();
+		let mut process = Process::new(
+			test_context.rebase_todo_file,
+			test_context.event_handler_context.event_handler,
+			view,
+		);
+		let notifications = Rc::new(RefCell::new(Notifications::new()));
+		process.set_notifications(Rc::clone(&notifications));
+		let result = ProcessResult::new().notification("Message");
+		process.handle_process_result(&mut modules, &result);
+		assert_eq!(notifications.borrow().visible(Instant::now()), Some("Message"));
+		assert!(process.update_notification(Instant::now()));
+	});
+}
+
+#[test]
+fn handle_process_result_state_without_module() {
+	module_test(&["pick aaa comment"], &[], |test_context| {
+		let crossterm = create_crossterm();
+		let display = Display::new(crossterm, &Theme::new());
+		let view = View::new(display, "~", "?");
+		let mut modules = create_modules();
+		let mut process = Process::new(
+			test_context.rebase_todo_file,
+			test_context.event_handler_context.event_handler,
+			view,
+		);
+		let notifications = Rc::new(RefCell::new(Notifications::new()));
+		process.set_notifications(Rc::clone(&notifications));
+		let result = ProcessResult::new().state(State::Settings);
+		process.handle_process_result(&mut modules, &result);
+		assert_eq!(process.state, State::List);
+		assert_eq!(
+			notifications.borrow().visible(Instant::now()),
+			Some("This feature is not included in this build")
+		);
+	});
+}
+
+#[test]
+fn dispatch_bus_events_to_subscribed_module() {
+	module_test(&["pick aaa comment"], &[], |test_context| {
+		let crossterm = create_crossterm();
+		let display = Display::new(crossterm, &Theme::new());
+		let view = View::new(display, "~", "?");
+		let mut modules = create_modules();
+		modules.register_module(State::List, BusTestModule {
+			view_data: ViewData::new(|_| {}),
+		});
+		let mut process = Process::new(
+			test_context.rebase_todo_file,
+			test_context.event_handler_context.event_handler,
+			view,
+		);
+		let notifications = Rc::new(RefCell::new(Notifications::new()));
+		process.set_notifications(Rc::clone(&notifications));
+		let event_bus = EventBus::new();
+		event_bus.sender().send(BusEvent::TodoFileChanged);
+		process.set_event_bus(event_bus);
+		assert!(process.dispatch_bus_events(&mut modules));
+		assert_eq!(notifications.borrow().visible(Instant::now()), Some("Changed"));
+		assert!(!process.dispatch_bus_events(&mut modules));
+	});
+}
+
+#[test]
+fn dispatch_bus_events_without_subscribed_module() {
+	module_test(&["pick aaa comment"], &[], |test_context| {
+		let crossterm = create_crossterm();
+		let display = Display::new(crossterm, &Theme::new());
+		let view = View::new(display, "~", "?");
+		let mut modules = create_modules();
+		let mut process = Process::new(
+			test_context.rebase_todo_file,
+			test_context.event_handler_context.event_handler,
+			view,
+		);
+		let event_bus = EventBus::new();
+		event_bus.sender().send(BusEvent::DetailsLoaded);
+		process.set_event_bus(event_bus);
+		assert!(!process.dispatch_bus_events(&mut modules));
+	});
+}
+
+#[test]
+fn dispatch_timer_tick() {
+	module_test(&["pick aaa comment"], &[], |test_context| {
+		let crossterm = create_crossterm();
+		let display = Display::new(crossterm, &Theme::new());
+		let view = View::new(display, "~", "?");
+		let mut modules = create_modules();
+		modules.register_module(State::List, BusTestModule {
+			view_data: ViewData::new(|_| {}),
+		});
+		let mut process = Process::new(
+			test_context.rebase_todo_file,
+			test_context.event_handler_context.event_handler,
+			view,
+		);
+		let start_result = ProcessResult::new().start_timer(Timer::RelativeDates, Duration::ZERO);
+		process.handle_process_result(&mut modules, &start_result);
+		assert!(process.dispatch_bus_events(&mut modules));
+		let stop_result = ProcessResult::new().stop_timer(Timer::RelativeDates);
+		process.handle_process_result(&mut modules, &stop_result);
+		assert!(!process.dispatch_bus_events(&mut modules));
+	});
+}
+
 #[test]
 fn handle_process_result_resize_event_not_too_small() {
 	module_test(&["pick aaa comment"], &[], |test_context| {
@@ -390,16 +590,22 @@ fn handle_process_result_external_command_not_executable() {
 		assert_rendered_output!(
 			view_data,
 			"{TITLE}",
+			"{LEADING}",
+			format!("{{IndicatorColor}}Unable to run {}", command),
 			"{BODY}",
-			format!("{{Normal}}Unable to run {}", command),
+			"{Normal,Dimmed}Caused by:",
 			if cfg!(windows) {
-				render_line!(StartsWith "{Normal}%1 is not a valid Win32 application.")
+				String::from(render_line!(StartsWith "{Normal}  %1 is not a valid Win32 application."))
 			}
 			else {
-				render_line!(StartsWith "{Normal}Permission denied")
+				format!("{{Normal}}  \"{}\" is not executable", command)
 			},
+			"",
+			"{Normal,Dimmed}Suggestions:",
+			"{Normal}  Start the tool with --log-file and a path, to write the details of the error to a log",
+			"{Normal}  Start the tool with --diagnostics, to show the configuration and the details of the repository",
 			"{TRAILING}",
-			"{IndicatorColor}Press any key to continue"
+			"{IndicatorColor}Up and down to scroll, c to copy the error, any other key to continue"
 		);
 	});
 }
@@ -430,16 +636,17 @@ fn handle_process_result_external_command_executable_not_found() {
 		assert_rendered_output!(
 			view_data,
 			"{TITLE}",
+			"{LEADING}",
+			format!("{{IndicatorColor}}Unable to run {}", command),
 			"{BODY}",
-			format!("{{Normal}}Unable to run {}", command),
-			if cfg!(windows) {
-				render_line!(StartsWith "{Normal}The system cannot find the path specified.")
-			}
-			else {
-				render_line!(StartsWith "{Normal}No such file or directory")
-			},
+			"{Normal,Dimmed}Caused by:",
+			format!("{{Normal}}  \"{}\" does not exist", command),
+			"",
+			"{Normal,Dimmed}Suggestions:",
+			"{Normal}  Start the tool with --log-file and a path, to write the details of the error to a log",
+			"{Normal}  Start the tool with --diagnostics, to show the configuration and the details of the repository",
 			"{TRAILING}",
-			"{IndicatorColor}Press any key to continue"
+			"{IndicatorColor}Up and down to scroll, c to copy the error, any other key to continue"
 		);
 	});
 }
@@ -0,0 +1,141 @@
+use git::{RangeDiff, RangeDiffStatus, Repository};
+use todo_file::TodoFile;
+
+use crate::{
+	exit::Exit,
+	module::ExitStatus,
+};
+
+// the length of the abbreviated hashes of the report, the default abbreviation of Git
+const HASH_LENGTH: usize = 7;
+
+fn abbreviate(hash: &str) -> &str {
+	hash.get(..HASH_LENGTH).unwrap_or(hash)
+}
+
+const fn marker(status: RangeDiffStatus) -> char {
+	match status {
+		RangeDiffStatus::Same => '=',
+		RangeDiffStatus::Modified => '!',
+		RangeDiffStatus::New => '+',
+		RangeDiffStatus::Upstream => '>',
+	}
+}
+
+/// Create a report of the comparison of the commits of the todo list to the commits of the upstream branch, with a
+/// line for each commit, in the format of `git range-diff`.
+pub(crate) fn build_report(range_diff: &RangeDiff, upstream: &str) -> String {
+	let missing_hash = "-".repeat(HASH_LENGTH);
+	let mut report = vec![];
+	let mut number = 0;
+	for entry in range_diff.entries() {
+		let (number_column, hash, upstream_hash) = if entry.status() == RangeDiffStatus::Upstream {
+			(String::from("-"), missing_hash.as_str(), abbreviate(entry.hash()))
+		}
+		else {
+			number += 1;
+			(
+				number.to_string(),
+				abbreviate(entry.hash()),
+				entry.upstream_hash().map_or(missing_hash.as_str(), abbreviate),
+			)
+		};
+		report.push(format!(
+			"{:>3}: {} {} {} {}",
+			number_column,
+			hash,
+			marker(entry.status()),
+			upstream_hash,
+			entry.summary()
+		));
+	}
+	report.push(String::new());
+	report.push(format!(
+		"= the same changes as {upstream}, ! the same summary with other changes, + not in {upstream}, > only in \
+		 {upstream}",
+		upstream = upstream
+	));
+	report.join("\n")
+}
+
+/// Compare the commits of the todo list to the commits of the upstream branch, and exit with the report.
+pub(crate) fn run(todo_file: &TodoFile, repo: &Repository, upstream: &str) -> Exit {
+	let mut hashes: Vec<&str> = vec![];
+	for line in todo_file.lines_iter().filter(|line| line.has_reference()) {
+		if !hashes.contains(&line.get_hash()) {
+			hashes.push(line.get_hash());
+		}
+	}
+	match repo.range_diff(hashes.as_slice(), upstream) {
+		Ok(range_diff) => {
+			log::info!(target: "git", "Compared {} commits to {}", hashes.len(), upstream);
+			Exit::from(build_report(&range_diff, upstream))
+		},
+		Err(err) => {
+			Exit::new(
+				ExitStatus::StateError,
+				format!("Unable to compare the commits to {}: {:#}", upstream, err).as_str(),
+			)
+		},
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use git::testutil::{with_temp_repository, RepoBuilder};
+	use todo_file::Line;
+
+	use super::*;
+
+	#[test]
+	fn report() {
+		RepoBuilder::new()
+			.commit("base")
+			.branch("feature")
+			.commit_files("one", &[("one.txt", "one\n")])
+			.commit_files("two", &[("two.txt", "two\n")])
+			.commit("three")
+			.checkout("main")
+			.commit_files("upstream one", &[("one.txt", "one\n")])
+			.commit_files("two\n\nreworked", &[("two.txt", "reworked\n")])
+			.commit("upstream only")
+			.with_repository(|repository, commits| {
+				let hashes = [commits.hash("one"), commits.hash("two"), commits.hash("three")];
+				let range_diff = repository
+					.range_diff(&[hashes[0].as_str(), hashes[1].as_str(), hashes[2].as_str()], "main")
+					.unwrap();
+				let short = |message: &str| String::from(&commits.hash(message)[..7]);
+				assert_eq!(
+					build_report(&range_diff, "main"),
+					format!(
+						"  1: {} = {} one\n  2: {} ! {} two\n  3: {} + ------- three\n  -: ------- > {} upstream \
+						 only\n\n= the same changes as main, ! the same summary with other changes, + not in main, > \
+						 only in main",
+						short("one"),
+						short("upstream one"),
+						short("two"),
+						short("two\n\nreworked"),
+						short("three"),
+						short("upstream only"),
+					)
+				);
+				Ok(())
+			});
+	}
+
+	#[test]
+	fn run_unknown_upstream() {
+		with_temp_repository(|repository| {
+			let mut todo_file = TodoFile::new("git-rebase-todo", 1, "#");
+			todo_file.set_lines(vec![Line::new("exec make").unwrap()]);
+			assert_eq!(
+				run(&todo_file, &repository, "unknown"),
+				Exit::new(
+					ExitStatus::StateError,
+					"Unable to compare the commits to unknown: Could not load commit unknown: revspec 'unknown' not found"
+				)
+			);
+			Ok(())
+		});
+	}
+}
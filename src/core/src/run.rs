@@ -1,30 +1,94 @@
+use std::{
+	cell::{Cell, RefCell},
+	env::var,
+	fs::{metadata, read_to_string, File},
+	panic::{catch_unwind, AssertUnwindSafe},
+	path::Path,
+	rc::Rc,
+	time::Duration,
+};
+
+use anyhow::anyhow;
 use config::Config;
 #[cfg(test)]
 use display::testutil::CrossTerm;
 #[cfg(not(test))]
 use display::CrossTerm;
 use display::{Display, Tui};
-use git::Repository;
-use input::{EventHandler, KeyBindings};
+use git::{GitBackend, Repository};
+use input::{parse_recording, record_events, replay_events, EventHandler, KeyBindings};
+use log::Level;
 use todo_file::TodoFile;
 use view::View;
 
 use crate::{
-	arguments::Args,
+	arguments::{Args, Mode},
+	crash,
+	event_bus::EventBus,
 	exit::Exit,
+	git_backend::open_alternate_backend,
 	help::build_help,
-	module::{ExitStatus, Modules, State},
-	modules::{ConfirmAbort, ConfirmRebase, Error, ExternalEditor, Insert, List, ShowCommit, WindowSizeError},
+	i18n,
+	icons::are_icons_supported,
+	key_binding_conflicts::find_key_binding_conflicts,
+	logger,
+	module::{ExitStatus, ModuleContext, Modules, State},
+	modules::{
+		is_setup_required,
+		register_optional_modules,
+		select_editor,
+		AutosquashPreview,
+		ConfirmAbort,
+		ConfirmQuit,
+		ConfirmRebase,
+		ConfirmRebaseCommand,
+		DetailsLoader,
+		FileWatcher,
+		Diagnostics,
+		Error,
+		ExternalEditor,
+		Insert,
+		List,
+		RebaseCommand,
+		ReviewChanges,
+		SetupWizard,
+		ShowCommit,
+		SquashMessage,
+		WindowSizeError,
+	},
+	notifications::Notifications,
 	process::Process,
+	range_diff,
+	script,
+	shell_script,
+	startup_profile::StartupProfile,
+	tasks::TaskManager,
+	todo_json,
+	ui_state::UiState,
+	version::build_version,
 };
+#[cfg(feature = "tutorial")]
+use crate::tutorial::Tutorial;
+
+// a task that does not stop on its cancellation, such as one that waits on Git, is left behind on exit after this time
+const TASK_SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(1);
 
-pub(super) fn load_config(repo: &Repository) -> Result<Config, Exit> {
-	Config::try_from(repo).map_err(|err| Exit::new(ExitStatus::ConfigError, format!("{:#}", err).as_str()))
+pub(super) fn load_config(repo: &Repository, overrides: &[String]) -> Result<Config, Exit> {
+	let mut config = Config::try_from_repository(repo, overrides).map_err(|err| {
+		log::error!(target: "config", "Unable to load configuration: {:#}", err);
+		Exit::new(ExitStatus::ConfigError, format!("{:#}", err).as_str())
+	})?;
+	if config.icons && !are_icons_supported() {
+		log::info!(target: "config", "Icons disabled, the locale or terminal does not appear to support them");
+		config.icons = false;
+	}
+	Ok(config)
 }
 
 pub(super) fn load_todo_file(filepath: &str, config: &Config) -> Result<TodoFile, Exit> {
 	let mut todo_file = TodoFile::new(filepath, config.undo_limit, config.git.comment_char.as_str());
 	if let Err(err) = todo_file.load_file() {
+		log::error!(target: "todo", "Unable to load {}: {}", filepath, err);
 		return Err(Exit::new(ExitStatus::FileReadError, err.to_string().as_str()));
 	}
 
@@ -42,73 +106,347 @@ pub(super) fn load_todo_file(filepath: &str, config: &Config) -> Result<TodoFile
 		));
 	}
 
+	log::info!(target: "todo", "Loaded {} with {} lines", filepath, todo_file.get_max_selected_line_index() + 1);
 	Ok(todo_file)
 }
 
 pub(super) fn run_process(
-	todo_file: TodoFile,
+	mut todo_file: TodoFile,
 	event_handler: EventHandler,
 	config: &Config,
 	repo: &Repository,
+	startup_profile: &mut StartupProfile,
+	mode: &Mode,
+	read_only: bool,
 ) -> Exit {
+	// the messages of the modules are translated when the modules are created
+	i18n::init();
+	// the backend is opened before the modules, since the modules borrow it
+	let alternate_backend = open_alternate_backend(repo);
+	let backend: &dyn GitBackend = alternate_backend.as_deref().unwrap_or(repo);
 	let mut modules = Modules::new();
 	modules.register_module(State::Error, Error::new());
-	modules.register_module(State::List, List::new(config));
-	modules.register_module(State::ShowCommit, ShowCommit::new(config, repo));
+	let notifications = Rc::new(RefCell::new(Notifications::new()));
+	register_optional_modules(&mut modules, &ModuleContext {
+		config,
+		notifications: &notifications,
+		repository: repo,
+	});
+	// the work that is done in the background sends events to the bus, that the process dispatches to the modules
+	let event_bus = EventBus::new();
+	let task_manager = TaskManager::new();
+	let mut list = List::new(config);
+	// the status of the repository and the details of the commits can be slow to load in large repositories, so they
+	// are loaded in the background while the list is shown
+	list.set_details_loader(DetailsLoader::spawn(
+		Path::new(todo_file.get_filepath()),
+		event_bus.sender(),
+		&task_manager,
+	));
+	// the todo file can be changed by a script or another editor while the list is open
+	list.set_file_watcher(
+		FileWatcher::spawn(
+			Path::new(todo_file.get_filepath()),
+			event_bus.sender(),
+			&task_manager,
+		),
+		&todo_file,
+	);
+	for conflict in find_key_binding_conflicts(&config.key_bindings) {
+		list.add_warning(conflict.description().as_str());
+	}
+	if read_only {
+		list.set_read_only();
+	}
+	#[cfg(feature = "tutorial")]
+	if matches!(*mode, Mode::Tutorial) {
+		list.set_tutorial(Tutorial::new(&config.key_bindings));
+	}
+	// the state is shared with the list, that updates it as it changes, and is saved after the list is closed
+	let ui_state_path = UiState::path(repo.git_directory());
+	let ui_state = config.persist_ui_state.then(|| {
+		let ui_state = UiState::load(ui_state_path.as_path());
+		ui_state.restore_selected_line(&mut todo_file);
+		Rc::new(RefCell::new(ui_state))
+	});
+	if let Some(ref ui_state) = ui_state {
+		list.set_ui_state(Rc::clone(ui_state));
+	}
+	modules.register_module(State::List, list);
+	// the memory used by the last loaded diff is shown with the diagnostics
+	let diff_memory_usage = Rc::new(Cell::new(None));
+	let mut show_commit = ShowCommit::new(config, backend);
+	if repo.is_partial_clone() {
+		show_commit.set_partial_clone(repo);
+	}
+	show_commit.set_diff_memory_usage(Rc::clone(&diff_memory_usage));
+	modules.register_module(State::ShowCommit, show_commit);
+	let mut squash_preview = ShowCommit::new(config, backend);
+	squash_preview.set_squash_preview(repo);
+	squash_preview.set_diff_memory_usage(Rc::clone(&diff_memory_usage));
+	modules.register_module(State::SquashPreview, squash_preview);
+	modules.register_module(State::SquashMessage, SquashMessage::new(config, repo));
 	modules.register_module(State::WindowSizeError, WindowSizeError::new());
 	modules.register_module(
 		State::ConfirmAbort,
-		ConfirmAbort::new(&config.key_bindings.confirm_yes, &config.key_bindings.confirm_no),
+		ConfirmAbort::new(
+			config.abort_behavior,
+			&config.key_bindings.confirm_yes,
+			&config.key_bindings.confirm_no,
+		),
+	);
+	modules.register_module(
+		State::ConfirmQuit,
+		ConfirmQuit::new(&config.key_bindings.confirm_yes, &config.key_bindings.confirm_no),
 	);
 	modules.register_module(
 		State::ConfirmRebase,
 		ConfirmRebase::new(&config.key_bindings.confirm_yes, &config.key_bindings.confirm_no),
 	);
-	modules.register_module(State::ExternalEditor, ExternalEditor::new(config.git.editor.as_str()));
-	modules.register_module(State::Insert, Insert::new());
+	modules.register_module(
+		State::ConfirmAbortRebase,
+		ConfirmRebaseCommand::new(
+			RebaseCommand::Abort,
+			&config.key_bindings.confirm_yes,
+			&config.key_bindings.confirm_no,
+		),
+	);
+	modules.register_module(
+		State::ConfirmSkipCommit,
+		ConfirmRebaseCommand::new(
+			RebaseCommand::Skip,
+			&config.key_bindings.confirm_yes,
+			&config.key_bindings.confirm_no,
+		),
+	);
+	// the todo file is compared to the lines it had when it was opened, before any changes are made to it
+	modules.register_module(
+		State::ReviewChanges,
+		ReviewChanges::new(config, todo_file.get_lines_owned()),
+	);
+	modules.register_module(State::AutosquashPreview, AutosquashPreview::new(config));
+	let todo_editor = select_editor(config.todo_editor.as_str(), config.git.editor.as_str());
+	modules.register_module(State::ExternalEditor, ExternalEditor::new(todo_editor));
+	modules.register_module(State::Insert, Insert::new(&config.exec_templates));
+	modules.register_module(State::SetupWizard, SetupWizard::new(None));
+	let mut diagnostics = Diagnostics::new(config, repo);
+	diagnostics.set_diff_memory_usage(diff_memory_usage);
+	modules.register_module(State::Diagnostics, diagnostics);
 
 	let display = Display::new(CrossTerm::new(), &config.theme);
-	let mut process = Process::new(
-		todo_file,
-		event_handler,
-		View::new(
-			display,
-			config.theme.character_vertical_spacing.as_str(),
-			config
-				.key_bindings
-				.help
-				.first()
-				.map_or(String::from("?"), String::from)
-				.as_str(),
-		),
+	let mut view = View::new(
+		display,
+		// a screen reader would read the vertical spacing character on each empty line
+		if config.screen_reader {
+			""
+		}
+		else {
+			config.theme.character_vertical_spacing.as_str()
+		},
+		config
+			.key_bindings
+			.help
+			.first()
+			.map_or(String::from("?"), String::from)
+			.as_str(),
 	);
-	match process.run(modules) {
-		Ok(status) => Exit::from(status),
-		Err(err) => Exit::new(ExitStatus::FileWriteError, err.to_string().as_str()),
+	view.set_show_scroll_bar(!config.screen_reader);
+	let mut process = Process::new(todo_file, event_handler, view);
+	process.set_notifications(notifications);
+	process.set_event_bus(event_bus);
+	if read_only {
+		process.set_read_only();
+	}
+	if !mode.is_sandbox() && CrossTerm::is_interactive() && is_setup_required(repo) {
+		process.set_initial_state(State::SetupWizard);
+	}
+	// the handlers of the signals are only registered for the tool, since they replace the default handlers of the
+	// process
+	#[cfg(not(test))]
+	process.set_signals(crate::signals::Signals::register());
+	crash::install_panic_hook();
+	startup_profile.record("Start interface");
+	let exit = match catch_unwind(AssertUnwindSafe(|| process.run(modules))) {
+		Ok(Ok(ExitStatus::Signal(signal))) => {
+			crash::save_after_signal(repo.git_directory(), process.rebase_todo(), signal)
+		},
+		Ok(Ok(status)) => Exit::from(status),
+		Ok(Err(err)) => Exit::new(ExitStatus::FileWriteError, err.to_string().as_str()),
+		Err(_) => crash::recover(repo.git_directory(), process.rebase_todo()),
+	};
+	// the modules are dropped with the process run, which cancels the work they own, and the rest of the work is
+	// cancelled here, so that the tool does not exit while a task is writing
+	let _stopped = task_manager.shutdown(TASK_SHUTDOWN_TIMEOUT);
+	if let Some(ref ui_state) = ui_state {
+		let mut ui_state = ui_state.borrow_mut();
+		ui_state.set_selected_line(process.rebase_todo());
+		if let Err(err) = ui_state.save(ui_state_path.as_path()) {
+			log::warn!(target: "state", "Unable to save the interface state: {:#}", err);
+		}
+	}
+	if let Some(first_frame_time) = process.first_frame_time() {
+		startup_profile.record_at("First frame", first_frame_time);
+	}
+	exit
+}
+
+// a todo file that cannot be written is opened to be browsed, instead of failing to write the changes on exit
+fn is_read_only(filepath: &str) -> bool {
+	metadata(filepath).map_or(false, |metadata| metadata.permissions().readonly())
+}
+
+fn load_script(args: &Args) -> Result<Option<String>, Exit> {
+	if let Some(path) = args.script_file().as_ref() {
+		return read_to_string(path).map(Some).map_err(|err| {
+			Exit::new(
+				ExitStatus::FileReadError,
+				format!("Unable to read script {}: {}", path, err).as_str(),
+			)
+		});
+	}
+	Ok(args.script().clone())
+}
+
+pub(super) fn create_event_handler(args: &Args, config: &Config) -> Result<EventHandler, Exit> {
+	let key_bindings = KeyBindings::new(&config.key_bindings);
+	if let Some(path) = args.replay_events().as_ref() {
+		let events = read_to_string(path)
+			.map_err(|err| anyhow!(err))
+			.and_then(|contents| parse_recording(contents.as_str()))
+			.map_err(|err| {
+				Exit::new(
+					ExitStatus::StateError,
+					format!("Unable to read events from {}: {:#}", path, err).as_str(),
+				)
+			})?;
+		log::info!(target: "input", "Replaying {} events from {}", events.len(), path);
+		return Ok(EventHandler::new(replay_events(events), key_bindings));
+	}
+	if let Some(path) = args.record_events().as_ref() {
+		let size = CrossTerm::new().get_size();
+		let event_provider = File::create(path)
+			.map_err(|err| anyhow!(err))
+			.and_then(|file| record_events(CrossTerm::read_event, file, size.width() as u16, size.height() as u16))
+			.map_err(|err| {
+				Exit::new(
+					ExitStatus::StateError,
+					format!("Unable to record events to {}: {:#}", path, err).as_str(),
+				)
+			})?;
+		log::info!(target: "input", "Recording events to {}", path);
+		return Ok(EventHandler::new(event_provider, key_bindings));
 	}
+	Ok(EventHandler::new(CrossTerm::read_event, key_bindings))
+}
+
+// The log file and level options take precedence over the GIRT_LOG_FILE and GIRT_LOG_LEVEL environment variables.
+pub(super) fn start_logging(args: &Args) -> Result<(), Exit> {
+	let log_file = match args.log_file().clone().or_else(|| var("GIRT_LOG_FILE").ok()) {
+		Some(log_file) => log_file,
+		None => return Ok(()),
+	};
+	let log_level = args
+		.log_level()
+		.clone()
+		.or_else(|| var("GIRT_LOG_LEVEL").ok())
+		.map_or(Ok(Level::Info), |level| logger::parse_level(level.as_str()))
+		.map_err(|err| Exit::new(ExitStatus::StateError, format!("Invalid log level: {:#}", err).as_str()))?;
+	logger::init(Path::new(log_file.as_str()), log_level)
+		.map_err(|err| Exit::new(ExitStatus::StateError, format!("{:#}", err).as_str()))?;
+	log::info!(target: "process", "Started {}", build_version());
+	Ok(())
 }
 
 pub(crate) fn run(args: &Args) -> Exit {
+	if let Err(exit) = start_logging(args) {
+		return exit;
+	}
 	if let Some(filepath) = args.todo_file_path().as_ref() {
-		let repo = match Repository::open_from_env() {
-			Ok(repo) => repo,
+		let mut startup_profile = StartupProfile::new();
+		let repo = match Repository::open_for_todo_file(Path::new(filepath)) {
+			Ok(repo) => {
+				log::info!(target: "git", "Opened repository at {}", repo.git_directory().display());
+				if repo.is_shallow() {
+					log::info!(
+						target: "git",
+						"Repository is a shallow clone, the parents of the oldest commits are not available"
+					);
+				}
+				if repo.is_partial_clone() {
+					log::info!(
+						target: "git",
+						"Repository is a partial clone, the objects that were not fetched are not available"
+					);
+				}
+				repo
+			},
 			Err(err) => {
+				log::error!(target: "git", "Unable to open repository: {:#}", err);
 				return Exit::new(
 					ExitStatus::StateError,
 					format!("Unable to load Git repository: {}", err).as_str(),
 				);
 			},
 		};
-		let config = match load_config(&repo) {
+		startup_profile.record("Open repository");
+		let config = match load_config(&repo, args.config_overrides()) {
 			Ok(config) => config,
 			Err(exit) => return exit,
 		};
-		let todo_file = match load_todo_file(filepath, &config) {
+		startup_profile.record("Load configuration");
+		let mut todo_file = match load_todo_file(filepath, &config) {
 			Ok(todo_file) => todo_file,
 			Err(exit) => return exit,
 		};
-		let event_handler = EventHandler::new(CrossTerm::read_event, KeyBindings::new(&config.key_bindings));
-		run_process(todo_file, event_handler, &config, &repo)
+		startup_profile.record("Parse todo file");
+		if let Some(path) = args.export_json().as_ref() {
+			return todo_json::export_file(&todo_file, &repo, path);
+		}
+		if let Some(path) = args.import_json().as_ref() {
+			if let Err(exit) = todo_json::import_file(&mut todo_file, path) {
+				return exit;
+			}
+		}
+		if let Some(upstream) = args.range_diff().as_ref() {
+			return range_diff::run(&todo_file, &repo, upstream);
+		}
+		// the script is exported after any import, so that an imported plan can be exported as a script
+		if let Some(path) = args.export_shell().as_ref() {
+			return shell_script::export_file(&todo_file, &repo, path);
+		}
+		match load_script(args) {
+			Ok(Some(script)) => return script::run(todo_file, script.as_str()),
+			Ok(None) => {},
+			Err(exit) => return exit,
+		}
+		let event_handler = match create_event_handler(args, &config) {
+			Ok(event_handler) => event_handler,
+			Err(exit) => return exit,
+		};
+		let read_only = args.read_only() || is_read_only(filepath);
+		if read_only {
+			log::info!(target: "todo", "Opened {} as read only, the todo file will not be written", filepath);
+		}
+		let exit = run_process(
+			todo_file,
+			event_handler,
+			&config,
+			&repo,
+			&mut startup_profile,
+			args.mode(),
+			read_only,
+		);
+		if args.profile_startup() {
+			let report = startup_profile.report();
+			return Exit::new(
+				*exit.get_status(),
+				exit.get_message()
+					.as_ref()
+					.map_or(report.clone(), |message| format!("{}\n{}", message, report))
+					.as_str(),
+			);
+		}
+		exit
 	}
 	else {
 		Exit::new(
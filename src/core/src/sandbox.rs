@@ -0,0 +1,99 @@
+use std::fs::{create_dir_all, write};
+
+use git::{Repository, SandboxCommit};
+use tempfile::{Builder, TempDir};
+
+use crate::{
+	arguments::Args,
+	exit::Exit,
+	module::ExitStatus,
+	run::{create_event_handler, load_config, load_todo_file, run_process},
+	startup_profile::StartupProfile,
+};
+
+/// A temporary repository, with commits that are created for a todo list, that is removed when dropped. This is used
+/// to open the list without a risk of changing a real repository.
+pub(crate) struct Sandbox {
+	directory: TempDir,
+	hashes: Vec<String>,
+	name: &'static str,
+	repository: Repository,
+}
+
+impl Sandbox {
+	pub(crate) fn new(name: &'static str, commits: &[SandboxCommit]) -> Result<Self, Exit> {
+		let directory = Builder::new()
+			.prefix(format!("interactive-rebase-tool-{}", name).as_str())
+			.tempdir()
+			.map_err(|err| {
+				Exit::new(
+					ExitStatus::StateError,
+					format!("Unable to create the {} repository: {}", name, err).as_str(),
+				)
+			})?;
+		let (repository, hashes) = Repository::init_sandbox(directory.path(), commits)
+			.map_err(|err| Exit::new(ExitStatus::StateError, format!("{:#}", err).as_str()))?;
+		log::info!(target: name, "Created {} repository at {}", name, directory.path().display());
+		Ok(Self {
+			directory,
+			hashes,
+			name,
+			repository,
+		})
+	}
+
+	/// The hashes of the commits, in the order that the commits were given.
+	pub(crate) fn hashes(&self) -> &[String] {
+		self.hashes.as_slice()
+	}
+
+	/// Open the list with the todo list, until the list is exited. The repository is removed on return.
+	pub(crate) fn run(self, args: &Args, todo_contents: &str) -> Exit {
+		// the todo file is in the state directory of a rebase, so that the details of the commits are loaded
+		let state_directory = self.repository.git_directory().join("rebase-merge");
+		let todo_file_path = state_directory.join("git-rebase-todo");
+		if let Err(err) = create_dir_all(&state_directory).and_then(|()| write(&todo_file_path, todo_contents)) {
+			return Exit::new(
+				ExitStatus::StateError,
+				format!("Unable to create the {} todo file: {}", self.name, err).as_str(),
+			);
+		}
+
+		let mut startup_profile = StartupProfile::new();
+		let config = match load_config(&self.repository, args.config_overrides()) {
+			Ok(config) => config,
+			Err(exit) => return exit,
+		};
+		let todo_file = match load_todo_file(todo_file_path.to_string_lossy().as_ref(), &config) {
+			Ok(todo_file) => todo_file,
+			Err(exit) => return exit,
+		};
+		let event_handler = match create_event_handler(args, &config) {
+			Ok(event_handler) => event_handler,
+			Err(exit) => return exit,
+		};
+		let exit = run_process(
+			todo_file,
+			event_handler,
+			&config,
+			&self.repository,
+			&mut startup_profile,
+			args.mode(),
+			args.read_only(),
+		);
+		log::info!(target: self.name, "Removing {} repository at {}", self.name, self.directory.path().display());
+		match *exit.get_status() {
+			ExitStatus::Good | ExitStatus::Abort | ExitStatus::Cancel | ExitStatus::Quit => {
+				Exit::new(
+					ExitStatus::Good,
+					format!(
+						"The {} has finished, the changes were made to a temporary repository, which has been removed",
+						self.name
+					)
+					.as_str(),
+				)
+			},
+			_ => exit,
+		}
+	}
+}
@@ -0,0 +1,242 @@
+use anyhow::{anyhow, Result};
+use todo_file::{Action, EditContext, Line, TodoFile};
+
+// Git uses a minimum of four characters for an abbreviated hash, so shorter numbers can safely be line numbers.
+const MAX_LINE_NUMBER_LENGTH: usize = 3;
+
+/// A reference to a line in the todo list, either by one based line number, or by a prefix of the commit hash.
+#[derive(Debug, PartialEq)]
+pub(super) enum LineReference {
+	Number(usize),
+	Hash(String),
+}
+
+impl LineReference {
+	fn parse(value: &str) -> Result<Self> {
+		if value.len() <= MAX_LINE_NUMBER_LENGTH && value.chars().all(|c| c.is_ascii_digit()) {
+			match value.parse::<usize>() {
+				Ok(number) if number > 0 => Ok(Self::Number(number)),
+				_ => Err(anyhow!("Invalid line number: {}", value)),
+			}
+		}
+		else if value.chars().all(|c| c.is_ascii_hexdigit()) {
+			Ok(Self::Hash(String::from(value)))
+		}
+		else {
+			Err(anyhow!("Invalid line number or commit hash: {}", value))
+		}
+	}
+
+	fn find(&self, todo_file: &TodoFile) -> Result<usize> {
+		match *self {
+			Self::Number(number) => {
+				if number > todo_file.lines_iter().count() {
+					Err(anyhow!("There is no line {}", number))
+				}
+				else {
+					Ok(number - 1)
+				}
+			},
+			Self::Hash(ref hash) => {
+				let mut matches = todo_file
+					.lines_iter()
+					.enumerate()
+					.filter(|&(_, line)| line.has_reference() && line.get_hash().starts_with(hash.as_str()))
+					.map(|(index, _)| index);
+				match (matches.next(), matches.next()) {
+					(Some(index), None) => Ok(index),
+					(None, _) => Err(anyhow!("No commit matches {}", hash)),
+					(Some(_), Some(_)) => Err(anyhow!("More than one commit matches {}", hash)),
+				}
+			},
+		}
+	}
+}
+
+#[derive(Debug, PartialEq)]
+pub(super) enum Command {
+	/// Set the action of a line, `drop abc123`.
+	SetAction { action: Action, line: LineReference },
+	/// Move a line after another line, and set the action, `squash def456 into abc789`.
+	Combine {
+		action: Action,
+		line: LineReference,
+		target: LineReference,
+	},
+	/// Move a line before or after another line, `move 3 after 7`.
+	Move {
+		line: LineReference,
+		target: LineReference,
+		after: bool,
+	},
+	/// Insert a break after a line, `break after 3`.
+	Break { target: LineReference },
+}
+
+fn parse_action(value: &str) -> Result<Action> {
+	let action = Action::try_from(value)?;
+	if action.is_static() {
+		return Err(anyhow!("Invalid action: {}", value));
+	}
+	Ok(action)
+}
+
+impl Command {
+	pub(super) fn parse(command: &str) -> Result<Self> {
+		let parts = command.split_whitespace().collect::<Vec<&str>>();
+		match parts.as_slice() {
+			["move", line, position @ ("after" | "before"), target] => {
+				Ok(Self::Move {
+					line: LineReference::parse(line)?,
+					target: LineReference::parse(target)?,
+					after: *position == "after",
+				})
+			},
+			["break" | "b", "after", target] => {
+				Ok(Self::Break {
+					target: LineReference::parse(target)?,
+				})
+			},
+			[action @ ("squash" | "s" | "fixup" | "f"), line, "into", target] => {
+				Ok(Self::Combine {
+					action: parse_action(action)?,
+					line: LineReference::parse(line)?,
+					target: LineReference::parse(target)?,
+				})
+			},
+			[action, line] => {
+				Ok(Self::SetAction {
+					action: parse_action(action)?,
+					line: LineReference::parse(line)?,
+				})
+			},
+			_ => Err(anyhow!("Unknown command")),
+		}
+	}
+
+	fn move_line(todo_file: &mut TodoFile, index: usize, target_index: usize, after: bool) -> usize {
+		let line = todo_file.get_line(index).cloned().unwrap();
+		todo_file.remove_lines(index, index);
+		let target = if target_index > index {
+			target_index - 1
+		}
+		else {
+			target_index
+		};
+		let new_index = if after { target + 1 } else { target };
+		todo_file.add_line(new_index, line);
+		new_index
+	}
+
+	pub(super) fn apply(&self, todo_file: &mut TodoFile) -> Result<()> {
+		match *self {
+			Self::SetAction { action, ref line } => {
+				let index = line.find(todo_file)?;
+				if todo_file.get_line(index).map_or(true, |l| l.get_action().is_static()) {
+					return Err(anyhow!("The action of line {} cannot be changed", index + 1));
+				}
+				todo_file.update_range(index, index, &EditContext::new().action(action));
+			},
+			Self::Combine {
+				action,
+				ref line,
+				ref target,
+			} => {
+				let index = line.find(todo_file)?;
+				let target_index = target.find(todo_file)?;
+				if index == target_index {
+					return Err(anyhow!("A commit cannot be combined with itself"));
+				}
+				if todo_file.get_line(index).map_or(true, |l| l.get_action().is_static()) {
+					return Err(anyhow!("The action of line {} cannot be changed", index + 1));
+				}
+				let new_index = Self::move_line(todo_file, index, target_index, true);
+				todo_file.update_range(new_index, new_index, &EditContext::new().action(action));
+			},
+			Self::Move {
+				ref line,
+				ref target,
+				after,
+			} => {
+				let index = line.find(todo_file)?;
+				let target_index = target.find(todo_file)?;
+				if index != target_index {
+					let _new_index = Self::move_line(todo_file, index, target_index, after);
+				}
+			},
+			Self::Break { ref target } => {
+				let target_index = target.find(todo_file)?;
+				todo_file.add_line(target_index + 1, Line::new_break());
+			},
+		}
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use rstest::rstest;
+
+	use super::*;
+
+	#[rstest]
+	#[case::number("3", LineReference::Number(3))]
+	#[case::max_number("999", LineReference::Number(999))]
+	#[case::hash("abc1", LineReference::Hash(String::from("abc1")))]
+	#[case::numeric_hash("1234", LineReference::Hash(String::from("1234")))]
+	fn line_reference_parse(#[case] value: &str, #[case] expected: LineReference) {
+		assert_eq!(LineReference::parse(value).unwrap(), expected);
+	}
+
+	#[rstest]
+	#[case::zero("0", "Invalid line number: 0")]
+	#[case::not_hash("xyz", "Invalid line number or commit hash: xyz")]
+	fn line_reference_parse_error(#[case] value: &str, #[case] expected: &str) {
+		assert_eq!(LineReference::parse(value).unwrap_err().to_string(), expected);
+	}
+
+	#[rstest]
+	#[case::drop("drop abc123", Command::SetAction {
+		action: Action::Drop,
+		line: LineReference::Hash(String::from("abc123")),
+	})]
+	#[case::abbreviation("r 2", Command::SetAction {
+		action: Action::Reword,
+		line: LineReference::Number(2),
+	})]
+	#[case::squash("squash def456 into abc789", Command::Combine {
+		action: Action::Squash,
+		line: LineReference::Hash(String::from("def456")),
+		target: LineReference::Hash(String::from("abc789")),
+	})]
+	#[case::fixup("f 4 into 1", Command::Combine {
+		action: Action::Fixup,
+		line: LineReference::Number(4),
+		target: LineReference::Number(1),
+	})]
+	#[case::move_after("move 3 after 7", Command::Move {
+		line: LineReference::Number(3),
+		target: LineReference::Number(7),
+		after: true,
+	})]
+	#[case::move_before("move  3  before  1", Command::Move {
+		line: LineReference::Number(3),
+		target: LineReference::Number(1),
+		after: false,
+	})]
+	#[case::break_after("break after 2", Command::Break {
+		target: LineReference::Number(2),
+	})]
+	fn command_parse(#[case] command: &str, #[case] expected: Command) {
+		assert_eq!(Command::parse(command).unwrap(), expected);
+	}
+
+	#[rstest]
+	#[case::unknown("move 3 to 4", "Unknown command")]
+	#[case::invalid_action("jump 3", "Invalid action: jump")]
+	#[case::static_action("exec 3", "Invalid action: exec")]
+	#[case::invalid_reference("drop xyz", "Invalid line number or commit hash: xyz")]
+	fn command_parse_error(#[case] command: &str, #[case] expected: &str) {
+		assert_eq!(Command::parse(command).unwrap_err().to_string(), expected);
+	}
+}
@@ -0,0 +1,48 @@
+mod command;
+
+#[cfg(test)]
+mod tests;
+
+use anyhow::{anyhow, Result};
+use todo_file::TodoFile;
+
+use self::command::Command;
+use crate::{
+	exit::Exit,
+	module::ExitStatus,
+};
+
+/// Split a script into commands, separated by semicolons or new lines. Commands that start with `#` are ignored.
+fn split_commands(script: &str) -> impl Iterator<Item = &str> {
+	script
+		.split(|c| c == ';' || c == '\n')
+		.map(str::trim)
+		.filter(|command| !command.is_empty() && !command.starts_with('#'))
+}
+
+/// Apply all the commands of the script to the todo file. The todo file is left as is when any of the commands fail.
+pub(crate) fn apply_script(todo_file: &mut TodoFile, script: &str) -> Result<()> {
+	let original_lines = todo_file.get_lines_owned();
+	let result = split_commands(script).enumerate().try_for_each(|(index, command)| {
+		log::debug!(target: "script", "Applying {}", command);
+		Command::parse(command)
+			.and_then(|c| c.apply(todo_file))
+			.map_err(|err| err.context(format!("Error in command {}, \"{}\"", index + 1, command)))
+	});
+	let result = result.and_then(|_| todo_file.validate().map_err(|err| anyhow!(err)));
+	if result.is_err() {
+		todo_file.set_lines(original_lines);
+	}
+	result
+}
+
+/// Apply the script to the todo file and write the todo file, without reading input or rendering to the terminal.
+pub(crate) fn run(mut todo_file: TodoFile, script: &str) -> Exit {
+	if let Err(err) = apply_script(&mut todo_file, script) {
+		return Exit::new(ExitStatus::StateError, format!("{:#}", err).as_str());
+	}
+	match todo_file.write_file() {
+		Ok(_) => Exit::from(ExitStatus::Good),
+		Err(err) => Exit::new(ExitStatus::FileWriteError, format!("{:#}", err).as_str()),
+	}
+}
@@ -0,0 +1,171 @@
+use std::fs::read_to_string;
+
+use tempfile::tempdir;
+use todo_file::Line;
+
+use super::*;
+
+fn create_todo_file(lines: &[&str]) -> TodoFile {
+	let mut todo_file = TodoFile::new("git-rebase-todo", 1, "#");
+	todo_file.set_lines(lines.iter().map(|line| Line::new(line).unwrap()).collect());
+	todo_file
+}
+
+fn todo_lines(todo_file: &TodoFile) -> Vec<String> {
+	todo_file.lines_iter().map(Line::to_text).collect()
+}
+
+const LINES: &[&str] = &[
+	"pick aaa111 first",
+	"pick bbb222 second",
+	"pick ccc333 third",
+	"pick ddd444 fourth",
+];
+
+#[test]
+fn apply_set_action() {
+	let mut todo_file = create_todo_file(LINES);
+	apply_script(&mut todo_file, "drop bbb2; reword 4").unwrap();
+	assert_eq!(todo_lines(&todo_file), vec![
+		"pick aaa111 first",
+		"drop bbb222 second",
+		"pick ccc333 third",
+		"reword ddd444 fourth",
+	]);
+}
+
+#[test]
+fn apply_squash_into() {
+	let mut todo_file = create_todo_file(LINES);
+	apply_script(&mut todo_file, "squash ddd4 into aaa1").unwrap();
+	assert_eq!(todo_lines(&todo_file), vec![
+		"pick aaa111 first",
+		"squash ddd444 fourth",
+		"pick bbb222 second",
+		"pick ccc333 third",
+	]);
+}
+
+#[test]
+fn apply_fixup_into_later_commit() {
+	let mut todo_file = create_todo_file(LINES);
+	apply_script(&mut todo_file, "fixup 2 into 3").unwrap();
+	assert_eq!(todo_lines(&todo_file), vec![
+		"pick aaa111 first",
+		"pick ccc333 third",
+		"fixup bbb222 second",
+		"pick ddd444 fourth",
+	]);
+}
+
+#[test]
+fn apply_move() {
+	let mut todo_file = create_todo_file(LINES);
+	apply_script(&mut todo_file, "move 1 after 3\nmove 4 before 1").unwrap();
+	assert_eq!(todo_lines(&todo_file), vec![
+		"pick ddd444 fourth",
+		"pick bbb222 second",
+		"pick ccc333 third",
+		"pick aaa111 first",
+	]);
+}
+
+#[test]
+fn apply_break() {
+	let mut todo_file = create_todo_file(LINES);
+	apply_script(&mut todo_file, "# stop after the second commit\nbreak after bbb222").unwrap();
+	assert_eq!(todo_lines(&todo_file), vec![
+		"pick aaa111 first",
+		"pick bbb222 second",
+		"break",
+		"pick ccc333 third",
+		"pick ddd444 fourth",
+	]);
+}
+
+#[test]
+fn apply_error_restores_lines() {
+	let mut todo_file = create_todo_file(LINES);
+	let err = apply_script(&mut todo_file, "drop 1; drop eee5").unwrap_err();
+	assert_eq!(
+		format!("{:#}", err),
+		"Error in command 2, \"drop eee5\": No commit matches eee5"
+	);
+	assert_eq!(todo_lines(&todo_file), LINES);
+}
+
+#[test]
+fn apply_error_ambiguous_hash() {
+	let mut todo_file = create_todo_file(&["pick abc111 first", "pick abc222 second"]);
+	let err = apply_script(&mut todo_file, "drop abc").unwrap_err();
+	assert_eq!(
+		format!("{:#}", err),
+		"Error in command 1, \"drop abc\": More than one commit matches abc"
+	);
+}
+
+#[test]
+fn apply_error_line_out_of_range() {
+	let mut todo_file = create_todo_file(LINES);
+	let err = apply_script(&mut todo_file, "move 1 after 9").unwrap_err();
+	assert_eq!(
+		format!("{:#}", err),
+		"Error in command 1, \"move 1 after 9\": There is no line 9"
+	);
+}
+
+#[test]
+fn apply_error_static_line() {
+	let mut todo_file = create_todo_file(&["pick aaa111 first", "break"]);
+	let err = apply_script(&mut todo_file, "drop 2").unwrap_err();
+	assert_eq!(
+		format!("{:#}", err),
+		"Error in command 1, \"drop 2\": The action of line 2 cannot be changed"
+	);
+}
+
+#[test]
+fn apply_error_combine_with_itself() {
+	let mut todo_file = create_todo_file(LINES);
+	let err = apply_script(&mut todo_file, "squash 2 into 2").unwrap_err();
+	assert_eq!(
+		format!("{:#}", err),
+		"Error in command 1, \"squash 2 into 2\": A commit cannot be combined with itself"
+	);
+}
+
+#[test]
+fn apply_error_first_commit_squash() {
+	let mut todo_file = create_todo_file(LINES);
+	let err = apply_script(&mut todo_file, "drop 1; fixup 2").unwrap_err();
+	assert_eq!(
+		format!("{:#}", err),
+		"The first commit, bbb222, cannot be a fixup, since there is no previous commit"
+	);
+	assert_eq!(todo_lines(&todo_file), LINES);
+}
+
+#[test]
+fn run_writes_file() {
+	let directory = tempdir().unwrap();
+	let path = directory.path().join("git-rebase-todo");
+	let mut todo_file = TodoFile::new(path.to_str().unwrap(), 1, "#");
+	todo_file.set_lines(LINES.iter().map(|line| Line::new(line).unwrap()).collect());
+	assert_eq!(run(todo_file, "drop 1"), Exit::from(ExitStatus::Good));
+	assert_eq!(
+		read_to_string(path).unwrap(),
+		"drop aaa111 first\npick bbb222 second\npick ccc333 third\npick ddd444 fourth\n"
+	);
+}
+
+#[test]
+fn run_error() {
+	let todo_file = create_todo_file(LINES);
+	assert_eq!(
+		run(todo_file, "jump 1"),
+		Exit::new(
+			ExitStatus::StateError,
+			"Error in command 1, \"jump 1\": Invalid action: jump"
+		)
+	);
+}
@@ -0,0 +1,176 @@
+#[cfg(test)]
+mod tests;
+
+use std::{
+	collections::HashSet,
+	fs::write,
+	io::{stdout, Write},
+};
+
+use git::Repository;
+use todo_file::{Action, Line, TodoFile};
+
+use crate::{
+	exit::Exit,
+	module::ExitStatus,
+};
+
+// The path used to write the exported script to standard output.
+const STDOUT_PATH: &str = "-";
+
+// Git keeps the commits of the labels of a rebase with merges in references under this prefix.
+const LABEL_REFERENCE_PREFIX: &str = "refs/rewritten/";
+
+const HEADER: &str = "#!/bin/sh
+# The Git commands that are equivalent to the todo list of an interactive rebase, to review the rebase, or to apply it
+# manually after the rebase has been aborted. Each line of the todo list is followed by its commands.
+set -e
+";
+
+fn is_safe_character(character: char) -> bool {
+	character.is_ascii_alphanumeric() || matches!(character, '-' | '_' | '.' | '/' | ':' | '@' | '^' | '~')
+}
+
+// Quote the value for the shell, unless it only has characters that are never special to the shell.
+fn quote(value: &str) -> String {
+	if !value.is_empty() && value.chars().all(is_safe_character) {
+		String::from(value)
+	}
+	else {
+		format!("'{}'", value.replace('\'', "'\\''"))
+	}
+}
+
+// A reset or merge can refer to a label of the todo list, or to any commit.
+fn revision(reference: &str, labels: &HashSet<&str>) -> String {
+	if labels.contains(reference) {
+		quote(format!("{}{}", LABEL_REFERENCE_PREFIX, reference).as_str())
+	}
+	else {
+		quote(reference)
+	}
+}
+
+fn line_commands(line: &Line, labels: &HashSet<&str>) -> Vec<String> {
+	let hash = quote(line.get_hash());
+	match *line.get_action() {
+		Action::Pick => vec![format!("git cherry-pick {}", hash)],
+		Action::Reword => vec![format!("git cherry-pick {}", hash), String::from("git commit --amend")],
+		Action::Edit => {
+			vec![
+				format!("git cherry-pick {}", hash),
+				String::from("# the rebase stops here, to amend the commit with git commit --amend"),
+			]
+		},
+		Action::Squash => {
+			vec![
+				format!("git cherry-pick --no-commit {}", hash),
+				String::from("git commit --amend"),
+			]
+		},
+		Action::Fixup => {
+			vec![
+				format!("git cherry-pick --no-commit {}", hash),
+				// with `-C` or `-c`, the message of the fixup commit replaces the message of the commit
+				line.get_option().map_or_else(
+					|| String::from("git commit --amend --no-edit"),
+					|option| format!("git commit --amend {} {}", option, hash),
+				),
+			]
+		},
+		Action::Exec => vec![String::from(line.get_content())],
+		Action::Break => vec![String::from("# the rebase stops here")],
+		Action::Label => {
+			line.get_labels()
+				.first()
+				.map(|label| {
+					format!(
+						"git update-ref {} HEAD",
+						quote(format!("{}{}", LABEL_REFERENCE_PREFIX, label).as_str())
+					)
+				})
+				.into_iter()
+				.collect()
+		},
+		Action::Reset => {
+			match line.get_labels().first() {
+				Some(&"[new") => {
+					vec![String::from(
+						"# a new root commit, the commits that follow have no parent",
+					)]
+				},
+				Some(target) => vec![format!("git reset --hard {}", revision(target, labels))],
+				None => vec![],
+			}
+		},
+		Action::Merge => {
+			let heads = line
+				.get_labels()
+				.iter()
+				.map(|label| revision(label, labels))
+				.collect::<Vec<String>>()
+				.join(" ");
+			vec![match line.get_merge_message_commit() {
+				Some((option, commit)) => {
+					format!(
+						"git merge --no-ff {} -m \"$(git show --no-patch --format=%B {})\" {}",
+						if option == "-c" { "--edit" } else { "--no-edit" },
+						quote(commit),
+						heads
+					)
+				},
+				None => format!("git merge --no-ff {}", heads),
+			}]
+		},
+		Action::Drop | Action::Noop => vec![],
+	}
+}
+
+/// Create a shell script of the Git commands that are equivalent to the todo list, starting from the commit that the
+/// commits are rebased onto, when it is known.
+pub(crate) fn export(todo_file: &TodoFile, onto: Option<&str>) -> String {
+	let labels: HashSet<&str> = todo_file
+		.lines_iter()
+		.filter(|line| *line.get_action() == Action::Label)
+		.filter_map(|line| line.get_labels().first().copied())
+		.collect();
+	let mut script = String::from(HEADER);
+	script.push_str(
+		onto.map_or(
+			String::from("# check out the commit that the commits are rebased onto, before running this script\n"),
+			|commit| format!("git checkout --detach {}\n", quote(commit)),
+		)
+		.as_str(),
+	);
+	for line in todo_file.lines_iter().filter(|line| *line.get_action() != Action::Noop) {
+		script.push_str(format!("\n# {}\n", line.to_text().trim_end()).as_str());
+		for command in line_commands(line, &labels) {
+			script.push_str(command.as_str());
+			script.push('\n');
+		}
+	}
+	script
+}
+
+/// Write the todo list as a shell script to the file, or to standard output when the path is `-`.
+pub(crate) fn export_file(todo_file: &TodoFile, repo: &Repository, path: &str) -> Exit {
+	let script = export(todo_file, repo.rebase_range().as_ref().map(|range| range.0.as_str()));
+	let result = if path == STDOUT_PATH {
+		stdout().lock().write_all(script.as_bytes())
+	}
+	else {
+		write(path, script)
+	};
+	match result {
+		Ok(_) => {
+			log::info!(target: "todo", "Exported the todo list as a shell script to {}", path);
+			Exit::from(ExitStatus::Good)
+		},
+		Err(err) => {
+			Exit::new(
+				ExitStatus::FileWriteError,
+				format!("Unable to export to {}: {}", path, err).as_str(),
+			)
+		},
+	}
+}
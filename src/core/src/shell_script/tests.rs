@@ -0,0 +1,89 @@
+use git::testutil::with_temp_repository;
+use rstest::rstest;
+
+use super::*;
+
+fn create_todo_file(lines: &[&str]) -> TodoFile {
+	let mut todo_file = TodoFile::new("git-rebase-todo", 1, "#");
+	todo_file.set_lines(lines.iter().map(|line| Line::new(line).unwrap()).collect());
+	todo_file
+}
+
+fn commands(line: &str) -> Vec<String> {
+	let todo_file = create_todo_file(&["label onto", line]);
+	let labels = HashSet::from(["onto"]);
+	line_commands(todo_file.get_line(1).unwrap(), &labels)
+}
+
+#[test]
+fn export_with_onto() {
+	let todo_file = create_todo_file(&["pick aaa c1", "fixup bbb c2", "exec cargo test"]);
+	assert_eq!(
+		export(&todo_file, Some("ccc")),
+		format!(
+			"{}git checkout --detach ccc\n\n# pick aaa c1\ngit cherry-pick aaa\n\n# fixup bbb c2\ngit cherry-pick \
+			 --no-commit bbb\ngit commit --amend --no-edit\n\n# exec cargo test\ncargo test\n",
+			HEADER
+		)
+	);
+}
+
+#[test]
+fn export_without_onto() {
+	let todo_file = create_todo_file(&["drop aaa c1"]);
+	assert_eq!(
+		export(&todo_file, None),
+		format!(
+			"{}# check out the commit that the commits are rebased onto, before running this script\n\n# drop aaa c1\n",
+			HEADER
+		)
+	);
+}
+
+#[rstest]
+#[case::pick("pick aaa c1", &["git cherry-pick aaa"])]
+#[case::reword("reword aaa c1", &["git cherry-pick aaa", "git commit --amend"])]
+#[case::edit("edit aaa c1", &[
+	"git cherry-pick aaa",
+	"# the rebase stops here, to amend the commit with git commit --amend"
+])]
+#[case::squash("squash aaa c1", &["git cherry-pick --no-commit aaa", "git commit --amend"])]
+#[case::fixup("fixup aaa c1", &["git cherry-pick --no-commit aaa", "git commit --amend --no-edit"])]
+#[case::fixup_use_message("fixup -C aaa c1", &["git cherry-pick --no-commit aaa", "git commit --amend -C aaa"])]
+#[case::fixup_edit_message("fixup -c aaa c1", &["git cherry-pick --no-commit aaa", "git commit --amend -c aaa"])]
+#[case::drop("drop aaa c1", &[])]
+#[case::exec("exec make test", &["make test"])]
+#[case::break_line("break", &["# the rebase stops here"])]
+#[case::label("label side", &["git update-ref refs/rewritten/side HEAD"])]
+#[case::label_with_special_characters("label it's", &["git update-ref 'refs/rewritten/it'\\''s' HEAD"])]
+#[case::reset_to_label("reset onto", &["git reset --hard refs/rewritten/onto"])]
+#[case::reset_to_commit("reset abc123 # c0", &["git reset --hard abc123"])]
+#[case::reset_to_new_root("reset [new root]", &["# a new root commit, the commits that follow have no parent"])]
+#[case::merge("merge onto other", &["git merge --no-ff refs/rewritten/onto other"])]
+#[case::merge_with_message(
+	"merge -C aaa onto # Merge onto",
+	&["git merge --no-ff --no-edit -m \"$(git show --no-patch --format=%B aaa)\" refs/rewritten/onto"]
+)]
+#[case::merge_with_edited_message(
+	"merge -c aaa onto",
+	&["git merge --no-ff --edit -m \"$(git show --no-patch --format=%B aaa)\" refs/rewritten/onto"]
+)]
+fn line_to_commands(#[case] line: &str, #[case] expected: &[&str]) {
+	assert_eq!(commands(line), expected);
+}
+
+#[test]
+fn export_file_invalid_path() {
+	with_temp_repository(|repository| {
+		let todo_file = create_todo_file(&["pick aaa c1"]);
+		let exit = export_file(&todo_file, &repository, "does-not-exist/plan.sh");
+		assert_eq!(
+			exit,
+			Exit::new(
+				ExitStatus::FileWriteError,
+				"Unable to export to does-not-exist/plan.sh: No such file or directory (os error 2)"
+			)
+		);
+		Ok(())
+	});
+}
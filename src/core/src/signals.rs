@@ -0,0 +1,81 @@
+use std::sync::{
+	atomic::{AtomicUsize, Ordering},
+	Arc,
+};
+
+/// The termination signals that were received, so that the tool restores the terminal and saves the edited todo
+/// list before it exits, instead of being stopped with the terminal in raw mode. A signal is only recorded by its
+/// handler, and is handled by the process between events.
+#[derive(Debug, Clone)]
+pub(crate) struct Signals {
+	received: Arc<AtomicUsize>,
+}
+
+impl Signals {
+	pub(crate) fn new() -> Self {
+		Self {
+			received: Arc::new(AtomicUsize::new(0)),
+		}
+	}
+
+	/// Register the handlers of the `SIGINT`, `SIGTERM` and `SIGHUP` signals. A signal that cannot be handled keeps
+	/// its default behaviour.
+	#[cfg(unix)]
+	#[cfg_attr(test, allow(dead_code))]
+	pub(crate) fn register() -> Self {
+		use signal_hook::{
+			consts::{SIGHUP, SIGINT, SIGTERM},
+			flag::register_usize,
+		};
+
+		let signals = Self::new();
+		for signal in [SIGINT, SIGTERM, SIGHUP] {
+			#[allow(clippy::cast_sign_loss)]
+			if let Err(err) = register_usize(signal, Arc::clone(&signals.received), signal as usize) {
+				log::warn!(target: "signal", "Unable to handle signal {}: {}", signal, err);
+			}
+		}
+		signals
+	}
+
+	#[cfg(not(unix))]
+	#[cfg_attr(test, allow(dead_code))]
+	pub(crate) fn register() -> Self {
+		log::debug!(target: "signal", "Termination signals are not handled on this platform");
+		Self::new()
+	}
+
+	/// The last termination signal that was received, if any.
+	pub(crate) fn received(&self) -> Option<i32> {
+		#[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+		match self.received.load(Ordering::Acquire) {
+			0 => None,
+			signal => Some(signal as i32),
+		}
+	}
+
+	/// Record a signal as if it was received, for the tests of the handling of a signal.
+	#[cfg(test)]
+	pub(crate) fn send(&self, signal: i32) {
+		#[allow(clippy::cast_sign_loss)]
+		self.received.store(signal as usize, Ordering::Release);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn received_none() {
+		assert_eq!(Signals::new().received(), None);
+	}
+
+	#[test]
+	fn received_signal() {
+		let signals = Signals::new();
+		let signals_clone = signals.clone();
+		signals_clone.send(15);
+		assert_eq!(signals.received(), Some(15));
+	}
+}
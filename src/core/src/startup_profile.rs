@@ -0,0 +1,93 @@
+use std::{
+	iter::once,
+	time::{Duration, Instant},
+};
+
+/// The time taken by each phase of the startup, from opening the repository to the first frame.
+#[derive(Debug)]
+pub(crate) struct StartupProfile {
+	last: Instant,
+	phases: Vec<(&'static str, Duration)>,
+}
+
+impl StartupProfile {
+	pub(crate) fn new() -> Self {
+		Self {
+			last: Instant::now(),
+			phases: vec![],
+		}
+	}
+
+	/// Record a phase that ended now, and started at the end of the previous phase.
+	pub(crate) fn record(&mut self, phase: &'static str) {
+		self.record_at(phase, Instant::now());
+	}
+
+	/// Record a phase that ended at `end`, and started at the end of the previous phase.
+	pub(crate) fn record_at(&mut self, phase: &'static str, end: Instant) {
+		let duration = end.saturating_duration_since(self.last);
+		log::info!(target: "startup", "{} took {}us", phase, duration.as_micros());
+		self.phases.push((phase, duration));
+		self.last = end;
+	}
+
+	pub(crate) fn report(&self) -> String {
+		let width = self
+			.phases
+			.iter()
+			.map(|&(phase, _)| phase.len())
+			.max()
+			.unwrap_or(0)
+			.max(5);
+		let total: Duration = self.phases.iter().map(|&(_, duration)| duration).sum();
+		let mut lines = vec![String::from("Startup profile:")];
+		for &(phase, duration) in self.phases.iter().chain(once(&("Total", total))) {
+			lines.push(format!(
+				"  {:width$}  {:>10.3}ms",
+				phase,
+				duration.as_secs_f64() * 1000.0,
+				width = width
+			));
+		}
+		lines.join("\n")
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn record() {
+		let mut profile = StartupProfile::new();
+		let start = profile.last;
+		profile.record_at("First", start + Duration::from_millis(2));
+		profile.record_at("Second phase", start + Duration::from_millis(5));
+		assert_eq!(profile.phases, vec![
+			("First", Duration::from_millis(2)),
+			("Second phase", Duration::from_millis(3))
+		]);
+	}
+
+	#[test]
+	fn record_before_previous_phase() {
+		let mut profile = StartupProfile::new();
+		let start = profile.last;
+		profile.record_at("First", start + Duration::from_millis(2));
+		profile.record_at("Second", start);
+		assert_eq!(profile.phases[1], ("Second", Duration::from_millis(0)));
+	}
+
+	#[test]
+	fn report() {
+		let mut profile = StartupProfile::new();
+		let start = profile.last;
+		profile.record_at("Open repository", start + Duration::from_micros(1500));
+		profile.record_at("First frame", start + Duration::from_micros(4250));
+		assert_eq!(
+			profile.report(),
+			"Startup profile:\n  Open repository       1.500ms\n  First frame           2.750ms\n  Total                 \
+			 4.250ms"
+		);
+	}
+}
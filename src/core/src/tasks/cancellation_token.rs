@@ -0,0 +1,87 @@
+use std::{
+	sync::{Arc, Condvar, Mutex, PoisonError},
+	time::Duration,
+};
+
+/// Cancels a task, that checks the token between the steps of its work, and that waits on the token rather than
+/// sleeping, so that it stops as soon as it is cancelled.
+#[derive(Clone, Debug)]
+pub(crate) struct CancellationToken {
+	state: Arc<(Mutex<bool>, Condvar)>,
+}
+
+impl CancellationToken {
+	pub(crate) fn new() -> Self {
+		Self {
+			state: Arc::new((Mutex::new(false), Condvar::new())),
+		}
+	}
+
+	pub(crate) fn cancel(&self) {
+		let (ref cancelled, ref changed) = *self.state;
+		*cancelled.lock().unwrap_or_else(PoisonError::into_inner) = true;
+		changed.notify_all();
+	}
+
+	pub(crate) fn is_cancelled(&self) -> bool {
+		*self.state.0.lock().unwrap_or_else(PoisonError::into_inner)
+	}
+
+	/// Wait for the duration, or until the token is cancelled. Returns if the token is cancelled.
+	pub(crate) fn sleep(&self, duration: Duration) -> bool {
+		let (ref cancelled, ref changed) = *self.state;
+		let (guard, _) = changed
+			.wait_timeout_while(
+				cancelled.lock().unwrap_or_else(PoisonError::into_inner),
+				duration,
+				|is_cancelled| !*is_cancelled,
+			)
+			.unwrap_or_else(PoisonError::into_inner);
+		*guard
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::{thread::spawn, time::Instant};
+
+	use super::*;
+
+	#[test]
+	fn not_cancelled() {
+		let token = CancellationToken::new();
+		assert!(!token.is_cancelled());
+	}
+
+	#[test]
+	fn cancel() {
+		let token = CancellationToken::new();
+		let token_clone = token.clone();
+		token_clone.cancel();
+		assert!(token.is_cancelled());
+	}
+
+	#[test]
+	fn sleep_without_cancel() {
+		let token = CancellationToken::new();
+		assert!(!token.sleep(Duration::from_millis(10)));
+	}
+
+	#[test]
+	fn sleep_after_cancel() {
+		let token = CancellationToken::new();
+		token.cancel();
+		assert!(token.sleep(Duration::from_secs(60)));
+	}
+
+	#[test]
+	fn sleep_until_cancel() {
+		let token = CancellationToken::new();
+		let thread_token = token.clone();
+		let start = Instant::now();
+		let sleeper = spawn(move || thread_token.sleep(Duration::from_secs(60)));
+		token.cancel();
+		assert!(sleeper.join().unwrap());
+		assert!(start.elapsed() < Duration::from_secs(60));
+	}
+}
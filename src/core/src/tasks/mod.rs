@@ -0,0 +1,233 @@
+mod cancellation_token;
+mod priority;
+
+#[cfg(test)]
+mod tests;
+
+use std::{
+	cmp::Ordering,
+	collections::{BinaryHeap, HashMap},
+	mem,
+	sync::{Arc, Condvar, Mutex, MutexGuard, PoisonError},
+	thread::{self, JoinHandle},
+	time::{Duration, Instant},
+};
+
+pub(crate) use self::{cancellation_token::CancellationToken, priority::Priority};
+
+// the background work is mostly waiting on Git and the file system, so a few workers are enough to keep the work that
+// is shown from waiting behind the work that watches for changes
+const MAX_WORKERS: usize = 4;
+
+struct Task {
+	id: u64,
+	name: &'static str,
+	priority: Priority,
+	run: Box<dyn FnOnce(&CancellationToken) + Send>,
+	token: CancellationToken,
+}
+
+impl PartialEq for Task {
+	fn eq(&self, other: &Self) -> bool {
+		self.id == other.id
+	}
+}
+
+impl Eq for Task {}
+
+impl PartialOrd for Task {
+	fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+		Some(self.cmp(other))
+	}
+}
+
+// the queue takes the greatest task first, which is the task with the highest priority that was spawned first
+impl Ord for Task {
+	fn cmp(&self, other: &Self) -> Ordering {
+		self.priority.cmp(&other.priority).then_with(|| other.id.cmp(&self.id))
+	}
+}
+
+#[derive(Default)]
+struct State {
+	idle_workers: usize,
+	next_id: u64,
+	queue: BinaryHeap<Task>,
+	running: usize,
+	stopped: bool,
+	tokens: HashMap<u64, CancellationToken>,
+	workers: usize,
+}
+
+#[derive(Default)]
+struct Shared {
+	state: Mutex<State>,
+	changed: Condvar,
+}
+
+impl Shared {
+	fn lock(&self) -> MutexGuard<'_, State> {
+		self.state.lock().unwrap_or_else(PoisonError::into_inner)
+	}
+}
+
+// the counts are updated when a task, or a worker, ends by panicking, so that a shutdown does not wait for it
+struct RunningGuard<'s> {
+	id: u64,
+	shared: &'s Shared,
+}
+
+impl Drop for RunningGuard<'_> {
+	fn drop(&mut self) {
+		let mut state = self.shared.lock();
+		state.running -= 1;
+		let _ = state.tokens.remove(&self.id);
+		self.shared.changed.notify_all();
+	}
+}
+
+struct WorkerGuard<'s> {
+	shared: &'s Shared,
+}
+
+impl Drop for WorkerGuard<'_> {
+	fn drop(&mut self) {
+		self.shared.lock().workers -= 1;
+	}
+}
+
+/// Runs the work that is done in the background on a small pool of worker threads, that it owns, in the order of the
+/// priority of the work. Each task is given a token, that cancels the task, and that is cancelled for every task
+/// when the manager is shut down.
+pub(crate) struct TaskManager {
+	handles: Mutex<Vec<JoinHandle<()>>>,
+	shared: Arc<Shared>,
+}
+
+impl TaskManager {
+	pub(crate) fn new() -> Self {
+		Self {
+			handles: Mutex::new(vec![]),
+			shared: Arc::new(Shared::default()),
+		}
+	}
+
+	/// Queue a task to run on a worker, returning the token that cancels it. A task that is spawned after the manager
+	/// is shut down is never run, and its token is cancelled.
+	pub(crate) fn spawn<F>(&self, name: &'static str, priority: Priority, task: F) -> CancellationToken
+	where F: FnOnce(&CancellationToken) + Send + 'static {
+		let token = CancellationToken::new();
+		let mut state = self.shared.lock();
+		if state.stopped {
+			token.cancel();
+			return token;
+		}
+		state.next_id += 1;
+		let id = state.next_id;
+		let _ = state.tokens.insert(id, token.clone());
+		state.queue.push(Task {
+			id,
+			name,
+			priority,
+			run: Box::new(task),
+			token: token.clone(),
+		});
+		// an idle worker only leaves the idle count once it wakes, so a worker is started for every queued task that
+		// there is no idle worker for, rather than only when there is no idle worker
+		let start_worker = state.queue.len() > state.idle_workers && state.workers < MAX_WORKERS;
+		if start_worker {
+			state.workers += 1;
+		}
+		drop(state);
+		self.shared.changed.notify_all();
+		if start_worker {
+			let shared = Arc::clone(&self.shared);
+			let spawn_result = thread::Builder::new()
+				.name(String::from("task-worker"))
+				.spawn(move || run_worker(&shared));
+			match spawn_result {
+				Ok(handle) => self.handles.lock().unwrap_or_else(PoisonError::into_inner).push(handle),
+				Err(err) => {
+					log::error!(target: "task", "Unable to start a worker: {}", err);
+					self.shared.lock().workers -= 1;
+				},
+			}
+		}
+		token
+	}
+
+	/// Cancel every task, and wait for the running tasks to stop, for at most the `timeout`, so that a task that does
+	/// not stop does not keep the tool from exiting. Returns if every task stopped.
+	pub(crate) fn shutdown(&self, timeout: Duration) -> bool {
+		let start = Instant::now();
+		let mut state = self.shared.lock();
+		state.stopped = true;
+		// the queued tasks are never started, so their tokens are not removed when a task ends
+		for task in mem::take(&mut state.queue) {
+			task.token.cancel();
+			let _ = state.tokens.remove(&task.id);
+		}
+		for token in state.tokens.values() {
+			token.cancel();
+		}
+		self.shared.changed.notify_all();
+		let (stopped_state, _) = self
+			.shared
+			.changed
+			.wait_timeout_while(state, timeout, |current| current.running > 0)
+			.unwrap_or_else(PoisonError::into_inner);
+		let running = stopped_state.running;
+		drop(stopped_state);
+		if running > 0 {
+			log::warn!(target: "task", "Exiting with {} tasks that did not stop", running);
+			return false;
+		}
+		// the workers end once there is no work, and are only waited for once they have no task
+		let handles = mem::take(&mut *self.handles.lock().unwrap_or_else(PoisonError::into_inner));
+		for handle in handles {
+			let _join_result = handle.join();
+		}
+		log::debug!(target: "task", "Stopped the tasks in {}ms", start.elapsed().as_millis());
+		true
+	}
+}
+
+// the queued tasks still run once the manager is dropped, and the workers end once there are no more tasks
+impl Drop for TaskManager {
+	fn drop(&mut self) {
+		self.shared.lock().stopped = true;
+		self.shared.changed.notify_all();
+	}
+}
+
+fn run_task(task: Task, shared: &Shared) {
+	let _running_guard = RunningGuard { id: task.id, shared };
+	// a task that is cancelled while it is queued is not started
+	if task.token.is_cancelled() {
+		return;
+	}
+	log::debug!(target: "task", "Started {}", task.name);
+	let start = Instant::now();
+	(task.run)(&task.token);
+	log::debug!(target: "task", "Finished {} in {}ms", task.name, start.elapsed().as_millis());
+}
+
+fn run_worker(shared: &Shared) {
+	let _worker_guard = WorkerGuard { shared };
+	let mut state = shared.lock();
+	loop {
+		if let Some(task) = state.queue.pop() {
+			state.running += 1;
+			drop(state);
+			run_task(task, shared);
+			state = shared.lock();
+			continue;
+		}
+		if state.stopped {
+			return;
+		}
+		state.idle_workers += 1;
+		state = shared.changed.wait(state).unwrap_or_else(PoisonError::into_inner);
+		state.idle_workers -= 1;
+	}
+}
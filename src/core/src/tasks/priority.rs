@@ -0,0 +1,12 @@
+/// The order that the queued tasks are started in, when every worker is busy, with the tasks of a priority started in
+/// the order that they were spawned.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) enum Priority {
+	/// Work that is not shown, such as watching for changes.
+	Low,
+	/// Work that is shown, but that is not waited on.
+	#[cfg_attr(not(test), allow(dead_code))]
+	Normal,
+	/// Work that is shown as soon as it is done.
+	High,
+}
@@ -0,0 +1,161 @@
+use std::sync::mpsc::{channel, Sender};
+
+use super::*;
+
+const TIMEOUT: Duration = Duration::from_secs(5);
+
+// fills every worker with a task that waits for its token to be cancelled, so that the next tasks are queued
+fn block_workers(task_manager: &TaskManager) -> Vec<CancellationToken> {
+	let (sender, receiver) = channel();
+	let tokens = (0..MAX_WORKERS)
+		.map(|_| {
+			let task_sender = sender.clone();
+			task_manager.spawn("block", Priority::Normal, move |token| {
+				let _result = task_sender.send(());
+				let _cancelled = token.sleep(TIMEOUT);
+			})
+		})
+		.collect();
+	for _ in 0..MAX_WORKERS {
+		receiver.recv_timeout(TIMEOUT).unwrap();
+	}
+	tokens
+}
+
+fn send_name(task_manager: &TaskManager, name: &'static str, priority: Priority, sender: &Sender<&'static str>) {
+	let task_sender = sender.clone();
+	let _token = task_manager.spawn(name, priority, move |_| {
+		let _result = task_sender.send(name);
+	});
+}
+
+#[test]
+fn spawn_runs_task() {
+	let task_manager = TaskManager::new();
+	let (sender, receiver) = channel();
+	send_name(&task_manager, "task", Priority::Normal, &sender);
+	assert_eq!(receiver.recv_timeout(TIMEOUT), Ok("task"));
+}
+
+#[test]
+fn spawn_while_worker_idle() {
+	let task_manager = TaskManager::new();
+	let (sender, receiver) = channel();
+	send_name(&task_manager, "first", Priority::Normal, &sender);
+	assert_eq!(receiver.recv_timeout(TIMEOUT), Ok("first"));
+	while task_manager.shared.lock().idle_workers == 0 {
+		thread::yield_now();
+	}
+	// the idle worker takes the blocking task, so the next task needs a worker of its own
+	let blocking_token = task_manager.spawn("block", Priority::Normal, |token| {
+		let _cancelled = token.sleep(TIMEOUT);
+	});
+	send_name(&task_manager, "second", Priority::Normal, &sender);
+	assert_eq!(receiver.recv_timeout(TIMEOUT), Ok("second"));
+	blocking_token.cancel();
+	assert!(task_manager.shutdown(TIMEOUT));
+}
+
+#[test]
+fn spawn_in_priority_order() {
+	let task_manager = TaskManager::new();
+	let blocking_tokens = block_workers(&task_manager);
+	let (sender, receiver) = channel();
+	send_name(&task_manager, "low", Priority::Low, &sender);
+	send_name(&task_manager, "normal first", Priority::Normal, &sender);
+	send_name(&task_manager, "high", Priority::High, &sender);
+	send_name(&task_manager, "normal second", Priority::Normal, &sender);
+	// a single worker is freed, so that the queued tasks run one at a time
+	blocking_tokens[0].cancel();
+	let names: Vec<&str> = (0..4).filter_map(|_| receiver.recv_timeout(TIMEOUT).ok()).collect();
+	assert_eq!(names, vec!["high", "normal first", "normal second", "low"]);
+	assert!(task_manager.shutdown(TIMEOUT));
+}
+
+#[test]
+fn cancel_queued_task() {
+	let task_manager = TaskManager::new();
+	let blocking_tokens = block_workers(&task_manager);
+	let (sender, receiver) = channel();
+	let sender_cancelled = sender.clone();
+	let token = task_manager.spawn("cancelled", Priority::High, move |_| {
+		let _result = sender_cancelled.send("cancelled");
+	});
+	send_name(&task_manager, "task", Priority::Normal, &sender);
+	token.cancel();
+	blocking_tokens[0].cancel();
+	assert_eq!(receiver.recv_timeout(TIMEOUT), Ok("task"));
+	assert!(task_manager.shutdown(TIMEOUT));
+}
+
+#[test]
+fn cancel_running_task() {
+	let task_manager = TaskManager::new();
+	let (sender, receiver) = channel();
+	let token = task_manager.spawn("task", Priority::Normal, move |token| {
+		let _started = sender.send(None);
+		let _cancelled = sender.send(Some(token.sleep(TIMEOUT)));
+	});
+	// the task is only cancelled once it is running, since a queued task that is cancelled is never started
+	assert_eq!(receiver.recv_timeout(TIMEOUT), Ok(None));
+	token.cancel();
+	assert_eq!(receiver.recv_timeout(TIMEOUT), Ok(Some(true)));
+}
+
+#[test]
+fn shutdown_cancels_running_tasks() {
+	let task_manager = TaskManager::new();
+	let tokens = block_workers(&task_manager);
+	assert!(task_manager.shutdown(TIMEOUT));
+	assert!(tokens.iter().all(CancellationToken::is_cancelled));
+}
+
+#[test]
+fn shutdown_cancels_queued_tasks() {
+	let task_manager = TaskManager::new();
+	let _blocking_tokens = block_workers(&task_manager);
+	let token = task_manager.spawn("queued", Priority::Normal, |_| {});
+	assert!(task_manager.shutdown(TIMEOUT));
+	assert!(token.is_cancelled());
+	assert!(task_manager.shared.lock().tokens.is_empty());
+}
+
+#[test]
+fn shutdown_timeout() {
+	let task_manager = TaskManager::new();
+	let (started_sender, started_receiver) = channel();
+	let (sender, receiver) = channel::<()>();
+	let _token = task_manager.spawn("task", Priority::Normal, move |_| {
+		let _started = started_sender.send(());
+		// ignores the token, and waits for the test to finish
+		let _finished = receiver.recv_timeout(TIMEOUT);
+	});
+	started_receiver.recv_timeout(TIMEOUT).unwrap();
+	assert!(!task_manager.shutdown(Duration::from_millis(10)));
+	drop(sender);
+}
+
+#[test]
+fn spawn_after_shutdown() {
+	let task_manager = TaskManager::new();
+	assert!(task_manager.shutdown(TIMEOUT));
+	let token = task_manager.spawn("task", Priority::Normal, |_| {});
+	assert!(token.is_cancelled());
+}
+
+#[test]
+fn queued_task_runs_after_drop() {
+	let (sender, receiver) = channel();
+	send_name(&TaskManager::new(), "task", Priority::Normal, &sender);
+	assert_eq!(receiver.recv_timeout(TIMEOUT), Ok("task"));
+}
+
+#[test]
+fn shutdown_after_panic() {
+	let task_manager = TaskManager::new();
+	let _token = task_manager.spawn("panic", Priority::Normal, |_| panic!("task panicked"));
+	let (sender, receiver) = channel();
+	send_name(&task_manager, "task", Priority::Normal, &sender);
+	assert_eq!(receiver.recv_timeout(TIMEOUT), Ok("task"));
+	assert!(task_manager.shutdown(TIMEOUT));
+}
@@ -1,13 +1,18 @@
-use std::{env::set_var, fs::File, path::Path};
+use std::{
+	env::set_var,
+	fs::{copy, create_dir, remove_file},
+	path::Path,
+};
 
 use display::{testutil::CrossTerm, Tui};
-use git::Repository;
+use git::{ConfigSource, Repository};
 use input::{Event, EventHandler, KeyBindings, MetaEvent};
 
 use super::*;
 use crate::{
 	module::ExitStatus,
 	run::{load_config, load_todo_file, run_process},
+	startup_profile::StartupProfile,
 };
 
 fn set_git_directory(repo: &str) -> String {
@@ -59,6 +64,248 @@ fn load_todo_file_error_empty_file() {
 	);
 }
 
+#[test]
+#[serial_test::serial]
+fn run_with_log_file() {
+	let path = set_git_directory("fixtures/simple");
+	let todo_file = Path::new(path.as_str()).join("rebase-todo-noop");
+	let directory = tempfile::tempdir().unwrap();
+	let log_file = directory.path().join("girt.log");
+	let exit = run(args(&[
+		"--log-file",
+		log_file.to_str().unwrap(),
+		"--log-level",
+		"debug",
+		todo_file.to_str().unwrap(),
+	]));
+	logger::stop();
+	assert_eq!(exit.get_status(), &ExitStatus::Good);
+	let log = std::fs::read_to_string(log_file).unwrap();
+	assert!(log.contains(" INFO  git: Opened repository at "));
+}
+
+#[test]
+#[serial_test::serial]
+fn run_with_invalid_log_level() {
+	let exit = run(args(&["--log-file", "girt.log", "--log-level", "verbose", "todo"]));
+	assert_eq!(
+		exit,
+		Exit::new(
+			ExitStatus::StateError,
+			"Invalid log level: \"verbose\" is not one of: error, warn, info, debug or trace"
+		)
+	);
+}
+
+#[test]
+#[serial_test::serial]
+fn run_with_replay_events() {
+	let path = set_git_directory("fixtures/simple");
+	let directory = tempfile::tempdir().unwrap();
+	let todo_file = directory.path().join("git-rebase-todo");
+	std::fs::write(&todo_file, "pick aaa comment\npick bbb comment\n").unwrap();
+	let recording = directory.path().join("events");
+	std::fs::write(&recording, "resize 100 50\nkey Down\nkey d\n").unwrap();
+	let exit = run(args(&[
+		"--replay-events",
+		recording.to_str().unwrap(),
+		todo_file.to_str().unwrap(),
+	]));
+	assert_eq!(exit, Exit::from(ExitStatus::Abort));
+	assert_eq!(
+		std::fs::read_to_string(todo_file).unwrap(),
+		"pick aaa comment\ndrop bbb comment\n"
+	);
+	// the selected line is remembered in the git directory of the repository
+	let ui_state_file = Path::new(path.as_str()).join("interactive-rebase-tool-state");
+	let ui_state = std::fs::read_to_string(ui_state_file.as_path()).unwrap();
+	remove_file(ui_state_file).unwrap();
+	assert!(ui_state.contains("selectedLine 1\n"));
+	assert!(ui_state.contains("selectedCommit bbb\n"));
+}
+
+#[test]
+#[serial_test::serial]
+fn run_with_replay_events_invalid_recording() {
+	let path = set_git_directory("fixtures/simple");
+	let todo_file = Path::new(path.as_str()).join("rebase-todo");
+	let directory = tempfile::tempdir().unwrap();
+	let recording = directory.path().join("events");
+	std::fs::write(&recording, "key Down\nkey Foo\n").unwrap();
+	let exit = run(args(&[
+		"--replay-events",
+		recording.to_str().unwrap(),
+		todo_file.to_str().unwrap(),
+	]));
+	assert_eq!(
+		exit,
+		Exit::new(
+			ExitStatus::StateError,
+			format!(
+				"Unable to read events from {}: Invalid event on line 2: Invalid key: Foo",
+				recording.display()
+			)
+			.as_str()
+		)
+	);
+}
+
+#[test]
+#[serial_test::serial]
+fn run_with_record_events_invalid_path() {
+	let path = set_git_directory("fixtures/simple");
+	let todo_file = Path::new(path.as_str()).join("rebase-todo");
+	let directory = tempfile::tempdir().unwrap();
+	let recording = directory.path().join("missing").join("events");
+	let exit = run(args(&[
+		"--record-events",
+		recording.to_str().unwrap(),
+		todo_file.to_str().unwrap(),
+	]));
+	assert_eq!(exit.get_status(), &ExitStatus::StateError);
+	assert!(exit
+		.get_message()
+		.as_ref()
+		.unwrap()
+		.starts_with(format!("Unable to record events to {}: ", recording.display()).as_str()));
+}
+
+#[test]
+#[serial_test::serial]
+fn run_with_record_and_replay_events() {
+	let exit = run(args(&["--record-events", "a", "--replay-events", "b", "todo"]));
+	assert_eq!(
+		exit,
+		Exit::new(
+			ExitStatus::StateError,
+			"The --record-events and --replay-events options cannot be used together"
+		)
+	);
+}
+
+#[test]
+#[serial_test::serial]
+fn run_with_script() {
+	let _path = set_git_directory("fixtures/simple");
+	let directory = tempfile::tempdir().unwrap();
+	let todo_file = directory.path().join("git-rebase-todo");
+	std::fs::write(&todo_file, "pick aaa1 first\npick bbb2 second\npick ccc3 third\n").unwrap();
+	let exit = run(args(&[
+		"--script",
+		"drop aaa1; fixup ccc3 into bbb2",
+		todo_file.to_str().unwrap(),
+	]));
+	assert_eq!(exit, Exit::from(ExitStatus::Good));
+	assert_eq!(
+		std::fs::read_to_string(todo_file).unwrap(),
+		"drop aaa1 first\npick bbb2 second\nfixup ccc3 third\n"
+	);
+}
+
+#[test]
+#[serial_test::serial]
+fn run_with_script_file_missing() {
+	let path = set_git_directory("fixtures/simple");
+	let todo_file = Path::new(path.as_str()).join("rebase-todo");
+	let exit = run(args(&["--script-file", "does-not-exist", todo_file.to_str().unwrap()]));
+	assert_eq!(
+		exit,
+		Exit::new(
+			ExitStatus::FileReadError,
+			"Unable to read script does-not-exist: No such file or directory (os error 2)"
+		)
+	);
+}
+
+#[test]
+#[serial_test::serial]
+fn run_with_export_json() {
+	let path = set_git_directory("fixtures/simple");
+	let todo_file = Path::new(path.as_str()).join("rebase-todo");
+	let directory = tempfile::tempdir().unwrap();
+	let plan = directory.path().join("plan.json");
+	let exit = run(args(&[
+		"--export-json",
+		plan.to_str().unwrap(),
+		todo_file.to_str().unwrap(),
+	]));
+	assert_eq!(exit, Exit::from(ExitStatus::Good));
+	assert!(std::fs::read_to_string(plan)
+		.unwrap()
+		.contains("\"action\": \"pick\",\n      \"hash\": \"abc\""));
+}
+
+#[test]
+#[serial_test::serial]
+fn run_with_export_shell() {
+	let path = set_git_directory("fixtures/simple");
+	let todo_file = Path::new(path.as_str()).join("rebase-todo");
+	let directory = tempfile::tempdir().unwrap();
+	let plan = directory.path().join("plan.sh");
+	let exit = run(args(&[
+		"--export-shell",
+		plan.to_str().unwrap(),
+		todo_file.to_str().unwrap(),
+	]));
+	assert_eq!(exit, Exit::from(ExitStatus::Good));
+	assert!(std::fs::read_to_string(plan)
+		.unwrap()
+		.contains("\ngit cherry-pick abc\n"));
+}
+
+#[test]
+#[serial_test::serial]
+fn run_with_export_json_and_export_shell() {
+	let exit = run(args(&["--export-json", "a.json", "--export-shell", "b.sh", "todo"]));
+	assert_eq!(
+		exit,
+		Exit::new(
+			ExitStatus::StateError,
+			"The --export-json and --export-shell options cannot be used together"
+		)
+	);
+}
+
+#[test]
+#[serial_test::serial]
+fn run_with_import_json() {
+	let _path = set_git_directory("fixtures/simple");
+	let directory = tempfile::tempdir().unwrap();
+	let todo_file = directory.path().join("git-rebase-todo");
+	std::fs::write(&todo_file, "pick aaa1 first\npick bbb2 second\n").unwrap();
+	let plan = directory.path().join("plan.json");
+	std::fs::write(
+		&plan,
+		r#"{"version": 1, "lines": [{"action": "pick", "hash": "bbb2"}, {"action": "squash", "hash": "aaa1"}]}"#,
+	)
+	.unwrap();
+	let exit = run(args(&[
+		"--import-json",
+		plan.to_str().unwrap(),
+		"--script",
+		"",
+		todo_file.to_str().unwrap(),
+	]));
+	assert_eq!(exit, Exit::from(ExitStatus::Good));
+	assert_eq!(
+		std::fs::read_to_string(todo_file).unwrap(),
+		"pick bbb2 \nsquash aaa1 \n"
+	);
+}
+
+#[test]
+#[serial_test::serial]
+fn run_with_export_and_import_json() {
+	let exit = run(args(&["--export-json", "a.json", "--import-json", "b.json", "todo"]));
+	assert_eq!(
+		exit,
+		Exit::new(
+			ExitStatus::StateError,
+			"The --export-json and --import-json options cannot be used together"
+		)
+	);
+}
+
 #[test]
 #[serial_test::serial]
 fn run_with_no_rebase_todo_filepath() {
@@ -123,25 +370,186 @@ fn run_with_argument_license() {
 		.contains("This program is free software: you can redistribute it and/or modify"));
 }
 
+#[test]
+#[serial_test::serial]
+fn run_with_argument_diagnostics() {
+	let _path = set_git_directory("fixtures/simple");
+	let exit = run(args(&["--diagnostics"]));
+	assert_eq!(exit.get_status(), &ExitStatus::Good);
+	assert!(exit
+		.get_message()
+		.as_ref()
+		.unwrap()
+		.starts_with("Git Interactive Rebase Tool Diagnostics"));
+}
+
+#[cfg(feature = "tutorial")]
+#[test]
+#[serial_test::serial]
+fn run_with_argument_tutorial() {
+	let directory = tempfile::tempdir().unwrap();
+	let recording = directory.path().join("events");
+	std::fs::write(&recording, "resize 100 50\nkey Down\nkey k\nkey w\nkey y\n").unwrap();
+	let log_file = directory.path().join("girt.log");
+	let exit = run(args(&[
+		"--tutorial",
+		"--log-file",
+		log_file.to_str().unwrap(),
+		"--replay-events",
+		recording.to_str().unwrap(),
+	]));
+	logger::stop();
+	assert_eq!(
+		exit,
+		Exit::new(
+			ExitStatus::Good,
+			"The tutorial has finished, the changes were made to a temporary repository, which has been removed"
+		)
+	);
+	let log = std::fs::read_to_string(log_file).unwrap();
+	let sandbox = log
+		.lines()
+		.find_map(|line| line.split_once("Created tutorial repository at ").map(|(_, path)| path))
+		.unwrap();
+	assert!(!Path::new(sandbox).exists());
+}
+
+#[test]
+#[serial_test::serial]
+fn run_with_argument_demo() {
+	let directory = tempfile::tempdir().unwrap();
+	let recording = directory.path().join("events");
+	std::fs::write(&recording, "resize 100 50\nkey Down\nkey q\nkey y\n").unwrap();
+	let exit = run(args(&[
+		"--demo",
+		"actions",
+		"--replay-events",
+		recording.to_str().unwrap(),
+	]));
+	assert_eq!(
+		exit,
+		Exit::new(
+			ExitStatus::Good,
+			"The demo has finished, the changes were made to a temporary repository, which has been removed"
+		)
+	);
+}
+
+#[test]
+fn run_with_argument_demo_unknown_fixture() {
+	let exit = run(args(&["--demo", "unknown"]));
+	assert_eq!(exit.get_status(), &ExitStatus::StateError);
+	assert!(exit
+		.get_message()
+		.as_ref()
+		.unwrap()
+		.starts_with("Unknown demo fixture: unknown"));
+}
+
 #[test]
 #[serial_test::serial]
 fn run_process_error() {
 	let path = set_git_directory("fixtures/simple");
-	let todo_file_path = Path::new(path.as_str()).join("rebase-todo-readonly");
-	let todo_file = File::open(todo_file_path.as_path()).unwrap();
-	let mut permissions = todo_file.metadata().unwrap().permissions();
-	permissions.set_readonly(true);
-	todo_file.set_permissions(permissions).unwrap();
+	let directory = tempfile::tempdir().unwrap();
+	let todo_file_path = directory.path().join("git-rebase-todo");
+	let _ = copy(Path::new(path.as_str()).join("rebase-todo"), todo_file_path.as_path()).unwrap();
 	let repo = Repository::open_from_env().unwrap();
-	let config = load_config(&repo).unwrap();
+	let mut config = load_config(&repo, &[]).unwrap();
+	// the state of the interface is not written to the fixture repository
+	config.persist_ui_state = false;
 	let rebase_todo_file = load_todo_file(todo_file_path.to_str().unwrap(), &config).unwrap();
+	// a directory cannot be replaced by the todo file
+	remove_file(todo_file_path.as_path()).unwrap();
+	create_dir(todo_file_path.as_path()).unwrap();
 	let event_handler = EventHandler::new(CrossTerm::read_event, KeyBindings::new(&config.key_bindings));
 	event_handler.push_event(Event::from(MetaEvent::Exit));
 	assert_eq!(
-		run_process(rebase_todo_file, event_handler, &config, &repo),
+		run_process(
+			rebase_todo_file,
+			event_handler,
+			&config,
+			&repo,
+			&mut StartupProfile::new(),
+			&Mode::Normal,
+			false
+		),
 		Exit::new(
 			ExitStatus::FileWriteError,
-			format!("Error opening file: {}", todo_file_path.to_str().unwrap()).as_str()
+			format!("Error replacing file: {}", todo_file_path.to_str().unwrap()).as_str()
+		)
+	);
+}
+
+#[test]
+#[serial_test::serial]
+fn run_process_read_only() {
+	let _path = set_git_directory("fixtures/simple");
+	let directory = tempfile::tempdir().unwrap();
+	let todo_file_path = directory.path().join("git-rebase-todo");
+	std::fs::write(&todo_file_path, "pick aaa1 first\n").unwrap();
+	let repo = Repository::open_from_env().unwrap();
+	let mut config = load_config(&repo, &[]).unwrap();
+	// the state of the interface is not written to the fixture repository
+	config.persist_ui_state = false;
+	let rebase_todo_file = load_todo_file(todo_file_path.to_str().unwrap(), &config).unwrap();
+	// the todo file is changed after it is loaded, so that a write on exit would replace the change
+	std::fs::write(&todo_file_path, "pick bbb2 second\n").unwrap();
+	let event_handler = EventHandler::new(CrossTerm::read_event, KeyBindings::new(&config.key_bindings));
+	event_handler.push_event(Event::from(MetaEvent::Exit));
+	assert_eq!(
+		run_process(
+			rebase_todo_file,
+			event_handler,
+			&config,
+			&repo,
+			&mut StartupProfile::new(),
+			&Mode::Normal,
+			true
+		),
+		Exit::from(ExitStatus::Abort)
+	);
+	assert_eq!(std::fs::read_to_string(todo_file_path).unwrap(), "pick bbb2 second\n");
+}
+
+#[test]
+#[serial_test::serial]
+fn run_with_read_only_and_script() {
+	let exit = run(args(&["--read-only", "--script", "drop 1", "todo"]));
+	assert_eq!(
+		exit,
+		Exit::new(
+			ExitStatus::StateError,
+			"The --read-only option cannot be used with --script or --script-file"
+		)
+	);
+}
+
+#[test]
+#[serial_test::serial]
+fn load_config_with_overrides() {
+	let _path = set_git_directory("fixtures/simple");
+	let repo = Repository::open_from_env().unwrap();
+	let config = load_config(&repo, &[
+		String::from("interactive-rebase-tool.persistUiState=false"),
+		String::from("interactive-rebase-tool.autoSelectNext"),
+	])
+	.unwrap();
+	assert!(!config.persist_ui_state);
+	assert!(config.auto_select_next);
+	assert_eq!(config.source("interactive-rebase-tool.autoSelectNext"), ConfigSource::CommandLine);
+}
+
+#[test]
+#[serial_test::serial]
+fn run_with_invalid_config_override() {
+	let path = set_git_directory("fixtures/simple");
+	let todo_file = Path::new(path.as_str()).join("rebase-todo");
+	let exit = run(args(&["-c", "icons=true", todo_file.to_str().unwrap()]));
+	assert_eq!(
+		exit,
+		Exit::new(
+			ExitStatus::ConfigError,
+			"Error reading config option: \"icons=true\" is not valid: key does not contain a section"
 		)
 	);
 }
@@ -152,12 +560,22 @@ fn run_process_success() {
 	let path = set_git_directory("fixtures/simple");
 	let todo_file = Path::new(path.as_str()).join("rebase-todo");
 	let repo = Repository::open_from_env().unwrap();
-	let config = load_config(&repo).unwrap();
+	let mut config = load_config(&repo, &[]).unwrap();
+	// the state of the interface is not written to the fixture repository
+	config.persist_ui_state = false;
 	let rebase_todo_file = load_todo_file(todo_file.to_str().unwrap(), &config).unwrap();
 	let event_handler = EventHandler::new(CrossTerm::read_event, KeyBindings::new(&config.key_bindings));
 	event_handler.push_event(Event::from(MetaEvent::Exit));
 	assert_eq!(
-		run_process(rebase_todo_file, event_handler, &config, &repo),
+		run_process(
+			rebase_todo_file,
+			event_handler,
+			&config,
+			&repo,
+			&mut StartupProfile::new(),
+			&Mode::Normal,
+			false
+		),
 		Exit::from(ExitStatus::Abort)
 	);
 }
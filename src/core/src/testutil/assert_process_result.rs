@@ -16,23 +16,37 @@ fn format_process_result(
 		exit_status.map_or("None", |exit_status| {
 			match exit_status {
 				ExitStatus::Abort => "Abort",
+				ExitStatus::Cancel => "Cancel",
 				ExitStatus::ConfigError => "ConfigError",
 				ExitStatus::FileReadError => "FileReadError",
 				ExitStatus::FileWriteError => "FileWriteError",
 				ExitStatus::Good => "Good",
 				ExitStatus::StateError => "StateError",
 				ExitStatus::Kill => "Kill",
+				ExitStatus::Quit => "Quit",
+				ExitStatus::Signal(_) => "Signal",
 			}
 		}),
 		state.map_or("None", |state| {
 			match state {
+				State::AutosquashPreview => "AutosquashPreview",
 				State::ConfirmAbort => "ConfirmAbort",
+				State::ConfirmAbortRebase => "ConfirmAbortRebase",
+				State::ConfirmQuit => "ConfirmQuit",
 				State::ConfirmRebase => "ConfirmRebase",
+				State::ConfirmSkipCommit => "ConfirmSkipCommit",
+				State::Diagnostics => "Diagnostics",
 				State::Error => "Error",
 				State::ExternalEditor => "ExternalEditor",
 				State::Insert => "Insert",
 				State::List => "List",
+				State::NotificationHistory => "NotificationHistory",
+				State::ReviewChanges => "ReviewChanges",
+				State::Settings => "Settings",
+				State::SetupWizard => "SetupWizard",
 				State::ShowCommit => "ShowCommit",
+				State::SquashMessage => "SquashMessage",
+				State::SquashPreview => "SquashPreview",
 				State::WindowSizeError => "WindowSizeError",
 			}
 		}),
@@ -126,6 +140,12 @@ macro_rules! assert_process_result {
 	($actual:expr, event = $event:expr, state = $state:expr) => {
 		crate::testutil::_assert_process_result(&$actual, Some($event), Some($state), None, &None, &None)
 	};
+	($actual:expr, event = $event:expr, error = $error:expr) => {
+		crate::testutil::_assert_process_result(&$actual, Some($event), None, None, &Some($error), &None)
+	};
+	($actual:expr, event = $event:expr, state = $state:expr, error = $error:expr) => {
+		crate::testutil::_assert_process_result(&$actual, Some($event), Some($state), None, &Some($error), &None)
+	};
 	($actual:expr, event = $event:expr, exit_status = $exit_status:expr) => {
 		crate::testutil::_assert_process_result(&$actual, Some($event), None, Some($exit_status), &None, &None)
 	};
@@ -90,15 +90,6 @@ impl TestContext {
 			.close()
 			.unwrap();
 	}
-
-	pub(crate) fn set_todo_file_readonly(&self) {
-		let t = self.todo_file.replace(NamedTempFile::new().unwrap());
-		let todo_file = t.as_file();
-		let mut permissions = todo_file.metadata().unwrap().permissions();
-		permissions.set_readonly(true);
-		todo_file.set_permissions(permissions).unwrap();
-		let _ = self.todo_file.replace(t);
-	}
 }
 
 pub(crate) fn module_test<C>(lines: &[&str], events: &[Event], callback: C)
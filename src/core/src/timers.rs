@@ -0,0 +1,116 @@
+use std::{
+	collections::HashMap,
+	time::{Duration, Instant},
+};
+
+/// The timers that modules request ticks from, to change the view without waiting for input.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub(crate) enum Timer {
+	/// The progress of a long operation is updated, and its spinner is advanced.
+	Progress,
+	/// The relative dates of the list, such as "3 minutes ago", are shown again as they age.
+	RelativeDates,
+}
+
+/// A request from a module, to start or to stop receiving the ticks of a timer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum TimerRequest {
+	Start(Timer, Duration),
+	Stop(Timer),
+}
+
+/// The timers that are started, with the interval of each timer and the time of its next tick.
+#[derive(Debug)]
+pub(crate) struct Timers {
+	timers: HashMap<Timer, (Duration, Instant)>,
+}
+
+impl Timers {
+	pub(crate) fn new() -> Self {
+		Self { timers: HashMap::new() }
+	}
+
+	/// Start or stop a timer, with the first tick of a started timer after its interval. Starting a timer that is
+	/// already started changes its interval.
+	pub(crate) fn request(&mut self, request: TimerRequest, now: Instant) {
+		match request {
+			TimerRequest::Start(timer, interval) => {
+				let _ = self.timers.insert(timer, (interval, now + interval));
+			},
+			TimerRequest::Stop(timer) => {
+				let _ = self.timers.remove(&timer);
+			},
+		}
+	}
+
+	/// Take the timers that are due to tick. A timer ticks once, even when more than one interval has passed, so a
+	/// process that was suspended does not receive a burst of ticks.
+	pub(crate) fn due(&mut self, now: Instant) -> Vec<Timer> {
+		let mut due = vec![];
+		for (&timer, &mut (interval, ref mut next)) in &mut self.timers {
+			if *next <= now {
+				*next = now + interval;
+				due.push(timer);
+			}
+		}
+		due
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const INTERVAL: Duration = Duration::from_secs(60);
+
+	#[test]
+	fn no_timers() {
+		let mut timers = Timers::new();
+		assert_eq!(timers.due(Instant::now()), vec![]);
+	}
+
+	#[test]
+	fn start_before_interval() {
+		let now = Instant::now();
+		let mut timers = Timers::new();
+		timers.request(TimerRequest::Start(Timer::RelativeDates, INTERVAL), now);
+		assert_eq!(timers.due(now + Duration::from_secs(59)), vec![]);
+	}
+
+	#[test]
+	fn start_after_interval() {
+		let now = Instant::now();
+		let mut timers = Timers::new();
+		timers.request(TimerRequest::Start(Timer::RelativeDates, INTERVAL), now);
+		assert_eq!(timers.due(now + INTERVAL), vec![Timer::RelativeDates]);
+		assert_eq!(timers.due(now + INTERVAL), vec![]);
+		assert_eq!(timers.due(now + INTERVAL * 2), vec![Timer::RelativeDates]);
+	}
+
+	#[test]
+	fn tick_once_after_many_intervals() {
+		let now = Instant::now();
+		let mut timers = Timers::new();
+		timers.request(TimerRequest::Start(Timer::RelativeDates, INTERVAL), now);
+		assert_eq!(timers.due(now + INTERVAL * 5), vec![Timer::RelativeDates]);
+		assert_eq!(timers.due(now + INTERVAL * 5 + Duration::from_secs(1)), vec![]);
+	}
+
+	#[test]
+	fn restart_changes_interval() {
+		let now = Instant::now();
+		let mut timers = Timers::new();
+		timers.request(TimerRequest::Start(Timer::RelativeDates, INTERVAL), now);
+		timers.request(TimerRequest::Start(Timer::RelativeDates, Duration::from_secs(1)), now);
+		assert_eq!(timers.due(now + Duration::from_secs(1)), vec![Timer::RelativeDates]);
+	}
+
+	#[test]
+	fn stop() {
+		let now = Instant::now();
+		let mut timers = Timers::new();
+		timers.request(TimerRequest::Start(Timer::RelativeDates, INTERVAL), now);
+		timers.request(TimerRequest::Stop(Timer::RelativeDates), now);
+		assert_eq!(timers.due(now + INTERVAL), vec![]);
+	}
+}
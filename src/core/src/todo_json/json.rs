@@ -0,0 +1,392 @@
+use std::{
+	fmt::{Display, Formatter, Write as _},
+	iter::Peekable,
+	str::Chars,
+};
+
+use anyhow::{anyhow, Result};
+
+// the parser recurses into each array and object, so the nesting is limited to keep a deeply nested document from
+// overflowing the stack, far deeper than the todo list needs
+const MAX_DEPTH: usize = 128;
+
+/// A minimal JSON document model, supporting only what is needed to import and export the todo list. Numbers are
+/// kept as their source text, since they are only ever passed through or written.
+#[derive(Debug, Clone, PartialEq)]
+pub(super) enum Value {
+	Null,
+	Bool(bool),
+	Number(String),
+	String(String),
+	Array(Vec<Value>),
+	Object(Vec<(String, Value)>),
+}
+
+impl Value {
+	pub(super) fn get(&self, key: &str) -> Option<&Self> {
+		match *self {
+			Self::Object(ref entries) => entries.iter().find(|&(k, _)| k == key).map(|(_, v)| v),
+			_ => None,
+		}
+	}
+
+	pub(super) fn as_str(&self) -> Option<&str> {
+		match *self {
+			Self::String(ref value) => Some(value.as_str()),
+			_ => None,
+		}
+	}
+
+	pub(super) fn as_array(&self) -> Option<&[Self]> {
+		match *self {
+			Self::Array(ref values) => Some(values.as_slice()),
+			_ => None,
+		}
+	}
+
+	pub(super) fn parse(input: &str) -> Result<Self> {
+		let mut parser = Parser {
+			chars: input.chars().peekable(),
+			depth: 0,
+			line: 1,
+		};
+		let value = parser.parse_value()?;
+		parser.skip_whitespace();
+		if parser.chars.peek().is_some() {
+			return Err(parser.error("Unexpected content after the document"));
+		}
+		Ok(value)
+	}
+
+	fn write_pretty(&self, f: &mut Formatter<'_>, indent: usize) -> std::fmt::Result {
+		match *self {
+			Self::Null => f.write_str("null"),
+			Self::Bool(value) => write!(f, "{}", value),
+			Self::Number(ref value) => f.write_str(value.as_str()),
+			Self::String(ref value) => write_string(f, value.as_str()),
+			Self::Array(ref values) => {
+				if values.is_empty() {
+					return f.write_str("[]");
+				}
+				f.write_str("[\n")?;
+				for (index, value) in values.iter().enumerate() {
+					write!(f, "{:width$}", "", width = (indent + 1) * 2)?;
+					value.write_pretty(f, indent + 1)?;
+					f.write_str(if index + 1 == values.len() { "\n" } else { ",\n" })?;
+				}
+				write!(f, "{:width$}]", "", width = indent * 2)
+			},
+			Self::Object(ref entries) => {
+				if entries.is_empty() {
+					return f.write_str("{}");
+				}
+				f.write_str("{\n")?;
+				for (index, &(ref key, ref value)) in entries.iter().enumerate() {
+					write!(f, "{:width$}", "", width = (indent + 1) * 2)?;
+					write_string(f, key.as_str())?;
+					f.write_str(": ")?;
+					value.write_pretty(f, indent + 1)?;
+					f.write_str(if index + 1 == entries.len() { "\n" } else { ",\n" })?;
+				}
+				write!(f, "{:width$}}}", "", width = indent * 2)
+			},
+		}
+	}
+}
+
+impl Display for Value {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		self.write_pretty(f, 0)
+	}
+}
+
+fn write_string(f: &mut Formatter<'_>, value: &str) -> std::fmt::Result {
+	f.write_char('"')?;
+	for c in value.chars() {
+		match c {
+			'"' => f.write_str("\\\"")?,
+			'\\' => f.write_str("\\\\")?,
+			'\n' => f.write_str("\\n")?,
+			'\r' => f.write_str("\\r")?,
+			'\t' => f.write_str("\\t")?,
+			c if (c as u32) < 0x20 => write!(f, "\\u{:04x}", c as u32)?,
+			c => f.write_char(c)?,
+		}
+	}
+	f.write_char('"')
+}
+
+struct Parser<'s> {
+	chars: Peekable<Chars<'s>>,
+	depth: usize,
+	line: usize,
+}
+
+impl Parser<'_> {
+	fn error(&self, message: &str) -> anyhow::Error {
+		anyhow!("{} on line {}", message, self.line)
+	}
+
+	fn next(&mut self) -> Option<char> {
+		let c = self.chars.next();
+		if c == Some('\n') {
+			self.line += 1;
+		}
+		c
+	}
+
+	fn skip_whitespace(&mut self) {
+		while matches!(self.chars.peek(), Some(&(' ' | '\t' | '\n' | '\r'))) {
+			let _ = self.next();
+		}
+	}
+
+	fn expect(&mut self, expected: char) -> Result<()> {
+		self.skip_whitespace();
+		match self.next() {
+			Some(c) if c == expected => Ok(()),
+			Some(c) => Err(self.error(format!("Expected '{}', found '{}'", expected, c).as_str())),
+			None => Err(self.error(format!("Expected '{}', found the end of the document", expected).as_str())),
+		}
+	}
+
+	fn parse_literal(&mut self, literal: &str, value: Value) -> Result<Value> {
+		for expected in literal.chars() {
+			if self.next() != Some(expected) {
+				return Err(self.error("Invalid value"));
+			}
+		}
+		Ok(value)
+	}
+
+	fn parse_value(&mut self) -> Result<Value> {
+		self.skip_whitespace();
+		match self.chars.peek().copied() {
+			Some('{') => self.parse_nested(Self::parse_object),
+			Some('[') => self.parse_nested(Self::parse_array),
+			Some('"') => Ok(Value::String(self.parse_string()?)),
+			Some('t') => self.parse_literal("true", Value::Bool(true)),
+			Some('f') => self.parse_literal("false", Value::Bool(false)),
+			Some('n') => self.parse_literal("null", Value::Null),
+			Some(c) if c == '-' || c.is_ascii_digit() => self.parse_number(),
+			Some(_) => Err(self.error("Invalid value")),
+			None => Err(self.error("Unexpected end of the document")),
+		}
+	}
+
+	fn parse_nested<F>(&mut self, parse: F) -> Result<Value>
+	where F: FnOnce(&mut Self) -> Result<Value> {
+		if self.depth == MAX_DEPTH {
+			return Err(self.error("Nested too deeply"));
+		}
+		self.depth += 1;
+		let value = parse(self);
+		self.depth -= 1;
+		value
+	}
+
+	fn parse_object(&mut self) -> Result<Value> {
+		self.expect('{')?;
+		let mut entries = vec![];
+		self.skip_whitespace();
+		if self.chars.peek() == Some(&'}') {
+			let _ = self.next();
+			return Ok(Value::Object(entries));
+		}
+		loop {
+			self.skip_whitespace();
+			if self.chars.peek() != Some(&'"') {
+				return Err(self.error("Expected a string key"));
+			}
+			let key = self.parse_string()?;
+			self.expect(':')?;
+			entries.push((key, self.parse_value()?));
+			self.skip_whitespace();
+			match self.next() {
+				Some(',') => {},
+				Some('}') => return Ok(Value::Object(entries)),
+				_ => return Err(self.error("Expected ',' or '}'")),
+			}
+		}
+	}
+
+	fn parse_array(&mut self) -> Result<Value> {
+		self.expect('[')?;
+		let mut values = vec![];
+		self.skip_whitespace();
+		if self.chars.peek() == Some(&']') {
+			let _ = self.next();
+			return Ok(Value::Array(values));
+		}
+		loop {
+			values.push(self.parse_value()?);
+			self.skip_whitespace();
+			match self.next() {
+				Some(',') => {},
+				Some(']') => return Ok(Value::Array(values)),
+				_ => return Err(self.error("Expected ',' or ']'")),
+			}
+		}
+	}
+
+	fn parse_number(&mut self) -> Result<Value> {
+		let mut number = String::new();
+		while let Some(&c) = self.chars.peek() {
+			if !(c.is_ascii_digit() || matches!(c, '-' | '+' | '.' | 'e' | 'E')) {
+				break;
+			}
+			number.push(c);
+			let _ = self.next();
+		}
+		let digits = number.trim_start_matches('-');
+		if digits.is_empty() || !digits.starts_with(|c: char| c.is_ascii_digit()) || number.ends_with('-') {
+			return Err(self.error("Invalid number"));
+		}
+		Ok(Value::Number(number))
+	}
+
+	fn parse_hex(&mut self) -> Result<u32> {
+		let mut code = 0;
+		for _ in 0..4 {
+			let digit = self
+				.next()
+				.and_then(|c| c.to_digit(16))
+				.ok_or_else(|| self.error("Invalid unicode escape"))?;
+			code = code * 16 + digit;
+		}
+		Ok(code)
+	}
+
+	fn parse_unicode_escape(&mut self) -> Result<char> {
+		let code = self.parse_hex()?;
+		let code = if (0xD800..0xDC00).contains(&code) {
+			if self.next() != Some('\\') || self.next() != Some('u') {
+				return Err(self.error("Invalid unicode escape"));
+			}
+			let low = self.parse_hex()?;
+			if !(0xDC00..0xE000).contains(&low) {
+				return Err(self.error("Invalid unicode escape"));
+			}
+			0x10000 + ((code - 0xD800) << 10) + (low - 0xDC00)
+		}
+		else {
+			code
+		};
+		char::from_u32(code).ok_or_else(|| self.error("Invalid unicode escape"))
+	}
+
+	fn parse_string(&mut self) -> Result<String> {
+		self.expect('"')?;
+		let mut value = String::new();
+		loop {
+			match self.next() {
+				Some('"') => return Ok(value),
+				Some('\\') => {
+					value.push(match self.next() {
+						Some('"') => '"',
+						Some('\\') => '\\',
+						Some('/') => '/',
+						Some('b') => '\u{8}',
+						Some('f') => '\u{c}',
+						Some('n') => '\n',
+						Some('r') => '\r',
+						Some('t') => '\t',
+						Some('u') => self.parse_unicode_escape()?,
+						_ => return Err(self.error("Invalid escape in string")),
+					});
+				},
+				Some(c) if (c as u32) < 0x20 => return Err(self.error("Invalid control character in string")),
+				Some(c) => value.push(c),
+				None => return Err(self.error("Unterminated string")),
+			}
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use rstest::rstest;
+
+	use super::*;
+
+	#[test]
+	fn display_pretty() {
+		let value = Value::Object(vec![
+			(String::from("version"), Value::Number(String::from("1"))),
+			(
+				String::from("lines"),
+				Value::Array(vec![Value::Null, Value::Bool(true), Value::Array(vec![])]),
+			),
+			(String::from("empty"), Value::Object(vec![])),
+		]);
+		assert_eq!(
+			value.to_string(),
+			"{\n  \"version\": 1,\n  \"lines\": [\n    null,\n    true,\n    []\n  ],\n  \"empty\": {}\n}"
+		);
+	}
+
+	#[test]
+	fn display_escaped_string() {
+		assert_eq!(
+			Value::String(String::from("a\"b\\c\nd\te\u{1}")).to_string(),
+			"\"a\\\"b\\\\c\\nd\\te\\u0001\""
+		);
+	}
+
+	#[test]
+	fn parse_round_trip() {
+		let value = Value::Object(vec![
+			(String::from("a"), Value::String(String::from("line\n\"quoted\" ✓"))),
+			(String::from("b"), Value::Number(String::from("-1.5e3"))),
+			(
+				String::from("c"),
+				Value::Array(vec![Value::Bool(false), Value::Null, Value::Object(vec![])]),
+			),
+		]);
+		assert_eq!(Value::parse(value.to_string().as_str()).unwrap(), value);
+	}
+
+	#[test]
+	fn parse_unicode_escapes() {
+		assert_eq!(
+			Value::parse(r#""\u00e9\ud83d\ude00\/""#).unwrap(),
+			Value::String(String::from("é😀/"))
+		);
+	}
+
+	#[test]
+	fn get_and_accessors() {
+		let value = Value::parse(r#"{"a": "b", "c": [1]}"#).unwrap();
+		assert_eq!(value.get("a").and_then(Value::as_str), Some("b"));
+		assert_eq!(value.get("c").and_then(Value::as_array).map(<[Value]>::len), Some(1));
+		assert!(value.get("d").is_none());
+		assert!(Value::Null.get("a").is_none());
+	}
+
+	#[rstest]
+	#[case::empty("", "Unexpected end of the document on line 1")]
+	#[case::trailing("{} {}", "Unexpected content after the document on line 1")]
+	#[case::missing_colon("{\"a\" 1}", "Expected ':', found '1' on line 1")]
+	#[case::key_not_string("{a: 1}", "Expected a string key on line 1")]
+	#[case::missing_comma("[1 2]", "Expected ',' or ']' on line 1")]
+	#[case::unterminated_string("\"abc", "Unterminated string on line 1")]
+	#[case::invalid_escape("\"\\q\"", "Invalid escape in string on line 1")]
+	#[case::invalid_literal("nul", "Invalid value on line 1")]
+	#[case::invalid_number("-", "Invalid number on line 1")]
+	#[case::line_number("{\n\"a\":\n?}", "Invalid value on line 3")]
+	fn parse_error(#[case] input: &str, #[case] expected: &str) {
+		assert_eq!(Value::parse(input).unwrap_err().to_string(), expected);
+	}
+
+	#[test]
+	fn parse_nested_to_max_depth() {
+		let input = format!("{}{}", "[".repeat(MAX_DEPTH), "]".repeat(MAX_DEPTH));
+		assert!(Value::parse(input.as_str()).is_ok());
+	}
+
+	#[test]
+	fn parse_nested_too_deeply() {
+		let input = format!("{}{}", "[{\"a\":".repeat(100_000), "1");
+		assert_eq!(Value::parse(input.as_str()).unwrap_err().to_string(), "Nested too deeply on line 1");
+	}
+}
@@ -0,0 +1,188 @@
+mod json;
+
+#[cfg(test)]
+mod tests;
+
+use std::{
+	fs::{read_to_string, write},
+	io::{stdout, Write},
+};
+
+use anyhow::{anyhow, Result};
+use git::{Commit, Repository};
+use todo_file::{Action, Line, TodoFile};
+
+use self::json::Value;
+use crate::{
+	exit::Exit,
+	module::ExitStatus,
+};
+
+const FORMAT_VERSION: &str = "1";
+
+// The path used to write the exported todo list to standard output.
+const STDOUT_PATH: &str = "-";
+
+fn string_value(value: &str) -> Value {
+	Value::String(String::from(value))
+}
+
+fn commit_to_value(commit: &Commit) -> Value {
+	let date = commit.authored_date().unwrap_or(*commit.committed_date());
+	Value::Object(vec![
+		(String::from("hash"), string_value(commit.hash())),
+		(
+			String::from("author"),
+			string_value(commit.author().to_string().as_str()),
+		),
+		(String::from("date"), Value::Number(date.timestamp().to_string())),
+		(
+			String::from("summary"),
+			commit.summary().as_deref().map_or(Value::Null, string_value),
+		),
+	])
+}
+
+fn line_to_value(line: &Line, repo: &Repository) -> Value {
+	let mut entries = vec![(
+		String::from("action"),
+		string_value(line.get_action().as_string().as_str()),
+	)];
+	if line.has_reference() {
+		entries.push((String::from("hash"), string_value(line.get_hash())));
+	}
+	if *line.get_action() != Action::Break {
+		entries.push((String::from("content"), string_value(line.get_content())));
+	}
+	if line.has_reference() {
+		let commit = repo.load_commit(line.get_hash()).ok();
+		entries.push((
+			String::from("commit"),
+			commit.as_ref().map_or(Value::Null, commit_to_value),
+		));
+	}
+	Value::Object(entries)
+}
+
+/// Create a JSON document of the todo list, including the details of the commit of each line.
+pub(crate) fn export(todo_file: &TodoFile, repo: &Repository) -> String {
+	let document = Value::Object(vec![
+		(String::from("version"), Value::Number(String::from(FORMAT_VERSION))),
+		(
+			String::from("lines"),
+			Value::Array(todo_file.lines_iter().map(|line| line_to_value(line, repo)).collect()),
+		),
+	]);
+	format!("{}\n", document)
+}
+
+fn value_to_line(value: &Value) -> Result<Line> {
+	let field = |name: &str| -> Result<Option<&str>> {
+		match value.get(name) {
+			None | Some(&Value::Null) => Ok(None),
+			Some(field) => {
+				let field = field
+					.as_str()
+					.ok_or_else(|| anyhow!("The \"{}\" field must be a string", name))?;
+				if field.contains('\n') || field.contains('\r') {
+					return Err(anyhow!("The \"{}\" field cannot contain a new line", name));
+				}
+				Ok(Some(field))
+			},
+		}
+	};
+	let action = Action::try_from(field("action")?.ok_or_else(|| anyhow!("The \"action\" field is missing"))?)?;
+	let content = field("content")?.unwrap_or("");
+	let text = if action.is_static() {
+		format!("{} {}", action.as_string(), content)
+	}
+	else {
+		let hash = field("hash")?.ok_or_else(|| anyhow!("The \"hash\" field is missing"))?;
+		format!("{} {} {}", action.as_string(), hash, content)
+	};
+	let line = Line::new(text.trim_end())?;
+	Ok(line)
+}
+
+fn is_same_commit(hash: &str, other: &str) -> bool {
+	hash.starts_with(other) || other.starts_with(hash)
+}
+
+/// Replace the lines of the todo file with the lines of a JSON document, that was created with `export`. The
+/// document may reorder, remove and change the lines, but cannot add commits that are not in the todo list.
+pub(crate) fn import(todo_file: &mut TodoFile, contents: &str) -> Result<()> {
+	let document = Value::parse(contents)?;
+	match document.get("version") {
+		Some(&Value::Number(ref version)) if version == FORMAT_VERSION => {},
+		Some(version) => return Err(anyhow!("Unsupported version: {}", version)),
+		None => return Err(anyhow!("The \"version\" field is missing")),
+	}
+	let values = document
+		.get("lines")
+		.and_then(Value::as_array)
+		.ok_or_else(|| anyhow!("The \"lines\" field must be an array"))?;
+	let lines = values
+		.iter()
+		.map(|value| {
+			let line = value_to_line(value)?;
+			if line.has_reference()
+				&& !todo_file
+					.lines_iter()
+					.any(|l| l.has_reference() && is_same_commit(l.get_hash(), line.get_hash()))
+			{
+				return Err(anyhow!("The commit {} is not in the todo list", line.get_hash()));
+			}
+			Ok(line)
+		})
+		.enumerate()
+		.map(|(index, line)| line.map_err(|err| err.context(format!("Line {}", index + 1))))
+		.collect::<Result<Vec<Line>>>()?;
+	let original_lines = todo_file.get_lines_owned();
+	todo_file.set_lines(lines);
+	if let Err(err) = todo_file.validate() {
+		todo_file.set_lines(original_lines);
+		return Err(anyhow!(err));
+	}
+	Ok(())
+}
+
+/// Write the todo list as a JSON document to the file, or to standard output when the path is `-`.
+pub(crate) fn export_file(todo_file: &TodoFile, repo: &Repository, path: &str) -> Exit {
+	let document = export(todo_file, repo);
+	let result = if path == STDOUT_PATH {
+		stdout().lock().write_all(document.as_bytes())
+	}
+	else {
+		write(path, document)
+	};
+	match result {
+		Ok(_) => {
+			log::info!(target: "todo", "Exported the todo list to {}", path);
+			Exit::from(ExitStatus::Good)
+		},
+		Err(err) => {
+			Exit::new(
+				ExitStatus::FileWriteError,
+				format!("Unable to export to {}: {}", path, err).as_str(),
+			)
+		},
+	}
+}
+
+/// Replace the lines of the todo file with the lines of the JSON document in the file.
+pub(crate) fn import_file(todo_file: &mut TodoFile, path: &str) -> Result<(), Exit> {
+	let contents = read_to_string(path).map_err(|err| {
+		Exit::new(
+			ExitStatus::FileReadError,
+			format!("Unable to read {}: {}", path, err).as_str(),
+		)
+	})?;
+	import(todo_file, contents.as_str()).map_err(|err| {
+		Exit::new(
+			ExitStatus::StateError,
+			format!("Unable to import {}: {:#}", path, err).as_str(),
+		)
+	})?;
+	log::info!(target: "todo", "Imported {} lines from {}", todo_file.lines_iter().count(), path);
+	Ok(())
+}
@@ -0,0 +1,154 @@
+use git::testutil::{create_commit, head_id, with_temp_repository};
+use rstest::rstest;
+
+use super::*;
+
+fn create_todo_file(lines: &[&str]) -> TodoFile {
+	let mut todo_file = TodoFile::new("git-rebase-todo", 1, "#");
+	todo_file.set_lines(lines.iter().map(|line| Line::new(line).unwrap()).collect());
+	todo_file
+}
+
+fn todo_lines(todo_file: &TodoFile) -> Vec<String> {
+	todo_file.lines_iter().map(Line::to_text).collect()
+}
+
+const LINES: &[&str] = &["pick aaa111 first", "pick bbb222 second", "exec cargo test"];
+
+#[test]
+fn export_with_commit() {
+	with_temp_repository(|repository| {
+		create_commit(&repository, None)?;
+		let hash = head_id(&repository, "main").to_string();
+		let todo_file = create_todo_file(&[format!("pick {} title", &hash[0..7]).as_str(), "break"]);
+		assert_eq!(
+			export(&todo_file, &repository),
+			format!(
+				"{{\n  \"version\": 1,\n  \"lines\": [\n    {{\n      \"action\": \"pick\",\n      \"hash\": \
+				 \"{}\",\n      \"content\": \"title\",\n      \"commit\": {{\n        \"hash\": \"{}\",\n        \
+				 \"author\": \"Author <author@example.com>\",\n        \"date\": 1609459200,\n        \"summary\": \
+				 \"title\"\n      }}\n    }},\n    {{\n      \"action\": \"break\"\n    }}\n  ]\n}}\n",
+				&hash[0..7],
+				hash
+			)
+		);
+		Ok(())
+	});
+}
+
+#[test]
+fn export_missing_commit() {
+	with_temp_repository(|repository| {
+		let todo_file = create_todo_file(&["drop fffffff gone", "exec echo \"foo\""]);
+		assert_eq!(
+			export(&todo_file, &repository),
+			"{\n  \"version\": 1,\n  \"lines\": [\n    {\n      \"action\": \"drop\",\n      \
+			 \"hash\": \"fffffff\",\n      \"content\": \"gone\",\n      \"commit\": null\n    },\n    {\n      \
+			 \"action\": \"exec\",\n      \"content\": \"echo \\\"foo\\\"\"\n    }\n  ]\n}\n"
+		);
+		Ok(())
+	});
+}
+
+#[test]
+fn import_lines() {
+	let mut todo_file = create_todo_file(LINES);
+	import(
+		&mut todo_file,
+		r#"{
+			"version": 1,
+			"lines": [
+				{"action": "pick", "hash": "bbb222", "content": "second", "commit": null},
+				{"action": "fixup", "hash": "aaa1", "content": "first"},
+				{"action": "break"},
+				{"action": "exec", "content": "cargo build"}
+			]
+		}"#,
+	)
+	.unwrap();
+	assert_eq!(todo_lines(&todo_file), vec![
+		"pick bbb222 second",
+		"fixup aaa1 first",
+		"break",
+		"exec cargo build",
+	]);
+}
+
+#[test]
+fn import_export_round_trip() {
+	with_temp_repository(|repository| {
+		let edited = create_todo_file(&["exec cargo test", "pick bbb222 second", "squash aaa111 first"]);
+		let document = export(&edited, &repository);
+		let mut todo_file = create_todo_file(LINES);
+		import(&mut todo_file, document.as_str()).unwrap();
+		assert_eq!(todo_lines(&todo_file), todo_lines(&edited));
+		Ok(())
+	});
+}
+
+#[rstest]
+#[case::invalid_json("{", "Expected a string key on line 1")]
+#[case::missing_version(r#"{"lines": []}"#, "The \"version\" field is missing")]
+#[case::unsupported_version(r#"{"version": 2, "lines": []}"#, "Unsupported version: 2")]
+#[case::missing_lines(r#"{"version": 1}"#, "The \"lines\" field must be an array")]
+#[case::missing_action(
+	r#"{"version": 1, "lines": [{"hash": "aaa111"}]}"#,
+	"Line 1: The \"action\" field is missing"
+)]
+#[case::invalid_action(
+	r#"{"version": 1, "lines": [{"action": "jump", "hash": "aaa111"}]}"#,
+	"Line 1: Invalid action: jump"
+)]
+#[case::field_not_string(
+	r#"{"version": 1, "lines": [{"action": "pick", "hash": 111}]}"#,
+	"Line 1: The \"hash\" field must be a string"
+)]
+#[case::new_line(
+	r#"{"version": 1, "lines": [{"action": "exec", "content": "a\nb"}]}"#,
+	"Line 1: The \"content\" field cannot contain a new line"
+)]
+#[case::missing_hash(
+	r#"{"version": 1, "lines": [{"action": "break"}, {"action": "pick", "content": "first"}]}"#,
+	"Line 2: The \"hash\" field is missing"
+)]
+#[case::unknown_commit(
+	r#"{"version": 1, "lines": [{"action": "pick", "hash": "ccc333"}]}"#,
+	"Line 1: The commit ccc333 is not in the todo list"
+)]
+#[case::first_commit_fixup(
+	r#"{"version": 1, "lines": [{"action": "fixup", "hash": "bbb222"}, {"action": "pick", "hash": "aaa111"}]}"#,
+	"The first commit, bbb222, cannot be a fixup, since there is no previous commit"
+)]
+fn import_error(#[case] document: &str, #[case] expected: &str) {
+	let mut todo_file = create_todo_file(LINES);
+	assert_eq!(format!("{:#}", import(&mut todo_file, document).unwrap_err()), expected);
+	assert_eq!(todo_lines(&todo_file), LINES);
+}
+
+#[test]
+fn import_file_missing() {
+	let mut todo_file = create_todo_file(LINES);
+	assert_eq!(
+		import_file(&mut todo_file, "does-not-exist.json").unwrap_err(),
+		Exit::new(
+			ExitStatus::FileReadError,
+			"Unable to read does-not-exist.json: No such file or directory (os error 2)"
+		)
+	);
+}
+
+#[test]
+fn export_file_invalid_path() {
+	with_temp_repository(|repository| {
+		let todo_file = create_todo_file(LINES);
+		let exit = export_file(&todo_file, &repository, "does-not-exist/plan.json");
+		assert_eq!(
+			exit,
+			Exit::new(
+				ExitStatus::FileWriteError,
+				"Unable to export to does-not-exist/plan.json: No such file or directory (os error 2)"
+			)
+		);
+		Ok(())
+	});
+}
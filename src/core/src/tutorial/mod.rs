@@ -0,0 +1,58 @@
+mod steps;
+
+use std::iter::once;
+
+use git::SandboxCommit;
+
+pub(crate) use self::steps::Tutorial;
+use crate::{arguments::Args, exit::Exit, run::start_logging, sandbox::Sandbox};
+
+const MOVE_COMMIT: &str = "Update the README";
+const SQUASH_TARGET_COMMIT: &str = "Add the login page";
+const SQUASH_COMMIT: &str = "Fix a typo in the login page";
+const REWORD_COMMIT: &str = "Add the settings page";
+const DEBUG_COMMITS: [&str; 2] = ["Add debugging output", "Remove debugging output"];
+
+const BASE_COMMIT: (&str, &[(&str, &str)]) = ("Initial commit", &[("README.md", "# Example\n")]);
+
+// the commits of the todo list, in the order that they are picked
+const TODO_COMMITS: [(&str, &[(&str, &str)]); 6] = [
+	(SQUASH_TARGET_COMMIT, &[("login.html", "<h1>Lgoin</h1>\n")]),
+	(SQUASH_COMMIT, &[("login.html", "<h1>Login</h1>\n")]),
+	(REWORD_COMMIT, &[("settings.html", "<h1>Settings</h1>\n")]),
+	(DEBUG_COMMITS[0], &[("login.html", "<h1>Login</h1>\n<script>console.log('login');</script>\n")]),
+	(DEBUG_COMMITS[1], &[("login.html", "<h1>Login</h1>\n")]),
+	(
+		MOVE_COMMIT,
+		&[("README.md", "# Example\n\nA login page and a settings page.\n")],
+	),
+];
+
+fn sandbox_commit(&(message, files): &(&str, &[(&str, &str)])) -> SandboxCommit {
+	files
+		.iter()
+		.fold(SandboxCommit::new(message), |commit, &(path, content)| commit.file(path, content))
+}
+
+/// Open the list with the todo list of a temporary repository, with prompts that walk through the main actions, so
+/// that the tool can be tried without a risk of changing a real repository.
+pub(crate) fn run(args: &Args) -> Exit {
+	if let Err(exit) = start_logging(args) {
+		return exit;
+	}
+	let commits = once(&BASE_COMMIT)
+		.chain(TODO_COMMITS.iter())
+		.map(sandbox_commit)
+		.collect::<Vec<SandboxCommit>>();
+	let sandbox = match Sandbox::new("tutorial", commits.as_slice()) {
+		Ok(sandbox) => sandbox,
+		Err(exit) => return exit,
+	};
+	let todo_contents = TODO_COMMITS
+		.iter()
+		.zip(sandbox.hashes().iter().skip(1))
+		.map(|(&(message, _), hash)| format!("pick {} {}", hash, message))
+		.collect::<Vec<String>>()
+		.join("\n");
+	sandbox.run(args, todo_contents.as_str())
+}
@@ -0,0 +1,245 @@
+use config::KeyBindings;
+use display::DisplayColor;
+use todo_file::{Action, TodoFile};
+use view::{LineSegment, ViewLine};
+
+use super::{DEBUG_COMMITS, MOVE_COMMIT, REWORD_COMMIT, SQUASH_COMMIT, SQUASH_TARGET_COMMIT};
+use crate::i18n::translate_format;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Goal {
+	Move,
+	Squash,
+	Reword,
+	VisualDrop,
+	Finish,
+}
+
+impl Goal {
+	fn is_reached(self, todo_file: &TodoFile, used_visual_mode: bool) -> bool {
+		let find = |content: &str| todo_file.lines_iter().position(|line| line.get_content() == content);
+		let action_of = |content: &str| {
+			find(content)
+				.and_then(|index| todo_file.get_line(index))
+				.map(|line| *line.get_action())
+		};
+		match self {
+			Self::Move => find(MOVE_COMMIT) == Some(0),
+			Self::Squash => {
+				matches!(action_of(SQUASH_COMMIT), Some(Action::Fixup | Action::Squash))
+					&& find(SQUASH_COMMIT)
+						.and_then(|index| index.checked_sub(1))
+						.and_then(|index| todo_file.get_line(index))
+						.map_or(false, |line| line.get_content() == SQUASH_TARGET_COMMIT)
+			},
+			Self::Reword => action_of(REWORD_COMMIT) == Some(Action::Reword),
+			Self::VisualDrop => {
+				used_visual_mode
+					&& DEBUG_COMMITS
+						.iter()
+						.all(|&content| action_of(content) == Some(Action::Drop))
+			},
+			Self::Finish => false,
+		}
+	}
+}
+
+#[derive(Debug)]
+struct Step {
+	goal: Goal,
+	prompt: String,
+}
+
+/// The steps of the tutorial, each with a prompt that is shown above the list until the goal of the step is reached.
+#[derive(Debug)]
+pub(crate) struct Tutorial {
+	current: usize,
+	steps: Vec<Step>,
+	used_visual_mode: bool,
+}
+
+impl Tutorial {
+	pub(crate) fn new(key_bindings: &KeyBindings) -> Self {
+		let key = |keys: &[String]| keys.first().cloned().unwrap_or_default();
+		let steps = vec![
+			Step {
+				goal: Goal::Move,
+				prompt: translate_format(
+					"Move \"{}\" to the top of the list: select it with {}, then move it up with {}",
+					&[&MOVE_COMMIT, &key(&key_bindings.move_down), &key(&key_bindings.move_selection_up)],
+				),
+			},
+			Step {
+				goal: Goal::Squash,
+				prompt: translate_format(
+					"Squash \"{}\" into the commit above it: select it and press {}, or {} to also discard its message",
+					&[
+						&SQUASH_COMMIT,
+						&key(&key_bindings.action_squash),
+						&key(&key_bindings.action_fixup),
+					],
+				),
+			},
+			Step {
+				goal: Goal::Reword,
+				prompt: translate_format(
+					"Reword \"{}\": select it and press {}, to change the message of the commit during the rebase",
+					&[&REWORD_COMMIT, &key(&key_bindings.action_reword)],
+				),
+			},
+			Step {
+				goal: Goal::VisualDrop,
+				prompt: translate_format(
+					"Drop both of the debugging commits at once: press {} to start visual mode, extend the selection \
+					 with {}, then press {}",
+					&[
+						&key(&key_bindings.toggle_visual_mode),
+						&key(&key_bindings.move_down),
+						&key(&key_bindings.action_drop),
+					],
+				),
+			},
+			Step {
+				goal: Goal::Finish,
+				prompt: translate_format(
+					"Done! Press {} to finish the tutorial, or {} to see all of the keys. No repository is changed.",
+					&[&key(&key_bindings.rebase), &key(&key_bindings.help)],
+				),
+			},
+		];
+		Self {
+			current: 0,
+			steps,
+			used_visual_mode: false,
+		}
+	}
+
+	/// Move to the next step once the goal of the current step has been reached, which can be more than one step when
+	/// the goals of the later steps were reached first.
+	pub(crate) fn update(&mut self, todo_file: &TodoFile, is_visual_mode: bool) {
+		self.used_visual_mode |= is_visual_mode;
+		while let Some(step) = self.steps.get(self.current) {
+			if !step.goal.is_reached(todo_file, self.used_visual_mode) {
+				break;
+			}
+			self.current += 1;
+		}
+	}
+
+	pub(crate) fn get_line(&self) -> Option<ViewLine> {
+		self.steps.get(self.current).map(|step| {
+			ViewLine::from(vec![
+				LineSegment::new_with_color(
+					translate_format("Tutorial {}/{}: ", &[&(self.current + 1), &self.steps.len()]).as_str(),
+					DisplayColor::IndicatorColor,
+				),
+				LineSegment::new(step.prompt.as_str()),
+			])
+		})
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use todo_file::{EditContext, Line};
+
+	use super::*;
+
+	fn create_todo_file() -> TodoFile {
+		let mut todo_file = TodoFile::new("git-rebase-todo", 1, "#");
+		todo_file.set_lines(
+			super::super::TODO_COMMITS
+				.iter()
+				.map(|&(message, _)| Line::new(format!("pick aaa {}", message).as_str()).unwrap())
+				.collect(),
+		);
+		todo_file
+	}
+
+	fn index_of(todo_file: &TodoFile, content: &str) -> usize {
+		todo_file
+			.lines_iter()
+			.position(|line| line.get_content() == content)
+			.unwrap()
+	}
+
+	fn set_action(todo_file: &mut TodoFile, content: &str, action: Action) {
+		let index = index_of(todo_file, content);
+		todo_file.update_range(index, index, &EditContext::new().action(action));
+	}
+
+	fn step_of(tutorial: &Tutorial) -> Goal {
+		tutorial.steps[tutorial.current].goal
+	}
+
+	#[test]
+	fn starts_with_move() {
+		let mut tutorial = Tutorial::new(&KeyBindings::new());
+		tutorial.update(&create_todo_file(), false);
+		assert_eq!(step_of(&tutorial), Goal::Move);
+	}
+
+	#[test]
+	fn complete_all_steps() {
+		let mut tutorial = Tutorial::new(&KeyBindings::new());
+		let mut todo_file = create_todo_file();
+
+		let index = index_of(&todo_file, MOVE_COMMIT);
+		todo_file.set_selected_line_index(index);
+		for _ in 0..index {
+			let _ = todo_file.swap_range_up(todo_file.get_selected_line_index(), todo_file.get_selected_line_index());
+			todo_file.set_selected_line_index(todo_file.get_selected_line_index() - 1);
+		}
+		tutorial.update(&todo_file, false);
+		assert_eq!(step_of(&tutorial), Goal::Squash);
+
+		set_action(&mut todo_file, SQUASH_COMMIT, Action::Fixup);
+		tutorial.update(&todo_file, false);
+		assert_eq!(step_of(&tutorial), Goal::Reword);
+
+		set_action(&mut todo_file, REWORD_COMMIT, Action::Reword);
+		tutorial.update(&todo_file, false);
+		assert_eq!(step_of(&tutorial), Goal::VisualDrop);
+
+		for content in DEBUG_COMMITS {
+			set_action(&mut todo_file, content, Action::Drop);
+		}
+		tutorial.update(&todo_file, true);
+		assert_eq!(step_of(&tutorial), Goal::Finish);
+	}
+
+	#[test]
+	fn visual_drop_requires_visual_mode() {
+		let mut tutorial = Tutorial::new(&KeyBindings::new());
+		let mut todo_file = create_todo_file();
+		tutorial.current = 3;
+		for content in DEBUG_COMMITS {
+			set_action(&mut todo_file, content, Action::Drop);
+		}
+		tutorial.update(&todo_file, false);
+		assert_eq!(step_of(&tutorial), Goal::VisualDrop);
+	}
+
+	#[test]
+	fn squash_requires_target_above() {
+		let mut tutorial = Tutorial::new(&KeyBindings::new());
+		let mut todo_file = create_todo_file();
+		tutorial.current = 1;
+		let index = index_of(&todo_file, SQUASH_COMMIT);
+		let _ = todo_file.swap_range_up(index, index);
+		set_action(&mut todo_file, SQUASH_COMMIT, Action::Squash);
+		tutorial.update(&todo_file, false);
+		assert_eq!(step_of(&tutorial), Goal::Squash);
+	}
+
+	#[test]
+	fn skips_steps_already_reached() {
+		let mut tutorial = Tutorial::new(&KeyBindings::new());
+		let mut todo_file = create_todo_file();
+		tutorial.current = 1;
+		set_action(&mut todo_file, SQUASH_COMMIT, Action::Squash);
+		set_action(&mut todo_file, REWORD_COMMIT, Action::Reword);
+		tutorial.update(&todo_file, false);
+		assert_eq!(step_of(&tutorial), Goal::VisualDrop);
+	}
+}
@@ -0,0 +1,186 @@
+use std::{
+	fs::{read_to_string, File},
+	io::Write,
+	path::{Path, PathBuf},
+};
+
+use anyhow::{anyhow, Result};
+use todo_file::TodoFile;
+
+const UI_STATE_FILE_NAME: &str = "interactive-rebase-tool-state";
+
+const PATH_FILTER_KEY: &str = "pathFilter";
+const SELECTED_COMMIT_KEY: &str = "selectedCommit";
+const SELECTED_LINE_KEY: &str = "selectedLine";
+const SHOW_STASH_INFO_KEY: &str = "showStashInfo";
+
+/// The state of the interface of the list of a repository, that is remembered between the rebases of the repository,
+/// in a file in the git directory of the repository.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub(crate) struct UiState {
+	path_filter: Option<String>,
+	selected_commit: Option<String>,
+	selected_line: usize,
+	show_stash_info: bool,
+}
+
+impl UiState {
+	/// Get the path of the file of the state, in a git directory.
+	pub(crate) fn path(git_directory: &Path) -> PathBuf {
+		git_directory.join(UI_STATE_FILE_NAME)
+	}
+
+	/// Load the state from a file, with the lines of the file as a key and a value. A file that does not exist, or
+	/// cannot be read, is an empty state, and the lines that are not known or not valid are ignored, so that a file
+	/// written by another version is still read.
+	pub(crate) fn load(path: &Path) -> Self {
+		let mut ui_state = Self::default();
+		let contents = match read_to_string(path) {
+			Ok(contents) => contents,
+			Err(_) => return ui_state,
+		};
+		for (key, value) in contents.lines().filter_map(|line| line.split_once(' ')) {
+			match key {
+				PATH_FILTER_KEY => ui_state.path_filter = Some(String::from(value)),
+				SELECTED_COMMIT_KEY => ui_state.selected_commit = Some(String::from(value)),
+				SELECTED_LINE_KEY => ui_state.selected_line = value.parse().unwrap_or_default(),
+				SHOW_STASH_INFO_KEY => ui_state.show_stash_info = value == "true",
+				_ => {},
+			}
+		}
+		ui_state
+	}
+
+	/// Write the state to a file.
+	pub(crate) fn save(&self, path: &Path) -> Result<()> {
+		let mut lines = vec![
+			format!("{} {}", SELECTED_LINE_KEY, self.selected_line),
+			format!("{} {}", SHOW_STASH_INFO_KEY, self.show_stash_info),
+		];
+		if let Some(ref selected_commit) = self.selected_commit {
+			lines.push(format!("{} {}", SELECTED_COMMIT_KEY, selected_commit));
+		}
+		if let Some(ref path_filter) = self.path_filter {
+			lines.push(format!("{} {}", PATH_FILTER_KEY, path_filter));
+		}
+		let mut file =
+			File::create(path).map_err(|err| anyhow!(err).context(format!("Error opening file: {}", path.display())))?;
+		writeln!(file, "{}", lines.join("\n"))
+			.map_err(|err| anyhow!(err).context(format!("Error writing file: {}", path.display())))
+	}
+
+	pub(crate) fn path_filter(&self) -> Option<&str> {
+		self.path_filter.as_deref()
+	}
+
+	pub(crate) fn set_path_filter(&mut self, path_filter: Option<&str>) {
+		self.path_filter = path_filter.map(String::from);
+	}
+
+	pub(crate) const fn show_stash_info(&self) -> bool {
+		self.show_stash_info
+	}
+
+	pub(crate) fn set_show_stash_info(&mut self, show_stash_info: bool) {
+		self.show_stash_info = show_stash_info;
+	}
+
+	/// Select the line that was selected in the todo file, which is the line of the commit that was selected when the
+	/// commit is still in the list, such as after the rebase is started again, and otherwise the line at the index
+	/// that was selected.
+	pub(crate) fn restore_selected_line(&self, todo_file: &mut TodoFile) {
+		let commit_index = self.selected_commit.as_ref().and_then(|hash| {
+			todo_file
+				.lines_iter()
+				.position(|line| line.has_reference() && line.get_hash() == hash.as_str())
+		});
+		todo_file.set_selected_line_index(commit_index.unwrap_or(self.selected_line));
+	}
+
+	/// Remember the selected line of the todo file, and the commit of the line.
+	pub(crate) fn set_selected_line(&mut self, todo_file: &TodoFile) {
+		self.selected_line = todo_file.get_selected_line_index();
+		self.selected_commit = todo_file
+			.get_selected_line()
+			.filter(|line| line.has_reference())
+			.map(|line| String::from(line.get_hash()));
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::fs::write;
+
+	use tempfile::tempdir;
+	use todo_file::Line;
+
+	use super::*;
+
+	fn create_todo_file(lines: &[&str]) -> TodoFile {
+		let mut todo_file = TodoFile::new("git-rebase-todo", 1, "#");
+		todo_file.set_lines(lines.iter().map(|line| Line::new(line).unwrap()).collect());
+		todo_file
+	}
+
+	#[test]
+	fn save_and_load() {
+		let directory = tempdir().unwrap();
+		let path = UiState::path(directory.path());
+		let todo_file = create_todo_file(&["pick aaa c1", "pick bbb c2"]);
+		let mut ui_state = UiState::default();
+		ui_state.set_path_filter(Some("src/**/*.rs"));
+		ui_state.set_show_stash_info(true);
+		ui_state.set_selected_line(&todo_file);
+		ui_state.save(path.as_path()).unwrap();
+		assert_eq!(UiState::load(path.as_path()), ui_state);
+	}
+
+	#[test]
+	fn load_missing_file() {
+		let directory = tempdir().unwrap();
+		assert_eq!(UiState::load(UiState::path(directory.path()).as_path()), UiState::default());
+	}
+
+	#[test]
+	fn load_unknown_and_invalid_lines() {
+		let directory = tempdir().unwrap();
+		let path = UiState::path(directory.path());
+		write(path.as_path(), "selectedLine many\nlayout wide\nshowStashInfo true\n").unwrap();
+		let ui_state = UiState::load(path.as_path());
+		assert!(ui_state.show_stash_info());
+		assert_eq!(ui_state.path_filter(), None);
+	}
+
+	#[test]
+	fn save_error() {
+		let directory = tempdir().unwrap();
+		let path = directory.path().join("missing").join(UI_STATE_FILE_NAME);
+		assert!(UiState::default()
+			.save(path.as_path())
+			.unwrap_err()
+			.to_string()
+			.starts_with("Error opening file: "));
+	}
+
+	#[test]
+	fn restore_selected_line_commit() {
+		let mut ui_state = UiState::default();
+		let mut todo_file = create_todo_file(&["pick aaa c1", "pick bbb c2", "pick ccc c3"]);
+		todo_file.set_selected_line_index(1);
+		ui_state.set_selected_line(&todo_file);
+		let mut todo_file = create_todo_file(&["pick ccc c3", "pick aaa c1", "exec make", "pick bbb c2"]);
+		ui_state.restore_selected_line(&mut todo_file);
+		assert_eq!(todo_file.get_selected_line_index(), 3);
+	}
+
+	#[test]
+	fn restore_selected_line_index() {
+		let mut ui_state = UiState::default();
+		let mut todo_file = create_todo_file(&["pick aaa c1", "exec make", "pick bbb c2"]);
+		todo_file.set_selected_line_index(1);
+		ui_state.set_selected_line(&todo_file);
+		let mut todo_file = create_todo_file(&["pick ccc c3", "pick ddd c4"]);
+		ui_state.restore_selected_line(&mut todo_file);
+		assert_eq!(todo_file.get_selected_line_index(), 1);
+	}
+}
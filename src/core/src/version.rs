@@ -5,7 +5,7 @@ pub(crate) const VERSION: &str = env!("CARGO_PKG_VERSION");
 #[cfg(feature = "dev")]
 pub(crate) const VERSION: &str = concat!(env!("CARGO_PKG_VERSION"), "-dev");
 
-fn build_version() -> String {
+pub(crate) fn build_version() -> String {
 	let mut parts = vec![];
 
 	if let Some(hash) = option_env!("GIRT_BUILD_GIT_HASH") {
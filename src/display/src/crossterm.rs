@@ -1,13 +1,13 @@
 use std::{
 	io,
-	io::{stdout, BufWriter, Stdout, Write},
+	io::{stdin, stdout, BufWriter, Stdout, Write},
 	time::Duration,
 };
 
 use anyhow::{anyhow, Error, Result};
 use crossterm::{
 	cursor::{Hide, MoveTo, MoveToColumn, MoveToNextLine, Show},
-	event::{poll, read, DisableMouseCapture, EnableMouseCapture, Event},
+	event::{poll, read, DisableBracketedPaste, DisableMouseCapture, EnableBracketedPaste, EnableMouseCapture, Event},
 	style::{available_color_count, Attribute, Colors, Print, ResetColor, SetAttribute, SetColors},
 	terminal::{
 		disable_raw_mode,
@@ -20,11 +20,17 @@ use crossterm::{
 		EnterAlternateScreen,
 		LeaveAlternateScreen,
 	},
+	tty::IsTty,
 	Command,
 	QueueableCommand,
 };
 
-use super::{color_mode::ColorMode, size::Size, tui::Tui, utils::detect_color_mode};
+use super::{
+	color_mode::ColorMode,
+	size::Size,
+	tui::Tui,
+	utils::{detect_color_mode, normalize_resize_event},
+};
 
 /// A thin wrapper over the [Crossterm library](https://github.com/crossterm-rs/crossterm).
 #[derive(Debug)]
@@ -103,7 +109,9 @@ impl Tui for CrossTerm {
 	#[inline]
 	fn read_event() -> Result<Option<Event>> {
 		if poll(Duration::from_millis(20)).unwrap_or(false) {
-			read().map(Some).map_err(Self::map_err)
+			read()
+				.map(|event| Some(normalize_resize_event(event, size().ok())))
+				.map_err(Self::map_err)
 		}
 		else {
 			Ok(None)
@@ -128,18 +136,31 @@ impl Tui for CrossTerm {
 		self.queue_command(MoveToNextLine(1))
 	}
 
+	#[inline]
+	fn move_to_line(&mut self, y: u16) -> Result<()> {
+		self.queue_command(MoveTo(0, y))
+	}
+
+	#[inline]
+	fn clear_until_end_of_line(&mut self) -> Result<()> {
+		self.queue_command(Clear(ClearType::UntilNewLine))
+	}
+
 	#[inline]
 	fn start(&mut self) -> Result<()> {
 		self.queue_command(EnterAlternateScreen)?;
 		self.queue_command(DisableLineWrap)?;
 		self.queue_command(Hide)?;
 		self.queue_command(EnableMouseCapture)?;
+		// a terminal that does not support bracketed paste sends the pasted text as key presses
+		let _result = self.queue_command(EnableBracketedPaste);
 		enable_raw_mode().map_err(Self::map_err)?;
 		self.flush()
 	}
 
 	#[inline]
 	fn end(&mut self) -> Result<()> {
+		let _result = self.queue_command(DisableBracketedPaste);
 		self.queue_command(DisableMouseCapture)?;
 		self.queue_command(Show)?;
 		self.queue_command(EnableLineWrap)?;
@@ -156,10 +177,27 @@ impl CrossTerm {
 	pub fn new() -> Self {
 		Self {
 			window: BufWriter::new(stdout()),
-			color_mode: detect_color_mode(available_color_count()),
+			color_mode: detect_color_mode(available_color_count(), Self::console_ansi_support()),
 		}
 	}
 
+	/// Are both the input and output connected to a terminal, which is not the case when run from a script.
+	#[inline]
+	#[must_use]
+	pub fn is_interactive() -> bool {
+		stdin().is_tty() && stdout().is_tty()
+	}
+
+	#[cfg(windows)]
+	fn console_ansi_support() -> Option<bool> {
+		Some(crossterm::ansi_support::supports_ansi())
+	}
+
+	#[cfg(not(windows))]
+	const fn console_ansi_support() -> Option<bool> {
+		None
+	}
+
 	#[allow(clippy::needless_pass_by_value)]
 	fn map_err(err: io::Error) -> Error {
 		anyhow!("{:#}", err).context("Unexpected Error")
@@ -124,7 +124,7 @@ use ::crossterm::style::{Color, Colors};
 use anyhow::Result;
 use config::Theme;
 
-use self::utils::register_selectable_color_pairs;
+use self::utils::{encode_base64, register_selectable_color_pairs};
 pub use self::{color_mode::ColorMode, crossterm::CrossTerm, display_color::DisplayColor, size::Size, tui::Tui};
 
 /// A high level interface to the terminal display.
@@ -155,137 +155,147 @@ impl<T: Tui> Display<T> {
 	/// Create a new display instance.
 	#[inline]
 	pub fn new(tui: T, theme: &Theme) -> Self {
-		let color_mode = tui.get_color_mode();
-		let normal = register_selectable_color_pairs(
+		let unset = (
+			Colors::new(Color::Reset, Color::Reset),
+			Colors::new(Color::Reset, Color::Reset),
+		);
+		let mut display = Self {
+			action_break: unset,
+			action_drop: unset,
+			action_edit: unset,
+			action_exec: unset,
+			action_fixup: unset,
+			action_label: unset,
+			action_merge: unset,
+			action_pick: unset,
+			action_reset: unset,
+			action_reword: unset,
+			action_squash: unset,
+			tui,
+			diff_add: unset,
+			diff_change: unset,
+			diff_context: unset,
+			diff_remove: unset,
+			diff_whitespace: unset,
+			indicator: unset,
+			normal: unset,
+		};
+		display.set_theme(theme);
+		display
+	}
+
+	/// Update the colors used to draw to the terminal interface from a theme.
+	#[inline]
+	pub fn set_theme(&mut self, theme: &Theme) {
+		let color_mode = self.tui.get_color_mode();
+		self.normal = register_selectable_color_pairs(
 			color_mode,
 			theme.color_foreground,
 			theme.color_background,
 			theme.color_selected_background,
 		);
-		let indicator = register_selectable_color_pairs(
+		self.indicator = register_selectable_color_pairs(
 			color_mode,
 			theme.color_indicator,
 			theme.color_background,
 			theme.color_selected_background,
 		);
-		let action_break = register_selectable_color_pairs(
+		self.action_break = register_selectable_color_pairs(
 			color_mode,
 			theme.color_action_break,
 			theme.color_background,
 			theme.color_selected_background,
 		);
-		let action_drop = register_selectable_color_pairs(
+		self.action_drop = register_selectable_color_pairs(
 			color_mode,
 			theme.color_action_drop,
 			theme.color_background,
 			theme.color_selected_background,
 		);
-		let action_edit = register_selectable_color_pairs(
+		self.action_edit = register_selectable_color_pairs(
 			color_mode,
 			theme.color_action_edit,
 			theme.color_background,
 			theme.color_selected_background,
 		);
-		let action_exec = register_selectable_color_pairs(
+		self.action_exec = register_selectable_color_pairs(
 			color_mode,
 			theme.color_action_exec,
 			theme.color_background,
 			theme.color_selected_background,
 		);
-		let action_fixup = register_selectable_color_pairs(
+		self.action_fixup = register_selectable_color_pairs(
 			color_mode,
 			theme.color_action_fixup,
 			theme.color_background,
 			theme.color_selected_background,
 		);
-		let action_pick = register_selectable_color_pairs(
+		self.action_pick = register_selectable_color_pairs(
 			color_mode,
 			theme.color_action_pick,
 			theme.color_background,
 			theme.color_selected_background,
 		);
-		let action_reword = register_selectable_color_pairs(
+		self.action_reword = register_selectable_color_pairs(
 			color_mode,
 			theme.color_action_reword,
 			theme.color_background,
 			theme.color_selected_background,
 		);
-		let action_squash = register_selectable_color_pairs(
+		self.action_squash = register_selectable_color_pairs(
 			color_mode,
 			theme.color_action_squash,
 			theme.color_background,
 			theme.color_selected_background,
 		);
-		let action_label = register_selectable_color_pairs(
+		self.action_label = register_selectable_color_pairs(
 			color_mode,
 			theme.color_action_label,
 			theme.color_background,
 			theme.color_selected_background,
 		);
-		let action_reset = register_selectable_color_pairs(
+		self.action_reset = register_selectable_color_pairs(
 			color_mode,
 			theme.color_action_reset,
 			theme.color_background,
 			theme.color_selected_background,
 		);
-		let action_merge = register_selectable_color_pairs(
+		self.action_merge = register_selectable_color_pairs(
 			color_mode,
 			theme.color_action_merge,
 			theme.color_background,
 			theme.color_selected_background,
 		);
-		let diff_add = register_selectable_color_pairs(
+		self.diff_add = register_selectable_color_pairs(
 			color_mode,
 			theme.color_diff_add,
 			theme.color_background,
 			theme.color_selected_background,
 		);
-		let diff_change = register_selectable_color_pairs(
+		self.diff_change = register_selectable_color_pairs(
 			color_mode,
 			theme.color_diff_change,
 			theme.color_background,
 			theme.color_selected_background,
 		);
-		let diff_remove = register_selectable_color_pairs(
+		self.diff_remove = register_selectable_color_pairs(
 			color_mode,
 			theme.color_diff_remove,
 			theme.color_background,
 			theme.color_selected_background,
 		);
-		let diff_context = register_selectable_color_pairs(
+		self.diff_context = register_selectable_color_pairs(
 			color_mode,
 			theme.color_diff_context,
 			theme.color_background,
 			theme.color_selected_background,
 		);
-		let diff_whitespace = register_selectable_color_pairs(
+		self.diff_whitespace = register_selectable_color_pairs(
 			color_mode,
 			theme.color_diff_whitespace,
 			theme.color_background,
 			theme.color_selected_background,
 		);
-
-		Self {
-			action_break,
-			action_drop,
-			action_edit,
-			action_exec,
-			action_fixup,
-			action_label,
-			action_merge,
-			action_pick,
-			action_reset,
-			action_reword,
-			action_squash,
-			tui,
-			diff_add,
-			diff_change,
-			diff_context,
-			diff_remove,
-			diff_whitespace,
-			indicator,
-			normal,
-		}
 	}
 
 	/// Draws a string of text to the terminal interface.
@@ -294,6 +304,17 @@ impl<T: Tui> Display<T> {
 		self.tui.print(s)
 	}
 
+	/// Copy text to the clipboard of the terminal, with an OSC 52 sequence. Terminals that do not support the
+	/// sequence, or that do not allow programs to set the clipboard, ignore it.
+	///
+	/// # Errors
+	/// Results in an error if the sequence cannot be written to the terminal.
+	#[inline]
+	pub fn copy_to_clipboard(&mut self, text: &str) -> Result<()> {
+		self.tui.print(format!("\x1b]52;c;{}\x07", encode_base64(text.as_bytes())).as_str())?;
+		self.tui.flush()
+	}
+
 	/// Clear the terminal interface and reset any style and color attributes.
 	#[inline]
 	pub fn clear(&mut self) -> Result<()> {
@@ -397,6 +418,18 @@ impl<T: Tui> Display<T> {
 		self.tui.move_next_line()
 	}
 
+	/// Move the cursor to the start of a line, counted from the top of the terminal interface.
+	#[inline]
+	pub fn move_to_line(&mut self, line: u16) -> Result<()> {
+		self.tui.move_to_line(line)
+	}
+
+	/// Clear the rest of the line, from the cursor position, with the current colors.
+	#[inline]
+	pub fn clear_until_end_of_line(&mut self) -> Result<()> {
+		self.tui.clear_until_end_of_line()
+	}
+
 	/// Start the terminal interface interactions. This should be called before any terminal
 	/// interactions are performed.
 	#[inline]
@@ -442,6 +475,13 @@ mod tests {
 		assert_eq!(display.tui.get_output(), &["Test String"]);
 	}
 
+	#[test]
+	fn copy_to_clipboard() {
+		let mut display = Display::new(CrossTerm::new(), &Theme::new());
+		display.copy_to_clipboard("foo").unwrap();
+		assert_eq!(display.tui.get_output(), &["\x1b]52;c;Zm9v\x07"]);
+	}
+
 	#[test]
 	fn clear() {
 		let mut display = Display::new(CrossTerm::new(), &Theme::new());
@@ -593,6 +633,18 @@ mod tests {
 			.is_colors_enabled(Colors::new(expected_foreground, expected_background)));
 	}
 
+	#[test]
+	fn set_theme() {
+		let mut display = Display::new(CrossTerm::new(), &Theme::new());
+		let mut theme = Theme::new();
+		theme.color_action_pick = config::Color::LightRed;
+		display.set_theme(&theme);
+		display.color(DisplayColor::ActionPick, false).unwrap();
+		assert!(display
+			.tui
+			.is_colors_enabled(Colors::new(CrosstermColor::Red, CrosstermColor::Reset)));
+	}
+
 	#[rstest]
 	#[case::all_off(false, false, false)]
 	#[case::reverse(false, false, true)]
@@ -15,6 +15,7 @@ pub struct CrossTerm {
 	dirty: bool,
 	output: Vec<String>,
 	position: (u16, u16),
+	screen: Vec<String>,
 	size: Size,
 	state: State,
 }
@@ -30,6 +31,8 @@ impl Tui for CrossTerm {
 		self.attributes = Attributes::from(Attribute::Reset);
 		self.colors = Colors::new(Color::Reset, Color::Reset);
 		self.output.clear();
+		self.position = (0, 0);
+		self.screen.clear();
 		self.state = State::Normal;
 		Ok(())
 	}
@@ -43,6 +46,7 @@ impl Tui for CrossTerm {
 	#[inline]
 	fn print(&mut self, s: &str) -> Result<()> {
 		self.output.push(String::from(s));
+		self.write_to_screen(s);
 		Ok(())
 	}
 
@@ -109,6 +113,21 @@ impl Tui for CrossTerm {
 		Ok(())
 	}
 
+	#[inline]
+	fn move_to_line(&mut self, y: u16) -> Result<()> {
+		self.position = (0, y);
+		Ok(())
+	}
+
+	#[inline]
+	fn clear_until_end_of_line(&mut self) -> Result<()> {
+		let column = self.column();
+		if let Some(line) = self.screen.get_mut(self.position.1 as usize) {
+			*line = line.chars().take(column).collect();
+		}
+		Ok(())
+	}
+
 	#[inline]
 	fn start(&mut self) -> Result<()> {
 		self.state = State::Normal;
@@ -134,11 +153,19 @@ impl CrossTerm {
 			dirty: true,
 			output: vec![],
 			position: (0, 0),
+			screen: vec![],
 			size: Size::new(10, 10),
 			state: State::New,
 		}
 	}
 
+	/// The mocked version is never connected to a terminal.
+	#[inline]
+	#[must_use]
+	pub const fn is_interactive() -> bool {
+		false
+	}
+
 	/// Get a representation of the rendered output.
 	#[inline]
 	#[must_use]
@@ -146,6 +173,14 @@ impl CrossTerm {
 		&self.output
 	}
 
+	/// Get the lines of the screen, as they would be seen in a terminal after all the drawing operations since the
+	/// last reset, unlike the output, which contains every drawn string.
+	#[inline]
+	#[must_use]
+	pub const fn get_screen(&self) -> &Vec<String> {
+		&self.screen
+	}
+
 	/// Get the current state.
 	#[inline]
 	#[must_use]
@@ -200,4 +235,32 @@ impl CrossTerm {
 	pub const fn is_dirty(&self) -> bool {
 		self.dirty
 	}
+
+	// columns are counted from one, with zero also being the start of the line
+	fn column(&self) -> usize {
+		(self.position.0 as usize).saturating_sub(1)
+	}
+
+	#[allow(clippy::cast_possible_truncation)]
+	fn write_to_screen(&mut self, s: &str) {
+		let row = self.position.1 as usize;
+		while self.screen.len() <= row {
+			self.screen.push(String::new());
+		}
+		let column = self.column();
+		let mut chars = self.screen[row].chars().collect::<Vec<char>>();
+		if chars.len() < column {
+			chars.resize(column, ' ');
+		}
+		for (index, c) in s.chars().enumerate() {
+			if let Some(existing) = chars.get_mut(column + index) {
+				*existing = c;
+			}
+			else {
+				chars.push(c);
+			}
+		}
+		self.screen[row] = chars.into_iter().collect();
+		self.position.0 = (column + s.chars().count() + 1) as u16;
+	}
 }
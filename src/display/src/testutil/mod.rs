@@ -3,10 +3,22 @@ mod mockcrossterm;
 mod state;
 
 pub use self::{mockcrossterm::CrossTerm, state::State};
-use crate::Display;
+use crate::{Display, Size};
 
 /// Assert the the content of the Display is an expected value.
 #[inline]
 pub fn assert_output(display: &Display<CrossTerm>, expected: &[&str]) {
 	assert_eq!(display.tui.get_output().join(""), format!("{}\n", expected.join("\n")));
 }
+
+/// Assert the the lines of the screen of the Display, after all drawing operations, are an expected value.
+#[inline]
+pub fn assert_screen(display: &Display<CrossTerm>, expected: &[&str]) {
+	assert_eq!(display.tui.get_screen(), &expected);
+}
+
+/// Change the size of the mocked terminal of the Display.
+#[inline]
+pub fn set_display_size(display: &mut Display<CrossTerm>, size: Size) {
+	display.tui.set_size(size);
+}
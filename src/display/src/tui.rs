@@ -30,6 +30,10 @@ pub trait Tui {
 	fn move_to_column(&mut self, x: u16) -> Result<()>;
 	/// Move the cursor to the next line.
 	fn move_next_line(&mut self) -> Result<()>;
+	/// Move the cursor to the start of the line `y` lines from the top of the terminal interface.
+	fn move_to_line(&mut self, y: u16) -> Result<()>;
+	/// Clear the text from the cursor position to the end of the line.
+	fn clear_until_end_of_line(&mut self) -> Result<()>;
 	/// Start the terminal interface interactions.
 	fn start(&mut self) -> Result<()>;
 	/// End the terminal interface interactions.
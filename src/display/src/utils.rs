@@ -1,10 +1,14 @@
 use std::env::var;
 
 use config::Color;
+use crossterm::event::Event;
 
 use super::{color_mode::ColorMode, Color as CrosstermColor, Colors};
 
-pub(super) fn detect_color_mode(number_of_colors: u16) -> ColorMode {
+const BASE64_ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+// The ANSI support of a Windows console is only known on Windows, and is `None` on other platforms.
+pub(super) fn detect_color_mode(number_of_colors: u16, console_ansi_support: Option<bool>) -> ColorMode {
 	// respect COLORTERM being truecolor or 24bit
 	if let Ok(color_term) = var("COLORTERM") {
 		if color_term == "truecolor" || color_term == "24bit" {
@@ -37,6 +41,14 @@ pub(super) fn detect_color_mode(number_of_colors: u16) -> ColorMode {
 		return ColorMode::TrueColor;
 	}
 
+	// Windows 10 consoles with virtual terminal processing support truecolor, while legacy consoles are limited to
+	// the 16 colors of the console API
+	match console_ansi_support {
+		Some(true) => return ColorMode::TrueColor,
+		Some(false) => return ColorMode::FourBit,
+		None => {},
+	}
+
 	// at this point there is no way to detect truecolor support, so the best we can get is 8bit
 	match number_of_colors {
 		n if n >= 256 => ColorMode::EightBit,
@@ -46,6 +58,41 @@ pub(super) fn detect_color_mode(number_of_colors: u16) -> ColorMode {
 	}
 }
 
+// Windows consoles can report the size of the screen buffer, instead of the size of the window, in resize events,
+// and a size of zero while the console is being resized, so the size of the terminal is used when it is known.
+pub(super) fn normalize_resize_event(event: Event, terminal_size: Option<(u16, u16)>) -> Event {
+	match (event, terminal_size) {
+		(Event::Resize(..), Some((width, height))) if width > 0 && height > 0 => Event::Resize(width, height),
+		(event, _) => event,
+	}
+}
+
+// the text of an OSC 52 sequence, that sets the clipboard of a terminal, is encoded with the standard base64 alphabet
+pub(super) fn encode_base64(input: &[u8]) -> String {
+	let mut output = String::with_capacity((input.len() + 2) / 3 * 4);
+	for chunk in input.chunks(3) {
+		let first = chunk.first().copied().unwrap_or(0);
+		let second = chunk.get(1).copied().unwrap_or(0);
+		let third = chunk.get(2).copied().unwrap_or(0);
+		let indexes = [
+			first >> 2,
+			((first & 0b11) << 4) | (second >> 4),
+			((second & 0b1111) << 2) | (third >> 6),
+			third & 0b11_1111,
+		];
+		// a chunk of one byte is two characters, and a chunk of two bytes is three characters, padded to four
+		for (index, value) in indexes.into_iter().enumerate() {
+			output.push(
+				BASE64_ALPHABET
+					.get(value as usize)
+					.filter(|_| index <= chunk.len())
+					.map_or('=', |&character| char::from(character)),
+			);
+		}
+	}
+	output
+}
+
 pub(super) fn register_selectable_color_pairs(
 	color_mode: ColorMode,
 	foreground: Color,
@@ -180,6 +227,7 @@ fn find_color(color_mode: ColorMode, color: Color) -> CrosstermColor {
 mod tests {
 	use std::env::{remove_var, set_var};
 
+	use crossterm::event::{KeyCode, KeyEvent};
 	use rstest::rstest;
 	use serial_test::serial;
 
@@ -196,56 +244,56 @@ mod tests {
 	#[serial]
 	fn detect_color_mode_no_env_2_colors() {
 		clear_env();
-		assert_eq!(detect_color_mode(2), ColorMode::TwoTone);
+		assert_eq!(detect_color_mode(2, None), ColorMode::TwoTone);
 	}
 
 	#[test]
 	#[serial]
 	fn detect_color_mode_no_env_8_colors() {
 		clear_env();
-		assert_eq!(detect_color_mode(8), ColorMode::ThreeBit);
+		assert_eq!(detect_color_mode(8, None), ColorMode::ThreeBit);
 	}
 
 	#[test]
 	#[serial]
 	fn detect_color_mode_no_env_less_8_colors() {
 		clear_env();
-		assert_eq!(detect_color_mode(7), ColorMode::TwoTone);
+		assert_eq!(detect_color_mode(7, None), ColorMode::TwoTone);
 	}
 
 	#[test]
 	#[serial]
 	fn detect_color_mode_no_env_16_colors() {
 		clear_env();
-		assert_eq!(detect_color_mode(16), ColorMode::FourBit);
+		assert_eq!(detect_color_mode(16, None), ColorMode::FourBit);
 	}
 
 	#[test]
 	#[serial]
 	fn detect_color_mode_no_env_less_16_colors() {
 		clear_env();
-		assert_eq!(detect_color_mode(15), ColorMode::ThreeBit);
+		assert_eq!(detect_color_mode(15, None), ColorMode::ThreeBit);
 	}
 
 	#[test]
 	#[serial]
 	fn detect_color_mode_no_env_256_colors() {
 		clear_env();
-		assert_eq!(detect_color_mode(256), ColorMode::EightBit);
+		assert_eq!(detect_color_mode(256, None), ColorMode::EightBit);
 	}
 
 	#[test]
 	#[serial]
 	fn detect_color_mode_no_env_less_256_colors() {
 		clear_env();
-		assert_eq!(detect_color_mode(255), ColorMode::FourBit);
+		assert_eq!(detect_color_mode(255, None), ColorMode::FourBit);
 	}
 
 	#[test]
 	#[serial]
 	fn detect_color_mode_no_env_more_256_colors() {
 		clear_env();
-		assert_eq!(detect_color_mode(257), ColorMode::EightBit);
+		assert_eq!(detect_color_mode(257, None), ColorMode::EightBit);
 	}
 
 	#[test]
@@ -253,7 +301,7 @@ mod tests {
 	fn detect_color_mode_term_env_no_256() {
 		clear_env();
 		set_var("TERM", "XTERM");
-		assert_eq!(detect_color_mode(0), ColorMode::TwoTone);
+		assert_eq!(detect_color_mode(0, None), ColorMode::TwoTone);
 	}
 
 	#[test]
@@ -261,7 +309,7 @@ mod tests {
 	fn detect_color_mode_term_env_with_256() {
 		clear_env();
 		set_var("TERM", "XTERM-256");
-		assert_eq!(detect_color_mode(0), ColorMode::EightBit);
+		assert_eq!(detect_color_mode(0, None), ColorMode::EightBit);
 	}
 
 	#[test]
@@ -269,7 +317,7 @@ mod tests {
 	fn detect_color_mode_vte_version_0_36_00() {
 		clear_env();
 		set_var("VTE_VERSION", "3600");
-		assert_eq!(detect_color_mode(0), ColorMode::TrueColor);
+		assert_eq!(detect_color_mode(0, None), ColorMode::TrueColor);
 	}
 
 	#[test]
@@ -277,7 +325,7 @@ mod tests {
 	fn detect_color_mode_vte_version_greater_0_36_00() {
 		clear_env();
 		set_var("VTE_VERSION", "3601");
-		assert_eq!(detect_color_mode(0), ColorMode::TrueColor);
+		assert_eq!(detect_color_mode(0, None), ColorMode::TrueColor);
 	}
 
 	#[test]
@@ -285,7 +333,7 @@ mod tests {
 	fn detect_color_mode_vte_version_less_0_36_00() {
 		clear_env();
 		set_var("VTE_VERSION", "1");
-		assert_eq!(detect_color_mode(0), ColorMode::EightBit);
+		assert_eq!(detect_color_mode(0, None), ColorMode::EightBit);
 	}
 
 	#[test]
@@ -293,14 +341,14 @@ mod tests {
 	fn detect_color_mode_vte_version_0() {
 		clear_env();
 		set_var("VTE_VERSION", "0");
-		assert_eq!(detect_color_mode(0), ColorMode::TwoTone);
+		assert_eq!(detect_color_mode(0, None), ColorMode::TwoTone);
 	}
 	#[test]
 	#[serial]
 	fn detect_color_mode_vte_version_invalid() {
 		clear_env();
 		set_var("VTE_VERSION", "invalid");
-		assert_eq!(detect_color_mode(0), ColorMode::TwoTone);
+		assert_eq!(detect_color_mode(0, None), ColorMode::TwoTone);
 	}
 
 	#[test]
@@ -308,7 +356,7 @@ mod tests {
 	fn detect_color_mode_colorterm_env_is_truecolor() {
 		clear_env();
 		set_var("COLORTERM", "truecolor");
-		assert_eq!(detect_color_mode(0), ColorMode::TrueColor);
+		assert_eq!(detect_color_mode(0, None), ColorMode::TrueColor);
 	}
 
 	#[test]
@@ -316,7 +364,7 @@ mod tests {
 	fn detect_color_mode_colorterm_env_is_24bit() {
 		clear_env();
 		set_var("COLORTERM", "24bit");
-		assert_eq!(detect_color_mode(0), ColorMode::TrueColor);
+		assert_eq!(detect_color_mode(0, None), ColorMode::TrueColor);
 	}
 
 	#[test]
@@ -324,7 +372,7 @@ mod tests {
 	fn detect_color_mode_colorterm_env_is_other() {
 		clear_env();
 		set_var("COLORTERM", "other");
-		assert_eq!(detect_color_mode(0), ColorMode::TwoTone);
+		assert_eq!(detect_color_mode(0, None), ColorMode::TwoTone);
 	}
 
 	#[test]
@@ -333,8 +381,55 @@ mod tests {
 		clear_env();
 		// WT_SESSION is generally a GUID of some sort
 		set_var("WT_SESSION", "32a25081-6745-4b65-909d-e8257bdbe852");
-		assert_eq!(detect_color_mode(0), ColorMode::TrueColor);
+		assert_eq!(detect_color_mode(0, None), ColorMode::TrueColor);
+	}
+
+	#[test]
+	#[serial]
+	fn detect_color_mode_windows_console_ansi_support() {
+		clear_env();
+		assert_eq!(detect_color_mode(8, Some(true)), ColorMode::TrueColor);
+	}
+
+	#[test]
+	#[serial]
+	fn detect_color_mode_windows_legacy_console() {
+		clear_env();
+		assert_eq!(detect_color_mode(8, Some(false)), ColorMode::FourBit);
+	}
+
+	#[test]
+	#[serial]
+	fn detect_color_mode_windows_legacy_console_with_term_env() {
+		clear_env();
+		set_var("TERM", "xterm-256color");
+		assert_eq!(detect_color_mode(8, Some(false)), ColorMode::EightBit);
+	}
+
+	#[rstest]
+	#[case::terminal_size(Event::Resize(120, 9001), Some((120, 40)), Event::Resize(120, 40))]
+	#[case::unknown_terminal_size(Event::Resize(100, 30), None, Event::Resize(100, 30))]
+	#[case::zero_terminal_size(Event::Resize(100, 30), Some((0, 0)), Event::Resize(100, 30))]
+	#[case::not_resize(
+		Event::Key(KeyEvent::from(KeyCode::Enter)),
+		Some((120, 40)),
+		Event::Key(KeyEvent::from(KeyCode::Enter))
+	)]
+	fn normalize_resize(#[case] event: Event, #[case] terminal_size: Option<(u16, u16)>, #[case] expected: Event) {
+		assert_eq!(normalize_resize_event(event, terminal_size), expected);
 	}
+
+	#[rstest]
+	#[case::empty("", "")]
+	#[case::one_byte("f", "Zg==")]
+	#[case::two_bytes("fo", "Zm8=")]
+	#[case::three_bytes("foo", "Zm9v")]
+	#[case::longer("foobar", "Zm9vYmFy")]
+	#[case::all_bits("\u{ff}\u{fe}", "w7/Dvg==")]
+	fn encode_base64_text(#[case] input: &str, #[case] expected: &str) {
+		assert_eq!(encode_base64(input.as_bytes()), expected);
+	}
+
 	#[rstest]
 	#[case::black(0, 0, 0, 0)]
 	#[case::black(0, 0, 127, 0)]
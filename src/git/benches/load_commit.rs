@@ -0,0 +1,73 @@
+//! Benchmarks for loading commits and commit diffs, run with `cargo bench`.
+use std::{fmt::Write, path::Path};
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use git::{CommitDiffLoaderOptions, Repository};
+
+const FILE_COUNT: usize = 50;
+const LINE_COUNT: usize = 200;
+// a commit that reformats a large part of a project, with the files of the other commits changed, and files added
+const LARGE_FILE_COUNT: usize = 500;
+const LARGE_LINE_COUNT: usize = 2000;
+
+fn commit_files(repository: &git2::Repository, version: usize, file_count: usize, line_count: usize) -> git2::Oid {
+	let mut index = repository.index().unwrap();
+	for file in 0..file_count {
+		let mut content = String::new();
+		for line in 0..line_count {
+			let line_version = if line % 10 == 0 { version } else { 0 };
+			let _ = writeln!(content, "file {} line {} version {}", file, line, line_version);
+		}
+		let path = format!("file-{}.txt", file);
+		std::fs::write(repository.workdir().unwrap().join(path.as_str()), content).unwrap();
+		index.add_path(Path::new(path.as_str())).unwrap();
+	}
+	index.write().unwrap();
+	let tree = repository.find_tree(index.write_tree().unwrap()).unwrap();
+	let signature = git2::Signature::now("name", "name@example.com").unwrap();
+	let parents = repository
+		.head()
+		.ok()
+		.and_then(|head| head.peel_to_commit().ok())
+		.into_iter()
+		.collect::<Vec<_>>();
+	repository
+		.commit(
+			Some("HEAD"),
+			&signature,
+			&signature,
+			"Change files",
+			&tree,
+			parents.iter().collect::<Vec<_>>().as_slice(),
+		)
+		.unwrap()
+}
+
+fn load_commit(criterion: &mut Criterion) {
+	let directory = tempfile::Builder::new()
+		.prefix("interactive-rebase-tool-bench")
+		.tempdir()
+		.unwrap();
+	let git2_repository = git2::Repository::init(directory.path()).unwrap();
+	let _ = commit_files(&git2_repository, 0, FILE_COUNT, LINE_COUNT);
+	let hash = commit_files(&git2_repository, 1, FILE_COUNT, LINE_COUNT).to_string();
+	let large_hash = commit_files(&git2_repository, 2, LARGE_FILE_COUNT, LARGE_LINE_COUNT).to_string();
+	let repository = Repository::from(git2_repository);
+	let options = CommitDiffLoaderOptions::new().context_lines(3).renames(true, 200);
+
+	let _ = criterion.bench_function("load_commit", |bencher| {
+		bencher.iter(|| repository.load_commit(hash.as_str()).unwrap());
+	});
+	let _ = criterion.bench_function("load_commit_diff", |bencher| {
+		bencher.iter(|| repository.load_commit_diff(hash.as_str(), &options).unwrap());
+	});
+	let mut group = criterion.benchmark_group("large");
+	let _ = group.sample_size(10);
+	let _ = group.bench_function("load_large_commit_diff", |bencher| {
+		bencher.iter(|| repository.load_commit_diff(large_hash.as_str(), &options).unwrap());
+	});
+	group.finish();
+}
+
+criterion_group!(benches, load_commit);
+criterion_main!(benches);
@@ -2,6 +2,9 @@ use chrono::{DateTime, Local, TimeZone};
 
 use crate::{reference::Reference, user::User};
 
+// The line that `git cherry-pick -x` adds to the message of a commit that was cherry picked.
+const CHERRY_PICK_PREFIX: &str = "(cherry picked from commit ";
+
 /// Represents a commit.
 #[derive(Debug, PartialEq)]
 pub struct Commit {
@@ -72,6 +75,17 @@ impl Commit {
 		&self.message
 	}
 
+	/// Get the hash of the commit that the commit was cherry picked from, from the last
+	/// `(cherry picked from commit ...)` line of the commit message.
+	#[must_use]
+	#[inline]
+	pub fn cherry_picked_from(&self) -> Option<&str> {
+		self.message.as_deref()?.lines().rev().find_map(|line| {
+			let hash = line.trim().strip_prefix(CHERRY_PICK_PREFIX)?.strip_suffix(')')?;
+			(!hash.is_empty() && hash.chars().all(|character| character.is_ascii_hexdigit())).then_some(hash)
+		})
+	}
+
 	fn new(commit: &git2::Commit<'_>, reference: Option<&git2::Reference<'_>>) -> Self {
 		let author = User::new(commit.author().name(), commit.author().email());
 		let authored_date = Local.timestamp(commit.author().when().seconds(), 0);
@@ -98,6 +112,43 @@ impl Commit {
 			summary,
 		}
 	}
+
+	#[cfg(feature = "gix")]
+	pub(crate) fn from_gix(commit: &gix::Commit<'_>) -> Result<Self, gix::objs::decode::Error> {
+		use gix::bstr::ByteSlice;
+
+		let author_signature = commit.author()?;
+		let committer_signature = commit.committer()?;
+		let author = User::new(author_signature.name.to_str().ok(), author_signature.email.to_str().ok());
+		let authored_date = Local.timestamp(author_signature.time.seconds, 0);
+		let raw_message = commit.message_raw()?;
+		// like libgit2, the leading new lines of the message are not included
+		let message = raw_message.to_str().ok().map(|message| String::from(message.trim_start_matches('\n')));
+		let summary = commit.message()?.summary().to_str().ok().map(String::from);
+		let committed_date = Local.timestamp(committer_signature.time.seconds, 0);
+
+		let try_committer = User::new(
+			committer_signature.name.to_str().ok(),
+			committer_signature.email.to_str().ok(),
+		);
+		let committer = (try_committer.is_some() && try_committer != author).then(|| try_committer);
+
+		Ok(Self {
+			hash: commit.id().to_string(),
+			reference: None,
+			author,
+			authored_date: if authored_date == committed_date {
+				None
+			}
+			else {
+				Some(authored_date)
+			},
+			message,
+			committer,
+			committed_date,
+			summary,
+		})
+	}
 }
 
 impl From<&git2::Reference<'_>> for Commit {
@@ -177,6 +228,28 @@ mod tests {
 		assert_eq!(commit.message().as_ref().unwrap(), "title\n\nbody");
 	}
 
+	#[test]
+	fn cherry_picked_from() {
+		let commit = CommitBuilder::new("0123456789ABCDEF")
+			.message("title\n\n(cherry picked from commit abc123)\n(cherry picked from commit def456)\n")
+			.build();
+		assert_eq!(commit.cherry_picked_from(), Some("def456"));
+	}
+
+	#[test]
+	fn cherry_picked_from_not_cherry_picked() {
+		let commit = CommitBuilder::new("0123456789ABCDEF")
+			.message("title\n\n(cherry picked from commit not-a-hash)")
+			.build();
+		assert_eq!(commit.cherry_picked_from(), None);
+	}
+
+	#[test]
+	fn cherry_picked_from_without_message() {
+		let commit = CommitBuilder::new("0123456789ABCDEF").build();
+		assert_eq!(commit.cherry_picked_from(), None);
+	}
+
 	#[test]
 	fn from_reference() {
 		with_temp_repository(|repository| {
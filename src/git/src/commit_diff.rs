@@ -1,4 +1,6 @@
-use crate::{commit::Commit, file_status::FileStatus};
+use std::{collections::HashSet, mem::size_of};
+
+use crate::{commit::Commit, diff_interner::DiffInterner, diff_memory_usage::DiffMemoryUsage, file_status::FileStatus};
 
 /// Represents a commit with a diff
 #[derive(Debug)]
@@ -9,6 +11,8 @@ pub struct CommitDiff {
 	pub(crate) number_files_changed: usize,
 	pub(crate) number_insertions: usize,
 	pub(crate) number_deletions: usize,
+	pub(crate) is_shallow_boundary: bool,
+	pub(crate) is_tree_available: bool,
 }
 
 impl CommitDiff {
@@ -26,6 +30,22 @@ impl CommitDiff {
 		&self.parent
 	}
 
+	/// Is the commit at the boundary of a shallow clone, where the parent commit is not in the repository, so like Git,
+	/// the files of the commit are compared to an empty tree.
+	#[inline]
+	#[must_use]
+	pub const fn is_shallow_boundary(&self) -> bool {
+		self.is_shallow_boundary
+	}
+
+	/// Are the trees of the commit and its parent in the repository, which is not the case for trees that have not been
+	/// fetched into a partial clone. A diff without its trees has no file statuses.
+	#[inline]
+	#[must_use]
+	pub const fn is_tree_available(&self) -> bool {
+		self.is_tree_available
+	}
+
 	/// The file statuses
 	#[inline]
 	#[must_use]
@@ -53,10 +73,44 @@ impl CommitDiff {
 	pub const fn number_deletions(&self) -> usize {
 		self.number_deletions
 	}
+
+	/// The size of the diff, with an estimate of the memory it uses.
+	#[inline]
+	#[must_use]
+	pub fn memory_usage(&self) -> DiffMemoryUsage {
+		let mut counted = HashSet::new();
+		let mut usage = DiffMemoryUsage {
+			bytes: size_of::<Self>() + self.file_statuses.capacity() * size_of::<FileStatus>(),
+			deltas: 0,
+			files: self.file_statuses.len(),
+			lines: 0,
+		};
+		for file_status in &self.file_statuses {
+			usage.bytes += file_status.heap_size(&mut counted);
+			usage.deltas += file_status.deltas().len();
+			for delta in file_status.deltas() {
+				usage.lines += delta.lines().len();
+			}
+		}
+		usage
+	}
+
+	/// Share the paths and the hunk contexts that are repeated within the diff, and release the capacity that was
+	/// reserved while the diff was loaded, once the diff is complete.
+	pub(crate) fn compact(&mut self) {
+		let mut interner = DiffInterner::new();
+		for file_status in &mut self.file_statuses {
+			file_status.compact(&mut interner);
+		}
+		self.file_statuses.shrink_to_fit();
+	}
 }
 
 #[cfg(test)]
 mod tests {
+	use std::mem::size_of;
+
+	use super::CommitDiff;
 	use crate::{
 		delta::Delta,
 		diff_line::DiffLine,
@@ -98,7 +152,7 @@ mod tests {
 			Status::Modified,
 		));
 		builder.add_delta(Delta::new("name", 0, 0, 0, 1));
-		builder.add_diff_line(DiffLine::new(Origin::Addition, "line", None, Some(1), false));
+		builder.add_diff_line(&DiffLine::new(Origin::Addition, "line", None, Some(1), false));
 		let file_statuses = builder.build();
 		let diff = CommitDiffBuilder::new(CommitBuilder::new("0123456789ABCDEF").build())
 			.file_statuses(file_statuses)
@@ -106,6 +160,46 @@ mod tests {
 		assert_eq!(diff.file_statuses()[0].source_path.to_str().unwrap(), "foo");
 	}
 
+	#[test]
+	fn memory_usage_empty() {
+		let usage = CommitDiffBuilder::new(CommitBuilder::new("0123456789ABCDEF").build())
+			.build()
+			.memory_usage();
+		assert_eq!(usage.files(), 0);
+		assert_eq!(usage.deltas(), 0);
+		assert_eq!(usage.lines(), 0);
+		assert_eq!(usage.bytes(), size_of::<CommitDiff>());
+	}
+
+	#[test]
+	fn memory_usage() {
+		let mut builder = FileStatusBuilder::new();
+		for path in ["foo", "bar"] {
+			builder.add_file_stat(FileStatus::new(
+				path,
+				FileMode::Normal,
+				false,
+				path,
+				FileMode::Normal,
+				false,
+				Status::Modified,
+			));
+			builder.add_delta(Delta::new("@@ -1,1 +1,2 @@ context", 1, 1, 1, 2));
+			builder.add_diff_line(&DiffLine::new(Origin::Context, "line", Some(1), Some(1), false));
+			builder.add_diff_line(&DiffLine::new(Origin::Addition, "line", None, Some(2), false));
+		}
+		let mut diff = CommitDiffBuilder::new(CommitBuilder::new("0123456789ABCDEF").build())
+			.file_statuses(builder.build())
+			.build();
+		let usage = diff.memory_usage();
+		assert_eq!(usage.files(), 2);
+		assert_eq!(usage.deltas(), 2);
+		assert_eq!(usage.lines(), 4);
+		diff.compact();
+		assert!(diff.memory_usage().bytes() < usage.bytes());
+		assert_eq!(diff.memory_usage().lines(), 4);
+	}
+
 	#[test]
 	fn number_files_changed() {
 		let diff = CommitDiffBuilder::new(CommitBuilder::new("0123456789ABCDEF").build())
@@ -129,4 +223,20 @@ mod tests {
 			.build();
 		assert_eq!(diff.number_deletions(), 3);
 	}
+
+	#[test]
+	fn is_shallow_boundary() {
+		let diff = CommitDiffBuilder::new(CommitBuilder::new("0123456789ABCDEF").build())
+			.shallow_boundary(true)
+			.build();
+		assert!(diff.is_shallow_boundary());
+	}
+
+	#[test]
+	fn is_tree_available() {
+		let diff = CommitDiffBuilder::new(CommitBuilder::new("0123456789ABCDEF").build())
+			.tree_available(false)
+			.build();
+		assert!(!diff.is_tree_available());
+	}
 }
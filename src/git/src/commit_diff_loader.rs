@@ -1,6 +1,6 @@
-use std::path::PathBuf;
+use std::{iter, path::PathBuf};
 
-use git2::{DiffFindOptions, DiffOptions, Oid, Repository};
+use git2::{DiffFindOptions, DiffOptions, ErrorClass, ErrorCode, Oid, Patch, Repository};
 use lazy_static::lazy_static;
 use parking_lot::Mutex;
 
@@ -32,27 +32,113 @@ impl<'d> CommitDiffLoader<'d> {
 
 	pub(crate) fn load_from_hash(&self, oid: Oid) -> Result<Vec<CommitDiff>, git2::Error> {
 		let commit = self.repo.find_commit(oid)?;
+		// the parents that are not in the repository, at the boundary of a shallow clone, are not provided
+		let parents = commit.parents().collect::<Vec<git2::Commit<'_>>>();
 
 		// some commits do not have parents, and can't have file stats
-		let diffs = if commit.parent_ids().count() == 0 {
-			vec![self.load_diff(None, &commit)?]
+		let diffs = if parents.is_empty() {
+			let mut diff = self.load_diff(None, &commit)?;
+			diff.is_shallow_boundary = commit.parent_ids().count() > 0;
+			vec![diff]
 		}
 		else {
 			//
 			let mut diffs = vec![];
-			for parent in commit.parents() {
-				diffs.push(self.load_diff(Some(&parent), &commit)?);
+			for parent in &parents {
+				diffs.push(self.load_diff(Some(parent), &commit)?);
 			}
 			diffs
 		};
 		Ok(diffs)
 	}
 
-	#[allow(clippy::as_conversions, clippy::unwrap_in_result)]
+	// the objects are found for the diff with the first parent, as the diff is shown
+	pub(crate) fn find_missing_objects(&self, oid: Oid) -> Result<Vec<Oid>, git2::Error> {
+		let commit = self.repo.find_commit(oid)?;
+		let parent = commit.parents().next();
+		let odb = self.repo.odb()?;
+		let tree_ids = iter::once(commit.tree_id())
+			.chain(parent.as_ref().map(git2::Commit::tree_id))
+			.collect::<Vec<Oid>>();
+		let missing_tree_ids = tree_ids
+			.iter()
+			.copied()
+			.filter(|tree_id| !odb.exists(*tree_id))
+			.collect::<Vec<Oid>>();
+		if !missing_tree_ids.is_empty() {
+			return Ok(missing_tree_ids);
+		}
+
+		let tree = commit.tree()?;
+		let parent_tree = parent.as_ref().map(git2::Commit::tree).transpose()?;
+		let diff = match self.repo.diff_tree_to_tree(parent_tree.as_ref(), Some(&tree), None) {
+			Ok(diff) => diff,
+			// a tree within the trees has not been fetched, and the trees within a tree are fetched with the tree
+			Err(err) if is_missing_object(&err) => return Ok(tree_ids),
+			Err(err) => return Err(err),
+		};
+		let mut missing_ids = vec![];
+		for diff_delta in diff.deltas() {
+			for file in [diff_delta.old_file(), diff_delta.new_file()] {
+				let id = file.id();
+				if !id.is_zero()
+					&& file.mode() != git2::FileMode::Commit
+					&& !missing_ids.contains(&id)
+					&& !odb.exists(id)
+				{
+					missing_ids.push(id);
+				}
+			}
+		}
+		Ok(missing_ids)
+	}
+
+	/// Load the diff of the commits as if they were squashed into the first commit, from the parent of the first commit
+	/// to the tree with the changes of each of the other commits applied in order, as they would be cherry picked. The
+	/// trees that combine the changes are written to the object database, as they are for a merge.
+	pub(crate) fn load_squash(&self, oids: &[Oid]) -> Result<CommitDiff, git2::Error> {
+		let (first_oid, other_oids) = oids
+			.split_first()
+			.ok_or_else(|| git2::Error::from_str("No commits to squash"))?;
+		let commit = self.repo.find_commit(*first_oid)?;
+		let mut tree = commit.tree()?;
+		for oid in other_oids {
+			let other_commit = self.repo.find_commit(*oid)?;
+			// the changes of a commit without a parent are the files of the commit
+			let base_tree = match other_commit.parents().next() {
+				Some(parent) => parent.tree()?,
+				None => self.repo.find_tree(self.repo.treebuilder(None)?.write()?)?,
+			};
+			let mut index = self
+				.repo
+				.merge_trees(&base_tree, &tree, &other_commit.tree()?, None)?;
+			if index.has_conflicts() {
+				return Err(git2::Error::from_str(
+					format!("The changes of {} conflict with the changes of the earlier commits", oid).as_str(),
+				));
+			}
+			tree = self.repo.find_tree(index.write_tree_to(self.repo)?)?;
+		}
+		let parent = commit.parents().next();
+		let mut diff = self.load_tree_diff(parent.as_ref(), &commit, Ok(tree))?;
+		diff.is_shallow_boundary = parent.is_none() && commit.parent_ids().count() > 0;
+		Ok(diff)
+	}
+
 	fn load_diff(
 		&self,
 		parent: Option<&git2::Commit<'_>>,
 		commit: &git2::Commit<'_>,
+	) -> Result<CommitDiff, git2::Error> {
+		self.load_tree_diff(parent, commit, commit.tree())
+	}
+
+	#[allow(clippy::as_conversions, clippy::unwrap_in_result)]
+	fn load_tree_diff(
+		&self,
+		parent: Option<&git2::Commit<'_>>,
+		commit: &git2::Commit<'_>,
+		tree: Result<git2::Tree<'_>, git2::Error>,
 	) -> Result<CommitDiff, git2::Error> {
 		let mut diff_options = DiffOptions::new();
 		// include_unmodified added to find copies from unmodified files
@@ -77,15 +163,47 @@ impl<'d> CommitDiffLoader<'d> {
 			.copies(self.config.copies)
 			.copies_from_unmodified(self.config.copies);
 
-		let mut diff = if let Some(p) = parent {
-			self.repo
-				.diff_tree_to_tree(Some(&p.tree()?), Some(&commit.tree()?), Some(&mut diff_options))?
-		}
-		else {
+		let mut commit_diff = CommitDiff {
+			commit: Commit::from(commit),
+			parent: parent.map(Commit::from),
+			file_statuses: vec![],
+			number_files_changed: 0,
+			number_insertions: 0,
+			number_deletions: 0,
+			is_shallow_boundary: false,
+			is_tree_available: true,
+		};
+
+		// the trees that have not been fetched into a partial clone are not in the repository
+		let tree_diff = tree.and_then(|tree| {
+			let parent_tree = parent.map(git2::Commit::tree).transpose()?;
 			self.repo
-				.diff_tree_to_tree(None, Some(&commit.tree()?), Some(&mut diff_options))?
+				.diff_tree_to_tree(parent_tree.as_ref(), Some(&tree), Some(&mut diff_options))
+		});
+		let mut diff = match tree_diff {
+			Ok(diff) => diff,
+			Err(err) if is_missing_object(&err) => {
+				commit_diff.is_tree_available = false;
+				return Ok(commit_diff);
+			},
+			Err(err) => return Err(err),
 		};
 
+		// the files that have not been fetched into a partial clone are not in the repository, and their content cannot
+		// be compared, so the changes are loaded a file at a time
+		let odb = self.repo.odb()?;
+		let is_missing_file = |file: &git2::DiffFile<'_>| {
+			!file.id().is_zero() && file.mode() != git2::FileMode::Commit && !odb.exists(file.id())
+		};
+		if diff
+			.deltas()
+			.any(|diff_delta| is_missing_file(&diff_delta.old_file()) || is_missing_file(&diff_delta.new_file()))
+		{
+			Self::load_partial_diff(&diff, &mut commit_diff, is_missing_file)?;
+			commit_diff.compact();
+			return Ok(commit_diff);
+		}
+
 		diff.find_similar(Some(&mut diff_find_options))?;
 
 		let mut unmodified_file_count: usize = 0;
@@ -101,25 +219,7 @@ impl<'d> CommitDiffLoader<'d> {
 				}
 
 				let mut fsb = file_stats_builder.lock();
-
-				let source_file = diff_delta.old_file();
-				let source_file_mode = FileMode::from(source_file.mode());
-				let source_file_path = source_file.path().unwrap_or_else(|| UNKNOWN_PATH.as_path());
-
-				let destination_file = diff_delta.new_file();
-				let destination_file_mode = FileMode::from(destination_file.mode());
-				let destination_file_path = destination_file.path().unwrap_or_else(|| UNKNOWN_PATH.as_path());
-
-				fsb.add_file_stat(FileStatus::new(
-					source_file_path,
-					source_file_mode,
-					source_file.is_binary(),
-					destination_file_path,
-					destination_file_mode,
-					destination_file.is_binary(),
-					Status::from(diff_delta.status()),
-				));
-
+				fsb.add_file_stat(Self::file_status(&diff_delta));
 				true
 			},
 			None,
@@ -130,34 +230,94 @@ impl<'d> CommitDiffLoader<'d> {
 			}),
 			Some(&mut |_, _, diff_line| {
 				let mut fsb = file_stats_builder.lock();
-				fsb.add_diff_line(DiffLine::from(&diff_line));
+				fsb.add_diff_line(&DiffLine::from(&diff_line));
 				true
 			}),
 		)
 		.expect("diff.foreach failed. Please report this as a bug.");
 
 		let stats = diff.stats()?;
-		let number_files_changed = stats.files_changed() - unmodified_file_count;
-		let number_insertions = stats.insertions();
-		let number_deletions = stats.deletions();
+		commit_diff.number_files_changed = stats.files_changed() - unmodified_file_count;
+		commit_diff.number_insertions = stats.insertions();
+		commit_diff.number_deletions = stats.deletions();
+		commit_diff.file_statuses = file_stats_builder.into_inner().build();
+		commit_diff.compact();
+		Ok(commit_diff)
+	}
 
-		let fsb = file_stats_builder.into_inner();
+	// without the content of every file, renames and copies are not found, and the changes to the files with content
+	// are loaded from a patch of each file
+	fn load_partial_diff<F>(
+		diff: &git2::Diff<'_>,
+		commit_diff: &mut CommitDiff,
+		is_missing_file: F,
+	) -> Result<(), git2::Error>
+	where
+		F: Fn(&git2::DiffFile<'_>) -> bool,
+	{
+		for (index, diff_delta) in diff.deltas().enumerate() {
+			if diff_delta.status() == git2::Delta::Unmodified {
+				continue;
+			}
+			if is_missing_file(&diff_delta.old_file()) || is_missing_file(&diff_delta.new_file()) {
+				let mut file_status = Self::file_status(&diff_delta);
+				file_status.content_available = false;
+				commit_diff.file_statuses.push(file_status);
+				continue;
+			}
+			let patch = match Patch::from_diff(diff, index)? {
+				Some(patch) => patch,
+				None => continue,
+			};
+			// the binary flags of the files are set once the content of the files is loaded
+			let mut file_status = Self::file_status(&patch.delta());
+			for hunk_index in 0..patch.num_hunks() {
+				let (diff_hunk, line_count) = patch.hunk(hunk_index)?;
+				let mut delta = Delta::from(&diff_hunk);
+				for line_index in 0..line_count {
+					delta.add_line(&DiffLine::from(&patch.line_in_hunk(hunk_index, line_index)?));
+				}
+				file_status.add_delta(delta);
+			}
+			let (_, insertions, deletions) = patch.line_stats()?;
+			commit_diff.number_insertions += insertions;
+			commit_diff.number_deletions += deletions;
+			commit_diff.file_statuses.push(file_status);
+		}
+		commit_diff.number_files_changed = commit_diff.file_statuses.len();
+		Ok(())
+	}
 
-		Ok(CommitDiff {
-			commit: Commit::from(commit),
-			parent: parent.map(Commit::from),
-			file_statuses: fsb.build(),
-			number_files_changed,
-			number_insertions,
-			number_deletions,
-		})
+	fn file_status(diff_delta: &git2::DiffDelta<'_>) -> FileStatus {
+		let source_file = diff_delta.old_file();
+		let source_file_mode = FileMode::from(source_file.mode());
+		let source_file_path = source_file.path().unwrap_or_else(|| UNKNOWN_PATH.as_path());
+
+		let destination_file = diff_delta.new_file();
+		let destination_file_mode = FileMode::from(destination_file.mode());
+		let destination_file_path = destination_file.path().unwrap_or_else(|| UNKNOWN_PATH.as_path());
+
+		FileStatus::new(
+			source_file_path,
+			source_file_mode,
+			source_file.is_binary(),
+			destination_file_path,
+			destination_file_mode,
+			destination_file.is_binary(),
+			Status::from(diff_delta.status()),
+		)
 	}
 }
 
+// an object that is not in the repository, such as an object that has not been fetched into a partial clone
+fn is_missing_object(err: &git2::Error) -> bool {
+	err.code() == ErrorCode::NotFound && err.class() == ErrorClass::Odb
+}
+
 #[cfg(all(unix, test))]
 mod tests {
 	use std::{
-		fs::{remove_file, File},
+		fs::{create_dir, remove_file, File},
 		io::Write,
 		os::unix::fs::symlink,
 	};
@@ -212,7 +372,7 @@ mod tests {
 		}
 	}
 
-	fn _format_diff_line(line: &DiffLine) -> String {
+	fn _format_diff_line(line: &DiffLine<'_>) -> String {
 		let origin = match line.origin() {
 			Origin::Addition => "+",
 			Origin::Binary => "B",
@@ -263,7 +423,7 @@ mod tests {
 					},
 				));
 				for line in delta.lines() {
-					actual.push(_format_diff_line(line));
+					actual.push(_format_diff_line(&line));
 				}
 			}
 		}
@@ -312,6 +472,24 @@ mod tests {
 		Ok(())
 	}
 
+	// remove an object, as if it had not been fetched into a shallow or partial clone, returning a repository without
+	// the objects that were cached by the repository
+	fn remove_object(repository: &crate::Repository, oid: Oid) -> crate::Repository {
+		let id = oid.to_string();
+		let objects_path = repository.git_directory().join("objects");
+		remove_file(objects_path.join(&id[..2]).join(&id[2..])).unwrap();
+		crate::Repository::open_from_path(repository.git_directory()).unwrap()
+	}
+
+	fn head_commit(repository: &crate::Repository) -> git2::Commit<'_> {
+		repository
+			.git2_repository()
+			.find_reference("refs/heads/main")
+			.unwrap()
+			.peel_to_commit()
+			.unwrap()
+	}
+
 	fn diff_from_head(repository: &crate::Repository, options: &CommitDiffLoaderOptions) -> CommitDiff {
 		let repo = repository.git2_repository();
 		let id = repo
@@ -656,4 +834,148 @@ mod tests {
 			Ok(())
 		});
 	}
+
+	#[test]
+	fn load_from_hash_shallow_boundary() {
+		with_temp_repository(|repo| {
+			write_normal_file(&repo, "a", &["line1"])?;
+			create_commit(&repo)?;
+			write_normal_file(&repo, "b", &["line1"])?;
+			create_commit(&repo)?;
+			let parent_id = head_commit(&repo).parent_id(0)?;
+			let shallow_repo = remove_object(&repo, parent_id);
+			let diff = diff_from_head(&shallow_repo, &CommitDiffLoaderOptions::new());
+			assert!(diff.is_shallow_boundary());
+			assert!(diff.parent().is_none());
+			assert_eq!(diff.number_files_changed(), 2);
+			assert_commit_diff!(
+				&diff,
+				"a (o) > a (n)",
+				"Status Added",
+				"@@ -0,0 +1,1 @@",
+				"+  1| line1",
+				"b (o) > b (n)",
+				"Status Added",
+				"@@ -0,0 +1,1 @@",
+				"+  1| line1"
+			);
+			Ok(())
+		});
+	}
+
+	#[test]
+	fn load_from_hash_not_shallow_boundary() {
+		with_temp_repository(|repo| {
+			let diff = diff_from_head(&repo, &CommitDiffLoaderOptions::new());
+			assert!(!diff.is_shallow_boundary());
+			Ok(())
+		});
+	}
+
+	#[test]
+	fn load_from_hash_missing_tree() {
+		with_temp_repository(|repo| {
+			write_normal_file(&repo, "a", &["line1"])?;
+			create_commit(&repo)?;
+			let tree_id = head_commit(&repo).tree_id();
+			let partial_repo = remove_object(&repo, tree_id);
+			let diff = diff_from_head(&partial_repo, &CommitDiffLoaderOptions::new());
+			assert!(!diff.is_tree_available());
+			assert!(diff.file_statuses().is_empty());
+			assert_eq!(diff.number_files_changed(), 0);
+			Ok(())
+		});
+	}
+
+	#[test]
+	fn load_from_hash_missing_file_content() {
+		with_temp_repository(|repo| {
+			write_normal_file(&repo, "a", &["line1"])?;
+			write_normal_file(&repo, "b", &["line1"])?;
+			create_commit(&repo)?;
+			write_normal_file(&repo, "a", &["line2"])?;
+			write_normal_file(&repo, "b", &["line2", "line3"])?;
+			create_commit(&repo)?;
+			let blob_id = head_commit(&repo).tree()?.get_name("a").unwrap().id();
+			let partial_repo = remove_object(&repo, blob_id);
+			let diff = diff_from_head(&partial_repo, &CommitDiffLoaderOptions::new().copies(true).renames(true, 200));
+			assert!(diff.is_tree_available());
+			assert_eq!(diff.number_files_changed(), 2);
+			assert_eq!(diff.number_insertions(), 2);
+			assert_eq!(diff.number_deletions(), 1);
+			assert!(!diff.file_statuses()[0].is_content_available());
+			assert!(diff.file_statuses()[1].is_content_available());
+			assert_commit_diff!(
+				&diff,
+				"a (n)",
+				"Status Modified",
+				"b (n)",
+				"Status Modified",
+				"@@ -1,1 +1,2 @@",
+				"-1  | line1",
+				"+  1| line2",
+				"+  2| line3"
+			);
+			Ok(())
+		});
+	}
+
+	fn missing_objects_from_head(repository: &crate::Repository) -> Vec<Oid> {
+		let options = CommitDiffLoaderOptions::new();
+		let loader = CommitDiffLoader::new(repository.git2_repository(), &options);
+		loader.find_missing_objects(head_commit(repository).id()).unwrap()
+	}
+
+	#[test]
+	fn find_missing_objects_none() {
+		with_temp_repository(|repo| {
+			write_normal_file(&repo, "a", &["line1"])?;
+			create_commit(&repo)?;
+			assert!(missing_objects_from_head(&repo).is_empty());
+			Ok(())
+		});
+	}
+
+	#[test]
+	fn find_missing_objects_tree() {
+		with_temp_repository(|repo| {
+			write_normal_file(&repo, "a", &["line1"])?;
+			create_commit(&repo)?;
+			let tree_id = head_commit(&repo).tree_id();
+			let partial_repo = remove_object(&repo, tree_id);
+			assert_eq!(missing_objects_from_head(&partial_repo), vec![tree_id]);
+			Ok(())
+		});
+	}
+
+	#[test]
+	fn find_missing_objects_tree_within_tree() {
+		with_temp_repository(|repo| {
+			create_dir(repo.work_directory().unwrap().join("a")).unwrap();
+			write_normal_file(&repo, "a/b", &["line1"])?;
+			create_commit(&repo)?;
+			let head = head_commit(&repo);
+			let tree_ids = vec![head.tree_id(), head.parent(0)?.tree_id()];
+			let subtree_id = head.tree()?.get_name("a").unwrap().id();
+			let partial_repo = remove_object(&repo, subtree_id);
+			assert_eq!(missing_objects_from_head(&partial_repo), tree_ids);
+			Ok(())
+		});
+	}
+
+	#[test]
+	fn find_missing_objects_file_content() {
+		with_temp_repository(|repo| {
+			write_normal_file(&repo, "a", &["line1"])?;
+			write_normal_file(&repo, "b", &["line1"])?;
+			create_commit(&repo)?;
+			write_normal_file(&repo, "a", &["line2"])?;
+			write_normal_file(&repo, "b", &["line2"])?;
+			create_commit(&repo)?;
+			let blob_id = head_commit(&repo).tree()?.get_name("a").unwrap().id();
+			let partial_repo = remove_object(&repo, blob_id);
+			assert_eq!(missing_objects_from_head(&partial_repo), vec![blob_id]);
+			Ok(())
+		});
+	}
 }
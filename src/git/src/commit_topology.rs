@@ -0,0 +1,11 @@
+/// The position of a commit in the history of a range of commits, relative to the first-parent history of the range.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[allow(clippy::exhaustive_enums)]
+pub enum CommitTopology {
+	/// A commit in the first-parent history, that is not a merge.
+	Mainline,
+	/// A merge commit in the first-parent history.
+	Merge,
+	/// A commit on a branch that is merged into the first-parent history.
+	SideBranch,
+}
@@ -0,0 +1,9 @@
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[allow(clippy::exhaustive_enums)]
+/// The git config file that settings are written to
+pub enum ConfigLocation {
+	/// The user's global config file, usually `~/.gitconfig`
+	Global,
+	/// The config file of the repository, shared between all worktrees of the repository
+	Local,
+}
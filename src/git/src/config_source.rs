@@ -0,0 +1,49 @@
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[allow(clippy::exhaustive_enums)]
+/// Where the value of a config setting was loaded from
+pub enum ConfigSource {
+	/// The setting is not set in any git config file, so the default value is used
+	Default,
+	/// The `config.toml` file of the tool, in the config directory of the user
+	ConfigFile,
+	/// The system wide config file, usually `/etc/gitconfig`
+	System,
+	/// The user's global config file, usually `~/.gitconfig`
+	Global,
+	/// The config file of the repository, or of the worktree
+	Local,
+	/// The `GIRT_*` environment variables
+	Environment,
+	/// The `-c` option of the tool
+	CommandLine,
+}
+
+impl From<git2::ConfigLevel> for ConfigSource {
+	#[inline]
+	fn from(level: git2::ConfigLevel) -> Self {
+		match level {
+			git2::ConfigLevel::ProgramData | git2::ConfigLevel::System => Self::System,
+			git2::ConfigLevel::XDG | git2::ConfigLevel::Global => Self::Global,
+			git2::ConfigLevel::Local | git2::ConfigLevel::App | git2::ConfigLevel::Highest => Self::Local,
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use rstest::rstest;
+
+	use super::*;
+
+	#[rstest]
+	#[case::program_data(git2::ConfigLevel::ProgramData, ConfigSource::System)]
+	#[case::system(git2::ConfigLevel::System, ConfigSource::System)]
+	#[case::xdg(git2::ConfigLevel::XDG, ConfigSource::Global)]
+	#[case::global(git2::ConfigLevel::Global, ConfigSource::Global)]
+	#[case::local(git2::ConfigLevel::Local, ConfigSource::Local)]
+	#[case::app(git2::ConfigLevel::App, ConfigSource::Local)]
+	#[case::highest(git2::ConfigLevel::Highest, ConfigSource::Local)]
+	fn from_config_level(#[case] level: git2::ConfigLevel, #[case] expected: ConfigSource) {
+		assert_eq!(ConfigSource::from(level), expected);
+	}
+}
@@ -0,0 +1,225 @@
+use std::{env::var_os, path::Path};
+
+use anyhow::{anyhow, Result};
+
+/// Writes values to a single git config file, leaving the formatting of the rest of the file intact. Values are
+/// quoted and escaped as needed, so that they are read back unchanged by git.
+pub struct ConfigWriter {
+	config: git2::Config,
+}
+
+impl ::std::fmt::Debug for ConfigWriter {
+	#[inline]
+	fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> Result<(), ::std::fmt::Error> {
+		f.debug_struct("ConfigWriter").finish()
+	}
+}
+
+impl ConfigWriter {
+	/// Open the user's global git config file for writing. If the file does not exist, it is created in the home
+	/// directory on the first write.
+	///
+	/// # Errors
+	/// Will result in an error if the global config file cannot be found or opened.
+	#[inline]
+	pub fn open_global() -> Result<Self> {
+		match git2::Config::find_global() {
+			Ok(path) => Self::open_path(path.as_path()),
+			Err(_) => {
+				let home = var_os("HOME").ok_or_else(|| anyhow!("Could not find the global git config file"))?;
+				Self::open_path(Path::new(&home).join(".gitconfig").as_path())
+			},
+		}
+	}
+
+	/// Open the git config file at `path` for writing. If the file does not exist, it is created on the first write.
+	///
+	/// # Errors
+	/// Will result in an error if the config file cannot be opened.
+	#[inline]
+	pub fn open_path(path: &Path) -> Result<Self> {
+		let config = git2::Config::open(path)
+			.map_err(|e| anyhow!(String::from(e.message())).context("Could not open git config for writing"))?;
+		Ok(Self { config })
+	}
+
+	/// Write a string value for the config `name`, replacing any existing value.
+	///
+	/// # Errors
+	/// Will result in an error if the name is not a valid config name, the value contains a null character, or the
+	/// value cannot be written.
+	#[inline]
+	pub fn set_string(&mut self, name: &str, value: &str) -> Result<()> {
+		if value.contains('\0') {
+			return Err(
+				anyhow!("Value cannot contain a null character").context(format!("Could not write \"{}\"", name))
+			);
+		}
+		self.config.set_str(name, value).map_err(|e| Self::map_err(name, &e))
+	}
+
+	/// Write a boolean value for the config `name`, replacing any existing value.
+	///
+	/// # Errors
+	/// Will result in an error if the value cannot be written.
+	#[inline]
+	pub fn set_bool(&mut self, name: &str, value: bool) -> Result<()> {
+		self.config.set_bool(name, value).map_err(|e| Self::map_err(name, &e))
+	}
+
+	/// Write an integer value for the config `name`, replacing any existing value.
+	///
+	/// # Errors
+	/// Will result in an error if the value cannot be written.
+	#[inline]
+	pub fn set_integer(&mut self, name: &str, value: i64) -> Result<()> {
+		self.config.set_i64(name, value).map_err(|e| Self::map_err(name, &e))
+	}
+
+	/// Remove the value for the config `name`. Removing a value that is not set is not an error.
+	///
+	/// # Errors
+	/// Will result in an error if the value cannot be removed.
+	#[inline]
+	pub fn remove(&mut self, name: &str) -> Result<()> {
+		match self.config.remove(name) {
+			Err(e) if e.code() != git2::ErrorCode::NotFound => Err(Self::map_err(name, &e)),
+			_ => Ok(()),
+		}
+	}
+
+	fn map_err(name: &str, error: &git2::Error) -> anyhow::Error {
+		anyhow!(String::from(error.message())).context(format!("Could not write \"{}\"", name))
+	}
+}
+
+#[cfg(all(unix, test))]
+mod tests {
+	use std::fs::{read_to_string, write};
+
+	use rstest::rstest;
+	use tempfile::tempdir;
+
+	use super::*;
+
+	fn with_config_file<F>(callback: F)
+	where F: FnOnce(&Path, ConfigWriter) {
+		let directory = tempdir().unwrap();
+		let path = directory.path().join("config");
+		let writer = ConfigWriter::open_path(path.as_path()).unwrap();
+		callback(path.as_path(), writer);
+	}
+
+	#[test]
+	fn set_string_creates_file() {
+		with_config_file(|path, mut writer| {
+			writer.set_string("interactive-rebase-tool.pickColor", "green").unwrap();
+			let config = git2::Config::open(path).unwrap();
+			assert_eq!(config.get_string("interactive-rebase-tool.pickColor").unwrap(), "green");
+		});
+	}
+
+	#[test]
+	fn set_string_replaces_value() {
+		with_config_file(|path, mut writer| {
+			writer.set_string("interactive-rebase-tool.pickColor", "green").unwrap();
+			writer.set_string("interactive-rebase-tool.pickColor", "blue").unwrap();
+			let config = git2::Config::open(path).unwrap();
+			assert_eq!(config.get_string("interactive-rebase-tool.pickColor").unwrap(), "blue");
+			assert_eq!(read_to_string(path).unwrap().matches("pickColor").count(), 1);
+		});
+	}
+
+	#[test]
+	fn set_string_keeps_existing_content() {
+		let directory = tempdir().unwrap();
+		let path = directory.path().join("config");
+		write(path.as_path(), "# a comment\n[core]\n\teditor = vim\n").unwrap();
+		let mut writer = ConfigWriter::open_path(path.as_path()).unwrap();
+		writer.set_string("interactive-rebase-tool.pickColor", "green").unwrap();
+		let content = read_to_string(path.as_path()).unwrap();
+		assert!(content.starts_with("# a comment\n[core]\n\teditor = vim\n"));
+	}
+
+	#[rstest]
+	#[case::plain("value")]
+	#[case::empty("")]
+	#[case::double_quote("a \"quoted\" value")]
+	#[case::backslash("C:\\path\\to\\editor")]
+	#[case::comment_hash("value # not a comment")]
+	#[case::comment_semicolon("value ; not a comment")]
+	#[case::leading_whitespace("  value")]
+	#[case::trailing_whitespace("value  ")]
+	#[case::tab("a\tb")]
+	#[case::newline("a\nb")]
+	#[case::equals("a = b")]
+	#[case::brackets("[section]")]
+	#[case::unicode("→·")]
+	fn set_string_escaping(#[case] value: &str) {
+		with_config_file(|path, mut writer| {
+			writer.set_string("interactive-rebase-tool.value", value).unwrap();
+			writer.set_string("interactive-rebase-tool.after", "after").unwrap();
+			let config = git2::Config::open(path).unwrap();
+			assert_eq!(config.get_string("interactive-rebase-tool.value").unwrap(), value);
+			assert_eq!(config.get_string("interactive-rebase-tool.after").unwrap(), "after");
+		});
+	}
+
+	#[test]
+	fn set_string_null_character() {
+		with_config_file(|_, mut writer| {
+			assert_eq!(
+				format!(
+					"{:#}",
+					writer.set_string("interactive-rebase-tool.value", "a\0b").unwrap_err()
+				),
+				"Could not write \"interactive-rebase-tool.value\": Value cannot contain a null character"
+			);
+		});
+	}
+
+	#[test]
+	fn set_string_invalid_name() {
+		with_config_file(|_, mut writer| {
+			assert_eq!(
+				writer.set_string("invalid", "value").unwrap_err().to_string(),
+				"Could not write \"invalid\""
+			);
+		});
+	}
+
+	#[test]
+	fn set_bool() {
+		with_config_file(|path, mut writer| {
+			writer.set_bool("interactive-rebase-tool.autoSelectNext", true).unwrap();
+			let config = git2::Config::open(path).unwrap();
+			assert!(config.get_bool("interactive-rebase-tool.autoSelectNext").unwrap());
+		});
+	}
+
+	#[test]
+	fn set_integer() {
+		with_config_file(|path, mut writer| {
+			writer.set_integer("interactive-rebase-tool.undoLimit", 42).unwrap();
+			let config = git2::Config::open(path).unwrap();
+			assert_eq!(config.get_i64("interactive-rebase-tool.undoLimit").unwrap(), 42);
+		});
+	}
+
+	#[test]
+	fn remove() {
+		with_config_file(|path, mut writer| {
+			writer.set_string("interactive-rebase-tool.pickColor", "green").unwrap();
+			writer.remove("interactive-rebase-tool.pickColor").unwrap();
+			let config = git2::Config::open(path).unwrap();
+			assert!(config.get_string("interactive-rebase-tool.pickColor").is_err());
+		});
+	}
+
+	#[test]
+	fn remove_not_set() {
+		with_config_file(|_, mut writer| {
+			assert!(writer.remove("interactive-rebase-tool.pickColor").is_ok());
+		});
+	}
+}
@@ -1,14 +1,34 @@
-use crate::DiffLine;
+use std::{collections::HashSet, mem::size_of, num::NonZeroU32, sync::Arc};
+
+use crate::{diff_interner::DiffInterner, DiffLine, Origin};
+
+/// The position and details of a line, the content of which is stored in the content of the `Delta`.
+///
+/// A diff can have millions of lines, so the entry is kept small, with the positions in the content stored as 32 bit
+/// offsets, and the line numbers, which start at one, stored without the space of an `Option`.
+#[derive(Debug, Copy, Clone, PartialEq)]
+struct LineEntry {
+	content_length: u32,
+	content_start: u32,
+	new_line_number: Option<NonZeroU32>,
+	old_line_number: Option<NonZeroU32>,
+	origin: Origin,
+	end_of_file: bool,
+}
 
 /// Represents a single set of changes
+///
+/// The content of all lines is stored in a single buffer, instead of an allocation for each line, and the lines are
+/// only created, borrowing from that buffer, when iterated.
 #[derive(Debug, Clone, PartialEq)]
 pub struct Delta {
 	old_lines_start: u32,
 	old_number_lines: u32,
 	new_lines_start: u32,
 	new_number_lines: u32,
-	context: String,
-	lines: Vec<DiffLine>,
+	context: Arc<str>,
+	content: String,
+	lines: Vec<LineEntry>,
 }
 
 impl Delta {
@@ -28,29 +48,56 @@ impl Delta {
 			old_number_lines,
 			new_lines_start,
 			new_number_lines,
-			context: String::from(context),
+			context: Arc::from(context),
+			content: String::new(),
 			lines: vec![],
 		}
 	}
 
 	/// Add a `DiffLine`.
 	#[inline]
-	pub fn add_line(&mut self, diff_line: DiffLine) {
-		self.lines.push(diff_line);
+	pub fn add_line(&mut self, diff_line: &DiffLine<'_>) {
+		// the content of a hunk is never close to 4 GiB, but a line past that is kept empty, instead of wrapping
+		let content_start = u32::try_from(self.content.len()).unwrap_or(u32::MAX);
+		let content_length = u32::try_from(diff_line.line().len())
+			.ok()
+			.filter(|length| content_start.checked_add(*length).is_some())
+			.unwrap_or(0);
+		if content_length > 0 {
+			self.content.push_str(diff_line.line());
+		}
+		self.lines.push(LineEntry {
+			content_length,
+			content_start,
+			new_line_number: diff_line.new_line_number().and_then(NonZeroU32::new),
+			old_line_number: diff_line.old_line_number().and_then(NonZeroU32::new),
+			origin: diff_line.origin(),
+			end_of_file: diff_line.end_of_file(),
+		});
 	}
 
 	/// Get the diff context.
 	#[inline]
 	#[must_use]
 	pub fn context(&self) -> &str {
-		self.context.as_str()
+		self.context.as_ref()
 	}
 
 	/// Get the lines.
 	#[inline]
 	#[must_use]
-	pub const fn lines(&self) -> &Vec<DiffLine> {
-		&self.lines
+	pub fn lines(&self) -> impl ExactSizeIterator<Item = DiffLine<'_>> + '_ {
+		self.lines.iter().map(move |entry| {
+			let start = usize::try_from(entry.content_start).unwrap_or(usize::MAX);
+			let length = usize::try_from(entry.content_length).unwrap_or(0);
+			DiffLine::new(
+				entry.origin,
+				self.content.get(start..start.saturating_add(length)).unwrap_or(""),
+				entry.old_line_number.map(NonZeroU32::get),
+				entry.new_line_number.map(NonZeroU32::get),
+				entry.end_of_file,
+			)
+		})
 	}
 
 	/// Get the old lines start.
@@ -81,6 +128,26 @@ impl Delta {
 		self.new_number_lines
 	}
 
+	/// Share the context with the other hunks that have the same context, and release the capacity of the content and
+	/// the lines that was reserved while the lines were added.
+	pub(crate) fn compact(&mut self, interner: &mut DiffInterner) {
+		self.context = interner.intern_context(&self.context);
+		self.content.shrink_to_fit();
+		self.lines.shrink_to_fit();
+	}
+
+	/// The number of bytes that the content and the lines of the hunk use, with the context counted only when it is not
+	/// in the already counted allocations.
+	pub(crate) fn heap_size(&self, counted: &mut HashSet<*const u8>) -> usize {
+		let context_size = if counted.insert(Arc::as_ptr(&self.context).cast::<u8>()) {
+			DiffInterner::shared_size(self.context.len())
+		}
+		else {
+			0
+		};
+		context_size + self.content.capacity() + self.lines.capacity() * size_of::<LineEntry>()
+	}
+
 	pub(crate) fn from(diff_hunk: &git2::DiffHunk<'_>) -> Self {
 		Self::new(
 			std::str::from_utf8(diff_hunk.header()).unwrap_or("<INVALID UTF8>"),
@@ -94,7 +161,7 @@ impl Delta {
 
 #[cfg(test)]
 mod tests {
-	use super::{super::origin::Origin, *};
+	use super::*;
 
 	#[test]
 	fn new_with_correctly_formatted_context() {
@@ -129,7 +196,7 @@ mod tests {
 	#[test]
 	fn add_line() {
 		let mut delta = Delta::new("@@ path/to/file.rs:56 @@ impl Delta {", 10, 12, 3, 4);
-		delta.add_line(DiffLine::new(
+		delta.add_line(&DiffLine::new(
 			Origin::Addition,
 			"this is a line",
 			Some(10),
@@ -139,6 +206,55 @@ mod tests {
 		assert_eq!(delta.lines().len(), 1);
 	}
 
+	#[test]
+	fn lines() {
+		let mut delta = Delta::new("@@ path/to/file.rs:56 @@ impl Delta {", 10, 12, 3, 4);
+		let lines = [
+			DiffLine::new(Origin::Context, "context\n", Some(10), Some(12), false),
+			DiffLine::new(Origin::Deletion, "", Some(11), None, false),
+			DiffLine::new(Origin::Addition, "añadido\n", None, Some(13), false),
+			DiffLine::new(Origin::Addition, "end\n\\ No newline at end of file\n", None, Some(14), true),
+		];
+		for line in &lines {
+			delta.add_line(line);
+		}
+		assert_eq!(delta.lines().collect::<Vec<_>>(), lines);
+	}
+
+	#[test]
+	fn lines_with_line_number_zero() {
+		let mut delta = Delta::new("@@ path/to/file.rs:56 @@ impl Delta {", 10, 12, 3, 4);
+		delta.add_line(&DiffLine::new(Origin::Context, "context\n", Some(0), Some(1), false));
+		assert_eq!(delta.lines().next().unwrap().old_line_number(), None);
+	}
+
+	#[test]
+	fn line_entry_is_compact() {
+		assert_eq!(size_of::<LineEntry>(), 20);
+	}
+
+	#[test]
+	fn compact_shares_context() {
+		let mut interner = DiffInterner::new();
+		let mut delta_1 = Delta::new("@@ -1,1 +1,1 @@ impl Delta {", 1, 1, 1, 1);
+		let mut delta_2 = Delta::new("@@ -9,1 +9,1 @@ impl Delta {", 9, 9, 1, 1);
+		delta_1.compact(&mut interner);
+		delta_2.compact(&mut interner);
+		assert!(Arc::ptr_eq(&delta_1.context, &delta_2.context));
+	}
+
+	#[test]
+	fn heap_size_counts_shared_context_once() {
+		let mut interner = DiffInterner::new();
+		let mut delta_1 = Delta::new("@@ -1,1 +1,1 @@ impl Delta {", 1, 1, 1, 1);
+		let mut delta_2 = Delta::new("@@ -9,1 +9,1 @@ impl Delta {", 9, 9, 1, 1);
+		delta_1.compact(&mut interner);
+		delta_2.compact(&mut interner);
+		let mut counted = HashSet::new();
+		assert_eq!(delta_1.heap_size(&mut counted), DiffInterner::shared_size(12));
+		assert_eq!(delta_2.heap_size(&mut counted), 0);
+	}
+
 	#[test]
 	fn from_diff_hunk() {
 		let diff = git2::Diff::from_buffer(
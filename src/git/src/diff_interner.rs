@@ -0,0 +1,80 @@
+use std::{collections::HashSet, mem::size_of, path::Path, sync::Arc};
+
+/// Shares a single allocation between the equal paths and hunk contexts of a diff, such as the contexts of the hunks
+/// within the same function, or the empty context of the hunks at the start of a file.
+#[derive(Debug, Default)]
+pub(crate) struct DiffInterner {
+	contexts: HashSet<Arc<str>>,
+	paths: HashSet<Arc<Path>>,
+}
+
+impl DiffInterner {
+	pub(crate) fn new() -> Self {
+		Self::default()
+	}
+
+	/// The shared context that is equal to the context, which is the context itself when there is no equal context.
+	pub(crate) fn intern_context(&mut self, context: &Arc<str>) -> Arc<str> {
+		if let Some(interned) = self.contexts.get(context) {
+			return Arc::clone(interned);
+		}
+		let _ = self.contexts.insert(Arc::clone(context));
+		Arc::clone(context)
+	}
+
+	/// The shared path that is equal to the path, which is the path itself when there is no equal path.
+	pub(crate) fn intern_path(&mut self, path: &Arc<Path>) -> Arc<Path> {
+		if let Some(interned) = self.paths.get(path) {
+			return Arc::clone(interned);
+		}
+		let _ = self.paths.insert(Arc::clone(path));
+		Arc::clone(path)
+	}
+
+	/// The number of bytes of a shared allocation of a value of the length, with the counts of its references.
+	pub(crate) const fn shared_size(length: usize) -> usize {
+		length + 2 * size_of::<usize>()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn intern_context_equal() {
+		let mut interner = DiffInterner::new();
+		let first = interner.intern_context(&Arc::from("fn main() {"));
+		let second = interner.intern_context(&Arc::from("fn main() {"));
+		assert!(Arc::ptr_eq(&first, &second));
+	}
+
+	#[test]
+	fn intern_context_different() {
+		let mut interner = DiffInterner::new();
+		let first = interner.intern_context(&Arc::from("fn main() {"));
+		let second = interner.intern_context(&Arc::from("fn other() {"));
+		assert!(!Arc::ptr_eq(&first, &second));
+	}
+
+	#[test]
+	fn intern_path_equal() {
+		let mut interner = DiffInterner::new();
+		let first = interner.intern_path(&Arc::from(Path::new("src/main.rs")));
+		let second = interner.intern_path(&Arc::from(Path::new("src/main.rs")));
+		assert!(Arc::ptr_eq(&first, &second));
+	}
+
+	#[test]
+	fn intern_path_different() {
+		let mut interner = DiffInterner::new();
+		let first = interner.intern_path(&Arc::from(Path::new("src/main.rs")));
+		let second = interner.intern_path(&Arc::from(Path::new("src/lib.rs")));
+		assert!(!Arc::ptr_eq(&first, &second));
+	}
+
+	#[test]
+	fn shared_size() {
+		assert_eq!(DiffInterner::shared_size(10), 10 + 2 * size_of::<usize>());
+	}
+}
@@ -1,22 +1,26 @@
+use std::borrow::Cow;
+
 use crate::Origin;
 
-/// Represents a single line in a diff
+const END_OF_FILE_MARKER: &str = "\n\\ No newline at end of file\n";
+
+/// Represents a single line in a diff, that borrows the content of the line from the `Delta` that contains it.
 #[derive(Debug, Clone, PartialEq)]
-pub struct DiffLine {
+pub struct DiffLine<'d> {
 	end_of_file: bool,
-	line: String,
+	line: Cow<'d, str>,
 	new_line_number: Option<u32>,
 	old_line_number: Option<u32>,
 	origin: Origin,
 }
 
-impl DiffLine {
+impl<'d> DiffLine<'d> {
 	/// Create a new `DiffLine`.
 	#[inline]
 	#[must_use]
 	pub fn new(
 		origin: Origin,
-		line: &str,
+		line: &'d str,
 		old_line_number: Option<u32>,
 		new_line_number: Option<u32>,
 		end_of_file: bool,
@@ -24,11 +28,11 @@ impl DiffLine {
 		Self {
 			end_of_file,
 			// remove the end of file marker from diff
-			line: if end_of_file {
-				line.replace("\n\\ No newline at end of file\n", "")
+			line: if end_of_file && line.contains(END_OF_FILE_MARKER) {
+				Cow::Owned(line.replace(END_OF_FILE_MARKER, ""))
 			}
 			else {
-				String::from(line)
+				Cow::Borrowed(line)
 			},
 			new_line_number,
 			old_line_number,
@@ -47,7 +51,7 @@ impl DiffLine {
 	#[inline]
 	#[must_use]
 	pub fn line(&self) -> &str {
-		self.line.as_str()
+		self.line.as_ref()
 	}
 
 	/// Get the old line number of the `DiffLine`, if it exists, else `None`.
@@ -71,7 +75,20 @@ impl DiffLine {
 		self.end_of_file
 	}
 
-	pub(crate) fn from(diff_line: &git2::DiffLine<'_>) -> Self {
+	/// Get a `DiffLine` that owns the content of the line.
+	#[inline]
+	#[must_use]
+	pub fn into_owned(self) -> DiffLine<'static> {
+		DiffLine {
+			end_of_file: self.end_of_file,
+			line: Cow::Owned(self.line.into_owned()),
+			new_line_number: self.new_line_number,
+			old_line_number: self.old_line_number,
+			origin: self.origin,
+		}
+	}
+
+	pub(crate) fn from(diff_line: &'d git2::DiffLine<'_>) -> Self {
 		Self::new(
 			Origin::from(diff_line.origin_value()),
 			std::str::from_utf8(diff_line.content()).unwrap_or("<INVALID UTF8>"),
@@ -90,7 +107,7 @@ mod tests {
 
 	use super::*;
 
-	fn create_diff_line() -> DiffLine {
+	fn create_diff_line() -> DiffLine<'static> {
 		DiffLine::new(Origin::Addition, "Line", Some(1), Some(2), false)
 	}
 
@@ -119,6 +136,14 @@ mod tests {
 		assert_eq!(create_diff_line().end_of_file(), false);
 	}
 
+	#[test]
+	fn into_owned() {
+		let content = String::from("Line");
+		let diff_line = DiffLine::new(Origin::Addition, content.as_str(), Some(1), Some(2), false).into_owned();
+		drop(content);
+		assert_eq!(diff_line, create_diff_line());
+	}
+
 	#[test]
 	fn new_without_end_of_file() {
 		let diff_line = DiffLine::new(
@@ -166,7 +191,7 @@ mod tests {
 
 		let lines = Mutex::new(vec![]);
 		diff.print(git2::DiffFormat::Patch, |_, _, diff_line| {
-			lines.lock().unwrap().push(DiffLine::from(&diff_line));
+			lines.lock().unwrap().push(DiffLine::from(&diff_line).into_owned());
 			true
 		})
 		.unwrap();
@@ -0,0 +1,39 @@
+/// The size of a loaded `CommitDiff`, with the number of its files, deltas and lines, and an estimate of the bytes of
+/// memory it uses.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct DiffMemoryUsage {
+	pub(crate) bytes: usize,
+	pub(crate) deltas: usize,
+	pub(crate) files: usize,
+	pub(crate) lines: usize,
+}
+
+impl DiffMemoryUsage {
+	/// The number of bytes used by the diff, with the allocations that are shared within the diff counted once.
+	#[inline]
+	#[must_use]
+	pub const fn bytes(&self) -> usize {
+		self.bytes
+	}
+
+	/// The number of deltas of all the files.
+	#[inline]
+	#[must_use]
+	pub const fn deltas(&self) -> usize {
+		self.deltas
+	}
+
+	/// The number of changed files.
+	#[inline]
+	#[must_use]
+	pub const fn files(&self) -> usize {
+		self.files
+	}
+
+	/// The number of lines of all the deltas.
+	#[inline]
+	#[must_use]
+	pub const fn lines(&self) -> usize {
+		self.lines
+	}
+}
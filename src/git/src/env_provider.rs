@@ -0,0 +1,46 @@
+use std::{env, ffi::OsString, io, path::PathBuf};
+
+/// A source of the environment variables and the current directory, that are used to find and open a repository,
+/// so that the environment can be provided, instead of always reading the environment of the process.
+pub trait EnvProvider {
+	/// Get the value of an environment variable, or `None` when the variable is not set.
+	fn var_os(&self, name: &str) -> Option<OsString>;
+
+	/// Get the current directory, which relative paths are resolved from, and the search for a repository starts from.
+	///
+	/// # Errors
+	/// Will result in an error if the current directory cannot be determined.
+	fn current_dir(&self) -> io::Result<PathBuf>;
+}
+
+/// The environment of the process.
+#[derive(Debug, Copy, Clone, Default)]
+#[allow(clippy::exhaustive_structs)]
+pub struct ProcessEnv;
+
+impl EnvProvider for ProcessEnv {
+	#[inline]
+	fn var_os(&self, name: &str) -> Option<OsString> {
+		env::var_os(name)
+	}
+
+	#[inline]
+	fn current_dir(&self) -> io::Result<PathBuf> {
+		env::current_dir()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn process_env_var_os() {
+		assert_eq!(ProcessEnv.var_os("CARGO_MANIFEST_DIR"), env::var_os("CARGO_MANIFEST_DIR"));
+	}
+
+	#[test]
+	fn process_env_current_dir() {
+		assert_eq!(ProcessEnv.current_dir().unwrap(), env::current_dir().unwrap());
+	}
+}
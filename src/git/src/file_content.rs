@@ -0,0 +1,125 @@
+use std::io::Read;
+
+use anyhow::{anyhow, Result};
+
+const CHUNK_SIZE: usize = 64 * 1024;
+
+/// Read the content of a blob in chunks, starting at the byte `offset`, until the callback returns `false` or the end
+/// of the content is reached, returning the size of the blob. The content is streamed from the object database when
+/// it is supported, such as for loose objects, otherwise the blob is loaded.
+pub(crate) fn read_blob_content<F>(
+	repository: &git2::Repository,
+	oid: git2::Oid,
+	offset: usize,
+	mut callback: F,
+) -> Result<usize>
+where
+	F: FnMut(&[u8]) -> bool,
+{
+	let odb = repository.odb()?;
+	if let Ok((mut reader, size, kind)) = odb.reader(oid) {
+		if kind != git2::ObjectType::Blob {
+			return Err(anyhow!("{} is not a file", oid));
+		}
+		let mut buffer = vec![0; CHUNK_SIZE];
+		let mut position = 0;
+		// a read of the stream reports the length of the buffer, instead of the number of bytes read, and fills the
+		// buffer until the end of the content, so the size of the blob is used to find the end of the content
+		while position < size {
+			let length = reader.read(buffer.as_mut_slice())?.min(size - position);
+			if length == 0 {
+				break;
+			}
+			let chunk_start = position;
+			position += length;
+			if position <= offset {
+				continue;
+			}
+			let chunk = buffer.get(offset.saturating_sub(chunk_start)..length).unwrap_or(&[]);
+			if !callback(chunk) {
+				break;
+			}
+		}
+		return Ok(size);
+	}
+
+	let blob = repository.find_blob(oid)?;
+	read_content(blob.content(), offset, callback);
+	Ok(blob.size())
+}
+
+/// Provide the loaded content of a blob to the callback in chunks, starting at the byte `offset`, until the callback
+/// returns `false` or the end of the content is reached.
+pub(crate) fn read_content<F>(content: &[u8], offset: usize, mut callback: F)
+where F: FnMut(&[u8]) -> bool {
+	for chunk in content.get(offset..).unwrap_or(&[]).chunks(CHUNK_SIZE) {
+		if !callback(chunk) {
+			break;
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::testutil::with_temp_repository;
+
+	fn read_all(repository: &git2::Repository, oid: git2::Oid, offset: usize) -> (usize, Vec<u8>) {
+		let mut content = vec![];
+		let size = read_blob_content(repository, oid, offset, |chunk| {
+			content.extend_from_slice(chunk);
+			true
+		})
+		.unwrap();
+		(size, content)
+	}
+
+	#[test]
+	fn read_from_start() {
+		with_temp_repository(|repository| {
+			let repo = repository.git2_repository();
+			let oid = repo.blob(b"line 1\nline 2\n")?;
+			assert_eq!(read_all(repo, oid, 0), (14, b"line 1\nline 2\n".to_vec()));
+			Ok(())
+		});
+	}
+
+	#[test]
+	fn read_from_offset() {
+		with_temp_repository(|repository| {
+			let repo = repository.git2_repository();
+			let oid = repo.blob(b"line 1\nline 2\n")?;
+			assert_eq!(read_all(repo, oid, 7), (14, b"line 2\n".to_vec()));
+			assert_eq!(read_all(repo, oid, 20), (14, vec![]));
+			Ok(())
+		});
+	}
+
+	#[test]
+	fn read_in_chunks_until_stopped() {
+		with_temp_repository(|repository| {
+			let repo = repository.git2_repository();
+			let content = vec![b'a'; CHUNK_SIZE * 3];
+			let oid = repo.blob(content.as_slice())?;
+			let mut chunks = vec![];
+			let size = read_blob_content(repo, oid, 10, |chunk| {
+				chunks.push(chunk.len());
+				chunks.len() < 2
+			})
+			.unwrap();
+			assert_eq!(size, CHUNK_SIZE * 3);
+			assert_eq!(chunks, vec![CHUNK_SIZE - 10, CHUNK_SIZE]);
+			Ok(())
+		});
+	}
+
+	#[test]
+	fn read_not_a_blob() {
+		with_temp_repository(|repository| {
+			let repo = repository.git2_repository();
+			let tree = repo.head()?.peel_to_tree()?.id();
+			assert!(read_blob_content(repo, tree, 0, |_| true).is_err());
+			Ok(())
+		});
+	}
+}
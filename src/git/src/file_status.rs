@@ -1,25 +1,26 @@
-use std::path::{Path, PathBuf};
+use std::{collections::HashSet, mem::size_of, path::Path, sync::Arc};
 
 use super::{delta::Delta, status::Status};
-use crate::file_mode::FileMode;
+use crate::{diff_interner::DiffInterner, file_mode::FileMode};
 
 /// Represents a file change within a Git repository
 #[derive(Debug, Clone, PartialEq)]
 pub struct FileStatus {
+	pub(crate) content_available: bool,
 	pub(crate) deltas: Vec<Delta>,
 	pub(crate) destination_is_binary: bool,
 	pub(crate) destination_mode: FileMode,
-	pub(crate) destination_path: PathBuf,
+	pub(crate) destination_path: Arc<Path>,
 	pub(crate) largest_new_line_number: u32,
 	pub(crate) largest_old_line_number: u32,
 	pub(crate) source_is_binary: bool,
 	pub(crate) source_mode: FileMode,
-	pub(crate) source_path: PathBuf,
+	pub(crate) source_path: Arc<Path>,
 	pub(crate) status: Status,
 }
 
 impl FileStatus {
-	/// Create a new `FileStat`.
+	/// Create a new `FileStat`, the paths share an allocation when they are the same.
 	#[inline]
 	#[must_use]
 	pub(crate) fn new<F: AsRef<Path>>(
@@ -31,16 +32,24 @@ impl FileStatus {
 		destination_is_binary: bool,
 		status: Status,
 	) -> Self {
+		let source: Arc<Path> = Arc::from(source_path.as_ref());
+		let destination = if destination_path.as_ref() == source.as_ref() {
+			Arc::clone(&source)
+		}
+		else {
+			Arc::from(destination_path.as_ref())
+		};
 		Self {
+			content_available: true,
 			deltas: vec![],
 			destination_is_binary,
 			destination_mode,
-			destination_path: PathBuf::from(destination_path.as_ref()),
+			destination_path: destination,
 			largest_new_line_number: 0,
 			largest_old_line_number: 0,
 			source_is_binary,
 			source_mode,
-			source_path: PathBuf::from(source_path.as_ref()),
+			source_path: source,
 			status,
 		}
 	}
@@ -48,11 +57,12 @@ impl FileStatus {
 	/// Add a delta to the change.
 	#[inline]
 	pub fn add_delta(&mut self, delta: Delta) {
-		let last_old_line_number = delta.old_lines_start() + delta.old_number_lines();
+		// the line numbers of a corrupt diff can overflow
+		let last_old_line_number = delta.old_lines_start().saturating_add(delta.old_number_lines());
 		if self.largest_old_line_number < last_old_line_number {
 			self.largest_old_line_number = last_old_line_number;
 		}
-		let last_new_line_number = delta.new_lines_start() + delta.new_number_lines();
+		let last_new_line_number = delta.new_lines_start().saturating_add(delta.new_number_lines());
 		if self.largest_new_line_number < last_new_line_number {
 			self.largest_new_line_number = last_new_line_number;
 		}
@@ -70,7 +80,7 @@ impl FileStatus {
 	#[inline]
 	#[must_use]
 	pub fn destination_path(&self) -> &Path {
-		self.destination_path.as_path()
+		self.destination_path.as_ref()
 	}
 
 	/// Get the destination file mode for this change.
@@ -91,7 +101,7 @@ impl FileStatus {
 	#[inline]
 	#[must_use]
 	pub fn source_path(&self) -> &Path {
-		self.source_path.as_path()
+		self.source_path.as_ref()
 	}
 
 	/// Get the source file mode for this change.
@@ -108,6 +118,14 @@ impl FileStatus {
 		self.source_is_binary
 	}
 
+	/// Is the content of the files of this change in the repository, which is not the case for files that have not been
+	/// fetched into a partial clone. The deltas of a change without its content are empty.
+	#[inline]
+	#[must_use]
+	pub const fn is_content_available(&self) -> bool {
+		self.content_available
+	}
+
 	/// Get the deltas for this change.
 	#[inline]
 	#[must_use]
@@ -128,10 +146,37 @@ impl FileStatus {
 	pub const fn last_new_line_number(&self) -> u32 {
 		self.largest_new_line_number
 	}
+
+	/// Share the paths and the contexts of the deltas with the rest of the diff, and release the unused capacity.
+	pub(crate) fn compact(&mut self, interner: &mut DiffInterner) {
+		self.source_path = interner.intern_path(&self.source_path);
+		self.destination_path = interner.intern_path(&self.destination_path);
+		for delta in &mut self.deltas {
+			delta.compact(interner);
+		}
+		self.deltas.shrink_to_fit();
+	}
+
+	/// The number of bytes that the paths and the deltas of the change use, with the shared allocations counted only
+	/// when they are not in the already counted allocations.
+	pub(crate) fn heap_size(&self, counted: &mut HashSet<*const u8>) -> usize {
+		let mut size = self.deltas.capacity() * size_of::<Delta>();
+		for path in [&self.source_path, &self.destination_path] {
+			if counted.insert(Arc::as_ptr(path).cast::<u8>()) {
+				size += DiffInterner::shared_size(path.as_os_str().len());
+			}
+		}
+		for delta in &self.deltas {
+			size += delta.heap_size(counted);
+		}
+		size
+	}
 }
 
 #[cfg(test)]
 mod tests {
+	use std::path::PathBuf;
+
 	use super::*;
 
 	fn create_file_stat() -> FileStatus {
@@ -156,6 +201,20 @@ mod tests {
 		assert_eq!(create_file_stat().destination_path(), PathBuf::from("/to/path"));
 	}
 
+	#[test]
+	fn same_source_and_destination_path_is_shared() {
+		let file_stat = FileStatus::new(
+			Path::new("/path"),
+			FileMode::Normal,
+			false,
+			Path::new("/path"),
+			FileMode::Normal,
+			false,
+			Status::Modified,
+		);
+		assert!(Arc::ptr_eq(&file_stat.source_path, &file_stat.destination_path));
+	}
+
 	#[test]
 	fn destination_mode() {
 		assert_eq!(create_file_stat().destination_mode(), FileMode::Executable);
@@ -181,6 +240,11 @@ mod tests {
 		assert_eq!(create_file_stat().source_is_binary(), false);
 	}
 
+	#[test]
+	fn is_content_available() {
+		assert!(create_file_stat().is_content_available());
+	}
+
 	#[test]
 	fn deltas_empty() {
 		let file_stat = create_file_stat();
@@ -242,4 +306,38 @@ mod tests {
 		file_stat.add_delta(Delta::new("@ path/to/file.rs:56 @ impl Delta {", 10, 10, 5, 5));
 		assert_eq!(file_stat.last_new_line_number(), 25);
 	}
+
+	#[test]
+	fn compact_shares_paths() {
+		let mut interner = DiffInterner::new();
+		let mut file_stat_1 = create_file_stat();
+		let mut file_stat_2 = create_file_stat();
+		file_stat_1.compact(&mut interner);
+		file_stat_2.compact(&mut interner);
+		assert!(Arc::ptr_eq(&file_stat_1.source_path, &file_stat_2.source_path));
+		assert!(Arc::ptr_eq(&file_stat_1.destination_path, &file_stat_2.destination_path));
+	}
+
+	#[test]
+	fn heap_size_counts_shared_path_once() {
+		let file_stat = FileStatus::new(
+			Path::new("/path"),
+			FileMode::Normal,
+			false,
+			Path::new("/path"),
+			FileMode::Normal,
+			false,
+			Status::Modified,
+		);
+		assert_eq!(file_stat.heap_size(&mut HashSet::new()), DiffInterner::shared_size(5));
+	}
+
+	// found by the diff renderer fuzz target
+	#[test]
+	fn deltas_with_line_numbers_that_overflow() {
+		let mut file_stat = create_file_stat();
+		file_stat.add_delta(Delta::new("@ path/to/file.rs:56 @ impl Delta {", u32::MAX, u32::MAX - 1, 5, 5));
+		assert_eq!(file_stat.last_old_line_number(), u32::MAX);
+		assert_eq!(file_stat.last_new_line_number(), u32::MAX);
+	}
 }
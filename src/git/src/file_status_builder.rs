@@ -17,24 +17,23 @@ impl FileStatusBuilder {
 	}
 
 	fn close_delta(&mut self) {
-		if let Some(ref d) = self.delta {
+		if let Some(d) = self.delta.take() {
 			self.file_stat
 				.as_mut()
 				.expect("add_file_stat must be called once before adding a delta")
-				.add_delta(d.clone());
+				.add_delta(d);
 		}
 	}
 
 	fn close_file_stat(&mut self) {
-		if let Some(ref fs) = self.file_stat {
-			self.file_stats.push(fs.clone());
+		if let Some(fs) = self.file_stat.take() {
+			self.file_stats.push(fs);
 		}
 	}
 
 	pub(crate) fn add_file_stat(&mut self, file_stat: FileStatus) {
 		self.close_delta();
 		self.close_file_stat();
-		self.delta = None;
 		self.file_stat = Some(file_stat);
 	}
 
@@ -43,7 +42,7 @@ impl FileStatusBuilder {
 		self.delta = Some(delta);
 	}
 
-	pub(crate) fn add_diff_line(&mut self, diff_line: DiffLine) {
+	pub(crate) fn add_diff_line(&mut self, diff_line: &DiffLine<'_>) {
 		self.delta
 			.as_mut()
 			.expect("add_delta must be called once before adding a diff line")
@@ -178,8 +177,8 @@ mod tests {
 		let mut file_stats_builder = FileStatusBuilder::new();
 		file_stats_builder.add_file_stat(file_stat_1.clone());
 		file_stats_builder.add_delta(delta_1.clone());
-		file_stats_builder.add_diff_line(diff_line_1.clone());
-		delta_1.add_line(diff_line_1);
+		file_stats_builder.add_diff_line(&diff_line_1);
+		delta_1.add_line(&diff_line_1);
 		file_stat_1.add_delta(delta_1);
 		assert_eq!(file_stats_builder.build(), vec![file_stat_1]);
 	}
@@ -209,9 +208,9 @@ mod tests {
 		let mut file_stats_builder = FileStatusBuilder::new();
 		file_stats_builder.add_file_stat(file_stat_1.clone());
 		file_stats_builder.add_delta(delta_1.clone());
-		file_stats_builder.add_diff_line(diff_line_1.clone());
+		file_stats_builder.add_diff_line(&diff_line_1);
 		file_stats_builder.add_file_stat(file_stat_2.clone());
-		delta_1.add_line(diff_line_1);
+		delta_1.add_line(&diff_line_1);
 		file_stat_1.add_delta(delta_1);
 		assert_eq!(file_stats_builder.build(), vec![file_stat_1, file_stat_2]);
 	}
@@ -233,9 +232,9 @@ mod tests {
 		let mut file_stats_builder = FileStatusBuilder::new();
 		file_stats_builder.add_file_stat(file_stat_1.clone());
 		file_stats_builder.add_delta(delta_1.clone());
-		file_stats_builder.add_diff_line(diff_line_1.clone());
+		file_stats_builder.add_diff_line(&diff_line_1);
 		file_stats_builder.add_delta(delta_2.clone());
-		delta_1.add_line(diff_line_1);
+		delta_1.add_line(&diff_line_1);
 		file_stat_1.add_delta(delta_1);
 		file_stat_1.add_delta(delta_2);
 		assert_eq!(file_stats_builder.build(), vec![file_stat_1]);
@@ -262,7 +261,7 @@ mod tests {
 			false,
 			Status::Added,
 		));
-		file_stats_builder.add_diff_line(DiffLine::new(Origin::Addition, "My Line", Some(1), Some(2), false));
+		file_stats_builder.add_diff_line(&DiffLine::new(Origin::Addition, "My Line", Some(1), Some(2), false));
 		let _ = file_stats_builder.build();
 	}
 }
@@ -0,0 +1,131 @@
+use std::path::Path;
+
+use anyhow::Result;
+
+use crate::{Commit, CommitDiff, CommitDiffLoaderOptions, Repository};
+
+/// The operations that read commits, and their diffs and files, from a repository, so that the implementation that
+/// reads the objects of the repository can be chosen when the tool starts. The trait is object safe, so a backend can
+/// be provided as a `&dyn GitBackend`.
+pub trait GitBackend {
+	/// The name of the backend, as shown in the logs and the diagnostics.
+	fn name(&self) -> &'static str;
+
+	/// Load a commit for a commit hash, without loading the diff of the commit.
+	///
+	/// # Errors
+	/// Will result in an error if the commit cannot be found.
+	fn load_commit(&self, hash: &str) -> Result<Commit>;
+
+	/// Load a diff for a commit hash.
+	///
+	/// # Errors
+	/// Will result in an error if the commit cannot be loaded.
+	fn load_commit_diff(&self, hash: &str, options: &CommitDiffLoaderOptions) -> Result<CommitDiff>;
+
+	/// Read the content of the file at `path` in the commit with the hash, in chunks, starting at the byte `offset`,
+	/// until the `callback` returns `false` or the end of the file is reached. Returns the size of the file in bytes.
+	///
+	/// # Errors
+	/// Will result in an error if the commit cannot be found, or if the path is not a file in the commit.
+	fn read_file_content(
+		&self,
+		hash: &str,
+		path: &Path,
+		offset: usize,
+		callback: &mut dyn FnMut(&[u8]) -> bool,
+	) -> Result<usize>;
+
+	/// Find the shortest abbreviation length, of at least `minimum`, at which each of the, possibly abbreviated,
+	/// hashes is unique in the object database.
+	///
+	/// # Errors
+	/// Will result in an error if the object database cannot be opened.
+	fn unique_abbreviation_length(&self, hashes: &[&str], minimum: usize) -> Result<usize>;
+}
+
+impl GitBackend for Repository {
+	#[inline]
+	fn name(&self) -> &'static str {
+		"libgit2"
+	}
+
+	#[inline]
+	fn load_commit(&self, hash: &str) -> Result<Commit> {
+		Self::load_commit(self, hash)
+	}
+
+	#[inline]
+	fn load_commit_diff(&self, hash: &str, options: &CommitDiffLoaderOptions) -> Result<CommitDiff> {
+		Self::load_commit_diff(self, hash, options)
+	}
+
+	#[inline]
+	fn read_file_content(
+		&self,
+		hash: &str,
+		path: &Path,
+		offset: usize,
+		callback: &mut dyn FnMut(&[u8]) -> bool,
+	) -> Result<usize> {
+		Self::read_file_content(self, hash, path, offset, callback)
+	}
+
+	#[inline]
+	fn unique_abbreviation_length(&self, hashes: &[&str], minimum: usize) -> Result<usize> {
+		Self::unique_abbreviation_length(self, hashes.iter().copied(), minimum)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::testutil::RepoBuilder;
+
+	#[test]
+	fn repository_name() {
+		RepoBuilder::new().with_repository(|repository, _| {
+			assert_eq!(GitBackend::name(&repository), "libgit2");
+			Ok(())
+		});
+	}
+
+	#[test]
+	fn repository_delegates_to_the_inherent_methods() {
+		RepoBuilder::new()
+			.commit_files("add file", &[("file.txt", "content\n")])
+			.with_repository(|repository, commits| {
+				let hash = commits.hash("add file");
+				let backend: &dyn GitBackend = &repository;
+				assert_eq!(
+					backend.load_commit(hash.as_str()).unwrap(),
+					repository.load_commit(hash.as_str()).unwrap()
+				);
+				let options = CommitDiffLoaderOptions::new().context_lines(3);
+				assert_eq!(
+					backend
+						.load_commit_diff(hash.as_str(), &options)
+						.unwrap()
+						.file_statuses(),
+					repository
+						.load_commit_diff(hash.as_str(), &options)
+						.unwrap()
+						.file_statuses()
+				);
+				let mut content = vec![];
+				let size = backend
+					.read_file_content(hash.as_str(), Path::new("file.txt"), 0, &mut |chunk| {
+						content.extend_from_slice(chunk);
+						true
+					})
+					.unwrap();
+				assert_eq!(size, 8);
+				assert_eq!(content, b"content\n");
+				assert_eq!(
+					backend.unique_abbreviation_length(&[hash.as_str()], 7).unwrap(),
+					repository.unique_abbreviation_length([hash.as_str()], 7).unwrap()
+				);
+				Ok(())
+			});
+	}
+}
@@ -0,0 +1,471 @@
+use std::{borrow::Cow, convert::Infallible, ops::Range};
+
+use anyhow::{anyhow, Result};
+use gix::{
+	bstr::{BString, ByteSlice},
+	diff::{
+		blob::{diff, intern::InternedInput, intern::Interner, Algorithm},
+		rewrites::{Copies, CopySource},
+		Rewrites,
+	},
+	hash::ObjectId,
+	object::tree::diff::{change::Event, Action},
+	objs::tree::{EntryKind, EntryMode},
+};
+
+use crate::{
+	commit::Commit,
+	commit_diff::CommitDiff,
+	commit_diff_loader_options::CommitDiffLoaderOptions,
+	delta::Delta,
+	diff_line::DiffLine,
+	file_mode::FileMode,
+	file_status::FileStatus,
+	origin::Origin,
+	status::Status,
+};
+
+// the same heuristic as libgit2, a file with a null byte in the first 8000 bytes is a binary file
+const BINARY_CHECK_LENGTH: usize = 8000;
+
+// the same limit as libgit2 on the length of the function context of a hunk header
+const MAXIMUM_FUNCTION_CONTEXT_LENGTH: usize = 80;
+
+// the similarity at which libgit2 considers a file renamed or copied
+const SIMILARITY_THRESHOLD: f32 = 0.5;
+
+/// A change to a file between the trees of the parent and the commit.
+struct FileChange {
+	destination: Option<(ObjectId, EntryMode)>,
+	destination_path: BString,
+	source: Option<(ObjectId, EntryMode)>,
+	source_path: BString,
+	status: Status,
+}
+
+/// Loads the diff of a commit with gitoxide, matching the diffs that are loaded with libgit2 by `CommitDiffLoader`.
+/// Unlike libgit2, file attributes are not read, so binary files are only detected from their content, and a change to
+/// only the mode of a file is not found, since gitoxide only compares the ids of the files.
+pub(crate) struct GixCommitDiffLoader<'d> {
+	options: &'d CommitDiffLoaderOptions,
+	repository: &'d gix::Repository,
+}
+
+impl<'d> GixCommitDiffLoader<'d> {
+	pub(crate) const fn new(repository: &'d gix::Repository, options: &'d CommitDiffLoaderOptions) -> Self {
+		Self { options, repository }
+	}
+
+	/// Load the diff of the commit against its first parent, or against an empty tree for a commit without parents.
+	pub(crate) fn load(&self, commit: &gix::Commit<'_>) -> Result<CommitDiff> {
+		// the parent of a commit at the boundary of a shallow clone is not in the repository, so like Git, the files of
+		// the commit are compared to an empty tree
+		let parent_id = commit.parent_ids().next();
+		let parent = match parent_id.map(|id| self.repository.try_find_object(id)).transpose()? {
+			Some(Some(object)) => Some(object.try_into_commit()?),
+			Some(None) | None => None,
+		};
+		let mut commit_diff = CommitDiff {
+			commit: Commit::from_gix(commit)?,
+			parent: parent.as_ref().map(Commit::from_gix).transpose()?,
+			file_statuses: vec![],
+			number_files_changed: 0,
+			number_insertions: 0,
+			number_deletions: 0,
+			is_shallow_boundary: parent_id.is_some() && parent.is_none(),
+			is_tree_available: true,
+		};
+
+		// the trees that have not been fetched into a partial clone are not in the repository
+		let tree_id = commit.tree_id()?;
+		let parent_tree_id = parent.as_ref().map(gix::Commit::tree_id).transpose()?;
+		if !self.repository.has_object(tree_id) || parent_tree_id.map_or(false, |id| !self.repository.has_object(id)) {
+			commit_diff.is_tree_available = false;
+			return Ok(commit_diff);
+		}
+		let parent_tree = match parent_tree_id {
+			Some(id) => self.repository.find_object(id)?.try_into_tree()?,
+			None => self.repository.empty_tree(),
+		};
+		let tree = self.repository.find_object(tree_id)?.try_into_tree()?;
+
+		// like libgit2, without the content of every file, renames and copies are not found
+		let mut changes = Self::changes(&parent_tree, &tree, None)?;
+		let is_content_available = changes
+			.iter()
+			.all(|change| self.is_available(change.source) && self.is_available(change.destination));
+		if is_content_available && self.rewrites().is_some() {
+			changes = Self::changes(&parent_tree, &tree, self.rewrites())?;
+		}
+
+		let mut number_insertions = 0;
+		let mut number_deletions = 0;
+		for change in changes {
+			let (file_status, insertions, deletions) = self.load_file_status(&change)?;
+			commit_diff.file_statuses.push(file_status);
+			number_insertions += insertions;
+			number_deletions += deletions;
+		}
+		commit_diff.number_files_changed = commit_diff.file_statuses.len();
+		commit_diff.number_insertions = number_insertions;
+		commit_diff.number_deletions = number_deletions;
+		commit_diff.compact();
+		Ok(commit_diff)
+	}
+
+	fn changes(parent_tree: &gix::Tree<'_>, tree: &gix::Tree<'_>, rewrites: Option<Rewrites>) -> Result<Vec<FileChange>> {
+		let mut changes = vec![];
+		let mut added_directories = vec![];
+		let _outcome = parent_tree
+			.changes()?
+			.track_path()
+			.track_rewrites(rewrites)
+			.for_each_to_obtain_tree(tree, |change| {
+				if let Event::Addition { entry_mode, .. } = change.event {
+					if entry_mode.is_tree() {
+						added_directories.push(change.location.to_owned());
+					}
+				}
+				if let Some(file_change) = Self::file_change(change.location.to_owned(), &change.event) {
+					changes.push(file_change);
+				}
+				Ok::<_, Infallible>(Action::Continue)
+			})?;
+		// like libgit2, a file that is replaced by a directory is a change of the type of the file, while a directory
+		// that is replaced by a file is an addition of the file
+		for change in &mut changes {
+			if change.status == Status::Deleted && added_directories.contains(&change.source_path) {
+				change.status = Status::Typechange;
+			}
+		}
+		// libgit2 provides the files in the order of their paths, but the tree is not walked in that order, and the
+		// renamed and copied files are provided last
+		changes.sort_by(|change, other| {
+			change
+				.destination_path
+				.cmp(&other.destination_path)
+				.then_with(|| change.source_path.cmp(&other.source_path))
+		});
+		Ok(changes)
+	}
+
+	// gitoxide tracks copies as a part of tracking renames, so renames are tracked when only copies are requested
+	#[allow(clippy::as_conversions)]
+	fn rewrites(&self) -> Option<Rewrites> {
+		(self.options.renames || self.options.copies).then(|| {
+			Rewrites {
+				copies: self.options.copies.then_some(Copies {
+					source: CopySource::FromSetOfModifiedFilesAndAllSources,
+					percentage: Some(SIMILARITY_THRESHOLD),
+				}),
+				percentage: Some(SIMILARITY_THRESHOLD),
+				limit: self.options.rename_limit as usize,
+			}
+		})
+	}
+
+	fn file_change(location: BString, event: &Event<'_, '_, '_>) -> Option<FileChange> {
+		let (source, source_path, destination, status) = match *event {
+			Event::Addition { entry_mode, id } => (None, location.clone(), Some((id.detach(), entry_mode)), Status::Added),
+			Event::Deletion { entry_mode, id } => {
+				(Some((id.detach(), entry_mode)), location.clone(), None, Status::Deleted)
+			},
+			Event::Modification {
+				previous_entry_mode,
+				previous_id,
+				entry_mode,
+				id,
+			} => {
+				let status = if previous_entry_mode.is_link() != entry_mode.is_link()
+					|| previous_entry_mode.is_commit() != entry_mode.is_commit()
+				{
+					Status::Typechange
+				}
+				else {
+					Status::Modified
+				};
+				(
+					Some((previous_id.detach(), previous_entry_mode)),
+					location.clone(),
+					Some((id.detach(), entry_mode)),
+					status,
+				)
+			},
+			Event::Rewrite {
+				source_location,
+				source_entry_mode,
+				source_id,
+				entry_mode,
+				id,
+				copy,
+				..
+			} => (
+				Some((source_id.detach(), source_entry_mode)),
+				source_location.to_owned(),
+				Some((id.detach(), entry_mode)),
+				if copy { Status::Copied } else { Status::Renamed },
+			),
+		};
+		// the changes to directories are provided along with the changes to their files, and a file that is replaced by
+		// a directory, or a directory by a file, is provided as a deletion and an addition of the path
+		if source.map_or(false, |(_, mode)| mode.is_tree()) || destination.map_or(false, |(_, mode)| mode.is_tree()) {
+			return None;
+		}
+		Some(FileChange {
+			destination,
+			destination_path: location,
+			source,
+			source_path,
+			status,
+		})
+	}
+
+	fn file_mode(side: Option<(ObjectId, EntryMode)>) -> FileMode {
+		match side.map(|(_, mode)| mode.kind()) {
+			Some(EntryKind::Blob) => FileMode::Normal,
+			Some(EntryKind::BlobExecutable) => FileMode::Executable,
+			Some(EntryKind::Link) => FileMode::Link,
+			Some(EntryKind::Tree | EntryKind::Commit) | None => FileMode::Other,
+		}
+	}
+
+	// the files that have not been fetched into a partial clone are not in the repository, while submodules do not have
+	// content in the repository
+	fn is_available(&self, side: Option<(ObjectId, EntryMode)>) -> bool {
+		match side {
+			Some((id, mode)) if mode.is_blob_or_symlink() => self.repository.has_object(id),
+			_ => true,
+		}
+	}
+
+	// the content of a file, or the target of a link, while submodules do not have content in the repository
+	fn content(&self, side: Option<(ObjectId, EntryMode)>) -> Result<Vec<u8>> {
+		match side {
+			Some((id, mode)) if mode.is_blob_or_symlink() => Ok(self.repository.find_object(id)?.detach().data),
+			_ => Ok(vec![]),
+		}
+	}
+
+	fn is_binary(content: &[u8]) -> bool {
+		content.iter().take(BINARY_CHECK_LENGTH).any(|&byte| byte == 0)
+	}
+
+	fn load_file_status(&self, change: &FileChange) -> Result<(FileStatus, usize, usize)> {
+		if !self.is_available(change.source) || !self.is_available(change.destination) {
+			let mut file_status = Self::file_status(change, false, false)?;
+			file_status.content_available = false;
+			return Ok((file_status, 0, 0));
+		}
+		let source_content = self.content(change.source)?;
+		let destination_content = self.content(change.destination)?;
+		let source_is_binary = Self::is_binary(source_content.as_slice());
+		let destination_is_binary = Self::is_binary(destination_content.as_slice());
+		let mut file_status = Self::file_status(change, source_is_binary, destination_is_binary)?;
+		// like libgit2, the content of a file that changed type is not compared
+		if source_is_binary || destination_is_binary || change.status == Status::Typechange {
+			return Ok((file_status, 0, 0));
+		}
+		let (insertions, deletions) = self.add_deltas(
+			&mut file_status,
+			source_content.as_slice(),
+			destination_content.as_slice(),
+		);
+		Ok((file_status, insertions, deletions))
+	}
+
+	fn file_status(change: &FileChange, source_is_binary: bool, destination_is_binary: bool) -> Result<FileStatus> {
+		Ok(FileStatus::new(
+			gix::path::try_from_bstr(change.source_path.as_bstr())
+				.map_err(|err| anyhow!("{}", err))?
+				.as_ref(),
+			Self::file_mode(change.source),
+			source_is_binary,
+			gix::path::try_from_bstr(change.destination_path.as_bstr())
+				.map_err(|err| anyhow!("{}", err))?
+				.as_ref(),
+			Self::file_mode(change.destination),
+			destination_is_binary,
+			change.status,
+		))
+	}
+
+	// the key that a line is compared with, which ignores whitespace as configured
+	fn line_key<'l>(&self, line: &'l [u8]) -> Cow<'l, [u8]> {
+		if self.options.ignore_whitespace {
+			Cow::Owned(line.iter().copied().filter(|byte| !byte.is_ascii_whitespace()).collect())
+		}
+		else if self.options.ignore_whitespace_change {
+			let mut key = vec![];
+			let mut previous_is_whitespace = false;
+			for &byte in line.trim_end() {
+				if byte.is_ascii_whitespace() {
+					if !previous_is_whitespace {
+						key.push(b' ');
+					}
+					previous_is_whitespace = true;
+				}
+				else {
+					key.push(byte);
+					previous_is_whitespace = false;
+				}
+			}
+			Cow::Owned(key)
+		}
+		else {
+			Cow::Borrowed(line)
+		}
+	}
+
+	// like the default of libgit2, the nearest line before the hunk, in the source, that starts with a letter, `_` or
+	// `$` is used as the function context of the hunk
+	fn function_context(lines: &[&[u8]], hunk_start: usize) -> String {
+		lines
+			.get(..hunk_start)
+			.unwrap_or(&[])
+			.iter()
+			.rev()
+			.find(|line| {
+				line.first()
+					.map_or(false, |&byte| byte.is_ascii_alphabetic() || byte == b'_' || byte == b'$')
+			})
+			.map_or_else(String::new, |line| {
+				let context = line.get(..MAXIMUM_FUNCTION_CONTEXT_LENGTH).unwrap_or(line).trim_end();
+				String::from_utf8_lossy(context).into_owned()
+			})
+	}
+
+	fn push_line(delta: &mut Delta, origin: Origin, line: &[u8], old_line_number: Option<u32>, new_line_number: Option<u32>) {
+		delta.add_line(&DiffLine::new(
+			origin,
+			std::str::from_utf8(line).unwrap_or("<INVALID UTF8>"),
+			old_line_number,
+			new_line_number,
+			false,
+		));
+		// like libgit2, a line without a new line is followed by a marker, with the origin of the other side
+		if !line.ends_with(b"\n") {
+			let marker_origin = match origin {
+				Origin::Addition => Origin::Deletion,
+				Origin::Deletion => Origin::Addition,
+				Origin::Binary | Origin::Context | Origin::Header => origin,
+			};
+			delta.add_line(&DiffLine::new(marker_origin, "", old_line_number, new_line_number, true));
+		}
+	}
+
+	/// Add the hunks of the changes between the content of the source and the destination, returning the number of
+	/// inserted and deleted lines.
+	#[allow(clippy::as_conversions, clippy::cast_possible_truncation)]
+	fn add_deltas(&self, file_status: &mut FileStatus, source: &[u8], destination: &[u8]) -> (usize, usize) {
+		let source_lines = source.split_inclusive(|&byte| byte == b'\n').collect::<Vec<&[u8]>>();
+		let destination_lines = destination.split_inclusive(|&byte| byte == b'\n').collect::<Vec<&[u8]>>();
+		let mut interner = Interner::new(source_lines.len() + destination_lines.len());
+		let input = InternedInput {
+			before: source_lines.iter().map(|line| interner.intern(self.line_key(line))).collect(),
+			after: destination_lines
+				.iter()
+				.map(|line| interner.intern(self.line_key(line)))
+				.collect(),
+			interner,
+		};
+		let mut changes: Vec<(Range<usize>, Range<usize>)> = vec![];
+		diff(
+			Algorithm::MyersMinimal,
+			&input,
+			|before: Range<u32>, after: Range<u32>| {
+				changes.push((
+					before.start as usize..before.end as usize,
+					after.start as usize..after.end as usize,
+				));
+			},
+		);
+
+		let context = self.options.context_lines as usize;
+		// changes that are separated by few enough unchanged lines are shown in the same hunk
+		let maximum_gap = 2 * context + self.options.interhunk_context as usize;
+		let mut insertions = 0;
+		let mut deletions = 0;
+		let mut hunk_start = 0;
+		while let Some(first) = changes.get(hunk_start) {
+			let mut hunk_end = hunk_start;
+			while let (Some(current), Some(next)) = (changes.get(hunk_end), changes.get(hunk_end + 1)) {
+				if next.0.start - current.0.end > maximum_gap {
+					break;
+				}
+				hunk_end += 1;
+			}
+			let last = changes.get(hunk_end).unwrap_or(first);
+
+			let leading_context = context.min(first.0.start);
+			let trailing_context = context.min(source_lines.len() - last.0.end);
+			let old_start = first.0.start - leading_context;
+			let new_start = first.1.start - leading_context;
+			let old_count = last.0.end + trailing_context - old_start;
+			let new_count = last.1.end + trailing_context - new_start;
+			// the start of an empty range is the line before the range
+			let old_lines_start = if old_count == 0 { old_start } else { old_start + 1 };
+			let new_lines_start = if new_count == 0 { new_start } else { new_start + 1 };
+			let header = format!(
+				"@@ -{},{} +{},{} @@ {}",
+				old_lines_start,
+				old_count,
+				new_lines_start,
+				new_count,
+				Self::function_context(source_lines.as_slice(), old_start)
+			);
+			let mut delta = Delta::new(
+				header.as_str(),
+				old_lines_start as u32,
+				new_lines_start as u32,
+				old_count as u32,
+				new_count as u32,
+			);
+
+			// the unchanged lines are shown from the destination, as libgit2 does when whitespace is ignored
+			let mut old_index = old_start;
+			let mut new_index = new_start;
+			let push_context = |hunk: &mut Delta, old_line: &mut usize, new_line: &mut usize, old_end: usize| {
+				while *old_line < old_end {
+					Self::push_line(
+						hunk,
+						Origin::Context,
+						destination_lines.get(*new_line).copied().unwrap_or(&[]),
+						Some(*old_line as u32 + 1),
+						Some(*new_line as u32 + 1),
+					);
+					*old_line += 1;
+					*new_line += 1;
+				}
+			};
+			for (before, after) in changes.get(hunk_start..=hunk_end).unwrap_or(&[]) {
+				push_context(&mut delta, &mut old_index, &mut new_index, before.start);
+				for index in before.clone() {
+					Self::push_line(
+						&mut delta,
+						Origin::Deletion,
+						source_lines.get(index).copied().unwrap_or(&[]),
+						Some(index as u32 + 1),
+						None,
+					);
+				}
+				for index in after.clone() {
+					Self::push_line(
+						&mut delta,
+						Origin::Addition,
+						destination_lines.get(index).copied().unwrap_or(&[]),
+						None,
+						Some(index as u32 + 1),
+					);
+				}
+				deletions += before.len();
+				insertions += after.len();
+				old_index = before.end;
+				new_index = after.end;
+			}
+			push_context(&mut delta, &mut old_index, &mut new_index, old_start + old_count);
+			file_status.add_delta(delta);
+			hunk_start = hunk_end + 1;
+		}
+		(insertions, deletions)
+	}
+}
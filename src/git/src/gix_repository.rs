@@ -0,0 +1,505 @@
+use std::path::Path;
+
+use anyhow::{anyhow, Result};
+use gix::hash::Prefix;
+
+use crate::{
+	file_content::read_content,
+	gix_commit_diff_loader::GixCommitDiffLoader,
+	repository::{MAXIMUM_HASH_LENGTH, MINIMUM_HASH_LENGTH},
+	Commit,
+	CommitDiff,
+	CommitDiffLoaderOptions,
+	GitBackend,
+};
+
+/// An experimental backend that reads the commits of a repository with gitoxide, rather than libgit2, which is only
+/// available with the `gix` feature. The configuration of the repository, and the state of a rebase, are still read
+/// with the `Repository`.
+pub struct GixRepository {
+	repository: gix::Repository,
+}
+
+impl GixRepository {
+	/// Open a repository from the path of its git directory, or of its working directory.
+	///
+	/// # Errors
+	/// Will result in an error if the repository cannot be opened.
+	#[inline]
+	pub fn open_from_path(path: &Path) -> Result<Self> {
+		let repository = gix::open(path).map_err(|err| anyhow!(err).context("Could not open repository"))?;
+		Ok(Self { repository })
+	}
+
+	fn find_commit(&self, hash: &str) -> Result<gix::Commit<'_>> {
+		let find = || -> Result<gix::Commit<'_>> {
+			let object = self.repository.rev_parse_single(hash)?.object()?;
+			Ok(object.peel_to_kind(gix::object::Kind::Commit)?.into_commit())
+		};
+		find().map_err(|err| anyhow!("{}", err).context(format!("Could not load commit {}", hash)))
+	}
+
+	// a prefix is unique when it matches a single object, while a prefix that matches no objects is not unique
+	fn is_unique_prefix(&self, prefix: Prefix) -> Result<bool> {
+		Ok(matches!(
+			self.repository
+				.objects
+				.lookup_prefix(prefix, None)
+				.map_err(|err| anyhow!("{}", err))?,
+			Some(Ok(_))
+		))
+	}
+}
+
+impl GitBackend for GixRepository {
+	#[inline]
+	fn name(&self) -> &'static str {
+		"gitoxide"
+	}
+
+	#[inline]
+	fn load_commit(&self, hash: &str) -> Result<Commit> {
+		let commit = self.find_commit(hash)?;
+		Ok(Commit::from_gix(&commit)?)
+	}
+
+	#[inline]
+	fn load_commit_diff(&self, hash: &str, options: &CommitDiffLoaderOptions) -> Result<CommitDiff> {
+		let commit = self.find_commit(hash)?;
+		GixCommitDiffLoader::new(&self.repository, options).load(&commit)
+	}
+
+	#[inline]
+	fn read_file_content(
+		&self,
+		hash: &str,
+		path: &Path,
+		offset: usize,
+		callback: &mut dyn FnMut(&[u8]) -> bool,
+	) -> Result<usize> {
+		let not_found = || format!("Could not find {} in {}", path.display(), hash);
+		let entry = self
+			.find_commit(hash)
+			.and_then(|commit| commit.tree().map_err(|err| anyhow!("{}", err)))
+			.and_then(|mut tree| tree.peel_to_entry_by_path(path).map_err(|err| anyhow!("{}", err)))
+			.map_err(|err| err.context(not_found()))?
+			.ok_or_else(|| anyhow!("the path '{}' does not exist in the given tree", path.display()).context(not_found()))?;
+		if entry.mode().is_tree() || entry.mode().is_commit() {
+			return Err(anyhow!("{} is not a file", entry.object_id()));
+		}
+		let content = entry.object().map_err(|err| anyhow!("{}", err))?.detach().data;
+		read_content(content.as_slice(), offset, callback);
+		Ok(content.len())
+	}
+
+	#[inline]
+	fn unique_abbreviation_length(&self, hashes: &[&str], minimum: usize) -> Result<usize> {
+		let mut length = minimum.clamp(MINIMUM_HASH_LENGTH, MAXIMUM_HASH_LENGTH);
+		for &hash in hashes {
+			let prefix = match Prefix::from_hex(hash) {
+				Ok(prefix) => prefix,
+				Err(_) => continue,
+			};
+			let oid = match self.repository.objects.lookup_prefix(prefix, None) {
+				Ok(Some(Ok(oid))) => oid,
+				_ => continue,
+			};
+			// a prefix is ambiguous when another object shares it, and is unique at every longer length
+			while length < MAXIMUM_HASH_LENGTH && !self.is_unique_prefix(Prefix::new(&oid, length)?)? {
+				length += 1;
+			}
+		}
+		Ok(length)
+	}
+}
+
+impl ::std::fmt::Debug for GixRepository {
+	#[inline]
+	fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> Result<(), ::std::fmt::Error> {
+		f.debug_struct("GixRepository")
+			.field("[path]", &self.repository.path())
+			.finish()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		testutil::{with_temp_repository, JAN_2021_EPOCH},
+		Repository,
+	};
+
+	const NORMAL: u32 = 0o100_644;
+	const EXECUTABLE: u32 = 0o100_755;
+	const LINK: u32 = 0o120_000;
+
+	// commit the files, with their modes and content, as the complete tree of the commit
+	fn commit(repository: &Repository, message: &str, files: &[(&str, u32, &str)]) -> Result<String, git2::Error> {
+		let repo = repository.git2_repository();
+		let mut index = repo.index()?;
+		index.clear()?;
+		for &(path, mode, content) in files {
+			let entry = git2::IndexEntry {
+				ctime: git2::IndexTime::new(0, 0),
+				mtime: git2::IndexTime::new(0, 0),
+				dev: 0,
+				ino: 0,
+				mode,
+				uid: 0,
+				gid: 0,
+				file_size: 0,
+				id: git2::Oid::zero(),
+				flags: 0,
+				flags_extended: 0,
+				path: path.as_bytes().to_vec(),
+			};
+			index.add_frombuffer(&entry, content.as_bytes())?;
+		}
+		let tree = repo.find_tree(index.write_tree()?)?;
+		let signature = git2::Signature::new("name", "name@example.com", &git2::Time::new(JAN_2021_EPOCH, 0))?;
+		let head = repo.head()?.peel_to_commit()?;
+		Ok(repo
+			.commit(Some("HEAD"), &signature, &signature, message, &tree, &[&head])?
+			.to_string())
+	}
+
+	fn assert_same_diff(repository: &Repository, hash: &str, options: &CommitDiffLoaderOptions) {
+		let gix_repository = GixRepository::open_from_path(repository.git_directory()).unwrap();
+		let expected = repository.load_commit_diff(hash, options).unwrap();
+		let actual = gix_repository.load_commit_diff(hash, options).unwrap();
+		pretty_assertions::assert_eq!(actual.commit(), expected.commit());
+		pretty_assertions::assert_eq!(actual.parent(), expected.parent());
+		pretty_assertions::assert_eq!(actual.file_statuses(), expected.file_statuses());
+		assert_eq!(actual.number_files_changed(), expected.number_files_changed());
+		assert_eq!(actual.number_insertions(), expected.number_insertions());
+		assert_eq!(actual.number_deletions(), expected.number_deletions());
+		assert_eq!(actual.is_shallow_boundary(), expected.is_shallow_boundary());
+		assert_eq!(actual.is_tree_available(), expected.is_tree_available());
+	}
+
+	// remove an object, as if it had not been fetched into a shallow or partial clone, returning a repository without
+	// the objects that were cached by the repository
+	fn remove_object(repository: &Repository, spec: &str) -> Repository {
+		let id = repository.git2_repository().revparse_single(spec).unwrap().id().to_string();
+		let objects_path = repository.git_directory().join("objects");
+		std::fs::remove_file(objects_path.join(&id[..2]).join(&id[2..])).unwrap();
+		Repository::open_from_path(repository.git_directory()).unwrap()
+	}
+
+	// the diff of each commit, after the first, is the same from both backends
+	fn assert_same_diffs(commits: &[&[(&str, u32, &str)]], options: CommitDiffLoaderOptions) {
+		with_temp_repository(|repository| {
+			for (index, files) in commits.iter().enumerate() {
+				let hash = commit(&repository, format!("commit {}\n\nbody", index).as_str(), files)?;
+				assert_same_diff(&repository, hash.as_str(), &options);
+			}
+			Ok(())
+		});
+	}
+
+	#[test]
+	fn name() {
+		with_temp_repository(|repository| {
+			let gix_repository = GixRepository::open_from_path(repository.git_directory()).unwrap();
+			assert_eq!(gix_repository.name(), "gitoxide");
+			Ok(())
+		});
+	}
+
+	#[test]
+	fn open_from_path_not_a_repository() {
+		let directory = tempfile::tempdir().unwrap();
+		assert_eq!(
+			format!("{}", GixRepository::open_from_path(directory.path()).unwrap_err()),
+			"Could not open repository"
+		);
+	}
+
+	#[test]
+	fn load_commit_same_as_libgit2() {
+		with_temp_repository(|repository| {
+			let hash = commit(&repository, "\nsummary\nline\n\nbody\n", &[])?;
+			let gix_repository = GixRepository::open_from_path(repository.git_directory()).unwrap();
+			assert_eq!(
+				gix_repository.load_commit(hash.as_str()).unwrap(),
+				repository.load_commit(hash.as_str()).unwrap()
+			);
+			Ok(())
+		});
+	}
+
+	#[test]
+	fn load_commit_not_found() {
+		with_temp_repository(|repository| {
+			let gix_repository = GixRepository::open_from_path(repository.git_directory()).unwrap();
+			assert_eq!(
+				format!("{}", gix_repository.load_commit("aaaaaaaa").unwrap_err()),
+				"Could not load commit aaaaaaaa"
+			);
+			Ok(())
+		});
+	}
+
+	#[test]
+	fn load_commit_diff_added_modified_and_deleted_files() {
+		assert_same_diffs(
+			&[
+				&[("a", NORMAL, "line1\n"), ("dir/b", NORMAL, "line1\nline2\n")],
+				&[
+					("a", NORMAL, "line1-m\n"),
+					("c", NORMAL, "new\n"),
+					("x-y", NORMAL, "new\n"),
+					("x/y", NORMAL, "new\n"),
+				],
+				&[("a/b", NORMAL, "line1\n"), ("x-y", NORMAL, "new\n"), ("x", NORMAL, "other\n")],
+			],
+			CommitDiffLoaderOptions::new(),
+		);
+	}
+
+	#[test]
+	fn load_commit_diff_with_context_and_function_context() {
+		assert_same_diffs(
+			&[
+				&[("a", NORMAL, "fn one\n  line1\n  line2\n  line3\n  line4\n  line5\n  line6\n  line7\n")],
+				&[("a", NORMAL, "fn one\n  line1\n  line2-m\n  line3\n  line4\n  line5\n  line6-m\n  line7\n")],
+			],
+			CommitDiffLoaderOptions::new().context_lines(1),
+		);
+	}
+
+	#[test]
+	fn load_commit_diff_interhunk_context() {
+		assert_same_diffs(
+			&[
+				&[("a", NORMAL, "line0\nline1\nline2\nline3\nline4\nline5\n")],
+				&[("a", NORMAL, "line0\nline1-m\nline2\nline3\nline4-m\nline5\n")],
+			],
+			CommitDiffLoaderOptions::new().interhunk_context(2),
+		);
+	}
+
+	#[test]
+	fn load_commit_diff_no_new_line_at_end_of_file() {
+		assert_same_diffs(
+			&[
+				&[("a", NORMAL, "x\ny")],
+				&[("a", NORMAL, "x\nz")],
+				&[("a", NORMAL, "x\nz\n")],
+				&[("a", NORMAL, "w\nx\nz")],
+			],
+			CommitDiffLoaderOptions::new().context_lines(3),
+		);
+	}
+
+	#[test]
+	fn load_commit_diff_ignore_whitespace() {
+		assert_same_diffs(
+			&[&[("a", NORMAL, "line0\nline1\n")], &[("a", NORMAL, "  line0\n line1-m\n")]],
+			CommitDiffLoaderOptions::new().ignore_whitespace(true),
+		);
+	}
+
+	#[test]
+	fn load_commit_diff_ignore_whitespace_change() {
+		assert_same_diffs(
+			&[&[("a", NORMAL, " line0\nline1\n")], &[("a", NORMAL, "  line0\n line1-m\n")]],
+			CommitDiffLoaderOptions::new().ignore_whitespace_change(true),
+		);
+	}
+
+	#[test]
+	fn load_commit_diff_renames() {
+		assert_same_diffs(
+			&[
+				&[("a", NORMAL, "line0\nline1\nline2\n")],
+				&[("b", NORMAL, "line0\nline1\nline2\n")],
+				&[("c", NORMAL, "line0\nline1\nline2-m\n")],
+			],
+			CommitDiffLoaderOptions::new().renames(true, 100),
+		);
+	}
+
+	#[test]
+	fn load_commit_diff_copies() {
+		assert_same_diffs(
+			&[
+				&[("a", NORMAL, "line0\n")],
+				&[("a", NORMAL, "line0\n"), ("b", NORMAL, "line0\n")],
+			],
+			CommitDiffLoaderOptions::new().renames(true, 100).copies(true),
+		);
+	}
+
+	#[test]
+	fn load_commit_diff_file_modes() {
+		assert_same_diffs(
+			&[
+				&[("a", NORMAL, "line0\n"), ("b", NORMAL, "line0\n")],
+				&[("a", EXECUTABLE, "line0-m\n"), ("b", LINK, "a")],
+			],
+			CommitDiffLoaderOptions::new(),
+		);
+	}
+
+	#[test]
+	fn load_commit_diff_only_file_mode_change_not_found() {
+		with_temp_repository(|repository| {
+			let _ = commit(&repository, "commit", &[("a", NORMAL, "line0\n")])?;
+			let hash = commit(&repository, "commit", &[("a", EXECUTABLE, "line0\n")])?;
+			let gix_repository = GixRepository::open_from_path(repository.git_directory()).unwrap();
+			let diff = gix_repository
+				.load_commit_diff(hash.as_str(), &CommitDiffLoaderOptions::new())
+				.unwrap();
+			assert!(diff.file_statuses().is_empty());
+			Ok(())
+		});
+	}
+
+	#[test]
+	fn load_commit_diff_binary() {
+		assert_same_diffs(
+			&[&[("a", NORMAL, "\0binary")], &[("a", NORMAL, "\0binary-m")]],
+			CommitDiffLoaderOptions::new(),
+		);
+	}
+
+	#[test]
+	fn shallow_boundary() {
+		with_temp_repository(|repository| {
+			let _ = commit(&repository, "commit", &[("a", NORMAL, "line0\n")])?;
+			let hash = commit(&repository, "commit", &[("a", NORMAL, "line0\n"), ("b", NORMAL, "line0\n")])?;
+			let shallow_repository = remove_object(&repository, format!("{}^", hash).as_str());
+			assert_same_diff(&shallow_repository, hash.as_str(), &CommitDiffLoaderOptions::new());
+			Ok(())
+		});
+	}
+
+	#[test]
+	fn missing_tree() {
+		with_temp_repository(|repository| {
+			let hash = commit(&repository, "commit", &[("a", NORMAL, "line0\n")])?;
+			let partial_repository = remove_object(&repository, format!("{}^{{tree}}", hash).as_str());
+			assert_same_diff(&partial_repository, hash.as_str(), &CommitDiffLoaderOptions::new());
+			Ok(())
+		});
+	}
+
+	#[test]
+	fn missing_file_content() {
+		with_temp_repository(|repository| {
+			let _ = commit(&repository, "commit", &[("a", NORMAL, "line0\n"), ("b", NORMAL, "line0\n")])?;
+			let hash = commit(&repository, "commit", &[
+				("a", NORMAL, "line1\n"),
+				("b", NORMAL, "line1\nline2\n"),
+				("c", NORMAL, "line0\n"),
+			])?;
+			let partial_repository = remove_object(&repository, format!("{}:a", hash).as_str());
+			assert_same_diff(
+				&partial_repository,
+				hash.as_str(),
+				&CommitDiffLoaderOptions::new().context_lines(3).renames(true, 200).copies(true),
+			);
+			Ok(())
+		});
+	}
+
+	#[test]
+	fn load_commit_diff_not_found() {
+		with_temp_repository(|repository| {
+			let gix_repository = GixRepository::open_from_path(repository.git_directory()).unwrap();
+			assert_eq!(
+				format!(
+					"{}",
+					gix_repository
+						.load_commit_diff("aaaaaaaa", &CommitDiffLoaderOptions::new())
+						.unwrap_err()
+				),
+				"Could not load commit aaaaaaaa"
+			);
+			Ok(())
+		});
+	}
+
+	#[test]
+	fn read_file_content_from_offset() {
+		with_temp_repository(|repository| {
+			let hash = commit(&repository, "commit", &[("dir/a", NORMAL, "line0\nline1\n")])?;
+			let gix_repository = GixRepository::open_from_path(repository.git_directory()).unwrap();
+			let mut content = vec![];
+			let size = gix_repository
+				.read_file_content(hash.as_str(), Path::new("dir/a"), 6, &mut |chunk| {
+					content.extend_from_slice(chunk);
+					true
+				})
+				.unwrap();
+			assert_eq!(size, 12);
+			assert_eq!(content, b"line1\n");
+			Ok(())
+		});
+	}
+
+	#[test]
+	fn read_file_content_missing_path() {
+		with_temp_repository(|repository| {
+			let hash = commit(&repository, "commit", &[("a", NORMAL, "line0\n")])?;
+			let gix_repository = GixRepository::open_from_path(repository.git_directory()).unwrap();
+			assert_eq!(
+				format!(
+					"{}",
+					gix_repository
+						.read_file_content(hash.as_str(), Path::new("b"), 0, &mut |_| true)
+						.unwrap_err()
+				),
+				format!("Could not find b in {}", hash)
+			);
+			Ok(())
+		});
+	}
+
+	#[test]
+	fn read_file_content_directory() {
+		with_temp_repository(|repository| {
+			let hash = commit(&repository, "commit", &[("dir/a", NORMAL, "line0\n")])?;
+			let gix_repository = GixRepository::open_from_path(repository.git_directory()).unwrap();
+			assert!(
+				format!(
+					"{}",
+					gix_repository
+						.read_file_content(hash.as_str(), Path::new("dir"), 0, &mut |_| true)
+						.unwrap_err()
+				)
+				.ends_with("is not a file")
+			);
+			Ok(())
+		});
+	}
+
+	#[test]
+	fn unique_abbreviation_length_same_as_libgit2() {
+		with_temp_repository(|repository| {
+			let mut hashes = vec![];
+			for index in 0..20 {
+				hashes.push(commit(
+					&repository,
+					format!("commit {}", index).as_str(),
+					&[("a", NORMAL, index.to_string().as_str())],
+				)?);
+			}
+			hashes.push(String::from("not a hash"));
+			hashes.push(String::from("ab"));
+			let hash_prefixes = hashes.iter().map(String::as_str).collect::<Vec<&str>>();
+			let gix_repository = GixRepository::open_from_path(repository.git_directory()).unwrap();
+			for minimum in [0, 4, 7, 50] {
+				assert_eq!(
+					gix_repository.unique_abbreviation_length(hash_prefixes.as_slice(), minimum).unwrap(),
+					repository
+						.unique_abbreviation_length(hash_prefixes.iter().copied(), minimum)
+						.unwrap()
+				);
+			}
+			Ok(())
+		});
+	}
+}
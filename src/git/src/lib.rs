@@ -92,33 +92,74 @@ mod commit;
 mod commit_diff;
 mod commit_diff_loader;
 mod commit_diff_loader_options;
+mod commit_topology;
+mod config_location;
+mod config_source;
+mod config_writer;
 mod delta;
+mod diff_interner;
 mod diff_line;
+mod diff_memory_usage;
+mod env_provider;
+mod git_backend;
+#[cfg(feature = "gix")]
+mod gix_commit_diff_loader;
+#[cfg(feature = "gix")]
+mod gix_repository;
+mod file_content;
 mod file_mode;
 mod file_status;
 mod file_status_builder;
+mod object_fetch;
 mod origin;
+mod path;
+mod range_diff;
+mod range_topology;
 mod reference;
 mod reference_kind;
 mod repository;
+mod repository_status;
+mod sandbox_commit;
 mod status;
 pub mod testutil;
 mod user;
+mod version;
+mod worktree_rebase;
 
-pub use git2::{Config, ErrorCode};
+// only used by the benchmarks
+#[cfg(test)]
+use criterion as _;
+
+pub use git2::{Config, ConfigLevel, ErrorCode};
 
 pub use crate::{
 	commit::Commit,
 	commit_diff::CommitDiff,
 	commit_diff_loader_options::CommitDiffLoaderOptions,
+	commit_topology::CommitTopology,
+	config_location::ConfigLocation,
+	config_source::ConfigSource,
+	config_writer::ConfigWriter,
 	delta::Delta,
 	diff_line::DiffLine,
+	diff_memory_usage::DiffMemoryUsage,
+	env_provider::{EnvProvider, ProcessEnv},
 	file_mode::FileMode,
 	file_status::FileStatus,
+	git_backend::GitBackend,
+	object_fetch::ObjectFetch,
 	origin::Origin,
+	range_diff::{RangeDiff, RangeDiffEntry, RangeDiffStatus},
+	range_topology::RangeTopology,
 	reference::Reference,
 	reference_kind::ReferenceKind,
 	repository::Repository,
+	repository_status::RepositoryStatus,
+	sandbox_commit::SandboxCommit,
 	status::Status,
 	user::User,
+	version::libgit2_version,
+	worktree_rebase::WorktreeRebase,
 };
+#[cfg(feature = "gix")]
+pub use crate::gix_repository::GixRepository;
@@ -0,0 +1,223 @@
+use std::{
+	io::Read,
+	path::Path,
+	process::{Child, Command, Stdio},
+	sync::Arc,
+	thread::{self, JoinHandle},
+	time::{Duration, Instant},
+};
+
+use anyhow::{anyhow, Result};
+use parking_lot::Mutex;
+
+// the phases of a fetch that the progress is reported for, where the objects are received, as a pack that is kept or
+// as objects that are unpacked from a small pack, the other phases are quick in comparison
+const PROGRESS_PHASES: [&str; 2] = ["Receiving objects:", "Unpacking objects:"];
+
+/// A fetch of the objects that have not been fetched into a partial clone, from the promisor remote of the clone.
+///
+/// The objects are fetched by a Git process in the background, which is stopped when the fetch is dropped before it has
+/// finished. The progress of the fetch is read from the error output of the process, as it is written.
+#[derive(Debug)]
+pub struct ObjectFetch {
+	child: Option<Child>,
+	object_count: usize,
+	output: Option<JoinHandle<String>>,
+	progress: Arc<Mutex<Option<(usize, usize)>>>,
+	remote: String,
+	started: Instant,
+}
+
+impl ObjectFetch {
+	// the objects are fetched the same way that Git fetches a missing object on demand, without updating any references
+	// or fetching the objects that the objects reference, other than the trees of a tree
+	pub(crate) fn start(git_directory: &Path, remote: &str, object_ids: &[String]) -> Result<Self> {
+		let mut child = Command::new("git")
+			.arg("--git-dir")
+			.arg(git_directory)
+			.args(["-c", "fetch.negotiationAlgorithm=noop", "fetch", remote])
+			.args([
+				"--no-tags",
+				"--no-write-fetch-head",
+				"--recurse-submodules=no",
+				"--filter=blob:none",
+				"--progress",
+			])
+			.args(object_ids)
+			.stdin(Stdio::null())
+			.stdout(Stdio::null())
+			.stderr(Stdio::piped())
+			.spawn()
+			.map_err(|err| anyhow!("Unable to start git fetch: {}", err))?;
+		let progress = Arc::new(Mutex::new(None));
+		let output = child.stderr.take().map(|mut stderr| {
+			let thread_progress = Arc::clone(&progress);
+			thread::spawn(move || read_output(&mut stderr, &thread_progress))
+		});
+		Ok(Self {
+			child: Some(child),
+			object_count: object_ids.len(),
+			output,
+			progress,
+			remote: String::from(remote),
+			started: Instant::now(),
+		})
+	}
+
+	/// The name of the remote that the objects are fetched from.
+	#[inline]
+	#[must_use]
+	pub fn remote(&self) -> &str {
+		self.remote.as_str()
+	}
+
+	/// The number of objects that are fetched.
+	#[inline]
+	#[must_use]
+	pub const fn object_count(&self) -> usize {
+		self.object_count
+	}
+
+	/// The number of objects that have been received, and the number of objects that are received in total, once Git
+	/// has reported the progress of the fetch.
+	#[inline]
+	#[must_use]
+	pub fn progress(&self) -> Option<(usize, usize)> {
+		*self.progress.lock()
+	}
+
+	/// The time since the fetch was started.
+	#[inline]
+	#[must_use]
+	pub fn elapsed(&self) -> Duration {
+		self.started.elapsed()
+	}
+
+	/// Check if the fetch has finished, without waiting for it. Returns `None` while the objects are being fetched.
+	///
+	/// # Errors
+	/// Will result in an error, with the error output of Git, if the objects could not be fetched.
+	#[inline]
+	pub fn poll(&mut self) -> Option<Result<()>> {
+		let child = self.child.as_mut()?;
+		let status = match child.try_wait() {
+			Ok(Some(status)) => status,
+			Ok(None) => return None,
+			Err(err) => return Some(Err(anyhow!("Unable to check git fetch: {}", err))),
+		};
+		// the output is only used to describe the error, and is complete once the process has exited
+		let output = self
+			.output
+			.take()
+			.and_then(|handle| handle.join().ok())
+			.unwrap_or_default();
+		self.child = None;
+		if status.success() {
+			Some(Ok(()))
+		}
+		else {
+			let message = output.trim();
+			Some(Err(if message.is_empty() {
+				anyhow!("git fetch failed with {}", status)
+			}
+			else {
+				anyhow!("git fetch failed with {}: {}", status, message)
+			}))
+		}
+	}
+
+	/// Stop the fetch, if it has not finished.
+	#[inline]
+	pub fn cancel(&mut self) {
+		if let Some(mut child) = self.child.take() {
+			// the process may have already exited
+			let _kill_result = child.kill();
+			let _wait_result = child.wait();
+		}
+	}
+}
+
+// Git ends each update of the progress of a phase with a carriage return, so the lines are split on both carriage
+// returns and line feeds, and the lines that are not progress are kept to describe an error
+fn read_output<R: Read>(reader: &mut R, progress: &Mutex<Option<(usize, usize)>>) -> String {
+	let mut output = vec![];
+	let mut line = vec![];
+	let mut buffer = [0; 1024];
+	while let Ok(length) = reader.read(&mut buffer) {
+		if length == 0 {
+			break;
+		}
+		for &byte in buffer.iter().take(length) {
+			if byte == b'\r' || byte == b'\n' {
+				read_line(String::from_utf8_lossy(&line).trim(), &mut output, progress);
+				line.clear();
+			}
+			else {
+				line.push(byte);
+			}
+		}
+	}
+	read_line(String::from_utf8_lossy(&line).trim(), &mut output, progress);
+	output.join("\n")
+}
+
+fn read_line(line: &str, output: &mut Vec<String>, progress: &Mutex<Option<(usize, usize)>>) {
+	if line.is_empty() {
+		return;
+	}
+	if let Some(counts) = PROGRESS_PHASES
+		.iter()
+		.find_map(|phase| line.strip_prefix(phase))
+		.and_then(parse_progress)
+	{
+		*progress.lock() = Some(counts);
+		return;
+	}
+	if !line.contains("% (") && !line.ends_with(", done.") {
+		output.push(String::from(line));
+	}
+}
+
+// the progress is written as the percentage followed by the counts, such as `45% (9/20), 1.00 KiB | 1.00 MiB/s`
+fn parse_progress(progress: &str) -> Option<(usize, usize)> {
+	let (_, rest) = progress.split_once('(')?;
+	let (counts, _) = rest.split_once(')')?;
+	let (completed, total) = counts.split_once('/')?;
+	Some((completed.parse().ok()?, total.parse().ok()?))
+}
+
+impl Drop for ObjectFetch {
+	#[inline]
+	fn drop(&mut self) {
+		self.cancel();
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use rstest::rstest;
+
+	use super::*;
+
+	#[rstest]
+	#[case::receiving("Receiving objects:  45% (9/20), 1.00 KiB | 1.00 MiB/s\r", Some((9, 20)))]
+	#[case::unpacking("Unpacking objects: 100% (1/1), done.\n", Some((1, 1)))]
+	#[case::last_update("Receiving objects:  50% (1/2)\rReceiving objects: 100% (2/2)\r", Some((2, 2)))]
+	#[case::other_phase("remote: Counting objects: 100% (1/1), done.\n", None)]
+	#[case::none("", None)]
+	fn read_output_progress(#[case] output: &str, #[case] expected: Option<(usize, usize)>) {
+		let progress = Mutex::new(None);
+		let _output = read_output(&mut output.as_bytes(), &progress);
+		assert_eq!(*progress.lock(), expected);
+	}
+
+	#[test]
+	fn read_output_without_progress() {
+		let progress = Mutex::new(None);
+		let output = read_output(
+			&mut "Receiving objects: 100% (1/1)\rfatal: not our ref\nerror: failed\n".as_bytes(),
+			&progress,
+		);
+		assert_eq!(output, "fatal: not our ref\nerror: failed");
+	}
+}
@@ -0,0 +1,28 @@
+use std::path::PathBuf;
+
+/// Remove the verbatim prefix, `\\?\`, that Windows adds to canonicalized paths, since libgit2 does not support
+/// verbatim paths. Paths without the prefix, which includes all paths on other platforms, are unchanged.
+pub(crate) fn strip_verbatim_prefix(path: PathBuf) -> PathBuf {
+	let stripped = path.to_str().and_then(|p| {
+		p.strip_prefix(r"\\?\UNC\")
+			.map(|rest| format!(r"\\{}", rest))
+			.or_else(|| p.strip_prefix(r"\\?\").map(String::from))
+	});
+	stripped.map_or(path, PathBuf::from)
+}
+
+#[cfg(test)]
+mod tests {
+	use rstest::rstest;
+
+	use super::*;
+
+	#[rstest]
+	#[case::unix("/home/user/project/.git", "/home/user/project/.git")]
+	#[case::drive(r"C:\project\.git", r"C:\project\.git")]
+	#[case::verbatim_drive(r"\\?\C:\project\.git", r"C:\project\.git")]
+	#[case::verbatim_unc(r"\\?\UNC\server\share\project\.git", r"\\server\share\project\.git")]
+	fn strip_verbatim_prefix_path(#[case] path: &str, #[case] expected: &str) {
+		assert_eq!(strip_verbatim_prefix(PathBuf::from(path)), PathBuf::from(expected));
+	}
+}
@@ -0,0 +1,224 @@
+/// How a commit of a rebase compares to the commits of an upstream branch.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[allow(clippy::exhaustive_enums)]
+pub enum RangeDiffStatus {
+	/// The commit has the same changes as a commit of the upstream branch, so it is already in the upstream branch.
+	Same,
+	/// The commit has the same summary as a commit of the upstream branch, but different changes.
+	Modified,
+	/// The commit has no equivalent commit in the upstream branch.
+	New,
+	/// A commit of the upstream branch, that has no equivalent commit in the rebase.
+	Upstream,
+}
+
+/// A commit of a rebase, or of an upstream branch, with the commit that it was matched with.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RangeDiffEntry {
+	pub(crate) hash: String,
+	pub(crate) status: RangeDiffStatus,
+	pub(crate) summary: String,
+	pub(crate) upstream_hash: Option<String>,
+}
+
+impl RangeDiffEntry {
+	/// The full hash of the commit.
+	#[must_use]
+	#[inline]
+	pub fn hash(&self) -> &str {
+		self.hash.as_str()
+	}
+
+	/// How the commit compares to the commits of the upstream branch.
+	#[must_use]
+	#[inline]
+	pub const fn status(&self) -> RangeDiffStatus {
+		self.status
+	}
+
+	/// The summary of the commit.
+	#[must_use]
+	#[inline]
+	pub fn summary(&self) -> &str {
+		self.summary.as_str()
+	}
+
+	/// The full hash of the commit of the upstream branch that the commit was matched with, if any.
+	#[must_use]
+	#[inline]
+	pub fn upstream_hash(&self) -> Option<&str> {
+		self.upstream_hash.as_deref()
+	}
+}
+
+/// A comparison of the commits of a rebase to the commits of an upstream branch, like `git range-diff`.
+///
+/// Commits are matched by the patch id of their changes, and then by their summary, so a commit that was applied to
+/// the upstream branch, with or without changes, can be found.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RangeDiff {
+	pub(crate) entries: Vec<RangeDiffEntry>,
+}
+
+impl RangeDiff {
+	/// The commits of the rebase, in the order they were given, followed by the commits of the upstream branch that
+	/// were not matched, from the oldest.
+	#[must_use]
+	#[inline]
+	pub fn entries(&self) -> &[RangeDiffEntry] {
+		self.entries.as_slice()
+	}
+}
+
+// A commit with the patch id of its changes, or `None` for a merge or an empty commit, which are only matched by
+// summary.
+#[derive(Debug)]
+pub(crate) struct RangeDiffCommit {
+	pub(crate) hash: String,
+	pub(crate) patch_id: Option<String>,
+	pub(crate) summary: String,
+}
+
+pub(crate) fn match_commits(commits: Vec<RangeDiffCommit>, upstream_commits: Vec<RangeDiffCommit>) -> RangeDiff {
+	let mut matches: Vec<Option<(RangeDiffStatus, usize)>> = vec![None; commits.len()];
+	let mut is_matched = vec![false; upstream_commits.len()];
+
+	// every commit is matched by patch id, or to itself when it is in the upstream branch, before any commit is
+	// matched by summary, so that a commit with the same summary does not take the upstream commit of a later commit
+	// with the same changes
+	for (commit, commit_match) in commits.iter().zip(matches.iter_mut()) {
+		if let Some(index) = upstream_commits.iter().enumerate().position(|(index, upstream)| {
+			!is_matched[index]
+				&& (upstream.hash == commit.hash || (commit.patch_id.is_some() && upstream.patch_id == commit.patch_id))
+		}) {
+			is_matched[index] = true;
+			*commit_match = Some((RangeDiffStatus::Same, index));
+		}
+	}
+	for (commit, commit_match) in commits.iter().zip(matches.iter_mut()) {
+		if commit_match.is_some() {
+			continue;
+		}
+		if let Some(index) = upstream_commits
+			.iter()
+			.enumerate()
+			.position(|(index, upstream)| !is_matched[index] && upstream.summary == commit.summary)
+		{
+			is_matched[index] = true;
+			*commit_match = Some((RangeDiffStatus::Modified, index));
+		}
+	}
+
+	let mut entries: Vec<RangeDiffEntry> = commits
+		.into_iter()
+		.zip(matches)
+		.map(|(commit, commit_match)| {
+			RangeDiffEntry {
+				hash: commit.hash,
+				status: commit_match.map_or(RangeDiffStatus::New, |(status, _)| status),
+				summary: commit.summary,
+				upstream_hash: commit_match.map(|(_, index)| upstream_commits[index].hash.clone()),
+			}
+		})
+		.collect();
+	entries.extend(
+		upstream_commits
+			.into_iter()
+			.zip(is_matched)
+			.filter(|&(_, matched)| !matched)
+			.map(|(upstream, _)| {
+				RangeDiffEntry {
+					hash: upstream.hash,
+					status: RangeDiffStatus::Upstream,
+					summary: upstream.summary,
+					upstream_hash: None,
+				}
+			}),
+	);
+	RangeDiff { entries }
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn commit(hash: &str, patch_id: Option<&str>, summary: &str) -> RangeDiffCommit {
+		RangeDiffCommit {
+			hash: String::from(hash),
+			patch_id: patch_id.map(String::from),
+			summary: String::from(summary),
+		}
+	}
+
+	fn statuses(range_diff: &RangeDiff) -> Vec<(&str, RangeDiffStatus, Option<&str>)> {
+		range_diff
+			.entries()
+			.iter()
+			.map(|entry| (entry.hash(), entry.status(), entry.upstream_hash()))
+			.collect()
+	}
+
+	#[test]
+	fn match_by_patch_id() {
+		let range_diff = match_commits(vec![commit("aaa", Some("1"), "first")], vec![commit(
+			"bbb",
+			Some("1"),
+			"renamed",
+		)]);
+		assert_eq!(statuses(&range_diff), vec![("aaa", RangeDiffStatus::Same, Some("bbb"))]);
+	}
+
+	#[test]
+	fn match_by_summary() {
+		let range_diff = match_commits(vec![commit("aaa", Some("1"), "first")], vec![commit(
+			"bbb",
+			Some("2"),
+			"first",
+		)]);
+		assert_eq!(statuses(&range_diff), vec![("aaa", RangeDiffStatus::Modified, Some("bbb"))]);
+	}
+
+	#[test]
+	fn patch_id_before_summary() {
+		let range_diff = match_commits(
+			vec![commit("aaa", Some("1"), "same"), commit("bbb", Some("2"), "other")],
+			vec![commit("ccc", Some("2"), "same")],
+		);
+		assert_eq!(statuses(&range_diff), vec![
+			("aaa", RangeDiffStatus::New, None),
+			("bbb", RangeDiffStatus::Same, Some("ccc")),
+		]);
+	}
+
+	#[test]
+	fn match_to_itself() {
+		let range_diff = match_commits(vec![commit("aaa", None, "first")], vec![commit("aaa", None, "first")]);
+		assert_eq!(statuses(&range_diff), vec![("aaa", RangeDiffStatus::Same, Some("aaa"))]);
+	}
+
+	#[test]
+	fn merge_matched_by_summary() {
+		let range_diff = match_commits(vec![commit("aaa", None, "merge")], vec![
+			commit("bbb", None, "other"),
+			commit("ccc", None, "merge"),
+		]);
+		assert_eq!(statuses(&range_diff), vec![
+			("aaa", RangeDiffStatus::Modified, Some("ccc")),
+			("bbb", RangeDiffStatus::Upstream, None),
+		]);
+	}
+
+	#[test]
+	fn new_and_upstream_commits() {
+		let range_diff = match_commits(vec![commit("aaa", Some("1"), "first")], vec![commit(
+			"bbb",
+			Some("2"),
+			"second",
+		)]);
+		assert_eq!(statuses(&range_diff), vec![
+			("aaa", RangeDiffStatus::New, None),
+			("bbb", RangeDiffStatus::Upstream, None),
+		]);
+		assert_eq!(range_diff.entries()[1].summary(), "second");
+	}
+}
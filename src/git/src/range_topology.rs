@@ -0,0 +1,88 @@
+use std::{collections::BTreeMap, ops::Bound};
+
+use crate::CommitTopology;
+
+/// The topology of the commits of a head commit that are not in the history of a base commit.
+///
+/// This is the range of commits of a rebase, with the number of commits that each side has that the other does not.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RangeTopology {
+	pub(crate) ahead: usize,
+	pub(crate) behind: usize,
+	pub(crate) commits: BTreeMap<String, CommitTopology>,
+}
+
+impl RangeTopology {
+	/// The number of commits in the history of the head that are not in the history of the base.
+	#[must_use]
+	#[inline]
+	pub const fn ahead(&self) -> usize {
+		self.ahead
+	}
+
+	/// The number of commits in the history of the base that are not in the history of the head.
+	#[must_use]
+	#[inline]
+	pub const fn behind(&self) -> usize {
+		self.behind
+	}
+
+	/// Find the topology of a commit in the range from its full or abbreviated hash, or `None` if the commit is not in
+	/// the range.
+	#[must_use]
+	#[inline]
+	pub fn find(&self, hash: &str) -> Option<CommitTopology> {
+		if hash.is_empty() {
+			return None;
+		}
+		self.commits
+			.range::<str, _>((Bound::Included(hash), Bound::Unbounded))
+			.next()
+			.filter(|&(commit_hash, _)| commit_hash.starts_with(hash))
+			.map(|(_, topology)| *topology)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn create_topology() -> RangeTopology {
+		RangeTopology {
+			ahead: 3,
+			behind: 2,
+			commits: BTreeMap::from([
+				(String::from("aaaa0000"), CommitTopology::Mainline),
+				(String::from("bbbb0000"), CommitTopology::SideBranch),
+				(String::from("cccc0000"), CommitTopology::Merge),
+			]),
+		}
+	}
+
+	#[test]
+	fn ahead_and_behind() {
+		let topology = create_topology();
+		assert_eq!(topology.ahead(), 3);
+		assert_eq!(topology.behind(), 2);
+	}
+
+	#[test]
+	fn find_full_hash() {
+		assert_eq!(create_topology().find("bbbb0000"), Some(CommitTopology::SideBranch));
+	}
+
+	#[test]
+	fn find_abbreviated_hash() {
+		assert_eq!(create_topology().find("cccc"), Some(CommitTopology::Merge));
+	}
+
+	#[test]
+	fn find_not_in_range() {
+		assert_eq!(create_topology().find("bbbb1"), None);
+	}
+
+	#[test]
+	fn find_empty_hash() {
+		assert_eq!(create_topology().find(""), None);
+	}
+}
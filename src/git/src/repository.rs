@@ -1,28 +1,192 @@
-use std::path::Path;
+use std::{
+	collections::{BTreeMap, HashSet},
+	env::split_paths,
+	ffi::OsString,
+	fs::{create_dir_all, write},
+	path::{Path, PathBuf},
+};
 
 use anyhow::{anyhow, Result};
 
-use crate::{commit_diff_loader::CommitDiffLoader, CommitDiff, CommitDiffLoaderOptions, Config};
+use crate::{
+	commit_diff_loader::CommitDiffLoader,
+	env_provider::ProcessEnv,
+	file_content::read_blob_content,
+	path::strip_verbatim_prefix,
+	range_diff::{match_commits, RangeDiffCommit},
+	worktree_rebase::{find_worktree_rebases, read_rebase_head_name, read_rebase_range, resolve_common_directory},
+	Commit,
+	CommitDiff,
+	CommitDiffLoaderOptions,
+	CommitTopology,
+	Config,
+	ConfigLocation,
+	ConfigWriter,
+	EnvProvider,
+	ObjectFetch,
+	RangeDiff,
+	RangeTopology,
+	RepositoryStatus,
+	SandboxCommit,
+	WorktreeRebase,
+};
 
 /// A light simple wrapper around the `git2::Repository` struct
 pub struct Repository {
 	repository: git2::Repository,
 }
 
+// the shortest abbreviation that Git and libgit2 accept, and the length of a full hexadecimal SHA-1 hash
+pub(crate) const MINIMUM_HASH_LENGTH: usize = 4;
+pub(crate) const MAXIMUM_HASH_LENGTH: usize = 40;
+
 impl Repository {
 	/// Find and open an existing repository, respecting git environment variables. This will check
 	/// for and use `$GIT_DIR`, and if unset will search for a repository starting in the current
-	/// directory, walking to the root.
+	/// directory, walking to the root. If `$GIT_WORK_TREE` is set, it is used as the working tree.
 	///
 	/// # Errors
 	/// Will result in an error if the repository cannot be opened.
 	#[inline]
 	pub fn open_from_env() -> Result<Self> {
-		let repository = git2::Repository::open_from_env()
+		Self::open_from_env_with(&ProcessEnv)
+	}
+
+	/// Find and open an existing repository, like `open_from_env`, using the git environment variables and the current
+	/// directory of a provided environment, rather than the environment of the process. Along with `$GIT_DIR` and
+	/// `$GIT_WORK_TREE`, this respects `$GIT_CEILING_DIRECTORIES`, `$GIT_DISCOVERY_ACROSS_FILESYSTEM`,
+	/// `$GIT_INDEX_FILE`, `$GIT_NAMESPACE`, `$GIT_OBJECT_DIRECTORY` and `$GIT_ALTERNATE_OBJECT_DIRECTORIES`, as Git does.
+	///
+	/// # Errors
+	/// Will result in an error if the repository cannot be opened.
+	#[inline]
+	pub fn open_from_env_with<E: EnvProvider>(env: &E) -> Result<Self> {
+		let repository = Self::open_git2_from_env(env)
 			.map_err(|e| anyhow!(String::from(e.message())).context("Could not open repository from environment"))?;
 		Ok(Self { repository })
 	}
 
+	/// Open the repository that owns a rebase todo file. The repository from the environment is used if it
+	/// owns the todo file, otherwise the git directory containing the todo file is opened. This handles a
+	/// working directory outside of the repository, `$GIT_DIR` pointing at a linked worktree's git
+	/// directory, and repositories created with `--separate-git-dir`.
+	///
+	/// # Errors
+	/// Will result in an error if neither the repository from the environment, or the repository
+	/// containing the todo file, can be opened.
+	#[inline]
+	pub fn open_for_todo_file(todo_file_path: &Path) -> Result<Self> {
+		Self::open_for_todo_file_with(todo_file_path, &ProcessEnv)
+	}
+
+	/// Open the repository that owns a rebase todo file, like `open_for_todo_file`, using a provided environment to
+	/// open the repository from the environment.
+	///
+	/// # Errors
+	/// Will result in an error if neither the repository from the environment, or the repository
+	/// containing the todo file, can be opened.
+	#[inline]
+	pub fn open_for_todo_file_with<E: EnvProvider>(todo_file_path: &Path, env: &E) -> Result<Self> {
+		let todo_git_directory = todo_file_path
+			.parent()
+			.filter(|state_directory| {
+				state_directory
+					.file_name()
+					.map_or(false, |name| name == "rebase-merge" || name == "rebase-apply")
+			})
+			.and_then(Path::parent)
+			.and_then(|git_directory| git_directory.canonicalize().ok())
+			.map(strip_verbatim_prefix);
+
+		let todo_git_directory = match todo_git_directory {
+			Some(todo_git_directory) => todo_git_directory,
+			None => return Self::open_from_env_with(env),
+		};
+
+		match Self::open_from_env_with(env) {
+			Ok(repository)
+				if repository
+					.git_directory()
+					.canonicalize()
+					.map_or(false, |path| strip_verbatim_prefix(path) == todo_git_directory) =>
+			{
+				Ok(repository)
+			},
+			Ok(_) => Self::open_from_path(todo_git_directory.as_path()),
+			Err(err) => Self::open_from_path(todo_git_directory.as_path()).map_err(|_| err),
+		}
+	}
+
+	// the same steps as opening a repository from the environment in libgit2, with the environment provided, and with
+	// support for `$GIT_WORK_TREE`, which libgit2 errors on
+	fn open_git2_from_env<E: EnvProvider>(env: &E) -> Result<git2::Repository, git2::Error> {
+		// relative paths in the environment are relative to the current directory of the environment
+		let resolve_path = |value: OsString| -> Result<PathBuf, git2::Error> {
+			let path = PathBuf::from(value);
+			if path.is_absolute() {
+				return Ok(path);
+			}
+			env.current_dir()
+				.map(|current_dir| current_dir.join(path))
+				.map_err(|err| git2::Error::from_str(err.to_string().as_str()))
+		};
+
+		if env.var_os("GIT_COMMON_DIR").is_some() {
+			return Err(git2::Error::from_str("GIT_COMMON_DIR unimplemented"));
+		}
+
+		let work_tree = env.var_os("GIT_WORK_TREE").map(resolve_path).transpose()?;
+		let mut flags = git2::RepositoryOpenFlags::empty();
+		if let Some(across_filesystem) = env.var_os("GIT_DISCOVERY_ACROSS_FILESYSTEM") {
+			if Config::parse_bool(across_filesystem.to_string_lossy().as_ref())? {
+				flags |= git2::RepositoryOpenFlags::CROSS_FS;
+			}
+		}
+		let ceiling_directories = env
+			.var_os("GIT_CEILING_DIRECTORIES")
+			.map(|directories| split_paths(&directories).collect::<Vec<PathBuf>>())
+			.unwrap_or_default();
+
+		let start_path = match env.var_os("GIT_DIR") {
+			Some(git_directory) => {
+				flags |= git2::RepositoryOpenFlags::NO_SEARCH | git2::RepositoryOpenFlags::NO_DOTGIT;
+				resolve_path(git_directory)?
+			},
+			// the repository of a working tree is found by searching from the working tree
+			None => {
+				match work_tree {
+					Some(ref work_tree_path) => work_tree_path.clone(),
+					None => resolve_path(OsString::from("."))?,
+				}
+			},
+		};
+		let repository = git2::Repository::open_ext(start_path, flags, ceiling_directories)?;
+
+		if let Some(ref work_tree_path) = work_tree {
+			repository.set_workdir(work_tree_path, false)?;
+		}
+		if let Some(index_file) = env.var_os("GIT_INDEX_FILE") {
+			repository.set_index(&mut git2::Index::open(resolve_path(index_file)?.as_path())?)?;
+		}
+		if let Some(object_directory) = env.var_os("GIT_OBJECT_DIRECTORY") {
+			let odb = git2::Odb::new()?;
+			odb.add_disk_alternate(resolve_path(object_directory)?.to_string_lossy().as_ref())?;
+			repository.set_odb(&odb)?;
+		}
+		if let Some(alternate_directories) = env.var_os("GIT_ALTERNATE_OBJECT_DIRECTORIES") {
+			let odb = repository.odb()?;
+			for alternate_directory in split_paths(&alternate_directories) {
+				odb.add_disk_alternate(alternate_directory.to_string_lossy().as_ref())?;
+			}
+		}
+		if let Some(namespace) = env.var_os("GIT_NAMESPACE") {
+			if !namespace.is_empty() {
+				repository.set_namespace(namespace.to_string_lossy().as_ref())?;
+			}
+		}
+		Ok(repository)
+	}
+
 	/// Attempt to open an already-existing repository at `path`.
 	///
 	/// # Errors
@@ -34,6 +198,66 @@ impl Repository {
 		Ok(Self { repository })
 	}
 
+	/// Create a new repository at `path`, with the commits on `main`, each commit building on the one before it. This is
+	/// used for a sandbox, where the changes cannot affect a real repository. The hashes of the commits are returned, in
+	/// the order of the commits.
+	///
+	/// # Errors
+	/// Will result in an error if the repository cannot be created, or a file or commit cannot be written.
+	#[inline]
+	pub fn init_sandbox(path: &Path, commits: &[SandboxCommit]) -> Result<(Self, Vec<String>)> {
+		let map_error =
+			|err: git2::Error| anyhow!(String::from(err.message())).context("Could not create sandbox repository");
+		let map_io_error = |err: std::io::Error| anyhow!(err).context("Could not create sandbox repository");
+		let mut options = git2::RepositoryInitOptions::new();
+		let _ = options.initial_head("main");
+		let repository = git2::Repository::init_opts(path, &options).map_err(map_error)?;
+		let mut hashes = vec![];
+		{
+			let mut index = repository.index().map_err(map_error)?;
+			let mut parent = None;
+			for commit in commits {
+				for (file_path, content) in &commit.files {
+					let full_path = path.join(file_path);
+					if let Some(directory) = full_path.parent() {
+						create_dir_all(directory).map_err(map_io_error)?;
+					}
+					write(full_path, content).map_err(map_io_error)?;
+					index.add_path(Path::new(file_path)).map_err(map_error)?;
+				}
+				index.write().map_err(map_error)?;
+				let tree = repository
+					.find_tree(index.write_tree().map_err(map_error)?)
+					.map_err(map_error)?;
+				let signature = match commit.time {
+					Some(time) => {
+						git2::Signature::new(
+							commit.author_name.as_str(),
+							commit.author_email.as_str(),
+							&git2::Time::new(time, 0),
+						)
+					},
+					None => git2::Signature::now(commit.author_name.as_str(), commit.author_email.as_str()),
+				}
+				.map_err(map_error)?;
+				let parents = parent.iter().collect::<Vec<&git2::Commit<'_>>>();
+				let id = repository
+					.commit(
+						Some("HEAD"),
+						&signature,
+						&signature,
+						commit.message.as_str(),
+						&tree,
+						parents.as_slice(),
+					)
+					.map_err(map_error)?;
+				hashes.push(id.to_string());
+				parent = Some(repository.find_commit(id).map_err(map_error)?);
+			}
+		}
+		Ok((Self { repository }, hashes))
+	}
+
 	/// Load the git configuration for the repository.
 	///
 	/// # Errors
@@ -43,6 +267,19 @@ impl Repository {
 		self.repository.config().map_err(|e| anyhow!(String::from(e.message())))
 	}
 
+	/// Open a git config file for writing settings back to, either the user's global config, or the config of
+	/// the repository. For a linked worktree, the repository config is the one shared by all of the worktrees.
+	///
+	/// # Errors
+	/// Will result in an error if the config file cannot be found or opened.
+	#[inline]
+	pub fn open_config_writer(&self, location: ConfigLocation) -> Result<ConfigWriter> {
+		match location {
+			ConfigLocation::Global => ConfigWriter::open_global(),
+			ConfigLocation::Local => ConfigWriter::open_path(self.common_directory().join("config").as_path()),
+		}
+	}
+
 	/// Load a diff for a commit hash
 	///
 	/// # Errors
@@ -55,6 +292,416 @@ impl Repository {
 		Ok(loader.load_from_hash(oid).map_err(|e| anyhow!("{}", e))?.remove(0))
 	}
 
+	/// Load the diff of the commits with the hashes, in order, as if they were squashed into the first commit.
+	///
+	/// # Errors
+	/// Will result in an error if a commit cannot be loaded, or if the changes of a commit conflict with the changes of
+	/// the earlier commits.
+	#[inline]
+	pub fn load_squash_diff(&self, hashes: &[&str], config: &CommitDiffLoaderOptions) -> Result<CommitDiff> {
+		let mut oids = vec![];
+		for hash in hashes {
+			oids.push(self.repository.revparse_single(hash)?.id());
+		}
+		let loader = CommitDiffLoader::new(&self.repository, config);
+		loader.load_squash(oids.as_slice()).map_err(|e| anyhow!("{}", e))
+	}
+
+	/// Create a copy of the commit with the hash, with the same changes, parents, author and committer, and the
+	/// message, without updating any reference, and return the hash of the copy.
+	///
+	/// # Errors
+	/// Will result in an error if the commit cannot be found, or if the copy cannot be written.
+	#[inline]
+	pub fn create_commit_with_message(&self, hash: &str, message: &str) -> Result<String> {
+		let commit = self
+			.repository
+			.revparse_single(hash)
+			.and_then(|object| object.peel_to_commit())
+			.map_err(|e| anyhow!(String::from(e.message())).context(format!("Could not load commit {}", hash)))?;
+		let oid = commit
+			.amend(None, None, None, None, Some(message), None)
+			.map_err(|e| anyhow!(String::from(e.message())).context(format!("Could not copy commit {}", hash)))?;
+		Ok(oid.to_string())
+	}
+
+	/// Load a commit for a commit hash, without loading the diff of the commit.
+	///
+	/// # Errors
+	/// Will result in an error if the commit cannot be found.
+	#[inline]
+	pub fn load_commit(&self, hash: &str) -> Result<Commit> {
+		let commit = self
+			.repository
+			.revparse_single(hash)
+			.and_then(|object| object.peel_to_commit())
+			.map_err(|e| anyhow!(String::from(e.message())).context(format!("Could not load commit {}", hash)))?;
+		Ok(Commit::from(&commit))
+	}
+
+	/// Read the content of the file at `path` in the commit with the hash, in chunks, starting at the byte `offset`,
+	/// until the `callback` returns `false` or the end of the file is reached, so large files are not loaded into
+	/// memory. Returns the size of the file in bytes.
+	///
+	/// # Errors
+	/// Will result in an error if the commit cannot be found, or if the path is not a file in the commit.
+	#[inline]
+	pub fn read_file_content<F>(&self, hash: &str, path: &Path, offset: usize, callback: F) -> Result<usize>
+	where F: FnMut(&[u8]) -> bool {
+		let entry = self
+			.repository
+			.revparse_single(hash)
+			.and_then(|object| object.peel_to_commit())
+			.and_then(|commit| commit.tree())
+			.and_then(|tree| tree.get_path(path))
+			.map_err(|e| {
+				anyhow!(String::from(e.message())).context(format!("Could not find {} in {}", path.display(), hash))
+			})?;
+		read_blob_content(&self.repository, entry.id(), offset, callback)
+	}
+
+	/// Find the shortest abbreviation length, of at least `minimum`, at which each of the, possibly abbreviated,
+	/// hashes is unique in the object database. The length is between 4 and 40, and hashes that do not match an
+	/// object are ignored.
+	///
+	/// # Errors
+	/// Will result in an error if the object database cannot be opened.
+	#[inline]
+	pub fn unique_abbreviation_length<'h, I>(&self, hashes: I, minimum: usize) -> Result<usize>
+	where I: IntoIterator<Item = &'h str> {
+		let odb = self.repository.odb().map_err(|e| anyhow!(String::from(e.message())))?;
+		let mut length = minimum.clamp(MINIMUM_HASH_LENGTH, MAXIMUM_HASH_LENGTH);
+		for hash in hashes {
+			let oid = match git2::Oid::from_str(hash).and_then(|prefix| odb.exists_prefix(prefix, hash.len())) {
+				Ok(oid) => oid,
+				Err(_) => continue,
+			};
+			// a prefix is ambiguous when another object shares it, and is unique at every longer length
+			while length < MAXIMUM_HASH_LENGTH && odb.exists_prefix(oid, length).is_err() {
+				length += 1;
+			}
+		}
+		Ok(length)
+	}
+
+	/// Check if the index or working tree has changes to tracked files. Untracked and ignored files are not
+	/// considered changes, since they do not prevent a rebase from starting.
+	///
+	/// # Errors
+	/// Will result in an error if the status of the repository cannot be read, such as for a bare repository.
+	#[inline]
+	pub fn has_uncommitted_changes(&self) -> Result<bool> {
+		let mut options = git2::StatusOptions::new();
+		let _ = options
+			.include_untracked(false)
+			.include_ignored(false)
+			.exclude_submodules(true);
+		let statuses = self
+			.repository
+			.statuses(Some(&mut options))
+			.map_err(|e| anyhow!(String::from(e.message())))?;
+		Ok(!statuses.is_empty())
+	}
+
+	/// Load a summary of the staged, unstaged and untracked files in the working tree, and of how far the branch, or
+	/// the branch being rebased, is ahead and behind of its upstream branch. Ignored files and submodules are not
+	/// included.
+	///
+	/// # Errors
+	/// Will result in an error if the status of the repository cannot be read, such as for a bare repository.
+	#[inline]
+	pub fn status(&self) -> Result<RepositoryStatus> {
+		let mut options = git2::StatusOptions::new();
+		let _ = options
+			.include_untracked(true)
+			.recurse_untracked_dirs(true)
+			.include_ignored(false)
+			.exclude_submodules(true)
+			.renames_head_to_index(true);
+		let statuses = self
+			.repository
+			.statuses(Some(&mut options))
+			.map_err(|e| anyhow!(String::from(e.message())))?;
+		Ok(RepositoryStatus::new(&statuses, self.load_upstream_ahead_behind()))
+	}
+
+	// during a rebase the head is detached, so the branch being rebased is compared with its upstream instead
+	fn load_upstream_ahead_behind(&self) -> Option<(usize, usize)> {
+		let reference = match self.rebase_head_name() {
+			Some(head_name) => self.repository.find_reference(head_name.as_str()).ok()?,
+			None => self.repository.head().ok()?,
+		};
+		if !reference.is_branch() {
+			return None;
+		}
+		let branch = git2::Branch::wrap(reference);
+		let local_id = branch.get().target()?;
+		let upstream_id = branch.upstream().ok()?.get().target()?;
+		self.repository.graph_ahead_behind(local_id, upstream_id).ok()
+	}
+
+	/// Get the path to the git directory of the repository. For a linked worktree, this is the worktree
+	/// specific directory inside of the common directory, and not the `.git` file in the working tree.
+	#[must_use]
+	#[inline]
+	pub fn git_directory(&self) -> &Path {
+		self.repository.path()
+	}
+
+	/// Get the path to the working tree of the repository, if the repository is not bare.
+	#[must_use]
+	#[inline]
+	pub fn work_directory(&self) -> Option<&Path> {
+		self.repository.workdir()
+	}
+
+	/// Get the path to the git directory shared between all worktrees of the repository.
+	#[must_use]
+	#[inline]
+	pub fn common_directory(&self) -> PathBuf {
+		resolve_common_directory(self.git_directory())
+	}
+
+	/// Is the repository a linked worktree, created with `git worktree add`.
+	#[must_use]
+	#[inline]
+	pub fn is_linked_worktree(&self) -> bool {
+		self.repository.is_worktree()
+	}
+
+	/// Is the repository a shallow clone, created with `git clone --depth`, where the history of the commits at the
+	/// boundary of the clone is not in the repository.
+	#[must_use]
+	#[inline]
+	pub fn is_shallow(&self) -> bool {
+		self.repository.is_shallow()
+	}
+
+	/// Is the repository a partial clone, created with `git clone --filter`, where objects are fetched from a promisor
+	/// remote when they are needed, so the objects that have not been fetched are not in the repository.
+	#[must_use]
+	#[inline]
+	pub fn is_partial_clone(&self) -> bool {
+		self.promisor_remote().is_some()
+	}
+
+	/// Get the name of the remote that the objects that have not been fetched into a partial clone are fetched from,
+	/// if the repository is a partial clone.
+	#[must_use]
+	#[inline]
+	pub fn promisor_remote(&self) -> Option<String> {
+		let config = self.repository.config().ok()?;
+		if let Ok(remote) = config.get_string("extensions.partialClone") {
+			return Some(remote);
+		}
+		// a partial clone has at least one remote that is a promisor remote
+		let entries = config.entries(Some(r"^remote\..*\.promisor$")).ok()?;
+		while let Some(Ok(entry)) = (&entries).next() {
+			if entry.value().map_or(false, |value| Config::parse_bool(value).unwrap_or(false)) {
+				if let Some(remote) = entry
+					.name()
+					.and_then(|name| name.strip_prefix("remote."))
+					.and_then(|name| name.strip_suffix(".promisor"))
+				{
+					return Some(String::from(remote));
+				}
+			}
+		}
+		None
+	}
+
+	/// Find the objects that are needed to load the diff of a commit, that have not been fetched into a partial clone.
+	/// When the trees of the commit have not been fetched, only the trees are found, since the files of the commit are
+	/// only known once the trees have been fetched.
+	///
+	/// # Errors
+	/// Will result in an error if the commit cannot be found.
+	#[inline]
+	pub fn find_missing_objects(&self, hash: &str) -> Result<Vec<String>> {
+		let oid = self.repository.revparse_single(hash)?.id();
+		let options = CommitDiffLoaderOptions::new();
+		let loader = CommitDiffLoader::new(&self.repository, &options);
+		Ok(loader
+			.find_missing_objects(oid)
+			.map_err(|e| anyhow!("{}", e))?
+			.iter()
+			.map(git2::Oid::to_string)
+			.collect())
+	}
+
+	/// Start fetching objects that have not been fetched into a partial clone from the promisor remote, in a Git
+	/// process in the background.
+	///
+	/// # Errors
+	/// Will result in an error if the repository is not a partial clone, or if Git cannot be started.
+	#[inline]
+	pub fn fetch_missing_objects(&self, object_ids: &[String]) -> Result<ObjectFetch> {
+		let remote = self
+			.promisor_remote()
+			.ok_or_else(|| anyhow!("The repository is not a partial clone"))?;
+		ObjectFetch::start(self.git_directory(), remote.as_str(), object_ids)
+	}
+
+	/// Get the name of the reference being rebased in this worktree, if a rebase is in progress on a branch.
+	#[must_use]
+	#[inline]
+	pub fn rebase_head_name(&self) -> Option<String> {
+		read_rebase_head_name(self.git_directory())
+	}
+
+	/// Get the commit that the commits are being rebased onto, and the commit that was the head when the rebase
+	/// started, if a rebase is in progress in this worktree.
+	#[must_use]
+	#[inline]
+	pub fn rebase_range(&self) -> Option<(String, String)> {
+		read_rebase_range(self.git_directory())
+	}
+
+	/// Count the commits in the history of `local` that are not in the history of `upstream`, and the commits in the
+	/// history of `upstream` that are not in the history of `local`.
+	///
+	/// # Errors
+	/// Will result in an error if either commit cannot be found.
+	#[inline]
+	pub fn ahead_behind(&self, local: &str, upstream: &str) -> Result<(usize, usize)> {
+		let local_id = self.repository.revparse_single(local)?.peel_to_commit()?.id();
+		let upstream_id = self.repository.revparse_single(upstream)?.peel_to_commit()?.id();
+		Ok(self.repository.graph_ahead_behind(local_id, upstream_id)?)
+	}
+
+	/// Load the topology of the commits in the history of `head` that are not in the history of `base`, relative to the
+	/// first-parent history of `head`.
+	///
+	/// # Errors
+	/// Will result in an error if either commit cannot be found, or the history cannot be read.
+	#[inline]
+	pub fn load_range_topology(&self, base: &str, head: &str) -> Result<RangeTopology> {
+		let base_id = self.repository.revparse_single(base)?.peel_to_commit()?.id();
+		let head_commit = self.repository.revparse_single(head)?.peel_to_commit()?;
+		let (ahead, behind) = self.repository.graph_ahead_behind(head_commit.id(), base_id)?;
+
+		let mut revwalk = self.repository.revwalk()?;
+		revwalk.push(head_commit.id())?;
+		revwalk.hide(base_id)?;
+		let range = revwalk.collect::<Result<HashSet<git2::Oid>, git2::Error>>()?;
+
+		// the first-parent history ends at the first commit that is not in the range
+		let mut mainline = HashSet::new();
+		let mut next_commit = Some(head_commit);
+		while let Some(commit) = next_commit {
+			if !range.contains(&commit.id()) {
+				break;
+			}
+			let _is_new = mainline.insert(commit.id());
+			next_commit = commit.parents().next();
+		}
+
+		let mut commits = BTreeMap::new();
+		for id in range {
+			let topology = if !mainline.contains(&id) {
+				CommitTopology::SideBranch
+			}
+			else if self.repository.find_commit(id)?.parent_count() > 1 {
+				CommitTopology::Merge
+			}
+			else {
+				CommitTopology::Mainline
+			};
+			let _previous = commits.insert(id.to_string(), topology);
+		}
+		Ok(RangeTopology { ahead, behind, commits })
+	}
+
+	/// Compare the commits with the hashes to the commits of `upstream` that are not in the history of any of the
+	/// commits, like `git range-diff`, to find the commits that have already been applied to `upstream`.
+	///
+	/// # Errors
+	/// Will result in an error if any of the commits, or `upstream`, cannot be found, or the history or the changes of
+	/// a commit cannot be read.
+	#[inline]
+	pub fn range_diff(&self, hashes: &[&str], upstream: &str) -> Result<RangeDiff> {
+		let find_commit = |hash: &str| {
+			self.repository
+				.revparse_single(hash)
+				.and_then(|object| object.peel_to_commit())
+				.map_err(|e| anyhow!(String::from(e.message())).context(format!("Could not load commit {}", hash)))
+		};
+		let commits = hashes
+			.iter()
+			.map(|hash| self.range_diff_commit(&find_commit(hash)?))
+			.collect::<Result<Vec<RangeDiffCommit>>>()?;
+
+		let upstream_id = find_commit(upstream)?.id();
+		let mut revwalk = self.repository.revwalk()?;
+		revwalk.set_sorting(git2::Sort::TOPOLOGICAL | git2::Sort::REVERSE)?;
+		revwalk.push(upstream_id)?;
+		// the history that the commits share with upstream is hidden, rather than the commits, so that a commit that
+		// is itself in the history of upstream is matched with itself
+		for commit in &commits {
+			let id = git2::Oid::from_str(commit.hash.as_str())?;
+			match self.repository.merge_base(id, upstream_id) {
+				Ok(base) if base == id => {
+					for parent_id in self.repository.find_commit(id)?.parent_ids() {
+						revwalk.hide(parent_id)?;
+					}
+				},
+				Ok(base) => revwalk.hide(base)?,
+				// a commit with no history in common with upstream hides nothing
+				Err(_) => {},
+			}
+		}
+		let upstream_commits = revwalk
+			.map(|id| self.range_diff_commit(&self.repository.find_commit(id?)?))
+			.collect::<Result<Vec<RangeDiffCommit>>>()?;
+		Ok(match_commits(commits, upstream_commits))
+	}
+
+	fn range_diff_commit(&self, commit: &git2::Commit<'_>) -> Result<RangeDiffCommit> {
+		let patch_id = if commit.parent_count() > 1 {
+			None
+		}
+		else {
+			let parent_tree = commit.parents().next().map(|parent| parent.tree()).transpose()?;
+			let diff = self
+				.repository
+				.diff_tree_to_tree(parent_tree.as_ref(), Some(&commit.tree()?), None)?;
+			// the changes of an empty commit are the same as those of any other empty commit
+			(diff.deltas().len() > 0)
+				.then(|| diff.patchid(None).map(|id| id.to_string()))
+				.transpose()?
+		};
+		Ok(RangeDiffCommit {
+			hash: commit.id().to_string(),
+			patch_id,
+			summary: String::from(commit.summary().unwrap_or("")),
+		})
+	}
+
+	/// Find rebases in progress in other worktrees of the repository that are on the same branch as the rebase
+	/// in progress in this worktree.
+	#[must_use]
+	#[inline]
+	pub fn find_conflicting_worktree_rebases(&self) -> Vec<WorktreeRebase> {
+		let head_name = match self.rebase_head_name() {
+			Some(head_name) => head_name,
+			None => return vec![],
+		};
+		let git_directory = self
+			.git_directory()
+			.canonicalize()
+			.unwrap_or_else(|_| self.git_directory().to_path_buf());
+		find_worktree_rebases(self.common_directory().as_path())
+			.into_iter()
+			.filter(|rebase| {
+				rebase.head_name() == head_name
+					&& rebase
+						.git_directory()
+						.canonicalize()
+						.map_or(true, |path| path != git_directory)
+			})
+			.collect()
+	}
+
 	pub(crate) const fn git2_repository(&self) -> &git2::Repository {
 		&self.repository
 	}
@@ -79,32 +726,37 @@ impl ::std::fmt::Debug for Repository {
 // Paths in Windows makes these tests difficult, so disable
 #[cfg(all(unix, test))]
 mod tests {
-	use std::env::set_var;
+	use std::{thread::sleep, time::Duration};
 
 	use super::*;
-	use crate::testutil::{with_temp_bare_repository, with_temp_repository};
+	use crate::{FileStatus, RangeDiffStatus};
+	use crate::testutil::{
+		create_commit,
+		head_id,
+		with_temp_bare_repository,
+		with_temp_partial_clone,
+		with_temp_repository,
+		CreateCommitOptions,
+		RepoBuilder,
+		TestEnv,
+	};
+
+	fn fixture_path(name: &str) -> PathBuf {
+		Path::new(env!("CARGO_MANIFEST_DIR")).join("test").join("fixtures").join(name)
+	}
 
 	#[test]
-	#[serial_test::serial]
 	fn open_from_env() {
-		let path = Path::new(env!("CARGO_MANIFEST_DIR"))
-			.join("test")
-			.join("fixtures")
-			.join("simple");
-		set_var("GIT_DIR", path.to_str().unwrap());
-		assert!(Repository::open_from_env().is_ok());
+		let env = TestEnv::new("/").var("GIT_DIR", fixture_path("simple"));
+		assert!(Repository::open_from_env_with(&env).is_ok());
 	}
 
 	#[test]
-	#[serial_test::serial]
 	fn open_from_env_error() {
-		let path = Path::new(env!("CARGO_MANIFEST_DIR"))
-			.join("test")
-			.join("fixtures")
-			.join("does-not-exist");
-		set_var("GIT_DIR", path.to_str().unwrap());
+		let path = fixture_path("does-not-exist");
+		let env = TestEnv::new("/").var("GIT_DIR", &path);
 		assert_eq!(
-			format!("{:#}", Repository::open_from_env().err().unwrap()),
+			format!("{:#}", Repository::open_from_env_with(&env).err().unwrap()),
 			format!(
 				"Could not open repository from environment: failed to resolve path '{}': No such file or directory",
 				path.to_str().unwrap()
@@ -112,6 +764,155 @@ mod tests {
 		);
 	}
 
+	#[test]
+	fn open_from_env_relative_git_directory() {
+		let env = TestEnv::new(fixture_path("")).var("GIT_DIR", "simple");
+		let opened = Repository::open_from_env_with(&env).unwrap();
+		assert_eq!(
+			opened.git_directory().canonicalize().unwrap(),
+			fixture_path("simple").canonicalize().unwrap()
+		);
+	}
+
+	#[test]
+	fn open_from_env_search_from_current_directory() {
+		with_temp_repository(|repository| {
+			let work_tree = repository.repository.workdir().unwrap().to_path_buf();
+			let current_dir = work_tree.join("a").join("b");
+			create_dir_all(&current_dir).unwrap();
+			let opened = Repository::open_from_env_with(&TestEnv::new(&current_dir)).unwrap();
+			assert_eq!(canonical_git_directory(&opened), canonical_git_directory(&repository));
+			Ok(())
+		});
+	}
+
+	#[test]
+	fn open_from_env_ceiling_directories() {
+		with_temp_repository(|repository| {
+			let work_tree = repository.repository.workdir().unwrap().to_path_buf();
+			let current_dir = work_tree.join("a");
+			create_dir_all(&current_dir).unwrap();
+			let env = TestEnv::new(&current_dir).var("GIT_CEILING_DIRECTORIES", &work_tree);
+			assert!(Repository::open_from_env_with(&env).is_err());
+			Ok(())
+		});
+	}
+
+	#[test]
+	fn open_from_env_common_directory_error() {
+		let env = TestEnv::new("/")
+			.var("GIT_DIR", fixture_path("simple"))
+			.var("GIT_COMMON_DIR", fixture_path("simple"));
+		assert_eq!(
+			format!("{:#}", Repository::open_from_env_with(&env).err().unwrap()),
+			"Could not open repository from environment: GIT_COMMON_DIR unimplemented"
+		);
+	}
+
+	#[test]
+	fn open_from_env_namespace() {
+		let env = TestEnv::new("/")
+			.var("GIT_DIR", fixture_path("simple"))
+			.var("GIT_NAMESPACE", "namespace");
+		let opened = Repository::open_from_env_with(&env).unwrap();
+		assert_eq!(opened.repository.namespace(), Some("namespace"));
+	}
+
+	fn create_todo_file(git_directory: &Path) -> PathBuf {
+		let state_directory = git_directory.join("rebase-merge");
+		create_dir_all(&state_directory).unwrap();
+		let todo_file = state_directory.join("git-rebase-todo");
+		write(&todo_file, "pick aaa comment\n").unwrap();
+		todo_file
+	}
+
+	fn canonical_git_directory(repository: &Repository) -> PathBuf {
+		repository.git_directory().canonicalize().unwrap()
+	}
+
+	#[test]
+	fn open_from_env_with_work_tree() {
+		with_temp_repository(|repository| {
+			let work_tree = repository.repository.workdir().unwrap().canonicalize().unwrap();
+			let env = TestEnv::new("/")
+				.var("GIT_DIR", repository.git_directory())
+				.var("GIT_WORK_TREE", &work_tree);
+			let opened = Repository::open_from_env_with(&env).unwrap();
+			assert_eq!(canonical_git_directory(&opened), canonical_git_directory(&repository));
+			assert_eq!(opened.repository.workdir().unwrap().canonicalize().unwrap(), work_tree);
+			Ok(())
+		});
+	}
+
+	#[test]
+	fn open_from_env_linked_worktree_git_directory() {
+		with_linked_worktree(|_, linked| {
+			let env = TestEnv::new("/").var("GIT_DIR", linked.git_directory());
+			let opened = Repository::open_from_env_with(&env).unwrap();
+			assert!(opened.is_linked_worktree());
+			assert_eq!(canonical_git_directory(&opened), canonical_git_directory(linked));
+		});
+	}
+
+	#[test]
+	fn open_for_todo_file_environment_repository() {
+		with_temp_repository(|repository| {
+			let todo_file = create_todo_file(repository.git_directory());
+			let env = TestEnv::new("/").var("GIT_DIR", repository.git_directory());
+			let opened = Repository::open_for_todo_file_with(&todo_file, &env).unwrap();
+			assert_eq!(canonical_git_directory(&opened), canonical_git_directory(&repository));
+			Ok(())
+		});
+	}
+
+	#[test]
+	fn open_for_todo_file_linked_worktree() {
+		with_linked_worktree(|main, linked| {
+			let todo_file = create_todo_file(linked.git_directory());
+			let env = TestEnv::new("/").var("GIT_DIR", main.git_directory());
+			let opened = Repository::open_for_todo_file_with(&todo_file, &env).unwrap();
+			assert!(opened.is_linked_worktree());
+			assert_eq!(canonical_git_directory(&opened), canonical_git_directory(linked));
+		});
+	}
+
+	#[test]
+	fn open_for_todo_file_separate_git_directory() {
+		let directory = tempfile::tempdir().unwrap();
+		let git_directory = directory.path().join("git");
+		let work_tree = directory.path().join("work");
+		let mut options = git2::RepositoryInitOptions::new();
+		let _ = options.workdir_path(&work_tree).no_dotgit_dir(true);
+		let _repository = git2::Repository::init_opts(&git_directory, &options).unwrap();
+		let todo_file = create_todo_file(&git_directory);
+		let env = TestEnv::new("/").var("GIT_DIR", fixture_path("simple"));
+		let opened = Repository::open_for_todo_file_with(&todo_file, &env).unwrap();
+		assert_eq!(canonical_git_directory(&opened), git_directory.canonicalize().unwrap());
+		assert_eq!(
+			opened.repository.workdir().unwrap().canonicalize().unwrap(),
+			work_tree.canonicalize().unwrap()
+		);
+	}
+
+	#[test]
+	fn open_for_todo_file_environment_error() {
+		with_temp_repository(|repository| {
+			let todo_file = create_todo_file(repository.git_directory());
+			let env = TestEnv::new("/").var("GIT_DIR", fixture_path("does-not-exist"));
+			let opened = Repository::open_for_todo_file_with(&todo_file, &env).unwrap();
+			assert_eq!(canonical_git_directory(&opened), canonical_git_directory(&repository));
+			Ok(())
+		});
+	}
+
+	#[test]
+	fn open_for_todo_file_outside_of_git_directory() {
+		let path = fixture_path("simple");
+		let env = TestEnv::new("/").var("GIT_DIR", &path);
+		let opened = Repository::open_for_todo_file_with(Path::new("/tmp/todo-file"), &env).unwrap();
+		assert_eq!(canonical_git_directory(&opened), path.canonicalize().unwrap());
+	}
+
 	#[test]
 	fn open_from_path() {
 		let path = Path::new(env!("CARGO_MANIFEST_DIR"))
@@ -136,6 +937,41 @@ mod tests {
 		);
 	}
 
+	#[test]
+	fn init_sandbox() {
+		let directory = tempfile::tempdir().unwrap();
+		let (repository, hashes) = Repository::init_sandbox(directory.path(), &[
+			SandboxCommit::new("Initial commit").file("README.md", "# Sandbox\n"),
+			SandboxCommit::new("Add a file")
+				.author("Author", "author@example.com")
+				.time(1_609_502_400)
+				.file("directory/file.txt", "content\n")
+				.file("README.md", "# Sandbox\n\nA file\n"),
+		])
+		.unwrap();
+		assert_eq!(hashes.len(), 2);
+		let commit = repository.load_commit(hashes[1].as_str()).unwrap();
+		assert_eq!(commit.summary(), &Some(String::from("Add a file")));
+		assert_eq!(commit.author().to_string(), "Author <author@example.com>");
+		assert_eq!(commit.committed_date().timestamp(), 1_609_502_400);
+		let diff = repository
+			.load_commit_diff(hashes[1].as_str(), &CommitDiffLoaderOptions::new())
+			.unwrap();
+		assert_eq!(diff.parent().as_ref().unwrap().hash(), hashes[0].as_str());
+		assert_eq!(diff.number_files_changed(), 2);
+	}
+
+	#[test]
+	fn init_sandbox_error() {
+		let directory = tempfile::tempdir().unwrap();
+		let file = directory.path().join("file");
+		write(&file, "").unwrap();
+		assert_eq!(
+			format!("{:#}", Repository::init_sandbox(file.as_path(), &[]).err().unwrap()).split(':').next(),
+			Some("Could not create sandbox repository")
+		);
+	}
+
 	#[test]
 	fn load_config() {
 		with_temp_bare_repository(|repo| {
@@ -145,24 +981,860 @@ mod tests {
 	}
 
 	#[test]
-	fn load_commit_diff() {
-		with_temp_repository(|repository| {
-			let repo: git2::Repository = repository.repository;
-			let id = {
-				let tree = repo.find_tree(repo.index()?.write_tree()?)?;
-				let sig = git2::Signature::new("name", "name@example.com", &git2::Time::new(1609459200, 0))?;
-				let head = repo.find_reference("refs/heads/main")?.peel_to_commit()?;
-				repo.commit(Some("HEAD"), &sig, &sig, "title", &tree, &[&head])?
-			};
-			let repository = Repository::from(repo);
+	fn work_directory() {
+		with_temp_repository(|repo| {
+			assert!(repo.work_directory().is_some());
+			Ok(())
+		});
+	}
+
+	#[test]
+	fn work_directory_bare() {
+		with_temp_bare_repository(|repo| {
+			assert!(repo.work_directory().is_none());
+			Ok(())
+		});
+	}
+
+	#[test]
+	fn open_config_writer_local() {
+		with_temp_repository(|repo| {
+			let mut writer = repo.open_config_writer(ConfigLocation::Local).unwrap();
+			writer.set_string("interactive-rebase-tool.pickColor", "green").unwrap();
+			let config = repo.load_config().unwrap();
+			assert_eq!(config.get_string("interactive-rebase-tool.pickColor")?, "green");
+			Ok(())
+		});
+	}
+
+	#[test]
+	fn open_config_writer_local_linked_worktree() {
+		with_linked_worktree(|main, linked| {
+			let mut writer = linked.open_config_writer(ConfigLocation::Local).unwrap();
+			writer.set_string("interactive-rebase-tool.pickColor", "green").unwrap();
+			let config = main.load_config().unwrap();
+			assert_eq!(config.get_string("interactive-rebase-tool.pickColor").unwrap(), "green");
+		});
+	}
 
+	#[test]
+	fn load_commit_diff() {
+		RepoBuilder::new().commit("title").with_repository(|repository, commits| {
 			let _diff = repository
-				.load_commit_diff(id.to_string().as_str(), &CommitDiffLoaderOptions::new())
+				.load_commit_diff(commits.hash("title").as_str(), &CommitDiffLoaderOptions::new())
 				.unwrap();
 			Ok(())
 		});
 	}
 
+	#[test]
+	fn load_commit_diff_merge() {
+		RepoBuilder::new()
+			.branch("feature")
+			.commit_files("feature", &[("feature.txt", "feature\n")])
+			.checkout("main")
+			.commit_files("main", &[("main.txt", "main\n")])
+			.merge("feature", "merge")
+			.with_repository(|repository, commits| {
+				let diff = repository
+					.load_commit_diff(commits.hash("merge").as_str(), &CommitDiffLoaderOptions::new())
+					.unwrap();
+				assert_eq!(diff.parent().as_ref().unwrap().hash(), commits.hash("main"));
+				assert_eq!(diff.number_files_changed(), 1);
+				Ok(())
+			});
+	}
+
+	#[test]
+	fn load_squash_diff() {
+		RepoBuilder::new()
+			.commit_files("base", &[("a.txt", "a\n"), ("b.txt", "b\n")])
+			.commit_files("one", &[("a.txt", "a\none\n")])
+			.commit_files("two", &[("b.txt", "b\ntwo\n")])
+			.commit_files("three", &[("c.txt", "three\n")])
+			.with_repository(|repository, commits| {
+				let diff = repository
+					.load_squash_diff(
+						&[commits.hash("one").as_str(), commits.hash("three").as_str()],
+						&CommitDiffLoaderOptions::new(),
+					)
+					.unwrap();
+				assert_eq!(diff.commit().hash(), commits.hash("one"));
+				assert_eq!(diff.parent().as_ref().unwrap().hash(), commits.hash("base"));
+				let paths: Vec<&Path> = diff
+					.file_statuses()
+					.iter()
+					.map(FileStatus::destination_path)
+					.collect();
+				assert_eq!(paths, vec![Path::new("a.txt"), Path::new("c.txt")]);
+				assert_eq!(diff.number_insertions(), 2);
+				Ok(())
+			});
+	}
+
+	#[test]
+	fn load_squash_diff_conflict() {
+		RepoBuilder::new()
+			.commit_files("base", &[("a.txt", "a\n")])
+			.commit_files("one", &[("a.txt", "one\n")])
+			.commit_files("two", &[("a.txt", "two\n")])
+			.with_repository(|repository, commits| {
+				let hash = commits.hash("base");
+				assert_eq!(
+					format!(
+						"{:#}",
+						repository
+							.load_squash_diff(
+								&[commits.hash("two").as_str(), hash.as_str()],
+								&CommitDiffLoaderOptions::new()
+							)
+							.unwrap_err()
+					),
+					format!("The changes of {} conflict with the changes of the earlier commits", hash)
+				);
+				Ok(())
+			});
+	}
+
+	#[test]
+	fn create_commit_with_message() {
+		RepoBuilder::new()
+			.commit_files("base", &[("a.txt", "a\n")])
+			.commit_files("one", &[("a.txt", "one\n")])
+			.with_repository(|repository, commits| {
+				let hash = repository
+					.create_commit_with_message(commits.hash("one").as_str(), "combined\n\nbody\n")
+					.unwrap();
+				let commit = repository.load_commit(hash.as_str()).unwrap();
+				assert_eq!(commit.message().as_deref(), Some("combined\n\nbody\n"));
+				assert_eq!(commit.author().name().as_deref(), Some("name"));
+				let diff = repository
+					.load_commit_diff(hash.as_str(), &CommitDiffLoaderOptions::new())
+					.unwrap();
+				assert_eq!(diff.parent().as_ref().unwrap().hash(), commits.hash("base"));
+				assert_eq!(diff.number_files_changed(), 1);
+				// the branch is not changed
+				assert_eq!(head_id(&repository, "main").to_string(), commits.hash("one"));
+				Ok(())
+			});
+	}
+
+	#[test]
+	fn create_commit_with_message_error() {
+		with_temp_repository(|repository| {
+			assert_eq!(
+				format!(
+					"{:#}",
+					repository
+						.create_commit_with_message("fffffff", "message")
+						.unwrap_err()
+				),
+				"Could not load commit fffffff: revspec 'fffffff' not found"
+			);
+			Ok(())
+		});
+	}
+
+	#[test]
+	fn load_commit() {
+		RepoBuilder::new().commit("title").with_repository(|repository, commits| {
+			let hash = commits.hash("title");
+			let commit = repository.load_commit(&hash[0..7]).unwrap();
+			assert_eq!(commit.hash(), hash);
+			assert_eq!(commit.summary().as_deref(), Some("title"));
+			assert_eq!(commit.author().name().as_deref(), Some("name"));
+			Ok(())
+		});
+	}
+
+	#[test]
+	fn load_commit_tag_reference() {
+		RepoBuilder::new()
+			.commit("title")
+			.tag("v1.0.0")
+			.with_repository(|repository, commits| {
+				let commit = repository.load_commit("v1.0.0").unwrap();
+				assert_eq!(commit.hash(), commits.hash("title"));
+				Ok(())
+			});
+	}
+
+	#[test]
+	fn load_commit_error() {
+		with_temp_repository(|repository| {
+			assert_eq!(
+				format!("{:#}", repository.load_commit("fffffff").unwrap_err()),
+				"Could not load commit fffffff: revspec 'fffffff' not found"
+			);
+			Ok(())
+		});
+	}
+
+	#[test]
+	fn read_file_content() {
+		with_temp_repository(|repository| {
+			create_commit(
+				&repository,
+				Some(CreateCommitOptions::new().file("src/file.txt", "line 1\nline 2\n")),
+			)?;
+			let hash = head_id(&repository, "main").to_string();
+			let mut content = vec![];
+			let size = repository
+				.read_file_content(hash.as_str(), Path::new("src/file.txt"), 7, |chunk| {
+					content.extend_from_slice(chunk);
+					true
+				})
+				.unwrap();
+			assert_eq!(size, 14);
+			assert_eq!(content, b"line 2\n");
+			Ok(())
+		});
+	}
+
+	#[test]
+	fn read_file_content_missing_file() {
+		with_temp_repository(|repository| {
+			let hash = head_id(&repository, "main").to_string();
+			assert_eq!(
+				format!(
+					"{:#}",
+					repository
+						.read_file_content(hash.as_str(), Path::new("missing.txt"), 0, |_| true)
+						.unwrap_err()
+				),
+				format!(
+					"Could not find missing.txt in {}: the path 'missing.txt' does not exist in the given tree",
+					hash
+				)
+			);
+			Ok(())
+		});
+	}
+
+	#[test]
+	fn unique_abbreviation_length_minimum() {
+		with_temp_repository(|repository| {
+			let hash = head_id(&repository, "main").to_string();
+			assert_eq!(repository.unique_abbreviation_length([&hash[0..4]], 7).unwrap(), 7);
+			Ok(())
+		});
+	}
+
+	#[test]
+	fn unique_abbreviation_length_maximum() {
+		with_temp_repository(|repository| {
+			let hash = head_id(&repository, "main").to_string();
+			assert_eq!(repository.unique_abbreviation_length([hash.as_str()], 50).unwrap(), 40);
+			Ok(())
+		});
+	}
+
+	#[test]
+	fn unique_abbreviation_length_unknown_hash() {
+		with_temp_repository(|repository| {
+			assert_eq!(repository.unique_abbreviation_length(["not-a-hash", "0000000"], 4).unwrap(), 4);
+			Ok(())
+		});
+	}
+
+	#[test]
+	fn unique_abbreviation_length_shared_prefix() {
+		with_temp_repository(|repository| {
+			// enough objects that some share the shortest prefixes
+			for index in 0..2000 {
+				let _ = repository.repository.blob(format!("{}", index).as_bytes())?;
+			}
+			let mut hashes = vec![];
+			repository.repository.odb()?.foreach(|oid| {
+				hashes.push(oid.to_string());
+				true
+			})?;
+			// the length at which no two objects share a prefix
+			let expected = (4..40)
+				.find(|&length| {
+					let mut prefixes = hashes.iter().map(|hash| &hash[0..length]).collect::<Vec<_>>();
+					prefixes.sort_unstable();
+					prefixes.dedup();
+					prefixes.len() == hashes.len()
+				})
+				.unwrap();
+			assert_eq!(
+				repository
+					.unique_abbreviation_length(hashes.iter().map(String::as_str), 1)
+					.unwrap(),
+				expected
+			);
+			Ok(())
+		});
+	}
+
+	#[test]
+	fn has_uncommitted_changes_clean() {
+		with_temp_repository(|repository| {
+			assert!(!repository.has_uncommitted_changes().unwrap());
+			Ok(())
+		});
+	}
+
+	#[test]
+	fn has_uncommitted_changes_untracked_file() {
+		with_temp_repository(|repository| {
+			let work_dir = repository.repository.workdir().unwrap().to_owned();
+			write(work_dir.join("untracked.txt"), "content").unwrap();
+			assert!(!repository.has_uncommitted_changes().unwrap());
+			Ok(())
+		});
+	}
+
+	#[test]
+	fn has_uncommitted_changes_staged_file() {
+		with_temp_repository(|repository| {
+			let work_dir = repository.repository.workdir().unwrap().to_owned();
+			write(work_dir.join("staged.txt"), "content").unwrap();
+			let mut index = repository.repository.index()?;
+			index.add_path(Path::new("staged.txt"))?;
+			index.write()?;
+			assert!(repository.has_uncommitted_changes().unwrap());
+			Ok(())
+		});
+	}
+
+	#[test]
+	fn has_uncommitted_changes_bare_repository() {
+		with_temp_bare_repository(|repository| {
+			assert!(repository.has_uncommitted_changes().is_err());
+			Ok(())
+		});
+	}
+
+	#[test]
+	fn status_clean() {
+		with_temp_repository(|repository| {
+			assert!(repository.status().unwrap().is_clean());
+			Ok(())
+		});
+	}
+
+	#[test]
+	fn status_untracked_file() {
+		with_temp_repository(|repository| {
+			let work_dir = repository.repository.workdir().unwrap().to_owned();
+			write(work_dir.join("untracked.txt"), "content").unwrap();
+			let status = repository.status().unwrap();
+			assert_eq!(status.untracked(), &[PathBuf::from("untracked.txt")]);
+			assert!(status.staged().is_empty());
+			assert!(status.unstaged().is_empty());
+			Ok(())
+		});
+	}
+
+	#[test]
+	fn status_staged_and_unstaged_file() {
+		with_temp_repository(|repository| {
+			let work_dir = repository.repository.workdir().unwrap().to_owned();
+			write(work_dir.join("file.txt"), "content").unwrap();
+			let mut index = repository.repository.index()?;
+			index.add_path(Path::new("file.txt"))?;
+			index.write()?;
+			write(work_dir.join("file.txt"), "modified content").unwrap();
+			let status = repository.status().unwrap();
+			assert_eq!(status.staged(), &[PathBuf::from("file.txt")]);
+			assert_eq!(status.unstaged(), &[PathBuf::from("file.txt")]);
+			assert!(status.untracked().is_empty());
+			Ok(())
+		});
+	}
+
+	#[test]
+	fn status_bare_repository() {
+		with_temp_bare_repository(|repository| {
+			assert!(repository.status().is_err());
+			Ok(())
+		});
+	}
+
+	#[test]
+	fn status_ahead_behind_without_upstream() {
+		with_temp_repository(|repository| {
+			assert_eq!(repository.status().unwrap().ahead_behind(), None);
+			Ok(())
+		});
+	}
+
+	#[test]
+	fn status_ahead_behind_with_upstream() {
+		RepoBuilder::new()
+			.branch("upstream")
+			.commit("upstream")
+			.checkout("main")
+			.commit("local")
+			.with_repository(|repository, _| {
+				repository
+					.repository
+					.find_branch("main", git2::BranchType::Local)?
+					.set_upstream(Some("upstream"))?;
+				assert_eq!(repository.status().unwrap().ahead_behind(), Some((1, 1)));
+				Ok(())
+			});
+	}
+
+	#[test]
+	fn status_ahead_behind_during_rebase() {
+		RepoBuilder::new()
+			.branch("upstream")
+			.commit("upstream")
+			.checkout("main")
+			.commit("local")
+			.with_repository(|repository, commits| {
+				repository
+					.repository
+					.find_branch("main", git2::BranchType::Local)?
+					.set_upstream(Some("upstream"))?;
+				repository
+					.repository
+					.set_head_detached(git2::Oid::from_str(commits.hash("upstream").as_str())?)?;
+				write_rebase_head_name(repository.git_directory(), "refs/heads/main\n");
+				assert_eq!(repository.status().unwrap().ahead_behind(), Some((1, 1)));
+				Ok(())
+			});
+	}
+
+	fn with_linked_worktree<F>(callback: F)
+	where F: FnOnce(&Repository, &Repository) {
+		with_temp_repository(|repository| {
+			let worktree_path = tempfile::tempdir().unwrap();
+			let worktree_checkout = worktree_path.path().join("linked");
+			let _worktree = repository.repository.worktree("linked", &worktree_checkout, None)?;
+			let linked = Repository::open_from_path(&worktree_checkout).unwrap();
+			callback(&repository, &linked);
+			Ok(())
+		});
+	}
+
+	fn write_rebase_head_name(git_directory: &Path, head_name: &str) {
+		create_dir_all(git_directory.join("rebase-merge")).unwrap();
+		write(git_directory.join("rebase-merge").join("head-name"), head_name).unwrap();
+	}
+
+	#[test]
+	fn worktree_main() {
+		with_temp_repository(|repository| {
+			assert!(!repository.is_linked_worktree());
+			assert_eq!(repository.common_directory(), repository.git_directory());
+			Ok(())
+		});
+	}
+
+	#[test]
+	fn worktree_linked() {
+		with_linked_worktree(|main, linked| {
+			assert!(linked.is_linked_worktree());
+			assert_ne!(linked.git_directory(), main.git_directory());
+			assert_eq!(
+				linked.common_directory().canonicalize().unwrap(),
+				main.git_directory().canonicalize().unwrap()
+			);
+		});
+	}
+
+	#[test]
+	fn is_shallow_not_shallow() {
+		with_temp_repository(|repository| {
+			assert!(!repository.is_shallow());
+			Ok(())
+		});
+	}
+
+	#[test]
+	fn is_shallow_with_shallow_file() {
+		with_temp_repository(|repository| {
+			let head = repository.git2_repository().head()?.peel_to_commit()?.id();
+			write(repository.git_directory().join("shallow"), format!("{}\n", head)).unwrap();
+			assert!(repository.is_shallow());
+			Ok(())
+		});
+	}
+
+	#[test]
+	fn is_partial_clone_not_partial_clone() {
+		with_temp_repository(|repository| {
+			assert!(!repository.is_partial_clone());
+			Ok(())
+		});
+	}
+
+	#[test]
+	fn is_partial_clone_with_promisor_remote() {
+		with_temp_repository(|repository| {
+			let mut config = repository.git2_repository().config()?;
+			config.set_str("remote.origin.url", "https://example.com/repository.git")?;
+			config.set_bool("remote.origin.promisor", true)?;
+			assert!(repository.is_partial_clone());
+			Ok(())
+		});
+	}
+
+	#[test]
+	fn is_partial_clone_with_remote_that_is_not_promisor() {
+		with_temp_repository(|repository| {
+			let mut config = repository.git2_repository().config()?;
+			config.set_bool("remote.origin.promisor", false)?;
+			config.set_bool("remote.upstream-promisor.fetch", true)?;
+			assert!(!repository.is_partial_clone());
+			Ok(())
+		});
+	}
+
+	#[test]
+	fn is_partial_clone_with_partial_clone_extension() {
+		with_temp_repository(|repository| {
+			let mut config = repository.git2_repository().config()?;
+			config.set_str("extensions.partialClone", "origin")?;
+			assert!(repository.is_partial_clone());
+			Ok(())
+		});
+	}
+
+	#[test]
+	fn promisor_remote_not_partial_clone() {
+		with_temp_repository(|repository| {
+			assert_eq!(repository.promisor_remote(), None);
+			Ok(())
+		});
+	}
+
+	#[test]
+	fn promisor_remote_with_promisor_remote() {
+		with_temp_repository(|repository| {
+			let mut config = repository.git2_repository().config()?;
+			config.set_bool("remote.origin.promisor", false)?;
+			config.set_bool("remote.upstream.promisor", true)?;
+			assert_eq!(repository.promisor_remote(), Some(String::from("upstream")));
+			Ok(())
+		});
+	}
+
+	#[test]
+	fn promisor_remote_with_partial_clone_extension() {
+		with_temp_repository(|repository| {
+			let mut config = repository.git2_repository().config()?;
+			config.set_str("extensions.partialClone", "upstream")?;
+			assert_eq!(repository.promisor_remote(), Some(String::from("upstream")));
+			Ok(())
+		});
+	}
+
+	fn wait_for_fetch(fetch: &mut ObjectFetch) -> Result<()> {
+		loop {
+			if let Some(result) = fetch.poll() {
+				return result;
+			}
+			sleep(Duration::from_millis(10));
+		}
+	}
+
+	#[test]
+	fn find_missing_objects_partial_clone() {
+		with_temp_partial_clone(|clone, hash| {
+			assert!(clone.is_partial_clone());
+			assert_eq!(clone.promisor_remote(), Some(String::from("origin")));
+			assert_eq!(clone.find_missing_objects(hash).unwrap().len(), 1);
+		});
+	}
+
+	#[test]
+	fn find_missing_objects_not_found() {
+		with_temp_repository(|repository| {
+			assert!(repository.find_missing_objects("not-a-commit").is_err());
+			Ok(())
+		});
+	}
+
+	#[test]
+	fn fetch_missing_objects() {
+		with_temp_partial_clone(|clone, hash| {
+			let object_ids = clone.find_missing_objects(hash).unwrap();
+			let mut fetch = clone.fetch_missing_objects(&object_ids).unwrap();
+			assert_eq!(fetch.remote(), "origin");
+			assert_eq!(fetch.object_count(), 1);
+			wait_for_fetch(&mut fetch).unwrap();
+			assert_eq!(fetch.progress(), Some((1, 1)));
+			assert!(clone.find_missing_objects(hash).unwrap().is_empty());
+			let diff = clone.load_commit_diff(hash, &CommitDiffLoaderOptions::new()).unwrap();
+			assert!(diff.file_statuses()[0].is_content_available());
+		});
+	}
+
+	#[test]
+	fn fetch_missing_objects_error() {
+		with_temp_partial_clone(|clone, _| {
+			let mut fetch = clone
+				.fetch_missing_objects(&[String::from("0123456789012345678901234567890123456789")])
+				.unwrap();
+			let err = wait_for_fetch(&mut fetch).unwrap_err();
+			assert!(err.to_string().starts_with("git fetch failed with"));
+		});
+	}
+
+	#[test]
+	fn fetch_missing_objects_cancel() {
+		with_temp_partial_clone(|clone, hash| {
+			let object_ids = clone.find_missing_objects(hash).unwrap();
+			let mut fetch = clone.fetch_missing_objects(&object_ids).unwrap();
+			fetch.cancel();
+			assert!(fetch.poll().is_none());
+		});
+	}
+
+	#[test]
+	fn fetch_missing_objects_not_partial_clone() {
+		with_temp_repository(|repository| {
+			assert_eq!(
+				repository
+					.fetch_missing_objects(&[String::from("0123456789012345678901234567890123456789")])
+					.unwrap_err()
+					.to_string(),
+				"The repository is not a partial clone"
+			);
+			Ok(())
+		});
+	}
+
+	#[test]
+	fn rebase_range_none() {
+		with_temp_repository(|repository| {
+			assert_eq!(repository.rebase_range(), None);
+			Ok(())
+		});
+	}
+
+	#[test]
+	fn rebase_range_in_progress() {
+		with_temp_repository(|repository| {
+			let state_directory = repository.git_directory().join("rebase-merge");
+			create_dir_all(&state_directory).unwrap();
+			write(state_directory.join("onto"), "0123\n").unwrap();
+			write(state_directory.join("orig-head"), "4567\n").unwrap();
+			assert_eq!(
+				repository.rebase_range(),
+				Some((String::from("0123"), String::from("4567")))
+			);
+			Ok(())
+		});
+	}
+
+	#[test]
+	fn ahead_behind() {
+		RepoBuilder::new()
+			.commit("base")
+			.branch("feature")
+			.commit("feature one")
+			.commit("feature two")
+			.checkout("main")
+			.commit("main one")
+			.with_repository(|repository, _| {
+				assert_eq!(repository.ahead_behind("feature", "main").unwrap(), (2, 1));
+				assert_eq!(repository.ahead_behind("main", "feature").unwrap(), (1, 2));
+				Ok(())
+			});
+	}
+
+	#[test]
+	fn ahead_behind_unknown_commit() {
+		with_temp_repository(|repository| {
+			assert!(repository.ahead_behind("main", "unknown").is_err());
+			Ok(())
+		});
+	}
+
+	#[test]
+	fn load_range_topology() {
+		RepoBuilder::new()
+			.commit("base")
+			.commit("first")
+			.branch("feature")
+			.commit_files("side", &[("side.txt", "side\n")])
+			.checkout("main")
+			.commit_files("main", &[("main.txt", "main\n")])
+			.merge("feature", "merge feature")
+			.with_repository(|repository, commits| {
+				let topology = repository
+					.load_range_topology(commits.hash("base").as_str(), "main")
+					.unwrap();
+				assert_eq!(topology.ahead(), 4);
+				assert_eq!(topology.behind(), 0);
+				assert_eq!(topology.find(commits.hash("base").as_str()), None);
+				assert_eq!(
+					topology.find(commits.hash("first").as_str()),
+					Some(CommitTopology::Mainline)
+				);
+				assert_eq!(
+					topology.find(commits.hash("side").as_str()),
+					Some(CommitTopology::SideBranch)
+				);
+				assert_eq!(
+					topology.find(commits.hash("main").as_str()),
+					Some(CommitTopology::Mainline)
+				);
+				assert_eq!(
+					topology.find(&commits.hash("merge feature")[..7]),
+					Some(CommitTopology::Merge)
+				);
+				Ok(())
+			});
+	}
+
+	#[test]
+	fn load_range_topology_behind_base() {
+		RepoBuilder::new()
+			.commit("base")
+			.branch("feature")
+			.commit("feature one")
+			.checkout("main")
+			.commit("main one")
+			.commit("main two")
+			.with_repository(|repository, _| {
+				let topology = repository.load_range_topology("main", "feature").unwrap();
+				assert_eq!(topology.ahead(), 1);
+				assert_eq!(topology.behind(), 2);
+				Ok(())
+			});
+	}
+
+	#[test]
+	fn load_range_topology_unknown_commit() {
+		with_temp_repository(|repository| {
+			assert!(repository.load_range_topology("unknown", "main").is_err());
+			Ok(())
+		});
+	}
+
+	#[test]
+	fn range_diff() {
+		RepoBuilder::new()
+			.commit("base")
+			.branch("feature")
+			.commit_files("feature one", &[("one.txt", "one\n")])
+			.commit_files("feature two", &[("two.txt", "two\n")])
+			.commit_files("feature three", &[("three.txt", "three\n")])
+			.checkout("main")
+			.commit_files("upstream one", &[("one.txt", "one\n")])
+			.commit_files("feature two\n\nreworked", &[("two.txt", "reworked two\n")])
+			.commit("upstream only")
+			.with_repository(|repository, commits| {
+				let hashes = [
+					commits.hash("feature one"),
+					commits.hash("feature two"),
+					commits.hash("feature three"),
+				];
+				let range_diff = repository
+					.range_diff(&[&hashes[0][..7], hashes[1].as_str(), hashes[2].as_str()], "main")
+					.unwrap();
+				let entries: Vec<(String, RangeDiffStatus, Option<String>)> = range_diff
+					.entries()
+					.iter()
+					.map(|entry| {
+						(
+							String::from(entry.hash()),
+							entry.status(),
+							entry.upstream_hash().map(String::from),
+						)
+					})
+					.collect();
+				assert_eq!(entries, vec![
+					(
+						commits.hash("feature one"),
+						RangeDiffStatus::Same,
+						Some(commits.hash("upstream one"))
+					),
+					(
+						commits.hash("feature two"),
+						RangeDiffStatus::Modified,
+						Some(commits.hash("feature two\n\nreworked"))
+					),
+					(commits.hash("feature three"), RangeDiffStatus::New, None),
+					(commits.hash("upstream only"), RangeDiffStatus::Upstream, None),
+				]);
+				Ok(())
+			});
+	}
+
+	#[test]
+	fn range_diff_commit_in_upstream() {
+		RepoBuilder::new()
+			.commit("base")
+			.commit("first")
+			.commit("second")
+			.with_repository(|repository, commits| {
+				let range_diff = repository
+					.range_diff(&[commits.hash("first").as_str()], "main")
+					.unwrap();
+				assert_eq!(range_diff.entries().len(), 2);
+				assert_eq!(range_diff.entries()[0].status(), RangeDiffStatus::Same);
+				assert_eq!(range_diff.entries()[1].summary(), "second");
+				Ok(())
+			});
+	}
+
+	#[test]
+	fn range_diff_unknown_commit() {
+		with_temp_repository(|repository| {
+			assert_eq!(
+				format!("{:#}", repository.range_diff(&["aaaaaaa"], "main").unwrap_err()),
+				"Could not load commit aaaaaaa: revspec 'aaaaaaa' not found"
+			);
+			Ok(())
+		});
+	}
+
+	#[test]
+	fn rebase_head_name_none() {
+		with_temp_repository(|repository| {
+			assert_eq!(repository.rebase_head_name(), None);
+			Ok(())
+		});
+	}
+
+	#[test]
+	fn find_conflicting_worktree_rebases_same_branch() {
+		with_linked_worktree(|main, linked| {
+			write_rebase_head_name(main.git_directory(), "refs/heads/feature\n");
+			write_rebase_head_name(linked.git_directory(), "refs/heads/feature\n");
+			let rebases = linked.find_conflicting_worktree_rebases();
+			assert_eq!(rebases.len(), 1);
+			assert_eq!(
+				rebases[0].git_directory().canonicalize().unwrap(),
+				main.git_directory().canonicalize().unwrap()
+			);
+			assert_eq!(
+				rebases[0].work_tree().canonicalize().unwrap(),
+				main.repository.workdir().unwrap().canonicalize().unwrap()
+			);
+			assert_eq!(
+				main.find_conflicting_worktree_rebases()[0].work_tree().canonicalize().unwrap(),
+				linked.repository.workdir().unwrap().canonicalize().unwrap()
+			);
+			assert_eq!(main.find_conflicting_worktree_rebases().len(), 1);
+		});
+	}
+
+	#[test]
+	fn find_conflicting_worktree_rebases_different_branch() {
+		with_linked_worktree(|main, linked| {
+			write_rebase_head_name(main.git_directory(), "refs/heads/main\n");
+			write_rebase_head_name(linked.git_directory(), "refs/heads/feature\n");
+			assert!(linked.find_conflicting_worktree_rebases().is_empty());
+		});
+	}
+
+	#[test]
+	fn find_conflicting_worktree_rebases_no_rebase() {
+		with_linked_worktree(|main, linked| {
+			write_rebase_head_name(main.git_directory(), "refs/heads/feature\n");
+			assert!(linked.find_conflicting_worktree_rebases().is_empty());
+		});
+	}
+
 	#[test]
 	fn from_git2_repository() {
 		with_temp_bare_repository(|repository| {
@@ -0,0 +1,151 @@
+use std::path::{Path, PathBuf};
+
+/// Represents a summary of the staged, unstaged and untracked files in a repository, and of how far the branch is
+/// ahead and behind of its upstream branch.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct RepositoryStatus {
+	pub(crate) staged: Vec<PathBuf>,
+	pub(crate) unstaged: Vec<PathBuf>,
+	pub(crate) untracked: Vec<PathBuf>,
+	pub(crate) ahead_behind: Option<(usize, usize)>,
+}
+
+impl RepositoryStatus {
+	pub(crate) fn new(statuses: &git2::Statuses<'_>, ahead_behind: Option<(usize, usize)>) -> Self {
+		let mut status = Self {
+			ahead_behind,
+			..Self::default()
+		};
+		for entry in statuses.iter() {
+			let path = match entry.path() {
+				Some(path) => PathBuf::from(path),
+				None => continue,
+			};
+			let flags = entry.status();
+			if flags.is_wt_new() {
+				status.untracked.push(path);
+				continue;
+			}
+			if flags.intersects(
+				git2::Status::INDEX_NEW
+					| git2::Status::INDEX_MODIFIED
+					| git2::Status::INDEX_DELETED
+					| git2::Status::INDEX_RENAMED
+					| git2::Status::INDEX_TYPECHANGE,
+			) {
+				status.staged.push(path.clone());
+			}
+			if flags.intersects(
+				git2::Status::WT_MODIFIED
+					| git2::Status::WT_DELETED
+					| git2::Status::WT_RENAMED
+					| git2::Status::WT_TYPECHANGE
+					| git2::Status::CONFLICTED,
+			) {
+				status.unstaged.push(path);
+			}
+		}
+		status
+	}
+
+	/// Get the paths of files with changes staged in the index.
+	#[must_use]
+	#[inline]
+	pub fn staged(&self) -> &[PathBuf] {
+		self.staged.as_slice()
+	}
+
+	/// Get the paths of tracked files with changes in the working tree that are not staged.
+	#[must_use]
+	#[inline]
+	pub fn unstaged(&self) -> &[PathBuf] {
+		self.unstaged.as_slice()
+	}
+
+	/// Get the paths of files in the working tree that are not tracked.
+	#[must_use]
+	#[inline]
+	pub fn untracked(&self) -> &[PathBuf] {
+		self.untracked.as_slice()
+	}
+
+	/// Get the number of commits that the branch is ahead and behind of its upstream branch, if the branch, or the
+	/// branch being rebased, has an upstream branch.
+	#[must_use]
+	#[inline]
+	pub const fn ahead_behind(&self) -> Option<(usize, usize)> {
+		self.ahead_behind
+	}
+
+	/// Does the index or working tree have changes to tracked files.
+	#[must_use]
+	#[inline]
+	pub fn has_tracked_changes(&self) -> bool {
+		!self.staged.is_empty() || !self.unstaged.is_empty()
+	}
+
+	/// Does the repository have no staged, unstaged or untracked files.
+	#[must_use]
+	#[inline]
+	pub fn is_clean(&self) -> bool {
+		!self.has_tracked_changes() && self.untracked.is_empty()
+	}
+
+	/// Does the status contain the path, in any of the staged, unstaged or untracked files.
+	#[must_use]
+	#[inline]
+	pub fn contains<P: AsRef<Path>>(&self, path: P) -> bool {
+		let path = path.as_ref();
+		self.staged.iter().any(|p| p == path)
+			|| self.unstaged.iter().any(|p| p == path)
+			|| self.untracked.iter().any(|p| p == path)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn default_is_clean() {
+		let status = RepositoryStatus::default();
+		assert!(status.is_clean());
+		assert!(!status.has_tracked_changes());
+		assert_eq!(status.ahead_behind(), None);
+	}
+
+	#[test]
+	fn untracked_only() {
+		let status = RepositoryStatus {
+			untracked: vec![PathBuf::from("a")],
+			..RepositoryStatus::default()
+		};
+		assert!(!status.is_clean());
+		assert!(!status.has_tracked_changes());
+		assert!(status.contains("a"));
+		assert!(!status.contains("b"));
+	}
+
+	#[test]
+	fn staged_and_unstaged() {
+		let status = RepositoryStatus {
+			staged: vec![PathBuf::from("a")],
+			unstaged: vec![PathBuf::from("b")],
+			..RepositoryStatus::default()
+		};
+		assert!(status.has_tracked_changes());
+		assert_eq!(status.staged(), &[PathBuf::from("a")]);
+		assert_eq!(status.unstaged(), &[PathBuf::from("b")]);
+		assert!(status.untracked().is_empty());
+	}
+
+	#[test]
+	fn ahead_behind() {
+		let status = RepositoryStatus {
+			ahead_behind: Some((2, 1)),
+			..RepositoryStatus::default()
+		};
+		assert!(status.is_clean());
+		assert_eq!(status.ahead_behind(), Some((2, 1)));
+	}
+}
@@ -0,0 +1,49 @@
+/// A commit to create in a sandbox repository, with `Repository::init_sandbox`.
+#[derive(Clone, Debug)]
+pub struct SandboxCommit {
+	pub(crate) author_email: String,
+	pub(crate) author_name: String,
+	pub(crate) files: Vec<(String, String)>,
+	pub(crate) message: String,
+	pub(crate) time: Option<i64>,
+}
+
+impl SandboxCommit {
+	/// Create a new commit with a message, authored by `Sandbox` at the time that the commit is created.
+	#[inline]
+	#[must_use]
+	pub fn new(message: &str) -> Self {
+		Self {
+			author_email: String::from("sandbox@example.com"),
+			author_name: String::from("Sandbox"),
+			files: vec![],
+			message: String::from(message),
+			time: None,
+		}
+	}
+
+	/// Set the name and email of the author, who is also the committer.
+	#[inline]
+	#[must_use]
+	pub fn author(mut self, name: &str, email: &str) -> Self {
+		self.author_name = String::from(name);
+		self.author_email = String::from(email);
+		self
+	}
+
+	/// Set the time of the commit, in seconds since the epoch.
+	#[inline]
+	#[must_use]
+	pub const fn time(mut self, time: i64) -> Self {
+		self.time = Some(time);
+		self
+	}
+
+	/// Add a file, with its content, to the commit. The path is relative to the root of the repository.
+	#[inline]
+	#[must_use]
+	pub fn file(mut self, path: &str, content: &str) -> Self {
+		self.files.push((String::from(path), String::from(content)));
+		self
+	}
+}
@@ -19,6 +19,8 @@ impl CommitDiffBuilder {
 				number_files_changed: 0,
 				number_insertions: 0,
 				number_deletions: 0,
+				is_shallow_boundary: false,
+				is_tree_available: true,
 			},
 		}
 	}
@@ -73,6 +75,22 @@ impl CommitDiffBuilder {
 		self
 	}
 
+	/// Set if the commit is at the boundary of a shallow clone.
+	#[inline]
+	#[must_use]
+	pub const fn shallow_boundary(mut self, shallow_boundary: bool) -> Self {
+		self.commit_diff.is_shallow_boundary = shallow_boundary;
+		self
+	}
+
+	/// Set if the trees of the commit are available.
+	#[inline]
+	#[must_use]
+	pub const fn tree_available(mut self, available: bool) -> Self {
+		self.commit_diff.is_tree_available = available;
+		self
+	}
+
 	/// Return the built `CommitDiff`
 	#[inline]
 	#[must_use]
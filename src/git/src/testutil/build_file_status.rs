@@ -1,4 +1,4 @@
-use std::path::{Path, PathBuf};
+use std::{path::Path, sync::Arc};
 
 use crate::{Delta, FileMode, FileStatus, Status};
 
@@ -15,20 +15,29 @@ impl FileStatusBuilder {
 	pub fn new() -> Self {
 		Self {
 			file_status: FileStatus {
+				content_available: true,
 				deltas: vec![],
 				destination_is_binary: false,
 				destination_mode: FileMode::Normal,
-				destination_path: PathBuf::default(),
+				destination_path: Arc::from(Path::new("")),
 				largest_new_line_number: 0,
 				largest_old_line_number: 0,
 				source_is_binary: false,
 				source_mode: FileMode::Normal,
-				source_path: PathBuf::default(),
+				source_path: Arc::from(Path::new("")),
 				status: Status::Added,
 			},
 		}
 	}
 
+	/// Set if the content of the files is available.
+	#[inline]
+	#[must_use]
+	pub const fn content_available(mut self, available: bool) -> Self {
+		self.file_status.content_available = available;
+		self
+	}
+
 	/// Push a `Delta`.
 	#[inline]
 	#[must_use]
@@ -57,7 +66,7 @@ impl FileStatusBuilder {
 	#[inline]
 	#[must_use]
 	pub fn destination_path<F: AsRef<Path>>(mut self, path: F) -> Self {
-		self.file_status.destination_path = PathBuf::from(path.as_ref());
+		self.file_status.destination_path = Arc::from(path.as_ref());
 		self
 	}
 
@@ -97,7 +106,7 @@ impl FileStatusBuilder {
 	#[inline]
 	#[must_use]
 	pub fn source_path<F: AsRef<Path>>(mut self, path: F) -> Self {
-		self.file_status.source_path = PathBuf::from(path.as_ref());
+		self.file_status.source_path = Arc::from(path.as_ref());
 		self
 	}
 
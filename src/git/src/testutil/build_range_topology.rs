@@ -0,0 +1,64 @@
+use std::collections::BTreeMap;
+
+use crate::{CommitTopology, RangeTopology};
+
+/// Builder for creating a new range topology.
+#[derive(Debug)]
+pub struct RangeTopologyBuilder {
+	range_topology: RangeTopology,
+}
+
+impl RangeTopologyBuilder {
+	/// Create a new instance, without any commits.
+	#[inline]
+	#[must_use]
+	pub const fn new() -> Self {
+		Self {
+			range_topology: RangeTopology {
+				ahead: 0,
+				behind: 0,
+				commits: BTreeMap::new(),
+			},
+		}
+	}
+
+	/// Set the number of commits of the head that are not in the history of the base.
+	#[inline]
+	#[must_use]
+	pub const fn ahead(mut self, ahead: usize) -> Self {
+		self.range_topology.ahead = ahead;
+		self
+	}
+
+	/// Set the number of commits of the base that are not in the history of the head.
+	#[inline]
+	#[must_use]
+	pub const fn behind(mut self, behind: usize) -> Self {
+		self.range_topology.behind = behind;
+		self
+	}
+
+	/// Add a commit, with the full hash of the commit, to the range.
+	#[inline]
+	#[must_use]
+	pub fn commit(mut self, hash: &str, topology: CommitTopology) -> Self {
+		let _previous = self.range_topology.commits.insert(String::from(hash), topology);
+		self
+	}
+
+	/// Build the `RangeTopology`.
+	#[inline]
+	#[must_use]
+	#[allow(clippy::missing_const_for_fn)]
+	pub fn build(self) -> RangeTopology {
+		self.range_topology
+	}
+}
+
+impl Default for RangeTopologyBuilder {
+	#[inline]
+	#[must_use]
+	fn default() -> Self {
+		Self::new()
+	}
+}
@@ -0,0 +1,241 @@
+#![cfg(not(tarpaulin_include))]
+
+use std::{collections::HashMap, fs, path::Path};
+
+use git2::Oid;
+use tempfile::Builder;
+
+use crate::{testutil::JAN_2021_EPOCH, Repository};
+
+#[derive(Debug)]
+enum Step {
+	Branch(String),
+	Checkout(String),
+	Commit(String, Vec<(String, String)>),
+	Merge(String, String),
+	Tag(String),
+}
+
+/// The ids of the commits created by a `RepoBuilder`, by the message of the commit.
+#[derive(Debug)]
+pub struct RepoCommits {
+	ids: HashMap<String, Oid>,
+}
+
+impl RepoCommits {
+	/// Get the id of the commit with the message.
+	///
+	/// # Panics
+	///
+	/// If no commit was created with the message.
+	#[inline]
+	#[must_use]
+	#[allow(clippy::panic)]
+	pub fn id(&self, message: &str) -> Oid {
+		*self
+			.ids
+			.get(message)
+			.unwrap_or_else(|| panic!("No commit was created with the message {}", message))
+	}
+
+	/// Get the full hash of the commit with the message.
+	///
+	/// # Panics
+	///
+	/// If no commit was created with the message.
+	#[inline]
+	#[must_use]
+	pub fn hash(&self, message: &str) -> String {
+		self.id(message).to_string()
+	}
+}
+
+/// Builder for a repository in a temporary directory, with commits, branches, merges and tags, for testing. The
+/// repository starts with an initial empty commit on `main`, like `with_temp_repository`, and each step is applied to
+/// the checked out branch. The commits are made a minute apart, from Jan 1, 2021 UTC, so that the ids of the commits
+/// are the same on each run.
+#[derive(Debug)]
+pub struct RepoBuilder {
+	steps: Vec<Step>,
+}
+
+impl RepoBuilder {
+	/// Create a new instance of the builder.
+	#[inline]
+	#[must_use]
+	pub const fn new() -> Self {
+		Self { steps: vec![] }
+	}
+
+	/// Create a commit, with the message, on the checked out branch, without changing any files.
+	#[inline]
+	#[must_use]
+	pub fn commit(self, message: &str) -> Self {
+		self.commit_files(message, &[])
+	}
+
+	/// Create a commit, with the message, on the checked out branch, that writes the files, with their content.
+	#[inline]
+	#[must_use]
+	pub fn commit_files(mut self, message: &str, files: &[(&str, &str)]) -> Self {
+		self.steps.push(Step::Commit(
+			String::from(message),
+			files
+				.iter()
+				.map(|&(path, content)| (String::from(path), String::from(content)))
+				.collect(),
+		));
+		self
+	}
+
+	/// Create a branch at the head of the checked out branch, and check it out.
+	#[inline]
+	#[must_use]
+	pub fn branch(mut self, name: &str) -> Self {
+		self.steps.push(Step::Branch(String::from(name)));
+		self
+	}
+
+	/// Check out an existing branch.
+	#[inline]
+	#[must_use]
+	pub fn checkout(mut self, name: &str) -> Self {
+		self.steps.push(Step::Checkout(String::from(name)));
+		self
+	}
+
+	/// Merge a branch into the checked out branch, with a merge commit with the message, which always has two
+	/// parents, even when the branch could be fast forwarded.
+	#[inline]
+	#[must_use]
+	pub fn merge(mut self, branch: &str, message: &str) -> Self {
+		self.steps.push(Step::Merge(String::from(branch), String::from(message)));
+		self
+	}
+
+	/// Create a lightweight tag at the head of the checked out branch.
+	#[inline]
+	#[must_use]
+	pub fn tag(mut self, name: &str) -> Self {
+		self.steps.push(Step::Tag(String::from(name)));
+		self
+	}
+
+	fn checkout_branch(repository: &git2::Repository, name: &str) -> Result<(), git2::Error> {
+		repository.set_head(format!("refs/heads/{}", name).as_str())?;
+		repository.checkout_head(Some(git2::build::CheckoutBuilder::new().force()))
+	}
+
+	fn apply(repository: &git2::Repository, steps: &[Step]) -> Result<RepoCommits, git2::Error> {
+		let mut ids = HashMap::new();
+		let mut time = JAN_2021_EPOCH;
+		let mut signature = || {
+			time += 60;
+			git2::Signature::new("name", "name@example.com", &git2::Time::new(time, 0))
+		};
+		let workdir = repository
+			.workdir()
+			.ok_or_else(|| git2::Error::from_str("Repository does not have a working directory"))?
+			.to_owned();
+
+		let initial_signature = git2::Signature::new("name", "name@example.com", &git2::Time::new(JAN_2021_EPOCH, 0))?;
+		let initial_tree = repository.find_tree(repository.index()?.write_tree()?)?;
+		let _ = repository.commit(
+			Some("HEAD"),
+			&initial_signature,
+			&initial_signature,
+			"initial commit",
+			&initial_tree,
+			&[],
+		)?;
+
+		for step in steps {
+			match *step {
+				Step::Branch(ref name) => {
+					let head = repository.head()?.peel_to_commit()?;
+					let _ = repository.branch(name.as_str(), &head, false)?;
+					Self::checkout_branch(repository, name.as_str())?;
+				},
+				Step::Checkout(ref name) => Self::checkout_branch(repository, name.as_str())?,
+				Step::Commit(ref message, ref files) => {
+					let mut index = repository.index()?;
+					for (path, content) in files {
+						let file_path = workdir.join(path);
+						if let Some(parent) = file_path.parent() {
+							fs::create_dir_all(parent).map_err(|err| git2::Error::from_str(err.to_string().as_str()))?;
+						}
+						fs::write(file_path, content).map_err(|err| git2::Error::from_str(err.to_string().as_str()))?;
+						index.add_path(Path::new(path))?;
+					}
+					index.write()?;
+					let tree = repository.find_tree(index.write_tree()?)?;
+					let head = repository.head()?.peel_to_commit()?;
+					let sig = signature()?;
+					let id = repository.commit(Some("HEAD"), &sig, &sig, message.as_str(), &tree, &[&head])?;
+					let _ = ids.insert(message.clone(), id);
+				},
+				Step::Merge(ref branch, ref message) => {
+					let head = repository.head()?.peel_to_commit()?;
+					let other = repository
+						.find_reference(format!("refs/heads/{}", branch).as_str())?
+						.peel_to_commit()?;
+					let mut merged_index = repository.merge_commits(&head, &other, None)?;
+					if merged_index.has_conflicts() {
+						return Err(git2::Error::from_str(
+							format!("Merging {} has conflicts", branch).as_str(),
+						));
+					}
+					let merged_tree = repository.find_tree(merged_index.write_tree_to(repository)?)?;
+					let sig = signature()?;
+					let id = repository.commit(
+						Some("HEAD"),
+						&sig,
+						&sig,
+						message.as_str(),
+						&merged_tree,
+						&[&head, &other],
+					)?;
+					repository.checkout_head(Some(git2::build::CheckoutBuilder::new().force()))?;
+					let _ = ids.insert(message.clone(), id);
+				},
+				Step::Tag(ref name) => {
+					let head = repository.head()?.peel(git2::ObjectType::Commit)?;
+					let _ = repository.tag_lightweight(name.as_str(), &head, false)?;
+				},
+			}
+		}
+		Ok(RepoCommits { ids })
+	}
+
+	/// Build the repository in a temporary directory, and provide it, with the ids of the created commits, to the
+	/// callback. The directory is removed when the callback returns.
+	///
+	/// # Panics
+	///
+	/// If the repository cannot be created for any reason, or the callback returns an error, this function will panic.
+	#[allow(clippy::panic)]
+	#[inline]
+	pub fn with_repository<F>(self, callback: F)
+	where F: FnOnce(Repository, &RepoCommits) -> Result<(), git2::Error> {
+		let directory = Builder::new()
+			.prefix("interactive-rebase-tool")
+			.tempdir()
+			.expect("Unable to create a temporary directory");
+		let mut opts = git2::RepositoryInitOptions::new();
+		let _ = opts.initial_head("main");
+		let repository = git2::Repository::init_opts(directory.path(), &opts).expect("Unable to init a repository");
+		let commits = Self::apply(&repository, self.steps.as_slice())
+			.unwrap_or_else(|err| panic!("Unable to build the repository: {}", err));
+		if let Err(err) = callback(Repository::from(repository), &commits) {
+			panic!("{} failed with {}", stringify!(err), err)
+		}
+		directory.close().expect("Unable to close temporary path");
+	}
+}
+
+impl Default for RepoBuilder {
+	#[inline]
+	fn default() -> Self {
+		Self::new()
+	}
+}
@@ -1,5 +1,7 @@
 #![cfg(not(tarpaulin_include))]
 
+use std::{fs, path::Path};
+
 use lazy_static::lazy_static;
 
 use crate::{testutil::JAN_2021_EPOCH, Repository};
@@ -18,6 +20,7 @@ pub struct CreateCommitOptions {
 	committer_name: Option<String>,
 	committer_email: Option<String>,
 	committer_time: i64,
+	files: Vec<(String, String)>,
 	head_name: String,
 	message: String,
 }
@@ -88,6 +91,13 @@ impl CreateCommitOptions {
 		self
 	}
 
+	/// Add a file, with its content, to the commit.
+	#[inline]
+	pub fn file(&mut self, path: &str, content: &str) -> &mut Self {
+		self.files.push((String::from(path), String::from(content)));
+		self
+	}
+
 	/// Set the head name.
 	#[inline]
 	pub fn head_name(&mut self, name: &str) -> &mut Self {
@@ -113,6 +123,7 @@ impl Default for CreateCommitOptions {
 			committer_name: None,
 			committer_email: None,
 			committer_time: JAN_2021_EPOCH,
+			files: vec![],
 			head_name: String::from("main"),
 			message: String::from("title\n\nbody"),
 		}
@@ -121,7 +132,7 @@ impl Default for CreateCommitOptions {
 
 /// Create a commit based on the provided options. If `options` is not provided, will create a
 /// commit using the default options. This function does not add modified or new files to the stage
-/// before creating a commit.
+/// before creating a commit, other than the files added to the options.
 ///
 /// # Errors
 ///
@@ -130,7 +141,19 @@ impl Default for CreateCommitOptions {
 pub fn create_commit(repository: &Repository, options: Option<&CreateCommitOptions>) -> Result<(), git2::Error> {
 	let repo = repository.git2_repository();
 	let opts = options.unwrap_or(&DEFAULT_COMMIT_OPTIONS);
-	let id = repo.index()?.write_tree()?;
+	let mut index = repo.index()?;
+	if let Some(workdir) = repo.workdir() {
+		for &(ref path, ref content) in &opts.files {
+			let file_path = workdir.join(path);
+			if let Some(parent) = file_path.parent() {
+				fs::create_dir_all(parent).map_err(|err| git2::Error::from_str(err.to_string().as_str()))?;
+			}
+			fs::write(file_path, content).map_err(|err| git2::Error::from_str(err.to_string().as_str()))?;
+			index.add_path(Path::new(path))?;
+		}
+		index.write()?;
+	}
+	let id = index.write_tree()?;
 	let tree = repo.find_tree(id)?;
 	let author_sig = git2::Signature::new(
 		opts.author_name.as_str(),
@@ -4,8 +4,11 @@
 mod build_commit;
 mod build_commit_diff;
 mod build_file_status;
+mod build_range_topology;
 mod build_reference;
+mod build_repository;
 mod create_commit;
+mod test_env;
 mod with_temp_repository;
 
 use git2::Oid;
@@ -14,9 +17,12 @@ pub use self::{
 	build_commit::CommitBuilder,
 	build_commit_diff::CommitDiffBuilder,
 	build_file_status::FileStatusBuilder,
+	build_range_topology::RangeTopologyBuilder,
 	build_reference::ReferenceBuilder,
+	build_repository::{RepoBuilder, RepoCommits},
 	create_commit::{create_commit, CreateCommitOptions},
-	with_temp_repository::{with_temp_bare_repository, with_temp_repository},
+	test_env::TestEnv,
+	with_temp_repository::{with_temp_bare_repository, with_temp_partial_clone, with_temp_repository},
 };
 use crate::Repository;
 
@@ -0,0 +1,48 @@
+use std::{
+	collections::HashMap,
+	ffi::{OsStr, OsString},
+	io,
+	path::{Path, PathBuf},
+};
+
+use crate::EnvProvider;
+
+/// An environment for testing, with only the environment variables that are set on it, and a current directory, so that
+/// tests that open a repository from the environment do not change the environment of the process.
+#[derive(Debug)]
+pub struct TestEnv {
+	current_dir: PathBuf,
+	variables: HashMap<String, OsString>,
+}
+
+impl TestEnv {
+	/// Create a new instance, with no environment variables, and a current directory.
+	#[inline]
+	#[must_use]
+	pub fn new<P: AsRef<Path>>(current_dir: P) -> Self {
+		Self {
+			current_dir: current_dir.as_ref().to_path_buf(),
+			variables: HashMap::new(),
+		}
+	}
+
+	/// Set an environment variable.
+	#[inline]
+	#[must_use]
+	pub fn var<V: AsRef<OsStr>>(mut self, name: &str, value: V) -> Self {
+		let _ = self.variables.insert(String::from(name), value.as_ref().to_os_string());
+		self
+	}
+}
+
+impl EnvProvider for TestEnv {
+	#[inline]
+	fn var_os(&self, name: &str) -> Option<OsString> {
+		self.variables.get(name).cloned()
+	}
+
+	#[inline]
+	fn current_dir(&self) -> io::Result<PathBuf> {
+		Ok(self.current_dir.clone())
+	}
+}
@@ -1,10 +1,13 @@
 #![cfg(not(tarpaulin_include))]
 
-use std::path::Path;
+use std::{path::Path, process::Command};
 
 use tempfile::Builder;
 
-use crate::{testutil::JAN_2021_EPOCH, Repository};
+use crate::{
+	testutil::{RepoBuilder, JAN_2021_EPOCH},
+	Repository,
+};
 
 fn with_temporary_path<F>(callback: F)
 where F: FnOnce(&Path) {
@@ -65,3 +68,37 @@ where F: FnOnce(Repository) -> anyhow::Result<()> {
 		};
 	});
 }
+
+/// Provide a partial clone, in a temporary directory, of a repository with a commit that adds `file.txt`.
+///
+/// The content of the file is not in the clone, and can be fetched from the repository. The callback is provided the
+/// clone and the hash of the commit.
+///
+/// # Panics
+///
+/// If Git cannot create the clone for any reason, this function will panic.
+#[allow(clippy::unwrap_used)]
+#[inline]
+pub fn with_temp_partial_clone<F>(callback: F)
+where F: FnOnce(&Repository, &str) {
+	RepoBuilder::new()
+		.commit_files("add file", &[("file.txt", "content\n")])
+		.with_repository(|repository, commits| {
+			let mut config = repository.git2_repository().config()?;
+			config.set_bool("uploadpack.allowFilter", true)?;
+			config.set_bool("uploadpack.allowAnySHA1InWant", true)?;
+			with_temporary_path(|path| {
+				// a local clone is not filtered, unless the objects are sent as they are to a remote repository
+				let status = Command::new("git")
+					.args(["clone", "--quiet", "--bare", "--no-local", "--filter=blob:none"])
+					.arg(repository.git_directory())
+					.arg(path)
+					.status()
+					.unwrap();
+				assert!(status.success(), "Unable to create a partial clone");
+				let clone = Repository::open_from_path(path).unwrap();
+				callback(&clone, commits.hash("add file").as_str());
+			});
+			Ok(())
+		});
+}
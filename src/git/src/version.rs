@@ -0,0 +1,19 @@
+/// Get the version of libgit2 that is used to read repositories, which can differ from the installed version of git.
+#[must_use]
+#[inline]
+pub fn libgit2_version() -> String {
+	let (major, minor, patch) = git2::Version::get().libgit2_version();
+	format!("{}.{}.{}", major, minor, patch)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn libgit2_version_format() {
+		let version = libgit2_version();
+		assert_eq!(version.split('.').count(), 3);
+		assert!(version.split('.').all(|part| part.parse::<u32>().is_ok()));
+	}
+}
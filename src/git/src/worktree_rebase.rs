@@ -0,0 +1,242 @@
+use std::{
+	fs::{read_dir, read_to_string},
+	path::{Path, PathBuf},
+};
+
+/// Represents a rebase that is in progress in a worktree of the repository.
+#[derive(Debug, PartialEq, Eq)]
+pub struct WorktreeRebase {
+	git_directory: PathBuf,
+	head_name: String,
+	work_tree: PathBuf,
+}
+
+impl WorktreeRebase {
+	/// Get the git directory of the worktree that the rebase is in progress in.
+	#[must_use]
+	#[inline]
+	pub fn git_directory(&self) -> &Path {
+		self.git_directory.as_path()
+	}
+
+	/// Get the name of the reference being rebased, for example `refs/heads/main`.
+	#[must_use]
+	#[inline]
+	pub fn head_name(&self) -> &str {
+		self.head_name.as_str()
+	}
+
+	/// Get the path of the working tree of the worktree that the rebase is in progress in.
+	#[must_use]
+	#[inline]
+	pub fn work_tree(&self) -> &Path {
+		self.work_tree.as_path()
+	}
+}
+
+// A linked worktree's git directory contains a `commondir` file, with a path that is relative to the git
+// directory, that points to the git directory shared between all the worktrees.
+pub(crate) fn resolve_common_directory(git_directory: &Path) -> PathBuf {
+	read_to_string(git_directory.join("commondir")).map_or_else(
+		|_| git_directory.to_path_buf(),
+		|common_directory| git_directory.join(common_directory.trim_end()),
+	)
+}
+
+// A linked worktree's git directory contains a `gitdir` file, with the path to the `.git` file in its working tree,
+// while the working tree of the main worktree is the parent of its `.git` directory.
+pub(crate) fn resolve_work_tree(git_directory: &Path) -> PathBuf {
+	let git_path = read_to_string(git_directory.join("gitdir")).map_or_else(
+		|_| git_directory.to_path_buf(),
+		|git_file| git_directory.join(git_file.trim_end()),
+	);
+	if git_path.file_name().map_or(false, |name| name == ".git") {
+		git_path.parent().map_or_else(|| git_path.clone(), Path::to_path_buf)
+	}
+	else {
+		git_path
+	}
+}
+
+pub(crate) fn read_rebase_head_name(git_directory: &Path) -> Option<String> {
+	["rebase-merge", "rebase-apply"].iter().find_map(|state_directory| {
+		read_to_string(git_directory.join(state_directory).join("head-name"))
+			.ok()
+			.map(|head_name| String::from(head_name.trim_end()))
+			.filter(|head_name| !head_name.is_empty() && head_name != "detached HEAD")
+	})
+}
+
+// the commit that the commits are rebased onto, and the commit that was the head when the rebase started
+pub(crate) fn read_rebase_range(git_directory: &Path) -> Option<(String, String)> {
+	["rebase-merge", "rebase-apply"].iter().find_map(|state_directory| {
+		let read_commit = |name: &str| {
+			read_to_string(git_directory.join(state_directory).join(name))
+				.ok()
+				.map(|hash| String::from(hash.trim_end()))
+				.filter(|hash| !hash.is_empty())
+		};
+		Some((read_commit("onto")?, read_commit("orig-head")?))
+	})
+}
+
+pub(crate) fn find_worktree_rebases(common_directory: &Path) -> Vec<WorktreeRebase> {
+	let mut git_directories = vec![common_directory.to_path_buf()];
+	if let Ok(entries) = read_dir(common_directory.join("worktrees")) {
+		let mut linked: Vec<PathBuf> = entries
+			.filter_map(|entry| entry.ok().map(|e| e.path()))
+			.filter(|path| path.is_dir())
+			.collect();
+		linked.sort();
+		git_directories.append(&mut linked);
+	}
+
+	git_directories
+		.into_iter()
+		.filter_map(|git_directory| {
+			read_rebase_head_name(git_directory.as_path()).map(|head_name| {
+				WorktreeRebase {
+					work_tree: resolve_work_tree(git_directory.as_path()),
+					git_directory,
+					head_name,
+				}
+			})
+		})
+		.collect()
+}
+
+#[cfg(all(unix, test))]
+mod tests {
+	use std::fs::{create_dir_all, write};
+
+	use tempfile::tempdir;
+
+	use super::*;
+
+	fn write_head_name(git_directory: &Path, state_directory: &str, head_name: &str) {
+		create_dir_all(git_directory.join(state_directory)).unwrap();
+		write(git_directory.join(state_directory).join("head-name"), head_name).unwrap();
+	}
+
+	#[test]
+	fn resolve_common_directory_main_worktree() {
+		let directory = tempdir().unwrap();
+		assert_eq!(resolve_common_directory(directory.path()), directory.path());
+	}
+
+	#[test]
+	fn resolve_common_directory_linked_worktree() {
+		let directory = tempdir().unwrap();
+		let git_directory = directory.path().join("worktrees").join("linked");
+		create_dir_all(&git_directory).unwrap();
+		write(git_directory.join("commondir"), "../..\n").unwrap();
+		assert_eq!(
+			resolve_common_directory(&git_directory),
+			directory.path().join("worktrees").join("linked").join("../..")
+		);
+	}
+
+	#[test]
+	fn resolve_work_tree_main_worktree() {
+		let directory = tempdir().unwrap();
+		let git_directory = directory.path().join(".git");
+		create_dir_all(&git_directory).unwrap();
+		assert_eq!(resolve_work_tree(&git_directory), directory.path());
+	}
+
+	#[test]
+	fn resolve_work_tree_linked_worktree() {
+		let directory = tempdir().unwrap();
+		let git_directory = directory.path().join("worktrees").join("linked");
+		create_dir_all(&git_directory).unwrap();
+		write(git_directory.join("gitdir"), "/path/to/linked/.git\n").unwrap();
+		assert_eq!(resolve_work_tree(&git_directory), Path::new("/path/to/linked"));
+	}
+
+	#[test]
+	fn resolve_work_tree_bare() {
+		let directory = tempdir().unwrap();
+		assert_eq!(resolve_work_tree(directory.path()), directory.path());
+	}
+
+	#[test]
+	fn read_rebase_head_name_none() {
+		let directory = tempdir().unwrap();
+		assert_eq!(read_rebase_head_name(directory.path()), None);
+	}
+
+	#[test]
+	fn read_rebase_head_name_merge() {
+		let directory = tempdir().unwrap();
+		write_head_name(directory.path(), "rebase-merge", "refs/heads/feature\n");
+		assert_eq!(
+			read_rebase_head_name(directory.path()),
+			Some(String::from("refs/heads/feature"))
+		);
+	}
+
+	#[test]
+	fn read_rebase_head_name_apply() {
+		let directory = tempdir().unwrap();
+		write_head_name(directory.path(), "rebase-apply", "refs/heads/feature\n");
+		assert_eq!(
+			read_rebase_head_name(directory.path()),
+			Some(String::from("refs/heads/feature"))
+		);
+	}
+
+	#[test]
+	fn read_rebase_head_name_detached() {
+		let directory = tempdir().unwrap();
+		write_head_name(directory.path(), "rebase-merge", "detached HEAD\n");
+		assert_eq!(read_rebase_head_name(directory.path()), None);
+	}
+
+	#[test]
+	fn read_rebase_range_none() {
+		let directory = tempdir().unwrap();
+		assert_eq!(read_rebase_range(directory.path()), None);
+	}
+
+	#[test]
+	fn read_rebase_range_merge() {
+		let directory = tempdir().unwrap();
+		let state_directory = directory.path().join("rebase-merge");
+		create_dir_all(&state_directory).unwrap();
+		write(state_directory.join("onto"), "0123\n").unwrap();
+		write(state_directory.join("orig-head"), "4567\n").unwrap();
+		assert_eq!(
+			read_rebase_range(directory.path()),
+			Some((String::from("0123"), String::from("4567")))
+		);
+	}
+
+	#[test]
+	fn read_rebase_range_without_orig_head() {
+		let directory = tempdir().unwrap();
+		let state_directory = directory.path().join("rebase-merge");
+		create_dir_all(&state_directory).unwrap();
+		write(state_directory.join("onto"), "0123\n").unwrap();
+		assert_eq!(read_rebase_range(directory.path()), None);
+	}
+
+	#[test]
+	fn find_worktree_rebases_multiple() {
+		let directory = tempdir().unwrap();
+		let common_directory = directory.path();
+		write_head_name(common_directory, "rebase-merge", "refs/heads/main\n");
+		write_head_name(
+			common_directory.join("worktrees").join("a").as_path(),
+			"rebase-merge",
+			"refs/heads/feature\n",
+		);
+		create_dir_all(common_directory.join("worktrees").join("b")).unwrap();
+		let rebases = find_worktree_rebases(common_directory);
+		assert_eq!(rebases.len(), 2);
+		assert_eq!(rebases[0].git_directory(), common_directory);
+		assert_eq!(rebases[0].head_name(), "refs/heads/main");
+		assert_eq!(rebases[1].git_directory(), common_directory.join("worktrees").join("a"));
+		assert_eq!(rebases[1].head_name(), "refs/heads/feature");
+		assert_eq!(rebases[1].work_tree(), common_directory.join("worktrees").join("a"));
+	}
+}
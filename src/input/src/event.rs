@@ -14,6 +14,9 @@ pub enum Event {
 	Mouse(MouseEvent),
 	/// An empty event.
 	None,
+	/// A character of text that was pasted into the terminal, which is inserted as is instead of being handled as a
+	/// key press.
+	Paste(char),
 	/// A terminal resize event.
 	Resize(u16, u16),
 }
@@ -25,6 +28,10 @@ impl From<crossterm::event::Event> for Event {
 			crossterm::event::Event::Key(evt) => Self::Key(evt),
 			crossterm::event::Event::Mouse(evt) => Self::Mouse(evt),
 			crossterm::event::Event::Resize(width, height) => Self::Resize(width, height),
+			// pasted text is split into paste events by the event handler, and focus changes are not used
+			crossterm::event::Event::Paste(_)
+			| crossterm::event::Event::FocusGained
+			| crossterm::event::Event::FocusLost => Self::None,
 		}
 	}
 }
@@ -39,20 +46,14 @@ impl From<MetaEvent> for Event {
 impl From<KeyCode> for Event {
 	#[inline]
 	fn from(code: KeyCode) -> Self {
-		Self::Key(KeyEvent {
-			code,
-			modifiers: KeyModifiers::empty(),
-		})
+		Self::Key(KeyEvent::new(code, KeyModifiers::empty()))
 	}
 }
 
 impl From<char> for Event {
 	#[inline]
 	fn from(c: char) -> Self {
-		Self::Key(KeyEvent {
-			code: KeyCode::Char(c),
-			modifiers: KeyModifiers::empty(),
-		})
+		Self::Key(KeyEvent::new(KeyCode::Char(c), KeyModifiers::empty()))
 	}
 }
 
@@ -87,6 +88,12 @@ mod tests {
 		assert_eq!(event, Event::Resize(100, 100));
 	}
 
+	#[test]
+	fn from_crossterm_paste_event() {
+		let event = Event::from(crossterm::event::Event::Paste(String::from("abc")));
+		assert_eq!(event, Event::None);
+	}
+
 	#[test]
 	fn from_meta_event() {
 		let event = Event::from(MetaEvent::Kill);
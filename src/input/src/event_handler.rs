@@ -28,14 +28,17 @@ impl EventHandler {
 	/// Poll for the next available event.
 	#[inline]
 	pub fn poll_event(&self) -> Event {
-		if let Some(event) = self.event_queue.borrow_mut().pop_front() {
-			event
-		}
-		else if let Ok(Some(event)) = (self.event_provider)() {
-			Event::from(event)
+		let queued_event = self.event_queue.borrow_mut().pop_front();
+		if let Some(event) = queued_event {
+			return event;
 		}
-		else {
-			Event::None
+		match (self.event_provider)() {
+			Ok(Some(crossterm::event::Event::Paste(text))) => {
+				self.push_paste(text.as_str());
+				self.event_queue.borrow_mut().pop_front().unwrap_or(Event::None)
+			},
+			Ok(Some(event)) => Event::from(event),
+			_ => Event::None,
 		}
 	}
 
@@ -45,6 +48,16 @@ impl EventHandler {
 		self.event_queue.borrow_mut().push_back(event);
 	}
 
+	/// Push the text of a bracketed paste to the events stack, as a paste event for each character, with the line
+	/// endings normalized to a new line.
+	#[inline]
+	pub fn push_paste(&self, text: &str) {
+		let mut event_queue = self.event_queue.borrow_mut();
+		for c in text.replace("\r\n", "\n").replace('\r', "\n").chars() {
+			event_queue.push_back(Event::Paste(c));
+		}
+	}
+
 	/// Read and handle an event.
 	#[inline]
 	#[allow(clippy::trivially_copy_pass_by_ref)]
@@ -89,10 +102,12 @@ impl EventHandler {
 			Event::Key(KeyEvent {
 				code: KeyCode::Char('c'),
 				modifiers: KeyModifiers::CONTROL,
+				..
 			}) => Some(Event::from(MetaEvent::Kill)),
 			Event::Key(KeyEvent {
 				code: KeyCode::Char('d'),
 				modifiers: KeyModifiers::CONTROL,
+				..
 			}) => Some(Event::from(MetaEvent::Exit)),
 			_ => None,
 		}
@@ -103,34 +118,42 @@ impl EventHandler {
 			Event::Key(KeyEvent {
 				code: KeyCode::Up,
 				modifiers: KeyModifiers::NONE,
+				..
 			}) => Some(Event::from(MetaEvent::ScrollUp)),
 			Event::Key(KeyEvent {
 				code: KeyCode::Down,
 				modifiers: KeyModifiers::NONE,
+				..
 			}) => Some(Event::from(MetaEvent::ScrollDown)),
 			Event::Key(KeyEvent {
 				code: KeyCode::Left,
 				modifiers: KeyModifiers::NONE,
+				..
 			}) => Some(Event::from(MetaEvent::ScrollLeft)),
 			Event::Key(KeyEvent {
 				code: KeyCode::Right,
 				modifiers: KeyModifiers::NONE,
+				..
 			}) => Some(Event::from(MetaEvent::ScrollRight)),
 			Event::Key(KeyEvent {
 				code: KeyCode::PageUp,
 				modifiers: KeyModifiers::NONE,
+				..
 			}) => Some(Event::from(MetaEvent::ScrollJumpUp)),
 			Event::Key(KeyEvent {
 				code: KeyCode::PageDown,
 				modifiers: KeyModifiers::NONE,
+				..
 			}) => Some(Event::from(MetaEvent::ScrollJumpDown)),
 			Event::Key(KeyEvent {
 				code: KeyCode::Home,
 				modifiers: KeyModifiers::NONE,
+				..
 			}) => Some(Event::from(MetaEvent::ScrollTop)),
 			Event::Key(KeyEvent {
 				code: KeyCode::End,
 				modifiers: KeyModifiers::NONE,
+				..
 			}) => Some(Event::from(MetaEvent::ScrollBottom)),
 			_ => None,
 		}
@@ -169,6 +192,17 @@ mod tests {
 		});
 	}
 
+	#[test]
+	fn poll_event_paste() {
+		let event_handler = EventHandler::new(
+			|| Ok(Some(crossterm::event::Event::Paste(String::from("a\r\nb")))),
+			create_test_keybindings(),
+		);
+		assert_eq!(event_handler.poll_event(), Event::Paste('a'));
+		assert_eq!(event_handler.poll_event(), Event::Paste('\n'));
+		assert_eq!(event_handler.poll_event(), Event::Paste('b'));
+	}
+
 	#[test]
 	fn poll_event_error() {
 		let event_handler = EventHandler::new(move || Err(anyhow!("Read Event Error")), create_test_keybindings());
@@ -176,17 +210,11 @@ mod tests {
 	}
 
 	#[rstest]
-	#[case::standard(Event::Key(KeyEvent {
-		code: KeyCode::Char('c'),
-		modifiers: KeyModifiers::CONTROL,
-	}), true)]
+	#[case::standard(Event::Key(KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL)), true)]
 	#[case::resize(Event::Resize(100, 100), false)]
 	#[case::movement(Event::from(KeyCode::Up), false)]
 	#[case::help(Event::from('?'), false)]
-	#[case::undo_redo(Event::Key(KeyEvent {
-		code: KeyCode::Char('z'),
-		modifiers: KeyModifiers::CONTROL,
-	}), false)]
+	#[case::undo_redo(Event::Key(KeyEvent::new(KeyCode::Char('z'), KeyModifiers::CONTROL)), false)]
 	#[case::other(Event::from('a'), false)]
 	fn read_event_options_disabled(#[case] event: Event, #[case] handled: bool) {
 		with_event_handler(&[event], |context| {
@@ -204,17 +232,12 @@ mod tests {
 	}
 
 	#[rstest]
-	#[case::standard(Event::Key(KeyEvent {
-		code: KeyCode::Char('c'),
-		modifiers: KeyModifiers::CONTROL,
-	}), true)]
+	#[case::standard(Event::Key(KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL)), true)]
 	#[case::resize(Event::Resize(100, 100), true)]
 	#[case::movement(Event::from(KeyCode::Up), true)]
 	#[case::help(Event::from('?'), true)]
-	#[case::undo_redo(Event::Key(KeyEvent {
-		code: KeyCode::Char('z'),
-		modifiers: KeyModifiers::CONTROL,
-	}), true)]
+	#[case::undo_redo(Event::Key(KeyEvent::new(KeyCode::Char('z'), KeyModifiers::CONTROL)), true)]
+	#[case::paste(Event::Paste('q'), false)]
 	#[case::other(Event::from('a'), false)]
 	fn read_event_enabled(#[case] event: Event, #[case] handled: bool) {
 		with_event_handler(&[event], |context| {
@@ -233,14 +256,14 @@ mod tests {
 	}
 
 	#[rstest]
-	#[case::standard(Event::Key(KeyEvent {
-		code: KeyCode::Char('c'),
-		modifiers: KeyModifiers::CONTROL,
-	}), Event::from(MetaEvent::Kill))]
-	#[case::standard(Event::Key(KeyEvent {
-		code: KeyCode::Char('d'),
-		modifiers: KeyModifiers::CONTROL,
-	}), Event::from(MetaEvent::Exit))]
+	#[case::standard(
+		Event::Key(KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL)),
+		Event::from(MetaEvent::Kill)
+	)]
+	#[case::standard(
+		Event::Key(KeyEvent::new(KeyCode::Char('d'), KeyModifiers::CONTROL)),
+		Event::from(MetaEvent::Exit)
+	)]
 	#[case::other(Event::from('a'), Event::from(KeyCode::Null))]
 	fn standard_inputs(#[case] event: Event, #[case] expected: Event) {
 		with_event_handler(&[event], |context| {
@@ -273,14 +296,14 @@ mod tests {
 	}
 
 	#[rstest]
-	#[case::standard(Event::Key(KeyEvent {
-		code: KeyCode::Char('z'),
-		modifiers: KeyModifiers::CONTROL,
-	}), Event::from(MetaEvent::Undo))]
-	#[case::standard(Event::Key(KeyEvent {
-		code: KeyCode::Char('y'),
-		modifiers: KeyModifiers::CONTROL,
-	}), Event::from(MetaEvent::Redo))]
+	#[case::standard(
+		Event::Key(KeyEvent::new(KeyCode::Char('z'), KeyModifiers::CONTROL)),
+		Event::from(MetaEvent::Undo)
+	)]
+	#[case::standard(
+		Event::Key(KeyEvent::new(KeyCode::Char('y'), KeyModifiers::CONTROL)),
+		Event::from(MetaEvent::Redo)
+	)]
 	#[case::other(Event::from('a'), Event::from(KeyCode::Null))]
 	fn undo_redo_inputs(#[case] event: Event, #[case] expected: Event) {
 		with_event_handler(&[event], |context| {
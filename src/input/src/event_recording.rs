@@ -0,0 +1,418 @@
+use std::{cell::RefCell, collections::VecDeque, io::Write};
+
+use anyhow::{anyhow, Result};
+use crossterm::event::{Event as CrosstermEvent, MouseButton, MouseEvent, MouseEventKind};
+
+use super::{KeyCode, KeyEvent, KeyModifiers};
+
+const MODIFIERS: [(&str, KeyModifiers); 3] = [
+	("Control", KeyModifiers::CONTROL),
+	("Alt", KeyModifiers::ALT),
+	("Shift", KeyModifiers::SHIFT),
+];
+
+const KEY_CODES: [(&str, KeyCode); 17] = [
+	("Backspace", KeyCode::Backspace),
+	("BackTab", KeyCode::BackTab),
+	("Delete", KeyCode::Delete),
+	("Down", KeyCode::Down),
+	("End", KeyCode::End),
+	("Enter", KeyCode::Enter),
+	("Esc", KeyCode::Esc),
+	("Home", KeyCode::Home),
+	("Insert", KeyCode::Insert),
+	("Left", KeyCode::Left),
+	("Null", KeyCode::Null),
+	("PageDown", KeyCode::PageDown),
+	("PageUp", KeyCode::PageUp),
+	("Right", KeyCode::Right),
+	("Space", KeyCode::Char(' ')),
+	("Tab", KeyCode::Tab),
+	("Up", KeyCode::Up),
+];
+
+const MOUSE_BUTTONS: [(&str, MouseButton); 3] = [
+	("Left", MouseButton::Left),
+	("Right", MouseButton::Right),
+	("Middle", MouseButton::Middle),
+];
+
+fn format_modifiers(modifiers: KeyModifiers) -> String {
+	MODIFIERS
+		.iter()
+		.filter(|&&(_, modifier)| modifiers.contains(modifier))
+		.map(|&(name, _)| name)
+		.collect()
+}
+
+fn parse_modifiers(value: &str) -> (KeyModifiers, &str) {
+	let mut modifiers = KeyModifiers::empty();
+	let mut rest = value;
+	'outer: loop {
+		for &(name, modifier) in &MODIFIERS {
+			if let Some(remaining) = rest.strip_prefix(name) {
+				if !remaining.is_empty() {
+					modifiers.insert(modifier);
+					rest = remaining;
+					continue 'outer;
+				}
+			}
+		}
+		return (modifiers, rest);
+	}
+}
+
+fn format_key_code(code: KeyCode) -> String {
+	if let Some(&(name, _)) = KEY_CODES.iter().find(|&&(_, c)| c == code) {
+		return String::from(name);
+	}
+	match code {
+		KeyCode::F(number) => format!("F{}", number),
+		KeyCode::Char(c) => String::from(c),
+		_ => String::from("Null"),
+	}
+}
+
+fn parse_key_code(value: &str) -> Result<KeyCode> {
+	if let Some(&(_, code)) = KEY_CODES.iter().find(|&&(name, _)| name == value) {
+		return Ok(code);
+	}
+	let mut chars = value.chars();
+	match (chars.next(), chars.next()) {
+		(Some(c), None) => Ok(KeyCode::Char(c)),
+		_ => {
+			value
+				.strip_prefix('F')
+				.and_then(|number| number.parse::<u8>().ok())
+				.map(KeyCode::F)
+				.ok_or_else(|| anyhow!("Invalid key: {}", value))
+		},
+	}
+}
+
+fn format_mouse_event_kind(kind: MouseEventKind) -> String {
+	let (name, button) = match kind {
+		MouseEventKind::Down(button) => ("Down", Some(button)),
+		MouseEventKind::Up(button) => ("Up", Some(button)),
+		MouseEventKind::Drag(button) => ("Drag", Some(button)),
+		MouseEventKind::Moved => ("Moved", None),
+		MouseEventKind::ScrollDown => ("ScrollDown", None),
+		MouseEventKind::ScrollUp => ("ScrollUp", None),
+	};
+	button.map_or_else(
+		|| String::from(name),
+		|button| {
+			let &(button_name, _) = MOUSE_BUTTONS.iter().find(|&&(_, b)| b == button).unwrap();
+			format!("{}{}", name, button_name)
+		},
+	)
+}
+
+fn parse_mouse_event_kind(value: &str) -> Result<MouseEventKind> {
+	match value {
+		"Moved" => return Ok(MouseEventKind::Moved),
+		"ScrollDown" => return Ok(MouseEventKind::ScrollDown),
+		"ScrollUp" => return Ok(MouseEventKind::ScrollUp),
+		_ => {},
+	}
+	for &(button_name, button) in &MOUSE_BUTTONS {
+		if let Some(name) = value.strip_suffix(button_name) {
+			match name {
+				"Down" => return Ok(MouseEventKind::Down(button)),
+				"Up" => return Ok(MouseEventKind::Up(button)),
+				"Drag" => return Ok(MouseEventKind::Drag(button)),
+				_ => {},
+			}
+		}
+	}
+	Err(anyhow!("Invalid mouse event: {}", value))
+}
+
+// pasted text is recorded on a single line, so the line endings, and the backslashes that escape them, are escaped
+fn format_paste(text: &str) -> String {
+	text.replace('\\', "\\\\").replace('\n', "\\n").replace('\r', "\\r")
+}
+
+fn parse_paste(value: &str) -> Result<String> {
+	let mut text = String::with_capacity(value.len());
+	let mut chars = value.chars();
+	while let Some(c) = chars.next() {
+		if c == '\\' {
+			match chars.next() {
+				Some('\\') => text.push('\\'),
+				Some('n') => text.push('\n'),
+				Some('r') => text.push('\r'),
+				_ => return Err(anyhow!("Invalid paste: {}", value)),
+			}
+		}
+		else {
+			text.push(c);
+		}
+	}
+	Ok(text)
+}
+
+fn parse_number(value: Option<&str>) -> Result<u16> {
+	value
+		.ok_or_else(|| anyhow!("Missing value"))
+		.and_then(|value| value.parse::<u16>().map_err(|_| anyhow!("Invalid number: {}", value)))
+}
+
+/// Format an event as a single line of a recording, for example `key Controlc`, `resize 80 24`,
+/// `mouse ScrollDown 10 4` or `paste some text`.
+#[inline]
+#[must_use]
+pub fn format_event(event: &CrosstermEvent) -> String {
+	match *event {
+		CrosstermEvent::Key(KeyEvent { code, modifiers, .. }) => {
+			format!("key {}{}", format_modifiers(modifiers), format_key_code(code))
+		},
+		CrosstermEvent::Mouse(MouseEvent {
+			kind,
+			column,
+			row,
+			modifiers,
+		}) => {
+			format!(
+				"mouse {}{} {} {}",
+				format_modifiers(modifiers),
+				format_mouse_event_kind(kind),
+				column,
+				row
+			)
+		},
+		CrosstermEvent::Resize(width, height) => format!("resize {} {}", width, height),
+		CrosstermEvent::Paste(ref text) => format!("paste {}", format_paste(text)),
+		CrosstermEvent::FocusGained => String::from("focus gained"),
+		CrosstermEvent::FocusLost => String::from("focus lost"),
+	}
+}
+
+/// Parse a line of a recording that was created with `format_event`.
+///
+/// # Errors
+/// Will error if the line is not a valid event.
+#[inline]
+pub fn parse_event(line: &str) -> Result<CrosstermEvent> {
+	let mut parts = line.split(' ');
+	match parts.next() {
+		Some("key") => {
+			let (modifiers, key) = parse_modifiers(parts.next().unwrap_or(""));
+			Ok(CrosstermEvent::Key(KeyEvent::new(parse_key_code(key)?, modifiers)))
+		},
+		Some("mouse") => {
+			let (modifiers, kind) = parse_modifiers(parts.next().unwrap_or(""));
+			Ok(CrosstermEvent::Mouse(MouseEvent {
+				kind: parse_mouse_event_kind(kind)?,
+				column: parse_number(parts.next())?,
+				row: parse_number(parts.next())?,
+				modifiers,
+			}))
+		},
+		Some("resize") => {
+			Ok(CrosstermEvent::Resize(
+				parse_number(parts.next())?,
+				parse_number(parts.next())?,
+			))
+		},
+		Some("paste") => {
+			Ok(CrosstermEvent::Paste(parse_paste(
+				line.strip_prefix("paste ").unwrap_or(""),
+			)?))
+		},
+		Some("focus") => {
+			match parts.next() {
+				Some("gained") => Ok(CrosstermEvent::FocusGained),
+				Some("lost") => Ok(CrosstermEvent::FocusLost),
+				_ => Err(anyhow!("Invalid focus event: {}", line)),
+			}
+		},
+		_ => Err(anyhow!("Unknown event: {}", line)),
+	}
+}
+
+/// Parse a recording, one event per line. Empty lines, and lines starting with `#`, are ignored.
+///
+/// # Errors
+/// Will error if any of the lines is not a valid event, with the line number of the invalid line.
+#[inline]
+pub fn parse_recording(contents: &str) -> Result<Vec<CrosstermEvent>> {
+	contents
+		.lines()
+		.enumerate()
+		.filter(|&(_, line)| !line.trim().is_empty() && !line.starts_with('#'))
+		.map(|(index, line)| {
+			parse_event(line).map_err(|err| err.context(format!("Invalid event on line {}", index + 1)))
+		})
+		.collect()
+}
+
+/// Wrap an event provider, writing each event that is read to the writer. The recording starts with a resize event to
+/// the provided size, so that a replay of the recording starts with the same size.
+///
+/// # Errors
+/// Will error if the initial resize event cannot be written.
+#[inline]
+pub fn record_events<F, W>(
+	event_provider: F,
+	mut writer: W,
+	width: u16,
+	height: u16,
+) -> Result<impl Fn() -> Result<Option<CrosstermEvent>>>
+where
+	F: Fn() -> Result<Option<CrosstermEvent>>,
+	W: Write,
+{
+	writeln!(writer, "{}", format_event(&CrosstermEvent::Resize(width, height)))?;
+	writer.flush()?;
+	let writer = RefCell::new(writer);
+	Ok(move || {
+		let event = event_provider()?;
+		if let Some(ref e) = event {
+			let mut w = writer.borrow_mut();
+			writeln!(w, "{}", format_event(e))?;
+			w.flush()?;
+		}
+		Ok(event)
+	})
+}
+
+/// Create an event provider that provides the events in order. Once all the events are provided, a `Control+d` event
+/// is provided, so that the application exits in the same way as when the end of input is reached.
+#[inline]
+pub fn replay_events(events: Vec<CrosstermEvent>) -> impl Fn() -> Result<Option<CrosstermEvent>> {
+	let events = RefCell::new(VecDeque::from(events));
+	move || {
+		Ok(Some(events.borrow_mut().pop_front().unwrap_or_else(|| {
+			CrosstermEvent::Key(KeyEvent::new(KeyCode::Char('d'), KeyModifiers::CONTROL))
+		})))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::{cell::Cell, rc::Rc};
+
+	use rstest::rstest;
+
+	use super::*;
+
+	fn key(code: KeyCode, modifiers: KeyModifiers) -> CrosstermEvent {
+		CrosstermEvent::Key(KeyEvent::new(code, modifiers))
+	}
+
+	fn mouse(kind: MouseEventKind, modifiers: KeyModifiers) -> CrosstermEvent {
+		CrosstermEvent::Mouse(MouseEvent {
+			kind,
+			column: 10,
+			row: 4,
+			modifiers,
+		})
+	}
+
+	#[rstest]
+	#[case::char(key(KeyCode::Char('a'), KeyModifiers::NONE), "key a")]
+	#[case::upper_char(key(KeyCode::Char('A'), KeyModifiers::SHIFT), "key ShiftA")]
+	#[case::space(key(KeyCode::Char(' '), KeyModifiers::NONE), "key Space")]
+	#[case::control(key(KeyCode::Char('c'), KeyModifiers::CONTROL), "key Controlc")]
+	#[case::all_modifiers(
+		key(KeyCode::Up, KeyModifiers::CONTROL | KeyModifiers::ALT | KeyModifiers::SHIFT),
+		"key ControlAltShiftUp"
+	)]
+	#[case::function(key(KeyCode::F(12), KeyModifiers::NONE), "key F12")]
+	#[case::null(key(KeyCode::Null, KeyModifiers::NONE), "key Null")]
+	#[case::backtab(key(KeyCode::BackTab, KeyModifiers::SHIFT), "key ShiftBackTab")]
+	#[case::mouse_scroll(mouse(MouseEventKind::ScrollDown, KeyModifiers::NONE), "mouse ScrollDown 10 4")]
+	#[case::mouse_down(
+		mouse(MouseEventKind::Down(MouseButton::Left), KeyModifiers::CONTROL),
+		"mouse ControlDownLeft 10 4"
+	)]
+	#[case::mouse_drag(
+		mouse(MouseEventKind::Drag(MouseButton::Middle), KeyModifiers::NONE),
+		"mouse DragMiddle 10 4"
+	)]
+	#[case::resize(CrosstermEvent::Resize(80, 24), "resize 80 24")]
+	#[case::paste(CrosstermEvent::Paste(String::from("echo foo")), "paste echo foo")]
+	#[case::paste_multiple_lines(CrosstermEvent::Paste(String::from("a\\b\r\nc")), "paste a\\\\b\\r\\nc")]
+	#[case::focus_gained(CrosstermEvent::FocusGained, "focus gained")]
+	#[case::focus_lost(CrosstermEvent::FocusLost, "focus lost")]
+	fn format_and_parse(#[case] event: CrosstermEvent, #[case] line: &str) {
+		assert_eq!(format_event(&event), line);
+		assert_eq!(parse_event(line).unwrap(), event);
+	}
+
+	#[rstest]
+	#[case::unknown("scroll 1", "Unknown event: scroll 1")]
+	#[case::empty("", "Unknown event: ")]
+	#[case::invalid_key("key Foo", "Invalid key: Foo")]
+	#[case::missing_key("key", "Invalid key: ")]
+	#[case::invalid_mouse("mouse Click 1 1", "Invalid mouse event: Click")]
+	#[case::invalid_number("resize 80 x", "Invalid number: x")]
+	#[case::missing_number("resize 80", "Missing value")]
+	#[case::invalid_paste("paste a\\b", "Invalid paste: a\\b")]
+	#[case::invalid_focus("focus away", "Invalid focus event: focus away")]
+	fn parse_event_error(#[case] line: &str, #[case] error: &str) {
+		assert_eq!(parse_event(line).unwrap_err().to_string(), error);
+	}
+
+	#[test]
+	fn parse_recording_skips_comments_and_empty_lines() {
+		assert_eq!(parse_recording("# comment\nresize 80 24\n\nkey a\n").unwrap(), vec![
+			CrosstermEvent::Resize(80, 24),
+			key(KeyCode::Char('a'), KeyModifiers::NONE)
+		]);
+	}
+
+	#[test]
+	fn parse_recording_error() {
+		assert_eq!(
+			format!("{:#}", parse_recording("key a\nkey Foo").unwrap_err()),
+			"Invalid event on line 2: Invalid key: Foo"
+		);
+	}
+
+	#[test]
+	fn record_events_writes_events() {
+		let count = Rc::new(Cell::new(0));
+		let provider_count = Rc::clone(&count);
+		let mut output = vec![];
+		{
+			let provider = record_events(
+				move || {
+					provider_count.set(provider_count.get() + 1);
+					Ok(match provider_count.get() {
+						1 => Some(key(KeyCode::Char('a'), KeyModifiers::NONE)),
+						2 => None,
+						_ => Some(CrosstermEvent::Resize(80, 24)),
+					})
+				},
+				&mut output,
+				100,
+				50,
+			)
+			.unwrap();
+			assert_eq!(provider().unwrap(), Some(key(KeyCode::Char('a'), KeyModifiers::NONE)));
+			assert_eq!(provider().unwrap(), None);
+			assert_eq!(provider().unwrap(), Some(CrosstermEvent::Resize(80, 24)));
+		}
+		assert_eq!(count.get(), 3);
+		assert_eq!(
+			String::from_utf8(output).unwrap(),
+			"resize 100 50\nkey a\nresize 80 24\n"
+		);
+	}
+
+	#[test]
+	fn replay_events_ends_with_exit() {
+		let provider = replay_events(vec![key(KeyCode::Char('a'), KeyModifiers::NONE)]);
+		assert_eq!(provider().unwrap(), Some(key(KeyCode::Char('a'), KeyModifiers::NONE)));
+		assert_eq!(
+			provider().unwrap(),
+			Some(key(KeyCode::Char('d'), KeyModifiers::CONTROL))
+		);
+		assert_eq!(
+			provider().unwrap(),
+			Some(key(KeyCode::Char('d'), KeyModifiers::CONTROL))
+		);
+	}
+}
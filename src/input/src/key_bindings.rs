@@ -5,6 +5,8 @@ use super::{Event, KeyCode, KeyEvent, KeyModifiers};
 pub struct KeyBindings {
 	/// Key bindings for aborting.
 	pub abort: Vec<Event>,
+	/// Key bindings for aborting an in progress rebase.
+	pub abort_rebase: Vec<Event>,
 	/// Key bindings for the break action.
 	pub action_break: Vec<Event>,
 	/// Key bindings for the drop action.
@@ -19,10 +21,24 @@ pub struct KeyBindings {
 	pub action_reword: Vec<Event>,
 	/// Key bindings for the squash action.
 	pub action_squash: Vec<Event>,
+	/// Key bindings for previewing the autosquash order of the fixup and squash commits.
+	pub autosquash: Vec<Event>,
+	/// Key bindings for moving the selected commits to a new branch.
+	pub branch_out: Vec<Event>,
+	/// Key bindings for inserting a break after every number of commits.
+	pub break_every: Vec<Event>,
+	/// Key bindings for unmarking all of the marked lines.
+	pub clear_marks: Vec<Event>,
 	/// Key bindings for positive confirmation.
 	pub confirm_yes: Vec<Event>,
+	/// Key bindings for cycling the option of a fixup line, between no option, `-C` and `-c`.
+	pub cycle_fixup_option: Vec<Event>,
+	/// Key bindings for describing the selected line.
+	pub describe_line: Vec<Event>,
 	/// Key bindings for editing.
 	pub edit: Vec<Event>,
+	/// Key bindings for filtering the commits to the commits that change a path.
+	pub filter_by_path: Vec<Event>,
 	/// Key bindings for forcing an abort.
 	pub force_abort: Vec<Event>,
 	/// Key bindings for forcing a rebase.
@@ -31,6 +47,10 @@ pub struct KeyBindings {
 	pub help: Vec<Event>,
 	/// Key bindings for inserting a line.
 	pub insert_line: Vec<Event>,
+	/// Key bindings for jumping to a line by its number.
+	pub jump_to_line: Vec<Event>,
+	/// Key bindings for marking the lines that match an action, an author, or the last path filter.
+	pub mark_matching: Vec<Event>,
 	/// Key bindings for moving down.
 	pub move_down: Vec<Event>,
 	/// Key bindings for moving down a step.
@@ -51,18 +71,64 @@ pub struct KeyBindings {
 	pub move_up: Vec<Event>,
 	/// Key bindings for moving up a step.
 	pub move_up_step: Vec<Event>,
+	/// Key bindings for naming the group of the selected line.
+	pub name_group: Vec<Event>,
 	/// Key bindings for opening the external editor.
 	pub open_in_external_editor: Vec<Event>,
+	/// Key bindings for opening the settings.
+	pub open_settings: Vec<Event>,
+	/// Key bindings for fixing the position or action of the selected fixup or squash line.
+	pub quick_fix: Vec<Event>,
+	/// Key bindings for quitting without saving, keeping the original todo file.
+	pub quit_without_saving: Vec<Event>,
 	/// Key bindings for rebasing.
 	pub rebase: Vec<Event>,
 	/// Key bindings for redoing a change.
 	pub redo: Vec<Event>,
 	/// Key bindings for removing a line.
 	pub remove_line: Vec<Event>,
+	/// Key bindings for removing all of the break lines.
+	pub remove_breaks: Vec<Event>,
+	/// Key bindings for reversing the order of the selected or marked lines, or of all of the lines.
+	pub reverse_lines: Vec<Event>,
+	/// Key bindings for saving the changed settings to the global git config.
+	pub save_global_config: Vec<Event>,
+	/// Key bindings for saving the changed settings to the git config of the repository.
+	pub save_repository_config: Vec<Event>,
 	/// Key bindings for showing a commit.
 	pub show_commit: Vec<Event>,
+	/// Key bindings for showing the diagnostics report.
+	pub show_diagnostics: Vec<Event>,
 	/// Key bindings for showing a diff.
 	pub show_diff: Vec<Event>,
+	/// Key bindings for showing the content of the changed files of a commit.
+	pub show_file: Vec<Event>,
+	/// Key bindings for showing the history of the notifications.
+	pub show_notifications: Vec<Event>,
+	/// Key bindings for showing the other commits that change the files of the selected commit.
+	pub show_related_commits: Vec<Event>,
+	/// Key bindings for showing the commit that a commit was cherry picked from.
+	pub show_source_commit: Vec<Event>,
+	/// Key bindings for showing the message of the combined commit of a chain of squash commits.
+	pub show_squash_message: Vec<Event>,
+	/// Key bindings for showing the statistics of the todo list.
+	pub show_statistics: Vec<Event>,
+	/// Key bindings for skipping the commit an in progress rebase stopped on.
+	pub skip_commit: Vec<Event>,
+	/// Key bindings for sorting the commits of the selected or marked lines.
+	pub sort_lines: Vec<Event>,
+	/// Key bindings for moving the selected lines with a preview of the new position.
+	pub start_move: Vec<Event>,
+	/// Key bindings for inserting the exec template that tests a commit after the selected commit.
+	pub test_commit: Vec<Event>,
+	/// Key bindings for collapsing all of the groups, or expanding all of the folds.
+	pub toggle_all_folds: Vec<Event>,
+	/// Key bindings for collapsing or expanding the group of the selected line.
+	pub toggle_group: Vec<Event>,
+	/// Key bindings for marking or unmarking the selected lines.
+	pub toggle_mark: Vec<Event>,
+	/// Key bindings for toggling the uncommitted changes details.
+	pub toggle_stash_info: Vec<Event>,
 	/// Key bindings for toggling visual mode.
 	pub toggle_visual_mode: Vec<Event>,
 	/// Key bindings for undoing a change.
@@ -127,6 +193,7 @@ impl KeyBindings {
 	pub fn new(key_bindings: &config::KeyBindings) -> Self {
 		Self {
 			abort: map_keybindings(&key_bindings.abort),
+			abort_rebase: map_keybindings(&key_bindings.abort_rebase),
 			action_break: map_keybindings(&key_bindings.action_break),
 			action_drop: map_keybindings(&key_bindings.action_drop),
 			action_edit: map_keybindings(&key_bindings.action_edit),
@@ -134,11 +201,15 @@ impl KeyBindings {
 			action_pick: map_keybindings(&key_bindings.action_pick),
 			action_reword: map_keybindings(&key_bindings.action_reword),
 			action_squash: map_keybindings(&key_bindings.action_squash),
+			clear_marks: map_keybindings(&key_bindings.clear_marks),
 			edit: map_keybindings(&key_bindings.edit),
+			filter_by_path: map_keybindings(&key_bindings.filter_by_path),
 			force_abort: map_keybindings(&key_bindings.force_abort),
 			force_rebase: map_keybindings(&key_bindings.force_rebase),
 			help: map_keybindings(&key_bindings.help),
 			insert_line: map_keybindings(&key_bindings.insert_line),
+			jump_to_line: map_keybindings(&key_bindings.jump_to_line),
+			mark_matching: map_keybindings(&key_bindings.mark_matching),
 			move_down: map_keybindings(&key_bindings.move_down),
 			move_down_step: map_keybindings(&key_bindings.move_down_step),
 			move_end: map_keybindings(&key_bindings.move_end),
@@ -149,15 +220,43 @@ impl KeyBindings {
 			move_selection_up: map_keybindings(&key_bindings.move_selection_up),
 			move_up: map_keybindings(&key_bindings.move_up),
 			move_up_step: map_keybindings(&key_bindings.move_up_step),
+			name_group: map_keybindings(&key_bindings.name_group),
 			open_in_external_editor: map_keybindings(&key_bindings.open_in_external_editor),
+			open_settings: map_keybindings(&key_bindings.open_settings),
+			quick_fix: map_keybindings(&key_bindings.quick_fix),
+			quit_without_saving: map_keybindings(&key_bindings.quit_without_saving),
 			rebase: map_keybindings(&key_bindings.rebase),
 			redo: map_keybindings(&key_bindings.redo),
 			remove_line: map_keybindings(&key_bindings.remove_line),
+			remove_breaks: map_keybindings(&key_bindings.remove_breaks),
+			reverse_lines: map_keybindings(&key_bindings.reverse_lines),
+			save_global_config: map_keybindings(&key_bindings.save_global_config),
+			save_repository_config: map_keybindings(&key_bindings.save_repository_config),
 			show_commit: map_keybindings(&key_bindings.show_commit),
+			show_diagnostics: map_keybindings(&key_bindings.show_diagnostics),
 			show_diff: map_keybindings(&key_bindings.show_diff),
+			show_file: map_keybindings(&key_bindings.show_file),
+			show_notifications: map_keybindings(&key_bindings.show_notifications),
+			show_related_commits: map_keybindings(&key_bindings.show_related_commits),
+			show_source_commit: map_keybindings(&key_bindings.show_source_commit),
+			show_squash_message: map_keybindings(&key_bindings.show_squash_message),
+			show_statistics: map_keybindings(&key_bindings.show_statistics),
+			skip_commit: map_keybindings(&key_bindings.skip_commit),
+			sort_lines: map_keybindings(&key_bindings.sort_lines),
+			start_move: map_keybindings(&key_bindings.start_move),
+			test_commit: map_keybindings(&key_bindings.test_commit),
+			toggle_all_folds: map_keybindings(&key_bindings.toggle_all_folds),
+			toggle_group: map_keybindings(&key_bindings.toggle_group),
+			toggle_mark: map_keybindings(&key_bindings.toggle_mark),
+			toggle_stash_info: map_keybindings(&key_bindings.toggle_stash_info),
 			toggle_visual_mode: map_keybindings(&key_bindings.toggle_visual_mode),
 			undo: map_keybindings(&key_bindings.undo),
 			confirm_yes: map_keybindings(&key_bindings.confirm_yes),
+			cycle_fixup_option: map_keybindings(&key_bindings.cycle_fixup_option),
+			describe_line: map_keybindings(&key_bindings.describe_line),
+			branch_out: map_keybindings(&key_bindings.branch_out),
+			break_every: map_keybindings(&key_bindings.break_every),
+			autosquash: map_keybindings(&key_bindings.autosquash),
 		}
 	}
 }
@@ -176,10 +275,10 @@ mod tests {
 	#[test]
 	fn map_keybindings_with_modifiers() {
 		assert_eq!(map_keybindings(&[String::from("ControlAltShifta")]), vec![Event::Key(
-			KeyEvent {
-				code: KeyCode::Char('a'),
-				modifiers: KeyModifiers::all()
-			}
+			KeyEvent::new(
+				KeyCode::Char('a'),
+				KeyModifiers::CONTROL | KeyModifiers::ALT | KeyModifiers::SHIFT
+			)
 		)]);
 	}
 
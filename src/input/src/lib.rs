@@ -89,6 +89,7 @@
 
 mod event;
 mod event_handler;
+mod event_recording;
 mod input_options;
 mod key_bindings;
 mod meta_event;
@@ -100,6 +101,7 @@ pub use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseEvent, MouseEve
 pub use self::{
 	event::Event,
 	event_handler::EventHandler,
+	event_recording::{format_event, parse_event, parse_recording, record_events, replay_events},
 	input_options::InputOptions,
 	key_bindings::KeyBindings,
 	meta_event::MetaEvent,
@@ -4,6 +4,8 @@
 pub enum MetaEvent {
 	/// The abort meta event.
 	Abort,
+	/// The abort rebase meta event.
+	AbortRebase,
 	/// The break action meta event.
 	ActionBreak,
 	/// The drop action meta event.
@@ -18,12 +20,26 @@ pub enum MetaEvent {
 	ActionReword,
 	/// The squash action meta event.
 	ActionSquash,
+	/// The autosquash meta event.
+	Autosquash,
+	/// The branch out meta event.
+	BranchOut,
+	/// The break every meta event.
+	BreakEvery,
+	/// The clear marks meta event.
+	ClearMarks,
 	/// The edit meta event.
 	Edit,
 	/// The exit meta event.
 	Exit,
 	/// The delete meta event.
 	Delete,
+	/// The cycle fixup option meta event.
+	CycleFixupOption,
+	/// The describe line meta event.
+	DescribeLine,
+	/// The filter by path meta event.
+	FilterByPath,
 	/// The force abort meta event.
 	ForceAbort,
 	/// The force rebase meta event.
@@ -32,8 +48,12 @@ pub enum MetaEvent {
 	Help,
 	/// The insert line meta event.
 	InsertLine,
+	/// The jump to line meta event.
+	JumpToLine,
 	/// The kill meta event.
 	Kill,
+	/// The mark matching meta event.
+	MarkMatching,
 	/// The move cursor down meta event.
 	MoveCursorDown,
 	/// The move cursor to end meta event.
@@ -50,14 +70,30 @@ pub enum MetaEvent {
 	MoveCursorRight,
 	/// The move cursor up meta event.
 	MoveCursorUp,
+	/// The name group meta event.
+	NameGroup,
 	/// The no meta event.
 	No,
 	/// The open in editor meta event.
 	OpenInEditor,
+	/// The open settings meta event.
+	OpenSettings,
+	/// The quick fix meta event.
+	QuickFix,
+	/// The quit without saving meta event.
+	QuitWithoutSaving,
 	/// The rebase meta event.
 	Rebase,
 	/// The redo meta event.
 	Redo,
+	/// The remove breaks meta event.
+	RemoveBreaks,
+	/// The reverse lines meta event.
+	ReverseLines,
+	/// The save global config meta event.
+	SaveGlobalConfig,
+	/// The save repository config meta event.
+	SaveRepositoryConfig,
 	/// The scroll bottom meta event.
 	ScrollBottom,
 	/// The scroll bottom meta event.
@@ -76,12 +112,42 @@ pub enum MetaEvent {
 	ScrollUp,
 	/// The show commit meta event.
 	ShowCommit,
+	/// The show diagnostics meta event.
+	ShowDiagnostics,
 	/// The show diff meta event.
 	ShowDiff,
+	/// The show file meta event.
+	ShowFile,
+	/// The show notifications meta event.
+	ShowNotifications,
+	/// The show related commits meta event.
+	ShowRelatedCommits,
+	/// The show source commit meta event.
+	ShowSourceCommit,
+	/// The show squash message meta event.
+	ShowSquashMessage,
+	/// The show statistics meta event.
+	ShowStatistics,
+	/// The skip commit meta event.
+	SkipCommit,
+	/// The sort lines meta event.
+	SortLines,
+	/// The start move meta event.
+	StartMove,
 	/// The swap selection down meta event.
 	SwapSelectedDown,
 	/// The swap selection up meta event.
 	SwapSelectedUp,
+	/// The test commit meta event.
+	TestCommit,
+	/// The toggle all folds meta event.
+	ToggleAllFolds,
+	/// The toggle group meta event.
+	ToggleGroup,
+	/// The toggle mark meta event.
+	ToggleMark,
+	/// The toggle stash info meta event.
+	ToggleStashInfo,
 	/// The toggle visual mode meta event.
 	ToggleVisualMode,
 	/// The undo meta event.
@@ -92,4 +158,6 @@ pub enum MetaEvent {
 	ExternalCommandSuccess,
 	/// the external command was an error meta event.
 	ExternalCommandError,
+	/// The data loaded in the background has changed meta event.
+	BackgroundLoaded,
 }
@@ -9,6 +9,7 @@ fn map_event_to_crossterm(event: Event) -> crossterm::event::Event {
 		Event::Meta(meta_event) => {
 			let key_event = match meta_event {
 				MetaEvent::Abort => KeyEvent::from(KeyCode::Char('q')),
+				MetaEvent::AbortRebase => KeyEvent::from(KeyCode::Char('A')),
 				MetaEvent::ActionBreak => KeyEvent::from(KeyCode::Char('b')),
 				MetaEvent::ActionDrop => KeyEvent::from(KeyCode::Char('d')),
 				MetaEvent::ActionEdit => KeyEvent::from(KeyCode::Char('e')),
@@ -16,24 +17,23 @@ fn map_event_to_crossterm(event: Event) -> crossterm::event::Event {
 				MetaEvent::ActionPick => KeyEvent::from(KeyCode::Char('p')),
 				MetaEvent::ActionReword => KeyEvent::from(KeyCode::Char('r')),
 				MetaEvent::ActionSquash => KeyEvent::from(KeyCode::Char('s')),
+				MetaEvent::Autosquash => KeyEvent::from(KeyCode::Char('a')),
+				MetaEvent::BranchOut => KeyEvent::from(KeyCode::Char('B')),
+				MetaEvent::BreakEvery => KeyEvent::from(KeyCode::Char('N')),
+				MetaEvent::ClearMarks => KeyEvent::from(KeyCode::Char('U')),
 				MetaEvent::Delete => KeyEvent::from(KeyCode::Delete),
+				MetaEvent::CycleFixupOption => KeyEvent::from(KeyCode::Char('C')),
+				MetaEvent::DescribeLine => KeyEvent::from(KeyCode::Char('L')),
 				MetaEvent::Edit => KeyEvent::from(KeyCode::Char('E')),
-				MetaEvent::Exit => {
-					KeyEvent {
-						code: KeyCode::Char('d'),
-						modifiers: KeyModifiers::CONTROL,
-					}
-				},
+				MetaEvent::Exit => KeyEvent::new(KeyCode::Char('d'), KeyModifiers::CONTROL),
+				MetaEvent::FilterByPath => KeyEvent::from(KeyCode::Char('/')),
 				MetaEvent::ForceAbort => KeyEvent::from(KeyCode::Char('Q')),
 				MetaEvent::ForceRebase => KeyEvent::from(KeyCode::Char('W')),
 				MetaEvent::Help => KeyEvent::from(KeyCode::Char('?')),
 				MetaEvent::InsertLine => KeyEvent::from(KeyCode::Char('I')),
-				MetaEvent::Kill => {
-					KeyEvent {
-						code: KeyCode::Char('c'),
-						modifiers: KeyModifiers::CONTROL,
-					}
-				},
+				MetaEvent::Kill => KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL),
+				MetaEvent::JumpToLine => KeyEvent::from(KeyCode::Char(':')),
+				MetaEvent::MarkMatching => KeyEvent::from(KeyCode::Char('*')),
 				MetaEvent::MoveCursorDown => KeyEvent::from(KeyCode::Down),
 				MetaEvent::MoveCursorEnd => KeyEvent::from(KeyCode::End),
 				MetaEvent::MoveCursorHome => KeyEvent::from(KeyCode::Home),
@@ -42,15 +42,18 @@ fn map_event_to_crossterm(event: Event) -> crossterm::event::Event {
 				MetaEvent::MoveCursorPageUp => KeyEvent::from(KeyCode::PageUp),
 				MetaEvent::MoveCursorRight => KeyEvent::from(KeyCode::Right),
 				MetaEvent::MoveCursorUp => KeyEvent::from(KeyCode::Up),
+				MetaEvent::NameGroup => KeyEvent::from(KeyCode::Char('n')),
 				MetaEvent::No => KeyEvent::from(KeyCode::Char('n')),
 				MetaEvent::OpenInEditor => KeyEvent::from(KeyCode::Char('!')),
+				MetaEvent::OpenSettings => KeyEvent::from(KeyCode::Char('o')),
+				MetaEvent::QuickFix => KeyEvent::from(KeyCode::Char('F')),
+				MetaEvent::QuitWithoutSaving => KeyEvent::from(KeyCode::Char('D')),
 				MetaEvent::Rebase => KeyEvent::from(KeyCode::Char('w')),
-				MetaEvent::Redo => {
-					KeyEvent {
-						code: KeyCode::Char('y'),
-						modifiers: KeyModifiers::CONTROL,
-					}
-				},
+				MetaEvent::Redo => KeyEvent::new(KeyCode::Char('y'), KeyModifiers::CONTROL),
+				MetaEvent::RemoveBreaks => KeyEvent::from(KeyCode::Char('X')),
+				MetaEvent::ReverseLines => KeyEvent::from(KeyCode::Char('~')),
+				MetaEvent::SaveGlobalConfig => KeyEvent::from(KeyCode::Char('s')),
+				MetaEvent::SaveRepositoryConfig => KeyEvent::from(KeyCode::Char('r')),
 				MetaEvent::ScrollBottom => KeyEvent::from(KeyCode::End),
 				MetaEvent::ScrollDown => KeyEvent::from(KeyCode::Down),
 				MetaEvent::ScrollJumpDown => KeyEvent::from(KeyCode::PageDown),
@@ -60,19 +63,30 @@ fn map_event_to_crossterm(event: Event) -> crossterm::event::Event {
 				MetaEvent::ScrollTop => KeyEvent::from(KeyCode::Home),
 				MetaEvent::ScrollUp => KeyEvent::from(KeyCode::Up),
 				MetaEvent::ShowCommit => KeyEvent::from(KeyCode::Char('c')),
+				MetaEvent::ShowDiagnostics => KeyEvent::from(KeyCode::Char('d')),
 				MetaEvent::ShowDiff => KeyEvent::from(KeyCode::Char('d')),
+				MetaEvent::ShowFile => KeyEvent::from(KeyCode::Char('f')),
+				MetaEvent::ShowNotifications => KeyEvent::from(KeyCode::Char('H')),
+				MetaEvent::ShowRelatedCommits => KeyEvent::from(KeyCode::Char('R')),
+				MetaEvent::ShowSourceCommit => KeyEvent::from(KeyCode::Char('o')),
+				MetaEvent::ShowSquashMessage => KeyEvent::from(KeyCode::Char('M')),
+				MetaEvent::ShowStatistics => KeyEvent::from(KeyCode::Char('%')),
+				MetaEvent::SkipCommit => KeyEvent::from(KeyCode::Char('S')),
+				MetaEvent::SortLines => KeyEvent::from(KeyCode::Char('t')),
+				MetaEvent::StartMove => KeyEvent::from(KeyCode::Char('x')),
 				MetaEvent::SwapSelectedDown => KeyEvent::from(KeyCode::Char('j')),
 				MetaEvent::SwapSelectedUp => KeyEvent::from(KeyCode::Char('k')),
+				MetaEvent::TestCommit => KeyEvent::from(KeyCode::Char('T')),
+				MetaEvent::ToggleAllFolds => KeyEvent::from(KeyCode::Char('Z')),
+				MetaEvent::ToggleGroup => KeyEvent::from(KeyCode::Char('z')),
+				MetaEvent::ToggleMark => KeyEvent::from(KeyCode::Char('m')),
+				MetaEvent::ToggleStashInfo => KeyEvent::from(KeyCode::Char('i')),
 				MetaEvent::ToggleVisualMode => KeyEvent::from(KeyCode::Char('v')),
-				MetaEvent::Undo => {
-					KeyEvent {
-						code: KeyCode::Char('z'),
-						modifiers: KeyModifiers::CONTROL,
-					}
-				},
+				MetaEvent::Undo => KeyEvent::new(KeyCode::Char('z'), KeyModifiers::CONTROL),
 				MetaEvent::Yes => KeyEvent::from(KeyCode::Char('y')),
 				MetaEvent::ExternalCommandSuccess => KeyEvent::from(KeyCode::Null),
 				MetaEvent::ExternalCommandError => KeyEvent::from(KeyCode::Null),
+				MetaEvent::BackgroundLoaded => KeyEvent::from(KeyCode::Null),
 			};
 			crossterm::event::Event::Key(key_event)
 		},
@@ -80,6 +94,7 @@ fn map_event_to_crossterm(event: Event) -> crossterm::event::Event {
 		Event::Mouse(mouse_event) => crossterm::event::Event::Mouse(mouse_event),
 		Event::Resize(width, height) => crossterm::event::Event::Resize(width, height),
 		Event::None => crossterm::event::Event::Key(KeyEvent::from(KeyCode::Null)),
+		Event::Paste(c) => crossterm::event::Event::Paste(String::from(c)),
 	}
 }
 
@@ -89,6 +104,7 @@ fn map_event_to_crossterm(event: Event) -> crossterm::event::Event {
 pub fn create_test_keybindings() -> KeyBindings {
 	KeyBindings {
 		abort: vec![Event::from(KeyCode::Char('q'))],
+		abort_rebase: vec![Event::from(KeyCode::Char('A'))],
 		action_break: vec![Event::from(KeyCode::Char('b'))],
 		action_drop: vec![Event::from(KeyCode::Char('d'))],
 		action_edit: vec![Event::from(KeyCode::Char('e'))],
@@ -96,12 +112,21 @@ pub fn create_test_keybindings() -> KeyBindings {
 		action_pick: vec![Event::from(KeyCode::Char('p'))],
 		action_reword: vec![Event::from(KeyCode::Char('r'))],
 		action_squash: vec![Event::from(KeyCode::Char('s'))],
+		autosquash: vec![Event::from(KeyCode::Char('a'))],
+		branch_out: vec![Event::from(KeyCode::Char('B'))],
+		break_every: vec![Event::from(KeyCode::Char('N'))],
+		clear_marks: vec![Event::from(KeyCode::Char('U'))],
 		confirm_yes: vec![Event::from(KeyCode::Char('y'))],
+		cycle_fixup_option: vec![Event::from(KeyCode::Char('C'))],
+		describe_line: vec![Event::from(KeyCode::Char('L'))],
 		edit: vec![Event::from(KeyCode::Char('E'))],
+		filter_by_path: vec![Event::from(KeyCode::Char('/'))],
 		force_abort: vec![Event::from(KeyCode::Char('Q'))],
 		force_rebase: vec![Event::from(KeyCode::Char('W'))],
 		help: vec![Event::from(KeyCode::Char('?'))],
 		insert_line: vec![Event::from(KeyCode::Char('I'))],
+		jump_to_line: vec![Event::from(KeyCode::Char(':'))],
+		mark_matching: vec![Event::from(KeyCode::Char('*'))],
 		move_down: vec![Event::from(KeyCode::Down)],
 		move_down_step: vec![Event::from(KeyCode::PageDown)],
 		move_end: vec![Event::from(KeyCode::End)],
@@ -112,24 +137,37 @@ pub fn create_test_keybindings() -> KeyBindings {
 		move_selection_up: vec![Event::from(KeyCode::Char('k'))],
 		move_up: vec![Event::from(KeyCode::Up)],
 		move_up_step: vec![Event::from(KeyCode::PageUp)],
+		name_group: vec![Event::from(KeyCode::Char('n'))],
 		open_in_external_editor: vec![Event::from(KeyCode::Char('!'))],
+		open_settings: vec![Event::from(KeyCode::Char('o'))],
+		quick_fix: vec![Event::from(KeyCode::Char('F'))],
+		quit_without_saving: vec![Event::from(KeyCode::Char('D'))],
 		rebase: vec![Event::from(KeyCode::Char('w'))],
-		redo: vec![Event::Key({
-			KeyEvent {
-				code: KeyCode::Char('y'),
-				modifiers: KeyModifiers::CONTROL,
-			}
-		})],
+		redo: vec![Event::Key(KeyEvent::new(KeyCode::Char('y'), KeyModifiers::CONTROL))],
+		remove_breaks: vec![Event::from(KeyCode::Char('X'))],
 		remove_line: vec![Event::from(KeyCode::Delete)],
+		reverse_lines: vec![Event::from(KeyCode::Char('~'))],
+		save_global_config: vec![Event::from(KeyCode::Char('s'))],
+		save_repository_config: vec![Event::from(KeyCode::Char('r'))],
 		show_commit: vec![Event::from(KeyCode::Char('c'))],
+		show_diagnostics: vec![Event::from(KeyCode::Char('d'))],
 		show_diff: vec![Event::from(KeyCode::Char('d'))],
+		show_file: vec![Event::from(KeyCode::Char('f'))],
+		show_notifications: vec![Event::from(KeyCode::Char('H'))],
+		show_related_commits: vec![Event::from(KeyCode::Char('R'))],
+		show_source_commit: vec![Event::from(KeyCode::Char('o'))],
+		show_squash_message: vec![Event::from(KeyCode::Char('M'))],
+		show_statistics: vec![Event::from(KeyCode::Char('%'))],
+		skip_commit: vec![Event::from(KeyCode::Char('S'))],
+		sort_lines: vec![Event::from(KeyCode::Char('t'))],
+		start_move: vec![Event::from(KeyCode::Char('x'))],
+		test_commit: vec![Event::from(KeyCode::Char('T'))],
+		toggle_all_folds: vec![Event::from(KeyCode::Char('Z'))],
+		toggle_group: vec![Event::from(KeyCode::Char('z'))],
+		toggle_mark: vec![Event::from(KeyCode::Char('m'))],
+		toggle_stash_info: vec![Event::from(KeyCode::Char('i'))],
 		toggle_visual_mode: vec![Event::from(KeyCode::Char('v'))],
-		undo: vec![Event::Key({
-			KeyEvent {
-				code: KeyCode::Char('z'),
-				modifiers: KeyModifiers::CONTROL,
-			}
-		})],
+		undo: vec![Event::Key(KeyEvent::new(KeyCode::Char('z'), KeyModifiers::CONTROL))],
 	}
 }
 
@@ -0,0 +1,37 @@
+//! Benchmarks for parsing and writing a todo file, run with `cargo bench`.
+use std::fmt::Write;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use todo_file::TodoFile;
+
+const LINE_COUNT: usize = 10_000;
+
+// a todo list of mostly picks, with the other actions, a comment and an empty line mixed in, like a long rebase
+fn create_contents() -> String {
+	let mut contents = String::new();
+	for index in 0..LINE_COUNT {
+		let _ = match index % 20 {
+			5 => writeln!(contents, "fixup {:07x} fixup! Commit message of line {}", index, index - 1),
+			10 => writeln!(contents, "exec cargo test --workspace"),
+			15 => writeln!(contents, "# a comment\n"),
+			19 => writeln!(contents, "label label-{}", index),
+			_ => writeln!(contents, "pick {:07x} Commit message of line {}", index, index),
+		};
+	}
+	contents
+}
+
+fn parse(criterion: &mut Criterion) {
+	let contents = create_contents();
+	let mut todo_file = TodoFile::new("git-rebase-todo", 1, "#");
+
+	let _ = criterion.bench_function("parse_todo", |bencher| {
+		bencher.iter(|| todo_file.load_str(contents.as_str()).unwrap());
+	});
+	let _ = criterion.bench_function("write_todo", |bencher| {
+		bencher.iter(|| todo_file.to_text().unwrap());
+	});
+}
+
+criterion_group!(benches, parse);
+criterion_main!(benches);
@@ -0,0 +1,199 @@
+use std::{
+	fs::{remove_file, rename, File, OpenOptions},
+	io::{self, Write},
+	path::{Path, PathBuf},
+	process,
+};
+
+use anyhow::{anyhow, Error, Result};
+
+// the reason for the errors that the user can do something about, that are not clear from the error of the system
+#[cfg(unix)]
+fn reason(err: &io::Error) -> &'static str {
+	match err.raw_os_error() {
+		Some(libc::EROFS) => ", the file system is read only",
+		Some(libc::ENOSPC) => ", there is no space left on the device",
+		Some(libc::EDQUOT) => ", the disk quota is exceeded",
+		_ => "",
+	}
+}
+
+#[cfg(not(unix))]
+const fn reason(_: &io::Error) -> &'static str {
+	""
+}
+
+fn file_error(err: io::Error, action: &str, display_path: &str) -> Error {
+	let context = format!("Error {} file: {}{}", action, display_path, reason(&err));
+	anyhow!(err).context(context)
+}
+
+// the temporary file is in the same directory as the file, so that it is on the same file system, and can be renamed
+// over the file
+fn temporary_path(path: &Path) -> PathBuf {
+	let file_name = path.file_name().map_or_else(String::new, |name| name.to_string_lossy().into_owned());
+	path.with_file_name(format!(".{}.{}.tmp", file_name, process::id()))
+}
+
+#[cfg(unix)]
+fn sync_directory(path: &Path) {
+	let directory = match path.parent() {
+		Some(parent) if !parent.as_os_str().is_empty() => parent,
+		_ => Path::new("."),
+	};
+	// not all file systems support syncing a directory, and the file is already written, so an error is ignored
+	if let Ok(directory) = File::open(directory) {
+		let _ = directory.sync_all();
+	}
+}
+
+#[cfg(not(unix))]
+const fn sync_directory(_: &Path) {}
+
+fn write_temporary_file(temporary_path: &Path, path: &Path, contents: &str) -> io::Result<()> {
+	let mut file = OpenOptions::new()
+		.write(true)
+		.create(true)
+		.truncate(true)
+		.open(temporary_path)?;
+	if let Ok(metadata) = path.metadata() {
+		file.set_permissions(metadata.permissions())?;
+	}
+	file.write_all(contents.as_bytes())?;
+	file.sync_all()
+}
+
+/// Write the contents to a file atomically, so that a crash or a loss of power while writing leaves either the
+/// previous or the new contents, and never a truncated file. The contents are written and synced to a temporary file
+/// in the same directory, that has the permissions of the file, and is renamed over the file.
+pub(crate) fn write_atomic(path: &Path, display_path: &str, contents: &str) -> Result<()> {
+	let temporary_path = temporary_path(path);
+	if let Err(err) = write_temporary_file(temporary_path.as_path(), path, contents) {
+		let action = if temporary_path.exists() { "writing" } else { "opening" };
+		let _ = remove_file(temporary_path.as_path());
+		return Err(file_error(err, action, display_path));
+	}
+
+	if let Err(err) = rename(temporary_path.as_path(), path) {
+		let _ = remove_file(temporary_path.as_path());
+		return Err(file_error(err, "replacing", display_path));
+	}
+	sync_directory(path);
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use std::fs::{create_dir, read_dir, read_to_string, write};
+
+	use super::*;
+
+	#[test]
+	fn write_atomic_new_file() {
+		let directory = tempfile::tempdir().unwrap();
+		let path = directory.path().join("git-rebase-todo");
+		write_atomic(path.as_path(), "git-rebase-todo", "pick aaa comment\n").unwrap();
+		assert_eq!(read_to_string(path.as_path()).unwrap(), "pick aaa comment\n");
+	}
+
+	#[test]
+	fn write_atomic_replaces_file() {
+		let directory = tempfile::tempdir().unwrap();
+		let path = directory.path().join("git-rebase-todo");
+		write(path.as_path(), "pick aaa comment\npick bbb comment\n").unwrap();
+		write_atomic(path.as_path(), "git-rebase-todo", "drop aaa comment\n").unwrap();
+		assert_eq!(read_to_string(path.as_path()).unwrap(), "drop aaa comment\n");
+	}
+
+	#[test]
+	fn write_atomic_does_not_leave_temporary_file() {
+		let directory = tempfile::tempdir().unwrap();
+		let path = directory.path().join("git-rebase-todo");
+		write(path.as_path(), "pick aaa comment\n").unwrap();
+		write_atomic(path.as_path(), "git-rebase-todo", "drop aaa comment\n").unwrap();
+		let files = read_dir(directory.path())
+			.unwrap()
+			.map(|entry| entry.unwrap().file_name())
+			.collect::<Vec<_>>();
+		assert_eq!(files, vec!["git-rebase-todo"]);
+	}
+
+	#[cfg(unix)]
+	#[test]
+	fn write_atomic_preserves_permissions() {
+		use std::{fs::set_permissions, os::unix::fs::PermissionsExt};
+
+		let directory = tempfile::tempdir().unwrap();
+		let path = directory.path().join("git-rebase-todo");
+		write(path.as_path(), "pick aaa comment\n").unwrap();
+		set_permissions(path.as_path(), PermissionsExt::from_mode(0o640)).unwrap();
+		write_atomic(path.as_path(), "git-rebase-todo", "drop aaa comment\n").unwrap();
+		assert_eq!(path.metadata().unwrap().permissions().mode() & 0o777, 0o640);
+	}
+
+	#[test]
+	fn write_atomic_directory_does_not_exist() {
+		let directory = tempfile::tempdir().unwrap();
+		let path = directory.path().join("missing").join("git-rebase-todo");
+		assert_eq!(
+			write_atomic(path.as_path(), "missing/git-rebase-todo", "pick aaa comment\n")
+				.unwrap_err()
+				.to_string(),
+			"Error opening file: missing/git-rebase-todo"
+		);
+		assert!(!directory.path().join("missing").exists());
+	}
+
+	#[test]
+	fn write_atomic_replace_fails() {
+		let directory = tempfile::tempdir().unwrap();
+		let path = directory.path().join("git-rebase-todo");
+		create_dir(path.as_path()).unwrap();
+		assert_eq!(
+			write_atomic(path.as_path(), "git-rebase-todo", "pick aaa comment\n")
+				.unwrap_err()
+				.to_string(),
+			"Error replacing file: git-rebase-todo"
+		);
+		let files = read_dir(directory.path())
+			.unwrap()
+			.map(|entry| entry.unwrap().file_name())
+			.collect::<Vec<_>>();
+		assert_eq!(files, vec!["git-rebase-todo"]);
+	}
+
+	#[cfg(unix)]
+	#[test]
+	fn file_error_read_only_file_system() {
+		assert_eq!(
+			file_error(io::Error::from_raw_os_error(libc::EROFS), "opening", "todo").to_string(),
+			"Error opening file: todo, the file system is read only"
+		);
+	}
+
+	#[cfg(unix)]
+	#[test]
+	fn file_error_storage_full() {
+		assert_eq!(
+			file_error(io::Error::from_raw_os_error(libc::ENOSPC), "writing", "todo").to_string(),
+			"Error writing file: todo, there is no space left on the device"
+		);
+	}
+
+	#[cfg(unix)]
+	#[test]
+	fn file_error_quota_exceeded() {
+		assert_eq!(
+			file_error(io::Error::from_raw_os_error(libc::EDQUOT), "writing", "todo").to_string(),
+			"Error writing file: todo, the disk quota is exceeded"
+		);
+	}
+
+	#[test]
+	fn file_error_other() {
+		assert_eq!(
+			file_error(io::Error::from(io::ErrorKind::Other), "replacing", "todo").to_string(),
+			"Error replacing file: todo"
+		);
+	}
+}
@@ -0,0 +1,301 @@
+use std::ops::Range;
+
+use super::{Action, Line};
+
+/// A line of the todo list, in the order that `git rebase --autosquash` would rebase the lines.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AutosquashLine {
+	index: usize,
+	action: Action,
+	target_index: Option<usize>,
+}
+
+impl AutosquashLine {
+	/// Get the index of the line in the todo list.
+	#[must_use]
+	#[inline]
+	pub const fn index(&self) -> usize {
+		self.index
+	}
+
+	/// Get the action of the line, after the autosquash.
+	#[must_use]
+	#[inline]
+	pub const fn action(&self) -> Action {
+		self.action
+	}
+
+	/// Get the index in the todo list of the line that the fixup or squash commit is applied to, if the line is a
+	/// fixup or squash commit that is moved by the autosquash.
+	#[must_use]
+	#[inline]
+	pub const fn target_index(&self) -> Option<usize> {
+		self.target_index
+	}
+}
+
+// The prefixes of the subjects created by `git commit --fixup` and `git commit --squash`, with the action that the
+// commits are given. An amend commit is a fixup commit that also replaces the message.
+const PREFIXES: &[(&str, Action)] = &[
+	("fixup! ", Action::Fixup),
+	("amend! ", Action::Fixup),
+	("squash! ", Action::Squash),
+];
+
+const fn is_commit_action(action: Action) -> bool {
+	matches!(
+		action,
+		Action::Pick | Action::Reword | Action::Edit | Action::Fixup | Action::Squash
+	)
+}
+
+// The action of a fixup or squash commit, from the first prefix of the subject, and the subject of the target, with
+// all of the prefixes removed, so that a fixup of a fixup commit is applied to the same commit.
+fn parse_subject(subject: &str) -> Option<(Action, &str)> {
+	let (_, action) = PREFIXES.iter().find(|&&(prefix, _)| subject.starts_with(prefix))?;
+	let mut target = subject;
+	while let Some(rest) = PREFIXES
+		.iter()
+		.find_map(|&(prefix, _)| target.strip_prefix(prefix))
+	{
+		target = rest.trim_start();
+	}
+	Some((*action, target))
+}
+
+fn is_hash_match(line: &Line, target: &str) -> bool {
+	target.len() >= 4
+		&& target.chars().all(|character| character.is_ascii_hexdigit())
+		&& (line.get_hash().starts_with(target) || target.starts_with(line.get_hash()))
+}
+
+// Like Git, the target of a fixup or squash commit is the first commit of the range with the same subject, then the
+// commit with the hash, and then the first commit with a subject that starts with the subject.
+fn find_target(lines: &[Line], range: Range<usize>, target: &str, is_target: fn(&Line) -> bool) -> Option<usize> {
+	let candidates = || {
+		lines
+			.iter()
+			.enumerate()
+			.skip(range.start)
+			.take(range.len())
+			.filter(|&(_, line)| is_target(line))
+	};
+	candidates()
+		.find(|&(_, line)| line.get_content() == target)
+		.or_else(|| candidates().find(|&(_, line)| is_hash_match(line, target)))
+		.or_else(|| candidates().find(|&(_, line)| line.get_content().starts_with(target)))
+		.map(|(target_index, _)| target_index)
+}
+
+fn is_commit_line(line: &Line) -> bool {
+	is_commit_action(*line.get_action())
+}
+
+fn is_commit_or_dropped_line(line: &Line) -> bool {
+	is_commit_line(line) || *line.get_action() == Action::Drop
+}
+
+/// Find the commit that a fixup or squash line, with a `fixup!`, `squash!` or `amend!` subject, is applied to, which
+/// is an earlier commit that is not dropped, and otherwise an earlier dropped commit or a later commit, that Git will
+/// not apply the line to.
+pub(crate) fn find_fixup_target(lines: &[Line], index: usize) -> Option<usize> {
+	let line = lines.get(index)?;
+	if !matches!(*line.get_action(), Action::Fixup | Action::Squash) {
+		return None;
+	}
+	let (_, subject) = parse_subject(line.get_content())?;
+	find_target(lines, 0..index, subject, is_commit_line)
+		.or_else(|| find_target(lines, 0..index, subject, is_commit_or_dropped_line))
+		.or_else(|| find_target(lines, index + 1..lines.len(), subject, is_commit_or_dropped_line))
+}
+
+/// Order the lines like `git rebase --autosquash`, from the subjects of the commits in the todo list, moving each fixup
+/// or squash commit after the commit that it is applied to, and after the earlier fixup and squash commits of that
+/// commit.
+pub(crate) fn autosquash_lines(lines: &[Line]) -> Vec<AutosquashLine> {
+	let mut autosquash_lines: Vec<AutosquashLine> = lines
+		.iter()
+		.enumerate()
+		.map(|(index, line)| {
+			AutosquashLine {
+				index,
+				action: *line.get_action(),
+				target_index: None,
+			}
+		})
+		.collect();
+	// each line is followed by the next line of its chain of fixup and squash commits, and the last line of the chain
+	// is kept, so that a commit is added to the end of the chain
+	let mut next: Vec<Option<usize>> = vec![None; lines.len()];
+	let mut tail: Vec<Option<usize>> = vec![None; lines.len()];
+	for (index, line) in lines.iter().enumerate() {
+		if !is_commit_action(*line.get_action()) {
+			continue;
+		}
+		let (action, subject) = match parse_subject(line.get_content()) {
+			Some((action, subject)) => (action, subject),
+			None => continue,
+		};
+		let target_index = match find_target(lines, 0..index, subject, is_commit_line) {
+			Some(target_index) => target_index,
+			None => continue,
+		};
+		let previous = tail[target_index].unwrap_or(target_index);
+		next[index] = next[previous];
+		next[previous] = Some(index);
+		tail[target_index] = Some(index);
+		autosquash_lines[index].action = action;
+		autosquash_lines[index].target_index = Some(target_index);
+	}
+
+	let mut ordered_lines = Vec::with_capacity(lines.len());
+	for autosquash_line in &autosquash_lines {
+		if autosquash_line.target_index.is_some() {
+			continue;
+		}
+		ordered_lines.push(*autosquash_line);
+		let mut next_index = next[autosquash_line.index];
+		while let Some(index) = next_index {
+			ordered_lines.push(autosquash_lines[index]);
+			next_index = next[index];
+		}
+	}
+	ordered_lines
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn autosquash(lines: &[&str]) -> Vec<(usize, Action, Option<usize>)> {
+		autosquash_lines(
+			lines
+				.iter()
+				.map(|line| Line::new(line).unwrap())
+				.collect::<Vec<Line>>()
+				.as_slice(),
+		)
+		.iter()
+		.map(|line| (line.index(), line.action(), line.target_index()))
+		.collect()
+	}
+
+	#[test]
+	fn without_fixup_commits() {
+		assert_eq!(autosquash(&["pick aaa c1", "exec make", "pick bbb c2"]), vec![
+			(0, Action::Pick, None),
+			(1, Action::Exec, None),
+			(2, Action::Pick, None),
+		]);
+	}
+
+	#[test]
+	fn fixup_and_squash_by_subject() {
+		assert_eq!(
+			autosquash(&[
+				"pick aaa c1",
+				"pick bbb c2",
+				"pick ccc squash! c1",
+				"pick ddd fixup! c2",
+				"pick eee fixup! c1",
+			]),
+			vec![
+				(0, Action::Pick, None),
+				(2, Action::Squash, Some(0)),
+				(4, Action::Fixup, Some(0)),
+				(1, Action::Pick, None),
+				(3, Action::Fixup, Some(1)),
+			]
+		);
+	}
+
+	#[test]
+	fn amend() {
+		assert_eq!(autosquash(&["pick aaa c1", "pick bbb c2", "pick ccc amend! c1"]), vec![
+			(0, Action::Pick, None),
+			(2, Action::Fixup, Some(0)),
+			(1, Action::Pick, None),
+		]);
+	}
+
+	#[test]
+	fn fixup_of_fixup_commit() {
+		assert_eq!(
+			autosquash(&[
+				"pick aaa c1",
+				"pick bbb fixup! c1",
+				"pick ccc c2",
+				"pick ddd fixup! fixup! c1",
+			]),
+			vec![
+				(0, Action::Pick, None),
+				(1, Action::Fixup, Some(0)),
+				(3, Action::Fixup, Some(0)),
+				(2, Action::Pick, None),
+			]
+		);
+	}
+
+	#[test]
+	fn target_by_hash() {
+		assert_eq!(autosquash(&["pick abcd123 c1", "pick bbb c2", "pick ccc fixup! abcd123456"]), vec![
+			(0, Action::Pick, None),
+			(2, Action::Fixup, Some(0)),
+			(1, Action::Pick, None),
+		]);
+	}
+
+	#[test]
+	fn target_by_subject_prefix() {
+		assert_eq!(autosquash(&["pick aaa add the parser", "pick bbb c2", "pick ccc fixup! add the"]), vec![
+			(0, Action::Pick, None),
+			(2, Action::Fixup, Some(0)),
+			(1, Action::Pick, None),
+		]);
+	}
+
+	#[test]
+	fn target_must_be_earlier() {
+		assert_eq!(autosquash(&["pick aaa fixup! c1", "pick bbb c1"]), vec![
+			(0, Action::Pick, None),
+			(1, Action::Pick, None),
+		]);
+	}
+
+	#[test]
+	fn fixup_target() {
+		let lines: Vec<Line> = ["pick aaa c1", "fixup bbb fixup! c1", "pick ccc c2", "squash ddd squash! c3"]
+			.iter()
+			.map(|line| Line::new(line).unwrap())
+			.collect();
+		assert_eq!(find_fixup_target(&lines, 1), Some(0));
+		assert_eq!(find_fixup_target(&lines, 2), None);
+		assert_eq!(find_fixup_target(&lines, 3), None);
+		assert_eq!(find_fixup_target(&lines, 4), None);
+	}
+
+	#[test]
+	fn fixup_target_dropped_and_later() {
+		let lines: Vec<Line> = [
+			"drop aaa c1",
+			"fixup bbb fixup! c1",
+			"pick ccc c1",
+			"fixup ddd fixup! c3",
+			"pick eee c3",
+		]
+		.iter()
+		.map(|line| Line::new(line).unwrap())
+		.collect();
+		assert_eq!(find_fixup_target(&lines, 1), Some(0));
+		assert_eq!(find_fixup_target(&lines, 3), Some(4));
+	}
+
+	#[test]
+	fn dropped_fixup_commit() {
+		assert_eq!(autosquash(&["pick aaa c1", "pick bbb c2", "drop ccc fixup! c1"]), vec![
+			(0, Action::Pick, None),
+			(1, Action::Pick, None),
+			(2, Action::Drop, None),
+		]);
+	}
+}
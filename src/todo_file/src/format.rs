@@ -0,0 +1,221 @@
+use anyhow::{anyhow, Result};
+
+use super::{action::Action, line::Line};
+
+// Stacked Git marks the end of the applied patches with this comment, patches after it are left unapplied.
+const STGIT_APPLY_LINE: &str = "# --- APPLY_LINE ---";
+
+// The name of a group of lines is stored in a comment before the line that starts the group, which Git ignores.
+const GROUP_NAME_PREFIX: &str = "group:";
+
+/// The format of a rebase file.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum Format {
+	/// A Git rebase todo file.
+	Git,
+	/// A Stacked Git, `stg rebase --interactive`, patch list. Patches are referenced by patch name instead of by
+	/// commit hash, and the apply line is represented with a break.
+	StGit,
+}
+
+impl Format {
+	/// Detect the format of the contents of a rebase file, defaulting to a Git rebase todo file.
+	#[must_use]
+	pub fn detect(contents: &str) -> Self {
+		let is_stgit = contents.lines().any(|line| {
+			line == STGIT_APPLY_LINE
+				|| matches!(
+					line.split_whitespace().next(),
+					Some("keep" | "k" | "delete" | "fix" | "hide" | "h")
+				)
+		});
+		if is_stgit {
+			Self::StGit
+		}
+		else {
+			Self::Git
+		}
+	}
+
+	/// Parse a single line of a rebase file, returning `None` for lines that should be ignored.
+	pub(crate) fn parse_line(self, line: &str, comment_char: &str) -> Option<Result<Line>> {
+		match self {
+			Self::Git => {
+				if line.starts_with(comment_char) || line.is_empty() {
+					None
+				}
+				else {
+					Some(Line::new(line))
+				}
+			},
+			Self::StGit => {
+				if line == STGIT_APPLY_LINE {
+					return Some(Ok(Line::new_break()));
+				}
+				if line.starts_with('#') || line.trim().is_empty() {
+					return None;
+				}
+				Some(parse_stgit_line(line))
+			},
+		}
+	}
+
+	/// Parse the name of a group of lines from a comment of a rebase file, returning `None` for any other line. A
+	/// Stacked Git patch list does not have group names.
+	pub(crate) fn parse_group_name<'line>(self, line: &'line str, comment_char: &str) -> Option<&'line str> {
+		match self {
+			Self::Git => {
+				line.strip_prefix(comment_char)?
+					.trim_start()
+					.strip_prefix(GROUP_NAME_PREFIX)
+					.map(str::trim)
+			},
+			Self::StGit => None,
+		}
+	}
+
+	/// Create the text of the lines, as they would be seen in a rebase file of the format.
+	pub(crate) fn write_lines(self, lines: &[Line], comment_char: &str) -> Result<String> {
+		match self {
+			Self::Git => {
+				let mut text = vec![];
+				for line in lines {
+					if let Some(name) = line.get_group_name() {
+						text.push(format!("{comment_char} {GROUP_NAME_PREFIX} {name}"));
+					}
+					text.push(line.to_text());
+				}
+				Ok(text.join("\n"))
+			},
+			Self::StGit => {
+				let mut has_apply_line = false;
+				let mut text = vec![];
+				for line in lines {
+					let command = match *line.get_action() {
+						Action::Pick => "keep",
+						Action::Drop => "delete",
+						Action::Edit => "edit",
+						Action::Squash => "squash",
+						Action::Fixup => "fix",
+						Action::Break => {
+							// only the first break is meaningful, since there is only one apply line
+							if !has_apply_line {
+								has_apply_line = true;
+								text.push(String::from(STGIT_APPLY_LINE));
+							}
+							continue;
+						},
+						action => {
+							return Err(anyhow!(
+								"The {} action is not supported by Stacked Git",
+								action.as_string()
+							))
+						},
+					};
+					text.push(format!("{} {}", command, line.get_hash()));
+				}
+				Ok(text.join("\n"))
+			},
+		}
+	}
+}
+
+fn parse_stgit_line(line: &str) -> Result<Line> {
+	let parts = line.split_whitespace().collect::<Vec<&str>>();
+	// the patch name is always the last part, it may be preceded by the index of the patch
+	let (command, patch) = match parts.as_slice() {
+		[command, patch] | [command, _, patch] => (*command, *patch),
+		_ => return Err(anyhow!("Invalid line: {}", line)),
+	};
+	let action = match command {
+		"keep" | "k" => Action::Pick,
+		"delete" | "d" => Action::Drop,
+		"edit" | "e" => Action::Edit,
+		"squash" | "s" => Action::Squash,
+		"fix" | "f" => Action::Fixup,
+		"hide" | "h" => return Err(anyhow!("The hide command is not supported: {}", line)),
+		_ => return Err(anyhow!("Invalid command: {}", command)),
+	};
+	Line::new(format!("{} {}", action.as_string(), patch).as_str())
+}
+
+#[cfg(test)]
+mod tests {
+	use rstest::rstest;
+
+	use super::*;
+
+	#[rstest]
+	#[case::git("pick aaa comment\n# keep this comment\n", Format::Git)]
+	#[case::empty("", Format::Git)]
+	#[case::stgit_keep("keep 00 first-patch\nkeep 01 second-patch\n", Format::StGit)]
+	#[case::stgit_apply_line("# Commands:\nd 00 patch\n# --- APPLY_LINE ---\n", Format::StGit)]
+	fn detect(#[case] contents: &str, #[case] expected: Format) {
+		assert_eq!(Format::detect(contents), expected);
+	}
+
+	#[rstest]
+	#[case::keep("keep 00 first", "pick first ")]
+	#[case::keep_short("k first", "pick first ")]
+	#[case::delete("delete 01 second", "drop second ")]
+	#[case::edit("e 02 third", "edit third ")]
+	#[case::squash("squash 03 fourth", "squash fourth ")]
+	#[case::fix("f fifth", "fixup fifth ")]
+	#[case::apply_line("# --- APPLY_LINE ---", "break")]
+	fn parse_stgit(#[case] line: &str, #[case] expected: &str) {
+		assert_eq!(
+			Format::StGit.parse_line(line, "#").unwrap().unwrap().to_text(),
+			expected
+		);
+	}
+
+	#[rstest]
+	#[case::comment("# keep 00 first")]
+	#[case::empty("  ")]
+	fn parse_stgit_ignored(#[case] line: &str) {
+		assert!(Format::StGit.parse_line(line, "#").is_none());
+	}
+
+	#[rstest]
+	#[case::hide("hide 00 first", "The hide command is not supported: hide 00 first")]
+	#[case::invalid_command("pick 00 first", "Invalid command: pick")]
+	#[case::missing_patch("keep", "Invalid line: keep")]
+	fn parse_stgit_error(#[case] line: &str, #[case] expected: &str) {
+		assert_eq!(
+			Format::StGit.parse_line(line, "#").unwrap().unwrap_err().to_string(),
+			expected
+		);
+	}
+
+	#[test]
+	fn write_stgit() {
+		let lines = [
+			"pick first",
+			"drop second",
+			"edit third",
+			"squash fourth",
+			"fixup fifth",
+			"break",
+			"edit sixth",
+			"break",
+		]
+		.iter()
+		.map(|line| Line::new(line).unwrap())
+		.collect::<Vec<Line>>();
+		assert_eq!(
+			Format::StGit.write_lines(&lines, "#").unwrap(),
+			"keep first\ndelete second\nedit third\nsquash fourth\nfix fifth\n# --- APPLY_LINE ---\nedit sixth"
+		);
+	}
+
+	// Stacked Git does not have a command that only changes the message of a patch, so a reword is not silently turned
+	// into an edit of the whole patch
+	#[rstest]
+	#[case::exec("exec make", "The exec action is not supported by Stacked Git")]
+	#[case::reword("reword first", "The reword action is not supported by Stacked Git")]
+	fn write_stgit_unsupported_action(#[case] line: &str, #[case] expected: &str) {
+		let lines = [Line::new(line).unwrap()];
+		assert_eq!(Format::StGit.write_lines(&lines, "#").unwrap_err().to_string(), expected);
+	}
+}
@@ -53,6 +53,12 @@ impl History {
 				add_range(lines, &operation.lines, operation.start_index, operation.end_index);
 				HistoryItem::new_add(operation.start_index, operation.end_index)
 			},
+			Operation::Replace => {
+				let removed_lines = remove_range(lines, operation.start_index, operation.end_index);
+				let end_index = operation.start_index + operation.lines.len() - 1;
+				add_range(lines, &operation.lines, operation.start_index, end_index);
+				HistoryItem::new_replace(operation.start_index, end_index, removed_lines)
+			},
 			Operation::SwapUp => {
 				swap_range_down(lines, operation.start_index - 1, operation.end_index - 1);
 				HistoryItem::new_swap_down(operation.start_index - 1, operation.end_index - 1)
@@ -98,7 +104,7 @@ impl History {
 
 	fn get_last_index_range(history_item: &HistoryItem, list_length: usize) -> (usize, usize) {
 		match history_item.operation {
-			Operation::Add | Operation::Modify => (history_item.start_index, history_item.end_index),
+			Operation::Add | Operation::Modify | Operation::Replace => (history_item.start_index, history_item.end_index),
 			Operation::Remove => {
 				let index = min(history_item.start_index, history_item.end_index);
 				if index == 0 || list_length == 0 {
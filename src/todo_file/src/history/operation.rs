@@ -5,4 +5,5 @@ pub(crate) enum Operation {
 	SwapDown,
 	Add,
 	Remove,
+	Replace,
 }
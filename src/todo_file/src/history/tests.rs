@@ -909,6 +909,53 @@ fn undo_redo_modify_range_up_index_end() {
 	);
 }
 
+#[test]
+fn undo_redo_replace_with_more_lines() {
+	let mut history = History::new(10);
+	history.record(HistoryItem::new_replace(1, 3, vec![Line::new("drop xx1 c1").unwrap()]));
+	let mut lines = create_lines();
+	assert_eq!(history.undo(&mut lines), Some((1, 1)));
+	assert_todo_lines!(lines, "pick aaa c1", "drop xx1 c1", "pick eee c5");
+	assert_eq!(history.redo(&mut lines), Some((1, 3)));
+	assert_todo_lines!(
+		lines,
+		"pick aaa c1",
+		"pick bbb c2",
+		"pick ccc c3",
+		"pick ddd c4",
+		"pick eee c5"
+	);
+}
+
+#[test]
+fn undo_redo_replace_with_fewer_lines() {
+	let mut history = History::new(10);
+	history.record(HistoryItem::new_replace(4, 4, vec![
+		Line::new("drop xx1 c1").unwrap(),
+		Line::new("drop xx2 c2").unwrap(),
+	]));
+	let mut lines = create_lines();
+	assert_eq!(history.undo(&mut lines), Some((4, 5)));
+	assert_todo_lines!(
+		lines,
+		"pick aaa c1",
+		"pick bbb c2",
+		"pick ccc c3",
+		"pick ddd c4",
+		"drop xx1 c1",
+		"drop xx2 c2"
+	);
+	assert_eq!(history.redo(&mut lines), Some((4, 4)));
+	assert_todo_lines!(
+		lines,
+		"pick aaa c1",
+		"pick bbb c2",
+		"pick ccc c3",
+		"pick ddd c4",
+		"pick eee c5"
+	);
+}
+
 #[test]
 fn reset() {
 	let mut history = History::new(3);
@@ -80,27 +80,74 @@
 //! Git Interactive Rebase Tool - Todo File Module
 //!
 //! # Description
-//! This module is used to handle working with the rebase todo file.
+//! This module is used to handle working with the rebase todo file. It can be used on its own, without the rest
+//! of the interactive rebase tool, to parse, modify, validate and serialize a rebase todo list.
+//!
+//! # Example
+//! ```
+//! use todo_file::{Action, EditContext, TodoFile};
+//!
+//! let mut todo_file = TodoFile::new("git-rebase-todo", 10, "#");
+//! todo_file
+//! 	.load_str("pick aaa first\n# a comment\npick bbb second\n")
+//! 	.unwrap();
+//! todo_file.update_range(1, 1, &EditContext::new().action(Action::Fixup));
+//! assert!(todo_file.validate().is_ok());
+//! assert_eq!(
+//! 	todo_file.to_text().unwrap(),
+//! 	"pick aaa first\nfixup bbb second\n"
+//! );
+//! ```
+//!
+//! # Stability
+//! The public API of this crate follows semantic versioning. The [`ValidationError`] and [`Lint`] enums are
+//! non-exhaustive, so that new validations and lints can be added in a minor release.
 
 mod action;
+mod atomic_write;
+mod autosquash;
 mod edit_content;
+mod format;
 mod history;
 mod line;
+mod lint;
+mod merge;
+mod path;
+#[cfg(test)]
+mod property_tests;
 mod utils;
+mod validation_error;
+
+// only used by the benchmarks
+#[cfg(test)]
+use criterion as _;
 
 use std::{
-	fs::{read_to_string, File},
-	io::Write,
-	path::Path,
+	cmp::{max, min},
+	collections::HashSet,
+	fs::read_to_string,
 	slice::Iter,
 };
 
 use anyhow::{anyhow, Result};
 
-pub use self::{action::Action, edit_content::EditContext, line::Line};
+pub use self::{
+	action::Action,
+	autosquash::AutosquashLine,
+	edit_content::EditContext,
+	format::Format,
+	line::Line,
+	lint::Lint,
+	validation_error::ValidationError,
+};
 use self::{
+	atomic_write::write_atomic,
+	autosquash::{autosquash_lines, find_fixup_target},
 	history::{History, HistoryItem},
-	utils::{remove_range, swap_range_down, swap_range_up},
+	merge::merge_lines,
+	lint::lint_lines,
+	path::file_system_path,
+	utils::{add_range, edit_line, remove_range, swap_range_down, swap_range_up},
 };
 
 /// Represents a rebase file.
@@ -108,10 +155,12 @@ use self::{
 pub struct TodoFile {
 	comment_char: String,
 	filepath: String,
+	format: Format,
 	history: History,
 	is_noop: bool,
 	lines: Vec<Line>,
 	selected_line_index: usize,
+	selected_range_start: Option<usize>,
 }
 
 impl TodoFile {
@@ -121,13 +170,25 @@ impl TodoFile {
 		Self {
 			comment_char: String::from(comment_char),
 			filepath: path.to_owned(),
+			format: Format::Git,
 			history: History::new(undo_limit),
 			lines: vec![],
 			is_noop: false,
 			selected_line_index: 0,
+			selected_range_start: None,
 		}
 	}
 
+	/// Create a todo file with other lines, such as a preview of a change that has not been made, with the path and the
+	/// format of the todo file, and without the history of the changes.
+	#[must_use]
+	pub fn with_lines(&self, lines: Vec<Line>) -> Self {
+		let mut todo_file = Self::new(self.filepath.as_str(), 1, self.comment_char.as_str());
+		todo_file.format = self.format;
+		todo_file.set_lines(lines);
+		todo_file
+	}
+
 	/// Set the rebase lines.
 	pub fn set_lines(&mut self, lines: Vec<Line>) {
 		self.is_noop = !lines.is_empty() && lines[0].get_action() == &Action::Noop;
@@ -140,42 +201,157 @@ impl TodoFile {
 		if self.selected_line_index >= self.lines.len() {
 			self.selected_line_index = if self.lines.is_empty() { 0 } else { self.lines.len() - 1 };
 		}
+		self.selected_range_start = None;
 		self.history.reset();
 	}
 
 	/// Load the rebase file from disk.
 	pub fn load_file(&mut self) -> Result<()> {
-		let lines = read_to_string(Path::new(&self.filepath))
-			.map_err(|err| anyhow!("Error reading file: {}", self.filepath).context(err))?
-			.lines()
-			.filter_map(|l| {
-				if l.starts_with(self.comment_char.as_str()) || l.is_empty() {
-					None
-				}
-				else {
-					Some(Line::new(l).map_err(|err| anyhow!("Error reading file: {}", self.filepath).context(err)))
-				}
-			})
-			.collect::<Result<Vec<Line>>>()?;
+		let contents = read_to_string(file_system_path(self.filepath.as_str()))
+			.map_err(|err| anyhow!("Error reading file: {}", self.filepath).context(err))?;
+		self.load_str(contents.as_str())
+			.map_err(|err| anyhow!("Error reading file: {}", self.filepath).context(err))
+	}
+
+	/// Load the rebase file from disk again, after it was edited outside of the tool, such as in an external editor.
+	/// The lines are replaced as a single change that can be undone, and the selected line is kept on the line of the
+	/// same commit, when the commit is still in the file. The error of a line that cannot be parsed has the number of
+	/// the line in the file.
+	pub fn reload_file(&mut self) -> Result<()> {
+		let contents = read_to_string(file_system_path(self.filepath.as_str()))
+			.map_err(|err| anyhow!("Error reading file: {}", self.filepath).context(err))?;
+		let format = Format::detect(contents.as_str());
+		let lines = self
+			.parse_lines(format, contents.as_str())
+			.map_err(|(number, err)| {
+				anyhow!("Error reading file: {}", self.filepath).context(format!("Error on line {}: {:#}", number, err))
+			})?;
+		self.format = format;
+		self.replace_lines(lines);
+		Ok(())
+	}
+
+	/// Replace all of the lines, such as with the lines of a rebase file that was changed by another program. The lines
+	/// are replaced as a single change that can be undone, and the selected line is kept on the line of the same
+	/// commit, when the commit is still in the lines.
+	pub fn replace_lines(&mut self, lines: Vec<Line>) {
+		// empty or noop lines cannot be a change of the lines, since the list is not edited without lines
+		if self.lines.is_empty() || lines.is_empty() || lines[0].get_action() == &Action::Noop {
+			self.set_lines(lines);
+			return;
+		}
+		let selected_hash = self
+			.get_selected_line()
+			.filter(|line| line.has_reference())
+			.map(|line| String::from(line.get_hash()));
+		if self.replace_all_lines(lines) {
+			self.selected_range_start = None;
+			let selected_index = selected_hash
+				.and_then(|hash| {
+					self.lines
+						.iter()
+						.position(|line| line.has_reference() && line.get_hash() == hash)
+				})
+				.unwrap_or(self.selected_line_index);
+			self.set_selected_line_index(selected_index);
+		}
+	}
+
+	/// Merge the changes to the lines with the changes of other lines, when both were changed from the same base
+	/// lines, such as when the rebase file was changed by another program while the lines were edited. A change to
+	/// lines that are next to lines that were changed differently in the other lines cannot be merged.
+	///
+	/// Returns the merged lines, or `None` if the changes conflict.
+	#[must_use]
+	pub fn merge_lines(&self, base: &[Line], other: &[Line]) -> Option<Vec<Line>> {
+		merge_lines(base, self.lines.as_slice(), other)
+	}
+
+	/// Parse the contents of a rebase file, and set the rebase lines. Comments and empty lines are ignored, except for
+	/// the comment with the name of a group, directly before the break or label line that starts the group. The format
+	/// of the contents is detected, and the same format is used when the rebase file is written.
+	pub fn load_str(&mut self, contents: &str) -> Result<()> {
+		let format = Format::detect(contents);
+		let lines = self.parse_lines(format, contents).map_err(|(_, err)| err)?;
+		self.format = format;
 		self.set_lines(lines);
 		Ok(())
 	}
 
-	/// Write the rebase file to disk.
+	// parse the lines of the contents of a rebase file, with the number of the line of the contents that cannot be
+	// parsed
+	fn parse_lines(&self, format: Format, contents: &str) -> Result<Vec<Line>, (usize, anyhow::Error)> {
+		let comment_char = self.comment_char.as_str();
+		let mut lines = vec![];
+		let mut group_name = None;
+		// like Git, a trailing carriage return is not part of the line, even on the last line without a new line, and
+		// each of the trailing carriage returns is removed, so that the line is loaded the same once it is written
+		for (index, l) in contents.lines().map(|l| l.trim_end_matches('\r')).enumerate() {
+			if let Some(name) = format.parse_group_name(l, comment_char) {
+				group_name = Some(name);
+				continue;
+			}
+			if let Some(line) = format.parse_line(l, comment_char) {
+				let mut line = line.map_err(|err| (index + 1, err))?;
+				line.set_group_name(group_name);
+				lines.push(line);
+			}
+			group_name = None;
+		}
+		Ok(lines)
+	}
+
+	/// Write the rebase file to disk. The file is replaced atomically, with the permissions of the file, so that Git
+	/// never reads a partially written rebase file.
 	pub fn write_file(&self) -> Result<()> {
-		let mut file = File::create(&self.filepath)
-			.map_err(|err| anyhow!(err).context(anyhow!("Error opening file: {}", self.filepath)))?;
-		let file_contents = if self.is_noop {
-			String::from("noop")
+		let file_contents = self.to_text()?;
+		write_atomic(
+			file_system_path(self.filepath.as_str()).as_path(),
+			self.filepath.as_str(),
+			file_contents.as_str(),
+		)
+	}
+
+	/// Serialize the rebase lines into the contents of a rebase file, in the format of the loaded rebase file.
+	pub fn to_text(&self) -> Result<String> {
+		if self.is_noop {
+			Ok(String::from("noop\n"))
 		}
 		else {
-			self.lines.iter().map(Line::to_text).collect::<Vec<String>>().join("\n")
-		};
-		writeln!(file, "{}", file_contents)
-			.map_err(|err| anyhow!(err).context(anyhow!("Error writing file: {}", self.filepath)))?;
+			Ok(format!(
+				"{}\n",
+				self.format.write_lines(&self.lines, self.comment_char.as_str())?
+			))
+		}
+	}
+
+	/// Check that Git will be able to start a rebase with the rebase lines.
+	pub fn validate(&self) -> Result<(), ValidationError> {
+		let first_commit = self
+			.lines
+			.iter()
+			.enumerate()
+			.find(|&(_, line)| line.has_reference() && line.get_action() != &Action::Drop);
+		if let Some((index, line)) = first_commit {
+			let action = *line.get_action();
+			if matches!(action, Action::Fixup | Action::Squash) {
+				return Err(ValidationError::NoPreviousCommit {
+					index,
+					hash: String::from(line.get_hash()),
+					action,
+				});
+			}
+		}
 		Ok(())
 	}
 
+	/// Find the problems with the labels of a rebase with merges, and with the fixup and squash lines that fix a commit
+	/// that is dropped or picked later, ordered by the index of the line with the problem.
+	#[must_use]
+	pub fn lint(&self) -> Vec<Lint> {
+		lint_lines(&self.lines)
+	}
+
 	/// Set the selected line index.
 	pub fn set_selected_line_index(&mut self, selected_line_index: usize) {
 		self.selected_line_index = if self.lines.is_empty() {
@@ -189,6 +365,12 @@ impl TodoFile {
 		}
 	}
 
+	/// Set the index of the line that the range of selected lines starts at, the range ends at the selected line, or
+	/// `None` to only select the selected line.
+	pub fn set_selected_range_start(&mut self, start_index: Option<usize>) {
+		self.selected_range_start = start_index;
+	}
+
 	/// Swap a range of lines up.
 	pub fn swap_range_up(&mut self, start_index: usize, end_index: usize) -> bool {
 		if end_index == 0 || start_index == 0 || self.lines.is_empty() {
@@ -223,6 +405,30 @@ impl TodoFile {
 		true
 	}
 
+	/// Move a range of lines, so that the first line of the range is at a new index, as a single change.
+	///
+	/// Returns if the lines were moved, which they are not when the range is already at the index, or the range would
+	/// be past the end of the list.
+	pub fn move_range(&mut self, start_index: usize, end_index: usize, new_start_index: usize) -> bool {
+		let (start, end) = if end_index < start_index {
+			(end_index, start_index)
+		}
+		else {
+			(start_index, end_index)
+		};
+		let new_end_index = new_start_index + end - start;
+		if new_start_index == start || end >= self.lines.len() || new_end_index >= self.lines.len() {
+			return false;
+		}
+		let changed_start = min(start, new_start_index);
+		let changed_end = max(end, new_end_index);
+		let lines = self.lines[changed_start..=changed_end].to_vec();
+		let moved_lines: Vec<Line> = self.lines.drain(start..=end).collect();
+		let _ = self.lines.splice(new_start_index..new_start_index, moved_lines);
+		self.history.record(HistoryItem::new_modify(changed_start, changed_end, lines));
+		true
+	}
+
 	/// Add a new line.
 	pub fn add_line(&mut self, index: usize, line: Line) {
 		let i = if index > self.lines.len() {
@@ -235,6 +441,22 @@ impl TodoFile {
 		self.history.record(HistoryItem::new_add(i, i));
 	}
 
+	/// Add new lines, as a single change, so that the lines are removed together by an undo.
+	pub fn add_lines(&mut self, index: usize, lines: &[Line]) {
+		if lines.is_empty() {
+			return;
+		}
+		let start = if index > self.lines.len() {
+			self.lines.len()
+		}
+		else {
+			index
+		};
+		let end = start + lines.len() - 1;
+		add_range(&mut self.lines, lines, start, end);
+		self.history.record(HistoryItem::new_add(start, end));
+	}
+
 	/// Remove a range of lines.
 	pub fn remove_lines(&mut self, start_index: usize, end_index: usize) {
 		if self.lines.is_empty() {
@@ -275,17 +497,379 @@ impl TodoFile {
 		for index in range {
 			let line = &mut self.lines[index];
 			lines.push(line.clone());
-			if let Some(action) = edit_context.get_action().as_ref() {
-				line.set_action(*action);
-			}
+			edit_line(line, edit_context);
+		}
+		self.history.record(HistoryItem::new_modify(start, end, lines));
+	}
 
-			if let Some(content) = edit_context.get_content().as_ref() {
-				line.edit_content(content);
-			}
+	/// Update the lines with the indexes, that do not have to be next to each other, as a single change. The indexes
+	/// past the end of the list are ignored.
+	pub fn update_lines(&mut self, indexes: &[usize], edit_context: &EditContext) {
+		let indexes: Vec<usize> = indexes.iter().copied().filter(|&index| index < self.lines.len()).collect();
+		let (start, end) = match (indexes.iter().min(), indexes.iter().max()) {
+			(Some(&start), Some(&end)) => (start, end),
+			_ => return,
+		};
+		let lines = self.lines[start..=end].to_vec();
+		for index in indexes {
+			edit_line(&mut self.lines[index], edit_context);
 		}
 		self.history.record(HistoryItem::new_modify(start, end, lines));
 	}
 
+	/// Move the lines with the indexes, that do not have to be next to each other, up by one line, as a single change.
+	/// The lines that are next to each other are moved together.
+	///
+	/// Returns if the lines were moved, which they are not when the first line of the list is one of the lines, or an
+	/// index is past the end of the list.
+	pub fn move_lines_up(&mut self, indexes: &[usize]) -> bool {
+		let mut indexes = indexes.to_vec();
+		indexes.sort_unstable();
+		indexes.dedup();
+		let (start, end) = match (indexes.first(), indexes.last()) {
+			(Some(&start), Some(&end)) => (start, end),
+			_ => return false,
+		};
+		if start == 0 || end >= self.lines.len() {
+			return false;
+		}
+		let lines = self.lines[start - 1..=end].to_vec();
+		for index in indexes {
+			self.lines.swap(index - 1, index);
+		}
+		self.history.record(HistoryItem::new_modify(start - 1, end, lines));
+		true
+	}
+
+	/// Move the lines with the indexes, that do not have to be next to each other, down by one line, as a single
+	/// change. The lines that are next to each other are moved together.
+	///
+	/// Returns if the lines were moved, which they are not when the last line of the list is one of the lines, or an
+	/// index is past the end of the list.
+	pub fn move_lines_down(&mut self, indexes: &[usize]) -> bool {
+		let mut indexes = indexes.to_vec();
+		indexes.sort_unstable();
+		indexes.dedup();
+		let (start, end) = match (indexes.first(), indexes.last()) {
+			(Some(&start), Some(&end)) => (start, end),
+			_ => return false,
+		};
+		if end + 1 >= self.lines.len() {
+			return false;
+		}
+		let lines = self.lines[start..=end + 1].to_vec();
+		for &index in indexes.iter().rev() {
+			self.lines.swap(index, index + 1);
+		}
+		self.history.record(HistoryItem::new_modify(start, end + 1, lines));
+		true
+	}
+
+	/// Reorder the lines with the indexes, that do not have to be next to each other, as a single change. The lines
+	/// keep the positions of the indexes, and are placed at the positions in the order of the indexes, so the line of
+	/// the first index is placed at the lowest of the indexes.
+	///
+	/// Returns if the lines were reordered, which they are not when the lines are already in the order, an index is
+	/// past the end of the list, or an index is repeated.
+	pub fn reorder_lines(&mut self, order: &[usize]) -> bool {
+		let mut positions = order.to_vec();
+		positions.sort_unstable();
+		positions.dedup();
+		let (start, end) = match (positions.first(), positions.last()) {
+			(Some(&start), Some(&end)) => (start, end),
+			_ => return false,
+		};
+		if positions.len() != order.len() || end >= self.lines.len() || positions.as_slice() == order {
+			return false;
+		}
+		let lines = self.lines[start..=end].to_vec();
+		for (&position, &index) in positions.iter().zip(order) {
+			self.lines[position] = lines[index - start].clone();
+		}
+		self.history.record(HistoryItem::new_modify(start, end, lines));
+		true
+	}
+
+	/// Cycle the option of the fixup lines of a range, to the option that follows the option of the first fixup line
+	/// of the range, so that all of the fixup lines have the same option.
+	///
+	/// Returns if the lines were changed, which they are not when the range has no fixup lines.
+	pub fn cycle_fixup_option(&mut self, start_index: usize, end_index: usize) -> bool {
+		let (start, end) = if end_index < start_index {
+			(end_index, start_index)
+		}
+		else {
+			(start_index, end_index)
+		};
+		let range = match self.lines.get(start..=end) {
+			Some(range) => range,
+			None => return false,
+		};
+		let option = match range
+			.iter()
+			.find(|line| *line.get_action() == Action::Fixup)
+			.map(Line::get_next_option)
+		{
+			Some(option) => option,
+			None => return false,
+		};
+		let lines = range.to_vec();
+		for line in &mut self.lines[start..=end] {
+			line.set_option(option);
+		}
+		self.history.record(HistoryItem::new_modify(start, end, lines));
+		true
+	}
+
+	/// Remove all of the break lines.
+	///
+	/// Returns if any line was removed.
+	pub fn remove_breaks(&mut self) -> bool {
+		let lines: Vec<Line> = self
+			.lines
+			.iter()
+			.filter(|line| *line.get_action() != Action::Break)
+			.cloned()
+			.collect();
+		self.replace_all_lines(lines)
+	}
+
+	/// Insert a break before every commit that would make a segment of the todo list, between breaks, have more than
+	/// a number of commits. A commit is a line that creates a new commit, the fixup and squash lines of a commit, and
+	/// any other lines, stay with it, and the breaks that are already in the list start a new segment.
+	///
+	/// Returns if any break was inserted, which is never when the number of commits is zero.
+	pub fn break_every(&mut self, commits: usize) -> bool {
+		if commits == 0 {
+			return false;
+		}
+		let mut lines = Vec::with_capacity(self.lines.len());
+		let mut segment_commits = 0;
+		for line in &self.lines {
+			match *line.get_action() {
+				Action::Break => segment_commits = 0,
+				Action::Pick | Action::Reword | Action::Edit | Action::Merge => {
+					if segment_commits == commits {
+						lines.push(Line::new_break());
+						segment_commits = 0;
+					}
+					segment_commits += 1;
+				},
+				_ => {},
+			}
+			lines.push(line.clone());
+		}
+		self.replace_all_lines(lines)
+	}
+
+	/// Get the range of the group of lines that contains a line, from the break or label line that starts the group,
+	/// or the first line, to the line before the start of the next group.
+	#[must_use]
+	pub fn get_group_range(&self, index: usize) -> Option<(usize, usize)> {
+		if index >= self.lines.len() {
+			return None;
+		}
+		let start = self.lines[..=index]
+			.iter()
+			.rposition(Line::is_group_start)
+			.unwrap_or(0);
+		let end = self.lines[index + 1..]
+			.iter()
+			.position(Line::is_group_start)
+			.map_or(self.lines.len() - 1, |position| index + position);
+		Some((start, end))
+	}
+
+	/// Set the name of the group that a break or label line starts, or remove the name with `None` or an empty name.
+	///
+	/// Returns if the name was changed, which is never for a line that does not start a group.
+	pub fn set_group_name(&mut self, index: usize, name: Option<&str>) -> bool {
+		let line = match self.lines.get_mut(index) {
+			Some(line) => line,
+			None => return false,
+		};
+		let previous_line = line.clone();
+		line.set_group_name(name);
+		if *line == previous_line {
+			return false;
+		}
+		self.history.record(HistoryItem::new_modify(index, index, vec![previous_line]));
+		true
+	}
+
+	// replace all of the lines of the list, as a single change
+	fn replace_all_lines(&mut self, lines: Vec<Line>) -> bool {
+		if lines == self.lines {
+			return false;
+		}
+		let removed_end = self.lines.len() - 1;
+		let removed_lines = remove_range(&mut self.lines, 0, removed_end);
+		if lines.is_empty() {
+			self.history.record(HistoryItem::new_remove(0, removed_end, removed_lines));
+		}
+		else {
+			let end = lines.len() - 1;
+			add_range(&mut self.lines, &lines, 0, end);
+			self.history.record(HistoryItem::new_replace(0, end, removed_lines));
+		}
+		true
+	}
+
+	/// Move a range of commit lines to a new branch, that is merged back after the range, by wrapping the range in
+	/// label, reset and merge lines, with new labels that are not used by any line. The range cannot contain a label,
+	/// reset or merge line, and must contain a commit, otherwise the lines are not changed.
+	///
+	/// Returns the label of the new branch, if the range was moved to a branch.
+	pub fn branch_out(&mut self, start_index: usize, end_index: usize) -> Option<String> {
+		let (start, end) = if end_index < start_index {
+			(end_index, start_index)
+		}
+		else {
+			(start_index, end_index)
+		};
+		let range = self.lines.get(start..=end)?;
+		if !range.iter().any(Line::has_reference)
+			|| range
+				.iter()
+				.any(|line| matches!(*line.get_action(), Action::Label | Action::Merge | Action::Noop | Action::Reset))
+		{
+			return None;
+		}
+
+		let used_labels: HashSet<&str> = self.lines.iter().flat_map(Line::get_labels).collect();
+		let number = (1..)
+			.find(|number| {
+				!used_labels.contains(format!("branch-{}", number).as_str())
+					&& !used_labels.contains(format!("branch-{}-base", number).as_str())
+			})
+			.unwrap_or_default();
+		let label = format!("branch-{}", number);
+		let base_label = format!("branch-{}-base", number);
+
+		let mut lines = vec![Line::new_label(base_label.as_str())];
+		lines.extend_from_slice(range);
+		lines.push(Line::new_label(label.as_str()));
+		lines.push(Line::new_reset(base_label.as_str()));
+		// without a commit to take the message from, Git uses the text after the `#` as the message of the merge
+		lines.push(Line::new_merge(format!("{} # Merge {}", label, label).as_str()));
+		let new_end = start + lines.len() - 1;
+		let removed_lines = remove_range(&mut self.lines, start, end);
+		add_range(&mut self.lines, &lines, start, new_end);
+		self.history
+			.record(HistoryItem::new_replace(start, new_end, removed_lines));
+		Some(label)
+	}
+
+	/// Get the lines in the order that `git rebase --autosquash` would rebase them, found from the `fixup!`,
+	/// `squash!` and `amend!` prefixes of the subjects of the commits, without changing the lines.
+	#[must_use]
+	pub fn autosquash_preview(&self) -> Vec<AutosquashLine> {
+		autosquash_lines(&self.lines)
+	}
+
+	/// Reorder the lines, and set the actions of the fixup and squash commits, like `git rebase --autosquash`.
+	///
+	/// Returns if any line was changed.
+	pub fn autosquash(&mut self) -> bool {
+		let autosquash_lines = autosquash_lines(&self.lines);
+		let lines: Vec<Line> = autosquash_lines
+			.iter()
+			.map(|autosquash_line| {
+				let mut line = self.lines[autosquash_line.index()].clone();
+				line.set_action(autosquash_line.action());
+				line
+			})
+			.collect();
+		if lines == self.lines {
+			return false;
+		}
+		let end = lines.len() - 1;
+		let removed_lines = remove_range(&mut self.lines, 0, end);
+		add_range(&mut self.lines, &lines, 0, end);
+		self.history.record(HistoryItem::new_replace(0, end, removed_lines));
+		true
+	}
+
+	/// Fix a fixup or squash line that fixes a commit that is dropped, by picking the line, or that fixes a commit that
+	/// is picked later, by moving the line after the commit, and after the fixup and squash lines that follow it.
+	///
+	/// Returns the new index of the line, if the line was changed.
+	pub fn fix_fixup_target(&mut self, index: usize) -> Option<usize> {
+		let target_index = find_fixup_target(&self.lines, index)?;
+		if target_index > index {
+			let end = self.lines[target_index + 1..]
+				.iter()
+				.position(|line| !matches!(*line.get_action(), Action::Fixup | Action::Squash))
+				.map_or(self.lines.len() - 1, |position| target_index + position);
+			let mut lines = self.lines[index + 1..=end].to_vec();
+			lines.push(self.lines[index].clone());
+			let removed_lines = remove_range(&mut self.lines, index, end);
+			add_range(&mut self.lines, &lines, index, end);
+			self.history.record(HistoryItem::new_replace(index, end, removed_lines));
+			Some(end)
+		}
+		else if *self.lines[target_index].get_action() == Action::Drop {
+			let previous_line = self.lines[index].clone();
+			self.lines[index].set_action(Action::Pick);
+			self.history
+				.record(HistoryItem::new_modify(index, index, vec![previous_line]));
+			Some(index)
+		}
+		else {
+			None
+		}
+	}
+
+	/// Get the start and end index of the chain of fixup and squash lines that the line at the index is in, with the
+	/// line of the commit that they are applied to, when the chain has a squash line, and Git asks for the message of
+	/// the combined commit.
+	#[must_use]
+	pub fn get_squash_chain(&self, index: usize) -> Option<(usize, usize)> {
+		let is_fixup_or_squash = |line: &Line| matches!(*line.get_action(), Action::Fixup | Action::Squash);
+		let start = self
+			.lines
+			.get(..=index)?
+			.iter()
+			.rposition(|line| !is_fixup_or_squash(line))?;
+		let end = self.lines[index + 1..]
+			.iter()
+			.position(|line| !is_fixup_or_squash(line))
+			.map_or(self.lines.len() - 1, |position| index + position);
+		let chain = &self.lines[start..=end];
+		(matches!(*chain[0].get_action(), Action::Pick | Action::Reword | Action::Edit)
+			&& chain.iter().any(|line| *line.get_action() == Action::Squash))
+		.then_some((start, end))
+	}
+
+	/// Set the message of the combined commit of a chain of fixup and squash lines, by replacing the line at the start
+	/// of the chain with a line for the commit with the hash, which is a copy of the commit with the message, and
+	/// setting the squash lines of the chain to fixup lines, so that Git keeps the message without asking for it.
+	///
+	/// Returns if the lines were changed, which they are not when the index is not the start of a chain.
+	pub fn set_squash_chain_message(&mut self, start_index: usize, hash: &str) -> bool {
+		let (start, end) = match self.get_squash_chain(start_index).filter(|&(start, _)| start == start_index) {
+			Some((start, end)) => (start, end),
+			None => return false,
+		};
+		let mut first_line = self.lines[start].with_hash(hash);
+		// the message is set, so a reworded commit does not need to be reworded
+		if *first_line.get_action() == Action::Reword {
+			first_line.set_action(Action::Pick);
+		}
+		let mut lines = vec![first_line];
+		for line in &self.lines[start + 1..=end] {
+			let mut line = line.clone();
+			line.set_action(Action::Fixup);
+			// the message of a fixup line with an option would replace the message
+			line.set_option(None);
+			lines.push(line);
+		}
+		let removed_lines = remove_range(&mut self.lines, start, end);
+		add_range(&mut self.lines, &lines, start, end);
+		self.history.record(HistoryItem::new_replace(start, end, removed_lines));
+		true
+	}
+
 	/// Undo the last modification.
 	pub fn undo(&mut self) -> Option<(usize, usize)> {
 		self.history.undo(&mut self.lines)
@@ -302,6 +886,18 @@ impl TodoFile {
 		self.lines.get(self.selected_line_index)
 	}
 
+	/// Get the lines of the range of selected lines, in the order of the rebase file, or the selected line when a range
+	/// is not selected.
+	#[must_use]
+	pub fn get_selected_lines(&self) -> &[Line] {
+		let range_start = self.selected_range_start.unwrap_or(self.selected_line_index);
+		let start_index = min(range_start, self.selected_line_index);
+		let end_index = max(range_start, self.selected_line_index);
+		self.lines
+			.get(start_index..=min(end_index, self.get_max_selected_line_index()))
+			.unwrap_or_default()
+	}
+
 	/// Get the index of the last line that can be selected.
 	#[must_use]
 	pub fn get_max_selected_line_index(&self) -> usize {
@@ -320,6 +916,12 @@ impl TodoFile {
 		self.selected_line_index
 	}
 
+	/// Get the format of the rebase file.
+	#[must_use]
+	pub const fn get_format(&self) -> Format {
+		self.format
+	}
+
 	/// Get the file path to the rebase file.
 	#[must_use]
 	pub fn get_filepath(&self) -> &str {
@@ -359,6 +961,9 @@ impl TodoFile {
 
 #[cfg(test)]
 mod tests {
+	use std::{fs::write, io::Write, path::Path};
+
+	use rstest::rstest;
 	use tempfile::{Builder, NamedTempFile};
 
 	use super::*;
@@ -401,6 +1006,25 @@ mod tests {
 		assert_todo_lines!(todo_file, "pick aaa foobar");
 	}
 
+	#[test]
+	fn load_str_trailing_carriage_return() {
+		let mut todo_file = TodoFile::new("git-rebase-todo", 1, "#");
+		todo_file.load_str("pick aaa foo\r\npick bbb bar\r").unwrap();
+		assert_todo_lines!(todo_file, "pick aaa foo", "pick bbb bar");
+	}
+
+	// found by the todo parser fuzz target, the carriage return left in the content was removed when the written line
+	// was loaded again
+	#[test]
+	fn load_str_trailing_carriage_returns() {
+		let mut todo_file = TodoFile::new("git-rebase-todo", 1, "#");
+		todo_file.load_str("t \r\r\nexec foo\r\r\r").unwrap();
+		assert_todo_lines!(todo_file, "reset ", "exec foo");
+		let text = todo_file.to_text().unwrap();
+		todo_file.load_str(text.as_str()).unwrap();
+		assert_eq!(todo_file.to_text().unwrap(), text);
+	}
+
 	#[test]
 	fn load_noop_file() {
 		let (todo_file, _) = create_and_load_todo_file(&["noop"]);
@@ -451,6 +1075,50 @@ mod tests {
 		assert_eq!(todo_file.selected_line_index, 0);
 	}
 
+	#[test]
+	fn get_selected_lines_without_range() {
+		let (mut todo_file, _) = create_and_load_todo_file(&["pick a a", "pick b b", "pick c c"]);
+		todo_file.set_selected_line_index(1);
+		assert_eq!(todo_file.get_selected_lines(), &[Line::new("pick b b").unwrap()]);
+	}
+
+	#[test]
+	fn get_selected_lines_range_before_selected_line() {
+		let (mut todo_file, _) = create_and_load_todo_file(&["pick a a", "pick b b", "pick c c"]);
+		todo_file.set_selected_line_index(2);
+		todo_file.set_selected_range_start(Some(1));
+		assert_eq!(todo_file.get_selected_lines(), &[
+			Line::new("pick b b").unwrap(),
+			Line::new("pick c c").unwrap()
+		]);
+	}
+
+	#[test]
+	fn get_selected_lines_range_after_selected_line() {
+		let (mut todo_file, _) = create_and_load_todo_file(&["pick a a", "pick b b", "pick c c"]);
+		todo_file.set_selected_range_start(Some(5));
+		assert_eq!(todo_file.get_selected_lines(), &[
+			Line::new("pick a a").unwrap(),
+			Line::new("pick b b").unwrap(),
+			Line::new("pick c c").unwrap()
+		]);
+	}
+
+	#[test]
+	fn get_selected_lines_empty() {
+		let mut todo_file = TodoFile::new("git-rebase-todo", 1, "#");
+		todo_file.set_selected_range_start(Some(1));
+		assert!(todo_file.get_selected_lines().is_empty());
+	}
+
+	#[test]
+	fn set_lines_reset_selected_range() {
+		let (mut todo_file, _) = create_and_load_todo_file(&["pick a a", "pick b b", "pick c c"]);
+		todo_file.set_selected_range_start(Some(2));
+		todo_file.set_lines(vec![Line::new("pick a a").unwrap(), Line::new("pick b b").unwrap()]);
+		assert_eq!(todo_file.get_selected_lines(), &[Line::new("pick a a").unwrap()]);
+	}
+
 	#[test]
 	fn write_file() {
 		let (mut todo_file, _) = create_and_load_todo_file(&[]);
@@ -503,6 +1171,154 @@ mod tests {
 		assert_todo_lines!(todo_file, "pick aaa comment");
 	}
 
+	#[test]
+	fn add_lines() {
+		let (mut todo_file, _) = create_and_load_todo_file(&["pick aaa comment", "drop bbb comment"]);
+		todo_file.add_lines(1, &[Line::new_exec("make"), Line::new_break()]);
+		assert_todo_lines!(
+			todo_file,
+			"pick aaa comment",
+			"exec make",
+			"break",
+			"drop bbb comment"
+		);
+	}
+
+	#[test]
+	fn add_lines_index_miss() {
+		let (mut todo_file, _) = create_and_load_todo_file(&["pick aaa comment"]);
+		todo_file.add_lines(100, &[Line::new_exec("make"), Line::new_break()]);
+		assert_todo_lines!(todo_file, "pick aaa comment", "exec make", "break");
+	}
+
+	#[test]
+	fn add_lines_empty() {
+		let (mut todo_file, _) = create_and_load_todo_file(&["pick aaa comment"]);
+		todo_file.add_lines(1, &[]);
+		assert_todo_lines!(todo_file, "pick aaa comment");
+		assert!(todo_file.undo().is_none());
+	}
+
+	#[test]
+	fn add_lines_record_history() {
+		let (mut todo_file, _) = create_and_load_todo_file(&["pick aaa comment"]);
+		todo_file.add_lines(1, &[Line::new_exec("make"), Line::new_break()]);
+		let _undo_result = todo_file.undo();
+		assert_todo_lines!(todo_file, "pick aaa comment");
+	}
+
+	#[test]
+	fn remove_breaks() {
+		let (mut todo_file, _) =
+			create_and_load_todo_file(&["break", "pick aaa c1", "break", "pick bbb c2", "exec make", "break"]);
+		assert!(todo_file.remove_breaks());
+		assert_todo_lines!(todo_file, "pick aaa c1", "pick bbb c2", "exec make");
+		let _undo_result = todo_file.undo();
+		assert_todo_lines!(
+			todo_file,
+			"break",
+			"pick aaa c1",
+			"break",
+			"pick bbb c2",
+			"exec make",
+			"break"
+		);
+	}
+
+	#[test]
+	fn remove_breaks_only_breaks() {
+		let (mut todo_file, _) = create_and_load_todo_file(&["break", "break"]);
+		assert!(todo_file.remove_breaks());
+		assert!(todo_file.is_empty());
+		let _undo_result = todo_file.undo();
+		assert_todo_lines!(todo_file, "break", "break");
+	}
+
+	#[test]
+	fn remove_breaks_without_breaks() {
+		let (mut todo_file, _) = create_and_load_todo_file(&["pick aaa c1"]);
+		assert!(!todo_file.remove_breaks());
+		assert!(todo_file.undo().is_none());
+	}
+
+	#[test]
+	fn break_every() {
+		let (mut todo_file, _) = create_and_load_todo_file(&[
+			"pick aaa c1",
+			"fixup bbb c2",
+			"pick ccc c3",
+			"exec make",
+			"pick ddd c4",
+			"squash eee c5",
+			"pick fff c6",
+			"drop 111 c7",
+			"reword 222 c8",
+		]);
+		assert!(todo_file.break_every(2));
+		assert_todo_lines!(
+			todo_file,
+			"pick aaa c1",
+			"fixup bbb c2",
+			"pick ccc c3",
+			"exec make",
+			"break",
+			"pick ddd c4",
+			"squash eee c5",
+			"pick fff c6",
+			"drop 111 c7",
+			"break",
+			"reword 222 c8"
+		);
+		let _undo_result = todo_file.undo();
+		assert_eq!(todo_file.get_lines_owned().len(), 9);
+	}
+
+	#[test]
+	fn break_every_counted_from_breaks() {
+		let (mut todo_file, _) =
+			create_and_load_todo_file(&["pick aaa c1", "break", "pick bbb c2", "pick ccc c3", "pick ddd c4"]);
+		assert!(todo_file.break_every(2));
+		assert_todo_lines!(
+			todo_file,
+			"pick aaa c1",
+			"break",
+			"pick bbb c2",
+			"pick ccc c3",
+			"break",
+			"pick ddd c4"
+		);
+	}
+
+	#[test]
+	fn break_every_merge() {
+		let (mut todo_file, _) = create_and_load_todo_file(&[
+			"label onto",
+			"pick aaa c1",
+			"label side",
+			"reset onto",
+			"merge -C bbb side",
+		]);
+		assert!(todo_file.break_every(1));
+		assert_todo_lines!(
+			todo_file,
+			"label onto",
+			"pick aaa c1",
+			"label side",
+			"reset onto",
+			"break",
+			"merge -C bbb side"
+		);
+	}
+
+	#[rstest]
+	#[case::zero(0)]
+	#[case::more_than_commits(3)]
+	fn break_every_unchanged(#[case] commits: usize) {
+		let (mut todo_file, _) = create_and_load_todo_file(&["pick aaa c1", "pick bbb c2"]);
+		assert!(!todo_file.break_every(commits));
+		assert!(todo_file.undo().is_none());
+	}
+
 	#[test]
 	fn remove_lines_index_miss_start() {
 		let (mut todo_file, _) =
@@ -610,6 +1426,123 @@ mod tests {
 		assert_todo_lines!(todo_file, "reword aaa comment", "reword bbb comment");
 	}
 
+	#[test]
+	fn update_lines() {
+		let (mut todo_file, _) =
+			create_and_load_todo_file(&["pick aaa c1", "pick bbb c2", "pick ccc c3", "pick ddd c4"]);
+		todo_file.update_lines(&[2, 0, 5], &EditContext::new().action(Action::Drop));
+		assert_todo_lines!(todo_file, "drop aaa c1", "pick bbb c2", "drop ccc c3", "pick ddd c4");
+	}
+
+	#[test]
+	fn update_lines_record_history() {
+		let (mut todo_file, _) = create_and_load_todo_file(&["pick aaa c1", "pick bbb c2", "pick ccc c3"]);
+		todo_file.update_lines(&[0, 2], &EditContext::new().action(Action::Drop));
+		assert_eq!(todo_file.undo(), Some((0, 2)));
+		assert_todo_lines!(todo_file, "pick aaa c1", "pick bbb c2", "pick ccc c3");
+	}
+
+	#[test]
+	fn update_lines_without_indexes() {
+		let (mut todo_file, _) = create_and_load_todo_file(&["pick aaa c1"]);
+		todo_file.update_lines(&[1], &EditContext::new().action(Action::Drop));
+		assert_todo_lines!(todo_file, "pick aaa c1");
+		assert_eq!(todo_file.undo(), None);
+	}
+
+	#[test]
+	fn move_lines_up() {
+		let (mut todo_file, _) =
+			create_and_load_todo_file(&["pick aaa c1", "pick bbb c2", "pick ccc c3", "pick ddd c4", "pick eee c5"]);
+		assert!(todo_file.move_lines_up(&[4, 1, 2]));
+		assert_todo_lines!(todo_file, "pick bbb c2", "pick ccc c3", "pick aaa c1", "pick eee c5", "pick ddd c4");
+		assert_eq!(todo_file.undo(), Some((0, 4)));
+		assert_todo_lines!(todo_file, "pick aaa c1", "pick bbb c2", "pick ccc c3", "pick ddd c4", "pick eee c5");
+	}
+
+	#[test]
+	fn move_lines_up_first_line() {
+		let (mut todo_file, _) = create_and_load_todo_file(&["pick aaa c1", "pick bbb c2", "pick ccc c3"]);
+		assert!(!todo_file.move_lines_up(&[0, 2]));
+		assert_todo_lines!(todo_file, "pick aaa c1", "pick bbb c2", "pick ccc c3");
+	}
+
+	#[test]
+	fn move_range_down() {
+		let (mut todo_file, _) =
+			create_and_load_todo_file(&["pick aaa c1", "pick bbb c2", "pick ccc c3", "pick ddd c4", "pick eee c5"]);
+		assert!(todo_file.move_range(1, 0, 3));
+		assert_todo_lines!(todo_file, "pick ccc c3", "pick ddd c4", "pick eee c5", "pick aaa c1", "pick bbb c2");
+		assert_eq!(todo_file.undo(), Some((0, 4)));
+		assert_todo_lines!(todo_file, "pick aaa c1", "pick bbb c2", "pick ccc c3", "pick ddd c4", "pick eee c5");
+	}
+
+	#[test]
+	fn move_range_up() {
+		let (mut todo_file, _) =
+			create_and_load_todo_file(&["pick aaa c1", "pick bbb c2", "pick ccc c3", "pick ddd c4", "pick eee c5"]);
+		assert!(todo_file.move_range(3, 3, 1));
+		assert_todo_lines!(todo_file, "pick aaa c1", "pick ddd c4", "pick bbb c2", "pick ccc c3", "pick eee c5");
+		assert_eq!(todo_file.undo(), Some((1, 3)));
+	}
+
+	#[test]
+	fn reorder_lines() {
+		let (mut todo_file, _) =
+			create_and_load_todo_file(&["pick aaa c1", "exec make", "pick bbb c2", "pick ccc c3", "pick ddd c4"]);
+		assert!(todo_file.reorder_lines(&[3, 0, 2]));
+		assert_todo_lines!(todo_file, "pick ccc c3", "exec make", "pick aaa c1", "pick bbb c2", "pick ddd c4");
+		assert_eq!(todo_file.undo(), Some((0, 3)));
+		assert_todo_lines!(todo_file, "pick aaa c1", "exec make", "pick bbb c2", "pick ccc c3", "pick ddd c4");
+	}
+
+	#[rstest]
+	#[case::empty(&[])]
+	#[case::same_order(&[0, 2])]
+	#[case::past_end(&[3, 0])]
+	#[case::repeated_index(&[1, 0, 1])]
+	fn reorder_lines_unchanged(#[case] order: &[usize]) {
+		let (mut todo_file, _) = create_and_load_todo_file(&["pick aaa c1", "pick bbb c2", "pick ccc c3"]);
+		assert!(!todo_file.reorder_lines(order));
+		assert_todo_lines!(todo_file, "pick aaa c1", "pick bbb c2", "pick ccc c3");
+	}
+
+	#[rstest]
+	#[case::same_index(1, 2, 1)]
+	#[case::past_end(1, 2, 2)]
+	#[case::range_past_end(2, 3, 0)]
+	fn move_range_unchanged(#[case] start_index: usize, #[case] end_index: usize, #[case] new_start_index: usize) {
+		let (mut todo_file, _) = create_and_load_todo_file(&["pick aaa c1", "pick bbb c2", "pick ccc c3"]);
+		assert!(!todo_file.move_range(start_index, end_index, new_start_index));
+		assert_todo_lines!(todo_file, "pick aaa c1", "pick bbb c2", "pick ccc c3");
+	}
+
+	#[test]
+	fn with_lines() {
+		let (todo_file, _) = create_and_load_todo_file(&["pick aaa c1", "pick bbb c2"]);
+		let preview = todo_file.with_lines(vec![Line::new("pick bbb c2").unwrap()]);
+		assert_todo_lines!(preview, "pick bbb c2");
+		assert_eq!(preview.get_filepath(), todo_file.get_filepath());
+	}
+
+	#[test]
+	fn move_lines_down() {
+		let (mut todo_file, _) =
+			create_and_load_todo_file(&["pick aaa c1", "pick bbb c2", "pick ccc c3", "pick ddd c4", "pick eee c5"]);
+		assert!(todo_file.move_lines_down(&[0, 2, 3]));
+		assert_todo_lines!(todo_file, "pick bbb c2", "pick aaa c1", "pick eee c5", "pick ccc c3", "pick ddd c4");
+		assert_eq!(todo_file.undo(), Some((0, 4)));
+		assert_todo_lines!(todo_file, "pick aaa c1", "pick bbb c2", "pick ccc c3", "pick ddd c4", "pick eee c5");
+	}
+
+	#[test]
+	fn move_lines_down_last_line() {
+		let (mut todo_file, _) = create_and_load_todo_file(&["pick aaa c1", "pick bbb c2", "pick ccc c3"]);
+		assert!(!todo_file.move_lines_down(&[0, 2]));
+		assert!(!todo_file.move_lines_down(&[]));
+		assert_todo_lines!(todo_file, "pick aaa c1", "pick bbb c2", "pick ccc c3");
+	}
+
 	#[test]
 	fn history_undo_redo() {
 		let (mut todo_file, _) =
@@ -621,6 +1554,228 @@ mod tests {
 		assert_todo_lines!(todo_file, "drop aaa comment", "drop bbb comment", "edit ccc comment");
 	}
 
+	#[test]
+	fn branch_out() {
+		let (mut todo_file, _) = create_and_load_todo_file(&[
+			"pick aaa comment 1",
+			"pick bbb comment 2",
+			"pick ccc comment 3",
+			"pick ddd comment 4",
+		]);
+		assert_eq!(todo_file.branch_out(2, 1), Some(String::from("branch-1")));
+		assert_todo_lines!(
+			todo_file,
+			"pick aaa comment 1",
+			"label branch-1-base",
+			"pick bbb comment 2",
+			"pick ccc comment 3",
+			"label branch-1",
+			"reset branch-1-base",
+			"merge branch-1 # Merge branch-1",
+			"pick ddd comment 4"
+		);
+		assert!(todo_file.lint().is_empty());
+	}
+
+	#[test]
+	fn branch_out_unique_labels() {
+		let (mut todo_file, _) = create_and_load_todo_file(&[
+			"label branch-1",
+			"label branch-2-base",
+			"pick aaa comment",
+			"reset branch-1",
+			"reset branch-2-base",
+		]);
+		assert_eq!(todo_file.branch_out(2, 2), Some(String::from("branch-3")));
+	}
+
+	#[test]
+	fn branch_out_record_history() {
+		let (mut todo_file, _) = create_and_load_todo_file(&["pick aaa comment", "pick bbb comment"]);
+		let _label = todo_file.branch_out(0, 1);
+		assert_eq!(todo_file.undo(), Some((0, 1)));
+		assert_todo_lines!(todo_file, "pick aaa comment", "pick bbb comment");
+		assert_eq!(todo_file.redo(), Some((0, 5)));
+		assert_eq!(todo_file.get_line(0).unwrap().to_text(), "label branch-1-base");
+	}
+
+	#[rstest]
+	#[case::without_commit(&["exec make", "break"])]
+	#[case::label(&["pick aaa comment", "label onto"])]
+	#[case::reset(&["pick aaa comment", "reset onto"])]
+	#[case::merge(&["pick aaa comment", "merge onto"])]
+	fn branch_out_invalid_range(#[case] lines: &[&str]) {
+		let (mut todo_file, _) = create_and_load_todo_file(lines);
+		assert_eq!(todo_file.branch_out(0, 1), None);
+		assert_eq!(todo_file.get_lines_owned().len(), 2);
+		assert_eq!(todo_file.undo(), None);
+	}
+
+	#[test]
+	fn branch_out_index_overflow() {
+		let (mut todo_file, _) = create_and_load_todo_file(&["pick aaa comment"]);
+		assert_eq!(todo_file.branch_out(0, 1), None);
+	}
+
+	#[test]
+	fn autosquash() {
+		let (mut todo_file, _) =
+			create_and_load_todo_file(&["pick aaa comment", "pick bbb other", "pick ccc fixup! comment"]);
+		assert_eq!(todo_file.autosquash_preview().len(), 3);
+		assert!(todo_file.autosquash());
+		assert_todo_lines!(todo_file, "pick aaa comment", "fixup ccc fixup! comment", "pick bbb other");
+	}
+
+	#[test]
+	fn autosquash_record_history() {
+		let (mut todo_file, _) = create_and_load_todo_file(&["pick aaa comment", "pick bbb squash! comment"]);
+		assert!(todo_file.autosquash());
+		assert_eq!(todo_file.undo(), Some((0, 1)));
+		assert_todo_lines!(todo_file, "pick aaa comment", "pick bbb squash! comment");
+		assert_eq!(todo_file.redo(), Some((0, 1)));
+		assert_todo_lines!(todo_file, "pick aaa comment", "squash bbb squash! comment");
+	}
+
+	#[test]
+	fn autosquash_without_changes() {
+		let (mut todo_file, _) = create_and_load_todo_file(&["pick aaa comment", "fixup bbb fixup! comment"]);
+		assert!(!todo_file.autosquash());
+		assert_eq!(todo_file.undo(), None);
+	}
+
+	#[test]
+	fn fix_fixup_target_later() {
+		let (mut todo_file, _) = create_and_load_todo_file(&[
+			"fixup aaa fixup! c1",
+			"pick bbb c1",
+			"squash ccc c1 again",
+			"pick ddd c2",
+		]);
+		assert_eq!(todo_file.fix_fixup_target(0), Some(2));
+		assert_todo_lines!(
+			todo_file,
+			"pick bbb c1",
+			"squash ccc c1 again",
+			"fixup aaa fixup! c1",
+			"pick ddd c2"
+		);
+		assert!(todo_file.lint().is_empty());
+		assert_eq!(todo_file.undo(), Some((0, 2)));
+		assert_eq!(todo_file.get_line(0).unwrap().to_text(), "fixup aaa fixup! c1");
+	}
+
+	#[test]
+	fn fix_fixup_target_later_at_end() {
+		let (mut todo_file, _) = create_and_load_todo_file(&["squash aaa squash! c1", "pick bbb c1"]);
+		assert_eq!(todo_file.fix_fixup_target(0), Some(1));
+		assert_todo_lines!(todo_file, "pick bbb c1", "squash aaa squash! c1");
+	}
+
+	#[test]
+	fn fix_fixup_target_dropped() {
+		let (mut todo_file, _) = create_and_load_todo_file(&["drop aaa c1", "fixup bbb fixup! c1"]);
+		assert_eq!(todo_file.fix_fixup_target(1), Some(1));
+		assert_todo_lines!(todo_file, "drop aaa c1", "pick bbb fixup! c1");
+		assert_eq!(todo_file.undo(), Some((1, 1)));
+		assert_todo_lines!(todo_file, "drop aaa c1", "fixup bbb fixup! c1");
+	}
+
+	#[test]
+	fn fix_fixup_target_without_problem() {
+		let (mut todo_file, _) = create_and_load_todo_file(&["pick aaa c1", "fixup bbb fixup! c1"]);
+		assert_eq!(todo_file.fix_fixup_target(1), None);
+		assert_eq!(todo_file.fix_fixup_target(0), None);
+		assert_eq!(todo_file.fix_fixup_target(5), None);
+	}
+
+	#[test]
+	fn get_squash_chain() {
+		let (todo_file, _) = create_and_load_todo_file(&[
+			"pick aaa c1",
+			"fixup bbb c2",
+			"squash ccc c3",
+			"pick ddd c4",
+			"fixup eee c5",
+			"exec make",
+			"squash fff c6",
+		]);
+		assert_eq!(todo_file.get_squash_chain(0), Some((0, 2)));
+		assert_eq!(todo_file.get_squash_chain(1), Some((0, 2)));
+		assert_eq!(todo_file.get_squash_chain(2), Some((0, 2)));
+		assert_eq!(todo_file.get_squash_chain(3), None);
+		assert_eq!(todo_file.get_squash_chain(4), None);
+		assert_eq!(todo_file.get_squash_chain(6), None);
+		assert_eq!(todo_file.get_squash_chain(7), None);
+	}
+
+	#[test]
+	fn set_squash_chain_message() {
+		let (mut todo_file, _) =
+			create_and_load_todo_file(&["reword aaa c1", "squash bbb c2", "fixup ccc c3", "pick ddd c4"]);
+		assert!(todo_file.set_squash_chain_message(0, "eee"));
+		assert_todo_lines!(todo_file, "pick eee c1", "fixup bbb c2", "fixup ccc c3", "pick ddd c4");
+		assert_eq!(todo_file.get_squash_chain(0), None);
+		assert_eq!(todo_file.undo(), Some((0, 2)));
+		assert_todo_lines!(todo_file, "reword aaa c1", "squash bbb c2", "fixup ccc c3", "pick ddd c4");
+	}
+
+	#[test]
+	fn set_squash_chain_message_edit() {
+		let (mut todo_file, _) = create_and_load_todo_file(&["edit aaa c1", "squash bbb c2"]);
+		assert!(todo_file.set_squash_chain_message(0, "ccc"));
+		assert_todo_lines!(todo_file, "edit ccc c1", "fixup bbb c2");
+	}
+
+	#[test]
+	fn set_squash_chain_message_removes_fixup_option() {
+		let (mut todo_file, _) = create_and_load_todo_file(&["pick aaa c1", "squash bbb c2", "fixup -C ccc c3"]);
+		assert!(todo_file.set_squash_chain_message(0, "ddd"));
+		assert_todo_lines!(todo_file, "pick ddd c1", "fixup bbb c2", "fixup ccc c3");
+	}
+
+	#[test]
+	fn cycle_fixup_option() {
+		let (mut todo_file, _) = create_and_load_todo_file(&["pick aaa c1", "fixup bbb c2"]);
+		assert!(todo_file.cycle_fixup_option(1, 1));
+		assert_todo_lines!(todo_file, "pick aaa c1", "fixup -C bbb c2");
+		assert!(todo_file.cycle_fixup_option(1, 1));
+		assert_todo_lines!(todo_file, "pick aaa c1", "fixup -c bbb c2");
+		assert!(todo_file.cycle_fixup_option(1, 1));
+		assert_todo_lines!(todo_file, "pick aaa c1", "fixup bbb c2");
+	}
+
+	#[test]
+	fn cycle_fixup_option_range() {
+		let (mut todo_file, _) =
+			create_and_load_todo_file(&["pick aaa c1", "fixup -C bbb c2", "fixup ccc c3", "pick ddd c4"]);
+		assert!(todo_file.cycle_fixup_option(3, 0));
+		assert_todo_lines!(todo_file, "pick aaa c1", "fixup -c bbb c2", "fixup -c ccc c3", "pick ddd c4");
+	}
+
+	#[test]
+	fn cycle_fixup_option_without_fixup_lines() {
+		let (mut todo_file, _) = create_and_load_todo_file(&["pick aaa c1", "squash bbb c2"]);
+		assert!(!todo_file.cycle_fixup_option(0, 1));
+		assert!(!todo_file.cycle_fixup_option(2, 3));
+		assert_todo_lines!(todo_file, "pick aaa c1", "squash bbb c2");
+	}
+
+	#[test]
+	fn cycle_fixup_option_records_history() {
+		let (mut todo_file, _) = create_and_load_todo_file(&["pick aaa c1", "fixup bbb c2"]);
+		assert!(todo_file.cycle_fixup_option(1, 1));
+		let _undo_result = todo_file.undo();
+		assert_todo_lines!(todo_file, "pick aaa c1", "fixup bbb c2");
+	}
+
+	#[test]
+	fn set_squash_chain_message_not_start_of_chain() {
+		let (mut todo_file, _) = create_and_load_todo_file(&["pick aaa c1", "squash bbb c2", "pick ccc c3"]);
+		assert!(!todo_file.set_squash_chain_message(1, "ddd"));
+		assert!(!todo_file.set_squash_chain_message(2, "ddd"));
+		assert_todo_lines!(todo_file, "pick aaa c1", "squash bbb c2", "pick ccc c3");
+	}
+
 	#[test]
 	fn swap_up() {
 		let (mut todo_file, _) =
@@ -819,4 +1974,259 @@ mod tests {
 		let (todo_file, _) = create_and_load_todo_file(&["pick aaa comment"]);
 		assert!(!todo_file.is_empty());
 	}
+
+	#[test]
+	fn load_str() {
+		let mut todo_file = TodoFile::new("git-rebase-todo", 1, "#");
+		todo_file
+			.load_str("pick aaa first\n\n# comment\ndrop bbb second")
+			.unwrap();
+		assert_todo_lines!(todo_file, "pick aaa first", "drop bbb second");
+	}
+
+	#[test]
+	fn load_str_invalid_line() {
+		let mut todo_file = TodoFile::new("git-rebase-todo", 1, "#");
+		todo_file.load_str("pick aaa first").unwrap();
+		assert_eq!(
+			todo_file.load_str("jump aaa first").unwrap_err().to_string(),
+			"Invalid action: jump"
+		);
+		assert_todo_lines!(todo_file, "pick aaa first");
+	}
+
+	#[test]
+	fn load_file_invalid_line() {
+		let todo_file_path = Builder::new().prefix("git-rebase-todo-scratch").tempfile().unwrap();
+		write!(todo_file_path.as_file(), "jump aaa first").unwrap();
+		let path = todo_file_path.path().to_str().unwrap();
+		let mut todo_file = TodoFile::new(path, 1, "#");
+		assert_eq!(
+			format!("{:#}", todo_file.load_file().unwrap_err()),
+			format!("Invalid action: jump: Error reading file: {}", path)
+		);
+	}
+
+	#[test]
+	fn reload_file_undoable() {
+		let (mut todo_file, todo_file_path) = create_and_load_todo_file(&["pick aaa c1", "pick bbb c2", "pick ccc c3"]);
+		todo_file.set_selected_line_index(1);
+		write(todo_file_path.path(), "# reordered\npick ccc c3\npick bbb c2\nexec make\n").unwrap();
+		todo_file.reload_file().unwrap();
+		assert_todo_lines!(todo_file, "pick ccc c3", "pick bbb c2", "exec make");
+		assert_eq!(todo_file.get_selected_line_index(), 1);
+		let _ = todo_file.undo();
+		assert_todo_lines!(todo_file, "pick aaa c1", "pick bbb c2", "pick ccc c3");
+	}
+
+	#[test]
+	fn reload_file_selected_commit() {
+		let (mut todo_file, todo_file_path) = create_and_load_todo_file(&["pick aaa c1", "pick bbb c2", "pick ccc c3"]);
+		todo_file.set_selected_line_index(0);
+		write(todo_file_path.path(), "pick bbb c2\npick ccc c3\nsquash aaa c1\n").unwrap();
+		todo_file.reload_file().unwrap();
+		assert_eq!(todo_file.get_selected_line_index(), 2);
+	}
+
+	#[test]
+	fn reload_file_noop() {
+		let (mut todo_file, todo_file_path) = create_and_load_todo_file(&["pick aaa c1"]);
+		write(todo_file_path.path(), "noop\n").unwrap();
+		todo_file.reload_file().unwrap();
+		assert!(todo_file.is_noop());
+		assert!(todo_file.undo().is_none());
+	}
+
+	#[test]
+	fn reload_file_invalid_line() {
+		let (mut todo_file, todo_file_path) = create_and_load_todo_file(&["pick aaa c1"]);
+		write(todo_file_path.path(), "# comment\npick aaa c1\njump bbb c2\n").unwrap();
+		let path = todo_file_path.path().to_str().unwrap();
+		assert_eq!(
+			format!("{:#}", todo_file.reload_file().unwrap_err()),
+			format!("Error on line 3: Invalid action: jump: Error reading file: {}", path)
+		);
+		assert_todo_lines!(todo_file, "pick aaa c1");
+	}
+
+	#[test]
+	fn replace_lines_undoable() {
+		let (mut todo_file, _) = create_and_load_todo_file(&["pick aaa c1", "pick bbb c2"]);
+		todo_file.set_selected_line_index(1);
+		todo_file.replace_lines(vec![Line::new("pick bbb c2").unwrap(), Line::new("drop aaa c1").unwrap()]);
+		assert_todo_lines!(todo_file, "pick bbb c2", "drop aaa c1");
+		assert_eq!(todo_file.get_selected_line_index(), 0);
+		let _ = todo_file.undo();
+		assert_todo_lines!(todo_file, "pick aaa c1", "pick bbb c2");
+	}
+
+	#[test]
+	fn merge_lines() {
+		let (mut todo_file, _) = create_and_load_todo_file(&["pick aaa c1", "pick bbb c2", "pick ccc c3"]);
+		let base = todo_file.get_lines_owned();
+		todo_file.update_range(0, 0, &EditContext::new().action(Action::Drop));
+		let other = vec![
+			Line::new("pick aaa c1").unwrap(),
+			Line::new("pick bbb c2").unwrap(),
+			Line::new("fixup ccc c3").unwrap(),
+		];
+		let merged = todo_file.merge_lines(base.as_slice(), other.as_slice()).unwrap();
+		todo_file.set_lines(merged);
+		assert_todo_lines!(todo_file, "drop aaa c1", "pick bbb c2", "fixup ccc c3");
+	}
+
+	#[test]
+	fn merge_lines_conflict() {
+		let (mut todo_file, _) = create_and_load_todo_file(&["pick aaa c1", "pick bbb c2"]);
+		let base = todo_file.get_lines_owned();
+		todo_file.update_range(0, 0, &EditContext::new().action(Action::Drop));
+		let other = vec![Line::new("edit aaa c1").unwrap(), Line::new("pick bbb c2").unwrap()];
+		assert!(todo_file.merge_lines(base.as_slice(), other.as_slice()).is_none());
+	}
+
+	#[test]
+	fn to_text() {
+		let (todo_file, _) = create_and_load_todo_file(&["pick aaa comment", "exec echo foo"]);
+		assert_eq!(todo_file.to_text().unwrap(), "pick aaa comment\nexec echo foo\n");
+	}
+
+	#[test]
+	fn load_str_group_names() {
+		let mut todo_file = TodoFile::new("git-rebase-todo", 1, "#");
+		todo_file
+			.load_str(
+				"pick aaa c1\n# group: second half\nbreak\n# group: not a group\npick bbb c2\n# group:\nlabel onto\n",
+			)
+			.unwrap();
+		assert_todo_lines!(todo_file, "pick aaa c1", "break", "pick bbb c2", "label onto");
+		assert_eq!(todo_file.get_line(1).unwrap().get_group_name(), Some("second half"));
+		assert_eq!(todo_file.get_line(3).unwrap().get_group_name(), None);
+	}
+
+	#[test]
+	fn load_str_group_name_not_directly_before() {
+		let mut todo_file = TodoFile::new("git-rebase-todo", 1, ";");
+		todo_file.load_str("; group: tests\n; a comment\nbreak\n").unwrap();
+		assert_eq!(todo_file.get_line(0).unwrap().get_group_name(), None);
+	}
+
+	#[test]
+	fn to_text_group_names() {
+		let mut todo_file = TodoFile::new("git-rebase-todo", 1, ";");
+		todo_file.load_str("pick aaa c1\n; group: tests\nbreak\n").unwrap();
+		assert_eq!(todo_file.to_text().unwrap(), "pick aaa c1\n; group: tests\nbreak\n");
+	}
+
+	#[rstest]
+	#[case::first_group(0, (0, 1))]
+	#[case::group_start(2, (2, 3))]
+	#[case::group_end(3, (2, 3))]
+	#[case::last_group(5, (4, 5))]
+	fn get_group_range(#[case] index: usize, #[case] expected: (usize, usize)) {
+		let (todo_file, _) = create_and_load_todo_file(&[
+			"pick aaa c1",
+			"pick bbb c2",
+			"break",
+			"pick ccc c3",
+			"label feature",
+			"pick ddd c4",
+		]);
+		assert_eq!(todo_file.get_group_range(index), Some(expected));
+	}
+
+	#[test]
+	fn get_group_range_out_of_range() {
+		let (todo_file, _) = create_and_load_todo_file(&["pick aaa c1"]);
+		assert_eq!(todo_file.get_group_range(1), None);
+	}
+
+	#[test]
+	fn set_group_name() {
+		let (mut todo_file, _) = create_and_load_todo_file(&["pick aaa c1", "break"]);
+		assert!(todo_file.set_group_name(1, Some("tests")));
+		assert_eq!(todo_file.get_line(1).unwrap().get_group_name(), Some("tests"));
+		assert!(!todo_file.set_group_name(1, Some("tests")));
+		assert_eq!(todo_file.undo(), Some((1, 1)));
+		assert_eq!(todo_file.get_line(1).unwrap().get_group_name(), None);
+	}
+
+	#[test]
+	fn set_group_name_not_group_start() {
+		let (mut todo_file, _) = create_and_load_todo_file(&["pick aaa c1"]);
+		assert!(!todo_file.set_group_name(0, Some("tests")));
+		assert!(!todo_file.set_group_name(1, Some("tests")));
+		assert!(todo_file.undo().is_none());
+	}
+
+	#[test]
+	fn to_text_noop() {
+		let (todo_file, _) = create_and_load_todo_file(&["noop"]);
+		assert_eq!(todo_file.to_text().unwrap(), "noop\n");
+	}
+
+	#[test]
+	fn load_str_stgit() {
+		let mut todo_file = TodoFile::new("git-rebase-todo", 1, "#");
+		todo_file
+			.load_str("# Commands:\nkeep 00 first\nkeep 01 second\n# --- APPLY_LINE ---\nkeep 02 third\n")
+			.unwrap();
+		assert_eq!(todo_file.get_format(), Format::StGit);
+		assert_todo_lines!(todo_file, "pick first", "pick second", "break", "pick third");
+	}
+
+	#[test]
+	fn write_file_stgit() {
+		let (mut todo_file, _) = create_and_load_todo_file(&["keep 00 first", "keep 01 second"]);
+		todo_file.update_range(1, 1, &EditContext::new().action(Action::Fixup));
+		todo_file.write_file().unwrap();
+		assert_read_todo_file!(todo_file.get_filepath(), "keep first", "fix second");
+	}
+
+	#[test]
+	fn write_file_stgit_unsupported_action() {
+		let (mut todo_file, _todo_file_path) = create_and_load_todo_file(&["keep 00 first"]);
+		todo_file.add_line(1, Line::new_exec("make"));
+		assert_eq!(
+			todo_file.write_file().unwrap_err().to_string(),
+			"The exec action is not supported by Stacked Git"
+		);
+		assert_eq!(read_to_string(todo_file.get_filepath()).unwrap(), "keep 00 first");
+	}
+
+	#[test]
+	fn validate_valid() {
+		let (todo_file, _) = create_and_load_todo_file(&["exec echo foo", "pick aaa comment", "fixup bbb comment"]);
+		assert!(todo_file.validate().is_ok());
+	}
+
+	#[test]
+	fn validate_empty() {
+		let (todo_file, _) = create_and_load_todo_file(&[]);
+		assert!(todo_file.validate().is_ok());
+	}
+
+	#[test]
+	fn validate_first_commit_squash() {
+		let (todo_file, _) = create_and_load_todo_file(&["drop aaa comment", "squash bbb comment", "pick ccc comment"]);
+		assert_eq!(todo_file.validate().unwrap_err(), ValidationError::NoPreviousCommit {
+			index: 1,
+			hash: String::from("bbb"),
+			action: Action::Squash
+		});
+	}
+
+	#[test]
+	fn lint() {
+		let (todo_file, _) = create_and_load_todo_file(&["label onto", "pick aaa comment", "reset missing"]);
+		assert_eq!(todo_file.lint(), vec![
+			Lint::UnusedLabel {
+				index: 0,
+				label: String::from("onto")
+			},
+			Lint::UndefinedLabel {
+				index: 2,
+				label: String::from("missing")
+			},
+		]);
+	}
 }
@@ -2,13 +2,20 @@ use anyhow::{anyhow, Result};
 
 use super::action::Action;
 
+// The options of a fixup line, in the order they are cycled, and of the commit of a merge line. With `-C` the message
+// of the commit is used, for the combined commit of a fixup, instead of the message of the commit it fixes, or for the
+// merge commit, and with `-c` the message is also opened in the editor.
+const MESSAGE_OPTIONS: [&str; 2] = ["-C", "-c"];
+
 /// Represents a line in the rebase file.
 #[derive(Clone, Debug, PartialEq)]
 pub struct Line {
 	action: Action,
 	content: String,
+	group_name: Option<String>,
 	hash: String,
 	mutated: bool,
+	option: Option<String>,
 }
 
 impl Line {
@@ -20,6 +27,8 @@ impl Line {
 			content: String::from(""),
 			hash: String::from(""),
 			mutated: false,
+			option: None,
+			group_name: None,
 		}
 	}
 
@@ -31,6 +40,8 @@ impl Line {
 			content: String::from(""),
 			hash: String::from(hash),
 			mutated: false,
+			option: None,
+			group_name: None,
 		}
 	}
 
@@ -42,6 +53,8 @@ impl Line {
 			content: String::from(""),
 			hash: String::from(""),
 			mutated: false,
+			option: None,
+			group_name: None,
 		}
 	}
 
@@ -53,6 +66,8 @@ impl Line {
 			content: String::from(command),
 			hash: String::from(""),
 			mutated: false,
+			option: None,
+			group_name: None,
 		}
 	}
 
@@ -64,6 +79,8 @@ impl Line {
 			content: String::from(command),
 			hash: String::from(""),
 			mutated: false,
+			option: None,
+			group_name: None,
 		}
 	}
 
@@ -75,6 +92,8 @@ impl Line {
 			content: String::from(label),
 			hash: String::from(""),
 			mutated: false,
+			option: None,
+			group_name: None,
 		}
 	}
 
@@ -86,6 +105,8 @@ impl Line {
 			content: String::from(label),
 			hash: String::from(""),
 			mutated: false,
+			option: None,
+			group_name: None,
 		}
 	}
 
@@ -113,34 +134,92 @@ impl Line {
 					hash: String::from(""),
 					content: String::from(input[1]),
 					mutated: false,
+					option: None,
+					group_name: None,
 				});
 			}
 		}
 		else {
 			let input: Vec<&str> = input_line.splitn(3, ' ').collect();
 			if input.len() >= 2 {
-				return Ok(Self {
-					action: Action::try_from(input[0])?,
-					hash: String::from(input[1]),
-					content: if input.len() == 3 {
-						String::from(input[2])
+				let action = Action::try_from(input[0])?;
+				// the option of a fixup line is before the hash of the commit
+				if action == Action::Fixup && MESSAGE_OPTIONS.contains(&input[1]) {
+					let rest: Vec<&str> = input.get(2).map_or(vec![], |rest| rest.splitn(2, ' ').collect());
+					if let Some(hash) = rest.first().filter(|hash| !hash.is_empty()) {
+						return Ok(Self {
+							action,
+							hash: String::from(*hash),
+							content: String::from(rest.get(1).copied().unwrap_or("")),
+							mutated: false,
+							option: Some(String::from(input[1])),
+							group_name: None,
+						});
 					}
-					else {
-						String::from("")
-					},
-					mutated: false,
-				});
+				}
+				else {
+					return Ok(Self {
+						action,
+						hash: String::from(input[1]),
+						content: if input.len() == 3 {
+							String::from(input[2])
+						}
+						else {
+							String::from("")
+						},
+						mutated: false,
+						option: None,
+						group_name: None,
+					});
+				}
 			}
 		}
 
 		Err(anyhow!("Invalid line: {}", input_line))
 	}
 
-	/// Set the action of the line.
+	// a copy of the line, for another commit
+	pub(crate) fn with_hash(&self, hash: &str) -> Self {
+		Self {
+			hash: String::from(hash),
+			mutated: true,
+			..self.clone()
+		}
+	}
+
+	/// Set the action of the line. The option of a fixup line is removed when the action is changed.
 	pub fn set_action(&mut self, action: Action) {
 		if !self.action.is_static() && self.action != action {
 			self.mutated = true;
 			self.action = action;
+			self.option = None;
+		}
+	}
+
+	/// Set the option of a fixup line, to `-C` or `-c`, or remove the option with `None`. The option of any other
+	/// line, and an unknown option, is ignored.
+	pub fn set_option(&mut self, option: Option<&str>) {
+		if self.action == Action::Fixup
+			&& option.map_or(true, |option| MESSAGE_OPTIONS.contains(&option))
+			&& self.option.as_deref() != option
+		{
+			self.mutated = true;
+			self.option = option.map(String::from);
+		}
+	}
+
+	/// Get the option of the line that follows the option of the line when the options of a fixup line are cycled, from
+	/// no option, to `-C`, to `-c`, and back to no option.
+	#[must_use]
+	pub fn get_next_option(&self) -> Option<&'static str> {
+		match self.option.as_deref() {
+			None => MESSAGE_OPTIONS.first().copied(),
+			Some(option) => {
+				MESSAGE_OPTIONS
+					.iter()
+					.position(|&fixup_option| fixup_option == option)
+					.and_then(|index| MESSAGE_OPTIONS.get(index + 1).copied())
+			},
 		}
 	}
 
@@ -163,6 +242,34 @@ impl Line {
 		self.content.as_str()
 	}
 
+	/// Get the option of the line, which is the `-C` or `-c` option of a fixup line.
+	#[must_use]
+	pub fn get_option(&self) -> Option<&str> {
+		self.option.as_deref()
+	}
+
+	/// Get the name of the group of lines that the line starts, which is stored in the rebase file as a comment before
+	/// the line.
+	#[must_use]
+	pub fn get_group_name(&self) -> Option<&str> {
+		self.group_name.as_deref()
+	}
+
+	/// Set the name of the group of lines that the line starts, or remove the name with `None` or an empty name. The
+	/// name of a line that does not start a group is ignored.
+	pub fn set_group_name(&mut self, name: Option<&str>) {
+		if self.is_group_start() {
+			self.group_name = name.map(str::trim).filter(|name| !name.is_empty()).map(String::from);
+		}
+	}
+
+	/// Does this line start a group of lines, the lines between break and label lines, which are shown separated, and
+	/// can be named and collapsed.
+	#[must_use]
+	pub const fn is_group_start(&self) -> bool {
+		matches!(self.action, Action::Break | Action::Label)
+	}
+
 	/// Get the commit hash for the line.
 	#[must_use]
 	pub fn get_hash(&self) -> &str {
@@ -175,6 +282,101 @@ impl Line {
 		!self.hash.is_empty()
 	}
 
+	/// Get the labels that the line refers to. This is the label that is created by a label line, the label or commit
+	/// that a reset line resets to, and the labels that are merged by a merge line, but not the commit that the message
+	/// of a merge is taken from. Lines with any other action do not refer to any labels.
+	#[must_use]
+	pub fn get_labels(&self) -> Vec<&str> {
+		// anything after a `#` is a description of the line, such as the summary of the commit
+		let mut words = self
+			.content
+			.split_whitespace()
+			.take_while(|word| !word.starts_with('#'));
+		match self.action {
+			Action::Label | Action::Reset => words.next().into_iter().collect(),
+			Action::Merge => {
+				let mut labels = vec![];
+				while let Some(word) = words.next() {
+					if word == "-C" || word == "-c" {
+						let _commit = words.next();
+					}
+					else {
+						labels.push(word);
+					}
+				}
+				labels
+			},
+			Action::Break
+			| Action::Drop
+			| Action::Edit
+			| Action::Exec
+			| Action::Fixup
+			| Action::Noop
+			| Action::Pick
+			| Action::Reword
+			| Action::Squash => vec![],
+		}
+	}
+
+	/// Get the `-C` or `-c` option of a merge line, with the commit that the message of the merge is taken from.
+	#[must_use]
+	pub fn get_merge_message_commit(&self) -> Option<(&str, &str)> {
+		if self.action != Action::Merge {
+			return None;
+		}
+		let mut words = self.content.split_whitespace();
+		let option = words.next().filter(|option| MESSAGE_OPTIONS.contains(option))?;
+		words.next().map(|commit| (option, commit))
+	}
+
+	/// Create a copy of a merge line, with the `-C` or `-c` option and the commit that the message of the merge is
+	/// taken from, or without a commit for the default message, and with the labels or commits that are merged. The
+	/// description of the merge, after a `#`, is kept.
+	///
+	/// # Errors
+	///
+	/// Returns an error if the line is not a merge line, if there are no labels, or if the option, the commit or a
+	/// label is not valid.
+	pub fn with_merge_arguments(&self, message_commit: Option<(&str, &str)>, labels: &[&str]) -> Result<Self> {
+		// a label or commit that starts with a `-` would be read as an option, and one with a `#` as the description
+		let is_valid_name = |name: &str| {
+			!name.is_empty() && !name.starts_with(['-', '#']) && !name.contains(char::is_whitespace)
+		};
+		if self.action != Action::Merge {
+			return Err(anyhow!("Not a merge line: {}", self.to_text()));
+		}
+		if labels.is_empty() {
+			return Err(anyhow!("A merge needs a label or commit to merge"));
+		}
+		if let Some(label) = labels.iter().find(|label| !is_valid_name(label)) {
+			return Err(anyhow!("Invalid label: {}", label));
+		}
+		let mut arguments = vec![];
+		if let Some((option, commit)) = message_commit {
+			if !MESSAGE_OPTIONS.contains(&option) {
+				return Err(anyhow!("Invalid option: {}, expected -C or -c", option));
+			}
+			if !is_valid_name(commit) {
+				return Err(anyhow!("Invalid commit: {}", commit));
+			}
+			arguments.push(option);
+			arguments.push(commit);
+		}
+		arguments.extend(labels);
+		let content = self.content.as_str();
+		let description = content
+			.match_indices('#')
+			.map(|(index, _)| index)
+			.find(|&index| index == 0 || content[..index].ends_with(char::is_whitespace))
+			.map(|index| &content[index..]);
+		arguments.extend(description);
+		Ok(Self {
+			content: arguments.join(" "),
+			mutated: true,
+			..self.clone()
+		})
+	}
+
 	/// Can this line be edited.
 	#[must_use]
 	pub const fn is_editable(&self) -> bool {
@@ -195,6 +397,15 @@ impl Line {
 	#[must_use]
 	pub fn to_text(&self) -> String {
 		match self.action {
+			Action::Fixup if self.option.is_some() => {
+				format!(
+					"{} {} {} {}",
+					self.action.as_string(),
+					self.option.as_deref().unwrap_or_default(),
+					self.hash,
+					self.content
+				)
+			},
 			Action::Drop | Action::Edit | Action::Fixup | Action::Pick | Action::Reword | Action::Squash => {
 				format!("{} {} {}", self.action.as_string(), self.hash, self.content)
 			},
@@ -218,78 +429,120 @@ mod tests {
 		hash: String::from("aaa"),
 		content: String::from("comment"),
 		mutated: false,
+		option: None,
+		group_name: None,
 	})]
 	#[case::reword_action("reword aaa comment", &Line {
 		action: Action::Reword,
 		hash: String::from("aaa"),
 		content: String::from("comment"),
 		mutated: false,
+		option: None,
+		group_name: None,
 	})]
 	#[case::edit_action("edit aaa comment", &Line {
 		action: Action::Edit,
 		hash: String::from("aaa"),
 		content: String::from("comment"),
 		mutated: false,
+		option: None,
+		group_name: None,
 	})]
 	#[case::squash_action("squash aaa comment", &Line {
 		action: Action::Squash,
 		hash: String::from("aaa"),
 		content: String::from("comment"),
 		mutated: false,
+		option: None,
+		group_name: None,
 	})]
 	#[case::fixup_action("fixup aaa comment", &Line {
 		action: Action::Fixup,
 		hash: String::from("aaa"),
 		content: String::from("comment"),
 		mutated: false,
+		option: None,
+		group_name: None,
+	})]
+	#[case::fixup_use_message_action("fixup -C aaa comment", &Line {
+		action: Action::Fixup,
+		hash: String::from("aaa"),
+		content: String::from("comment"),
+		mutated: false,
+		option: Some(String::from("-C")),
+		group_name: None,
+	})]
+	#[case::fixup_edit_message_action("f -c aaa", &Line {
+		action: Action::Fixup,
+		hash: String::from("aaa"),
+		content: String::from(""),
+		mutated: false,
+		option: Some(String::from("-c")),
+		group_name: None,
 	})]
 	#[case::drop_action("drop aaa comment", &Line {
 		action: Action::Drop,
 		hash: String::from("aaa"),
 		content: String::from("comment"),
 		mutated: false,
+		option: None,
+		group_name: None,
 	})]
 	#[case::action_without_comment("pick aaa", &Line {
 		action: Action::Pick,
 		hash: String::from("aaa"),
 		content: String::from(""),
 		mutated: false,
+		option: None,
+		group_name: None,
 	})]
 	#[case::exec_action("exec command", &Line {
 		action: Action::Exec,
 		hash: String::from(""),
 		content: String::from("command"),
 		mutated: false,
+		option: None,
+		group_name: None,
 	})]
 	#[case::label_action("label ref", &Line {
 		action: Action::Label,
 		hash: String::from(""),
 		content: String::from("ref"),
 		mutated: false,
+		option: None,
+		group_name: None,
 	})]
 	#[case::reset_action("reset ref", &Line {
 		action: Action::Reset,
 		hash: String::from(""),
 		content: String::from("ref"),
 		mutated: false,
+		option: None,
+		group_name: None,
 	})]
 	#[case::reset_action("merge command", &Line {
 		action: Action::Merge,
 		hash: String::from(""),
 		content: String::from("command"),
 		mutated: false,
+		option: None,
+		group_name: None,
 	})]
 	#[case::break_action("break", &Line {
 		action: Action::Break,
 		hash: String::from(""),
 		content: String::from(""),
 		mutated: false,
+		option: None,
+		group_name: None,
 	})]
 	#[case::nnop( "noop", &Line {
 		action: Action::Noop,
 		hash: String::from(""),
 		content: String::from(""),
 		mutated: false,
+		option: None,
+		group_name: None,
 	})]
 	fn new(#[case] line: &str, #[case] expected: &Line) {
 		assert_eq!(&Line::new(line).unwrap(), expected);
@@ -302,6 +555,8 @@ mod tests {
 			hash: String::from("abc123"),
 			content: String::from(""),
 			mutated: false,
+			option: None,
+			group_name: None,
 		});
 	}
 
@@ -312,6 +567,8 @@ mod tests {
 			hash: String::from(""),
 			content: String::from(""),
 			mutated: false,
+			option: None,
+			group_name: None,
 		});
 	}
 
@@ -322,6 +579,8 @@ mod tests {
 			hash: String::from(""),
 			content: String::from("command"),
 			mutated: false,
+			option: None,
+			group_name: None,
 		});
 	}
 
@@ -332,6 +591,8 @@ mod tests {
 			hash: String::from(""),
 			content: String::from("command"),
 			mutated: false,
+			option: None,
+			group_name: None,
 		});
 	}
 
@@ -342,6 +603,8 @@ mod tests {
 			hash: String::from(""),
 			content: String::from("label"),
 			mutated: false,
+			option: None,
+			group_name: None,
 		});
 	}
 
@@ -352,6 +615,8 @@ mod tests {
 			hash: String::from(""),
 			content: String::from("label"),
 			mutated: false,
+			option: None,
+			group_name: None,
 		});
 	}
 
@@ -363,6 +628,8 @@ mod tests {
 	#[case::edit_line_only("edit", "Invalid line: edit")]
 	#[case::squash_line_only("squash", "Invalid line: squash")]
 	#[case::fixup_line_only("fixup", "Invalid line: fixup")]
+	#[case::fixup_option_only("fixup -C", "Invalid line: fixup -C")]
+	#[case::fixup_option_without_hash("fixup -c ", "Invalid line: fixup -c ")]
 	#[case::exec_line_only("exec", "Invalid line: exec")]
 	#[case::drop_line_only("drop", "Invalid line: drop")]
 	#[case::label_line_only("label", "Invalid line: label")]
@@ -489,6 +756,20 @@ mod tests {
 		assert_eq!(Line::new(line).unwrap().has_reference(), expected);
 	}
 
+	#[rstest]
+	#[case::label("label onto", &["onto"])]
+	#[case::reset("reset onto", &["onto"])]
+	#[case::reset_commit("reset aaa # comment", &["aaa"])]
+	#[case::merge("merge feature", &["feature"])]
+	#[case::merge_with_message("merge -C aaa feature # Merge feature", &["feature"])]
+	#[case::merge_with_edited_message("merge -c aaa feature", &["feature"])]
+	#[case::octopus_merge("merge -C aaa one two # Merge one and two", &["one", "two"])]
+	#[case::pick("pick aaa comment", &[])]
+	#[case::exec("exec git label", &[])]
+	fn get_labels(#[case] line: &str, #[case] expected: &[&str]) {
+		assert_eq!(Line::new(line).unwrap().get_labels(), expected);
+	}
+
 	#[rstest]
 	#[case::drop(Action::Break, false)]
 	#[case::drop(Action::Drop, false)]
@@ -513,10 +794,124 @@ mod tests {
 	#[case::edit("edit aaa comment")]
 	#[case::exec("exec git commit --amend 'foo'")]
 	#[case::fixup("fixup aaa comment")]
+	#[case::fixup_use_message("fixup -C aaa comment")]
+	#[case::fixup_edit_message("fixup -c aaa comment")]
 	#[case::pick("pick aaa comment")]
 	#[case::reword("reword aaa comment")]
 	#[case::squash("squash aaa comment")]
 	fn to_text(#[case] line: &str) {
 		assert_eq!(Line::new(line).unwrap().to_text(), line);
 	}
+
+	#[rstest]
+	#[case::fixup("fixup aaa comment", Some("-C"), Some("-C"))]
+	#[case::fixup_remove("fixup -c aaa comment", None, None)]
+	#[case::fixup_unknown("fixup aaa comment", Some("-x"), None)]
+	#[case::pick("pick aaa comment", Some("-C"), None)]
+	fn set_option(#[case] line: &str, #[case] option: Option<&str>, #[case] expected: Option<&str>) {
+		let mut line = Line::new(line).unwrap();
+		line.set_option(option);
+		assert_eq!(line.get_option(), expected);
+	}
+
+	#[test]
+	fn set_option_mutated() {
+		let mut line = Line::new("fixup aaa comment").unwrap();
+		line.set_option(None);
+		assert!(!line.mutated);
+		line.set_option(Some("-C"));
+		assert!(line.mutated);
+	}
+
+	#[test]
+	fn set_action_removes_option() {
+		let mut line = Line::new("fixup -C aaa comment").unwrap();
+		line.set_action(Action::Pick);
+		line.set_action(Action::Fixup);
+		assert_eq!(line.to_text(), "fixup aaa comment");
+	}
+
+	#[rstest]
+	#[case::break_action("break", Some("  first half "), Some("first half"))]
+	#[case::label("label onto", Some("base"), Some("base"))]
+	#[case::empty("break", Some(" "), None)]
+	#[case::remove("break", None, None)]
+	#[case::pick("pick aaa comment", Some("first half"), None)]
+	fn set_group_name(#[case] line: &str, #[case] name: Option<&str>, #[case] expected: Option<&str>) {
+		let mut line = Line::new(line).unwrap();
+		line.set_group_name(name);
+		assert_eq!(line.get_group_name(), expected);
+	}
+
+	#[rstest]
+	#[case::break_action("break", true)]
+	#[case::label("label onto", true)]
+	#[case::reset("reset onto", false)]
+	#[case::pick("pick aaa comment", false)]
+	fn is_group_start(#[case] line: &str, #[case] expected: bool) {
+		assert_eq!(Line::new(line).unwrap().is_group_start(), expected);
+	}
+
+	#[rstest]
+	#[case::no_option("fixup aaa comment", Some("-C"))]
+	#[case::use_message("fixup -C aaa comment", Some("-c"))]
+	#[case::edit_message("fixup -c aaa comment", None)]
+	fn get_next_option(#[case] line: &str, #[case] expected: Option<&str>) {
+		assert_eq!(Line::new(line).unwrap().get_next_option(), expected);
+	}
+
+	#[rstest]
+	#[case::use_message("merge -C aaa feature # Merge feature", Some(("-C", "aaa")))]
+	#[case::edit_message("merge -c aaa feature", Some(("-c", "aaa")))]
+	#[case::default_message("merge feature", None)]
+	#[case::option_without_commit("merge -C", None)]
+	#[case::pick("pick aaa -C", None)]
+	fn get_merge_message_commit(#[case] line: &str, #[case] expected: Option<(&str, &str)>) {
+		assert_eq!(Line::new(line).unwrap().get_merge_message_commit(), expected);
+	}
+
+	#[rstest]
+	#[case::labels("merge one", None, &["two", "three"], "merge two three")]
+	#[case::add_commit("merge one", Some(("-C", "aaa")), &["one"], "merge -C aaa one")]
+	#[case::change_commit("merge -C aaa one", Some(("-c", "bbb")), &["one"], "merge -c bbb one")]
+	#[case::remove_commit("merge -C aaa one", None, &["one"], "merge one")]
+	#[case::description("merge -C aaa one # Merge one", None, &["two"], "merge two # Merge one")]
+	#[case::description_without_space("merge one #1", None, &["two"], "merge two #1")]
+	#[case::hash_in_label("merge one#1", None, &["two"], "merge two")]
+	fn with_merge_arguments(
+		#[case] line: &str,
+		#[case] message_commit: Option<(&str, &str)>,
+		#[case] labels: &[&str],
+		#[case] expected: &str,
+	) {
+		let line = Line::new(line)
+			.unwrap()
+			.with_merge_arguments(message_commit, labels)
+			.unwrap();
+		assert_eq!(line.to_text(), expected);
+		assert!(line.mutated);
+	}
+
+	#[rstest]
+	#[case::not_merge("pick aaa one", None, &["one"], "Not a merge line: pick aaa one")]
+	#[case::no_labels("merge one", None, &[], "A merge needs a label or commit to merge")]
+	#[case::option_label("merge one", None, &["-C"], "Invalid label: -C")]
+	#[case::description_label("merge one", None, &["#one"], "Invalid label: #one")]
+	#[case::invalid_option("merge one", Some(("-m", "aaa")), &["one"], "Invalid option: -m, expected -C or -c")]
+	#[case::invalid_commit("merge one", Some(("-C", "")), &["one"], "Invalid commit: ")]
+	fn with_merge_arguments_error(
+		#[case] line: &str,
+		#[case] message_commit: Option<(&str, &str)>,
+		#[case] labels: &[&str],
+		#[case] expected: &str,
+	) {
+		assert_eq!(
+			Line::new(line)
+				.unwrap()
+				.with_merge_arguments(message_commit, labels)
+				.unwrap_err()
+				.to_string(),
+			expected
+		);
+	}
 }
@@ -0,0 +1,371 @@
+use std::{
+	collections::HashMap,
+	fmt::{Display, Formatter},
+};
+
+use super::{autosquash::find_fixup_target, Action, Line};
+
+/// A problem with the labels of a rebase with merges, or with the order of the fixup and squash commits.
+///
+/// Unlike a [`ValidationError`](super::ValidationError), Git will start the rebase, but the rebase will stop when it
+/// reaches a reset or merge line that refers to a label that does not exist, and a label that is created twice or is
+/// never used, or a fixup commit that is not applied to the commit that it fixes, is likely a mistake.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Lint {
+	/// A reset or merge line refers to a label that is not created by any label line.
+	UndefinedLabel {
+		/// The index of the reset or merge line.
+		index: usize,
+		/// The label.
+		label: String,
+	},
+	/// A reset or merge line refers to a label that is only created by a later label line.
+	LabelDefinedLater {
+		/// The index of the reset or merge line.
+		index: usize,
+		/// The label.
+		label: String,
+		/// The index of the label line that creates the label.
+		definition_index: usize,
+	},
+	/// A label line creates a label that is already created by an earlier label line.
+	DuplicateLabel {
+		/// The index of the label line.
+		index: usize,
+		/// The label.
+		label: String,
+		/// The index of the label line that first creates the label.
+		first_index: usize,
+	},
+	/// A label line creates a label that no reset or merge line refers to.
+	UnusedLabel {
+		/// The index of the label line.
+		index: usize,
+		/// The label.
+		label: String,
+	},
+	/// A fixup or squash line, with a `fixup!`, `squash!` or `amend!` subject, fixes a commit that is dropped.
+	FixupTargetDropped {
+		/// The index of the fixup or squash line.
+		index: usize,
+		/// The index of the dropped line of the commit that is fixed.
+		target_index: usize,
+	},
+	/// A fixup or squash line, with a `fixup!`, `squash!` or `amend!` subject, fixes a commit that is only picked by a
+	/// later line, so the changes are applied to an earlier commit.
+	FixupTargetLater {
+		/// The index of the fixup or squash line.
+		index: usize,
+		/// The index of the later line of the commit that is fixed.
+		target_index: usize,
+	},
+}
+
+impl Lint {
+	/// Get the index of the line with the problem.
+	#[must_use]
+	#[inline]
+	pub const fn index(&self) -> usize {
+		match *self {
+			Self::UndefinedLabel { index, .. }
+			| Self::LabelDefinedLater { index, .. }
+			| Self::DuplicateLabel { index, .. }
+			| Self::UnusedLabel { index, .. }
+			| Self::FixupTargetDropped { index, .. }
+			| Self::FixupTargetLater { index, .. } => index,
+		}
+	}
+}
+
+impl Display for Lint {
+	#[inline]
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		match *self {
+			Self::UndefinedLabel { ref label, .. } => write!(f, "The label {} is not created by any line", label),
+			Self::LabelDefinedLater {
+				ref label,
+				definition_index,
+				..
+			} => {
+				write!(
+					f,
+					"The label {} is not created until line {}",
+					label,
+					definition_index + 1
+				)
+			},
+			Self::DuplicateLabel {
+				ref label, first_index, ..
+			} => write!(f, "The label {} is already created on line {}", label, first_index + 1),
+			Self::UnusedLabel { ref label, .. } => write!(f, "The label {} is not used by any line", label),
+			Self::FixupTargetDropped { target_index, .. } => {
+				write!(f, "The commit that this commit fixes is dropped on line {}", target_index + 1)
+			},
+			Self::FixupTargetLater { target_index, .. } => {
+				write!(f, "The commit that this commit fixes is not picked until line {}", target_index + 1)
+			},
+		}
+	}
+}
+
+// A reset or merge can refer to a commit, rather than a label, which Git writes as the hash of the commit, or as
+// `[new root]` for a reset to a new root commit.
+fn is_commit(reference: &str) -> bool {
+	reference.starts_with('[') || (reference.len() >= 4 && reference.chars().all(|c| c.is_ascii_hexdigit()))
+}
+
+pub(crate) fn lint_lines(lines: &[Line]) -> Vec<Lint> {
+	let mut definitions: HashMap<&str, usize> = HashMap::new();
+	let mut lints = vec![];
+	for (index, line) in lines.iter().enumerate() {
+		if *line.get_action() != Action::Label {
+			continue;
+		}
+		if let Some(&label) = line.get_labels().first() {
+			if let Some(&first_index) = definitions.get(label) {
+				lints.push(Lint::DuplicateLabel {
+					index,
+					label: String::from(label),
+					first_index,
+				});
+			}
+			else {
+				let _previous = definitions.insert(label, index);
+			}
+		}
+	}
+
+	let mut used_labels = vec![];
+	for (index, line) in lines.iter().enumerate() {
+		if !matches!(*line.get_action(), Action::Merge | Action::Reset) {
+			continue;
+		}
+		for label in line.get_labels() {
+			match definitions.get(label) {
+				Some(&definition_index) if definition_index > index => {
+					lints.push(Lint::LabelDefinedLater {
+						index,
+						label: String::from(label),
+						definition_index,
+					});
+				},
+				Some(_) => {},
+				None if is_commit(label) => {},
+				None => {
+					lints.push(Lint::UndefinedLabel {
+						index,
+						label: String::from(label),
+					});
+				},
+			}
+			used_labels.push(label);
+		}
+	}
+
+	for (&label, &index) in &definitions {
+		if !used_labels.contains(&label) {
+			lints.push(Lint::UnusedLabel {
+				index,
+				label: String::from(label),
+			});
+		}
+	}
+
+	for index in 0..lines.len() {
+		match find_fixup_target(lines, index) {
+			Some(target_index) if target_index > index => {
+				lints.push(Lint::FixupTargetLater { index, target_index });
+			},
+			Some(target_index) if *lines[target_index].get_action() == Action::Drop => {
+				lints.push(Lint::FixupTargetDropped { index, target_index });
+			},
+			_ => {},
+		}
+	}
+	lints.sort_by_key(Lint::index);
+	lints
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn lint(lines: &[&str]) -> Vec<Lint> {
+		lint_lines(
+			lines
+				.iter()
+				.map(|line| Line::new(line).unwrap())
+				.collect::<Vec<Line>>()
+				.as_slice(),
+		)
+	}
+
+	#[test]
+	fn valid() {
+		assert!(lint(&[
+			"label onto",
+			"reset onto",
+			"pick aaa side",
+			"label side",
+			"reset onto",
+			"pick bbb main",
+			"merge -C ccc side # Merge side",
+		])
+		.is_empty());
+	}
+
+	#[test]
+	fn without_labels() {
+		assert!(lint(&["pick aaa c1", "exec make", "break"]).is_empty());
+	}
+
+	#[test]
+	fn reset_to_commit() {
+		assert!(lint(&["reset [new root]", "pick aaa c1", "reset abc123 # c0", "merge -C bbb def456"]).is_empty());
+	}
+
+	#[test]
+	fn undefined_label() {
+		assert_eq!(lint(&["pick aaa c1", "reset missing", "merge -C bbb other"]), vec![
+			Lint::UndefinedLabel {
+				index: 1,
+				label: String::from("missing"),
+			},
+			Lint::UndefinedLabel {
+				index: 2,
+				label: String::from("other"),
+			},
+		]);
+	}
+
+	#[test]
+	fn label_defined_later() {
+		assert_eq!(
+			lint(&["label onto", "reset onto", "merge -C aaa side", "pick bbb c1", "label side"]),
+			vec![Lint::LabelDefinedLater {
+				index: 2,
+				label: String::from("side"),
+				definition_index: 4,
+			}]
+		);
+	}
+
+	#[test]
+	fn duplicate_label() {
+		assert_eq!(lint(&["label onto", "pick aaa c1", "label onto", "reset onto"]), vec![
+			Lint::DuplicateLabel {
+				index: 2,
+				label: String::from("onto"),
+				first_index: 0,
+			}
+		]);
+	}
+
+	#[test]
+	fn unused_label() {
+		assert_eq!(lint(&["label onto", "pick aaa c1", "label side", "reset onto"]), vec![
+			Lint::UnusedLabel {
+				index: 2,
+				label: String::from("side"),
+			}
+		]);
+	}
+
+	#[test]
+	fn fixup_target_dropped() {
+		assert_eq!(lint(&["drop aaa c1", "fixup bbb fixup! c1"]), vec![Lint::FixupTargetDropped {
+			index: 1,
+			target_index: 0,
+		}]);
+	}
+
+	#[test]
+	fn fixup_target_later() {
+		assert_eq!(lint(&["pick aaa c1", "squash bbb squash! c2", "pick ccc c2"]), vec![
+			Lint::FixupTargetLater {
+				index: 1,
+				target_index: 2,
+			}
+		]);
+	}
+
+	#[test]
+	fn fixup_target_valid() {
+		assert!(lint(&["pick aaa c1", "pick bbb c2", "fixup ccc fixup! c1", "pick ddd fixup! c2"]).is_empty());
+	}
+
+	#[test]
+	fn display_undefined_label() {
+		assert_eq!(
+			Lint::UndefinedLabel {
+				index: 0,
+				label: String::from("side"),
+			}
+			.to_string(),
+			"The label side is not created by any line"
+		);
+	}
+
+	#[test]
+	fn display_label_defined_later() {
+		assert_eq!(
+			Lint::LabelDefinedLater {
+				index: 0,
+				label: String::from("side"),
+				definition_index: 4,
+			}
+			.to_string(),
+			"The label side is not created until line 5"
+		);
+	}
+
+	#[test]
+	fn display_duplicate_label() {
+		assert_eq!(
+			Lint::DuplicateLabel {
+				index: 2,
+				label: String::from("side"),
+				first_index: 0,
+			}
+			.to_string(),
+			"The label side is already created on line 1"
+		);
+	}
+
+	#[test]
+	fn display_unused_label() {
+		assert_eq!(
+			Lint::UnusedLabel {
+				index: 0,
+				label: String::from("side"),
+			}
+			.to_string(),
+			"The label side is not used by any line"
+		);
+	}
+
+	#[test]
+	fn display_fixup_target_dropped() {
+		assert_eq!(
+			Lint::FixupTargetDropped {
+				index: 1,
+				target_index: 0,
+			}
+			.to_string(),
+			"The commit that this commit fixes is dropped on line 1"
+		);
+	}
+
+	#[test]
+	fn display_fixup_target_later() {
+		assert_eq!(
+			Lint::FixupTargetLater {
+				index: 1,
+				target_index: 2,
+			}
+			.to_string(),
+			"The commit that this commit fixes is not picked until line 3"
+		);
+	}
+}
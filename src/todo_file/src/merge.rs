@@ -0,0 +1,153 @@
+use super::Line;
+
+// the lines are compared as they are written to the rebase file, since a line that was changed and then changed back
+// is the same line
+type LineKey<'l> = (String, Option<&'l str>);
+
+fn line_keys(lines: &[Line]) -> Vec<LineKey<'_>> {
+	lines.iter().map(|line| (line.to_text(), line.get_group_name())).collect()
+}
+
+// for each line of the base, the index of the same line in the other lines, from the longest common subsequence of
+// the lines
+fn match_lines(base: &[LineKey<'_>], other: &[LineKey<'_>]) -> Vec<Option<usize>> {
+	let mut lengths = vec![vec![0_usize; other.len() + 1]; base.len() + 1];
+	for (base_index, base_line) in base.iter().enumerate().rev() {
+		for (other_index, other_line) in other.iter().enumerate().rev() {
+			lengths[base_index][other_index] = if base_line == other_line {
+				lengths[base_index + 1][other_index + 1] + 1
+			}
+			else {
+				lengths[base_index + 1][other_index].max(lengths[base_index][other_index + 1])
+			};
+		}
+	}
+
+	let mut matches = vec![None; base.len()];
+	let (mut base_index, mut other_index) = (0, 0);
+	while base_index < base.len() && other_index < other.len() {
+		if base[base_index] == other[other_index] {
+			matches[base_index] = Some(other_index);
+			base_index += 1;
+			other_index += 1;
+		}
+		else if lengths[base_index + 1][other_index] >= lengths[base_index][other_index + 1] {
+			base_index += 1;
+		}
+		else {
+			other_index += 1;
+		}
+	}
+	matches
+}
+
+/// Merge the changes of two versions of the lines that were both changed from the same base lines, like `git
+/// merge-file`. The lines that are the same in the base and in both versions separate the lines into chunks, and a
+/// chunk that was changed in only one of the versions takes that change. Returns `None` when a chunk was changed
+/// differently in both versions.
+pub(crate) fn merge_lines(base: &[Line], ours: &[Line], theirs: &[Line]) -> Option<Vec<Line>> {
+	let (base_keys, our_keys, their_keys) = (line_keys(base), line_keys(ours), line_keys(theirs));
+	let our_matches = match_lines(base_keys.as_slice(), our_keys.as_slice());
+	let their_matches = match_lines(base_keys.as_slice(), their_keys.as_slice());
+	let mut merged = vec![];
+	let (mut base_start, mut our_start, mut their_start) = (0, 0, 0);
+	loop {
+		// the next line of the base that is in both versions, after the current chunk
+		let stable = (base_start..base.len()).find_map(|index| {
+			match (our_matches[index], their_matches[index]) {
+				(Some(our_index), Some(their_index)) => Some((index, our_index, their_index)),
+				_ => None,
+			}
+		});
+		let (base_end, our_end, their_end) = stable.unwrap_or((base.len(), ours.len(), theirs.len()));
+
+		let base_chunk = &base_keys[base_start..base_end];
+		let our_chunk = &our_keys[our_start..our_end];
+		let their_chunk = &their_keys[their_start..their_end];
+		if our_chunk == base_chunk || our_chunk == their_chunk {
+			merged.extend_from_slice(&theirs[their_start..their_end]);
+		}
+		else if their_chunk == base_chunk {
+			merged.extend_from_slice(&ours[our_start..our_end]);
+		}
+		else {
+			return None;
+		}
+
+		if stable.is_none() {
+			return Some(merged);
+		}
+		merged.push(ours[our_end].clone());
+		base_start = base_end + 1;
+		our_start = our_end + 1;
+		their_start = their_end + 1;
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use rstest::rstest;
+
+	use super::*;
+	use crate::Action;
+
+	fn lines(lines: &[&str]) -> Vec<Line> {
+		lines.iter().map(|line| Line::new(line).unwrap()).collect()
+	}
+
+	#[rstest]
+	#[case::unchanged(&["pick a a", "pick b b", "pick c c"], &["pick a a", "pick b b", "pick c c"], &[
+		"pick a a", "pick b b", "pick c c"
+	])]
+	#[case::only_ours(&["drop a a", "pick b b", "pick c c"], &["pick a a", "pick b b", "pick c c"], &[
+		"drop a a", "pick b b", "pick c c"
+	])]
+	#[case::only_theirs(&["pick a a", "pick b b", "pick c c"], &["pick a a", "drop b b", "pick c c"], &[
+		"pick a a", "drop b b", "pick c c"
+	])]
+	#[case::both_separate(&["drop a a", "pick b b", "pick c c"], &["pick a a", "pick b b", "drop c c"], &[
+		"drop a a", "pick b b", "drop c c"
+	])]
+	#[case::both_same(&["drop a a", "pick b b"], &["drop a a", "pick b b"], &["drop a a", "pick b b"])]
+	#[case::ours_added(&["pick a a", "break", "pick b b", "pick c c"], &["pick a a", "pick b b", "exec make"], &[
+		"pick a a", "break", "pick b b", "exec make"
+	])]
+	#[case::theirs_removed(&["drop a a", "pick b b", "pick c c"], &["pick a a", "pick b b"], &["drop a a", "pick b b"])]
+	fn merge(#[case] ours: &[&str], #[case] theirs: &[&str], #[case] expected: &[&str]) {
+		let base = lines(&["pick a a", "pick b b", "pick c c"]);
+		assert_eq!(
+			merge_lines(base.as_slice(), lines(ours).as_slice(), lines(theirs).as_slice()),
+			Some(lines(expected))
+		);
+	}
+
+	#[test]
+	fn merge_line_changed_back() {
+		let base = lines(&["pick a a", "pick b b", "pick c c"]);
+		let mut ours = base.clone();
+		ours[1].set_action(Action::Drop);
+		ours[1].set_action(Action::Pick);
+		let merged = merge_lines(
+			base.as_slice(),
+			ours.as_slice(),
+			lines(&["pick a a", "pick b b", "drop c c"]).as_slice(),
+		)
+		.unwrap();
+		assert_eq!(
+			merged.iter().map(Line::to_text).collect::<Vec<String>>(),
+			vec!["pick a a", "pick b b", "drop c c"]
+		);
+	}
+
+	#[rstest]
+	#[case::same_line(&["drop a a", "pick b b", "pick c c"], &["edit a a", "pick b b", "pick c c"])]
+	#[case::changed_and_removed(&["pick a a", "drop b b", "pick c c"], &["pick a a", "pick c c"])]
+	#[case::added_same_place(&["pick a a", "break", "pick b b", "pick c c"], &["pick a a", "exec make", "pick b b", "pick c c"])]
+	fn merge_conflict(#[case] ours: &[&str], #[case] theirs: &[&str]) {
+		let base = lines(&["pick a a", "pick b b", "pick c c"]);
+		assert_eq!(
+			merge_lines(base.as_slice(), lines(ours).as_slice(), lines(theirs).as_slice()),
+			None
+		);
+	}
+}
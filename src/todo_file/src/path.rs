@@ -0,0 +1,99 @@
+use std::path::PathBuf;
+
+// The longest path that the Windows file system functions support, without the extended-length prefix.
+const MAX_PATH: usize = 260;
+
+/// Convert a long absolute Windows path to an extended-length path, with the `\\?\` prefix, so that it can be
+/// opened. Git provides paths with forward slashes, which are not supported in extended-length paths, and the
+/// `.` and `..` components are not resolved, so these are normalized.
+fn to_extended_length_path(path: &str) -> String {
+	let (prefix, rest) = if let Some(rest) = path.strip_prefix(r"\\").or_else(|| path.strip_prefix("//")) {
+		(r"\\?\UNC\", rest)
+	}
+	else if path.len() > 2 && path.as_bytes()[1] == b':' && matches!(path.as_bytes()[2], b'\\' | b'/') {
+		(r"\\?\", path)
+	}
+	else {
+		return String::from(path);
+	};
+
+	if path.len() < MAX_PATH || path.starts_with(r"\\?\") || path.starts_with(r"\\.\") {
+		return String::from(path);
+	}
+
+	let mut components: Vec<&str> = vec![];
+	for component in rest.split(|c| c == '\\' || c == '/') {
+		match component {
+			"" | "." => {},
+			".." => {
+				// never remove the drive, or the server and share
+				if components.len() > if prefix == r"\\?\" { 1 } else { 2 } {
+					let _ = components.pop();
+				}
+			},
+			component => components.push(component),
+		}
+	}
+	format!("{}{}", prefix, components.join(r"\"))
+}
+
+/// Get the path to use with the file system functions for the path of a rebase file.
+pub(crate) fn file_system_path(path: &str) -> PathBuf {
+	if cfg!(windows) {
+		PathBuf::from(to_extended_length_path(path))
+	}
+	else {
+		PathBuf::from(path)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use rstest::rstest;
+
+	use super::*;
+
+	fn long_name() -> String {
+		"a".repeat(MAX_PATH)
+	}
+
+	#[rstest]
+	#[case::relative("git-rebase-todo")]
+	#[case::unix("/home/user/project/.git/rebase-merge/git-rebase-todo")]
+	#[case::short_drive(r"C:\project\.git\rebase-merge\git-rebase-todo")]
+	#[case::short_unc(r"\\server\share\project\.git\rebase-merge\git-rebase-todo")]
+	fn to_extended_length_path_unchanged(#[case] path: &str) {
+		assert_eq!(to_extended_length_path(path), path);
+	}
+
+	#[test]
+	fn to_extended_length_path_already_extended() {
+		let path = format!(r"\\?\C:\{}\git-rebase-todo", long_name());
+		assert_eq!(to_extended_length_path(path.as_str()), path);
+	}
+
+	#[test]
+	fn to_extended_length_path_long_drive_path() {
+		let path = format!("C:/{}/./project/../.git/rebase-merge/git-rebase-todo", long_name());
+		assert_eq!(
+			to_extended_length_path(path.as_str()),
+			format!(r"\\?\C:\{}\.git\rebase-merge\git-rebase-todo", long_name())
+		);
+	}
+
+	#[test]
+	fn to_extended_length_path_long_unc_path() {
+		let path = format!(r"\\server\share\{}\..\..\..\git-rebase-todo", long_name());
+		assert_eq!(
+			to_extended_length_path(path.as_str()),
+			r"\\?\UNC\server\share\git-rebase-todo"
+		);
+	}
+
+	#[test]
+	#[cfg(not(windows))]
+	fn file_system_path_unchanged() {
+		let path = format!("C:/{}/git-rebase-todo", long_name());
+		assert_eq!(file_system_path(path.as_str()), PathBuf::from(path));
+	}
+}
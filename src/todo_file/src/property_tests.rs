@@ -0,0 +1,194 @@
+// Property based tests of parsing and serializing todo files. A failing case is shrunk by proptest to a minimal input,
+// which is reported with the failure.
+use proptest::{prelude::*, sample::select};
+
+use super::*;
+
+const CASES: u32 = 2000;
+
+const COMMIT_ACTIONS: [(&str, Action); 12] = [
+	("pick", Action::Pick),
+	("p", Action::Pick),
+	("reword", Action::Reword),
+	("r", Action::Reword),
+	("edit", Action::Edit),
+	("e", Action::Edit),
+	("squash", Action::Squash),
+	("s", Action::Squash),
+	("fixup", Action::Fixup),
+	("f", Action::Fixup),
+	("drop", Action::Drop),
+	("d", Action::Drop),
+];
+
+const EDITABLE_ACTIONS: [(&str, Action); 8] = [
+	("exec", Action::Exec),
+	("x", Action::Exec),
+	("label", Action::Label),
+	("l", Action::Label),
+	("reset", Action::Reset),
+	("t", Action::Reset),
+	("merge", Action::Merge),
+	("m", Action::Merge),
+];
+
+// fragments of content, with odd whitespace, comment characters and multibyte characters
+const CONTENT_FRAGMENTS: [&str; 14] = [
+	"word", "fix", "#", ";", " ", "  ", "\t", "pick", "-C", "é", "👍", "\"quoted\"", "a/b.rs", "done.",
+];
+
+// fragments of input that are close to being valid, used to build almost valid todo files
+const INPUT_FRAGMENTS: [&str; 25] = [
+	"pick", "p", "exec", "x", "break", "b", "noop", "keep", "k", "fix", "delete", "hide", "reword", "label", "merge",
+	"# --- APPLY_LINE ---", "#", " ", "\t", "\n", "\r", "\r\r", "aaa", "é", "",
+];
+
+#[derive(Clone, Debug)]
+struct ExpectedLine {
+	action: Action,
+	content: String,
+	hash: String,
+}
+
+fn hash() -> impl Strategy<Value = String> {
+	"[0-9a-f]{1,40}"
+}
+
+fn content(min_fragments: usize) -> impl Strategy<Value = String> {
+	prop::collection::vec(select(&CONTENT_FRAGMENTS[..]), min_fragments..6).prop_map(|fragments| fragments.concat())
+}
+
+// a valid line, with the text of the line and the line that it is expected to parse to
+fn line() -> impl Strategy<Value = (String, ExpectedLine)> {
+	prop_oneof![
+		1 => select(&["break", "b"][..]).prop_map(|name| {
+			(String::from(name), ExpectedLine {
+				action: Action::Break,
+				content: String::new(),
+				hash: String::new(),
+			})
+		}),
+		2 => (select(&EDITABLE_ACTIONS[..]), content(1)).prop_map(|((name, action), content)| {
+			(format!("{} {}", name, content), ExpectedLine {
+				action,
+				content,
+				hash: String::new(),
+			})
+		}),
+		7 => (select(&COMMIT_ACTIONS[..]), hash(), content(0), any::<bool>()).prop_map(
+			|((name, action), hash, content, without_content)| {
+				let text = if content.is_empty() && without_content {
+					format!("{} {}", name, hash)
+				}
+				else {
+					format!("{} {} {}", name, hash, content)
+				};
+				(text, ExpectedLine { action, content, hash })
+			}
+		),
+	]
+}
+
+// a valid todo file, with comments and empty lines mixed between the lines
+fn todo_file(comment_char: &'static str) -> impl Strategy<Value = (String, Vec<ExpectedLine>)> {
+	let entry = prop_oneof![
+		1 => content(0).prop_map(move |content| (format!("{}{}", comment_char, content), None)),
+		1 => Just((String::new(), None)),
+		4 => line().prop_map(|(text, expected_line)| (text, Some(expected_line))),
+	];
+	(prop::collection::vec(entry, 1..20), any::<bool>()).prop_map(|(entries, trailing_new_line)| {
+		let mut contents = entries.iter().map(|entry| entry.0.as_str()).collect::<Vec<&str>>().join("\n");
+		if trailing_new_line {
+			contents.push('\n');
+		}
+		(
+			contents,
+			entries.into_iter().filter_map(|(_, expected_line)| expected_line).collect(),
+		)
+	})
+}
+
+fn todo_file_with_comment_char() -> impl Strategy<Value = (&'static str, String, Vec<ExpectedLine>)> {
+	select(&["#", ";"][..]).prop_flat_map(|comment_char| {
+		todo_file(comment_char).prop_map(move |(contents, expected)| (comment_char, contents, expected))
+	})
+}
+
+// an almost valid todo file, from a valid todo file with a character removed, or a fragment inserted, which often
+// leaves the file loadable
+fn almost_valid_todo_file() -> impl Strategy<Value = String> {
+	(
+		todo_file("#"),
+		0..3u8,
+		any::<prop::sample::Index>(),
+		select(&INPUT_FRAGMENTS[..]),
+	)
+		.prop_map(|((contents, _), mutation, index, fragment)| {
+			let mut characters = contents.chars().collect::<Vec<char>>();
+			match mutation {
+				0 if !characters.is_empty() => {
+					let _ = characters.remove(index.index(characters.len()));
+				},
+				1 => characters.extend(fragment.chars()),
+				_ => {
+					let position = index.index(characters.len() + 1);
+					let _ = characters.splice(position..position, fragment.chars()).count();
+				},
+			}
+			characters.into_iter().collect()
+		})
+}
+
+fn fragments() -> impl Strategy<Value = String> {
+	prop::collection::vec(select(&INPUT_FRAGMENTS[..]), 0..30).prop_map(|fragments| fragments.concat())
+}
+
+fn load(contents: &str, comment_char: &str) -> Result<TodoFile> {
+	let mut todo_file = TodoFile::new("git-rebase-todo", 1, comment_char);
+	todo_file.load_str(contents).map(|()| todo_file)
+}
+
+// loading and serializing the input must not panic, and once the input has been loaded and serialized, loading and
+// serializing the result must give the same text
+fn assert_stable(contents: &str) -> Result<(), TestCaseError> {
+	let text = match load(contents, "#").and_then(|todo_file| todo_file.to_text()) {
+		Ok(text) => text,
+		Err(_) => return Ok(()),
+	};
+	let reloaded = load(text.as_str(), "#")
+		.map_err(|err| TestCaseError::fail(format!("serialized text could not be loaded: {:#}\n{:?}", err, text)))?;
+	prop_assert_eq!(reloaded.to_text().unwrap(), text, "serialized text is not stable");
+	Ok(())
+}
+
+proptest! {
+	#![proptest_config(ProptestConfig::with_cases(CASES))]
+
+	#[test]
+	fn valid_todo_file_round_trip((comment_char, contents, expected) in todo_file_with_comment_char()) {
+		let todo_file = load(contents.as_str(), comment_char)
+			.map_err(|err| TestCaseError::fail(format!("could not load: {:#}", err)))?;
+		let actual = todo_file.get_lines_owned();
+		prop_assert_eq!(actual.len(), expected.len());
+		for (line, expected_line) in actual.iter().zip(expected.iter()) {
+			prop_assert_eq!(line.get_action(), &expected_line.action);
+			prop_assert_eq!(line.get_hash(), expected_line.hash.as_str());
+			prop_assert_eq!(line.get_content(), expected_line.content.as_str());
+		}
+
+		let text = todo_file.to_text().unwrap();
+		let reloaded = load(text.as_str(), comment_char)
+			.map_err(|err| TestCaseError::fail(format!("could not reload {:?}: {:#}", text, err)))?;
+		prop_assert_eq!(reloaded.get_lines_owned(), actual);
+	}
+
+	#[test]
+	fn almost_valid_todo_file_stable(contents in almost_valid_todo_file()) {
+		assert_stable(contents.as_str())?;
+	}
+
+	#[test]
+	fn arbitrary_fragments_stable(contents in fragments()) {
+		assert_stable(contents.as_str())?;
+	}
+}
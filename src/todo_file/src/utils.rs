@@ -1,4 +1,14 @@
-use super::line::Line;
+use super::{edit_content::EditContext, line::Line};
+
+pub(crate) fn edit_line(line: &mut Line, edit_context: &EditContext) {
+	if let Some(action) = edit_context.get_action().as_ref() {
+		line.set_action(*action);
+	}
+
+	if let Some(content) = edit_context.get_content().as_ref() {
+		line.edit_content(content);
+	}
+}
 
 pub(crate) fn swap_range_up(lines: &mut Vec<Line>, start_index: usize, end_index: usize) {
 	let range = if end_index <= start_index {
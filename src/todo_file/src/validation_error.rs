@@ -0,0 +1,54 @@
+use std::fmt::{Display, Formatter};
+
+use super::Action;
+
+/// A problem with the todo list, that would result in Git failing to start the rebase.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum ValidationError {
+	/// A squash or fixup line is not preceded by a commit, so there is no commit to combine it with.
+	NoPreviousCommit {
+		/// The index of the squash or fixup line.
+		index: usize,
+		/// The hash of the commit of the line.
+		hash: String,
+		/// The action of the line.
+		action: Action,
+	},
+}
+
+impl Display for ValidationError {
+	#[inline]
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		match *self {
+			Self::NoPreviousCommit { ref hash, action, .. } => {
+				write!(
+					f,
+					"The first commit, {}, cannot be a {}, since there is no previous commit",
+					hash,
+					action.as_string()
+				)
+			},
+		}
+	}
+}
+
+impl std::error::Error for ValidationError {}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn display_no_previous_commit() {
+		assert_eq!(
+			ValidationError::NoPreviousCommit {
+				index: 0,
+				hash: String::from("abc"),
+				action: Action::Squash
+			}
+			.to_string(),
+			"The first commit, abc, cannot be a squash, since there is no previous commit"
+		);
+	}
+}
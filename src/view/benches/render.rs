@@ -0,0 +1,106 @@
+//! Benchmarks for rendering the view, run with `cargo bench`.
+use std::sync::mpsc;
+
+use config::Theme;
+use criterion::{criterion_group, criterion_main, Criterion};
+use display::{testutil::CrossTerm, Display, DisplayColor, Size};
+use view::{LineSegment, View, ViewData, ViewLine, ViewSender};
+
+const WIDTH: u16 = 200;
+const HEIGHT: u16 = 60;
+// the size of a maximized terminal on a large monitor
+const LIST_WIDTH: u16 = 300;
+const LIST_HEIGHT: u16 = 80;
+const LIST_LINE_COUNT: usize = 10_000;
+
+fn create_view_data(selected: usize) -> ViewData {
+	ViewData::new(|updater| {
+		for index in 0..usize::from(HEIGHT) {
+			let marker = if index == selected { ">" } else { " " };
+			updater.push_line(ViewLine::from(format!(
+				"{} pick {:07x} Commit message of line {}",
+				marker, index, index
+			)));
+		}
+	})
+}
+
+// the lines of a long todo list, with the colored segments of the list, and the view scrolled to the selected line
+fn create_list_view_data(selected: usize) -> ViewData {
+	ViewData::new(|updater| {
+		updater.push_leading_line(ViewLine::from(LineSegment::new_with_color(
+			"Git Interactive Rebase Tool",
+			DisplayColor::IndicatorColor,
+		)));
+		for index in 0..LIST_LINE_COUNT {
+			let (action, color) = if index % 5 == 0 {
+				("fixup ", DisplayColor::ActionFixup)
+			}
+			else {
+				("pick  ", DisplayColor::ActionPick)
+			};
+			updater.push_line(
+				ViewLine::new_with_pinned_segments(
+					vec![
+						LineSegment::new(if index == selected { " > " } else { "   " }),
+						LineSegment::new_with_color(action, color),
+						LineSegment::new(format!("{:07x} ", index).as_str()),
+						LineSegment::new(format!("Commit message of line {}", index).as_str()),
+					],
+					2,
+				)
+				.set_selected(index == selected),
+			);
+		}
+		updater.ensure_line_visible(selected);
+	})
+}
+
+fn create_view(width: u16, height: u16) -> View<CrossTerm> {
+	let mut crossterm = CrossTerm::new();
+	crossterm.set_size(Size::new(usize::from(width), usize::from(height)));
+	View::new(Display::new(crossterm, &Theme::new()), "~", "?")
+}
+
+fn render(criterion: &mut Criterion) {
+	let mut view = create_view(WIDTH, HEIGHT);
+	let (sender, _receiver) = mpsc::channel();
+	let view_sender = ViewSender::new(sender);
+	view_sender.resize(WIDTH, HEIGHT);
+	let render_slice = view_sender.clone_render_slice();
+	let view_data = [create_view_data(0), create_view_data(1)];
+	let mut frame = 0;
+
+	let _ = criterion.bench_function("render_selection_change", |bencher| {
+		bencher.iter(|| {
+			frame += 1;
+			view_sender.render(&view_data[frame % 2]).unwrap();
+			view.render(&render_slice.lock().unwrap()).unwrap();
+		});
+	});
+	let _ = criterion.bench_function("render_full_frame", |bencher| {
+		bencher.iter(|| {
+			view.start().unwrap();
+			view_sender.render(&view_data[0]).unwrap();
+			view.render(&render_slice.lock().unwrap()).unwrap();
+		});
+	});
+
+	let mut list_view = create_view(LIST_WIDTH, LIST_HEIGHT);
+	view_sender.resize(LIST_WIDTH, LIST_HEIGHT);
+	let list_view_data = [
+		create_list_view_data(LIST_LINE_COUNT / 2),
+		create_list_view_data(LIST_LINE_COUNT / 2 + 1),
+	];
+	let _ = criterion.bench_function("render_list_frame", |bencher| {
+		bencher.iter(|| {
+			frame += 1;
+			list_view.start().unwrap();
+			view_sender.render(&list_view_data[frame % 2]).unwrap();
+			list_view.render(&render_slice.lock().unwrap()).unwrap();
+		});
+	});
+}
+
+criterion_group!(benches, render);
+criterion_main!(benches);
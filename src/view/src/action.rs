@@ -8,6 +8,10 @@ pub enum ViewAction {
 	Refresh,
 	/// Render the latest `ViewData`.
 	Render,
+	/// Update the colors of the view from the latest theme.
+	UpdateTheme,
+	/// Copy the latest text queued for the clipboard to the clipboard of the terminal.
+	CopyToClipboard,
 	/// Start processing actions.
 	Start,
 	/// End the thread and the processing of actions.
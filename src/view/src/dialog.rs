@@ -0,0 +1,293 @@
+use display::DisplayColor;
+use input::{Event, KeyCode, KeyEvent, KeyModifiers};
+
+use super::{LineSegment, ViewData, ViewLine};
+
+#[derive(Debug)]
+struct DialogChoice<T> {
+	key: char,
+	label: String,
+	value: T,
+}
+
+/// A dialog with a title, a body, and a list of choices, that each have a key of their own. A choice is made with
+/// its key, or by moving the selection with up and down, or tab, to the choice and pressing enter.
+///
+/// The events given to the dialog are expected to be read without the movement inputs, so that up and down are key
+/// events.
+#[derive(Debug)]
+pub struct Dialog<T> {
+	body: Vec<String>,
+	choices: Vec<DialogChoice<T>>,
+	hint: Option<String>,
+	selected_index: usize,
+	title: String,
+	view_data: ViewData,
+}
+
+impl<T> Dialog<T> {
+	/// Create a new dialog with a title, and without a body or any choices.
+	#[must_use]
+	#[inline]
+	pub fn new(title: &str) -> Self {
+		Self {
+			body: vec![],
+			choices: vec![],
+			hint: None,
+			selected_index: 0,
+			title: String::from(title),
+			view_data: ViewData::new(|updater| {
+				updater.set_show_title(true);
+				updater.set_retain_scroll_position(false);
+			}),
+		}
+	}
+
+	/// Add a line to the body, that is shown between the title and the choices.
+	#[inline]
+	pub fn push_body_line(&mut self, line: &str) {
+		self.body.push(String::from(line));
+	}
+
+	/// Set a hint, such as how to make a choice, that is shown below the choices.
+	#[inline]
+	pub fn set_hint(&mut self, hint: &str) {
+		self.hint = Some(String::from(hint));
+	}
+
+	/// Add a choice, with the key that chooses it and the label that describes it. The first choice is selected until
+	/// a default is set.
+	///
+	/// # Panics
+	/// Panics if another choice of the dialog has the same key.
+	#[inline]
+	pub fn add_choice(&mut self, key: char, label: &str, value: T) {
+		assert!(
+			self.choices.iter().all(|choice| choice.key != key),
+			"the key {} is used by more than one choice",
+			key
+		);
+		self.choices.push(DialogChoice {
+			key,
+			label: String::from(label),
+			value,
+		});
+	}
+
+	/// Get the view data of the dialog.
+	#[inline]
+	pub fn get_view_data(&mut self) -> &ViewData {
+		let title = self.title.as_str();
+		let body = &self.body;
+		let choices = &self.choices;
+		let hint = self.hint.as_ref();
+		let selected_index = self.selected_index;
+		self.view_data.update_view_data(|updater| {
+			updater.clear();
+			updater.push_leading_line(ViewLine::from(LineSegment::new_with_color(
+				title,
+				DisplayColor::IndicatorColor,
+			)));
+			updater.push_leading_line(ViewLine::new_empty_line());
+			for line in body {
+				updater.push_leading_line(ViewLine::from(line.as_str()));
+			}
+			if !body.is_empty() {
+				updater.push_leading_line(ViewLine::new_empty_line());
+			}
+			for (index, choice) in choices.iter().enumerate() {
+				updater.push_line(
+					ViewLine::from(vec![
+						LineSegment::new_with_color(format!("{}) ", choice.key).as_str(), DisplayColor::IndicatorColor),
+						LineSegment::new(choice.label.as_str()),
+					])
+					.set_selected(index == selected_index),
+				);
+			}
+			if let Some(hint_text) = hint {
+				updater.push_trailing_line(ViewLine::new_pinned(vec![LineSegment::new_with_color(
+					hint_text.as_str(),
+					DisplayColor::IndicatorColor,
+				)]));
+			}
+			updater.ensure_line_visible(selected_index);
+		});
+		&self.view_data
+	}
+
+	/// Handle an event, returning the value of the choice when a choice is made.
+	#[inline]
+	pub fn handle_event(&mut self, event: Event) -> Option<&T> {
+		let length = self.choices.len();
+		if length == 0 {
+			return None;
+		}
+		match event {
+			Event::Key(KeyEvent {
+				code: KeyCode::Up | KeyCode::BackTab,
+				..
+			}) => {
+				self.selected_index = (self.selected_index + length - 1) % length;
+				None
+			},
+			Event::Key(KeyEvent {
+				code: KeyCode::Down | KeyCode::Tab,
+				..
+			}) => {
+				self.selected_index = (self.selected_index + 1) % length;
+				None
+			},
+			Event::Key(KeyEvent {
+				code: KeyCode::Enter,
+				modifiers: KeyModifiers::NONE,
+				..
+			}) => self.choices.get(self.selected_index).map(|choice| &choice.value),
+			Event::Key(KeyEvent {
+				code: KeyCode::Char(c),
+				modifiers: KeyModifiers::NONE | KeyModifiers::SHIFT,
+				..
+			}) => {
+				let index = self.choices.iter().position(|choice| choice.key == c)?;
+				self.selected_index = index;
+				self.choices.get(index).map(|choice| &choice.value)
+			},
+			_ => None,
+		}
+	}
+}
+
+impl<T: PartialEq> Dialog<T> {
+	/// Select the choice of a value, that is chosen by enter without moving the selection.
+	#[inline]
+	pub fn set_default(&mut self, value: &T) {
+		if let Some(index) = self.choices.iter().position(|choice| &choice.value == value) {
+			self.selected_index = index;
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use rstest::rstest;
+
+	use super::*;
+	use crate::testutil::{_assert_rendered_output_from_view_data, AssertRenderOptions};
+
+	fn create_dialog() -> Dialog<u32> {
+		let mut dialog = Dialog::new("Title");
+		dialog.push_body_line("First body line");
+		dialog.push_body_line("Second body line");
+		dialog.add_choice('a', "Choice A", 1);
+		dialog.add_choice('b', "Choice B", 2);
+		dialog.add_choice('c', "Choice C", 3);
+		dialog
+	}
+
+	fn assert_rendered(dialog: &mut Dialog<u32>, expected: &[&str]) {
+		_assert_rendered_output_from_view_data(
+			dialog.get_view_data(),
+			&expected.iter().map(|line| String::from(*line)).collect::<Vec<String>>(),
+			AssertRenderOptions::default(),
+		);
+	}
+
+	#[test]
+	fn render() {
+		let mut dialog = create_dialog();
+		dialog.set_hint("Hint");
+		assert_rendered(&mut dialog, &[
+			"{TITLE}",
+			"{LEADING}",
+			"{IndicatorColor}Title",
+			"",
+			"{Normal}First body line",
+			"{Normal}Second body line",
+			"",
+			"{BODY}",
+			"{Selected}{IndicatorColor}a) {Normal}Choice A",
+			"{IndicatorColor}b) {Normal}Choice B",
+			"{IndicatorColor}c) {Normal}Choice C",
+			"{TRAILING}",
+			"{IndicatorColor}Hint",
+		]);
+	}
+
+	#[test]
+	fn render_without_body() {
+		let mut dialog = Dialog::new("Title");
+		dialog.add_choice('a', "Choice A", 1);
+		assert_rendered(&mut dialog, &[
+			"{TITLE}",
+			"{LEADING}",
+			"{IndicatorColor}Title",
+			"",
+			"{BODY}",
+			"{Selected}{IndicatorColor}a) {Normal}Choice A",
+		]);
+	}
+
+	#[test]
+	fn render_default() {
+		let mut dialog = create_dialog();
+		dialog.set_default(&2);
+		assert_rendered(&mut dialog, &[
+			"{TITLE}",
+			"{LEADING}",
+			"{IndicatorColor}Title",
+			"",
+			"{Normal}First body line",
+			"{Normal}Second body line",
+			"",
+			"{BODY}",
+			"{IndicatorColor}a) {Normal}Choice A",
+			"{Selected}{IndicatorColor}b) {Normal}Choice B",
+			"{IndicatorColor}c) {Normal}Choice C",
+		]);
+	}
+
+	#[rstest]
+	#[case::key_a(Event::from('a'), Some(1))]
+	#[case::key_c(Event::from('c'), Some(3))]
+	#[case::other_key(Event::from('z'), None)]
+	#[case::enter(Event::from(KeyCode::Enter), Some(2))]
+	#[case::resize(Event::Resize(100, 100), None)]
+	fn handle_event(#[case] event: Event, #[case] expected: Option<u32>) {
+		let mut dialog = create_dialog();
+		dialog.set_default(&2);
+		assert_eq!(dialog.handle_event(event).copied(), expected);
+	}
+
+	#[rstest]
+	#[case::down(KeyCode::Down, 3)]
+	#[case::tab(KeyCode::Tab, 3)]
+	#[case::up(KeyCode::Up, 1)]
+	#[case::back_tab(KeyCode::BackTab, 1)]
+	fn move_selection(#[case] code: KeyCode, #[case] expected: u32) {
+		let mut dialog = create_dialog();
+		dialog.set_default(&2);
+		assert!(dialog.handle_event(Event::from(code)).is_none());
+		assert_eq!(dialog.handle_event(Event::from(KeyCode::Enter)).copied(), Some(expected));
+	}
+
+	#[test]
+	fn move_selection_wraps() {
+		let mut dialog = create_dialog();
+		assert!(dialog.handle_event(Event::from(KeyCode::Up)).is_none());
+		assert_eq!(dialog.handle_event(Event::from(KeyCode::Enter)).copied(), Some(3));
+		assert!(dialog.handle_event(Event::from(KeyCode::Down)).is_none());
+		assert_eq!(dialog.handle_event(Event::from(KeyCode::Enter)).copied(), Some(1));
+	}
+
+	#[test]
+	fn handle_event_without_choices() {
+		let mut dialog: Dialog<u32> = Dialog::new("Title");
+		assert!(dialog.handle_event(Event::from(KeyCode::Enter)).is_none());
+	}
+
+	#[test]
+	#[should_panic(expected = "the key a is used by more than one choice")]
+	fn add_choice_duplicate_key() {
+		let mut dialog = create_dialog();
+		dialog.add_choice('a', "Other", 4);
+	}
+}
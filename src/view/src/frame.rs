@@ -0,0 +1,244 @@
+use std::borrow::Cow;
+
+use display::{DisplayColor, Size};
+
+/// A segment of text in a line of a frame, with the color and style it is drawn with.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct FrameSegment {
+	pub(crate) color: DisplayColor,
+	pub(crate) dim: bool,
+	pub(crate) reverse: bool,
+	pub(crate) selected: bool,
+	pub(crate) text: Cow<'static, str>,
+	pub(crate) underline: bool,
+}
+
+impl FrameSegment {
+	pub(crate) fn new(text: &str, color: DisplayColor, selected: bool) -> Self {
+		let mut segment = Self::new_static("", color, selected);
+		segment.text = Cow::Owned(String::from(text));
+		segment
+	}
+
+	/// Create a segment with a static text, that is not copied.
+	pub(crate) const fn new_static(text: &'static str, color: DisplayColor, selected: bool) -> Self {
+		Self {
+			color,
+			dim: false,
+			reverse: false,
+			selected,
+			text: Cow::Borrowed(text),
+			underline: false,
+		}
+	}
+
+	pub(crate) const fn style(mut self, dim: bool, underline: bool, reverse: bool) -> Self {
+		self.dim = dim;
+		self.underline = underline;
+		self.reverse = reverse;
+		self
+	}
+
+	pub(crate) fn set_style(&mut self, dim: bool, underline: bool, reverse: bool) {
+		self.dim = dim;
+		self.underline = underline;
+		self.reverse = reverse;
+	}
+
+	// replace the segment, with the style cleared, reusing the memory of the text
+	fn set(&mut self, text: &str, color: DisplayColor, selected: bool) {
+		let segment_text = self.text.to_mut();
+		segment_text.clear();
+		segment_text.push_str(text);
+		self.color = color;
+		self.selected = selected;
+		self.set_style(false, false, false);
+	}
+}
+
+/// A line in a frame, with an optional scroll indicator that is drawn in the last column of the line.
+#[derive(Debug, Clone)]
+pub(crate) struct FrameLine {
+	pub(crate) scroll_indicator: Option<FrameSegment>,
+	segments: Vec<FrameSegment>,
+	// the segments past the length are kept from a previous frame, so that their memory is reused
+	length: usize,
+}
+
+impl FrameLine {
+	pub(crate) fn new(segments: Vec<FrameSegment>) -> Self {
+		Self {
+			scroll_indicator: None,
+			length: segments.len(),
+			segments,
+		}
+	}
+
+	#[cfg(test)]
+	#[allow(clippy::missing_const_for_fn)]
+	pub(crate) fn scroll_indicator(mut self, scroll_indicator: FrameSegment) -> Self {
+		self.scroll_indicator = Some(scroll_indicator);
+		self
+	}
+
+	pub(crate) fn segments(&self) -> &[FrameSegment] {
+		self.segments.get(..self.length).unwrap_or_default()
+	}
+
+	/// Add a segment to the end of the line, reusing a segment of the line from a previous frame when there is one,
+	/// and get the segment, so that it can be styled.
+	pub(crate) fn push_segment(&mut self, text: &str, color: DisplayColor, selected: bool) -> &mut FrameSegment {
+		if let Some(segment) = self.segments.get_mut(self.length) {
+			segment.set(text, color, selected);
+		}
+		else {
+			self.segments.push(FrameSegment::new(text, color, selected));
+		}
+		self.length += 1;
+		self.segments
+			.get_mut(self.length - 1)
+			.expect("segment must exist after it is pushed")
+	}
+
+	fn clear(&mut self) {
+		self.scroll_indicator = None;
+		self.length = 0;
+	}
+}
+
+impl PartialEq for FrameLine {
+	fn eq(&self, other: &Self) -> bool {
+		self.scroll_indicator == other.scroll_indicator && self.segments() == other.segments()
+	}
+}
+
+/// The lines drawn to the terminal in a single render, kept so that the next render only needs to draw the lines
+/// that have changed.
+#[derive(Debug)]
+pub(crate) struct Frame {
+	lines: Vec<FrameLine>,
+	// the lines past the length are kept from a previous frame, so that their memory is reused
+	length: usize,
+	size: Size,
+}
+
+impl Frame {
+	pub(crate) fn new(lines: Vec<FrameLine>, size: Size) -> Self {
+		Self {
+			length: lines.len(),
+			lines,
+			size,
+		}
+	}
+
+	pub(crate) fn lines(&self) -> &[FrameLine] {
+		self.lines.get(..self.length).unwrap_or_default()
+	}
+
+	/// Clear the frame to build another frame of a size into it, keeping the memory of its lines.
+	pub(crate) fn clear(&mut self, size: Size) {
+		self.length = 0;
+		self.size = size;
+	}
+
+	/// Add an empty line to the end of the frame, reusing a line from a previous frame when there is one.
+	pub(crate) fn push_line(&mut self) -> &mut FrameLine {
+		if self.length == self.lines.len() {
+			self.lines.push(FrameLine::new(vec![]));
+		}
+		self.length += 1;
+		let line = self
+			.lines
+			.get_mut(self.length - 1)
+			.expect("line must exist after it is pushed");
+		line.clear();
+		line
+	}
+
+	pub(crate) fn last_line_mut(&mut self) -> Option<&mut FrameLine> {
+		self.length.checked_sub(1).and_then(|index| self.lines.get_mut(index))
+	}
+
+	/// Get the indexes of the lines of this frame that are different from the previous frame, or `None` when the
+	/// size of the window has changed and the whole frame must be drawn. Lines of the previous frame past the end
+	/// of this frame are included, so that they are cleared.
+	pub(crate) fn changed_lines(&self, previous: &Self) -> Option<Vec<usize>> {
+		if self.size != previous.size {
+			return None;
+		}
+		let length = self.length.max(previous.length);
+		Some(
+			(0..length)
+				.filter(|&index| self.lines().get(index) != previous.lines().get(index))
+				.collect(),
+		)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn frame(lines: &[&str], width: usize, height: usize) -> Frame {
+		Frame::new(
+			lines
+				.iter()
+				.map(|line| FrameLine::new(vec![FrameSegment::new(line, DisplayColor::Normal, false)]))
+				.collect(),
+			Size::new(width, height),
+		)
+	}
+
+	#[test]
+	fn changed_lines_same() {
+		let previous = frame(&["a", "b", "c"], 10, 3);
+		assert_eq!(frame(&["a", "b", "c"], 10, 3).changed_lines(&previous), Some(vec![]));
+	}
+
+	#[test]
+	fn changed_lines_changed_text() {
+		let previous = frame(&["a", "b", "c"], 10, 3);
+		assert_eq!(frame(&["a", "x", "c"], 10, 3).changed_lines(&previous), Some(vec![1]));
+	}
+
+	#[test]
+	fn changed_lines_changed_style() {
+		let previous = frame(&["a"], 10, 3);
+		let next = Frame::new(
+			vec![FrameLine::new(vec![FrameSegment::new(
+				"a",
+				DisplayColor::Normal,
+				false,
+			)
+			.style(true, false, false)])],
+			Size::new(10, 3),
+		);
+		assert_eq!(next.changed_lines(&previous), Some(vec![0]));
+	}
+
+	#[test]
+	fn changed_lines_changed_scroll_indicator() {
+		let previous = frame(&["a"], 10, 3);
+		let next = Frame::new(
+			vec![
+				FrameLine::new(vec![FrameSegment::new("a", DisplayColor::Normal, false)])
+					.scroll_indicator(FrameSegment::new("█", DisplayColor::Normal, true)),
+			],
+			Size::new(10, 3),
+		);
+		assert_eq!(next.changed_lines(&previous), Some(vec![0]));
+	}
+
+	#[test]
+	fn changed_lines_different_length() {
+		let previous = frame(&["a", "b", "c"], 10, 3);
+		assert_eq!(frame(&["a"], 10, 3).changed_lines(&previous), Some(vec![1, 2]));
+		assert_eq!(previous.changed_lines(&frame(&["a"], 10, 3)), Some(vec![1, 2]));
+	}
+
+	#[test]
+	fn changed_lines_resized() {
+		let previous = frame(&["a"], 10, 3);
+		assert_eq!(frame(&["a"], 11, 3).changed_lines(&previous), None);
+	}
+}
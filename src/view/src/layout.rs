@@ -0,0 +1,94 @@
+const MINIMUM_FULL_WINDOW_WIDTH: usize = 34; // " > squash cccccccc mmmmmmmmmmmmm %".len()
+const MINIMUM_WIDE_WINDOW_WIDTH: usize = 120;
+// the extra columns needed to change to a larger layout, so that resizing near a breakpoint does not flicker
+const LAYOUT_HYSTERESIS: usize = 4;
+
+/// The layout of the interface, chosen from the width of the terminal window.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[allow(clippy::exhaustive_enums)]
+pub enum Layout {
+	/// A single column, with abbreviated actions and short, or hidden, optional columns.
+	Compact,
+	/// Full width lines, with full action names and hashes.
+	Full,
+	/// Full width lines, with additional columns and a preview pane.
+	Wide,
+}
+
+impl Layout {
+	/// Get the layout for a window width, without considering a previous layout.
+	#[must_use]
+	#[inline]
+	pub const fn from_width(width: usize) -> Self {
+		if width >= MINIMUM_WIDE_WINDOW_WIDTH {
+			Self::Wide
+		}
+		else if width >= MINIMUM_FULL_WINDOW_WIDTH {
+			Self::Full
+		}
+		else {
+			Self::Compact
+		}
+	}
+
+	/// Get the layout for a window width, after a resize from the previous layout. A larger layout is only used
+	/// once the width is past the breakpoint by a few columns, while a smaller layout is used as soon as the width
+	/// is under the breakpoint, so the layout always fits the window.
+	#[must_use]
+	#[inline]
+	pub const fn from_width_with_previous(width: usize, previous: Self) -> Self {
+		let wide_width = if matches!(previous, Self::Wide) {
+			MINIMUM_WIDE_WINDOW_WIDTH
+		}
+		else {
+			MINIMUM_WIDE_WINDOW_WIDTH + LAYOUT_HYSTERESIS
+		};
+		let full_width = if matches!(previous, Self::Compact) {
+			MINIMUM_FULL_WINDOW_WIDTH + LAYOUT_HYSTERESIS
+		}
+		else {
+			MINIMUM_FULL_WINDOW_WIDTH
+		};
+
+		if width >= wide_width {
+			Self::Wide
+		}
+		else if width >= full_width {
+			Self::Full
+		}
+		else {
+			Self::Compact
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use rstest::rstest;
+
+	use super::*;
+
+	#[rstest]
+	#[case::compact(MINIMUM_FULL_WINDOW_WIDTH - 1, Layout::Compact)]
+	#[case::full(MINIMUM_FULL_WINDOW_WIDTH, Layout::Full)]
+	#[case::full_before_wide(MINIMUM_WIDE_WINDOW_WIDTH - 1, Layout::Full)]
+	#[case::wide(MINIMUM_WIDE_WINDOW_WIDTH, Layout::Wide)]
+	fn from_width(#[case] width: usize, #[case] expected: Layout) {
+		assert_eq!(Layout::from_width(width), expected);
+	}
+
+	#[rstest]
+	#[case::compact_to_full_within_margin(MINIMUM_FULL_WINDOW_WIDTH, Layout::Compact, Layout::Compact)]
+	#[case::compact_to_full(MINIMUM_FULL_WINDOW_WIDTH + LAYOUT_HYSTERESIS, Layout::Compact, Layout::Full)]
+	#[case::full_to_compact(MINIMUM_FULL_WINDOW_WIDTH - 1, Layout::Full, Layout::Compact)]
+	#[case::full_stays_full(MINIMUM_FULL_WINDOW_WIDTH, Layout::Full, Layout::Full)]
+	#[case::full_to_wide_within_margin(MINIMUM_WIDE_WINDOW_WIDTH, Layout::Full, Layout::Full)]
+	#[case::full_to_wide(MINIMUM_WIDE_WINDOW_WIDTH + LAYOUT_HYSTERESIS, Layout::Full, Layout::Wide)]
+	#[case::wide_stays_wide(MINIMUM_WIDE_WINDOW_WIDTH, Layout::Wide, Layout::Wide)]
+	#[case::wide_to_full(MINIMUM_WIDE_WINDOW_WIDTH - 1, Layout::Wide, Layout::Full)]
+	#[case::wide_to_compact(MINIMUM_FULL_WINDOW_WIDTH - 1, Layout::Wide, Layout::Compact)]
+	#[case::compact_to_wide(MINIMUM_WIDE_WINDOW_WIDTH + LAYOUT_HYSTERESIS, Layout::Compact, Layout::Wide)]
+	fn from_width_with_previous(#[case] width: usize, #[case] previous: Layout, #[case] expected: Layout) {
+		assert_eq!(Layout::from_width_with_previous(width, previous), expected);
+	}
+}
@@ -96,8 +96,12 @@
 //! performance should only be used in test code.
 
 mod action;
+mod dialog;
+mod frame;
+mod layout;
 mod line_segment;
 mod render_context;
+mod render_scheduler;
 mod render_slice;
 mod scroll_position;
 mod sender;
@@ -112,12 +116,20 @@ mod view_line;
 #[cfg(test)]
 mod tests;
 
+// only used by the benchmarks
+#[cfg(test)]
+use criterion as _;
+
+use std::iter;
+
 use anyhow::Result;
+use config::Theme;
 use display::{Display, DisplayColor, Size, Tui};
 
-use self::render_slice::RenderSlice;
 pub use self::{
 	action::ViewAction,
+	dialog::Dialog,
+	layout::Layout,
 	line_segment::LineSegment,
 	render_context::RenderContext,
 	sender::Sender as ViewSender,
@@ -127,6 +139,10 @@ pub use self::{
 	view_data_updater::ViewDataUpdater,
 	view_line::ViewLine,
 };
+use self::{
+	frame::{Frame, FrameLine, FrameSegment},
+	render_slice::RenderSlice,
+};
 
 const TITLE: &str = "Git Interactive Rebase Tool";
 const TITLE_SHORT: &str = "Git Rebase";
@@ -139,6 +155,10 @@ pub struct View<C: Tui> {
 	display: Display<C>,
 	help_indicator_key: String,
 	last_render_version: u32,
+	previous_frame: Option<Frame>,
+	show_scroll_bar: bool,
+	// the frame before the previous frame, that the next frame is built into, so that its memory is reused
+	spare_frame: Option<Frame>,
 }
 
 impl<C: Tui> View<C> {
@@ -150,15 +170,27 @@ impl<C: Tui> View<C> {
 			display,
 			help_indicator_key: String::from(help_indicator_key),
 			last_render_version: u32::MAX,
+			previous_frame: None,
+			show_scroll_bar: true,
+			spare_frame: None,
 		}
 	}
 
+	/// Set if the scroll bar is shown when the lines do not fit in the window. The scroll bar is not read well by
+	/// screen readers.
+	#[inline]
+	pub fn set_show_scroll_bar(&mut self, show_scroll_bar: bool) {
+		self.show_scroll_bar = show_scroll_bar;
+	}
+
 	/// End processing of the view.
 	///
 	/// # Errors
 	/// Results in an error if the terminal cannot be started.
 	#[inline]
 	pub fn start(&mut self) -> Result<()> {
+		self.last_render_version = u32::MAX;
+		self.previous_frame = None;
 		self.display.start()
 	}
 
@@ -168,9 +200,28 @@ impl<C: Tui> View<C> {
 	/// Results in an error if the terminal cannot be ended.
 	#[inline]
 	pub fn end(&mut self) -> Result<()> {
+		self.previous_frame = None;
 		self.display.end()
 	}
 
+	/// Update the colors used by the view from a theme. The next render will redraw the view, even if the
+	/// rendered content has not changed.
+	#[inline]
+	pub fn set_theme(&mut self, theme: &Theme) {
+		self.display.set_theme(theme);
+		self.last_render_version = u32::MAX;
+		self.previous_frame = None;
+	}
+
+	/// Copy text to the clipboard of the terminal.
+	///
+	/// # Errors
+	/// Results in an error if the text cannot be written to the terminal.
+	#[inline]
+	pub fn copy_to_clipboard(&mut self, text: &str) -> Result<()> {
+		self.display.copy_to_clipboard(text)
+	}
+
 	/// Get the size of the view.
 	#[inline]
 	#[deprecated = "This leaks internals of the Display and will eventually be removed"]
@@ -178,7 +229,8 @@ impl<C: Tui> View<C> {
 		self.display.get_window_size()
 	}
 
-	/// Render a slice.
+	/// Render a slice. Only the lines that have changed since the previous render are drawn, unless the size of
+	/// the window has changed.
 	///
 	/// # Errors
 	/// Results in an error if there are errors with interacting with the terminal.
@@ -189,23 +241,64 @@ impl<C: Tui> View<C> {
 			return Ok(());
 		}
 		self.last_render_version = current_render_version;
+		let mut frame = self
+			.spare_frame
+			.take()
+			.unwrap_or_else(|| Frame::new(vec![], self.display.get_window_size()));
+		self.rebuild_frame(&mut frame, render_slice);
+
+		match self
+			.previous_frame
+			.as_ref()
+			.and_then(|previous_frame| frame.changed_lines(previous_frame))
+		{
+			Some(changed_lines) if changed_lines.is_empty() => {
+				self.spare_frame = Some(frame);
+				return Ok(());
+			},
+			Some(changed_lines) => {
+				let empty_line = FrameLine::new(vec![]);
+				for index in changed_lines {
+					self.display.move_to_line(index as u16)?;
+					self.draw_frame_line(frame.lines().get(index).unwrap_or(&empty_line), true)?;
+				}
+			},
+			None => {
+				self.display.clear()?;
+				for line in frame.lines() {
+					self.draw_frame_line(line, false)?;
+					self.display.next_line()?;
+				}
+			},
+		}
+		self.display.refresh()?;
+		self.spare_frame = self.previous_frame.replace(frame);
+		Ok(())
+	}
+
+	fn build_frame(&self, render_slice: &RenderSlice) -> Frame {
+		let mut frame = Frame::new(vec![], self.display.get_window_size());
+		self.rebuild_frame(&mut frame, render_slice);
+		frame
+	}
+
+	// the frame is built into the lines of an earlier frame, so that a render allocates only when the lines grow
+	fn rebuild_frame(&self, frame: &mut Frame, render_slice: &RenderSlice) {
 		let view_size = self.display.get_window_size();
 		let window_height = view_size.height();
+		frame.clear(view_size);
 
-		self.display.clear()?;
-
-		self.display.ensure_at_line_start()?;
 		if render_slice.show_title() {
-			self.display.ensure_at_line_start()?;
-			self.draw_title(render_slice.show_help())?;
-			self.display.next_line()?;
+			let title = frame.push_line().push_segment("", DisplayColor::Normal, false);
+			title.set_style(false, true, false);
+			self.build_title(title.text.to_mut(), view_size.width(), render_slice.show_help());
 		}
 
 		let lines = render_slice.get_lines();
 		let leading_line_count = render_slice.get_leading_lines_count();
 		let trailing_line_count = render_slice.get_trailing_lines_count();
 		let lines_count = lines.len() - leading_line_count - trailing_line_count;
-		let show_scroll_bar = render_slice.should_show_scroll_bar();
+		let show_scroll_bar = self.show_scroll_bar && render_slice.should_show_scroll_bar();
 		let scroll_indicator_index = render_slice.get_scroll_index();
 		let view_height = window_height - leading_line_count - trailing_line_count;
 
@@ -214,99 +307,117 @@ impl<C: Tui> View<C> {
 		let trailing_lines_iter = lines.iter().skip(leading_line_count + lines_count);
 
 		for line in leading_lines_iter {
-			self.display.ensure_at_line_start()?;
-			self.draw_view_line(line)?;
-			self.display.next_line()?;
+			Self::build_frame_line(frame.push_line(), line);
 		}
 
 		for (index, line) in lines_iter.enumerate() {
-			self.display.ensure_at_line_start()?;
-			self.draw_view_line(line)?;
+			let frame_line = frame.push_line();
+			Self::build_frame_line(frame_line, line);
 			if show_scroll_bar {
-				self.display.move_from_end_of_line(1)?;
-				self.display.color(DisplayColor::Normal, true)?;
-				self.display
-					.draw_str(if scroll_indicator_index == index { "█" } else { " " })?;
+				frame_line.scroll_indicator = Some(FrameSegment::new_static(
+					if scroll_indicator_index == index { "█" } else { " " },
+					DisplayColor::Normal,
+					true,
+				));
 			}
-			self.display.color(DisplayColor::Normal, false)?;
-			self.display.set_style(false, false, false)?;
-			self.display.next_line()?;
 		}
 
 		if view_height > lines_count {
-			self.display.color(DisplayColor::Normal, false)?;
-			self.display.set_style(false, false, false)?;
 			let draw_height = view_height - lines_count - if render_slice.show_title() { 1 } else { 0 };
-			self.display.ensure_at_line_start()?;
 			for _x in 0..draw_height {
-				self.display.draw_str(self.character_vertical_spacing.as_str())?;
-				self.display.next_line()?;
+				let _segment = frame.push_line().push_segment(
+					self.character_vertical_spacing.as_str(),
+					DisplayColor::Normal,
+					false,
+				);
 			}
 		}
 
 		for line in trailing_lines_iter {
-			self.display.ensure_at_line_start()?;
-			self.draw_view_line(line)?;
-			self.display.next_line()?;
+			Self::build_frame_line(frame.push_line(), line);
 		}
-		self.display.refresh()?;
-		Ok(())
+
+		// a notification is drawn over the last line, so that it does not move the lines of the view
+		if let Some(notification) = render_slice.get_notification() {
+			if let Some(last_line) = frame.last_line_mut() {
+				*last_line = Self::build_notification_line(notification, view_size.width());
+			}
+		}
+	}
+
+	fn build_notification_line(notification: &str, window_width: usize) -> FrameLine {
+		let segment = LineSegment::new(format!(" {} ", notification).as_str());
+		let partial = segment.get_partial_segment(0, window_width);
+		FrameLine::new(vec![
+			FrameSegment::new(
+				" ".repeat(window_width - partial.get_length()).as_str(),
+				DisplayColor::Normal,
+				false,
+			),
+			FrameSegment::new(partial.get_content(), DisplayColor::IndicatorColor, false).style(false, false, true),
+		])
 	}
 
-	fn draw_view_line(&mut self, line: &ViewLine) -> Result<()> {
+	fn build_frame_line(frame_line: &mut FrameLine, line: &ViewLine) {
 		for segment in line.get_segments() {
-			self.display.color(segment.get_color(), line.get_selected())?;
+			frame_line
+				.push_segment(segment.get_content(), segment.get_color(), line.get_selected())
+				.set_style(segment.is_dimmed(), segment.is_underlined(), segment.is_reversed());
+		}
+	}
+
+	fn draw_frame_line(&mut self, line: &FrameLine, clear: bool) -> Result<()> {
+		self.display.ensure_at_line_start()?;
+		for segment in line.segments() {
+			self.display.color(segment.color, segment.selected)?;
 			self.display
-				.set_style(segment.is_dimmed(), segment.is_underlined(), segment.is_reversed())?;
-			self.display.draw_str(segment.get_content())?;
+				.set_style(segment.dim, segment.underline, segment.reverse)?;
+			self.display.draw_str(segment.text.as_ref())?;
 		}
 
 		// reset style
 		self.display.color(DisplayColor::Normal, false)?;
 		self.display.set_style(false, false, false)?;
+		if clear {
+			self.display.clear_until_end_of_line()?;
+		}
+
+		if let Some(ref scroll_indicator) = line.scroll_indicator {
+			self.display.move_from_end_of_line(1)?;
+			self.display.color(scroll_indicator.color, scroll_indicator.selected)?;
+			self.display.draw_str(scroll_indicator.text.as_ref())?;
+			self.display.color(DisplayColor::Normal, false)?;
+		}
 		Ok(())
 	}
 
-	fn draw_title(&mut self, show_help: bool) -> Result<()> {
-		self.display.color(DisplayColor::Normal, false)?;
-		self.display.set_style(false, true, false)?;
-		let window_width = self.display.get_window_size().width();
-
+	fn build_title(&self, title: &mut String, window_width: usize, show_help: bool) {
 		let title_help_indicator_total_length = TITLE_HELP_INDICATOR_LABEL.len() + self.help_indicator_key.len();
 
 		if window_width >= TITLE.len() {
-			self.display.draw_str(TITLE)?;
+			title.push_str(TITLE);
 			// only draw help if there is room
 			if window_width > TITLE.len() + title_help_indicator_total_length {
-				if (window_width - TITLE.len() - title_help_indicator_total_length) > 0 {
-					let padding = " ".repeat(window_width - TITLE.len() - title_help_indicator_total_length);
-					self.display.draw_str(padding.as_str())?;
-				}
+				push_spaces(title, window_width - TITLE.len() - title_help_indicator_total_length);
 				if show_help {
-					self.display
-						.draw_str(format!("{}{}", TITLE_HELP_INDICATOR_LABEL, self.help_indicator_key).as_str())?;
+					title.push_str(TITLE_HELP_INDICATOR_LABEL);
+					title.push_str(self.help_indicator_key.as_str());
 				}
 				else {
-					let padding = " ".repeat(title_help_indicator_total_length);
-					self.display.draw_str(padding.as_str())?;
+					push_spaces(title, title_help_indicator_total_length);
 				}
 			}
-			else if (window_width - TITLE.len()) > 0 {
-				let padding = " ".repeat(window_width - TITLE.len());
-				self.display.draw_str(padding.as_str())?;
+			else {
+				push_spaces(title, window_width - TITLE.len());
 			}
 		}
 		else {
-			self.display.draw_str(TITLE_SHORT)?;
-			if (window_width - TITLE_SHORT.len()) > 0 {
-				let padding = " ".repeat(window_width - TITLE_SHORT.len());
-				self.display.draw_str(padding.as_str())?;
-			}
+			title.push_str(TITLE_SHORT);
+			push_spaces(title, window_width.saturating_sub(TITLE_SHORT.len()));
 		}
-
-		// reset style
-		self.display.color(DisplayColor::Normal, false)?;
-		self.display.set_style(false, false, false)?;
-		Ok(())
 	}
 }
+
+fn push_spaces(text: &mut String, count: usize) {
+	text.extend(iter::repeat(' ').take(count));
+}
@@ -1,4 +1,4 @@
-use std::cell::RefCell;
+use std::borrow::Cow;
 
 use display::DisplayColor;
 use unicode_segmentation::UnicodeSegmentation;
@@ -18,21 +18,19 @@ fn grapheme_column_width(s: &str) -> usize {
 	UnicodeWidthStr::width(s)
 }
 
-pub(crate) struct SegmentPartial {
-	content: String,
+/// The part of the content of a segment that is visible, borrowed from the segment.
+pub(crate) struct SegmentPartial<'s> {
+	content: &'s str,
 	length: usize,
 }
 
-impl SegmentPartial {
-	fn new(content: &str, length: usize) -> Self {
-		Self {
-			content: String::from(content),
-			length,
-		}
+impl<'s> SegmentPartial<'s> {
+	const fn new(content: &'s str, length: usize) -> Self {
+		Self { content, length }
 	}
 
-	pub(crate) fn get_content(&self) -> &str {
-		self.content.as_str()
+	pub(crate) const fn get_content(&self) -> &str {
+		self.content
 	}
 
 	pub(crate) const fn get_length(&self) -> usize {
@@ -46,7 +44,7 @@ pub struct LineSegment {
 	color: DisplayColor,
 	dim: bool,
 	reverse: bool,
-	text: String,
+	text: Cow<'static, str>,
 	length: usize,
 	underline: bool,
 }
@@ -76,18 +74,41 @@ impl LineSegment {
 		underline: bool,
 		reverse: bool,
 	) -> Self {
+		let segment = Self::from(String::from(text));
+		segment.with_color_and_style(color, dim, underline, reverse)
+	}
+
+	/// Set the color and style of the segment. Combined with creating a segment from a `String`, or from a static
+	/// `&str`, a segment is created without copying its content.
+	#[must_use]
+	#[inline]
+	pub const fn with_color_and_style(
+		mut self,
+		color: DisplayColor,
+		dim: bool,
+		underline: bool,
+		reverse: bool,
+	) -> Self {
+		self.color = color;
+		self.dim = dim;
+		self.underline = underline;
+		self.reverse = reverse;
+		self
+	}
+
+	fn from_text(text: Cow<'static, str>) -> Self {
 		Self {
-			text: String::from(text),
-			color,
-			reverse,
-			dim,
-			length: unicode_column_width(text),
-			underline,
+			color: DisplayColor::Normal,
+			dim: false,
+			reverse: false,
+			length: unicode_column_width(text.as_ref()),
+			text,
+			underline: false,
 		}
 	}
 
 	pub(crate) fn get_content(&self) -> &str {
-		self.text.as_str()
+		self.text.as_ref()
 	}
 
 	pub(crate) const fn get_color(&self) -> DisplayColor {
@@ -110,51 +131,81 @@ impl LineSegment {
 		self.length
 	}
 
-	pub(crate) fn get_partial_segment(&self, left: usize, max_width: usize) -> SegmentPartial {
-		let segment_length = unicode_column_width(self.text.as_str());
+	pub(crate) fn get_partial_segment(&self, left: usize, max_width: usize) -> SegmentPartial<'_> {
+		let text = self.get_content();
 
 		// segment is hidden to the left of the line/scroll
-		if segment_length <= left {
-			SegmentPartial::new("", 0)
+		if self.length <= left {
+			return SegmentPartial::new("", 0);
 		}
-		else {
-			let graphemes = UnicodeSegmentation::graphemes(self.text.as_str(), true);
-
-			let skip_length = RefCell::new(0);
-			let graphemes_itr = graphemes.skip_while(|v| {
-				let len = grapheme_column_width(*v);
-				let value = *skip_length.borrow();
-				if value + len > left {
-					false
-				}
-				else {
-					let _ = skip_length.replace(value + len);
-					true
+
+		let mut start = None;
+		let mut end = text.len();
+		let mut skip_length = 0;
+		let mut take_length = 0;
+		for (index, grapheme) in text.grapheme_indices(true) {
+			let length = grapheme_column_width(grapheme);
+			if start.is_none() {
+				if skip_length + length <= left {
+					skip_length += length;
+					continue;
 				}
-			});
-
-			if segment_length - *skip_length.borrow() >= max_width {
-				let take_length = RefCell::new(0);
-				let partial_line = graphemes_itr
-					.take_while(|v| {
-						let len = grapheme_column_width(v);
-						let value = *take_length.borrow();
-						if value + len > max_width {
-							false
-						}
-						else {
-							let _ = take_length.replace(value + len);
-							true
-						}
-					})
-					.collect::<String>();
-				SegmentPartial::new(partial_line.as_str(), take_length.into_inner())
+				start = Some(index);
 			}
-			else {
-				let partial_line = graphemes_itr.collect::<String>();
-				SegmentPartial::new(partial_line.as_str(), segment_length - skip_length.into_inner())
+			if take_length + length > max_width {
+				end = index;
+				break;
 			}
+			take_length += length;
+		}
+		SegmentPartial::new(
+			text.get(start.unwrap_or(end)..end).unwrap_or_default(),
+			take_length,
+		)
+	}
+
+	/// Replace the content of the segment with a part of the content of another segment, with the color and style of
+	/// the other segment. The memory of the content is reused, so that a segment that is rendered again does not
+	/// allocate.
+	pub(crate) fn set_partial(&mut self, segment: &Self, partial: &SegmentPartial<'_>) {
+		self.set_style_from(segment);
+		let text = self.text.to_mut();
+		text.clear();
+		text.push_str(partial.get_content());
+		self.length = partial.get_length();
+	}
+
+	/// Replace the content of the segment with the content of another segment repeated, with the color and style of
+	/// the other segment, reusing the memory of the content.
+	pub(crate) fn set_repeated(&mut self, segment: &Self, count: usize) {
+		self.set_style_from(segment);
+		let text = self.text.to_mut();
+		text.clear();
+		for _ in 0..count {
+			text.push_str(segment.get_content());
 		}
+		self.length = segment.get_length() * count;
+	}
+
+	fn set_style_from(&mut self, segment: &Self) {
+		self.color = segment.color;
+		self.dim = segment.dim;
+		self.underline = segment.underline;
+		self.reverse = segment.reverse;
+	}
+}
+
+impl From<String> for LineSegment {
+	#[inline]
+	fn from(text: String) -> Self {
+		Self::from_text(Cow::Owned(text))
+	}
+}
+
+impl From<&'static str> for LineSegment {
+	#[inline]
+	fn from(text: &'static str) -> Self {
+		Self::from_text(Cow::Borrowed(text))
 	}
 }
 
@@ -404,4 +455,59 @@ mod tests {
 		assert_eq!(partial.get_content(), "123456789");
 		assert_eq!(partial.get_length(), 9);
 	}
+
+	#[test]
+	fn line_segment_case_from_string() {
+		let line_segment = LineSegment::from(String::from("Árvíztűrő tükörfúrógép"));
+
+		assert_eq!(line_segment.get_content(), "Árvíztűrő tükörfúrógép");
+		assert_eq!(line_segment.get_color(), DisplayColor::Normal);
+		assert_eq!(line_segment.get_length(), 22);
+	}
+
+	#[test]
+	fn line_segment_case_from_static_str() {
+		let line_segment = LineSegment::from("1234567890");
+
+		assert_eq!(line_segment.get_content(), "1234567890");
+		assert_eq!(line_segment.get_length(), 10);
+	}
+
+	#[test]
+	fn line_segment_case_with_color_and_style() {
+		let segment = LineSegment::from("Test String");
+		let line_segment = segment.with_color_and_style(DisplayColor::ActionDrop, true, false, true);
+
+		assert_eq!(line_segment.get_color(), DisplayColor::ActionDrop);
+		assert!(line_segment.is_dimmed());
+		assert!(!line_segment.is_underlined());
+		assert!(line_segment.is_reversed());
+	}
+
+	#[test]
+	fn line_segment_case_set_partial() {
+		let mut line_segment = LineSegment::new("a longer segment");
+		let segment = LineSegment::new_with_color_and_style("1😊2😊3", DisplayColor::IndicatorColor, true, true, true);
+
+		line_segment.set_partial(&segment, &segment.get_partial_segment(1, 5));
+
+		assert_eq!(line_segment.get_content(), "😊2😊");
+		assert_eq!(line_segment.get_length(), 5);
+		assert_eq!(line_segment.get_color(), DisplayColor::IndicatorColor);
+		assert!(line_segment.is_dimmed());
+		assert!(line_segment.is_underlined());
+		assert!(line_segment.is_reversed());
+	}
+
+	#[test]
+	fn line_segment_case_set_repeated() {
+		let mut line_segment = LineSegment::from("static");
+		let segment = LineSegment::new_with_color("😊", DisplayColor::IndicatorColor);
+
+		line_segment.set_repeated(&segment, 3);
+
+		assert_eq!(line_segment.get_content(), "😊😊😊");
+		assert_eq!(line_segment.get_length(), 6);
+		assert_eq!(line_segment.get_color(), DisplayColor::IndicatorColor);
+	}
 }
@@ -1,11 +1,14 @@
-const MINIMUM_WINDOW_HEIGHT: usize = 5; // title + pad top + line + pad bottom + help
+use super::Layout;
+
+const MINIMUM_WINDOW_HEIGHT: usize = 2; // line + help, the title and padding are hidden on short windows
+const MINIMUM_WINDOW_WIDTH: usize = 10; // ">s mmmmmmm".len()
 const MINIMUM_COMPACT_WINDOW_WIDTH: usize = 20; // ">s ccc mmmmmmmmmmmmm".len()
-const MINIMUM_FULL_WINDOW_WIDTH: usize = 34; // " > squash cccccccc mmmmmmmmmmmmm %".len()
 
 /// Represents data associated with rendering content.
 #[derive(Debug, Copy, Clone)]
 pub struct RenderContext {
 	height: usize,
+	layout: Layout,
 	width: usize,
 }
 
@@ -16,15 +19,17 @@ impl RenderContext {
 	pub const fn new(width: u16, height: u16) -> Self {
 		Self {
 			height: height as usize,
+			layout: Layout::from_width(width as usize),
 			width: width as usize,
 		}
 	}
 
-	/// Update the recorded width and height.
+	/// Update the recorded width and height, and the layout for the new width.
 	#[inline]
 	pub fn update(&mut self, width: u16, height: u16) {
 		self.width = width as usize;
 		self.height = height as usize;
+		self.layout = Layout::from_width_with_previous(self.width, self.layout);
 	}
 
 	/// Get the width of the terminal window.
@@ -41,11 +46,18 @@ impl RenderContext {
 		self.height
 	}
 
+	/// Get the layout for the terminal window.
+	#[must_use]
+	#[inline]
+	pub const fn layout(&self) -> Layout {
+		self.layout
+	}
+
 	/// Is the terminal window width at least the minimal supported width.
 	#[must_use]
 	#[inline]
 	pub const fn is_minimum_view_width(&self) -> bool {
-		self.width > MINIMUM_COMPACT_WINDOW_WIDTH
+		self.width > MINIMUM_WINDOW_WIDTH
 	}
 
 	/// Is the terminal window height at least the minimal supported height.
@@ -55,11 +67,19 @@ impl RenderContext {
 		self.height > MINIMUM_WINDOW_HEIGHT
 	}
 
+	/// Is the terminal window large enough to render lines using the compact width, with optional columns, such as
+	/// the commit hash, shortened instead of hidden.
+	#[must_use]
+	#[inline]
+	pub const fn is_compact_width(&self) -> bool {
+		self.width > MINIMUM_COMPACT_WINDOW_WIDTH
+	}
+
 	/// Is the terminal window large enough to render lines using their full width.
 	#[must_use]
 	#[inline]
 	pub const fn is_full_width(&self) -> bool {
-		self.width >= MINIMUM_FULL_WINDOW_WIDTH
+		!matches!(self.layout, Layout::Compact)
 	}
 
 	/// Is the terminal window too small to render content.
@@ -76,45 +96,58 @@ mod tests {
 
 	#[test]
 	fn update() {
-		let mut context = RenderContext { width: 10, height: 20 };
+		let mut context = RenderContext::new(10, 20);
 		context.update(100, 200);
 		assert_eq!(context.width(), 100);
 		assert_eq!(context.height(), 200);
+		assert_eq!(context.layout(), Layout::Full);
+	}
+
+	#[test]
+	fn update_with_layout_hysteresis() {
+		let mut context = RenderContext::new(130, 20);
+		context.update(100, 20);
+		assert_eq!(context.layout(), Layout::Full);
+		context.update(121, 20);
+		assert_eq!(context.layout(), Layout::Full);
+		context.update(130, 20);
+		assert_eq!(context.layout(), Layout::Wide);
+	}
+
+	#[test]
+	fn is_full_width() {
+		assert!(!RenderContext::new(30, 10).is_full_width());
+		assert!(RenderContext::new(40, 10).is_full_width());
+		assert!(RenderContext::new(200, 10).is_full_width());
+	}
+
+	#[test]
+	fn is_compact_width() {
+		assert!(!RenderContext::new(MINIMUM_COMPACT_WINDOW_WIDTH as u16, 10).is_compact_width());
+		assert!(RenderContext::new(MINIMUM_COMPACT_WINDOW_WIDTH as u16 + 1, 10).is_compact_width());
 	}
 
 	#[test]
 	fn is_window_too_small_width_too_small() {
-		let context = RenderContext {
-			width: MINIMUM_COMPACT_WINDOW_WIDTH,
-			height: MINIMUM_WINDOW_HEIGHT + 1,
-		};
+		let context = RenderContext::new(MINIMUM_WINDOW_WIDTH as u16, MINIMUM_WINDOW_HEIGHT as u16 + 1);
 		assert!(context.is_window_too_small());
 	}
 
 	#[test]
 	fn is_window_too_small_height_too_small() {
-		let context = RenderContext {
-			width: MINIMUM_COMPACT_WINDOW_WIDTH + 1,
-			height: MINIMUM_WINDOW_HEIGHT,
-		};
+		let context = RenderContext::new(MINIMUM_WINDOW_WIDTH as u16 + 1, MINIMUM_WINDOW_HEIGHT as u16);
 		assert!(context.is_window_too_small());
 	}
 
 	#[test]
 	fn is_window_too_small_height_and_width_too_small() {
-		let context = RenderContext {
-			width: MINIMUM_COMPACT_WINDOW_WIDTH,
-			height: MINIMUM_WINDOW_HEIGHT,
-		};
+		let context = RenderContext::new(MINIMUM_WINDOW_WIDTH as u16, MINIMUM_WINDOW_HEIGHT as u16);
 		assert!(context.is_window_too_small());
 	}
 
 	#[test]
 	fn is_window_too_small_width_and_height_large() {
-		let context = RenderContext {
-			width: MINIMUM_COMPACT_WINDOW_WIDTH + 1,
-			height: MINIMUM_WINDOW_HEIGHT + 1,
-		};
+		let context = RenderContext::new(MINIMUM_WINDOW_WIDTH as u16 + 1, MINIMUM_WINDOW_HEIGHT as u16 + 1);
 		assert!(!context.is_window_too_small());
 	}
 }
@@ -0,0 +1,96 @@
+use std::time::{Duration, Instant};
+
+/// Schedules the renders of the view, coalescing render requests that arrive faster than the render interval into a
+/// single render, and skipping renders entirely when none have been requested.
+#[derive(Debug)]
+pub(crate) struct RenderScheduler {
+	interval: Duration,
+	next_render: Instant,
+	pending: bool,
+}
+
+impl RenderScheduler {
+	pub(crate) fn new(interval: Duration) -> Self {
+		Self {
+			interval,
+			next_render: Instant::now(),
+			pending: false,
+		}
+	}
+
+	/// Request a render, that will happen once the render interval since the previous render has elapsed.
+	pub(crate) fn request(&mut self) {
+		self.pending = true;
+	}
+
+	/// Get the time to wait for the next render, or `None` when no render has been requested and the wait can be
+	/// indefinite.
+	pub(crate) fn timeout(&self, now: Instant) -> Option<Duration> {
+		self.pending.then(|| self.next_render.saturating_duration_since(now))
+	}
+
+	/// Should a render happen now, if so, the request is cleared and the next render is delayed by the interval.
+	pub(crate) fn should_render(&mut self, now: Instant) -> bool {
+		if !self.pending || now < self.next_render {
+			return false;
+		}
+		self.pending = false;
+		self.next_render = now + self.interval;
+		true
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const INTERVAL: Duration = Duration::from_millis(20);
+
+	#[test]
+	fn no_render_without_request() {
+		let mut scheduler = RenderScheduler::new(INTERVAL);
+		let now = Instant::now();
+		assert_eq!(scheduler.timeout(now), None);
+		assert!(!scheduler.should_render(now + INTERVAL));
+	}
+
+	#[test]
+	fn first_request_renders_immediately() {
+		let mut scheduler = RenderScheduler::new(INTERVAL);
+		let now = Instant::now();
+		scheduler.request();
+		assert_eq!(scheduler.timeout(now), Some(Duration::from_millis(0)));
+		assert!(scheduler.should_render(now));
+		assert_eq!(scheduler.timeout(now), None);
+	}
+
+	#[test]
+	fn requests_within_interval_are_coalesced() {
+		let mut scheduler = RenderScheduler::new(INTERVAL);
+		let now = Instant::now();
+		scheduler.request();
+		assert!(scheduler.should_render(now));
+		scheduler.request();
+		scheduler.request();
+		assert!(!scheduler.should_render(now + Duration::from_millis(5)));
+		assert_eq!(
+			scheduler.timeout(now + Duration::from_millis(5)),
+			Some(Duration::from_millis(15))
+		);
+		assert!(scheduler.should_render(now + INTERVAL));
+		assert!(!scheduler.should_render(now + INTERVAL * 3));
+	}
+
+	#[test]
+	fn interval_after_idle_does_not_accumulate() {
+		let mut scheduler = RenderScheduler::new(INTERVAL);
+		let now = Instant::now();
+		scheduler.request();
+		assert!(scheduler.should_render(now));
+		let later = now + Duration::from_secs(10);
+		scheduler.request();
+		assert!(scheduler.should_render(later));
+		scheduler.request();
+		assert!(!scheduler.should_render(later + Duration::from_millis(1)));
+	}
+}
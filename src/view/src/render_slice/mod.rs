@@ -10,7 +10,10 @@ use std::{
 
 pub(crate) use render_action::RenderAction;
 
-use super::{scroll_position::ScrollPosition, LineSegment, ViewData, ViewLine};
+use super::{scroll_position::ScrollPosition, ViewData, ViewLine};
+
+// the title is hidden on windows that are too short to show it along with a few lines
+const MINIMUM_TITLE_WINDOW_HEIGHT: usize = 3;
 
 #[derive(Debug)]
 pub struct RenderSlice {
@@ -20,6 +23,7 @@ pub struct RenderSlice {
 	lines_count: usize,
 	lines_leading_count: usize,
 	lines_trailing_count: usize,
+	notification: Option<String>,
 	padding_height: usize,
 	scroll_position: ScrollPosition,
 	scroll_position_cache: HashMap<String, ScrollPosition>,
@@ -41,6 +45,7 @@ impl RenderSlice {
 			lines_count: 0,
 			lines_leading_count: 0,
 			lines_trailing_count: 0,
+			notification: None,
 			padding_height: 0,
 			scroll_position: ScrollPosition::new(),
 			scroll_position_cache: HashMap::new(),
@@ -82,6 +87,15 @@ impl RenderSlice {
 		self.actions.push_back(RenderAction::Resize(width, height));
 	}
 
+	/// Set the notification that is shown over the bottom of the view, or none to hide it. The slice is only
+	/// rendered again when the notification changes.
+	pub fn set_notification(&mut self, notification: Option<&str>) {
+		if self.notification.as_deref() != notification {
+			self.notification = notification.map(String::from);
+			self.version += 1;
+		}
+	}
+
 	pub fn sync_view_data(&mut self, view_data: &ViewData) {
 		let cache_expired = self.cache_expired(view_data);
 		// scroll position depends on padding, so if the view has changed it needs to be updated early
@@ -98,7 +112,12 @@ impl RenderSlice {
 				RenderAction::ScrollLeft => self.scroll_position.scroll_left(),
 				RenderAction::PageUp => self.scroll_position.page_up(),
 				RenderAction::PageDown => self.scroll_position.page_down(),
-				RenderAction::Resize(width, height) => self.set_size(width, height),
+				RenderAction::Resize(width, height) => {
+					self.set_size(width, height);
+					// the title may have been hidden, or shown again, at the new height
+					self.set_padding_height(view_data);
+					self.update_scroll_position_size();
+				},
 			}
 		}
 		if has_actions || cache_expired {
@@ -181,6 +200,10 @@ impl RenderSlice {
 		&self.lines
 	}
 
+	pub(crate) fn get_notification(&self) -> Option<&str> {
+		self.notification.as_deref()
+	}
+
 	pub(crate) const fn get_version(&self) -> u32 {
 		self.version
 	}
@@ -193,6 +216,10 @@ impl RenderSlice {
 		self.view_data_name != view_data.get_name() || self.view_data_version != view_data.get_version()
 	}
 
+	fn is_title_visible(&self, view_data: &ViewData) -> bool {
+		view_data.show_title() && (self.height == 0 || self.height > MINIMUM_TITLE_WINDOW_HEIGHT)
+	}
+
 	fn set_size(&mut self, view_width: usize, view_height: usize) {
 		if self.height != view_height || self.width != view_width {
 			self.height = view_height;
@@ -202,7 +229,7 @@ impl RenderSlice {
 	}
 
 	fn set_padding_height(&mut self, view_data: &ViewData) {
-		let padding_height = if view_data.show_title() { 1 } else { 0 }
+		let padding_height = if self.is_title_visible(view_data) { 1 } else { 0 }
 			+ view_data.get_leading_lines().len()
 			+ view_data.get_trailing_lines().len();
 
@@ -253,12 +280,14 @@ impl RenderSlice {
 	fn rebuild(&mut self, view_data: &ViewData) {
 		let leading_lines_length = view_data.get_leading_lines().len();
 		let trailing_lines_length = view_data.get_trailing_lines().len();
-		let lines_length = view_data.get_lines().len();
+		let lines_length = view_data.get_lines_length();
+		let lines_offset = view_data.get_lines_offset();
 
 		self.version += 1;
-		self.view_data_name = String::from(view_data.get_name());
+		self.view_data_name.clear();
+		self.view_data_name.push_str(view_data.get_name());
 		self.view_data_version = view_data.get_version();
-		self.show_title = view_data.show_title();
+		self.show_title = self.is_title_visible(view_data);
 		self.show_help = view_data.show_help();
 		self.should_show_scrollbar =
 			self.padding_height < self.height && lines_length > (self.height - self.padding_height);
@@ -340,7 +369,11 @@ impl RenderSlice {
 				available_height
 			};
 
-			let max_line_length = Self::calculate_max_line_length(view_data.get_lines(), lines_start, lines_end);
+			let max_line_length = Self::calculate_max_line_length(
+				view_data.get_lines(),
+				lines_start.saturating_sub(lines_offset),
+				lines_end,
+			);
 			(
 				lines_start,
 				lines_end,
@@ -360,15 +393,17 @@ impl RenderSlice {
 			self.scroll_position.ensure_column_visible(*column);
 		}
 
-		self.lines.clear();
-		self.push_lines(view_data.get_leading_lines(), 0, leading_lines_end, false);
-		self.push_lines(
+		// the lines of the previous render are replaced, rather than cleared, so that their memory is reused
+		let mut index = self.push_lines(0, view_data.get_leading_lines(), 0, leading_lines_end, false);
+		index = self.push_lines(
+			index,
 			view_data.get_lines(),
-			lines_start,
+			lines_start.saturating_sub(lines_offset),
 			lines_end,
 			self.should_show_scrollbar,
 		);
-		self.push_lines(view_data.get_trailing_lines(), 0, trailing_lines_end, false);
+		index = self.push_lines(index, view_data.get_trailing_lines(), 0, trailing_lines_end, false);
+		self.lines.truncate(index);
 	}
 
 	fn calculate_max_line_length(view_lines: &[ViewLine], start: usize, length: usize) -> usize {
@@ -393,7 +428,15 @@ impl RenderSlice {
 			})
 	}
 
-	fn push_lines(&mut self, view_lines: &[ViewLine], start: usize, end: usize, scroll_bar: bool) {
+	// render the lines into the lines of the slice, starting at `index`, returning the index after the last line
+	fn push_lines(
+		&mut self,
+		mut index: usize,
+		view_lines: &[ViewLine],
+		start: usize,
+		end: usize,
+		scroll_bar: bool,
+	) -> usize {
 		let window_width = if scroll_bar && self.width > 0 {
 			self.width - 1
 		}
@@ -402,10 +445,16 @@ impl RenderSlice {
 		};
 		let left = self.scroll_position.get_left_position();
 
-		view_lines.iter().skip(start).take(end).for_each(|line| {
+		for line in view_lines.iter().skip(start).take(end) {
+			if index >= self.lines.len() {
+				self.lines.push(ViewLine::new_empty_line());
+			}
+			let rendered_line = self.lines.get_mut(index).expect("line must exist after it is pushed");
+			index += 1;
+			rendered_line.reuse(line.get_number_of_pinned_segment(), line.get_selected());
 			let mut cursor = 0;
 			let mut left_start = 0;
-			let mut segments = vec![];
+			let mut segment_count = 0;
 			// window width can be zero when there is a scrollbar and a view width of 1
 			if window_width > 0 {
 				for (i, segment) in line.get_segments().iter().enumerate() {
@@ -417,13 +466,8 @@ impl RenderSlice {
 					let partial = segment.get_partial_segment(left_start, window_width - cursor);
 
 					if partial.get_length() > 0 {
-						segments.push(LineSegment::new_with_color_and_style(
-							partial.get_content(),
-							segment.get_color(),
-							segment.is_dimmed(),
-							segment.is_underlined(),
-							segment.is_reversed(),
-						));
+						rendered_line.segment_mut(segment_count).set_partial(segment, &partial);
+						segment_count += 1;
 
 						cursor += partial.get_length();
 						if cursor >= window_width {
@@ -438,21 +482,15 @@ impl RenderSlice {
 
 				if cursor < window_width {
 					if let Some(padding) = line.get_padding().as_ref() {
-						segments.push(LineSegment::new_with_color_and_style(
-							padding.get_content().repeat(window_width - cursor).as_str(),
-							padding.get_color(),
-							padding.is_dimmed(),
-							padding.is_underlined(),
-							padding.is_reversed(),
-						));
+						rendered_line
+							.segment_mut(segment_count)
+							.set_repeated(padding, window_width - cursor);
+						segment_count += 1;
 					}
 				}
 			}
-
-			self.lines.push(
-				ViewLine::new_with_pinned_segments(segments, line.get_number_of_pinned_segment())
-					.set_selected(line.get_selected()),
-			);
-		});
+			rendered_line.truncate_segments(segment_count);
+		}
+		index
 	}
 }
@@ -1,7 +1,10 @@
 use display::DisplayColor;
 
 use super::*;
-use crate::testutil::{_assert_rendered_output, render_view_line, AssertRenderOptions};
+use crate::{
+	testutil::{_assert_rendered_output, render_view_line, AssertRenderOptions},
+	LineSegment,
+};
 
 fn assert_rendered(render_slice: &RenderSlice, expected: &[&str]) {
 	let mut output = vec![];
@@ -209,6 +212,37 @@ fn resize_action() {
 	]);
 }
 
+#[test]
+fn resize_action_hides_title_on_short_window() {
+	let mut view_data = create_view_data(0, 3, 0);
+	view_data.update_view_data(|updater| updater.set_show_title(true));
+	let mut render_slice = create_render_slice(100, 100, &view_data);
+	render_slice.record_resize(100, 3);
+	render_slice.sync_view_data(&view_data);
+	assert_rendered(&render_slice, &[
+		"{BODY}",
+		"{Normal}B(1)",
+		"{Normal}B(2)",
+		"{Normal}B(3)",
+	]);
+}
+
+#[test]
+fn resize_action_shows_title_after_short_window() {
+	let mut view_data = create_view_data(0, 3, 0);
+	view_data.update_view_data(|updater| updater.set_show_title(true));
+	let mut render_slice = create_render_slice(100, 3, &view_data);
+	render_slice.record_resize(100, 100);
+	render_slice.sync_view_data(&view_data);
+	assert_rendered(&render_slice, &[
+		"{TITLE}",
+		"{BODY}",
+		"{Normal}B(1)",
+		"{Normal}B(2)",
+		"{Normal}B(3)",
+	]);
+}
+
 #[test]
 fn resize_action_zero_width() {
 	let view_data = create_view_data(0, 3, 0);
@@ -261,6 +295,29 @@ fn rebuild_with_cache_miss_new_version() {
 	assert_rendered(&render_slice, &["{BODY}", "{Normal}B(1)", "{Normal}View Data 2"]);
 }
 
+#[test]
+fn set_notification_changes_version() {
+	let view_data = create_view_data(0, 1, 0);
+	let mut render_slice = create_render_slice(100, 300, &view_data);
+	let version = render_slice.get_version();
+	render_slice.set_notification(Some("Notification"));
+	assert_ne!(version, render_slice.get_version());
+	assert_eq!(render_slice.get_notification(), Some("Notification"));
+}
+
+#[test]
+fn set_notification_unchanged_keeps_version() {
+	let view_data = create_view_data(0, 1, 0);
+	let mut render_slice = create_render_slice(100, 300, &view_data);
+	render_slice.set_notification(Some("Notification"));
+	let version = render_slice.get_version();
+	render_slice.set_notification(Some("Notification"));
+	assert_eq!(version, render_slice.get_version());
+	render_slice.set_notification(None);
+	assert_ne!(version, render_slice.get_version());
+	assert_eq!(render_slice.get_notification(), None);
+}
+
 #[test]
 fn swap_active_scroll_position() {
 	let mut view_data_1 = create_view_data(0, 10, 0);
@@ -759,12 +816,13 @@ fn with_height_only_for_leading_lines() {
 }
 
 #[test]
-fn with_height_only_for_leading_lines_with_title() {
+fn with_height_only_for_leading_lines_with_hidden_title() {
 	let mut view_data = create_view_data(2, 3, 2);
 	view_data.update_view_data(|updater| updater.set_show_title(true));
 	let render_slice = create_render_slice(100, 3, &view_data);
 	assert_rendered(&render_slice, &[
-		"{TITLE}",
+		"{LEADING}",
+		"{Normal}L(1)",
 		"{TRAILING}",
 		"{Normal}T(1)",
 		"{Normal}T(2)",
@@ -778,11 +836,11 @@ fn with_zero_height() {
 }
 
 #[test]
-fn with_height_for_title() {
+fn with_height_for_one_line_with_hidden_title() {
 	let mut view_data = create_view_data(2, 2, 2);
 	view_data.update_view_data(|updater| updater.set_show_title(true));
 	let render_slice = create_render_slice(100, 1, &view_data);
-	assert_rendered(&render_slice, &["{TITLE}", "{EMPTY}"]);
+	assert_rendered(&render_slice, &["{TRAILING}", "{Normal}T(1)"]);
 }
 
 #[test]
@@ -1218,3 +1276,46 @@ fn calculate_max_line_length_ignore_pinned() {
 	];
 	assert_eq!(RenderSlice::calculate_max_line_length(&view_lines, 0, 4), 16);
 }
+
+#[test]
+fn virtual_lines_render_same_as_all_lines() {
+	let all_lines_view_data = ViewData::new(|updater| {
+		for index in 0..1000 {
+			updater.push_line(ViewLine::from(format!("B({})", index)));
+		}
+		updater.ensure_line_visible(510);
+	});
+	let virtual_lines_view_data = ViewData::new(|updater| {
+		updater.set_virtual_lines(490, 1000);
+		for index in 490..530 {
+			updater.push_line(ViewLine::from(format!("B({})", index)));
+		}
+		updater.ensure_line_visible(510);
+	});
+	let all_lines_render_slice = create_render_slice(100, 5, &all_lines_view_data);
+	let virtual_lines_render_slice = create_render_slice(100, 5, &virtual_lines_view_data);
+	assert_rendered(&virtual_lines_render_slice, &[
+		"{BODY}",
+		"{Normal}B(506)",
+		"{Normal}B(507)",
+		"{Normal}B(508)",
+		"{Normal}B(509)",
+		"{Normal}B(510)",
+	]);
+	assert_eq!(
+		virtual_lines_render_slice
+			.get_lines()
+			.iter()
+			.map(render_view_line)
+			.collect::<Vec<String>>(),
+		all_lines_render_slice
+			.get_lines()
+			.iter()
+			.map(render_view_line)
+			.collect::<Vec<String>>()
+	);
+	assert_eq!(
+		virtual_lines_render_slice.get_scroll_index(),
+		all_lines_render_slice.get_scroll_index()
+	);
+}
@@ -9,6 +9,7 @@ use std::{
 };
 
 use anyhow::{anyhow, Error, Result};
+use config::Theme;
 
 use super::{action::ViewAction, render_slice::RenderSlice, view_data::ViewData};
 
@@ -19,9 +20,11 @@ fn map_send_err(_: mpsc::SendError<ViewAction>) -> Error {
 /// Represents a message sender and receiver for passing actions between threads.
 #[derive(Clone, Debug)]
 pub struct Sender {
+	clipboard: Arc<Mutex<Option<String>>>,
 	poisoned: Arc<AtomicBool>,
 	sender: mpsc::Sender<ViewAction>,
 	render_slice: Arc<Mutex<RenderSlice>>,
+	theme: Arc<Mutex<Option<Theme>>>,
 }
 
 impl Sender {
@@ -29,9 +32,11 @@ impl Sender {
 	#[inline]
 	pub fn new(sender: mpsc::Sender<ViewAction>) -> Self {
 		Self {
+			clipboard: Arc::new(Mutex::new(None)),
 			poisoned: Arc::new(AtomicBool::new(false)),
 			sender,
 			render_slice: Arc::new(Mutex::new(RenderSlice::new())),
+			theme: Arc::new(Mutex::new(None)),
 		}
 	}
 
@@ -53,6 +58,18 @@ impl Sender {
 		Arc::clone(&self.render_slice)
 	}
 
+	/// Clone the pending theme update.
+	#[inline]
+	pub fn clone_theme(&self) -> Arc<Mutex<Option<Theme>>> {
+		Arc::clone(&self.theme)
+	}
+
+	/// Clone the pending text for the clipboard.
+	#[inline]
+	pub fn clone_clipboard(&self) -> Arc<Mutex<Option<String>>> {
+		Arc::clone(&self.clipboard)
+	}
+
 	/// Queue a start action.
 	///
 	/// # Errors
@@ -151,6 +168,37 @@ impl Sender {
 			.record_resize(width as usize, height as usize);
 	}
 
+	/// Set the notification that is shown over the bottom of the view, or none to hide it. The notification is shown
+	/// by the next render.
+	#[inline]
+	pub fn set_notification(&self, notification: Option<&str>) {
+		self.render_slice
+			.lock()
+			.expect("Unable to lock render slice")
+			.borrow_mut()
+			.set_notification(notification);
+	}
+
+	/// Queue an update of the colors of the view from `theme`.
+	///
+	/// # Errors
+	/// Results in an error if the sender has been closed.
+	#[inline]
+	pub fn update_theme(&self, theme: &Theme) -> Result<()> {
+		*self.theme.lock().map_err(|_err| anyhow!("Unable to lock theme"))? = Some(theme.clone());
+		self.sender.send(ViewAction::UpdateTheme).map_err(map_send_err)
+	}
+
+	/// Queue a copy of `text` to the clipboard of the terminal.
+	///
+	/// # Errors
+	/// Results in an error if the sender has been closed.
+	#[inline]
+	pub fn copy_to_clipboard(&self, text: &str) -> Result<()> {
+		*self.clipboard.lock().map_err(|_err| anyhow!("Unable to lock clipboard"))? = Some(String::from(text));
+		self.sender.send(ViewAction::CopyToClipboard).map_err(map_send_err)
+	}
+
 	/// Sync the `ViewData` and queue a render action.
 	///
 	/// # Errors
@@ -170,6 +218,8 @@ impl Sender {
 mod tests {
 	use std::sync::atomic::Ordering;
 
+	use config::Theme;
+
 	use crate::{
 		testutil::{render_view_line, with_view_sender},
 		ViewData,
@@ -232,6 +282,43 @@ mod tests {
 		});
 	}
 
+	#[test]
+	fn update_theme_success() {
+		with_view_sender(|context| {
+			context.sender.update_theme(&Theme::new()).unwrap();
+			context.assert_sent_messages(vec!["UpdateTheme"]);
+			assert!(context.sender.clone_theme().lock().unwrap().is_some());
+		});
+	}
+
+	#[test]
+	fn update_theme_error() {
+		with_view_sender(|mut context| {
+			context.drop_receiver();
+			assert_eq!(
+				context.sender.update_theme(&Theme::new()).unwrap_err().to_string(),
+				"Unable to send data"
+			);
+		});
+	}
+
+	#[test]
+	fn copy_to_clipboard_success() {
+		with_view_sender(|context| {
+			context.sender.copy_to_clipboard("Text").unwrap();
+			context.assert_sent_messages(vec!["CopyToClipboard"]);
+			assert_eq!(context.sender.clone_clipboard().lock().unwrap().as_deref(), Some("Text"));
+		});
+	}
+
+	#[test]
+	fn copy_to_clipboard_error() {
+		with_view_sender(|mut context| {
+			context.drop_receiver();
+			assert_eq!(context.sender.copy_to_clipboard("Text").unwrap_err().to_string(), "Unable to send data");
+		});
+	}
+
 	#[test]
 	fn scroll_up() {
 		with_view_sender(|context| {
@@ -288,6 +375,17 @@ mod tests {
 		});
 	}
 
+	#[test]
+	fn set_notification() {
+		with_view_sender(|context| {
+			context.sender.set_notification(Some("Notification"));
+			assert_eq!(
+				context.sender.clone_render_slice().lock().unwrap().get_notification(),
+				Some("Notification")
+			);
+		});
+	}
+
 	#[test]
 	fn render() {
 		with_view_sender(|context| {
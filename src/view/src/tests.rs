@@ -1,14 +1,37 @@
+use std::{cell::RefCell, io::Write, rc::Rc};
+
 use config::Theme;
-use display::testutil::{assert_output, CrossTerm};
+use crossterm::{
+	cursor::{MoveTo, MoveToColumn, MoveToNextLine},
+	event::Event,
+	style::{Attribute, Colors, Print, ResetColor, SetAttribute, SetColors},
+	terminal::{Clear, ClearType},
+	QueueableCommand,
+};
+use display::{
+	testutil::{assert_output, assert_screen, set_display_size, CrossTerm},
+	ColorMode,
+};
 
 use super::*;
 
 fn assert_render(width: usize, height: usize, view_data: &ViewData, expected: &[&str]) {
+	assert_render_with_scroll_bar(width, height, true, view_data, expected);
+}
+
+fn assert_render_with_scroll_bar(
+	width: usize,
+	height: usize,
+	show_scroll_bar: bool,
+	view_data: &ViewData,
+	expected: &[&str],
+) {
 	let theme = Theme::new();
 	let mut crossterm = CrossTerm::new();
 	crossterm.set_size(Size::new(width, height));
 	let display = Display::new(crossterm, &theme);
 	let mut view = View::new(display, "~", "?");
+	view.set_show_scroll_bar(show_scroll_bar);
 
 	let mut render_slice = RenderSlice::new();
 	render_slice.record_resize(width, height);
@@ -184,3 +207,338 @@ fn render_with_scroll_bar() {
 		],
 	);
 }
+
+#[test]
+fn render_with_scroll_bar_hidden() {
+	assert_render_with_scroll_bar(
+		30,
+		4,
+		false,
+		&ViewData::new(|updater| {
+			updater.push_line(ViewLine::from("This is line 1"));
+			updater.push_line(ViewLine::from("This is line 2"));
+			updater.push_line(ViewLine::from("This is line 3"));
+			updater.push_line(ViewLine::from("This is line 4"));
+			updater.push_line(ViewLine::from("This is line 5"));
+		}),
+		&["This is line 1", "This is line 2", "This is line 3", "This is line 4"],
+	);
+}
+
+#[test]
+fn set_theme_forces_render() {
+	let mut crossterm = CrossTerm::new();
+	crossterm.set_size(Size::new(20, 1));
+	let display = Display::new(crossterm, &Theme::new());
+	let mut view = View::new(display, "~", "?");
+
+	let mut render_slice = RenderSlice::new();
+	render_slice.record_resize(20, 1);
+	render_slice.sync_view_data(&ViewData::new(|_| {}));
+	view.render(&render_slice).unwrap();
+	view.display.clear().unwrap();
+	view.set_theme(&Theme::new());
+	view.render(&render_slice).unwrap();
+	assert_output(&view.display, &["~"]);
+}
+
+#[test]
+fn start_forces_render() {
+	let mut crossterm = CrossTerm::new();
+	crossterm.set_size(Size::new(20, 1));
+	let display = Display::new(crossterm, &Theme::new());
+	let mut view = View::new(display, "~", "?");
+
+	let mut render_slice = RenderSlice::new();
+	render_slice.record_resize(20, 1);
+	render_slice.sync_view_data(&ViewData::new(|_| {}));
+	view.render(&render_slice).unwrap();
+	view.display.clear().unwrap();
+	view.start().unwrap();
+	view.render(&render_slice).unwrap();
+	assert_output(&view.display, &["~"]);
+}
+
+fn create_list_view_data(selected: usize) -> ViewData {
+	ViewData::new(|updater| {
+		updater.set_show_title(true);
+		for index in 0..100 {
+			let line = ViewLine::from(vec![
+				LineSegment::new_with_color(" pick   ", DisplayColor::ActionPick),
+				LineSegment::new(format!("{:08x} ", index * 12345).as_str()),
+				LineSegment::new(format!("A commit with a reasonably long summary, number {}", index).as_str()),
+			]);
+			updater.push_line(line.set_selected(index == selected));
+		}
+	})
+}
+
+fn render_view_data<C: Tui>(view: &mut View<C>, render_slice: &mut RenderSlice, view_data: &ViewData) {
+	render_slice.sync_view_data(view_data);
+	view.render(render_slice).unwrap();
+}
+
+#[test]
+fn render_only_changed_lines() {
+	let mut crossterm = CrossTerm::new();
+	crossterm.set_size(Size::new(20, 3));
+	let mut view = View::new(Display::new(crossterm, &Theme::new()), "~", "?");
+	let mut render_slice = RenderSlice::new();
+	render_slice.record_resize(20, 3);
+	let mut view_data = ViewData::new(|updater| {
+		updater.push_line(ViewLine::from("first"));
+		updater.push_line(ViewLine::from("second"));
+	});
+	render_view_data(&mut view, &mut render_slice, &view_data);
+	view_data.update_view_data(|updater| {
+		updater.clear();
+		updater.push_line(ViewLine::from("first"));
+		updater.push_line(ViewLine::from("changed"));
+	});
+	render_view_data(&mut view, &mut render_slice, &view_data);
+
+	assert_screen(&view.display, &["first", "changed", "~"]);
+}
+
+#[test]
+fn render_shorter_line_clears_previous_content() {
+	let mut crossterm = CrossTerm::new();
+	crossterm.set_size(Size::new(20, 1));
+	let mut view = View::new(Display::new(crossterm, &Theme::new()), "~", "?");
+	let mut render_slice = RenderSlice::new();
+	render_slice.record_resize(20, 1);
+	let mut view_data = ViewData::new(|updater| updater.push_line(ViewLine::from("a longer line")));
+	render_view_data(&mut view, &mut render_slice, &view_data);
+	view_data.update_view_data(|updater| {
+		updater.clear();
+		updater.push_line(ViewLine::from("short"));
+	});
+	render_view_data(&mut view, &mut render_slice, &view_data);
+
+	assert_screen(&view.display, &["short"]);
+}
+
+fn create_notification_view(width: usize, height: usize) -> (View<CrossTerm>, RenderSlice) {
+	let mut crossterm = CrossTerm::new();
+	crossterm.set_size(Size::new(width, height));
+	let view = View::new(Display::new(crossterm, &Theme::new()), "~", "?");
+	let mut render_slice = RenderSlice::new();
+	render_slice.record_resize(width, height);
+	(view, render_slice)
+}
+
+#[test]
+fn render_notification() {
+	let (mut view, mut render_slice) = create_notification_view(20, 3);
+	let view_data = ViewData::new(|updater| updater.push_line(ViewLine::from("line")));
+	render_slice.set_notification(Some("Saved"));
+	render_view_data(&mut view, &mut render_slice, &view_data);
+
+	assert_screen(&view.display, &["line", "~", "              Saved "]);
+}
+
+#[test]
+fn render_notification_truncated() {
+	let (mut view, mut render_slice) = create_notification_view(10, 2);
+	let view_data = ViewData::new(|updater| updater.push_line(ViewLine::from("line")));
+	render_slice.set_notification(Some("A long notification"));
+	render_view_data(&mut view, &mut render_slice, &view_data);
+
+	assert_screen(&view.display, &["line", " A long no"]);
+}
+
+#[test]
+fn render_notification_hidden() {
+	let (mut view, mut render_slice) = create_notification_view(20, 2);
+	let view_data = ViewData::new(|updater| updater.push_line(ViewLine::from("line")));
+	render_slice.set_notification(Some("Saved"));
+	render_view_data(&mut view, &mut render_slice, &view_data);
+	render_slice.set_notification(None);
+	render_view_data(&mut view, &mut render_slice, &view_data);
+
+	assert_screen(&view.display, &["line", "~"]);
+}
+
+#[test]
+fn render_all_lines_after_resize() {
+	let mut crossterm = CrossTerm::new();
+	crossterm.set_size(Size::new(20, 2));
+	let mut view = View::new(Display::new(crossterm, &Theme::new()), "~", "?");
+	let mut render_slice = RenderSlice::new();
+	render_slice.record_resize(20, 2);
+	let view_data = ViewData::new(|updater| updater.push_line(ViewLine::from("line")));
+	render_view_data(&mut view, &mut render_slice, &view_data);
+	set_display_size(&mut view.display, Size::new(30, 2));
+	render_slice.record_resize(30, 2);
+	render_view_data(&mut view, &mut render_slice, &view_data);
+
+	assert_output(&view.display, &["line", "~"]);
+}
+
+#[test]
+fn render_all_lines_after_start() {
+	let mut crossterm = CrossTerm::new();
+	crossterm.set_size(Size::new(20, 1));
+	let mut view = View::new(Display::new(crossterm, &Theme::new()), "~", "?");
+	let mut render_slice = RenderSlice::new();
+	render_slice.record_resize(20, 1);
+	let mut view_data = ViewData::new(|updater| updater.push_line(ViewLine::from("line")));
+	render_view_data(&mut view, &mut render_slice, &view_data);
+	view.start().unwrap();
+	view_data.update_view_data(|updater| updater.set_show_help(false));
+	render_view_data(&mut view, &mut render_slice, &view_data);
+
+	assert_output(&view.display, &["line"]);
+}
+
+/// A terminal interface that writes the same commands as the terminal interface of the display crate to a buffer,
+/// so that the number of bytes written by a render can be measured.
+#[derive(Debug)]
+struct BufferedTerminal {
+	buffer: Rc<RefCell<Vec<u8>>>,
+	size: Size,
+}
+
+impl BufferedTerminal {
+	fn queue(&mut self, command: impl crossterm::Command) -> Result<()> {
+		let _ = self.buffer.borrow_mut().queue(command)?;
+		Ok(())
+	}
+}
+
+impl Tui for BufferedTerminal {
+	fn get_color_mode(&self) -> ColorMode {
+		ColorMode::TrueColor
+	}
+
+	fn reset(&mut self) -> Result<()> {
+		self.queue(ResetColor)?;
+		self.queue(SetAttribute(Attribute::Reset))?;
+		self.queue(Clear(ClearType::All))?;
+		self.queue(MoveTo(0, 0))
+	}
+
+	fn flush(&mut self) -> Result<()> {
+		Ok(self.buffer.borrow_mut().flush()?)
+	}
+
+	fn print(&mut self, s: &str) -> Result<()> {
+		self.queue(Print(s))
+	}
+
+	fn set_color(&mut self, colors: Colors) -> Result<()> {
+		self.queue(SetColors(colors))
+	}
+
+	fn set_dim(&mut self, dim: bool) -> Result<()> {
+		self.queue(SetAttribute(
+			if dim {
+				Attribute::Dim
+			}
+			else {
+				Attribute::NormalIntensity
+			},
+		))
+	}
+
+	fn set_underline(&mut self, underline: bool) -> Result<()> {
+		self.queue(SetAttribute(
+			if underline {
+				Attribute::Underlined
+			}
+			else {
+				Attribute::NoUnderline
+			},
+		))
+	}
+
+	fn set_reverse(&mut self, reverse: bool) -> Result<()> {
+		self.queue(SetAttribute(
+			if reverse {
+				Attribute::Reverse
+			}
+			else {
+				Attribute::NoReverse
+			},
+		))
+	}
+
+	fn read_event() -> Result<Option<Event>> {
+		Ok(None)
+	}
+
+	fn get_size(&self) -> Size {
+		self.size
+	}
+
+	fn move_to_column(&mut self, x: u16) -> Result<()> {
+		self.queue(MoveToColumn(x))
+	}
+
+	fn move_next_line(&mut self) -> Result<()> {
+		self.queue(MoveToNextLine(1))
+	}
+
+	fn move_to_line(&mut self, y: u16) -> Result<()> {
+		self.queue(MoveTo(0, y))
+	}
+
+	fn clear_until_end_of_line(&mut self) -> Result<()> {
+		self.queue(Clear(ClearType::UntilNewLine))
+	}
+
+	fn start(&mut self) -> Result<()> {
+		Ok(())
+	}
+
+	fn end(&mut self) -> Result<()> {
+		Ok(())
+	}
+}
+
+// Moving the selected line of a list, on a large terminal, only writes the lines that changed from the previous frame,
+// which is a fraction of the bytes of a full render.
+#[test]
+fn moving_selection_writes_only_changed_lines() {
+	let size = Size::new(200, 60);
+	let buffer = Rc::new(RefCell::new(vec![]));
+	let buffered_terminal = BufferedTerminal {
+		buffer: Rc::clone(&buffer),
+		size,
+	};
+	let mut view = View::new(Display::new(buffered_terminal, &Theme::new()), "~", "?");
+	let mut render_slice = RenderSlice::new();
+	render_slice.record_resize(size.width(), size.height());
+
+	render_view_data(&mut view, &mut render_slice, &create_list_view_data(10));
+	let full_render_bytes = buffer.borrow().len();
+
+	buffer.borrow_mut().clear();
+	render_view_data(&mut view, &mut render_slice, &create_list_view_data(11));
+	let diff_render_bytes = buffer.borrow().len();
+
+	assert!(diff_render_bytes * 10 < full_render_bytes);
+}
+
+#[test]
+fn render_unchanged_frame_writes_nothing() {
+	let size = Size::new(20, 3);
+	let buffer = Rc::new(RefCell::new(vec![]));
+	let buffered_terminal = BufferedTerminal {
+		buffer: Rc::clone(&buffer),
+		size,
+	};
+	let mut view = View::new(Display::new(buffered_terminal, &Theme::new()), "~", "?");
+	let mut render_slice = RenderSlice::new();
+	render_slice.record_resize(size.width(), size.height());
+	let mut view_data = ViewData::new(|updater| updater.push_line(ViewLine::from("line")));
+	render_view_data(&mut view, &mut render_slice, &view_data);
+
+	buffer.borrow_mut().clear();
+	view_data.update_view_data(|updater| {
+		updater.clear();
+		updater.push_line(ViewLine::from("line"));
+	});
+	render_view_data(&mut view, &mut render_slice, &view_data);
+	assert!(buffer.borrow().is_empty());
+}
@@ -1,4 +1,6 @@
 //! Utilities for writing tests that interact with input events.
+mod snapshot;
+
 use std::{
 	sync::{mpsc, mpsc::Receiver},
 	time::Duration,
@@ -6,6 +8,7 @@ use std::{
 
 use display::DisplayColor;
 
+pub use self::snapshot::{_assert_snapshot, render_frame, UPDATE_SNAPSHOTS_VARIABLE};
 use super::{action::ViewAction, render_slice::RenderAction, view_data::ViewData, view_line::ViewLine, ViewSender};
 
 const STARTS_WITH: &str = "{{StartsWith}}";
@@ -367,6 +370,8 @@ fn action_to_string(action: ViewAction) -> String {
 		ViewAction::Stop => "Stop",
 		ViewAction::Refresh => "Refresh",
 		ViewAction::Render => "Render",
+		ViewAction::UpdateTheme => "UpdateTheme",
+		ViewAction::CopyToClipboard => "CopyToClipboard",
 		ViewAction::Start => "Start",
 		ViewAction::End => "End",
 	})
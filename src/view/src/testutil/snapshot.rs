@@ -0,0 +1,146 @@
+use std::{
+	env::var_os,
+	fs::{create_dir_all, read_to_string, write},
+	path::Path,
+};
+
+use config::Theme;
+use display::{testutil::CrossTerm, Display, Size};
+
+use super::{render_style, replace_invisibles};
+use crate::{render_slice::RenderSlice, View, ViewData};
+
+/// The environment variable that, when set, replaces the stored snapshots with the rendered frames.
+pub const UPDATE_SNAPSHOTS_VARIABLE: &str = "GIRT_UPDATE_SNAPSHOTS";
+
+/// Render a `ViewData` to the lines of a frame, as the `View` would draw it in a window of a size, including the title,
+/// the padding and the scroll bar. The text of each line is preceded by the style that it is drawn with, as
+/// `{Color,Style}`, which is only repeated when the style changes within the line, and selected lines start with
+/// `{Selected}`. Trailing whitespace is removed, so that the snapshots are not changed by editors.
+#[must_use]
+#[inline]
+pub fn render_frame(view_data: &ViewData, size: Size) -> Vec<String> {
+	let mut crossterm = CrossTerm::new();
+	crossterm.set_size(size);
+	let view = View::new(Display::new(crossterm, &Theme::new()), "~", "?");
+	let mut render_slice = RenderSlice::new();
+	render_slice.record_resize(size.width(), size.height());
+	render_slice.sync_view_data(view_data);
+
+	view.build_frame(&render_slice)
+		.lines()
+		.iter()
+		.map(|frame_line| {
+			let mut line = String::new();
+			if frame_line.segments().iter().any(|segment| segment.selected) {
+				line.push_str("{Selected}");
+			}
+			let mut previous_style = None;
+			for segment in frame_line.segments() {
+				let style = render_style(segment.color, segment.dim, segment.underline, segment.reverse);
+				if previous_style.as_ref() != Some(&style) {
+					line.push_str(style.as_str());
+				}
+				line.push_str(segment.text.as_ref());
+				previous_style = Some(style);
+			}
+			if let Some(ref scroll_indicator) = frame_line.scroll_indicator {
+				line.push_str(format!("{{ScrollBar}}{}", scroll_indicator.text).as_str());
+			}
+			String::from(line.trim_end())
+		})
+		.collect()
+}
+
+fn format_snapshot(size: Size, lines: &[String]) -> String {
+	format!("# Size: {}x{}\n{}\n", size.width(), size.height(), lines.join("\n"))
+}
+
+/// Assert that the frame rendered from a `ViewData`, by `render_frame`, matches the snapshot stored in a file. A
+/// missing snapshot is written and fails the assertion, so that it can be reviewed before it is committed, and all
+/// snapshots are replaced when the `GIRT_UPDATE_SNAPSHOTS` environment variable is set. Generally this function is not
+/// used directly, instead use the `assert_snapshot!` macro.
+///
+/// # Panics
+/// Will panic if the frame does not match the snapshot, the snapshot is missing, or the snapshot cannot be written.
+#[inline]
+pub fn _assert_snapshot(view_data: &ViewData, width: usize, height: usize, snapshot_path: &Path) {
+	let size = Size::new(width, height);
+	let actual = format_snapshot(size, render_frame(view_data, size).as_slice());
+	let write_snapshot = || {
+		if let Some(directory) = snapshot_path.parent() {
+			create_dir_all(directory).expect("Unable to create the snapshot directory");
+		}
+		write(snapshot_path, actual.as_str()).expect("Unable to write the snapshot");
+	};
+
+	if var_os(UPDATE_SNAPSHOTS_VARIABLE).is_some() {
+		write_snapshot();
+		return;
+	}
+
+	let expected = if let Ok(expected) = read_to_string(snapshot_path) {
+		expected
+	}
+	else {
+		write_snapshot();
+		panic!(
+			"\nNew snapshot written to {}, review it and run the test again\n",
+			snapshot_path.display()
+		);
+	};
+
+	if expected == actual {
+		return;
+	}
+
+	let expected_lines = expected.lines().collect::<Vec<&str>>();
+	let actual_lines = actual.lines().collect::<Vec<&str>>();
+	let mut error_output = vec![
+		format!("\nSnapshot mismatch: {}", snapshot_path.display()),
+		String::from("--- Snapshot"),
+		String::from("+++ Actual"),
+		String::from("=========="),
+	];
+	for index in 0..expected_lines.len().max(actual_lines.len()) {
+		match (expected_lines.get(index), actual_lines.get(index)) {
+			(Some(expected_line), Some(actual_line)) if expected_line == actual_line => {
+				error_output.push(format!(" {}", replace_invisibles(expected_line)));
+			},
+			(expected_line, actual_line) => {
+				if let Some(line) = expected_line {
+					error_output.push(format!("-{}", replace_invisibles(line)));
+				}
+				if let Some(line) = actual_line {
+					error_output.push(format!("+{}", replace_invisibles(line)));
+				}
+			},
+		}
+	}
+	error_output.push(format!(
+		"==========\nSet {} to update the snapshot\n",
+		UPDATE_SNAPSHOTS_VARIABLE
+	));
+	panic!("{}", error_output.join("\n"));
+}
+
+/// Assert that the frame rendered from a `ViewData` matches a stored snapshot, in the `snapshots` directory of the
+/// crate of the test, named `<name>.snap`. The frame is rendered at 80 columns by 20 rows, unless a width and height
+/// are provided.
+#[macro_export]
+macro_rules! assert_snapshot {
+	($name:expr, $view_data:expr) => {
+		view::assert_snapshot!($name, $view_data, 80, 20)
+	};
+	($name:expr, $view_data:expr, $width:expr, $height:expr) => {
+		view::testutil::_assert_snapshot(
+			$view_data,
+			$width,
+			$height,
+			std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+				.join("snapshots")
+				.join(format!("{}.snap", $name))
+				.as_path(),
+		)
+	};
+}
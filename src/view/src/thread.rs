@@ -1,50 +1,82 @@
 use std::{
 	borrow::Borrow,
-	sync::{atomic::Ordering, mpsc},
-	thread::{sleep, spawn, JoinHandle},
+	sync::{
+		atomic::Ordering,
+		mpsc::{self, RecvTimeoutError},
+	},
+	thread::{spawn, JoinHandle},
 	time::{Duration, Instant},
 };
 
 use display::Tui;
 
-use super::{action::ViewAction, sender::Sender, View};
+use super::{action::ViewAction, render_scheduler::RenderScheduler, sender::Sender, View};
 
 const MINIMUM_TICK_RATE: Duration = Duration::from_millis(20); // ~50 Hz update
 
-/// Spawn a thread that will handle rendering contents to the `View`.
+/// Spawn a thread that will handle rendering contents to the `View`. Renders are limited to the tick rate, with any
+/// renders queued in the meantime coalesced into a single render, and the thread sleeps while there is nothing to
+/// render.
 ///
 /// # Panics
 /// This may panic if there is an unexpected error in the processing of the `View`, i.e. a bug.
 #[inline]
 pub fn spawn_view_thread<T: Tui + Send + 'static>(mut view: View<T>) -> (Sender, JoinHandle<()>) {
 	let (sender, receiver) = mpsc::channel();
-	let view_sender = Sender::new(sender.clone());
+	let view_sender = Sender::new(sender);
 	let view_render_slice = view_sender.clone_render_slice();
 	let crashed = view_sender.clone_poisoned();
+	let view_theme = view_sender.clone_theme();
+	let view_clipboard = view_sender.clone_clipboard();
 
 	let thread = spawn(move || {
-		let mut last_render_time = Instant::now() + MINIMUM_TICK_RATE;
-		let mut should_render = true;
-		for msg in receiver {
+		let mut scheduler = RenderScheduler::new(MINIMUM_TICK_RATE);
+		loop {
+			let msg = match scheduler.timeout(Instant::now()) {
+				Some(timeout) => {
+					match receiver.recv_timeout(timeout) {
+						Ok(msg) => Some(msg),
+						Err(RecvTimeoutError::Timeout) => None,
+						Err(RecvTimeoutError::Disconnected) => break,
+					}
+				},
+				None => {
+					match receiver.recv() {
+						Ok(msg) => Some(msg),
+						Err(_) => break,
+					}
+				},
+			};
 			let mut err = false;
 			match msg {
-				ViewAction::Render => should_render = true,
-				ViewAction::Start => {
+				Some(ViewAction::Render) => scheduler.request(),
+				Some(ViewAction::Start) => {
 					if view.start().is_err() {
 						err = true;
 					}
 				},
-				ViewAction::Stop => {
+				Some(ViewAction::Stop) => {
 					if view.end().is_err() {
 						err = true;
 					}
 				},
-				ViewAction::Refresh => {},
-				ViewAction::End => break,
+				Some(ViewAction::UpdateTheme) => {
+					if let Some(theme) = view_theme.lock().unwrap().take() {
+						view.set_theme(&theme);
+						scheduler.request();
+					}
+				},
+				Some(ViewAction::CopyToClipboard) => {
+					if let Some(text) = view_clipboard.lock().unwrap().take() {
+						if view.copy_to_clipboard(text.as_str()).is_err() {
+							err = true;
+						}
+					}
+				},
+				Some(ViewAction::Refresh) | None => {},
+				Some(ViewAction::End) => break,
 			}
-			if should_render && Instant::now() >= last_render_time {
-				last_render_time += MINIMUM_TICK_RATE;
-				should_render = false;
+			if scheduler.should_render(Instant::now()) {
 				let render_slice = view_render_slice.lock().unwrap();
 				if view.render(render_slice.borrow()).is_err() {
 					err = true;
@@ -56,14 +88,5 @@ pub fn spawn_view_thread<T: Tui + Send + 'static>(mut view: View<T>) -> (Sender,
 		}
 	});
 
-	let _refresh_thread = spawn(move || {
-		let sleep_time = MINIMUM_TICK_RATE / 2;
-		let mut time = Instant::now();
-		while sender.send(ViewAction::Refresh).is_ok() {
-			sleep(time.saturating_duration_since(Instant::now()));
-			time += sleep_time;
-		}
-	});
-
 	(view_sender, thread)
 }
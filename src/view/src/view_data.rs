@@ -7,6 +7,8 @@ use super::{ViewDataUpdater, ViewLine};
 pub struct ViewData {
 	lines: Vec<ViewLine>,
 	lines_leading: Vec<ViewLine>,
+	lines_length: Option<usize>,
+	lines_offset: usize,
 	lines_trailing: Vec<ViewLine>,
 	name: String,
 	retain_scroll_position: bool,
@@ -26,6 +28,8 @@ impl ViewData {
 		let mut view_data = Self {
 			lines: vec![],
 			lines_leading: vec![],
+			lines_length: None,
+			lines_offset: 0,
 			lines_trailing: vec![],
 			name: Uuid::new_v4().to_hyphenated().to_string(),
 			retain_scroll_position: true,
@@ -65,11 +69,20 @@ impl ViewData {
 	pub(crate) fn clear(&mut self) {
 		self.lines_leading.clear();
 		self.lines.clear();
+		self.lines_length = None;
+		self.lines_offset = 0;
 		self.lines_trailing.clear();
 	}
 
 	pub(crate) fn clear_body(&mut self) {
 		self.lines.clear();
+		self.lines_length = None;
+		self.lines_offset = 0;
+	}
+
+	pub(crate) fn set_virtual_lines(&mut self, offset: usize, length: usize) {
+		self.lines_offset = offset;
+		self.lines_length = Some(length);
 	}
 
 	pub(crate) fn ensure_line_visible(&mut self, row_index: usize) {
@@ -124,6 +137,14 @@ impl ViewData {
 		&self.lines
 	}
 
+	pub(crate) const fn get_lines_offset(&self) -> usize {
+		self.lines_offset
+	}
+
+	pub(crate) fn get_lines_length(&self) -> usize {
+		self.lines_length.unwrap_or_else(|| self.lines.len())
+	}
+
 	pub(crate) const fn get_trailing_lines(&self) -> &Vec<ViewLine> {
 		&self.lines_trailing
 	}
@@ -258,6 +279,18 @@ mod tests {
 		assert_eq!(view_data.get_trailing_lines().len(), 1);
 	}
 
+	#[test]
+	fn set_virtual_lines() {
+		let mut view_data = ViewData::new(|_| {});
+		view_data.push_line(ViewLine::new_empty_line());
+		view_data.set_virtual_lines(10, 100);
+		assert_eq!(view_data.get_lines_offset(), 10);
+		assert_eq!(view_data.get_lines_length(), 100);
+		view_data.clear_body();
+		assert_eq!(view_data.get_lines_offset(), 0);
+		assert_eq!(view_data.get_lines_length(), 0);
+	}
+
 	#[test]
 	fn set_retain_scroll_position() {
 		let mut view_data = ViewData::new(|_| {});
@@ -81,6 +81,15 @@ impl<'v> ViewDataUpdater<'v> {
 		}
 	}
 
+	/// Set the pushed body lines to be a window of a larger body, starting at the `offset` of a body with `length`
+	/// lines, so that only the lines that can be visible are built. The pushed lines must include at least a view
+	/// height of lines around the line that must be visible.
+	#[inline]
+	pub fn set_virtual_lines(&mut self, offset: usize, length: usize) {
+		self.modified = true;
+		self.view_data.set_virtual_lines(offset, length);
+	}
+
 	/// Push a new trailing line to the view data.
 	#[inline]
 	pub fn push_trailing_line(&mut self, view_line: ViewLine) {
@@ -197,6 +206,15 @@ mod tests {
 		assert_eq!(view_data.get_trailing_lines().len(), 1);
 	}
 
+	#[test]
+	fn set_virtual_lines() {
+		let mut view_data = ViewData::new(|_| {});
+		let mut updater = ViewDataUpdater::new(&mut view_data);
+		updater.set_virtual_lines(10, 100);
+		assert!(updater.is_modified());
+		assert_eq!(view_data.get_lines_length(), 100);
+	}
+
 	#[test]
 	fn set_retain_scroll_position() {
 		let mut view_data = ViewData::new(|_| {});
@@ -100,6 +100,29 @@ impl ViewLine {
 	pub(crate) const fn get_padding(&self) -> &Option<LineSegment> {
 		&self.padding
 	}
+
+	/// Clear the line to render another line into it, keeping its segments, so that their memory is reused.
+	pub(crate) fn reuse(&mut self, pinned_segments: usize, selected: bool) {
+		self.pinned_segments = pinned_segments;
+		self.selected = selected;
+		self.padding = None;
+	}
+
+	/// Get the segment at `index` to be replaced, reusing the segment of the previous contents of the line when there
+	/// is one. The segments are replaced in order, and the segments past the last one replaced are removed with
+	/// `truncate_segments`.
+	pub(crate) fn segment_mut(&mut self, index: usize) -> &mut LineSegment {
+		if index >= self.segments.len() {
+			self.segments.push(LineSegment::from(""));
+		}
+		self.segments
+			.get_mut(index)
+			.expect("segment must exist after it is pushed")
+	}
+
+	pub(crate) fn truncate_segments(&mut self, length: usize) {
+		self.segments.truncate(length);
+	}
 }
 
 impl From<&str> for ViewLine {
@@ -112,7 +135,7 @@ impl From<&str> for ViewLine {
 impl From<String> for ViewLine {
 	#[inline]
 	fn from(line: String) -> Self {
-		Self::from(LineSegment::new(line.as_str()))
+		Self::from(LineSegment::from(line))
 	}
 }
 
@@ -0,0 +1,171 @@
+//! Checks that syncing the render slice with updated view data, that has the same lines as the previous render, and
+//! rendering the slice, do not allocate. The test has a binary of its own, so that its counting allocator is only the
+//! global allocator of this test.
+use std::{
+	alloc::{GlobalAlloc, Layout, System},
+	sync::{
+		atomic::{AtomicBool, AtomicUsize, Ordering},
+		mpsc,
+	},
+};
+
+use anyhow::Result;
+use config::Theme;
+use crossterm::{event::Event, style::Colors};
+use display::{ColorMode, Display, DisplayColor, Size, Tui};
+use view::{LineSegment, View, ViewData, ViewDataUpdater, ViewLine, ViewSender};
+
+const WIDTH: u16 = 200;
+const HEIGHT: u16 = 60;
+
+// the allocations are only counted while this is set, and this is the only test of the binary, so there are no
+// allocations from other tests
+static COUNTING: AtomicBool = AtomicBool::new(false);
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+struct CountingAllocator;
+
+impl CountingAllocator {
+	fn count() {
+		if COUNTING.load(Ordering::SeqCst) {
+			let _ = ALLOCATIONS.fetch_add(1, Ordering::SeqCst);
+		}
+	}
+}
+
+// SAFETY: the allocations are made by the system allocator, with the layouts that are given to this allocator
+unsafe impl GlobalAlloc for CountingAllocator {
+	unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+		Self::count();
+		System.alloc(layout)
+	}
+
+	unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+		System.dealloc(ptr, layout);
+	}
+
+	unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+		Self::count();
+		System.realloc(ptr, layout, new_size)
+	}
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+fn count_allocations<F: FnOnce()>(callback: F) -> usize {
+	ALLOCATIONS.store(0, Ordering::SeqCst);
+	COUNTING.store(true, Ordering::SeqCst);
+	callback();
+	COUNTING.store(false, Ordering::SeqCst);
+	ALLOCATIONS.load(Ordering::SeqCst)
+}
+
+// a terminal that discards its output, so that only the allocations of the view are counted
+struct NullTerminal;
+
+impl Tui for NullTerminal {
+	fn get_color_mode(&self) -> ColorMode {
+		ColorMode::TrueColor
+	}
+
+	fn reset(&mut self) -> Result<()> {
+		Ok(())
+	}
+
+	fn flush(&mut self) -> Result<()> {
+		Ok(())
+	}
+
+	fn print(&mut self, _: &str) -> Result<()> {
+		Ok(())
+	}
+
+	fn set_color(&mut self, _: Colors) -> Result<()> {
+		Ok(())
+	}
+
+	fn set_dim(&mut self, _: bool) -> Result<()> {
+		Ok(())
+	}
+
+	fn set_underline(&mut self, _: bool) -> Result<()> {
+		Ok(())
+	}
+
+	fn set_reverse(&mut self, _: bool) -> Result<()> {
+		Ok(())
+	}
+
+	fn read_event() -> Result<Option<Event>> {
+		Ok(None)
+	}
+
+	fn get_size(&self) -> Size {
+		Size::new(usize::from(WIDTH), usize::from(HEIGHT))
+	}
+
+	fn move_to_column(&mut self, _: u16) -> Result<()> {
+		Ok(())
+	}
+
+	fn move_next_line(&mut self) -> Result<()> {
+		Ok(())
+	}
+
+	fn move_to_line(&mut self, _: u16) -> Result<()> {
+		Ok(())
+	}
+
+	fn clear_until_end_of_line(&mut self) -> Result<()> {
+		Ok(())
+	}
+
+	fn start(&mut self) -> Result<()> {
+		Ok(())
+	}
+
+	fn end(&mut self) -> Result<()> {
+		Ok(())
+	}
+}
+
+fn push_list_lines(updater: &mut ViewDataUpdater<'_>) {
+	updater.clear();
+	for index in 0..100 {
+		let line = ViewLine::from(vec![
+			LineSegment::new_with_color(" pick   ", DisplayColor::ActionPick),
+			LineSegment::new(format!("{:08x} ", index * 12345).as_str()),
+			LineSegment::new(format!("A commit with a reasonably long summary, number {}", index).as_str()),
+		]);
+		updater.push_line(line.set_selected(index == 10));
+	}
+}
+
+// the view data is updated before the allocations are counted, since building the lines of view data allocates them,
+// and only the sync of the render slice with the view data and the render of the slice are counted
+#[test]
+fn render_updated_view_data_without_allocating() {
+	let mut view = View::new(Display::new(NullTerminal, &Theme::new()), "~", "?");
+	// the render slice of the view is created by a sender
+	let (sender, _receiver) = mpsc::channel();
+	let view_sender = ViewSender::new(sender);
+	view_sender.resize(WIDTH, HEIGHT);
+	let render_slice = view_sender.clone_render_slice();
+	let mut view_data = ViewData::new(|updater| updater.set_show_title(true));
+	// the lines of the slice, and of the two frames that are compared, are allocated by the first renders
+	for _ in 0..3 {
+		view_data.update_view_data(push_list_lines);
+		let mut slice = render_slice.lock().unwrap();
+		slice.sync_view_data(&view_data);
+		view.render(&slice).unwrap();
+	}
+
+	view_data.update_view_data(push_list_lines);
+	let allocations = count_allocations(|| {
+		let mut slice = render_slice.lock().unwrap();
+		slice.sync_view_data(&view_data);
+		view.render(&slice).unwrap();
+	});
+	assert_eq!(allocations, 0);
+}